@@ -0,0 +1,184 @@
+//! Bare-Number Clarification for DomainAgent
+//!
+//! "I need five" could mean five thousand, five lakh or five grams. When the
+//! intent detector flags a bare number while the goal still needs an amount
+//! or weight, the agent asks which one was meant instead of committing a
+//! guess to the dialogue state, then fills the slot from the user's answer
+//! ("lakh"). The question is the slot's configured `clarification_prompts`
+//! entry in the caller's language.
+
+use super::DomainAgent;
+use crate::dst::ChangeSource;
+use crate::intent::DetectedIntent;
+
+/// Confidence of a slot filled from a clarified bare number
+const CLARIFIED_CONFIDENCE: f32 = 0.9;
+
+/// Weight slots (canonical and legacy names) a bare number may be meant for
+const WEIGHT_SLOTS: &[&str] = &["asset_quantity", "gold_weight", "gold_weight_grams"];
+
+/// Amount slots (canonical and legacy names) a bare number may be meant for
+const AMOUNT_SLOTS: &[&str] = &["offer_amount", "requested_amount", "loan_amount", "amount"];
+
+/// Slot a bare number is being clarified for
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum AmountKind {
+    LoanAmount,
+    GoldWeight,
+}
+
+impl AmountKind {
+    /// Dialogue state slot filled once the number is clarified
+    fn slot(&self) -> &'static str {
+        match self {
+            AmountKind::LoanAmount => "loan_amount",
+            AmountKind::GoldWeight => "gold_weight",
+        }
+    }
+
+    /// Slot names the kind goes by in the domain config
+    fn slots(&self) -> &'static [&'static str] {
+        match self {
+            AmountKind::LoanAmount => AMOUNT_SLOTS,
+            AmountKind::GoldWeight => WEIGHT_SLOTS,
+        }
+    }
+
+    /// Multiplier picked by the user's answer, if it names one
+    fn scale(&self, answer: &str) -> Option<f64> {
+        let has = |words: &[&str]| words.iter().any(|w| answer.contains(w));
+        match self {
+            AmountKind::LoanAmount => {
+                if has(&["crore", "करोड़"]) {
+                    Some(10_000_000.0)
+                } else if has(&["lakh", "lac", "लाख"]) {
+                    Some(100_000.0)
+                } else if has(&["thousand", "hazar", "हज़ार", "हजार"]) {
+                    Some(1_000.0)
+                } else if has(&["rupee", "rupaye"]) {
+                    Some(1.0)
+                } else {
+                    None
+                }
+            },
+            AmountKind::GoldWeight => {
+                if has(&["tola", "तोला"]) {
+                    Some(11.66)
+                } else if has(&["gram", "gm", "ग्राम"]) {
+                    Some(1.0)
+                } else {
+                    None
+                }
+            },
+        }
+    }
+}
+
+/// Bare number awaiting the user's choice of scale or unit
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct PendingAmount {
+    pub kind: AmountKind,
+    pub value: f64,
+}
+
+/// "5" rather than "5.0"; fractional values keep two decimals
+fn format_number(value: f64) -> String {
+    if value.fract() == 0.0 {
+        format!("{}", value as i64)
+    } else {
+        format!("{:.2}", value)
+    }
+}
+
+impl DomainAgent {
+    /// Question to ask when the user's turn gives only a bare number
+    ///
+    /// Only asked while the current goal is missing an amount or weight slot
+    /// that has a clarification prompt configured. An answer to a previous
+    /// question is resolved into the slot first and never asked about again,
+    /// so an unhelpful answer falls back to the regular slot prompts.
+    pub(super) fn amount_clarification(
+        &self,
+        user_input: &str,
+        intent: &DetectedIntent,
+    ) -> Option<String> {
+        let pending = self.pending_amount.write().take();
+        if let Some(pending) = pending {
+            self.resolve_pending_amount(&pending, user_input, intent);
+            return None;
+        }
+
+        let number = intent.ambiguous_number.as_ref()?;
+        let missing = self.current_goal_missing_slots();
+        let wants = |slots: &[&str]| missing.iter().any(|s| slots.contains(&s.as_str()));
+        let kind = if wants(AMOUNT_SLOTS) {
+            AmountKind::LoanAmount
+        } else if wants(WEIGHT_SLOTS) {
+            AmountKind::GoldWeight
+        } else {
+            return None;
+        };
+        let question = self.clarification_question(kind, &format_number(number.value))?;
+
+        tracing::debug!(
+            number = %number.text,
+            slot = kind.slot(),
+            "Bare number without a unit, asking to clarify"
+        );
+        *self.pending_amount.write() = Some(PendingAmount {
+            kind,
+            value: number.value,
+        });
+        Some(question)
+    }
+
+    /// The kind's configured clarification prompt, in the user's language
+    ///
+    /// Falls back to the English prompt, which is translated like other
+    /// responses.
+    fn clarification_question(&self, kind: AmountKind, number: &str) -> Option<String> {
+        let slots = self.domain_view.as_ref()?.slots_config();
+        let prompts = kind.slots().iter().find_map(|name| {
+            slots
+                .get_slot(name)
+                .and_then(|slot| slot.clarification_prompts.as_ref())
+        })?;
+        let prompt = prompts
+            .get(self.user_language().code())
+            .or_else(|| prompts.get("en"))?;
+        Some(prompt.replace("{number}", number))
+    }
+
+    /// Fill the clarified slot from the user's answer
+    fn resolve_pending_amount(
+        &self,
+        pending: &PendingAmount,
+        answer: &str,
+        intent: &DetectedIntent,
+    ) {
+        // The answer restated the amount with its unit ("5 lakh"); already tracked
+        if intent
+            .slots
+            .get(pending.kind.slot())
+            .is_some_and(|s| s.value.is_some())
+        {
+            return;
+        }
+
+        let Some(scale) = pending.kind.scale(&answer.to_lowercase()) else {
+            tracing::debug!(answer = %answer, "Clarification answer named no unit");
+            return;
+        };
+
+        let value = format_number(pending.value * scale);
+        let mut dst = self.dialogue_state.write();
+        let turn = dst.history().len();
+        dst.update_slot(
+            pending.kind.slot(),
+            &value,
+            CLARIFIED_CONFIDENCE,
+            ChangeSource::UserUtterance,
+            turn,
+        );
+    }
+}
@@ -0,0 +1,233 @@
+//! Consent Capture for DomainAgent
+//!
+//! At call start the agent gives the config-driven AI disclosure and asks
+//! for recording consent. The answer is stored as a `ConsentRecord` on the
+//! conversation and written to the audit log; recording and transcript
+//! retention check `recording_allowed()` before doing anything.
+//!
+//! When the call ends, `end_call` gives a disclosure that was never spoken,
+//! audits any compliance violation that is still outstanding, records the
+//! session's experiment variants and pushes the call summary to the CRM.
+
+use std::sync::Arc;
+
+use voice_agent_config::domain::ComplianceConfig;
+use voice_agent_core::Language;
+use voice_agent_persistence::AuditLogger;
+
+use super::{DomainAgent, OpeningState};
+use crate::agent_config::AgentEvent;
+use crate::conversation::{ConversationEvent, ConversationState, EndReason};
+use crate::AgentError;
+
+/// Audit note recorded when the customer refuses recording
+const REFUSAL_AUDIT_NOTE: &str =
+    "Customer declined; call recording and transcript retention disabled";
+
+/// Audit note recorded when a refusal during the opening ends the call
+const OPENING_REFUSAL_AUDIT_NOTE: &str = "Customer declined during the opening; call ended";
+
+impl DomainAgent {
+    /// Set audit logger for disclosure and consent events (RBI compliance)
+    pub fn with_audit_logger(mut self, audit_logger: Arc<AuditLogger>) -> Self {
+        self.audit_logger = Some(audit_logger);
+        self
+    }
+
+    /// Give the AI disclosure and ask for recording consent
+    ///
+    /// Call once when the call connects and speak the returned text. The
+    /// next user turn is treated as the answer to the consent question.
+    pub async fn begin_consent(&self) -> String {
+        let prompt = self.conversation.begin_consent();
+        self.audit_ai_disclosure(&self.conversation.get_ai_disclosure_message())
+            .await;
+
+        self.set_response_protected(true);
+        let _ = self.event_tx.send(AgentEvent::Response(prompt.clone()));
+        prompt
+    }
+
+    /// End the call, closing compliance gaps first
+    ///
+    /// If the AI disclosure was never given it is given now, and the
+    /// returned text should be spoken before hanging up. Violations still
+    /// outstanding are emitted as `ComplianceIncomplete` and audited, and
+    /// the call summary is pushed to the CRM (see `crm_summary`). Subscribers
+    /// get `ConversationEvent::Ended` once the call is closed.
+    ///
+    /// A call that already ended is left alone, so transports can end the
+    /// call when they close without repeating any of this.
+    pub async fn end_call(&self, reason: EndReason) -> Option<String> {
+        if self.conversation.state() == ConversationState::Ended {
+            return None;
+        }
+
+        let closing = if self.conversation.ai_disclosure_given() {
+            None
+        } else {
+            let disclosure = self.conversation.mark_ai_disclosed();
+            self.audit_ai_disclosure(&disclosure).await;
+            Some(disclosure)
+        };
+
+        let violations = self.conversation.outstanding_violations();
+        if !violations.is_empty() {
+            if let Some(ref audit) = self.audit_logger {
+                if let Err(e) = audit
+                    .log_compliance_incomplete(self.conversation.session_id(), &violations)
+                    .await
+                {
+                    tracing::warn!(error = %e, "Failed to audit incomplete compliance");
+                }
+            }
+            let _ = self.event_tx.send(AgentEvent::Conversation(
+                ConversationEvent::ComplianceIncomplete { violations },
+            ));
+        }
+
+        self.audit_experiments().await;
+        self.push_session_summary().await;
+
+        if let Some(ref text) = closing {
+            self.set_response_protected(true);
+            let _ = self.event_tx.send(AgentEvent::Response(text.clone()));
+        }
+        let _ = self
+            .event_tx
+            .send(AgentEvent::Conversation(ConversationEvent::Ended {
+                reason: reason.clone(),
+            }));
+        self.conversation.end(reason);
+        closing
+    }
+
+    /// Whether the customer agreed to recording (and transcript retention)
+    pub fn recording_allowed(&self) -> bool {
+        self.conversation.recording_allowed()
+    }
+
+    /// Handle the answer to the consent question asked by `begin_consent`
+    /// or the opening script
+    ///
+    /// Unclear answers repeat the question; refusals are recorded, audited
+    /// with a note, and the conversation continues without recording. During
+    /// the opening the script resumes after the answer, or the call ends if
+    /// the script says a refusal does.
+    pub(super) async fn handle_consent_answer(
+        &self,
+        user_input: &str,
+    ) -> Result<String, AgentError> {
+        let Some(record) = self.conversation.resolve_consent(user_input) else {
+            let reprompt = format!(
+                "{} {}",
+                self.localize("Sorry, I didn't catch that.").await,
+                self.conversation.consent_prompt()
+            );
+            self.set_response_protected(true);
+            let _ = self.event_tx.send(AgentEvent::Response(reprompt.clone()));
+            return Ok(reprompt);
+        };
+
+        let given = record.recording_allowed();
+        let ends_call = !given && self.opening_ends_on_refusal();
+        let _ = self.event_tx.send(AgentEvent::Conversation(
+            ConversationEvent::ConsentRecorded {
+                given,
+                method: record.consent_method,
+            },
+        ));
+
+        if let Some(ref audit) = self.audit_logger {
+            let method = format!("{:?}", record.consent_method);
+            let note = match (given, ends_call) {
+                (true, _) => None,
+                (false, true) => Some(OPENING_REFUSAL_AUDIT_NOTE),
+                (false, false) => Some(REFUSAL_AUDIT_NOTE),
+            };
+            if let Err(e) = audit
+                .log_consent_with_note(
+                    self.conversation.session_id(),
+                    "recording",
+                    given,
+                    &method,
+                    note,
+                )
+                .await
+            {
+                tracing::warn!(error = %e, "Failed to audit recording consent");
+            }
+        }
+
+        if ends_call {
+            return Ok(self.end_opening_on_refusal().await);
+        }
+
+        // The rest of the opening script stands in for "how can I help"
+        let script = match self.opening_state() {
+            OpeningState::AwaitingConsent { next } => Some(self.run_opening(next).await),
+            _ => None,
+        }
+        .filter(|script| !script.is_empty());
+        let mut response = self.consent_acknowledgement(given, script.is_none()).await;
+        if let Some(script) = script {
+            self.set_response_protected(true);
+            response = format!("{} {}", response, script);
+        }
+        let _ = self.event_tx.send(AgentEvent::Response(response.clone()));
+        Ok(response)
+    }
+
+    /// Reply to the consent answer in the user's language
+    ///
+    /// With `follow_up` the reply goes on to ask how the agent can help.
+    async fn consent_acknowledgement(&self, given: bool, follow_up: bool) -> String {
+        let Some(ref view) = self.domain_view else {
+            let defaults = ComplianceConfig::default();
+            let mut text = defaults
+                .get_consent_acknowledgement(given, "en")
+                .to_string();
+            if follow_up {
+                text = format!("{} {}", text, defaults.get_consent_follow_up("en"));
+            }
+            return self.localize(&text).await;
+        };
+
+        let language = self.user_language().code();
+        let acknowledgement = view.consent_acknowledgement(given, language);
+        if follow_up {
+            format!("{} {}", acknowledgement, view.consent_follow_up(language))
+        } else {
+            acknowledgement.to_string()
+        }
+    }
+
+    pub(super) async fn audit_ai_disclosure(&self, disclosure: &str) {
+        if let Some(ref audit) = self.audit_logger {
+            if let Err(e) = audit
+                .log_ai_disclosure(
+                    self.conversation.session_id(),
+                    &self.config.language,
+                    disclosure,
+                )
+                .await
+            {
+                tracing::warn!(error = %e, "Failed to audit AI disclosure");
+            }
+        }
+    }
+
+    /// Translate a fixed English response into the user's language
+    pub(super) async fn localize(&self, text: &str) -> String {
+        if self.user_language() == Language::English {
+            return text.to_string();
+        }
+        match self.translator {
+            Some(ref translator) => translator
+                .translate(text, Language::English, self.user_language())
+                .await
+                .unwrap_or_else(|_| text.to_string()),
+            None => text.to_string(),
+        }
+    }
+}
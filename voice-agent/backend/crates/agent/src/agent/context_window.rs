@@ -0,0 +1,49 @@
+//! Context Window Fitting for DomainAgent
+//!
+//! Both prompt builders tag what they add (memory, RAG, domain guidance,
+//! history) and pass the assembled prompt through `fit_context` before
+//! sending it. A prompt over `context_window_tokens` (less the reply
+//! reserve) is trimmed in `context_overflow.trim_order`, and what was
+//! dropped is logged and counted in `voice_agent_context_trimmed_total`.
+
+use metrics::counter;
+use voice_agent_llm::PromptBuilder;
+
+use super::DomainAgent;
+
+impl DomainAgent {
+    /// Trim the assembled prompt to fit the model's context window
+    pub(super) fn fit_context(&self, builder: PromptBuilder) -> PromptBuilder {
+        let overflow = &self.config.context_overflow;
+        if !overflow.enabled {
+            return builder;
+        }
+        let window = self
+            .config
+            .context_window_tokens
+            .saturating_sub(overflow.reserve_tokens);
+        let (builder, trim) = builder.fit_to_window(window, &overflow.trim_order);
+
+        for dropped in &trim.dropped {
+            counter!(
+                "voice_agent_context_trimmed_total",
+                "section" => dropped.section.as_str()
+            )
+            .increment(1);
+            tracing::info!(
+                section = dropped.section.as_str(),
+                items = dropped.items,
+                tokens = dropped.tokens,
+                "Dropped prompt context to fit the model window"
+            );
+        }
+        if trim.tokens_after > window {
+            tracing::warn!(
+                tokens = trim.tokens_after,
+                window,
+                "Prompt still exceeds the model window after trimming"
+            );
+        }
+        builder
+    }
+}
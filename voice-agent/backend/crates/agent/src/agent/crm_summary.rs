@@ -0,0 +1,322 @@
+//! End-of-Call CRM Summary for DomainAgent
+//!
+//! When the call closes, the sales team wants a short summary of the
+//! customer's needs, the objections raised, the outcome and what to do next.
+//! The LLM writes it from the full conversation memory; when the LLM is
+//! unavailable or its answer can't be parsed, a rule-based summary is built
+//! from memory, lead signals and the next best action. The summary is pushed
+//! to the CRM as a `CrmLead` and merged into the caller's existing lead when
+//! the CRM already has one for the normalized phone number or PAN.
+
+use std::collections::HashMap;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+
+use serde::Deserialize;
+use voice_agent_core::GenerateRequest;
+use voice_agent_tools::{
+    normalize_pan, normalize_phone, save_lead, CrmIntegration, CrmLead, InterestLevel, LeadSource,
+    LeadStatus,
+};
+
+use super::DomainAgent;
+use crate::dst::DialogueStateTrait;
+use crate::lead_scoring::{LeadQualification, NextBestAction};
+use crate::memory::TurnRole;
+
+const SUMMARY_SYSTEM_PROMPT: &str = "You summarize sales calls for a CRM. \
+    Reply with a single JSON object and nothing else.";
+
+/// Who wrote the summary
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SummarySource {
+    /// Written by the LLM
+    Llm,
+    /// Built from memory and lead signals
+    RuleBased,
+}
+
+/// End-of-call summary, structured for the CRM
+#[derive(Debug, Clone)]
+pub struct SessionSummary {
+    /// What the customer wants
+    pub customer_needs: String,
+    /// Objections the customer raised
+    pub objections: Vec<String>,
+    /// How the call ended
+    pub outcome: String,
+    /// What sales should do next
+    pub recommended_follow_up: String,
+    /// Interest level for the CRM lead
+    pub interest_level: InterestLevel,
+    /// Slots captured during the call
+    pub slots: HashMap<String, String>,
+    /// Who wrote the summary
+    pub source: SummarySource,
+}
+
+impl SessionSummary {
+    /// Human-readable summary for the lead's notes
+    pub fn notes(&self) -> String {
+        let objections = if self.objections.is_empty() {
+            "None".to_string()
+        } else {
+            self.objections.join(", ")
+        };
+        format!(
+            "Needs: {}\nObjections: {}\nOutcome: {}\nFollow-up: {}",
+            self.customer_needs, objections, self.outcome, self.recommended_follow_up
+        )
+    }
+
+    fn slot(&self, names: &[&str]) -> Option<&String> {
+        names.iter().find_map(|name| self.slots.get(*name))
+    }
+
+    /// CRM lead for this call, or None when no phone number was captured
+    pub fn to_crm_lead(&self) -> Option<CrmLead> {
+        let phone = self.slot(&["phone_number", "phone"])?;
+        let status = match self.interest_level {
+            InterestLevel::High => LeadStatus::Qualified,
+            _ => LeadStatus::Contacted,
+        };
+
+        Some(CrmLead {
+            id: None,
+            name: self
+                .slot(&["customer_name", "name"])
+                .cloned()
+                .unwrap_or_default(),
+            phone: normalize_phone(phone),
+            pan: self.slot(&["pan", "pan_number"]).map(|p| normalize_pan(p)),
+            email: self.slot(&["email"]).cloned(),
+            city: self.slot(&["city", "location"]).cloned(),
+            source: LeadSource::VoiceAgent,
+            interest_level: self.interest_level,
+            estimated_asset_value: self
+                .slot(&["asset_quantity", "gold_weight", "collateral_weight"])
+                .and_then(|v| v.replace(',', "").parse().ok()),
+            current_provider: self.slot(&["current_lender", "current_provider"]).cloned(),
+            notes: Some(self.notes()),
+            assigned_to: None,
+            status,
+        })
+    }
+}
+
+/// Summary fields the LLM is asked to fill
+#[derive(Debug, Deserialize)]
+struct LlmSummary {
+    customer_needs: String,
+    #[serde(default)]
+    objections: Vec<String>,
+    outcome: String,
+    #[serde(alias = "next_steps")]
+    recommended_follow_up: String,
+    #[serde(default)]
+    interest_level: Option<String>,
+}
+
+impl LlmSummary {
+    /// Parse the JSON object in an LLM reply, ignoring text around it
+    fn parse(reply: &str) -> Option<Self> {
+        let start = reply.find('{')?;
+        let end = reply.rfind('}')?;
+        serde_json::from_str(reply.get(start..=end)?).ok()
+    }
+}
+
+fn parse_interest_level(level: &str) -> Option<InterestLevel> {
+    match level.trim().to_lowercase().as_str() {
+        "high" => Some(InterestLevel::High),
+        "medium" => Some(InterestLevel::Medium),
+        "low" => Some(InterestLevel::Low),
+        _ => None,
+    }
+}
+
+fn summary_prompt(transcript: &str, max_words: usize) -> String {
+    format!(
+        "Summarize this call for the sales CRM in at most {} words. Reply with JSON:\n\
+        {{\"customer_needs\": string, \"objections\": [string], \"outcome\": string, \
+        \"recommended_follow_up\": string, \
+        \"interest_level\": \"high\" | \"medium\" | \"low\"}}\n\n\
+        ## Conversation\n{}",
+        max_words, transcript
+    )
+}
+
+fn follow_up_for(action: Option<&NextBestAction>) -> String {
+    match action {
+        Some(NextBestAction::CollectSlot { slot }) => format!("Call back to collect {}", slot),
+        Some(NextBestAction::HandleObjection { objection }) => {
+            format!("Call back to address the {} concern", objection)
+        },
+        Some(NextBestAction::OfferAppointment) => {
+            "Call back to book a branch appointment".to_string()
+        },
+        Some(NextBestAction::Escalate { reason }) => {
+            format!("Assign to a specialist ({})", reason)
+        },
+        Some(NextBestAction::Close) => "Confirm next steps with the customer".to_string(),
+        Some(NextBestAction::Continue) | None => {
+            "Follow up to continue the conversation".to_string()
+        },
+    }
+}
+
+impl DomainAgent {
+    /// Set the CRM that end-of-call summaries are pushed to
+    pub fn with_crm(mut self, crm: Arc<dyn CrmIntegration>) -> Self {
+        self.crm = Some(crm);
+        self
+    }
+
+    /// Summarize the call for the CRM
+    ///
+    /// Uses the LLM when configured and available, falling back to the
+    /// rule-based summary.
+    pub async fn session_summary(&self) -> SessionSummary {
+        let slots = self.captured_slots();
+        let rule_based = self.rule_based_summary(slots);
+        if !self.config.session_summary.use_llm {
+            return rule_based;
+        }
+        let Some(ref llm) = self.llm else {
+            return rule_based;
+        };
+
+        let transcript = self
+            .conversation
+            .agentic_memory()
+            .get_all_turns()
+            .iter()
+            .map(|turn| turn.format_for_context())
+            .collect::<Vec<_>>()
+            .join("\n");
+        let request = GenerateRequest::new(SUMMARY_SYSTEM_PROMPT).with_user_message(
+            summary_prompt(&transcript, self.config.session_summary.max_words),
+        );
+
+        let reply = match llm.generate(request).await {
+            Ok(response) => response.text,
+            Err(e) => {
+                tracing::warn!(error = %e, "LLM session summary failed, using rule-based summary");
+                return rule_based;
+            },
+        };
+        let Some(parsed) = LlmSummary::parse(&reply) else {
+            tracing::warn!("LLM session summary was not valid JSON, using rule-based summary");
+            return rule_based;
+        };
+
+        SessionSummary {
+            customer_needs: parsed.customer_needs,
+            objections: parsed.objections,
+            outcome: parsed.outcome,
+            recommended_follow_up: parsed.recommended_follow_up,
+            interest_level: parsed
+                .interest_level
+                .as_deref()
+                .and_then(parse_interest_level)
+                .unwrap_or(rule_based.interest_level),
+            slots: rule_based.slots,
+            source: SummarySource::Llm,
+        }
+    }
+
+    /// Summarize the call and push it to the CRM, if one is configured
+    ///
+    /// Called from `end_call`, including when the caller's transport
+    /// closes; the summary is only pushed once per call.
+    pub async fn push_session_summary(&self) {
+        if !self.config.session_summary.enabled {
+            return;
+        }
+        let Some(crm) = self.crm.clone() else {
+            return;
+        };
+        if self.summary_pushed.swap(true, Ordering::AcqRel) {
+            return;
+        }
+
+        let summary = self.session_summary().await;
+        let Some(lead) = summary.to_crm_lead() else {
+            tracing::debug!("No phone number captured, session summary not pushed to CRM");
+            return;
+        };
+
+        match save_lead(crm.as_ref(), lead).await {
+            Ok((lead_id, existing_lead)) => tracing::info!(
+                lead_id = %lead_id,
+                existing_lead,
+                source = ?summary.source,
+                "Session summary pushed to CRM"
+            ),
+            Err(e) => tracing::warn!(error = %e, "Failed to push session summary to CRM"),
+        }
+    }
+
+    /// Filled dialogue state slots
+    fn captured_slots(&self) -> HashMap<String, String> {
+        let dst = self.dialogue_state.read();
+        let state = dst.state();
+        state
+            .filled_slots()
+            .into_iter()
+            .filter_map(|slot| state.get_slot_value(slot).map(|v| (slot.to_string(), v)))
+            .collect()
+    }
+
+    /// Summary built from memory, lead signals and the next best action
+    fn rule_based_summary(&self, slots: HashMap<String, String>) -> SessionSummary {
+        let memory = self.conversation.agentic_memory();
+        let turns = memory.get_all_turns();
+
+        let goal = self.dialogue_state.read().goal_id().replace('_', " ");
+        let facts = memory.rule_based_session_summary();
+        let customer_needs = if facts.is_empty() {
+            format!("Interested in {}", goal)
+        } else {
+            format!("Interested in {}. {}", goal, facts)
+        };
+
+        let mut objections: Vec<String> = Vec::new();
+        for turn in turns.iter().filter(|t| t.role == TurnRole::User) {
+            if let Some(objection) = self
+                .persuasion
+                .detect_objection(&turn.content, self.user_language())
+            {
+                if !objections.contains(&objection) {
+                    objections.push(objection);
+                }
+            }
+        }
+
+        let mut completed: Vec<String> = self.completed_tools.read().iter().cloned().collect();
+        completed.sort();
+        let outcome = if completed.is_empty() {
+            "No action completed".to_string()
+        } else {
+            format!("Completed: {}", completed.join(", "))
+        };
+
+        let score = self.get_lead_score();
+        let interest_level = match score.qualification {
+            LeadQualification::Hot | LeadQualification::Qualified => InterestLevel::High,
+            LeadQualification::Warm => InterestLevel::Medium,
+            LeadQualification::Cold => InterestLevel::Low,
+        };
+        let next_action = self.next_best_action();
+
+        SessionSummary {
+            customer_needs,
+            objections,
+            outcome,
+            recommended_follow_up: follow_up_for(next_action.as_ref().map(|a| &a.action)),
+            interest_level,
+            slots,
+            source: SummarySource::RuleBased,
+        }
+    }
+}
@@ -0,0 +1,240 @@
+//! Escalation Throttling for DomainAgent
+//!
+//! Lead-score escalation triggers are re-evaluated every turn, so a stalled
+//! call would otherwise page a human on every turn. A session escalates once;
+//! a later reason goes through only if it is new and more severe than
+//! anything already escalated ("customer is frustrated" after "conversation
+//! stalled"). A sliding window, shared by the sessions given the same
+//! `EscalationLimiter`, throttles notification storms across them.
+//! Suppressed escalations are counted in
+//! `voice_agent_escalations_suppressed_total` and audited.
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use metrics::counter;
+use parking_lot::Mutex;
+
+use super::DomainAgent;
+use crate::agent_config::EscalationConfig;
+use crate::lead_scoring::EscalationTrigger;
+
+/// How urgently a human is needed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum EscalationSeverity {
+    Low,
+    Medium,
+    High,
+    Critical,
+}
+
+impl EscalationSeverity {
+    /// Severity of an automatic escalation trigger
+    pub fn for_trigger(trigger: &EscalationTrigger) -> Self {
+        match trigger {
+            EscalationTrigger::ComplianceSensitive => EscalationSeverity::Critical,
+            EscalationTrigger::CustomerRequested | EscalationTrigger::CustomerFrustration => {
+                EscalationSeverity::High
+            },
+            EscalationTrigger::HighValueLoan { .. }
+            | EscalationTrigger::ExcessiveObjections { .. } => EscalationSeverity::Medium,
+            EscalationTrigger::ConversationStalled { .. } | EscalationTrigger::ComplexQuery => {
+                EscalationSeverity::Low
+            },
+        }
+    }
+
+    /// Severity of an `escalate_to_human` tool reason
+    pub fn for_tool_reason(reason: &str) -> Self {
+        match reason {
+            "sensitive_matter" => EscalationSeverity::Critical,
+            "customer_request" | "complaint" => EscalationSeverity::High,
+            "technical_issue" => EscalationSeverity::Medium,
+            _ => EscalationSeverity::Low,
+        }
+    }
+}
+
+/// Reason an escalation is deduped on, ignoring counts that change each turn
+pub(crate) fn trigger_reason(trigger: &EscalationTrigger) -> &'static str {
+    match trigger {
+        EscalationTrigger::ExcessiveObjections { .. } => "excessive_objections",
+        EscalationTrigger::ConversationStalled { .. } => "conversation_stalled",
+        EscalationTrigger::HighValueLoan { .. } => "high_value_loan",
+        EscalationTrigger::CustomerFrustration => "customer_frustration",
+        EscalationTrigger::CustomerRequested => "customer_requested",
+        EscalationTrigger::ComplexQuery => "complex_query",
+        EscalationTrigger::ComplianceSensitive => "compliance_sensitive",
+    }
+}
+
+/// Why an escalation was suppressed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SuppressionScope {
+    /// The session already escalated for this or a more severe reason
+    Session,
+    /// Too many escalations across all sessions in the window
+    Global,
+}
+
+impl SuppressionScope {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SuppressionScope::Session => "session",
+            SuppressionScope::Global => "global",
+        }
+    }
+}
+
+/// Sliding-window limit on escalations, shared across sessions
+#[derive(Debug, Default)]
+pub struct EscalationLimiter {
+    sent: Mutex<VecDeque<Instant>>,
+}
+
+impl EscalationLimiter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Take a slot if fewer than `limit` escalations went out in `window`
+    fn try_acquire(&self, limit: u32, window: Duration) -> bool {
+        if limit == 0 {
+            return true;
+        }
+        let now = Instant::now();
+        let mut sent = self.sent.lock();
+        while sent
+            .front()
+            .is_some_and(|at| now.duration_since(*at) >= window)
+        {
+            sent.pop_front();
+        }
+        if sent.len() >= limit as usize {
+            return false;
+        }
+        sent.push_back(now);
+        true
+    }
+}
+
+/// Per-session escalation state
+pub(crate) struct EscalationGuard {
+    config: EscalationConfig,
+    limiter: Arc<EscalationLimiter>,
+    /// Reasons escalated this session, with their severity
+    escalated: Mutex<HashMap<String, EscalationSeverity>>,
+    suppressed: AtomicU32,
+}
+
+impl EscalationGuard {
+    pub(crate) fn new(config: EscalationConfig, limiter: Arc<EscalationLimiter>) -> Self {
+        Self {
+            config,
+            limiter,
+            escalated: Mutex::new(HashMap::new()),
+            suppressed: AtomicU32::new(0),
+        }
+    }
+
+    fn check(&self, reason: &str, severity: EscalationSeverity) -> Result<(), SuppressionScope> {
+        if !self.config.enabled {
+            return Ok(());
+        }
+
+        let mut escalated = self.escalated.lock();
+        if self.config.dedupe_per_session {
+            let most_severe = escalated.values().max().copied();
+            if escalated.contains_key(reason) || most_severe.is_some_and(|s| severity <= s) {
+                return Err(SuppressionScope::Session);
+            }
+        }
+
+        let window = Duration::from_secs(self.config.global_window_secs);
+        if !self.limiter.try_acquire(self.config.global_limit, window) {
+            return Err(SuppressionScope::Global);
+        }
+        escalated.insert(reason.to_string(), severity);
+        Ok(())
+    }
+}
+
+impl DomainAgent {
+    /// Share an escalation rate limiter with other agents
+    pub fn with_escalation_limiter(mut self, limiter: Arc<EscalationLimiter>) -> Self {
+        self.escalations = EscalationGuard::new(self.config.escalation.clone(), limiter);
+        self
+    }
+
+    /// Escalations suppressed this session
+    pub fn suppressed_escalations(&self) -> u32 {
+        self.escalations.suppressed.load(Ordering::Relaxed)
+    }
+
+    /// Whether an escalation may notify a human
+    ///
+    /// A suppressed escalation is counted, logged and audited instead.
+    pub(super) fn admit_escalation(&self, reason: &str, severity: EscalationSeverity) -> bool {
+        let scope = match self.escalations.check(reason, severity) {
+            Ok(()) => return true,
+            Err(scope) => scope,
+        };
+
+        self.escalations.suppressed.fetch_add(1, Ordering::Relaxed);
+        counter!("voice_agent_escalations_suppressed_total", "scope" => scope.as_str())
+            .increment(1);
+        tracing::info!(
+            reason = %reason,
+            severity = ?severity,
+            scope = scope.as_str(),
+            "Escalation suppressed"
+        );
+
+        if let Some(audit) = self.audit_logger.clone() {
+            let session_id = self.conversation.session_id().to_string();
+            let reason = reason.to_string();
+            tokio::spawn(async move {
+                if let Err(e) = audit
+                    .log_escalation_suppressed(&session_id, &reason, scope.as_str())
+                    .await
+                {
+                    tracing::warn!(error = %e, "Failed to audit suppressed escalation");
+                }
+            });
+        }
+        false
+    }
+
+    /// Reply standing in for an `escalate_to_human` call that was suppressed
+    pub(super) fn suppress_escalation_tool(
+        &self,
+        tool: &str,
+        args: &serde_json::Value,
+    ) -> Option<String> {
+        if tool != "escalate_to_human" {
+            return None;
+        }
+        let reason = args
+            .get("reason")
+            .and_then(|r| r.as_str())
+            .unwrap_or("customer_request");
+        // Same reason as the CustomerRequested trigger
+        let key = match reason {
+            "customer_request" => "customer_requested",
+            other => other,
+        };
+        if self.admit_escalation(key, EscalationSeverity::for_tool_reason(reason)) {
+            return None;
+        }
+        Some(
+            serde_json::json!({
+                "success": true,
+                "status": "already_escalated",
+                "message": "A human agent has already been notified and will join shortly.",
+            })
+            .to_string(),
+        )
+    }
+}
@@ -0,0 +1,114 @@
+//! A/B Experiments for DomainAgent
+//!
+//! The session is bucketed into one variant of each configured experiment
+//! when the agent is built (by session ID). Experiments bucketed by customer
+//! are re-bucketed once the customer is identified. The assigned variants
+//! override persona parameters, add a prompt section, replace the greeting
+//! or toggle RAG, and are audited when the call ends.
+
+use std::collections::HashMap;
+
+use voice_agent_config::domain::ExperimentAssignment;
+use voice_agent_config::PersonaConfig;
+
+use super::DomainAgent;
+
+impl DomainAgent {
+    /// Experiment variants this session is bucketed into
+    pub fn experiment_assignments(&self) -> Vec<ExperimentAssignment> {
+        self.experiments.read().clone()
+    }
+
+    /// Experiment ID -> variant ID, for session metadata
+    pub fn experiment_variants(&self) -> HashMap<String, String> {
+        self.experiments
+            .read()
+            .iter()
+            .map(|a| (a.experiment_id.clone(), a.variant_id.clone()))
+            .collect()
+    }
+
+    /// Re-bucket customer-keyed experiments once the customer is known
+    ///
+    /// Session-keyed experiments keep their variant, so an assignment never
+    /// changes for reasons other than learning who the customer is.
+    pub fn assign_experiments_for_customer(&self, customer_id: &str) {
+        let Some(ref view) = self.domain_view else {
+            return;
+        };
+
+        let assignments = view
+            .config()
+            .experiments
+            .assign(self.conversation.session_id(), Some(customer_id));
+        tracing::debug!(
+            customer_id = %customer_id,
+            experiments = assignments.len(),
+            "Bucketed experiments for customer"
+        );
+        *self.experiments.write() = assignments;
+    }
+
+    /// Persona with experiment overrides applied
+    pub(super) fn effective_persona(&self) -> PersonaConfig {
+        let mut persona = self.config.persona.clone();
+        for assignment in self.experiments.read().iter() {
+            assignment.variant.persona.apply(&mut persona);
+        }
+        persona
+    }
+
+    /// Extra system prompt sections from assigned variants
+    pub(super) fn experiment_prompt_context(&self) -> Option<String> {
+        let sections: Vec<String> = self
+            .experiments
+            .read()
+            .iter()
+            .filter_map(|a| a.variant.prompt_context.clone())
+            .collect();
+        (!sections.is_empty()).then(|| sections.join("\n"))
+    }
+
+    /// Whether RAG is enabled, after experiment overrides
+    pub(super) fn rag_enabled(&self) -> bool {
+        self.experiments
+            .read()
+            .iter()
+            .find_map(|a| a.variant.rag_enabled)
+            .unwrap_or(self.config.rag_enabled)
+    }
+
+    /// Greeting override from an assigned variant
+    pub(super) fn experiment_greeting(&self, language: &str) -> Option<String> {
+        self.experiments
+            .read()
+            .iter()
+            .find_map(|a| a.variant.greetings.get(language).cloned())
+    }
+
+    /// Write the session's experiment assignments to the audit log
+    pub(super) async fn audit_experiments(&self) {
+        let Some(ref audit) = self.audit_logger else {
+            return;
+        };
+
+        let assignments = self.experiment_assignments();
+        for assignment in &assignments {
+            let bucket_by = match assignment.bucket_by {
+                voice_agent_config::domain::BucketBy::Session => "session",
+                voice_agent_config::domain::BucketBy::Customer => "customer",
+            };
+            if let Err(e) = audit
+                .log_experiment_assignment(
+                    self.conversation.session_id(),
+                    &assignment.experiment_id,
+                    &assignment.variant_id,
+                    bucket_by,
+                )
+                .await
+            {
+                tracing::warn!(error = %e, "Failed to audit experiment assignment");
+            }
+        }
+    }
+}
@@ -0,0 +1,159 @@
+//! Conversation Goal Tracking for DomainAgent
+//!
+//! Progress toward each configured goal (slots.yaml `goals`) is derived from
+//! three completion criteria: required slots filled in the dialogue state,
+//! the required stage reached, and required tools that ran successfully.
+//! Progress feeds lead scoring and the session metadata.
+
+use serde::{Deserialize, Serialize};
+
+use voice_agent_config::domain::GoalDefinition;
+
+use super::DomainAgent;
+use crate::agent_config::AgentEvent;
+use crate::dst::DialogueStateTrait;
+use crate::stage::ConversationStage;
+
+/// Progress toward a single conversation goal
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GoalProgress {
+    /// Goal ID from config
+    pub goal_id: String,
+    /// Goal description
+    pub description: String,
+    /// Required slots not yet filled
+    pub missing_slots: Vec<String>,
+    /// Required stage not yet reached
+    pub missing_stage: Option<String>,
+    /// Required tools that have not run successfully
+    pub missing_tools: Vec<String>,
+    /// Fraction of completion criteria met (0.0 - 1.0)
+    pub progress: f32,
+    /// All completion criteria met
+    pub completed: bool,
+}
+
+impl DomainAgent {
+    /// Progress toward each configured goal, sorted by goal ID
+    ///
+    /// Goals without completion criteria (e.g. plain exploration) are skipped.
+    pub fn goal_progress(&self) -> Vec<GoalProgress> {
+        let Some(ref view) = self.domain_view else {
+            return Vec::new();
+        };
+
+        let mut progress: Vec<GoalProgress> = view
+            .config()
+            .slots
+            .goals
+            .iter()
+            .filter_map(|(goal_id, definition)| self.progress_for(goal_id, definition))
+            .collect();
+        progress.sort_by(|a, b| a.goal_id.cmp(&b.goal_id));
+        progress
+    }
+
+    /// IDs of completed goals
+    pub fn completed_goals(&self) -> Vec<String> {
+        self.goal_progress()
+            .into_iter()
+            .filter(|g| g.completed)
+            .map(|g| g.goal_id)
+            .collect()
+    }
+
+    /// Record a tool outcome and emit the `ToolResult` event
+    pub(super) fn record_tool_result(&self, name: &str, success: bool) {
+        if success {
+            self.completed_tools.write().insert(name.to_string());
+        }
+        let _ = self.event_tx.send(AgentEvent::ToolResult {
+            name: name.to_string(),
+            success,
+        });
+    }
+
+    /// Required slots of the current dialogue goal that are still missing,
+    /// excluding slots skipped after hitting their retry limit
+    pub(super) fn current_goal_missing_slots(&self) -> Vec<String> {
+        let Some(ref view) = self.domain_view else {
+            return Vec::new();
+        };
+        let goal_id = self.dialogue_state.read().goal_id().to_string();
+        let Some(definition) = view.config().slots.goals.get(&goal_id) else {
+            return Vec::new();
+        };
+
+        let dst = self.dialogue_state.read();
+        definition
+            .required_slots
+            .iter()
+            .filter(|slot| dst.state().get_slot_value(slot).is_none())
+            .filter(|slot| !dst.skipped_slots().contains(*slot))
+            .cloned()
+            .collect()
+    }
+
+    fn progress_for(&self, goal_id: &str, definition: &GoalDefinition) -> Option<GoalProgress> {
+        let criteria = definition.required_slots.len()
+            + definition.required_tools.len()
+            + usize::from(definition.required_stage.is_some());
+        if criteria == 0 {
+            return None;
+        }
+
+        let missing_slots: Vec<String> = {
+            let dst = self.dialogue_state.read();
+            definition
+                .required_slots
+                .iter()
+                .filter(|slot| dst.state().get_slot_value(slot).is_none())
+                .cloned()
+                .collect()
+        };
+
+        let missing_stage = definition
+            .required_stage
+            .as_ref()
+            .filter(|stage| !self.stage_reached(stage))
+            .cloned();
+
+        let missing_tools: Vec<String> = {
+            let completed = self.completed_tools.read();
+            definition
+                .required_tools
+                .iter()
+                .filter(|tool| !completed.contains(*tool))
+                .cloned()
+                .collect()
+        };
+
+        let unmet =
+            missing_slots.len() + missing_tools.len() + usize::from(missing_stage.is_some());
+        Some(GoalProgress {
+            goal_id: goal_id.to_string(),
+            description: definition.description.clone(),
+            missing_slots,
+            missing_stage,
+            missing_tools,
+            progress: (criteria - unmet) as f32 / criteria as f32,
+            completed: unmet == 0,
+        })
+    }
+
+    /// Whether the conversation is in or has passed through a stage
+    fn stage_reached(&self, stage_id: &str) -> bool {
+        let Some(stage) = ConversationStage::from_str(stage_id) else {
+            tracing::warn!(stage = %stage_id, "Unknown stage in goal completion criteria");
+            return false;
+        };
+
+        self.conversation.stage() == stage
+            || self
+                .conversation
+                .stage_manager()
+                .history()
+                .iter()
+                .any(|t| t.to == stage)
+    }
+}
@@ -0,0 +1,45 @@
+//! Knowledge Gap Guard for DomainAgent
+//!
+//! When a stage retrieves knowledge but nothing clears its score cutoff, the
+//! LLM tends to fill the gap with invented rates and fees. Depending on
+//! `KnowledgeGuardConfig::mode`, the agent either tells the LLM not to make
+//! specific claims, or skips the LLM and answers with a configured fallback
+//! that offers to connect the customer with someone who can confirm.
+
+use voice_agent_llm::PromptBuilder;
+
+use super::DomainAgent;
+use crate::agent_config::KnowledgeGapMode;
+
+impl DomainAgent {
+    /// Record the outcome of this turn's retrieval
+    ///
+    /// In hedge mode an empty retrieval adds the no-specifics instruction to
+    /// the prompt; in fallback mode `knowledge_gap_fallback` picks it up.
+    /// Callers pass `found` for a turn a tool result already answers, so it
+    /// is neither hedged nor replaced by the fallback.
+    pub(super) fn guard_knowledge_gap(&self, builder: PromptBuilder, found: bool) -> PromptBuilder {
+        self.record_scope(found);
+        let guard = &self.config.knowledge_guard;
+        let gap = guard.enabled && !found;
+        *self.knowledge_gap.write() = gap;
+        if !gap {
+            return builder;
+        }
+
+        tracing::info!(mode = ?guard.mode, "No relevant knowledge retrieved for turn");
+        match guard.mode {
+            KnowledgeGapMode::Hedge => builder.with_context(&guard.instruction),
+            KnowledgeGapMode::Fallback => builder,
+        }
+    }
+
+    /// Fallback reply to use instead of the LLM, if the last retrieval was empty
+    ///
+    /// Clears the recorded gap, so each retrieval is acted on at most once.
+    pub(super) fn knowledge_gap_fallback(&self) -> Option<String> {
+        let gap = std::mem::take(&mut *self.knowledge_gap.write());
+        let guard = &self.config.knowledge_guard;
+        (gap && guard.mode == KnowledgeGapMode::Fallback).then(|| guard.fallback_response.clone())
+    }
+}
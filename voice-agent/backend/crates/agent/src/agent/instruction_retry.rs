@@ -0,0 +1,155 @@
+//! Re-grounded Retry of LLM Replies that Ignore Instructions
+//!
+//! Smaller LLMs drift from the prompt: they answer a Hindi-speaking caller
+//! in Hindi when the Translate-Think-Translate flow needs English, or answer
+//! in prose when the turn was routed to a tool the agent couldn't call
+//! itself. `generate_response` checks each final reply for these failures
+//! and, up to `InstructionRetryConfig::max_retries` times, asks again with
+//! the reply and a reminder of the broken constraint appended to the
+//! prompt. Retries are counted in `voice_agent_llm_instruction_retries_total`.
+//!
+//! A Latin-script reply to a caller expecting an Indic script is flagged
+//! unless it reads as romanized Hindi to a Hindi caller. Streamed replies
+//! are spoken as they arrive, so only their first sentence is checked, for
+//! the language, before anything is sent; a reply in the wrong language is
+//! dropped and asked for again without streaming.
+
+use std::sync::Arc;
+
+use metrics::counter;
+use voice_agent_core::{GenerateRequest, Language, LanguageModel};
+use voice_agent_llm::Message;
+
+use super::routing::TurnRoute;
+use super::DomainAgent;
+
+/// Instruction an LLM reply ignored
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum InstructionViolation {
+    /// Reply is in another language than the one expected
+    WrongLanguage { expected: Language, found: Language },
+    /// Reply is plain text although the turn needs a tool call
+    MissingToolCall,
+}
+
+impl InstructionViolation {
+    /// Label for metrics and logs
+    pub(super) fn as_str(&self) -> &'static str {
+        match self {
+            Self::WrongLanguage { .. } => "wrong_language",
+            Self::MissingToolCall => "missing_tool_call",
+        }
+    }
+}
+
+impl DomainAgent {
+    /// Language the LLM should reply in
+    ///
+    /// Replies are translated to a non-English caller's language when a
+    /// translator is available, so the LLM itself answers in English.
+    fn reply_language(&self) -> Language {
+        match self.translator {
+            Some(_) => Language::English,
+            None => self.user_language(),
+        }
+    }
+
+    /// Whether the LLM must answer this turn with a tool call
+    ///
+    /// True when the turn was routed to the intent's tool only, the agent
+    /// got no result running it, and tools were offered to the LLM.
+    pub(super) fn tool_call_required(&self, offered: bool, tool_result: Option<&str>) -> bool {
+        offered && tool_result.is_none() && self.router.current() == TurnRoute::Tool
+    }
+
+    /// The instruction a final LLM reply ignored, if any
+    pub(super) fn instruction_violation(
+        &self,
+        reply: &str,
+        tool_call_required: bool,
+    ) -> Option<InstructionViolation> {
+        let retry = &self.config.instruction_retry;
+        if !retry.enabled {
+            return None;
+        }
+        if tool_call_required {
+            return Some(InstructionViolation::MissingToolCall);
+        }
+
+        let expected = self.reply_language();
+        let (found, confidence) = self.script_detector.detect_with_confidence(reply);
+        let wrong_language = match found {
+            _ if found.script() == expected.script() => false,
+            Language::English => {
+                expected != Language::Hindi || !self.script_detector.is_romanized_hindi(reply)
+            },
+            _ => true,
+        };
+        (wrong_language && confidence >= retry.min_confidence)
+            .then_some(InstructionViolation::WrongLanguage { expected, found })
+    }
+
+    /// Ask again for a streamed reply whose first sentence ignored an instruction
+    ///
+    /// Nothing of the reply has been sent yet. The LLM is asked without
+    /// streaming, up to `max_retries` times; None when retries are off or
+    /// the LLM fails, in which case the stream carries on as it was.
+    pub(super) async fn retry_streamed_reply(
+        &self,
+        llm: &Arc<dyn LanguageModel>,
+        mut request: GenerateRequest,
+        first_sentence: &str,
+    ) -> Option<String> {
+        let mut violation = self.instruction_violation(first_sentence, false)?;
+        let max_retries = self.config.instruction_retry.max_retries;
+        let mut reply = first_sentence.to_string();
+        for _ in 0..max_retries {
+            request.messages.push(Message::assistant(reply));
+            request.messages.push(self.regrounding_message(violation));
+            reply = match llm.generate(request.clone()).await {
+                Ok(response) => response.text,
+                Err(e) => {
+                    tracing::warn!(error = %e, "Re-grounded LLM retry failed");
+                    return None;
+                },
+            };
+            match self.instruction_violation(&reply, false) {
+                Some(next) => violation = next,
+                None => return Some(reply),
+            }
+        }
+        if max_retries == 0 {
+            return None;
+        }
+        tracing::warn!(
+            violation = violation.as_str(),
+            retries = max_retries,
+            "LLM reply still ignores the instruction, accepting it"
+        );
+        Some(reply)
+    }
+
+    /// Prompt reminder re-grounding the LLM on the ignored instruction
+    pub(super) fn regrounding_message(&self, violation: InstructionViolation) -> Message {
+        counter!("voice_agent_llm_instruction_retries_total", "reason" => violation.as_str())
+            .increment(1);
+        let retry = &self.config.instruction_retry;
+        let reminder = match violation {
+            InstructionViolation::WrongLanguage { expected, found } => {
+                tracing::warn!(
+                    expected = ?expected,
+                    found = ?found,
+                    "LLM replied in the wrong language, retrying"
+                );
+                retry
+                    .language_reminder
+                    .replace("{language}", expected.name())
+            },
+            InstructionViolation::MissingToolCall => {
+                tracing::warn!("LLM skipped a required tool call, retrying");
+                retry.tool_call_reminder.clone()
+            },
+        };
+        Message::system(reminder)
+    }
+}
@@ -0,0 +1,57 @@
+//! Language Switch on Spoken-Language Detection
+//!
+//! The user language is picked up front, from config or the caller's
+//! profile, but callers don't always answer in it. The STT stage identifies
+//! the spoken language from its early decode, before any final transcript,
+//! and hands it to `apply_language_detection`. A confident detection that
+//! disagrees with the current language switches translation and TTS to it
+//! from the next response on. Languages translation can't handle resolve
+//! through `language_fallbacks`, as they do for the configured language.
+//! Switches are counted in `voice_agent_language_switches_total`.
+
+use metrics::counter;
+use voice_agent_core::Language;
+
+use super::DomainAgent;
+
+impl DomainAgent {
+    /// Act on the spoken language detected for the caller's current turn
+    ///
+    /// Returns whether the user language changed.
+    pub fn apply_language_detection(&self, detected: Language, confidence: f32) -> bool {
+        let switch = &self.config.language_switch;
+        if !switch.enabled || confidence < switch.min_confidence {
+            return false;
+        }
+
+        let current = self.user_language();
+        if detected == current {
+            return false;
+        }
+        let target = match self.translator {
+            Some(ref t) => t.resolve_language(detected, &self.config.language_fallbacks),
+            // Without a translator only English can be spoken
+            None if detected == Language::English => detected,
+            None => return false,
+        };
+        if target == current {
+            return false;
+        }
+
+        *self.user_language.write() = target;
+        counter!(
+            "voice_agent_language_switches_total",
+            "from" => current.code(),
+            "to" => target.code()
+        )
+        .increment(1);
+        tracing::info!(
+            from = ?current,
+            to = ?target,
+            detected = ?detected,
+            confidence = format!("{:.2}", confidence),
+            "Switching user language to the spoken language"
+        );
+        true
+    }
+}
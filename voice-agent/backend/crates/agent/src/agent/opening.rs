@@ -0,0 +1,157 @@
+//! Opening Script for DomainAgent
+//!
+//! Every call opens with the same sequence: the AI disclosure, the
+//! recording consent question, the greeting and what the agent can help
+//! with. The steps and their order come from `opening_script` in
+//! compliance.yaml, and the text from config for the call's language.
+//! `begin_opening` speaks the steps up to the consent question and waits;
+//! the answer (see `compliance`) resumes the script. A refusal ends the
+//! call with the configured closing line unless `end_call_on_refusal` is
+//! off, in which case the script goes on without recording. Normal dialog
+//! starts once every step has been spoken.
+//!
+//! Each step is audited: the disclosure and the consent answer as their own
+//! events, the greeting and purpose as `OpeningStepGiven`. Spoken text is
+//! audited as a digest, so the chain holds nothing a customer's erasure
+//! would have to remove.
+
+use voice_agent_config::domain::{
+    ComplianceConfig, GreetingContext, OpeningScriptConfig, OpeningStep,
+};
+
+use super::DomainAgent;
+use crate::agent_config::AgentEvent;
+use crate::conversation::EndReason;
+
+/// Where the call is in its opening script
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OpeningState {
+    /// No opening script has been run
+    NotStarted,
+    /// Waiting for the consent answer; the script resumes at step `next`
+    AwaitingConsent { next: usize },
+    /// Every step has been spoken; normal dialog
+    Complete,
+    /// Consent was refused and the call ended
+    Refused,
+}
+
+impl DomainAgent {
+    /// Start the call's opening script
+    ///
+    /// Call once when the call connects and speak the returned text. If the
+    /// script asks for consent, the next user turn is taken as the answer.
+    pub async fn begin_opening(&self) -> String {
+        let text = self.run_opening(0).await;
+        self.set_response_protected(true);
+        let _ = self.event_tx.send(AgentEvent::Response(text.clone()));
+        text
+    }
+
+    /// Where the call is in its opening script
+    pub fn opening_state(&self) -> OpeningState {
+        *self.opening.read()
+    }
+
+    /// Whether a consent refusal during the opening ends the call
+    pub(super) fn opening_ends_on_refusal(&self) -> bool {
+        matches!(self.opening_state(), OpeningState::AwaitingConsent { .. })
+            && self
+                .domain_view
+                .as_ref()
+                .map_or(true, |view| view.opening_script().end_call_on_refusal)
+    }
+
+    /// Speak the script's steps from `start`, stopping at the consent question
+    pub(super) async fn run_opening(&self, start: usize) -> String {
+        let steps = self
+            .domain_view
+            .as_ref()
+            .map(|view| view.opening_script().steps.clone())
+            .unwrap_or_else(|| OpeningScriptConfig::default().steps);
+
+        let mut spoken = Vec::new();
+        for (index, step) in steps.into_iter().enumerate().skip(start) {
+            match step {
+                OpeningStep::AiDisclosure => {
+                    let disclosure = self.conversation.mark_ai_disclosed();
+                    self.audit_ai_disclosure(&disclosure).await;
+                    spoken.push(disclosure);
+                },
+                OpeningStep::Consent => {
+                    spoken.push(self.conversation.ask_consent());
+                    *self.opening.write() = OpeningState::AwaitingConsent { next: index + 1 };
+                    return spoken.join(" ");
+                },
+                OpeningStep::Greeting | OpeningStep::Purpose => {
+                    let Some(text) = self.opening_text(step) else {
+                        continue;
+                    };
+                    self.audit_opening_step(step, &text).await;
+                    spoken.push(text);
+                },
+            }
+        }
+
+        tracing::debug!(steps = spoken.len(), "Opening script complete");
+        *self.opening.write() = OpeningState::Complete;
+        spoken.join(" ")
+    }
+
+    /// End the call after consent was refused during the opening
+    ///
+    /// Returns the closing line to speak before hanging up.
+    pub(super) async fn end_opening_on_refusal(&self) -> String {
+        *self.opening.write() = OpeningState::Refused;
+        let message = match self.domain_view {
+            Some(ref view) => view.opening_refusal(self.user_language().code()),
+            None => {
+                self.localize(ComplianceConfig::default().get_opening_refusal("en"))
+                    .await
+            },
+        };
+        tracing::info!("Consent refused during opening, ending call");
+
+        self.set_response_protected(true);
+        let _ = self.event_tx.send(AgentEvent::Response(message.clone()));
+        match self.end_call(EndReason::ConsentRefused).await {
+            Some(disclosure) => format!("{} {}", message, disclosure),
+            None => message,
+        }
+    }
+
+    /// Greeting or purpose text in the user's language
+    fn opening_text(&self, step: OpeningStep) -> Option<String> {
+        let view = self.domain_view.as_ref()?;
+        let language = self.user_language().code();
+        match step {
+            OpeningStep::Greeting => Some(
+                self.experiment_greeting(language)
+                    .map(|greeting| view.with_brand(&greeting))
+                    .unwrap_or_else(|| {
+                        let returning = self.personalization_ctx.read().returning_customer;
+                        let context = GreetingContext::now().returning(returning);
+                        view.personalized_greeting(language, &context)
+                    }),
+            ),
+            OpeningStep::Purpose => Some(view.opening_purpose(language)),
+            OpeningStep::AiDisclosure | OpeningStep::Consent => None,
+        }
+    }
+
+    async fn audit_opening_step(&self, step: OpeningStep, text: &str) {
+        if let Some(ref audit) = self.audit_logger {
+            if let Err(e) = audit
+                .log_opening_step(
+                    self.conversation.session_id(),
+                    step.as_str(),
+                    self.user_language().code(),
+                    text,
+                )
+                .await
+            {
+                tracing::warn!(error = %e, step = step.as_str(), "Failed to audit opening step");
+            }
+        }
+    }
+}
@@ -2,11 +2,16 @@
 //!
 //! This module contains the main processing logic including:
 //! - process() - Main turn processing
-//! - process_stream() - Streaming turn processing
+//! - process_stream() / process_stream_into() - Streaming turn processing
 //! - build_llm_request() - LLM request construction
 
 use futures::StreamExt;
+use std::collections::HashSet;
+use std::time::Instant;
+use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
 
+use super::escalation::{self, EscalationSeverity};
 use super::{find_sentence_end, DomainAgent};
 use crate::agent_config::AgentEvent;
 use crate::conversation::ConversationEvent;
@@ -15,7 +20,10 @@ use crate::lead_scoring::{EscalationTrigger, LeadRecommendation};
 use crate::memory::{ConversationTurn, TurnRole};
 use crate::AgentError;
 use voice_agent_core::Language;
-use voice_agent_llm::{Message, PromptBuilder, Role};
+use voice_agent_llm::{
+    ContextSection, Message, PromptBuilder, Role, StreamSegment, ToolCallDetector,
+};
+use voice_agent_config::domain::{SlotRetryFallback, SlotRetryPolicy};
 use voice_agent_rag::QueryContext;
 
 impl DomainAgent {
@@ -28,40 +36,31 @@ impl DomainAgent {
     pub async fn process(&self, user_input: &str) -> Result<String, AgentError> {
         // Emit thinking event
         let _ = self.event_tx.send(AgentEvent::Thinking);
+        self.set_response_protected(false);
+        let screened = self.screen_input(user_input);
+        let user_input = screened.as_str();
+        self.track_sentiment(user_input);
+
+        // The first answer after the call-start disclosure is the consent answer
+        if self.conversation.awaiting_consent() {
+            return self.handle_consent_answer(user_input).await;
+        }
+
+        // A supervisor holding the call does the talking
+        if self.record_during_takeover(user_input) {
+            return Ok(String::new());
+        }
+        let turn_started = Instant::now();
+        self.begin_turn_debug(user_input);
 
         // P5 FIX: Translate user input to English if needed
-        let english_input = if self.user_language != Language::English {
-            if let Some(ref translator) = self.translator {
-                match translator
-                    .translate(user_input, self.user_language, Language::English)
-                    .await
-                {
-                    Ok(translated) => {
-                        tracing::debug!(
-                            from = ?self.user_language,
-                            original = %user_input,
-                            translated = %translated,
-                            "Translated user input to English"
-                        );
-                        translated
-                    }
-                    Err(e) => {
-                        tracing::warn!(
-                            error = %e,
-                            "Translation failed, using original input"
-                        );
-                        user_input.to_string()
-                    }
-                }
-            } else {
-                user_input.to_string()
-            }
-        } else {
-            user_input.to_string()
-        };
+        let english_input = self.english_input(user_input).await;
 
         // Add user turn and detect intent
+        let intent_started = Instant::now();
         let intent = self.conversation.add_user_turn(user_input)?;
+        self.debug_intent(&intent, intent_started.elapsed());
+        self.check_stage_timeout();
 
         // Add to MemGPT-style agentic memory recall
         let turn = ConversationTurn::new(TurnRole::User, user_input)
@@ -130,6 +129,7 @@ impl DomainAgent {
         }
 
         // Phase 5: Update Dialogue State Tracker with detected intent
+        let slots_before = self.debug_slots_before();
         {
             let mut dst = self.dialogue_state.write();
             dst.update(&intent);
@@ -145,15 +145,20 @@ impl DomainAgent {
                 "Dialogue state updated"
             );
         }
+        self.debug_slot_changes(slots_before);
 
         // P4 FIX: Process input through personalization engine
-        {
+        let inferred_segment = {
             let mut ctx = self.personalization_ctx.write();
-            self.personalization.process_input(&mut ctx, user_input);
+            let inferred = self.personalization.process_input(&mut ctx, user_input);
 
             if let Some(recent_signal) = ctx.recent_signals(1).first() {
                 tracing::debug!(signal = ?recent_signal, "Personalization signal detected");
             }
+            inferred
+        };
+        if let Some(segment) = inferred_segment {
+            self.apply_inferred_segment(segment);
         }
 
         // Phase 10: Update lead scoring engine with detected signals
@@ -201,15 +206,32 @@ impl DomainAgent {
                 intent.clone(),
             )));
 
+        // Recap on request; ask a clarifying question instead of guessing between
+        // near-tied intents or at a bare number, then collect the intent's required
+        // slots before acting on it
+        let recap = self.requested_recap(&english_input, &intent).await;
+        let tool_turn = self.accumulate_tool_arguments(&intent);
+        let clarification = recap
+            .or_else(|| self.conversation.pending_clarification())
+            .or_else(|| self.amount_clarification(user_input, &intent))
+            .or_else(|| self.missing_slot_prompt(&intent))
+            .or_else(|| tool_turn.then(|| self.missing_tool_argument_prompt())?);
+        self.set_response_protected(clarification.is_some());
+        self.publish_slot_progress();
+
+        // Decide whether this turn needs retrieval, the intent's tool, both or neither
+        let route = self.route_turn(&intent, &english_input);
+
         // Check for tool calls based on intent
-        let tool_result = if self.config.tools_enabled {
-            self.maybe_call_tool(&intent).await?
-        } else {
-            None
-        };
+        let tool_result =
+            if self.config.tools_enabled && clarification.is_none() && route.needs_tool() {
+                self.maybe_call_tool(&intent).await?
+            } else {
+                None
+            };
 
         // Phase 12: Auto-capture lead when we have contact info
-        if self.config.tools_enabled {
+        if self.config.tools_enabled && clarification.is_none() {
             let should_capture = {
                 let dst = self.dialogue_state.read();
                 dst.should_auto_capture_lead()
@@ -227,20 +249,46 @@ impl DomainAgent {
         }
 
         // Build prompt for LLM
-        let english_response = self
-            .generate_response(&english_input, tool_result.as_deref())
-            .await?;
+        // Clarifications are protected; LLM replies are trimmed to the stage's
+        // length limit at a sentence boundary (disclosures are appended later)
+        let localized = clarification
+            .as_deref()
+            .is_some_and(|question| self.in_user_script(question));
+        let english_response = match clarification {
+            Some(question) => question,
+            None => {
+                let response_started = Instant::now();
+                let response = self
+                    .generate_response(&english_input, tool_result.as_deref())
+                    .await?;
+                self.debug_response_time(response_started.elapsed());
+                // The out-of-scope redirect is used as configured
+                if self.is_out_of_scope_turn() {
+                    response
+                } else {
+                    let response = self.response_limit().truncate(&response);
+                    match self.auto_recap().await {
+                        Some(recap) => format!("{} {}", recap, response),
+                        None => response,
+                    }
+                }
+            },
+        };
 
-        // P5 FIX: Translate response back to user's language if needed
-        let response = if self.user_language != Language::English {
+        // P5 FIX: Translate response back to user's language if needed (the
+        // out-of-scope redirect and localized clarifications already are in it)
+        let user_language = self.user_language();
+        let translate =
+            user_language != Language::English && !self.is_out_of_scope_turn() && !localized;
+        let response = if translate {
             if let Some(ref translator) = self.translator {
                 match translator
-                    .translate(&english_response, Language::English, self.user_language)
+                    .translate(&english_response, Language::English, user_language)
                     .await
                 {
                     Ok(translated) => {
                         tracing::debug!(
-                            to = ?self.user_language,
+                            to = ?user_language,
                             original = %english_response,
                             translated = %translated,
                             "Translated response to user language"
@@ -308,76 +356,8 @@ impl DomainAgent {
             );
         }
 
-        // Phase 10: Calculate lead score and emit events
-        let lead_score = {
-            let mut lead_scoring = self.lead_scoring.write();
-            lead_scoring.calculate_score()
-        };
-
-        // Emit lead score update event
-        let _ = self.event_tx.send(AgentEvent::LeadScoreUpdated {
-            score: lead_score.total,
-            qualification: format!("{:?}", lead_score.qualification),
-            classification: format!("{:?}", lead_score.classification),
-            conversion_probability: lead_score.conversion_probability,
-        });
-
-        tracing::info!(
-            score = lead_score.total,
-            qualification = ?lead_score.qualification,
-            classification = ?lead_score.classification,
-            conversion_prob = lead_score.conversion_probability,
-            recommendation = ?lead_score.recommendation,
-            "Lead score calculated"
-        );
-
-        // Check for escalation triggers
-        for trigger in &lead_score.escalation_triggers {
-            let trigger_str = match trigger {
-                EscalationTrigger::ExcessiveObjections { count, threshold } => {
-                    format!(
-                        "ExcessiveObjections: {} objections (threshold: {})",
-                        count, threshold
-                    )
-                }
-                EscalationTrigger::ConversationStalled { turns, threshold } => {
-                    format!(
-                        "ConversationStalled: {} turns (threshold: {})",
-                        turns, threshold
-                    )
-                }
-                EscalationTrigger::HighValueLoan { amount, threshold } => {
-                    format!(
-                        "HighValueLoan: ₹{:.0} (threshold: ₹{:.0})",
-                        amount, threshold
-                    )
-                }
-                EscalationTrigger::CustomerFrustration => "CustomerFrustration".to_string(),
-                EscalationTrigger::CustomerRequested => "CustomerRequested".to_string(),
-                EscalationTrigger::ComplexQuery => "ComplexQuery".to_string(),
-                EscalationTrigger::ComplianceSensitive => "ComplianceSensitive".to_string(),
-            };
-
-            let recommendation_str = match &lead_score.recommendation {
-                LeadRecommendation::ContinueConversation => "ContinueConversation".to_string(),
-                LeadRecommendation::PushForAppointment => "PushForAppointment".to_string(),
-                LeadRecommendation::OfferCallback => "OfferCallback".to_string(),
-                LeadRecommendation::EscalateNow { reason } => format!("EscalateNow: {}", reason),
-                LeadRecommendation::SendFollowUp => "SendFollowUp".to_string(),
-                LeadRecommendation::LowPriority => "LowPriority".to_string(),
-            };
-
-            tracing::warn!(
-                trigger = %trigger_str,
-                recommendation = %recommendation_str,
-                "Escalation trigger detected"
-            );
-
-            let _ = self.event_tx.send(AgentEvent::EscalationTriggered {
-                trigger: trigger_str,
-                recommendation: recommendation_str,
-            });
-        }
+        self.score_turn(user_input);
+        self.finish_turn_debug(&response, turn_started);
 
         // Emit response event
         let _ = self.event_tx.send(AgentEvent::Response(response.clone()));
@@ -386,34 +366,64 @@ impl DomainAgent {
     }
 
     /// P0-2 FIX: Process user input with streaming LLM output
+    ///
+    /// Returns once the whole response has been generated; use
+    /// `process_stream_into` to consume sentences while the LLM is streaming.
     pub async fn process_stream(
         &self,
         user_input: &str,
-    ) -> Result<tokio::sync::mpsc::Receiver<String>, AgentError> {
+    ) -> Result<mpsc::Receiver<String>, AgentError> {
+        let (tx, rx) = mpsc::channel::<String>(32);
+        self.process_stream_into(user_input, tx).await?;
+        Ok(rx)
+    }
+
+    /// Process user input, sending each response sentence to `tx` as it completes
+    ///
+    /// For non-English users every completed English sentence is translated
+    /// while the LLM keeps streaming, so the first translated sentence can
+    /// reach TTS before the English response is finished. The caller must
+    /// read from the receiving end concurrently.
+    pub async fn process_stream_into(
+        &self,
+        user_input: &str,
+        tx: mpsc::Sender<String>,
+    ) -> Result<(), AgentError> {
         // Emit thinking event
         let _ = self.event_tx.send(AgentEvent::Thinking);
+        self.set_response_protected(false);
+        let screened = self.screen_input(user_input);
+        let user_input = screened.as_str();
+        self.track_sentiment(user_input);
+
+        if self.conversation.awaiting_consent() {
+            let response = self.handle_consent_answer(user_input).await?;
+            let _ = tx.send(response).await;
+            return Ok(());
+        }
+
+        if self.record_during_takeover(user_input) {
+            return Ok(());
+        }
+        let turn_started = Instant::now();
+        self.begin_turn_debug(user_input);
 
         // P5 FIX: Translate user input to English if needed
-        let english_input = if self.user_language != Language::English {
-            if let Some(ref translator) = self.translator {
-                translator
-                    .translate(user_input, self.user_language, Language::English)
-                    .await
-                    .unwrap_or_else(|_| user_input.to_string())
-            } else {
-                user_input.to_string()
-            }
-        } else {
-            user_input.to_string()
-        };
+        let english_input = self.english_input(user_input).await;
 
         // Add user turn and detect intent
+        let intent_started = Instant::now();
         let intent = self.conversation.add_user_turn(user_input)?;
+        self.debug_intent(&intent, intent_started.elapsed());
+        self.check_stage_timeout();
 
         // P4 FIX: Process through personalization engine
-        {
+        let inferred_segment = {
             let mut ctx = self.personalization_ctx.write();
-            self.personalization.process_input(&mut ctx, user_input);
+            self.personalization.process_input(&mut ctx, user_input)
+        };
+        if let Some(segment) = inferred_segment {
+            self.apply_inferred_segment(segment);
         }
 
         // Forward intent event
@@ -423,8 +433,45 @@ impl DomainAgent {
                 intent.clone(),
             )));
 
+        // Recap on request, or ask a clarifying question or for a missing
+        // required slot before acting
+        let slots_before = self.debug_slots_before();
+        let recap = self.requested_recap(&english_input, &intent).await;
+        let tool_turn = self.accumulate_tool_arguments(&intent);
+        let clarification = recap
+            .or_else(|| self.conversation.pending_clarification())
+            .or_else(|| self.amount_clarification(user_input, &intent))
+            .or_else(|| self.missing_slot_prompt(&intent))
+            .or_else(|| tool_turn.then(|| self.missing_tool_argument_prompt())?);
+        self.debug_slot_changes(slots_before);
+        self.publish_slot_progress();
+        if let Some(question) = clarification {
+            self.set_response_protected(true);
+            let localized = self.in_user_script(&question);
+            let response = if self.user_language() != Language::English && !localized {
+                if let Some(ref translator) = self.translator {
+                    translator
+                        .translate(&question, Language::English, self.user_language())
+                        .await
+                        .unwrap_or(question)
+                } else {
+                    question
+                }
+            } else {
+                question
+            };
+
+            self.conversation.add_assistant_turn(&response)?;
+            self.finish_turn_debug(&response, turn_started);
+            let _ = self.event_tx.send(AgentEvent::Response(response.clone()));
+
+            let _ = tx.send(response).await;
+            return Ok(());
+        }
+
         // Check for tool calls
-        let tool_result = if self.config.tools_enabled {
+        let route = self.route_turn(&intent, &english_input);
+        let tool_result = if self.config.tools_enabled && route.needs_tool() {
             self.maybe_call_tool(&intent).await?
         } else {
             None
@@ -435,48 +482,94 @@ impl DomainAgent {
             .build_llm_request(&english_input, tool_result.as_deref())
             .await?;
 
-        // Create output channel
-        let (tx, rx) = tokio::sync::mpsc::channel::<String>(32);
+        // Off-topic question: redirect
+        if let Some(redirect) = self.out_of_scope_redirect().await {
+            self.conversation.add_assistant_turn(&redirect)?;
+            self.finish_turn_debug(&redirect, turn_started);
+            let _ = self.event_tx.send(AgentEvent::Response(redirect.clone()));
+            let _ = tx.send(redirect).await;
+            return Ok(());
+        }
+
+        // Nothing relevant retrieved: answer with the configured fallback
+        if let Some(fallback) = self.knowledge_gap_fallback() {
+            let response = self.localize(&fallback).await;
+            self.conversation.add_assistant_turn(&response)?;
+            self.finish_turn_debug(&response, turn_started);
+            let _ = self.event_tx.send(AgentEvent::Response(response.clone()));
+            let _ = tx.send(response).await;
+            return Ok(());
+        }
+
+        // Open with a recap every few turns
+        if let Some(recap) = self.auto_recap().await {
+            let _ = tx.send(self.localize(&recap).await).await;
+        }
 
         // Check if LLM is available for streaming
         if let Some(ref llm) = self.llm {
             if llm.is_available().await {
+                let response_started = Instant::now();
+                let retry_request = prompt_request.clone();
                 let mut stream = llm.generate_stream(prompt_request);
 
-                let translator = &self.translator;
-                let user_language = self.user_language;
-                let terminators = user_language.sentence_terminators();
+                let terminators = self.user_language().sentence_terminators();
+                let (sentence_tx, translation) = self.spawn_sentence_translator(tx);
 
                 let mut buffer = String::new();
                 let mut full_response = String::new();
-
-                while let Some(result) = stream.next().await {
+                // Tool calls are dispatched as soon as their JSON closes and never spoken
+                let mut detector = ToolCallDetector::new();
+                let mut streamed_tool_results = Vec::new();
+                // The first sentence is checked for the reply language before it's sent
+                let mut first_sentence = true;
+                let mut regrounded = None;
+
+                'stream: while let Some(result) = stream.next().await {
                     match result {
                         Ok(chunk) => {
-                            buffer.push_str(&chunk.delta);
-                            full_response.push_str(&chunk.delta);
+                            for segment in detector.push(&chunk.delta) {
+                                match segment {
+                                    StreamSegment::Text(text) => {
+                                        buffer.push_str(&text);
+                                        full_response.push_str(&text);
+                                    }
+                                    StreamSegment::ToolCall(call) => {
+                                        tracing::info!(
+                                            tool = %call.name,
+                                            "Dispatching tool call detected mid-stream"
+                                        );
+                                        streamed_tool_results.push(
+                                            self.execute_llm_tool_call(&call.name, call.arguments)
+                                                .await,
+                                        );
+                                    }
+                                }
+                            }
+                            if chunk.is_final {
+                                let rest = detector.finish();
+                                buffer.push_str(&rest);
+                                full_response.push_str(&rest);
+                            }
 
-                            while let Some(pos) = find_sentence_end(&buffer, terminators) {
-                                let sentence = buffer[..=pos].trim().to_string();
-                                buffer = buffer[pos + 1..].to_string();
+                            while let Some(end) = find_sentence_end(&buffer, terminators) {
+                                let sentence = buffer[..end].trim().to_string();
+                                buffer = buffer[end..].to_string();
 
                                 if sentence.is_empty() {
                                     continue;
                                 }
 
-                                let translated = if user_language != Language::English {
-                                    if let Some(ref t) = translator {
-                                        t.translate(&sentence, Language::English, user_language)
-                                            .await
-                                            .unwrap_or(sentence)
-                                    } else {
-                                        sentence
+                                if std::mem::take(&mut first_sentence) {
+                                    regrounded = self
+                                        .retry_streamed_reply(llm, retry_request.clone(), &sentence)
+                                        .await;
+                                    if regrounded.is_some() {
+                                        break 'stream;
                                     }
-                                } else {
-                                    sentence
-                                };
+                                }
 
-                                if tx.send(translated).await.is_err() {
+                                if sentence_tx.send(sentence).await.is_err() {
                                     tracing::debug!("Stream receiver dropped");
                                     break;
                                 }
@@ -493,63 +586,340 @@ impl DomainAgent {
                     }
                 }
 
-                // Flush remaining buffer
-                if !buffer.trim().is_empty() {
-                    let sentence = buffer.trim().to_string();
-                    let translated = if user_language != Language::English {
-                        if let Some(ref t) = translator {
-                            t.translate(&sentence, Language::English, user_language)
-                                .await
-                                .unwrap_or(sentence)
-                        } else {
-                            sentence
-                        }
-                    } else {
-                        sentence
-                    };
-                    let _ = tx.send(translated).await;
+                // Flush remaining buffer, which is the first sentence of a
+                // reply without a terminator
+                if regrounded.is_none() {
+                    let rest = detector.finish();
+                    buffer.push_str(&rest);
+                    full_response.push_str(&rest);
+                    let sentence = buffer.trim();
+                    if first_sentence && !sentence.is_empty() {
+                        regrounded = self
+                            .retry_streamed_reply(llm, retry_request, sentence)
+                            .await;
+                    }
+                    if regrounded.is_none() && !sentence.is_empty() {
+                        let _ = sentence_tx.send(sentence.to_string()).await;
+                    }
+                }
+                if let Some(reply) = regrounded {
+                    // The re-grounded reply replaces everything streamed
+                    full_response = reply.clone();
+                    let _ = sentence_tx.send(reply).await;
                 }
 
-                // Update conversation with full response
-                let final_response = if user_language != Language::English {
-                    if let Some(ref t) = translator {
-                        t.translate(&full_response, Language::English, user_language)
-                            .await
-                            .unwrap_or(full_response.clone())
-                    } else {
-                        full_response.clone()
+                // Let the LLM answer from the streamed tool output
+                if !streamed_tool_results.is_empty() {
+                    let combined_results = streamed_tool_results.join("\n\n");
+                    let follow_up = self
+                        .generate_response(&english_input, Some(&combined_results))
+                        .await?;
+                    if !follow_up.trim().is_empty() {
+                        if !full_response.is_empty() && !full_response.ends_with(' ') {
+                            full_response.push(' ');
+                        }
+                        full_response.push_str(&follow_up);
+                        let _ = sentence_tx.send(follow_up).await;
                     }
-                } else {
-                    full_response.clone()
+                }
+
+                self.debug_response_time(response_started.elapsed());
+
+                // Record what was actually sent: the translated sentences, if any
+                drop(sentence_tx);
+                let translated = translation.await.unwrap_or_default();
+                let final_response = match translated {
+                    Some(sentences) => sentences.join(" "),
+                    None => full_response,
                 };
 
                 if let Err(e) = self.conversation.add_assistant_turn(&final_response) {
                     tracing::warn!("Failed to add assistant turn: {}", e);
                 }
+                self.score_turn(user_input);
+                self.finish_turn_debug(&final_response, turn_started);
 
                 let _ = self.event_tx.send(AgentEvent::Response(final_response));
 
-                return Ok(rx);
+                return Ok(());
             }
         }
 
         // Fallback: No LLM available
         let response = self.generate_mock_response(user_input, tool_result.as_deref());
         self.conversation.add_assistant_turn(&response)?;
+        self.score_turn(user_input);
+        self.finish_turn_debug(&response, turn_started);
         let _ = self.event_tx.send(AgentEvent::Response(response.clone()));
 
         let _ = tx.send(response).await;
 
-        Ok(rx)
+        Ok(())
+    }
+
+    /// Score the lead after a turn
+    ///
+    /// Emits the updated lead score, any escalation its triggers call for and
+    /// the next best action for the frontend.
+    fn score_turn(&self, user_input: &str) {
+        // Phase 10: Calculate lead score and emit events
+        let goals_completed = self.completed_goals().len() as u32;
+        let lead_score = {
+            let mut lead_scoring = self.lead_scoring.write();
+            lead_scoring.update_goals_completed(goals_completed);
+            lead_scoring.calculate_score()
+        };
+
+        // Emit lead score update event
+        let _ = self.event_tx.send(AgentEvent::LeadScoreUpdated {
+            score: lead_score.total,
+            qualification: format!("{:?}", lead_score.qualification),
+            classification: format!("{:?}", lead_score.classification),
+            conversion_probability: lead_score.conversion_probability,
+        });
+
+        tracing::info!(
+            score = lead_score.total,
+            qualification = ?lead_score.qualification,
+            classification = ?lead_score.classification,
+            conversion_prob = lead_score.conversion_probability,
+            recommendation = ?lead_score.recommendation,
+            "Lead score calculated"
+        );
+
+        // Check for escalation triggers
+        for trigger in &lead_score.escalation_triggers {
+            let trigger_str = match trigger {
+                EscalationTrigger::ExcessiveObjections { count, threshold } => {
+                    format!(
+                        "ExcessiveObjections: {} objections (threshold: {})",
+                        count, threshold
+                    )
+                }
+                EscalationTrigger::ConversationStalled { turns, threshold } => {
+                    format!(
+                        "ConversationStalled: {} turns (threshold: {})",
+                        turns, threshold
+                    )
+                }
+                EscalationTrigger::HighValueLoan { amount, threshold } => {
+                    format!(
+                        "HighValueLoan: ₹{:.0} (threshold: ₹{:.0})",
+                        amount, threshold
+                    )
+                }
+                EscalationTrigger::CustomerFrustration => "CustomerFrustration".to_string(),
+                EscalationTrigger::CustomerRequested => "CustomerRequested".to_string(),
+                EscalationTrigger::ComplexQuery => "ComplexQuery".to_string(),
+                EscalationTrigger::ComplianceSensitive => "ComplianceSensitive".to_string(),
+            };
+
+            let recommendation_str = match &lead_score.recommendation {
+                LeadRecommendation::ContinueConversation => "ContinueConversation".to_string(),
+                LeadRecommendation::PushForAppointment => "PushForAppointment".to_string(),
+                LeadRecommendation::OfferCallback => "OfferCallback".to_string(),
+                LeadRecommendation::EscalateNow { reason } => format!("EscalateNow: {}", reason),
+                LeadRecommendation::SendFollowUp => "SendFollowUp".to_string(),
+                LeadRecommendation::LowPriority => "LowPriority".to_string(),
+            };
+
+            tracing::warn!(
+                trigger = %trigger_str,
+                recommendation = %recommendation_str,
+                "Escalation trigger detected"
+            );
+
+            let reason = escalation::trigger_reason(trigger);
+            if !self.admit_escalation(reason, EscalationSeverity::for_trigger(trigger)) {
+                continue;
+            }
+            let _ = self.event_tx.send(AgentEvent::EscalationTriggered {
+                trigger: trigger_str,
+                recommendation: recommendation_str,
+            });
+        }
+
+        // Recommend the next best action for the frontend
+        let objection = self
+            .persuasion
+            .detect_objection(user_input, self.user_language());
+        let next_action = self.recommend_next_action(&lead_score, objection.as_deref());
+        tracing::debug!(
+            action = ?next_action.action,
+            rationale = %next_action.rationale,
+            "Next best action"
+        );
+        let _ = self.event_tx.send(AgentEvent::NextBestAction(next_action));
+    }
+
+    /// Forward English sentences to `tx`, translating them for non-English users
+    ///
+    /// Translation runs on its own task so the LLM stream is never held up by
+    /// it. The task returns the translated sentences in order, or None when no
+    /// translation was needed.
+    fn spawn_sentence_translator(
+        &self,
+        tx: mpsc::Sender<String>,
+    ) -> (mpsc::Sender<String>, JoinHandle<Option<Vec<String>>>) {
+        let (sentence_tx, mut sentence_rx) = mpsc::channel::<String>(32);
+        let user_language = self.user_language();
+        let translator = self
+            .translator
+            .clone()
+            .filter(|_| user_language != Language::English);
+
+        let handle = tokio::spawn(async move {
+            let Some(translator) = translator else {
+                while let Some(sentence) = sentence_rx.recv().await {
+                    if tx.send(sentence).await.is_err() {
+                        break;
+                    }
+                }
+                return None;
+            };
+
+            let mut translated = Vec::new();
+            while let Some(sentence) = sentence_rx.recv().await {
+                let sentence = translator
+                    .translate(&sentence, Language::English, user_language)
+                    .await
+                    .unwrap_or_else(|e| {
+                        tracing::warn!(error = %e, "Sentence translation failed, sending English");
+                        sentence
+                    });
+                translated.push(sentence.clone());
+                if tx.send(sentence).await.is_err() {
+                    tracing::debug!("Stream receiver dropped");
+                    break;
+                }
+            }
+            Some(translated)
+        });
+
+        (sentence_tx, handle)
+    }
+
+    /// Prompt for the first required slot of the detected intent that is unfilled
+    ///
+    /// Required slots come from the intent definition in config. A slot counts
+    /// as filled when it, or any alias resolving to it, has a value in the DST
+    /// or was extracted this turn. Prompts come from the intent's goal, falling
+    /// back to the DST's generic slot prompt. Once a slot has been asked for
+    /// more often than its retry policy allows, the policy's fallback is taken.
+    pub(super) fn missing_slot_prompt(
+        &self,
+        intent: &crate::intent::DetectedIntent,
+    ) -> Option<String> {
+        let view = self.domain_view.as_ref()?;
+        let definition = view.get_intent(&intent.intent)?;
+        if definition.required_slots.is_empty() {
+            return None;
+        }
+
+        let slots_config = view.slots_config();
+        let missing = {
+            let dst = self.dialogue_state.read();
+            let filled: HashSet<&str> = dst
+                .state()
+                .filled_slots()
+                .into_iter()
+                .chain(dst.skipped_slots().iter().map(String::as_str))
+                .chain(
+                    intent
+                        .slots
+                        .iter()
+                        .filter(|(_, slot)| slot.value.is_some())
+                        .map(|(name, _)| name.as_str()),
+                )
+                .map(|name| slots_config.canonical_fact_key(name))
+                .collect();
+
+            definition
+                .required_slots
+                .iter()
+                .find(|slot| !filled.contains(slots_config.canonical_fact_key(slot)))?
+        };
+
+        let policy = slots_config.retry_policy_for(missing);
+        let attempts = self.dialogue_state.write().record_slot_attempt(missing);
+        if policy.exhausted(attempts) {
+            return self.slot_retry_fallback(missing, attempts, &policy);
+        }
+
+        let prompt = self.slot_question(&intent.intent, missing);
+
+        tracing::debug!(
+            intent = %intent.intent,
+            slot = %missing,
+            attempt = attempts + 1,
+            "Asking for missing required slot"
+        );
+
+        Some(prompt)
+    }
+
+    /// Question asking for `slot` in the user's language, from the intent's
+    /// goal or the slot config
+    pub(super) fn slot_question(&self, intent: &str, slot: &str) -> String {
+        let language = self.user_language().code();
+        let goal_prompt = self.domain_view.as_ref().and_then(|view| {
+            let goals = &view.config().goals;
+            let goal_id = goals.goal_for_intent(intent).unwrap_or(intent);
+            goals
+                .get_goal(goal_id)
+                .and_then(|goal| goal.get_slot_prompt(slot, language))
+                .map(str::to_string)
+        });
+        goal_prompt.unwrap_or_else(|| self.dialogue_state.read().slot_prompt(slot, language))
+    }
+
+    /// Stop asking for a slot whose retries are exhausted and take the fallback
+    ///
+    /// Returns the policy's message for the fallback, to speak instead of the
+    /// slot prompt. Only a skip may lack one (startup validation requires the
+    /// others to have a message), and the turn then proceeds without the slot.
+    pub(super) fn slot_retry_fallback(
+        &self,
+        slot: &str,
+        attempts: u32,
+        policy: &SlotRetryPolicy,
+    ) -> Option<String> {
+        let fallback = policy.fallback;
+        self.dialogue_state.write().skip_slot(slot);
+
+        tracing::warn!(
+            slot = %slot,
+            attempts,
+            fallback = fallback.as_str(),
+            "Slot retry limit reached"
+        );
+        let _ = self.event_tx.send(AgentEvent::SlotRetryLimitReached {
+            slot: slot.to_string(),
+            attempts,
+            fallback: fallback.as_str().to_string(),
+        });
+
+        if fallback == SlotRetryFallback::Escalate
+            && self.admit_escalation("slot_retry_limit", EscalationSeverity::Medium)
+        {
+            let _ = self.event_tx.send(AgentEvent::EscalationTriggered {
+                trigger: format!("SlotRetryLimit: {} after {} attempts", slot, attempts),
+                recommendation: format!("EscalateNow: could not collect {}", slot),
+            });
+        }
+
+        policy.message(fallback).map(str::to_string)
     }
 
     /// Build LLM request
+    ///
+    /// Sampling parameters follow the current stage (see `sampling_params`).
     pub(super) async fn build_llm_request(
         &self,
         english_input: &str,
         tool_result: Option<&str>,
     ) -> Result<voice_agent_core::GenerateRequest, AgentError> {
-        let persona = self.config.persona.clone();
+        let persona = self.effective_persona();
 
         let mut builder = PromptBuilder::new()
             .with_persona(persona.clone());
@@ -573,13 +943,20 @@ impl DomainAgent {
             builder = builder.with_context("You are a helpful assistant.");
         }
 
+        // A/B experiment prompt sections
+        if let Some(context) = self.experiment_prompt_context() {
+            builder = builder.with_context(&context);
+        }
+
         // Add personalization instructions
         {
             let ctx = self.personalization_ctx.read();
             let instructions = self.personalization.generate_instructions(&ctx);
             if !instructions.is_empty() {
-                builder =
-                    builder.with_context(&format!("## Personalization Guidance\n{}", instructions));
+                builder = builder.with_context_section(
+                    ContextSection::Domain,
+                    &format!("## Personalization Guidance\n{}", instructions),
+                );
             }
         }
 
@@ -622,7 +999,7 @@ impl DomainAgent {
         }
 
         if !context.is_empty() {
-            builder = builder.with_context(&context);
+            builder = builder.with_context_section(ContextSection::Memory, &context);
         }
 
         // Phase 5 + Phase 12: Add DST state context with goal tracking
@@ -651,8 +1028,10 @@ impl DomainAgent {
                     .map(|(k, entry)| format!("- {}: {}", k, entry.value))
                     .collect::<Vec<_>>()
                     .join("\n");
-                builder =
-                    builder.with_context(&format!("## Customer Facts from Memory\n{}", facts_str));
+                builder = builder.with_context_section(
+                    ContextSection::Memory,
+                    &format!("## Customer Facts from Memory\n{}", facts_str),
+                );
             }
 
             let goal_id = dst.goal_id();
@@ -665,7 +1044,7 @@ impl DomainAgent {
         }
 
         // Phase 11: Add RAG context using Agentic RAG
-        if self.config.rag_enabled {
+        if self.turn_needs_rag() {
             let stage = self.conversation.stage();
             let rag_fraction = stage.rag_context_fraction();
 
@@ -673,6 +1052,7 @@ impl DomainAgent {
                 if let (Some(agentic_retriever), Some(vector_store)) =
                     (&self.agentic_retriever, &self.vector_store)
                 {
+                    let retrieval_started = Instant::now();
                     let results = if let Some(prefetched) = self.get_prefetch_results(english_input)
                     {
                         self.clear_prefetch_cache();
@@ -703,6 +1083,9 @@ impl DomainAgent {
                                         "Agentic RAG rewrote query"
                                     );
                                 }
+                                if self.config.agentic_rag.explain {
+                                    *self.last_retrieval.write() = agentic_result.results.clone();
+                                }
                                 agentic_result.results
                             }
                             Err(e) => {
@@ -712,16 +1095,21 @@ impl DomainAgent {
                         }
                     };
 
+                    let results = self.retrieval_limits(stage).apply(results);
+                    self.debug_retrieval(&results, retrieval_started.elapsed());
+                    // A tool result answers the turn even when retrieval is empty
+                    let found = tool_result.is_some() || !results.is_empty();
+                    builder = self.guard_knowledge_gap(builder, found);
                     if !results.is_empty() {
-                        let max_results = ((rag_fraction * 10.0).ceil() as usize).clamp(1, 5);
                         let rag_context = results
                             .iter()
-                            .take(max_results)
                             .map(|r| format!("- {}", r.content))
                             .collect::<Vec<_>>()
                             .join("\n");
-                        builder = builder
-                            .with_context(&format!("## Relevant Information\n{}", rag_context));
+                        builder = builder.with_context_section(
+                            ContextSection::Rag,
+                            &format!("## Relevant Information\n{}", rag_context),
+                        );
                     }
                 }
             }
@@ -740,10 +1128,24 @@ impl DomainAgent {
             }
         }
 
+        // Ask for a reply within the stage's response length limit
+        if let Some(instruction) = self.response_limit().prompt_instruction() {
+            builder = builder.with_context(&instruction);
+        }
+
+        // Don't follow instructions embedded in a flagged turn
+        builder = self.guard_injection(builder);
+
+        // Bridge into the next stage after a stage timeout
+        builder = self.guide_stage_advance(builder);
+
+        // Carry on from a supervisor who handed the call back
+        builder = self.guide_handback(builder);
+
         // Add persuasion guidance
         if let Some(objection_response) = self
             .persuasion
-            .handle_objection(english_input, self.user_language)
+            .handle_objection(english_input, self.user_language())
         {
             let guidance = format!(
                 "## Objection Handling Guidance\n\
@@ -756,7 +1158,7 @@ impl DomainAgent {
                 objection_response.evidence,
                 objection_response.call_to_action
             );
-            builder = builder.with_context(&guidance);
+            builder = builder.with_context_section(ContextSection::Domain, &guidance);
         }
 
         // Add conversation history
@@ -793,6 +1195,14 @@ impl DomainAgent {
             .unwrap_or_else(|| stage.context_budget_tokens());
         let effective_budget = self.config.context_window_tokens.min(stage_budget);
 
-        Ok(builder.build_request_with_limit(effective_budget))
+        let mut request = self
+            .fit_context(builder)
+            .build_request_with_limit(effective_budget);
+        let sampling = self.sampling_params();
+        request.temperature = sampling.temperature.or(request.temperature);
+        request.top_p = sampling.top_p.or(request.top_p);
+        request.max_tokens = sampling.max_tokens.or(request.max_tokens);
+        self.debug_prompt(&request.messages);
+        Ok(request)
     }
 }
@@ -23,7 +23,7 @@ impl DomainAgent {
     /// Returns true if prefetch was triggered, false if skipped (no RAG or low confidence)
     pub async fn prefetch_on_partial(&self, partial_transcript: &str, confidence: f32) -> bool {
         // Skip if RAG is disabled or components not available
-        if !self.config.rag_enabled {
+        if !self.rag_enabled() {
             return false;
         }
 
@@ -54,6 +54,12 @@ impl DomainAgent {
             return false;
         }
 
+        // Nor for small talk or a plain tool request, which won't retrieve
+        if !self.partial_needs_rag(partial_transcript) {
+            tracing::trace!("Skipping prefetch - partial routed away from retrieval");
+            return false;
+        }
+
         // Clone for async task
         let partial = partial_transcript.to_string();
         let cache = self.prefetch_cache.read().clone();
@@ -108,7 +114,7 @@ impl DomainAgent {
     /// Use this when you want to trigger prefetch without waiting for results.
     /// The prefetch will run in the background and populate the cache.
     pub fn prefetch_background(&self, partial_transcript: String, confidence: f32) {
-        if !self.config.rag_enabled {
+        if !self.rag_enabled() {
             return;
         }
 
@@ -119,7 +125,9 @@ impl DomainAgent {
                 _ => return,
             };
 
-        if partial_transcript.split_whitespace().count() < 2 {
+        if partial_transcript.split_whitespace().count() < 2
+            || !self.partial_needs_rag(&partial_transcript)
+        {
             return;
         }
 
@@ -182,6 +190,13 @@ impl DomainAgent {
         None
     }
 
+    /// Score breakdown for the most recent RAG search
+    ///
+    /// Empty unless `agentic_rag.explain` is enabled in the agent config.
+    pub fn last_retrieval_debug(&self) -> Vec<SearchResult> {
+        self.last_retrieval.read().clone()
+    }
+
     /// P2 FIX: Clear prefetch cache
     pub fn clear_prefetch_cache(&self) {
         *self.prefetch_cache.write() = None;
@@ -0,0 +1,176 @@
+//! "Summary So Far" Recaps for DomainAgent
+//!
+//! On long calls both sides lose track. A recap tells the customer what was
+//! covered, what they've shared, what is still needed and what comes next.
+//! It is built from the stage history and the dialogue state, then phrased
+//! by the LLM with a recap prompt; without the LLM the rule-based text is
+//! spoken as is. The customer can ask for one ("summarize", "what have we
+//! covered"), and `every_n_turns` opens every Nth reply with one.
+
+use voice_agent_core::GenerateRequest;
+
+use super::DomainAgent;
+use crate::dst::DialogueStateTrait;
+use crate::intent::DetectedIntent;
+use crate::stage::ConversationStage;
+
+const RECAP_SYSTEM_PROMPT: &str = "You are a friendly loan advisor on a phone call. \
+    Recap the conversation for the customer in plain spoken sentences.";
+
+impl DomainAgent {
+    /// Short "so far we've discussed X, you need Y, next we'll Z" recap
+    pub async fn recap(&self) -> String {
+        let facts = self.rule_based_recap();
+        if !self.config.recap.use_llm {
+            return facts;
+        }
+        let Some(ref llm) = self.llm else {
+            return facts;
+        };
+
+        let transcript = self
+            .conversation
+            .agentic_memory()
+            .get_recent_turns()
+            .iter()
+            .map(|turn| turn.format_for_context())
+            .collect::<Vec<_>>()
+            .join("\n");
+        let prompt = recap_prompt(&facts, &transcript, self.config.recap.max_words);
+        let request = GenerateRequest::new(RECAP_SYSTEM_PROMPT).with_user_message(prompt);
+
+        match llm.generate(request).await {
+            Ok(response) if !response.text.trim().is_empty() => response.text.trim().to_string(),
+            Ok(_) => facts,
+            Err(e) => {
+                tracing::warn!(error = %e, "LLM recap failed, using rule-based recap");
+                facts
+            },
+        }
+    }
+
+    /// Recap for a turn that asks for one
+    ///
+    /// Also counts the turn toward the automatic recap.
+    pub(super) async fn requested_recap(
+        &self,
+        user_input: &str,
+        intent: &DetectedIntent,
+    ) -> Option<String> {
+        *self.turns_since_recap.write() += 1;
+        if !self.is_recap_request(user_input, intent) {
+            return None;
+        }
+        *self.turns_since_recap.write() = 0;
+        tracing::debug!(intent = %intent.intent, "Customer asked for a recap");
+        Some(self.recap().await)
+    }
+
+    /// Recap to open this reply with, every `every_n_turns` turns
+    pub(super) async fn auto_recap(&self) -> Option<String> {
+        let every = self.config.recap.every_n_turns;
+        if every == 0 || *self.turns_since_recap.read() < every {
+            return None;
+        }
+        *self.turns_since_recap.write() = 0;
+        Some(self.recap().await)
+    }
+
+    fn is_recap_request(&self, user_input: &str, intent: &DetectedIntent) -> bool {
+        let config = &self.config.recap;
+        if !config.enabled {
+            return false;
+        }
+        let input = user_input.to_lowercase();
+        config.intents.contains(&intent.intent)
+            || config.phrases.iter().any(|p| input.contains(p.as_str()))
+    }
+
+    /// Recap built from the stage history and dialogue state
+    fn rule_based_recap(&self) -> String {
+        let current = self.conversation.stage();
+        let mut covered: Vec<ConversationStage> = Vec::new();
+        for transition in self.conversation.stage_manager().history() {
+            if !covered.contains(&transition.to) {
+                covered.push(transition.to);
+            }
+        }
+        covered.retain(|stage| *stage != ConversationStage::Greeting);
+        if !covered.contains(&current) {
+            covered.push(current);
+        }
+        let covered: Vec<String> = covered
+            .iter()
+            .map(|stage| stage.display_name().to_lowercase())
+            .collect();
+        let mut sentences = vec![format!("So far we've covered {}.", join_list(&covered))];
+
+        let shared: Vec<String> = {
+            let dst = self.dialogue_state.read();
+            let state = dst.state();
+            let mut slots: Vec<&str> = state.filled_slots();
+            slots.sort_unstable();
+            slots
+                .into_iter()
+                .filter_map(|slot| {
+                    let value = state.get_slot_value(slot)?;
+                    Some(format!("{} {}", self.slot_label(slot), value))
+                })
+                .collect()
+        };
+        if !shared.is_empty() {
+            sentences.push(format!("You've shared your {}.", join_list(&shared)));
+        }
+
+        let missing: Vec<String> = self
+            .current_goal_missing_slots()
+            .iter()
+            .map(|slot| self.slot_label(slot))
+            .collect();
+        if !missing.is_empty() {
+            sentences.push(format!("We still need your {}.", join_list(&missing)));
+        }
+
+        let next = self
+            .conversation
+            .stage_manager()
+            .suggest_next()
+            .or_else(|| current.valid_transitions().first().copied());
+        if let Some(next) = next {
+            sentences.push(format!(
+                "Next, we'll move on to {}.",
+                next.display_name().to_lowercase()
+            ));
+        }
+        sentences.join(" ")
+    }
+
+    fn slot_label(&self, slot: &str) -> String {
+        match self.domain_view {
+            Some(ref view) => view
+                .slots_config()
+                .get_slot_display_label(slot)
+                .to_lowercase(),
+            None => slot.replace('_', " "),
+        }
+    }
+}
+
+/// "a", "a and b", "a, b and c"
+fn join_list(items: &[String]) -> String {
+    match items {
+        [] => String::new(),
+        [only] => only.clone(),
+        [rest @ .., last] => format!("{} and {}", rest.join(", "), last),
+    }
+}
+
+fn recap_prompt(facts: &str, transcript: &str, max_words: usize) -> String {
+    format!(
+        "Recap the conversation so far in at most {} words: what we covered, what the \
+        customer shared, what is still needed and what comes next. Use only these facts \
+        and do not add new offers or numbers.\n\n\
+        ## Facts\n{}\n\n## Recent Conversation\n{}",
+        max_words, facts, transcript
+    )
+}
@@ -8,20 +8,83 @@
 use super::DomainAgent;
 use crate::stage::ConversationStage;
 use crate::AgentError;
+use voice_agent_config::domain::{ResponseLimit, SamplingParams};
 use voice_agent_core::{FinishReason, ToolDefinition};
-use voice_agent_llm::{Message, PromptBuilder, Role};
-use voice_agent_rag::QueryContext;
+use voice_agent_llm::speculative::ModelUsed;
+use voice_agent_llm::{ContextSection, Message, PromptBuilder, Role, UsageSource};
+use voice_agent_rag::{QueryContext, RetrievalLimits, Stage as RagStage};
 use voice_agent_tools::ToolExecutor;
 
+/// Minimum RAG context fraction for turns whose intent was below the confidence floor
+const LOW_CONFIDENCE_RAG_FRACTION: f32 = 0.3;
+
 impl DomainAgent {
+    /// Retrieval top-k and score cutoff for a stage
+    ///
+    /// Stage config overrides the built-in per-stage defaults field by field.
+    pub(super) fn retrieval_limits(&self, stage: ConversationStage) -> RetrievalLimits {
+        let defaults =
+            voice_agent_rag::retrieval_limits_for_stage(RagStage::from_str(stage.as_str()));
+        let Some(view) = self.domain_view.as_ref() else {
+            return defaults;
+        };
+        RetrievalLimits {
+            top_k: view
+                .stage_rag_top_k(stage.as_str())
+                .unwrap_or(defaults.top_k),
+            min_score: view
+                .stage_rag_min_score(stage.as_str())
+                .unwrap_or(defaults.min_score),
+        }
+    }
+
+    /// Response length limit for the current stage
+    ///
+    /// Uses the stage's configured limit, falling back to the built-in
+    /// stage default when no domain config defines the stage.
+    pub(super) fn response_limit(&self) -> ResponseLimit {
+        let stage = self.conversation.stage();
+        self.domain_view
+            .as_ref()
+            .and_then(|v| v.stage_response_limit(stage.as_str()))
+            .unwrap_or_else(|| stage.response_limit())
+    }
+
+    /// LLM sampling parameters for the current stage
+    ///
+    /// Agent config overrides the stage's domain config field by field.
+    /// Compliance stages are capped at the compliance temperature, so regulated
+    /// terms and disclosures are worded near-deterministically.
+    pub(super) fn sampling_params(&self) -> SamplingParams {
+        let stage = self.conversation.stage().as_str();
+        let sampling = &self.config.sampling;
+        let configured = self
+            .domain_view
+            .as_ref()
+            .and_then(|v| v.stage_sampling(stage))
+            .unwrap_or_default();
+        let mut params = sampling
+            .stages
+            .get(stage)
+            .copied()
+            .unwrap_or_default()
+            .or(configured);
+
+        if sampling.compliance_stages.iter().any(|s| s == stage) {
+            let ceiling = sampling.compliance_temperature;
+            params.temperature = Some(params.temperature.map_or(ceiling, |t| t.min(ceiling)));
+        }
+        params
+    }
+
     /// Generate response using LLM
     pub(super) async fn generate_response(
         &self,
         user_input: &str,
         tool_result: Option<&str>,
     ) -> Result<String, AgentError> {
-        // Build prompt - P0 FIX: now just clones consolidated PersonaConfig
-        let persona = self.config.persona.clone();
+        // Build prompt - persona with any experiment overrides applied
+        let persona = self.effective_persona();
 
         let mut builder = PromptBuilder::new()
             .with_persona(persona.clone());
@@ -44,16 +107,24 @@ impl DomainAgent {
             builder = builder.with_context("You are a helpful assistant.");
         }
 
+        // A/B experiment prompt sections
+        if let Some(context) = self.experiment_prompt_context() {
+            builder = builder.with_context(&context);
+        }
+
         // P4 FIX: Add personalization instructions based on detected signals
         // This dynamically adapts the prompt based on customer behavior
         {
             let ctx = self.personalization_ctx.read();
             let personalization_instructions = self.personalization.generate_instructions(&ctx);
             if !personalization_instructions.is_empty() {
-                builder = builder.with_context(&format!(
-                    "## Personalization Guidance\n{}",
-                    personalization_instructions
-                ));
+                builder = builder.with_context_section(
+                    ContextSection::Domain,
+                    &format!(
+                        "## Personalization Guidance\n{}",
+                        personalization_instructions
+                    ),
+                );
                 tracing::trace!(
                     instructions_len = personalization_instructions.len(),
                     "Added personalization instructions to prompt"
@@ -72,20 +143,27 @@ impl DomainAgent {
             .unwrap_or_else(|| stage.context_budget_tokens());
         let context = self.conversation.get_context_for_query(user_input, context_budget);
         if !context.is_empty() {
-            builder = builder.with_context(&context);
+            builder = builder.with_context_section(ContextSection::Memory, &context);
         }
 
         // P1 FIX: Add RAG context if retriever and vector store are available
         // P2 FIX: Use prefetched results if available, otherwise do fresh search
         // P2 FIX: Stage-aware RAG - use rag_context_fraction to determine how much RAG to include
-        if self.config.rag_enabled {
+        if self.turn_needs_rag() {
             let stage = self.conversation.stage();
             // P1.5 FIX: Use config-driven RAG fraction, fall back to hardcoded defaults
-            let rag_fraction = self
+            let stage_rag_fraction = self
                 .domain_view
                 .as_ref()
                 .map(|v| v.stage_rag_fraction(stage.as_str()))
                 .unwrap_or_else(|| stage.rag_context_fraction());
+            // Low-confidence intent: answer generally from the knowledge base
+            // instead of relying on the (skipped) intent action
+            let rag_fraction = if self.conversation.is_low_confidence_turn() {
+                stage_rag_fraction.max(LOW_CONFIDENCE_RAG_FRACTION)
+            } else {
+                stage_rag_fraction
+            };
 
             // Skip RAG entirely for stages that don't need it (greeting, farewell)
             if rag_fraction > 0.0 {
@@ -94,6 +172,7 @@ impl DomainAgent {
                     (&self.agentic_retriever, &self.vector_store)
                 {
                     // First, try to use prefetched results
+                    let retrieval_started = std::time::Instant::now();
                     let results = if let Some(prefetched) = self.get_prefetch_results(user_input) {
                         tracing::debug!("Using {} prefetched RAG results", prefetched.len());
                         // Clear cache after use
@@ -136,29 +215,37 @@ impl DomainAgent {
                         }
                     };
 
-                    if !results.is_empty() {
-                        // P2 FIX: Calculate how many results to include based on stage RAG fraction
-                        // Higher fraction = more results (1-5 based on fraction)
-                        let max_results = ((rag_fraction * 10.0).ceil() as usize).clamp(1, 5);
+                    // Per-stage top-k and score cutoff; weak matches are dropped
+                    let limits = self.retrieval_limits(stage);
+                    let retrieved = results.len();
+                    let results = limits.apply(results);
+                    self.debug_retrieval(&results, retrieval_started.elapsed());
+                    // A tool result answers the turn even when retrieval is empty
+                    let found = tool_result.is_some() || !results.is_empty();
+                    builder = self.guard_knowledge_gap(builder, found);
 
+                    if !results.is_empty() {
                         let rag_context = results
                             .iter()
-                            .take(max_results)
                             .map(|r| format!("- {}", r.content))
                             .collect::<Vec<_>>()
                             .join("\n");
-                        builder = builder
-                            .with_context(&format!("## Relevant Information\n{}", rag_context));
+                        builder = builder.with_context_section(
+                            ContextSection::Rag,
+                            &format!("## Relevant Information\n{}", rag_context),
+                        );
 
                         tracing::debug!(
                             stage = ?stage,
                             rag_fraction = rag_fraction,
-                            max_results = max_results,
-                            actual_results = results.len().min(max_results),
+                            top_k = limits.top_k,
+                            min_score = limits.min_score,
+                            retrieved = retrieved,
+                            used = results.len(),
                             "Stage-aware RAG context added"
                         );
                     } else {
-                        tracing::debug!("RAG returned no results for query");
+                        tracing::debug!(retrieved = retrieved, "No RAG results above stage cutoff");
                     }
                 }
             } else {
@@ -179,11 +266,25 @@ impl DomainAgent {
             }
         }
 
+        // Ask for a reply within the stage's response length limit
+        if let Some(instruction) = self.response_limit().prompt_instruction() {
+            builder = builder.with_context(&instruction);
+        }
+
+        // Don't follow instructions embedded in a flagged turn
+        builder = self.guard_injection(builder);
+
+        // Bridge into the next stage after a stage timeout
+        builder = self.guide_stage_advance(builder);
+
+        // Carry on from a supervisor who handed the call back
+        builder = self.guide_handback(builder);
+
         // P0 FIX: Detect objections and add persuasion guidance to prompt
         // Uses acknowledge-reframe-evidence pattern from PersuasionEngine
         if let Some(objection_response) = self
             .persuasion
-            .handle_objection(user_input, self.user_language)
+            .handle_objection(user_input, self.user_language())
         {
             let persuasion_guidance = format!(
                 "## Objection Handling Guidance\n\
@@ -197,7 +298,7 @@ impl DomainAgent {
                 objection_response.evidence,
                 objection_response.call_to_action
             );
-            builder = builder.with_context(&persuasion_guidance);
+            builder = builder.with_context_section(ContextSection::Domain, &persuasion_guidance);
 
             tracing::debug!("Detected objection, adding persuasion guidance to prompt");
         }
@@ -247,6 +348,15 @@ impl DomainAgent {
             "Using stage-aware context budget"
         );
 
+        // Off-topic question: redirect; nothing relevant retrieved: answer
+        // with the configured fallback
+        if let Some(redirect) = self.out_of_scope_redirect().await {
+            return Ok(redirect);
+        }
+        if let Some(fallback) = self.knowledge_gap_fallback() {
+            return Ok(fallback);
+        }
+
         // P1-2 FIX: Try speculative execution first if enabled and appropriate
         // Speculative doesn't support tool calling, so only use for non-tool responses
         let tool_defs: Vec<ToolDefinition> = if self.config.tools_enabled {
@@ -265,7 +375,8 @@ impl DomainAgent {
         if let Some(ref speculative) = self.speculative {
             if !has_tools {
                 // Build messages for speculative executor (uses llm crate's Message type)
-                let messages = builder.build_with_limit(effective_budget);
+                let messages = self.fit_context(builder).build_with_limit(effective_budget);
+                self.debug_prompt(&messages);
 
                 tracing::debug!(
                     mode = ?self.config.speculative.mode,
@@ -282,6 +393,25 @@ impl DomainAgent {
                             tokens = result.generation.tokens,
                             "Speculative execution succeeded"
                         );
+
+                        // Backends report completion tokens only; estimate the prompt
+                        // with the same chars/3 heuristic as LanguageModel::estimate_tokens
+                        let prompt_tokens: u64 = messages
+                            .iter()
+                            .map(|m| (m.content.chars().count() / 3) as u64)
+                            .sum();
+                        let (source, model) = match result.model_used {
+                            ModelUsed::Slm => (UsageSource::Slm, speculative.slm_model_name()),
+                            ModelUsed::Llm | ModelUsed::Hybrid => {
+                                (UsageSource::Llm, speculative.llm_model_name())
+                            }
+                        };
+                        self.cost.record(
+                            source,
+                            model,
+                            prompt_tokens,
+                            result.generation.tokens as u64,
+                        );
                         return Ok(result.text);
                     }
                     Err(e) => {
@@ -299,126 +429,133 @@ impl DomainAgent {
 
         // P1 FIX: Use build_request_with_limit for LanguageModel trait (fallback path)
         // Rebuild the request since speculative may have consumed the builder
-        let request = self.build_llm_request(user_input, tool_result).await?;
+        let mut request = self.build_llm_request(user_input, tool_result).await?;
+        if let Some(redirect) = self.out_of_scope_redirect().await {
+            return Ok(redirect);
+        }
+        if let Some(fallback) = self.knowledge_gap_fallback() {
+            return Ok(fallback);
+        }
 
         // Try to use LLM backend if available
         if let Some(ref llm) = self.llm {
             // Check if LLM is available
             if llm.is_available().await {
-                tracing::debug!(
-                    tool_count = tool_defs.len(),
-                    tools_enabled = self.config.tools_enabled,
-                    "Calling LLM with tool definitions"
-                );
+                let max_rounds = self.config.max_tool_rounds;
+                let mut round = 0;
+                // Re-grounded retries of replies that ignored an instruction
+                let mut retries = 0;
+
+                // Bounded tool loop: each round executes the requested tools and
+                // re-injects their output as tool messages. Once the cap is hit the
+                // LLM is called without tools so it has to answer.
+                loop {
+                    let offer_tools = has_tools && round < max_rounds;
+                    tracing::debug!(
+                        tool_count = tool_defs.len(),
+                        round = round,
+                        offer_tools = offer_tools,
+                        "Calling LLM"
+                    );
+
+                    // P0-2 FIX: Use generate_with_tools when tools are available
+                    let result = if offer_tools {
+                        llm.generate_with_tools(request.clone(), &tool_defs).await
+                    } else {
+                        llm.generate(request.clone()).await
+                    };
 
-                // P0-2 FIX: Use generate_with_tools when tools are available
-                let result = if has_tools {
-                    llm.generate_with_tools(request, &tool_defs).await
-                } else {
-                    llm.generate(request).await
-                };
+                    let response = match result {
+                        Ok(response) => response,
+                        Err(e) => {
+                            tracing::warn!("LLM generation failed, falling back to mock: {}", e);
+                            break;
+                        }
+                    };
 
-                match result {
-                    Ok(response) => {
-                        // P1 FIX: Use GenerateResponse fields (LanguageModel trait)
-                        let tokens = response
-                            .usage
-                            .as_ref()
-                            .map(|u| u.completion_tokens)
-                            .unwrap_or(0);
-                        tracing::debug!(
-                            "LLM generated {} tokens, finish_reason={:?}, tool_calls={}",
-                            tokens,
-                            response.finish_reason,
-                            response.tool_calls.len()
+                    // P1 FIX: Use GenerateResponse fields (LanguageModel trait)
+                    let tokens = response
+                        .usage
+                        .as_ref()
+                        .map(|u| u.completion_tokens)
+                        .unwrap_or(0);
+                    tracing::debug!(
+                        "LLM generated {} tokens, finish_reason={:?}, tool_calls={}",
+                        tokens,
+                        response.finish_reason,
+                        response.tool_calls.len()
+                    );
+
+                    // P0-2 FIX: Handle tool calls from LLM
+                    if offer_tools
+                        && response.finish_reason == FinishReason::ToolCalls
+                        && !response.tool_calls.is_empty()
+                    {
+                        round += 1;
+                        tracing::info!(
+                            round = round,
+                            max_rounds = max_rounds,
+                            tool_calls = response.tool_calls.len(),
+                            "LLM requested tool calls"
                         );
 
-                        // P0-2 FIX: Handle tool calls from LLM
-                        if response.finish_reason == FinishReason::ToolCalls
-                            && !response.tool_calls.is_empty()
-                        {
-                            tracing::info!(
-                                tool_calls = response.tool_calls.len(),
-                                "LLM requested tool calls"
-                            );
+                        request.messages.push(Message::assistant(response.text.clone()));
+                        for tool_call in &response.tool_calls {
+                            // Convert HashMap arguments to serde_json::Value
+                            let args = serde_json::to_value(&tool_call.arguments)
+                                .unwrap_or(serde_json::json!({}));
+                            let output = self.execute_llm_tool_call(&tool_call.name, args).await;
+                            request
+                                .messages
+                                .push(Message::tool(output, tool_call.id.clone()));
+                        }
 
-                            // Execute each tool call and collect results
-                            let mut tool_results = Vec::new();
-                            for tool_call in &response.tool_calls {
-                                let _ = self.event_tx.send(crate::agent_config::AgentEvent::ToolCall {
-                                    name: tool_call.name.clone(),
-                                });
-
-                                // Convert HashMap arguments to serde_json::Value
-                                let args = serde_json::to_value(&tool_call.arguments)
-                                    .unwrap_or(serde_json::json!({}));
-
-                                match self.tools.execute(&tool_call.name, args).await {
-                                    Ok(output) => {
-                                        let _ = self.event_tx.send(
-                                            crate::agent_config::AgentEvent::ToolResult {
-                                                name: tool_call.name.clone(),
-                                                success: true,
-                                            },
-                                        );
-
-                                        // Extract text from output
-                                        let text = output
-                                            .content
-                                            .iter()
-                                            .filter_map(|c| match c {
-                                                voice_agent_tools::mcp::ContentBlock::Text {
-                                                    text,
-                                                } => Some(text.clone()),
-                                                _ => None,
-                                            })
-                                            .collect::<Vec<_>>()
-                                            .join("\n");
-
-                                        tool_results.push(format!(
-                                            "Tool '{}' result:\n{}",
-                                            tool_call.name, text
-                                        ));
-                                        tracing::debug!(
-                                            tool = %tool_call.name,
-                                            "Tool execution successful"
-                                        );
-                                    }
-                                    Err(e) => {
-                                        let _ = self.event_tx.send(
-                                            crate::agent_config::AgentEvent::ToolResult {
-                                                name: tool_call.name.clone(),
-                                                success: false,
-                                            },
-                                        );
-                                        tool_results.push(format!(
-                                            "Tool '{}' failed: {}",
-                                            tool_call.name, e
-                                        ));
-                                        tracing::warn!(
-                                            tool = %tool_call.name,
-                                            error = %e,
-                                            "Tool execution failed"
-                                        );
-                                    }
-                                }
-                            }
+                        let _ = self.event_tx.send(crate::agent_config::AgentEvent::ToolRound {
+                            round,
+                            tools: response.tool_calls.iter().map(|c| c.name.clone()).collect(),
+                        });
+                        continue;
+                    }
 
-                            // Recursive call with tool results to get final response
-                            // Use Box::pin to avoid infinitely-sized future
-                            let combined_results = tool_results.join("\n\n");
-                            return Box::pin(
-                                self.generate_response(user_input, Some(&combined_results)),
-                            )
-                            .await;
+                    // Past the cap, never speak tool-call markup the LLM still emitted
+                    if has_tools && !offer_tools {
+                        if let Some(parsed) = voice_agent_llm::parse_tool_call(&response.text) {
+                            tracing::warn!(
+                                tool = %parsed.name,
+                                max_rounds = max_rounds,
+                                "Tool round limit reached, ignoring further tool call"
+                            );
+                            let text = format!("{} {}", parsed.text_before, parsed.text_after)
+                                .trim()
+                                .to_string();
+                            if text.is_empty() {
+                                break;
+                            }
+                            return Ok(text);
                         }
-
-                        return Ok(response.text);
                     }
-                    Err(e) => {
-                        tracing::warn!("LLM generation failed, falling back to mock: {}", e);
-                        // Fall through to mock response
+
+                    let tool_call_required =
+                        self.tool_call_required(offer_tools && round == 0, tool_result);
+                    if let Some(violation) =
+                        self.instruction_violation(&response.text, tool_call_required)
+                    {
+                        if retries < self.config.instruction_retry.max_retries {
+                            retries += 1;
+                            request
+                                .messages
+                                .push(Message::assistant(response.text.clone()));
+                            request.messages.push(self.regrounding_message(violation));
+                            continue;
+                        }
+                        tracing::warn!(
+                            violation = violation.as_str(),
+                            retries = retries,
+                            "LLM reply still ignores the instruction, accepting it"
+                        );
                     }
+
+                    return Ok(response.text);
                 }
             } else {
                 tracing::debug!("LLM not available, using mock response");
@@ -436,8 +573,9 @@ impl DomainAgent {
     /// P17 FIX: Config-driven fallback responses with brand substitution
     ///
     /// Generates fallback responses based on:
-    /// 1. Config-driven stage_fallback_responses from prompts config (preferred)
-    /// 2. Generic defaults if config not available
+    /// 1. Cached FAQ answer for the detected intent (see `FaqCache`)
+    /// 2. Config-driven stage_fallback_responses from prompts config (preferred)
+    /// 3. Generic defaults if config not available
     ///
     /// Language support:
     /// - "hi" or "hi-IN": Hinglish (Hindi + English mix)
@@ -454,6 +592,18 @@ impl DomainAgent {
             }
         }
 
+        // Answer common questions from the FAQ cache before the stage fallback
+        if let Some(intent) = self.conversation.last_intent() {
+            if let Some(answer) = self.faq_cache.lookup(&intent, &self.config.language) {
+                tracing::info!(
+                    intent = %intent,
+                    language = %answer.language,
+                    "LLM unavailable, answering from FAQ cache"
+                );
+                return answer.spoken();
+            }
+        }
+
         let language = if self.config.language.starts_with("en") { "en" } else { "hi" };
 
         // P17 FIX: Try config-driven fallback first
@@ -469,6 +619,12 @@ impl DomainAgent {
                 ConversationStage::Farewell => "farewell",
             };
 
+            if stage == ConversationStage::Greeting {
+                if let Some(greeting) = self.experiment_greeting(language) {
+                    return view.with_brand(&greeting);
+                }
+            }
+
             // Try to get config-driven response with brand substitution
             if let Some(response) = view.stage_fallback_response(&stage_name, language) {
                 return response;
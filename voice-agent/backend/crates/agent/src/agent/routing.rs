@@ -0,0 +1,180 @@
+//! RAG/Tool Routing for DomainAgent
+//!
+//! Decides per turn whether the answer needs retrieved knowledge, a tool call,
+//! both, or neither. Greetings and farewells skip retrieval entirely, and a
+//! transactional intent with a mapped tool ("book an appointment") goes
+//! straight to the tool. Retrieval still runs alongside the tool when the
+//! utterance also asks an informational question ("am I eligible, and what
+//! documents do I need?"). Prefetch on a partial transcript is routed the
+//! same way, on the partial's own intent.
+
+use std::sync::OnceLock;
+
+use parking_lot::RwLock;
+use voice_agent_rag::{DomainBooster, QueryIntent};
+
+use super::DomainAgent;
+use crate::agent_config::RoutingConfig;
+use crate::intent::DetectedIntent;
+
+/// What a turn needs before the LLM answers
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TurnRoute {
+    /// Answer from retrieved knowledge
+    Rag,
+    /// Answer from a tool result
+    Tool,
+    /// Call the tool and retrieve knowledge
+    Both,
+    /// Smalltalk; neither retrieval nor tools
+    Neither,
+}
+
+impl TurnRoute {
+    /// Whether the turn retrieves knowledge
+    pub fn needs_rag(self) -> bool {
+        matches!(self, TurnRoute::Rag | TurnRoute::Both)
+    }
+
+    /// Whether the turn calls the intent's tool
+    pub fn needs_tool(self) -> bool {
+        matches!(self, TurnRoute::Tool | TurnRoute::Both)
+    }
+}
+
+/// Pattern-based query intent, used to spot informational questions
+///
+/// Built once and shared by every agent; its patterns don't depend on the
+/// session.
+fn query_booster() -> &'static DomainBooster {
+    static BOOSTER: OnceLock<DomainBooster> = OnceLock::new();
+    BOOSTER.get_or_init(DomainBooster::with_defaults)
+}
+
+/// Routes turns and remembers the current turn's route
+pub(crate) struct TurnRouter {
+    config: RoutingConfig,
+    booster: &'static DomainBooster,
+    current: RwLock<TurnRoute>,
+}
+
+impl TurnRouter {
+    pub(crate) fn new(config: RoutingConfig) -> Self {
+        Self {
+            config,
+            booster: query_booster(),
+            // Nothing is skipped until a turn has been routed
+            current: RwLock::new(TurnRoute::Both),
+        }
+    }
+
+    /// Route of the turn being processed
+    pub(crate) fn current(&self) -> TurnRoute {
+        *self.current.read()
+    }
+
+    /// Decide the route for a turn
+    ///
+    /// `intent` is None when the intent is too uncertain to act on; such
+    /// turns are answered generally from retrieval.
+    fn route(&self, intent: Option<&str>, has_tool: bool, query: &str) -> TurnRoute {
+        if !self.config.enabled {
+            return TurnRoute::Both;
+        }
+        let Some(intent) = intent else {
+            return TurnRoute::Rag;
+        };
+        if self.is_smalltalk(intent) {
+            return TurnRoute::Neither;
+        }
+        if !has_tool {
+            return TurnRoute::Rag;
+        }
+        if self.is_informational(query) {
+            TurnRoute::Both
+        } else {
+            TurnRoute::Tool
+        }
+    }
+
+    /// Whether the intent is small talk, however confidently detected
+    pub(crate) fn is_smalltalk(&self, intent: &str) -> bool {
+        self.config.smalltalk_intents.iter().any(|s| s == intent)
+    }
+
+    /// Whether the query mentions anything from the domain
+    pub(crate) fn mentions_domain(&self, query: &str) -> bool {
+        let boost = self.booster.boost(query);
+        boost.intent.is_some() || !boost.matched_terms.is_empty()
+    }
+
+    /// Whether the query asks something the knowledge base answers
+    fn is_informational(&self, query: &str) -> bool {
+        matches!(
+            self.booster.boost(query).intent,
+            Some(
+                QueryIntent::RateInquiry
+                    | QueryIntent::DocumentInquiry
+                    | QueryIntent::ApplicationProcess
+                    | QueryIntent::CompetitorComparison
+                    | QueryIntent::RepaymentInquiry
+            )
+        )
+    }
+}
+
+impl DomainAgent {
+    /// Route this turn between retrieval and the intent's tool
+    ///
+    /// The route is kept for the rest of the turn so both prompt builders
+    /// skip retrieval consistently.
+    pub(super) fn route_turn(&self, intent: &DetectedIntent, query: &str) -> TurnRoute {
+        let confident = !self.conversation.is_low_confidence_turn();
+        let has_tool = self.has_tool_for(intent);
+
+        self.assess_scope(intent, has_tool, query);
+
+        let route = self
+            .router
+            .route(confident.then_some(intent.intent.as_str()), has_tool, query);
+        tracing::debug!(intent = %intent.intent, route = ?route, "Routed turn");
+        *self.router.current.write() = route;
+        route
+    }
+
+    /// Whether a partial transcript is worth prefetching knowledge for
+    ///
+    /// Prefetch runs before the turn is routed, so the partial is routed on
+    /// its own intent: small talk and plain tool requests aren't prefetched.
+    /// The current turn's route is left alone.
+    pub(super) fn partial_needs_rag(&self, partial: &str) -> bool {
+        let intent = self.conversation.detect_intent(partial);
+        let has_tool = self.has_tool_for(&intent);
+        self.router
+            .route(Some(intent.intent.as_str()), has_tool, partial)
+            .needs_rag()
+    }
+
+    /// Whether the intent has a tool to call
+    fn has_tool_for(&self, intent: &DetectedIntent) -> bool {
+        // A call whose arguments were completed over several turns also
+        // needs the tool
+        let ready_call = self
+            .pending_tool_call
+            .read()
+            .as_ref()
+            .is_some_and(|call| call.is_ready());
+        self.config.tools_enabled
+            && (ready_call
+                || self.domain_view.as_ref().is_some_and(|view| {
+                    let slots: Vec<&str> = intent.slots.keys().map(|s| s.as_str()).collect();
+                    view.resolve_tool_for_intent(&intent.intent, &slots)
+                        .is_some()
+                }))
+    }
+
+    /// Whether this turn retrieves knowledge (RAG enabled and routed to it)
+    pub(super) fn turn_needs_rag(&self) -> bool {
+        self.rag_enabled() && self.router.current().needs_rag()
+    }
+}
@@ -0,0 +1,66 @@
+//! Out-of-Scope Redirect for DomainAgent
+//!
+//! Callers ask things the agent isn't there for ("what's the weather",
+//! "tell me a joke"), and the LLM tends to play along. A turn is out of
+//! scope when the intent is below `OutOfScopeConfig`'s confidence threshold
+//! for the caller's language, no tool is mapped to it, the query mentions
+//! nothing from the domain, and retrieval comes back empty. Such turns skip
+//! the LLM and get the domain's `out_of_scope` response template in the
+//! caller's language. Small talk intents and turns that are only fillers
+//! ("okay thanks") are part of any conversation and never redirected, nor
+//! is anything when the domain has no redirect.
+//!
+//! The first three are known when the turn is routed; retrieval settles it
+//! once results are in.
+
+use super::DomainAgent;
+use crate::intent::DetectedIntent;
+
+impl DomainAgent {
+    /// Note whether this turn may be out of scope, before retrieval runs
+    pub(super) fn assess_scope(&self, intent: &DetectedIntent, has_tool: bool, query: &str) {
+        let scope = &self.config.out_of_scope;
+        let threshold = scope.max_confidence_for(self.user_language().code());
+        let off_topic = scope.enabled
+            && !has_tool
+            && intent.confidence < threshold
+            && !self.router.is_smalltalk(&intent.intent)
+            && !self.router.mentions_domain(query)
+            && self.domain_view.as_ref().is_some_and(|view| {
+                !view.is_filler_only(query) && view.out_of_scope_redirect("en").is_some()
+            });
+        *self.off_topic.write() = off_topic;
+        *self.out_of_scope.write() = false;
+    }
+
+    /// Settle the turn's scope once retrieval has run
+    pub(super) fn record_scope(&self, found: bool) {
+        let out_of_scope = *self.off_topic.read() && !found;
+        if out_of_scope {
+            tracing::info!("Turn is out of scope, redirecting");
+        }
+        *self.out_of_scope.write() = out_of_scope;
+    }
+
+    /// Whether this turn was found to be out of scope
+    pub(super) fn is_out_of_scope_turn(&self) -> bool {
+        *self.out_of_scope.read()
+    }
+
+    /// Redirect to reply with instead of the LLM, if the turn is out of scope
+    ///
+    /// Already in the caller's language. The redirect also stands in for
+    /// the knowledge gap fallback, so any recorded gap is cleared.
+    pub(super) async fn out_of_scope_redirect(&self) -> Option<String> {
+        if !self.is_out_of_scope_turn() {
+            return None;
+        }
+        *self.knowledge_gap.write() = false;
+        let view = self.domain_view.as_ref()?;
+        if let Some(redirect) = view.out_of_scope_redirect(self.user_language().code()) {
+            return Some(redirect);
+        }
+        let english = view.out_of_scope_redirect("en")?;
+        Some(self.localize(&english).await)
+    }
+}
@@ -0,0 +1,93 @@
+//! Slot Collection Progress for DomainAgent
+//!
+//! Frontends render what the agent has collected so far as a form (name ✓,
+//! amount ✓, phone ✗). After each dialogue state update the agent emits
+//! `AgentEvent::SlotsUpdated` with the filled slots and the current goal's
+//! missing required slots, but only when that picture changed. Values of
+//! slots marked `pii` in slots.yaml are masked with the configured
+//! `slot_redaction` strategy before they leave the agent.
+
+use serde::{Deserialize, Serialize};
+
+use super::DomainAgent;
+use crate::agent_config::AgentEvent;
+use crate::dst::DialogueStateTrait;
+
+/// A slot with a value in the dialogue state
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FilledSlot {
+    /// Slot name from config
+    pub name: String,
+    /// Slot value, masked for PII slots
+    pub value: String,
+    /// Extraction confidence (0.0 - 1.0)
+    pub confidence: f32,
+    /// Customer confirmed the value
+    pub confirmed: bool,
+}
+
+/// Slots collected so far for the current goal
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SlotProgress {
+    /// Current dialogue goal
+    pub goal_id: String,
+    /// Slots with a value, sorted by name
+    pub filled: Vec<FilledSlot>,
+    /// Required slots of the goal still missing
+    pub missing: Vec<String>,
+}
+
+impl DomainAgent {
+    /// Slots collected so far, PII values masked
+    pub fn slot_progress(&self) -> SlotProgress {
+        let dst = self.dialogue_state.read();
+        let state = dst.state();
+        let mut filled: Vec<FilledSlot> = state
+            .filled_slots()
+            .into_iter()
+            .filter_map(|name| {
+                let slot = state.get_slot_with_confidence(name)?;
+                let value = self.mask_slot_value(name, &slot.value);
+                Some(FilledSlot {
+                    name: name.to_string(),
+                    value,
+                    confidence: slot.confidence,
+                    confirmed: slot.confirmed || state.confirmed_slots().contains(name),
+                })
+            })
+            .collect();
+        filled.sort_by(|a, b| a.name.cmp(&b.name));
+        let goal_id = dst.goal_id().to_string();
+        drop(dst);
+
+        SlotProgress {
+            goal_id,
+            filled,
+            missing: self.current_goal_missing_slots(),
+        }
+    }
+
+    /// `value` of slot `name`, masked if slots.yaml marks the slot `pii`
+    pub(super) fn mask_slot_value(&self, name: &str, value: &str) -> String {
+        let pii = self
+            .domain_view
+            .as_ref()
+            .and_then(|view| view.slots_config().slots.get(name))
+            .and_then(|definition| definition.pii);
+        match pii {
+            Some(pii) => self.config.slot_redaction.apply(value, pii),
+            None => value.to_string(),
+        }
+    }
+
+    /// Emit `SlotsUpdated` if the collected slots changed since the last one
+    pub(super) fn publish_slot_progress(&self) {
+        let progress = self.slot_progress();
+        let mut last = self.last_slot_progress.write();
+        if last.as_ref() == Some(&progress) {
+            return;
+        }
+        *last = Some(progress.clone());
+        let _ = self.event_tx.send(AgentEvent::SlotsUpdated(progress));
+    }
+}
@@ -0,0 +1,135 @@
+//! Stage Timeouts for DomainAgent
+//!
+//! A stage advances when the customer gives its trigger (an intent or the
+//! required info). A customer who never does would keep the call in that
+//! stage forever, so a stage can set a `timeout` of turns and/or seconds.
+//! Past it, the agent either moves on to the next stage with a bridging
+//! line ("let me tell you about the next steps") or escalates to a human.
+//!
+//! Unlike the lead-score stall counter, which counts turns without progress
+//! across the whole call, the limit is per stage visit. An advance resets the
+//! stall counter; an escalation marks the turn as stalled and restarts the
+//! stage's clock, so the stage escalates again only after another full
+//! limit. Timeouts are counted in `voice_agent_stage_timeouts_total`.
+
+use metrics::counter;
+use voice_agent_config::domain::StageTimeoutAction;
+use voice_agent_llm::PromptBuilder;
+
+use super::escalation::EscalationSeverity;
+use super::DomainAgent;
+use crate::agent_config::AgentEvent;
+use crate::stage::ConversationStage;
+
+/// Bridging line used when the stage config sets none
+const DEFAULT_ADVANCE_MESSAGE: &str = "Let me tell you about the next steps.";
+
+/// Stage move made after a stage timeout
+#[derive(Debug, Clone)]
+pub(crate) struct StageAdvance {
+    pub from: ConversationStage,
+    pub to: ConversationStage,
+    pub message: String,
+}
+
+impl DomainAgent {
+    /// Advance or escalate if the current stage ran past its limit
+    ///
+    /// Runs after the user turn is recorded, so an intent that moves the
+    /// stage on this turn takes precedence.
+    pub(super) fn check_stage_timeout(&self) {
+        *self.stage_advance.write() = None;
+        let Some(view) = self.domain_view.as_ref() else {
+            return;
+        };
+        let from = self.conversation.stage();
+        let Some(timeout) = view.stage_timeout(from.as_str()) else {
+            return;
+        };
+        let stage_manager = self.conversation.stage_manager();
+        let turns = stage_manager.turns_in_stage();
+        if !timeout.exceeded(turns, stage_manager.time_in_stage()) {
+            return;
+        }
+
+        match timeout.action {
+            StageTimeoutAction::Advance => {
+                let target = timeout
+                    .target
+                    .as_deref()
+                    .or_else(|| view.stage_transitions(from.as_str()).first().copied());
+                let Some(to) = target.and_then(ConversationStage::from_str) else {
+                    tracing::warn!(
+                        stage = from.as_str(),
+                        "Stage timed out with no stage to advance to"
+                    );
+                    return;
+                };
+                if let Err(e) = self.conversation.transition_stage_on_timeout(to) {
+                    tracing::warn!(error = %e, "Failed to advance timed-out stage");
+                    return;
+                }
+                counter!(
+                    "voice_agent_stage_timeouts_total",
+                    "stage" => from.as_str(),
+                    "action" => "advance"
+                )
+                .increment(1);
+                tracing::info!(
+                    from = from.as_str(),
+                    to = to.as_str(),
+                    turns,
+                    "Stage timed out, advancing"
+                );
+                self.reset_stall_counter();
+                *self.stage_advance.write() = Some(StageAdvance {
+                    from,
+                    to,
+                    message: timeout
+                        .message
+                        .clone()
+                        .unwrap_or_else(|| DEFAULT_ADVANCE_MESSAGE.to_string()),
+                });
+            },
+            StageTimeoutAction::Escalate => {
+                self.mark_conversation_stalled();
+                // Give the stage a fresh limit rather than timing out every turn
+                stage_manager.restart_stage_clock();
+                if !self.admit_escalation("stage_timeout", EscalationSeverity::Medium) {
+                    return;
+                }
+                counter!(
+                    "voice_agent_stage_timeouts_total",
+                    "stage" => from.as_str(),
+                    "action" => "escalate"
+                )
+                .increment(1);
+                tracing::warn!(stage = from.as_str(), turns, "Stage timed out, escalating");
+                let _ = self.event_tx.send(AgentEvent::EscalationTriggered {
+                    trigger: format!("StageTimeout: {} after {} turns", from.as_str(), turns),
+                    recommendation: "EscalateNow: stage timeout".to_string(),
+                });
+            },
+        }
+    }
+
+    /// Stage move made this turn after a stage timeout, if any
+    pub(crate) fn stage_advance(&self) -> Option<StageAdvance> {
+        self.stage_advance.read().clone()
+    }
+
+    /// Ask the LLM to bridge into the stage a timeout moved to
+    pub(super) fn guide_stage_advance(&self, builder: PromptBuilder) -> PromptBuilder {
+        match self.stage_advance() {
+            Some(advance) => builder.with_context(&format!(
+                "## Moving On\n\
+                 The conversation has spent long enough on {}. Gently move on to {} \
+                 now, opening with a line like: \"{}\"",
+                advance.from.display_name(),
+                advance.to.display_name(),
+                advance.message
+            )),
+            None => builder,
+        }
+    }
+}
@@ -0,0 +1,59 @@
+//! Speaking Style Selection for DomainAgent
+//!
+//! Each user turn is run through the sentiment analyzer; the latest result
+//! and the conversation stage pick the `TtsStyle` the next response is
+//! spoken in. An upset customer always gets the empathetic style, even
+//! while closing.
+//!
+//! The segment persona sets the baseline voice: its speaking rate and pitch
+//! (from the segment config) scale the configured TTS voice.
+
+use voice_agent_pipeline::tts::{TtsConfig, TtsStyle};
+use voice_agent_text_processing::SentimentResult;
+
+use super::DomainAgent;
+use crate::stage::ConversationStage;
+
+/// Pick a speaking style from the customer's sentiment and the stage
+pub fn select_tts_style(sentiment: &SentimentResult, stage: ConversationStage) -> TtsStyle {
+    if sentiment.sentiment.is_negative() {
+        return TtsStyle::Empathetic;
+    }
+
+    match stage {
+        ConversationStage::ObjectionHandling => TtsStyle::Reassuring,
+        ConversationStage::Closing | ConversationStage::Farewell => TtsStyle::Upbeat,
+        _ => TtsStyle::Neutral,
+    }
+}
+
+impl DomainAgent {
+    /// Speaking style for the next response
+    pub fn tts_style(&self) -> TtsStyle {
+        select_tts_style(&self.last_sentiment.read(), self.stage())
+    }
+
+    /// TTS config with the current persona's speaking rate and pitch applied
+    ///
+    /// Follows `set_segment_id`, e.g. slower and warmer for trust seekers.
+    pub fn tts_config(&self, base: &TtsConfig) -> TtsConfig {
+        let ctx = self.personalization_ctx.read();
+        TtsConfig {
+            speaking_rate: base.speaking_rate * ctx.persona.speaking_rate,
+            pitch: base.pitch * ctx.persona.pitch,
+            ..base.clone()
+        }
+    }
+
+    /// Sentiment of the most recent user turn
+    pub fn last_sentiment(&self) -> SentimentResult {
+        self.last_sentiment.read().clone()
+    }
+
+    pub(super) fn track_sentiment(&self, user_input: &str) {
+        if user_input.trim().is_empty() {
+            return;
+        }
+        *self.last_sentiment.write() = self.sentiment.analyze(user_input);
+    }
+}
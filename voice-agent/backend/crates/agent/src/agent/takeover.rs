@@ -0,0 +1,130 @@
+//! Supervisor Takeover and Handback for DomainAgent
+//!
+//! A supervisor can take a call over from the agent. While they hold it the
+//! agent stays silent and only records what the customer says, so the history
+//! stays complete. Handing back ends the takeover. The supervisor's notes are
+//! screened like caller input and kept in core memory as a context note, so
+//! every later prompt carries them. The server only accepts takeover and
+//! handback from keys scoped to supervisors.
+//! The first reply after the handback is told to pick up from the current
+//! state, and the customer hears a short "thanks for holding" line, sent
+//! as `AgentEvent::HandedBack` for the voice loop to speak.
+
+use voice_agent_llm::PromptBuilder;
+
+use super::DomainAgent;
+use crate::agent_config::AgentEvent;
+
+/// Spoken to the customer when the supervisor hands the call back
+const HANDBACK_ACKNOWLEDGEMENT: &str = "Thanks for holding. I'm back with you now.";
+
+/// Who is handling the call
+#[derive(Debug, Clone, Default, PartialEq)]
+pub(crate) enum TakeoverState {
+    /// The agent responds on its own
+    #[default]
+    Agent,
+    /// A supervisor holds the call; the agent stays silent
+    Supervisor,
+    /// Handed back; the next prompt still has to be told
+    HandedBack { notes: Option<String> },
+}
+
+impl DomainAgent {
+    /// Hand the call to a supervisor
+    ///
+    /// Returns false if a supervisor already holds it.
+    pub fn take_over(&self) -> bool {
+        let mut state = self.takeover.write();
+        if *state == TakeoverState::Supervisor {
+            return false;
+        }
+        *state = TakeoverState::Supervisor;
+        tracing::info!(session_id = %self.conversation.session_id(), "Supervisor took over");
+        true
+    }
+
+    /// Whether a supervisor currently holds the call
+    pub fn is_taken_over(&self) -> bool {
+        *self.takeover.read() == TakeoverState::Supervisor
+    }
+
+    /// Return the call to the agent, with the supervisor's notes
+    ///
+    /// Returns the acknowledgement spoken to the customer, or `None` if no
+    /// supervisor held the call.
+    pub async fn hand_back(&self, notes: Option<&str>) -> Option<String> {
+        // Notes go into every later prompt; strip instructions aimed at the LLM
+        let notes = notes
+            .map(|notes| {
+                self.injection_guard
+                    .sanitize(notes.trim(), self.user_language())
+            })
+            .filter(|notes| !notes.is_empty());
+        {
+            let mut state = self.takeover.write();
+            if *state != TakeoverState::Supervisor {
+                return None;
+            }
+            *state = TakeoverState::HandedBack {
+                notes: notes.clone(),
+            };
+        }
+        if let Some(ref notes) = notes {
+            self.conversation
+                .agentic_memory()
+                .core
+                .add_context_note(&format!("Supervisor note: {}", notes));
+        }
+        tracing::info!(
+            session_id = %self.conversation.session_id(),
+            has_notes = notes.is_some(),
+            "Supervisor handed the call back"
+        );
+
+        let acknowledgement = self.localize(HANDBACK_ACKNOWLEDGEMENT).await;
+        if let Err(e) = self.conversation.add_assistant_turn(&acknowledgement) {
+            tracing::warn!(error = %e, "Failed to record handback acknowledgement");
+        }
+        // The voice loop speaks it; the supervisor's console sees the notes
+        let _ = self.event_tx.send(AgentEvent::HandedBack {
+            acknowledgement: acknowledgement.clone(),
+            notes,
+        });
+        Some(acknowledgement)
+    }
+
+    /// Record a customer turn without replying while a supervisor holds the call
+    ///
+    /// Returns false when the agent is handling the call itself.
+    pub(super) fn record_during_takeover(&self, user_input: &str) -> bool {
+        if !self.is_taken_over() {
+            return false;
+        }
+        self.conversation.agentic_memory().add_user_turn(user_input);
+        true
+    }
+
+    /// Tell the first reply after a handback to carry on from the supervisor
+    pub(super) fn guide_handback(&self, builder: PromptBuilder) -> PromptBuilder {
+        let notes = {
+            let mut state = self.takeover.write();
+            let TakeoverState::HandedBack { ref notes } = *state else {
+                return builder;
+            };
+            let notes = notes.clone();
+            *state = TakeoverState::Agent;
+            notes
+        };
+        let notes = notes
+            .map(|notes| format!("\nTheir notes: {}", notes))
+            .unwrap_or_default();
+        builder.with_context(&format!(
+            "## Supervisor Handback\n\
+             A human supervisor handled the last part of this call and has handed it \
+             back to you. Continue from where they left off without repeating what was \
+             already settled.{}",
+            notes
+        ))
+    }
+}
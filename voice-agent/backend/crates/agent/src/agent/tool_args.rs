@@ -0,0 +1,362 @@
+//! Multi-Turn Tool Argument Accumulation for DomainAgent
+//!
+//! A tool with several required parameters rarely gets all of them in one
+//! turn ("am I eligible?" → "about 40 grams" → "22 karat, and I need two
+//! lakh"). Once an intent maps to a tool, the agent keeps a pending call:
+//! each turn, the intent's slots and the dialogue state's filled slots are
+//! mapped onto the tool's parameters and merged into it, and the parameters
+//! the tool's `ToolSchema` requires but still lacks are tracked. In between,
+//! the agent asks for the first missing parameter using that slot's prompt
+//! and retry policy. The tool only runs once nothing is missing. A value
+//! given under another of a parameter's names (`parameter_aliases` in
+//! tools/schemas.yaml) fills that parameter, and slot text is converted to
+//! the type the schema declares ("2,00,000" → 200000 for a number).
+//!
+//! A turn whose intent maps to no tool keeps working on the pending call
+//! only if it fills one of the missing parameters; otherwise the call waits
+//! and the turn is answered normally. Once the conversation stage or the
+//! dialogue goal changes, the caller has moved on and the call is dropped.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use voice_agent_tools::{PropertySchema, Tool};
+
+use voice_agent_config::domain::AgentDomainView;
+
+use super::DomainAgent;
+use crate::dst::DialogueStateTrait;
+use crate::intent::DetectedIntent;
+
+/// Tool call whose arguments are being gathered across turns
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PendingToolCall {
+    /// Tool to invoke
+    pub tool: String,
+    /// Intent that started the call, for the goal's slot prompts
+    pub intent: String,
+    /// Arguments collected so far
+    pub arguments: Map<String, Value>,
+    /// Required parameters of the tool's schema without a value, in schema order
+    pub missing: Vec<String>,
+    /// Conversation stage when the call started
+    #[serde(default)]
+    pub stage: String,
+    /// Dialogue goal when the call started
+    #[serde(default)]
+    pub goal: String,
+}
+
+impl PendingToolCall {
+    /// Whether every required parameter has a value
+    pub fn is_ready(&self) -> bool {
+        self.missing.is_empty()
+    }
+}
+
+impl DomainAgent {
+    /// Tool call whose arguments are being gathered, if any
+    pub fn pending_tool_call(&self) -> Option<PendingToolCall> {
+        self.pending_tool_call.read().clone()
+    }
+
+    /// Merge this turn's slots into the pending tool call
+    ///
+    /// Starts a call when the intent maps to a tool, and continues one when
+    /// the turn fills a missing parameter. Returns whether the turn worked
+    /// on a call.
+    pub(super) fn accumulate_tool_arguments(&self, intent: &DetectedIntent) -> bool {
+        if !self.config.tools_enabled {
+            return false;
+        }
+        let Some(view) = self.domain_view.as_ref() else {
+            return false;
+        };
+        self.drop_stale_tool_call(view);
+
+        let mapped = view
+            .get_intent_mapping(&intent.intent)
+            .map(|mapping| mapping.tool.clone());
+        let starts = mapped.is_some();
+        let pending = self.pending_tool_call.read().clone();
+        let (tool, origin, mut arguments, missing_before, started) = match (mapped, pending) {
+            (Some(tool), Some(call)) if call.tool == tool => (
+                tool,
+                call.intent,
+                call.arguments,
+                call.missing,
+                (call.stage, call.goal),
+            ),
+            (Some(tool), _) => (
+                tool,
+                intent.intent.clone(),
+                Map::new(),
+                Vec::new(),
+                self.tool_call_context(),
+            ),
+            (None, Some(call)) => (
+                call.tool,
+                call.intent,
+                call.arguments,
+                call.missing,
+                (call.stage, call.goal),
+            ),
+            (None, None) => return false,
+        };
+        let Some(schema) = self.tools.get(&tool).map(|t| t.schema()) else {
+            return false;
+        };
+        let properties = &schema.input_schema.properties;
+
+        // Earlier turns' arguments, then what the dialogue state holds, then
+        // this turn's slots; later sources win
+        let state_slots: Vec<(String, String)> = {
+            let dst = self.dialogue_state.read();
+            let state = dst.state();
+            state
+                .filled_slots()
+                .into_iter()
+                .filter_map(|name| Some((name.to_string(), state.get_slot_value(name)?)))
+                .collect()
+        };
+        for (name, value) in self.slot_arguments(&tool, state_slots) {
+            if properties.contains_key(&name) {
+                arguments.insert(name, value);
+            }
+        }
+        let turn_slots = intent
+            .slots
+            .iter()
+            .filter_map(|(name, slot)| Some((name.clone(), slot.value.clone()?)));
+        arguments.extend(self.slot_arguments(&tool, turn_slots));
+
+        let engaged = starts || missing_before.iter().any(|p| arguments.contains_key(p));
+        if !engaged {
+            return false;
+        }
+
+        if let Some(defaults) = view.get_tool_defaults(&tool) {
+            for (name, value) in defaults {
+                arguments
+                    .entry(name.clone())
+                    .or_insert_with(|| value.clone());
+            }
+        }
+        for name in properties.keys() {
+            if arguments.contains_key(name) {
+                continue;
+            }
+            let value = self
+                .parameter_names(name)
+                .iter()
+                .find_map(|alias| arguments.get(*alias).cloned());
+            if let Some(value) = value {
+                arguments.insert(name.clone(), value);
+            }
+        }
+        if tool.contains("capture") && !arguments.contains_key("interest_level") {
+            let level = if intent.confidence > 0.8 {
+                "High"
+            } else {
+                "Medium"
+            };
+            arguments.insert("interest_level".to_string(), serde_json::json!(level));
+        }
+
+        coerce_to_schema(properties, &mut arguments);
+
+        let missing: Vec<String> = schema
+            .input_schema
+            .required
+            .iter()
+            .filter(|name| {
+                !arguments.contains_key(*name)
+                    && properties.get(*name).map_or(true, |p| p.default.is_none())
+            })
+            .cloned()
+            .collect();
+
+        tracing::debug!(
+            tool = %tool,
+            collected = arguments.len(),
+            missing = ?missing,
+            "Accumulated tool arguments"
+        );
+        let (stage, goal) = started;
+        *self.pending_tool_call.write() = Some(PendingToolCall {
+            tool,
+            intent: origin,
+            arguments,
+            missing,
+            stage,
+            goal,
+        });
+        true
+    }
+
+    /// Current conversation stage and dialogue goal
+    fn tool_call_context(&self) -> (String, String) {
+        let stage = self.conversation.stage().as_str().to_string();
+        let goal = self.dialogue_state.read().goal_id().to_string();
+        (stage, goal)
+    }
+
+    /// Drop the pending call if the stage or the dialogue goal changed since it started
+    ///
+    /// Only a move to another goal configured in goals.yaml counts: the
+    /// dialogue state also takes the name of any intent as its goal.
+    fn drop_stale_tool_call(&self, view: &AgentDomainView) {
+        let (stage, goal) = self.tool_call_context();
+        let mut pending = self.pending_tool_call.write();
+        let Some(call) = pending.as_ref() else {
+            return;
+        };
+        let goal_changed = goal != call.goal && view.config().goals.get_goal(&goal).is_some();
+        if call.stage == stage && !goal_changed {
+            return;
+        }
+        tracing::debug!(
+            tool = %call.tool,
+            stage = %stage,
+            goal = %goal,
+            "Dropping pending tool call after a stage or goal change"
+        );
+        *pending = None;
+    }
+
+    /// Ask for the first parameter the pending tool call still lacks
+    ///
+    /// Follows the slot's retry policy; once retries run out, or the slot
+    /// was skipped, the call is dropped.
+    pub(super) fn missing_tool_argument_prompt(&self) -> Option<String> {
+        let (tool, intent, parameter) = {
+            let pending = self.pending_tool_call.read();
+            let call = pending.as_ref()?;
+            (
+                call.tool.clone(),
+                call.intent.clone(),
+                call.missing.first()?.clone(),
+            )
+        };
+        let view = self.domain_view.as_ref()?;
+        let slot = self.slot_for_argument(&tool, &parameter);
+
+        if self.dialogue_state.read().skipped_slots().contains(&slot) {
+            *self.pending_tool_call.write() = None;
+            return None;
+        }
+        let policy = view.slots_config().retry_policy_for(&slot);
+        let attempts = self.dialogue_state.write().record_slot_attempt(&slot);
+        if policy.exhausted(attempts) {
+            *self.pending_tool_call.write() = None;
+            return self.slot_retry_fallback(&slot, attempts, &policy);
+        }
+
+        tracing::debug!(
+            tool = %tool,
+            parameter = %parameter,
+            slot = %slot,
+            attempt = attempts + 1,
+            "Asking for missing tool argument"
+        );
+        Some(self.slot_question(&intent, &slot))
+    }
+
+    /// Take the pending tool call if its arguments are complete
+    pub(super) fn take_ready_tool_call(&self) -> Option<PendingToolCall> {
+        let mut pending = self.pending_tool_call.write();
+        if pending.as_ref().is_some_and(PendingToolCall::is_ready) {
+            pending.take()
+        } else {
+            None
+        }
+    }
+
+    /// Slot values renamed to the tool's parameter names
+    fn slot_arguments(
+        &self,
+        tool: &str,
+        slots: impl IntoIterator<Item = (String, String)>,
+    ) -> Map<String, Value> {
+        let mut args: Map<String, Value> = slots
+            .into_iter()
+            .map(|(name, value)| (name, Value::String(value)))
+            .collect();
+        self.apply_tool_argument_mapping(tool, &mut args);
+        self.apply_common_argument_mappings(&mut args);
+        args
+    }
+
+    /// A parameter's name and its aliases from the tools config
+    fn parameter_names<'a>(&'a self, parameter: &'a str) -> Vec<&'a str> {
+        let aliases = self
+            .domain_view
+            .as_ref()
+            .map(|view| &view.config().tools.parameter_aliases);
+        let group = aliases.into_iter().flatten().find(|(generic, names)| {
+            generic.as_str() == parameter || names.iter().any(|n| n == parameter)
+        });
+        match group {
+            Some((generic, names)) => std::iter::once(generic.as_str())
+                .chain(names.iter().map(String::as_str))
+                .collect(),
+            None => vec![parameter],
+        }
+    }
+
+    /// Dialogue state slot that fills a tool parameter
+    ///
+    /// Inverts the tool's argument mapping; a parameter no slot maps to is
+    /// taken to be a slot of the same name.
+    pub(super) fn slot_for_argument(&self, tool: &str, parameter: &str) -> String {
+        let Some(view) = self.domain_view.as_ref() else {
+            return parameter.to_string();
+        };
+        let slots_config = view.slots_config();
+        let names = self.parameter_names(parameter);
+        let mut candidates: Vec<&str> = view
+            .get_argument_mapping(tool)
+            .into_iter()
+            .flatten()
+            .chain(view.get_common_argument_mappings())
+            .filter(|(_, arg)| names.contains(&arg.as_str()))
+            .map(|(slot, _)| slots_config.canonical_fact_key(slot))
+            .collect();
+        candidates.sort_unstable();
+        candidates
+            .first()
+            .copied()
+            .unwrap_or_else(|| slots_config.canonical_fact_key(parameter))
+            .to_string()
+    }
+}
+
+/// Convert slot text to the types the tool's schema declares
+///
+/// Values that don't parse are left as text for the tool's validation to
+/// report.
+fn coerce_to_schema(
+    properties: &HashMap<String, PropertySchema>,
+    arguments: &mut Map<String, Value>,
+) {
+    for (name, value) in arguments.iter_mut() {
+        let (Some(property), Value::String(text)) = (properties.get(name), &*value) else {
+            continue;
+        };
+        let text = text.trim().replace(',', "");
+        let typed = match property.prop_type.as_str() {
+            "number" => text
+                .parse::<f64>()
+                .ok()
+                .and_then(serde_json::Number::from_f64)
+                .map(Value::Number),
+            "integer" => text.parse::<i64>().ok().map(Value::from),
+            "boolean" => text.parse::<bool>().ok().map(Value::Bool),
+            _ => None,
+        };
+        if let Some(typed) = typed {
+            *value = typed;
+        }
+    }
+}
@@ -13,14 +13,45 @@
 //!
 //! Legacy hardcoded fallbacks have been removed. If config is missing,
 //! tools will not be called (fail-fast approach).
+//!
+//! Tools that change the caller's own records, such as rescheduling an
+//! appointment, always get the phone number given in this session, never
+//! one chosen by the LLM.
 
 use super::DomainAgent;
 use crate::agent_config::AgentEvent;
 use crate::dst::DialogueStateTrait;
 use crate::AgentError;
-use voice_agent_tools::ToolExecutor;
+
+/// Tools acting on records booked under the caller's phone number
+const CALLER_SCOPED_TOOLS: [&str; 1] = ["change_appointment"];
 
 impl DomainAgent {
+    /// Bind a caller-scoped tool's `phone_number` to the caller's number
+    ///
+    /// The number collected in this session replaces whatever the LLM or
+    /// intent slots passed, so a caller can only change appointments booked
+    /// on their own number. Until the caller gives one, the tool is called
+    /// without it and asks for it.
+    pub(super) fn bind_caller_phone(&self, name: &str, arguments: &mut serde_json::Value) {
+        if !CALLER_SCOPED_TOOLS.contains(&name) {
+            return;
+        }
+        let Some(args) = arguments.as_object_mut() else {
+            return;
+        };
+        let phone = self
+            .dialogue_state
+            .read()
+            .state()
+            .phone_number()
+            .map(str::to_string);
+        match phone {
+            Some(phone) => args.insert("phone_number".to_string(), serde_json::json!(phone)),
+            None => args.remove("phone_number"),
+        };
+    }
+
     /// Maybe call a tool based on intent
     ///
     /// P20 FIX: Fully config-driven - NO hardcoded fallback mappings.
@@ -29,6 +60,13 @@ impl DomainAgent {
         &self,
         intent: &crate::intent::DetectedIntent,
     ) -> Result<Option<String>, AgentError> {
+        // Arguments gathered over several turns are complete (see `tool_args`)
+        if let Some(call) = self.take_ready_tool_call() {
+            return Ok(self
+                .run_tool(&call.tool, serde_json::Value::Object(call.arguments))
+                .await);
+        }
+
         // Collect available slot names
         let available_slots: Vec<&str> = intent.slots.keys().map(|s| s.as_str()).collect();
 
@@ -61,10 +99,6 @@ impl DomainAgent {
             });
 
         if let Some(name) = tool_name {
-            let _ = self.event_tx.send(AgentEvent::ToolCall {
-                name: name.to_string(),
-            });
-
             // Build arguments from slots
             let mut args = serde_json::Map::new();
             for (key, slot) in &intent.slots {
@@ -77,18 +111,7 @@ impl DomainAgent {
             // All defaults and argument mappings come from tools/schemas.yaml
             if let Some(view) = self.domain_view.as_ref() {
                 // Apply argument name mappings from config
-                if let Some(arg_mapping) = view.get_argument_mapping(&name) {
-                    let keys: Vec<String> = args.keys().cloned().collect();
-                    for slot_name in keys {
-                        if let Some(arg_name) = arg_mapping.get(&slot_name) {
-                            if !args.contains_key(arg_name) {
-                                if let Some(value) = args.remove(&slot_name) {
-                                    args.insert(arg_name.clone(), value);
-                                }
-                            }
-                        }
-                    }
-                }
+                self.apply_tool_argument_mapping(&name, &mut args);
 
                 // Apply defaults from config
                 if let Some(tool_defaults) = view.get_tool_defaults(&name) {
@@ -117,40 +140,44 @@ impl DomainAgent {
                 args.insert("interest_level".to_string(), serde_json::json!(level));
             }
 
-            let result = self
-                .tools
-                .execute(&name, serde_json::Value::Object(args))
-                .await;
+            Ok(self.run_tool(&name, serde_json::Value::Object(args)).await)
+        } else {
+            Ok(None)
+        }
+    }
 
-            let success = result.is_ok();
-            let _ = self.event_tx.send(AgentEvent::ToolResult {
-                name: name.to_string(),
-                success,
-            });
+    /// Run an intent's tool and return its text output
+    ///
+    /// Emits `ToolCall`; None if the tool failed.
+    async fn run_tool(&self, name: &str, args: serde_json::Value) -> Option<String> {
+        let _ = self.event_tx.send(AgentEvent::ToolCall {
+            name: name.to_string(),
+        });
+        if let Some(reply) = self.suppress_escalation_tool(name, &args) {
+            return Some(reply);
+        }
+        let result = self.execute_tool(name, args).await;
 
-            match result {
-                Ok(output) => {
-                    // Extract text from output
-                    let text = output
-                        .content
-                        .iter()
-                        .filter_map(|c| match c {
-                            voice_agent_tools::mcp::ContentBlock::Text { text } => {
-                                Some(text.clone())
-                            }
-                            _ => None,
-                        })
-                        .collect::<Vec<_>>()
-                        .join("\n");
-                    Ok(Some(text))
-                }
-                Err(e) => {
-                    tracing::warn!("Tool error: {}", e);
-                    Ok(None)
-                }
+        self.record_tool_result(name, result.is_ok());
+
+        match result {
+            Ok(output) => {
+                // Extract text from output
+                let text = output
+                    .content
+                    .iter()
+                    .filter_map(|c| match c {
+                        voice_agent_tools::mcp::ContentBlock::Text { text } => Some(text.clone()),
+                        _ => None,
+                    })
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                Some(text)
+            }
+            Err(e) => {
+                tracing::warn!("Tool error: {}", e);
+                None
             }
-        } else {
-            Ok(None)
         }
     }
 
@@ -193,6 +220,10 @@ impl DomainAgent {
                 args.entry("city".to_string())
                     .or_insert(serde_json::json!(val));
             }
+            if let Some(val) = state.get_slot_value("pincode") {
+                args.entry("pincode".to_string())
+                    .or_insert(serde_json::json!(val));
+            }
             // P19 FIX: Try generic slot names first, then legacy names
             // Generic names are defined in slots.yaml, legacy names for backwards compat
             if let Some(val) = state.get_slot_value("asset_quantity")
@@ -228,18 +259,7 @@ impl DomainAgent {
         // P20 FIX: Config-driven defaults ONLY
         if let Some(view) = self.domain_view.as_ref() {
             // Apply argument name mappings from config
-            if let Some(arg_mapping) = view.get_argument_mapping(tool_name) {
-                let keys: Vec<String> = args.keys().cloned().collect();
-                for slot_name in keys {
-                    if let Some(arg_name) = arg_mapping.get(&slot_name) {
-                        if !args.contains_key(arg_name) {
-                            if let Some(value) = args.remove(&slot_name) {
-                                args.insert(arg_name.clone(), value);
-                            }
-                        }
-                    }
-                }
-            }
+            self.apply_tool_argument_mapping(tool_name, &mut args);
 
             // Apply defaults from config
             if let Some(tool_defaults) = view.get_tool_defaults(tool_name) {
@@ -272,16 +292,13 @@ impl DomainAgent {
             "Calling tool proactively with DST state"
         );
 
-        let result = self
-            .tools
-            .execute(tool_name, serde_json::Value::Object(args))
-            .await;
+        let args = serde_json::Value::Object(args);
+        if let Some(reply) = self.suppress_escalation_tool(tool_name, &args) {
+            return Ok(Some(reply));
+        }
+        let result = self.execute_tool(tool_name, args).await;
 
-        let success = result.is_ok();
-        let _ = self.event_tx.send(AgentEvent::ToolResult {
-            name: tool_name.to_string(),
-            success,
-        });
+        self.record_tool_result(tool_name, result.is_ok());
 
         match result {
             Ok(output) => {
@@ -303,11 +320,83 @@ impl DomainAgent {
         }
     }
 
+    /// Execute a tool call requested by the LLM
+    ///
+    /// Emits `ToolCall`/`ToolResult` events and returns the outcome formatted
+    /// for the `## Tool Result` prompt section.
+    pub(super) async fn execute_llm_tool_call(
+        &self,
+        name: &str,
+        arguments: serde_json::Value,
+    ) -> String {
+        let _ = self.event_tx.send(AgentEvent::ToolCall {
+            name: name.to_string(),
+        });
+        if let Some(reply) = self.suppress_escalation_tool(name, &arguments) {
+            return format!("Tool '{}' result:\n{}", name, reply);
+        }
+
+        match self.execute_tool(name, arguments).await {
+            Ok(output) => {
+                self.record_tool_result(name, true);
+
+                let text = output
+                    .content
+                    .iter()
+                    .filter_map(|c| match c {
+                        voice_agent_tools::mcp::ContentBlock::Text { text } => Some(text.clone()),
+                        _ => None,
+                    })
+                    .collect::<Vec<_>>()
+                    .join("\n");
+
+                tracing::debug!(tool = %name, "Tool execution successful");
+                format!("Tool '{}' result:\n{}", name, text)
+            }
+            Err(e) => {
+                self.record_tool_result(name, false);
+                tracing::warn!(tool = %name, error = %e, "Tool execution failed");
+                format!("Tool '{}' failed: {}", name, e)
+            }
+        }
+    }
+
+    /// Rename slot-named arguments to the tool's parameter names
+    ///
+    /// Uses the tool's `argument_mappings` from config; a slot is left as is
+    /// when its parameter already has a value.
+    pub(super) fn apply_tool_argument_mapping(
+        &self,
+        tool: &str,
+        args: &mut serde_json::Map<String, serde_json::Value>,
+    ) {
+        let Some(arg_mapping) = self
+            .domain_view
+            .as_ref()
+            .and_then(|view| view.get_argument_mapping(tool))
+        else {
+            return;
+        };
+        let keys: Vec<String> = args.keys().cloned().collect();
+        for slot_name in keys {
+            if let Some(arg_name) = arg_mapping.get(&slot_name) {
+                if !args.contains_key(arg_name) {
+                    if let Some(value) = args.remove(&slot_name) {
+                        args.insert(arg_name.clone(), value);
+                    }
+                }
+            }
+        }
+    }
+
     /// Apply common slot-to-argument mappings
     ///
     /// P20 FIX: Uses config-driven common mappings when available.
     /// Falls back to hardcoded mappings only when domain_view is not configured.
-    fn apply_common_argument_mappings(&self, args: &mut serde_json::Map<String, serde_json::Value>) {
+    pub(super) fn apply_common_argument_mappings(
+        &self,
+        args: &mut serde_json::Map<String, serde_json::Value>,
+    ) {
         // P20 FIX: Try config-driven common mappings first
         if let Some(ref view) = self.domain_view {
             let common_mappings = view.get_common_argument_mappings();
@@ -0,0 +1,72 @@
+//! Translation Gate for Caller Input
+//!
+//! Non-English callers' input is translated to English before intent
+//! detection and the LLM. Callers mix languages freely though, and forcing
+//! "I want a gold loan, what's the rate?" through the translator garbles it,
+//! or translates English words twice over. The script detector scores each
+//! turn first: input that is already mostly Latin script, or whose script is
+//! too mixed to call, goes to the English-capable LLM as is. Latin script
+//! isn't always English: a Hindi caller's romanized Hindi ("mujhe gold loan
+//! chahiye, kitna milega?") is still Hindi and is translated, once common
+//! Hindi words make up `hinglish_threshold` of it. Skipped
+//! translations are counted in `voice_agent_translation_skipped_total`.
+
+use metrics::counter;
+use voice_agent_core::Language;
+
+use super::DomainAgent;
+
+impl DomainAgent {
+    /// Caller input in English for intent detection, memory and the LLM
+    pub(super) async fn english_input(&self, user_input: &str) -> String {
+        if self.user_language() == Language::English {
+            return user_input.to_string();
+        }
+        let Some(ref translator) = self.translator else {
+            return user_input.to_string();
+        };
+        if let Some(reason) = self.skip_translation(user_input) {
+            counter!("voice_agent_translation_skipped_total", "reason" => reason).increment(1);
+            tracing::debug!(reason, "Passing caller input through untranslated");
+            return user_input.to_string();
+        }
+
+        match translator
+            .translate(user_input, self.user_language(), Language::English)
+            .await
+        {
+            Ok(translated) => {
+                tracing::debug!(
+                    from = ?self.user_language(),
+                    original = %user_input,
+                    translated = %translated,
+                    "Translated user input to English"
+                );
+                translated
+            },
+            Err(e) => {
+                tracing::warn!(error = %e, "Translation failed, using original input");
+                user_input.to_string()
+            },
+        }
+    }
+
+    /// Why the input should skip translation, if it should
+    fn skip_translation(&self, user_input: &str) -> Option<&'static str> {
+        let gate = &self.config.translation_gate;
+        if !gate.enabled {
+            return None;
+        }
+        let (language, confidence) = self.script_detector.detect_with_confidence(user_input);
+        if language == Language::English && confidence >= gate.english_threshold {
+            let hinglish = self.user_language() == Language::Hindi
+                && self.script_detector.romanized_hindi_share(user_input)
+                    >= gate.hinglish_threshold;
+            (!hinglish).then_some("english")
+        } else if confidence < gate.min_confidence {
+            Some("low_confidence")
+        } else {
+            None
+        }
+    }
+}
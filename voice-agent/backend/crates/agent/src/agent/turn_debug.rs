@@ -0,0 +1,277 @@
+//! Per-Turn Debug Output for DomainAgent
+//!
+//! Working out why the agent said something means piecing together the
+//! intent, the slots, what retrieval found, the prompt and the tool calls.
+//! With `turn_debug` on in the agent config, `process` and
+//! `process_stream_into` collect all of it for the turn into a `TurnDebug`,
+//! emit it as `AgentEvent::TurnDebug` and keep it for `last_turn_debug`;
+//! `process_with_debug` returns it alongside the response. Off, nothing is
+//! collected, cloned or timed beyond what the turn does anyway.
+//!
+//! Slot values, and tool arguments filled from PII slots, are masked the
+//! same way as in `SlotsUpdated`. The prompt is kept as sent, so debug
+//! output should stay out of production.
+
+use std::time::{Duration, Instant};
+
+use voice_agent_core::llm_types::Message;
+use voice_agent_rag::SearchResult;
+use voice_agent_tools::{ToolError, ToolExecutor, ToolOutput};
+
+use super::{DomainAgent, SlotProgress};
+use crate::agent_config::AgentEvent;
+use crate::intent::DetectedIntent;
+use crate::AgentError;
+
+/// What went into one turn's response
+#[derive(Debug, Clone, Default)]
+pub struct TurnDebug {
+    /// Caller input after screening
+    pub input: String,
+    /// Detected intent
+    pub intent: String,
+    /// Confidence of the detected intent
+    pub confidence: f32,
+    /// Runner-up intents with their confidence
+    pub alternatives: Vec<(String, f32)>,
+    /// Dialogue state slots this turn set, changed or cleared
+    pub slot_changes: Vec<SlotChange>,
+    /// Retrieval results that went into the turn's prompts, with scores
+    pub retrieval: Vec<SearchResult>,
+    /// Prompt as last sent to the LLM (empty if the LLM wasn't prompted)
+    pub prompt: Vec<Message>,
+    /// Tools that ran, in order
+    pub tool_calls: Vec<ToolCallDebug>,
+    /// Where the turn's time went
+    pub latency: TurnLatency,
+    /// Response given to the caller
+    pub response: String,
+}
+
+/// A dialogue state slot whose value changed this turn
+#[derive(Debug, Clone, PartialEq)]
+pub struct SlotChange {
+    /// Slot name from config
+    pub name: String,
+    /// Value before the turn (masked for PII slots)
+    pub before: Option<String>,
+    /// Value after the turn (masked for PII slots)
+    pub after: Option<String>,
+}
+
+/// A tool run during the turn
+#[derive(Debug, Clone)]
+pub struct ToolCallDebug {
+    /// Tool name
+    pub name: String,
+    /// Arguments the tool was called with (masked for PII slots)
+    pub arguments: serde_json::Value,
+    /// Whether the tool succeeded
+    pub success: bool,
+    /// Time the tool took
+    pub duration_ms: u64,
+}
+
+/// Time spent in each part of a turn, in milliseconds
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TurnLatency {
+    /// Intent detection
+    pub intent_ms: u64,
+    /// Retrieval, across every search the turn ran
+    pub retrieval_ms: u64,
+    /// Tool execution, across every tool call
+    pub tools_ms: u64,
+    /// Response generation, retrieval and LLM tool calls included
+    pub response_ms: u64,
+    /// Whole turn
+    pub total_ms: u64,
+}
+
+impl DomainAgent {
+    /// Debug output of the last turn handled
+    ///
+    /// None unless `turn_debug` is enabled in the agent config.
+    pub fn last_turn_debug(&self) -> Option<TurnDebug> {
+        self.turn_debug.read().clone()
+    }
+
+    /// Process user input, returning the turn's debug output with the response
+    ///
+    /// The debug output is None when `turn_debug` is off, or when the turn
+    /// was the consent answer or handled by a supervisor.
+    pub async fn process_with_debug(
+        &self,
+        user_input: &str,
+    ) -> Result<(String, Option<TurnDebug>), AgentError> {
+        *self.turn_debug.write() = None;
+        let response = self.process(user_input).await?;
+        Ok((response, self.last_turn_debug()))
+    }
+
+    /// Whether turns are collecting debug output
+    pub(super) fn turn_debug_enabled(&self) -> bool {
+        self.config.turn_debug
+    }
+
+    /// Start collecting debug output for a turn
+    pub(super) fn begin_turn_debug(&self, input: &str) {
+        *self.turn_debug.write() = self.turn_debug_enabled().then(|| TurnDebug {
+            input: input.to_string(),
+            ..Default::default()
+        });
+    }
+
+    /// Record the turn's intent and how long detection took
+    pub(super) fn debug_intent(&self, intent: &DetectedIntent, elapsed: Duration) {
+        self.update_turn_debug(|debug| {
+            debug.intent = intent.intent.clone();
+            debug.confidence = intent.confidence;
+            debug.alternatives = intent.alternatives.clone();
+            debug.latency.intent_ms = millis(elapsed);
+        });
+    }
+
+    /// Slot picture before the dialogue state update, if collecting
+    pub(super) fn debug_slots_before(&self) -> Option<SlotProgress> {
+        self.turn_debug_enabled().then(|| self.slot_progress())
+    }
+
+    /// Record how the dialogue state update changed the slots
+    pub(super) fn debug_slot_changes(&self, before: Option<SlotProgress>) {
+        let Some(before) = before else {
+            return;
+        };
+        let changes = slot_changes(&before, &self.slot_progress());
+        self.update_turn_debug(|debug| debug.slot_changes = changes);
+    }
+
+    /// Record the retrieval results used in a prompt
+    ///
+    /// A turn can search more than once; each document is kept once.
+    pub(super) fn debug_retrieval(&self, results: &[SearchResult], elapsed: Duration) {
+        self.update_turn_debug(|debug| {
+            for result in results {
+                if !debug.retrieval.iter().any(|r| r.id == result.id) {
+                    debug.retrieval.push(result.clone());
+                }
+            }
+            debug.latency.retrieval_ms += millis(elapsed);
+        });
+    }
+
+    /// Record the prompt sent to the LLM
+    pub(super) fn debug_prompt(&self, messages: &[Message]) {
+        self.update_turn_debug(|debug| debug.prompt = messages.to_vec());
+    }
+
+    /// Record how long response generation took
+    pub(super) fn debug_response_time(&self, elapsed: Duration) {
+        self.update_turn_debug(|debug| debug.latency.response_ms = millis(elapsed));
+    }
+
+    /// Execute a tool, recording the call in the turn's debug output
+    pub(super) async fn execute_tool(
+        &self,
+        name: &str,
+        mut arguments: serde_json::Value,
+    ) -> Result<ToolOutput, ToolError> {
+        self.bind_caller_phone(name, &mut arguments);
+        if !self.turn_debug_enabled() {
+            return self.tools.execute(name, arguments).await;
+        }
+        let started = Instant::now();
+        let result = self.tools.execute(name, arguments.clone()).await;
+        let duration_ms = millis(started.elapsed());
+        self.mask_tool_arguments(name, &mut arguments);
+        self.update_turn_debug(|debug| {
+            debug.latency.tools_ms += duration_ms;
+            debug.tool_calls.push(ToolCallDebug {
+                name: name.to_string(),
+                arguments,
+                success: result.is_ok(),
+                duration_ms,
+            });
+        });
+        result
+    }
+
+    /// Finish the turn's debug output and emit it
+    pub(super) fn finish_turn_debug(&self, response: &str, started: Instant) {
+        let debug = {
+            let mut current = self.turn_debug.write();
+            let Some(debug) = current.as_mut() else {
+                return;
+            };
+            debug.response = response.to_string();
+            debug.latency.total_ms = millis(started.elapsed());
+            debug.clone()
+        };
+        tracing::debug!(
+            intent = %debug.intent,
+            retrieved = debug.retrieval.len(),
+            tool_calls = debug.tool_calls.len(),
+            latency = ?debug.latency,
+            "Turn debug collected"
+        );
+        let _ = self.event_tx.send(AgentEvent::TurnDebug(Box::new(debug)));
+    }
+
+    /// Mask arguments of `tool` that come from PII slots
+    fn mask_tool_arguments(&self, tool: &str, arguments: &mut serde_json::Value) {
+        let Some(args) = arguments.as_object_mut() else {
+            return;
+        };
+        for (parameter, value) in args.iter_mut() {
+            let slot = self.slot_for_argument(tool, parameter);
+            let text = match value {
+                serde_json::Value::String(text) => text.clone(),
+                serde_json::Value::Number(number) => number.to_string(),
+                _ => continue,
+            };
+            let masked = self.mask_slot_value(&slot, &text);
+            if masked != text {
+                *value = serde_json::Value::String(masked);
+            }
+        }
+    }
+
+    fn update_turn_debug(&self, update: impl FnOnce(&mut TurnDebug)) {
+        if let Some(debug) = self.turn_debug.write().as_mut() {
+            update(debug);
+        }
+    }
+}
+
+/// Slots whose value differs between two slot pictures, sorted by name
+fn slot_changes(before: &SlotProgress, after: &SlotProgress) -> Vec<SlotChange> {
+    let value = |progress: &SlotProgress, name: &str| {
+        progress
+            .filled
+            .iter()
+            .find(|slot| slot.name == name)
+            .map(|slot| slot.value.clone())
+    };
+    let mut names: Vec<&str> = before
+        .filled
+        .iter()
+        .chain(&after.filled)
+        .map(|slot| slot.name.as_str())
+        .collect();
+    names.sort_unstable();
+    names.dedup();
+    names
+        .into_iter()
+        .filter_map(|name| {
+            let (before, after) = (value(before, name), value(after, name));
+            (before != after).then(|| SlotChange {
+                name: name.to_string(),
+                before,
+                after,
+            })
+        })
+        .collect()
+}
+
+fn millis(duration: Duration) -> u64 {
+    u64::try_from(duration.as_millis()).unwrap_or(u64::MAX)
+}
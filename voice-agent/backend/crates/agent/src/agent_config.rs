@@ -2,19 +2,35 @@
 //!
 //! Configuration structs for the DomainAgent.
 
+use std::collections::HashMap;
+
+use voice_agent_config::domain::SamplingParams;
 use voice_agent_config::PersonaConfig;
-use voice_agent_llm::{LlmProviderConfig, SpeculativeConfig, SpeculativeMode};
+use voice_agent_core::{LanguageFallbackChain, RedactionStrategy};
+use voice_agent_llm::{
+    ConcurrencyLimitConfig, ContextSection, LlmProviderConfig, PricingTable, SpeculativeConfig,
+    SpeculativeMode,
+};
 use voice_agent_rag::AgenticRagConfig;
+use voice_agent_text_processing::InjectionConfig;
 
+use crate::agent::{SlotProgress, TurnDebug};
 use crate::conversation::ConversationConfig;
 use crate::dst::DstConfig;
+use crate::lead_scoring::ActionRecommendation;
+use crate::memory::AgenticMemoryConfig;
 use crate::stage::RagTimingStrategy;
 
+/// Default cap on tool-call rounds in a single turn
+pub const DEFAULT_MAX_TOOL_ROUNDS: usize = 3;
+
 /// Agent configuration
 #[derive(Debug, Clone)]
 pub struct AgentConfig {
     /// Default language
     pub language: String,
+    /// Languages to fall back to when translation doesn't support `language`
+    pub language_fallbacks: LanguageFallbackChain,
     /// Conversation config
     pub conversation: ConversationConfig,
     /// Persona configuration (P0 FIX: now uses consolidated PersonaConfig)
@@ -23,6 +39,8 @@ pub struct AgentConfig {
     pub rag_enabled: bool,
     /// Enable tools
     pub tools_enabled: bool,
+    /// Maximum LLM tool-call rounds per turn before the LLM must answer without tools
+    pub max_tool_rounds: usize,
     /// P1 FIX: Configurable tool defaults (no more hardcoded values)
     pub tool_defaults: ToolDefaults,
     /// P2 FIX: Context window size in tokens (for LLM prompt truncation)
@@ -33,12 +51,42 @@ pub struct AgentConfig {
     pub llm_provider: LlmProviderConfig,
     /// P1-2 FIX: Speculative decoding configuration (SLM + LLM)
     pub speculative: SpeculativeDecodingConfig,
+    /// Per-model token pricing for session cost accounting
+    pub llm_pricing: PricingTable,
     /// Phase 5: Dialogue State Tracking configuration
     pub dst_config: DstConfig,
     /// Phase 11: Agentic RAG configuration for multi-step retrieval
     pub agentic_rag: AgenticRagConfig,
     /// Small model optimizations (auto-detected or manual)
     pub small_model: SmallModelConfig,
+    /// What to do when retrieval finds no relevant knowledge for a turn
+    pub knowledge_guard: KnowledgeGuardConfig,
+    /// Polite redirect for questions outside the domain
+    pub out_of_scope: OutOfScopeConfig,
+    /// Per-turn routing between retrieval and tools
+    pub routing: RoutingConfig,
+    /// End-of-call summary pushed to the CRM
+    pub session_summary: SessionSummaryConfig,
+    /// Dedupe and rate limiting of human escalations
+    pub escalation: EscalationConfig,
+    /// Per-stage LLM sampling overrides
+    pub sampling: SamplingConfig,
+    /// Prompt injection screening of caller input
+    pub injection: InjectionConfig,
+    /// How PII slot values are masked in `SlotsUpdated` events
+    pub slot_redaction: RedactionStrategy,
+    /// "Summary so far" recaps on request or every few turns
+    pub recap: RecapConfig,
+    /// When caller input skips translation to English
+    pub translation_gate: TranslationGateConfig,
+    /// Switching language when the caller is heard speaking another
+    pub language_switch: LanguageSwitchConfig,
+    /// Retrying LLM replies that ignore the language or a required tool call
+    pub instruction_retry: InstructionRetryConfig,
+    /// Trimming of prompts that overflow `context_window_tokens`
+    pub context_overflow: ContextOverflowConfig,
+    /// Collect a `TurnDebug` for every turn (development only)
+    pub turn_debug: bool,
 }
 
 impl Default for AgentConfig {
@@ -71,10 +119,12 @@ impl Default for AgentConfig {
 
         Self {
             language: "en".to_string(),
+            language_fallbacks: LanguageFallbackChain::default(),
             conversation: ConversationConfig::default(),
             persona: PersonaConfig::default(),
             rag_enabled: true,
             tools_enabled: true,
+            max_tool_rounds: DEFAULT_MAX_TOOL_ROUNDS,
             tool_defaults: ToolDefaults::default(),
             // Context window adjusted for small models (2500 vs 4096)
             // Research: Qwen2.5 Technical Report (arXiv:2412.15115)
@@ -86,12 +136,28 @@ impl Default for AgentConfig {
             llm_provider: LlmProviderConfig::ollama(default_model),
             // P1-2 FIX: Speculative decoding disabled by default
             speculative: SpeculativeDecodingConfig::default(),
+            // Unpriced models (local Ollama) cost nothing
+            llm_pricing: PricingTable::default(),
             // Phase 5: DST configuration
             dst_config: DstConfig::default(),
             // Phase 11: Agentic RAG - single-shot for small models, iterative for large
             agentic_rag,
             // Small model config (auto-detected)
             small_model,
+            knowledge_guard: KnowledgeGuardConfig::default(),
+            out_of_scope: OutOfScopeConfig::default(),
+            routing: RoutingConfig::default(),
+            session_summary: SessionSummaryConfig::default(),
+            escalation: EscalationConfig::default(),
+            sampling: SamplingConfig::default(),
+            injection: InjectionConfig::default(),
+            slot_redaction: RedactionStrategy::default(),
+            recap: RecapConfig::default(),
+            translation_gate: TranslationGateConfig::default(),
+            language_switch: LanguageSwitchConfig::default(),
+            instruction_retry: InstructionRetryConfig::default(),
+            context_overflow: ContextOverflowConfig::default(),
+            turn_debug: false,
         }
     }
 }
@@ -146,6 +212,369 @@ impl AgentConfig {
     }
 }
 
+/// Agent settings from the config file, over the defaults
+impl From<&voice_agent_config::AgentConfig> for AgentConfig {
+    fn from(settings: &voice_agent_config::AgentConfig) -> Self {
+        let defaults = Self::default();
+        Self {
+            language: settings.language.clone(),
+            language_fallbacks: settings.language_fallbacks.clone(),
+            out_of_scope: OutOfScopeConfig::from(&settings.out_of_scope),
+            routing: RoutingConfig::from(&settings.routing),
+            conversation: ConversationConfig {
+                agentic_memory: AgenticMemoryConfig::from(&settings.memory),
+                ..Default::default()
+            },
+            llm_provider: defaults
+                .llm_provider
+                .with_concurrency(ConcurrencyLimitConfig::from(&settings.llm.concurrency)),
+            llm_pricing: PricingTable::from(&settings.llm.pricing),
+            turn_debug: settings.turn_debug,
+            ..defaults
+        }
+    }
+}
+
+/// How the agent answers when retrieval finds nothing relevant
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KnowledgeGapMode {
+    /// Let the LLM answer, but tell it not to state specific figures or policy
+    Hedge,
+    /// Skip the LLM and reply with the configured fallback response
+    Fallback,
+}
+
+/// Guard against the LLM inventing policy details when retrieval is empty
+///
+/// Applies to turns where the stage retrieves knowledge and nothing clears
+/// the stage's score cutoff; stages without RAG are never guarded.
+#[derive(Debug, Clone)]
+pub struct KnowledgeGuardConfig {
+    /// Enable the guard
+    pub enabled: bool,
+    /// Hedge in the LLM prompt or reply with `fallback_response`
+    pub mode: KnowledgeGapMode,
+    /// Prompt instruction added in `Hedge` mode
+    pub instruction: String,
+    /// English reply used in `Fallback` mode (translated for the user)
+    pub fallback_response: String,
+}
+
+impl Default for KnowledgeGuardConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            mode: KnowledgeGapMode::Hedge,
+            instruction: "## No Verified Information\n\
+                No knowledge base entry matched this question. Do not state specific \
+                interest rates, fees, amounts, limits or policy terms. Say you want to \
+                confirm the exact details and offer to connect the customer with a \
+                specialist."
+                .to_string(),
+            fallback_response: "I don't want to give you incorrect details on that. \
+                Let me connect you to someone who can confirm."
+                .to_string(),
+        }
+    }
+}
+
+/// Redirect for questions the agent isn't there to answer
+///
+/// A turn is out of scope when its intent confidence is below the
+/// threshold, no tool is mapped to it, it mentions nothing from the domain
+/// and retrieval finds nothing. Such turns get the domain's `out_of_scope`
+/// response template instead of an LLM answer. Stages without RAG, small
+/// talk and fillers ("okay thanks") are never redirected.
+#[derive(Debug, Clone)]
+pub struct OutOfScopeConfig {
+    /// Enable the redirect
+    pub enabled: bool,
+    /// Intent confidence below which a turn may be out of scope
+    pub max_intent_confidence: f32,
+    /// Per-language overrides of `max_intent_confidence`, by language code
+    pub language_confidence: HashMap<String, f32>,
+}
+
+impl OutOfScopeConfig {
+    /// Confidence threshold for a language
+    pub fn max_confidence_for(&self, language: &str) -> f32 {
+        self.language_confidence
+            .get(language)
+            .copied()
+            .unwrap_or(self.max_intent_confidence)
+    }
+}
+
+impl Default for OutOfScopeConfig {
+    fn default() -> Self {
+        Self::from(&voice_agent_config::OutOfScopeConfig::default())
+    }
+}
+
+impl From<&voice_agent_config::OutOfScopeConfig> for OutOfScopeConfig {
+    fn from(settings: &voice_agent_config::OutOfScopeConfig) -> Self {
+        Self {
+            enabled: settings.enabled,
+            max_intent_confidence: settings.max_intent_confidence,
+            language_confidence: settings.language_confidence.clone(),
+        }
+    }
+}
+
+/// Per-turn routing between retrieval and tools
+///
+/// When disabled every turn retrieves and may call tools, as gated by
+/// `rag_enabled` and `tools_enabled`.
+#[derive(Debug, Clone)]
+pub struct RoutingConfig {
+    /// Enable routing
+    pub enabled: bool,
+    /// Intents answered without retrieval or tools
+    pub smalltalk_intents: Vec<String>,
+}
+
+impl Default for RoutingConfig {
+    fn default() -> Self {
+        Self::from(&voice_agent_config::RoutingConfig::default())
+    }
+}
+
+impl From<&voice_agent_config::RoutingConfig> for RoutingConfig {
+    fn from(settings: &voice_agent_config::RoutingConfig) -> Self {
+        Self {
+            enabled: settings.enabled,
+            smalltalk_intents: settings.smalltalk_intents.clone(),
+        }
+    }
+}
+
+/// End-of-call summary for the CRM
+#[derive(Debug, Clone)]
+pub struct SessionSummaryConfig {
+    /// Push a summary to the CRM when the call ends
+    pub enabled: bool,
+    /// Have the LLM write the summary (rule-based otherwise)
+    pub use_llm: bool,
+    /// Word budget given to the LLM
+    pub max_words: usize,
+}
+
+impl Default for SessionSummaryConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            use_llm: true,
+            max_words: 120,
+        }
+    }
+}
+
+/// "Summary so far" recaps for long calls
+#[derive(Debug, Clone)]
+pub struct RecapConfig {
+    /// Answer a customer's request for a recap
+    pub enabled: bool,
+    /// Open every Nth reply with a recap (0 = only on request)
+    pub every_n_turns: usize,
+    /// Intents that ask for a recap
+    pub intents: Vec<String>,
+    /// Lowercase phrases that ask for a recap
+    pub phrases: Vec<String>,
+    /// Have the LLM phrase the recap (rule-based otherwise)
+    pub use_llm: bool,
+    /// Word budget given to the LLM
+    pub max_words: usize,
+}
+
+impl Default for RecapConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            every_n_turns: 0,
+            intents: vec!["recap_request".to_string()],
+            phrases: [
+                "summarize",
+                "summarise",
+                "recap",
+                "what have we covered",
+                "what did we discuss",
+                "where were we",
+            ]
+            .iter()
+            .map(|p| p.to_string())
+            .collect(),
+            use_llm: true,
+            max_words: 60,
+        }
+    }
+}
+
+/// Script-confidence gate on translating caller input to English
+///
+/// Callers often answer in Hinglish. Input that is already mostly English,
+/// or whose script is too mixed to call, goes to the LLM as is; romanized
+/// Hindi from a Hindi caller is still translated.
+#[derive(Debug, Clone)]
+pub struct TranslationGateConfig {
+    /// Gate translation on script detection (always translate otherwise)
+    pub enabled: bool,
+    /// Latin script share at or above which input counts as English
+    pub english_threshold: f32,
+    /// Script confidence below which input is passed through
+    pub min_confidence: f32,
+    /// Share of common Hindi words at or above which a Hindi caller's
+    /// Latin-script input counts as romanized Hindi and is translated
+    pub hinglish_threshold: f32,
+}
+
+impl Default for TranslationGateConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            english_threshold: 0.6,
+            min_confidence: 0.6,
+            hinglish_threshold: 0.5,
+        }
+    }
+}
+
+/// Switching the user language on spoken-language detection
+///
+/// The STT stage identifies the language the caller actually speaks, which
+/// can differ from the one picked up front. A detection that disagrees with
+/// the current language at or above `min_confidence` switches to it, if
+/// translation supports it. Off by default: a deployment turns it on once
+/// its STT models are known to decode each language in its own script.
+#[derive(Debug, Clone)]
+pub struct LanguageSwitchConfig {
+    /// Act on language detections
+    pub enabled: bool,
+    /// Detection confidence needed to switch
+    pub min_confidence: f32,
+}
+
+impl Default for LanguageSwitchConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            min_confidence: 0.8,
+        }
+    }
+}
+
+/// Retrying LLM replies that ignore an instruction
+///
+/// Small models sometimes reply in the wrong language, or in plain text when
+/// the turn needed a tool call. Such replies are retried with a reminder of
+/// the constraint added to the prompt before the bad reply is accepted.
+#[derive(Debug, Clone)]
+pub struct InstructionRetryConfig {
+    /// Check LLM replies and retry violations
+    pub enabled: bool,
+    /// Retries per turn
+    pub max_retries: usize,
+    /// Script confidence at which a reply counts as another language
+    pub min_confidence: f32,
+    /// Reminder added after a wrong-language reply; `{language}` is the
+    /// expected language
+    pub language_reminder: String,
+    /// Reminder added after a reply that skipped a required tool call
+    pub tool_call_reminder: String,
+}
+
+impl Default for InstructionRetryConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            max_retries: 1,
+            min_confidence: 0.8,
+            language_reminder: "Your last reply was not in {language}. \
+                Reply again, only in {language}."
+                .to_string(),
+            tool_call_reminder: "Your last reply did not call a tool, but this request \
+                needs one. Reply again with the tool call for the available tool."
+                .to_string(),
+        }
+    }
+}
+
+/// Fitting the assembled prompt into the model's context window
+#[derive(Debug, Clone)]
+pub struct ContextOverflowConfig {
+    /// Trim prompts that overflow the window
+    pub enabled: bool,
+    /// Tokens of the window kept free for the reply
+    pub reserve_tokens: usize,
+    /// Sections trimmed on overflow, lowest priority first
+    pub trim_order: Vec<ContextSection>,
+}
+
+impl Default for ContextOverflowConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            reserve_tokens: 256,
+            trim_order: vec![
+                ContextSection::History,
+                ContextSection::Memory,
+                ContextSection::Rag,
+                ContextSection::Domain,
+            ],
+        }
+    }
+}
+
+/// Dedupe and rate limiting of human escalations
+///
+/// Suppressed escalations are still counted and audited.
+#[derive(Debug, Clone)]
+pub struct EscalationConfig {
+    /// Enable dedupe and rate limiting
+    pub enabled: bool,
+    /// Escalate once per session, unless a more severe reason comes up
+    pub dedupe_per_session: bool,
+    /// Escalations allowed per window across the sessions sharing an
+    /// `EscalationLimiter`, or the session alone without one (0 = unlimited)
+    pub global_limit: u32,
+    /// Window for `global_limit`
+    pub global_window_secs: u64,
+}
+
+impl Default for EscalationConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            dedupe_per_session: true,
+            global_limit: 20,
+            global_window_secs: 60,
+        }
+    }
+}
+
+/// LLM sampling per conversation stage
+///
+/// Parameters set here override the stage's domain config field by field.
+/// Replies in compliance stages never sample above `compliance_temperature`.
+#[derive(Debug, Clone)]
+pub struct SamplingConfig {
+    /// Sampling overrides keyed by stage ID (e.g. "discovery")
+    pub stages: HashMap<String, SamplingParams>,
+    /// Stages whose replies carry regulated terms or disclosures
+    pub compliance_stages: Vec<String>,
+    /// Temperature ceiling in compliance stages
+    pub compliance_temperature: f32,
+}
+
+impl Default for SamplingConfig {
+    fn default() -> Self {
+        Self {
+            stages: HashMap::new(),
+            compliance_stages: vec!["closing".to_string(), "farewell".to_string()],
+            compliance_temperature: 0.2,
+        }
+    }
+}
+
 /// P1 FIX: Configurable default values for tool calls
 #[derive(Debug, Clone)]
 pub struct ToolDefaults {
@@ -347,6 +776,8 @@ pub enum AgentEvent {
     ToolCall { name: String },
     /// Tool result
     ToolResult { name: String, success: bool },
+    /// One round of LLM-requested tool calls finished (1-based)
+    ToolRound { round: usize, tools: Vec<String> },
     /// Conversation event
     Conversation(ConversationEvent),
     /// Error
@@ -363,6 +794,29 @@ pub enum AgentEvent {
         trigger: String,
         recommendation: String,
     },
+    /// A slot was asked for too many times; the retry fallback was taken
+    SlotRetryLimitReached {
+        slot: String,
+        attempts: u32,
+        fallback: String,
+    },
+    /// Inferred customer segment changed mid-call
+    SegmentChanged {
+        segment: String,
+        persona: String,
+    },
+    /// Recommended next best action for this turn
+    NextBestAction(ActionRecommendation),
+    /// Slots collected so far changed (PII values masked)
+    SlotsUpdated(SlotProgress),
+    /// A supervisor handed the call back; the acknowledgement is spoken
+    /// to the customer
+    HandedBack {
+        acknowledgement: String,
+        notes: Option<String>,
+    },
+    /// What went into the turn's response (only with `turn_debug` on)
+    TurnDebug(Box<TurnDebug>),
 }
 
 // Re-export for backwards compatibility
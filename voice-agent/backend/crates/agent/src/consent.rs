@@ -0,0 +1,167 @@
+//! Consent Response Detection
+//!
+//! Classifies the customer's answer to the recording consent question
+//! asked at call start. Refusals win over agreement so that "yes, but
+//! don't record" is not stored as consent; unclear answers return None
+//! and the question is asked again.
+
+/// Phrases that contain a negation but mean agreement
+const AGREEMENT_IDIOMS: &[&str] = &[
+    "no problem",
+    "no issue",
+    "no issues",
+    "not a problem",
+    "why not",
+    "don't mind",
+    "do not mind",
+    "koi baat nahi",
+    "koi baat nahin",
+    "koi dikkat nahi",
+    "koi dikkat nahin",
+    "koi problem nahi",
+    "कोई बात नहीं",
+    "कोई दिक्कत नहीं",
+];
+
+/// Phrases that refuse consent (English, Hinglish and Hindi)
+///
+/// A bare "not" or "mat" is left out: it negates too many agreeing answers.
+const REFUSAL_PHRASES: &[&str] = &[
+    "no",
+    "nope",
+    "do not",
+    "don't",
+    "dont",
+    "not allowed",
+    "not okay",
+    "not ok",
+    "never",
+    "refuse",
+    "decline",
+    "nahi",
+    "nahin",
+    "nai",
+    "mat karo",
+    "mat kijiye",
+    "नहीं",
+    "नही",
+    "मत करो",
+    "मत कीजिए",
+];
+
+/// Phrases that give consent (English, Hinglish and Hindi)
+///
+/// A bare "ji" or "ha" is left out: "ji, ek minute" only asks to wait.
+const AGREEMENT_PHRASES: &[&str] = &[
+    "yes",
+    "yeah",
+    "yep",
+    "sure",
+    "okay",
+    "ok",
+    "fine",
+    "agree",
+    "allowed",
+    "go ahead",
+    "haan",
+    "han",
+    "theek hai",
+    "thik hai",
+    "bilkul",
+    "chalega",
+    "हाँ",
+    "हां",
+    "ठीक है",
+    "बिल्कुल",
+];
+
+/// Classify a consent answer: Some(true) agrees, Some(false) refuses,
+/// None if the answer is unclear
+///
+/// Phrases match whole words only, so "not" in "why not" or "ha" in
+/// "haan" never match a shorter phrase by accident.
+pub fn detect_consent_response(text: &str) -> Option<bool> {
+    let lowered = text.to_lowercase().replace('’', "'");
+    let words: Vec<&str> = lowered
+        .split(|c: char| !(c.is_alphanumeric() || c == '\'' || is_devanagari_mark(c)))
+        .filter(|w| !w.is_empty())
+        .collect();
+    // Padded so every phrase can be matched as " phrase "
+    let mut padded = format!(" {} ", words.join(" "));
+
+    let mut agreed = false;
+    for idiom in AGREEMENT_IDIOMS {
+        let idiom = format!(" {} ", idiom);
+        if padded.contains(&idiom) {
+            agreed = true;
+            padded = padded.replace(&idiom, " ");
+        }
+    }
+
+    if contains_any(&padded, REFUSAL_PHRASES) {
+        return Some(false);
+    }
+    if agreed || contains_any(&padded, AGREEMENT_PHRASES) {
+        return Some(true);
+    }
+    None
+}
+
+/// Whether the padded word sequence contains any of the phrases
+fn contains_any(padded: &str, phrases: &[&str]) -> bool {
+    phrases
+        .iter()
+        .any(|phrase| padded.contains(&format!(" {} ", phrase)))
+}
+
+/// Devanagari vowel signs and nasalisation marks are not alphanumeric in
+/// Rust's classification but are part of words like "नहीं"
+fn is_devanagari_mark(c: char) -> bool {
+    ('\u{0900}'..='\u{097F}').contains(&c)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_agreement() {
+        for text in [
+            "Yes",
+            "yeah sure",
+            "haan ji theek hai",
+            "हाँ ठीक है",
+            "No problem, go ahead",
+            "why not",
+            "Don’t mind at all",
+        ] {
+            assert_eq!(detect_consent_response(text), Some(true), "{}", text);
+        }
+    }
+
+    #[test]
+    fn test_refusal() {
+        for text in [
+            "No",
+            "please don't record",
+            "nahi",
+            "yes but do not record",
+            "मत करो",
+        ] {
+            assert_eq!(detect_consent_response(text), Some(false), "{}", text);
+        }
+    }
+
+    #[test]
+    fn test_unclear() {
+        for text in [
+            "what is the interest rate?",
+            "hmm",
+            "",
+            "ji, ek minute",
+            "haa... what was that?",
+        ] {
+            assert_eq!(detect_consent_response(text), None, "{}", text);
+        }
+    }
+}
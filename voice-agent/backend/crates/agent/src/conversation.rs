@@ -18,13 +18,17 @@ use std::sync::Arc;
 use std::time::{Duration, Instant};
 use tokio::sync::broadcast;
 
-use crate::intent::{DetectedIntent, IntentDetector};
+use crate::consent::detect_consent_response;
+use crate::disambiguation::{IntentDisambiguator, PendingDisambiguation};
+use crate::intent::{
+    ConfidenceCalibration, DetectedIntent, Intent, IntentDetector, UNKNOWN_INTENT,
+};
 use crate::memory::{AgenticMemory, AgenticMemoryConfig, MemoryConfig};
 use crate::memory_legacy::{ConversationMemory, MemoryEntry};
 use crate::stage::{ConversationStage, StageManager, TransitionReason};
 use crate::AgentError;
-use voice_agent_config::domain::StagesConfig;
-use voice_agent_core::{Turn, TurnRole};
+use voice_agent_config::domain::{ComplianceConfig, DisambiguationConfig, StagesConfig};
+use voice_agent_core::{ComplianceViolation, Severity, Turn, TurnRole, ViolationCategory};
 
 // =============================================================================
 // Phase 2: ConversationContext Trait (Domain-Agnostic Abstraction)
@@ -73,6 +77,13 @@ pub trait ConversationContext: Send + Sync {
     /// Transition to a new conversation stage
     fn transition_stage(&self, to: ConversationStage) -> Result<(), AgentError>;
 
+    /// Advance to a new stage because the current one ran past its limit
+    ///
+    /// Defaults to `transition_stage`, which records a natural-flow move.
+    fn transition_stage_on_timeout(&self, to: ConversationStage) -> Result<(), AgentError> {
+        self.transition_stage(to)
+    }
+
     /// Get context string for the conversation
     fn get_context(&self) -> String;
 
@@ -143,6 +154,51 @@ pub trait ConversationContext: Send + Sync {
     /// Get AI disclosure message for configured language
     fn get_ai_disclosure_message(&self) -> String;
 
+    /// Give the AI disclosure and ask for recording consent; returns the prompt
+    fn begin_consent(&self) -> String;
+
+    /// Ask for recording consent without the disclosure; returns the question
+    ///
+    /// Defaults to `begin_consent`, which gives the disclosure again.
+    fn ask_consent(&self) -> String {
+        self.begin_consent()
+    }
+
+    /// Whether the consent question is awaiting an answer
+    fn awaiting_consent(&self) -> bool;
+
+    /// Recording consent question for the configured language
+    fn consent_prompt(&self) -> &str;
+
+    /// Record the customer's answer to the consent question
+    ///
+    /// Returns None (and keeps waiting) if the answer is unclear.
+    fn resolve_consent(&self, user_input: &str) -> Option<ConsentRecord>;
+
+    /// Whether the call may be recorded and its transcript retained
+    fn recording_allowed(&self) -> bool;
+
+    /// Record a compliance violation raised during the call
+    fn record_compliance_violation(&self, violation: ComplianceViolation);
+
+    /// Resolve a previously recorded violation by rule ID
+    fn resolve_compliance_violation(&self, rule_id: &str) -> bool;
+
+    /// Violations that would leave the call non-compliant if it ended now
+    fn outstanding_violations(&self) -> Vec<ComplianceViolation>;
+
+    /// Whether the last user turn's intent fell below the confidence floor
+    fn is_low_confidence_turn(&self) -> bool;
+
+    /// Intent of `content` without recording a turn, e.g. for a partial transcript
+    fn detect_intent(&self, content: &str) -> DetectedIntent;
+
+    /// Clarifying question to ask instead of answering, if any
+    fn pending_clarification(&self) -> Option<String>;
+
+    /// Intent resolved for the last user turn, if any
+    fn last_intent(&self) -> Option<String>;
+
     /// Subscribe to conversation events
     fn subscribe(&self) -> broadcast::Receiver<ConversationEvent>;
 }
@@ -156,6 +212,8 @@ pub struct ConversationConfig {
     pub session_timeout_seconds: u32,
     /// Memory config
     pub memory: MemoryConfig,
+    /// Agentic memory config (context selection, compression)
+    pub agentic_memory: AgenticMemoryConfig,
     /// Enable intent detection
     pub intent_detection: bool,
     /// Default language
@@ -168,6 +226,7 @@ impl Default for ConversationConfig {
             max_duration_seconds: 1800, // 30 minutes max conversation
             session_timeout_seconds: 300, // 5 minutes inactivity timeout
             memory: MemoryConfig::default(),
+            agentic_memory: AgenticMemoryConfig::default(),
             intent_detection: true,
             language: "en".to_string(),
         }
@@ -192,6 +251,19 @@ pub enum ConversationEvent {
     FactLearned { key: String, value: String },
     /// Tool called
     ToolCalled { name: String, success: bool },
+    /// Near-tied intents; asking the user to clarify
+    ClarificationRequested {
+        question: String,
+        candidates: Vec<String>,
+    },
+    /// Best intent was below the confidence floor and was not acted on
+    LowConfidenceIntent { intent: String, confidence: f32 },
+    /// Customer answered the recording consent question
+    ConsentRecorded { given: bool, method: ConsentMethod },
+    /// Conversation ended with compliance violations still outstanding
+    ComplianceIncomplete {
+        violations: Vec<ComplianceViolation>,
+    },
     /// Conversation ended
     Ended { reason: EndReason },
     /// Error occurred
@@ -204,6 +276,10 @@ pub enum EndReason {
     UserEnded,
     AgentEnded,
     Timeout,
+    /// User stayed silent through the re-engagement prompt
+    IdleTimeout,
+    /// Customer refused consent during the opening script
+    ConsentRefused,
     MaxDuration,
     Error(String),
 }
@@ -358,6 +434,13 @@ impl ConsentRecord {
     pub fn has_full_consent(&self) -> bool {
         self.recording_consent && self.pii_processing_consent
     }
+
+    /// Whether recording (and retaining the transcript) is allowed
+    ///
+    /// Only an explicit answer counts; no answer yet means not allowed.
+    pub fn recording_allowed(&self) -> bool {
+        self.recording_consent && self.recording_consent_timestamp.is_some()
+    }
 }
 
 /// P0 FIX: Compliance status for the conversation
@@ -371,6 +454,9 @@ pub struct ComplianceStatus {
     pub compliant: bool,
     /// List of pending compliance requirements
     pub pending_requirements: Vec<String>,
+    /// Violations raised during the call that have not been resolved
+    #[serde(default)]
+    pub violations: Vec<ComplianceViolation>,
 }
 
 impl Default for ComplianceStatus {
@@ -383,6 +469,7 @@ impl Default for ComplianceStatus {
                 "ai_disclosure".to_string(),
                 "recording_consent".to_string(),
             ],
+            violations: Vec::new(),
         }
     }
 }
@@ -401,7 +488,42 @@ impl ComplianceStatus {
                 .push("recording_consent".to_string());
         }
 
-        self.compliant = self.pending_requirements.is_empty();
+        self.compliant = self.pending_requirements.is_empty() && self.violations.is_empty();
+    }
+
+    /// Record a violation; a repeat of the same rule replaces the earlier one
+    pub fn record_violation(&mut self, violation: ComplianceViolation) {
+        self.violations.retain(|v| v.rule_id != violation.rule_id);
+        self.violations.push(violation);
+        self.update();
+    }
+
+    /// Mark a violation as resolved (e.g. the missing disclosure was given)
+    ///
+    /// Returns false if no violation with this rule ID is outstanding.
+    pub fn resolve_violation(&mut self, rule_id: &str) -> bool {
+        let before = self.violations.len();
+        self.violations.retain(|v| v.rule_id != rule_id);
+        self.update();
+        self.violations.len() != before
+    }
+
+    /// Violations that would leave the call non-compliant if it ended now
+    ///
+    /// A missing AI disclosure counts as a MissingDisclosure violation. A
+    /// refused recording consent does not: declining is the customer's right.
+    pub fn outstanding_violations(&self) -> Vec<ComplianceViolation> {
+        let mut outstanding = Vec::new();
+        if !self.ai_disclosure.given {
+            outstanding.push(ComplianceViolation::new(
+                "ai_disclosure",
+                "Customer was not told they are speaking with an AI assistant",
+                ViolationCategory::MissingDisclosure,
+                Severity::Critical,
+            ));
+        }
+        outstanding.extend(self.violations.iter().cloned());
+        outstanding
     }
 
     /// Check if ready for PII processing
@@ -442,6 +564,20 @@ pub struct Conversation {
     /// P16 FIX: AI disclosure message loaded from config (RBI compliance)
     /// Stored at construction to avoid needing view reference later
     ai_disclosure_message: String,
+    /// Config-driven clarifying questions for near-tied intents
+    disambiguator: Option<IntentDisambiguator>,
+    /// Clarifying question awaiting the user's answer
+    pending_disambiguation: Mutex<Option<PendingDisambiguation>>,
+    /// Calibrated confidence below which intents are treated as unknown
+    low_confidence_floor: Option<f32>,
+    /// Whether the last user turn fell below the confidence floor
+    low_confidence_turn: Mutex<bool>,
+    /// Intent resolved for the last user turn
+    last_intent: Mutex<Option<String>>,
+    /// Recording consent question asked after the AI disclosure
+    consent_prompt: String,
+    /// Whether the consent question is awaiting an answer
+    awaiting_consent: Mutex<bool>,
 }
 
 impl Conversation {
@@ -454,7 +590,7 @@ impl Conversation {
         let session_id_str = session_id.into();
 
         // Phase 10: Create agentic memory with session ID for archival retrieval
-        let agentic_config = AgenticMemoryConfig::default();
+        let agentic_config = config.agentic_memory.clone();
 
         // P16 FIX: Use static fallback for AI disclosure (config-driven version uses from_view)
         let ai_disclosure = AiDisclosure::get_disclosure_message(&config.language).to_string();
@@ -474,6 +610,15 @@ impl Conversation {
             compliance: Mutex::new(ComplianceStatus::default()),
             stages_config: None, // No config-driven transitions in basic constructor
             ai_disclosure_message: ai_disclosure,
+            disambiguator: None,
+            pending_disambiguation: Mutex::new(None),
+            low_confidence_floor: None,
+            low_confidence_turn: Mutex::new(false),
+            last_intent: Mutex::new(None),
+            consent_prompt: ComplianceConfig::default()
+                .get_consent_prompt(&config.language)
+                .to_string(),
+            awaiting_consent: Mutex::new(false),
         }
     }
 
@@ -490,11 +635,28 @@ impl Conversation {
         let session_id_str = session_id.into();
 
         // Create agentic memory with config-driven compressor
-        let agentic_config = AgenticMemoryConfig::default();
+        let agentic_config = config.agentic_memory.clone();
         let agentic_memory = AgenticMemory::from_view(agentic_config, &session_id_str, view);
 
-        // Create intent detector with config-driven patterns
-        let mut intent_detector = IntentDetector::new();
+        // Create intent detector with config-driven intents and patterns;
+        // the core conversational intents are kept alongside the domain's own
+        let domain_intents: Vec<Intent> = view
+            .intents_config()
+            .intents
+            .iter()
+            .map(|definition| Intent {
+                name: definition.name.clone(),
+                description: definition.description.clone(),
+                required_slots: definition.required_slots.clone(),
+                optional_slots: definition.optional_slots.clone(),
+                examples: definition.examples.clone(),
+            })
+            .collect();
+        let mut intent_detector = if domain_intents.is_empty() {
+            IntentDetector::new()
+        } else {
+            IntentDetector::with_domain_intents(domain_intents)
+        };
 
         // Wire competitor patterns from config
         // Note: We need to convert the owned Strings to &str references
@@ -524,12 +686,24 @@ impl Conversation {
         let location_pattern = view.location_intent_pattern();
         intent_detector.set_location_pattern(&location_pattern);
 
+        // Calibrated confidences make the min_confidence floor meaningful
+        let intents_config = view.intents_config();
+        let low_confidence_floor = intents_config.calibration.map(|calibration| {
+            intent_detector.set_calibration(Some(ConfidenceCalibration {
+                slope: calibration.slope,
+                midpoint: calibration.midpoint,
+            }));
+            intents_config.min_confidence
+        });
+
         // P16 FIX: Store stages config for config-driven intent transitions
         let stages_config = Arc::new(view.stages_config().clone());
 
         // P16 FIX: Load AI disclosure message from compliance config (RBI requirement)
         let ai_disclosure_message = view.ai_disclosure(&config.language).to_string();
 
+        let disambiguator = IntentDisambiguator::new(view.intents_config().disambiguation.clone());
+
         Self {
             session_id: session_id_str.clone(),
             config: config.clone(),
@@ -545,9 +719,34 @@ impl Conversation {
             compliance: Mutex::new(ComplianceStatus::default()),
             stages_config: Some(stages_config), // P16 FIX: Config-driven transitions
             ai_disclosure_message, // P16 FIX: Config-driven AI disclosure
+            disambiguator: disambiguator.is_enabled().then_some(disambiguator),
+            pending_disambiguation: Mutex::new(None),
+            low_confidence_floor,
+            low_confidence_turn: Mutex::new(false),
+            last_intent: Mutex::new(None),
+            consent_prompt: view.consent_prompt(&config.language).to_string(),
+            awaiting_consent: Mutex::new(false),
         }
     }
 
+    /// Calibrate intent confidence and treat intents below `floor` as unknown
+    pub fn with_intent_calibration(
+        mut self,
+        calibration: ConfidenceCalibration,
+        floor: f32,
+    ) -> Self {
+        self.intent_detector.set_calibration(Some(calibration));
+        self.low_confidence_floor = Some(floor);
+        self
+    }
+
+    /// Enable clarifying questions for near-tied intents
+    pub fn with_disambiguation(mut self, config: DisambiguationConfig) -> Self {
+        let disambiguator = IntentDisambiguator::new(config);
+        self.disambiguator = disambiguator.is_enabled().then_some(disambiguator);
+        self
+    }
+
     /// Subscribe to conversation events
     pub fn subscribe(&self) -> broadcast::Receiver<ConversationEvent> {
         self.event_tx.subscribe()
@@ -609,10 +808,15 @@ impl Conversation {
                 confidence: 0.0,
                 slots: std::collections::HashMap::new(),
                 alternatives: vec![],
+                ambiguous_number: None,
             }
         };
 
+        let detected = self.apply_confidence_floor(detected);
+        let detected = self.disambiguate(content, detected);
+
         entry.intents = vec![detected.intent.clone()];
+        *self.last_intent.lock() = Some(detected.intent.clone());
 
         // Extract and store entities
         for (key, slot) in &detected.slots {
@@ -647,6 +851,108 @@ impl Conversation {
         Ok(detected)
     }
 
+    /// Whether the last user turn's intent fell below the confidence floor
+    ///
+    /// The agent answers such turns generally (e.g. from RAG) rather than
+    /// acting on a likely-wrong intent.
+    pub fn is_low_confidence_turn(&self) -> bool {
+        *self.low_confidence_turn.lock()
+    }
+
+    /// Intent of `content` without recording a turn or touching the
+    /// dialogue state, e.g. for a partial transcript
+    pub fn detect_intent(&self, content: &str) -> DetectedIntent {
+        self.intent_detector.detect(content)
+    }
+
+    /// Intent resolved for the last user turn, if any
+    pub fn last_intent(&self) -> Option<String> {
+        self.last_intent.lock().clone()
+    }
+
+    /// Low-confidence path: replace an unreliable intent with `UNKNOWN_INTENT`
+    ///
+    /// Answers to a pending clarifying question are left to the disambiguator.
+    fn apply_confidence_floor(&self, mut detected: DetectedIntent) -> DetectedIntent {
+        let below_floor = self.low_confidence_floor.is_some_and(|floor| {
+            detected.intent != UNKNOWN_INTENT
+                && detected.confidence < floor
+                && self.pending_disambiguation.lock().is_none()
+        });
+        *self.low_confidence_turn.lock() = below_floor;
+
+        if below_floor {
+            tracing::debug!(
+                intent = %detected.intent,
+                confidence = detected.confidence,
+                "Intent below confidence floor, treating as unknown"
+            );
+            let _ = self.event_tx.send(ConversationEvent::LowConfidenceIntent {
+                intent: detected.intent.clone(),
+                confidence: detected.confidence,
+            });
+            let guessed = std::mem::replace(&mut detected.intent, UNKNOWN_INTENT.to_string());
+            detected
+                .alternatives
+                .insert(0, (guessed, detected.confidence));
+            detected.alternatives.truncate(3);
+        }
+
+        detected
+    }
+
+    /// Clarifying question to ask instead of answering, if the last user
+    /// turn was ambiguous
+    pub fn pending_clarification(&self) -> Option<String> {
+        self.pending_disambiguation
+            .lock()
+            .as_ref()
+            .map(|p| p.question.clone())
+    }
+
+    /// Resolve a pending clarification or start a new one
+    ///
+    /// An answer to a clarifying question is never itself clarified again, so
+    /// an unhelpful answer falls back to regular detection.
+    fn disambiguate(&self, content: &str, mut detected: DetectedIntent) -> DetectedIntent {
+        let Some(disambiguator) = &self.disambiguator else {
+            return detected;
+        };
+        let mut pending = self.pending_disambiguation.lock();
+
+        if let Some(previous) = pending.take() {
+            if let Some((intent, confidence)) = disambiguator.resolve(&previous, content, &detected)
+            {
+                tracing::debug!(
+                    intent = %intent,
+                    candidates = ?previous.candidates,
+                    "Resolved ambiguous intent from clarification"
+                );
+                let replaced = std::mem::replace(&mut detected.intent, intent);
+                detected
+                    .alternatives
+                    .retain(|(name, _)| *name != detected.intent);
+                detected
+                    .alternatives
+                    .insert(0, (replaced, detected.confidence));
+                detected.confidence = confidence;
+            }
+            return detected;
+        }
+
+        if let Some(next) = disambiguator.check(&detected) {
+            let _ = self
+                .event_tx
+                .send(ConversationEvent::ClarificationRequested {
+                    question: next.question.clone(),
+                    candidates: next.candidates.clone(),
+                });
+            *pending = Some(next);
+        }
+
+        detected
+    }
+
     /// Add assistant turn
     pub fn add_assistant_turn(&self, content: &str) -> Result<(), AgentError> {
         self.check_active()?;
@@ -691,6 +997,21 @@ impl Conversation {
         }
     }
 
+    /// Advance to a new stage because the current one ran past its limit
+    pub fn transition_stage_on_timeout(&self, to: ConversationStage) -> Result<(), AgentError> {
+        let from = self.stage();
+
+        match self.stage_manager.transition(to, TransitionReason::Timeout) {
+            Ok(_) => {
+                let _ = self
+                    .event_tx
+                    .send(ConversationEvent::StageChanged { from, to });
+                Ok(())
+            },
+            Err(e) => Err(AgentError::Stage(e)),
+        }
+    }
+
     /// Check and perform stage transitions based on intent
     ///
     /// P16 FIX: Config-driven intent→stage transitions loaded from stages.yaml.
@@ -789,8 +1110,22 @@ impl Conversation {
     }
 
     /// End the conversation
+    ///
+    /// Emits `ComplianceIncomplete` before `Ended` if any compliance
+    /// violation is still outstanding, so it can be audited.
     pub fn end(&self, reason: EndReason) {
         *self.state.lock() = ConversationState::Ended;
+        let violations = self.outstanding_violations();
+        if !violations.is_empty() {
+            tracing::warn!(
+                session_id = %self.session_id,
+                count = violations.len(),
+                "Conversation ended with outstanding compliance violations"
+            );
+            let _ = self
+                .event_tx
+                .send(ConversationEvent::ComplianceIncomplete { violations });
+        }
         let _ = self.event_tx.send(ConversationEvent::Ended { reason });
     }
 
@@ -945,6 +1280,71 @@ impl Conversation {
     pub fn get_ai_disclosure_message(&self) -> String {
         self.ai_disclosure_message.clone()
     }
+
+    /// Give the AI disclosure and ask for recording consent
+    ///
+    /// Should be called once at call start. Returns the text to speak: the
+    /// config-driven disclosure followed by the consent question.
+    pub fn begin_consent(&self) -> String {
+        let disclosure = self.mark_ai_disclosed();
+        format!("{} {}", disclosure, self.ask_consent())
+    }
+
+    /// Ask the recording consent question; the next answer resolves it
+    pub fn ask_consent(&self) -> String {
+        *self.awaiting_consent.lock() = true;
+        self.consent_prompt.clone()
+    }
+
+    /// Whether the consent question is awaiting an answer
+    pub fn awaiting_consent(&self) -> bool {
+        *self.awaiting_consent.lock()
+    }
+
+    /// Record the customer's spoken answer to the consent question
+    ///
+    /// Returns the updated record, or None if no question is pending or the
+    /// answer is unclear (the caller should repeat the question).
+    pub fn resolve_consent(&self, user_input: &str) -> Option<ConsentRecord> {
+        if !self.awaiting_consent() {
+            return None;
+        }
+        let given = detect_consent_response(user_input)?;
+
+        self.record_recording_consent(given, ConsentMethod::Voice);
+        *self.awaiting_consent.lock() = false;
+        let _ = self.event_tx.send(ConversationEvent::ConsentRecorded {
+            given,
+            method: ConsentMethod::Voice,
+        });
+
+        Some(self.compliance.lock().consent.clone())
+    }
+
+    /// Whether the call may be recorded and its transcript retained
+    pub fn recording_allowed(&self) -> bool {
+        self.compliance.lock().consent.recording_allowed()
+    }
+
+    /// Recording consent question for the configured language
+    pub fn consent_prompt(&self) -> &str {
+        &self.consent_prompt
+    }
+
+    /// Record a compliance violation raised during the call
+    pub fn record_compliance_violation(&self, violation: ComplianceViolation) {
+        self.compliance.lock().record_violation(violation);
+    }
+
+    /// Resolve a previously recorded violation by rule ID
+    pub fn resolve_compliance_violation(&self, rule_id: &str) -> bool {
+        self.compliance.lock().resolve_violation(rule_id)
+    }
+
+    /// Violations that would leave the call non-compliant if it ended now
+    pub fn outstanding_violations(&self) -> Vec<ComplianceViolation> {
+        self.compliance.lock().outstanding_violations()
+    }
 }
 
 // =============================================================================
@@ -988,6 +1388,10 @@ impl ConversationContext for Conversation {
         Conversation::transition_stage(self, to)
     }
 
+    fn transition_stage_on_timeout(&self, to: ConversationStage) -> Result<(), AgentError> {
+        Conversation::transition_stage_on_timeout(self, to)
+    }
+
     fn get_context(&self) -> String {
         self.memory.get_context()
     }
@@ -1080,6 +1484,58 @@ impl ConversationContext for Conversation {
         Conversation::get_ai_disclosure_message(self)
     }
 
+    fn begin_consent(&self) -> String {
+        Conversation::begin_consent(self)
+    }
+
+    fn ask_consent(&self) -> String {
+        Conversation::ask_consent(self)
+    }
+
+    fn awaiting_consent(&self) -> bool {
+        Conversation::awaiting_consent(self)
+    }
+
+    fn consent_prompt(&self) -> &str {
+        Conversation::consent_prompt(self)
+    }
+
+    fn resolve_consent(&self, user_input: &str) -> Option<ConsentRecord> {
+        Conversation::resolve_consent(self, user_input)
+    }
+
+    fn recording_allowed(&self) -> bool {
+        Conversation::recording_allowed(self)
+    }
+
+    fn record_compliance_violation(&self, violation: ComplianceViolation) {
+        Conversation::record_compliance_violation(self, violation)
+    }
+
+    fn resolve_compliance_violation(&self, rule_id: &str) -> bool {
+        Conversation::resolve_compliance_violation(self, rule_id)
+    }
+
+    fn outstanding_violations(&self) -> Vec<ComplianceViolation> {
+        Conversation::outstanding_violations(self)
+    }
+
+    fn is_low_confidence_turn(&self) -> bool {
+        Conversation::is_low_confidence_turn(self)
+    }
+
+    fn detect_intent(&self, content: &str) -> DetectedIntent {
+        Conversation::detect_intent(self, content)
+    }
+
+    fn pending_clarification(&self) -> Option<String> {
+        Conversation::pending_clarification(self)
+    }
+
+    fn last_intent(&self) -> Option<String> {
+        Conversation::last_intent(self)
+    }
+
     fn subscribe(&self) -> broadcast::Receiver<ConversationEvent> {
         self.event_tx.subscribe()
     }
@@ -1111,6 +1567,90 @@ mod tests {
         assert_eq!(conv.turn_count(), 2);
     }
 
+    fn rate_disambiguation() -> DisambiguationConfig {
+        let yaml = r#"
+clarifications:
+  - intents: [price_inquiry, interest_rate]
+    question: "Would you like today's price or our interest rate?"
+    cues:
+      price_inquiry: ["price", "today"]
+      interest_rate: ["interest"]
+"#;
+        serde_yaml::from_str(yaml).unwrap()
+    }
+
+    #[test]
+    fn test_ambiguous_intent_asks_clarification() {
+        let conv = Conversation::new("test", ConversationConfig::default())
+            .with_disambiguation(rate_disambiguation());
+        let mut events = conv.subscribe();
+
+        // Ties "What is the interest rate" and "What is the current rate"
+        conv.add_user_turn("What is the rate").unwrap();
+
+        assert_eq!(
+            conv.pending_clarification().as_deref(),
+            Some("Would you like today's price or our interest rate?")
+        );
+        let mut requested = false;
+        while let Ok(event) = events.try_recv() {
+            if let ConversationEvent::ClarificationRequested { candidates, .. } = event {
+                assert!(candidates.contains(&"interest_rate".to_string()));
+                assert!(candidates.contains(&"price_inquiry".to_string()));
+                requested = true;
+            }
+        }
+        assert!(requested);
+    }
+
+    #[test]
+    fn test_clarification_resolves_intent() {
+        let conv = Conversation::new("test", ConversationConfig::default())
+            .with_disambiguation(rate_disambiguation());
+
+        conv.add_user_turn("What is the rate").unwrap();
+        conv.add_assistant_turn(&conv.pending_clarification().unwrap())
+            .unwrap();
+
+        let resolved = conv.add_user_turn("today's price please").unwrap();
+
+        assert_eq!(resolved.intent, "price_inquiry");
+        assert!(conv.pending_clarification().is_none());
+    }
+
+    #[test]
+    fn test_no_clarification_without_config() {
+        let conv = Conversation::new("test", ConversationConfig::default());
+
+        conv.add_user_turn("What is the rate").unwrap();
+
+        assert!(conv.pending_clarification().is_none());
+    }
+
+    #[test]
+    fn test_low_confidence_intent_falls_back_to_unknown() {
+        let conv = Conversation::new("test", ConversationConfig::default())
+            .with_intent_calibration(ConfidenceCalibration::default(), 0.3);
+        let mut events = conv.subscribe();
+
+        let intent = conv.add_user_turn("play some music").unwrap();
+
+        assert_eq!(intent.intent, UNKNOWN_INTENT);
+        assert!(conv.is_low_confidence_turn());
+        let mut flagged = false;
+        while let Ok(event) = events.try_recv() {
+            if let ConversationEvent::LowConfidenceIntent { confidence, .. } = event {
+                assert!(confidence < 0.3);
+                flagged = true;
+            }
+        }
+        assert!(flagged);
+
+        let intent = conv.add_user_turn("What is the interest rate").unwrap();
+        assert_eq!(intent.intent, "interest_rate");
+        assert!(!conv.is_low_confidence_turn());
+    }
+
     #[test]
     fn test_stage_transition() {
         let conv = Conversation::new("test", ConversationConfig::default());
@@ -1129,4 +1669,51 @@ mod tests {
         assert!(fact.is_some());
         assert_eq!(fact.unwrap().value, "Rajesh");
     }
+
+    fn rate_disclosure_violation() -> ComplianceViolation {
+        ComplianceViolation::new(
+            "rate_disclosure",
+            "Interest rate quoted without the processing fee disclosure",
+            ViolationCategory::MissingDisclosure,
+            Severity::Error,
+        )
+    }
+
+    #[test]
+    fn test_end_with_outstanding_violation_emits_event() {
+        let conv = Conversation::new("test", ConversationConfig::default());
+        conv.mark_ai_disclosed();
+        conv.record_compliance_violation(rate_disclosure_violation());
+        let mut events = conv.subscribe();
+
+        conv.end(EndReason::UserEnded);
+
+        match events.try_recv().unwrap() {
+            ConversationEvent::ComplianceIncomplete { violations } => {
+                assert_eq!(violations.len(), 1);
+                assert_eq!(violations[0].category, ViolationCategory::MissingDisclosure);
+            },
+            other => panic!("expected ComplianceIncomplete, got {:?}", other),
+        }
+        assert!(matches!(
+            events.try_recv().unwrap(),
+            ConversationEvent::Ended { .. }
+        ));
+    }
+
+    #[test]
+    fn test_resolved_violation_ends_cleanly() {
+        let conv = Conversation::new("test", ConversationConfig::default());
+        conv.mark_ai_disclosed();
+        conv.record_compliance_violation(rate_disclosure_violation());
+        assert!(conv.resolve_compliance_violation("rate_disclosure"));
+        let mut events = conv.subscribe();
+
+        conv.end(EndReason::UserEnded);
+
+        assert!(matches!(
+            events.try_recv().unwrap(),
+            ConversationEvent::Ended { .. }
+        ));
+    }
 }
@@ -0,0 +1,226 @@
+//! Multi-turn Intent Disambiguation
+//!
+//! When the top two detected intents are nearly tied (e.g. "price_inquiry" vs
+//! "interest_rate"), the agent asks a clarifying question from config instead
+//! of guessing, then resolves the pending ambiguity with the user's answer.
+//!
+//! Resolution order for the follow-up answer:
+//! 1. Configured cue phrases for each candidate intent
+//! 2. Detector scores restricted to the candidate intents
+
+use voice_agent_config::domain::DisambiguationConfig;
+
+use crate::intent::DetectedIntent;
+
+/// A clarifying question awaiting the user's answer
+#[derive(Debug, Clone, PartialEq)]
+pub struct PendingDisambiguation {
+    /// Candidate intents, best first
+    pub candidates: Vec<String>,
+    /// Question asked to the user
+    pub question: String,
+}
+
+/// Detects near-tied intents and resolves them from follow-up answers
+#[derive(Debug, Clone)]
+pub struct IntentDisambiguator {
+    config: DisambiguationConfig,
+}
+
+impl IntentDisambiguator {
+    /// Create from the domain's disambiguation config
+    pub fn new(config: DisambiguationConfig) -> Self {
+        Self { config }
+    }
+
+    /// Whether any clarifications are configured
+    pub fn is_enabled(&self) -> bool {
+        !self.config.clarifications.is_empty()
+    }
+
+    /// Check whether the detected intent is ambiguous enough to clarify
+    ///
+    /// Returns the question to ask if the top two intents are within the
+    /// configured margin and a clarification exists for that pair.
+    pub fn check(&self, detected: &DetectedIntent) -> Option<PendingDisambiguation> {
+        if detected.confidence < self.config.min_confidence {
+            return None;
+        }
+
+        let (runner_up, runner_up_score) = detected.alternatives.first()?;
+        if detected.confidence - runner_up_score > self.config.margin {
+            return None;
+        }
+
+        let rule = self.config.clarification_for(&detected.intent, runner_up)?;
+
+        Some(PendingDisambiguation {
+            candidates: vec![detected.intent.clone(), runner_up.clone()],
+            question: rule.question.clone(),
+        })
+    }
+
+    /// Resolve a pending disambiguation from the user's answer
+    ///
+    /// `detected` is the regular detection result for the answer. Returns the
+    /// chosen intent with its confidence, or None if the answer picks neither.
+    pub fn resolve(
+        &self,
+        pending: &PendingDisambiguation,
+        answer: &str,
+        detected: &DetectedIntent,
+    ) -> Option<(String, f32)> {
+        if let Some(intent) = self.resolve_by_cues(pending, answer) {
+            let confidence = Self::score_of(detected, &intent).max(self.config.min_confidence);
+            return Some((intent, confidence));
+        }
+
+        pending
+            .candidates
+            .iter()
+            .map(|c| (c.clone(), Self::score_of(detected, c)))
+            .filter(|(_, score)| *score >= self.config.min_confidence)
+            .max_by(|a, b| a.1.total_cmp(&b.1))
+    }
+
+    /// Pick the candidate whose cue phrases appear most in the answer
+    fn resolve_by_cues(&self, pending: &PendingDisambiguation, answer: &str) -> Option<String> {
+        let rule = self
+            .config
+            .clarification_for(&pending.candidates[0], &pending.candidates[1])?;
+        let answer_lower = answer.to_lowercase();
+
+        let mut hits: Vec<(&String, usize)> = pending
+            .candidates
+            .iter()
+            .map(|candidate| {
+                let count = rule
+                    .cues
+                    .get(candidate)
+                    .map(|cues| {
+                        cues.iter()
+                            .filter(|cue| answer_lower.contains(&cue.to_lowercase()))
+                            .count()
+                    })
+                    .unwrap_or(0);
+                (candidate, count)
+            })
+            .collect();
+        hits.sort_by(|a, b| b.1.cmp(&a.1));
+
+        match hits.as_slice() {
+            [(best, best_hits), (_, second_hits), ..] if *best_hits > *second_hits => {
+                Some((*best).clone())
+            },
+            _ => None,
+        }
+    }
+
+    fn score_of(detected: &DetectedIntent, intent: &str) -> f32 {
+        if detected.intent == intent {
+            return detected.confidence;
+        }
+        detected
+            .alternatives
+            .iter()
+            .find(|(name, _)| name == intent)
+            .map(|(_, score)| *score)
+            .unwrap_or(0.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+    use voice_agent_config::domain::ClarificationRule;
+
+    fn disambiguator() -> IntentDisambiguator {
+        let mut cues = HashMap::new();
+        cues.insert("price_inquiry".to_string(), vec!["price".to_string()]);
+        cues.insert("interest_rate".to_string(), vec!["interest".to_string()]);
+
+        IntentDisambiguator::new(DisambiguationConfig {
+            clarifications: vec![ClarificationRule {
+                intents: vec!["price_inquiry".to_string(), "interest_rate".to_string()],
+                question: "Today's price or our interest rate?".to_string(),
+                cues,
+            }],
+            ..Default::default()
+        })
+    }
+
+    fn detected(intent: &str, confidence: f32, alternatives: &[(&str, f32)]) -> DetectedIntent {
+        DetectedIntent {
+            intent: intent.to_string(),
+            confidence,
+            slots: HashMap::new(),
+            alternatives: alternatives
+                .iter()
+                .map(|(name, score)| (name.to_string(), *score))
+                .collect(),
+            ambiguous_number: None,
+        }
+    }
+
+    #[test]
+    fn test_near_tie_asks_question() {
+        let pending = disambiguator()
+            .check(&detected(
+                "interest_rate",
+                0.64,
+                &[("price_inquiry", 0.6), ("greeting", 0.1)],
+            ))
+            .unwrap();
+
+        assert_eq!(pending.candidates, vec!["interest_rate", "price_inquiry"]);
+        assert_eq!(pending.question, "Today's price or our interest rate?");
+    }
+
+    #[test]
+    fn test_clear_winner_or_unconfigured_pair_not_ambiguous() {
+        let d = disambiguator();
+
+        assert!(d
+            .check(&detected("interest_rate", 0.9, &[("price_inquiry", 0.4)]))
+            .is_none());
+        assert!(d
+            .check(&detected("interest_rate", 0.6, &[("greeting", 0.6)]))
+            .is_none());
+        assert!(d
+            .check(&detected("interest_rate", 0.2, &[("price_inquiry", 0.2)]))
+            .is_none());
+    }
+
+    #[test]
+    fn test_resolve_by_cue_and_by_score() {
+        let d = disambiguator();
+        let pending = PendingDisambiguation {
+            candidates: vec!["interest_rate".to_string(), "price_inquiry".to_string()],
+            question: String::new(),
+        };
+
+        let (intent, _) = d
+            .resolve(
+                &pending,
+                "the price please",
+                &detected("greeting", 0.1, &[]),
+            )
+            .unwrap();
+        assert_eq!(intent, "price_inquiry");
+
+        let (intent, confidence) = d
+            .resolve(
+                &pending,
+                "what you charge",
+                &detected("interest_rate", 0.7, &[("price_inquiry", 0.5)]),
+            )
+            .unwrap();
+        assert_eq!(intent, "interest_rate");
+        assert_eq!(confidence, 0.7);
+
+        assert!(d
+            .resolve(&pending, "never mind", &detected("greeting", 0.1, &[]))
+            .is_none());
+    }
+}
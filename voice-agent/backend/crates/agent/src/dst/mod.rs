@@ -207,6 +207,10 @@ pub struct DialogueStateTracker {
     slots_config: Arc<voice_agent_config::domain::SlotsConfig>,
     /// Domain view for config-driven instructions (optional)
     domain_view: Option<Arc<AgentDomainView>>,
+    /// Times each missing slot has been asked for
+    slot_attempts: HashMap<String, u32>,
+    /// Slots given up on after their retry limit
+    skipped_slots: HashSet<String>,
 }
 
 impl DialogueStateTracker {
@@ -221,6 +225,8 @@ impl DialogueStateTracker {
             config: DstConfig::default(),
             slots_config,
             domain_view: None,
+            slot_attempts: HashMap::new(),
+            skipped_slots: HashSet::new(),
         }
     }
 
@@ -236,6 +242,8 @@ impl DialogueStateTracker {
             config: dst_config,
             slots_config,
             domain_view: None,
+            slot_attempts: HashMap::new(),
+            skipped_slots: HashSet::new(),
         }
     }
 
@@ -248,6 +256,8 @@ impl DialogueStateTracker {
             config: DstConfig::default(),
             slots_config,
             domain_view: None,
+            slot_attempts: HashMap::new(),
+            skipped_slots: HashSet::new(),
         }
     }
 
@@ -265,6 +275,8 @@ impl DialogueStateTracker {
             config: dst_config,
             slots_config,
             domain_view: None,
+            slot_attempts: HashMap::new(),
+            skipped_slots: HashSet::new(),
         }
     }
 
@@ -281,6 +293,8 @@ impl DialogueStateTracker {
             config: dst_config,
             slots_config,
             domain_view: None,
+            slot_attempts: HashMap::new(),
+            skipped_slots: HashSet::new(),
         }
     }
 
@@ -369,6 +383,8 @@ impl DialogueStateTracker {
 
         // Apply change to state
         self.state.set_slot_value(slot_name, value, confidence);
+        self.slot_attempts.remove(slot_name);
+        self.skipped_slots.remove(slot_name);
 
         // Mark as pending confirmation if not auto-confirmed
         if confidence < self.config.auto_confirm_confidence {
@@ -590,10 +606,35 @@ impl DialogueStateTracker {
             .and_then(|g| g.completion_action.as_deref())
     }
 
+    /// Record that a missing slot is being asked for; returns prior attempts
+    pub fn record_slot_attempt(&mut self, slot_name: &str) -> u32 {
+        let attempts = self.slot_attempts.entry(slot_name.to_string()).or_insert(0);
+        let prior = *attempts;
+        *attempts += 1;
+        prior
+    }
+
+    /// Times a slot has been asked for without being filled
+    pub fn slot_attempts(&self, slot_name: &str) -> u32 {
+        self.slot_attempts.get(slot_name).copied().unwrap_or(0)
+    }
+
+    /// Stop asking for a slot after its retry limit
+    pub fn skip_slot(&mut self, slot_name: &str) {
+        self.skipped_slots.insert(slot_name.to_string());
+    }
+
+    /// Slots given up on after their retry limit
+    pub fn skipped_slots(&self) -> &HashSet<String> {
+        &self.skipped_slots
+    }
+
     /// Reset the tracker
     pub fn reset(&mut self) {
         self.state = DynamicDialogueState::from_config(self.slots_config.clone());
         self.history.clear();
+        self.slot_attempts.clear();
+        self.skipped_slots.clear();
     }
 }
 
@@ -914,6 +955,22 @@ intent_mapping:
         assert!(tracker.is_intent_complete("eligibility_check"));
     }
 
+    #[test]
+    fn test_slot_attempts_reset_when_filled() {
+        let config = create_test_config();
+        let mut tracker = DialogueStateTracker::from_config(config);
+
+        assert_eq!(tracker.record_slot_attempt("gold_weight"), 0);
+        assert_eq!(tracker.record_slot_attempt("gold_weight"), 1);
+        assert_eq!(tracker.slot_attempts("gold_weight"), 2);
+        tracker.skip_slot("gold_weight");
+        assert!(tracker.skipped_slots().contains("gold_weight"));
+
+        tracker.update_slot("gold_weight", "50", 0.9, ChangeSource::UserUtterance, 0);
+        assert_eq!(tracker.slot_attempts("gold_weight"), 0);
+        assert!(tracker.skipped_slots().is_empty());
+    }
+
     #[test]
     fn test_state_context() {
         let config = create_test_config();
@@ -0,0 +1,543 @@
+//! Conversation Quality Evaluation
+//!
+//! Offline scoring of recorded conversations, intended as a CI gate for
+//! prompt and config changes. A `ConversationReplay` is scored against the
+//! domain's goal definitions (from `goals.yaml`) on:
+//! - Goal completion (all required slots of the goal collected)
+//! - Slot-fill rate (fraction of required slots collected)
+//! - Compliance violations in agent responses
+//! - Escalation appropriateness (escalated if and only if the customer asked)
+//! - Average agent response latency
+//!
+//! # Example
+//!
+//! ```ignore
+//! use voice_agent_agent::eval::{ConversationEvaluator, ConversationReplay};
+//!
+//! let evaluator = ConversationEvaluator::new(domain.slots.goals.clone())
+//!     .with_compliance_checker(checker);
+//! let replay: ConversationReplay = serde_json::from_str(&recording)?;
+//! let report = evaluator.evaluate(&replay).await;
+//! assert!(report.passed, "{:?}", report);
+//! ```
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use voice_agent_config::domain::GoalDefinition;
+use voice_agent_core::{ComplianceChecker, TurnRole};
+
+/// Weight of goal completion in the overall score
+const GOAL_WEIGHT: f32 = 0.35;
+/// Weight of slot-fill rate in the overall score
+const SLOT_FILL_WEIGHT: f32 = 0.25;
+/// Weight of compliance in the overall score
+const COMPLIANCE_WEIGHT: f32 = 0.2;
+/// Weight of escalation appropriateness in the overall score
+const ESCALATION_WEIGHT: f32 = 0.1;
+/// Weight of latency in the overall score
+const LATENCY_WEIGHT: f32 = 0.1;
+
+/// A single recorded turn
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReplayTurn {
+    /// Who spoke
+    pub role: TurnRole,
+    /// Turn text
+    pub text: String,
+    /// Detected intent (user turns)
+    #[serde(default)]
+    pub intent: Option<String>,
+    /// Slots filled by this turn
+    #[serde(default)]
+    pub slots: HashMap<String, String>,
+    /// Time to produce the response in milliseconds (agent turns)
+    #[serde(default)]
+    pub latency_ms: Option<u64>,
+    /// Whether the agent escalated to a human on this turn
+    #[serde(default)]
+    pub escalated: bool,
+}
+
+impl ReplayTurn {
+    fn new(role: TurnRole, text: impl Into<String>) -> Self {
+        Self {
+            role,
+            text: text.into(),
+            intent: None,
+            slots: HashMap::new(),
+            latency_ms: None,
+            escalated: false,
+        }
+    }
+
+    /// Create a user turn
+    pub fn user(text: impl Into<String>) -> Self {
+        Self::new(TurnRole::User, text)
+    }
+
+    /// Create an agent turn
+    pub fn agent(text: impl Into<String>) -> Self {
+        Self::new(TurnRole::Assistant, text)
+    }
+
+    /// Set the detected intent
+    pub fn with_intent(mut self, intent: impl Into<String>) -> Self {
+        self.intent = Some(intent.into());
+        self
+    }
+
+    /// Record a slot filled by this turn
+    pub fn with_slot(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.slots.insert(name.into(), value.into());
+        self
+    }
+
+    /// Set the response latency
+    pub fn with_latency_ms(mut self, latency_ms: u64) -> Self {
+        self.latency_ms = Some(latency_ms);
+        self
+    }
+
+    /// Mark this turn as an escalation to a human
+    pub fn with_escalation(mut self) -> Self {
+        self.escalated = true;
+        self
+    }
+}
+
+/// A recorded conversation to evaluate
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ConversationReplay {
+    /// Session identifier of the recording
+    pub session_id: String,
+    /// Goal the conversation was pursuing; inferred from slots if absent
+    #[serde(default)]
+    pub goal: Option<String>,
+    /// Recorded turns in order
+    #[serde(default)]
+    pub turns: Vec<ReplayTurn>,
+}
+
+impl ConversationReplay {
+    /// Create an empty replay
+    pub fn new(session_id: impl Into<String>) -> Self {
+        Self {
+            session_id: session_id.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Set the goal being pursued
+    pub fn with_goal(mut self, goal: impl Into<String>) -> Self {
+        self.goal = Some(goal.into());
+        self
+    }
+
+    /// Append a turn
+    pub fn with_turn(mut self, turn: ReplayTurn) -> Self {
+        self.turns.push(turn);
+        self
+    }
+
+    /// Slots collected over the conversation (later values win)
+    pub fn collected_slots(&self) -> HashMap<String, String> {
+        let mut slots = HashMap::new();
+        for turn in &self.turns {
+            for (name, value) in &turn.slots {
+                slots.insert(name.clone(), value.clone());
+            }
+        }
+        slots
+    }
+}
+
+/// Whether escalation to a human was handled correctly
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EscalationAssessment {
+    /// Customer did not ask and agent did not escalate
+    NotNeeded,
+    /// Customer asked and agent escalated
+    Appropriate,
+    /// Customer asked but agent never escalated
+    Missed,
+    /// Agent escalated without the customer asking
+    Unnecessary,
+}
+
+impl EscalationAssessment {
+    /// Whether the agent behaved correctly
+    pub fn is_appropriate(&self) -> bool {
+        matches!(self, Self::NotNeeded | Self::Appropriate)
+    }
+}
+
+/// Evaluator configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EvaluatorConfig {
+    /// User intents that warrant escalation to a human
+    pub escalation_intents: Vec<String>,
+    /// Average latency at or below which the latency score is full
+    pub target_latency_ms: u64,
+    /// Minimum overall score for a passing report
+    pub pass_threshold: f32,
+}
+
+impl Default for EvaluatorConfig {
+    fn default() -> Self {
+        Self {
+            escalation_intents: vec!["escalate".to_string()],
+            target_latency_ms: 800,
+            pass_threshold: 0.7,
+        }
+    }
+}
+
+/// Structured quality report for one conversation
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EvaluationReport {
+    /// Session identifier of the recording
+    pub session_id: String,
+    /// Goal evaluated against (None if no goal matched)
+    pub goal: Option<String>,
+    /// All required slots of the goal were collected
+    pub goal_completed: bool,
+    /// Fraction of required slots collected (0.0 - 1.0)
+    pub slot_fill_rate: f32,
+    /// Required slots that were never collected
+    pub missing_slots: Vec<String>,
+    /// Number of compliance violations across agent turns
+    pub compliance_violations: usize,
+    /// Escalation handling
+    pub escalation: EscalationAssessment,
+    /// Average agent response latency (None if no latencies recorded)
+    pub avg_latency_ms: Option<f64>,
+    /// Weighted overall score (0.0 - 1.0)
+    pub overall_score: f32,
+    /// Score met the threshold and no compliance violations occurred
+    pub passed: bool,
+}
+
+/// Scores recorded conversations against domain goals
+pub struct ConversationEvaluator {
+    goals: HashMap<String, GoalDefinition>,
+    compliance_checker: Option<Arc<dyn ComplianceChecker>>,
+    config: EvaluatorConfig,
+}
+
+impl ConversationEvaluator {
+    /// Create an evaluator for the domain's goals
+    pub fn new(goals: HashMap<String, GoalDefinition>) -> Self {
+        Self {
+            goals,
+            compliance_checker: None,
+            config: EvaluatorConfig::default(),
+        }
+    }
+
+    /// Check agent turns with a compliance checker
+    pub fn with_compliance_checker(mut self, checker: Arc<dyn ComplianceChecker>) -> Self {
+        self.compliance_checker = Some(checker);
+        self
+    }
+
+    /// Override the evaluator configuration
+    pub fn with_config(mut self, config: EvaluatorConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Evaluate a recorded conversation
+    pub async fn evaluate(&self, replay: &ConversationReplay) -> EvaluationReport {
+        let collected = replay.collected_slots();
+        let goal = self.resolve_goal(replay, &collected);
+
+        let (slot_fill_rate, missing_slots) = match goal.and_then(|g| self.goals.get(g)) {
+            Some(definition) => Self::slot_fill(definition, &collected),
+            None => (0.0, Vec::new()),
+        };
+        let goal_completed = goal.is_some() && missing_slots.is_empty();
+
+        let compliance_violations = self.count_violations(replay).await;
+        let escalation = self.assess_escalation(replay);
+        let avg_latency_ms = Self::average_latency(replay);
+
+        let compliance_score = 1.0 / (1.0 + compliance_violations as f32);
+        let escalation_score = if escalation.is_appropriate() {
+            1.0
+        } else {
+            0.0
+        };
+        let latency_score = match avg_latency_ms {
+            Some(avg) if avg > self.config.target_latency_ms as f64 => {
+                (self.config.target_latency_ms as f64 / avg) as f32
+            },
+            _ => 1.0,
+        };
+
+        let overall_score = GOAL_WEIGHT * if goal_completed { 1.0 } else { 0.0 }
+            + SLOT_FILL_WEIGHT * slot_fill_rate
+            + COMPLIANCE_WEIGHT * compliance_score
+            + ESCALATION_WEIGHT * escalation_score
+            + LATENCY_WEIGHT * latency_score;
+
+        EvaluationReport {
+            session_id: replay.session_id.clone(),
+            goal: goal.map(str::to_string),
+            goal_completed,
+            slot_fill_rate,
+            missing_slots,
+            compliance_violations,
+            escalation,
+            avg_latency_ms,
+            overall_score,
+            passed: overall_score >= self.config.pass_threshold && compliance_violations == 0,
+        }
+    }
+
+    /// Use the replay's goal if known, otherwise the goal with the best slot coverage
+    fn resolve_goal<'a>(
+        &'a self,
+        replay: &'a ConversationReplay,
+        collected: &HashMap<String, String>,
+    ) -> Option<&'a str> {
+        if let Some(goal) = replay.goal.as_deref() {
+            return self.goals.contains_key(goal).then_some(goal);
+        }
+
+        self.goals
+            .iter()
+            .map(|(name, definition)| (name, Self::slot_fill(definition, collected).0))
+            .filter(|(_, rate)| *rate > 0.0)
+            .max_by(|a, b| a.1.total_cmp(&b.1).then_with(|| b.0.cmp(a.0)))
+            .map(|(name, _)| name.as_str())
+    }
+
+    fn slot_fill(
+        definition: &GoalDefinition,
+        collected: &HashMap<String, String>,
+    ) -> (f32, Vec<String>) {
+        if definition.required_slots.is_empty() {
+            return (1.0, Vec::new());
+        }
+
+        let missing: Vec<String> = definition
+            .required_slots
+            .iter()
+            .filter(|slot| !collected.contains_key(*slot))
+            .cloned()
+            .collect();
+        let filled = definition.required_slots.len() - missing.len();
+
+        (
+            filled as f32 / definition.required_slots.len() as f32,
+            missing,
+        )
+    }
+
+    async fn count_violations(&self, replay: &ConversationReplay) -> usize {
+        let Some(checker) = &self.compliance_checker else {
+            return 0;
+        };
+
+        let mut violations = 0;
+        for turn in replay
+            .turns
+            .iter()
+            .filter(|t| t.role == TurnRole::Assistant)
+        {
+            match checker.check(&turn.text).await {
+                Ok(result) => violations += result.violations.len(),
+                Err(e) => tracing::warn!("Compliance check failed during evaluation: {}", e),
+            }
+        }
+        violations
+    }
+
+    fn assess_escalation(&self, replay: &ConversationReplay) -> EscalationAssessment {
+        let requested = replay.turns.iter().any(|t| {
+            t.role == TurnRole::User
+                && t.intent
+                    .as_ref()
+                    .is_some_and(|i| self.config.escalation_intents.contains(i))
+        });
+        let escalated = replay
+            .turns
+            .iter()
+            .any(|t| t.role == TurnRole::Assistant && t.escalated);
+
+        match (requested, escalated) {
+            (false, false) => EscalationAssessment::NotNeeded,
+            (true, true) => EscalationAssessment::Appropriate,
+            (true, false) => EscalationAssessment::Missed,
+            (false, true) => EscalationAssessment::Unnecessary,
+        }
+    }
+
+    fn average_latency(replay: &ConversationReplay) -> Option<f64> {
+        let latencies: Vec<u64> = replay
+            .turns
+            .iter()
+            .filter(|t| t.role == TurnRole::Assistant)
+            .filter_map(|t| t.latency_ms)
+            .collect();
+
+        if latencies.is_empty() {
+            None
+        } else {
+            Some(latencies.iter().sum::<u64>() as f64 / latencies.len() as f64)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use voice_agent_core::{ComplianceResult, ComplianceViolation, Severity, ViolationCategory};
+
+    /// Flags any response promising guaranteed approval
+    struct GuaranteeChecker;
+
+    #[async_trait::async_trait]
+    impl ComplianceChecker for GuaranteeChecker {
+        async fn check(&self, text: &str) -> voice_agent_core::Result<ComplianceResult> {
+            if text.to_lowercase().contains("guaranteed") {
+                Ok(ComplianceResult::non_compliant(vec![ComplianceViolation {
+                    rule_id: "TEST-001".to_string(),
+                    description: "Guaranteed approval claim".to_string(),
+                    category: ViolationCategory::MisleadingClaim,
+                    severity: Severity::Critical,
+                    text_span: None,
+                    violating_text: None,
+                }]))
+            } else {
+                Ok(ComplianceResult::compliant())
+            }
+        }
+
+        async fn make_compliant(&self, text: &str) -> voice_agent_core::Result<String> {
+            Ok(text.to_string())
+        }
+
+        fn rules_version(&self) -> &str {
+            "test"
+        }
+    }
+
+    fn goals() -> HashMap<String, GoalDefinition> {
+        let mut goals = HashMap::new();
+        goals.insert(
+            "schedule_visit".to_string(),
+            GoalDefinition {
+                description: "Book a branch visit".to_string(),
+                required_slots: vec![
+                    "customer_name".to_string(),
+                    "phone_number".to_string(),
+                    "location".to_string(),
+                ],
+                optional_slots: vec![],
+                completion_action: Some("schedule_appointment".to_string()),
+                ..Default::default()
+            },
+        );
+        goals
+    }
+
+    fn evaluator() -> ConversationEvaluator {
+        ConversationEvaluator::new(goals()).with_compliance_checker(Arc::new(GuaranteeChecker))
+    }
+
+    fn good_conversation() -> ConversationReplay {
+        ConversationReplay::new("good")
+            .with_goal("schedule_visit")
+            .with_turn(ReplayTurn::user("I want to visit a branch").with_intent("schedule_visit"))
+            .with_turn(ReplayTurn::agent("Sure, may I have your name?").with_latency_ms(450))
+            .with_turn(ReplayTurn::user("Rahul").with_slot("customer_name", "Rahul"))
+            .with_turn(
+                ReplayTurn::user("my number is 9876543210").with_slot("phone_number", "9876543210"),
+            )
+            .with_turn(ReplayTurn::agent("Which city are you in?").with_latency_ms(520))
+            .with_turn(ReplayTurn::user("Mumbai").with_slot("location", "Mumbai"))
+            .with_turn(ReplayTurn::agent("Your visit is booked in Mumbai.").with_latency_ms(610))
+    }
+
+    fn bad_conversation() -> ConversationReplay {
+        ConversationReplay::new("bad")
+            .with_goal("schedule_visit")
+            .with_turn(ReplayTurn::user("I want to visit a branch").with_intent("schedule_visit"))
+            .with_turn(ReplayTurn::agent("Approval is guaranteed for you!").with_latency_ms(2400))
+            .with_turn(ReplayTurn::user("My name is Rahul").with_slot("customer_name", "Rahul"))
+            .with_turn(ReplayTurn::user("Let me talk to a person").with_intent("escalate"))
+            .with_turn(ReplayTurn::agent("I can help you with that myself.").with_latency_ms(3200))
+    }
+
+    #[tokio::test]
+    async fn test_good_conversation_scores_high() {
+        let report = evaluator().evaluate(&good_conversation()).await;
+
+        assert_eq!(report.goal.as_deref(), Some("schedule_visit"));
+        assert!(report.goal_completed);
+        assert_eq!(report.slot_fill_rate, 1.0);
+        assert!(report.missing_slots.is_empty());
+        assert_eq!(report.compliance_violations, 0);
+        assert_eq!(report.escalation, EscalationAssessment::NotNeeded);
+        assert_eq!(report.avg_latency_ms, Some((450.0 + 520.0 + 610.0) / 3.0));
+        assert!(report.overall_score > 0.95);
+        assert!(report.passed);
+    }
+
+    #[tokio::test]
+    async fn test_bad_conversation_scores_low() {
+        let report = evaluator().evaluate(&bad_conversation()).await;
+
+        assert!(!report.goal_completed);
+        assert!((report.slot_fill_rate - 1.0 / 3.0).abs() < 1e-6);
+        assert_eq!(report.missing_slots.len(), 2);
+        assert!(report.missing_slots.contains(&"location".to_string()));
+        assert_eq!(report.compliance_violations, 1);
+        assert_eq!(report.escalation, EscalationAssessment::Missed);
+        assert_eq!(report.avg_latency_ms, Some(2800.0));
+        assert!(report.overall_score < 0.4);
+        assert!(!report.passed);
+    }
+
+    #[tokio::test]
+    async fn test_goal_inferred_from_slots() {
+        let replay = ConversationReplay::new("inferred")
+            .with_turn(ReplayTurn::user("I'm Rahul").with_slot("customer_name", "Rahul"));
+
+        let report = ConversationEvaluator::new(goals()).evaluate(&replay).await;
+
+        assert_eq!(report.goal.as_deref(), Some("schedule_visit"));
+        assert_eq!(report.avg_latency_ms, None);
+    }
+
+    #[tokio::test]
+    async fn test_unnecessary_escalation() {
+        let replay = good_conversation()
+            .with_turn(ReplayTurn::agent("Transferring you to a human.").with_escalation());
+
+        let report = evaluator().evaluate(&replay).await;
+
+        assert_eq!(report.escalation, EscalationAssessment::Unnecessary);
+        assert!(!report.escalation.is_appropriate());
+    }
+
+    #[test]
+    fn test_replay_deserializes_from_json() {
+        let json = r#"{
+            "session_id": "rec-1",
+            "turns": [
+                {"role": "user", "text": "hi", "intent": "greeting"},
+                {"role": "assistant", "text": "hello", "latency_ms": 300}
+            ]
+        }"#;
+
+        let replay: ConversationReplay = serde_json::from_str(json).unwrap();
+
+        assert_eq!(replay.turns.len(), 2);
+        assert_eq!(replay.turns[1].latency_ms, Some(300));
+    }
+}
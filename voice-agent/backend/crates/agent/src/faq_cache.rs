@@ -0,0 +1,192 @@
+//! Cached FAQ Answers
+//!
+//! Pre-computed answers for high-frequency questions ("what documents do I
+//! need"), keyed on detected intent and language. The agent falls back to
+//! this cache when the LLM is unavailable or generation fails, so common
+//! questions still get a useful answer instead of a generic stage reply.
+//!
+//! Answers are loaded from `faq_answers` in the domain's prompts config and
+//! can be warmed further at startup with `insert`. A cached answer is spoken
+//! with a short note that it is general information.
+//!
+//! Language lookup order: exact code, base code ("hi-IN" -> "hi"), English.
+
+use std::collections::HashMap;
+
+use parking_lot::RwLock;
+use voice_agent_config::domain::AgentDomainView;
+
+/// A cached answer for one intent
+#[derive(Debug, Clone, PartialEq)]
+pub struct FaqAnswer {
+    /// Intent the answer was cached for
+    pub intent: String,
+    /// Language of the answer text
+    pub language: String,
+    /// Canned answer
+    pub text: String,
+    /// Low-confidence note spoken after the answer
+    pub note: Option<String>,
+}
+
+impl FaqAnswer {
+    /// Answer followed by its note, as spoken to the user
+    pub fn spoken(&self) -> String {
+        match self.note {
+            Some(ref note) if !note.is_empty() => format!("{} {}", self.text, note),
+            _ => self.text.clone(),
+        }
+    }
+}
+
+/// Canned FAQ answers keyed on intent and language
+#[derive(Debug, Default)]
+pub struct FaqCache {
+    /// intent -> language -> answer
+    answers: RwLock<HashMap<String, HashMap<String, String>>>,
+    /// language -> note appended to cached answers
+    notes: RwLock<HashMap<String, String>>,
+}
+
+impl FaqCache {
+    /// Create an empty cache
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a cache from the domain's `faq_answers` and `faq_cached_notes`
+    pub fn from_view(view: &AgentDomainView) -> Self {
+        let cache = Self::new();
+        cache.load_view(view);
+        cache
+    }
+
+    /// Add the domain's configured answers and notes, replacing existing ones
+    pub fn load_view(&self, view: &AgentDomainView) {
+        {
+            let mut answers = self.answers.write();
+            for (intent, by_language) in view.faq_answers() {
+                answers.entry(intent).or_default().extend(by_language);
+            }
+        }
+        self.notes.write().extend(view.faq_cached_notes());
+    }
+
+    /// Cache an answer for an intent in one language
+    pub fn insert(
+        &self,
+        intent: impl Into<String>,
+        language: impl Into<String>,
+        answer: impl Into<String>,
+    ) {
+        self.answers
+            .write()
+            .entry(intent.into())
+            .or_default()
+            .insert(language.into(), answer.into());
+    }
+
+    /// Set the note appended to cached answers in one language
+    pub fn set_note(&self, language: impl Into<String>, note: impl Into<String>) {
+        self.notes.write().insert(language.into(), note.into());
+    }
+
+    /// Cached answer for an intent, if any
+    pub fn lookup(&self, intent: &str, language: &str) -> Option<FaqAnswer> {
+        let answers = self.answers.read();
+        let by_language = answers.get(intent)?;
+        let (language, text) = Self::candidates(language)
+            .into_iter()
+            .find_map(|lang| by_language.get(lang).map(|text| (lang, text)))?;
+
+        let note = {
+            let notes = self.notes.read();
+            Self::candidates(language)
+                .into_iter()
+                .find_map(|lang| notes.get(lang).cloned())
+                .or_else(|| default_note(language).map(str::to_string))
+        };
+
+        Some(FaqAnswer {
+            intent: intent.to_string(),
+            language: language.to_string(),
+            text: text.clone(),
+            note,
+        })
+    }
+
+    /// Intents with at least one cached answer
+    pub fn intents(&self) -> Vec<String> {
+        self.answers.read().keys().cloned().collect()
+    }
+
+    /// Number of cached intents
+    pub fn len(&self) -> usize {
+        self.answers.read().len()
+    }
+
+    /// Whether no answers are cached
+    pub fn is_empty(&self) -> bool {
+        self.answers.read().is_empty()
+    }
+
+    /// Language codes to try, most specific first
+    fn candidates(language: &str) -> Vec<&str> {
+        let mut candidates = vec![language];
+        if let Some((base, _)) = language.split_once('-') {
+            candidates.push(base);
+        }
+        if !candidates.contains(&"en") {
+            candidates.push("en");
+        }
+        candidates
+    }
+}
+
+/// Built-in note for domains that configure none
+fn default_note(language: &str) -> Option<&'static str> {
+    match language {
+        "en" => Some("I can't reach all my systems right now, so please treat this as general information."),
+        "hi" => Some("Abhi main saare systems tak nahi pahunch pa rahi, isliye ise general jaankari samjhiye."),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lookup_by_intent_and_language() {
+        let cache = FaqCache::new();
+        cache.insert("document_inquiry", "en", "Bring a photo ID.");
+        cache.insert("document_inquiry", "hi", "Photo ID laaiye.");
+
+        let answer = cache.lookup("document_inquiry", "hi-IN").unwrap();
+        assert_eq!(answer.language, "hi");
+        assert_eq!(answer.text, "Photo ID laaiye.");
+        assert!(answer.spoken().starts_with("Photo ID laaiye. "));
+
+        assert!(cache.lookup("interest_rate", "en").is_none());
+    }
+
+    #[test]
+    fn test_falls_back_to_english() {
+        let cache = FaqCache::new();
+        cache.insert("document_inquiry", "en", "Bring a photo ID.");
+
+        let answer = cache.lookup("document_inquiry", "ta").unwrap();
+        assert_eq!(answer.language, "en");
+        assert_eq!(answer.text, "Bring a photo ID.");
+    }
+
+    #[test]
+    fn test_configured_note_overrides_default() {
+        let cache = FaqCache::new();
+        cache.insert("document_inquiry", "en", "Bring a photo ID.");
+        cache.set_note("en", "Please confirm at the branch.");
+
+        let answer = cache.lookup("document_inquiry", "en").unwrap();
+        assert_eq!(answer.spoken(), "Bring a photo ID. Please confirm at the branch.");
+    }
+}
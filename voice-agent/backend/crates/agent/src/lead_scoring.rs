@@ -223,6 +223,7 @@ impl LeadSignals {
             "objections_resolved" => self.objections_resolved,
             "urgency_keywords_count" => self.urgency_keywords_count,
             "conversation_stalled_turns" => self.conversation_stalled_turns,
+            "goals_completed" => self.goals_completed,
             _ => {
                 tracing::warn!(field = %field_name, "Unknown numeric field in classification config");
                 0
@@ -271,6 +272,9 @@ pub struct LeadSignals {
     pub expressed_disinterest: bool,
     pub mentioned_competitor_preference: bool,
     pub conversation_stalled_turns: u32,
+
+    // Goal progress
+    pub goals_completed: u32,
 }
 
 /// Trust level indicator
@@ -382,6 +386,48 @@ pub enum LeadRecommendation {
     LowPriority,
 }
 
+/// Typed action recommended to the agent or supervisor for the next turn
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum NextBestAction {
+    /// Ask for a required slot that is still missing
+    CollectSlot { slot: String },
+    /// Address the objection the customer just raised
+    HandleObjection { objection: String },
+    /// Push to book an appointment
+    OfferAppointment,
+    /// Hand the call to a human
+    Escalate { reason: String },
+    /// Wrap up the call
+    Close,
+    /// Nothing specific to push; keep the conversation going
+    Continue,
+}
+
+/// Next best action with the reason it was chosen
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ActionRecommendation {
+    pub action: NextBestAction,
+    pub rationale: String,
+}
+
+impl ActionRecommendation {
+    fn new(action: NextBestAction, rationale: String) -> Self {
+        Self { action, rationale }
+    }
+}
+
+/// Conversation state the next best action is chosen from
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ActionContext<'a> {
+    /// Current stage ID (e.g. "discovery")
+    pub stage: &'a str,
+    /// Required slots of the current goal that are still missing
+    pub missing_slots: &'a [String],
+    /// Objection detected in the latest customer turn
+    pub objection: Option<&'a str>,
+}
+
 /// Lead Scoring Engine
 pub struct LeadScoringEngine {
     /// Configuration
@@ -741,6 +787,11 @@ impl LeadScoringEngine {
         };
     }
 
+    /// Update the number of conversation goals completed
+    pub fn update_goals_completed(&mut self, count: u32) {
+        self.signals.goals_completed = count;
+    }
+
     /// Mark conversation as stalled (no meaningful progress)
     pub fn mark_stalled(&mut self) {
         self.signals.conversation_stalled_turns += 1;
@@ -786,6 +837,11 @@ impl LeadScoringEngine {
         }
     }
 
+    /// Current classification, without recording a score
+    pub fn classification(&self) -> LeadClassification {
+        self.classify_lead()
+    }
+
     /// P20 FIX: Classify lead using config-driven rules when available
     ///
     /// Priority:
@@ -1202,6 +1258,71 @@ impl LeadScoringEngine {
         }
     }
 
+    /// Recommend the next action from the lead score and conversation state
+    ///
+    /// Priority: escalation, a fresh objection, missing slots, the closing
+    /// stage, then strong buying signals (hot lead or intent to proceed).
+    pub fn next_best_action(
+        &self,
+        score: &LeadScore,
+        context: &ActionContext<'_>,
+    ) -> ActionRecommendation {
+        if let LeadRecommendation::EscalateNow { reason } = &score.recommendation {
+            return ActionRecommendation::new(
+                NextBestAction::Escalate {
+                    reason: reason.clone(),
+                },
+                format!("Lead scoring recommends escalation: {}", reason),
+            );
+        }
+
+        if let Some(objection) = context.objection {
+            return ActionRecommendation::new(
+                NextBestAction::HandleObjection {
+                    objection: objection.to_string(),
+                },
+                format!("Customer raised a '{}' objection", objection),
+            );
+        }
+
+        if let Some(slot) = context.missing_slots.first() {
+            return ActionRecommendation::new(
+                NextBestAction::CollectSlot { slot: slot.clone() },
+                format!(
+                    "Required slot '{}' is missing ({} outstanding)",
+                    slot,
+                    context.missing_slots.len()
+                ),
+            );
+        }
+
+        if matches!(context.stage, "closing" | "farewell") {
+            return ActionRecommendation::new(
+                NextBestAction::Close,
+                format!("Conversation is in the {} stage", context.stage),
+            );
+        }
+
+        let strong_signals = matches!(
+            score.qualification,
+            LeadQualification::Hot | LeadQualification::Qualified
+        ) || self.signals.expressed_intent_to_proceed;
+        if strong_signals {
+            return ActionRecommendation::new(
+                NextBestAction::OfferAppointment,
+                format!(
+                    "Strong buying signals ({:?} lead, score {})",
+                    score.qualification, score.total
+                ),
+            );
+        }
+
+        ActionRecommendation::new(
+            NextBestAction::Continue,
+            format!("{:?} lead with no outstanding slots", score.qualification),
+        )
+    }
+
     /// Get score trend (positive = improving, negative = declining)
     pub fn score_trend(&self) -> i32 {
         if self.score_history.len() < 2 {
@@ -1453,6 +1574,67 @@ mod tests {
         assert!(negative_score < positive_score);
     }
 
+    #[test]
+    fn test_next_best_action() {
+        let mut engine = LeadScoringEngine::new();
+        let score = engine.calculate_score();
+        let missing = vec!["phone_number".to_string()];
+
+        let context = ActionContext {
+            stage: "discovery",
+            missing_slots: &missing,
+            objection: None,
+        };
+        assert_eq!(
+            engine.next_best_action(&score, &context).action,
+            NextBestAction::CollectSlot {
+                slot: "phone_number".to_string()
+            }
+        );
+
+        // A fresh objection takes priority over collecting slots
+        let context = ActionContext {
+            objection: Some("safety"),
+            ..context
+        };
+        assert_eq!(
+            engine.next_best_action(&score, &context).action,
+            NextBestAction::HandleObjection {
+                objection: "safety".to_string()
+            }
+        );
+
+        let context = ActionContext {
+            stage: "closing",
+            ..Default::default()
+        };
+        assert_eq!(
+            engine.next_best_action(&score, &context).action,
+            NextBestAction::Close
+        );
+
+        let context = ActionContext {
+            stage: "presentation",
+            ..Default::default()
+        };
+        assert_eq!(
+            engine.next_best_action(&score, &context).action,
+            NextBestAction::Continue
+        );
+
+        engine.signals_mut().expressed_intent_to_proceed = true;
+        let recommendation = engine.next_best_action(&score, &context);
+        assert_eq!(recommendation.action, NextBestAction::OfferAppointment);
+        assert!(!recommendation.rationale.is_empty());
+
+        engine.signals_mut().requested_human_agent = true;
+        let score = engine.calculate_score();
+        assert!(matches!(
+            engine.next_best_action(&score, &context).action,
+            NextBestAction::Escalate { .. }
+        ));
+    }
+
     #[test]
     fn test_score_trend() {
         let mut engine = LeadScoringEngine::new();
@@ -15,6 +15,12 @@
 pub mod agent;
 pub mod agent_config;
 pub mod conversation;
+// Recording consent answer detection
+pub mod consent;
+// Clarifying questions for near-tied intents
+pub mod disambiguation;
+// Cached FAQ answers for when the LLM is unavailable
+pub mod faq_cache;
 pub mod memory;
 // Legacy memory module for backward compatibility
 pub mod memory_legacy;
@@ -32,6 +38,13 @@ pub mod fsm_adapter;
 pub mod dst;
 // Phase 10: Lead Scoring for Sales Conversion
 pub mod lead_scoring;
+// Offline conversation quality evaluation
+pub mod eval;
+// Scripted dialogue test harness
+#[cfg(any(test, feature = "testing"))]
+pub mod testing;
+// Structured per-session event recording (JSONL)
+pub mod recorder;
 
 // P1-2 FIX: Re-export intent module from text_processing for backward compatibility
 pub mod intent {
@@ -47,6 +60,9 @@ pub use conversation::{
     Conversation, ConversationConfig, ConversationContext, ConversationEvent,
     ConversationState, EndReason, ComplianceStatus, ConsentMethod, AiDisclosure, ConsentRecord,
 };
+pub use consent::detect_consent_response;
+pub use disambiguation::{IntentDisambiguator, PendingDisambiguation};
+pub use faq_cache::{FaqAnswer, FaqCache};
 pub use memory::MemoryConfig;
 // Context compression types
 pub use memory::{CompressionLevel, CompressionMethod, CompressionStats};
@@ -64,11 +80,18 @@ pub use voice_agent_text_processing::intent::{
     DetectedIntent, Intent, IntentDetector, Slot, SlotType,
 };
 // Primary agent export
-pub use agent::DomainAgent;
+pub use agent::{
+    select_tts_style, DomainAgent, EscalationLimiter, EscalationSeverity, FilledSlot,
+    GoalProgress, OpeningState, PendingToolCall, SessionSummary, SlotChange, SlotProgress,
+    SummarySource, ToolCallDebug, TurnDebug, TurnLatency, TurnRoute,
+};
 // P1-SRP: Export agent config types
 pub use agent_config::{
-    AgentConfig, AgentEvent, PersonaTraits, SmallModelConfig, SpeculativeDecodingConfig,
-    ToolDefaults, is_small_model,
+    AgentConfig, AgentEvent, ContextOverflowConfig, EscalationConfig, InstructionRetryConfig,
+    KnowledgeGapMode, KnowledgeGuardConfig, LanguageSwitchConfig, OutOfScopeConfig,
+    PersonaTraits, RecapConfig, RoutingConfig, SamplingConfig, SessionSummaryConfig,
+    SmallModelConfig, SpeculativeDecodingConfig, ToolDefaults, TranslationGateConfig,
+    is_small_model,
 };
 // Phase 2: PersuasionStrategy trait for domain-agnostic persuasion handling
 pub use persuasion::{
@@ -77,7 +100,9 @@ pub use persuasion::{
     // Config-driven objection handling
     ObjectionDetector, objection_ids,
 };
-pub use voice_session::{VoiceSession, VoiceSessionConfig, VoiceSessionEvent, VoiceSessionState};
+pub use voice_session::{
+    IdleAction, IdleMonitor, VoiceSession, VoiceSessionConfig, VoiceSessionEvent, VoiceSessionState,
+};
 // P1-1 FIX: Export Agent traits
 pub use traits::{Agent, PersonalizableAgent, PrefetchingAgent};
 // P3 FIX: Export FSM adapter
@@ -93,9 +118,16 @@ pub use dst::{
 };
 // Phase 10: Export Lead Scoring types
 pub use lead_scoring::{
-    EscalationTrigger, LeadClassification, LeadQualification, LeadRecommendation, LeadScore,
-    LeadScoringConfig, LeadScoringEngine, LeadSignals, ScoreBreakdown, ScoreWeights, TrustLevel,
+    ActionContext, ActionRecommendation, EscalationTrigger, LeadClassification, LeadQualification,
+    LeadRecommendation, LeadScore, LeadScoringConfig, LeadScoringEngine, LeadSignals,
+    NextBestAction, ScoreBreakdown, ScoreWeights, TrustLevel,
+};
+// Conversation quality evaluation exports
+pub use eval::{
+    ConversationEvaluator, ConversationReplay, EscalationAssessment, EvaluationReport,
+    EvaluatorConfig, ReplayTurn,
 };
+pub use recorder::{ConversationRecorder, RecordingSink};
 
 // Re-export transport types for convenience
 pub use voice_agent_transport::{
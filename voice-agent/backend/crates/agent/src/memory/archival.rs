@@ -294,16 +294,40 @@ impl ArchivalMemory {
         results
     }
 
-    /// Search with embedding vector (for production use)
+    /// Search with embedding vector
+    ///
+    /// Scores are cosine similarity, held to `min_similarity`; notes stored
+    /// without an embedding are skipped.
     pub fn search_by_embedding(
         &self,
-        _embedding: &[f32],
+        embedding: &[f32],
         top_k: Option<usize>,
     ) -> Vec<ArchivalSearchResult> {
-        // In production, this would query Qdrant with the embedding vector
-        // For now, return empty (no embeddings stored in test mode)
-        let _ = top_k;
-        Vec::new()
+        let top_k = top_k.unwrap_or(self.config.default_top_k);
+        let memories = self.memories.read();
+
+        let mut results: Vec<ArchivalSearchResult> = memories
+            .iter()
+            .filter_map(|note| {
+                let score = cosine_similarity(embedding, note.embedding.as_deref()?);
+                Some(ArchivalSearchResult {
+                    note: note.clone(),
+                    score,
+                    via_link: false,
+                })
+            })
+            .filter(|r| r.score >= self.config.min_similarity)
+            .collect();
+
+        results.sort_by(|a, b| b.score.total_cmp(&a.score));
+        results.truncate(top_k);
+
+        drop(memories);
+        for result in &results {
+            self.mark_accessed(result.note.id);
+        }
+
+        results
     }
 
     /// Search within a specific session
@@ -416,6 +440,22 @@ impl ArchivalMemory {
         self.memories.read().is_empty()
     }
 
+    /// Every note, oldest first, with its keyword relevance to `query`
+    ///
+    /// Unlike `search`, nothing is cut by `min_similarity` or `top_k` and no
+    /// note is marked accessed, so callers can rank on more than relevance
+    /// and mark the notes they use.
+    pub fn score_notes(&self, query: &str) -> Vec<(MemoryNote, f32)> {
+        let mut scored: Vec<(MemoryNote, f32)> = self
+            .memories
+            .read()
+            .iter()
+            .map(|note| (note.clone(), self.compute_keyword_score(query, note)))
+            .collect();
+        scored.sort_by_key(|(note, _)| note.created_at);
+        scored
+    }
+
     // =========================================================================
     // Private Helpers
     // =========================================================================
@@ -506,7 +546,7 @@ impl ArchivalMemory {
     }
 
     /// Mark memory as accessed
-    fn mark_accessed(&self, id: Uuid) {
+    pub(crate) fn mark_accessed(&self, id: Uuid) {
         let mut memories = self.memories.write();
         if let Some(note) = memories.iter_mut().find(|n| n.id == id) {
             note.mark_accessed();
@@ -546,6 +586,18 @@ impl Default for ArchivalMemory {
     }
 }
 
+/// Cosine similarity of two embeddings; 0.0 if either is all zeros
+pub(crate) fn cosine_similarity(a: &[f32], b: &[f32]) -> f32 {
+    let dot: f32 = a.iter().zip(b).map(|(x, y)| x * y).sum();
+    let norm_a = a.iter().map(|x| x * x).sum::<f32>().sqrt();
+    let norm_b = b.iter().map(|x| x * x).sum::<f32>().sqrt();
+    if norm_a == 0.0 || norm_b == 0.0 {
+        0.0
+    } else {
+        dot / (norm_a * norm_b)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -576,6 +628,25 @@ mod tests {
         assert!(results[0].score > 0.0);
     }
 
+    #[test]
+    fn test_search_by_embedding() {
+        let archival = ArchivalMemory::default();
+        let gold = MemoryNote::new("session-1", "Owns 50g of gold", MemoryType::CustomerFact);
+        let city = MemoryNote::new("session-1", "Lives in Pune", MemoryType::CustomerFact);
+        let bare = MemoryNote::new("session-1", "No embedding", MemoryType::CustomerFact);
+        archival.insert(gold.with_embedding(vec![1.0, 0.2]));
+        archival.insert(city.with_embedding(vec![0.0, 1.0]));
+        archival.insert(bare);
+
+        // Below min_similarity (0.5) and unembedded notes are left out
+        let results = archival.search_by_embedding(&[1.0, 0.0], None);
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].note.content, "Owns 50g of gold");
+        assert!(results[0].score > 0.9);
+        assert_eq!(results[0].note.access_count, 0);
+        assert_eq!(archival.get(results[0].note.id).unwrap().access_count, 1);
+    }
+
     #[test]
     fn test_session_search() {
         let archival = ArchivalMemory::default();
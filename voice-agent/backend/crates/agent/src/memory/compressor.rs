@@ -346,6 +346,25 @@ impl ExtractiveCompressor {
         &self,
         turns: &[ConversationTurn],
         dst_state: Option<&str>,
+    ) -> (String, ExtractionStats) {
+        self.compress_with_limits(
+            turns,
+            dst_state,
+            self.config.max_tokens,
+            self.config.max_sentences,
+        )
+    }
+
+    /// Compress with token and sentence limits overriding the configured ones
+    ///
+    /// Used to retry at a less aggressive level when compression drops
+    /// entities that must be kept.
+    pub fn compress_with_limits(
+        &self,
+        turns: &[ConversationTurn],
+        dst_state: Option<&str>,
+        max_tokens: usize,
+        max_sentences: usize,
     ) -> (String, ExtractionStats) {
         if turns.is_empty() {
             return (String::new(), ExtractionStats::default());
@@ -359,7 +378,7 @@ impl ExtractiveCompressor {
 
         // 3. Select sentences within token budget
         let mut selected = Vec::new();
-        let mut token_budget = self.config.max_tokens;
+        let mut token_budget = max_tokens;
 
         // Reserve tokens for DST state if enabled
         let dst_summary = if self.config.include_dst_summary {
@@ -376,7 +395,7 @@ impl ExtractiveCompressor {
         // Select top sentences within budget
         let mut seen_entities: HashSet<String> = HashSet::new();
         for sentence in &scored_sentences {
-            if selected.len() >= self.config.max_sentences {
+            if selected.len() >= max_sentences {
                 break;
             }
             if sentence.tokens > token_budget {
@@ -50,6 +50,7 @@ pub use core::{
 };
 pub use recall::{
     ConversationTurn, RecallMemory, RecallMemoryConfig, RecallSearchResult, TurnRole,
+    INTERRUPTED_MARKER,
 };
 
 use parking_lot::RwLock;
@@ -57,6 +58,8 @@ use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 use uuid::Uuid;
 use voice_agent_core::{GenerateRequest, LanguageModel};
+use voice_agent_rag::compressor::{compression_prompt, COMPRESSION_SYSTEM_PROMPT};
+use voice_agent_text_processing::entities::{EntityExtractor, ExtractedEntities};
 
 /// Unified memory configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -82,6 +85,42 @@ pub struct AgenticMemoryConfig {
     /// Extractive compressor configuration (RECOMP-style)
     #[serde(default)]
     pub extractive: ExtractiveCompressorConfig,
+    /// Entities that must survive compression (`ExtractedEntities` field names)
+    #[serde(default = "default_must_keep_entities")]
+    pub must_keep_entities: Vec<String>,
+    /// Must-keep entities whose loss triggers a retry at a less aggressive level
+    #[serde(default = "default_critical_entities")]
+    pub critical_entities: Vec<String>,
+    /// Weight of recency when ranking recalled and archival memories for
+    /// query context
+    #[serde(default)]
+    pub recency_weight: f32,
+    /// Weight of relevance to the query in the same ranking
+    #[serde(default = "default_relevance_weight")]
+    pub relevance_weight: f32,
+}
+
+fn default_must_keep_entities() -> Vec<String> {
+    [
+        "amount",
+        "customer_name",
+        "collateral_weight",
+        "collateral_quality",
+        "interest_rate",
+        "tenure",
+        "current_provider",
+    ]
+    .iter()
+    .map(|s| s.to_string())
+    .collect()
+}
+
+fn default_critical_entities() -> Vec<String> {
+    vec!["amount".to_string(), "customer_name".to_string()]
+}
+
+fn default_relevance_weight() -> f32 {
+    1.0
 }
 
 impl Default for AgenticMemoryConfig {
@@ -96,6 +135,23 @@ impl Default for AgenticMemoryConfig {
             auto_summarize: true,
             use_extractive_compression: false, // Default to LLM, enable for small models
             extractive: ExtractiveCompressorConfig::default(),
+            must_keep_entities: default_must_keep_entities(),
+            critical_entities: default_critical_entities(),
+            recency_weight: 0.0,
+            relevance_weight: default_relevance_weight(),
+        }
+    }
+}
+
+impl From<&voice_agent_config::MemoryConfig> for AgenticMemoryConfig {
+    fn from(settings: &voice_agent_config::MemoryConfig) -> Self {
+        Self {
+            max_context_tokens: settings.max_context_tokens,
+            high_watermark_tokens: settings.high_watermark_tokens,
+            low_watermark_tokens: settings.low_watermark_tokens,
+            recency_weight: settings.recency_weight,
+            relevance_weight: settings.relevance_weight,
+            ..Default::default()
         }
     }
 }
@@ -134,6 +190,10 @@ pub struct CompressionStats {
     pub compression_ratio: f32,
     /// Compression method used
     pub method: CompressionMethod,
+    /// Must-keep entities from the original turns missing in the compressed output
+    pub entities_lost: usize,
+    /// Compression level that produced the output (after any fallback)
+    pub level: CompressionLevel,
 }
 
 impl CompressionStats {
@@ -151,6 +211,8 @@ impl CompressionStats {
             turns_intact: intact,
             compression_ratio: ratio,
             method: CompressionMethod::LlmSummarization,
+            entities_lost: 0,
+            level: CompressionLevel::default(),
         }
     }
 
@@ -208,6 +270,26 @@ impl CompressionLevel {
             CompressionLevel::Maximum => 2,
         }
     }
+
+    /// Scale applied to summary budgets (Balanced = configured budget)
+    pub fn budget_factor(&self) -> f32 {
+        match self {
+            CompressionLevel::Conservative => 2.0,
+            CompressionLevel::Balanced => 1.0,
+            CompressionLevel::Aggressive => 0.5,
+            CompressionLevel::Maximum => 0.25,
+        }
+    }
+
+    /// Next less aggressive level, or None if already conservative
+    pub fn less_aggressive(&self) -> Option<CompressionLevel> {
+        match self {
+            CompressionLevel::Conservative => None,
+            CompressionLevel::Balanced => Some(CompressionLevel::Conservative),
+            CompressionLevel::Aggressive => Some(CompressionLevel::Balanced),
+            CompressionLevel::Maximum => Some(CompressionLevel::Aggressive),
+        }
+    }
 }
 
 /// Agentic Memory System
@@ -236,6 +318,17 @@ pub struct AgenticMemory {
     /// P19 FIX: Config-driven slot display labels (e.g., "gold_weight" -> "Gold Weight")
     /// Loaded from domain config, empty if no config provided
     slot_display_labels: std::collections::HashMap<String, String>,
+    /// Starting aggressiveness for compaction
+    compression_level: CompressionLevel,
+    /// Extracts must-keep entities to measure compression retention
+    entity_extractor: EntityExtractor,
+}
+
+/// Summary produced by retention-guarded compression
+struct RetainedSummary {
+    text: String,
+    level: CompressionLevel,
+    entities_lost: usize,
 }
 
 impl AgenticMemory {
@@ -257,6 +350,8 @@ impl AgenticMemory {
             competitor_names: Vec::new(),
             // P19 FIX: Empty by default - use from_view() for config-driven display labels
             slot_display_labels: std::collections::HashMap::new(),
+            compression_level: CompressionLevel::default(),
+            entity_extractor: EntityExtractor::new(),
         }
     }
 
@@ -283,6 +378,7 @@ impl AgenticMemory {
         // P21 FIX: Load ALL slot display labels from config (no hardcoded slot names)
         // This replaces the hardcoded list with config-driven labels
         let slot_display_labels = view.all_slot_display_labels();
+        let entity_extractor = EntityExtractor::with_providers(competitor_names.clone());
 
         Self {
             core: CoreMemory::new(config.core.clone()),
@@ -294,6 +390,8 @@ impl AgenticMemory {
             llm: RwLock::new(None),
             competitor_names,
             slot_display_labels,
+            compression_level: CompressionLevel::default(),
+            entity_extractor,
         }
     }
 
@@ -387,6 +485,11 @@ impl AgenticMemory {
         self.recall.add_turn(turn)
     }
 
+    /// Record that the latest response was interrupted after `spoken_text`
+    pub fn mark_last_assistant_interrupted(&self, spoken_text: &str) -> bool {
+        self.recall.mark_last_assistant_interrupted(spoken_text)
+    }
+
     /// Get recent conversation (FIFO)
     pub fn get_recent_turns(&self) -> Vec<ConversationTurn> {
         self.recall.get_fifo()
@@ -553,16 +656,137 @@ impl AgenticMemory {
         Ok(())
     }
 
+    /// Summarize turns, guarding against loss of must-keep entities
+    async fn summarize_turns(&self, turns: &[ConversationTurn]) -> Result<String, String> {
+        Ok(self.summarize_with_retention(turns).await?.text)
+    }
+
+    /// Summarize at the configured level, retrying less aggressively while
+    /// critical entities (e.g. loan amount, customer name) are dropped
+    async fn summarize_with_retention(
+        &self,
+        turns: &[ConversationTurn],
+    ) -> Result<RetainedSummary, String> {
+        let must_keep = self.must_keep_entities(turns);
+        let mut level = self.compression_level;
+        // Rule-based summaries ignore the level; retrying would change nothing
+        let level_sensitive = self.config.use_extractive_compression || self.llm.read().is_some();
+
+        loop {
+            let text = self.summarize_turns_at(turns, level).await?;
+            let lost: Vec<&str> = must_keep
+                .iter()
+                .filter(|(_, forms)| !Self::is_retained(&text, forms))
+                .map(|(kind, _)| *kind)
+                .collect();
+            let critical_lost = lost
+                .iter()
+                .any(|kind| self.config.critical_entities.iter().any(|c| c == kind));
+
+            if critical_lost && level_sensitive {
+                if let Some(next) = level.less_aggressive() {
+                    tracing::debug!(
+                        from = ?level,
+                        to = ?next,
+                        lost = ?lost,
+                        "Compression dropped critical entities, retrying less aggressively"
+                    );
+                    level = next;
+                    continue;
+                }
+            }
+
+            if !lost.is_empty() {
+                tracing::warn!(level = ?level, lost = ?lost, "Compression lost entities");
+            }
+            return Ok(RetainedSummary {
+                text,
+                level,
+                entities_lost: lost.len(),
+            });
+        }
+    }
+
+    /// Must-keep entities present in the turns, with accepted surface forms
+    fn must_keep_entities(&self, turns: &[ConversationTurn]) -> Vec<(&'static str, Vec<String>)> {
+        let mut merged = ExtractedEntities::default();
+        for turn in turns {
+            let mut extracted = self.entity_extractor.extract(&turn.content);
+            // A bare number (no unit or currency marker) is too ambiguous to track
+            if let Some(amount) = &extracted.amount {
+                if amount.text.trim().chars().all(|c| c.is_ascii_digit() || c == '.') {
+                    extracted.amount = None;
+                }
+            }
+            merged.merge(&extracted);
+        }
+
+        let mut entities: Vec<(&'static str, Vec<String>)> = Vec::new();
+        if let Some(amount) = merged.amount {
+            entities.push(("amount", vec![amount.text.trim().to_string(), amount.as_rupees()]));
+        }
+        if let Some(name) = merged.customer_name {
+            entities.push(("customer_name", vec![name]));
+        }
+        if let Some(weight) = merged.collateral_weight {
+            entities.push(("collateral_weight", vec![weight.text]));
+        }
+        if let Some(quality) = merged.collateral_quality {
+            entities.push((
+                "collateral_quality",
+                vec![format!("{}k", quality), format!("{} karat", quality)],
+            ));
+        }
+        if let Some(rate) = merged.interest_rate {
+            entities.push(("interest_rate", vec![rate.text, format!("{}%", rate.value)]));
+        }
+        if let Some(tenure) = merged.tenure {
+            entities.push(("tenure", vec![tenure.text]));
+        }
+        if let Some(provider) = merged.current_provider {
+            entities.push(("current_provider", vec![provider]));
+        }
+
+        entities.retain(|(kind, _)| self.config.must_keep_entities.iter().any(|k| k == kind));
+        entities
+    }
+
+    /// Whether any surface form of an entity appears in the text
+    fn is_retained(text: &str, forms: &[String]) -> bool {
+        let normalize = |s: &str| s.to_lowercase().split_whitespace().collect::<Vec<_>>().join(" ");
+        let text = normalize(text);
+        forms
+            .iter()
+            .map(|form| normalize(form))
+            .any(|form| !form.is_empty() && text.contains(&form))
+    }
+
     /// Summarize turns using LLM with enhanced prompts
     ///
     /// Uses LLMLingua-inspired compression techniques:
     /// - Focus on key entities and facts
     /// - Preserve customer-stated information
     /// - Maintain conversation flow markers
-    async fn summarize_turns(&self, turns: &[ConversationTurn]) -> Result<String, String> {
+    ///
+    /// `level` scales the summary budget relative to the configured one.
+    async fn summarize_turns_at(
+        &self,
+        turns: &[ConversationTurn],
+        level: CompressionLevel,
+    ) -> Result<String, String> {
+        let factor = level.budget_factor();
+
         // Use RECOMP-style extractive compression for small models
         if self.config.use_extractive_compression {
-            let (compressed, stats) = self.extractive_compressor.compress(turns, None);
+            let max_tokens = (self.config.extractive.max_tokens as f32 * factor) as usize;
+            let max_sentences =
+                ((self.config.extractive.max_sentences as f32 * factor).ceil() as usize).max(1);
+            let (compressed, stats) = self.extractive_compressor.compress_with_limits(
+                turns,
+                None,
+                max_tokens,
+                max_sentences,
+            );
             tracing::debug!(
                 compression_ratio = stats.compression_ratio,
                 sentences_selected = stats.selected_sentences,
@@ -601,27 +825,9 @@ impl AgenticMemory {
             .collect::<Vec<_>>()
             .join("\n");
 
-        // P21 FIX: Domain-agnostic summarization prompt
-        // Enhanced summarization prompt inspired by LLMLingua research
-        let prompt = format!(
-            r#"Compress this conversation into a concise summary.
-
-RULES:
-1. KEEP: Customer name, asset details, loan amount, interest rates, competitor names
-2. KEEP: Customer concerns, objections, and preferences
-3. REMOVE: Greetings, filler words, repeated information
-4. FORMAT: Use key-value pairs where possible (e.g., "Name: Rahul, Amount: 5 lakh")
-
-Conversation:
-{}
-
-Compressed Summary (max 100 words):"#,
-            conversation
-        );
-
-        let request = GenerateRequest::new(
-            "You are a context compression assistant. Extract and preserve only essential information."
-        ).with_user_message(prompt);
+        let max_words = (100.0 * factor) as usize;
+        let request = GenerateRequest::new(COMPRESSION_SYSTEM_PROMPT)
+            .with_user_message(compression_prompt(&conversation, max_words));
 
         match llm.generate(request).await {
             Ok(response) => Ok(response.text.trim().to_string()),
@@ -632,6 +838,11 @@ Compressed Summary (max 100 words):"#,
         }
     }
 
+    /// Rule-based summary of every turn in the session (no LLM needed)
+    pub fn rule_based_session_summary(&self) -> String {
+        self.rule_based_summary(&self.get_all_turns())
+    }
+
     /// Rule-based summarization fallback (no LLM needed)
     ///
     /// Extracts key information using pattern matching:
@@ -835,6 +1046,12 @@ Compressed Summary (max 100 words):"#,
     /// 2. Include recent FIFO turns
     /// 3. Search archival/recall for query-relevant memories
     /// 4. Inject only relevant additional context
+    ///
+    /// All archival and earlier recall memories are ranked by a blend of
+    /// recency and relevance to the query, weighted by `recency_weight` and
+    /// `relevance_weight`, before the best are taken. Archival notes must
+    /// reach the archival `min_similarity` on that blend. The default ranks by
+    /// relevance alone.
     pub fn get_context_for_query(&self, query: &str, max_tokens: usize) -> String {
         let mut context = String::new();
         let mut used_tokens = 0;
@@ -861,14 +1078,22 @@ Compressed Summary (max 100 words):"#,
         // 3. Query-relevant archival memories (if space allows)
         let remaining_tokens = max_tokens.saturating_sub(used_tokens);
         if remaining_tokens > 100 {
-            let archival_results = self.archival_memory_search(query, Some(3));
+            let archival_notes: Vec<_> = self
+                .rank_by_recency_and_relevance(
+                    self.archival.score_notes(query),
+                    self.config.archival.min_similarity,
+                )
+                .into_iter()
+                .take(3)
+                .collect();
 
-            if !archival_results.is_empty() {
+            if !archival_notes.is_empty() {
                 let mut archival_context = String::new();
                 let mut archival_tokens = 0;
 
-                for result in archival_results {
-                    let note_text = result.note.format_for_context();
+                for note in archival_notes {
+                    self.archival.mark_accessed(note.id);
+                    let note_text = note.format_for_context();
                     let note_tokens = note_text.len() / 4;
 
                     if archival_tokens + note_tokens <= remaining_tokens / 2 {
@@ -892,19 +1117,24 @@ Compressed Summary (max 100 words):"#,
         if remaining_tokens > 100 {
             // Exclude FIFO turns (already included)
             let fifo_ids: std::collections::HashSet<_> = fifo.iter().map(|t| t.id).collect();
-            let recall_results = self.conversation_search(query, Some(3));
+            let earlier_turns = self
+                .recall
+                .score_turns(query)
+                .into_iter()
+                .filter(|(turn, _)| !fifo_ids.contains(&turn.id));
 
-            let relevant_turns: Vec<_> = recall_results
+            let relevant_turns: Vec<_> = self
+                .rank_by_recency_and_relevance(earlier_turns, 0.0)
                 .into_iter()
-                .filter(|r| !fifo_ids.contains(&r.turn.id))
+                .take(3)
                 .collect();
 
             if !relevant_turns.is_empty() {
                 let mut history_context = String::new();
                 let mut history_tokens = 0;
 
-                for result in relevant_turns {
-                    let turn_text = result.turn.format_for_context();
+                for turn in relevant_turns {
+                    let turn_text = turn.format_for_context();
                     let turn_tokens = turn_text.len() / 4;
 
                     if history_tokens + turn_tokens <= remaining_tokens {
@@ -924,6 +1154,38 @@ Compressed Summary (max 100 words):"#,
         context
     }
 
+    /// Rank memories, given oldest first with their relevance, best first
+    ///
+    /// Recency runs from 1/n for the oldest to 1.0 for the newest. The blend
+    /// is normalized by the total weight; memories whose blended score is
+    /// zero or below `min_score` are dropped.
+    fn rank_by_recency_and_relevance<T>(
+        &self,
+        candidates: impl IntoIterator<Item = (T, f32)>,
+        min_score: f32,
+    ) -> Vec<T> {
+        let total_weight = self.config.recency_weight + self.config.relevance_weight;
+        if total_weight <= 0.0 {
+            return Vec::new();
+        }
+        let candidates: Vec<_> = candidates.into_iter().collect();
+        let count = candidates.len();
+        let mut ranked: Vec<(T, f32)> = candidates
+            .into_iter()
+            .enumerate()
+            .map(|(i, (memory, relevance))| {
+                let recency = (i + 1) as f32 / count as f32;
+                let score = (self.config.recency_weight * recency
+                    + self.config.relevance_weight * relevance)
+                    / total_weight;
+                (memory, score)
+            })
+            .filter(|(_, score)| *score > 0.0 && *score >= min_score)
+            .collect();
+        ranked.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+        ranked.into_iter().map(|(memory, _)| memory).collect()
+    }
+
     /// Compact with tracking and return compression stats
     pub async fn compact_with_stats(&self) -> Result<CompressionStats, String> {
         let pending = self.recall.get_pending_summarization();
@@ -936,7 +1198,8 @@ Compressed Summary (max 100 words):"#,
         let original_tokens: usize = pending.iter().map(|t| t.estimated_tokens).sum();
 
         // Summarize
-        let summary = self.summarize_turns(&pending).await?;
+        let retained = self.summarize_with_retention(&pending).await?;
+        let summary = retained.text;
         let compressed_tokens = summary.len() / 4;
 
         // Store summary in archival
@@ -946,18 +1209,22 @@ Compressed Summary (max 100 words):"#,
 
         self.archival.insert(note);
 
-        let stats = CompressionStats::new(
+        let mut stats = CompressionStats::new(
             original_tokens,
             compressed_tokens,
             pending.len(),
             self.recall.get_fifo().len(),
         );
+        stats.entities_lost = retained.entities_lost;
+        stats.level = retained.level;
 
         tracing::debug!(
             turns = pending.len(),
             original_tokens = original_tokens,
             compressed_tokens = compressed_tokens,
             ratio = stats.compression_ratio,
+            entities_lost = stats.entities_lost,
+            level = ?stats.level,
             "Compacted conversation with {}x compression",
             stats.compression_ratio
         );
@@ -966,11 +1233,12 @@ Compressed Summary (max 100 words):"#,
     }
 
     /// Set compression level for automatic compaction
+    ///
+    /// Compaction starts at this level and falls back to less aggressive
+    /// levels if critical entities would be lost.
     pub fn set_compression_level(&mut self, level: CompressionLevel) {
-        // Update recall memory FIFO size based on compression level
-        // Note: This requires mutable access, which is why we have &mut self
-        let _ = level; // Used to determine aggressiveness
-        // The actual implementation would need RecallMemoryConfig to be mutable
+        // Note: Recall memory FIFO size is not adjusted; RecallMemoryConfig is fixed at creation
+        self.compression_level = level;
         tracing::debug!("Compression level set to {:?}", level);
     }
 
@@ -1128,6 +1396,98 @@ mod tests {
         assert!(CompressionLevel::Maximum.target_ratio() > CompressionLevel::Conservative.target_ratio());
     }
 
+    #[test]
+    fn test_compression_level_fallback_order() {
+        assert_eq!(CompressionLevel::Maximum.less_aggressive(), Some(CompressionLevel::Aggressive));
+        assert_eq!(CompressionLevel::Aggressive.less_aggressive(), Some(CompressionLevel::Balanced));
+        assert_eq!(CompressionLevel::Balanced.less_aggressive(), Some(CompressionLevel::Conservative));
+        assert_eq!(CompressionLevel::Conservative.less_aggressive(), None);
+        assert_eq!(CompressionLevel::Balanced.budget_factor(), 1.0);
+    }
+
+    use futures::Stream;
+    use std::pin::Pin;
+    use voice_agent_core::{GenerateResponse, StreamChunk, ToolDefinition};
+
+    /// Summarizer that only keeps the loan amount when given a 200-word budget
+    struct BudgetSensitiveLlm;
+
+    #[async_trait::async_trait]
+    impl LanguageModel for BudgetSensitiveLlm {
+        async fn generate(
+            &self,
+            request: GenerateRequest,
+        ) -> voice_agent_core::Result<GenerateResponse> {
+            let prompt = request.messages.last().map(|m| m.content.as_str()).unwrap_or("");
+            let text = if prompt.contains("(max 200 words)") {
+                "Name: Rahul, Amount: 5 lakh, wants a gold loan"
+            } else {
+                "Name: Rahul, wants a gold loan"
+            };
+            Ok(GenerateResponse::text(text))
+        }
+
+        fn generate_stream<'a>(
+            &'a self,
+            _request: GenerateRequest,
+        ) -> Pin<Box<dyn Stream<Item = voice_agent_core::Result<StreamChunk>> + Send + 'a>> {
+            Box::pin(futures::stream::empty())
+        }
+
+        async fn generate_with_tools(
+            &self,
+            request: GenerateRequest,
+            _tools: &[ToolDefinition],
+        ) -> voice_agent_core::Result<GenerateResponse> {
+            self.generate(request).await
+        }
+
+        async fn is_available(&self) -> bool {
+            true
+        }
+
+        fn model_name(&self) -> &str {
+            "budget-sensitive-mock"
+        }
+    }
+
+    fn memory_with_level(level: CompressionLevel) -> AgenticMemory {
+        let mut memory = AgenticMemory::with_session("test-session");
+        memory.set_compression_level(level);
+        memory.set_llm(Arc::new(BudgetSensitiveLlm));
+        memory
+    }
+
+    #[tokio::test]
+    async fn test_dropped_amount_falls_back_to_conservative() {
+        let memory = memory_with_level(CompressionLevel::Aggressive);
+        let turns = vec![
+            ConversationTurn::new(TurnRole::User, "My name is Rahul"),
+            ConversationTurn::new(TurnRole::User, "I need 5 lakh for my business"),
+        ];
+
+        let retained = memory.summarize_with_retention(&turns).await.unwrap();
+
+        assert_eq!(retained.level, CompressionLevel::Conservative);
+        assert_eq!(retained.entities_lost, 0);
+        assert!(retained.text.contains("5 lakh"), "{}", retained.text);
+    }
+
+    #[tokio::test]
+    async fn test_non_critical_loss_is_counted_without_fallback() {
+        let memory = memory_with_level(CompressionLevel::Conservative);
+        let turns = vec![
+            ConversationTurn::new(TurnRole::User, "My name is Rahul"),
+            ConversationTurn::new(TurnRole::User, "I need 5 lakh for 12 months"),
+        ];
+
+        let retained = memory.summarize_with_retention(&turns).await.unwrap();
+
+        // Tenure is must-keep but not critical
+        assert_eq!(retained.level, CompressionLevel::Conservative);
+        assert_eq!(retained.entities_lost, 1);
+    }
+
     #[test]
     fn test_rule_based_summary_with_entities() {
         let memory = AgenticMemory::with_session("test-session");
@@ -1200,6 +1560,85 @@ mod tests {
         assert!(context.contains("gold") || context.contains("Gold"));
     }
 
+    /// Memory with an old turn about the gold chain, later small talk, and a
+    /// two-turn FIFO
+    fn memory_with_weights(recency_weight: f32, relevance_weight: f32) -> AgenticMemory {
+        let mut config = AgenticMemoryConfig::default();
+        config.recall.fifo_size = 2;
+        config.recall.summarization_threshold = 50;
+        config.recency_weight = recency_weight;
+        config.relevance_weight = relevance_weight;
+        let memory = AgenticMemory::new(config, "test-session");
+
+        memory.add_user_turn("My gold chain weighs 40 grams");
+        for i in 0..6 {
+            memory.add_user_turn(&format!("Small talk number {}", i));
+        }
+        memory.add_user_turn("Yes, please continue");
+        memory.add_assistant_turn("Let me check the details");
+        memory
+    }
+
+    #[test]
+    fn test_recency_weight_prefers_recent_turns() {
+        let memory = memory_with_weights(1.0, 0.0);
+
+        let context = memory.get_context_for_query("gold chain grams", 1000);
+        assert!(context.contains("Let me check the details"));
+        assert!(context.contains("Small talk number 5"));
+        assert!(context.contains("Small talk number 3"));
+        assert!(!context.contains("Small talk number 2"));
+        assert!(!context.contains("40 grams"));
+    }
+
+    #[test]
+    fn test_recency_weight_ranks_every_archival_note() {
+        let mut config = AgenticMemoryConfig::default();
+        // A search would stop at the single most relevant note
+        config.archival.default_top_k = 1;
+        config.recency_weight = 1.0;
+        let memory = AgenticMemory::new(config, "test-session");
+        memory.archival_memory_insert(
+            "Gold loan rates fell last month",
+            MemoryType::DomainKnowledge,
+        );
+        memory.archival_memory_insert("Customer prefers Hindi", MemoryType::Preference);
+        memory.archival_memory_insert("Customer visits the Pune branch", MemoryType::CustomerFact);
+
+        let context = memory.get_context_for_query("gold loan rates", 1000);
+        let background = context.split("## Relevant Background").nth(1).unwrap();
+        assert!(background.contains("Gold loan rates fell"));
+        // The newest note reaches min_similarity on the blend alone
+        assert!(background.contains("Pune branch"));
+        assert!(!background.contains("prefers Hindi"));
+    }
+
+    #[test]
+    fn test_memory_weights_from_settings() {
+        let settings: voice_agent_config::MemoryConfig =
+            serde_json::from_value(serde_json::json!({ "recency_weight": 0.3 })).unwrap();
+
+        let config = AgenticMemoryConfig::from(&settings);
+        assert_eq!(config.recency_weight, 0.3);
+        assert_eq!(config.relevance_weight, 1.0);
+        assert_eq!(config.max_context_tokens, settings.max_context_tokens);
+    }
+
+    #[test]
+    fn test_relevance_weight_pulls_in_older_turn() {
+        let memory = memory_with_weights(0.2, 1.0);
+
+        let context = memory.get_context_for_query("gold chain grams", 1000);
+        assert!(context.contains("Let me check the details"));
+        // The relevant turn ranks first, ahead of the most recent small talk
+        let history = context
+            .split("## Earlier Relevant Discussion")
+            .nth(1)
+            .unwrap();
+        assert!(history.contains("40 grams"));
+        assert!(history.find("40 grams") < history.find("Small talk number 5"));
+    }
+
     #[test]
     fn test_context_token_limit() {
         let memory = AgenticMemory::with_session("test-session");
@@ -15,6 +15,8 @@ use parking_lot::RwLock;
 use serde::{Deserialize, Serialize};
 use std::collections::VecDeque;
 
+use super::archival::cosine_similarity;
+
 /// Recall memory configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RecallMemoryConfig {
@@ -39,6 +41,9 @@ impl Default for RecallMemoryConfig {
     }
 }
 
+/// Marker appended to responses the user interrupted
+pub const INTERRUPTED_MARKER: &str = "[interrupted]";
+
 /// A conversation turn in recall memory
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ConversationTurn {
@@ -61,6 +66,10 @@ pub struct ConversationTurn {
     /// Embedding vector (optional, for semantic search)
     #[serde(skip)]
     pub embedding: Option<Vec<f32>>,
+    /// Assistant turn cut short by a barge-in; `content` holds only the
+    /// portion that was actually spoken
+    #[serde(default)]
+    pub interrupted: bool,
 }
 
 impl ConversationTurn {
@@ -78,6 +87,7 @@ impl ConversationTurn {
             stage: None,
             estimated_tokens,
             embedding: None,
+            interrupted: false,
         }
     }
 
@@ -107,7 +117,16 @@ impl ConversationTurn {
 
     /// Format for LLM context
     pub fn format_for_context(&self) -> String {
-        format!("{}: {}", self.role.as_str(), self.content)
+        if self.interrupted {
+            format!(
+                "{}: {} {}",
+                self.role.as_str(),
+                self.content,
+                INTERRUPTED_MARKER
+            )
+        } else {
+            format!("{}: {}", self.role.as_str(), self.content)
+        }
     }
 }
 
@@ -237,40 +256,57 @@ impl RecallMemory {
         scored
             .into_iter()
             .take(top_k)
-            .map(|(idx, score)| {
-                let turn = turns[idx].clone();
+            .map(|(idx, score)| with_context(&turns, idx, score))
+            .collect()
+    }
 
-                // Get context (1 turn before and after)
-                let context_before = if idx > 0 {
-                    vec![turns[idx - 1].clone()]
-                } else {
-                    Vec::new()
-                };
+    /// Every turn, oldest first, with its relevance to `query` (0.0 if none)
+    pub fn score_turns(&self, query: &str) -> Vec<(ConversationTurn, f32)> {
+        let query_lower = query.to_lowercase();
+        let query_words: Vec<&str> = query_lower.split_whitespace().collect();
 
-                let context_after = if idx + 1 < turns.len() {
-                    vec![turns[idx + 1].clone()]
+        self.turns
+            .read()
+            .iter()
+            .map(|turn| {
+                let score = if query_words.is_empty() {
+                    0.0
                 } else {
-                    Vec::new()
+                    compute_relevance(&query_words, turn)
                 };
-
-                RecallSearchResult {
-                    turn,
-                    score,
-                    context_before,
-                    context_after,
-                }
+                (turn.clone(), score)
             })
             .collect()
     }
 
-    /// Search by embedding vector (for production use)
+    /// Search by embedding vector
+    ///
+    /// Scores are cosine similarity; turns stored without an embedding are
+    /// skipped.
     pub fn search_by_embedding(
         &self,
-        _embedding: &[f32],
-        _top_k: Option<usize>,
+        embedding: &[f32],
+        top_k: Option<usize>,
     ) -> Vec<RecallSearchResult> {
-        // In production, this would use vector similarity
-        Vec::new()
+        let top_k = top_k.unwrap_or(self.config.default_top_k);
+        let turns = self.turns.read();
+
+        let mut scored: Vec<(usize, f32)> = turns
+            .iter()
+            .enumerate()
+            .filter_map(|(idx, turn)| {
+                let score = cosine_similarity(embedding, turn.embedding.as_deref()?);
+                Some((idx, score))
+            })
+            .filter(|(_, score)| *score > 0.0)
+            .collect();
+        scored.sort_by(|a, b| b.1.total_cmp(&a.1));
+
+        scored
+            .into_iter()
+            .take(top_k)
+            .map(|(idx, score)| with_context(&turns, idx, score))
+            .collect()
     }
 
     /// Get recent FIFO turns (always included in context)
@@ -285,6 +321,29 @@ impl RecallMemory {
         self.turns.read().iter().cloned().collect()
     }
 
+    /// Truncate the latest assistant turn to what was spoken before a barge-in
+    ///
+    /// Returns false if there is no assistant turn or it is already marked.
+    pub fn mark_last_assistant_interrupted(&self, spoken_text: &str) -> bool {
+        let mut turns = self.turns.write();
+        let Some(turn) = turns
+            .iter_mut()
+            .rev()
+            .find(|t| t.role == TurnRole::Assistant)
+        else {
+            return false;
+        };
+        if turn.interrupted {
+            return false;
+        }
+
+        turn.content = spoken_text.trim().to_string();
+        turn.estimated_tokens = estimate_tokens(&turn.content);
+        turn.embedding = None;
+        turn.interrupted = true;
+        true
+    }
+
     /// Get turn by ID
     pub fn get_turn(&self, id: u64) -> Option<ConversationTurn> {
         self.turns.read().iter().find(|t| t.id == id).cloned()
@@ -376,6 +435,28 @@ impl Default for RecallMemory {
 }
 
 /// Estimate tokens for text (simple 4-chars-per-token estimate)
+/// Result for the turn at `idx`, with one turn either side as context
+fn with_context(turns: &VecDeque<ConversationTurn>, idx: usize, score: f32) -> RecallSearchResult {
+    let context_before = if idx > 0 {
+        vec![turns[idx - 1].clone()]
+    } else {
+        Vec::new()
+    };
+
+    let context_after = if idx + 1 < turns.len() {
+        vec![turns[idx + 1].clone()]
+    } else {
+        Vec::new()
+    };
+
+    RecallSearchResult {
+        turn: turns[idx].clone(),
+        score,
+        context_before,
+        context_after,
+    }
+}
+
 fn estimate_tokens(text: &str) -> usize {
     use unicode_segmentation::UnicodeSegmentation;
 
@@ -444,6 +525,49 @@ mod tests {
         assert!(turn.intents.contains(&"service_inquiry".to_string()));
     }
 
+    #[test]
+    fn test_mark_last_assistant_interrupted() {
+        let recall = RecallMemory::default();
+        assert!(!recall.mark_last_assistant_interrupted("nothing yet"));
+
+        recall.add_turn(ConversationTurn::new(TurnRole::Assistant, "Welcome back"));
+        recall.add_turn(ConversationTurn::new(TurnRole::User, "Tell me the rates"));
+        recall.add_turn(ConversationTurn::new(
+            TurnRole::Assistant,
+            "Our rate is eleven percent and there are no hidden charges",
+        ));
+
+        assert!(recall.mark_last_assistant_interrupted("Our rate is eleven percent"));
+        assert!(!recall.mark_last_assistant_interrupted("Our rate"));
+
+        let turns = recall.get_all();
+        let last = turns.last().unwrap();
+        assert!(last.interrupted);
+        assert_eq!(last.content, "Our rate is eleven percent");
+        assert_eq!(
+            last.format_for_context(),
+            "assistant: Our rate is eleven percent [interrupted]"
+        );
+        assert!(!turns[0].interrupted);
+    }
+
+    #[test]
+    fn test_search_by_embedding_ranks_by_similarity() {
+        let recall = RecallMemory::default();
+        let question = ConversationTurn::new(TurnRole::User, "What is the rate?");
+        recall.add_turn(question.with_embedding(vec![1.0, 0.0]));
+        let answer = ConversationTurn::new(TurnRole::Assistant, "Eleven percent");
+        recall.add_turn(answer.with_embedding(vec![0.6, 0.8]));
+        recall.add_turn(ConversationTurn::new(TurnRole::User, "No embedding"));
+
+        let results = recall.search_by_embedding(&[1.0, 0.0], None);
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].turn.content, "What is the rate?");
+        assert!((results[0].score - 1.0).abs() < 1e-6);
+        assert_eq!(results[0].context_after[0].content, "Eleven percent");
+        assert!((results[1].score - 0.6).abs() < 1e-6);
+    }
+
     #[test]
     fn test_add_and_get_turns() {
         let recall = RecallMemory::default();
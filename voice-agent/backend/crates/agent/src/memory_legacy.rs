@@ -15,6 +15,8 @@ use std::sync::Arc;
 
 use voice_agent_core::{GenerateRequest, LanguageModel, Turn, TurnRole};
 
+use crate::memory::INTERRUPTED_MARKER;
+
 // P2-3 FIX: Re-export MemoryConfig from config crate
 pub use voice_agent_config::MemoryConfig;
 
@@ -351,6 +353,22 @@ Summary:"#,
         self.semantic.read().clone()
     }
 
+    /// Truncate the latest assistant entry to what was spoken before a barge-in
+    ///
+    /// Returns false if there is no assistant entry or it is already marked.
+    pub fn mark_last_assistant_interrupted(&self, spoken_text: &str) -> bool {
+        let mut working = self.working.write();
+        let Some(entry) = working.iter_mut().rev().find(|e| e.role == "assistant") else {
+            return false;
+        };
+        if entry.content.ends_with(INTERRUPTED_MARKER) {
+            return false;
+        }
+
+        entry.content = format!("{} {}", spoken_text.trim(), INTERRUPTED_MARKER);
+        true
+    }
+
     /// Get working memory entries
     pub fn working_memory(&self) -> Vec<MemoryEntry> {
         self.working.read().clone()
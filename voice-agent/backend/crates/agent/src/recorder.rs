@@ -0,0 +1,760 @@
+//! Conversation Event Recording
+//!
+//! `ConversationRecorder` writes every agent and conversation event of a
+//! session as one JSON line (turns, intents with slots, tool calls, stage
+//! transitions, lead scores), with a timestamp, the session id and a
+//! per-session sequence number. Responses and tool results carry the latency
+//! since the matching `Thinking` / `ToolCall` event. Records are meant for
+//! analytics and replay, and are independent of the compliance audit log.
+//!
+//! Only calls the customer agreed to record are written. Events are skipped
+//! until recording is allowed: from the start if the conversation's
+//! `recording_allowed()` already holds, otherwise from the `ConsentRecorded`
+//! event that grants it. The disclosure and consent question themselves,
+//! and every event of a call where consent was refused, are never written.
+//!
+//! Caller speech is masked before it is written: every text field of a
+//! record (utterances, responses, slot values, tool arguments) is scanned
+//! for the configured PII types, which are replaced by a type mask such as
+//! `[PHONE]`.
+//!
+//! Recording never blocks the agent: records are queued on a bounded channel
+//! and written by a background thread through a buffered writer. When the
+//! queue is full, records are dropped and counted.
+//!
+//! A customer's sessions can be erased from a file sink, which the writer
+//! thread rewrites without their records. Records written to stdout or
+//! another writer are out of reach, so erasing from those fails.
+//!
+//! ```json
+//! {"ts":"2026-01-05T10:15:02.114Z","session_id":"abc","seq":3,"event":"turn_added",...}
+//! ```
+
+use std::collections::{HashMap, HashSet};
+use std::fs::{self, OpenOptions};
+use std::io::{self, BufRead, BufReader, BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::Instant;
+
+use serde_json::{json, Map, Value};
+use tokio::sync::broadcast::{self, error::RecvError};
+use tokio::sync::{mpsc, oneshot};
+use tokio::task::JoinHandle;
+use voice_agent_config::ConversationRecordingConfig;
+use voice_agent_core::RedactionStrategy;
+use voice_agent_persistence::{ErasureTarget, PersistenceError};
+use voice_agent_text_processing::HybridPIIDetector;
+
+use crate::agent::DomainAgent;
+use crate::agent_config::AgentEvent;
+use crate::conversation::ConversationEvent;
+
+/// Where recorded events are written
+pub enum RecordingSink {
+    /// Standard output
+    Stdout,
+    /// File records are appended to
+    File(PathBuf),
+    /// Any writer, e.g. an in-memory buffer
+    Writer(Box<dyn Write + Send>),
+}
+
+impl RecordingSink {
+    /// Parse a configured sink: "stdout" or a file path
+    pub fn parse(sink: &str) -> Self {
+        match sink.trim() {
+            "" | "stdout" | "-" => RecordingSink::Stdout,
+            path => RecordingSink::File(PathBuf::from(path)),
+        }
+    }
+
+    fn open(self) -> io::Result<Box<dyn Write + Send>> {
+        Ok(match self {
+            RecordingSink::Stdout => Box::new(io::stdout()),
+            RecordingSink::File(path) => Box::new(open_append(&path)?),
+            RecordingSink::Writer(writer) => writer,
+        })
+    }
+}
+
+fn open_append(path: &Path) -> io::Result<fs::File> {
+    OpenOptions::new().create(true).append(true).open(path)
+}
+
+enum Command {
+    Record(String),
+    Flush(oneshot::Sender<()>),
+    Erase(HashSet<String>, oneshot::Sender<io::Result<usize>>),
+}
+
+/// Writes session events to a JSONL sink
+///
+/// One recorder is shared by all sessions; `attach` it to each agent.
+pub struct ConversationRecorder {
+    tx: mpsc::Sender<Command>,
+    dropped: AtomicU64,
+    /// Masks PII in text fields; None records them as spoken
+    redactor: Option<HybridPIIDetector>,
+}
+
+impl ConversationRecorder {
+    /// Create a recorder writing to `sink`, buffering up to `buffer_size` records
+    pub fn new(sink: RecordingSink, buffer_size: usize) -> io::Result<Self> {
+        let path = match sink {
+            RecordingSink::File(ref path) => Some(path.clone()),
+            _ => None,
+        };
+        let writer = sink.open()?;
+        let (tx, rx) = mpsc::channel(buffer_size.max(1));
+        std::thread::Builder::new()
+            .name("conversation-recorder".to_string())
+            .spawn(move || write_records(writer, path, rx))?;
+        Ok(Self {
+            tx,
+            dropped: AtomicU64::new(0),
+            redactor: None,
+        })
+    }
+
+    /// Mask the given PII entity types in every recorded text field
+    pub fn with_redaction(mut self, entities: &[String]) -> Self {
+        self.redactor = (!entities.is_empty()).then(|| HybridPIIDetector::regex_only(entities));
+        self
+    }
+
+    /// Create a recorder from config; None when recording is disabled
+    pub fn from_config(config: &ConversationRecordingConfig) -> Option<Arc<Self>> {
+        if !config.enabled {
+            return None;
+        }
+        match Self::new(RecordingSink::parse(&config.sink), config.buffer_size) {
+            Ok(recorder) => Some(Arc::new(
+                recorder.with_redaction(&config.redaction_entities),
+            )),
+            Err(e) => {
+                tracing::error!(sink = %config.sink, error = %e, "Failed to open recording sink");
+                None
+            },
+        }
+    }
+
+    /// Records dropped because the buffer was full
+    pub fn dropped(&self) -> u64 {
+        self.dropped.load(Ordering::Relaxed)
+    }
+
+    /// Queue one record without waiting
+    pub fn record(&self, session_id: &str, seq: u64, event: &str, fields: Map<String, Value>) {
+        let mut record = Map::new();
+        record.insert(
+            "ts".to_string(),
+            json!(chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Millis, true)),
+        );
+        record.insert("session_id".to_string(), json!(session_id));
+        record.insert("seq".to_string(), json!(seq));
+        record.insert("event".to_string(), json!(event));
+        for (key, mut value) in fields {
+            if let Some(ref redactor) = self.redactor {
+                mask_strings(redactor, &mut value);
+            }
+            record.insert(key, value);
+        }
+
+        let line = Value::Object(record).to_string();
+        if self.tx.try_send(Command::Record(line)).is_err() {
+            self.dropped.fetch_add(1, Ordering::Relaxed);
+            tracing::debug!(session_id = %session_id, event = %event, "Recording buffer full");
+        }
+    }
+
+    /// Wait until every queued record has been written to the sink
+    pub async fn flush(&self) {
+        let (ack_tx, ack_rx) = oneshot::channel();
+        if self.tx.send(Command::Flush(ack_tx)).await.is_ok() {
+            let _ = ack_rx.await;
+        }
+    }
+
+    /// Remove every record of the given sessions from the sink
+    ///
+    /// Records queued before the call are written first, so they are
+    /// removed too. Returns how many records were removed; fails for sinks
+    /// other than a file.
+    pub async fn erase_sessions(&self, session_ids: &[String]) -> io::Result<usize> {
+        let (ack_tx, ack_rx) = oneshot::channel();
+        let sessions = session_ids.iter().cloned().collect();
+        self.tx
+            .send(Command::Erase(sessions, ack_tx))
+            .await
+            .map_err(|_| io::Error::new(io::ErrorKind::BrokenPipe, "recorder stopped"))?;
+        ack_rx
+            .await
+            .map_err(|_| io::Error::new(io::ErrorKind::BrokenPipe, "recorder stopped"))?
+    }
+
+    /// Record the agent's events until its conversation ends
+    pub fn attach(self: Arc<Self>, agent: &DomainAgent) -> JoinHandle<()> {
+        let session = SessionRecording::new(
+            agent.conversation().session_id().to_string(),
+            agent.recording_allowed(),
+        );
+        let agent_rx = agent.subscribe();
+        let conversation_rx = agent.conversation().subscribe();
+        tokio::spawn(async move {
+            session.run(&self, agent_rx, conversation_rx).await;
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl ErasureTarget for ConversationRecorder {
+    fn name(&self) -> &'static str {
+        "recorded_events_deleted"
+    }
+
+    async fn erase(&self, _phone: &str, session_ids: &[String]) -> Result<usize, PersistenceError> {
+        self.erase_sessions(session_ids)
+            .await
+            .map_err(|e| PersistenceError::Query(format!("Recording erasure failed: {}", e)))
+    }
+}
+
+/// Writer thread: drains queued records, flushing whenever the queue is empty
+fn write_records(
+    writer: Box<dyn Write + Send>,
+    path: Option<PathBuf>,
+    mut rx: mpsc::Receiver<Command>,
+) {
+    let mut writer = BufWriter::new(writer);
+    let mut acks = Vec::new();
+    while let Some(command) = rx.blocking_recv() {
+        let mut next = Some(command);
+        while let Some(command) = next {
+            match command {
+                Command::Record(line) => {
+                    if let Err(e) = writeln!(writer, "{}", line) {
+                        tracing::warn!(error = %e, "Failed to write conversation record");
+                    }
+                },
+                Command::Flush(ack) => acks.push(ack),
+                Command::Erase(sessions, ack) => {
+                    let erased = writer
+                        .flush()
+                        .and_then(|()| erase_from_file(path.as_deref(), &sessions));
+                    // The file was replaced, so later records go to the new one
+                    if erased.is_ok() {
+                        if let Some(file) = path.as_deref().and_then(|p| open_append(p).ok()) {
+                            writer = BufWriter::new(Box::new(file));
+                        }
+                    }
+                    let _ = ack.send(erased);
+                },
+            }
+            next = rx.try_recv().ok();
+        }
+        if let Err(e) = writer.flush() {
+            tracing::warn!(error = %e, "Failed to flush conversation records");
+        }
+        for ack in acks.drain(..) {
+            let _ = ack.send(());
+        }
+    }
+}
+
+/// Per-session recording state
+struct SessionRecording {
+    session_id: String,
+    /// Whether recording is allowed as of the agent events seen so far
+    agent_consent: bool,
+    /// Whether recording is allowed as of the conversation events seen so far
+    conversation_consent: bool,
+    seq: u64,
+    /// When the current turn started processing
+    thinking_since: Option<Instant>,
+    /// When each in-flight tool was called
+    tools_since: HashMap<String, Instant>,
+}
+
+impl SessionRecording {
+    fn new(session_id: String, recording_allowed: bool) -> Self {
+        Self {
+            session_id,
+            agent_consent: recording_allowed,
+            conversation_consent: recording_allowed,
+            seq: 0,
+            thinking_since: None,
+            tools_since: HashMap::new(),
+        }
+    }
+
+    async fn run(
+        mut self,
+        recorder: &ConversationRecorder,
+        mut agent_rx: broadcast::Receiver<AgentEvent>,
+        mut conversation_rx: broadcast::Receiver<ConversationEvent>,
+    ) {
+        let mut agent_open = true;
+        loop {
+            tokio::select! {
+                event = agent_rx.recv(), if agent_open => match event {
+                    Ok(event) => self.on_agent_event(recorder, event),
+                    Err(RecvError::Lagged(n)) => self.on_lagged(recorder, "agent", n),
+                    Err(RecvError::Closed) => agent_open = false,
+                },
+                event = conversation_rx.recv() => match event {
+                    Ok(event) => {
+                        let ended = matches!(event, ConversationEvent::Ended { .. });
+                        self.on_conversation_event(recorder, event);
+                        if ended {
+                            break;
+                        }
+                    },
+                    Err(RecvError::Lagged(n)) => self.on_lagged(recorder, "conversation", n),
+                    Err(RecvError::Closed) => break,
+                },
+            }
+        }
+
+        // Agent events already sent before the conversation ended
+        while let Ok(event) = agent_rx.try_recv() {
+            self.on_agent_event(recorder, event);
+        }
+    }
+
+    fn emit(&mut self, recorder: &ConversationRecorder, event: &str, fields: Value) {
+        let fields = match fields {
+            Value::Object(map) => map,
+            _ => Map::new(),
+        };
+        recorder.record(&self.session_id, self.seq, event, fields);
+        self.seq += 1;
+    }
+
+    fn on_lagged(&mut self, recorder: &ConversationRecorder, stream: &str, missed: u64) {
+        tracing::warn!(session_id = %self.session_id, stream, missed, "Recorder lagged");
+        if !(self.agent_consent || self.conversation_consent) {
+            return;
+        }
+        self.emit(
+            recorder,
+            "events_missed",
+            json!({ "stream": stream, "count": missed }),
+        );
+    }
+
+    fn on_agent_event(&mut self, recorder: &ConversationRecorder, event: AgentEvent) {
+        if let AgentEvent::Conversation(ConversationEvent::ConsentRecorded { given, .. }) = event {
+            self.agent_consent = given;
+        }
+        if !self.agent_consent {
+            return;
+        }
+        let (name, fields) = match event {
+            AgentEvent::Thinking => {
+                self.thinking_since = Some(Instant::now());
+                ("thinking", json!({}))
+            },
+            AgentEvent::Response(text) => {
+                let latency = self.thinking_since.take().map(elapsed_ms);
+                ("response", json!({ "text": text, "latency_ms": latency }))
+            },
+            AgentEvent::ToolCall { name } => {
+                self.tools_since.insert(name.clone(), Instant::now());
+                ("tool_call", json!({ "name": name }))
+            },
+            AgentEvent::ToolResult { name, success } => {
+                let latency = self.tools_since.remove(&name).map(elapsed_ms);
+                (
+                    "tool_result",
+                    json!({ "name": name, "success": success, "latency_ms": latency }),
+                )
+            },
+            AgentEvent::ToolRound { round, tools } => {
+                ("tool_round", json!({ "round": round, "tools": tools }))
+            },
+            AgentEvent::Error(message) => ("error", json!({ "message": message })),
+            AgentEvent::LeadScoreUpdated {
+                score,
+                qualification,
+                classification,
+                conversion_probability,
+            } => (
+                "lead_score_updated",
+                json!({
+                    "score": score,
+                    "qualification": qualification,
+                    "classification": classification,
+                    "conversion_probability": conversion_probability,
+                }),
+            ),
+            AgentEvent::EscalationTriggered {
+                trigger,
+                recommendation,
+            } => (
+                "escalation_triggered",
+                json!({ "trigger": trigger, "recommendation": recommendation }),
+            ),
+            AgentEvent::SlotRetryLimitReached {
+                slot,
+                attempts,
+                fallback,
+            } => (
+                "slot_retry_limit_reached",
+                json!({ "slot": slot, "attempts": attempts, "fallback": fallback }),
+            ),
+            AgentEvent::SegmentChanged { segment, persona } => (
+                "segment_changed",
+                json!({ "segment": segment, "persona": persona }),
+            ),
+            AgentEvent::NextBestAction(recommendation) => (
+                "next_best_action",
+                serde_json::to_value(recommendation).unwrap_or_default(),
+            ),
+            AgentEvent::SlotsUpdated(progress) => (
+                "slots_updated",
+                serde_json::to_value(progress).unwrap_or_default(),
+            ),
+            AgentEvent::HandedBack {
+                acknowledgement,
+                notes,
+            } => (
+                "handed_back",
+                json!({ "text": acknowledgement, "notes": notes }),
+            ),
+            // The conversation's own stream carries these
+            AgentEvent::Conversation(_) => return,
+            // Carries the full prompt; for live debugging only
+            AgentEvent::TurnDebug(_) => return,
+        };
+        self.emit(recorder, name, fields);
+    }
+
+    fn on_conversation_event(&mut self, recorder: &ConversationRecorder, event: ConversationEvent) {
+        if let ConversationEvent::ConsentRecorded { given, .. } = event {
+            self.conversation_consent = given;
+        }
+        if !self.conversation_consent {
+            return;
+        }
+        let (name, fields) = match event {
+            ConversationEvent::Started { .. } => ("session_started", json!({})),
+            ConversationEvent::TurnAdded { role, content } => (
+                "turn_added",
+                json!({ "role": role.as_str(), "content": content }),
+            ),
+            ConversationEvent::IntentDetected(intent) => {
+                let slots: Map<String, Value> = intent
+                    .slots
+                    .iter()
+                    .map(|(name, slot)| (name.clone(), json!(slot.value)))
+                    .collect();
+                (
+                    "intent_detected",
+                    json!({
+                        "intent": intent.intent,
+                        "confidence": intent.confidence,
+                        "slots": slots,
+                    }),
+                )
+            },
+            ConversationEvent::StageChanged { from, to } => (
+                "stage_changed",
+                json!({ "from": from.as_str(), "to": to.as_str() }),
+            ),
+            ConversationEvent::FactLearned { key, value } => {
+                ("fact_learned", json!({ "key": key, "value": value }))
+            },
+            ConversationEvent::ToolCalled { name, success } => {
+                ("tool_called", json!({ "name": name, "success": success }))
+            },
+            ConversationEvent::ClarificationRequested {
+                question,
+                candidates,
+            } => (
+                "clarification_requested",
+                json!({ "question": question, "candidates": candidates }),
+            ),
+            ConversationEvent::LowConfidenceIntent { intent, confidence } => (
+                "low_confidence_intent",
+                json!({ "intent": intent, "confidence": confidence }),
+            ),
+            ConversationEvent::ConsentRecorded { given, method } => (
+                "consent_recorded",
+                json!({ "given": given, "method": method }),
+            ),
+            ConversationEvent::ComplianceIncomplete { violations } => {
+                ("compliance_incomplete", json!({ "violations": violations }))
+            },
+            ConversationEvent::Ended { reason } => (
+                "session_ended",
+                json!({ "reason": format!("{:?}", reason) }),
+            ),
+            ConversationEvent::Error(message) => ("error", json!({ "message": message })),
+        };
+        self.emit(recorder, name, fields);
+    }
+}
+
+/// Rewrite the records file without the given sessions' records
+fn erase_from_file(path: Option<&Path>, sessions: &HashSet<String>) -> io::Result<usize> {
+    let Some(path) = path else {
+        return Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            "records not written to a file can't be erased",
+        ));
+    };
+    let rewritten = path.with_extension("erasing");
+    let mut kept = BufWriter::new(fs::File::create(&rewritten)?);
+    let mut erased = 0;
+    for line in BufReader::new(fs::File::open(path)?).lines() {
+        let line = line?;
+        let session_id = serde_json::from_str::<Value>(&line)
+            .ok()
+            .and_then(|record| record["session_id"].as_str().map(String::from));
+        if session_id.is_some_and(|id| sessions.contains(&id)) {
+            erased += 1;
+        } else {
+            writeln!(kept, "{}", line)?;
+        }
+    }
+    kept.flush()?;
+    fs::rename(&rewritten, path)?;
+    Ok(erased)
+}
+
+/// Mask PII in every string nested in `value`
+fn mask_strings(redactor: &HybridPIIDetector, value: &mut Value) {
+    match value {
+        Value::String(text) => *text = redactor.redact_sync(text, &RedactionStrategy::TypeMask),
+        Value::Array(items) => items
+            .iter_mut()
+            .for_each(|item| mask_strings(redactor, item)),
+        Value::Object(map) => map
+            .values_mut()
+            .for_each(|item| mask_strings(redactor, item)),
+        _ => {},
+    }
+}
+
+fn elapsed_ms(since: Instant) -> u64 {
+    since.elapsed().as_millis() as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::agent_config::AgentConfig;
+    use crate::conversation::EndReason;
+    use crate::testing::DialogueScript;
+    use parking_lot::Mutex;
+
+    /// In-memory sink shared with the test
+    #[derive(Clone, Default)]
+    struct SharedBuffer(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for SharedBuffer {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.lock().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    impl SharedBuffer {
+        fn records(&self) -> Vec<Value> {
+            String::from_utf8(self.0.lock().clone())
+                .unwrap()
+                .lines()
+                .map(|line| serde_json::from_str(line).unwrap())
+                .collect()
+        }
+    }
+
+    fn events<'a>(records: &'a [Value], names: &[&str]) -> Vec<&'a Value> {
+        records
+            .iter()
+            .filter(|r| names.contains(&r["event"].as_str().unwrap()))
+            .collect()
+    }
+
+    fn recorder_with_buffer() -> (Arc<ConversationRecorder>, SharedBuffer) {
+        let buffer = SharedBuffer::default();
+        let recorder = Arc::new(
+            ConversationRecorder::new(RecordingSink::Writer(Box::new(buffer.clone())), 1024)
+                .unwrap(),
+        );
+        (recorder, buffer)
+    }
+
+    fn agent(session_id: &str) -> DomainAgent {
+        DomainAgent::new(
+            session_id,
+            AgentConfig::default(),
+            Arc::new(voice_agent_config::MasterDomainConfig::default()),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_scripted_session_recorded_in_order() {
+        let (recorder, buffer) = recorder_with_buffer();
+        let agent = agent("rec-session");
+        let handle = recorder.clone().attach(&agent);
+
+        agent.begin_consent().await;
+        agent.process("Yes, that's fine").await.unwrap();
+        DialogueScript::new()
+            .turn("Hello")
+            .turn("I want a gold loan")
+            .run(&agent)
+            .await
+            .unwrap();
+        agent.end_call(EndReason::UserEnded).await;
+        handle.await.unwrap();
+        recorder.flush().await;
+
+        let records = buffer.records();
+        assert!(records.iter().all(|r| r["session_id"] == "rec-session"));
+        assert!(records.iter().all(|r| r["ts"].is_string()));
+        let seqs: Vec<u64> = records.iter().map(|r| r["seq"].as_u64().unwrap()).collect();
+        assert_eq!(seqs, (0..records.len() as u64).collect::<Vec<_>>());
+
+        // Turns in conversation order, then the end of the session
+        let turns = events(&records, &["turn_added", "session_ended"]);
+        let roles: Vec<&str> = turns
+            .iter()
+            .map(|r| r["role"].as_str().unwrap_or("end"))
+            .collect();
+        assert_eq!(&roles[..4], ["user", "assistant", "user", "assistant"]);
+        assert_eq!(turns[0]["content"], "Hello");
+        assert_eq!(turns[2]["content"], "I want a gold loan");
+        assert_eq!(roles.last(), Some(&"end"));
+        assert_eq!(turns.last().unwrap()["reason"], "UserEnded");
+
+        // The consent acknowledgement, then per turn: thinking and a timed response
+        let processing = events(&records, &["thinking", "response"]);
+        let names: Vec<&str> = processing
+            .iter()
+            .map(|r| r["event"].as_str().unwrap())
+            .collect();
+        assert_eq!(
+            &names[..5],
+            ["response", "thinking", "response", "thinking", "response"]
+        );
+        assert!(processing[2]["latency_ms"].is_u64());
+
+        let intents = events(&records, &["intent_detected"]);
+        assert!(!intents.is_empty());
+        assert!(intents[0]["intent"].is_string());
+        assert!(intents[0]["slots"].is_object());
+    }
+
+    #[tokio::test]
+    async fn test_nothing_recorded_without_consent() {
+        let (recorder, buffer) = recorder_with_buffer();
+        let agent = agent("rec-refused");
+        let handle = recorder.clone().attach(&agent);
+
+        agent.begin_consent().await;
+        agent.process("No, please don't record").await.unwrap();
+        agent.process("I want a gold loan").await.unwrap();
+        agent.end_call(EndReason::UserEnded).await;
+        handle.await.unwrap();
+        recorder.flush().await;
+
+        assert!(buffer.records().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_pii_masked_in_text_fields() {
+        let buffer = SharedBuffer::default();
+        let entities = ["PhoneNumber".to_string(), "PAN".to_string()];
+        let recorder =
+            ConversationRecorder::new(RecordingSink::Writer(Box::new(buffer.clone())), 8)
+                .unwrap()
+                .with_redaction(&entities);
+
+        let fields = json!({
+            "content": "Call me on 9876543210",
+            "slots": { "phone": "9876543210", "pan": "ABCDE1234F" },
+            "args": [{ "customer_phone": "+91 9876543210" }],
+            "confidence": 0.9,
+        });
+        let Value::Object(fields) = fields else {
+            unreachable!()
+        };
+        recorder.record("rec-pii", 0, "turn_added", fields);
+        recorder.flush().await;
+
+        let records = buffer.records();
+        let line = records[0].to_string();
+        assert!(!line.contains("9876543210"));
+        assert!(!line.contains("ABCDE1234F"));
+        assert!(records[0]["content"]
+            .as_str()
+            .unwrap()
+            .starts_with("Call me on "));
+        assert_eq!(records[0]["confidence"], 0.9);
+        assert_eq!(records[0]["session_id"], "rec-pii");
+    }
+
+    #[tokio::test]
+    async fn test_erased_sessions_removed_from_file() {
+        let path = std::env::temp_dir().join(format!("recording-{}.jsonl", uuid::Uuid::new_v4()));
+        let recorder = ConversationRecorder::new(RecordingSink::File(path.clone()), 64).unwrap();
+        for session in ["erased", "kept", "erased"] {
+            recorder.record(session, 0, "thinking", Map::new());
+        }
+
+        let erased = recorder
+            .erase_sessions(&["erased".to_string()])
+            .await
+            .unwrap();
+        assert_eq!(erased, 2);
+
+        // Recording goes on in the rewritten file
+        recorder.record("kept", 1, "thinking", Map::new());
+        recorder.flush().await;
+        let contents = fs::read_to_string(&path).unwrap();
+        let sessions: Vec<Value> = contents
+            .lines()
+            .map(|line| serde_json::from_str::<Value>(line).unwrap()["session_id"].clone())
+            .collect();
+        assert_eq!(sessions, vec!["kept", "kept"]);
+        fs::remove_file(&path).unwrap();
+
+        // Records sent elsewhere can't be taken back
+        let (recorder, _) = recorder_with_buffer();
+        assert!(recorder
+            .erase_sessions(&["kept".to_string()])
+            .await
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn test_full_buffer_drops_records() {
+        let recorder =
+            ConversationRecorder::new(RecordingSink::Writer(Box::new(io::sink())), 1).unwrap();
+        for seq in 0..1000 {
+            recorder.record("s", seq, "thinking", Map::new());
+        }
+        recorder.flush().await;
+        assert!(recorder.dropped() > 0);
+    }
+
+    #[test]
+    fn test_sink_parse() {
+        assert!(matches!(
+            RecordingSink::parse("stdout"),
+            RecordingSink::Stdout
+        ));
+        assert!(matches!(
+            RecordingSink::parse("/var/log/calls.jsonl"),
+            RecordingSink::File(_)
+        ));
+    }
+}
@@ -11,6 +11,8 @@
 use parking_lot::Mutex;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::time::{Duration, Instant};
+use voice_agent_config::domain::ResponseLimit;
 
 /// P4 FIX: RAG timing strategy for prefetch behavior
 ///
@@ -172,6 +174,23 @@ impl ConversationStage {
         }
     }
 
+    /// Get the default maximum response length for this stage
+    ///
+    /// Spoken replies should be short: greeting and closing are a couple of
+    /// sentences, discovery and presentation can say a little more.
+    pub fn response_limit(&self) -> ResponseLimit {
+        let (sentences, chars) = match self {
+            ConversationStage::Greeting => (2, 200),
+            ConversationStage::Discovery => (4, 400),
+            ConversationStage::Qualification => (3, 350),
+            ConversationStage::Presentation => (4, 450),
+            ConversationStage::ObjectionHandling => (4, 450),
+            ConversationStage::Closing => (2, 250),
+            ConversationStage::Farewell => (2, 200),
+        };
+        ResponseLimit::sentences(sentences).with_max_chars(chars)
+    }
+
     /// P2 FIX: Get recommended number of conversation history turns to keep
     ///
     /// Returns the number of most recent user+assistant turn pairs to include.
@@ -332,6 +351,11 @@ pub struct StageManager {
     current_stage: Mutex<ConversationStage>,
     stage_history: Mutex<Vec<StageTransition>>,
     stage_turns: Mutex<HashMap<ConversationStage, usize>>,
+    /// When the current stage was entered, or its clock restarted
+    stage_entered_at: Mutex<Instant>,
+    /// Turns recorded in the current stage before it was entered, or its
+    /// clock restarted
+    turns_at_entry: Mutex<usize>,
     collected_info: Mutex<HashMap<String, String>>,
     /// P0 FIX: Track detected intents for stage requirement validation
     detected_intents: Mutex<Vec<String>>,
@@ -348,6 +372,8 @@ impl StageManager {
             current_stage: Mutex::new(ConversationStage::Greeting),
             stage_history: Mutex::new(Vec::new()),
             stage_turns: Mutex::new(HashMap::new()),
+            stage_entered_at: Mutex::new(Instant::now()),
+            turns_at_entry: Mutex::new(0),
             collected_info: Mutex::new(HashMap::new()),
             detected_intents: Mutex::new(Vec::new()),
             requirements: Self::default_requirements(),
@@ -381,6 +407,8 @@ impl StageManager {
             current_stage: Mutex::new(ConversationStage::Greeting),
             stage_history: Mutex::new(Vec::new()),
             stage_turns: Mutex::new(HashMap::new()),
+            stage_entered_at: Mutex::new(Instant::now()),
+            turns_at_entry: Mutex::new(0),
             collected_info: Mutex::new(HashMap::new()),
             detected_intents: Mutex::new(Vec::new()),
             requirements,
@@ -614,6 +642,9 @@ impl StageManager {
 
         // Update state
         *self.current_stage.lock() = to;
+        if to != from {
+            self.restart_stage_clock();
+        }
         self.stage_history.lock().push(transition.clone());
 
         Ok(transition)
@@ -626,6 +657,7 @@ impl StageManager {
     pub fn set_stage(&self, stage: ConversationStage) {
         let from = self.current();
         *self.current_stage.lock() = stage;
+        self.restart_stage_clock();
 
         // Record the transition for history
         let transition = StageTransition {
@@ -668,11 +700,34 @@ impl StageManager {
         self.stage_turns.lock().get(&stage).copied().unwrap_or(0)
     }
 
+    /// Time spent in the current stage since it was entered, or its clock
+    /// restarted
+    pub fn time_in_stage(&self) -> Duration {
+        self.stage_entered_at.lock().elapsed()
+    }
+
+    /// Turns taken in the current stage since it was entered, or its clock
+    /// restarted
+    pub fn turns_in_stage(&self) -> usize {
+        self.current_stage_turns()
+            .saturating_sub(*self.turns_at_entry.lock())
+    }
+
+    /// Count time and turns in the current stage from now
+    ///
+    /// Done on entering a stage, and when a stage timeout escalates so the
+    /// stage isn't timed out again on every following turn.
+    pub fn restart_stage_clock(&self) {
+        *self.stage_entered_at.lock() = Instant::now();
+        *self.turns_at_entry.lock() = self.current_stage_turns();
+    }
+
     /// Reset manager
     pub fn reset(&self) {
         *self.current_stage.lock() = ConversationStage::Greeting;
         self.stage_history.lock().clear();
         self.stage_turns.lock().clear();
+        self.restart_stage_clock();
         self.collected_info.lock().clear();
     }
 }
@@ -772,6 +827,14 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_response_limit() {
+        let greeting = ConversationStage::Greeting.response_limit();
+        let discovery = ConversationStage::Discovery.response_limit();
+        assert!(greeting.max_sentences < discovery.max_sentences);
+        assert!(ConversationStage::Closing.response_limit().max_sentences <= Some(2));
+    }
+
     #[test]
     fn test_history_turns_to_keep() {
         // P2 FIX: Test history turns recommendations
@@ -0,0 +1,418 @@
+//! Scripted Dialogue Test Harness
+//!
+//! `DialogueScript` drives a `DomainAgent` through a list of user turns and
+//! checks expectations after each one, so an agent test reads as a
+//! transcript instead of turn-by-turn setup. Use it with an agent built via
+//! `DomainAgent::without_llm` (or with a mock LLM) to keep runs deterministic.
+//! Outside this crate's own tests, enable the `testing` feature.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use voice_agent_agent::testing::DialogueScript;
+//!
+//! DialogueScript::new()
+//!     .turn("Am I eligible")
+//!     .expect_intent("eligibility_check")
+//!     .expect_response_contains("How much gold")
+//!     .turn("I have 50 grams")
+//!     .expect_slot("asset_quantity", "50")
+//!     .run(&agent)
+//!     .await
+//!     .unwrap();
+//! ```
+
+use thiserror::Error;
+use tokio::sync::broadcast::error::TryRecvError;
+
+use crate::agent::DomainAgent;
+use crate::agent_config::AgentEvent;
+use crate::conversation::ConversationEvent;
+use crate::dst::DialogueStateTrait;
+use crate::stage::ConversationStage;
+
+type ResponseCheck = Box<dyn Fn(&str) -> bool + Send + Sync>;
+type EventCheck = Box<dyn Fn(&AgentEvent) -> bool + Send + Sync>;
+
+/// A check run after a turn
+enum Expectation {
+    Stage(ConversationStage),
+    Slot {
+        name: String,
+        value: String,
+    },
+    NoSlot(String),
+    Intent(String),
+    Response {
+        description: String,
+        check: ResponseCheck,
+    },
+    Event {
+        description: String,
+        check: EventCheck,
+    },
+}
+
+impl Expectation {
+    /// What the expectation asks for, for error messages
+    fn describe(&self) -> String {
+        match self {
+            Expectation::Stage(stage) => format!("stage {:?}", stage),
+            Expectation::Slot { name, value } => format!("slot {} = {:?}", name, value),
+            Expectation::NoSlot(name) => format!("slot {} unfilled", name),
+            Expectation::Intent(intent) => format!("intent {:?}", intent),
+            Expectation::Response { description, .. } => description.clone(),
+            Expectation::Event { description, .. } => format!("event {}", description),
+        }
+    }
+}
+
+/// One user turn and the expectations checked after it
+struct ScriptTurn {
+    input: String,
+    expectations: Vec<Expectation>,
+}
+
+/// What the agent did on one turn
+#[derive(Debug, Clone)]
+pub struct TurnOutcome {
+    /// User input
+    pub input: String,
+    /// Agent response
+    pub response: String,
+    /// Conversation stage after the turn
+    pub stage: ConversationStage,
+    /// Intent detected on the turn, if any
+    pub intent: Option<String>,
+    /// Events the agent emitted during the turn
+    pub events: Vec<AgentEvent>,
+}
+
+/// Why a script failed
+#[derive(Error, Debug, Clone, PartialEq)]
+pub enum ScriptError {
+    #[error("turn {turn} ({input:?}): expected {expected}, got {actual}")]
+    Mismatch {
+        turn: usize,
+        input: String,
+        expected: String,
+        actual: String,
+    },
+
+    #[error("turn {turn} ({input:?}): agent failed: {error}")]
+    Agent {
+        turn: usize,
+        input: String,
+        error: String,
+    },
+
+    #[error("expectation {expected} added before any turn")]
+    NoTurn { expected: String },
+}
+
+/// Scripted dialogue run against a `DomainAgent`
+///
+/// `expect_*` calls apply to the most recent `turn`.
+#[derive(Default)]
+pub struct DialogueScript {
+    turns: Vec<ScriptTurn>,
+    /// First mistake made building the script, reported by `run`
+    error: Option<ScriptError>,
+}
+
+impl DialogueScript {
+    /// Create an empty script
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a user turn
+    pub fn turn(mut self, input: impl Into<String>) -> Self {
+        self.turns.push(ScriptTurn {
+            input: input.into(),
+            expectations: Vec::new(),
+        });
+        self
+    }
+
+    /// Expect the conversation to be in `stage` after the turn
+    pub fn expect_stage(self, stage: ConversationStage) -> Self {
+        self.expect(Expectation::Stage(stage))
+    }
+
+    /// Expect slot `name` to hold `value` after the turn
+    pub fn expect_slot(self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.expect(Expectation::Slot {
+            name: name.into(),
+            value: value.into(),
+        })
+    }
+
+    /// Expect slot `name` to be unfilled after the turn
+    pub fn expect_no_slot(self, name: impl Into<String>) -> Self {
+        self.expect(Expectation::NoSlot(name.into()))
+    }
+
+    /// Expect `intent` to be detected on the turn
+    pub fn expect_intent(self, intent: impl Into<String>) -> Self {
+        self.expect(Expectation::Intent(intent.into()))
+    }
+
+    /// Expect the response to contain `text` (case-insensitive)
+    pub fn expect_response_contains(self, text: impl Into<String>) -> Self {
+        let text = text.into();
+        let needle = text.to_lowercase();
+        self.expect_response(format!("response containing {:?}", text), move |r| {
+            r.to_lowercase().contains(&needle)
+        })
+    }
+
+    /// Expect the response to satisfy `check`
+    pub fn expect_response(
+        self,
+        description: impl Into<String>,
+        check: impl Fn(&str) -> bool + Send + Sync + 'static,
+    ) -> Self {
+        self.expect(Expectation::Response {
+            description: description.into(),
+            check: Box::new(check),
+        })
+    }
+
+    /// Expect the agent to emit an event matching `check` during the turn
+    pub fn expect_event(
+        self,
+        description: impl Into<String>,
+        check: impl Fn(&AgentEvent) -> bool + Send + Sync + 'static,
+    ) -> Self {
+        self.expect(Expectation::Event {
+            description: description.into(),
+            check: Box::new(check),
+        })
+    }
+
+    fn expect(mut self, expectation: Expectation) -> Self {
+        match self.turns.last_mut() {
+            Some(turn) => turn.expectations.push(expectation),
+            None => {
+                let expected = expectation.describe();
+                self.error.get_or_insert(ScriptError::NoTurn { expected });
+            },
+        }
+        self
+    }
+
+    /// Run the script, stopping at the first failed expectation
+    ///
+    /// A script built wrongly (an expectation before the first turn) fails
+    /// without running.
+    pub async fn run(&self, agent: &DomainAgent) -> Result<Vec<TurnOutcome>, ScriptError> {
+        if let Some(ref error) = self.error {
+            return Err(error.clone());
+        }
+        let mut outcomes = Vec::with_capacity(self.turns.len());
+
+        for (turn, script_turn) in self.turns.iter().enumerate() {
+            let outcome = Self::play(agent, turn, &script_turn.input).await?;
+            for expectation in &script_turn.expectations {
+                Self::check(agent, turn, &outcome, expectation)?;
+            }
+            outcomes.push(outcome);
+        }
+
+        Ok(outcomes)
+    }
+
+    async fn play(
+        agent: &DomainAgent,
+        turn: usize,
+        input: &str,
+    ) -> Result<TurnOutcome, ScriptError> {
+        let mut rx = agent.subscribe();
+        let response = agent.process(input).await.map_err(|e| ScriptError::Agent {
+            turn,
+            input: input.to_string(),
+            error: e.to_string(),
+        })?;
+
+        let mut events = Vec::new();
+        loop {
+            match rx.try_recv() {
+                Ok(event) => events.push(event),
+                Err(TryRecvError::Lagged(_)) => continue,
+                Err(_) => break,
+            }
+        }
+
+        let intent = events.iter().rev().find_map(|e| match e {
+            AgentEvent::Conversation(ConversationEvent::IntentDetected(detected)) => {
+                Some(detected.intent.clone())
+            },
+            _ => None,
+        });
+
+        Ok(TurnOutcome {
+            input: input.to_string(),
+            response,
+            stage: agent.stage(),
+            intent,
+            events,
+        })
+    }
+
+    fn check(
+        agent: &DomainAgent,
+        turn: usize,
+        outcome: &TurnOutcome,
+        expectation: &Expectation,
+    ) -> Result<(), ScriptError> {
+        let actual = match expectation {
+            Expectation::Stage(stage) => {
+                if outcome.stage == *stage {
+                    return Ok(());
+                }
+                format!("{:?}", outcome.stage)
+            },
+            Expectation::Slot { name, value } => {
+                let actual = agent.dialogue_state.read().state().get_slot_value(name);
+                if actual.as_deref() == Some(value.as_str()) {
+                    return Ok(());
+                }
+                format!("{:?}", actual)
+            },
+            Expectation::NoSlot(name) => {
+                let actual = agent.dialogue_state.read().state().get_slot_value(name);
+                if actual.is_none() {
+                    return Ok(());
+                }
+                format!("{:?}", actual)
+            },
+            Expectation::Intent(intent) => {
+                if outcome.intent.as_deref() == Some(intent.as_str()) {
+                    return Ok(());
+                }
+                format!("{:?}", outcome.intent)
+            },
+            Expectation::Response { check, .. } => {
+                if check(outcome.response.as_str()) {
+                    return Ok(());
+                }
+                format!("{:?}", outcome.response)
+            },
+            Expectation::Event { check, .. } => {
+                if outcome.events.iter().any(check.as_ref()) {
+                    return Ok(());
+                }
+                format!("{} other events", outcome.events.len())
+            },
+        };
+
+        Err(ScriptError::Mismatch {
+            turn,
+            input: outcome.input.clone(),
+            expected: expectation.describe(),
+            actual,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::agent::tests::slot_filling_domain_config;
+    use crate::agent::AgentConfig;
+
+    fn eligibility_agent() -> DomainAgent {
+        DomainAgent::new(
+            "test-script",
+            AgentConfig::default(),
+            slot_filling_domain_config(),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_script_runs_flow() {
+        let agent = eligibility_agent();
+
+        let outcomes = DialogueScript::new()
+            .turn("Am I eligible")
+            .expect_intent("eligibility_check")
+            .expect_response_contains("how much gold")
+            .expect_no_slot("asset_quantity")
+            .expect_event("response", |e| matches!(e, AgentEvent::Response(_)))
+            .turn("Hello")
+            .expect_response("non-empty response", |r| !r.is_empty())
+            .run(&agent)
+            .await
+            .unwrap();
+
+        assert_eq!(outcomes.len(), 2);
+        assert_eq!(outcomes[0].intent.as_deref(), Some("eligibility_check"));
+        assert!(!outcomes[1].events.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_script_reports_mismatch() {
+        let agent = eligibility_agent();
+
+        let err = DialogueScript::new()
+            .turn("Am I eligible")
+            .expect_intent("eligibility_check")
+            .expect_slot("asset_quantity", "50")
+            .run(&agent)
+            .await
+            .unwrap_err();
+
+        match err {
+            ScriptError::Mismatch {
+                turn,
+                input,
+                expected,
+                actual,
+            } => {
+                assert_eq!(turn, 0);
+                assert_eq!(input, "Am I eligible");
+                assert!(expected.contains("asset_quantity"));
+                assert_eq!(actual, "None");
+            },
+            other => panic!("unexpected error: {}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_later_turns_not_run_after_failure() {
+        let agent = eligibility_agent();
+
+        let err = DialogueScript::new()
+            .turn("Am I eligible")
+            .expect_response_contains("this text is not in the response")
+            .turn("Hello")
+            .run(&agent)
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, ScriptError::Mismatch { turn: 0, .. }));
+        assert!(err.to_string().contains("this text is not in the response"));
+    }
+
+    #[tokio::test]
+    async fn test_expectation_before_turn_fails_run() {
+        let agent = eligibility_agent();
+
+        let err = DialogueScript::new()
+            .expect_intent("eligibility_check")
+            .turn("Am I eligible")
+            .run(&agent)
+            .await
+            .unwrap_err();
+
+        assert_eq!(
+            err,
+            ScriptError::NoTurn {
+                expected: "intent \"eligibility_check\"".to_string()
+            }
+        );
+        // Nothing was played
+        assert_eq!(agent.conversation().turn_count(), 0);
+    }
+}
@@ -14,6 +14,7 @@
 //!       └────────────────── Audio Playback ◀─────────────────────────┘
 //! ```
 
+use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 use tokio::sync::{broadcast, mpsc, RwLock};
@@ -24,10 +25,11 @@ use voice_agent_pipeline::{
     stt::{IndicConformerConfig, StreamingStt, SttConfig, SttEngine},
     tts::{create_hindi_g2p, StreamingTts, TtsConfig, TtsEngine, TtsEvent},
     vad::{SileroConfig, SileroVad, VadResult, VadState},
+    PlaybackTracker, TtsFallback, TtsRecovery,
 };
 use voice_agent_transport::{SessionConfig, TransportEvent, TransportSession};
 
-use crate::{AgentConfig, AgentError, AgentEvent, DomainAgent};
+use crate::{AgentConfig, AgentError, AgentEvent, DomainAgent, EndReason};
 
 /// Voice session configuration
 #[derive(Debug, Clone)]
@@ -63,6 +65,19 @@ pub struct VoiceSessionConfig {
     /// Domain vocabulary entities for STT biasing (loaded from config)
     /// If empty, uses generic fallback entities
     pub stt_entities: Vec<String>,
+    /// Silence (ms) before the agent checks the user is still there
+    pub idle_prompt_ms: u64,
+    /// Silence (ms) after which the session ends with `EndReason::IdleTimeout`
+    pub idle_end_ms: u64,
+    /// Re-engagement prompts by language (loaded from config)
+    /// If empty, uses a generic English prompt
+    pub idle_prompts: HashMap<String, String>,
+    /// Closing lines by language, spoken before ending an idle session
+    pub idle_farewells: HashMap<String, String>,
+    /// Times a chunk TTS fails on is synthesized again before the fallback plays
+    pub tts_retries: usize,
+    /// Audio played, once per response, when TTS keeps failing
+    pub tts_fallback: TtsFallback,
 }
 
 impl Default for VoiceSessionConfig {
@@ -90,11 +105,56 @@ impl Default for VoiceSessionConfig {
             vad_model_path: None,
             stt_model_path: None,
             stt_entities: Vec::new(), // Will be loaded from domain config
+            idle_prompt_ms: 10_000,
+            idle_end_ms: 25_000,
+            idle_prompts: HashMap::new(), // Will be loaded from domain config
+            idle_farewells: HashMap::new(),
+            tts_retries: 1,
+            tts_fallback: TtsFallback::default(),
         }
     }
 }
 
 impl VoiceSessionConfig {
+    /// Take re-engagement prompts and closing lines from domain prompts config
+    pub fn with_domain_prompts(mut self, prompts: &voice_agent_config::PromptsConfig) -> Self {
+        self.idle_prompts = prompts.idle_prompts.clone();
+        self.idle_farewells = prompts.idle_farewells.clone();
+        self
+    }
+
+    /// Take TTS retries and fallback from the `pipeline.tts` config section
+    pub fn with_tts_settings(mut self, settings: &voice_agent_config::pipeline::TtsConfig) -> Self {
+        self.tts_retries = settings.retries;
+        self.tts_fallback = TtsFallback::from_settings(&settings.fallback);
+        self
+    }
+
+    /// Silence tracking with this config's thresholds
+    pub fn idle_monitor(&self) -> IdleMonitor {
+        IdleMonitor::new(
+            Duration::from_millis(self.idle_prompt_ms),
+            Duration::from_millis(self.idle_end_ms),
+        )
+    }
+
+    /// Retries and fallback for one response
+    fn tts_recovery(&self) -> TtsRecovery {
+        TtsRecovery::new(self.tts_retries, self.tts_fallback.clone())
+    }
+
+    /// Re-engagement prompt for `language`, falling back to English
+    pub fn idle_prompt(&self, language: &str) -> &str {
+        localized(&self.idle_prompts, language)
+            .unwrap_or("Are you still there? I'm here whenever you're ready.")
+    }
+
+    /// Closing line for an idle session in `language`, falling back to English
+    pub fn idle_farewell(&self, language: &str) -> &str {
+        localized(&self.idle_farewells, language)
+            .unwrap_or("It seems you've stepped away, so I'll end the call here. Goodbye!")
+    }
+
     /// Get STT entities for entity boosting
     ///
     /// Returns config-driven entities if available, otherwise falls back
@@ -123,6 +183,79 @@ impl VoiceSessionConfig {
     }
 }
 
+fn localized<'a>(templates: &'a HashMap<String, String>, language: &str) -> Option<&'a str> {
+    templates
+        .get(language)
+        .or_else(|| templates.get("en"))
+        .map(|s| s.as_str())
+}
+
+/// What to do about a user who has gone quiet
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IdleAction {
+    /// Keep listening
+    None,
+    /// Ask whether the user is still there
+    Prompt,
+    /// End the session
+    End,
+}
+
+/// Tracks how long the user has been silent
+///
+/// The clock restarts whenever the user speaks or the agent finishes
+/// answering them; the re-engagement prompt itself does not restart it.
+/// Sessions driven outside `VoiceSession` (the WebSocket handler) poll one
+/// of these directly.
+#[derive(Debug)]
+pub struct IdleMonitor {
+    prompt_after: Duration,
+    end_after: Duration,
+    silent_since: Option<Instant>,
+    prompted: bool,
+}
+
+impl IdleMonitor {
+    pub fn new(prompt_after: Duration, end_after: Duration) -> Self {
+        Self {
+            prompt_after,
+            end_after,
+            silent_since: None,
+            prompted: false,
+        }
+    }
+
+    /// Start counting silence from `now`
+    pub fn reset(&mut self, now: Instant) {
+        self.silent_since = Some(now);
+        self.prompted = false;
+    }
+
+    /// Stop counting while a turn is under way
+    pub fn pause(&mut self) {
+        self.silent_since = None;
+        self.prompted = false;
+    }
+
+    /// What to do about the silence as of `now`
+    pub fn poll(&mut self, now: Instant) -> IdleAction {
+        let Some(since) = self.silent_since else {
+            return IdleAction::None;
+        };
+        let silent_for = now.saturating_duration_since(since);
+
+        if silent_for >= self.end_after {
+            self.silent_since = None;
+            IdleAction::End
+        } else if silent_for >= self.prompt_after && !self.prompted {
+            self.prompted = true;
+            IdleAction::Prompt
+        } else {
+            IdleAction::None
+        }
+    }
+}
+
 /// Voice session state
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum VoiceSessionState {
@@ -158,6 +291,8 @@ pub enum VoiceSessionEvent {
     AudioChunk { samples: Vec<f32>, sample_rate: u32 },
     /// Barge-in detected
     BargedIn,
+    /// User went quiet; the agent is checking they are still there
+    IdlePrompt { text: String },
     /// Agent event
     Agent(AgentEvent),
     /// Error occurred
@@ -190,6 +325,10 @@ pub struct VoiceSession {
     last_voice_activity: Arc<RwLock<Option<Instant>>>,
     /// VAD state for speech detection
     vad_state: Arc<RwLock<VadState>>,
+    /// Silence tracking for re-engagement and idle timeout
+    idle: Arc<parking_lot::Mutex<IdleMonitor>>,
+    /// What the caller heard of the current utterance
+    playback: Arc<parking_lot::Mutex<PlaybackTracker>>,
 }
 
 impl VoiceSession {
@@ -221,6 +360,7 @@ impl VoiceSession {
 
         // Create TTS
         let tts = Arc::new(StreamingTts::simple(config.tts.clone()));
+        tts.set_language(agent.user_language());
 
         // Create VAD if enabled
         let vad = if config.use_silero_vad {
@@ -244,6 +384,8 @@ impl VoiceSession {
             None
         };
 
+        let idle = config.idle_monitor();
+
         Ok(Self {
             session_id,
             config,
@@ -260,6 +402,8 @@ impl VoiceSession {
             shutdown_tx,
             last_voice_activity: Arc::new(RwLock::new(None)),
             vad_state: Arc::new(RwLock::new(VadState::Silence)),
+            idle: Arc::new(parking_lot::Mutex::new(idle)),
+            playback: Arc::new(parking_lot::Mutex::new(PlaybackTracker::new())),
         })
     }
 
@@ -308,6 +452,7 @@ impl VoiceSession {
         // Play greeting
         let greeting = self.agent.process("").await?;
         self.speak(&greeting).await?;
+        self.idle.lock().reset(Instant::now());
 
         Ok(())
     }
@@ -319,6 +464,8 @@ impl VoiceSession {
         let event_tx = self.event_tx.clone();
         let config = self.config.clone();
         let last_voice_activity = Arc::clone(&self.last_voice_activity);
+        let idle = Arc::clone(&self.idle);
+        let playback = Arc::clone(&self.playback);
         let _transport_event_tx = self.transport_event_tx.clone();
         let mut shutdown_rx = self.shutdown_tx.subscribe();
 
@@ -364,6 +511,7 @@ impl VoiceSession {
 
                                         if energy > config.vad_energy_threshold {
                                             *last_voice_activity.write().await = Some(Instant::now());
+                                            idle.lock().reset(Instant::now());
 
                                             // Process through STT
                                             if let Some(result) = stt.process(&samples)
@@ -446,20 +594,46 @@ impl VoiceSession {
 
                                     let g2p = create_hindi_g2p();
                                     if let Ok(_phonemes) = g2p.convert(&response) {
-                                        let (tts_tx, mut tts_rx) = mpsc::channel::<TtsEvent>(10);
+                                        let (tts_tx, _tts_rx) = mpsc::channel::<TtsEvent>(10);
+                                        let voice = agent.tts_config(&config.tts);
+                                        tts.set_voice(voice.speaking_rate, voice.pitch);
+                                        tts.set_style(agent.tts_style());
                                         tts.start(&response, tts_tx);
-
-                                        // Process TTS chunks
-                                        while let Some(tts_event) = tts_rx.recv().await {
+                                        playback.lock().reset();
+
+                                        // Process TTS chunks, retrying ones that fail
+                                        let mut recovery = config.tts_recovery();
+                                        let mut remaining = response.clone();
+                                        while let Some(tts_event) =
+                                            recovery.next_event(&tts, &mut remaining)
+                                        {
                                             match tts_event {
-                                                TtsEvent::Audio { samples, is_final, .. } => {
+                                                TtsEvent::Audio { samples, text, is_final, .. } => {
+                                                    let rate = tts.sample_rate();
+                                                    track_audio(&playback, &text, samples.len(), rate);
                                                     let _ = audio_out_tx.send(samples.to_vec()).await;
                                                     if is_final {
                                                         break;
                                                     }
                                                 }
                                                 TtsEvent::Complete => break,
-                                                TtsEvent::BargedIn { .. } => break,
+                                                TtsEvent::BargedIn { .. } => {
+                                                    let heard = playback.lock().played_text();
+                                                    agent.record_interrupted_response(&heard);
+                                                    break;
+                                                }
+                                                TtsEvent::Error(e) => {
+                                                    // Only what was sent reaches the caller
+                                                    let heard = playback.lock().sent_text();
+                                                    agent.record_interrupted_response(&heard);
+                                                    let rate = tts.sample_rate();
+                                                    let fallback = recovery.fallback_samples(rate);
+                                                    if !fallback.is_empty() {
+                                                        let _ = audio_out_tx.send(fallback).await;
+                                                    }
+                                                    let _ = event_tx.send(VoiceSessionEvent::Error(e));
+                                                    break;
+                                                }
                                                 _ => {}
                                             }
                                         }
@@ -470,6 +644,7 @@ impl VoiceSession {
                             // Reset for next turn
                             stt.reset();
                             *last_voice_activity.write().await = None;
+                            idle.lock().reset(Instant::now());
                             *state.write().await = VoiceSessionState::Listening;
                         }
                     }
@@ -543,6 +718,10 @@ impl VoiceSession {
 
         match state {
             VoiceSessionState::Listening => {
+                if self.detect_voice_activity(samples).0 {
+                    self.idle.lock().reset(Instant::now());
+                }
+
                 // Process through STT
                 if let Some(result) = self
                     .stt
@@ -596,6 +775,7 @@ impl VoiceSession {
 
         // Speak response
         self.speak(&response).await?;
+        self.idle.lock().reset(Instant::now());
 
         // Reset STT for next turn
         self.stt.reset();
@@ -603,6 +783,53 @@ impl VoiceSession {
         Ok(())
     }
 
+    /// Re-engage or end the session if the user has gone quiet
+    ///
+    /// Call periodically while the session runs (like `end_user_turn`, this is
+    /// driven by the owner of the session). Once silence passes
+    /// `idle_prompt_ms` the agent asks whether the user is still there; at
+    /// `idle_end_ms` it says goodbye and ends with `EndReason::IdleTimeout`.
+    pub async fn check_idle(&self) -> Result<IdleAction, AgentError> {
+        self.check_idle_at(Instant::now()).await
+    }
+
+    /// `check_idle` as of `now`
+    pub async fn check_idle_at(&self, now: Instant) -> Result<IdleAction, AgentError> {
+        if *self.state.read().await != VoiceSessionState::Listening {
+            return Ok(IdleAction::None);
+        }
+
+        let action = self.idle.lock().poll(now);
+        let language = &self.config.agent.language;
+        match action {
+            IdleAction::None => {},
+            IdleAction::Prompt => {
+                let prompt = self.config.idle_prompt(language).to_string();
+                tracing::info!(session_id = %self.session_id, "User silent, re-engaging");
+                let _ = self.event_tx.send(VoiceSessionEvent::IdlePrompt {
+                    text: prompt.clone(),
+                });
+                self.speak(&prompt).await?;
+            },
+            IdleAction::End => {
+                tracing::info!(session_id = %self.session_id, "User silent, ending session");
+                let farewell = self.config.idle_farewell(language).to_string();
+                if let Err(e) = self.speak(&farewell).await {
+                    tracing::warn!("Failed to speak idle farewell: {}", e);
+                }
+                // Audits open compliance gaps; a disclosure never given is spoken last
+                if let Some(closing) = self.agent.end_call(EndReason::IdleTimeout).await {
+                    if let Err(e) = self.speak(&closing).await {
+                        tracing::warn!("Failed to speak closing disclosure: {}", e);
+                    }
+                }
+                self.end("idle_timeout").await;
+            },
+        }
+
+        Ok(action)
+    }
+
     /// Speak text using TTS
     async fn speak(&self, text: &str) -> Result<(), AgentError> {
         self.set_state(VoiceSessionState::Speaking).await;
@@ -617,20 +844,28 @@ impl VoiceSession {
             .convert(text)
             .map_err(|e| AgentError::Pipeline(e.to_string()))?;
 
-        // Start TTS
+        // Start TTS in the style the agent picked for this turn, in the
+        // caller's current language
         let (tts_tx, mut tts_rx) = mpsc::channel::<TtsEvent>(10);
+        let voice = self.agent.tts_config(&self.config.tts);
+        self.tts.set_voice(voice.speaking_rate, voice.pitch);
+        self.tts.set_style(self.agent.tts_style());
+        self.tts.set_language(self.agent.user_language());
         self.tts.start(text, tts_tx);
+        self.playback.lock().reset();
 
-        // Process TTS chunks
+        // Process TTS chunks, retrying ones that fail
+        let mut recovery = self.config.tts_recovery();
+        let mut remaining = text.to_string();
         loop {
-            match self
-                .tts
-                .process_next()
-                .map_err(|e| AgentError::Pipeline(e.to_string()))?
-            {
+            match recovery.next_event(&self.tts, &mut remaining) {
                 Some(TtsEvent::Audio {
-                    samples, is_final, ..
+                    samples,
+                    text,
+                    is_final,
+                    ..
                 }) => {
+                    track_audio(&self.playback, &text, samples.len(), self.tts.sample_rate());
                     let _ = self.event_tx.send(VoiceSessionEvent::AudioChunk {
                         samples: samples.to_vec(),
                         sample_rate: self.tts.sample_rate(),
@@ -642,12 +877,22 @@ impl VoiceSession {
                 },
                 Some(TtsEvent::Complete) => break,
                 Some(TtsEvent::BargedIn { .. }) => {
+                    self.agent
+                        .record_interrupted_response(&self.playback.lock().played_text());
                     let _ = self.event_tx.send(VoiceSessionEvent::BargedIn);
                     break;
                 },
                 Some(TtsEvent::Error(e)) => {
-                    return Err(AgentError::Pipeline(e));
+                    let fallback = recovery.fallback_samples(self.tts.sample_rate());
+                    if !fallback.is_empty() {
+                        let _ = self.event_tx.send(VoiceSessionEvent::AudioChunk {
+                            samples: fallback,
+                            sample_rate: self.tts.sample_rate(),
+                        });
+                    }
+                    return Err(self.tts_failed(e));
                 },
+                None => break,
                 _ => {},
             }
 
@@ -663,9 +908,23 @@ impl VoiceSession {
         Ok(())
     }
 
+    /// Record a response TTS failed on as not fully delivered
+    ///
+    /// The agent keeps only what was sent to the caller, so the next turn can
+    /// give the rest again.
+    fn tts_failed(&self, error: String) -> AgentError {
+        tracing::warn!(error = %error, "TTS failed, response not delivered");
+        self.agent
+            .record_interrupted_response(&self.playback.lock().sent_text());
+        AgentError::Pipeline(error)
+    }
+
     /// Handle barge-in during TTS
     async fn handle_barge_in(&self) -> Result<(), AgentError> {
         self.tts.barge_in();
+        let heard = self.playback.lock().played_text();
+        self.agent.record_interrupted_response(&heard);
+        self.playback.lock().reset();
 
         let _ = self.event_tx.send(VoiceSessionEvent::BargedIn);
 
@@ -802,6 +1061,18 @@ impl VoiceSession {
     }
 }
 
+/// Record a TTS chunk of `samples` at `sample_rate` carrying `text` as sent
+fn track_audio(
+    playback: &parking_lot::Mutex<PlaybackTracker>,
+    text: &str,
+    samples: usize,
+    sample_rate: u32,
+) {
+    let mut playback = playback.lock();
+    playback.text(text);
+    playback.audio(Duration::from_secs_f64(samples as f64 / sample_rate as f64));
+}
+
 /// Calculate RMS energy of audio samples
 fn calculate_energy(samples: &[f32]) -> f32 {
     if samples.is_empty() {
@@ -887,4 +1158,99 @@ mod tests {
         assert_eq!(config.audio_poll_interval_ms, 20);
         assert!(config.vad_energy_threshold > 0.0);
     }
+
+    fn idle_config() -> VoiceSessionConfig {
+        VoiceSessionConfig {
+            idle_prompt_ms: 10_000,
+            idle_end_ms: 20_000,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_idle_monitor_thresholds() {
+        let secs = Duration::from_secs;
+        let start = Instant::now();
+        let mut idle = IdleMonitor::new(secs(10), secs(20));
+        assert_eq!(idle.poll(start + secs(30)), IdleAction::None);
+
+        idle.reset(start);
+        assert_eq!(idle.poll(start + secs(9)), IdleAction::None);
+        assert_eq!(idle.poll(start + secs(10)), IdleAction::Prompt);
+        // Prompts once per silence
+        assert_eq!(idle.poll(start + secs(15)), IdleAction::None);
+
+        // The user answers, so the clock starts over
+        let spoke = start + secs(16);
+        idle.reset(spoke);
+        assert_eq!(idle.poll(spoke + secs(10)), IdleAction::Prompt);
+        assert_eq!(idle.poll(spoke + secs(20)), IdleAction::End);
+        assert_eq!(idle.poll(spoke + secs(30)), IdleAction::None);
+
+        // Nothing is counted while a turn is under way
+        idle.reset(spoke);
+        idle.pause();
+        assert_eq!(idle.poll(spoke + secs(30)), IdleAction::None);
+    }
+
+    #[tokio::test]
+    async fn test_prolonged_silence_prompts_then_ends() {
+        let session = VoiceSession::new("test-idle", idle_config()).unwrap();
+        session.start().await.unwrap();
+        let silent_from = Instant::now();
+        let mut events = session.subscribe();
+
+        let at = |secs| silent_from + Duration::from_secs(secs);
+        let action = session.check_idle_at(at(5)).await.unwrap();
+        assert_eq!(action, IdleAction::None);
+
+        // First threshold: the agent checks in and keeps listening
+        let action = session.check_idle_at(at(10)).await.unwrap();
+        assert_eq!(action, IdleAction::Prompt);
+        assert_eq!(session.state().await, VoiceSessionState::Listening);
+        let mut prompt = None;
+        while let Ok(event) = events.try_recv() {
+            if let VoiceSessionEvent::IdlePrompt { text } = event {
+                prompt = Some(text);
+            }
+        }
+        assert_eq!(prompt.as_deref(), Some(session.config.idle_prompt("en")));
+
+        let action = session.check_idle_at(at(15)).await.unwrap();
+        assert_eq!(action, IdleAction::None);
+
+        // Second threshold: the call is closed
+        let action = session.check_idle_at(at(20)).await.unwrap();
+        assert_eq!(action, IdleAction::End);
+        assert_eq!(session.state().await, VoiceSessionState::Ended);
+        assert_eq!(
+            session.agent().conversation().state(),
+            crate::ConversationState::Ended
+        );
+        let mut ended = None;
+        while let Ok(event) = events.try_recv() {
+            if let VoiceSessionEvent::Ended { reason } = event {
+                ended = Some(reason);
+            }
+        }
+        assert_eq!(ended.as_deref(), Some("idle_timeout"));
+    }
+
+    #[test]
+    fn test_idle_prompts_by_language() {
+        let mut prompts = voice_agent_config::PromptsConfig::default();
+        prompts
+            .idle_prompts
+            .insert("en".to_string(), "Still with me?".to_string());
+        prompts
+            .idle_prompts
+            .insert("hi".to_string(), "क्या आप लाइन पर हैं?".to_string());
+        let config = VoiceSessionConfig::default().with_domain_prompts(&prompts);
+
+        assert_eq!(config.idle_prompt("hi"), "क्या आप लाइन पर हैं?");
+        // Unknown languages fall back to English
+        assert_eq!(config.idle_prompt("ta"), "Still with me?");
+        // No closing lines configured: built-in default
+        assert!(!config.idle_farewell("hi").is_empty());
+    }
 }
@@ -317,6 +317,7 @@ async fn test_silero_vad_config() {
         min_speech_frames: 4,
         min_silence_frames: 6,
         energy_floor_db: -45.0,
+        hangover_ms: 0,
     };
 
     let session = VoiceSession::new("test-silero-config", config.clone()).unwrap();
@@ -758,7 +759,8 @@ async fn test_tool_registry_integration() {
     // Original 5: check_eligibility, calculate_savings, capture_lead, schedule_appointment, find_branches
     // P0 added 3: get_gold_price, escalate_to_human, send_sms
     // Phase 6 added 2: get_document_checklist, compare_lenders
-    assert_eq!(registry.len(), 10);
+    // Plus change_appointment to reschedule or cancel
+    assert_eq!(registry.len(), 11);
 
     // Test executing each tool type
     let eligibility_result = registry
@@ -9,6 +9,8 @@
 //! Use MasterDomainConfig.brand for the real values.
 
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use voice_agent_core::LanguageFallbackChain;
 
 use crate::constants::endpoints;
 use crate::settings::RagConfig;
@@ -24,6 +26,10 @@ pub struct AgentConfig {
     #[serde(default = "default_agent_language")]
     pub language: String,
 
+    /// Languages to fall back to when translation doesn't support `language`
+    #[serde(default)]
+    pub language_fallbacks: LanguageFallbackChain,
+
     /// Maximum conversation duration (seconds)
     #[serde(default = "default_max_duration")]
     pub max_duration_seconds: u32,
@@ -47,6 +53,18 @@ pub struct AgentConfig {
     /// Memory configuration
     #[serde(default)]
     pub memory: MemoryConfig,
+
+    /// Redirect of questions outside the domain
+    #[serde(default)]
+    pub out_of_scope: OutOfScopeConfig,
+
+    /// Per-turn routing between retrieval and tools
+    #[serde(default)]
+    pub routing: RoutingConfig,
+
+    /// Collect per-turn debug output (development only)
+    #[serde(default)]
+    pub turn_debug: bool,
 }
 
 fn default_agent_name() -> String {
@@ -68,12 +86,16 @@ impl Default for AgentConfig {
         Self {
             name: default_agent_name(),
             language: default_agent_language(),
+            language_fallbacks: LanguageFallbackChain::default(),
             max_duration_seconds: default_max_duration(),
             tools_enabled: true,
             persona: PersonaConfig::default(),
             llm: LlmConfig::default(),
             rag: RagConfig::default(),
             memory: MemoryConfig::default(),
+            out_of_scope: OutOfScopeConfig::default(),
+            routing: RoutingConfig::default(),
+            turn_debug: false,
         }
     }
 }
@@ -173,6 +195,74 @@ pub struct LlmConfig {
     /// Speculative mode
     #[serde(default = "default_speculative_mode")]
     pub speculative_mode: SpeculativeMode,
+
+    /// Token pricing for session cost accounting
+    #[serde(default)]
+    pub pricing: LlmPricingConfig,
+
+    /// Cap on concurrent calls, shared by all sessions on the node
+    #[serde(default)]
+    pub concurrency: ConcurrencyLimitSettings,
+}
+
+/// Per-model token pricing for session cost accounting
+///
+/// Models not listed are priced at `default`, which is zero unless set, so
+/// local Ollama models cost nothing.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct LlmPricingConfig {
+    /// Pricing by model name
+    #[serde(default)]
+    pub models: HashMap<String, ModelPriceConfig>,
+    /// Pricing for models not in `models`
+    #[serde(default)]
+    pub default: ModelPriceConfig,
+}
+
+/// Price of one model, in USD per 1,000 tokens
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub struct ModelPriceConfig {
+    /// Prompt (input) tokens
+    #[serde(default)]
+    pub input_per_1k: f64,
+    /// Completion (output) tokens
+    #[serde(default)]
+    pub output_per_1k: f64,
+}
+
+/// Cap on concurrent calls to a backend shared by every session on the node
+///
+/// Unlimited unless `max_in_flight` is set. Callers beyond it wait up to
+/// `max_wait_ms` for a slot, and once `max_queued` are waiting new callers
+/// are turned away.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ConcurrencyLimitSettings {
+    /// Calls in flight at once (0 = unlimited)
+    #[serde(default)]
+    pub max_in_flight: usize,
+    /// Callers waiting for a slot before new callers are rejected
+    #[serde(default = "default_max_queued")]
+    pub max_queued: usize,
+    /// How long a caller waits for a slot (ms)
+    #[serde(default = "default_max_wait_ms")]
+    pub max_wait_ms: u64,
+}
+
+impl Default for ConcurrencyLimitSettings {
+    fn default() -> Self {
+        Self {
+            max_in_flight: 0,
+            max_queued: default_max_queued(),
+            max_wait_ms: default_max_wait_ms(),
+        }
+    }
+}
+
+fn default_max_queued() -> usize {
+    16
+}
+fn default_max_wait_ms() -> u64 {
+    2000
 }
 
 fn default_llm_provider() -> LlmProvider {
@@ -209,6 +299,8 @@ impl Default for LlmConfig {
             temperature: default_temperature(),
             speculative_enabled: true,
             speculative_mode: default_speculative_mode(),
+            pricing: LlmPricingConfig::default(),
+            concurrency: ConcurrencyLimitSettings::default(),
         }
     }
 }
@@ -279,6 +371,14 @@ pub struct MemoryConfig {
     /// P1 FIX: Low watermark - target after truncation
     #[serde(default = "default_low_watermark_tokens")]
     pub low_watermark_tokens: usize,
+
+    /// Weight of recency when ranking earlier memories for query context
+    #[serde(default)]
+    pub recency_weight: f32,
+
+    /// Weight of relevance to the query in the same ranking
+    #[serde(default = "default_relevance_weight")]
+    pub relevance_weight: f32,
 }
 
 fn default_working_memory() -> usize {
@@ -299,6 +399,9 @@ fn default_high_watermark_tokens() -> usize {
 fn default_low_watermark_tokens() -> usize {
     2048 // 50% - target after cleanup
 }
+fn default_relevance_weight() -> f32 {
+    1.0
+}
 
 impl Default for MemoryConfig {
     fn default() -> Self {
@@ -310,6 +413,67 @@ impl Default for MemoryConfig {
             max_context_tokens: default_max_context_tokens(),
             high_watermark_tokens: default_high_watermark_tokens(),
             low_watermark_tokens: default_low_watermark_tokens(),
+            recency_weight: 0.0,
+            relevance_weight: default_relevance_weight(),
+        }
+    }
+}
+
+/// Out-of-scope redirect configuration
+///
+/// The redirect itself is the domain's `out_of_scope` response template.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OutOfScopeConfig {
+    /// Enable the redirect
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Intent confidence below which a turn may be out of scope
+    #[serde(default = "default_out_of_scope_confidence")]
+    pub max_intent_confidence: f32,
+
+    /// Per-language overrides of `max_intent_confidence`, by language code
+    #[serde(default)]
+    pub language_confidence: HashMap<String, f32>,
+}
+
+/// Per-turn routing between retrieval and tools
+///
+/// When disabled every turn retrieves and may call tools.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RoutingConfig {
+    /// Enable routing
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+
+    /// Intents answered without retrieval or tools
+    #[serde(default = "default_smalltalk_intents")]
+    pub smalltalk_intents: Vec<String>,
+}
+
+fn default_smalltalk_intents() -> Vec<String> {
+    vec!["greeting".to_string(), "farewell".to_string()]
+}
+
+impl Default for RoutingConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            smalltalk_intents: default_smalltalk_intents(),
+        }
+    }
+}
+
+fn default_out_of_scope_confidence() -> f32 {
+    0.4
+}
+
+impl Default for OutOfScopeConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            max_intent_confidence: default_out_of_scope_confidence(),
+            language_confidence: HashMap::new(),
         }
     }
 }
@@ -145,6 +145,12 @@ pub mod audio {
 
     /// Minimum consecutive silence frames to confirm speech end (300ms at 10ms frames)
     pub const VAD_MIN_SILENCE_FRAMES: usize = 30;
+
+    /// Audio before speech onset handed to STT with the utterance (ms)
+    pub const VAD_PRE_ROLL_MS: u32 = 200;
+
+    /// Extra silence after speech offset before declaring silence (ms)
+    pub const VAD_HANGOVER_MS: u32 = 0;
 }
 
 /// P1-4 FIX: Turn detection timing constants
@@ -18,6 +18,11 @@ pub struct BranchesConfig {
     /// Mobile/doorstep service configuration
     #[serde(default)]
     pub doorstep_service: DoorstepServiceConfig,
+    /// PIN code prefixes and the cities they belong to
+    ///
+    /// Replaces the bundled table used to resolve PIN codes when not empty.
+    #[serde(default)]
+    pub pincode_regions: Vec<PincodeRegionEntry>,
 }
 
 impl Default for BranchesConfig {
@@ -26,6 +31,7 @@ impl Default for BranchesConfig {
             branches: Vec::new(),
             defaults: BranchDefaults::default(),
             doorstep_service: DoorstepServiceConfig::default(),
+            pincode_regions: Vec::new(),
         }
     }
 }
@@ -121,6 +127,21 @@ pub struct BranchEntry {
     pub facilities: Vec<String>,
 }
 
+/// City and state that a PIN code prefix belongs to
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PincodeRegionEntry {
+    /// PIN code prefix; the longest matching prefix wins
+    pub prefix: String,
+    pub city: String,
+    #[serde(default)]
+    pub state: String,
+    /// Approximate centre of the region, used as the caller's location
+    #[serde(default)]
+    pub latitude: Option<f64>,
+    #[serde(default)]
+    pub longitude: Option<f64>,
+}
+
 fn default_true() -> bool {
     true
 }
@@ -228,6 +249,26 @@ defaults:
         assert_eq!(config.branches[0].branch_id, "LOC001");
         assert!(config.branches[0].service_available);
         assert_eq!(config.defaults.max_results, 10);
+        assert!(config.pincode_regions.is_empty());
+    }
+
+    #[test]
+    fn test_pincode_regions_deserialization() {
+        let yaml = r#"
+pincode_regions:
+  - prefix: "400"
+    city: "Mumbai"
+    state: "Maharashtra"
+    latitude: 19.076
+    longitude: 72.8777
+  - prefix: "4006"
+    city: "Thane"
+"#;
+        let config: BranchesConfig = serde_yaml::from_str(yaml).unwrap();
+        assert_eq!(config.pincode_regions.len(), 2);
+        assert_eq!(config.pincode_regions[0].latitude, Some(19.076));
+        assert_eq!(config.pincode_regions[1].city, "Thane");
+        assert!(config.pincode_regions[1].longitude.is_none());
     }
 
     #[test]
@@ -279,6 +320,7 @@ branches:
             ],
             defaults: BranchDefaults::default(),
             doorstep_service: DoorstepServiceConfig::default(),
+            pincode_regions: Vec::new(),
         };
 
         let mumbai = config.find_by_city("mumbai");
@@ -317,6 +359,7 @@ branches:
             ],
             defaults: BranchDefaults::default(),
             doorstep_service: DoorstepServiceConfig::default(),
+            pincode_regions: Vec::new(),
         };
 
         let service_locs = config.service_locations();
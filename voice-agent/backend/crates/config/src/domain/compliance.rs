@@ -57,6 +57,18 @@ pub struct ComplianceConfig {
     /// Key is language code (en, hi, mr, ta, etc.), value is the disclosure message
     #[serde(default)]
     pub ai_disclosures: HashMap<String, String>,
+
+    /// Recording consent question asked after the AI disclosure, by language
+    #[serde(default)]
+    pub consent_prompts: HashMap<String, String>,
+
+    /// Steps every call opens with, and their text
+    #[serde(default)]
+    pub opening_script: OpeningScriptConfig,
+
+    /// Replies to the customer's answer to the consent question
+    #[serde(default)]
+    pub consent_acknowledgements: ConsentAcknowledgements,
 }
 
 fn default_version() -> String {
@@ -136,6 +148,84 @@ fn default_true() -> bool {
     true
 }
 
+/// Step of the call opening
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OpeningStep {
+    /// The AI disclosure (`ai_disclosures`)
+    AiDisclosure,
+    /// The recording consent question (`consent_prompts`); the opening
+    /// waits here for the answer
+    Consent,
+    /// The greeting from the prompts config
+    Greeting,
+    /// What the agent can help with (`opening_script.purposes`)
+    Purpose,
+}
+
+impl OpeningStep {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::AiDisclosure => "ai_disclosure",
+            Self::Consent => "consent",
+            Self::Greeting => "greeting",
+            Self::Purpose => "purpose",
+        }
+    }
+}
+
+/// Opening script spoken before normal dialog starts
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OpeningScriptConfig {
+    /// Steps in the order they are spoken
+    #[serde(default = "default_opening_steps")]
+    pub steps: Vec<OpeningStep>,
+    /// Purpose statement by language (brand placeholders allowed)
+    #[serde(default)]
+    pub purposes: HashMap<String, String>,
+    /// Closing line when the customer refuses consent, by language
+    #[serde(default)]
+    pub refusal_messages: HashMap<String, String>,
+    /// End the call when consent is refused; otherwise the opening goes on
+    /// without recording
+    #[serde(default = "default_true")]
+    pub end_call_on_refusal: bool,
+}
+
+impl Default for OpeningScriptConfig {
+    fn default() -> Self {
+        Self {
+            steps: default_opening_steps(),
+            purposes: HashMap::new(),
+            refusal_messages: HashMap::new(),
+            end_call_on_refusal: true,
+        }
+    }
+}
+
+/// Replies to the consent answer, by language
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct ConsentAcknowledgements {
+    /// Reply when the customer agrees to recording
+    #[serde(default)]
+    pub given: HashMap<String, String>,
+    /// Reply when the customer refuses and the call goes on unrecorded
+    #[serde(default)]
+    pub refused: HashMap<String, String>,
+    /// Question that follows the reply when no opening script continues
+    #[serde(default)]
+    pub follow_up: HashMap<String, String>,
+}
+
+fn default_opening_steps() -> Vec<OpeningStep> {
+    vec![
+        OpeningStep::AiDisclosure,
+        OpeningStep::Consent,
+        OpeningStep::Greeting,
+        OpeningStep::Purpose,
+    ]
+}
+
 /// Regulatory body information
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct RegulatoryInfo {
@@ -244,21 +334,7 @@ impl ComplianceConfig {
         }
 
         // Try normalized language code (e.g., "hindi" -> "hi")
-        let normalized = match language {
-            "hindi" => "hi",
-            "marathi" => "mr",
-            "tamil" => "ta",
-            "telugu" => "te",
-            "bengali" => "bn",
-            "gujarati" => "gu",
-            "kannada" => "kn",
-            "malayalam" => "ml",
-            "punjabi" => "pa",
-            "odia" => "or",
-            _ => language,
-        };
-
-        if let Some(msg) = self.ai_disclosures.get(normalized) {
+        if let Some(msg) = self.ai_disclosures.get(normalize_language(language)) {
             return msg.as_str();
         }
 
@@ -270,6 +346,93 @@ impl ComplianceConfig {
         // Default message if nothing configured
         "This is an AI assistant. You can speak with a human agent at any time by saying 'speak to agent'."
     }
+
+    /// Get the recording consent question for a language
+    ///
+    /// Falls back to English, then to a default question.
+    pub fn get_consent_prompt(&self, language: &str) -> &str {
+        self.consent_prompts
+            .get(language)
+            .or_else(|| self.consent_prompts.get(normalize_language(language)))
+            .or_else(|| self.consent_prompts.get("en"))
+            .map(|s| s.as_str())
+            .unwrap_or(DEFAULT_CONSENT_PROMPT)
+    }
+
+    /// Get the opening purpose statement for a language
+    ///
+    /// Falls back to English, then to a default statement.
+    pub fn get_opening_purpose(&self, language: &str) -> &str {
+        localized(&self.opening_script.purposes, language).unwrap_or(DEFAULT_OPENING_PURPOSE)
+    }
+
+    /// Get the closing line for a consent refusal during the opening
+    ///
+    /// Falls back to English, then to a default line.
+    pub fn get_opening_refusal(&self, language: &str) -> &str {
+        localized(&self.opening_script.refusal_messages, language)
+            .unwrap_or(DEFAULT_OPENING_REFUSAL)
+    }
+
+    /// Get the reply to the consent answer for a language
+    ///
+    /// Falls back to English, then to a default reply.
+    pub fn get_consent_acknowledgement(&self, given: bool, language: &str) -> &str {
+        let acknowledgements = &self.consent_acknowledgements;
+        if given {
+            localized(&acknowledgements.given, language).unwrap_or(DEFAULT_CONSENT_GIVEN)
+        } else {
+            localized(&acknowledgements.refused, language).unwrap_or(DEFAULT_CONSENT_REFUSED)
+        }
+    }
+
+    /// Get the question asked after the consent reply for a language
+    ///
+    /// Falls back to English, then to a default question.
+    pub fn get_consent_follow_up(&self, language: &str) -> &str {
+        localized(&self.consent_acknowledgements.follow_up, language)
+            .unwrap_or(DEFAULT_CONSENT_FOLLOW_UP)
+    }
+}
+
+/// Text for a language, its normalized code, or English
+fn localized<'a>(texts: &'a HashMap<String, String>, language: &str) -> Option<&'a str> {
+    texts
+        .get(language)
+        .or_else(|| texts.get(normalize_language(language)))
+        .or_else(|| texts.get("en"))
+        .map(|s| s.as_str())
+}
+
+const DEFAULT_CONSENT_PROMPT: &str =
+    "This call may be recorded for quality and compliance. Is that okay with you?";
+
+const DEFAULT_OPENING_PURPOSE: &str = "I can help you with {product_name} today.";
+
+const DEFAULT_OPENING_REFUSAL: &str = "I understand. We need your consent to continue this call, \
+     so I'll end it here. Thank you for your time.";
+
+const DEFAULT_CONSENT_GIVEN: &str = "Thank you.";
+
+const DEFAULT_CONSENT_REFUSED: &str = "No problem, this call will not be recorded.";
+
+const DEFAULT_CONSENT_FOLLOW_UP: &str = "How can I help you today?";
+
+/// Map a language name to its code (e.g., "hindi" -> "hi")
+fn normalize_language(language: &str) -> &str {
+    match language {
+        "hindi" => "hi",
+        "marathi" => "mr",
+        "tamil" => "ta",
+        "telugu" => "te",
+        "bengali" => "bn",
+        "gujarati" => "gu",
+        "kannada" => "kn",
+        "malayalam" => "ml",
+        "punjabi" => "pa",
+        "odia" => "or",
+        _ => language,
+    }
 }
 
 /// Errors during compliance config loading
@@ -321,4 +484,61 @@ mod tests {
         assert!(config.is_forbidden("GUARANTEED APPROVAL"));
         assert!(!config.is_forbidden("High approval rate"));
     }
+
+    #[test]
+    fn test_opening_script_from_yaml() {
+        let config: ComplianceConfig = serde_yaml::from_str(
+            r#"
+opening_script:
+  steps: [ai_disclosure, greeting, consent]
+  purposes:
+    en: "I can help with your loan."
+  refusal_messages:
+    hi: "कोई बात नहीं, धन्यवाद।"
+"#,
+        )
+        .unwrap();
+        let script = &config.opening_script;
+
+        assert_eq!(
+            script.steps,
+            vec![
+                OpeningStep::AiDisclosure,
+                OpeningStep::Greeting,
+                OpeningStep::Consent
+            ]
+        );
+        assert!(script.end_call_on_refusal);
+        assert_eq!(
+            config.get_opening_purpose("hindi"),
+            "I can help with your loan."
+        );
+        assert_eq!(config.get_opening_refusal("hindi"), "कोई बात नहीं, धन्यवाद।");
+        assert_eq!(config.get_opening_refusal("en"), DEFAULT_OPENING_REFUSAL);
+    }
+
+    #[test]
+    fn test_consent_acknowledgements_by_language() {
+        let config: ComplianceConfig = serde_yaml::from_str(
+            r#"
+consent_acknowledgements:
+  given:
+    hi: "धन्यवाद।"
+  follow_up:
+    en: "What can I do for you?"
+"#,
+        )
+        .unwrap();
+
+        assert_eq!(config.get_consent_acknowledgement(true, "hindi"), "धन्यवाद।");
+        assert_eq!(
+            config.get_consent_acknowledgement(true, "ta"),
+            DEFAULT_CONSENT_GIVEN
+        );
+        assert_eq!(
+            config.get_consent_acknowledgement(false, "hi"),
+            DEFAULT_CONSENT_REFUSED
+        );
+        assert_eq!(config.get_consent_follow_up("hi"), "What can I do for you?");
+    }
 }
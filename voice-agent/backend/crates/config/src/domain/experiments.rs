@@ -0,0 +1,328 @@
+//! A/B Experiments Configuration
+//!
+//! Defines persona/prompt/RAG experiments loaded from experiments.yaml.
+//! Each session is bucketed into one variant per experiment by hashing the
+//! session or customer ID, so the same ID always lands in the same variant
+//! and traffic splits follow the configured variant weights.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::Path;
+
+/// Experiments configuration loaded from experiments.yaml
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ExperimentsConfig {
+    /// Experiment definitions
+    #[serde(default)]
+    pub experiments: Vec<ExperimentDefinition>,
+}
+
+impl ExperimentsConfig {
+    /// Load from a YAML file
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, ExperimentsConfigError> {
+        let content = std::fs::read_to_string(path.as_ref()).map_err(|e| {
+            ExperimentsConfigError::FileNotFound(path.as_ref().display().to_string(), e.to_string())
+        })?;
+
+        let config: Self = serde_yaml::from_str(&content)
+            .map_err(|e| ExperimentsConfigError::ParseError(e.to_string()))?;
+        config.validate()?;
+        Ok(config)
+    }
+
+    /// Check every enabled experiment has variants with a non-zero total weight
+    pub fn validate(&self) -> Result<(), ExperimentsConfigError> {
+        for experiment in self.experiments.iter().filter(|e| e.enabled) {
+            if experiment.total_weight() == 0 {
+                return Err(ExperimentsConfigError::InvalidExperiment(
+                    experiment.id.clone(),
+                    "variant weights sum to zero".to_string(),
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// Get experiment by ID
+    pub fn get(&self, experiment_id: &str) -> Option<&ExperimentDefinition> {
+        self.experiments.iter().find(|e| e.id == experiment_id)
+    }
+
+    /// Assign a variant of every enabled experiment
+    ///
+    /// Experiments bucketed by customer use `customer_id` when known and
+    /// fall back to `session_id` otherwise.
+    pub fn assign(&self, session_id: &str, customer_id: Option<&str>) -> Vec<ExperimentAssignment> {
+        self.experiments
+            .iter()
+            .filter(|e| e.enabled)
+            .filter_map(|experiment| {
+                let unit_id = match (experiment.bucket_by, customer_id) {
+                    (BucketBy::Customer, Some(customer_id)) => customer_id,
+                    _ => session_id,
+                };
+                experiment.assign(unit_id)
+            })
+            .collect()
+    }
+}
+
+/// What a session is bucketed on
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BucketBy {
+    /// Session ID: each call is bucketed independently
+    #[default]
+    Session,
+    /// Customer ID: repeat callers keep their variant
+    Customer,
+}
+
+/// A single experiment with weighted variants
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExperimentDefinition {
+    /// Experiment ID (also the hash salt)
+    pub id: String,
+    /// Description for reports
+    #[serde(default)]
+    pub description: String,
+    /// Disabled experiments assign nothing
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+    /// Bucketing unit
+    #[serde(default)]
+    pub bucket_by: BucketBy,
+    /// Variants with their split weights
+    #[serde(default)]
+    pub variants: Vec<ExperimentVariant>,
+}
+
+fn default_enabled() -> bool {
+    true
+}
+
+impl ExperimentDefinition {
+    /// Sum of variant weights
+    pub fn total_weight(&self) -> u64 {
+        self.variants.iter().map(|v| v.weight as u64).sum()
+    }
+
+    /// Variant for a bucketing unit (deterministic)
+    pub fn variant_for(&self, unit_id: &str) -> Option<&ExperimentVariant> {
+        let total = self.total_weight();
+        if total == 0 {
+            return None;
+        }
+
+        let mut point = bucket_hash(&self.id, unit_id) % total;
+        for variant in &self.variants {
+            let weight = variant.weight as u64;
+            if point < weight {
+                return Some(variant);
+            }
+            point -= weight;
+        }
+        None
+    }
+
+    /// Assignment record for a bucketing unit
+    pub fn assign(&self, unit_id: &str) -> Option<ExperimentAssignment> {
+        self.variant_for(unit_id)
+            .map(|variant| ExperimentAssignment {
+                experiment_id: self.id.clone(),
+                variant_id: variant.id.clone(),
+                bucket_by: self.bucket_by,
+                variant: variant.clone(),
+            })
+    }
+}
+
+/// One arm of an experiment and the settings it overrides
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ExperimentVariant {
+    /// Variant ID recorded in session metadata and audit
+    pub id: String,
+    /// Relative share of traffic
+    #[serde(default = "default_weight")]
+    pub weight: u32,
+    /// Persona parameter overrides
+    #[serde(default)]
+    pub persona: PersonaOverrides,
+    /// Extra system prompt section for this variant
+    #[serde(default)]
+    pub prompt_context: Option<String>,
+    /// Greeting overrides by language
+    #[serde(default)]
+    pub greetings: HashMap<String, String>,
+    /// Enable/disable RAG for this variant
+    #[serde(default)]
+    pub rag_enabled: Option<bool>,
+}
+
+fn default_weight() -> u32 {
+    1
+}
+
+/// Persona parameters a variant can override (unset keeps the base value)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PersonaOverrides {
+    #[serde(default)]
+    pub warmth: Option<f32>,
+    #[serde(default)]
+    pub formality: Option<f32>,
+    #[serde(default)]
+    pub urgency: Option<f32>,
+    #[serde(default)]
+    pub empathy: Option<f32>,
+}
+
+impl PersonaOverrides {
+    /// Apply the overrides to a persona
+    pub fn apply(&self, persona: &mut crate::PersonaConfig) {
+        if let Some(warmth) = self.warmth {
+            persona.warmth = warmth;
+        }
+        if let Some(formality) = self.formality {
+            persona.formality = formality;
+        }
+        if let Some(urgency) = self.urgency {
+            persona.urgency = urgency;
+        }
+        if let Some(empathy) = self.empathy {
+            persona.empathy = empathy;
+        }
+    }
+}
+
+/// Variant a session was bucketed into
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExperimentAssignment {
+    pub experiment_id: String,
+    pub variant_id: String,
+    pub bucket_by: BucketBy,
+    /// Settings of the assigned variant
+    #[serde(skip)]
+    pub variant: ExperimentVariant,
+}
+
+/// Stable 64-bit FNV-1a hash of `salt:unit_id`
+///
+/// Unlike `DefaultHasher`, the result does not change between Rust releases
+/// or process restarts, so assignments stay valid across deployments.
+fn bucket_hash(salt: &str, unit_id: &str) -> u64 {
+    const OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0000_0100_0000_01b3;
+
+    salt.bytes()
+        .chain(std::iter::once(b':'))
+        .chain(unit_id.bytes())
+        .fold(OFFSET, |hash, byte| {
+            (hash ^ byte as u64).wrapping_mul(PRIME)
+        })
+}
+
+/// Errors when loading experiments configuration
+#[derive(Debug)]
+pub enum ExperimentsConfigError {
+    FileNotFound(String, String),
+    ParseError(String),
+    InvalidExperiment(String, String),
+}
+
+impl std::fmt::Display for ExperimentsConfigError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::FileNotFound(path, err) => {
+                write!(f, "Experiments config not found at {}: {}", path, err)
+            },
+            Self::ParseError(err) => write!(f, "Failed to parse experiments config: {}", err),
+            Self::InvalidExperiment(id, err) => write!(f, "Invalid experiment '{}': {}", id, err),
+        }
+    }
+}
+
+impl std::error::Error for ExperimentsConfigError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn greeting_experiment() -> ExperimentsConfig {
+        let yaml = r#"
+experiments:
+  - id: greeting_style
+    bucket_by: customer
+    variants:
+      - id: control
+        weight: 70
+      - id: warm
+        weight: 30
+        persona:
+          warmth: 0.95
+        greetings:
+          en: "Hi there! Lovely to hear from you."
+"#;
+        serde_yaml::from_str(yaml).unwrap()
+    }
+
+    #[test]
+    fn test_bucketing_is_stable() {
+        let config = greeting_experiment();
+        let experiment = config.get("greeting_style").unwrap();
+
+        for i in 0..100 {
+            let id = format!("customer-{}", i);
+            let first = experiment.variant_for(&id).unwrap().id.clone();
+            assert_eq!(experiment.variant_for(&id).unwrap().id, first);
+        }
+
+        // Customer bucketing ignores the session
+        let a = config.assign("session-1", Some("customer-7"));
+        let b = config.assign("session-2", Some("customer-7"));
+        assert_eq!(a[0].variant_id, b[0].variant_id);
+    }
+
+    #[test]
+    fn test_split_ratio_is_honored() {
+        let config = greeting_experiment();
+        let experiment = config.get("greeting_style").unwrap();
+
+        let n = 10_000;
+        let warm = (0..n)
+            .filter(|i| {
+                experiment
+                    .variant_for(&format!("session-{}", i))
+                    .unwrap()
+                    .id
+                    == "warm"
+            })
+            .count();
+        let share = warm as f64 / n as f64;
+        assert!((share - 0.30).abs() < 0.02, "warm share was {}", share);
+    }
+
+    #[test]
+    fn test_disabled_and_empty_experiments() {
+        let mut config = greeting_experiment();
+        config.experiments[0].enabled = false;
+        assert!(config.assign("session-1", None).is_empty());
+
+        config.experiments[0].enabled = true;
+        for variant in &mut config.experiments[0].variants {
+            variant.weight = 0;
+        }
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_persona_overrides() {
+        let config = greeting_experiment();
+        let warm = &config.get("greeting_style").unwrap().variants[1];
+
+        let mut persona = crate::PersonaConfig::default();
+        let formality = persona.formality;
+        warm.persona.apply(&mut persona);
+        assert_eq!(persona.warmth, 0.95);
+        assert_eq!(persona.formality, formality);
+    }
+}
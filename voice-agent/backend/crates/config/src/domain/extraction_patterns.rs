@@ -602,6 +602,14 @@ pub struct WeightConversions {
     #[serde(default = "default_tola")]
     pub tola_to_grams: f64,
 
+    /// Masha to grams (default: 0.972, 12 masha = 1 tola)
+    #[serde(default = "default_masha")]
+    pub masha_to_grams: f64,
+
+    /// Ratti to grams (default: 0.1215, 8 ratti = 1 masha)
+    #[serde(default = "default_ratti")]
+    pub ratti_to_grams: f64,
+
     /// Gram to milligrams
     #[serde(default = "default_gram_to_mg")]
     pub gram_to_mg: f64,
@@ -619,6 +627,14 @@ fn default_tola() -> f64 {
     11.66
 }
 
+fn default_masha() -> f64 {
+    0.972
+}
+
+fn default_ratti() -> f64 {
+    0.1215
+}
+
 fn default_gram_to_mg() -> f64 {
     1000.0
 }
@@ -628,13 +644,15 @@ fn default_kg_to_grams() -> f64 {
 }
 
 fn default_oz_to_grams() -> f64 {
-    31.1
+    31.103
 }
 
 impl Default for WeightConversions {
     fn default() -> Self {
         Self {
             tola_to_grams: default_tola(),
+            masha_to_grams: default_masha(),
+            ratti_to_grams: default_ratti(),
             gram_to_mg: default_gram_to_mg(),
             kg_to_grams: default_kg_to_grams(),
             oz_to_grams: default_oz_to_grams(),
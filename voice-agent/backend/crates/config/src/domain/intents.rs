@@ -4,6 +4,7 @@
 //! Intents are loaded from domain config files instead of being hardcoded.
 
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::path::Path;
 
 /// Intents configuration loaded from intents.yaml
@@ -16,8 +17,17 @@ pub struct IntentsConfig {
     #[serde(default = "default_intent")]
     pub default_intent: String,
     /// Minimum confidence threshold
+    ///
+    /// With `calibration` set, intents below this calibrated confidence are
+    /// treated as unknown and answered generally instead of acted on.
     #[serde(default = "default_min_confidence")]
     pub min_confidence: f32,
+    /// Logistic calibration of raw intent match scores
+    #[serde(default)]
+    pub calibration: Option<IntentCalibrationConfig>,
+    /// Clarifying questions for near-tied intents
+    #[serde(default)]
+    pub disambiguation: DisambiguationConfig,
 }
 
 fn default_intent() -> String {
@@ -34,6 +44,8 @@ impl Default for IntentsConfig {
             intents: Vec::new(),
             default_intent: default_intent(),
             min_confidence: default_min_confidence(),
+            calibration: None,
+            disambiguation: DisambiguationConfig::default(),
         }
     }
 }
@@ -107,6 +119,80 @@ impl IntentDefinition {
     }
 }
 
+/// Logistic calibration parameters for intent confidence
+///
+/// Confidence is `1 / (1 + e^(-slope * (raw - midpoint)))`.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct IntentCalibrationConfig {
+    /// Steepness of the logistic curve
+    #[serde(default = "default_calibration_slope")]
+    pub slope: f32,
+    /// Raw score that maps to a confidence of 0.5
+    #[serde(default = "default_calibration_midpoint")]
+    pub midpoint: f32,
+}
+
+fn default_calibration_slope() -> f32 {
+    10.0
+}
+
+fn default_calibration_midpoint() -> f32 {
+    0.5
+}
+
+/// Disambiguation of near-tied intents
+///
+/// When the top two detected intents score within `margin` of each other and
+/// a clarification is configured for that pair, the agent asks the question
+/// instead of guessing.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DisambiguationConfig {
+    /// Maximum score gap between the top two intents to treat them as tied
+    #[serde(default = "default_disambiguation_margin")]
+    pub margin: f32,
+    /// Minimum top-intent score; weaker matches are not worth clarifying
+    #[serde(default = "default_min_confidence")]
+    pub min_confidence: f32,
+    /// Clarifying questions per intent pair
+    #[serde(default)]
+    pub clarifications: Vec<ClarificationRule>,
+}
+
+fn default_disambiguation_margin() -> f32 {
+    0.1
+}
+
+impl Default for DisambiguationConfig {
+    fn default() -> Self {
+        Self {
+            margin: default_disambiguation_margin(),
+            min_confidence: default_min_confidence(),
+            clarifications: Vec::new(),
+        }
+    }
+}
+
+impl DisambiguationConfig {
+    /// Find the clarification for an intent pair (order-insensitive)
+    pub fn clarification_for(&self, first: &str, second: &str) -> Option<&ClarificationRule> {
+        self.clarifications.iter().find(|rule| {
+            rule.intents.iter().any(|i| i == first) && rule.intents.iter().any(|i| i == second)
+        })
+    }
+}
+
+/// Clarifying question for a pair of easily confused intents
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClarificationRule {
+    /// The ambiguous intents
+    pub intents: Vec<String>,
+    /// Question asked to the user
+    pub question: String,
+    /// Phrases in the answer that select each intent (intent -> phrases)
+    #[serde(default)]
+    pub cues: HashMap<String, Vec<String>>,
+}
+
 /// Errors when loading intents configuration
 #[derive(Debug)]
 pub enum IntentsConfigError {
@@ -146,17 +232,50 @@ intents:
       - "Can I get approved"
 default_intent: unknown
 min_confidence: 0.4
+calibration:
+  slope: 12.0
 "#;
         let config: IntentsConfig = serde_yaml::from_str(yaml).unwrap();
         assert_eq!(config.intents.len(), 1);
         assert_eq!(config.default_intent, "unknown");
         assert_eq!(config.min_confidence, 0.4);
+        let calibration = config.calibration.unwrap();
+        assert_eq!(calibration.slope, 12.0);
+        assert_eq!(calibration.midpoint, 0.5);
 
         let intent = config.get_intent("eligibility_check").unwrap();
         assert_eq!(intent.required_slots, vec!["asset_quantity"]);
         assert_eq!(intent.examples.len(), 2);
     }
 
+    #[test]
+    fn test_disambiguation_config() {
+        let yaml = r#"
+intents: []
+disambiguation:
+  margin: 0.15
+  clarifications:
+    - intents: [price_inquiry, interest_rate]
+      question: "Do you mean today's price or our interest rate?"
+      cues:
+        price_inquiry: ["price"]
+        interest_rate: ["interest"]
+"#;
+        let config: IntentsConfig = serde_yaml::from_str(yaml).unwrap();
+        assert!(config.calibration.is_none());
+        let disambiguation = &config.disambiguation;
+        assert_eq!(disambiguation.margin, 0.15);
+        assert_eq!(disambiguation.min_confidence, 0.3);
+
+        let rule = disambiguation
+            .clarification_for("interest_rate", "price_inquiry")
+            .unwrap();
+        assert_eq!(rule.cues["interest_rate"], vec!["interest"]);
+        assert!(disambiguation
+            .clarification_for("interest_rate", "greeting")
+            .is_none());
+    }
+
     #[test]
     fn test_has_required_slots() {
         let intent = IntentDefinition {
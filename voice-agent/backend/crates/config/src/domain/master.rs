@@ -14,6 +14,7 @@ use super::branches::BranchesConfig;
 use super::competitors::CompetitorsConfig;
 use super::documents::DocumentsConfig;
 use super::entities::EntitiesConfig;
+use super::experiments::ExperimentsConfig;
 use super::features::FeaturesConfig;
 use super::goals::GoalsConfig;
 use super::intents::IntentsConfig;
@@ -168,6 +169,12 @@ pub struct VocabularyConfig {
     /// Entity types to preserve
     #[serde(default)]
     pub preserve_entities: Vec<String>,
+    /// TTS pronunciations for brand/domain terms (term → spoken form)
+    #[serde(default)]
+    pub pronunciations: HashMap<String, String>,
+    /// Per-language TTS pronunciations keyed by language code
+    #[serde(default)]
+    pub language_pronunciations: HashMap<String, HashMap<String, String>>,
 }
 
 /// Contextual correction rule for phonetic corrector
@@ -591,6 +598,9 @@ pub struct MasterDomainConfig {
     /// P24 FIX: Persona configurations for tone/style (loaded from personas.yaml)
     #[serde(skip)]
     pub personas: PersonasConfig,
+    /// A/B experiments for personas/prompts (loaded from experiments.yaml)
+    #[serde(skip)]
+    pub experiments: ExperimentsConfig,
     // P23 FIX: Removed raw_config field - was never accessed
     // Use typed config fields instead
 }
@@ -642,6 +652,7 @@ impl Default for MasterDomainConfig {
             entities: EntitiesConfig::default(),
             signals: SignalsConfig::default(),
             personas: PersonasConfig::default(),
+            experiments: ExperimentsConfig::default(),
             // P23 FIX: Removed raw_config - use typed config fields
         }
     }
@@ -1174,7 +1185,26 @@ impl MasterDomainConfig {
             tracing::debug!("No personas config found at {:?}", personas_path);
         }
 
-        // 27. P16 FIX: Apply variable substitution to all text configs
+        // 27. Load A/B experiments configuration (optional)
+        let experiments_path = config_dir.join(format!("domains/{}/experiments.yaml", domain_id));
+        if experiments_path.exists() {
+            match ExperimentsConfig::load(&experiments_path) {
+                Ok(experiments) => {
+                    tracing::info!(
+                        experiments = experiments.experiments.len(),
+                        "Loaded experiments configuration"
+                    );
+                    config.experiments = experiments;
+                }
+                Err(e) => {
+                    tracing::warn!("Failed to load experiments config: {}", e);
+                }
+            }
+        } else {
+            tracing::debug!("No experiments config found at {:?}", experiments_path);
+        }
+
+        // 28. P16 FIX: Apply variable substitution to all text configs
         // This allows YAML files to use {{variable_name}} placeholders
         // that are replaced with values from adaptation.yaml variables
         config.substitute_all_variables();
@@ -15,6 +15,7 @@ mod compliance;
 mod competitors;
 mod documents;
 mod entities;
+mod experiments;
 mod extraction_patterns;
 mod features;
 mod goals;
@@ -38,16 +39,23 @@ mod vocabulary;
 pub use adaptation::{
     AdaptationConfig, AdaptationConfigError, SegmentAdaptation, SpecialProgram,
 };
-pub use branches::{BranchDefaults, BranchEntry, BranchesConfig, BranchesConfigError, DoorstepServiceConfig};
+pub use branches::{
+    BranchDefaults, BranchEntry, BranchesConfig, BranchesConfigError, DoorstepServiceConfig,
+    PincodeRegionEntry,
+};
 pub use compliance::{
     AutoCorrections, ClaimRule, CompetitorRules as ComplianceCompetitorRules, ComplianceConfig,
-    ComplianceConfigError, LanguageRules, RateRules, RegulatoryInfo, RequiredDisclosure,
-    SeverityLevels,
+    ComplianceConfigError, ConsentAcknowledgements, LanguageRules, OpeningScriptConfig,
+    OpeningStep, RateRules, RegulatoryInfo, RequiredDisclosure, SeverityLevels,
 };
 pub use documents::{
     CustomerTypeEntry, DocumentEntry, DocumentsConfig, DocumentsConfigError, DocumentToolConfig,
     ImportantNotes, ServiceTypeEntry,
 };
+pub use experiments::{
+    BucketBy, ExperimentAssignment, ExperimentDefinition, ExperimentVariant, ExperimentsConfig,
+    ExperimentsConfigError, PersonaOverrides,
+};
 pub use extraction_patterns::{
     AssetQualityConfig, AssetQualityTier, CityEntry, CompiledCityPattern, CompiledPurposePattern,
     CompiledQualityTier, ExtractionPatternsConfig, ExtractionPatternsError, LocationsConfig,
@@ -65,7 +73,10 @@ pub use entities::{
     CompetitorTypeDefaults, CompetitorTypeDefinition, EntitiesConfig, EntitiesConfigError,
     EntityCategory, EntityTypeDefinition,
 };
-pub use intents::{IntentDefinition, IntentsConfig, IntentsConfigError};
+pub use intents::{
+    ClarificationRule, DisambiguationConfig, IntentCalibrationConfig, IntentDefinition,
+    IntentsConfig, IntentsConfigError,
+};
 pub use master::{
     BrandConfig, ContextualRule, CurrencyConfig, DisplayUnit, DisplayUnitsConfig, DomainBoostConfig,
     DomainBoostTermEntry, DomainKeywordsConfig, EntityPatternConfig, IntentKeywordConfig,
@@ -81,10 +92,10 @@ pub use personas::{
     NameUsageConfig, PersonasConfig, PersonasConfigError, RangeGuideline,
     ResponseLengthGuidelines, ThresholdConfig, ToneConfig, UrgencyConfig,
 };
-pub use prompts::{PromptsConfig, PromptsConfigError};
+pub use prompts::{FestivalGreeting, PromptsConfig, PromptsConfigError};
 pub use scoring::{
-    CategoryWeights, ConversionMultipliers, EscalationConfig, QualificationThresholds,
-    ScoringConfig, ScoringConfigError, TrustScores,
+    CategoryWeights, ConversionMultipliers, EscalationConfig, LiveAgentHours,
+    QualificationThresholds, ScoringConfig, ScoringConfigError, TrustScores,
 };
 pub use signals::{
     EscalationTriggerDef, ScoringThreshold, SignalCategory, SignalDefinition as SignalDefConfig,
@@ -95,16 +106,19 @@ pub use segments::{
     SegmentsConfig, SegmentsConfigError,
 };
 pub use slots::{
-    EnumParsingConfig, EnumValue, GoalDefinition, NumericPatternRule, SlotDefinition, SlotType,
-    SlotsConfig, SlotsConfigError,
+    EnumParsingConfig, EnumValue, GoalDefinition, NumericPatternRule, SlotDefinition,
+    SlotRetryFallback, SlotRetryPolicy, SlotType, SlotsConfig, SlotsConfigError,
 };
 pub use sms_templates::{SmsCategories, SmsConfig, SmsTemplatesConfig, SmsTemplatesConfigError};
 pub use stages::{
-    StageDefinition, StageRequirements, StagesConfig, StagesConfigError, TransitionTrigger,
+    ResponseLimit, SamplingParams, StageDefinition, StageRequirements, StageTimeout,
+    StageTimeoutAction, StagesConfig, StagesConfigError, TransitionTrigger,
 };
 pub use tool_responses::{ToolResponsesConfig, ToolResponsesConfigError, ToolTemplates, TemplateVariant};
 pub use tools::{IntentToolMapping, IntentToolMappingsConfig, ToolDefinition, ToolParameter, ToolSchema, ToolSchemaMetadata, ToolsConfig, ToolsConfigError};
-pub use views::{AgentDomainView, CompetitorInfo, LlmDomainView, MonthlySavings, ToolsDomainView};
+pub use views::{
+    AgentDomainView, CompetitorInfo, GreetingContext, LlmDomainView, MonthlySavings, ToolsDomainView,
+};
 pub use vocabulary::{DomainTerm, FullVocabularyConfig, FullVocabularyConfigError};
 
 // P13 FIX: Domain bridge for trait implementations
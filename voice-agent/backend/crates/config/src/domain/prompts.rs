@@ -2,6 +2,7 @@
 //!
 //! Defines config-driven prompt templates for LLM interactions.
 
+use chrono::{Datelike, NaiveDate};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::path::Path;
@@ -51,6 +52,12 @@ pub struct PromptsConfig {
     /// P16 FIX: Farewell templates by language
     #[serde(default)]
     pub farewells: HashMap<String, String>,
+    /// Re-engagement prompts by language, spoken when the user goes quiet
+    #[serde(default)]
+    pub idle_prompts: HashMap<String, String>,
+    /// Closing lines by language, spoken before ending a call the user left
+    #[serde(default)]
+    pub idle_farewells: HashMap<String, String>,
     /// P16 FIX: Agent role description (e.g., "Gold Loan specialist", "Insurance advisor")
     #[serde(default)]
     pub agent_role: String,
@@ -58,6 +65,44 @@ pub struct PromptsConfig {
     /// Used when LLM is unavailable. Supports brand placeholders.
     #[serde(default)]
     pub stage_fallback_responses: HashMap<String, HashMap<String, String>>,
+    /// Canned FAQ answers (keyed by intent, then language)
+    /// Spoken when the LLM is unavailable. Supports brand placeholders.
+    #[serde(default)]
+    pub faq_answers: HashMap<String, HashMap<String, String>>,
+    /// Note appended to cached FAQ answers, by language
+    #[serde(default)]
+    pub faq_cached_notes: HashMap<String, String>,
+    /// Time-of-day salutations (keyed by language, then morning/afternoon/evening/night)
+    #[serde(default)]
+    pub time_of_day_greetings: HashMap<String, HashMap<String, String>>,
+    /// "Welcome back" lines for returning customers, by language
+    #[serde(default)]
+    pub returning_greetings: HashMap<String, String>,
+    /// Festival and holiday greetings, checked against the call date
+    #[serde(default)]
+    pub festival_greetings: Vec<FestivalGreeting>,
+}
+
+/// Greeting spoken on a festival or holiday
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FestivalGreeting {
+    /// Festival name (e.g., "diwali")
+    pub name: String,
+    /// Dates as "YYYY-MM-DD" (lunar festivals) or "MM-DD" (fixed every year)
+    #[serde(default)]
+    pub dates: Vec<String>,
+    /// Greeting by language (e.g., "Happy Diwali!")
+    #[serde(default)]
+    pub greetings: HashMap<String, String>,
+}
+
+impl FestivalGreeting {
+    /// Whether the festival falls on a date
+    pub fn falls_on(&self, date: NaiveDate) -> bool {
+        let full = date.format("%Y-%m-%d").to_string();
+        let yearly = format!("{:02}-{:02}", date.month(), date.day());
+        self.dates.iter().any(|d| d.trim() == full || d.trim() == yearly)
+    }
 }
 
 impl Default for PromptsConfig {
@@ -77,8 +122,15 @@ impl Default for PromptsConfig {
             stage_guidance: HashMap::new(),
             greetings: HashMap::new(),
             farewells: HashMap::new(),
+            idle_prompts: HashMap::new(),
+            idle_farewells: HashMap::new(),
             agent_role: String::new(),
             stage_fallback_responses: HashMap::new(),
+            faq_answers: HashMap::new(),
+            faq_cached_notes: HashMap::new(),
+            time_of_day_greetings: HashMap::new(),
+            returning_greetings: HashMap::new(),
+            festival_greetings: Vec::new(),
         }
     }
 }
@@ -253,6 +305,48 @@ impl PromptsConfig {
             .unwrap_or("Hello!")
     }
 
+    /// Salutation for the hour of day (0-23), falling back to English
+    pub fn get_time_of_day_greeting(&self, language: &str, hour: u32) -> &str {
+        let (part, default) = match hour {
+            5..=11 => ("morning", "Good morning"),
+            12..=16 => ("afternoon", "Good afternoon"),
+            17..=20 => ("evening", "Good evening"),
+            _ => ("night", "Hello"),
+        };
+        self.time_of_day_greetings
+            .get(language)
+            .and_then(|parts| parts.get(part))
+            .or_else(|| {
+                self.time_of_day_greetings
+                    .get("en")
+                    .and_then(|parts| parts.get(part))
+            })
+            .map(|s| s.as_str())
+            .unwrap_or(default)
+    }
+
+    /// "Welcome back" line for a returning customer, falling back to English
+    pub fn get_returning_greeting(&self, language: &str) -> &str {
+        self.returning_greetings
+            .get(language)
+            .or_else(|| self.returning_greetings.get("en"))
+            .map(|s| s.as_str())
+            .unwrap_or("Welcome back!")
+    }
+
+    /// Festival greeting for a date, if a configured festival falls on it
+    pub fn get_festival_greeting(&self, date: NaiveDate, language: &str) -> Option<&str> {
+        self.festival_greetings
+            .iter()
+            .filter(|f| f.falls_on(date))
+            .find_map(|f| {
+                f.greetings
+                    .get(language)
+                    .or_else(|| f.greetings.get("en"))
+            })
+            .map(|s| s.as_str())
+    }
+
     /// P16 FIX: Get farewell template for a language
     pub fn get_farewell(&self, language: &str) -> &str {
         self.farewells
@@ -175,6 +175,9 @@ pub struct EscalationConfig {
     pub max_objections: u32,
     pub max_stalled_turns: u32,
     pub high_value_threshold: f64,
+    /// When human agents take live transfers (None = always)
+    #[serde(default)]
+    pub live_agent_hours: Option<LiveAgentHours>,
 }
 
 impl Default for EscalationConfig {
@@ -183,10 +186,33 @@ impl Default for EscalationConfig {
             max_objections: 3,
             max_stalled_turns: 5,
             high_value_threshold: 1_000_000.0,
+            live_agent_hours: None,
         }
     }
 }
 
+/// Hours human agents are available for live escalation
+///
+/// Outside these hours escalations are deferred to a callback or SMS.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LiveAgentHours {
+    /// Opening hours, e.g. "9:00 AM - 7:00 PM (Mon-Sat)"
+    pub timing: String,
+    /// Offset of the local time zone from UTC, in minutes (330 = IST)
+    #[serde(default = "default_utc_offset_minutes")]
+    pub utc_offset_minutes: i32,
+    /// Dates with no live agents
+    #[serde(default)]
+    pub holidays: Vec<chrono::NaiveDate>,
+    /// Hours for specific regions or branch cities (lowercase keys)
+    #[serde(default)]
+    pub regions: HashMap<String, LiveAgentHours>,
+}
+
+fn default_utc_offset_minutes() -> i32 {
+    330
+}
+
 /// Category weights for scoring
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CategoryWeights {
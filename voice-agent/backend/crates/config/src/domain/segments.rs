@@ -303,6 +303,12 @@ pub struct SegmentPersonaConfig {
     /// Maximum response length preference (words)
     #[serde(default = "default_max_response_words")]
     pub max_response_words: usize,
+    /// TTS speaking rate multiplier (1.0 = normal, lower = slower)
+    #[serde(default = "default_voice_scale")]
+    pub speaking_rate: f32,
+    /// TTS pitch multiplier (1.0 = normal, lower = deeper/warmer)
+    #[serde(default = "default_voice_scale")]
+    pub pitch: f32,
 }
 
 fn default_warmth() -> f32 {
@@ -333,6 +339,10 @@ fn default_max_response_words() -> usize {
     60
 }
 
+fn default_voice_scale() -> f32 {
+    1.0
+}
+
 fn default_priority() -> i32 {
     5
 }
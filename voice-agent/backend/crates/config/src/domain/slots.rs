@@ -5,6 +5,7 @@
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::path::Path;
+use voice_agent_core::PIIType;
 
 /// Slot schema loaded from slots.yaml
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -26,6 +27,9 @@ pub struct SlotsConfig {
     /// P16 FIX: Slots that should trigger customer name update (instead of fact storage)
     #[serde(default)]
     pub customer_name_slots: Vec<String>,
+    /// Default limit on re-asking for a slot and what to do once it is hit
+    #[serde(default)]
+    pub retry_policy: SlotRetryPolicy,
 }
 
 impl Default for SlotsConfig {
@@ -36,6 +40,7 @@ impl Default for SlotsConfig {
             intent_mapping: HashMap::new(),
             slot_aliases: HashMap::new(),
             customer_name_slots: vec!["customer_name".to_string(), "name".to_string()],
+            retry_policy: SlotRetryPolicy::default(),
         }
     }
 }
@@ -60,6 +65,20 @@ impl SlotsConfig {
         self.goals.get(name)
     }
 
+    /// Retry policy for a slot, with the slot's own overrides applied
+    pub fn retry_policy_for(&self, slot_name: &str) -> SlotRetryPolicy {
+        let mut policy = self.retry_policy.clone();
+        if let Some(slot) = self.slots.get(slot_name) {
+            if let Some(max_retries) = slot.max_retries {
+                policy.max_retries = max_retries;
+            }
+            if let Some(fallback) = slot.retry_fallback {
+                policy.fallback = fallback;
+            }
+        }
+        policy
+    }
+
     /// Map an intent to a goal
     pub fn goal_for_intent(&self, intent: &str) -> Option<&str> {
         for (goal, intents) in &self.intent_mapping {
@@ -314,6 +333,86 @@ pub struct SlotDefinition {
     /// P20 FIX: Currency code (e.g., "INR" for offer_amount)
     #[serde(default)]
     pub currency: Option<String>,
+    /// Re-asks allowed before the retry fallback (overrides `retry_policy`)
+    #[serde(default)]
+    pub max_retries: Option<u32>,
+    /// Fallback once retries are exhausted (overrides `retry_policy`)
+    #[serde(default)]
+    pub retry_fallback: Option<SlotRetryFallback>,
+    /// Kind of personal data the slot holds; masked before leaving the agent
+    #[serde(default)]
+    pub pii: Option<PIIType>,
+    /// Question confirming the unit of a number given without one, by
+    /// language; `{number}` is replaced with the number
+    #[serde(default)]
+    pub clarification_prompts: Option<HashMap<String, String>>,
+}
+
+/// Limit on re-asking for a slot the customer's answers keep failing to fill
+///
+/// A slot is asked for once plus `max_retries` more times; the next time it
+/// would be asked, `fallback` is taken instead so the agent never loops.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SlotRetryPolicy {
+    /// Re-asks allowed after the first prompt
+    #[serde(default = "default_max_retries")]
+    pub max_retries: u32,
+    /// What to do once retries are exhausted
+    #[serde(default)]
+    pub fallback: SlotRetryFallback,
+    /// Spoken message per fallback (English; translated like other responses)
+    #[serde(default)]
+    pub messages: HashMap<SlotRetryFallback, String>,
+}
+
+fn default_max_retries() -> u32 {
+    2
+}
+
+impl Default for SlotRetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: default_max_retries(),
+            fallback: SlotRetryFallback::default(),
+            messages: HashMap::new(),
+        }
+    }
+}
+
+impl SlotRetryPolicy {
+    /// Whether a slot asked `attempts` times has exhausted its retries
+    pub fn exhausted(&self, attempts: u32) -> bool {
+        attempts > self.max_retries
+    }
+
+    /// Configured message for a fallback
+    pub fn message(&self, fallback: SlotRetryFallback) -> Option<&str> {
+        self.messages.get(&fallback).map(String::as_str)
+    }
+}
+
+/// Action taken when a slot still isn't collected after its retry limit
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SlotRetryFallback {
+    /// Hand the call to a human agent
+    Escalate,
+    /// Offer to SMS a form link so the customer can fill the slot in writing
+    SendFormLink,
+    /// Move on without the slot and note it as skipped
+    #[default]
+    Skip,
+}
+
+impl SlotRetryFallback {
+    /// Config name of the fallback
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Escalate => "escalate",
+            Self::SendFormLink => "send_form_link",
+            Self::Skip => "skip",
+        }
+    }
 }
 
 /// Slot type enumeration
@@ -405,7 +504,7 @@ pub struct EnumParsingConfig {
 }
 
 /// Goal definition
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct GoalDefinition {
     /// Description of the goal
     #[serde(default)]
@@ -419,6 +518,12 @@ pub struct GoalDefinition {
     /// Action to take when goal is complete
     #[serde(default)]
     pub completion_action: Option<String>,
+    /// Stage the conversation must reach for the goal to be complete
+    #[serde(default)]
+    pub required_stage: Option<String>,
+    /// Tools that must run successfully for the goal to be complete
+    #[serde(default)]
+    pub required_tools: Vec<String>,
 }
 
 /// Errors when loading slot configuration
@@ -443,6 +548,43 @@ impl std::error::Error for SlotsConfigError {}
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_retry_policy_slot_overrides() {
+        let yaml = r#"
+slots:
+  phone_number:
+    type: string
+    max_retries: 1
+    retry_fallback: send_form_link
+  loan_amount:
+    type: number
+retry_policy:
+  max_retries: 3
+  fallback: escalate
+  messages:
+    escalate: "Let me connect you to a colleague."
+"#;
+        let config: SlotsConfig = serde_yaml::from_str(yaml).unwrap();
+
+        let phone = config.retry_policy_for("phone_number");
+        assert_eq!(phone.max_retries, 1);
+        assert_eq!(phone.fallback, SlotRetryFallback::SendFormLink);
+        assert!(!phone.exhausted(1));
+        assert!(phone.exhausted(2));
+
+        let amount = config.retry_policy_for("loan_amount");
+        assert_eq!(amount.max_retries, 3);
+        assert_eq!(amount.fallback, SlotRetryFallback::Escalate);
+        assert_eq!(
+            amount.message(SlotRetryFallback::Escalate),
+            Some("Let me connect you to a colleague.")
+        );
+
+        let default = SlotsConfig::default().retry_policy_for("loan_amount");
+        assert_eq!(default.max_retries, 2);
+        assert_eq!(default.fallback, SlotRetryFallback::Skip);
+    }
+
     #[test]
     fn test_slot_type_deserialization() {
         let yaml = r#"
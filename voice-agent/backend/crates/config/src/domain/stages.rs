@@ -5,6 +5,7 @@
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::path::Path;
+use voice_agent_core::ends_with_abbreviation;
 
 /// Stages configuration loaded from stages.yaml
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -145,6 +146,11 @@ impl StagesConfig {
             .unwrap_or(2048)
     }
 
+    /// Get response length limit for a stage, if the stage is defined
+    pub fn get_response_limit(&self, stage_id: &str) -> Option<ResponseLimit> {
+        self.stages.get(stage_id).map(|s| s.response_limit())
+    }
+
     /// Get RAG context fraction for a stage
     pub fn get_rag_fraction(&self, stage_id: &str) -> f32 {
         self.stages
@@ -153,6 +159,26 @@ impl StagesConfig {
             .unwrap_or(0.0)
     }
 
+    /// Get LLM sampling parameters for a stage, if the stage is defined
+    pub fn get_sampling(&self, stage_id: &str) -> Option<SamplingParams> {
+        self.stages.get(stage_id).map(|s| s.sampling())
+    }
+
+    /// Get the turn/time limit for a stage, if one is configured
+    pub fn get_timeout(&self, stage_id: &str) -> Option<&StageTimeout> {
+        self.stages.get(stage_id).and_then(|s| s.timeout.as_ref())
+    }
+
+    /// Get configured retrieval top-k for a stage
+    pub fn get_rag_top_k(&self, stage_id: &str) -> Option<usize> {
+        self.stages.get(stage_id).and_then(|s| s.rag_top_k)
+    }
+
+    /// Get configured retrieval score cutoff for a stage
+    pub fn get_rag_min_score(&self, stage_id: &str) -> Option<f32> {
+        self.stages.get(stage_id).and_then(|s| s.rag_min_score)
+    }
+
     /// P16 FIX: Get intent-based transition target
     ///
     /// Returns the target stage for a given intent and current stage, if defined.
@@ -210,6 +236,12 @@ pub struct StageDefinition {
     /// Fraction of context budget for RAG (0.0-1.0)
     #[serde(default)]
     pub rag_context_fraction: f32,
+    /// Maximum retrieved results used in this stage (unset = built-in default)
+    #[serde(default)]
+    pub rag_top_k: Option<usize>,
+    /// Minimum score for a retrieved result to be used (unset = built-in default)
+    #[serde(default)]
+    pub rag_min_score: Option<f32>,
     /// Number of conversation history turns to keep
     #[serde(default = "default_history_turns")]
     pub history_turns_to_keep: usize,
@@ -219,6 +251,43 @@ pub struct StageDefinition {
     /// Requirements to stay in or leave this stage
     #[serde(default)]
     pub requirements: StageRequirements,
+    /// Maximum sentences in a spoken response (unset = no limit)
+    #[serde(default)]
+    pub max_response_sentences: Option<usize>,
+    /// Maximum characters in a spoken response (unset = no limit)
+    #[serde(default)]
+    pub max_response_chars: Option<usize>,
+    /// LLM sampling temperature in this stage (unset = LLM default)
+    #[serde(default)]
+    pub temperature: Option<f32>,
+    /// LLM nucleus sampling cutoff in this stage (unset = LLM default)
+    #[serde(default)]
+    pub top_p: Option<f32>,
+    /// Maximum tokens the LLM generates in this stage (unset = LLM default)
+    #[serde(default)]
+    pub max_tokens: Option<u32>,
+    /// Limit on turns/time spent in this stage (unset = no limit)
+    #[serde(default)]
+    pub timeout: Option<StageTimeout>,
+}
+
+impl StageDefinition {
+    /// Response length limit for this stage
+    pub fn response_limit(&self) -> ResponseLimit {
+        ResponseLimit {
+            max_sentences: self.max_response_sentences,
+            max_chars: self.max_response_chars,
+        }
+    }
+
+    /// LLM sampling parameters for this stage
+    pub fn sampling(&self) -> SamplingParams {
+        SamplingParams {
+            temperature: self.temperature,
+            top_p: self.top_p,
+            max_tokens: self.max_tokens,
+        }
+    }
 }
 
 fn default_context_budget() -> usize {
@@ -229,6 +298,188 @@ fn default_history_turns() -> usize {
     3
 }
 
+/// LLM sampling parameters for a turn; unset fields keep the LLM default
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct SamplingParams {
+    /// Sampling temperature
+    #[serde(default)]
+    pub temperature: Option<f32>,
+    /// Nucleus sampling cutoff
+    #[serde(default)]
+    pub top_p: Option<f32>,
+    /// Maximum tokens to generate
+    #[serde(default)]
+    pub max_tokens: Option<u32>,
+}
+
+impl SamplingParams {
+    /// Fill fields unset here from `fallback`
+    pub fn or(self, fallback: SamplingParams) -> Self {
+        Self {
+            temperature: self.temperature.or(fallback.temperature),
+            top_p: self.top_p.or(fallback.top_p),
+            max_tokens: self.max_tokens.or(fallback.max_tokens),
+        }
+    }
+}
+
+/// What to do when a stage runs past its limit
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StageTimeoutAction {
+    /// Move on to the next stage with a bridging line
+    #[default]
+    Advance,
+    /// Hand the call to a human
+    Escalate,
+}
+
+/// Limit on how long a conversation may stay in one stage
+///
+/// Keeps calls from dead-ending in a stage whose exit trigger the customer
+/// never gives (e.g. discovery without a current lender).
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct StageTimeout {
+    /// Maximum user turns in the stage (unset = no turn limit)
+    #[serde(default)]
+    pub max_turns: Option<usize>,
+    /// Maximum seconds in the stage (unset = no time limit)
+    #[serde(default)]
+    pub max_duration_secs: Option<u64>,
+    /// Advance or escalate once the limit is reached
+    #[serde(default)]
+    pub action: StageTimeoutAction,
+    /// Stage to advance to (unset = first listed transition)
+    #[serde(default)]
+    pub target: Option<String>,
+    /// Bridging line the agent opens the next stage with
+    #[serde(default)]
+    pub message: Option<String>,
+}
+
+impl StageTimeout {
+    /// Whether `turns` user turns or `elapsed` in the stage exceed the limit
+    pub fn exceeded(&self, turns: usize, elapsed: std::time::Duration) -> bool {
+        self.max_turns.is_some_and(|max| turns >= max)
+            || self
+                .max_duration_secs
+                .is_some_and(|max| elapsed.as_secs() >= max)
+    }
+}
+
+/// Maximum length of a spoken response
+///
+/// Responses are cut at a sentence boundary, never mid-sentence: if even the
+/// first sentence exceeds `max_chars`, that sentence is kept whole.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ResponseLimit {
+    /// Maximum number of sentences
+    pub max_sentences: Option<usize>,
+    /// Maximum number of characters
+    pub max_chars: Option<usize>,
+}
+
+impl ResponseLimit {
+    /// Limit on sentences only
+    pub fn sentences(max_sentences: usize) -> Self {
+        Self {
+            max_sentences: Some(max_sentences),
+            max_chars: None,
+        }
+    }
+
+    /// Add a character limit
+    pub fn with_max_chars(mut self, max_chars: usize) -> Self {
+        self.max_chars = Some(max_chars);
+        self
+    }
+
+    /// Whether any limit is set
+    pub fn is_limited(&self) -> bool {
+        self.max_sentences.is_some() || self.max_chars.is_some()
+    }
+
+    /// Prompt instruction asking the LLM to stay within the limit
+    pub fn prompt_instruction(&self) -> Option<String> {
+        match (self.max_sentences, self.max_chars) {
+            (Some(sentences), Some(chars)) => Some(format!(
+                "Keep your reply to at most {} sentence(s) and {} characters; it will be spoken aloud.",
+                sentences, chars
+            )),
+            (Some(sentences), None) => Some(format!(
+                "Keep your reply to at most {} sentence(s); it will be spoken aloud.",
+                sentences
+            )),
+            (None, Some(chars)) => Some(format!(
+                "Keep your reply under {} characters; it will be spoken aloud.",
+                chars
+            )),
+            (None, None) => None,
+        }
+    }
+
+    /// Trim text to the limit at a sentence boundary
+    pub fn truncate(&self, text: &str) -> String {
+        let text = text.trim();
+        if !self.is_limited() {
+            return text.to_string();
+        }
+
+        let max_sentences = self.max_sentences.unwrap_or(usize::MAX).max(1);
+        let max_chars = self.max_chars.unwrap_or(usize::MAX);
+
+        let mut end = 0;
+        for (count, boundary) in sentence_ends(text).into_iter().enumerate() {
+            if count >= max_sentences || (count > 0 && text[..boundary].chars().count() > max_chars)
+            {
+                break;
+            }
+            end = boundary;
+        }
+
+        if end == 0 || end == text.len() {
+            text.to_string()
+        } else {
+            text[..end].trim_end().to_string()
+        }
+    }
+}
+
+/// Byte offsets just past each sentence terminator, plus the end of the text
+fn sentence_ends(text: &str) -> Vec<usize> {
+    let mut ends = Vec::new();
+    let mut chars = text.char_indices().peekable();
+
+    while let Some((i, c)) = chars.next() {
+        if !matches!(c, '.' | '!' | '?' | '।' | '॥') {
+            continue;
+        }
+        // Closing quotes/brackets belong to the sentence
+        let mut end = i + c.len_utf8();
+        while let Some(&(j, next)) = chars.peek() {
+            if matches!(next, '"' | '\'' | ')' | '”' | '’') {
+                end = j + next.len_utf8();
+                chars.next();
+            } else {
+                break;
+            }
+        }
+        // A terminator ends a sentence only when followed by whitespace or the end
+        let at_break = match chars.peek() {
+            Some(&(_, next)) => next.is_whitespace(),
+            None => true,
+        };
+        if at_break && !(c == '.' && ends_with_abbreviation(&text[..i])) {
+            ends.push(end);
+        }
+    }
+
+    if ends.last() != Some(&text.len()) {
+        ends.push(text.len());
+    }
+    ends
+}
+
 /// Requirements for stage transitions
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct StageRequirements {
@@ -277,6 +528,7 @@ impl std::error::Error for StagesConfigError {}
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::time::Duration;
 
     #[test]
     fn test_stage_deserialization() {
@@ -314,6 +566,58 @@ stages:
         assert_eq!(greeting.transitions, vec!["discovery", "farewell"]);
     }
 
+    #[test]
+    fn test_response_limit_trims_at_sentence_boundary() {
+        let yaml = r#"
+stages:
+  greeting:
+    max_response_sentences: 2
+  discovery:
+    guidance: "Understand customer needs"
+"#;
+        let config: StagesConfig = serde_yaml::from_str(yaml).unwrap();
+        let limit = config.get_response_limit("greeting").unwrap();
+        assert!(!config.get_response_limit("discovery").unwrap().is_limited());
+        assert!(config.get_response_limit("closing").is_none());
+
+        let long = "Hello! I'm Priya from Kotak. Our rate starts at 9.5% p.a. and Rs. 5,000 \
+                    is the minimum. Would you like to know more?";
+        assert_eq!(limit.truncate(long), "Hello! I'm Priya from Kotak.");
+
+        // Decimals and abbreviations are not sentence ends
+        let limit = ResponseLimit::sentences(3);
+        assert_eq!(
+            limit.truncate(long),
+            "Hello! I'm Priya from Kotak. Our rate starts at 9.5% p.a. and Rs. 5,000 \
+                    is the minimum."
+        );
+
+        // A number before the period still ends the sentence
+        let limit = ResponseLimit::sentences(1);
+        assert_eq!(limit.truncate("I need 5. Thanks"), "I need 5.");
+    }
+
+    #[test]
+    fn test_response_limit_chars_never_cut_mid_sentence() {
+        let text = "This sentence is quite long on its own. Second one.";
+
+        let limit = ResponseLimit::default().with_max_chars(45);
+        assert_eq!(
+            limit.truncate(text),
+            "This sentence is quite long on its own."
+        );
+
+        // The first sentence is kept whole even if it alone is over the limit
+        let limit = ResponseLimit::default().with_max_chars(10);
+        assert_eq!(
+            limit.truncate(text),
+            "This sentence is quite long on its own."
+        );
+
+        // Within the limit, text is unchanged
+        assert_eq!(ResponseLimit::sentences(5).truncate(text), text);
+    }
+
     #[test]
     fn test_transition_validation() {
         let yaml = r#"
@@ -383,4 +687,32 @@ intent_transitions:
         // Test non-existent transition
         assert!(config.get_intent_transition("loan_inquiry", "closing").is_none());
     }
+
+    #[test]
+    fn test_stage_timeout() {
+        let yaml = r#"
+stages:
+  discovery:
+    timeout:
+      max_turns: 4
+      max_duration_secs: 180
+      message: "Let me tell you about the next steps."
+  objection_handling:
+    timeout:
+      max_turns: 3
+      action: escalate
+  greeting: {}
+"#;
+        let config: StagesConfig = serde_yaml::from_str(yaml).unwrap();
+
+        let discovery = config.get_timeout("discovery").unwrap();
+        assert_eq!(discovery.action, StageTimeoutAction::Advance);
+        assert!(!discovery.exceeded(3, Duration::from_secs(60)));
+        assert!(discovery.exceeded(4, Duration::from_secs(60)));
+        assert!(discovery.exceeded(1, Duration::from_secs(180)));
+
+        let objection = config.get_timeout("objection_handling").unwrap();
+        assert_eq!(objection.action, StageTimeoutAction::Escalate);
+        assert!(config.get_timeout("greeting").is_none());
+    }
 }
@@ -18,7 +18,7 @@
 
 use std::collections::HashSet;
 use super::MasterDomainConfig;
-use super::slots::SlotType;
+use super::slots::{SlotRetryFallback, SlotType};
 
 /// Validation error with context
 #[derive(Debug, Clone)]
@@ -264,6 +264,36 @@ impl ConfigValidator {
                 }
             }
         }
+
+        // A fallback that escalates or sends a form link must tell the
+        // customer first, so the default policy and every slot override
+        // need a message for the fallback they take
+        let overrides = slots
+            .slots
+            .iter()
+            .filter(|(_, slot)| slot.retry_fallback.is_some())
+            .map(|(id, _)| (id.clone(), slots.retry_policy_for(id)));
+        let policies = std::iter::once(("retry_policy".to_string(), slots.retry_policy.clone()))
+            .chain(overrides);
+        for (field, policy) in policies {
+            let fallback = policy.fallback;
+            if fallback == SlotRetryFallback::Skip {
+                continue;
+            }
+            let message = policy.message(fallback).unwrap_or_default();
+            if message.trim().is_empty() {
+                result.add_error(ValidationError {
+                    category: ValidationCategory::MissingRequired,
+                    source: "slots.yaml".to_string(),
+                    field: Some(field),
+                    message: format!(
+                        "Retry fallback '{}' needs a message in retry_policy.messages",
+                        fallback.as_str()
+                    ),
+                    severity: ValidationSeverity::Critical,
+                });
+            }
+        }
     }
 
     /// Validate goals configuration
@@ -493,6 +523,60 @@ mod tests {
         assert!(display.contains("References unknown slot"));
     }
 
+    fn retry_errors(slots_yaml: &str) -> Vec<ValidationError> {
+        let mut config = MasterDomainConfig::default();
+        config.slots = serde_yaml::from_str(slots_yaml).unwrap();
+        let result = ConfigValidator::new().validate("test_domain", &config);
+        result
+            .errors
+            .into_iter()
+            .filter(|e| e.message.starts_with("Retry fallback"))
+            .collect()
+    }
+
+    #[test]
+    fn test_retry_fallbacks_require_messages() {
+        let skip = r#"
+slots:
+  loan_amount:
+    type: number
+    description: "Amount"
+"#;
+        assert!(retry_errors(skip).is_empty());
+
+        let silent = r#"
+slots:
+  phone_number:
+    type: string
+    description: "Phone"
+    retry_fallback: send_form_link
+retry_policy:
+  fallback: escalate
+"#;
+        let errors = retry_errors(silent);
+        assert_eq!(errors.len(), 2);
+        for error in &errors {
+            assert_eq!(error.severity, ValidationSeverity::Critical);
+        }
+        let fields: HashSet<_> = errors.iter().filter_map(|e| e.field.as_deref()).collect();
+        assert!(fields.contains("retry_policy"));
+        assert!(fields.contains("phone_number"));
+
+        let spoken = r#"
+slots:
+  phone_number:
+    type: string
+    description: "Phone"
+    retry_fallback: send_form_link
+retry_policy:
+  fallback: escalate
+  messages:
+    escalate: "Let me connect you to a colleague."
+    send_form_link: "I can send you a link to fill it in."
+"#;
+        assert!(retry_errors(spoken).is_empty());
+    }
+
     #[test]
     fn test_severity_ordering() {
         assert!(ValidationSeverity::Warning < ValidationSeverity::Error);
@@ -10,10 +10,11 @@ use super::branches::{BranchEntry, BranchesConfig};
 use super::competitors::{CompetitorEntry as ExtCompetitorEntry, CompetitorsConfig};
 use super::objections::{ObjectionResponse, ObjectionsConfig};
 use super::prompts::PromptsConfig;
-use super::scoring::{CategoryWeights, EscalationConfig, ScoringConfig};
+use super::scoring::{CategoryWeights, EscalationConfig, LiveAgentHours, ScoringConfig};
 use super::segments::{SegmentDefinition, SegmentsConfig};
 use super::slots::{GoalDefinition, SlotDefinition, SlotsConfig};
 use super::sms_templates::SmsTemplatesConfig;
+use super::stages::{ResponseLimit, SamplingParams, StageTimeout};
 use super::stages::{StageDefinition, StagesConfig, TransitionTrigger};
 use super::tools::{ToolSchema, ToolsConfig};
 use super::{
@@ -240,11 +241,36 @@ impl AgentDomainView {
         self.config.stages.get_context_budget(stage_id)
     }
 
+    /// Get response length limit for a stage, if the stage is configured
+    pub fn stage_response_limit(&self, stage_id: &str) -> Option<ResponseLimit> {
+        self.config.stages.get_response_limit(stage_id)
+    }
+
+    /// Get LLM sampling parameters for a stage, if the stage is configured
+    pub fn stage_sampling(&self, stage_id: &str) -> Option<SamplingParams> {
+        self.config.stages.get_sampling(stage_id)
+    }
+
+    /// Get the turn/time limit for a stage, if one is configured
+    pub fn stage_timeout(&self, stage_id: &str) -> Option<&StageTimeout> {
+        self.config.stages.get_timeout(stage_id)
+    }
+
     /// Get RAG context fraction for a stage (0.0-1.0)
     pub fn stage_rag_fraction(&self, stage_id: &str) -> f32 {
         self.config.stages.get_rag_fraction(stage_id)
     }
 
+    /// Get configured retrieval top-k for a stage
+    pub fn stage_rag_top_k(&self, stage_id: &str) -> Option<usize> {
+        self.config.stages.get_rag_top_k(stage_id)
+    }
+
+    /// Get configured retrieval score cutoff for a stage
+    pub fn stage_rag_min_score(&self, stage_id: &str) -> Option<f32> {
+        self.config.stages.get_rag_min_score(stage_id)
+    }
+
     /// Get transition trigger for a stage
     pub fn stage_trigger(&self, stage_id: &str) -> Option<&TransitionTrigger> {
         self.config.stages.get_trigger(stage_id)
@@ -447,6 +473,8 @@ impl AgentDomainView {
                 acknowledge_emotions: seg_persona.acknowledge_emotions,
                 use_hinglish: seg_persona.use_hinglish,
                 max_response_words: seg_persona.max_response_words,
+                speaking_rate: seg_persona.speaking_rate,
+                pitch: seg_persona.pitch,
             }
         })
     }
@@ -523,18 +551,60 @@ impl AgentDomainView {
             .map(|r| self.substitute_brand_placeholders(r))
     }
 
+    /// Canned FAQ answers (intent -> language -> answer) with brand substitution
+    pub fn faq_answers(&self) -> HashMap<String, HashMap<String, String>> {
+        self.config
+            .prompts
+            .faq_answers
+            .iter()
+            .map(|(intent, answers)| {
+                let answers = answers
+                    .iter()
+                    .map(|(lang, text)| (lang.clone(), self.substitute_brand_placeholders(text)))
+                    .collect();
+                (intent.clone(), answers)
+            })
+            .collect()
+    }
+
+    /// Note appended to cached FAQ answers, by language, with brand substitution
+    pub fn faq_cached_notes(&self) -> HashMap<String, String> {
+        self.config
+            .prompts
+            .faq_cached_notes
+            .iter()
+            .map(|(lang, text)| (lang.clone(), self.substitute_brand_placeholders(text)))
+            .collect()
+    }
+
     /// Get greeting text for a language
     pub fn greeting(&self, language: &str) -> String {
         let template = self.config.prompts.get_greeting(language);
         self.substitute_brand_placeholders(template)
     }
 
+    /// Greeting for a language, personalized for the call date and the
+    /// customer's history
+    ///
+    /// Adds a festival greeting when a configured festival falls on the call
+    /// date, and a "welcome back" line after the time-of-day salutation for
+    /// returning customers.
+    pub fn personalized_greeting(&self, language: &str, context: &GreetingContext) -> String {
+        personalize_greeting(&self.config.prompts, &self.greeting(language), language, context)
+    }
+
     /// Get farewell text for a language
     pub fn farewell(&self, language: &str) -> String {
         let template = self.config.prompts.get_farewell(language);
         self.substitute_brand_placeholders(template)
     }
 
+    /// Fill brand placeholders in text that doesn't come from this view,
+    /// e.g. experiment variant greetings
+    pub fn with_brand(&self, text: &str) -> String {
+        self.substitute_brand_placeholders(text)
+    }
+
     /// Substitute brand placeholders in text
     /// P16 FIX: Supports both new ({company_name}) and legacy ({bank_name}) placeholders
     fn substitute_brand_placeholders(&self, text: &str) -> String {
@@ -923,6 +993,69 @@ impl AgentDomainView {
         self.config.compliance.get_ai_disclosure(language)
     }
 
+    /// Get the recording consent question asked after the AI disclosure
+    pub fn consent_prompt(&self, language: &str) -> &str {
+        self.config.compliance.get_consent_prompt(language)
+    }
+
+    /// Opening script the call starts with
+    pub fn opening_script(&self) -> &super::OpeningScriptConfig {
+        &self.config.compliance.opening_script
+    }
+
+    /// Opening purpose statement for a language, with brand substitution
+    pub fn opening_purpose(&self, language: &str) -> String {
+        self.substitute_brand_placeholders(self.config.compliance.get_opening_purpose(language))
+    }
+
+    /// Closing line when consent is refused during the opening
+    pub fn opening_refusal(&self, language: &str) -> String {
+        self.substitute_brand_placeholders(self.config.compliance.get_opening_refusal(language))
+    }
+
+    /// Reply to the customer's answer to the consent question
+    pub fn consent_acknowledgement(&self, given: bool, language: &str) -> &str {
+        self.config
+            .compliance
+            .get_consent_acknowledgement(given, language)
+    }
+
+    /// Question asked after the consent reply when no opening script follows
+    pub fn consent_follow_up(&self, language: &str) -> &str {
+        self.config.compliance.get_consent_follow_up(language)
+    }
+
+    /// Redirect for questions outside the domain, from the `out_of_scope`
+    /// response template
+    pub fn out_of_scope_redirect(&self, language: &str) -> Option<String> {
+        self.config
+            .prompts
+            .response_template("out_of_scope", language)
+            .map(|text| self.substitute_brand_placeholders(text))
+    }
+
+    /// Whether `text` is only fillers and acknowledgements ("okay thanks")
+    pub fn is_filler_only(&self, text: &str) -> bool {
+        let patterns = &self.config.memory_compressor.filler_patterns;
+        let text = text.to_lowercase();
+        let mut rest = text.trim_matches(|c: char| !c.is_alphanumeric());
+        if rest.is_empty() {
+            return false;
+        }
+        while !rest.is_empty() {
+            // A filler ends at a word boundary, so "ok" doesn't match "okay"
+            let filler = patterns.values().flatten().find(|pattern| {
+                rest.strip_prefix(pattern.as_str())
+                    .is_some_and(|after| !after.starts_with(char::is_alphanumeric))
+            });
+            let Some(filler) = filler else {
+                return false;
+            };
+            rest = rest[filler.len()..].trim_start_matches(|c: char| !c.is_alphanumeric());
+        }
+        true
+    }
+
     /// Check if a phrase is forbidden by compliance rules
     pub fn is_forbidden_phrase(&self, text: &str) -> bool {
         self.config.compliance.is_forbidden(text)
@@ -1029,6 +1162,72 @@ impl AgentDomainView {
     }
 }
 
+/// India Standard Time (UTC+05:30), the callers' time zone
+const IST_OFFSET_SECS: i32 = 5 * 3600 + 30 * 60;
+
+/// When and to whom a greeting is spoken
+#[derive(Debug, Clone, Default)]
+pub struct GreetingContext {
+    /// Caller's hour of day (0-23)
+    pub hour: u32,
+    /// Caller's date, for festival greetings
+    pub date: Option<chrono::NaiveDate>,
+    /// Whether the customer has called before
+    pub returning_customer: bool,
+}
+
+impl GreetingContext {
+    /// Context for the current time in IST
+    pub fn now() -> Self {
+        Self::at(chrono::Utc::now())
+    }
+
+    /// Context for `time`, taken in IST whatever the server's time zone
+    pub fn at(time: chrono::DateTime<chrono::Utc>) -> Self {
+        use chrono::Timelike;
+
+        let ist = chrono::FixedOffset::east_opt(IST_OFFSET_SECS).expect("valid offset");
+        let local = time.with_timezone(&ist);
+        Self {
+            hour: local.hour(),
+            date: Some(local.date_naive()),
+            returning_customer: false,
+        }
+    }
+
+    /// Mark the customer as returning
+    pub fn returning(mut self, returning: bool) -> Self {
+        self.returning_customer = returning;
+        self
+    }
+}
+
+/// Festival greeting, time-of-day salutation and "welcome back" line
+/// ahead of `base_greeting`, whose own "Hello!" the salutation replaces
+fn personalize_greeting(
+    prompts: &PromptsConfig,
+    base_greeting: &str,
+    language: &str,
+    context: &GreetingContext,
+) -> String {
+    let time_greeting = prompts.get_time_of_day_greeting(language, context.hour);
+
+    let mut parts = Vec::new();
+    if let Some(festival) = context
+        .date
+        .and_then(|date| prompts.get_festival_greeting(date, language))
+    {
+        parts.push(festival.to_string());
+    }
+    parts.push(format!("{}!", time_greeting));
+    if context.returning_customer {
+        parts.push(prompts.get_returning_greeting(language).to_string());
+    }
+    parts.push(base_greeting.trim_start_matches("Hello! ").to_string());
+
+    parts.join(" ")
+}
+
 /// View for the llm crate
 /// Provides access to prompts, tool schemas, brand info
 pub struct LlmDomainView {
@@ -1202,12 +1401,7 @@ impl LlmDomainView {
 
     /// Get greeting with time-based prefix (morning/afternoon/evening)
     pub fn get_greeting_with_time(&self, language: &str, hour: u32) -> String {
-        let time_greeting = match hour {
-            5..=11 => "Good morning",
-            12..=16 => "Good afternoon",
-            17..=20 => "Good evening",
-            _ => "Hello",
-        };
+        let time_greeting = self.config.prompts.get_time_of_day_greeting(language, hour);
 
         let base_greeting = self.get_greeting(language);
         format!("{}! {}", time_greeting, base_greeting.trim_start_matches("Hello! "))
@@ -1308,6 +1502,11 @@ impl ToolsDomainView {
 
     // ====== Branch Configuration ======
 
+    /// Hours human agents take live escalations (None = always)
+    pub fn live_agent_hours(&self) -> Option<&LiveAgentHours> {
+        self.config.scoring.escalation.live_agent_hours.as_ref()
+    }
+
     /// Get the full branches configuration
     pub fn branches_config(&self) -> &BranchesConfig {
         &self.config.branches
@@ -1993,6 +2192,83 @@ fn format_amount(amount: f64) -> String {
 mod tests {
     use super::*;
 
+    fn greeting_view() -> AgentDomainView {
+        let mut config = MasterDomainConfig::default();
+        config.brand.agent_name = "Priya".to_string();
+        config.brand.company_name = "Acme Bank".to_string();
+        config.prompts.greetings.insert(
+            "en".to_string(),
+            "Hello! I'm {agent_name} from {company_name}. How can I help you today?".to_string(),
+        );
+        config.prompts.festival_greetings.push(crate::domain::FestivalGreeting {
+            name: "diwali".to_string(),
+            dates: vec!["2026-11-08".to_string()],
+            greetings: HashMap::from([("en".to_string(), "Happy Diwali!".to_string())]),
+        });
+        AgentDomainView::new(Arc::new(config))
+    }
+
+    #[test]
+    fn test_first_time_morning_greeting() {
+        let view = greeting_view();
+        let context = GreetingContext {
+            hour: 9,
+            date: chrono::NaiveDate::from_ymd_opt(2026, 3, 2),
+            returning_customer: false,
+        };
+
+        assert_eq!(
+            view.personalized_greeting("en", &context),
+            "Good morning! I'm Priya from Acme Bank. How can I help you today?"
+        );
+    }
+
+    #[test]
+    fn test_returning_customer_greeting() {
+        let view = greeting_view();
+        let context = GreetingContext {
+            hour: 18,
+            date: None,
+            returning_customer: true,
+        };
+
+        assert_eq!(
+            view.personalized_greeting("en", &context),
+            "Good evening! Welcome back! I'm Priya from Acme Bank. How can I help you today?"
+        );
+    }
+
+    #[test]
+    fn test_festival_greeting() {
+        let view = greeting_view();
+        let context = GreetingContext {
+            hour: 10,
+            date: chrono::NaiveDate::from_ymd_opt(2026, 11, 8),
+            returning_customer: false,
+        };
+
+        let greeting = view.personalized_greeting("en", &context);
+        assert!(greeting.starts_with("Happy Diwali! Good morning!"));
+
+        // Not on the next day
+        let next_day = GreetingContext {
+            date: chrono::NaiveDate::from_ymd_opt(2026, 11, 9),
+            ..context
+        };
+        assert!(!view.personalized_greeting("en", &next_day).contains("Diwali"));
+    }
+
+    #[test]
+    fn test_greeting_context_uses_ist() {
+        use chrono::TimeZone;
+
+        // 20:00 UTC is 01:30 the next day in India
+        let time = chrono::Utc.with_ymd_and_hms(2026, 11, 7, 20, 0, 0).unwrap();
+        let context = GreetingContext::at(time);
+        assert_eq!(context.hour, 1);
+        assert_eq!(context.date, chrono::NaiveDate::from_ymd_opt(2026, 11, 8));
+    }
+
     #[test]
     fn test_format_amount() {
         assert_eq!(format_amount(10000.0), "10000");
@@ -2001,4 +2277,20 @@ mod tests {
         assert_eq!(format_amount(10000000.0), "1.0 Cr");
         assert_eq!(format_amount(25000000.0), "2.5 Cr");
     }
+
+    #[test]
+    fn test_filler_only_turns() {
+        let mut config = MasterDomainConfig::default();
+        config.memory_compressor.filler_patterns = HashMap::from([(
+            "en".to_string(),
+            vec!["ok".to_string(), "okay".to_string(), "thanks".to_string()],
+        )]);
+        let view = AgentDomainView::new(Arc::new(config));
+
+        assert!(view.is_filler_only("Okay, thanks!"));
+        assert!(view.is_filler_only("ok"));
+        assert!(!view.is_filler_only("Okay, what's the weather?"));
+        assert!(!view.is_filler_only("okra"));
+        assert!(!view.is_filler_only("..."));
+    }
 }
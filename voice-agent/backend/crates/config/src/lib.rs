@@ -28,18 +28,24 @@ pub mod domain;
 pub mod pipeline;
 pub mod settings;
 
-pub use agent::{AgentConfig, MemoryConfig, PersonaConfig};
-pub use pipeline::PipelineConfig;
+pub use agent::{
+    AgentConfig, ConcurrencyLimitSettings, LlmPricingConfig, MemoryConfig, ModelPriceConfig,
+    OutOfScopeConfig, PersonaConfig, RoutingConfig,
+};
+pub use pipeline::{PipelineConfig, SpellOutConfig, SpellOutMode, SpellOutRule};
 pub use settings::{
-    load_settings, AuthConfig, PersistenceConfig, RagConfig, RateLimitConfig, RuntimeEnvironment,
-    ServerConfig, Settings, TurnServerConfig,
+    load_settings, AppointmentCalendarConfig, AppointmentReminderConfig, AudioPlayoutConfig,
+    AuthConfig, ConversationRecordingConfig, DataErasureConfig, DataExportConfig,
+    DuplicateConnectionPolicy, PersistenceConfig, RagConfig,
+    RateLimitConfig, RedisConfig, ResumeConfig, RuntimeEnvironment, ScopedApiKey, ServerConfig,
+    SessionBackend, Settings, TurnServerConfig, VectorBackend, WebhookConfig,
 };
 
 // P13 FIX: Domain configuration via MasterDomainConfig + views
 pub use domain::{
     MasterDomainConfig,
     // Sub-config types
-    BranchDefaults, BranchEntry, BranchesConfig,
+    BranchDefaults, BranchEntry, BranchesConfig, PincodeRegionEntry,
     ComparisonPoint, CompetitorDefaults, CompetitorEntry,
     CompetitorsConfig, NumericThreshold, ObjectionDefinition, ObjectionResponse, ObjectionsConfig,
     PromptsConfig, QualificationThresholds, ScoringConfig, SegmentDefinition, SegmentDetection,
@@ -1,6 +1,8 @@
 //! Pipeline configuration
 
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use voice_agent_core::Language;
 
 /// Pipeline configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -269,6 +271,18 @@ pub struct TtsConfig {
     /// Maximum queue depth
     #[serde(default = "default_queue_depth")]
     pub max_queue_depth: usize,
+
+    /// Per-language spell-out of codes and words the voice can't pronounce
+    #[serde(default)]
+    pub spell_out: SpellOutConfig,
+
+    /// Times synthesis is tried again after failing, before the fallback plays
+    #[serde(default = "default_tts_retries")]
+    pub retries: usize,
+
+    /// What the caller hears when synthesis keeps failing
+    #[serde(default)]
+    pub fallback: TtsFallbackConfig,
 }
 
 fn default_voice() -> String {
@@ -289,6 +303,9 @@ fn default_crossfade() -> u32 {
 fn default_queue_depth() -> usize {
     5
 }
+fn default_tts_retries() -> usize {
+    1
+}
 
 impl Default for TtsConfig {
     fn default() -> Self {
@@ -300,6 +317,47 @@ impl Default for TtsConfig {
             chunk_mode: default_chunk_mode(),
             crossfade_ms: default_crossfade(),
             max_queue_depth: default_queue_depth(),
+            spell_out: SpellOutConfig::default(),
+            retries: default_tts_retries(),
+            fallback: TtsFallbackConfig::default(),
+        }
+    }
+}
+
+/// Audio played, once per response, in place of speech TTS couldn't synthesize
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum TtsFallbackConfig {
+    /// Nothing
+    Silence,
+    /// A sine tone
+    Beep {
+        /// Tone frequency (Hz)
+        #[serde(default = "default_beep_frequency")]
+        frequency_hz: f32,
+        /// Tone length (ms)
+        #[serde(default = "default_beep_duration")]
+        duration_ms: u32,
+    },
+    /// A pre-recorded WAV clip ("please hold"), resampled as needed
+    Clip {
+        /// Path to the WAV file
+        path: String,
+    },
+}
+
+fn default_beep_frequency() -> f32 {
+    440.0
+}
+fn default_beep_duration() -> u32 {
+    300
+}
+
+impl Default for TtsFallbackConfig {
+    fn default() -> Self {
+        TtsFallbackConfig::Beep {
+            frequency_hz: default_beep_frequency(),
+            duration_ms: default_beep_duration(),
         }
     }
 }
@@ -318,6 +376,102 @@ pub enum TtsChunkMode {
     SentenceLevel,
 }
 
+/// How a token is spelled out before synthesis
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SpellOutMode {
+    /// Leave tokens as they are
+    Off,
+    /// Separate characters with spaces ("GL24" → "G L 2 4")
+    #[default]
+    Letters,
+    /// Latin letters as their names in the language's script
+    Transliterate,
+}
+
+/// Spell-out rule for one language
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SpellOutRule {
+    #[serde(default)]
+    pub mode: SpellOutMode,
+    /// Spell out codes: uppercase letters mixed with digits ("GL2024X")
+    #[serde(default = "default_true")]
+    pub codes: bool,
+    /// Spell out every word written in a script other than the language's
+    ///
+    /// Only for voices that can't read the other script at all; Hinglish
+    /// voices read English words fine.
+    #[serde(default)]
+    pub mismatched_script: bool,
+}
+
+impl Default for SpellOutRule {
+    fn default() -> Self {
+        Self {
+            mode: SpellOutMode::Letters,
+            codes: true,
+            mismatched_script: false,
+        }
+    }
+}
+
+/// Spell-out rules by language
+///
+/// English voices read Latin codes themselves, so English is off unless
+/// `languages` is set without it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SpellOutConfig {
+    /// Rule for languages without their own
+    #[serde(default)]
+    pub default: SpellOutRule,
+    /// Per-language overrides
+    #[serde(default = "default_spell_out_languages")]
+    pub languages: HashMap<Language, SpellOutRule>,
+}
+
+fn default_spell_out_languages() -> HashMap<Language, SpellOutRule> {
+    HashMap::from([(
+        Language::English,
+        SpellOutRule {
+            mode: SpellOutMode::Off,
+            ..Default::default()
+        },
+    )])
+}
+
+impl Default for SpellOutConfig {
+    fn default() -> Self {
+        Self {
+            default: SpellOutRule::default(),
+            languages: default_spell_out_languages(),
+        }
+    }
+}
+
+impl SpellOutConfig {
+    /// Spell-out switched off for every language
+    pub fn disabled() -> Self {
+        Self {
+            default: SpellOutRule {
+                mode: SpellOutMode::Off,
+                ..Default::default()
+            },
+            languages: HashMap::new(),
+        }
+    }
+
+    /// Set the rule for a language
+    pub fn with_rule(mut self, language: Language, rule: SpellOutRule) -> Self {
+        self.languages.insert(language, rule);
+        self
+    }
+
+    /// Rule applied to `language`
+    pub fn rule(&self, language: Language) -> &SpellOutRule {
+        self.languages.get(&language).unwrap_or(&self.default)
+    }
+}
+
 /// Barge-in configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BargeInConfig {
@@ -344,6 +498,43 @@ pub struct BargeInConfig {
     /// Cooldown after barge-in (ms)
     #[serde(default = "default_cooldown")]
     pub cooldown_ms: u32,
+
+    /// Longest a protected utterance (disclosure, required question) can
+    /// hold off barge-in (ms)
+    #[serde(default = "default_protected_max")]
+    pub protected_max_ms: u32,
+
+    /// Playback time after the agent starts speaking during which barge-in
+    /// is ignored (ms)
+    #[serde(default = "default_barge_in_grace")]
+    pub grace_period_ms: u32,
+
+    /// Words the caller must say before speech counts as barge-in (0 = any speech)
+    #[serde(default)]
+    pub min_words: usize,
+
+    /// Sensitivity overrides keyed by conversation stage ID (e.g. "closing")
+    #[serde(default)]
+    pub stages: HashMap<String, BargeInProfile>,
+}
+
+/// Barge-in sensitivity for one conversation stage
+///
+/// Fields left out use the top-level `BargeInConfig` value.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct BargeInProfile {
+    /// Playback time after the agent starts speaking during which barge-in
+    /// is ignored (ms)
+    #[serde(default)]
+    pub grace_period_ms: Option<u32>,
+
+    /// Minimum speech duration to trigger interrupt (ms)
+    #[serde(default)]
+    pub min_speech_ms: Option<u32>,
+
+    /// Words the caller must say before speech counts as barge-in
+    #[serde(default)]
+    pub min_words: Option<usize>,
 }
 
 fn default_barge_in_threshold() -> f32 {
@@ -361,6 +552,12 @@ fn default_barge_in_action() -> BargeInAction {
 fn default_cooldown() -> u32 {
     500
 }
+fn default_protected_max() -> u32 {
+    8000
+}
+fn default_barge_in_grace() -> u32 {
+    200
+}
 
 impl Default for BargeInConfig {
     fn default() -> Self {
@@ -371,6 +568,10 @@ impl Default for BargeInConfig {
             energy_threshold_db: default_barge_in_energy(),
             action: default_barge_in_action(),
             cooldown_ms: default_cooldown(),
+            protected_max_ms: default_protected_max(),
+            grace_period_ms: default_barge_in_grace(),
+            min_words: 0,
+            stages: HashMap::new(),
         }
     }
 }
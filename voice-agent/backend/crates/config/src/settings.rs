@@ -2,11 +2,12 @@
 
 use config::{Config, Environment, File};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::path::Path;
 
 use crate::constants::{endpoints, rag};
 // P13 FIX: GoldLoanConfig removed - use MasterDomainConfig + views instead
-use crate::{AgentConfig, ConfigError, PipelineConfig};
+use crate::{AgentConfig, ConcurrencyLimitSettings, ConfigError, PipelineConfig};
 
 /// P1 FIX: Runtime environment enum
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
@@ -98,6 +99,125 @@ pub struct PersistenceConfig {
     /// ScyllaDB replication factor
     #[serde(default = "default_replication_factor")]
     pub replication_factor: u8,
+
+    /// Connections kept open to each ScyllaDB node
+    #[serde(default = "default_scylla_pool_size")]
+    pub pool_size: usize,
+
+    /// Where session metadata is stored
+    #[serde(default)]
+    pub session_backend: SessionBackend,
+
+    /// Redis connection, used when `session_backend` is `redis`
+    #[serde(default)]
+    pub redis: RedisConfig,
+
+    /// SMS reminders ahead of booked appointments
+    #[serde(default)]
+    pub reminders: AppointmentReminderConfig,
+
+    /// Branch visit calendar kept in the appointments table
+    #[serde(default)]
+    pub calendar: AppointmentCalendarConfig,
+}
+
+/// SMS reminders ahead of booked appointments
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AppointmentReminderConfig {
+    /// Schedule a reminder when an appointment is booked
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// How long before the appointment the reminder goes out
+    #[serde(default = "default_reminder_lead_time_mins")]
+    pub lead_time_mins: u64,
+
+    /// How often the worker checks for due reminders
+    #[serde(default = "default_reminder_poll_interval_secs")]
+    pub poll_interval_secs: u64,
+}
+
+fn default_reminder_lead_time_mins() -> u64 {
+    24 * 60
+}
+
+fn default_reminder_poll_interval_secs() -> u64 {
+    60
+}
+
+impl Default for AppointmentReminderConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            lead_time_mins: default_reminder_lead_time_mins(),
+            poll_interval_secs: default_reminder_poll_interval_secs(),
+        }
+    }
+}
+
+/// Branch visit calendar backed by the appointments table
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AppointmentCalendarConfig {
+    /// Book, reschedule and cancel appointments in the appointments table
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+
+    /// Appointments a branch takes per time slot
+    #[serde(default = "default_slot_capacity")]
+    pub slot_capacity: u32,
+}
+
+fn default_slot_capacity() -> u32 {
+    4
+}
+
+impl Default for AppointmentCalendarConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            slot_capacity: default_slot_capacity(),
+        }
+    }
+}
+
+/// Backend for persisted session metadata
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum SessionBackend {
+    /// Sessions table in ScyllaDB
+    #[default]
+    Scylla,
+    /// Redis keys that expire with the session
+    Redis,
+}
+
+/// Redis connection for the session store
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RedisConfig {
+    /// Connection URL
+    #[serde(default = "default_redis_url")]
+    pub url: String,
+
+    /// Prefix for session keys
+    #[serde(default = "default_redis_key_prefix")]
+    pub key_prefix: String,
+}
+
+fn default_redis_url() -> String {
+    std::env::var("REDIS_URL").unwrap_or_else(|_| "redis://127.0.0.1:6379".to_string())
+}
+
+fn default_redis_key_prefix() -> String {
+    "voice_agent:session:".to_string()
+}
+
+impl Default for RedisConfig {
+    fn default() -> Self {
+        Self {
+            url: default_redis_url(),
+            key_prefix: default_redis_key_prefix(),
+        }
+    }
 }
 
 fn default_scylla_hosts() -> Vec<String> {
@@ -115,6 +235,10 @@ fn default_replication_factor() -> u8 {
     1
 }
 
+fn default_scylla_pool_size() -> usize {
+    2
+}
+
 impl Default for PersistenceConfig {
     fn default() -> Self {
         Self {
@@ -122,6 +246,11 @@ impl Default for PersistenceConfig {
             scylla_hosts: default_scylla_hosts(),
             keyspace: default_scylla_keyspace(),
             replication_factor: default_replication_factor(),
+            pool_size: default_scylla_pool_size(),
+            session_backend: SessionBackend::default(),
+            redis: RedisConfig::default(),
+            reminders: AppointmentReminderConfig::default(),
+            calendar: AppointmentCalendarConfig::default(),
         }
     }
 }
@@ -492,6 +621,203 @@ pub struct ServerConfig {
     /// P2 FIX: TURN servers for WebRTC relay (when STUN fails)
     #[serde(default)]
     pub turn_servers: Vec<TurnServerConfig>,
+
+    /// What to do when a second WebSocket connects to a session that has one
+    #[serde(default)]
+    pub duplicate_connection: DuplicateConnectionPolicy,
+
+    /// Audio frames queued for a WebSocket client before the oldest are dropped
+    #[serde(default = "default_ws_audio_queue_frames")]
+    pub ws_audio_queue_frames: usize,
+
+    /// Jitter buffering and pacing of TTS audio sent to WebSocket clients
+    #[serde(default)]
+    pub audio_playout: AudioPlayoutConfig,
+
+    /// Resume tokens that let a client rejoin its session after a dropped connection
+    #[serde(default)]
+    pub resume: ResumeConfig,
+
+    /// Outbound webhooks notifying downstream systems of call events
+    #[serde(default)]
+    pub webhooks: WebhookConfig,
+
+    /// Export of a customer's data on an access request
+    #[serde(default)]
+    pub data_export: DataExportConfig,
+
+    /// Erasure of a customer's data on a right-to-erasure request
+    #[serde(default)]
+    pub data_erasure: DataErasureConfig,
+}
+
+/// TTS audio playout configuration
+///
+/// TTS audio is produced in bursts, and clients that play frames as they
+/// arrive stutter on variable networks. With a jitter target set, the server
+/// holds back the first frames of an utterance until that much audio is
+/// buffered, then sends frames at the rate they play. A larger target plays
+/// more smoothly at the cost of that much added latency.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AudioPlayoutConfig {
+    /// Audio buffered before the first frame is sent, in ms (0 = send frames as produced)
+    #[serde(default)]
+    pub jitter_target_ms: u64,
+}
+
+/// Session resume token configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ResumeConfig {
+    /// Issue resume tokens and accept them on session creation
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+
+    /// How long a token stays valid after it is issued
+    #[serde(default = "default_resume_token_ttl")]
+    pub token_ttl_seconds: u64,
+
+    /// Signing secret (should be set via VOICE_AGENT__SERVER__RESUME__SECRET env var)
+    ///
+    /// Without one a random secret is generated at startup, so tokens do not
+    /// survive a restart or work across instances.
+    #[serde(default)]
+    pub secret: Option<String>,
+}
+
+impl Default for ResumeConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            token_ttl_seconds: default_resume_token_ttl(),
+            secret: None,
+        }
+    }
+}
+
+fn default_resume_token_ttl() -> u64 {
+    900
+}
+
+/// Outbound webhook configuration
+///
+/// Events are POSTed as JSON to every URL. When a secret is set, each request
+/// carries an `X-Webhook-Signature: sha256=<hex>` HMAC of the body.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WebhookConfig {
+    /// Endpoints every event is delivered to (empty = webhooks disabled)
+    #[serde(default)]
+    pub urls: Vec<String>,
+
+    /// Event types to deliver (e.g. "lead_captured"); empty = all events
+    #[serde(default)]
+    pub events: Vec<String>,
+
+    /// Signing secret (should be set via VOICE_AGENT__SERVER__WEBHOOKS__SECRET env var)
+    #[serde(default)]
+    pub secret: Option<String>,
+
+    /// Retries after the first failed attempt before an event is dead-lettered
+    #[serde(default = "default_webhook_max_retries")]
+    pub max_retries: u32,
+
+    /// Delay before the first retry; doubled for each further retry
+    #[serde(default = "default_webhook_backoff_ms")]
+    pub initial_backoff_ms: u64,
+
+    /// Per-request timeout
+    #[serde(default = "default_webhook_timeout_ms")]
+    pub timeout_ms: u64,
+
+    /// File permanently failed deliveries are appended to, one JSON line each
+    #[serde(default)]
+    pub dead_letter_path: Option<String>,
+}
+
+impl Default for WebhookConfig {
+    fn default() -> Self {
+        Self {
+            urls: Vec::new(),
+            events: Vec::new(),
+            secret: None,
+            max_retries: default_webhook_max_retries(),
+            initial_backoff_ms: default_webhook_backoff_ms(),
+            timeout_ms: default_webhook_timeout_ms(),
+            dead_letter_path: None,
+        }
+    }
+}
+
+fn default_webhook_max_retries() -> u32 {
+    3
+}
+
+fn default_webhook_backoff_ms() -> u64 {
+    500
+}
+
+fn default_webhook_timeout_ms() -> u64 {
+    5000
+}
+
+/// Customer data export configuration
+///
+/// Serves `GET /customers/{phone}/export`, which bundles every record held
+/// about a customer. Needs persistence, and a key with the
+/// `customers:export` scope.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DataExportConfig {
+    /// Serve the export endpoint
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Records exported per store (sessions, SMS, ...); the rest are cut off
+    #[serde(default = "default_export_max_records")]
+    pub max_records: usize,
+
+    /// Secret keying the customer pseudonym exports are audited under
+    /// (should be set via VOICE_AGENT__SERVER__DATA_EXPORT__CUSTOMER_KEY_SECRET)
+    ///
+    /// Without one a random secret is generated at startup, so a customer's
+    /// exports are only linked on the audit trail within one run.
+    #[serde(default)]
+    pub customer_key_secret: Option<String>,
+}
+
+impl Default for DataExportConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            max_records: default_export_max_records(),
+            customer_key_secret: None,
+        }
+    }
+}
+
+fn default_export_max_records() -> usize {
+    1000
+}
+
+/// Customer data erasure configuration
+///
+/// Serves `POST /admin/customers/{phone}/erase`, which deletes or anonymizes
+/// every record held about a customer. Needs persistence, and a key with
+/// the `customers:erase` scope.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DataErasureConfig {
+    /// Serve the erasure endpoint
+    #[serde(default)]
+    pub enabled: bool,
+}
+
+/// Handling of a second connection for the same session
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum DuplicateConnectionPolicy {
+    /// Close the existing connection and hand the session to the new one
+    #[default]
+    Supersede,
+    /// Refuse the new connection while the existing one is open
+    Reject,
 }
 
 /// P2 FIX: TURN server configuration
@@ -526,6 +852,30 @@ pub struct AuthConfig {
     /// Paths that bypass authentication (e.g., health checks)
     #[serde(default = "default_public_paths")]
     pub public_paths: Vec<String>,
+
+    /// Additional API keys, each limited to a set of scopes
+    #[serde(default)]
+    pub scoped_keys: Vec<ScopedApiKey>,
+
+    /// Path prefixes that require a scope (e.g., "/admin/audit" -> "audit:read")
+    ///
+    /// Only a scoped key holding the scope may call these paths; the main
+    /// API key is not enough.
+    #[serde(default = "default_scoped_paths")]
+    pub scoped_paths: HashMap<String, String>,
+}
+
+/// API key granted a set of scopes
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScopedApiKey {
+    /// The key (should be set via env var, not committed config)
+    pub key: String,
+    /// Scopes granted to this key
+    #[serde(default)]
+    pub scopes: Vec<String>,
+    /// Tenant this key is limited to (None = all tenants)
+    #[serde(default)]
+    pub tenant: Option<String>,
 }
 
 fn default_public_paths() -> Vec<String> {
@@ -536,12 +886,35 @@ fn default_public_paths() -> Vec<String> {
     ]
 }
 
+fn default_scoped_paths() -> HashMap<String, String> {
+    HashMap::from([
+        ("/admin/audit".to_string(), "audit:read".to_string()),
+        ("/admin/sessions".to_string(), "sessions:read".to_string()),
+        ("/customers".to_string(), "customers:export".to_string()),
+        ("/admin/customers".to_string(), "customers:erase".to_string()),
+        ("/admin/supervise".to_string(), "calls:supervise".to_string()),
+    ])
+}
+
+impl AuthConfig {
+    /// Scope required for a path, if any (longest matching prefix wins)
+    pub fn required_scope(&self, path: &str) -> Option<&str> {
+        self.scoped_paths
+            .iter()
+            .filter(|(prefix, _)| path.starts_with(prefix.as_str()))
+            .max_by_key(|(prefix, _)| prefix.len())
+            .map(|(_, scope)| scope.as_str())
+    }
+}
+
 impl Default for AuthConfig {
     fn default() -> Self {
         Self {
             enabled: false, // Disabled by default for development
             api_key: None,
             public_paths: default_public_paths(),
+            scoped_keys: Vec::new(),
+            scoped_paths: default_scoped_paths(),
         }
     }
 }
@@ -589,6 +962,17 @@ impl Default for RateLimitConfig {
     }
 }
 
+/// Backend holding the dense vectors for RAG
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum VectorBackend {
+    /// Qdrant at `qdrant_endpoint`
+    #[default]
+    Qdrant,
+    /// Process memory; no external service, lost on restart
+    Memory,
+}
+
 /// P5 FIX: RAG configuration for retrieval and reranking
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RagConfig {
@@ -596,6 +980,10 @@ pub struct RagConfig {
     #[serde(default = "default_true")]
     pub enabled: bool,
 
+    /// Where dense vectors are stored
+    #[serde(default)]
+    pub vector_backend: VectorBackend,
+
     // P0 FIX: Vector store connection settings
     /// Qdrant endpoint URL
     #[serde(default = "default_qdrant_endpoint")]
@@ -667,6 +1055,10 @@ pub struct RagConfig {
     /// Top-K results for prefetch (smaller for speed)
     #[serde(default = "default_prefetch_top_k")]
     pub prefetch_top_k: usize,
+
+    /// Cap on concurrent embedding calls, shared by all sessions on the node
+    #[serde(default)]
+    pub embedding_concurrency: ConcurrencyLimitSettings,
 }
 
 // RAG default value functions - P1 FIX: Use centralized constants
@@ -722,6 +1114,7 @@ impl Default for RagConfig {
     fn default() -> Self {
         Self {
             enabled: true,
+            vector_backend: VectorBackend::default(),
             // P0 FIX: Vector store connection defaults
             qdrant_endpoint: default_qdrant_endpoint(),
             qdrant_collection: default_qdrant_collection(),
@@ -741,6 +1134,7 @@ impl Default for RagConfig {
             early_termination_min_results: default_early_termination_min_results(),
             prefetch_confidence_threshold: default_prefetch_confidence(),
             prefetch_top_k: default_prefetch_top_k(),
+            embedding_concurrency: ConcurrencyLimitSettings::default(),
         }
     }
 }
@@ -757,6 +1151,9 @@ fn default_ws_path() -> String {
 fn default_max_connections() -> usize {
     1000
 }
+fn default_ws_audio_queue_frames() -> usize {
+    50
+}
 fn default_timeout() -> u64 {
     30
 }
@@ -780,6 +1177,13 @@ impl Default for ServerConfig {
             auth: AuthConfig::default(),          // P1 FIX: Auth config
             stun_servers: default_stun_servers(), // P2 FIX: WebRTC STUN
             turn_servers: Vec::new(),             // P2 FIX: WebRTC TURN (requires configuration)
+            duplicate_connection: DuplicateConnectionPolicy::default(),
+            ws_audio_queue_frames: default_ws_audio_queue_frames(),
+            audio_playout: AudioPlayoutConfig::default(),
+            resume: ResumeConfig::default(),
+            webhooks: WebhookConfig::default(),
+            data_export: DataExportConfig::default(),
+            data_erasure: DataErasureConfig::default(),
         }
     }
 }
@@ -886,6 +1290,22 @@ pub struct ObservabilityConfig {
     /// Metrics port
     #[serde(default = "default_metrics_port")]
     pub metrics_port: u16,
+
+    /// Mask PII in logged user content (transcripts, responses)
+    #[serde(default = "default_true")]
+    pub redact_logs: bool,
+
+    /// Log fields that carry user content and are scanned for PII
+    #[serde(default = "default_log_content_fields")]
+    pub log_content_fields: Vec<String>,
+
+    /// PII entity types masked in logs
+    #[serde(default = "default_log_redaction_entities")]
+    pub log_redaction_entities: Vec<String>,
+
+    /// Structured per-session event recording for analytics
+    #[serde(default)]
+    pub recording: ConversationRecordingConfig,
 }
 
 fn default_log_level() -> String {
@@ -894,6 +1314,26 @@ fn default_log_level() -> String {
 fn default_metrics_port() -> u16 {
     9090
 }
+fn default_log_content_fields() -> Vec<String> {
+    vec![
+        "text".to_string(),
+        "transcript".to_string(),
+        "current_text".to_string(),
+        "original".to_string(),
+        "response".to_string(),
+        "answer".to_string(),
+    ]
+}
+fn default_log_redaction_entities() -> Vec<String> {
+    vec![
+        "Aadhaar".to_string(),
+        "PAN".to_string(),
+        "PhoneNumber".to_string(),
+        "Email".to_string(),
+        "BankAccount".to_string(),
+        "CardNumber".to_string(),
+    ]
+}
 
 impl Default for ObservabilityConfig {
     fn default() -> Self {
@@ -904,10 +1344,56 @@ impl Default for ObservabilityConfig {
             otlp_endpoint: None,
             metrics_enabled: true,
             metrics_port: default_metrics_port(),
+            redact_logs: true,
+            log_content_fields: default_log_content_fields(),
+            log_redaction_entities: default_log_redaction_entities(),
+            recording: ConversationRecordingConfig::default(),
+        }
+    }
+}
+
+/// Conversation recording configuration
+///
+/// Each session's turns, intents, tool calls and stage transitions are
+/// written as JSON lines, separately from the compliance audit log.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConversationRecordingConfig {
+    /// Record sessions
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Where records go: "stdout" or a file path records are appended to
+    #[serde(default = "default_recording_sink")]
+    pub sink: String,
+
+    /// Records buffered before new ones are dropped
+    #[serde(default = "default_recording_buffer_size")]
+    pub buffer_size: usize,
+
+    /// PII entity types masked in recorded text; empty records it as spoken
+    #[serde(default = "default_log_redaction_entities")]
+    pub redaction_entities: Vec<String>,
+}
+
+impl Default for ConversationRecordingConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            sink: default_recording_sink(),
+            buffer_size: default_recording_buffer_size(),
+            redaction_entities: default_log_redaction_entities(),
         }
     }
 }
 
+fn default_recording_sink() -> String {
+    "stdout".to_string()
+}
+
+fn default_recording_buffer_size() -> usize {
+    4096
+}
+
 /// Feature flags for experimentation
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FeatureFlags {
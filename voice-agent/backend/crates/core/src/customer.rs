@@ -638,7 +638,7 @@ impl SegmentDetector {
     }
 
     /// Detect price-sensitive customer
-    fn detect_price_sensitivity(&self, text: &str) -> bool {
+    pub(crate) fn detect_price_sensitivity(&self, text: &str) -> bool {
         let price_patterns = [
             // English
             "interest rate",
@@ -686,7 +686,7 @@ impl SegmentDetector {
     ///
     /// NOTE: For domain-specific patterns (e.g., "gold safe"), load from config via
     /// `detect_trust_seeking_with_config()` and pass domain-specific patterns.
-    fn detect_trust_seeking(&self, text: &str) -> bool {
+    pub(crate) fn detect_trust_seeking(&self, text: &str) -> bool {
         let trust_patterns = [
             // Generic safety concerns (domain-agnostic)
             "is it safe",
@@ -718,7 +718,7 @@ impl SegmentDetector {
     }
 
     /// Detect first-time customer
-    fn detect_first_time(&self, text: &str) -> bool {
+    pub(crate) fn detect_first_time(&self, text: &str) -> bool {
         let first_time_patterns = [
             "first time",
             "pehli baar",
@@ -832,6 +832,19 @@ impl CompanyRelationship {
     }
 }
 
+/// 10-digit mobile number without separators, country code or trunk prefix
+///
+/// "+91 98765-43210", "098765 43210" and "9876543210" all normalize to
+/// "9876543210". Numbers of other lengths are returned as bare digits.
+pub fn normalize_phone(phone: &str) -> String {
+    let digits: String = phone.chars().filter(|c| c.is_ascii_digit()).collect();
+    match digits.len() {
+        12 if digits.starts_with("91") => digits[2..].to_string(),
+        11 if digits.starts_with('0') => digits[1..].to_string(),
+        _ => digits,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -4,6 +4,7 @@
 //! the Eighth Schedule of the Indian Constitution.
 
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 /// Supported languages (22 scheduled Indian languages + English)
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
@@ -211,6 +212,124 @@ impl std::fmt::Display for Language {
     }
 }
 
+/// Abbreviations that end in a period without ending the sentence
+///
+/// Matched case-insensitively against the word before the period. Words
+/// that commonly end sentences too ("No.", "etc.") are deliberately left out.
+#[rustfmt::skip]
+pub const DEFAULT_ABBREVIATIONS: &[&str] = &[
+    // English titles and business terms
+    "Mr", "Mrs", "Ms", "Dr", "Prof", "Sr", "Jr", "St", "Ltd", "Pvt", "Co", "Inc", "vs",
+    "approx", "e.g", "i.e", "a.m", "p.m",
+    // Indian currency and units
+    "Rs", "Re", "INR", "gm", "gms", "kg",
+    // Devanagari (Dr., Shri, Rupees)
+    "डॉ", "श्री", "रु",
+];
+
+/// Whether a period right after `before` closes an abbreviation
+///
+/// Besides `DEFAULT_ABBREVIATIONS`, a single letter is taken as an initial or
+/// the end of a dotted abbreviation ("p.a."). Digits are not: "I need 5."
+/// ends a sentence.
+pub fn ends_with_abbreviation(before: &str) -> bool {
+    let word = before
+        .rsplit(|c: char| c.is_whitespace() || matches!(c, '(' | '[' | '"' | '\'' | '“'))
+        .next()
+        .unwrap_or("");
+    let mut last = word.rsplit('.').next().unwrap_or("").chars();
+    let single_letter = matches!((last.next(), last.next()), (Some(c), None) if c.is_alphabetic());
+
+    single_letter
+        || DEFAULT_ABBREVIATIONS
+            .iter()
+            .any(|a| a.eq_ignore_ascii_case(word))
+}
+
+/// Languages to try, in order, when a language isn't supported
+///
+/// Used by translation, TTS and voice selection so an unsupported language
+/// (e.g. Maithili) downgrades to a close one (Hindi) instead of failing.
+/// English always ends every chain.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct LanguageFallbackChain {
+    chains: HashMap<Language, Vec<Language>>,
+}
+
+impl Default for LanguageFallbackChain {
+    fn default() -> Self {
+        use Language::*;
+
+        Self::empty()
+            .with_chain(Maithili, [Hindi])
+            .with_chain(Dogri, [Hindi])
+            .with_chain(Nepali, [Hindi])
+            .with_chain(Sanskrit, [Hindi])
+            .with_chain(Konkani, [Marathi, Hindi])
+            .with_chain(Bodo, [Assamese, Hindi])
+            .with_chain(Kashmiri, [Urdu, Hindi])
+            .with_chain(Sindhi, [Urdu, Hindi])
+            .with_chain(Santali, [Bengali, Hindi])
+            .with_chain(Manipuri, [Bengali, Hindi])
+            .with_chain(Assamese, [Bengali, Hindi])
+    }
+}
+
+impl LanguageFallbackChain {
+    /// No configured fallbacks; every language falls back straight to English
+    pub fn empty() -> Self {
+        Self {
+            chains: HashMap::new(),
+        }
+    }
+
+    /// Set the fallbacks tried after `language`
+    pub fn with_chain(
+        mut self,
+        language: Language,
+        fallbacks: impl IntoIterator<Item = Language>,
+    ) -> Self {
+        self.chains
+            .insert(language, fallbacks.into_iter().collect());
+        self
+    }
+
+    /// Full chain for `language`: itself, its fallbacks, then English
+    pub fn chain(&self, language: Language) -> Vec<Language> {
+        let mut chain = vec![language];
+        for &fallback in self.chains.get(&language).into_iter().flatten() {
+            if !chain.contains(&fallback) {
+                chain.push(fallback);
+            }
+        }
+        if !chain.contains(&Language::English) {
+            chain.push(Language::English);
+        }
+        chain
+    }
+
+    /// First language in the chain accepted by `is_supported`
+    ///
+    /// Falls back to English when nothing in the chain is supported.
+    pub fn resolve(&self, language: Language, is_supported: impl Fn(Language) -> bool) -> Language {
+        let resolved = self
+            .chain(language)
+            .into_iter()
+            .find(|&candidate| is_supported(candidate))
+            .unwrap_or(Language::English);
+
+        if resolved != language {
+            tracing::warn!(
+                requested = %language,
+                using = %resolved,
+                "Language not supported, falling back"
+            );
+        }
+        resolved
+    }
+}
+
 /// Script systems used by Indian languages
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum Script {
@@ -291,6 +410,40 @@ impl Script {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_fallback_chain_walks_to_supported_language() {
+        let fallbacks = LanguageFallbackChain::default();
+        let supported = [Language::English, Language::Hindi];
+
+        assert_eq!(
+            fallbacks.chain(Language::Maithili),
+            vec![Language::Maithili, Language::Hindi, Language::English]
+        );
+        assert_eq!(
+            fallbacks.resolve(Language::Maithili, |l| supported.contains(&l)),
+            Language::Hindi
+        );
+        assert_eq!(
+            fallbacks.resolve(Language::Hindi, |l| supported.contains(&l)),
+            Language::Hindi
+        );
+    }
+
+    #[test]
+    fn test_exhausted_fallback_chain_uses_english() {
+        let fallbacks =
+            LanguageFallbackChain::empty().with_chain(Language::Konkani, [Language::Marathi]);
+
+        assert_eq!(
+            fallbacks.resolve(Language::Konkani, |l| l == Language::English),
+            Language::English
+        );
+        assert_eq!(
+            fallbacks.resolve(Language::Tamil, |_| false),
+            Language::English
+        );
+    }
+
     #[test]
     fn test_language_code() {
         assert_eq!(Language::Hindi.code(), "hi");
@@ -329,6 +482,19 @@ mod tests {
         assert!(hindi_terms.contains(&'.'));
     }
 
+    #[test]
+    fn test_ends_with_abbreviation() {
+        assert!(ends_with_abbreviation("Please pay Rs"));
+        assert!(ends_with_abbreviation("Meet dr"));
+        assert!(ends_with_abbreviation("9.5% p.a"));
+        assert!(ends_with_abbreviation("A. P"));
+        assert!(ends_with_abbreviation("(e.g"));
+        assert!(ends_with_abbreviation("कृपया डॉ"));
+        assert!(!ends_with_abbreviation("I need 5"));
+        assert!(!ends_with_abbreviation("Thank you"));
+        assert!(!ends_with_abbreviation(""));
+    }
+
     #[test]
     fn test_all_languages() {
         let all = Language::all();
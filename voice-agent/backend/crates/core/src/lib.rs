@@ -35,7 +35,7 @@ pub mod financial;
 pub use audio::{AudioEncoding, AudioFrame, Channels, SampleRate};
 pub use conversation::{ConversationStage, Turn, TurnRole};
 pub use customer::{
-    CompanyRelationship, CustomerProfile, CustomerSegment, SegmentDetector,
+    normalize_phone, CompanyRelationship, CustomerProfile, CustomerSegment, SegmentDetector,
     SegmentId as CustomerSegmentId,  // Re-export for clarity
 };
 pub use error::{Error, Result};
@@ -47,7 +47,9 @@ pub use compliance::{
     Severity, SuggestedRewrite, ViolationCategory,
 };
 pub use domain_context::{Abbreviation, DomainContext};
-pub use language::{Language, Script};
+pub use language::{
+    ends_with_abbreviation, Language, LanguageFallbackChain, Script, DEFAULT_ABBREVIATIONS,
+};
 pub use llm_types::{
     FinishReason, GenerateRequest, GenerateResponse, Message, Role, StreamChunk, TokenUsage,
     ToolCall, ToolDefinition,
@@ -0,0 +1,337 @@
+//! In-conversation segment inference
+//!
+//! Refines the customer segment from what the customer says during the call,
+//! rather than relying only on the profile known up front:
+//! - Vocabulary complexity (long words, financial jargon)
+//! - Price sensitivity (rate/fee questions, comparisons)
+//! - Urgency markers
+//! - Trust concerns (skepticism, reassurance requests)
+//!
+//! Per-turn evidence is accumulated into decaying per-segment scores. A new
+//! segment is only adopted after it has led for several consecutive turns,
+//! and not again until a cooldown has passed, so a single off-hand remark
+//! does not flip the persona mid-call.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use super::signals::{BehaviorSignal, SignalDetection};
+use crate::{CustomerSegment, SegmentDetector};
+
+/// Financial terms that indicate a sophisticated speaker
+const JARGON: &[&str] = &[
+    "ltv",
+    "loan to value",
+    "loan-to-value",
+    "apr",
+    "foreclosure",
+    "prepayment",
+    "tenure",
+    "collateral",
+    "portfolio",
+    "liquidity",
+    "amortization",
+    "emi",
+    "compounding",
+    "valuation",
+];
+
+/// Segment inference tuning
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SegmentInferenceConfig {
+    /// Weight kept from previous scores each turn (0.0 - 1.0)
+    pub decay: f32,
+    /// Minimum accumulated score before a segment can be adopted
+    pub min_score: f32,
+    /// How far the leader must be ahead of the current segment's score
+    pub margin: f32,
+    /// Consecutive turns a new leader must hold before switching
+    pub sustain_turns: usize,
+    /// Turns after a switch during which no further switch happens
+    pub cooldown_turns: usize,
+}
+
+impl Default for SegmentInferenceConfig {
+    fn default() -> Self {
+        Self {
+            decay: 0.7,
+            min_score: 1.0,
+            margin: 0.3,
+            sustain_turns: 2,
+            cooldown_turns: 3,
+        }
+    }
+}
+
+/// Debounced segment inference state for one conversation
+#[derive(Debug, Clone)]
+pub struct SegmentInference {
+    /// Tuning
+    pub config: SegmentInferenceConfig,
+    /// Decaying evidence score per segment
+    scores: HashMap<CustomerSegment, f32>,
+    /// Segment currently leading but not yet adopted
+    candidate: Option<CustomerSegment>,
+    /// Consecutive turns the candidate has led
+    streak: usize,
+    /// Turns since the last switch
+    since_switch: usize,
+}
+
+impl Default for SegmentInference {
+    fn default() -> Self {
+        Self {
+            config: SegmentInferenceConfig::default(),
+            scores: HashMap::new(),
+            candidate: None,
+            streak: 0,
+            since_switch: usize::MAX,
+        }
+    }
+}
+
+impl SegmentInference {
+    /// Create with default tuning
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create with custom tuning
+    pub fn with_config(config: SegmentInferenceConfig) -> Self {
+        Self {
+            config,
+            ..Self::default()
+        }
+    }
+
+    /// Evidence for each segment from a single utterance
+    pub fn evidence(
+        detector: &SegmentDetector,
+        text: &str,
+        detection: Option<&SignalDetection>,
+    ) -> HashMap<CustomerSegment, f32> {
+        let lower = text.to_lowercase();
+        let mut evidence: HashMap<CustomerSegment, f32> = HashMap::new();
+        let mut add = |segment, weight: f32| {
+            *evidence.entry(segment).or_insert(0.0) += weight;
+        };
+
+        // Lexical cues shared with the profile-level detector
+        if detector.detect_from_text(&lower) == Some(CustomerSegment::HighValue) {
+            add(CustomerSegment::HighValue, 1.0);
+        }
+        if detector.detect_price_sensitivity(&lower) {
+            add(CustomerSegment::PriceSensitive, 0.8);
+        }
+        if detector.detect_trust_seeking(&lower) {
+            add(CustomerSegment::TrustSeeker, 0.6);
+        }
+        if detector.detect_first_time(&lower) {
+            add(CustomerSegment::FirstTime, 0.5);
+        }
+        if detector.detect_urgency(&lower) {
+            add(CustomerSegment::Professional, 0.3);
+        }
+
+        // Vocabulary complexity
+        let complexity = vocabulary_complexity(&lower);
+        if complexity >= 0.5 {
+            add(CustomerSegment::Professional, complexity);
+        }
+
+        // Behavior signals
+        if let Some(detection) = detection {
+            let signals = std::iter::once((detection.primary, detection.confidence))
+                .chain(detection.secondary.iter().copied());
+            for (signal, confidence) in signals {
+                match signal {
+                    BehaviorSignal::Comparison => {
+                        add(CustomerSegment::PriceSensitive, 0.6 * confidence)
+                    },
+                    BehaviorSignal::Skepticism | BehaviorSignal::NeedsReassurance => {
+                        add(CustomerSegment::TrustSeeker, 0.7 * confidence)
+                    },
+                    BehaviorSignal::Confusion => add(CustomerSegment::FirstTime, 0.6 * confidence),
+                    BehaviorSignal::Urgency => add(CustomerSegment::Professional, 0.4 * confidence),
+                    _ => {},
+                }
+            }
+        }
+
+        evidence
+    }
+
+    /// Record one turn of evidence
+    ///
+    /// Returns the new segment when the inferred segment has changed and the
+    /// change has been sustained long enough to act on.
+    pub fn observe(
+        &mut self,
+        current: Option<CustomerSegment>,
+        evidence: &HashMap<CustomerSegment, f32>,
+    ) -> Option<CustomerSegment> {
+        for score in self.scores.values_mut() {
+            *score *= self.config.decay;
+        }
+        for (segment, weight) in evidence {
+            *self.scores.entry(*segment).or_insert(0.0) += weight;
+        }
+        self.since_switch = self.since_switch.saturating_add(1);
+
+        let leader = self.leader().filter(|&(segment, score)| {
+            let current_score = current.map(|c| self.score(c)).unwrap_or(0.0);
+            Some(segment) != current
+                && score >= self.config.min_score
+                && score - current_score >= self.config.margin
+        });
+
+        match leader {
+            Some((segment, _)) => {
+                if self.candidate == Some(segment) {
+                    self.streak += 1;
+                } else {
+                    self.candidate = Some(segment);
+                    self.streak = 1;
+                }
+            },
+            None => {
+                self.candidate = None;
+                self.streak = 0;
+            },
+        }
+
+        if self.streak >= self.config.sustain_turns
+            && self.since_switch > self.config.cooldown_turns
+        {
+            let segment = self.candidate.take();
+            self.streak = 0;
+            self.since_switch = 0;
+            return segment;
+        }
+
+        None
+    }
+
+    /// Accumulated score for a segment
+    pub fn score(&self, segment: CustomerSegment) -> f32 {
+        self.scores.get(&segment).copied().unwrap_or(0.0)
+    }
+
+    /// Highest-scoring segment
+    pub fn leader(&self) -> Option<(CustomerSegment, f32)> {
+        self.scores
+            .iter()
+            .map(|(segment, score)| (*segment, *score))
+            .max_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal))
+    }
+
+    /// Segment waiting to be adopted, if any
+    pub fn candidate(&self) -> Option<CustomerSegment> {
+        self.candidate
+    }
+}
+
+/// Vocabulary complexity of an utterance (0.0 - 1.0)
+///
+/// Combines the share of long words with financial jargon.
+fn vocabulary_complexity(lower: &str) -> f32 {
+    let words: Vec<&str> = lower
+        .split(|c: char| !c.is_alphanumeric() && c != '-')
+        .filter(|w| !w.is_empty())
+        .collect();
+    if words.is_empty() {
+        return 0.0;
+    }
+
+    let long_words = words.iter().filter(|w| w.chars().count() >= 9).count();
+    let long_share = long_words as f32 / words.len() as f32;
+    let jargon = JARGON.iter().filter(|term| lower.contains(*term)).count();
+
+    (long_share * 2.0 + jargon as f32 * 0.3).min(1.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn price(weight: f32) -> HashMap<CustomerSegment, f32> {
+        HashMap::from([(CustomerSegment::PriceSensitive, weight)])
+    }
+
+    #[test]
+    fn test_single_turn_does_not_switch() {
+        let mut inference = SegmentInference::new();
+        assert_eq!(
+            inference.observe(Some(CustomerSegment::FirstTime), &price(1.2)),
+            None
+        );
+        assert_eq!(inference.candidate(), Some(CustomerSegment::PriceSensitive));
+
+        // Evidence stops; the candidate is dropped once its lead fades
+        for _ in 0..5 {
+            assert_eq!(
+                inference.observe(Some(CustomerSegment::FirstTime), &HashMap::new()),
+                None
+            );
+        }
+        assert_eq!(inference.candidate(), None);
+    }
+
+    #[test]
+    fn test_sustained_shift_switches_once() {
+        let mut inference = SegmentInference::new();
+        let current = Some(CustomerSegment::FirstTime);
+
+        assert_eq!(inference.observe(current, &price(1.2)), None);
+        assert_eq!(
+            inference.observe(current, &price(1.2)),
+            Some(CustomerSegment::PriceSensitive)
+        );
+
+        // Already adopted: further evidence keeps it without re-triggering
+        let current = Some(CustomerSegment::PriceSensitive);
+        assert_eq!(inference.observe(current, &price(1.2)), None);
+    }
+
+    #[test]
+    fn test_cooldown_blocks_flapping() {
+        let mut inference = SegmentInference::new();
+        let trust = HashMap::from([(CustomerSegment::TrustSeeker, 3.0)]);
+
+        inference.observe(None, &price(1.2));
+        assert_eq!(
+            inference.observe(None, &price(1.2)),
+            Some(CustomerSegment::PriceSensitive)
+        );
+
+        let current = Some(CustomerSegment::PriceSensitive);
+        assert_eq!(inference.observe(current, &trust), None);
+        assert_eq!(inference.observe(current, &trust), None);
+        assert_eq!(inference.observe(current, &trust), None);
+        assert_eq!(
+            inference.observe(current, &trust),
+            Some(CustomerSegment::TrustSeeker)
+        );
+    }
+
+    #[test]
+    fn test_evidence_from_text_and_signals() {
+        let detector = SegmentDetector::new();
+
+        let evidence =
+            SegmentInference::evidence(&detector, "What is the best rate you offer?", None);
+        assert!(evidence[&CustomerSegment::PriceSensitive] > 0.0);
+
+        let detection = SignalDetection::new(BehaviorSignal::Skepticism, 0.9);
+        let evidence = SegmentInference::evidence(&detector, "Hmm", Some(&detection));
+        assert!(evidence[&CustomerSegment::TrustSeeker] > 0.0);
+
+        let evidence = SegmentInference::evidence(
+            &detector,
+            "What LTV and prepayment terms apply over the tenure?",
+            None,
+        );
+        assert!(evidence[&CustomerSegment::Professional] >= 0.5);
+    }
+}
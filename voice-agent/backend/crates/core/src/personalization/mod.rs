@@ -43,6 +43,7 @@
 //! ```
 
 pub mod adaptation;
+pub mod inference;
 pub mod persona;
 pub mod signals;
 
@@ -51,12 +52,13 @@ pub use adaptation::{
     feature_ids, objection_ids, parse_segment_id, segment_to_id, FeatureId, ObjectionId,
     ObjectionResponse, ObjectionResponseConfig, SegmentAdapter, SegmentAdapterConfig,
 };
+pub use inference::{SegmentInference, SegmentInferenceConfig};
 pub use persona::{LanguageComplexity, Persona, PersonaTemplates, ResponseUrgency, Tone};
 pub use signals::{
     BehaviorSignal, SignalDetection, SignalDetector, SignalDetectorConfig, TrendAnalysis,
 };
 
-use crate::{CustomerProfile, CustomerSegment};
+use crate::{CustomerProfile, CustomerSegment, SegmentDetector};
 use serde::{Deserialize, Serialize};
 
 /// Personalization context for a conversation
@@ -80,6 +82,12 @@ pub struct PersonalizationContext {
     pub customer_name: Option<String>,
     /// Preferred language
     pub preferred_language: String,
+    /// Existing customer, greeted with "welcome back"
+    #[serde(default)]
+    pub returning_customer: bool,
+    /// In-conversation segment inference state
+    #[serde(skip)]
+    pub segment_inference: SegmentInference,
 }
 
 impl PersonalizationContext {
@@ -109,6 +117,11 @@ impl PersonalizationContext {
             current_objection_id: None,
             customer_name: profile.name.clone(),
             preferred_language: profile.preferred_language.clone(),
+            returning_customer: profile
+                .relationship_with_company
+                .as_ref()
+                .is_some_and(|r| r.is_customer),
+            segment_inference: SegmentInference::new(),
         }
     }
 
@@ -124,6 +137,8 @@ impl PersonalizationContext {
             current_objection_id: None,
             customer_name: None,
             preferred_language: "en".to_string(),
+            returning_customer: false,
+            segment_inference: SegmentInference::new(),
         }
     }
 
@@ -212,6 +227,8 @@ pub struct PersonalizationEngine {
     signal_detector: SignalDetector,
     /// Segment adapter (config-driven)
     segment_adapter: SegmentAdapter,
+    /// Lexical segment cues for in-conversation inference
+    segment_detector: SegmentDetector,
     /// Enable adaptive persona
     adaptive_persona: bool,
 }
@@ -224,6 +241,7 @@ impl PersonalizationEngine {
         Self {
             signal_detector: SignalDetector::new(),
             segment_adapter: SegmentAdapter::empty(),
+            segment_detector: SegmentDetector::new(),
             adaptive_persona: true,
         }
     }
@@ -233,6 +251,7 @@ impl PersonalizationEngine {
         Self {
             signal_detector: SignalDetector::new(),
             segment_adapter: adapter,
+            segment_detector: SegmentDetector::new(),
             adaptive_persona: true,
         }
     }
@@ -249,6 +268,12 @@ impl PersonalizationEngine {
         self
     }
 
+    /// Create with custom segment detector (config-driven thresholds)
+    pub fn with_segment_detector(mut self, detector: SegmentDetector) -> Self {
+        self.segment_detector = detector;
+        self
+    }
+
     /// Enable/disable adaptive persona
     pub fn with_adaptive_persona(mut self, enabled: bool) -> Self {
         self.adaptive_persona = enabled;
@@ -410,13 +435,34 @@ impl PersonalizationEngine {
     }
 
     /// Process user input and update context
-    pub fn process_input(&self, ctx: &mut PersonalizationContext, text: &str) {
+    ///
+    /// Also refines the inferred segment from the input. Returns the new
+    /// segment when a sustained shift switched `ctx.segment` and its persona
+    /// this turn.
+    pub fn process_input(
+        &self,
+        ctx: &mut PersonalizationContext,
+        text: &str,
+    ) -> Option<CustomerSegment> {
         ctx.next_turn();
 
         // Detect signals
-        if let Some(detection) = self.detect_signal(text) {
-            ctx.update_from_detection(&detection);
+        let detection = self.detect_signal(text);
+        if let Some(ref detection) = detection {
+            ctx.update_from_detection(detection);
+        }
+
+        if !self.adaptive_persona {
+            return None;
         }
+
+        let evidence =
+            SegmentInference::evidence(&self.segment_detector, text, detection.as_ref());
+        let current = ctx.segment;
+        let segment = ctx.segment_inference.observe(current, &evidence)?;
+        ctx.segment = Some(segment);
+        ctx.persona = Persona::for_segment(segment);
+        Some(segment)
     }
 
     /// Get segment adapter
@@ -533,6 +579,25 @@ mod tests {
         assert!(!ctx.signals.is_empty());
     }
 
+    #[test]
+    fn test_sustained_signals_shift_segment_and_persona() {
+        let engine = PersonalizationEngine::new();
+        let mut ctx = PersonalizationContext::new().with_segment(CustomerSegment::FirstTime);
+        let first_time_tone = ctx.persona.tone;
+
+        let utterance = "Is it safe? Are you sure my gold is secure?";
+        assert_eq!(engine.process_input(&mut ctx, utterance), None);
+        assert_eq!(ctx.segment, Some(CustomerSegment::FirstTime));
+
+        assert_eq!(
+            engine.process_input(&mut ctx, utterance),
+            Some(CustomerSegment::TrustSeeker)
+        );
+        assert_eq!(ctx.segment, Some(CustomerSegment::TrustSeeker));
+        assert_eq!(ctx.persona.name, "trust_builder");
+        assert_ne!(ctx.persona.tone, first_time_tone);
+    }
+
     #[test]
     fn test_trend_analysis() {
         let engine = PersonalizationEngine::new();
@@ -193,6 +193,16 @@ pub struct Persona {
     pub use_hinglish: bool,
     /// Maximum response length preference (words)
     pub max_response_words: usize,
+    /// TTS speaking rate multiplier (1.0 = normal, lower = slower)
+    #[serde(default = "default_voice_scale")]
+    pub speaking_rate: f32,
+    /// TTS pitch multiplier (1.0 = normal, lower = deeper/warmer)
+    #[serde(default = "default_voice_scale")]
+    pub pitch: f32,
+}
+
+fn default_voice_scale() -> f32 {
+    1.0
 }
 
 impl Default for Persona {
@@ -208,6 +218,8 @@ impl Default for Persona {
             acknowledge_emotions: true,
             use_hinglish: false,
             max_response_words: 60,
+            speaking_rate: 1.0,
+            pitch: 1.0,
         }
     }
 }
@@ -244,6 +256,8 @@ impl Persona {
             acknowledge_emotions: config.acknowledge_emotions,
             use_hinglish: config.use_hinglish,
             max_response_words: config.max_response_words,
+            speaking_rate: config.speaking_rate,
+            pitch: config.pitch,
         }
     }
 
@@ -260,6 +274,8 @@ impl Persona {
             acknowledge_emotions: self.acknowledge_emotions,
             use_hinglish: self.use_hinglish,
             max_response_words: self.max_response_words,
+            speaking_rate: self.speaking_rate,
+            pitch: self.pitch,
         }
     }
 
@@ -292,6 +308,8 @@ impl Persona {
                 acknowledge_emotions: true,
                 use_hinglish: false,
                 max_response_words: 80,
+                speaking_rate: 0.95,
+                pitch: 1.0,
             },
             CustomerSegment::TrustSeeker => Self {
                 name: "trust_builder".to_string(),
@@ -304,6 +322,8 @@ impl Persona {
                 acknowledge_emotions: true,
                 use_hinglish: true,
                 max_response_words: 70,
+                speaking_rate: 0.9,
+                pitch: 0.95,
             },
             CustomerSegment::FirstTime => Self {
                 name: "helpful_guide".to_string(),
@@ -316,6 +336,8 @@ impl Persona {
                 acknowledge_emotions: true,
                 use_hinglish: true,
                 max_response_words: 50,
+                speaking_rate: 0.92,
+                pitch: 0.98,
             },
             CustomerSegment::PriceSensitive => Self {
                 name: "value_expert".to_string(),
@@ -328,6 +350,8 @@ impl Persona {
                 acknowledge_emotions: false,
                 use_hinglish: false,
                 max_response_words: 55,
+                speaking_rate: 1.0,
+                pitch: 1.0,
             },
             CustomerSegment::Women => Self {
                 name: "shakti_advisor".to_string(),
@@ -340,6 +364,8 @@ impl Persona {
                 acknowledge_emotions: true,
                 use_hinglish: true,
                 max_response_words: 55,
+                speaking_rate: 0.95,
+                pitch: 0.98,
             },
             CustomerSegment::Professional => Self {
                 name: "smart_advisor".to_string(),
@@ -352,6 +378,8 @@ impl Persona {
                 acknowledge_emotions: false,
                 use_hinglish: false,
                 max_response_words: 45,
+                speaking_rate: 1.05,
+                pitch: 1.0,
             },
         }
     }
@@ -533,6 +561,8 @@ impl Persona {
             max_response_words: ((self.max_response_words as f32) * inv
                 + (other.max_response_words as f32) * factor)
                 as usize,
+            speaking_rate: self.speaking_rate * inv + other.speaking_rate * factor,
+            pitch: self.pitch * inv + other.pitch * factor,
         }
     }
 }
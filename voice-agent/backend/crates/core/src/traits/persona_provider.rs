@@ -56,6 +56,10 @@ pub struct PersonaConfig {
     pub use_hinglish: bool,
     /// Maximum response length preference (words)
     pub max_response_words: usize,
+    /// TTS speaking rate multiplier (1.0 = normal, lower = slower)
+    pub speaking_rate: f32,
+    /// TTS pitch multiplier (1.0 = normal, lower = deeper/warmer)
+    pub pitch: f32,
 }
 
 impl Default for PersonaConfig {
@@ -71,6 +75,8 @@ impl Default for PersonaConfig {
             acknowledge_emotions: true,
             use_hinglish: false,
             max_response_words: 60,
+            speaking_rate: 1.0,
+            pitch: 1.0,
         }
     }
 }
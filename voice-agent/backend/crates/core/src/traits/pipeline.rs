@@ -146,6 +146,8 @@ pub enum ControlFrame {
     Configure(HashMap<String, serde_json::Value>),
     /// Request metrics
     GetMetrics,
+    /// Start (`true`) or end (`false`) an utterance that barge-in must not cut off
+    ProtectUtterance(bool),
 }
 
 impl Frame {
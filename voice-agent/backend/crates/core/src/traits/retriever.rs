@@ -86,6 +86,14 @@ pub struct RetrieveOptions {
     /// Reranking model to use (if different from default)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub rerank_model: Option<String>,
+    /// Minimum number of results to return before falling back to documents
+    /// in other languages when a language filter is set (None = strict filtering)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub language_fallback_min: Option<usize>,
+    /// Attach a per-document score breakdown under the `explain` metadata key
+    /// (for relevance debugging; slower, keep disabled in production)
+    #[serde(default)]
+    pub explain: bool,
 }
 
 fn default_true() -> bool {
@@ -101,6 +109,8 @@ impl Default for RetrieveOptions {
             include_metadata: true,
             rerank: true,
             rerank_model: None,
+            language_fallback_min: None,
+            explain: false,
         }
     }
 }
@@ -129,8 +139,86 @@ impl RetrieveOptions {
         self.rerank = false;
         self
     }
+
+    /// Enable score explanations
+    pub fn with_explain(mut self) -> Self {
+        self.explain = true;
+        self
+    }
+
+    /// Prefer documents in the given language (adds a `language` metadata filter)
+    pub fn with_language(mut self, language: impl Into<String>) -> Self {
+        let language: String = language.into();
+        self.filters
+            .retain(|f| f.field != LANGUAGE_FIELD || !matches!(f.op, FilterOp::Equals));
+        self.filters
+            .push(MetadataFilter::eq(LANGUAGE_FIELD, language));
+        self
+    }
+
+    /// Fall back to other languages when fewer than `min_results` documents
+    /// match the language filter
+    pub fn with_language_fallback(mut self, min_results: usize) -> Self {
+        self.language_fallback_min = Some(min_results);
+        self
+    }
+
+    /// Language requested via a `language` equality filter, if any
+    pub fn language(&self) -> Option<&str> {
+        self.filters
+            .iter()
+            .find(|f| f.field == LANGUAGE_FIELD && matches!(f.op, FilterOp::Equals))
+            .and_then(|f| f.value.as_str())
+    }
+
+    /// Apply metadata filters to already-ranked documents
+    ///
+    /// Documents must match every filter. If a language filter is set and
+    /// `language_fallback_min` is configured, documents in other languages
+    /// (that still match the remaining filters) are appended in rank order
+    /// until the minimum is reached. Ranking order is otherwise preserved.
+    pub fn apply_filters(&self, documents: Vec<Document>) -> Vec<Document> {
+        if self.filters.is_empty() {
+            return documents;
+        }
+
+        let (matching, rest): (Vec<Document>, Vec<Document>) = documents
+            .into_iter()
+            .partition(|doc| self.filters.iter().all(|f| f.matches(&doc.metadata)));
+
+        let min_results = match (self.language(), self.language_fallback_min) {
+            (Some(_), Some(min)) if matching.len() < min => min,
+            _ => return matching,
+        };
+
+        let needed = min_results - matching.len();
+        let fallback: Vec<Document> = rest
+            .into_iter()
+            .filter(|doc| {
+                self.filters
+                    .iter()
+                    .filter(|f| f.field != LANGUAGE_FIELD)
+                    .all(|f| f.matches(&doc.metadata))
+            })
+            .take(needed)
+            .collect();
+
+        if !fallback.is_empty() {
+            tracing::debug!(
+                language = self.language().unwrap_or_default(),
+                matched = matching.len(),
+                fallback = fallback.len(),
+                "Language filter returned too few results, falling back to other languages"
+            );
+        }
+
+        matching.into_iter().chain(fallback).collect()
+    }
 }
 
+/// Metadata key holding a document's language code
+const LANGUAGE_FIELD: &str = "language";
+
 /// Metadata filter for retrieval
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MetadataFilter {
@@ -160,6 +248,40 @@ impl MetadataFilter {
             value: serde_json::Value::String(value.into()),
         }
     }
+
+    /// Check whether document metadata satisfies this filter
+    ///
+    /// Missing fields never match, except for `NotEquals`. String comparisons
+    /// are case-insensitive so "HI" and "hi" are treated as the same language.
+    pub fn matches(&self, metadata: &std::collections::HashMap<String, serde_json::Value>) -> bool {
+        let Some(actual) = metadata.get(&self.field) else {
+            return matches!(self.op, FilterOp::NotEquals);
+        };
+
+        match self.op {
+            FilterOp::Equals => values_equal(actual, &self.value),
+            FilterOp::NotEquals => !values_equal(actual, &self.value),
+            FilterOp::Contains => match (actual.as_str(), self.value.as_str()) {
+                (Some(a), Some(v)) => a.to_lowercase().contains(&v.to_lowercase()),
+                _ => false,
+            },
+            FilterOp::GreaterThan => match (actual.as_f64(), self.value.as_f64()) {
+                (Some(a), Some(v)) => a > v,
+                _ => false,
+            },
+            FilterOp::LessThan => match (actual.as_f64(), self.value.as_f64()) {
+                (Some(a), Some(v)) => a < v,
+                _ => false,
+            },
+        }
+    }
+}
+
+fn values_equal(a: &serde_json::Value, b: &serde_json::Value) -> bool {
+    match (a.as_str(), b.as_str()) {
+        (Some(a), Some(b)) => a.eq_ignore_ascii_case(b),
+        _ => a == b,
+    }
 }
 
 /// Filter operations
@@ -282,6 +404,69 @@ mod tests {
         assert_eq!(options.min_score, 0.5);
         assert_eq!(options.filters.len(), 1);
         assert!(!options.rerank);
+        assert!(!options.explain);
+        assert!(RetrieveOptions::default().with_explain().explain);
+    }
+
+    #[test]
+    fn test_metadata_filter_matches() {
+        let doc = Document::new("doc-1", "content", 0.9)
+            .with_metadata("language", "hi")
+            .with_metadata("priority", 3);
+
+        assert!(MetadataFilter::eq("language", "HI").matches(&doc.metadata));
+        assert!(!MetadataFilter::eq("language", "en").matches(&doc.metadata));
+        assert!(!MetadataFilter::eq("category", "faq").matches(&doc.metadata));
+        assert!(MetadataFilter {
+            field: "priority".to_string(),
+            op: FilterOp::GreaterThan,
+            value: 2.into(),
+        }
+        .matches(&doc.metadata));
+    }
+
+    fn mixed_language_docs() -> Vec<Document> {
+        vec![
+            Document::new("en-1", "Interest rates", 0.9).with_metadata("language", "en"),
+            Document::new("hi-1", "Byaj dar", 0.8).with_metadata("language", "hi"),
+            Document::new("en-2", "Eligibility", 0.7).with_metadata("language", "en"),
+            Document::new("en-3", "Documents", 0.6).with_metadata("language", "en"),
+        ]
+    }
+
+    #[test]
+    fn test_language_filter_strict() {
+        let options = RetrieveOptions::default().with_language("hi");
+        assert_eq!(options.language(), Some("hi"));
+
+        let filtered = options.apply_filters(mixed_language_docs());
+        assert_eq!(filtered.len(), 1);
+        assert_eq!(filtered[0].id, "hi-1");
+    }
+
+    #[test]
+    fn test_language_filter_fallback() {
+        let options = RetrieveOptions::default()
+            .with_language("hi")
+            .with_language_fallback(3);
+
+        let filtered = options.apply_filters(mixed_language_docs());
+        let ids: Vec<&str> = filtered.iter().map(|d| d.id.as_str()).collect();
+        // Matching language first, then other languages in rank order
+        assert_eq!(ids, vec!["hi-1", "en-1", "en-2"]);
+    }
+
+    #[test]
+    fn test_language_fallback_not_needed() {
+        let options = RetrieveOptions::default()
+            .with_language("en")
+            .with_language_fallback(2);
+
+        let filtered = options.apply_filters(mixed_language_docs());
+        assert_eq!(filtered.len(), 3);
+        assert!(filtered
+            .iter()
+            .all(|d| d.metadata.get("language") == Some(&"en".into())));
     }
 
     #[test]
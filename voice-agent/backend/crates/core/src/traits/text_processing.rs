@@ -1,7 +1,8 @@
 //! Text processing traits
 
 use crate::{
-    ComplianceResult, DomainContext, Language, PIIEntity, PIIType, RedactionStrategy, Result,
+    ComplianceResult, DomainContext, Language, LanguageFallbackChain, PIIEntity, PIIType,
+    RedactionStrategy, Result,
 };
 use async_trait::async_trait;
 use futures::Stream;
@@ -120,6 +121,17 @@ pub trait Translator: Send + Sync + 'static {
     /// true if translation between these languages is supported
     fn supports_pair(&self, from: Language, to: Language) -> bool;
 
+    /// Language to converse in for `language`, walking the fallback chain
+    ///
+    /// A language is usable when it translates both to and from English.
+    fn resolve_language(&self, language: Language, fallbacks: &LanguageFallbackChain) -> Language {
+        fallbacks.resolve(language, |l| {
+            l == Language::English
+                || (self.supports_pair(l, Language::English)
+                    && self.supports_pair(Language::English, l))
+        })
+    }
+
     /// Get translator name for logging
     fn name(&self) -> &str;
 }
@@ -14,7 +14,16 @@ use voice_agent_core::{
     Error, GenerateRequest, GenerateResponse, LanguageModel, Result, StreamChunk, ToolDefinition,
 };
 
-use crate::backend::{FinishReason as BackendFinishReason, LlmBackend};
+use crate::backend::{FinishReason as BackendFinishReason, GenerationResult, LlmBackend};
+use crate::prompt::{coerce_tool_call, try_parse_tool_call, ParsedToolCall, ToolCallParseError};
+use crate::tool_format::ToolCallFormat;
+use crate::LlmError;
+
+/// A generation plus the tool calls parsed out of it
+type ToolGeneration = (
+    GenerationResult,
+    Vec<std::result::Result<ParsedToolCall, ToolCallParseError>>,
+);
 
 /// Adapter that wraps an LlmBackend to implement the core LanguageModel trait.
 ///
@@ -85,6 +94,37 @@ impl LanguageModelAdapter {
             BackendFinishReason::Cancelled => CoreFinishReason::Error,
         }
     }
+
+    /// Tool calling for backends without a native tools field: inject the
+    /// definitions into the system prompt and parse `[TOOL_CALL: ...]` back out
+    async fn generate_with_injected_tools(
+        &self,
+        request: &GenerateRequest,
+        tools: &[ToolDefinition],
+    ) -> std::result::Result<ToolGeneration, LlmError> {
+        let mut messages = Self::convert_messages(request);
+
+        let tool_prompt = crate::prompt::PromptBuilder::new()
+            .with_tools(tools)
+            .build();
+
+        // Prepend tool definitions to messages
+        if let Some(tool_msg) = tool_prompt.first() {
+            messages.insert(
+                0,
+                crate::prompt::Message {
+                    role: crate::prompt::Role::System,
+                    content: tool_msg.content.clone(),
+                    name: None,
+                    tool_call_id: None,
+                },
+            );
+        }
+
+        let result = self.backend.generate(&messages).await?;
+        let parsed = try_parse_tool_call(&result.text);
+        Ok((result, vec![parsed]))
+    }
 }
 
 #[async_trait]
@@ -154,76 +194,66 @@ impl LanguageModel for LanguageModelAdapter {
         request: GenerateRequest,
         tools: &[ToolDefinition],
     ) -> Result<GenerateResponse> {
-        // P0-3: Implement tool calling for Ollama via text injection
-        // For Ollama, we inject tool definitions into the prompt and parse [TOOL_CALL: ...]
-        // Claude backend has native tool support via ClaudeBackend::generate_with_tools
-
         if tools.is_empty() {
             return self.generate(request).await;
         }
 
-        // Build prompt with tool definitions injected
-        let mut messages = Self::convert_messages(&request);
-
-        // Add tool definitions to system prompt
-        let tool_prompt = crate::prompt::PromptBuilder::new()
-            .with_tools(tools)
-            .build();
-
-        // Prepend tool definitions to messages
-        if let Some(tool_msg) = tool_prompt.first() {
-            messages.insert(
-                0,
-                crate::prompt::Message {
-                    role: crate::prompt::Role::System,
-                    content: tool_msg.content.clone(),
-                    name: None,
-                    tool_call_id: None,
-                },
-            );
-        }
-
         let model = self.model_name.clone();
         let tool_count = tools.len();
 
-        self.backend
-            .generate(&messages)
-            .await
-            .map(|result| {
-                // Parse tool calls from response text
-                let tool_calls: Vec<voice_agent_core::llm_types::ToolCall> =
-                    crate::prompt::parse_tool_call(&result.text)
-                        .map(|tc| voice_agent_core::llm_types::ToolCall {
-                            id: uuid::Uuid::new_v4().to_string(),
-                            name: tc.name,
-                            arguments: tc
-                                .arguments
-                                .as_object()
-                                .map(|o| o.iter().map(|(k, v)| (k.clone(), v.clone())).collect())
-                                .unwrap_or_default(),
-                        })
-                        .into_iter()
-                        .collect();
-
-                let finish_reason = if !tool_calls.is_empty() {
-                    CoreFinishReason::ToolCalls
-                } else {
-                    Self::convert_finish_reason(result.finish_reason)
-                };
-
-                GenerateResponse {
-                    text: result.text,
-                    finish_reason,
-                    usage: Some(TokenUsage::new(0, result.tokens as u32)),
-                    tool_calls,
-                }
+        // Backends with native tool calling get the definitions in their own
+        // request field; the rest see them injected into the system prompt
+        let generated = if self.backend.tool_call_format() == ToolCallFormat::Text {
+            self.generate_with_injected_tools(&request, tools).await
+        } else {
+            let messages = Self::convert_messages(&request);
+            self.backend
+                .generate_with_native_tools(&messages, tools)
+                .await
+                .map(|(result, calls)| (result, calls.into_iter().map(Ok).collect()))
+        };
+
+        let (result, parsed) = generated.map_err(|e| {
+            Error::Llm(format!(
+                "generate_with_tools failed (model={}, tools={}): {}",
+                model, tool_count, e
+            ))
+        })?;
+
+        // Repairs malformed JSON and coerces arguments to the tool schema
+        let tool_calls: Vec<voice_agent_core::llm_types::ToolCall> = parsed
+            .into_iter()
+            .filter_map(|call| match call.and_then(|c| coerce_tool_call(c, tools)) {
+                Ok(tc) => Some(tc),
+                Err(ToolCallParseError::NoToolCall) => None,
+                Err(e) => {
+                    tracing::warn!(model = %model, error = %e, "Failed to parse tool call");
+                    None
+                },
             })
-            .map_err(|e| {
-                Error::Llm(format!(
-                    "generate_with_tools failed (model={}, tools={}): {}",
-                    model, tool_count, e
-                ))
+            .map(|tc| voice_agent_core::llm_types::ToolCall {
+                id: tc.id.unwrap_or_else(|| uuid::Uuid::new_v4().to_string()),
+                name: tc.name,
+                arguments: tc
+                    .arguments
+                    .as_object()
+                    .map(|o| o.iter().map(|(k, v)| (k.clone(), v.clone())).collect())
+                    .unwrap_or_default(),
             })
+            .collect();
+
+        let finish_reason = if !tool_calls.is_empty() {
+            CoreFinishReason::ToolCalls
+        } else {
+            Self::convert_finish_reason(result.finish_reason)
+        };
+
+        Ok(GenerateResponse {
+            text: result.text,
+            finish_reason,
+            usage: Some(TokenUsage::new(0, result.tokens as u32)),
+            tool_calls,
+        })
     }
 
     async fn is_available(&self) -> bool {
@@ -251,12 +281,22 @@ mod tests {
     // Mock backend for testing
     struct MockBackend {
         response: String,
+        /// Tool calls returned natively; `None` means text-only tool calling
+        native_calls: Option<Vec<ParsedToolCall>>,
     }
 
     impl MockBackend {
         fn new(response: &str) -> Self {
             Self {
                 response: response.to_string(),
+                native_calls: None,
+            }
+        }
+
+        fn native(response: &str, calls: Vec<ParsedToolCall>) -> Self {
+            Self {
+                response: response.to_string(),
+                native_calls: Some(calls),
             }
         }
     }
@@ -305,6 +345,30 @@ mod tests {
         fn model_name(&self) -> &str {
             "mock-model"
         }
+
+        fn tool_call_format(&self) -> ToolCallFormat {
+            match self.native_calls {
+                Some(_) => ToolCallFormat::OpenAI,
+                None => ToolCallFormat::Text,
+            }
+        }
+
+        async fn generate_with_native_tools(
+            &self,
+            messages: &[crate::prompt::Message],
+            _tools: &[ToolDefinition],
+        ) -> std::result::Result<(GenerationResult, Vec<ParsedToolCall>), crate::LlmError> {
+            // Tools travel in the request, never in the prompt
+            assert!(messages.iter().all(|m| !m.content.contains("TOOL_CALL")));
+            let result = self.generate(messages).await?;
+            Ok((result, self.native_calls.clone().unwrap_or_default()))
+        }
+    }
+
+    fn quote_tool() -> ToolDefinition {
+        crate::prompt::ToolBuilder::new("get_quote", "Get a loan quote")
+            .param("quantity", "number", "Quantity value", true)
+            .build()
     }
 
     #[tokio::test]
@@ -332,4 +396,59 @@ mod tests {
         let adapter = LanguageModelAdapter::new(backend);
         assert_eq!(adapter.model_name(), "mock-model");
     }
+
+    #[tokio::test]
+    async fn test_adapter_parses_text_tool_calls() {
+        let backend = MockBackend::new(
+            r#"[TOOL_CALL: {"name": "get_quote", "arguments": {"quantity": "25"}}]"#,
+        );
+        let adapter = LanguageModelAdapter::new(backend);
+        let request = GenerateRequest::new("You are helpful").with_user_message("Quote 25");
+
+        let tools = [quote_tool()];
+        let response = adapter.generate_with_tools(request, &tools).await.unwrap();
+        assert_eq!(response.finish_reason, CoreFinishReason::ToolCalls);
+        assert_eq!(response.tool_calls[0].name, "get_quote");
+        assert_eq!(response.tool_calls[0].arguments["quantity"], 25.0);
+    }
+
+    #[tokio::test]
+    async fn test_adapter_uses_native_tool_calls() {
+        let call = ParsedToolCall {
+            id: Some("call_1".to_string()),
+            name: "get_quote".to_string(),
+            arguments: serde_json::json!({"quantity": "25"}),
+            text_before: String::new(),
+            text_after: String::new(),
+        };
+        let adapter = LanguageModelAdapter::new(MockBackend::native("", vec![call]));
+        let request = GenerateRequest::new("You are helpful").with_user_message("Quote 25");
+
+        let tools = [quote_tool()];
+        let response = adapter.generate_with_tools(request, &tools).await.unwrap();
+        assert_eq!(response.finish_reason, CoreFinishReason::ToolCalls);
+        // Provider call IDs are kept and arguments coerced to the schema
+        assert_eq!(response.tool_calls[0].id, "call_1");
+        assert_eq!(response.tool_calls[0].arguments["quantity"], 25.0);
+    }
+
+    #[tokio::test]
+    async fn test_adapter_drops_unknown_native_tool_calls() {
+        let call = ParsedToolCall {
+            id: Some("call_1".to_string()),
+            name: "transfer_funds".to_string(),
+            arguments: serde_json::json!({}),
+            text_before: String::new(),
+            text_after: String::new(),
+        };
+        let backend = MockBackend::native("Let me check that.", vec![call]);
+        let adapter = LanguageModelAdapter::new(backend);
+        let request = GenerateRequest::new("You are helpful").with_user_message("Quote 25");
+
+        let tools = [quote_tool()];
+        let response = adapter.generate_with_tools(request, &tools).await.unwrap();
+        assert!(response.tool_calls.is_empty());
+        assert_eq!(response.finish_reason, CoreFinishReason::Stop);
+        assert_eq!(response.text, "Let me check that.");
+    }
 }
@@ -21,7 +21,8 @@ use tokio::sync::mpsc;
 // P1 FIX: Use centralized constants
 use voice_agent_config::constants::endpoints;
 
-use crate::prompt::Message;
+use crate::prompt::{Message, ParsedToolCall, ToolDefinition};
+use crate::tool_format::ToolCallFormat;
 use crate::LlmError;
 
 /// LLM configuration
@@ -119,6 +120,30 @@ pub trait LlmBackend: Send + Sync {
     /// Get model name
     fn model_name(&self) -> &str;
 
+    /// Tool-calling format the backend speaks natively
+    ///
+    /// With `Text` (the default) tools are described in the prompt and calls
+    /// are parsed from the reply text instead.
+    fn tool_call_format(&self) -> ToolCallFormat {
+        ToolCallFormat::Text
+    }
+
+    /// Generate with tools sent in the backend's native format
+    ///
+    /// Returns the reply and the tool calls the model made. Only backends
+    /// whose `tool_call_format` isn't `Text` support it.
+    async fn generate_with_native_tools(
+        &self,
+        messages: &[Message],
+        tools: &[ToolDefinition],
+    ) -> Result<(GenerationResult, Vec<ParsedToolCall>), LlmError> {
+        let _ = (messages, tools);
+        Err(LlmError::Configuration(format!(
+            "{} has no native tool calling",
+            self.model_name()
+        )))
+    }
+
     /// Estimate tokens
     ///
     /// P0 FIX: Improved token estimation for multilingual content.
@@ -235,6 +260,7 @@ impl OllamaBackend {
             keep_alive: Some(self.config.keep_alive.clone()),
             context: context.map(|c| c.to_vec()),
             think: Some(false), // Disable extended thinking for faster responses
+            tools: None,
         };
 
         // Retry loop with exponential backoff
@@ -253,25 +279,8 @@ impl OllamaBackend {
                 backoff *= 2;
             }
 
-            match self.execute_request(&request).await {
-                Ok(result) => {
-                    let total_time = start.elapsed();
-                    return Ok(GenerationResult {
-                        text: result.message.content,
-                        tokens: result.eval_count.unwrap_or(0) as usize,
-                        time_to_first_token_ms: result.prompt_eval_duration.unwrap_or(0)
-                            / 1_000_000,
-                        total_time_ms: total_time.as_millis() as u64,
-                        tokens_per_second: result.eval_count.unwrap_or(0) as f32
-                            / (result.eval_duration.unwrap_or(1) as f32 / 1e9),
-                        finish_reason: if result.done {
-                            FinishReason::Stop
-                        } else {
-                            FinishReason::Length
-                        },
-                        context: result.context, // P0 FIX: Capture context for reuse
-                    });
-                },
+            match self.execute_request::<OllamaChatResponse>(&request).await {
+                Ok(result) => return Ok(result.into_result(start.elapsed())),
                 Err(e) if Self::is_retryable(&e) => {
                     last_error = Some(e);
                 },
@@ -297,10 +306,10 @@ impl OllamaBackend {
     }
 
     /// P1 FIX: Execute a single request (used by retry logic)
-    async fn execute_request(
+    async fn execute_request<T: serde::de::DeserializeOwned>(
         &self,
         request: &OllamaChatRequest,
-    ) -> Result<OllamaChatResponse, LlmError> {
+    ) -> Result<T, LlmError> {
         let response = self
             .client
             .post(self.api_url("/chat"))
@@ -374,6 +383,7 @@ impl LlmBackend for OllamaBackend {
             keep_alive: Some(self.config.keep_alive.clone()),
             context: cached_context,
             think: Some(false), // Disable extended thinking for faster responses
+            tools: None,
         };
 
         let response = self
@@ -469,6 +479,44 @@ impl LlmBackend for OllamaBackend {
     fn model_name(&self) -> &str {
         &self.config.model
     }
+
+    fn tool_call_format(&self) -> ToolCallFormat {
+        ToolCallFormat::Ollama
+    }
+
+    /// Generate with tools in Ollama's `tools` field (stateless, no KV cache reuse)
+    async fn generate_with_native_tools(
+        &self,
+        messages: &[Message],
+        tools: &[ToolDefinition],
+    ) -> Result<(GenerationResult, Vec<ParsedToolCall>), LlmError> {
+        let start = std::time::Instant::now();
+        let format = self.tool_call_format();
+
+        let request = OllamaChatRequest {
+            model: self.config.model.clone(),
+            messages: messages.iter().map(|m| m.into()).collect(),
+            stream: false,
+            options: Some(OllamaOptions {
+                temperature: Some(self.config.temperature),
+                top_p: Some(self.config.top_p),
+                num_predict: Some(self.config.max_tokens as i32),
+            }),
+            keep_alive: Some(self.config.keep_alive.clone()),
+            context: None,
+            think: Some(false),
+            tools: Some(format.serialize_tools(tools)),
+        };
+
+        let body: serde_json::Value = self.execute_request(&request).await?;
+        let tool_calls = format
+            .parse_tool_calls(&body)
+            .map_err(|e| LlmError::InvalidResponse(e.to_string()))?;
+        let response: OllamaChatResponse =
+            serde_json::from_value(body).map_err(|e| LlmError::InvalidResponse(e.to_string()))?;
+
+        Ok((response.into_result(start.elapsed()), tool_calls))
+    }
 }
 
 // Ollama API types
@@ -488,6 +536,9 @@ struct OllamaChatRequest {
     /// Disable extended thinking for models like qwen3/deepseek-r1
     #[serde(skip_serializing_if = "Option::is_none")]
     think: Option<bool>,
+    /// Tool definitions in Ollama's native format
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tools: Option<serde_json::Value>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -530,6 +581,25 @@ struct OllamaChatResponse {
     context: Option<Vec<i64>>,
 }
 
+impl OllamaChatResponse {
+    fn into_result(self, elapsed: Duration) -> GenerationResult {
+        GenerationResult {
+            text: self.message.content,
+            tokens: self.eval_count.unwrap_or(0) as usize,
+            time_to_first_token_ms: self.prompt_eval_duration.unwrap_or(0) / 1_000_000,
+            total_time_ms: elapsed.as_millis() as u64,
+            tokens_per_second: self.eval_count.unwrap_or(0) as f32
+                / (self.eval_duration.unwrap_or(1) as f32 / 1e9),
+            finish_reason: if self.done {
+                FinishReason::Stop
+            } else {
+                FinishReason::Length
+            },
+            context: self.context, // P0 FIX: Capture context for reuse
+        }
+    }
+}
+
 #[derive(Debug, Deserialize)]
 struct OllamaStreamChunk {
     message: OllamaMessage,
@@ -703,40 +773,17 @@ impl OpenAIBackend {
 
         headers
     }
-}
-
-#[async_trait]
-impl LlmBackend for OpenAIBackend {
-    async fn generate(&self, messages: &[Message]) -> Result<GenerationResult, LlmError> {
-        let start = std::time::Instant::now();
-
-        let openai_messages: Vec<OpenAIMessage> = messages
-            .iter()
-            .map(|m| OpenAIMessage {
-                role: match m.role {
-                    crate::prompt::Role::System => "system".to_string(),
-                    crate::prompt::Role::User => "user".to_string(),
-                    crate::prompt::Role::Assistant => "assistant".to_string(),
-                    crate::prompt::Role::Tool => "tool".to_string(),
-                },
-                content: m.content.clone(),
-            })
-            .collect();
-
-        let request = OpenAIChatRequest {
-            model: self.config.model.clone(),
-            messages: openai_messages,
-            max_tokens: Some(self.config.max_tokens),
-            temperature: Some(self.config.temperature),
-            top_p: Some(self.config.top_p),
-            stream: Some(false),
-        };
 
+    /// Send a non-streaming chat completion request
+    async fn post_chat<T: serde::de::DeserializeOwned>(
+        &self,
+        request: &OpenAIChatRequest,
+    ) -> Result<T, LlmError> {
         let response = self
             .client
             .post(self.chat_url())
             .headers(self.build_headers())
-            .json(&request)
+            .json(request)
             .send()
             .await?;
 
@@ -746,10 +793,45 @@ impl LlmBackend for OpenAIBackend {
             return Err(LlmError::Api(format!("HTTP {}: {}", status, error_text)));
         }
 
-        let response: OpenAIChatResponse = response
+        response
             .json()
             .await
-            .map_err(|e| LlmError::InvalidResponse(e.to_string()))?;
+            .map_err(|e| LlmError::InvalidResponse(e.to_string()))
+    }
+}
+
+/// Messages in OpenAI's chat format
+fn openai_messages(messages: &[Message]) -> Vec<OpenAIMessage> {
+    messages
+        .iter()
+        .map(|m| OpenAIMessage {
+            role: match m.role {
+                crate::prompt::Role::System => "system".to_string(),
+                crate::prompt::Role::User => "user".to_string(),
+                crate::prompt::Role::Assistant => "assistant".to_string(),
+                crate::prompt::Role::Tool => "tool".to_string(),
+            },
+            content: m.content.clone(),
+        })
+        .collect()
+}
+
+#[async_trait]
+impl LlmBackend for OpenAIBackend {
+    async fn generate(&self, messages: &[Message]) -> Result<GenerationResult, LlmError> {
+        let start = std::time::Instant::now();
+
+        let request = OpenAIChatRequest {
+            model: self.config.model.clone(),
+            messages: openai_messages(messages),
+            max_tokens: Some(self.config.max_tokens),
+            temperature: Some(self.config.temperature),
+            top_p: Some(self.config.top_p),
+            stream: Some(false),
+            tools: None,
+        };
+
+        let response: OpenAIChatResponse = self.post_chat(&request).await?;
 
         let choice = response
             .choices
@@ -788,26 +870,14 @@ impl LlmBackend for OpenAIBackend {
         let mut full_text = String::new();
         let mut token_count = 0;
 
-        let openai_messages: Vec<OpenAIMessage> = messages
-            .iter()
-            .map(|m| OpenAIMessage {
-                role: match m.role {
-                    crate::prompt::Role::System => "system".to_string(),
-                    crate::prompt::Role::User => "user".to_string(),
-                    crate::prompt::Role::Assistant => "assistant".to_string(),
-                    crate::prompt::Role::Tool => "tool".to_string(),
-                },
-                content: m.content.clone(),
-            })
-            .collect();
-
         let request = OpenAIChatRequest {
             model: self.config.model.clone(),
-            messages: openai_messages,
+            messages: openai_messages(messages),
             max_tokens: Some(self.config.max_tokens),
             temperature: Some(self.config.temperature),
             top_p: Some(self.config.top_p),
             stream: Some(true),
+            tools: None,
         };
 
         let response = self
@@ -900,6 +970,69 @@ impl LlmBackend for OpenAIBackend {
     fn model_name(&self) -> &str {
         &self.config.model
     }
+
+    fn tool_call_format(&self) -> ToolCallFormat {
+        ToolCallFormat::OpenAI
+    }
+
+    /// Generate with tools in the request's `tools` field (function calling)
+    async fn generate_with_native_tools(
+        &self,
+        messages: &[Message],
+        tools: &[ToolDefinition],
+    ) -> Result<(GenerationResult, Vec<ParsedToolCall>), LlmError> {
+        let start = std::time::Instant::now();
+        let format = self.tool_call_format();
+
+        let request = OpenAIChatRequest {
+            model: self.config.model.clone(),
+            messages: openai_messages(messages),
+            max_tokens: Some(self.config.max_tokens),
+            temperature: Some(self.config.temperature),
+            top_p: Some(self.config.top_p),
+            stream: Some(false),
+            tools: Some(format.serialize_tools(tools)),
+        };
+
+        // Parsed loosely: `content` is null when the reply is only tool calls
+        let body: serde_json::Value = self.post_chat(&request).await?;
+        let tool_calls = format
+            .parse_tool_calls(&body)
+            .map_err(|e| LlmError::InvalidResponse(e.to_string()))?;
+
+        let text = body
+            .pointer("/choices/0/message/content")
+            .and_then(|c| c.as_str())
+            .unwrap_or_default()
+            .to_string();
+        let tokens = body
+            .pointer("/usage/completion_tokens")
+            .and_then(|t| t.as_u64())
+            .unwrap_or(0) as usize;
+        let finish_reason = match body
+            .pointer("/choices/0/finish_reason")
+            .and_then(|r| r.as_str())
+        {
+            Some("length") => FinishReason::Length,
+            _ => FinishReason::Stop,
+        };
+        let total_time_ms = start.elapsed().as_millis() as u64;
+
+        let result = GenerationResult {
+            text,
+            tokens,
+            time_to_first_token_ms: total_time_ms,
+            total_time_ms,
+            tokens_per_second: if total_time_ms > 0 {
+                tokens as f32 / (total_time_ms as f32 / 1000.0)
+            } else {
+                0.0
+            },
+            finish_reason,
+            context: None,
+        };
+        Ok((result, tool_calls))
+    }
 }
 
 // OpenAI API types
@@ -915,6 +1048,9 @@ struct OpenAIChatRequest {
     top_p: Option<f32>,
     #[serde(skip_serializing_if = "Option::is_none")]
     stream: Option<bool>,
+    /// Tool definitions in OpenAI's function-calling format
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tools: Option<serde_json::Value>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -964,7 +1100,7 @@ struct OpenAIDelta {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::prompt::Role;
+    use crate::prompt::{Role, ToolBuilder};
 
     #[test]
     fn test_config_default() {
@@ -1015,11 +1151,40 @@ mod tests {
             keep_alive: Some("5m".to_string()),
             context: Some(vec![1, 2, 3]),
             think: Some(false),
+            tools: None,
         };
 
         let json = serde_json::to_string(&request).unwrap();
         assert!(json.contains("keep_alive"));
         assert!(json.contains("context"));
+        assert!(!json.contains("tools"));
+    }
+
+    fn branch_tool() -> ToolDefinition {
+        ToolBuilder::new("find_branches", "Find nearby branches")
+            .param("city", "string", "City name", true)
+            .build()
+    }
+
+    #[test]
+    fn test_ollama_request_carries_native_tools() {
+        let backend = OllamaBackend::new(LlmConfig::default()).unwrap();
+        assert_eq!(backend.tool_call_format(), ToolCallFormat::Ollama);
+
+        let request = OllamaChatRequest {
+            model: "test".to_string(),
+            messages: vec![],
+            stream: false,
+            options: None,
+            keep_alive: None,
+            context: None,
+            think: Some(false),
+            tools: Some(backend.tool_call_format().serialize_tools(&[branch_tool()])),
+        };
+
+        let json = serde_json::to_value(&request).unwrap();
+        assert_eq!(json["tools"][0]["type"], "function");
+        assert_eq!(json["tools"][0]["function"]["name"], "find_branches");
     }
 
     // P2 FIX: OpenAI backend tests
@@ -1111,11 +1276,35 @@ mod tests {
             temperature: Some(0.7),
             top_p: Some(0.9),
             stream: Some(false),
+            tools: None,
         };
 
         let json = serde_json::to_string(&request).unwrap();
         assert!(json.contains("gpt-4"));
         assert!(json.contains("Hello"));
         assert!(json.contains("max_tokens"));
+        assert!(!json.contains("tools"));
+    }
+
+    #[test]
+    fn test_openai_request_carries_native_tools() {
+        let config = OpenAIConfig::local("http://localhost:8000", "test");
+        let backend = OpenAIBackend::new(config).unwrap();
+        assert_eq!(backend.tool_call_format(), ToolCallFormat::OpenAI);
+
+        let request = OpenAIChatRequest {
+            model: "test".to_string(),
+            messages: openai_messages(&[]),
+            max_tokens: None,
+            temperature: None,
+            top_p: None,
+            stream: Some(false),
+            tools: Some(backend.tool_call_format().serialize_tools(&[branch_tool()])),
+        };
+
+        let json = serde_json::to_value(&request).unwrap();
+        let function = &json["tools"][0]["function"];
+        assert_eq!(function["name"], "find_branches");
+        assert_eq!(function["parameters"]["required"][0], "city");
     }
 }
@@ -17,7 +17,8 @@ use std::time::Duration;
 use tokio::sync::mpsc;
 
 use crate::backend::{FinishReason, GenerationResult, LlmBackend};
-use crate::prompt::Message;
+use crate::prompt::{Message, ParsedToolCall};
+use crate::tool_format::ToolCallFormat;
 use crate::LlmError;
 use voice_agent_core::llm_types::{ToolCall, ToolDefinition};
 
@@ -160,7 +161,6 @@ impl ClaudeBackend {
         tools: &[ToolDefinition],
     ) -> Result<ClaudeResponse, LlmError> {
         let claude_messages = self.convert_messages(messages);
-        let claude_tools = self.convert_tools(tools);
 
         // Extract system message if present
         let system = messages
@@ -173,11 +173,7 @@ impl ClaudeBackend {
             max_tokens: self.config.max_tokens,
             messages: claude_messages,
             system,
-            tools: if claude_tools.is_empty() {
-                None
-            } else {
-                Some(claude_tools)
-            },
+            tools: (!tools.is_empty()).then(|| ToolCallFormat::Claude.serialize_tools(tools)),
             temperature: Some(self.config.temperature),
             top_p: self.config.top_p,
             stream: Some(false),
@@ -215,7 +211,6 @@ impl ClaudeBackend {
         tx: mpsc::Sender<String>,
     ) -> Result<ClaudeResponse, LlmError> {
         let claude_messages = self.convert_messages(messages);
-        let claude_tools = self.convert_tools(tools);
 
         let system = messages
             .iter()
@@ -227,11 +222,7 @@ impl ClaudeBackend {
             max_tokens: self.config.max_tokens,
             messages: claude_messages,
             system,
-            tools: if claude_tools.is_empty() {
-                None
-            } else {
-                Some(claude_tools)
-            },
+            tools: (!tools.is_empty()).then(|| ToolCallFormat::Claude.serialize_tools(tools)),
             temperature: Some(self.config.temperature),
             top_p: self.config.top_p,
             stream: Some(true),
@@ -367,18 +358,6 @@ impl ClaudeBackend {
             .collect()
     }
 
-    /// Convert tool definitions to Claude format
-    fn convert_tools(&self, tools: &[ToolDefinition]) -> Vec<ClaudeTool> {
-        tools
-            .iter()
-            .map(|t| ClaudeTool {
-                name: t.name.clone(),
-                description: t.description.clone(),
-                input_schema: t.parameters.clone(),
-            })
-            .collect()
-    }
-
     /// Parse Claude API response
     fn parse_response(&self, response: ClaudeApiResponse) -> ClaudeResponse {
         let mut text = String::new();
@@ -414,11 +393,32 @@ impl ClaudeBackend {
 #[async_trait]
 impl LlmBackend for ClaudeBackend {
     async fn generate(&self, messages: &[Message]) -> Result<GenerationResult, LlmError> {
+        let (result, _) = self.generate_with_native_tools(messages, &[]).await?;
+        Ok(result)
+    }
+
+    async fn generate_with_native_tools(
+        &self,
+        messages: &[Message],
+        tools: &[ToolDefinition],
+    ) -> Result<(GenerationResult, Vec<ParsedToolCall>), LlmError> {
         let start = std::time::Instant::now();
-        let response = self.generate_with_tools(messages, &[]).await?;
+        let response = self.generate_with_tools(messages, tools).await?;
         let total_time_ms = start.elapsed().as_millis() as u64;
 
-        Ok(GenerationResult {
+        let tool_calls = response
+            .tool_calls
+            .iter()
+            .map(|call| ParsedToolCall {
+                id: Some(call.id.clone()),
+                name: call.name.clone(),
+                arguments: serde_json::Value::Object(call.arguments.clone().into_iter().collect()),
+                text_before: response.text.clone(),
+                text_after: String::new(),
+            })
+            .collect();
+
+        let result = GenerationResult {
             text: response.text,
             tokens: response.output_tokens,
             time_to_first_token_ms: total_time_ms,
@@ -434,7 +434,12 @@ impl LlmBackend for ClaudeBackend {
                 ClaudeStopReason::ToolUse => FinishReason::Stop,
             },
             context: None,
-        })
+        };
+        Ok((result, tool_calls))
+    }
+
+    fn tool_call_format(&self) -> ToolCallFormat {
+        ToolCallFormat::Claude
     }
 
     async fn generate_stream(
@@ -509,7 +514,7 @@ struct ClaudeRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
     system: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    tools: Option<Vec<ClaudeTool>>,
+    tools: Option<serde_json::Value>,
     #[serde(skip_serializing_if = "Option::is_none")]
     temperature: Option<f32>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -544,13 +549,6 @@ enum ClaudeContentBlock {
     },
 }
 
-#[derive(Debug, Serialize)]
-struct ClaudeTool {
-    name: String,
-    description: String,
-    input_schema: serde_json::Value,
-}
-
 #[derive(Debug, Deserialize)]
 struct ClaudeApiResponse {
     content: Vec<ClaudeContentBlock>,
@@ -692,6 +690,12 @@ mod tests {
         let props = tool.parameters.get("properties").unwrap();
         assert!(props.get("quantity").is_some());
         assert!(props.get("quality_tier").is_some());
+
+        // Tools go out as Claude's native `{name, description, input_schema}`
+        let tools = ToolCallFormat::Claude.serialize_tools(&[tool.clone()]);
+        assert_eq!(tools[0]["name"], "check_eligibility");
+        assert_eq!(tools[0]["input_schema"]["properties"], *props);
+        assert!(tools[0].get("function").is_none());
     }
 
     #[test]
@@ -0,0 +1,297 @@
+//! Prompt Context Assembly
+//!
+//! System prompt, domain context, memory, RAG results and history together
+//! can exceed the model's window, which otherwise only surfaces as
+//! `LlmError::ContextTooLong` from the backend. `PromptBuilder` tags every
+//! message with the section it came from, and `fit_to_window` trims the
+//! assembled prompt before it is sent, one section at a time in the given
+//! order (lowest priority first):
+//!
+//! - `History` drops the oldest messages
+//! - `Memory`, `Rag` and `Domain` drop their last bullet lines (RAG results
+//!   are ranked, so the weakest go first), then the whole message
+//!
+//! The system prompt, instructions and the current user message are never
+//! trimmed.
+
+use crate::prompt::{Message, PromptBuilder};
+
+/// Where a prompt message came from, for overflow trimming
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ContextSection {
+    /// Persona and system prompt
+    System,
+    /// Guidance, guards, tool definitions and results
+    Instruction,
+    /// Domain context such as personalization and objection guidance
+    Domain,
+    /// Memory context and remembered customer facts
+    Memory,
+    /// Retrieved knowledge base results
+    Rag,
+    /// Earlier conversation turns
+    History,
+    /// The current user message
+    User,
+}
+
+impl ContextSection {
+    /// Whether overflow trimming may drop this section's content
+    pub fn is_trimmable(&self) -> bool {
+        matches!(
+            self,
+            ContextSection::Domain
+                | ContextSection::Memory
+                | ContextSection::Rag
+                | ContextSection::History
+        )
+    }
+
+    /// Section name for logs and metrics
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ContextSection::System => "system",
+            ContextSection::Instruction => "instruction",
+            ContextSection::Domain => "domain",
+            ContextSection::Memory => "memory",
+            ContextSection::Rag => "rag",
+            ContextSection::History => "history",
+            ContextSection::User => "user",
+        }
+    }
+}
+
+/// Content dropped from one section
+#[derive(Debug, Clone, PartialEq)]
+pub struct DroppedContext {
+    pub section: ContextSection,
+    /// Messages or bullet lines dropped
+    pub items: usize,
+    /// Estimated tokens freed
+    pub tokens: usize,
+}
+
+/// What `fit_to_window` dropped to fit the prompt
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ContextTrim {
+    /// Estimated prompt tokens before trimming
+    pub tokens_before: usize,
+    /// Estimated prompt tokens after trimming
+    pub tokens_after: usize,
+    /// Dropped content, in trim order
+    pub dropped: Vec<DroppedContext>,
+}
+
+impl ContextTrim {
+    /// Whether anything was dropped
+    pub fn is_trimmed(&self) -> bool {
+        !self.dropped.is_empty()
+    }
+}
+
+impl PromptBuilder {
+    /// Trim the prompt to `max_tokens`, dropping sections in `trim_order`
+    ///
+    /// Sections that are not trimmable are skipped. The prompt can still be
+    /// over the limit if trimming everything allowed was not enough; check
+    /// `ContextTrim::tokens_after`.
+    pub fn fit_to_window(
+        mut self,
+        max_tokens: usize,
+        trim_order: &[ContextSection],
+    ) -> (Self, ContextTrim) {
+        let tokens_before = self.estimate_tokens();
+        let mut trim = ContextTrim {
+            tokens_before,
+            tokens_after: tokens_before,
+            dropped: Vec::new(),
+        };
+        if tokens_before <= max_tokens {
+            return (self, trim);
+        }
+
+        let mut tokens = tokens_before;
+        for &section in trim_order.iter().filter(|s| s.is_trimmable()) {
+            let mut dropped = DroppedContext {
+                section,
+                items: 0,
+                tokens: 0,
+            };
+            while tokens > max_tokens {
+                let freed = match section {
+                    ContextSection::History => self.drop_oldest(section),
+                    _ => self.drop_last_item(section),
+                };
+                let Some(freed) = freed else {
+                    break;
+                };
+                tokens = tokens.saturating_sub(freed);
+                dropped.items += 1;
+                dropped.tokens += freed;
+            }
+            if dropped.items > 0 {
+                trim.dropped.push(dropped);
+            }
+            if tokens <= max_tokens {
+                break;
+            }
+        }
+        trim.tokens_after = tokens;
+        (self, trim)
+    }
+
+    /// Remove the oldest message of `section`, returning the tokens freed
+    fn drop_oldest(&mut self, section: ContextSection) -> Option<usize> {
+        let index = self.sections.iter().position(|s| *s == section)?;
+        Some(self.remove_message(index))
+    }
+
+    /// Remove the last bullet line of the last `section` message, or the
+    /// whole message once it has none left, returning the tokens freed
+    fn drop_last_item(&mut self, section: ContextSection) -> Option<usize> {
+        let index = self.sections.iter().rposition(|s| *s == section)?;
+        let content = &self.messages[index].content;
+        let lines: Vec<&str> = content.lines().collect();
+        let bullets: Vec<usize> = lines
+            .iter()
+            .enumerate()
+            .filter(|(_, line)| line.trim_start().starts_with("- "))
+            .map(|(i, _)| i)
+            .collect();
+        if bullets.len() < 2 {
+            return Some(self.remove_message(index));
+        }
+
+        let before = PromptBuilder::estimate_single_message_tokens(content);
+        let last = bullets[bullets.len() - 1];
+        let trimmed = lines
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| *i != last)
+            .map(|(_, line)| *line)
+            .collect::<Vec<_>>()
+            .join("\n");
+        let after = PromptBuilder::estimate_single_message_tokens(&trimmed);
+        self.messages[index].content = trimmed;
+        Some(before.saturating_sub(after))
+    }
+
+    fn remove_message(&mut self, index: usize) -> usize {
+        self.sections.remove(index);
+        let message: Message = self.messages.remove(index);
+        PromptBuilder::estimate_single_message_tokens(&message.content)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ORDER: [ContextSection; 4] = [
+        ContextSection::History,
+        ContextSection::Memory,
+        ContextSection::Rag,
+        ContextSection::Domain,
+    ];
+
+    fn filler(label: &str) -> String {
+        format!("{} {}", label, "x".repeat(200))
+    }
+
+    fn history(count: usize) -> Vec<Message> {
+        (0..count)
+            .map(|i| Message::user(filler(&format!("turn {}", i))))
+            .collect()
+    }
+
+    fn bullets(labels: &[&str]) -> String {
+        labels
+            .iter()
+            .map(|label| format!("- {}", filler(label)))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    fn prompt(history: &[Message], memory: &[&str], rag: &[&str]) -> PromptBuilder {
+        PromptBuilder::new()
+            .with_context("Answer in two sentences.")
+            .with_context_section(ContextSection::Domain, &bullets(&["warm", "brief"]))
+            .with_context_section(ContextSection::Memory, &bullets(memory))
+            .with_context_section(ContextSection::Rag, &bullets(rag))
+            .with_history(history)
+            .user_message("What is the rate?")
+    }
+
+    fn contents(builder: PromptBuilder) -> Vec<String> {
+        builder.build().into_iter().map(|m| m.content).collect()
+    }
+
+    #[test]
+    fn test_prompt_within_window_is_untouched() {
+        let builder = prompt(&history(2), &["pune"], &["rate"]);
+        let tokens = builder.estimate_tokens();
+
+        let (fitted, trim) = builder.fit_to_window(tokens, &ORDER);
+
+        assert!(!trim.is_trimmed());
+        assert_eq!(trim.tokens_after, tokens);
+        assert_eq!(fitted.estimate_tokens(), tokens);
+    }
+
+    #[test]
+    fn test_oldest_history_dropped_first() {
+        let turns = history(4);
+        let expected = prompt(&turns[2..], &["pune"], &["rate"]);
+        let budget = expected.estimate_tokens();
+
+        let (fitted, trim) = prompt(&turns, &["pune"], &["rate"]).fit_to_window(budget, &ORDER);
+
+        assert_eq!(trim.dropped.len(), 1);
+        assert_eq!(trim.dropped[0].section, ContextSection::History);
+        assert_eq!(trim.dropped[0].items, 2);
+        assert_eq!(trim.tokens_after, budget);
+        assert_eq!(contents(fitted), contents(expected));
+    }
+
+    #[test]
+    fn test_overflow_trims_sections_in_priority_order() {
+        // History and memory go entirely, then the weakest RAG result;
+        // domain context is never reached
+        let expected = prompt(&[], &[], &["gold rate", "ltv"]);
+        let budget = expected.estimate_tokens();
+
+        let builder = prompt(&history(2), &["pune", "50g"], &["gold rate", "ltv", "fees"]);
+        let before = builder.estimate_tokens();
+        let (fitted, trim) = builder.fit_to_window(budget, &ORDER);
+
+        let sections: Vec<ContextSection> = trim.dropped.iter().map(|d| d.section).collect();
+        assert_eq!(
+            sections,
+            vec![
+                ContextSection::History,
+                ContextSection::Memory,
+                ContextSection::Rag
+            ]
+        );
+        assert_eq!(trim.dropped[2].items, 1);
+        assert_eq!(trim.tokens_before, before);
+        assert!(trim.tokens_after <= budget);
+        let fitted = contents(fitted);
+        assert_eq!(fitted, contents(expected));
+        assert!(!fitted.iter().any(|c| c.contains("fees")));
+        assert!(fitted
+            .iter()
+            .any(|c| c.contains("- warm") && c.contains("- brief")));
+    }
+
+    #[test]
+    fn test_instructions_and_user_message_never_trimmed() {
+        let (fitted, trim) = prompt(&history(2), &["pune"], &["rate"]).fit_to_window(1, &ORDER);
+
+        assert!(trim.tokens_after > 1);
+        let fitted = contents(fitted);
+        assert_eq!(fitted.len(), 2);
+        assert!(fitted[0].contains("Answer in two sentences."));
+        assert_eq!(fitted[1], "What is the rate?");
+    }
+}
@@ -0,0 +1,420 @@
+//! Token and Cost Accounting
+//!
+//! `CostTracker` accumulates token usage for one session and prices it with a
+//! configurable per-model `PricingTable`. Usage is attributed to the main LLM
+//! or to the speculative SLM so the two can be compared.
+//!
+//! `CostTrackingModel` wraps any `LanguageModel` and records usage for every
+//! `generate`, `generate_with_tools` and `generate_stream` call. Streams do
+//! not report usage, so their tokens are estimated from the prompt and the
+//! streamed text with the model's `estimate_tokens`.
+
+use std::collections::HashMap;
+use std::pin::Pin;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use futures::{Stream, StreamExt};
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+
+use voice_agent_core::{
+    llm_types::TokenUsage, GenerateRequest, GenerateResponse, LanguageModel, Result, StreamChunk,
+    ToolDefinition,
+};
+
+/// Price of one model, in USD per 1,000 tokens
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub struct ModelPricing {
+    /// Prompt (input) tokens
+    pub input_per_1k: f64,
+    /// Completion (output) tokens
+    pub output_per_1k: f64,
+}
+
+impl ModelPricing {
+    pub fn new(input_per_1k: f64, output_per_1k: f64) -> Self {
+        Self {
+            input_per_1k,
+            output_per_1k,
+        }
+    }
+
+    /// Cost of a given usage
+    pub fn cost(&self, prompt_tokens: u64, completion_tokens: u64) -> f64 {
+        (prompt_tokens as f64 * self.input_per_1k + completion_tokens as f64 * self.output_per_1k)
+            / 1000.0
+    }
+}
+
+/// Per-model pricing with a fallback for unlisted models
+///
+/// Local models (Ollama) are free by default: leave them out of `models`
+/// and keep `default` at zero.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PricingTable {
+    /// Pricing by model name
+    #[serde(default)]
+    pub models: HashMap<String, ModelPricing>,
+    /// Pricing for models not in `models`
+    #[serde(default)]
+    pub default: ModelPricing,
+}
+
+impl From<&voice_agent_config::LlmPricingConfig> for PricingTable {
+    fn from(settings: &voice_agent_config::LlmPricingConfig) -> Self {
+        let price = |p: &voice_agent_config::ModelPriceConfig| {
+            ModelPricing::new(p.input_per_1k, p.output_per_1k)
+        };
+        Self {
+            models: settings
+                .models
+                .iter()
+                .map(|(model, pricing)| (model.clone(), price(pricing)))
+                .collect(),
+            default: price(&settings.default),
+        }
+    }
+}
+
+impl PricingTable {
+    /// Add or replace pricing for a model
+    pub fn with_model(mut self, model: impl Into<String>, pricing: ModelPricing) -> Self {
+        self.models.insert(model.into(), pricing);
+        self
+    }
+
+    /// Pricing for a model
+    pub fn pricing(&self, model: &str) -> ModelPricing {
+        self.models.get(model).copied().unwrap_or(self.default)
+    }
+}
+
+/// Which model in a speculative setup produced the tokens
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum UsageSource {
+    /// Main (large) model
+    Llm,
+    /// Speculative small model
+    Slm,
+}
+
+impl UsageSource {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            UsageSource::Llm => "llm",
+            UsageSource::Slm => "slm",
+        }
+    }
+}
+
+/// Accumulated usage and cost for one source
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub struct UsageCost {
+    pub calls: u64,
+    pub prompt_tokens: u64,
+    pub completion_tokens: u64,
+    /// Estimated spend in USD
+    pub cost_usd: f64,
+}
+
+/// Session cost snapshot
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub struct CostSummary {
+    pub llm: UsageCost,
+    pub slm: UsageCost,
+}
+
+impl CostSummary {
+    /// Total estimated spend in USD
+    pub fn total_cost_usd(&self) -> f64 {
+        self.llm.cost_usd + self.slm.cost_usd
+    }
+
+    /// Total tokens across both sources
+    pub fn total_tokens(&self) -> u64 {
+        self.llm.prompt_tokens
+            + self.llm.completion_tokens
+            + self.slm.prompt_tokens
+            + self.slm.completion_tokens
+    }
+}
+
+/// Running token and cost totals for a session
+#[derive(Debug, Default)]
+pub struct CostTracker {
+    pricing: PricingTable,
+    summary: Mutex<CostSummary>,
+}
+
+impl CostTracker {
+    pub fn new(pricing: PricingTable) -> Self {
+        Self {
+            pricing,
+            summary: Mutex::new(CostSummary::default()),
+        }
+    }
+
+    /// Record one call's usage and return its cost in USD
+    pub fn record(
+        &self,
+        source: UsageSource,
+        model: &str,
+        prompt_tokens: u64,
+        completion_tokens: u64,
+    ) -> f64 {
+        let cost = self.pricing.pricing(model).cost(prompt_tokens, completion_tokens);
+        let mut summary = self.summary.lock();
+        let entry = match source {
+            UsageSource::Llm => &mut summary.llm,
+            UsageSource::Slm => &mut summary.slm,
+        };
+        entry.calls += 1;
+        entry.prompt_tokens += prompt_tokens;
+        entry.completion_tokens += completion_tokens;
+        entry.cost_usd += cost;
+
+        tracing::trace!(
+            source = source.as_str(),
+            model = model,
+            prompt_tokens = prompt_tokens,
+            completion_tokens = completion_tokens,
+            cost_usd = cost,
+            "Recorded LLM usage"
+        );
+        cost
+    }
+
+    /// Record a `TokenUsage` reported by a model
+    pub fn record_usage(&self, source: UsageSource, model: &str, usage: &TokenUsage) -> f64 {
+        self.record(
+            source,
+            model,
+            usage.prompt_tokens as u64,
+            usage.completion_tokens as u64,
+        )
+    }
+
+    /// Current totals
+    pub fn summary(&self) -> CostSummary {
+        *self.summary.lock()
+    }
+
+    /// Pricing used by this tracker
+    pub fn pricing(&self) -> &PricingTable {
+        &self.pricing
+    }
+}
+
+/// `LanguageModel` wrapper that records usage into a `CostTracker`
+pub struct CostTrackingModel {
+    inner: Arc<dyn LanguageModel>,
+    tracker: Arc<CostTracker>,
+    source: UsageSource,
+}
+
+impl CostTrackingModel {
+    pub fn new(inner: Arc<dyn LanguageModel>, tracker: Arc<CostTracker>) -> Self {
+        Self {
+            inner,
+            tracker,
+            source: UsageSource::Llm,
+        }
+    }
+
+    /// Attribute this model's usage to a different source
+    pub fn with_source(mut self, source: UsageSource) -> Self {
+        self.source = source;
+        self
+    }
+
+    /// Tracker this model records into
+    pub fn tracker(&self) -> &Arc<CostTracker> {
+        &self.tracker
+    }
+
+    fn prompt_tokens(&self, request: &GenerateRequest) -> u64 {
+        request
+            .messages
+            .iter()
+            .map(|m| self.inner.estimate_tokens(&m.content) as u64)
+            .sum()
+    }
+
+    /// Record reported usage, estimating it when the model reports none
+    fn record_response(&self, request_tokens: u64, response: &GenerateResponse) {
+        match response.usage {
+            Some(ref usage) => {
+                // Some backends only report completion tokens
+                let prompt = if usage.prompt_tokens == 0 {
+                    request_tokens
+                } else {
+                    usage.prompt_tokens as u64
+                };
+                self.tracker.record(
+                    self.source,
+                    self.inner.model_name(),
+                    prompt,
+                    usage.completion_tokens as u64,
+                );
+            }
+            None => {
+                let completion = self.inner.estimate_tokens(&response.text) as u64;
+                self.tracker
+                    .record(self.source, self.inner.model_name(), request_tokens, completion);
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl LanguageModel for CostTrackingModel {
+    async fn generate(&self, request: GenerateRequest) -> Result<GenerateResponse> {
+        let request_tokens = self.prompt_tokens(&request);
+        let response = self.inner.generate(request).await?;
+        self.record_response(request_tokens, &response);
+        Ok(response)
+    }
+
+    fn generate_stream<'a>(
+        &'a self,
+        request: GenerateRequest,
+    ) -> Pin<Box<dyn Stream<Item = Result<StreamChunk>> + Send + 'a>> {
+        let request_tokens = self.prompt_tokens(&request);
+        let mut inner = self.inner.generate_stream(request);
+
+        Box::pin(async_stream::stream! {
+            let mut text = String::new();
+            while let Some(chunk) = inner.next().await {
+                if let Ok(ref c) = chunk {
+                    text.push_str(&c.delta);
+                }
+                yield chunk;
+            }
+            let completion = self.inner.estimate_tokens(&text) as u64;
+            self.tracker
+                .record(self.source, self.inner.model_name(), request_tokens, completion);
+        })
+    }
+
+    async fn generate_with_tools(
+        &self,
+        request: GenerateRequest,
+        tools: &[ToolDefinition],
+    ) -> Result<GenerateResponse> {
+        let request_tokens = self.prompt_tokens(&request);
+        let response = self.inner.generate_with_tools(request, tools).await?;
+        self.record_response(request_tokens, &response);
+        Ok(response)
+    }
+
+    async fn is_available(&self) -> bool {
+        self.inner.is_available().await
+    }
+
+    fn model_name(&self) -> &str {
+        self.inner.model_name()
+    }
+
+    fn context_size(&self) -> usize {
+        self.inner.context_size()
+    }
+
+    fn estimate_tokens(&self, text: &str) -> usize {
+        self.inner.estimate_tokens(text)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::MockLanguageModel;
+
+    fn pricing() -> PricingTable {
+        PricingTable::default()
+            .with_model("mock-llm", ModelPricing::new(3.0, 15.0))
+            .with_model("slm", ModelPricing::new(0.1, 0.2))
+    }
+
+    #[test]
+    fn test_pricing_lookup_and_cost() {
+        let table = pricing();
+        assert_eq!(table.pricing("unknown"), ModelPricing::default());
+        let cost = table.pricing("mock-llm").cost(1000, 2000);
+        assert!((cost - 33.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_pricing_from_settings() {
+        let json = serde_json::json!({
+            "models": { "gpt-4o": { "input_per_1k": 2.5, "output_per_1k": 10.0 } }
+        });
+        let settings: voice_agent_config::LlmPricingConfig = serde_json::from_value(json).unwrap();
+
+        let table = PricingTable::from(&settings);
+        assert_eq!(table.pricing("gpt-4o"), ModelPricing::new(2.5, 10.0));
+        // Unlisted (local) models stay free
+        assert_eq!(table.pricing("qwen3:4b"), ModelPricing::default());
+    }
+
+    #[test]
+    fn test_tracker_attributes_sources() {
+        let tracker = CostTracker::new(pricing());
+        tracker.record(UsageSource::Llm, "mock-llm", 500, 100);
+        tracker.record(UsageSource::Slm, "slm", 1000, 1000);
+
+        let summary = tracker.summary();
+        assert_eq!(summary.llm.calls, 1);
+        assert!((summary.llm.cost_usd - (1.5 + 1.5)).abs() < 1e-9);
+        assert!((summary.slm.cost_usd - 0.3).abs() < 1e-9);
+        assert!((summary.total_cost_usd() - 3.3).abs() < 1e-9);
+        assert_eq!(summary.total_tokens(), 2600);
+    }
+
+    #[tokio::test]
+    async fn test_cost_accumulates_across_turns() {
+        let mock = MockLanguageModel::new()
+            .with_response("one two three")
+            .with_response("four five");
+        let tracker = Arc::new(CostTracker::new(pricing()));
+        let model = CostTrackingModel::new(Arc::new(mock), tracker.clone());
+
+        // The mock reports one completion token per word and no prompt tokens,
+        // so prompt tokens are estimated from the request
+        let request = GenerateRequest::new("abcdefghi");
+        let prompt = model.estimate_tokens("abcdefghi") as u64;
+        model.generate(request.clone()).await.unwrap();
+        model.generate_with_tools(request, &[]).await.unwrap();
+
+        let summary = tracker.summary();
+        assert_eq!(summary.llm.calls, 2);
+        assert_eq!(summary.llm.prompt_tokens, prompt * 2);
+        assert_eq!(summary.llm.completion_tokens, 5);
+        let expected = ModelPricing::new(3.0, 15.0).cost(prompt * 2, 5);
+        assert!((summary.llm.cost_usd - expected).abs() < 1e-9);
+        assert_eq!(summary.slm, UsageCost::default());
+    }
+
+    #[tokio::test]
+    async fn test_stream_usage_is_recorded() {
+        let mock = MockLanguageModel::new().with_response("streamed reply text");
+        let tracker = Arc::new(CostTracker::new(pricing()));
+        let model = CostTrackingModel::new(Arc::new(mock), tracker.clone())
+            .with_source(UsageSource::Slm);
+
+        let chunks: Vec<_> = model
+            .generate_stream(GenerateRequest::new("system"))
+            .collect()
+            .await;
+        assert!(chunks.iter().all(|c| c.is_ok()));
+
+        let summary = tracker.summary();
+        assert_eq!(summary.slm.calls, 1);
+        assert_eq!(
+            summary.slm.completion_tokens,
+            model.estimate_tokens("streamed reply text") as u64
+        );
+        assert_eq!(summary.llm.calls, 0);
+    }
+}
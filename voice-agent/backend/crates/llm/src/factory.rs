@@ -5,7 +5,7 @@
 //!
 //! ## Supported Providers
 //! - **Claude**: Native tool_use support, best for complex tool calling
-//! - **Ollama**: Local models with text-based tool injection
+//! - **Ollama**: Local models with native `tools` support
 //! - **OpenAI**: GPT-4, GPT-3.5, Azure OpenAI
 //!
 //! ## Example
@@ -23,6 +23,7 @@ use crate::{
     adapter::LanguageModelAdapter,
     backend::{LlmBackend, LlmConfig, OllamaBackend, OpenAIBackend, OpenAIConfig},
     claude::{ClaudeBackend, ClaudeConfig},
+    limiter::{ConcurrencyLimitConfig, ConcurrencyLimitedModel, ConcurrencyLimiter},
     LlmError,
 };
 
@@ -32,7 +33,7 @@ pub enum LlmProvider {
     /// Claude (Anthropic) - native tool_use support
     #[default]
     Claude,
-    /// Ollama - local models, text-based tool injection
+    /// Ollama - local models, native `tools` support
     Ollama,
     /// OpenAI - GPT-4, GPT-3.5
     OpenAI,
@@ -50,6 +51,11 @@ impl LlmProvider {
             _ => None,
         }
     }
+
+    /// Tool-calling format this provider speaks natively
+    pub fn tool_call_format(&self) -> crate::tool_format::ToolCallFormat {
+        crate::tool_format::ToolCallFormat::for_provider(*self)
+    }
 }
 
 /// Unified LLM provider configuration
@@ -73,6 +79,8 @@ pub struct LlmProviderConfig {
     pub azure_api_version: Option<String>,
     /// Organization ID (for OpenAI only)
     pub organization: Option<String>,
+    /// Cap on concurrent calls, shared by all models for the same backend
+    pub concurrency: ConcurrencyLimitConfig,
 }
 
 impl Default for LlmProviderConfig {
@@ -87,6 +95,7 @@ impl Default for LlmProviderConfig {
             streaming: true,
             azure_api_version: None,
             organization: None,
+            concurrency: ConcurrencyLimitConfig::default(),
         }
     }
 }
@@ -169,6 +178,22 @@ impl LlmProviderConfig {
         self.endpoint = Some(endpoint.into());
         self
     }
+
+    /// Set the concurrent call limit
+    pub fn with_concurrency(mut self, concurrency: ConcurrencyLimitConfig) -> Self {
+        self.concurrency = concurrency;
+        self
+    }
+
+    /// Key identifying the backend calls go to, for sharing limits
+    fn backend_key(&self) -> String {
+        format!(
+            "{:?}:{}:{}",
+            self.provider,
+            self.endpoint.as_deref().unwrap_or_default(),
+            self.model
+        )
+    }
 }
 
 /// Factory for creating LLM backends
@@ -176,8 +201,23 @@ pub struct LlmFactory;
 
 impl LlmFactory {
     /// Create a LanguageModel from config (implements core trait)
+    ///
+    /// Calls are limited by `config.concurrency`, shared with every other
+    /// model created for the same backend.
     pub fn create(
         config: &LlmProviderConfig,
+    ) -> std::result::Result<Arc<dyn LanguageModel>, LlmError> {
+        let model = Self::create_unlimited(config)?;
+        if !config.concurrency.is_limited() {
+            return Ok(model);
+        }
+        let limiter =
+            ConcurrencyLimiter::shared("llm", &config.backend_key(), config.concurrency.clone());
+        Ok(Arc::new(ConcurrencyLimitedModel::new(model, limiter)))
+    }
+
+    fn create_unlimited(
+        config: &LlmProviderConfig,
     ) -> std::result::Result<Arc<dyn LanguageModel>, LlmError> {
         match config.provider {
             LlmProvider::Claude => {
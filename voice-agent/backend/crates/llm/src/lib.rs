@@ -2,12 +2,13 @@
 //!
 //! Features:
 //! - Multiple backend support (Ollama, Claude, OpenAI)
-//! - Native tool calling (Claude tool_use, text-based for Ollama)
+//! - Native tool calling (Claude tool_use, OpenAI/Ollama `tools`, text fallback)
 //! - Speculative execution (SLM-first, race parallel, hybrid streaming)
 //! - Streaming token generation
 //! - Context management
 
 pub mod backend;
+pub mod context;
 pub mod prompt;
 pub mod speculative;
 pub mod streaming;
@@ -17,25 +18,44 @@ pub mod adapter;
 pub mod claude;
 // P0-3c: LLM factory with provider abstraction
 pub mod factory;
+// Scripted LanguageModel for deterministic tests
+pub mod testing;
+// Provider-native tool definition and tool-call formats
+pub mod tool_format;
+// Per-session token usage and cost accounting
+pub mod cost;
+// Node-wide caps on concurrent LLM calls
+pub mod limiter;
 
 pub use backend::{
     FinishReason, GenerationResult, LlmBackend, LlmConfig, OllamaBackend, OpenAIBackend,
     OpenAIConfig,
 };
+pub use context::{ContextSection, ContextTrim, DroppedContext};
+pub use cost::{
+    CostSummary, CostTracker, CostTrackingModel, ModelPricing, PricingTable, UsageCost,
+    UsageSource,
+};
 // P0 FIX: Export adapter for clean dependency injection
 pub use adapter::LanguageModelAdapter;
 // P0-3a: Export Claude backend
 pub use claude::{ClaudeBackend, ClaudeConfig, ClaudeModel, ClaudeResponse, ClaudeStopReason};
 // P0-3c: Export factory
 pub use factory::{ClaudeLanguageModel, LlmFactory, LlmProvider, LlmProviderConfig};
+pub use limiter::{ConcurrencyLimitConfig, ConcurrencyLimitedModel, ConcurrencyLimiter};
 // P16 FIX: gold_loan_tools removed - tools loaded from domain config
 // Use voice_agent_config::domain::ToolsConfig::to_tool_definitions() instead
 pub use prompt::{
-    parse_tool_call, BrandConfig, BrandDefaults, Message, ParsedToolCall, PersonaConfig,
-    ProductFacts, PromptBuilder, ResponseTemplates, Role, ToolBuilder, ToolDefinition,
+    coerce_tool_call, parse_tool_call, parse_tool_call_for_tools, try_parse_tool_call, BrandConfig,
+    BrandDefaults, Message, ParsedToolCall, PersonaConfig, ProductFacts, PromptBuilder,
+    ResponseTemplates, Role, ToolBuilder, ToolCallParseError, ToolDefinition,
 };
 pub use speculative::{SpeculativeConfig, SpeculativeExecutor, SpeculativeMode, SpeculativeResult};
-pub use streaming::{GenerationEvent, StreamingGenerator, TokenStream};
+pub use streaming::{
+    GenerationEvent, StreamSegment, StreamingGenerator, TokenStream, ToolCallDetector,
+};
+pub use testing::MockLanguageModel;
+pub use tool_format::ToolCallFormat;
 
 use thiserror::Error;
 
@@ -65,6 +85,9 @@ pub enum LlmError {
 
     #[error("Configuration error: {0}")]
     Configuration(String),
+
+    #[error("Overloaded: {0}")]
+    Overloaded(String),
 }
 
 impl From<reqwest::Error> for LlmError {
@@ -0,0 +1,388 @@
+//! Concurrency Limits for LLM Calls
+//!
+//! During a burst, every session generating at once can exhaust GPU memory on
+//! a local model or overwhelm a provider. `ConcurrencyLimiter` caps the calls
+//! in flight. Callers beyond the cap queue for up to `max_wait`, and once
+//! `max_queued` callers are waiting new ones are turned away immediately, both
+//! with `LlmError::Overloaded`. Rejections are counted in
+//! `voice_agent_concurrency_rejected_total` by resource and reason.
+//!
+//! Calls are unlimited unless a limit is configured (`agent.llm.concurrency`).
+//! `LlmFactory::create` then wraps the models it builds in a
+//! `ConcurrencyLimitedModel` whose limiter is shared by every model for the
+//! same backend and limit, so all sessions on the node draw from one limit.
+
+use std::collections::HashMap;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, OnceLock};
+use std::time::Duration;
+
+use async_trait::async_trait;
+use futures::{Stream, StreamExt};
+use metrics::counter;
+use parking_lot::Mutex;
+use tokio::sync::{Semaphore, SemaphorePermit};
+
+use voice_agent_core::{
+    GenerateRequest, GenerateResponse, LanguageModel, Result, StreamChunk, ToolDefinition,
+};
+
+use crate::LlmError;
+
+/// Limits on concurrent calls to a shared resource
+///
+/// The default is unlimited; set `max_in_flight` to enable the limit.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConcurrencyLimitConfig {
+    /// Calls in flight at once (0 = unlimited)
+    pub max_in_flight: usize,
+    /// Callers waiting for a slot before new callers are rejected
+    pub max_queued: usize,
+    /// How long a caller waits for a slot before it is rejected
+    pub max_wait: Duration,
+}
+
+impl Default for ConcurrencyLimitConfig {
+    fn default() -> Self {
+        Self::unlimited()
+    }
+}
+
+/// Limits from the `agent.llm.concurrency` or `rag.embedding_concurrency`
+/// config section
+impl From<&voice_agent_config::ConcurrencyLimitSettings> for ConcurrencyLimitConfig {
+    fn from(settings: &voice_agent_config::ConcurrencyLimitSettings) -> Self {
+        Self {
+            max_in_flight: settings.max_in_flight,
+            max_queued: settings.max_queued,
+            max_wait: Duration::from_millis(settings.max_wait_ms),
+        }
+    }
+}
+
+impl ConcurrencyLimitConfig {
+    /// No limit on calls in flight
+    pub fn unlimited() -> Self {
+        Self {
+            max_in_flight: 0,
+            max_queued: 16,
+            max_wait: Duration::from_secs(2),
+        }
+    }
+
+    /// Limit calls in flight to `max_in_flight`
+    pub fn limited(max_in_flight: usize) -> Self {
+        Self {
+            max_in_flight,
+            ..Self::unlimited()
+        }
+    }
+
+    /// Whether calls are limited at all
+    pub fn is_limited(&self) -> bool {
+        self.max_in_flight > 0
+    }
+}
+
+/// Caps concurrent calls with a bounded wait queue
+#[derive(Debug)]
+pub struct ConcurrencyLimiter {
+    /// Resource name for logs and metrics ("llm", "embedding")
+    resource: &'static str,
+    config: ConcurrencyLimitConfig,
+    semaphore: Semaphore,
+    queued: AtomicUsize,
+}
+
+impl ConcurrencyLimiter {
+    /// Create a limiter; `max_in_flight` of 0 is treated as 1
+    pub fn new(resource: &'static str, config: ConcurrencyLimitConfig) -> Self {
+        let permits = config.max_in_flight.max(1);
+        Self {
+            resource,
+            config,
+            semaphore: Semaphore::new(permits),
+            queued: AtomicUsize::new(0),
+        }
+    }
+
+    /// Limiter shared by everything calling the same backend with the same limit
+    ///
+    /// The config is part of the key, so a backend configured with a
+    /// different limit gets a limiter of its own rather than silently
+    /// sharing one created with other settings.
+    pub fn shared(
+        resource: &'static str,
+        key: &str,
+        config: ConcurrencyLimitConfig,
+    ) -> Arc<ConcurrencyLimiter> {
+        static SHARED: OnceLock<Mutex<HashMap<String, Arc<ConcurrencyLimiter>>>> = OnceLock::new();
+        SHARED
+            .get_or_init(Default::default)
+            .lock()
+            .entry(format!("{}:{}:{:?}", resource, key, config))
+            .or_insert_with(|| Arc::new(ConcurrencyLimiter::new(resource, config)))
+            .clone()
+    }
+
+    /// Wait for a slot, or fail with `LlmError::Overloaded`
+    ///
+    /// The slot is released when the permit is dropped.
+    pub async fn acquire(&self) -> std::result::Result<SemaphorePermit<'_>, LlmError> {
+        if let Ok(permit) = self.semaphore.try_acquire() {
+            return Ok(permit);
+        }
+
+        let Some(_queued) = QueueSlot::reserve(&self.queued, self.config.max_queued) else {
+            self.reject("queue_full");
+            return Err(LlmError::Overloaded(format!(
+                "{} queue is full ({} waiting)",
+                self.resource, self.config.max_queued
+            )));
+        };
+        match tokio::time::timeout(self.config.max_wait, self.semaphore.acquire()).await {
+            Ok(Ok(permit)) => Ok(permit),
+            Ok(Err(_)) => Err(LlmError::Overloaded(format!(
+                "{} limiter closed",
+                self.resource
+            ))),
+            Err(_) => {
+                self.reject("timeout");
+                Err(LlmError::Overloaded(format!(
+                    "no {} slot free within {:?}",
+                    self.resource, self.config.max_wait
+                )))
+            },
+        }
+    }
+
+    /// Calls currently in flight
+    pub fn in_flight(&self) -> usize {
+        self.config.max_in_flight.max(1) - self.semaphore.available_permits()
+    }
+
+    /// Callers currently waiting for a slot
+    pub fn queued(&self) -> usize {
+        self.queued.load(Ordering::Acquire)
+    }
+
+    fn reject(&self, reason: &'static str) {
+        counter!(
+            "voice_agent_concurrency_rejected_total",
+            "resource" => self.resource,
+            "reason" => reason
+        )
+        .increment(1);
+        tracing::warn!(
+            resource = self.resource,
+            reason,
+            in_flight = self.in_flight(),
+            queued = self.queued(),
+            "Rejected call over the concurrency limit"
+        );
+    }
+}
+
+/// A place in the wait queue, given back on drop
+struct QueueSlot<'a>(&'a AtomicUsize);
+
+impl<'a> QueueSlot<'a> {
+    fn reserve(queued: &'a AtomicUsize, max_queued: usize) -> Option<Self> {
+        queued
+            .fetch_update(Ordering::AcqRel, Ordering::Acquire, |n| {
+                (n < max_queued).then_some(n + 1)
+            })
+            .ok()
+            .map(|_| QueueSlot(queued))
+    }
+}
+
+impl Drop for QueueSlot<'_> {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::AcqRel);
+    }
+}
+
+/// LanguageModel whose calls go through a `ConcurrencyLimiter`
+pub struct ConcurrencyLimitedModel {
+    inner: Arc<dyn LanguageModel>,
+    limiter: Arc<ConcurrencyLimiter>,
+}
+
+impl ConcurrencyLimitedModel {
+    pub fn new(inner: Arc<dyn LanguageModel>, limiter: Arc<ConcurrencyLimiter>) -> Self {
+        Self { inner, limiter }
+    }
+
+    /// Limiter this model's calls go through
+    pub fn limiter(&self) -> &Arc<ConcurrencyLimiter> {
+        &self.limiter
+    }
+}
+
+#[async_trait]
+impl LanguageModel for ConcurrencyLimitedModel {
+    async fn generate(&self, request: GenerateRequest) -> Result<GenerateResponse> {
+        let _permit = self.limiter.acquire().await?;
+        self.inner.generate(request).await
+    }
+
+    fn generate_stream<'a>(
+        &'a self,
+        request: GenerateRequest,
+    ) -> Pin<Box<dyn Stream<Item = Result<StreamChunk>> + Send + 'a>> {
+        Box::pin(async_stream::stream! {
+            // Held until the stream is done or dropped
+            let _permit = match self.limiter.acquire().await {
+                Ok(permit) => permit,
+                Err(e) => {
+                    yield Err(voice_agent_core::Error::from(e));
+                    return;
+                },
+            };
+            let mut inner = self.inner.generate_stream(request);
+            while let Some(chunk) = inner.next().await {
+                yield chunk;
+            }
+        })
+    }
+
+    async fn generate_with_tools(
+        &self,
+        request: GenerateRequest,
+        tools: &[ToolDefinition],
+    ) -> Result<GenerateResponse> {
+        let _permit = self.limiter.acquire().await?;
+        self.inner.generate_with_tools(request, tools).await
+    }
+
+    async fn is_available(&self) -> bool {
+        self.inner.is_available().await
+    }
+
+    fn model_name(&self) -> &str {
+        self.inner.model_name()
+    }
+
+    fn context_size(&self) -> usize {
+        self.inner.context_size()
+    }
+
+    fn estimate_tokens(&self, text: &str) -> usize {
+        self.inner.estimate_tokens(text)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::MockLanguageModel;
+
+    fn limiter(max_queued: usize, max_wait_ms: u64) -> Arc<ConcurrencyLimiter> {
+        Arc::new(ConcurrencyLimiter::new(
+            "llm",
+            ConcurrencyLimitConfig {
+                max_in_flight: 1,
+                max_queued,
+                max_wait: Duration::from_millis(max_wait_ms),
+            },
+        ))
+    }
+
+    #[tokio::test]
+    async fn test_second_call_waits_for_the_first() {
+        let limiter = limiter(1, 1000);
+        let first = limiter.acquire().await.unwrap();
+        assert_eq!(limiter.in_flight(), 1);
+
+        let waiter = {
+            let limiter = limiter.clone();
+            tokio::spawn(async move { limiter.acquire().await.map(|_| ()) })
+        };
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        assert_eq!(limiter.queued(), 1);
+        assert!(!waiter.is_finished());
+
+        drop(first);
+        assert!(waiter.await.unwrap().is_ok());
+        assert_eq!(limiter.queued(), 0);
+        assert_eq!(limiter.in_flight(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_calls_beyond_queue_or_wait_are_rejected() {
+        let limiter = limiter(1, 50);
+        let _first = limiter.acquire().await.unwrap();
+
+        let queued = {
+            let limiter = limiter.clone();
+            tokio::spawn(async move { limiter.acquire().await.map(|_| ()) })
+        };
+        tokio::time::sleep(Duration::from_millis(10)).await;
+
+        // The queue holds one caller, so a third is turned away at once
+        let third = limiter.acquire().await;
+        assert!(matches!(third, Err(LlmError::Overloaded(ref m)) if m.contains("queue")));
+
+        // The queued caller gives up once the wait runs out
+        let started = std::time::Instant::now();
+        let queued = queued.await.unwrap();
+        assert!(matches!(queued, Err(LlmError::Overloaded(ref m)) if m.contains("within")));
+        assert!(started.elapsed() >= Duration::from_millis(30));
+        assert_eq!(limiter.queued(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_limited_model_rejects_when_saturated() {
+        let limiter = limiter(0, 10);
+        let model = ConcurrencyLimitedModel::new(
+            Arc::new(MockLanguageModel::new().with_response("hello")),
+            limiter.clone(),
+        );
+
+        let permit = limiter.acquire().await.unwrap();
+        let err = model.generate(GenerateRequest::new("system")).await;
+        assert!(err.is_err());
+
+        drop(permit);
+        let response = model
+            .generate(GenerateRequest::new("system"))
+            .await
+            .unwrap();
+        assert_eq!(response.text, "hello");
+    }
+
+    #[test]
+    fn test_shared_limiter_per_backend_and_config() {
+        let config = ConcurrencyLimitConfig::limited(8);
+        let a = ConcurrencyLimiter::shared("llm", "ollama:qwen-test", config.clone());
+        let b = ConcurrencyLimiter::shared("llm", "ollama:qwen-test", config.clone());
+        let c = ConcurrencyLimiter::shared("llm", "claude:opus-test", config);
+        assert!(Arc::ptr_eq(&a, &b));
+        assert!(!Arc::ptr_eq(&a, &c));
+
+        let d = ConcurrencyLimiter::shared(
+            "llm",
+            "ollama:qwen-test",
+            ConcurrencyLimitConfig::limited(2),
+        );
+        assert!(!Arc::ptr_eq(&a, &d));
+    }
+
+    #[test]
+    fn test_limits_from_settings() {
+        assert!(!ConcurrencyLimitConfig::default().is_limited());
+        assert!(!ConcurrencyLimitConfig::from(
+            &voice_agent_config::ConcurrencyLimitSettings::default()
+        )
+        .is_limited());
+
+        let settings: voice_agent_config::ConcurrencyLimitSettings =
+            serde_json::from_str(r#"{"max_in_flight": 4, "max_wait_ms": 500}"#).unwrap();
+        let config = ConcurrencyLimitConfig::from(&settings);
+        assert_eq!(config.max_in_flight, 4);
+        assert_eq!(config.max_queued, 16);
+        assert_eq!(config.max_wait, Duration::from_millis(500));
+    }
+}
@@ -14,6 +14,8 @@
 
 use std::sync::OnceLock;
 
+use crate::context::ContextSection;
+
 /// P19 FIX: Brand defaults loaded from domain config YAML at app startup.
 /// This allows deprecated methods to still be domain-agnostic.
 /// Generic placeholders are used until init() is called with domain config.
@@ -215,43 +217,312 @@ impl ToolBuilder {
 
 /// P4 FIX: Parse tool call from LLM response
 ///
-/// Extracts tool calls in the format: `[TOOL_CALL: {"name": "...", "arguments": {...}}]`
+/// Extracts tool calls in the format: `[TOOL_CALL: {"name": "...", "arguments": {...}}]`.
+/// Malformed JSON is repaired first (see [`try_parse_tool_call`]); calls that
+/// cannot be repaired are logged and treated as no tool call.
 pub fn parse_tool_call(response: &str) -> Option<ParsedToolCall> {
-    // Look for the tool call pattern
-    let start_marker = "[TOOL_CALL:";
-    let end_marker = "]";
-
-    let start_idx = response.find(start_marker)?;
-    let json_start = start_idx + start_marker.len();
+    match try_parse_tool_call(response) {
+        Ok(parsed) => Some(parsed),
+        Err(ToolCallParseError::NoToolCall) => None,
+        Err(e) => {
+            tracing::warn!(error = %e, "Dropping unparseable tool call");
+            None
+        }
+    }
+}
 
-    // Find matching end bracket
-    let remaining = &response[json_start..];
-    let end_idx = remaining.find(end_marker)?;
-    let json_str = remaining[..end_idx].trim();
+/// Parse a tool call, repairing common small-model JSON mistakes
+///
+/// Accepts the `[TOOL_CALL: {...}]` marker format as well as a bare
+/// ```` ```json ```` fenced object with a `name` field. Before parsing, the
+/// JSON is stripped of code fences and trailing commas, unquoted keys are
+/// quoted and single-quoted strings are converted to double quotes.
+pub fn try_parse_tool_call(response: &str) -> Result<ParsedToolCall, ToolCallParseError> {
+    let start_marker = "[TOOL_CALL:";
 
-    // Parse the JSON
-    let value: serde_json::Value = serde_json::from_str(json_str).ok()?;
-    let name = value.get("name")?.as_str()?.to_string();
+    let (start_idx, json_start, body_end, call_end) = match response.find(start_marker) {
+        Some(start_idx) => {
+            let json_start = start_idx + start_marker.len();
+            let remaining = &response[json_start..];
+            let (obj_start, obj_end) =
+                find_json_object(remaining).ok_or(ToolCallParseError::Unterminated)?;
+            // Consume a closing fence and the marker's closing bracket, if present
+            let mut call_end = json_start + obj_end;
+            let rest = &response[call_end..];
+            let trimmed = rest.trim_start();
+            let trimmed = trimmed.strip_prefix("```").unwrap_or(trimmed).trim_start();
+            if let Some(after) = trimmed.strip_prefix(']') {
+                call_end = response.len() - after.len();
+            }
+            (start_idx, json_start + obj_start, json_start + obj_end, call_end)
+        }
+        None => {
+            let fence = response.find("```").ok_or(ToolCallParseError::NoToolCall)?;
+            let after_fence = fence + 3;
+            let (obj_start, obj_end) =
+                find_json_object(&response[after_fence..]).ok_or(ToolCallParseError::NoToolCall)?;
+            let mut call_end = after_fence + obj_end;
+            if let Some(close) = response[call_end..].find("```") {
+                call_end += close + 3;
+            }
+            (fence, after_fence + obj_start, after_fence + obj_end, call_end)
+        }
+    };
+
+    let raw = &response[json_start..body_end];
+    let value: serde_json::Value = match serde_json::from_str(raw) {
+        Ok(value) => value,
+        Err(_) => {
+            let repaired = repair_json(raw);
+            serde_json::from_str(&repaired)
+                .map_err(|e| ToolCallParseError::InvalidJson(e.to_string()))?
+        }
+    };
+
+    let name = match value.get("name").and_then(|n| n.as_str()) {
+        Some(name) if !name.is_empty() => name.to_string(),
+        // A fenced block without a name is just JSON in the reply, not a call
+        _ if !response.contains(start_marker) => return Err(ToolCallParseError::NoToolCall),
+        _ => return Err(ToolCallParseError::MissingName),
+    };
     let arguments = value
         .get("arguments")
         .cloned()
         .unwrap_or(serde_json::Value::Object(serde_json::Map::new()));
+    if !arguments.is_object() {
+        return Err(ToolCallParseError::InvalidJson(
+            "arguments is not an object".to_string(),
+        ));
+    }
 
-    // Extract the text before and after the tool call
-    let text_before = response[..start_idx].trim().to_string();
-    let text_after = response[json_start + end_idx + 1..].trim().to_string();
-
-    Some(ParsedToolCall {
+    Ok(ParsedToolCall {
+        id: None,
         name,
         arguments,
-        text_before,
-        text_after,
+        text_before: response[..start_idx].trim().to_string(),
+        text_after: response[call_end..].trim().to_string(),
     })
 }
 
+/// Parse a tool call and coerce its arguments against the tool's schema
+///
+/// Numbers, integers and booleans sent as strings (`"50"`, `"true"`) and
+/// numbers sent where a string is expected are converted to the declared
+/// type. A call naming a tool not in `tools` is an error.
+pub fn parse_tool_call_for_tools(
+    response: &str,
+    tools: &[ToolDefinition],
+) -> Result<ParsedToolCall, ToolCallParseError> {
+    coerce_tool_call(try_parse_tool_call(response)?, tools)
+}
+
+/// Coerce a parsed tool call's arguments against the tool's schema
+///
+/// Used for calls parsed from reply text and from a provider's native
+/// tool-call response alike.
+pub fn coerce_tool_call(
+    mut parsed: ParsedToolCall,
+    tools: &[ToolDefinition],
+) -> Result<ParsedToolCall, ToolCallParseError> {
+    let tool = tools
+        .iter()
+        .find(|t| t.name == parsed.name)
+        .ok_or_else(|| ToolCallParseError::UnknownTool(parsed.name.clone()))?;
+
+    let properties = match tool.parameters.get("properties").and_then(|p| p.as_object()) {
+        Some(properties) => properties,
+        None => return Ok(parsed),
+    };
+    let Some(args) = parsed.arguments.as_object_mut() else {
+        return Ok(parsed);
+    };
+
+    for (key, value) in args.iter_mut() {
+        let Some(expected) = properties
+            .get(key)
+            .and_then(|p| p.get("type"))
+            .and_then(|t| t.as_str())
+        else {
+            continue;
+        };
+        *value = coerce_value(value, expected).ok_or_else(|| ToolCallParseError::ArgumentType {
+            argument: key.clone(),
+            expected: expected.to_string(),
+            actual: value.to_string(),
+        })?;
+    }
+
+    Ok(parsed)
+}
+
+/// Why a tool call could not be parsed
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum ToolCallParseError {
+    #[error("response contains no tool call")]
+    NoToolCall,
+
+    #[error("tool call JSON is not terminated")]
+    Unterminated,
+
+    #[error("tool call JSON could not be repaired: {0}")]
+    InvalidJson(String),
+
+    #[error("tool call has no name")]
+    MissingName,
+
+    #[error("unknown tool: {0}")]
+    UnknownTool(String),
+
+    #[error("argument '{argument}' should be {expected}, got {actual}")]
+    ArgumentType {
+        argument: String,
+        expected: String,
+        actual: String,
+    },
+}
+
+/// Byte range of the first balanced `{...}` object in `text`
+///
+/// Braces inside string literals are ignored. Returns `None` when no object
+/// starts or the first one is never closed.
+pub(crate) fn find_json_object(text: &str) -> Option<(usize, usize)> {
+    let start = text.find('{')?;
+    let mut depth = 0usize;
+    let mut quote: Option<char> = None;
+    let mut escaped = false;
+
+    for (i, c) in text[start..].char_indices() {
+        if let Some(q) = quote {
+            if escaped {
+                escaped = false;
+            } else if c == '\\' {
+                escaped = true;
+            } else if c == q {
+                quote = None;
+            }
+            continue;
+        }
+        match c {
+            '"' | '\'' => quote = Some(c),
+            '{' => depth += 1,
+            '}' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some((start, start + i + 1));
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Rewrite near-JSON into JSON
+///
+/// Fixes trailing commas, unquoted object keys and single-quoted strings.
+/// String contents are copied through untouched.
+pub(crate) fn repair_json(raw: &str) -> String {
+    let chars: Vec<char> = raw.trim().chars().collect();
+    let mut out = String::with_capacity(raw.len() + 8);
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+        match c {
+            '"' | '\'' => {
+                // Copy a string literal, normalising the quote character
+                out.push('"');
+                i += 1;
+                while i < chars.len() && chars[i] != c {
+                    if chars[i] == '\\' && i + 1 < chars.len() {
+                        out.push(chars[i]);
+                        out.push(chars[i + 1]);
+                        i += 2;
+                        continue;
+                    }
+                    if chars[i] == '"' {
+                        out.push('\\');
+                    }
+                    out.push(chars[i]);
+                    i += 1;
+                }
+                out.push('"');
+                i += 1;
+            }
+            ',' => {
+                let next = chars[i + 1..].iter().find(|c| !c.is_whitespace());
+                if !matches!(next, Some('}') | Some(']') | None) {
+                    out.push(',');
+                }
+                i += 1;
+            }
+            c if c.is_alphabetic() || c == '_' => {
+                let start = i;
+                while i < chars.len() && (chars[i].is_alphanumeric() || chars[i] == '_') {
+                    i += 1;
+                }
+                let word: String = chars[start..i].iter().collect();
+                let next = chars[i..].iter().find(|c| !c.is_whitespace());
+                if next == Some(&':') {
+                    out.push('"');
+                    out.push_str(&word);
+                    out.push('"');
+                } else {
+                    match word.as_str() {
+                        "True" => out.push_str("true"),
+                        "False" => out.push_str("false"),
+                        "None" => out.push_str("null"),
+                        _ => out.push_str(&word),
+                    }
+                }
+            }
+            _ => {
+                out.push(c);
+                i += 1;
+            }
+        }
+    }
+
+    out
+}
+
+/// Convert a JSON value to a JSON-Schema primitive type, if it can be
+fn coerce_value(value: &serde_json::Value, expected: &str) -> Option<serde_json::Value> {
+    use serde_json::Value;
+
+    match (expected, value) {
+        ("number", Value::Number(_)) => Some(value.clone()),
+        ("number", Value::String(s)) => s
+            .trim()
+            .replace(',', "")
+            .parse::<f64>()
+            .ok()
+            .and_then(serde_json::Number::from_f64)
+            .map(Value::Number),
+        ("integer", Value::Number(n)) if n.is_i64() || n.is_u64() => Some(value.clone()),
+        ("integer", Value::Number(n)) => n
+            .as_f64()
+            .filter(|f| f.fract() == 0.0)
+            .map(|f| Value::from(f as i64)),
+        ("integer", Value::String(s)) => s.trim().replace(',', "").parse::<i64>().ok().map(Value::from),
+        ("boolean", Value::Bool(_)) => Some(value.clone()),
+        ("boolean", Value::String(s)) => match s.trim().to_lowercase().as_str() {
+            "true" | "yes" => Some(Value::Bool(true)),
+            "false" | "no" => Some(Value::Bool(false)),
+            _ => None,
+        },
+        ("string", Value::String(_)) => Some(value.clone()),
+        ("string", Value::Number(n)) => Some(Value::String(n.to_string())),
+        ("string", Value::Bool(b)) => Some(Value::String(b.to_string())),
+        _ => Some(value.clone()),
+    }
+}
+
 /// P4 FIX: Parsed tool call from LLM response
 #[derive(Debug, Clone)]
 pub struct ParsedToolCall {
+    /// Provider-assigned call ID (native tool calling only)
+    pub id: Option<String>,
     /// Tool name to call
     pub name: String,
     /// Arguments as JSON value
@@ -294,7 +565,9 @@ impl Default for ProductFacts {
 
 /// Prompt builder for voice agent (domain-agnostic)
 pub struct PromptBuilder {
-    messages: Vec<Message>,
+    pub(crate) messages: Vec<Message>,
+    /// Section of each message, for overflow trimming (see `context`)
+    pub(crate) sections: Vec<ContextSection>,
     persona: PersonaConfig,
     /// P13 FIX: Config-driven product facts
     product_facts: ProductFacts,
@@ -317,6 +590,7 @@ impl PromptBuilder {
     pub fn new() -> Self {
         Self {
             messages: Vec::new(),
+            sections: Vec::new(),
             persona: PersonaConfig::default(),
             product_facts: ProductFacts::default(),
         }
@@ -370,7 +644,7 @@ impl PromptBuilder {
             &brand.helpline,
         );
 
-        self.messages.push(Message::system(system));
+        self.push(ContextSection::System, Message::system(system));
         self
     }
 
@@ -399,13 +673,21 @@ impl PromptBuilder {
     }
 
     /// Add RAG context
-    pub fn with_context(mut self, context: &str) -> Self {
+    pub fn with_context(self, context: &str) -> Self {
+        self.with_context_section(ContextSection::Instruction, context)
+    }
+
+    /// Add context tagged with the section it belongs to
+    ///
+    /// The section decides whether and in which order `fit_to_window` may
+    /// trim it.
+    pub fn with_context_section(mut self, section: ContextSection, context: &str) -> Self {
         if !context.is_empty() {
             let context_msg = format!(
                 "## Relevant Information\n{}\n\nUse this information to answer the customer's question if relevant.",
                 context
             );
-            self.messages.push(Message::system(context_msg));
+            self.push(section, Message::system(context_msg));
         }
         self
     }
@@ -431,20 +713,22 @@ impl PromptBuilder {
 
         if !profile_parts.is_empty() {
             let profile = format!("## Customer Profile\n{}", profile_parts.join("\n"));
-            self.messages.push(Message::system(profile));
+            self.push(ContextSection::Domain, Message::system(profile));
         }
         self
     }
 
     /// Add conversation history
     pub fn with_history(mut self, history: &[Message]) -> Self {
-        self.messages.extend(history.iter().cloned());
+        for message in history {
+            self.push(ContextSection::History, message.clone());
+        }
         self
     }
 
     /// Add current user message
     pub fn user_message(mut self, message: &str) -> Self {
-        self.messages.push(Message::user(message));
+        self.push(ContextSection::User, Message::user(message));
         self
     }
 
@@ -457,12 +741,12 @@ impl PromptBuilder {
         if let Some(guidance) = prompts_config.get_stage_guidance(stage) {
             let wrapper = prompts_config.build_stage_guidance(guidance);
             if !wrapper.is_empty() {
-                self.messages.push(Message::system(wrapper));
+                self.push(ContextSection::Instruction, Message::system(wrapper));
             } else {
-                self.messages.push(Message::system(format!(
-                    "## Current Stage Guidance\n{}",
-                    guidance
-                )));
+                self.push(
+                    ContextSection::Instruction,
+                    Message::system(format!("## Current Stage Guidance\n{}", guidance)),
+                );
             }
         }
         self
@@ -470,11 +754,11 @@ impl PromptBuilder {
 
     /// P0-2 FIX: Add available tools for LLM-based tool calling
     ///
-    /// For Ollama (non-native tool support), we inject tool definitions into the system prompt
-    /// and instruct the LLM to output tool calls in a specific JSON format.
+    /// For backends without native tool support (`ToolCallFormat::Text`), we inject tool
+    /// definitions into the system prompt and instruct the LLM to output tool calls in a
+    /// specific JSON format.
     ///
-    /// For Claude API, tools are passed natively via the API (handled by Claude backend).
-    /// This method is primarily used for Ollama text-based tool injection.
+    /// Claude, OpenAI and Ollama get tools natively via `ToolCallFormat` instead.
     ///
     /// The LLM should output: `[TOOL_CALL: {"name": "tool_name", "arguments": {...}}]`
     pub fn with_tools(mut self, tools: &[ToolDefinition]) -> Self {
@@ -549,10 +833,15 @@ Available tools:
             "\nOnly use tools when the customer's request requires specific calculations or data lookup. For general conversation, respond naturally without tools."
         );
 
-        self.messages.push(Message::system(tool_prompt));
+        self.push(ContextSection::Instruction, Message::system(tool_prompt));
         self
     }
 
+    fn push(&mut self, section: ContextSection, message: Message) {
+        self.messages.push(message);
+        self.sections.push(section);
+    }
+
     /// Build final message list
     pub fn build(self) -> Vec<Message> {
         self.messages
@@ -668,7 +957,7 @@ Available tools:
     }
 
     /// Estimate tokens for a single message content
-    fn estimate_single_message_tokens(content: &str) -> usize {
+    pub(crate) fn estimate_single_message_tokens(content: &str) -> usize {
         use unicode_segmentation::UnicodeSegmentation;
 
         let grapheme_count = content.graphemes(true).count();
@@ -1016,6 +1305,71 @@ mod tests {
         assert_eq!(parsed.text_after, "I'll wait for the results.");
     }
 
+    #[test]
+    fn test_parse_tool_call_in_code_fence() {
+        let response = "Checking now.\n```json\n{\"name\": \"find_branches\", \"arguments\": {\"city\": \"Pune\"}}\n```";
+
+        let parsed = parse_tool_call(response).expect("Should parse fenced tool call");
+        assert_eq!(parsed.name, "find_branches");
+        assert_eq!(parsed.arguments["city"], "Pune");
+        assert_eq!(parsed.text_before, "Checking now.");
+        assert!(parsed.text_after.is_empty());
+
+        let marked = "[TOOL_CALL: ```json\n{\"name\": \"find_branches\", \"arguments\": {}}\n```]";
+        assert_eq!(parse_tool_call(marked).unwrap().name, "find_branches");
+    }
+
+    #[test]
+    fn test_parse_tool_call_repairs_trailing_commas_and_keys() {
+        let response = r#"[TOOL_CALL: {name: 'check_eligibility', arguments: {gold_weight: 50, purity: "22K",},}]"#;
+
+        let parsed = parse_tool_call(response).expect("Should repair tool call");
+        assert_eq!(parsed.name, "check_eligibility");
+        assert_eq!(parsed.arguments["gold_weight"], 50);
+        assert_eq!(parsed.arguments["purity"], "22K");
+    }
+
+    #[test]
+    fn test_parse_tool_call_coerces_against_schema() {
+        let tools = vec![ToolBuilder::new("check_eligibility", "Check eligibility")
+            .param("gold_weight", "number", "Weight in grams", true)
+            .param("city", "string", "City", false)
+            .build()];
+        let response = r#"[TOOL_CALL: {"name": "check_eligibility", "arguments": {"gold_weight": "50", "city": 411001}}]"#;
+
+        let parsed = parse_tool_call_for_tools(response, &tools).expect("Should coerce arguments");
+        assert_eq!(parsed.arguments["gold_weight"], 50.0);
+        assert_eq!(parsed.arguments["city"], "411001");
+
+        let bad = r#"[TOOL_CALL: {"name": "check_eligibility", "arguments": {"gold_weight": "fifty"}}]"#;
+        assert!(matches!(
+            parse_tool_call_for_tools(bad, &tools),
+            Err(ToolCallParseError::ArgumentType { ref argument, .. }) if argument == "gold_weight"
+        ));
+
+        let unknown = r#"[TOOL_CALL: {"name": "transfer_funds", "arguments": {}}]"#;
+        assert_eq!(
+            parse_tool_call_for_tools(unknown, &tools).unwrap_err(),
+            ToolCallParseError::UnknownTool("transfer_funds".to_string())
+        );
+    }
+
+    #[test]
+    fn test_parse_tool_call_unrepairable_fails_cleanly() {
+        let unterminated = r#"[TOOL_CALL: {"name": "find_branches", "arguments": {"city": "Pune"}"#;
+        assert_eq!(
+            try_parse_tool_call(unterminated).unwrap_err(),
+            ToolCallParseError::Unterminated
+        );
+
+        let garbage = r#"[TOOL_CALL: {"name": "find_branches" "arguments" {city = Pune}}]"#;
+        assert!(matches!(
+            try_parse_tool_call(garbage),
+            Err(ToolCallParseError::InvalidJson(_))
+        ));
+        assert!(parse_tool_call(garbage).is_none());
+    }
+
     #[test]
     fn test_with_tools() {
         // P16 FIX: Tools created via ToolBuilder instead of hardcoded gold_loan_tools()
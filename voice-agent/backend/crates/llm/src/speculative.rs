@@ -159,6 +159,16 @@ impl SpeculativeExecutor {
         }
     }
 
+    /// Model name of the small (speculative) model
+    pub fn slm_model_name(&self) -> &str {
+        self.slm.model_name()
+    }
+
+    /// Model name of the large model
+    pub fn llm_model_name(&self) -> &str {
+        self.llm.model_name()
+    }
+
     /// Execute with speculative strategy
     pub async fn execute(&self, messages: &[Message]) -> Result<SpeculativeResult, LlmError> {
         match self.config.mode {
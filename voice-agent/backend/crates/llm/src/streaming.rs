@@ -8,6 +8,7 @@ use tokio::sync::mpsc;
 use tokio_stream::Stream;
 
 use crate::backend::GenerationResult;
+use crate::prompt::{find_json_object, try_parse_tool_call, ParsedToolCall};
 
 /// Token stream type
 pub type TokenStream = Pin<Box<dyn Stream<Item = String> + Send>>;
@@ -243,6 +244,133 @@ impl Default for ResponseBuilder {
     }
 }
 
+/// Marker that opens a text-format tool call
+const TOOL_CALL_MARKER: &str = "[TOOL_CALL:";
+
+/// Piece of a token stream after tool-call detection
+#[derive(Debug, Clone)]
+pub enum StreamSegment {
+    /// Text that is safe to speak
+    Text(String),
+    /// A tool call that finished mid-stream
+    ToolCall(ParsedToolCall),
+}
+
+/// Incremental `[TOOL_CALL: {...}]` detector for token streams
+///
+/// Tokens pass through as `Text` until the call marker appears. From there
+/// the JSON is held back and a `ToolCall` is emitted as soon as its object
+/// closes, so the tool can be dispatched before the stream ends and the raw
+/// JSON never reaches TTS. Text that might be the start of a marker is held
+/// back until it is disambiguated.
+#[derive(Debug, Default)]
+pub struct ToolCallDetector {
+    pending: String,
+    in_call: bool,
+    /// Strip the `]` (and fence) closing a call that was already emitted
+    skip_close: bool,
+}
+
+impl ToolCallDetector {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Feed a token and get the segments it completes
+    pub fn push(&mut self, token: &str) -> Vec<StreamSegment> {
+        self.pending.push_str(token);
+        let mut segments = Vec::new();
+
+        loop {
+            if self.in_call {
+                let Some((_, obj_end)) = find_json_object(&self.pending) else {
+                    break;
+                };
+                match try_parse_tool_call(&self.pending[..obj_end]) {
+                    Ok(call) => segments.push(StreamSegment::ToolCall(call)),
+                    Err(e) => tracing::warn!(error = %e, "Dropping unparseable streamed tool call"),
+                }
+                self.pending.drain(..obj_end);
+                self.in_call = false;
+                self.skip_close = true;
+                continue;
+            }
+
+            if self.skip_close {
+                let trimmed = self.pending.trim_start();
+                if trimmed.is_empty() || "```".starts_with(trimmed) {
+                    break;
+                }
+                let rest = trimmed.strip_prefix("```").unwrap_or(trimmed).trim_start();
+                if rest.is_empty() {
+                    break;
+                }
+                let rest = rest.strip_prefix(']').unwrap_or(rest);
+                self.pending = rest.to_string();
+                self.skip_close = false;
+            }
+
+            if let Some(idx) = self.pending.find(TOOL_CALL_MARKER) {
+                if idx > 0 {
+                    segments.push(StreamSegment::Text(self.pending[..idx].to_string()));
+                }
+                self.pending.drain(..idx);
+                self.in_call = true;
+                continue;
+            }
+
+            // Release everything except a suffix that could begin the marker
+            let hold = (1..TOOL_CALL_MARKER.len())
+                .rev()
+                .find(|&n| self.pending.ends_with(&TOOL_CALL_MARKER[..n]))
+                .unwrap_or(0);
+            let release = self.pending.len() - hold;
+            if release > 0 {
+                segments.push(StreamSegment::Text(self.pending[..release].to_string()));
+                self.pending.drain(..release);
+            }
+            break;
+        }
+
+        segments
+    }
+
+    /// Feed a generation event; tokens go through `push`, completion flushes
+    pub fn push_event(&mut self, event: &GenerationEvent) -> Vec<StreamSegment> {
+        match event {
+            GenerationEvent::Token(token) => self.push(token),
+            GenerationEvent::Complete(_) | GenerationEvent::Error(_) => {
+                let rest = self.finish();
+                if rest.is_empty() {
+                    Vec::new()
+                } else {
+                    vec![StreamSegment::Text(rest)]
+                }
+            }
+            GenerationEvent::Started => Vec::new(),
+        }
+    }
+
+    /// End of stream: return held-back text
+    ///
+    /// An unterminated tool call is discarded rather than spoken.
+    pub fn finish(&mut self) -> String {
+        let rest = std::mem::take(&mut self.pending);
+        let in_call = std::mem::replace(&mut self.in_call, false);
+        self.skip_close = false;
+        if in_call {
+            tracing::warn!("Stream ended inside a tool call, dropping it");
+            return String::new();
+        }
+        rest
+    }
+
+    /// Whether a tool call is currently being held back
+    pub fn in_tool_call(&self) -> bool {
+        self.in_call
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -290,4 +418,74 @@ mod tests {
         assert_eq!(tokens.len(), 2);
         assert_eq!(gen.text(), "Hello world");
     }
+
+    fn collect(segments: &[StreamSegment]) -> (String, Vec<String>) {
+        let mut text = String::new();
+        let mut calls = Vec::new();
+        for segment in segments {
+            match segment {
+                StreamSegment::Text(t) => text.push_str(t),
+                StreamSegment::ToolCall(c) => calls.push(c.name.clone()),
+            }
+        }
+        (text, calls)
+    }
+
+    #[test]
+    fn test_tool_call_detected_before_stream_ends() {
+        let tokens = [
+            "Let me ",
+            "check. [TOOL",
+            "_CALL: {\"name\": ",
+            "\"find_branches\", \"arguments\": ",
+            "{\"city\": \"Pune\"}}",
+            "] One ",
+            "moment please.",
+        ];
+        let mut detector = ToolCallDetector::new();
+        let mut spoken = String::new();
+        let mut detected_at = None;
+
+        for (i, token) in tokens.iter().enumerate() {
+            let (text, calls) = collect(&detector.push(token));
+            spoken.push_str(&text);
+            if !calls.is_empty() {
+                assert_eq!(calls, vec!["find_branches"]);
+                detected_at = Some(i);
+            }
+        }
+        spoken.push_str(&detector.finish());
+
+        assert_eq!(detected_at, Some(4), "Call fires on the token that closes it");
+        assert_eq!(spoken, "Let me check.  One moment please.");
+        assert!(!spoken.contains("TOOL_CALL") && !spoken.contains('{'));
+    }
+
+    #[test]
+    fn test_detector_releases_marker_lookalikes() {
+        let mut detector = ToolCallDetector::new();
+        let (text, _) = collect(&detector.push("Rates [TO"));
+        assert_eq!(text, "Rates ");
+        let (text, calls) = collect(&detector.push("P] are low"));
+        assert_eq!(text, "[TOP] are low");
+        assert!(calls.is_empty());
+    }
+
+    #[test]
+    fn test_detector_drops_unterminated_call() {
+        let mut detector = ToolCallDetector::new();
+        let (text, _) = collect(&detector.push("Sure. [TOOL_CALL: {\"name\": \"x\""));
+        assert_eq!(text, "Sure. ");
+        assert!(detector.in_tool_call());
+        assert!(detector.finish().is_empty());
+    }
+
+    #[test]
+    fn test_detector_push_event() {
+        let mut detector = ToolCallDetector::new();
+        let (text, _) = collect(&detector.push_event(&GenerationEvent::Token("Hi [".to_string())));
+        assert_eq!(text, "Hi ");
+        let (text, _) = collect(&detector.push_event(&GenerationEvent::Error("eof".to_string())));
+        assert_eq!(text, "[");
+    }
 }
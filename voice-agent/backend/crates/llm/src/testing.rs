@@ -0,0 +1,296 @@
+//! Scripted Language Model for Tests
+//!
+//! `MockLanguageModel` implements `LanguageModel` by replaying a queue of
+//! scripted responses in order, so agent paths that depend on the LLM
+//! (tool-call parsing, streaming, fallbacks) can be tested deterministically.
+//! Every request it receives is recorded for assertions.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use voice_agent_llm::testing::MockLanguageModel;
+//!
+//! let llm = Arc::new(
+//!     MockLanguageModel::new()
+//!         .with_response(r#"[TOOL_CALL: {"name": "find_locations", "arguments": {"city": "Pune"}}]"#)
+//!         .with_response("The nearest branch is on FC Road."),
+//! );
+//! let agent = DomainAgent::with_llm("session", config, llm.clone());
+//! agent.process("Where is your branch?").await?;
+//! assert_eq!(llm.prompts().len(), 2);
+//! ```
+
+use std::collections::VecDeque;
+use std::pin::Pin;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use futures::Stream;
+use parking_lot::Mutex;
+
+use voice_agent_core::{
+    llm_types::{FinishReason, TokenUsage, ToolCall},
+    Error, GenerateRequest, GenerateResponse, LanguageModel, Result, StreamChunk, ToolDefinition,
+};
+
+use crate::prompt::parse_tool_call;
+
+/// One queued reply
+#[derive(Debug, Clone)]
+enum Scripted {
+    Text(String),
+    Error(String),
+}
+
+/// Language model that replays scripted responses
+///
+/// Responses are consumed front-to-back by `generate`, `generate_stream` and
+/// `generate_with_tools` alike. Running out of responses is an error, which
+/// surfaces unexpected extra LLM calls in tests.
+pub struct MockLanguageModel {
+    responses: Mutex<VecDeque<Scripted>>,
+    prompts: Mutex<Vec<GenerateRequest>>,
+    token_delay: Duration,
+    available: bool,
+    model_name: String,
+}
+
+impl MockLanguageModel {
+    /// Create a mock with an empty response queue
+    pub fn new() -> Self {
+        Self {
+            responses: Mutex::new(VecDeque::new()),
+            prompts: Mutex::new(Vec::new()),
+            token_delay: Duration::ZERO,
+            available: true,
+            model_name: "mock-llm".to_string(),
+        }
+    }
+
+    /// Queue a text response
+    pub fn with_response(self, text: impl Into<String>) -> Self {
+        self.push_response(text);
+        self
+    }
+
+    /// Queue an error in place of a response
+    pub fn with_error(self, message: impl Into<String>) -> Self {
+        self.push_error(message);
+        self
+    }
+
+    /// Delay between streamed tokens
+    pub fn with_token_delay(mut self, delay: Duration) -> Self {
+        self.token_delay = delay;
+        self
+    }
+
+    /// Set what `is_available` reports
+    pub fn with_available(mut self, available: bool) -> Self {
+        self.available = available;
+        self
+    }
+
+    /// Set the reported model name
+    pub fn with_model_name(mut self, name: impl Into<String>) -> Self {
+        self.model_name = name.into();
+        self
+    }
+
+    /// Queue a text response on a shared mock
+    pub fn push_response(&self, text: impl Into<String>) {
+        self.responses.lock().push_back(Scripted::Text(text.into()));
+    }
+
+    /// Queue an error on a shared mock
+    pub fn push_error(&self, message: impl Into<String>) {
+        self.responses
+            .lock()
+            .push_back(Scripted::Error(message.into()));
+    }
+
+    /// Requests received so far, in call order
+    pub fn prompts(&self) -> Vec<GenerateRequest> {
+        self.prompts.lock().clone()
+    }
+
+    /// Number of scripted responses not yet consumed
+    pub fn remaining(&self) -> usize {
+        self.responses.lock().len()
+    }
+
+    /// Record the request and take the next scripted reply
+    fn next_reply(&self, request: GenerateRequest) -> Result<String> {
+        self.prompts.lock().push(request);
+        match self.responses.lock().pop_front() {
+            Some(Scripted::Text(text)) => Ok(text),
+            Some(Scripted::Error(message)) => Err(Error::Llm(message)),
+            None => Err(Error::Llm(format!(
+                "{}: no scripted response left",
+                self.model_name
+            ))),
+        }
+    }
+
+    /// Split text into whitespace-preserving tokens for streaming
+    fn tokenize(text: &str) -> Vec<String> {
+        text.split_inclusive(' ').map(str::to_string).collect()
+    }
+
+    fn usage(text: &str) -> TokenUsage {
+        TokenUsage::new(0, Self::tokenize(text).len() as u32)
+    }
+}
+
+impl Default for MockLanguageModel {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl LanguageModel for MockLanguageModel {
+    async fn generate(&self, request: GenerateRequest) -> Result<GenerateResponse> {
+        let text = self.next_reply(request)?;
+        Ok(GenerateResponse {
+            usage: Some(Self::usage(&text)),
+            ..GenerateResponse::text(text)
+        })
+    }
+
+    fn generate_stream<'a>(
+        &'a self,
+        request: GenerateRequest,
+    ) -> Pin<Box<dyn Stream<Item = Result<StreamChunk>> + Send + 'a>> {
+        let reply = self.next_reply(request);
+        let delay = self.token_delay;
+
+        Box::pin(async_stream::stream! {
+            match reply {
+                Ok(text) => {
+                    for token in Self::tokenize(&text) {
+                        if !delay.is_zero() {
+                            tokio::time::sleep(delay).await;
+                        }
+                        yield Ok(StreamChunk::text(token));
+                    }
+                    yield Ok(StreamChunk::final_chunk(FinishReason::Stop));
+                }
+                Err(e) => yield Err(e),
+            }
+        })
+    }
+
+    async fn generate_with_tools(
+        &self,
+        request: GenerateRequest,
+        _tools: &[ToolDefinition],
+    ) -> Result<GenerateResponse> {
+        let text = self.next_reply(request)?;
+
+        // Same `[TOOL_CALL: ...]` convention as `LanguageModelAdapter`'s text fallback
+        let tool_calls: Vec<ToolCall> = parse_tool_call(&text)
+            .map(|tc| ToolCall {
+                id: format!("mock-call-{}", self.prompts.lock().len()),
+                name: tc.name,
+                arguments: tc
+                    .arguments
+                    .as_object()
+                    .map(|o| o.iter().map(|(k, v)| (k.clone(), v.clone())).collect())
+                    .unwrap_or_default(),
+            })
+            .into_iter()
+            .collect();
+
+        let finish_reason = if tool_calls.is_empty() {
+            FinishReason::Stop
+        } else {
+            FinishReason::ToolCalls
+        };
+
+        Ok(GenerateResponse {
+            usage: Some(Self::usage(&text)),
+            text,
+            finish_reason,
+            tool_calls,
+        })
+    }
+
+    async fn is_available(&self) -> bool {
+        self.available
+    }
+
+    fn model_name(&self) -> &str {
+        &self.model_name
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::StreamExt;
+
+    #[tokio::test]
+    async fn test_responses_replay_in_order() {
+        let llm = MockLanguageModel::new()
+            .with_response("first")
+            .with_response("second");
+
+        let a = llm.generate(GenerateRequest::new("sys").with_user_message("one")).await;
+        let b = llm.generate(GenerateRequest::new("sys").with_user_message("two")).await;
+
+        assert_eq!(a.unwrap().text, "first");
+        assert_eq!(b.unwrap().text, "second");
+        assert!(llm.generate(GenerateRequest::new("sys")).await.is_err());
+
+        let prompts = llm.prompts();
+        assert_eq!(prompts.len(), 3);
+        assert_eq!(prompts[0].messages.last().unwrap().content, "one");
+        assert_eq!(prompts[1].messages.last().unwrap().content, "two");
+    }
+
+    #[tokio::test]
+    async fn test_injected_error() {
+        let llm = MockLanguageModel::new()
+            .with_error("backend down")
+            .with_response("recovered");
+
+        let err = llm.generate(GenerateRequest::new("sys")).await.unwrap_err();
+        assert!(err.to_string().contains("backend down"));
+        assert_eq!(llm.generate(GenerateRequest::new("sys")).await.unwrap().text, "recovered");
+    }
+
+    #[tokio::test]
+    async fn test_stream_emits_tokens_in_order() {
+        let llm = MockLanguageModel::new()
+            .with_response("Gold loans are quick.")
+            .with_token_delay(Duration::from_millis(1));
+
+        let chunks: Vec<StreamChunk> = llm
+            .generate_stream(GenerateRequest::new("sys"))
+            .map(|c| c.unwrap())
+            .collect()
+            .await;
+
+        let deltas: Vec<&str> = chunks.iter().map(|c| c.delta.as_str()).collect();
+        assert_eq!(deltas, vec!["Gold ", "loans ", "are ", "quick.", ""]);
+        assert!(chunks.last().unwrap().is_final);
+    }
+
+    #[tokio::test]
+    async fn test_generate_with_tools_parses_tool_call() {
+        let llm = MockLanguageModel::new().with_response(
+            r#"Let me check. [TOOL_CALL: {"name": "find_locations", "arguments": {"city": "Pune"}}]"#,
+        );
+
+        let response = llm
+            .generate_with_tools(GenerateRequest::new("sys"), &[])
+            .await
+            .unwrap();
+
+        assert_eq!(response.finish_reason, FinishReason::ToolCalls);
+        assert_eq!(response.tool_calls.len(), 1);
+        assert_eq!(response.tool_calls[0].name, "find_locations");
+        assert_eq!(response.tool_calls[0].arguments["city"], "Pune");
+    }
+}
@@ -0,0 +1,352 @@
+//! Provider Tool-Calling Formats
+//!
+//! Each provider expects tool definitions and returns tool calls in its own
+//! shape. `ToolCallFormat` translates the crate's canonical `ToolDefinition`
+//! into the provider's native request JSON and parses the provider's native
+//! tool-call response back into `ParsedToolCall`, so tool handling above the
+//! backends is provider-independent. Backends report their format through
+//! `LlmBackend::tool_call_format`; `LanguageModelAdapter` uses the native path
+//! for every format except `Text`, which falls back to prompt injection.
+//!
+//! | Format | Tool definition | Tool call in response |
+//! |--------|-----------------|-----------------------|
+//! | OpenAI | `{"type": "function", "function": {name, description, parameters}}` | `message.tool_calls[].function` with `arguments` as a JSON string |
+//! | Claude | `{name, description, input_schema}` | `content[]` blocks of `"type": "tool_use"` |
+//! | Ollama | same as OpenAI | `message.tool_calls[].function` with `arguments` as an object |
+//! | Text   | injected into the system prompt | `[TOOL_CALL: {...}]` in the reply text |
+
+use serde_json::{json, Value};
+
+use crate::factory::LlmProvider;
+use crate::prompt::{
+    repair_json, try_parse_tool_call, ParsedToolCall, ToolCallParseError, ToolDefinition,
+};
+
+/// Tool-calling wire format
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ToolCallFormat {
+    /// OpenAI / Azure OpenAI function calling
+    OpenAI,
+    /// Anthropic tool_use blocks
+    Claude,
+    /// Ollama native `tools` field
+    Ollama,
+    /// `[TOOL_CALL: ...]` markers in plain text (models without native tools)
+    Text,
+}
+
+impl ToolCallFormat {
+    /// Native format for a provider
+    pub fn for_provider(provider: LlmProvider) -> Self {
+        match provider {
+            LlmProvider::Claude => ToolCallFormat::Claude,
+            LlmProvider::OpenAI | LlmProvider::AzureOpenAI => ToolCallFormat::OpenAI,
+            LlmProvider::Ollama => ToolCallFormat::Ollama,
+        }
+    }
+
+    /// Serialize one tool definition to the provider's request shape
+    ///
+    /// The text format has no request field; it returns the canonical
+    /// definition, which `PromptBuilder::with_tools` renders into the prompt.
+    pub fn serialize_tool(&self, tool: &ToolDefinition) -> Value {
+        match self {
+            ToolCallFormat::OpenAI | ToolCallFormat::Ollama => json!({
+                "type": "function",
+                "function": {
+                    "name": tool.name,
+                    "description": tool.description,
+                    "parameters": tool.parameters,
+                }
+            }),
+            ToolCallFormat::Claude => json!({
+                "name": tool.name,
+                "description": tool.description,
+                "input_schema": tool.parameters,
+            }),
+            ToolCallFormat::Text => json!({
+                "name": tool.name,
+                "description": tool.description,
+                "parameters": tool.parameters,
+            }),
+        }
+    }
+
+    /// Serialize tool definitions for the request's `tools` field
+    pub fn serialize_tools(&self, tools: &[ToolDefinition]) -> Value {
+        Value::Array(tools.iter().map(|t| self.serialize_tool(t)).collect())
+    }
+
+    /// Parse the tool calls out of a provider response body
+    ///
+    /// Accepts the full response or just its message object. A response
+    /// without tool calls yields an empty list; for the text format the
+    /// response is the reply string.
+    pub fn parse_tool_calls(
+        &self,
+        response: &Value,
+    ) -> Result<Vec<ParsedToolCall>, ToolCallParseError> {
+        match self {
+            ToolCallFormat::OpenAI | ToolCallFormat::Ollama => parse_function_calls(response),
+            ToolCallFormat::Claude => parse_tool_use_blocks(response),
+            ToolCallFormat::Text => {
+                let text = response.as_str().unwrap_or_default();
+                match try_parse_tool_call(text) {
+                    Ok(call) => Ok(vec![call]),
+                    Err(ToolCallParseError::NoToolCall) => Ok(Vec::new()),
+                    Err(e) => Err(e),
+                }
+            },
+        }
+    }
+}
+
+/// OpenAI and Ollama: `message.tool_calls[].function`
+fn parse_function_calls(response: &Value) -> Result<Vec<ParsedToolCall>, ToolCallParseError> {
+    let message = response
+        .pointer("/choices/0/message")
+        .or_else(|| response.get("message"))
+        .unwrap_or(response);
+    let text = message
+        .get("content")
+        .and_then(|c| c.as_str())
+        .unwrap_or_default()
+        .trim()
+        .to_string();
+    let Some(calls) = message.get("tool_calls").and_then(|c| c.as_array()) else {
+        return Ok(Vec::new());
+    };
+
+    calls
+        .iter()
+        .map(|call| {
+            let function = call.get("function").unwrap_or(call);
+            let name = function
+                .get("name")
+                .and_then(|n| n.as_str())
+                .filter(|n| !n.is_empty())
+                .ok_or(ToolCallParseError::MissingName)?;
+            // OpenAI sends arguments as a JSON string, Ollama as an object
+            let arguments = match function.get("arguments") {
+                Some(Value::String(raw)) => parse_argument_string(raw)?,
+                Some(value @ Value::Object(_)) => value.clone(),
+                Some(Value::Null) | None => json!({}),
+                Some(other) => {
+                    return Err(ToolCallParseError::InvalidJson(format!(
+                        "arguments is not an object: {}",
+                        other
+                    )))
+                },
+            };
+
+            Ok(ParsedToolCall {
+                id: call.get("id").and_then(|i| i.as_str()).map(str::to_string),
+                name: name.to_string(),
+                arguments,
+                text_before: text.clone(),
+                text_after: String::new(),
+            })
+        })
+        .collect()
+}
+
+/// Claude: `content[]` blocks with `"type": "tool_use"`
+fn parse_tool_use_blocks(response: &Value) -> Result<Vec<ParsedToolCall>, ToolCallParseError> {
+    let Some(blocks) = response.get("content").and_then(|c| c.as_array()) else {
+        return Ok(Vec::new());
+    };
+    let text = blocks
+        .iter()
+        .filter(|b| b.get("type").and_then(|t| t.as_str()) == Some("text"))
+        .filter_map(|b| b.get("text").and_then(|t| t.as_str()))
+        .collect::<Vec<_>>()
+        .join("")
+        .trim()
+        .to_string();
+
+    blocks
+        .iter()
+        .filter(|b| b.get("type").and_then(|t| t.as_str()) == Some("tool_use"))
+        .map(|block| {
+            let name = block
+                .get("name")
+                .and_then(|n| n.as_str())
+                .filter(|n| !n.is_empty())
+                .ok_or(ToolCallParseError::MissingName)?;
+            Ok(ParsedToolCall {
+                id: block.get("id").and_then(|i| i.as_str()).map(str::to_string),
+                name: name.to_string(),
+                arguments: block.get("input").cloned().unwrap_or_else(|| json!({})),
+                text_before: text.clone(),
+                text_after: String::new(),
+            })
+        })
+        .collect()
+}
+
+/// Parse a stringified arguments object, repairing it if needed
+fn parse_argument_string(raw: &str) -> Result<Value, ToolCallParseError> {
+    if raw.trim().is_empty() {
+        return Ok(json!({}));
+    }
+    let value: Value = serde_json::from_str(raw)
+        .or_else(|_| serde_json::from_str(&repair_json(raw)))
+        .map_err(|e| ToolCallParseError::InvalidJson(e.to_string()))?;
+    if value.is_object() {
+        Ok(value)
+    } else {
+        Err(ToolCallParseError::InvalidJson(format!(
+            "arguments is not an object: {}",
+            value
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prompt::ToolBuilder;
+
+    fn branch_tool() -> ToolDefinition {
+        ToolBuilder::new("find_branches", "Find nearby branches")
+            .param("city", "string", "City name", true)
+            .build()
+    }
+
+    #[test]
+    fn test_provider_formats() {
+        assert_eq!(
+            LlmProvider::Claude.tool_call_format(),
+            ToolCallFormat::Claude
+        );
+        assert_eq!(
+            LlmProvider::OpenAI.tool_call_format(),
+            ToolCallFormat::OpenAI
+        );
+        assert_eq!(
+            LlmProvider::AzureOpenAI.tool_call_format(),
+            ToolCallFormat::OpenAI
+        );
+        assert_eq!(
+            LlmProvider::Ollama.tool_call_format(),
+            ToolCallFormat::Ollama
+        );
+    }
+
+    #[test]
+    fn test_serialize_openai_and_ollama() {
+        let tool = branch_tool();
+        for format in [ToolCallFormat::OpenAI, ToolCallFormat::Ollama] {
+            let value = format.serialize_tool(&tool);
+            assert_eq!(value["type"], "function");
+            assert_eq!(value["function"]["name"], "find_branches");
+            assert_eq!(value["function"]["description"], "Find nearby branches");
+            assert_eq!(value["function"]["parameters"], tool.parameters);
+        }
+    }
+
+    #[test]
+    fn test_serialize_claude() {
+        let tool = branch_tool();
+        let tools = ToolCallFormat::Claude.serialize_tools(std::slice::from_ref(&tool));
+        let value = &tools[0];
+        assert_eq!(value["name"], "find_branches");
+        assert_eq!(value["input_schema"], tool.parameters);
+        assert!(value.get("type").is_none());
+        assert!(value.get("parameters").is_none());
+    }
+
+    #[test]
+    fn test_parse_openai_tool_calls() {
+        let response = json!({
+            "choices": [{
+                "message": {
+                    "role": "assistant",
+                    "content": null,
+                    "tool_calls": [{
+                        "id": "call_abc",
+                        "type": "function",
+                        "function": {
+                            "name": "find_branches",
+                            "arguments": "{\"city\": \"Pune\"}"
+                        }
+                    }]
+                }
+            }]
+        });
+
+        let calls = ToolCallFormat::OpenAI.parse_tool_calls(&response).unwrap();
+        assert_eq!(calls.len(), 1);
+        assert_eq!(calls[0].id.as_deref(), Some("call_abc"));
+        assert_eq!(calls[0].name, "find_branches");
+        assert_eq!(calls[0].arguments["city"], "Pune");
+    }
+
+    #[test]
+    fn test_parse_claude_tool_use() {
+        let response = json!({
+            "content": [
+                {"type": "text", "text": "Let me look that up."},
+                {"type": "tool_use", "id": "toolu_01", "name": "find_branches", "input": {"city": "Pune"}}
+            ],
+            "stop_reason": "tool_use"
+        });
+
+        let calls = ToolCallFormat::Claude.parse_tool_calls(&response).unwrap();
+        assert_eq!(calls.len(), 1);
+        assert_eq!(calls[0].id.as_deref(), Some("toolu_01"));
+        assert_eq!(calls[0].arguments["city"], "Pune");
+        assert_eq!(calls[0].text_before, "Let me look that up.");
+    }
+
+    #[test]
+    fn test_parse_ollama_tool_calls() {
+        let response = json!({
+            "model": "qwen2.5",
+            "message": {
+                "role": "assistant",
+                "content": "",
+                "tool_calls": [{
+                    "function": {"name": "find_branches", "arguments": {"city": "Pune"}}
+                }]
+            },
+            "done": true
+        });
+
+        let calls = ToolCallFormat::Ollama.parse_tool_calls(&response).unwrap();
+        assert_eq!(calls.len(), 1);
+        assert!(calls[0].id.is_none());
+        assert_eq!(calls[0].arguments["city"], "Pune");
+    }
+
+    #[test]
+    fn test_parse_text_tool_call() {
+        let response =
+            json!(r#"[TOOL_CALL: {"name": "find_branches", "arguments": {"city": "Pune"}}]"#);
+        let calls = ToolCallFormat::Text.parse_tool_calls(&response).unwrap();
+        assert_eq!(calls[0].name, "find_branches");
+
+        let plain = json!("Hello! How can I help?");
+        assert!(ToolCallFormat::Text
+            .parse_tool_calls(&plain)
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_parse_without_tool_calls_or_name() {
+        let response = json!({"message": {"role": "assistant", "content": "Hi"}});
+        assert!(ToolCallFormat::OpenAI
+            .parse_tool_calls(&response)
+            .unwrap()
+            .is_empty());
+
+        let nameless = json!({"message": {"tool_calls": [{"function": {"arguments": "{}"}}]}});
+        assert_eq!(
+            ToolCallFormat::Ollama
+                .parse_tool_calls(&nameless)
+                .unwrap_err(),
+            ToolCallParseError::MissingName
+        );
+    }
+}
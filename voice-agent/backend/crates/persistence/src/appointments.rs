@@ -4,6 +4,7 @@ use crate::{PersistenceError, ScyllaClient};
 use async_trait::async_trait;
 use chrono::{DateTime, NaiveDate, Utc};
 use serde::{Deserialize, Serialize};
+use tokio::sync::Mutex;
 use uuid::Uuid;
 
 /// Appointment status
@@ -12,6 +13,8 @@ use uuid::Uuid;
 pub enum AppointmentStatus {
     Scheduled,
     Confirmed,
+    /// Moved to a new date or time
+    Rescheduled,
     Cancelled,
     Completed,
     NoShow,
@@ -22,6 +25,7 @@ impl AppointmentStatus {
         match self {
             Self::Scheduled => "scheduled",
             Self::Confirmed => "confirmed",
+            Self::Rescheduled => "rescheduled",
             Self::Cancelled => "cancelled",
             Self::Completed => "completed",
             Self::NoShow => "no_show",
@@ -32,6 +36,7 @@ impl AppointmentStatus {
         match s {
             "scheduled" => Self::Scheduled,
             "confirmed" => Self::Confirmed,
+            "rescheduled" => Self::Rescheduled,
             "cancelled" => Self::Cancelled,
             "completed" => Self::Completed,
             "no_show" => Self::NoShow,
@@ -86,6 +91,27 @@ impl Appointment {
             notes: None,
         }
     }
+
+    /// Whether the appointment still holds its slot
+    pub fn is_active(&self) -> bool {
+        matches!(
+            self.status,
+            AppointmentStatus::Scheduled
+                | AppointmentStatus::Confirmed
+                | AppointmentStatus::Rescheduled
+        )
+    }
+
+    /// Copy with the customer's identity removed, keyed under `pseudonym`
+    pub fn anonymized(&self, pseudonym: &str) -> Self {
+        Self {
+            customer_phone: pseudonym.to_string(),
+            customer_name: None,
+            notes: None,
+            updated_at: Utc::now(),
+            ..self.clone()
+        }
+    }
 }
 
 /// Appointment store trait
@@ -97,6 +123,8 @@ pub trait AppointmentStore: Send + Sync {
         phone: &str,
         appointment_id: Uuid,
     ) -> Result<Option<Appointment>, PersistenceError>;
+    /// Look an appointment up by ID alone, whoever booked it
+    async fn find(&self, appointment_id: Uuid) -> Result<Option<Appointment>, PersistenceError>;
     async fn update_status(
         &self,
         phone: &str,
@@ -109,15 +137,179 @@ pub trait AppointmentStore: Send + Sync {
         appointment_id: Uuid,
         sms_id: Uuid,
     ) -> Result<(), PersistenceError>;
+    /// Move an appointment to a new slot and mark it rescheduled
+    ///
+    /// The store doesn't track branch capacity; callers check the new slot
+    /// is available with the calendar first.
+    async fn reschedule(
+        &self,
+        phone: &str,
+        appointment_id: Uuid,
+        new_date: NaiveDate,
+        new_time: &str,
+    ) -> Result<(), PersistenceError>;
+    /// Cancel an appointment
+    async fn cancel(&self, phone: &str, appointment_id: Uuid) -> Result<(), PersistenceError> {
+        self.update_status(phone, appointment_id, AppointmentStatus::Cancelled)
+            .await
+    }
     async fn list_for_customer(
         &self,
         phone: &str,
         limit: i32,
     ) -> Result<Vec<Appointment>, PersistenceError>;
     async fn list_for_date(&self, date: NaiveDate) -> Result<Vec<Appointment>, PersistenceError>;
+    /// Remove a customer's identity from their appointments
+    ///
+    /// The appointments stay, re-keyed under `pseudonym` with the name and
+    /// notes cleared, so branch and slot counts still add up. Returns how
+    /// many were anonymized.
+    async fn anonymize_customer(
+        &self,
+        phone: &str,
+        pseudonym: &str,
+    ) -> Result<usize, PersistenceError>;
+}
+
+/// In-process appointment store, for tests and development
+#[derive(Default)]
+pub struct InMemoryAppointmentStore {
+    appointments: Mutex<Vec<Appointment>>,
+}
+
+impl InMemoryAppointmentStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl AppointmentStore for InMemoryAppointmentStore {
+    async fn create(&self, appointment: &Appointment) -> Result<(), PersistenceError> {
+        self.appointments.lock().await.push(appointment.clone());
+        Ok(())
+    }
+
+    async fn get(
+        &self,
+        phone: &str,
+        appointment_id: Uuid,
+    ) -> Result<Option<Appointment>, PersistenceError> {
+        let appointments = self.appointments.lock().await;
+        Ok(appointments
+            .iter()
+            .find(|a| a.customer_phone == phone && a.appointment_id == appointment_id)
+            .cloned())
+    }
+
+    async fn find(&self, appointment_id: Uuid) -> Result<Option<Appointment>, PersistenceError> {
+        let appointments = self.appointments.lock().await;
+        Ok(appointments
+            .iter()
+            .find(|a| a.appointment_id == appointment_id)
+            .cloned())
+    }
+
+    async fn update_status(
+        &self,
+        phone: &str,
+        appointment_id: Uuid,
+        status: AppointmentStatus,
+    ) -> Result<(), PersistenceError> {
+        let mut appointments = self.appointments.lock().await;
+        if let Some(stored) = appointments
+            .iter_mut()
+            .find(|a| a.customer_phone == phone && a.appointment_id == appointment_id)
+        {
+            stored.status = status;
+            stored.updated_at = Utc::now();
+        }
+        Ok(())
+    }
+
+    async fn set_confirmation_sms(
+        &self,
+        phone: &str,
+        appointment_id: Uuid,
+        sms_id: Uuid,
+    ) -> Result<(), PersistenceError> {
+        let mut appointments = self.appointments.lock().await;
+        if let Some(stored) = appointments
+            .iter_mut()
+            .find(|a| a.customer_phone == phone && a.appointment_id == appointment_id)
+        {
+            stored.confirmation_sms_id = Some(sms_id);
+            stored.updated_at = Utc::now();
+        }
+        Ok(())
+    }
+
+    async fn reschedule(
+        &self,
+        phone: &str,
+        appointment_id: Uuid,
+        new_date: NaiveDate,
+        new_time: &str,
+    ) -> Result<(), PersistenceError> {
+        let mut appointments = self.appointments.lock().await;
+        if let Some(stored) = appointments
+            .iter_mut()
+            .find(|a| a.customer_phone == phone && a.appointment_id == appointment_id)
+        {
+            stored.appointment_date = new_date;
+            stored.appointment_time = new_time.to_string();
+            stored.status = AppointmentStatus::Rescheduled;
+            stored.updated_at = Utc::now();
+        }
+        Ok(())
+    }
+
+    async fn list_for_customer(
+        &self,
+        phone: &str,
+        limit: i32,
+    ) -> Result<Vec<Appointment>, PersistenceError> {
+        let appointments = self.appointments.lock().await;
+        Ok(appointments
+            .iter()
+            .filter(|a| a.customer_phone == phone)
+            .take(limit.max(0) as usize)
+            .cloned()
+            .collect())
+    }
+
+    async fn list_for_date(&self, date: NaiveDate) -> Result<Vec<Appointment>, PersistenceError> {
+        let appointments = self.appointments.lock().await;
+        Ok(appointments
+            .iter()
+            .filter(|a| a.appointment_date == date)
+            .cloned()
+            .collect())
+    }
+
+    async fn anonymize_customer(
+        &self,
+        phone: &str,
+        pseudonym: &str,
+    ) -> Result<usize, PersistenceError> {
+        let mut appointments = self.appointments.lock().await;
+        let mut count = 0;
+        for stored in appointments
+            .iter_mut()
+            .filter(|a| a.customer_phone == phone)
+        {
+            *stored = stored.anonymized(pseudonym);
+            count += 1;
+        }
+        Ok(count)
+    }
 }
 
 /// ScyllaDB implementation of appointment store
+///
+/// Appointments are partitioned by the customer's number. Lookup tables by
+/// appointment ID and by date find them without it, for changing a booking
+/// by its ID and counting a day's bookings against branch capacity.
 #[derive(Clone)]
 pub struct ScyllaAppointmentStore {
     client: ScyllaClient,
@@ -143,8 +335,7 @@ impl AppointmentStore for ScyllaAppointmentStore {
         );
 
         self.client
-            .session()
-            .query_unpaged(
+            .query(
                 query,
                 (
                     &appointment.customer_phone,
@@ -164,6 +355,7 @@ impl AppointmentStore for ScyllaAppointmentStore {
                 ),
             )
             .await?;
+        self.index(appointment).await?;
 
         tracing::info!(
             appointment_id = %appointment.appointment_id,
@@ -192,8 +384,7 @@ impl AppointmentStore for ScyllaAppointmentStore {
 
         let result = self
             .client
-            .session()
-            .query_unpaged(query, (phone, appointment_id))
+            .query_idempotent(query, (phone, appointment_id))
             .await?;
 
         if let Some(rows) = result.rows {
@@ -205,6 +396,25 @@ impl AppointmentStore for ScyllaAppointmentStore {
         Ok(None)
     }
 
+    async fn find(&self, appointment_id: Uuid) -> Result<Option<Appointment>, PersistenceError> {
+        let query = format!(
+            "SELECT customer_phone FROM {}.appointments_by_id WHERE appointment_id = ?",
+            self.client.keyspace()
+        );
+        let result = self
+            .client
+            .query_idempotent(query, (appointment_id,))
+            .await?;
+        let phone = result
+            .rows
+            .and_then(|rows| rows.into_iter().next())
+            .and_then(|row| row.into_typed::<(String,)>().ok());
+        match phone {
+            Some((phone,)) => self.get(&phone, appointment_id).await,
+            None => Ok(None),
+        }
+    }
+
     async fn update_status(
         &self,
         phone: &str,
@@ -218,8 +428,7 @@ impl AppointmentStore for ScyllaAppointmentStore {
         );
 
         self.client
-            .session()
-            .query_unpaged(
+            .query(
                 query,
                 (
                     status.as_str(),
@@ -252,8 +461,7 @@ impl AppointmentStore for ScyllaAppointmentStore {
         );
 
         self.client
-            .session()
-            .query_unpaged(
+            .query(
                 query,
                 (sms_id, Utc::now().timestamp_millis(), phone, appointment_id),
             )
@@ -262,6 +470,68 @@ impl AppointmentStore for ScyllaAppointmentStore {
         Ok(())
     }
 
+    async fn reschedule(
+        &self,
+        phone: &str,
+        appointment_id: Uuid,
+        new_date: NaiveDate,
+        new_time: &str,
+    ) -> Result<(), PersistenceError> {
+        let Some(current) = self.get(phone, appointment_id).await? else {
+            return Err(PersistenceError::InvalidData(format!(
+                "No appointment {}",
+                appointment_id
+            )));
+        };
+        let query = format!(
+            "UPDATE {}.appointments
+             SET appointment_date = ?, appointment_time = ?, status = ?, updated_at = ?
+             WHERE customer_phone = ? AND appointment_id = ?",
+            self.client.keyspace()
+        );
+
+        self.client
+            .query(
+                query,
+                (
+                    new_date.to_string(),
+                    new_time,
+                    AppointmentStatus::Rescheduled.as_str(),
+                    Utc::now().timestamp_millis(),
+                    phone,
+                    appointment_id,
+                ),
+            )
+            .await?;
+
+        // Move the date lookup row with it
+        let query = format!(
+            "DELETE FROM {}.appointments_by_date
+             WHERE appointment_date = ? AND appointment_id = ?",
+            self.client.keyspace()
+        );
+        self.client
+            .query(
+                query,
+                (current.appointment_date.to_string(), appointment_id),
+            )
+            .await?;
+        self.index(&Appointment {
+            appointment_date: new_date,
+            ..current
+        })
+        .await?;
+
+        tracing::info!(
+            appointment_id = %appointment_id,
+            date = %new_date,
+            time = %new_time,
+            "Appointment rescheduled"
+        );
+
+        Ok(())
+    }
+
     async fn list_for_customer(
         &self,
         phone: &str,
@@ -276,11 +546,7 @@ impl AppointmentStore for ScyllaAppointmentStore {
             self.client.keyspace()
         );
 
-        let result = self
-            .client
-            .session()
-            .query_unpaged(query, (phone, limit))
-            .await?;
+        let result = self.client.query_idempotent(query, (phone, limit)).await?;
 
         let mut appointments = Vec::new();
         if let Some(rows) = result.rows {
@@ -292,15 +558,92 @@ impl AppointmentStore for ScyllaAppointmentStore {
         Ok(appointments)
     }
 
-    async fn list_for_date(&self, _date: NaiveDate) -> Result<Vec<Appointment>, PersistenceError> {
-        // Note: This would require a secondary index or materialized view in production
-        // For now, return empty - would need ALLOW FILTERING or different partition key
-        tracing::warn!("list_for_date requires secondary index - returning empty");
-        Ok(Vec::new())
+    async fn list_for_date(&self, date: NaiveDate) -> Result<Vec<Appointment>, PersistenceError> {
+        let query = format!(
+            "SELECT customer_phone, appointment_id
+             FROM {}.appointments_by_date WHERE appointment_date = ?",
+            self.client.keyspace()
+        );
+        let result = self
+            .client
+            .query_idempotent(query, (date.to_string(),))
+            .await?;
+
+        let mut appointments = Vec::new();
+        for row in result.rows.unwrap_or_default() {
+            let (phone, appointment_id): (String, Uuid) = row
+                .into_typed()
+                .map_err(|e| PersistenceError::InvalidData(e.to_string()))?;
+            if let Some(appointment) = self.get(&phone, appointment_id).await? {
+                // Skip a lookup row left behind by an interrupted reschedule
+                if appointment.appointment_date == date {
+                    appointments.push(appointment);
+                }
+            }
+        }
+        Ok(appointments)
+    }
+
+    async fn anonymize_customer(
+        &self,
+        phone: &str,
+        pseudonym: &str,
+    ) -> Result<usize, PersistenceError> {
+        // The phone is the partition key, so each appointment is copied
+        // under the pseudonym and the customer's partition dropped
+        let appointments = self.list_for_customer(phone, i32::MAX).await?;
+        for appointment in &appointments {
+            self.create(&appointment.anonymized(pseudonym)).await?;
+        }
+
+        let query = format!(
+            "DELETE FROM {}.appointments WHERE customer_phone = ?",
+            self.client.keyspace()
+        );
+        self.client.query(query, (phone,)).await?;
+
+        tracing::info!(
+            count = appointments.len(),
+            pseudonym = %pseudonym,
+            "Customer's appointments anonymized"
+        );
+
+        Ok(appointments.len())
     }
 }
 
 impl ScyllaAppointmentStore {
+    /// Write the lookup rows by ID and by date
+    async fn index(&self, appointment: &Appointment) -> Result<(), PersistenceError> {
+        let query = format!(
+            "INSERT INTO {}.appointments_by_id (appointment_id, customer_phone) VALUES (?, ?)",
+            self.client.keyspace()
+        );
+        self.client
+            .query(
+                query,
+                (appointment.appointment_id, &appointment.customer_phone),
+            )
+            .await?;
+
+        let query = format!(
+            "INSERT INTO {}.appointments_by_date (appointment_date, appointment_id, customer_phone)
+             VALUES (?, ?, ?)",
+            self.client.keyspace()
+        );
+        self.client
+            .query(
+                query,
+                (
+                    appointment.appointment_date.to_string(),
+                    appointment.appointment_id,
+                    &appointment.customer_phone,
+                ),
+            )
+            .await?;
+        Ok(())
+    }
+
     fn row_to_appointment(
         &self,
         row: scylla::frame::response::result::Row,
@@ -387,5 +730,9 @@ mod tests {
             AppointmentStatus::Confirmed
         );
         assert_eq!(AppointmentStatus::Confirmed.as_str(), "confirmed");
+        assert_eq!(
+            AppointmentStatus::from_str(AppointmentStatus::Rescheduled.as_str()),
+            AppointmentStatus::Rescheduled
+        );
     }
 }
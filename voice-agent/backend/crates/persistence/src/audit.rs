@@ -12,10 +12,14 @@
 
 use crate::{PersistenceError, ScyllaClient};
 use async_trait::async_trait;
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveDate, Utc};
+use hmac::{Hmac, Mac};
 use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
+use std::borrow::Cow;
+use std::fmt;
 use uuid::Uuid;
+use voice_agent_core::{normalize_phone, ComplianceViolation, ViolationCategory};
 
 /// Audit event types for compliance tracking
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -23,6 +27,8 @@ use uuid::Uuid;
 pub enum AuditEventType {
     /// AI disclosure was given to customer
     AiDisclosureGiven,
+    /// A step of the call's opening script was spoken
+    OpeningStepGiven,
     /// Recording consent was obtained
     RecordingConsentObtained,
     /// Recording consent was denied
@@ -51,12 +57,19 @@ pub enum AuditEventType {
     StageTransition,
     /// Data was exported
     DataExported,
+    /// A customer's data was erased
+    DataErased,
+    /// Session was bucketed into an experiment variant
+    ExperimentAssigned,
+    /// Caller input tried to override the agent's instructions
+    PromptInjectionDetected,
 }
 
 impl AuditEventType {
     pub fn as_str(&self) -> &'static str {
         match self {
             Self::AiDisclosureGiven => "ai_disclosure_given",
+            Self::OpeningStepGiven => "opening_step_given",
             Self::RecordingConsentObtained => "recording_consent_obtained",
             Self::RecordingConsentDenied => "recording_consent_denied",
             Self::PiiConsentObtained => "pii_consent_obtained",
@@ -71,12 +84,16 @@ impl AuditEventType {
             Self::ToolExecuted => "tool_executed",
             Self::StageTransition => "stage_transition",
             Self::DataExported => "data_exported",
+            Self::DataErased => "data_erased",
+            Self::ExperimentAssigned => "experiment_assigned",
+            Self::PromptInjectionDetected => "prompt_injection_detected",
         }
     }
 
     pub fn from_str(s: &str) -> Self {
         match s {
             "ai_disclosure_given" => Self::AiDisclosureGiven,
+            "opening_step_given" => Self::OpeningStepGiven,
             "recording_consent_obtained" => Self::RecordingConsentObtained,
             "recording_consent_denied" => Self::RecordingConsentDenied,
             "pii_consent_obtained" => Self::PiiConsentObtained,
@@ -91,6 +108,9 @@ impl AuditEventType {
             "tool_executed" => Self::ToolExecuted,
             "stage_transition" => Self::StageTransition,
             "data_exported" => Self::DataExported,
+            "data_erased" => Self::DataErased,
+            "experiment_assigned" => Self::ExperimentAssigned,
+            "prompt_injection_detected" => Self::PromptInjectionDetected,
             _ => Self::ComplianceCheckPerformed, // Default
         }
     }
@@ -147,6 +167,15 @@ impl Actor {
         }
     }
 
+    /// The system acting within a session (chained with that session)
+    pub fn system_for(session_id: &str) -> Self {
+        Self {
+            actor_type: "system".to_string(),
+            actor_id: "voice-agent".to_string(),
+            session_id: Some(session_id.to_string()),
+        }
+    }
+
     pub fn agent(session_id: &str) -> Self {
         Self {
             actor_type: "agent".to_string(),
@@ -162,6 +191,15 @@ impl Actor {
             session_id: Some(session_id.to_string()),
         }
     }
+
+    /// An operator acting through the admin API (on the resource's system chain)
+    pub fn admin(actor_id: &str) -> Self {
+        Self {
+            actor_type: "admin".to_string(),
+            actor_id: actor_id.to_string(),
+            session_id: None,
+        }
+    }
 }
 
 /// Audit log entry with merkle chain linking
@@ -204,7 +242,9 @@ impl AuditEntry {
         previous_hash: impl Into<String>,
     ) -> Self {
         let id = Uuid::new_v4();
-        let timestamp = Utc::now();
+        // Millisecond precision, matching storage, so stored entries re-hash the same
+        let now = Utc::now();
+        let timestamp = DateTime::from_timestamp_millis(now.timestamp_millis()).unwrap_or(now);
         let resource_type = resource_type.into();
         let resource_id = resource_id.into();
         let action = action.into();
@@ -293,49 +333,307 @@ impl AuditEntry {
     pub fn verify_chain(&self, expected_previous: &str) -> bool {
         self.previous_hash == expected_previous && self.verify()
     }
+
+    /// Link this entry after `previous_hash`, recomputing its hash
+    pub fn link(&mut self, previous_hash: impl Into<String>) {
+        self.previous_hash = previous_hash.into();
+        self.hash = Self::compute_hash(
+            &self.id,
+            &self.timestamp,
+            &self.event_type,
+            &self.actor,
+            &self.resource_type,
+            &self.resource_id,
+            &self.action,
+            &self.outcome,
+            &self.details,
+            &self.previous_hash,
+        );
+    }
+
+    /// Chain this entry belongs to
+    ///
+    /// Its session's, or for entries outside any session (admin actions
+    /// such as data exports) a system chain per resource, so unrelated
+    /// session-less entries don't all contend for one chain head.
+    pub fn chain_id(&self) -> Cow<'_, str> {
+        match self.actor.session_id.as_deref() {
+            Some(session_id) => Cow::Borrowed(session_id),
+            None => Cow::Owned(format!(
+                "{}:{}:{}",
+                SYSTEM_CHAIN, self.resource_type, self.resource_id
+            )),
+        }
+    }
+
+    fn partition_date(&self) -> String {
+        self.timestamp.format("%Y-%m-%d").to_string()
+    }
+}
+
+/// Where and how a session's audit chain is broken
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChainBreak {
+    /// Position in the chain (0 = first entry after genesis)
+    pub position: usize,
+    /// Entry at which the break was found (None if entries are missing)
+    pub entry_id: Option<Uuid>,
+    pub kind: ChainBreakKind,
+}
+
+/// Kind of chain break
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChainBreakKind {
+    /// The entry's content no longer matches its hash
+    HashMismatch,
+    /// No entry links to the previous one: an entry was altered and
+    /// re-hashed, removed, or inserted
+    BrokenLink,
+    /// The chain head points past the last stored entry
+    MissingEntry,
+}
+
+/// Walk a chain from the genesis hash by following each entry's link
+///
+/// Entries may be given in any order; concurrent writers can produce
+/// timestamps that disagree with chain order, so links decide the order.
+/// Returns the first break, or None if every entry is on an intact chain.
+pub fn check_chain(entries: &[AuditEntry]) -> Option<ChainBreak> {
+    let mut remaining: Vec<&AuditEntry> = entries.iter().collect();
+    remaining.sort_by_key(|e| (e.timestamp.timestamp_millis(), e.id));
+
+    let mut expected_previous = ScyllaAuditLog::genesis_hash();
+    let mut position = 0;
+    while !remaining.is_empty() {
+        let Some(index) = remaining
+            .iter()
+            .position(|e| e.previous_hash == expected_previous)
+        else {
+            return Some(ChainBreak {
+                position,
+                entry_id: Some(remaining[0].id),
+                kind: ChainBreakKind::BrokenLink,
+            });
+        };
+
+        let entry = remaining.remove(index);
+        if !entry.verify() {
+            return Some(ChainBreak {
+                position,
+                entry_id: Some(entry.id),
+                kind: ChainBreakKind::HashMismatch,
+            });
+        }
+        expected_previous = entry.hash.clone();
+        position += 1;
+    }
+    None
 }
 
+/// Page size when a query sets no limit
+const DEFAULT_PAGE_SIZE: usize = 100;
+/// Largest page a single query may return
+const MAX_PAGE_SIZE: usize = 1000;
+/// Days scanned back from `to` when a query has no start time
+const DEFAULT_QUERY_WINDOW_DAYS: i64 = 7;
+
 /// Query for audit log entries
+///
+/// All filters are optional and combined with AND. Results are returned
+/// oldest first; pass the page's `next_cursor` back as `after` to continue.
 #[derive(Debug, Clone, Default)]
 pub struct AuditQuery {
     /// Filter by session ID
     pub session_id: Option<String>,
     /// Filter by event type
     pub event_type: Option<AuditEventType>,
+    /// Filter by outcome
+    pub outcome: Option<AuditOutcome>,
+    /// Filter by actor type (system, agent, user, admin)
+    pub actor_type: Option<String>,
+    /// Filter by actor identifier
+    pub actor_id: Option<String>,
     /// Filter by resource type
     pub resource_type: Option<String>,
     /// Filter by resource ID
     pub resource_id: Option<String>,
-    /// Filter by date range start
+    /// Filter by date range start (inclusive)
     pub from: Option<DateTime<Utc>>,
-    /// Filter by date range end
+    /// Filter by date range end (inclusive)
     pub to: Option<DateTime<Utc>>,
-    /// Maximum results
+    /// Maximum results per page
     pub limit: Option<i32>,
+    /// Resume after this position (from a previous page)
+    pub after: Option<AuditCursor>,
+}
+
+impl AuditQuery {
+    /// Whether an entry passes every filter (the cursor is not checked)
+    pub fn matches(&self, entry: &AuditEntry) -> bool {
+        fn eq(filter: &Option<String>, value: &str) -> bool {
+            filter.as_deref().map(|f| f == value).unwrap_or(true)
+        }
+
+        self.session_id
+            .as_deref()
+            .map(|s| entry.actor.session_id.as_deref() == Some(s))
+            .unwrap_or(true)
+            && self
+                .event_type
+                .map(|t| entry.event_type == t)
+                .unwrap_or(true)
+            && self.outcome.map(|o| entry.outcome == o).unwrap_or(true)
+            && eq(&self.actor_type, &entry.actor.actor_type)
+            && eq(&self.actor_id, &entry.actor.actor_id)
+            && eq(&self.resource_type, &entry.resource_type)
+            && eq(&self.resource_id, &entry.resource_id)
+            && self
+                .from
+                .map(|from| entry.timestamp >= from)
+                .unwrap_or(true)
+            && self.to.map(|to| entry.timestamp <= to).unwrap_or(true)
+    }
+
+    /// Requested page size, clamped to 1..=MAX_PAGE_SIZE
+    pub fn page_size(&self) -> usize {
+        self.limit
+            .map(|l| (l.max(1) as usize).min(MAX_PAGE_SIZE))
+            .unwrap_or(DEFAULT_PAGE_SIZE)
+    }
+}
+
+/// Position of an entry in time order, used to resume a query
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AuditCursor {
+    /// Entry timestamp in milliseconds (the clustering key in ScyllaDB)
+    pub timestamp_millis: i64,
+    /// Entry ID, breaking ties between entries in the same millisecond
+    pub id: Uuid,
+}
+
+impl AuditCursor {
+    /// Cursor positioned just after the given entry
+    pub fn after(entry: &AuditEntry) -> Self {
+        Self {
+            timestamp_millis: entry.timestamp.timestamp_millis(),
+            id: entry.id,
+        }
+    }
+
+    /// Opaque string form for use in URLs
+    pub fn encode(&self) -> String {
+        format!("{}_{}", self.timestamp_millis, self.id.simple())
+    }
+
+    /// Parse a cursor produced by `encode`
+    pub fn decode(s: &str) -> Option<Self> {
+        let (millis, id) = s.split_once('_')?;
+        Some(Self {
+            timestamp_millis: millis.parse().ok()?,
+            id: Uuid::parse_str(id).ok()?,
+        })
+    }
+
+    /// Whether the entry comes after this position
+    pub fn precedes(&self, entry: &AuditEntry) -> bool {
+        (entry.timestamp.timestamp_millis(), entry.id) > (self.timestamp_millis, self.id)
+    }
+}
+
+/// One page of audit query results
+#[derive(Debug, Clone, Default)]
+pub struct AuditPage {
+    /// Matching entries, oldest first
+    pub entries: Vec<AuditEntry>,
+    /// Cursor for the next page; None when there are no more results
+    pub next_cursor: Option<AuditCursor>,
+}
+
+impl AuditPage {
+    /// Build a page from up to `page_size + 1` time-ordered matches; the
+    /// extra match only signals that another page exists
+    fn from_matches(mut entries: Vec<AuditEntry>, page_size: usize) -> Self {
+        let has_more = entries.len() > page_size;
+        entries.truncate(page_size);
+        let next_cursor = if has_more {
+            entries.last().map(AuditCursor::after)
+        } else {
+            None
+        };
+        Self {
+            entries,
+            next_cursor,
+        }
+    }
 }
 
 /// Audit log service trait
 #[async_trait]
 pub trait AuditLog: Send + Sync {
     /// Log an audit entry
+    ///
+    /// The entry is appended to its session's chain: implementations set
+    /// `previous_hash` to the current chain head and recompute `hash`.
     async fn log(&self, entry: AuditEntry) -> Result<(), PersistenceError>;
 
-    /// Query audit entries
-    async fn query(&self, query: AuditQuery) -> Result<Vec<AuditEntry>, PersistenceError>;
+    /// Query audit entries, oldest first, one page at a time
+    async fn query(&self, query: AuditQuery) -> Result<AuditPage, PersistenceError>;
 
     /// Get the latest entry hash (for chaining)
     async fn get_latest_hash(&self, session_id: &str) -> Result<String, PersistenceError>;
 
+    /// First point at which a session's chain is broken, if any
+    async fn find_chain_break(
+        &self,
+        session_id: &str,
+    ) -> Result<Option<ChainBreak>, PersistenceError>;
+
     /// Verify chain integrity for a session
-    async fn verify_chain(&self, session_id: &str) -> Result<bool, PersistenceError>;
+    async fn verify_chain(&self, session_id: &str) -> Result<bool, PersistenceError> {
+        Ok(self.find_chain_break(session_id).await?.is_none())
+    }
 }
 
+/// Columns of `audit_log` and `audit_log_by_date`, in insert/select order
+const AUDIT_COLUMNS: &str = "partition_date, session_id, timestamp, id, event_type, \
+     actor_type, actor_id, resource_type, resource_id, \
+     action, outcome, details, previous_hash, hash";
+
+/// Chain ID prefix for entries that belong to no session
+const SYSTEM_CHAIN: &str = "system";
+
+/// Whether a stored chain ID is a system chain rather than a session
+///
+/// Entries written before system chains were split per resource are all
+/// under the bare `SYSTEM_CHAIN`.
+fn is_system_chain(chain_id: &str) -> bool {
+    chain_id
+        .strip_prefix(SYSTEM_CHAIN)
+        .is_some_and(|rest| rest.is_empty() || rest.starts_with(':'))
+}
+
+/// Attempts to append to a chain before giving up under write contention
+const MAX_CHAIN_ATTEMPTS: usize = 8;
+
 /// ScyllaDB-backed audit log implementation
+///
+/// Each session's chain head lives in `audit_chain_heads` and is advanced
+/// with a lightweight transaction once the entry is stored, so concurrent
+/// writers (even on other nodes) append to the chain one at a time instead
+/// of forking it, and a failed write never leaves the head pointing at an
+/// entry that isn't there.
 #[derive(Clone)]
 pub struct ScyllaAuditLog {
     client: ScyllaClient,
 }
 
+/// Current head of a session's chain
+struct ChainHead {
+    hash: String,
+    first_date: NaiveDate,
+    last_date: NaiveDate,
+}
+
 impl ScyllaAuditLog {
     pub fn new(client: ScyllaClient) -> Self {
         Self { client }
@@ -345,71 +643,182 @@ impl ScyllaAuditLog {
     pub fn genesis_hash() -> String {
         "0".repeat(64) // SHA-256 produces 64 hex chars
     }
-}
-
-#[async_trait]
-impl AuditLog for ScyllaAuditLog {
-    async fn log(&self, entry: AuditEntry) -> Result<(), PersistenceError> {
-        let date = entry.timestamp.format("%Y-%m-%d").to_string();
-        let session_id = entry.actor.session_id.as_deref().unwrap_or("system");
 
+    async fn chain_head(&self, chain_id: &str) -> Result<Option<ChainHead>, PersistenceError> {
         let query = format!(
-            "INSERT INTO {}.audit_log (
-                partition_date, session_id, timestamp, id, event_type,
-                actor_type, actor_id, resource_type, resource_id,
-                action, outcome, details, previous_hash, hash
-            ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+            "SELECT hash, first_date, last_date FROM {}.audit_chain_heads WHERE session_id = ?",
             self.client.keyspace()
         );
+        let result = self.client.query_idempotent(query, (chain_id,)).await?;
 
-        self.client
-            .session()
-            .query_unpaged(
-                query,
-                (
-                    &date,
-                    session_id,
-                    entry.timestamp.timestamp_millis(),
-                    entry.id,
-                    entry.event_type.as_str(),
-                    &entry.actor.actor_type,
-                    &entry.actor.actor_id,
-                    &entry.resource_type,
-                    &entry.resource_id,
-                    &entry.action,
-                    entry.outcome.as_str(),
-                    entry.details.to_string(),
-                    &entry.previous_hash,
-                    &entry.hash,
-                ),
-            )
-            .await?;
+        let Some(row) = result.rows.and_then(|rows| rows.into_iter().next()) else {
+            return Ok(None);
+        };
+        let (hash, first_date, last_date): (String, String, String) = row
+            .into_typed()
+            .map_err(|e| PersistenceError::InvalidData(e.to_string()))?;
+        Ok(Some(ChainHead {
+            hash,
+            first_date: parse_partition_date(&first_date)?,
+            last_date: parse_partition_date(&last_date)?,
+        }))
+    }
 
-        tracing::debug!(
-            event_type = entry.event_type.as_str(),
-            resource_id = %entry.resource_id,
-            hash = %entry.hash,
-            "Audit entry logged"
-        );
+    /// Move the chain head from `expected` to `entry`, failing (false) if
+    /// another writer moved it first
+    async fn advance_chain_head(
+        &self,
+        chain_id: &str,
+        expected: Option<&ChainHead>,
+        entry: &AuditEntry,
+    ) -> Result<bool, PersistenceError> {
+        let date = entry.partition_date();
+        let result = match expected {
+            None => {
+                let query = format!(
+                    "INSERT INTO {}.audit_chain_heads (session_id, hash, first_date, last_date)
+                     VALUES (?, ?, ?, ?) IF NOT EXISTS",
+                    self.client.keyspace()
+                );
+                self.client
+                    .query(query, (chain_id, &entry.hash, &date, &date))
+                    .await?
+            },
+            Some(head) => {
+                let query = format!(
+                    "UPDATE {}.audit_chain_heads SET hash = ?, last_date = ?
+                     WHERE session_id = ? IF hash = ?",
+                    self.client.keyspace()
+                );
+                self.client
+                    .query(query, (&entry.hash, &date, chain_id, &head.hash))
+                    .await?
+            },
+        };
+
+        // The first column of a conditional statement's result is [applied]
+        let applied = result
+            .rows
+            .and_then(|rows| rows.into_iter().next())
+            .and_then(|row| row.columns.into_iter().next().flatten())
+            .and_then(|value| value.as_boolean())
+            .unwrap_or(false);
+        Ok(applied)
+    }
+
+    async fn write_entry(&self, entry: &AuditEntry) -> Result<(), PersistenceError> {
+        let date = entry.partition_date();
+        let details = entry.details.to_string();
+        let chain_id = entry.chain_id();
+
+        // Written twice: per-session for chain verification, per-day for
+        // cross-session compliance queries in time order
+        for table in ["audit_log", "audit_log_by_date"] {
+            let query = format!(
+                "INSERT INTO {}.{} ({}) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+                self.client.keyspace(),
+                table,
+                AUDIT_COLUMNS
+            );
+
+            self.client
+                .query(
+                    query,
+                    (
+                        &date,
+                        chain_id.as_ref(),
+                        entry.timestamp.timestamp_millis(),
+                        entry.id,
+                        entry.event_type.as_str(),
+                        &entry.actor.actor_type,
+                        &entry.actor.actor_id,
+                        &entry.resource_type,
+                        &entry.resource_id,
+                        &entry.action,
+                        entry.outcome.as_str(),
+                        &details,
+                        &entry.previous_hash,
+                        &entry.hash,
+                    ),
+                )
+                .await?;
+        }
 
         Ok(())
     }
 
-    async fn query(&self, query: AuditQuery) -> Result<Vec<AuditEntry>, PersistenceError> {
-        // Build query based on filters
-        let limit = query.limit.unwrap_or(100);
+    /// Remove an entry stored for a chain head that another writer moved first
+    async fn delete_entry(&self, entry: &AuditEntry) -> Result<(), PersistenceError> {
+        let date = entry.partition_date();
+        let chain_id = entry.chain_id();
+        let timestamp = entry.timestamp.timestamp_millis();
 
-        // Simple query - in production would build dynamic WHERE clause
-        let cql = format!(
-            "SELECT partition_date, session_id, timestamp, id, event_type,
-                    actor_type, actor_id, resource_type, resource_id,
-                    action, outcome, details, previous_hash, hash
-             FROM {}.audit_log
-             LIMIT ?",
+        let query = format!(
+            "DELETE FROM {}.audit_log
+             WHERE partition_date = ? AND session_id = ? AND timestamp = ? AND id = ?",
             self.client.keyspace()
         );
+        self.client
+            .query(query, (&date, chain_id.as_ref(), timestamp, entry.id))
+            .await?;
 
-        let result = self.client.session().query_unpaged(cql, (limit,)).await?;
+        let query = format!(
+            "DELETE FROM {}.audit_log_by_date
+             WHERE partition_date = ? AND timestamp = ? AND id = ?",
+            self.client.keyspace()
+        );
+        self.client
+            .query(query, (&date, timestamp, entry.id))
+            .await?;
+        Ok(())
+    }
+
+    /// Read one day's entries after `after`, oldest first
+    ///
+    /// Session queries read the (date, session) partition of `audit_log`;
+    /// everything else reads the day partition of `audit_log_by_date`.
+    async fn fetch_partition(
+        &self,
+        query: &AuditQuery,
+        day: NaiveDate,
+        after: &AuditCursor,
+        limit: usize,
+    ) -> Result<Vec<AuditEntry>, PersistenceError> {
+        let date = day.format("%Y-%m-%d").to_string();
+        let limit = limit as i32;
+
+        let result = match query.session_id {
+            Some(ref session_id) => {
+                let cql = format!(
+                    "SELECT {} FROM {}.audit_log
+                     WHERE partition_date = ? AND session_id = ?
+                       AND (timestamp, id) > (?, ?)
+                     ORDER BY timestamp ASC, id ASC
+                     LIMIT ?",
+                    AUDIT_COLUMNS,
+                    self.client.keyspace()
+                );
+                self.client
+                    .query_idempotent(
+                        cql,
+                        (&date, session_id, after.timestamp_millis, after.id, limit),
+                    )
+                    .await?
+            },
+            None => {
+                let cql = format!(
+                    "SELECT {} FROM {}.audit_log_by_date
+                     WHERE partition_date = ?
+                       AND (timestamp, id) > (?, ?)
+                     LIMIT ?",
+                    AUDIT_COLUMNS,
+                    self.client.keyspace()
+                );
+                self.client
+                    .query_idempotent(cql, (&date, after.timestamp_millis, after.id, limit))
+                    .await?
+            },
+        };
 
         let mut entries = Vec::new();
         if let Some(rows) = result.rows {
@@ -455,7 +864,8 @@ impl AuditLog for ScyllaAuditLog {
                     actor: Actor {
                         actor_type,
                         actor_id,
-                        session_id: Some(session_id),
+                        // Session-less entries are stored under a system chain
+                        session_id: (!is_system_chain(&session_id)).then_some(session_id),
                     },
                     resource_type,
                     resource_id,
@@ -470,138 +880,335 @@ impl AuditLog for ScyllaAuditLog {
 
         Ok(entries)
     }
+}
 
-    async fn get_latest_hash(&self, session_id: &str) -> Result<String, PersistenceError> {
-        let query = format!(
-            "SELECT hash FROM {}.audit_log WHERE session_id = ? ORDER BY timestamp DESC LIMIT 1",
-            self.client.keyspace()
-        );
+/// Hex SHA-256 of text spoken on a call, logged in place of the text
+fn text_digest(text: &str) -> String {
+    format!("{:x}", Sha256::digest(text.as_bytes()))
+}
 
-        let result = self
-            .client
-            .session()
-            .query_unpaged(query, (session_id,))
-            .await?;
+fn parse_partition_date(date: &str) -> Result<NaiveDate, PersistenceError> {
+    NaiveDate::parse_from_str(date, "%Y-%m-%d")
+        .map_err(|e| PersistenceError::InvalidData(format!("partition date {}: {}", date, e)))
+}
 
-        if let Some(rows) = result.rows {
-            if let Some(row) = rows.into_iter().next() {
-                let (hash,): (String,) = row
-                    .into_typed()
-                    .map_err(|e| PersistenceError::InvalidData(e.to_string()))?;
-                return Ok(hash);
+#[async_trait]
+impl AuditLog for ScyllaAuditLog {
+    async fn log(&self, mut entry: AuditEntry) -> Result<(), PersistenceError> {
+        let chain_id = entry.chain_id().to_string();
+
+        for attempt in 1..=MAX_CHAIN_ATTEMPTS {
+            let head = self.chain_head(&chain_id).await?;
+            entry.link(
+                head.as_ref()
+                    .map(|h| h.hash.clone())
+                    .unwrap_or_else(Self::genesis_hash),
+            );
+
+            // Store the entry before moving the head onto it
+            self.write_entry(&entry).await?;
+            let advanced = match self
+                .advance_chain_head(&chain_id, head.as_ref(), &entry)
+                .await
+            {
+                Ok(applied) => applied,
+                // The head may or may not have moved; it decides
+                Err(e) => {
+                    let head = self.chain_head(&chain_id).await?;
+                    if !head.is_some_and(|h| h.hash == entry.hash) {
+                        self.delete_entry(&entry).await?;
+                        return Err(e);
+                    }
+                    true
+                },
+            };
+            if advanced {
+                tracing::debug!(
+                    event_type = entry.event_type.as_str(),
+                    resource_id = %entry.resource_id,
+                    hash = %entry.hash,
+                    "Audit entry logged"
+                );
+                return Ok(());
             }
+
+            // Another writer appended first; drop the entry linked to the old head
+            self.delete_entry(&entry).await?;
+            tracing::debug!(chain_id = %chain_id, attempt, "Audit chain head moved, retrying");
         }
 
-        // No previous entries - return genesis hash
-        Ok(Self::genesis_hash())
+        Err(PersistenceError::Query(format!(
+            "Could not append to audit chain {} after {} attempts",
+            chain_id, MAX_CHAIN_ATTEMPTS
+        )))
     }
 
-    async fn verify_chain(&self, session_id: &str) -> Result<bool, PersistenceError> {
-        let query = format!(
-            "SELECT timestamp, id, event_type, actor_type, actor_id,
-                    resource_type, resource_id, action, outcome,
-                    details, previous_hash, hash
-             FROM {}.audit_log
-             WHERE session_id = ?
-             ORDER BY timestamp ASC",
-            self.client.keyspace()
-        );
+    async fn query(&self, query: AuditQuery) -> Result<AuditPage, PersistenceError> {
+        let page_size = query.page_size();
+        let to = query.to.unwrap_or_else(Utc::now);
+        let from = query
+            .from
+            .unwrap_or_else(|| to - chrono::Duration::days(DEFAULT_QUERY_WINDOW_DAYS));
+
+        // Rows are read strictly after this (timestamp, id) position
+        let mut position = match query.after {
+            Some(cursor) => cursor,
+            None => AuditCursor {
+                timestamp_millis: from.timestamp_millis() - 1,
+                id: Uuid::from_u128(u128::MAX),
+            },
+        };
+        let mut day = DateTime::from_timestamp_millis(position.timestamp_millis)
+            .map(|t| t.date_naive())
+            .unwrap_or_else(|| from.date_naive())
+            .max(from.date_naive());
+
+        // Collect one extra match so we know whether another page exists
+        let mut matched = Vec::new();
+        'days: while day <= to.date_naive() {
+            loop {
+                let batch = self
+                    .fetch_partition(&query, day, &position, page_size)
+                    .await?;
+                let exhausted = batch.len() < page_size;
+                for entry in batch {
+                    if entry.timestamp > to {
+                        break 'days;
+                    }
+                    position = AuditCursor::after(&entry);
+                    if query.matches(&entry) {
+                        matched.push(entry);
+                        if matched.len() > page_size {
+                            break 'days;
+                        }
+                    }
+                }
+                if exhausted {
+                    break;
+                }
+            }
+            day = match day.succ_opt() {
+                Some(next) => next,
+                None => break,
+            };
+        }
 
-        let result = self
-            .client
-            .session()
-            .query_unpaged(query, (session_id,))
-            .await?;
+        Ok(AuditPage::from_matches(matched, page_size))
+    }
 
-        let mut expected_previous = Self::genesis_hash();
+    async fn get_latest_hash(&self, session_id: &str) -> Result<String, PersistenceError> {
+        Ok(self
+            .chain_head(session_id)
+            .await?
+            .map(|head| head.hash)
+            .unwrap_or_else(Self::genesis_hash))
+    }
 
-        if let Some(rows) = result.rows {
-            for row in rows {
-                let (
-                    timestamp,
-                    id,
-                    event_type,
-                    actor_type,
-                    actor_id,
-                    resource_type,
-                    resource_id,
-                    action,
-                    outcome,
-                    details_str,
-                    previous_hash,
-                    hash,
-                ): (
-                    i64,
-                    Uuid,
-                    String,
-                    String,
-                    String,
-                    String,
-                    String,
-                    String,
-                    String,
-                    String,
-                    String,
-                    String,
-                ) = row
-                    .into_typed()
-                    .map_err(|e| PersistenceError::InvalidData(e.to_string()))?;
+    async fn find_chain_break(
+        &self,
+        session_id: &str,
+    ) -> Result<Option<ChainBreak>, PersistenceError> {
+        let Some(head) = self.chain_head(session_id).await? else {
+            return Ok(None);
+        };
 
-                let entry = AuditEntry {
-                    id,
-                    timestamp: DateTime::from_timestamp_millis(timestamp).unwrap_or_else(Utc::now),
-                    event_type: AuditEventType::from_str(&event_type),
-                    actor: Actor {
-                        actor_type,
-                        actor_id,
-                        session_id: Some(session_id.to_string()),
-                    },
-                    resource_type,
-                    resource_id,
-                    action,
-                    outcome: AuditOutcome::from_str(&outcome),
-                    details: serde_json::from_str(&details_str).unwrap_or(serde_json::Value::Null),
-                    previous_hash,
-                    hash: hash.clone(),
-                };
-
-                if !entry.verify_chain(&expected_previous) {
-                    tracing::error!(
-                        entry_id = %entry.id,
-                        expected = %expected_previous,
-                        actual = %entry.previous_hash,
-                        "Audit chain verification failed"
-                    );
-                    return Ok(false);
+        // The chain spans one (date, session) partition per day it was written
+        let query = AuditQuery {
+            session_id: Some(session_id.to_string()),
+            ..Default::default()
+        };
+        let mut entries = Vec::new();
+        let mut day = head.first_date;
+        while day <= head.last_date {
+            let mut position = AuditCursor {
+                timestamp_millis: i64::MIN,
+                id: Uuid::nil(),
+            };
+            loop {
+                let batch = self
+                    .fetch_partition(&query, day, &position, MAX_PAGE_SIZE)
+                    .await?;
+                let exhausted = batch.len() < MAX_PAGE_SIZE;
+                if let Some(last) = batch.last() {
+                    position = AuditCursor::after(last);
+                }
+                entries.extend(batch);
+                if exhausted {
+                    break;
                 }
-
-                expected_previous = hash;
             }
+            day = match day.succ_opt() {
+                Some(next) => next,
+                None => break,
+            };
         }
 
-        Ok(true)
+        let chain_break = check_chain(&entries).or_else(|| {
+            // Entries after the last stored one were deleted
+            let stored_head = entries.iter().any(|e| e.hash == head.hash);
+            (!stored_head).then(|| ChainBreak {
+                position: entries.len(),
+                entry_id: None,
+                kind: ChainBreakKind::MissingEntry,
+            })
+        });
+        if let Some(ref b) = chain_break {
+            tracing::error!(
+                session_id = %session_id,
+                position = b.position,
+                kind = ?b.kind,
+                "Audit chain verification failed"
+            );
+        }
+        Ok(chain_break)
+    }
+}
+
+/// In-memory audit log for tests and deployments without ScyllaDB
+///
+/// Entries are kept in insertion order and are lost on restart.
+#[derive(Default)]
+pub struct InMemoryAuditLog {
+    entries: std::sync::Mutex<Vec<AuditEntry>>,
+}
+
+impl InMemoryAuditLog {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// All entries on a chain (a session's, or a system chain), oldest first
+    pub fn entries_for(&self, chain_id: &str) -> Vec<AuditEntry> {
+        self.entries
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|e| e.chain_id() == chain_id)
+            .cloned()
+            .collect()
+    }
+}
+
+#[async_trait]
+impl AuditLog for InMemoryAuditLog {
+    async fn log(&self, mut entry: AuditEntry) -> Result<(), PersistenceError> {
+        // Holding the lock while linking serializes concurrent writers
+        let mut entries = self.entries.lock().unwrap();
+        let head = entries
+            .iter()
+            .rev()
+            .find(|e| e.chain_id() == entry.chain_id())
+            .map(|e| e.hash.clone())
+            .unwrap_or_else(ScyllaAuditLog::genesis_hash);
+        entry.link(head);
+        entries.push(entry);
+        Ok(())
+    }
+
+    async fn query(&self, query: AuditQuery) -> Result<AuditPage, PersistenceError> {
+        let page_size = query.page_size();
+        let mut matched: Vec<AuditEntry> = self
+            .entries
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|e| query.matches(e))
+            .filter(|e| query.after.map(|c| c.precedes(e)).unwrap_or(true))
+            .cloned()
+            .collect();
+        matched.sort_by_key(|e| (e.timestamp.timestamp_millis(), e.id));
+        matched.truncate(page_size + 1);
+        Ok(AuditPage::from_matches(matched, page_size))
+    }
+
+    async fn get_latest_hash(&self, session_id: &str) -> Result<String, PersistenceError> {
+        Ok(self
+            .entries_for(session_id)
+            .last()
+            .map(|e| e.hash.clone())
+            .unwrap_or_else(ScyllaAuditLog::genesis_hash))
+    }
+
+    async fn find_chain_break(
+        &self,
+        session_id: &str,
+    ) -> Result<Option<ChainBreak>, PersistenceError> {
+        Ok(check_chain(&self.entries_for(session_id)))
+    }
+}
+
+/// A customer's pseudonym on the audit trail
+///
+/// A keyed hash of the normalized phone number, from
+/// `AuditLogger::customer_key`. Entries about one customer share it, but
+/// without the logger's secret the number can't be recovered, nor found
+/// by hashing every possible number.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CustomerKey(String);
+
+impl CustomerKey {
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for CustomerKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
     }
 }
 
 /// Helper for common audit logging operations
 pub struct AuditLogger {
     log: std::sync::Arc<dyn AuditLog>,
+    /// Secret keying customer pseudonyms
+    customer_key_secret: Vec<u8>,
 }
 
 impl AuditLogger {
+    /// Create with a random customer key secret
+    ///
+    /// Keys then differ per logger; set a shared secret with
+    /// `with_customer_key_secret` to link a customer's entries across
+    /// restarts and instances.
     pub fn new(log: std::sync::Arc<dyn AuditLog>) -> Self {
-        Self { log }
+        let secret = format!("{}{}", Uuid::new_v4(), Uuid::new_v4());
+        Self {
+            log,
+            customer_key_secret: secret.into_bytes(),
+        }
+    }
+
+    /// Key customer pseudonyms with `secret`
+    pub fn with_customer_key_secret(mut self, secret: impl AsRef<[u8]>) -> Self {
+        self.customer_key_secret = secret.as_ref().to_vec();
+        self
+    }
+
+    /// Pseudonym for the customer with this phone number, in any format
+    pub fn customer_key(&self, phone: &str) -> CustomerKey {
+        let mut mac = Hmac::<Sha256>::new_from_slice(&self.customer_key_secret)
+            .expect("HMAC accepts keys of any length");
+        mac.update(normalize_phone(phone).as_bytes());
+        CustomerKey(format!("{:x}", mac.finalize().into_bytes()))
+    }
+
+    /// Query audit entries (for compliance investigations)
+    pub async fn query(&self, query: AuditQuery) -> Result<AuditPage, PersistenceError> {
+        self.log.query(query).await
     }
 
     /// Log AI disclosure event
+    ///
+    /// The disclosure is recorded as a digest of its text, which can be
+    /// matched against the configured script without the chain holding
+    /// anything said on the call.
     pub async fn log_ai_disclosure(
         &self,
         session_id: &str,
         language: &str,
         disclosure_text: &str,
     ) -> Result<(), PersistenceError> {
-        let previous_hash = self.log.get_latest_hash(session_id).await?;
-
         let entry = AuditEntry::new(
             AuditEventType::AiDisclosureGiven,
             Actor::agent(session_id),
@@ -611,9 +1218,38 @@ impl AuditLogger {
             AuditOutcome::Success,
             serde_json::json!({
                 "language": language,
-                "disclosure_text": disclosure_text,
+                "disclosure_sha256": text_digest(disclosure_text),
             }),
-            previous_hash,
+            ScyllaAuditLog::genesis_hash(),
+        );
+
+        self.log.log(entry).await
+    }
+
+    /// Log a step of the opening script other than the disclosure and consent
+    ///
+    /// The text is recorded as a digest, as for the disclosure; a greeting
+    /// may name the customer.
+    pub async fn log_opening_step(
+        &self,
+        session_id: &str,
+        step: &str,
+        language: &str,
+        text: &str,
+    ) -> Result<(), PersistenceError> {
+        let entry = AuditEntry::new(
+            AuditEventType::OpeningStepGiven,
+            Actor::agent(session_id),
+            "conversation",
+            session_id,
+            format!("gave_opening_{}", step),
+            AuditOutcome::Success,
+            serde_json::json!({
+                "step": step,
+                "language": language,
+                "text_sha256": text_digest(text),
+            }),
+            ScyllaAuditLog::genesis_hash(),
         );
 
         self.log.log(entry).await
@@ -627,8 +1263,19 @@ impl AuditLogger {
         given: bool,
         method: &str,
     ) -> Result<(), PersistenceError> {
-        let previous_hash = self.log.get_latest_hash(session_id).await?;
+        self.log_consent_with_note(session_id, consent_type, given, method, None)
+            .await
+    }
 
+    /// Log consent event with a free-text note (e.g. what a refusal disabled)
+    pub async fn log_consent_with_note(
+        &self,
+        session_id: &str,
+        consent_type: &str,
+        given: bool,
+        method: &str,
+        note: Option<&str>,
+    ) -> Result<(), PersistenceError> {
         let event_type = if consent_type == "recording" {
             if given {
                 AuditEventType::RecordingConsentObtained
@@ -654,8 +1301,9 @@ impl AuditLogger {
                 "consent_type": consent_type,
                 "given": given,
                 "method": method,
+                "note": note,
             }),
-            previous_hash,
+            ScyllaAuditLog::genesis_hash(),
         );
 
         self.log.log(entry).await
@@ -669,7 +1317,7 @@ impl AuditLogger {
     ) -> Result<(), PersistenceError> {
         let entry = AuditEntry::new(
             AuditEventType::ConversationStarted,
-            Actor::system(),
+            Actor::system_for(session_id),
             "conversation",
             session_id,
             "conversation_started",
@@ -691,11 +1339,9 @@ impl AuditLogger {
         reason: &str,
         duration_seconds: u64,
     ) -> Result<(), PersistenceError> {
-        let previous_hash = self.log.get_latest_hash(session_id).await?;
-
         let entry = AuditEntry::new(
             AuditEventType::ConversationEnded,
-            Actor::system(),
+            Actor::system_for(session_id),
             "conversation",
             session_id,
             "conversation_ended",
@@ -705,7 +1351,39 @@ impl AuditLogger {
                 "duration_seconds": duration_seconds,
                 "ended_at": Utc::now().to_rfc3339(),
             }),
-            previous_hash,
+            ScyllaAuditLog::genesis_hash(),
+        );
+
+        self.log.log(entry).await
+    }
+
+    /// Log a conversation that ended with compliance violations outstanding
+    pub async fn log_compliance_incomplete(
+        &self,
+        session_id: &str,
+        violations: &[ComplianceViolation],
+    ) -> Result<(), PersistenceError> {
+        let mut categories: Vec<ViolationCategory> = Vec::new();
+        for violation in violations {
+            if !categories.contains(&violation.category) {
+                categories.push(violation.category);
+            }
+        }
+        let rule_ids: Vec<&str> = violations.iter().map(|v| v.rule_id.as_str()).collect();
+
+        let entry = AuditEntry::new(
+            AuditEventType::ComplianceViolationDetected,
+            Actor::system_for(session_id),
+            "conversation",
+            session_id,
+            "compliance_incomplete_at_end",
+            AuditOutcome::Failure,
+            serde_json::json!({
+                "categories": categories,
+                "rule_ids": rule_ids,
+                "ended_at": Utc::now().to_rfc3339(),
+            }),
+            ScyllaAuditLog::genesis_hash(),
         );
 
         self.log.log(entry).await
@@ -719,8 +1397,6 @@ impl AuditLogger {
         success: bool,
         details: serde_json::Value,
     ) -> Result<(), PersistenceError> {
-        let previous_hash = self.log.get_latest_hash(session_id).await?;
-
         let entry = AuditEntry::new(
             AuditEventType::ToolExecuted,
             Actor::agent(session_id),
@@ -733,7 +1409,33 @@ impl AuditLogger {
                 AuditOutcome::Failure
             },
             details,
-            previous_hash,
+            ScyllaAuditLog::genesis_hash(),
+        );
+
+        self.log.log(entry).await
+    }
+
+    /// Log the experiment variant a session was bucketed into
+    pub async fn log_experiment_assignment(
+        &self,
+        session_id: &str,
+        experiment_id: &str,
+        variant_id: &str,
+        bucket_by: &str,
+    ) -> Result<(), PersistenceError> {
+        let entry = AuditEntry::new(
+            AuditEventType::ExperimentAssigned,
+            Actor::system_for(session_id),
+            "experiment",
+            experiment_id,
+            "assigned_variant",
+            AuditOutcome::Success,
+            serde_json::json!({
+                "experiment_id": experiment_id,
+                "variant_id": variant_id,
+                "bucket_by": bucket_by,
+            }),
+            ScyllaAuditLog::genesis_hash(),
         );
 
         self.log.log(entry).await
@@ -746,8 +1448,6 @@ impl AuditLogger {
         reason: &str,
         escalation_id: &str,
     ) -> Result<(), PersistenceError> {
-        let previous_hash = self.log.get_latest_hash(session_id).await?;
-
         let entry = AuditEntry::new(
             AuditEventType::HumanEscalationRequested,
             Actor::agent(session_id),
@@ -759,7 +1459,139 @@ impl AuditLogger {
                 "reason": reason,
                 "escalation_id": escalation_id,
             }),
-            previous_hash,
+            ScyllaAuditLog::genesis_hash(),
+        );
+
+        self.log.log(entry).await
+    }
+
+    /// Log an escalation deferred to a callback outside live agent hours
+    pub async fn log_escalation_deferred(
+        &self,
+        session_id: &str,
+        reason: &str,
+        escalation_id: &str,
+        region: Option<&str>,
+    ) -> Result<(), PersistenceError> {
+        let entry = AuditEntry::new(
+            AuditEventType::HumanEscalationRequested,
+            Actor::agent(session_id),
+            "escalation",
+            escalation_id,
+            "defer_human_escalation",
+            AuditOutcome::Pending,
+            serde_json::json!({
+                "reason": reason,
+                "escalation_id": escalation_id,
+                "region": region,
+            }),
+            ScyllaAuditLog::genesis_hash(),
+        );
+
+        self.log.log(entry).await
+    }
+
+    /// Log an escalation that was deduped or rate limited
+    pub async fn log_escalation_suppressed(
+        &self,
+        session_id: &str,
+        reason: &str,
+        scope: &str,
+    ) -> Result<(), PersistenceError> {
+        let entry = AuditEntry::new(
+            AuditEventType::HumanEscalationRequested,
+            Actor::agent(session_id),
+            "escalation",
+            session_id,
+            "suppress_human_escalation",
+            AuditOutcome::Skipped,
+            serde_json::json!({
+                "reason": reason,
+                "scope": scope,
+            }),
+            ScyllaAuditLog::genesis_hash(),
+        );
+
+        self.log.log(entry).await
+    }
+
+    /// Log an export of a customer's data (an access request)
+    ///
+    /// The entry is keyed by the customer's pseudonym, never their number,
+    /// and records how many records of each kind went out, not the records.
+    pub async fn log_data_export(
+        &self,
+        customer: &CustomerKey,
+        requested_by: &str,
+        record_counts: serde_json::Value,
+    ) -> Result<(), PersistenceError> {
+        let entry = AuditEntry::new(
+            AuditEventType::DataExported,
+            Actor::admin(requested_by),
+            "customer",
+            customer.as_str(),
+            "export_customer_data",
+            AuditOutcome::Success,
+            serde_json::json!({
+                "records": record_counts,
+                "exported_at": Utc::now().to_rfc3339(),
+            }),
+            ScyllaAuditLog::genesis_hash(),
+        );
+
+        self.log.log(entry).await
+    }
+
+    /// Log the erasure of a customer's data
+    ///
+    /// The entry is keyed by the erasure ID rather than the customer, and
+    /// records only how many records were deleted or anonymized, so it
+    /// holds nothing that was erased.
+    pub async fn log_data_erasure(
+        &self,
+        erasure_id: Uuid,
+        requested_by: &str,
+        outcome: AuditOutcome,
+        record_counts: serde_json::Value,
+    ) -> Result<(), PersistenceError> {
+        let entry = AuditEntry::new(
+            AuditEventType::DataErased,
+            Actor::admin(requested_by),
+            "customer_erasure",
+            &erasure_id.to_string(),
+            "erase_customer_data",
+            outcome,
+            serde_json::json!({
+                "records": record_counts,
+                "erased_at": Utc::now().to_rfc3339(),
+            }),
+            ScyllaAuditLog::genesis_hash(),
+        );
+
+        self.log.log(entry).await
+    }
+
+    /// Log a caller turn flagged as a prompt injection attempt
+    ///
+    /// Only the categories are recorded, not the caller's words.
+    pub async fn log_prompt_injection(
+        &self,
+        session_id: &str,
+        categories: &[&str],
+        action: &str,
+    ) -> Result<(), PersistenceError> {
+        let entry = AuditEntry::new(
+            AuditEventType::PromptInjectionDetected,
+            Actor::user(session_id, None),
+            "conversation",
+            session_id,
+            "prompt_injection_detected",
+            AuditOutcome::Failure,
+            serde_json::json!({
+                "categories": categories,
+                "action": action,
+            }),
+            ScyllaAuditLog::genesis_hash(),
         );
 
         self.log.log(entry).await
@@ -841,6 +1673,285 @@ mod tests {
         assert!(!entry.verify());
     }
 
+    #[tokio::test]
+    async fn test_in_memory_log_chains_entries() {
+        let log = std::sync::Arc::new(InMemoryAuditLog::new());
+        let logger = AuditLogger::new(log.clone());
+
+        logger
+            .log_ai_disclosure("session-1", "en", "This is an AI assistant.")
+            .await
+            .unwrap();
+        logger
+            .log_consent_with_note("session-1", "recording", false, "Voice", Some("declined"))
+            .await
+            .unwrap();
+
+        let entries = log.entries_for("session-1");
+        assert_eq!(entries.len(), 2);
+        assert_eq!(
+            entries[1].event_type,
+            AuditEventType::RecordingConsentDenied
+        );
+        assert_eq!(entries[1].details["note"], "declined");
+        assert!(log.verify_chain("session-1").await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_session_less_entries_chain_per_resource() {
+        let log = std::sync::Arc::new(InMemoryAuditLog::new());
+        let logger = AuditLogger::new(log.clone());
+
+        for phone in ["9876543210", "9123456780", "+91 98765 43210"] {
+            let customer = logger.customer_key(phone);
+            logger
+                .log_data_export(&customer, "dpo-desk", serde_json::json!({ "sessions": 1 }))
+                .await
+                .unwrap();
+        }
+
+        // Each customer's exports form their own chain from genesis
+        let first_chain = format!("system:customer:{}", logger.customer_key("9876543210"));
+        let first = log.entries_for(&first_chain);
+        let second = log.entries_for(&format!(
+            "system:customer:{}",
+            logger.customer_key("9123456780")
+        ));
+        assert_eq!(first.len(), 2);
+        assert_eq!(second.len(), 1);
+        assert_eq!(second[0].previous_hash, ScyllaAuditLog::genesis_hash());
+        assert_eq!(first[1].previous_hash, first[0].hash);
+        assert!(log.verify_chain(&first_chain).await.unwrap());
+        assert!(!first_chain.contains("9876543210"));
+
+        assert!(is_system_chain("system"));
+        assert!(is_system_chain("system:customer:customer-1"));
+        assert!(!is_system_chain("systematic-session"));
+    }
+
+    #[test]
+    fn test_customer_key_is_keyed_by_the_secret() {
+        let log = std::sync::Arc::new(InMemoryAuditLog::new());
+        let logger = AuditLogger::new(log.clone()).with_customer_key_secret("secret");
+        let key = logger.customer_key("9876543210");
+
+        // Stable across number formats and loggers sharing the secret
+        assert_eq!(key, logger.customer_key("+91 98765-43210"));
+        let same = AuditLogger::new(log.clone()).with_customer_key_secret("secret");
+        assert_eq!(key, same.customer_key("9876543210"));
+
+        // A plain hash of the number doesn't match it
+        assert_ne!(key.as_str(), format!("{:x}", Sha256::digest(b"9876543210")));
+        let other = AuditLogger::new(log).with_customer_key_secret("other-secret");
+        assert_ne!(key, other.customer_key("9876543210"));
+    }
+
+    async fn chained_log(session_id: &str, len: usize) -> InMemoryAuditLog {
+        let log = InMemoryAuditLog::new();
+        for i in 0..len {
+            log.log(AuditEntry::new(
+                AuditEventType::ToolExecuted,
+                Actor::agent(session_id),
+                "tool",
+                format!("tool-{}", i),
+                "executed",
+                AuditOutcome::Success,
+                serde_json::json!({ "i": i }),
+                // Relinked to the chain head by the log
+                ScyllaAuditLog::genesis_hash(),
+            ))
+            .await
+            .unwrap();
+        }
+        log
+    }
+
+    #[tokio::test]
+    async fn test_chain_break_found_at_modified_entry() {
+        let log = chained_log("session-1", 5).await;
+        assert!(log.verify_chain("session-1").await.unwrap());
+        assert_eq!(log.find_chain_break("session-1").await.unwrap(), None);
+
+        let tampered_id = {
+            let mut entries = log.entries.lock().unwrap();
+            entries[2].details = serde_json::json!({ "i": 99 });
+            entries[2].id
+        };
+
+        assert!(!log.verify_chain("session-1").await.unwrap());
+        assert_eq!(
+            log.find_chain_break("session-1").await.unwrap(),
+            Some(ChainBreak {
+                position: 2,
+                entry_id: Some(tampered_id),
+                kind: ChainBreakKind::HashMismatch,
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_rehashed_entry_breaks_next_link() {
+        let log = chained_log("session-1", 5).await;
+
+        // Re-hashing a modified entry hides it, but the next link no longer matches
+        let next_id = {
+            let mut entries = log.entries.lock().unwrap();
+            let previous = entries[1].hash.clone();
+            entries[2].action = "rewritten".to_string();
+            entries[2].link(previous);
+            entries[3].id
+        };
+
+        assert_eq!(
+            log.find_chain_break("session-1").await.unwrap(),
+            Some(ChainBreak {
+                position: 3,
+                entry_id: Some(next_id),
+                kind: ChainBreakKind::BrokenLink,
+            })
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn test_concurrent_writers_keep_one_chain() {
+        let log = std::sync::Arc::new(InMemoryAuditLog::new());
+        let logger = std::sync::Arc::new(AuditLogger::new(log.clone()));
+
+        let writers: Vec<_> = (0..16)
+            .map(|i| {
+                let logger = logger.clone();
+                tokio::spawn(async move {
+                    logger
+                        .log_tool_execution(
+                            "session-1",
+                            &format!("tool-{}", i),
+                            true,
+                            serde_json::json!({}),
+                        )
+                        .await
+                })
+            })
+            .collect();
+        for writer in writers {
+            writer.await.unwrap().unwrap();
+        }
+
+        assert_eq!(log.entries_for("session-1").len(), 16);
+        assert!(log.verify_chain("session-1").await.unwrap());
+    }
+
+    fn entry_at(i: i64) -> AuditEntry {
+        let event_type = if i % 2 == 0 {
+            AuditEventType::ComplianceViolationDetected
+        } else {
+            AuditEventType::ToolExecuted
+        };
+        let outcome = if i % 4 == 0 {
+            AuditOutcome::Failure
+        } else {
+            AuditOutcome::Success
+        };
+        let actor = if i % 3 == 0 {
+            Actor::system()
+        } else {
+            Actor::agent(&format!("session-{}", i % 2))
+        };
+        let mut entry = AuditEntry::new(
+            event_type,
+            actor,
+            "conversation",
+            format!("resource-{}", i),
+            "test",
+            outcome,
+            serde_json::json!({ "i": i }),
+            ScyllaAuditLog::genesis_hash(),
+        );
+        entry.timestamp = base_time() + chrono::Duration::hours(i);
+        entry
+    }
+
+    fn base_time() -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339("2026-10-01T10:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    #[tokio::test]
+    async fn test_query_filters_and_paginates() {
+        let log = InMemoryAuditLog::new();
+        // Inserted newest first; results must still come back in time order
+        for i in (0..24).rev() {
+            log.log(entry_at(i)).await.unwrap();
+        }
+
+        let mut query = AuditQuery {
+            event_type: Some(AuditEventType::ComplianceViolationDetected),
+            outcome: Some(AuditOutcome::Failure),
+            from: Some(base_time() + chrono::Duration::hours(1)),
+            to: Some(base_time() + chrono::Duration::hours(20)),
+            limit: Some(2),
+            ..Default::default()
+        };
+
+        let mut pages = Vec::new();
+        loop {
+            let page = log.query(query.clone()).await.unwrap();
+            pages.push(
+                page.entries
+                    .iter()
+                    .map(|e| e.details["i"].as_i64().unwrap())
+                    .collect::<Vec<_>>(),
+            );
+            match page.next_cursor {
+                Some(cursor) => {
+                    // Cursors survive a round trip through a URL
+                    query.after = AuditCursor::decode(&cursor.encode());
+                    assert_eq!(query.after, Some(cursor));
+                },
+                None => break,
+            }
+        }
+
+        assert_eq!(pages, vec![vec![4, 8], vec![12, 16], vec![20]]);
+    }
+
+    #[tokio::test]
+    async fn test_query_by_actor() {
+        let log = InMemoryAuditLog::new();
+        for i in 0..12 {
+            log.log(entry_at(i)).await.unwrap();
+        }
+
+        let page = log
+            .query(AuditQuery {
+                actor_type: Some("system".to_string()),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        let indices: Vec<i64> = page
+            .entries
+            .iter()
+            .map(|e| e.details["i"].as_i64().unwrap())
+            .collect();
+        assert_eq!(indices, vec![0, 3, 6, 9]);
+        assert!(page.next_cursor.is_none());
+
+        let page = log
+            .query(AuditQuery {
+                session_id: Some("session-1".to_string()),
+                outcome: Some(AuditOutcome::Success),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        assert!(page
+            .entries
+            .iter()
+            .all(|e| e.actor.session_id.as_deref() == Some("session-1")));
+        assert_eq!(page.entries.len(), 4); // 1, 5, 7, 11
+    }
+
     #[test]
     fn test_event_type_serialization() {
         assert_eq!(
@@ -1,16 +1,34 @@
 //! ScyllaDB client and connection management
+//!
+//! The driver session keeps `pool_size` connections per node and its
+//! default retry policy retries idempotent statements on the next node.
+//! If the whole cluster becomes unreachable, whichever request or
+//! `health_check` notices first rebuilds the session with backoff.
 
 use crate::error::PersistenceError;
+use crate::pool::{ConnectionManager, Connector, PoolMetrics, ReconnectPolicy};
 use crate::schema;
-use scylla::{Session, SessionBuilder};
+use async_trait::async_trait;
+use scylla::query::Query;
+use scylla::serialize::row::SerializeRow;
+use scylla::transport::session::PoolSize;
+use scylla::{QueryResult, Session, SessionBuilder};
+use std::num::NonZeroUsize;
 use std::sync::Arc;
 
+/// Connections per node when not configured
+const DEFAULT_POOL_SIZE: usize = 2;
+
 /// ScyllaDB configuration
 #[derive(Debug, Clone)]
 pub struct ScyllaConfig {
     pub hosts: Vec<String>,
     pub keyspace: String,
     pub replication_factor: u8,
+    /// Connections kept open to each node
+    pub pool_size: usize,
+    /// Backoff for the initial connect and for reconnects
+    pub reconnect: ReconnectPolicy,
 }
 
 impl Default for ScyllaConfig {
@@ -28,51 +46,150 @@ impl Default for ScyllaConfig {
             hosts,
             keyspace,
             replication_factor: 1,
+            pool_size: DEFAULT_POOL_SIZE,
+            reconnect: ReconnectPolicy::default(),
         }
     }
 }
 
+/// Builds driver sessions for the configured cluster
+struct ScyllaConnector {
+    hosts: Vec<String>,
+    pool_size: NonZeroUsize,
+}
+
+#[async_trait]
+impl Connector for ScyllaConnector {
+    type Connection = Session;
+
+    async fn connect(&self) -> Result<Session, PersistenceError> {
+        Ok(SessionBuilder::new()
+            .known_nodes(&self.hosts)
+            .pool_size(PoolSize::PerHost(self.pool_size))
+            .build()
+            .await?)
+    }
+
+    async fn ping(&self, session: &Session) -> Result<(), PersistenceError> {
+        let mut query = Query::new("SELECT now() FROM system.local");
+        query.set_is_idempotent(true);
+        session.query_unpaged(query, &[]).await?;
+        Ok(())
+    }
+
+    fn active_connections(&self, session: &Session) -> usize {
+        let nodes_up = session
+            .get_cluster_data()
+            .get_nodes_info()
+            .iter()
+            .filter(|node| !node.is_down())
+            .count();
+        nodes_up * self.pool_size.get()
+    }
+}
+
 /// ScyllaDB client wrapper
 #[derive(Clone)]
 pub struct ScyllaClient {
-    session: Arc<Session>,
+    connection: Arc<ConnectionManager<ScyllaConnector>>,
     config: ScyllaConfig,
 }
 
 impl ScyllaClient {
-    /// Connect to ScyllaDB cluster
+    /// Connect to ScyllaDB cluster, retrying with backoff
     pub async fn connect(config: ScyllaConfig) -> Result<Self, PersistenceError> {
-        tracing::info!(hosts = ?config.hosts, keyspace = %config.keyspace, "Connecting to ScyllaDB");
+        tracing::info!(
+            hosts = ?config.hosts,
+            keyspace = %config.keyspace,
+            pool_size = config.pool_size,
+            "Connecting to ScyllaDB"
+        );
 
-        let session = SessionBuilder::new()
-            .known_nodes(&config.hosts)
-            .build()
-            .await?;
-
-        let client = Self {
-            session: Arc::new(session),
-            config,
+        let connector = ScyllaConnector {
+            hosts: config.hosts.clone(),
+            pool_size: NonZeroUsize::new(config.pool_size).unwrap_or(NonZeroUsize::MIN),
         };
+        let connection = ConnectionManager::connect(connector, config.reconnect.clone()).await?;
 
-        Ok(client)
+        Ok(Self {
+            connection: Arc::new(connection),
+            config,
+        })
     }
 
     /// Ensure keyspace and tables exist
     pub async fn ensure_schema(&self) -> Result<(), PersistenceError> {
+        let session = self.session();
         schema::create_keyspace(
-            &self.session,
+            &session,
             &self.config.keyspace,
             self.config.replication_factor,
         )
         .await?;
-        schema::create_tables(&self.session, &self.config.keyspace).await?;
+        schema::create_tables(&session, &self.config.keyspace).await?;
         tracing::info!(keyspace = %self.config.keyspace, "Schema ensured");
         Ok(())
     }
 
-    /// Get the underlying session
-    pub fn session(&self) -> &Session {
-        &self.session
+    /// Get the current session
+    ///
+    /// Don't hold on to it: after a reconnect a new session replaces it.
+    /// Prefer `query` and `query_idempotent`, which reconnect when the
+    /// session has lost the cluster.
+    pub fn session(&self) -> Arc<Session> {
+        self.connection.get()
+    }
+
+    /// Run a statement
+    ///
+    /// If the session has lost the cluster, it is rebuilt for later requests
+    /// but the statement isn't repeated.
+    pub async fn query(
+        &self,
+        cql: impl Into<String>,
+        values: impl SerializeRow,
+    ) -> Result<QueryResult, PersistenceError> {
+        self.run(false, Query::new(cql.into()), &values).await
+    }
+
+    /// Run a statement that is safe to repeat (reads, plain upserts)
+    ///
+    /// Marking it idempotent lets the driver retry it on another node when
+    /// a node times out or fails mid-request, and it runs again on the new
+    /// session if the old one lost the cluster.
+    pub async fn query_idempotent(
+        &self,
+        cql: impl Into<String>,
+        values: impl SerializeRow,
+    ) -> Result<QueryResult, PersistenceError> {
+        let mut query = Query::new(cql.into());
+        query.set_is_idempotent(true);
+        self.run(true, query, &values).await
+    }
+
+    /// Run `query`, reconnecting after a connection error
+    async fn run(
+        &self,
+        retry: bool,
+        query: Query,
+        values: &impl SerializeRow,
+    ) -> Result<QueryResult, PersistenceError> {
+        self.connection
+            .run(retry, |session| {
+                let query = query.clone();
+                async move { Ok(session.query_unpaged(query, values).await?) }
+            })
+            .await
+    }
+
+    /// Check connectivity, reconnecting if the cluster stopped responding
+    pub async fn health_check(&self) -> bool {
+        self.connection.health_check().await
+    }
+
+    /// Connection pool metrics
+    pub fn metrics(&self) -> PoolMetrics {
+        self.connection.metrics()
     }
 
     /// Get keyspace name
@@ -0,0 +1,563 @@
+//! Customer data erasure for right-to-erasure requests
+//!
+//! `CustomerDataEraser::delete_customer_data` removes what is held about a
+//! customer, keyed by their phone number in any common format. Sessions
+//! are deleted outright, and with them the agent's memory, archival notes
+//! included, which is persisted with each session as `memory_json`.
+//! Appointments, SMS records and scheduled SMS are anonymized instead: they
+//! are re-keyed under a random pseudonym with names, notes and message text
+//! cleared, so branch and delivery counts still add up but can't be linked
+//! back to the customer. Scheduled SMS not sent yet are cancelled.
+//!
+//! Data held outside these stores, such as CRM leads or the conversation
+//! recording, is reached through `ErasureTarget`s added with `with_target`;
+//! each is handed the number and the IDs of the deleted sessions.
+//!
+//! The erasure is recorded on the system audit chain under a fresh erasure
+//! ID, with record counts only. Audit entries already on the chain are
+//! left as they are; rewriting them would break the hash chain. They hold
+//! nothing to erase: scripts the agent spoke are logged as digests, and
+//! entries about a customer, such as data exports, are keyed by
+//! `AuditLogger::customer_key`, a keyed hash of the number.
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use uuid::Uuid;
+use voice_agent_core::normalize_phone;
+
+use crate::{
+    AppointmentStore, AuditLog, AuditLogger, AuditOutcome, PersistenceError, ScheduledMessageStore,
+    SessionStore, SmsService,
+};
+
+/// Customer data held outside the persistence stores that an erasure must
+/// also reach, such as a CRM or the conversation recording
+#[async_trait]
+pub trait ErasureTarget: Send + Sync {
+    /// Key the target's count is reported under, e.g. "crm_leads_deleted"
+    fn name(&self) -> &'static str;
+
+    /// Erase what is held for the customer's `phone` or their deleted
+    /// sessions, returning how many records were erased
+    async fn erase(&self, phone: &str, session_ids: &[String]) -> Result<usize, PersistenceError>;
+}
+
+/// What an erasure removed
+#[derive(Debug, Clone, Serialize)]
+pub struct ErasureReport {
+    /// Key of the erasure's audit entry, for the requester's records
+    pub erasure_id: Uuid,
+    pub erased_at: DateTime<Utc>,
+    pub sessions_deleted: usize,
+    /// Sessions that carried agent memory
+    pub memory_deleted: usize,
+    pub appointments_anonymized: usize,
+    pub sms_anonymized: usize,
+    pub scheduled_sms_anonymized: usize,
+    /// Records erased by each `ErasureTarget`, by its name
+    #[serde(flatten)]
+    pub other_records: BTreeMap<String, usize>,
+}
+
+impl ErasureReport {
+    /// Number of records in each store, for the audit trail
+    pub fn record_counts(&self) -> serde_json::Value {
+        let mut counts = serde_json::json!({
+            "sessions_deleted": self.sessions_deleted,
+            "memory_deleted": self.memory_deleted,
+            "appointments_anonymized": self.appointments_anonymized,
+            "sms_anonymized": self.sms_anonymized,
+            "scheduled_sms_anonymized": self.scheduled_sms_anonymized,
+        });
+        for (name, count) in &self.other_records {
+            counts[name.as_str()] = serde_json::json!(count);
+        }
+        counts
+    }
+
+    /// Key the customer's anonymized records are moved under
+    pub fn pseudonym(&self) -> String {
+        format!("erased:{}", self.erasure_id)
+    }
+}
+
+/// Erases a customer's records across the persistence stores
+pub struct CustomerDataEraser {
+    sessions: Arc<dyn SessionStore>,
+    appointments: Arc<dyn AppointmentStore>,
+    sms: Arc<dyn SmsService>,
+    scheduled_sms: Option<Arc<dyn ScheduledMessageStore>>,
+    targets: Vec<Arc<dyn ErasureTarget>>,
+    audit: AuditLogger,
+}
+
+impl CustomerDataEraser {
+    pub fn new(
+        sessions: Arc<dyn SessionStore>,
+        appointments: Arc<dyn AppointmentStore>,
+        sms: Arc<dyn SmsService>,
+        audit: Arc<dyn AuditLog>,
+    ) -> Self {
+        Self {
+            sessions,
+            appointments,
+            sms,
+            scheduled_sms: None,
+            targets: Vec::new(),
+            audit: AuditLogger::new(audit),
+        }
+    }
+
+    /// Also anonymize the customer's scheduled SMS, such as reminders
+    pub fn with_scheduled_sms(mut self, store: Arc<dyn ScheduledMessageStore>) -> Self {
+        self.scheduled_sms = Some(store);
+        self
+    }
+
+    /// Also erase the customer's data held by `target`
+    pub fn with_target(mut self, target: Arc<dyn ErasureTarget>) -> Self {
+        self.targets.push(target);
+        self
+    }
+
+    /// Delete or anonymize every record keyed to `phone`
+    ///
+    /// The number is normalized first, as records are stored under the
+    /// normalized form. The erasure is audited either way; if a store fails
+    /// part way, the entry is logged as a failure with what had been erased
+    /// so far.
+    pub async fn delete_customer_data(
+        &self,
+        phone: &str,
+        requested_by: &str,
+    ) -> Result<ErasureReport, PersistenceError> {
+        let phone = normalize_phone(phone);
+        if phone.is_empty() {
+            return Err(PersistenceError::InvalidData(
+                "phone number has no digits".to_string(),
+            ));
+        }
+        let mut report = ErasureReport {
+            erasure_id: Uuid::new_v4(),
+            erased_at: Utc::now(),
+            sessions_deleted: 0,
+            memory_deleted: 0,
+            appointments_anonymized: 0,
+            sms_anonymized: 0,
+            scheduled_sms_anonymized: 0,
+            other_records: BTreeMap::new(),
+        };
+
+        match self.erase(&phone, &mut report).await {
+            Ok(()) => {
+                self.audit
+                    .log_data_erasure(
+                        report.erasure_id,
+                        requested_by,
+                        AuditOutcome::Success,
+                        report.record_counts(),
+                    )
+                    .await?;
+                tracing::info!(
+                    erasure_id = %report.erasure_id,
+                    "Customer data erased"
+                );
+                Ok(report)
+            },
+            Err(e) => {
+                tracing::error!(
+                    erasure_id = %report.erasure_id,
+                    error = %e,
+                    "Customer data erasure failed part way"
+                );
+                if let Err(audit_error) = self
+                    .audit
+                    .log_data_erasure(
+                        report.erasure_id,
+                        requested_by,
+                        AuditOutcome::Failure,
+                        report.record_counts(),
+                    )
+                    .await
+                {
+                    tracing::error!(error = %audit_error, "Failed to audit erasure failure");
+                }
+                Err(e)
+            },
+        }
+    }
+
+    async fn erase(&self, phone: &str, report: &mut ErasureReport) -> Result<(), PersistenceError> {
+        let sessions = self.sessions.list_for_customer(phone, i32::MAX).await?;
+        let mut session_ids = Vec::new();
+        for session in sessions
+            .iter()
+            .filter(|s| s.customer_phone.as_deref() == Some(phone))
+        {
+            self.sessions.delete(&session.session_id).await?;
+            session_ids.push(session.session_id.clone());
+            report.sessions_deleted += 1;
+            if session.memory_json.is_some() {
+                report.memory_deleted += 1;
+            }
+        }
+
+        let pseudonym = report.pseudonym();
+        report.appointments_anonymized = self
+            .appointments
+            .anonymize_customer(phone, &pseudonym)
+            .await?;
+        report.sms_anonymized = self.sms.anonymize_for_phone(phone, &pseudonym).await?;
+        if let Some(ref scheduled_sms) = self.scheduled_sms {
+            report.scheduled_sms_anonymized =
+                scheduled_sms.anonymize_for_phone(phone, &pseudonym).await?;
+        }
+
+        for target in &self.targets {
+            let erased = target.erase(phone, &session_ids).await?;
+            report
+                .other_records
+                .insert(target.name().to_string(), erased);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        Appointment, AuditEventType, AuditQuery, InMemoryAppointmentStore, InMemoryAuditLog,
+        InMemoryRedis, InMemoryScheduledMessageStore, InMemorySmsService, RedisSessionConfig,
+        RedisSessionStore, ReminderDispatcher, ScheduledMessage, ScheduledMessageStatus,
+        ScyllaAuditLog, SessionData, SmsType, ERASED_MESSAGE_TEXT,
+    };
+    use std::sync::Mutex;
+
+    const CUSTOMER: &str = "9876543210";
+    const OTHER: &str = "9123456780";
+
+    struct Stores {
+        sessions: Arc<RedisSessionStore>,
+        appointments: Arc<InMemoryAppointmentStore>,
+        sms: Arc<InMemorySmsService>,
+        scheduled_sms: Arc<InMemoryScheduledMessageStore>,
+        audit: Arc<InMemoryAuditLog>,
+    }
+
+    /// Target remembering the sessions it was asked to erase
+    #[derive(Default)]
+    struct RecordingTarget {
+        erased: Mutex<Vec<String>>,
+    }
+
+    #[async_trait]
+    impl ErasureTarget for RecordingTarget {
+        fn name(&self) -> &'static str {
+            "recordings_deleted"
+        }
+
+        async fn erase(
+            &self,
+            _phone: &str,
+            session_ids: &[String],
+        ) -> Result<usize, PersistenceError> {
+            self.erased.lock().unwrap().extend_from_slice(session_ids);
+            Ok(session_ids.len())
+        }
+    }
+
+    impl Stores {
+        fn new() -> Self {
+            Self {
+                sessions: Arc::new(RedisSessionStore::with_backend(
+                    Arc::new(InMemoryRedis::new()),
+                    RedisSessionConfig::default(),
+                )),
+                appointments: Arc::new(InMemoryAppointmentStore::new()),
+                sms: Arc::new(InMemorySmsService::new()),
+                scheduled_sms: Arc::new(InMemoryScheduledMessageStore::new()),
+                audit: Arc::new(InMemoryAuditLog::new()),
+            }
+        }
+
+        fn eraser(&self) -> CustomerDataEraser {
+            CustomerDataEraser::new(
+                self.sessions.clone(),
+                self.appointments.clone(),
+                self.sms.clone(),
+                self.audit.clone(),
+            )
+            .with_scheduled_sms(self.scheduled_sms.clone())
+        }
+
+        /// A call from `phone` with remembered facts, a booked appointment
+        /// and its confirmation SMS
+        async fn seed_customer(&self, session_id: &str, phone: &str) {
+            let mut session = SessionData::new(session_id);
+            session.customer_phone = Some(phone.to_string());
+            session.customer_name = Some("Priya Sharma".to_string());
+            session.memory_json = Some(format!(r#"{{"facts":["caller {}"]}}"#, phone));
+            self.sessions.create(&session).await.unwrap();
+
+            let date = Utc::now().date_naive() + chrono::Duration::days(2);
+            let mut appointment = Appointment::new(
+                phone,
+                "KMBL001",
+                "Andheri West",
+                "S.V. Road",
+                date,
+                "11:00 AM",
+            );
+            appointment.customer_name = Some("Priya Sharma".to_string());
+            appointment.notes = Some(format!("Call back on {}", phone));
+            self.appointments.create(&appointment).await.unwrap();
+
+            self.sms
+                .send_sms(
+                    phone,
+                    "Dear Priya Sharma, your visit is booked",
+                    SmsType::AppointmentConfirmation,
+                    Some(session_id),
+                )
+                .await
+                .unwrap();
+
+            let reminder = ScheduledMessage::new(
+                &appointment.appointment_id.to_string(),
+                phone,
+                "Reminder: Priya Sharma, your visit is tomorrow",
+                SmsType::AppointmentReminder,
+                Utc::now() + chrono::Duration::days(1),
+            );
+            self.scheduled_sms.schedule(&reminder).await.unwrap();
+        }
+    }
+
+    #[tokio::test]
+    async fn test_erasure_removes_the_customers_data_only() {
+        let stores = Stores::new();
+        stores.seed_customer("call-1", CUSTOMER).await;
+        stores.seed_customer("call-2", CUSTOMER).await;
+        stores.seed_customer("call-3", OTHER).await;
+
+        let report = stores
+            .eraser()
+            .delete_customer_data(CUSTOMER, "dpo-desk")
+            .await
+            .unwrap();
+
+        assert_eq!(report.sessions_deleted, 2);
+        assert_eq!(report.memory_deleted, 2);
+        assert_eq!(report.appointments_anonymized, 2);
+        assert_eq!(report.sms_anonymized, 2);
+
+        // Sessions, and the memory stored with them, are gone
+        assert!(stores.sessions.get("call-1").await.unwrap().is_none());
+        assert!(stores.sessions.get("call-2").await.unwrap().is_none());
+        assert!(stores
+            .sessions
+            .list_for_customer(CUSTOMER, 10)
+            .await
+            .unwrap()
+            .is_empty());
+
+        // Appointments and SMS are kept for the counts, without identity
+        assert!(stores
+            .appointments
+            .list_for_customer(CUSTOMER, 10)
+            .await
+            .unwrap()
+            .is_empty());
+        let appointments = stores
+            .appointments
+            .list_for_customer(&report.pseudonym(), 10)
+            .await
+            .unwrap();
+        assert_eq!(appointments.len(), 2);
+        assert!(appointments
+            .iter()
+            .all(|a| a.customer_name.is_none() && a.notes.is_none()));
+        assert_eq!(appointments[0].branch_id, "KMBL001");
+
+        assert!(stores
+            .sms
+            .get_messages_for_phone(CUSTOMER, 10)
+            .await
+            .unwrap()
+            .is_empty());
+        let sms = stores
+            .sms
+            .get_messages_for_phone(&report.pseudonym(), 10)
+            .await
+            .unwrap();
+        assert_eq!(sms.len(), 2);
+        assert!(sms.iter().all(|m| m.message_text == ERASED_MESSAGE_TEXT));
+        assert_eq!(sms[0].message_type, SmsType::AppointmentConfirmation);
+
+        // The other customer is untouched
+        assert!(stores.sessions.get("call-3").await.unwrap().is_some());
+        let other = stores
+            .appointments
+            .list_for_customer(OTHER, 10)
+            .await
+            .unwrap();
+        assert_eq!(other[0].customer_name.as_deref(), Some("Priya Sharma"));
+        assert_eq!(
+            stores
+                .sms
+                .get_messages_for_phone(OTHER, 10)
+                .await
+                .unwrap()
+                .len(),
+            1
+        );
+    }
+
+    #[tokio::test]
+    async fn test_erasure_cancels_pending_reminders() {
+        let stores = Stores::new();
+        stores.seed_customer("call-1", CUSTOMER).await;
+        stores.seed_customer("call-2", OTHER).await;
+
+        let report = stores
+            .eraser()
+            .delete_customer_data(CUSTOMER, "dpo-desk")
+            .await
+            .unwrap();
+        assert_eq!(report.scheduled_sms_anonymized, 1);
+
+        // Only the other customer's reminder still goes out
+        let dispatcher = ReminderDispatcher::new(stores.scheduled_sms.clone(), stores.sms.clone());
+        let sent = dispatcher
+            .dispatch_due(Utc::now() + chrono::Duration::days(2))
+            .await
+            .unwrap();
+        assert_eq!(sent, 1);
+
+        let appointment = &stores
+            .appointments
+            .list_for_customer(&report.pseudonym(), 10)
+            .await
+            .unwrap()[0];
+        let reminders = stores
+            .scheduled_sms
+            .list_for_reference(&appointment.appointment_id.to_string())
+            .await
+            .unwrap();
+        assert_eq!(reminders[0].status, ScheduledMessageStatus::Cancelled);
+        assert_eq!(reminders[0].phone_number, report.pseudonym());
+        assert_eq!(reminders[0].message_text, ERASED_MESSAGE_TEXT);
+    }
+
+    #[tokio::test]
+    async fn test_erasure_normalizes_the_number_and_reaches_targets() {
+        let stores = Stores::new();
+        stores.seed_customer("call-1", CUSTOMER).await;
+        stores.seed_customer("call-2", OTHER).await;
+        let target = Arc::new(RecordingTarget::default());
+
+        let report = stores
+            .eraser()
+            .with_target(target.clone())
+            .delete_customer_data("+91 98765-43210", "dpo-desk")
+            .await
+            .unwrap();
+
+        assert_eq!(report.sessions_deleted, 1);
+        assert_eq!(*target.erased.lock().unwrap(), vec!["call-1".to_string()]);
+        assert_eq!(report.record_counts()["recordings_deleted"], 1);
+
+        let error = stores
+            .eraser()
+            .delete_customer_data("unknown", "dpo-desk")
+            .await;
+        assert!(matches!(error, Err(PersistenceError::InvalidData(_))));
+    }
+
+    #[tokio::test]
+    async fn test_erasure_is_audited_without_the_erased_data() {
+        let stores = Stores::new();
+        stores.seed_customer("call-1", CUSTOMER).await;
+
+        let report = stores
+            .eraser()
+            .delete_customer_data(CUSTOMER, "dpo-desk")
+            .await
+            .unwrap();
+
+        let logger = AuditLogger::new(stores.audit.clone());
+        let page = logger
+            .query(AuditQuery {
+                event_type: Some(AuditEventType::DataErased),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        assert_eq!(page.entries.len(), 1);
+        let entry = &page.entries[0];
+        assert_eq!(entry.resource_id, report.erasure_id.to_string());
+        assert_eq!(entry.actor.actor_id, "dpo-desk");
+        assert_eq!(entry.outcome, AuditOutcome::Success);
+        assert_eq!(entry.details["records"]["sessions_deleted"], 1);
+        assert_eq!(entry.previous_hash, ScyllaAuditLog::genesis_hash());
+
+        let json = serde_json::to_string(entry).unwrap();
+        assert!(!json.contains(CUSTOMER) && !json.contains("Priya"));
+    }
+
+    #[tokio::test]
+    async fn test_no_audit_entry_holds_the_number_after_export_and_erasure() {
+        let stores = Stores::new();
+        stores.seed_customer("call-1", CUSTOMER).await;
+
+        let logger = AuditLogger::new(stores.audit.clone());
+        let customer = logger.customer_key(CUSTOMER);
+        logger
+            .log_data_export(&customer, "dpo-desk", serde_json::json!({ "sessions": 1 }))
+            .await
+            .unwrap();
+        stores
+            .eraser()
+            .delete_customer_data(CUSTOMER, "dpo-desk")
+            .await
+            .unwrap();
+
+        let page = logger.query(AuditQuery::default()).await.unwrap();
+        assert!(page
+            .entries
+            .iter()
+            .any(|e| e.event_type == AuditEventType::DataExported));
+        for entry in &page.entries {
+            assert!(!entry.chain_id().contains(CUSTOMER));
+            let json = serde_json::to_string(entry).unwrap();
+            assert!(!json.contains(CUSTOMER), "{} holds the number", json);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_erasing_an_unknown_customer_is_still_audited() {
+        let stores = Stores::new();
+
+        let report = stores
+            .eraser()
+            .delete_customer_data(CUSTOMER, "dpo-desk")
+            .await
+            .unwrap();
+
+        assert_eq!(report.sessions_deleted, 0);
+        assert_eq!(report.appointments_anonymized, 0);
+        assert_eq!(report.sms_anonymized, 0);
+        let page = AuditLogger::new(stores.audit.clone())
+            .query(AuditQuery {
+                event_type: Some(AuditEventType::DataErased),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        assert_eq!(page.entries.len(), 1);
+    }
+}
@@ -4,10 +4,10 @@ use thiserror::Error;
 
 #[derive(Error, Debug)]
 pub enum PersistenceError {
-    #[error("ScyllaDB connection error: {0}")]
+    #[error("Database connection error: {0}")]
     Connection(String),
 
-    #[error("ScyllaDB query error: {0}")]
+    #[error("Database query error: {0}")]
     Query(String),
 
     #[error("Serialization error: {0}")]
@@ -31,6 +31,13 @@ impl From<scylla::transport::errors::NewSessionError> for PersistenceError {
 
 impl From<scylla::transport::errors::QueryError> for PersistenceError {
     fn from(e: scylla::transport::errors::QueryError) -> Self {
-        PersistenceError::Query(e.to_string())
+        use scylla::transport::errors::QueryError;
+
+        match e {
+            QueryError::BrokenConnection(_)
+            | QueryError::ConnectionPoolError(_)
+            | QueryError::EmptyPlan => PersistenceError::Connection(e.to_string()),
+            _ => PersistenceError::Query(e.to_string()),
+        }
     }
 }
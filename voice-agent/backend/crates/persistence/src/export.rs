@@ -0,0 +1,363 @@
+//! Customer data export for access requests
+//!
+//! Under the DPDP Act a customer can ask for every record held about them.
+//! `CustomerDataExporter` gathers those records, keyed by the customer's
+//! phone number, from the session, appointment, SMS and audit stores into
+//! one `CustomerDataExport`. The agent's memory, archival notes included,
+//! is persisted with each session as `memory_json` and is exported as its
+//! own section. Audit entries are read from each of the customer's session
+//! chains. Each section is capped at `max_records`; sections that hit the
+//! cap are listed in `truncated`.
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use std::sync::Arc;
+
+use crate::{
+    Appointment, AppointmentStore, AuditEntry, AuditLog, AuditQuery, PersistenceError, SessionData,
+    SessionStore, SmsMessage, SmsService,
+};
+
+/// Records per section when no cap is set
+pub const DEFAULT_MAX_RECORDS: usize = 1000;
+
+/// Agent memory persisted with one session
+#[derive(Debug, Clone, Serialize)]
+pub struct SessionMemory {
+    pub session_id: String,
+    pub memory: serde_json::Value,
+}
+
+/// Everything held about one customer
+#[derive(Debug, Clone, Serialize)]
+pub struct CustomerDataExport {
+    pub customer_id: String,
+    pub generated_at: DateTime<Utc>,
+    pub sessions: Vec<SessionData>,
+    pub memory: Vec<SessionMemory>,
+    pub appointments: Vec<Appointment>,
+    pub sms: Vec<SmsMessage>,
+    pub audit: Vec<AuditEntry>,
+    /// Sections cut off at the record cap
+    pub truncated: Vec<&'static str>,
+}
+
+impl CustomerDataExport {
+    /// Number of records in each section, for the audit trail
+    pub fn record_counts(&self) -> serde_json::Value {
+        serde_json::json!({
+            "sessions": self.sessions.len(),
+            "memory": self.memory.len(),
+            "appointments": self.appointments.len(),
+            "sms": self.sms.len(),
+            "audit": self.audit.len(),
+        })
+    }
+}
+
+/// Gathers a customer's records across the persistence stores
+pub struct CustomerDataExporter {
+    sessions: Arc<dyn SessionStore>,
+    appointments: Arc<dyn AppointmentStore>,
+    sms: Arc<dyn SmsService>,
+    audit: Arc<dyn AuditLog>,
+    max_records: usize,
+}
+
+impl CustomerDataExporter {
+    pub fn new(
+        sessions: Arc<dyn SessionStore>,
+        appointments: Arc<dyn AppointmentStore>,
+        sms: Arc<dyn SmsService>,
+        audit: Arc<dyn AuditLog>,
+    ) -> Self {
+        Self {
+            sessions,
+            appointments,
+            sms,
+            audit,
+            max_records: DEFAULT_MAX_RECORDS,
+        }
+    }
+
+    /// Cap on the records exported per section
+    pub fn with_max_records(mut self, max_records: usize) -> Self {
+        self.max_records = max_records.max(1);
+        self
+    }
+
+    /// Export every record keyed to `phone`
+    pub async fn export(&self, phone: &str) -> Result<CustomerDataExport, PersistenceError> {
+        // One over the cap, to tell a full section from a truncated one
+        let fetch = i32::try_from(self.max_records + 1).unwrap_or(i32::MAX);
+        let mut truncated = Vec::new();
+
+        let mut sessions = self.sessions.list_for_customer(phone, fetch).await?;
+        self.cap(&mut sessions, "sessions", &mut truncated);
+        // Stores keyed by phone only ever return this customer's records;
+        // the filters guard against a store that matches loosely
+        sessions.retain(|s| s.customer_phone.as_deref() == Some(phone));
+
+        let memory = sessions
+            .iter()
+            .filter_map(|s| {
+                let memory = serde_json::from_str(s.memory_json.as_deref()?).ok()?;
+                Some(SessionMemory {
+                    session_id: s.session_id.clone(),
+                    memory,
+                })
+            })
+            .collect();
+
+        let mut appointments = self.appointments.list_for_customer(phone, fetch).await?;
+        self.cap(&mut appointments, "appointments", &mut truncated);
+        appointments.retain(|a| a.customer_phone == phone);
+
+        let mut sms = self.sms.get_messages_for_phone(phone, fetch).await?;
+        self.cap(&mut sms, "sms", &mut truncated);
+        sms.retain(|m| m.phone_number == phone);
+
+        let mut audit = Vec::new();
+        for session in &sessions {
+            audit.extend(self.session_audit(session).await?);
+            if audit.len() > self.max_records {
+                break;
+            }
+        }
+        self.cap(&mut audit, "audit", &mut truncated);
+
+        Ok(CustomerDataExport {
+            customer_id: phone.to_string(),
+            generated_at: Utc::now(),
+            sessions,
+            memory,
+            appointments,
+            sms,
+            audit,
+            truncated,
+        })
+    }
+
+    /// Audit entries on a session's chain, oldest first
+    async fn session_audit(
+        &self,
+        session: &SessionData,
+    ) -> Result<Vec<AuditEntry>, PersistenceError> {
+        // From the start of the day the session was created
+        let from = session
+            .created_at
+            .date_naive()
+            .and_hms_opt(0, 0, 0)
+            .map(|start| start.and_utc());
+        let mut entries = Vec::new();
+        let mut after = None;
+        loop {
+            let page = self
+                .audit
+                .query(AuditQuery {
+                    session_id: Some(session.session_id.clone()),
+                    from,
+                    to: Some(Utc::now()),
+                    limit: Some(i32::MAX),
+                    after,
+                    ..Default::default()
+                })
+                .await?;
+            entries.extend(page.entries);
+            match page.next_cursor {
+                Some(cursor) if entries.len() <= self.max_records => after = Some(cursor),
+                _ => return Ok(entries),
+            }
+        }
+    }
+
+    fn cap<T>(
+        &self,
+        records: &mut Vec<T>,
+        section: &'static str,
+        truncated: &mut Vec<&'static str>,
+    ) {
+        if records.len() > self.max_records {
+            records.truncate(self.max_records);
+            truncated.push(section);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        AuditEventType, AuditLogger, AuditOutcome, InMemoryAppointmentStore, InMemoryAuditLog,
+        InMemoryRedis, InMemorySmsService, RedisSessionConfig, RedisSessionStore, ScyllaAuditLog,
+        SmsType,
+    };
+
+    const CUSTOMER: &str = "9876543210";
+    const OTHER: &str = "9123456780";
+
+    struct Stores {
+        sessions: Arc<RedisSessionStore>,
+        appointments: Arc<InMemoryAppointmentStore>,
+        sms: Arc<InMemorySmsService>,
+        audit: Arc<InMemoryAuditLog>,
+    }
+
+    impl Stores {
+        fn new() -> Self {
+            Self {
+                sessions: Arc::new(RedisSessionStore::with_backend(
+                    Arc::new(InMemoryRedis::new()),
+                    RedisSessionConfig::default(),
+                )),
+                appointments: Arc::new(InMemoryAppointmentStore::new()),
+                sms: Arc::new(InMemorySmsService::new()),
+                audit: Arc::new(InMemoryAuditLog::new()),
+            }
+        }
+
+        fn exporter(&self) -> CustomerDataExporter {
+            CustomerDataExporter::new(
+                self.sessions.clone(),
+                self.appointments.clone(),
+                self.sms.clone(),
+                self.audit.clone(),
+            )
+        }
+
+        /// A call from `phone`, with an appointment, a confirmation SMS
+        /// and the conversation's audit trail
+        async fn seed_customer(&self, session_id: &str, phone: &str) {
+            let mut session = SessionData::new(session_id);
+            session.customer_phone = Some(phone.to_string());
+            session.memory_json = Some(format!(r#"{{"facts":["caller {}"]}}"#, phone));
+            self.sessions.create(&session).await.unwrap();
+
+            let date = Utc::now().date_naive() + chrono::Duration::days(2);
+            let appointment = Appointment::new(
+                phone,
+                "KMBL001",
+                "Andheri West",
+                "S.V. Road",
+                date,
+                "11:00 AM",
+            );
+            self.appointments.create(&appointment).await.unwrap();
+
+            self.sms
+                .send_sms(
+                    phone,
+                    "Your visit is booked",
+                    SmsType::AppointmentConfirmation,
+                    Some(session_id),
+                )
+                .await
+                .unwrap();
+
+            let logger = AuditLogger::new(self.audit.clone());
+            logger
+                .log_conversation_start(session_id, "hi")
+                .await
+                .unwrap();
+            logger
+                .log_conversation_end(session_id, "user_ended", 90)
+                .await
+                .unwrap();
+        }
+    }
+
+    #[tokio::test]
+    async fn test_export_contains_all_of_the_customers_records_only() {
+        let stores = Stores::new();
+        stores.seed_customer("call-1", CUSTOMER).await;
+        stores.seed_customer("call-2", CUSTOMER).await;
+        stores.seed_customer("call-3", OTHER).await;
+        // A session where the caller never gave a number
+        stores
+            .sessions
+            .create(&SessionData::new("call-4"))
+            .await
+            .unwrap();
+
+        let export = stores.exporter().export(CUSTOMER).await.unwrap();
+
+        let mut session_ids: Vec<&str> = export
+            .sessions
+            .iter()
+            .map(|s| s.session_id.as_str())
+            .collect();
+        session_ids.sort();
+        assert_eq!(session_ids, vec!["call-1", "call-2"]);
+        assert_eq!(export.memory.len(), 2);
+        assert!(export
+            .memory
+            .iter()
+            .all(|m| m.memory["facts"][0] == format!("caller {}", CUSTOMER)));
+        assert_eq!(export.appointments.len(), 2);
+        assert!(export
+            .appointments
+            .iter()
+            .all(|a| a.customer_phone == CUSTOMER));
+        assert_eq!(export.sms.len(), 2);
+        assert!(export.sms.iter().all(|m| m.phone_number == CUSTOMER));
+        assert_eq!(export.audit.len(), 4);
+        assert!(export
+            .audit
+            .iter()
+            .all(|e| matches!(e.actor.session_id.as_deref(), Some("call-1" | "call-2"))));
+        assert!(export.truncated.is_empty());
+
+        // Nothing from the other customer shows up anywhere in the bundle
+        let json = serde_json::to_string(&export).unwrap();
+        assert!(!json.contains(OTHER) && !json.contains("call-3"));
+    }
+
+    #[tokio::test]
+    async fn test_sections_over_the_cap_are_truncated() {
+        let stores = Stores::new();
+        stores.seed_customer("call-1", CUSTOMER).await;
+        stores.seed_customer("call-2", CUSTOMER).await;
+
+        let export = stores
+            .exporter()
+            .with_max_records(1)
+            .export(CUSTOMER)
+            .await
+            .unwrap();
+
+        assert_eq!(export.sessions.len(), 1);
+        assert_eq!(export.sms.len(), 1);
+        assert_eq!(export.audit.len(), 1);
+        assert_eq!(
+            export.truncated,
+            vec!["sessions", "appointments", "sms", "audit"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_export_is_logged_on_the_system_chain() {
+        let audit = Arc::new(InMemoryAuditLog::new());
+        let logger = AuditLogger::new(audit.clone());
+        let customer = logger.customer_key(CUSTOMER);
+        logger
+            .log_data_export(&customer, "dpo-desk", serde_json::json!({ "sessions": 2 }))
+            .await
+            .unwrap();
+
+        let page = logger
+            .query(AuditQuery {
+                event_type: Some(AuditEventType::DataExported),
+                resource_id: Some(customer.to_string()),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        assert_eq!(page.entries.len(), 1);
+        let entry = &page.entries[0];
+        assert_eq!(entry.actor.actor_type, "admin");
+        assert_eq!(entry.actor.actor_id, "dpo-desk");
+        assert_eq!(entry.outcome, AuditOutcome::Success);
+        assert_eq!(entry.details["records"]["sessions"], 2);
+        assert_eq!(entry.previous_hash, ScyllaAuditLog::genesis_hash());
+    }
+}
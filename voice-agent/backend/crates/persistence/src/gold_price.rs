@@ -223,7 +223,7 @@ impl SimulatedAssetPriceService {
             self.client.keyspace()
         );
 
-        let result = self.client.session().query_unpaged(query, &[]).await?;
+        let result = self.client.query_idempotent(query, &[]).await?;
 
         if let Some(rows) = result.rows {
             if let Some(row) = rows.into_iter().next() {
@@ -259,8 +259,7 @@ impl SimulatedAssetPriceService {
         );
 
         self.client
-            .session()
-            .query_unpaged(
+            .query(
                 query,
                 (
                     price.base_price_per_unit,
@@ -291,8 +290,7 @@ impl SimulatedAssetPriceService {
         );
 
         self.client
-            .session()
-            .query_unpaged(
+            .query(
                 query,
                 (
                     date.to_string(),
@@ -352,8 +350,7 @@ impl AssetPriceService for SimulatedAssetPriceService {
 
         let result = self
             .client
-            .session()
-            .query_unpaged(query, (date.to_string(),))
+            .query_idempotent(query, (date.to_string(),))
             .await?;
 
         if let Some(rows) = result.rows {
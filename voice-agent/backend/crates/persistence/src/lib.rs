@@ -1,32 +1,55 @@
 //! ScyllaDB persistence layer for voice-agent-rust
 //!
 //! Provides persistent storage for:
-//! - Sessions (replaces Redis stub)
+//! - Sessions (ScyllaDB, or Redis via `RedisSessionStore`)
 //! - SMS messages (simulated, persisted for audit)
 //! - Gold prices (simulated with realistic fluctuation)
-//! - Appointments
+//! - Appointments and scheduled reminder SMS
 //! - Audit logging (P0 FIX: RBI compliance)
+//! - Customer data export and erasure for access requests
 
 pub mod appointments;
 pub mod audit;
 pub mod client;
+pub mod erasure;
 pub mod error;
+pub mod export;
 pub mod gold_price;
+pub mod pool;
+pub mod redis_sessions;
+pub mod reminders;
 pub mod schema;
 pub mod sessions;
 pub mod sms;
 
-pub use appointments::{Appointment, AppointmentStatus, AppointmentStore, ScyllaAppointmentStore};
+pub use appointments::{
+    Appointment, AppointmentStatus, AppointmentStore, InMemoryAppointmentStore,
+    ScyllaAppointmentStore,
+};
 pub use audit::{
-    Actor, AuditEntry, AuditEventType, AuditLog, AuditLogger, AuditOutcome, AuditQuery,
+    check_chain, Actor, AuditCursor, AuditEntry, AuditEventType, AuditLog, AuditLogger,
+    AuditOutcome, AuditPage, AuditQuery, ChainBreak, ChainBreakKind, CustomerKey, InMemoryAuditLog,
     ScyllaAuditLog,
 };
 pub use client::{ScyllaClient, ScyllaConfig};
+pub use erasure::{CustomerDataEraser, ErasureReport, ErasureTarget};
 pub use error::PersistenceError;
+pub use export::{CustomerDataExport, CustomerDataExporter, SessionMemory, DEFAULT_MAX_RECORDS};
+pub use pool::{ConnectionManager, Connector, PoolMetrics, ReconnectPolicy};
+pub use redis_sessions::{
+    InMemoryRedis, RedisBackend, RedisClient, RedisSessionConfig, RedisSessionStore,
+};
 // Asset price types (domain-agnostic)
 pub use gold_price::{AssetPrice, AssetPriceService, SimulatedAssetPriceService, TierDefinition};
+pub use reminders::{
+    InMemoryScheduledMessageStore, ReminderDispatcher, ScheduledMessage, ScheduledMessageStatus,
+    ScheduledMessageStore, ScyllaScheduledMessageStore,
+};
 pub use sessions::{ScyllaSessionStore, SessionData, SessionStore};
-pub use sms::{SimulatedSmsService, SmsMessage, SmsService, SmsStatus, SmsType};
+pub use sms::{
+    InMemorySmsService, SimulatedSmsService, SmsBrandContext, SmsMessage, SmsService, SmsStatus,
+    SmsType, ERASED_MESSAGE_TEXT,
+};
 
 /// Initialize the persistence layer with ScyllaDB and domain-specific tiers
 ///
@@ -47,7 +70,9 @@ pub async fn init(
         sms: SimulatedSmsService::new(client.clone()),
         asset_price: SimulatedAssetPriceService::new(client.clone(), base_price, tiers),
         appointments: ScyllaAppointmentStore::new(client.clone()),
-        audit: ScyllaAuditLog::new(client),
+        reminders: ScyllaScheduledMessageStore::new(client.clone()),
+        audit: ScyllaAuditLog::new(client.clone()),
+        client,
     })
 }
 
@@ -59,7 +84,11 @@ pub struct PersistenceLayer {
     /// Asset price service with config-driven tier support
     pub asset_price: SimulatedAssetPriceService,
     pub appointments: ScyllaAppointmentStore,
+    /// Scheduled SMS such as appointment reminders
+    pub reminders: ScyllaScheduledMessageStore,
     /// Audit logging for compliance
     pub audit: ScyllaAuditLog,
+    /// Shared client, for health checks and pool metrics
+    pub client: ScyllaClient,
 }
 
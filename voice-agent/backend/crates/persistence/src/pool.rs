@@ -0,0 +1,361 @@
+//! Connection Management
+//!
+//! Keeps one live connection (for ScyllaDB, a driver `Session` with its own
+//! per-node pools), reconnects with exponential backoff when a health check
+//! or a request fails on it, and counts reconnects for metrics. The `Connector` trait is the
+//! seam that lets the reconnection logic run against a mock in tests.
+
+use crate::PersistenceError;
+use async_trait::async_trait;
+use serde::Serialize;
+use std::future::Future;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+
+/// Backoff between connection attempts
+#[derive(Debug, Clone)]
+pub struct ReconnectPolicy {
+    /// Delay before the second attempt
+    pub initial_backoff: Duration,
+    /// Upper bound on the delay between attempts
+    pub max_backoff: Duration,
+    /// Attempts per connect or reconnect before giving up
+    pub max_attempts: u32,
+}
+
+impl Default for ReconnectPolicy {
+    fn default() -> Self {
+        Self {
+            initial_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_secs(10),
+            max_attempts: 5,
+        }
+    }
+}
+
+impl ReconnectPolicy {
+    /// Delay after the given failed attempt (1-based), doubling each time
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        let factor = 2u32.saturating_pow(attempt.saturating_sub(1));
+        self.initial_backoff
+            .saturating_mul(factor)
+            .min(self.max_backoff)
+    }
+}
+
+/// Opens and checks connections of one kind
+#[async_trait]
+pub trait Connector: Send + Sync + 'static {
+    type Connection: Send + Sync + 'static;
+
+    /// Open a new connection
+    async fn connect(&self) -> Result<Self::Connection, PersistenceError>;
+
+    /// Cheap round trip to check a connection still works
+    async fn ping(&self, connection: &Self::Connection) -> Result<(), PersistenceError>;
+
+    /// Open connections behind this connection (e.g. across all nodes)
+    fn active_connections(&self, connection: &Self::Connection) -> usize;
+}
+
+/// Connection pool metrics
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct PoolMetrics {
+    pub active_connections: usize,
+    pub reconnect_count: u64,
+    pub failed_health_checks: u64,
+    pub healthy: bool,
+}
+
+/// Holds the current connection and replaces it when it stops working
+pub struct ConnectionManager<C: Connector> {
+    connector: C,
+    policy: ReconnectPolicy,
+    current: RwLock<Arc<C::Connection>>,
+    /// Only one task reconnects at a time; the rest wait and reuse its result
+    reconnecting: tokio::sync::Mutex<()>,
+    reconnects: AtomicU64,
+    failed_health_checks: AtomicU64,
+    healthy: AtomicBool,
+}
+
+impl<C: Connector> ConnectionManager<C> {
+    /// Connect, retrying with backoff
+    pub async fn connect(connector: C, policy: ReconnectPolicy) -> Result<Self, PersistenceError> {
+        let connection = connect_with_backoff(&connector, &policy).await?;
+        Ok(Self {
+            connector,
+            policy,
+            current: RwLock::new(Arc::new(connection)),
+            reconnecting: tokio::sync::Mutex::new(()),
+            reconnects: AtomicU64::new(0),
+            failed_health_checks: AtomicU64::new(0),
+            healthy: AtomicBool::new(true),
+        })
+    }
+
+    /// The current connection
+    pub fn get(&self) -> Arc<C::Connection> {
+        self.current.read().unwrap().clone()
+    }
+
+    /// Ping the current connection, reconnecting if it fails
+    ///
+    /// Returns whether a working connection is available afterwards.
+    pub async fn health_check(&self) -> bool {
+        let connection = self.get();
+        match self.connector.ping(&connection).await {
+            Ok(()) => {
+                self.healthy.store(true, Ordering::Relaxed);
+                true
+            },
+            Err(e) => {
+                tracing::warn!(error = %e, "Connection health check failed, reconnecting");
+                self.failed_health_checks.fetch_add(1, Ordering::Relaxed);
+                self.healthy.store(false, Ordering::Relaxed);
+                self.reconnect(&connection).await.is_ok()
+            },
+        }
+    }
+
+    /// Run `op` on the current connection, reconnecting if it fails with a
+    /// connection error
+    ///
+    /// With `retry`, `op` runs again on the new connection. Statements that
+    /// aren't safe to repeat leave it unset: their error is returned and the
+    /// next request uses the new connection.
+    pub async fn run<T, F, Fut>(&self, retry: bool, op: F) -> Result<T, PersistenceError>
+    where
+        F: Fn(Arc<C::Connection>) -> Fut,
+        Fut: Future<Output = Result<T, PersistenceError>>,
+    {
+        let connection = self.get();
+        let error = match op(connection.clone()).await {
+            Err(e @ PersistenceError::Connection(_)) => e,
+            result => return result,
+        };
+
+        tracing::warn!(error = %error, "Connection failed during request, reconnecting");
+        self.healthy.store(false, Ordering::Relaxed);
+        match self.reconnect(&connection).await {
+            Ok(connection) if retry => op(connection).await,
+            _ => Err(error),
+        }
+    }
+
+    /// Replace `failed` with a new connection
+    ///
+    /// If another task already replaced it, that connection is returned
+    /// without connecting again.
+    pub async fn reconnect(
+        &self,
+        failed: &Arc<C::Connection>,
+    ) -> Result<Arc<C::Connection>, PersistenceError> {
+        let _guard = self.reconnecting.lock().await;
+        let current = self.get();
+        if !Arc::ptr_eq(&current, failed) {
+            return Ok(current);
+        }
+
+        let connection = Arc::new(connect_with_backoff(&self.connector, &self.policy).await?);
+        *self.current.write().unwrap() = connection.clone();
+        self.reconnects.fetch_add(1, Ordering::Relaxed);
+        self.healthy.store(true, Ordering::Relaxed);
+        tracing::info!("Reconnected");
+        Ok(connection)
+    }
+
+    pub fn metrics(&self) -> PoolMetrics {
+        PoolMetrics {
+            active_connections: self.connector.active_connections(&self.get()),
+            reconnect_count: self.reconnects.load(Ordering::Relaxed),
+            failed_health_checks: self.failed_health_checks.load(Ordering::Relaxed),
+            healthy: self.healthy.load(Ordering::Relaxed),
+        }
+    }
+}
+
+async fn connect_with_backoff<C: Connector>(
+    connector: &C,
+    policy: &ReconnectPolicy,
+) -> Result<C::Connection, PersistenceError> {
+    let mut attempt = 1;
+    loop {
+        match connector.connect().await {
+            Ok(connection) => return Ok(connection),
+            Err(e) if attempt >= policy.max_attempts => return Err(e),
+            Err(e) => {
+                let delay = policy.backoff(attempt);
+                tracing::warn!(error = %e, attempt, ?delay, "Connection attempt failed, retrying");
+                tokio::time::sleep(delay).await;
+                attempt += 1;
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::AtomicUsize;
+
+    /// Single-node "cluster" that can be taken down and brought back
+    #[derive(Default)]
+    struct MockCluster {
+        down: AtomicBool,
+        /// Bumped on failure; connections from an older epoch stay broken
+        epoch: AtomicUsize,
+        connects: AtomicUsize,
+    }
+
+    impl MockCluster {
+        fn fail(&self) {
+            self.down.store(true, Ordering::SeqCst);
+            self.epoch.fetch_add(1, Ordering::SeqCst);
+        }
+
+        fn recover(&self) {
+            self.down.store(false, Ordering::SeqCst);
+        }
+    }
+
+    struct MockConnector(Arc<MockCluster>);
+
+    #[async_trait]
+    impl Connector for MockConnector {
+        type Connection = usize;
+
+        async fn connect(&self) -> Result<usize, PersistenceError> {
+            self.0.connects.fetch_add(1, Ordering::SeqCst);
+            if self.0.down.load(Ordering::SeqCst) {
+                return Err(PersistenceError::Connection("node down".to_string()));
+            }
+            Ok(self.0.epoch.load(Ordering::SeqCst))
+        }
+
+        async fn ping(&self, connection: &usize) -> Result<(), PersistenceError> {
+            if self.0.down.load(Ordering::SeqCst)
+                || *connection != self.0.epoch.load(Ordering::SeqCst)
+            {
+                return Err(PersistenceError::Connection("connection reset".to_string()));
+            }
+            Ok(())
+        }
+
+        fn active_connections(&self, _connection: &usize) -> usize {
+            if self.0.down.load(Ordering::SeqCst) {
+                0
+            } else {
+                4
+            }
+        }
+    }
+
+    fn fast_policy() -> ReconnectPolicy {
+        ReconnectPolicy {
+            initial_backoff: Duration::from_millis(1),
+            max_backoff: Duration::from_millis(5),
+            max_attempts: 3,
+        }
+    }
+
+    #[test]
+    fn test_backoff_doubles_up_to_max() {
+        let policy = ReconnectPolicy::default();
+        assert_eq!(policy.backoff(1), Duration::from_millis(100));
+        assert_eq!(policy.backoff(2), Duration::from_millis(200));
+        assert_eq!(policy.backoff(4), Duration::from_millis(800));
+        assert_eq!(policy.backoff(20), Duration::from_secs(10));
+    }
+
+    #[tokio::test]
+    async fn test_reconnects_after_failure() {
+        let cluster = Arc::new(MockCluster::default());
+        let manager = ConnectionManager::connect(MockConnector(cluster.clone()), fast_policy())
+            .await
+            .unwrap();
+        assert!(manager.health_check().await);
+        assert_eq!(manager.metrics().active_connections, 4);
+        let original = manager.get();
+
+        // Node goes down: the health check fails and reconnection is attempted
+        cluster.fail();
+        assert!(!manager.health_check().await);
+        let metrics = manager.metrics();
+        assert!(!metrics.healthy);
+        assert_eq!(metrics.failed_health_checks, 1);
+        assert_eq!(metrics.reconnect_count, 0);
+        assert_eq!(metrics.active_connections, 0);
+        assert_eq!(cluster.connects.load(Ordering::SeqCst), 1 + 3);
+
+        // Node comes back: the stale connection fails its ping and is replaced
+        cluster.recover();
+        assert!(manager.health_check().await);
+        let metrics = manager.metrics();
+        assert!(metrics.healthy);
+        assert_eq!(metrics.failed_health_checks, 2);
+        assert_eq!(metrics.reconnect_count, 1);
+        assert!(!Arc::ptr_eq(&original, &manager.get()));
+
+        // Later checks reuse the new connection
+        assert!(manager.health_check().await);
+        assert_eq!(manager.metrics().reconnect_count, 1);
+    }
+
+    #[tokio::test]
+    async fn test_request_reconnects_after_failure() {
+        let cluster = Arc::new(MockCluster::default());
+        let manager = ConnectionManager::connect(MockConnector(cluster.clone()), fast_policy())
+            .await
+            .unwrap();
+        let request = |connection: Arc<usize>| {
+            let connector = MockConnector(cluster.clone());
+            async move { connector.ping(&connection).await }
+        };
+
+        // The node restarts between requests, breaking the open connection
+        cluster.fail();
+        cluster.recover();
+
+        // A repeatable request reconnects and runs again
+        assert!(manager.run(true, request).await.is_ok());
+        assert_eq!(manager.metrics().reconnect_count, 1);
+        assert!(manager.metrics().healthy);
+
+        // Any other request fails, but the next one gets a new connection
+        cluster.fail();
+        cluster.recover();
+        assert!(manager.run(false, request).await.is_err());
+        assert_eq!(manager.metrics().reconnect_count, 2);
+        assert!(manager.run(false, request).await.is_ok());
+        assert_eq!(manager.metrics().reconnect_count, 2);
+    }
+
+    #[tokio::test]
+    async fn test_concurrent_reconnects_connect_once() {
+        let cluster = Arc::new(MockCluster::default());
+        let manager = ConnectionManager::connect(MockConnector(cluster.clone()), fast_policy())
+            .await
+            .unwrap();
+        let failed = manager.get();
+
+        let (a, b) = tokio::join!(manager.reconnect(&failed), manager.reconnect(&failed));
+        assert!(Arc::ptr_eq(&a.unwrap(), &b.unwrap()));
+        assert_eq!(manager.metrics().reconnect_count, 1);
+        assert_eq!(cluster.connects.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_connect_gives_up_after_max_attempts() {
+        let cluster = Arc::new(MockCluster::default());
+        cluster.fail();
+
+        assert!(
+            ConnectionManager::connect(MockConnector(cluster.clone()), fast_policy())
+                .await
+                .is_err()
+        );
+        assert_eq!(cluster.connects.load(Ordering::SeqCst), 3);
+    }
+}
@@ -0,0 +1,522 @@
+//! Session persistence using Redis
+//!
+//! Alternative to `ScyllaSessionStore` for deployments that already run
+//! Redis. Each session is one JSON-encoded `SessionData` value whose key
+//! expires with the session, so Redis drops abandoned sessions on its own.
+//! A sorted set scored by expiry time indexes the sessions for
+//! `list_active`; index members whose key has expired are pruned lazily.
+//!
+//! The `RedisBackend` trait is the seam between the store and the server:
+//! `RedisClient` talks to a real Redis, `InMemoryRedis` stands in for it in
+//! tests and local development.
+
+use crate::sessions::{SessionData, SessionStore};
+use crate::PersistenceError;
+use async_trait::async_trait;
+use chrono::Utc;
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// Redis session store configuration
+#[derive(Debug, Clone)]
+pub struct RedisSessionConfig {
+    /// Connection URL, e.g. `redis://127.0.0.1:6379`
+    pub url: String,
+    /// Prefix for all keys written by the store
+    pub key_prefix: String,
+    /// How long a session lives after its last activity
+    pub session_ttl: Duration,
+}
+
+impl Default for RedisSessionConfig {
+    fn default() -> Self {
+        Self {
+            url: "redis://127.0.0.1:6379".to_string(),
+            key_prefix: "voice_agent:session:".to_string(),
+            session_ttl: Duration::from_secs(3600),
+        }
+    }
+}
+
+/// The Redis commands the session store needs
+#[async_trait]
+pub trait RedisBackend: Send + Sync {
+    /// `SET key value PX ttl`
+    async fn set_with_ttl(
+        &self,
+        key: &str,
+        value: &str,
+        ttl: Duration,
+    ) -> Result<(), PersistenceError>;
+
+    /// `GET key`
+    async fn get(&self, key: &str) -> Result<Option<String>, PersistenceError>;
+
+    /// `DEL key`
+    async fn del(&self, key: &str) -> Result<(), PersistenceError>;
+
+    /// `ZADD key score member`
+    async fn zadd(&self, key: &str, member: &str, score: i64) -> Result<(), PersistenceError>;
+
+    /// `ZREM key member`
+    async fn zrem(&self, key: &str, member: &str) -> Result<(), PersistenceError>;
+
+    /// `ZREMRANGEBYSCORE key -inf (max`
+    async fn zrem_below(&self, key: &str, max: i64) -> Result<(), PersistenceError>;
+
+    /// `ZRANGEBYSCORE key min +inf LIMIT 0 limit`
+    async fn zrange_from(
+        &self,
+        key: &str,
+        min: i64,
+        limit: usize,
+    ) -> Result<Vec<String>, PersistenceError>;
+}
+
+impl From<redis::RedisError> for PersistenceError {
+    fn from(e: redis::RedisError) -> Self {
+        if e.is_io_error() || e.is_connection_refusal() || e.is_connection_dropped() {
+            PersistenceError::Connection(e.to_string())
+        } else {
+            PersistenceError::Query(e.to_string())
+        }
+    }
+}
+
+/// Redis backend over a multiplexed connection that reconnects on its own
+#[derive(Clone)]
+pub struct RedisClient {
+    connection: redis::aio::ConnectionManager,
+}
+
+impl RedisClient {
+    pub async fn connect(url: &str) -> Result<Self, PersistenceError> {
+        let client = redis::Client::open(url)?;
+        let connection = client.get_connection_manager().await?;
+        tracing::info!("Connected to Redis");
+        Ok(Self { connection })
+    }
+}
+
+#[async_trait]
+impl RedisBackend for RedisClient {
+    async fn set_with_ttl(
+        &self,
+        key: &str,
+        value: &str,
+        ttl: Duration,
+    ) -> Result<(), PersistenceError> {
+        use redis::AsyncCommands;
+        let millis = (ttl.as_millis() as u64).max(1);
+        self.connection
+            .clone()
+            .pset_ex::<_, _, ()>(key, value, millis)
+            .await?;
+        Ok(())
+    }
+
+    async fn get(&self, key: &str) -> Result<Option<String>, PersistenceError> {
+        use redis::AsyncCommands;
+        Ok(self.connection.clone().get(key).await?)
+    }
+
+    async fn del(&self, key: &str) -> Result<(), PersistenceError> {
+        use redis::AsyncCommands;
+        self.connection.clone().del::<_, ()>(key).await?;
+        Ok(())
+    }
+
+    async fn zadd(&self, key: &str, member: &str, score: i64) -> Result<(), PersistenceError> {
+        use redis::AsyncCommands;
+        self.connection
+            .clone()
+            .zadd::<_, _, _, ()>(key, member, score)
+            .await?;
+        Ok(())
+    }
+
+    async fn zrem(&self, key: &str, member: &str) -> Result<(), PersistenceError> {
+        use redis::AsyncCommands;
+        self.connection
+            .clone()
+            .zrem::<_, _, ()>(key, member)
+            .await?;
+        Ok(())
+    }
+
+    async fn zrem_below(&self, key: &str, max: i64) -> Result<(), PersistenceError> {
+        use redis::AsyncCommands;
+        self.connection
+            .clone()
+            .zrembyscore::<_, _, _, ()>(key, "-inf", format!("({}", max))
+            .await?;
+        Ok(())
+    }
+
+    async fn zrange_from(
+        &self,
+        key: &str,
+        min: i64,
+        limit: usize,
+    ) -> Result<Vec<String>, PersistenceError> {
+        use redis::AsyncCommands;
+        Ok(self
+            .connection
+            .clone()
+            .zrangebyscore_limit(key, min, "+inf", 0, limit as isize)
+            .await?)
+    }
+}
+
+/// In-process stand-in for Redis, with a clock that tests can move forward
+#[derive(Default)]
+pub struct InMemoryRedis {
+    state: Mutex<InMemoryRedisState>,
+}
+
+#[derive(Default)]
+struct InMemoryRedisState {
+    /// Time skipped by `advance`
+    offset: Duration,
+    values: HashMap<String, (String, Instant)>,
+    sorted_sets: HashMap<String, BTreeMap<String, i64>>,
+}
+
+impl InMemoryRedisState {
+    fn now(&self) -> Instant {
+        Instant::now() + self.offset
+    }
+}
+
+impl InMemoryRedis {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Move the clock forward, expiring keys whose TTL runs out
+    pub fn advance(&self, by: Duration) {
+        let mut state = self.state.lock().unwrap();
+        state.offset += by;
+        let now = state.now();
+        state.values.retain(|_, (_, deadline)| *deadline > now);
+    }
+
+    /// Number of unexpired keys (sorted sets not included)
+    pub fn len(&self) -> usize {
+        let state = self.state.lock().unwrap();
+        let now = state.now();
+        state
+            .values
+            .values()
+            .filter(|(_, deadline)| *deadline > now)
+            .count()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+#[async_trait]
+impl RedisBackend for InMemoryRedis {
+    async fn set_with_ttl(
+        &self,
+        key: &str,
+        value: &str,
+        ttl: Duration,
+    ) -> Result<(), PersistenceError> {
+        let mut state = self.state.lock().unwrap();
+        let deadline = state.now() + ttl;
+        state
+            .values
+            .insert(key.to_string(), (value.to_string(), deadline));
+        Ok(())
+    }
+
+    async fn get(&self, key: &str) -> Result<Option<String>, PersistenceError> {
+        let state = self.state.lock().unwrap();
+        let now = state.now();
+        Ok(state
+            .values
+            .get(key)
+            .filter(|(_, deadline)| *deadline > now)
+            .map(|(value, _)| value.clone()))
+    }
+
+    async fn del(&self, key: &str) -> Result<(), PersistenceError> {
+        self.state.lock().unwrap().values.remove(key);
+        Ok(())
+    }
+
+    async fn zadd(&self, key: &str, member: &str, score: i64) -> Result<(), PersistenceError> {
+        self.state
+            .lock()
+            .unwrap()
+            .sorted_sets
+            .entry(key.to_string())
+            .or_default()
+            .insert(member.to_string(), score);
+        Ok(())
+    }
+
+    async fn zrem(&self, key: &str, member: &str) -> Result<(), PersistenceError> {
+        if let Some(set) = self.state.lock().unwrap().sorted_sets.get_mut(key) {
+            set.remove(member);
+        }
+        Ok(())
+    }
+
+    async fn zrem_below(&self, key: &str, max: i64) -> Result<(), PersistenceError> {
+        if let Some(set) = self.state.lock().unwrap().sorted_sets.get_mut(key) {
+            set.retain(|_, score| *score >= max);
+        }
+        Ok(())
+    }
+
+    async fn zrange_from(
+        &self,
+        key: &str,
+        min: i64,
+        limit: usize,
+    ) -> Result<Vec<String>, PersistenceError> {
+        let state = self.state.lock().unwrap();
+        let mut members: Vec<(&String, i64)> = state
+            .sorted_sets
+            .get(key)
+            .map(|set| {
+                set.iter()
+                    .filter(|(_, score)| **score >= min)
+                    .map(|(member, score)| (member, *score))
+                    .collect()
+            })
+            .unwrap_or_default();
+        members.sort_by(|a, b| a.1.cmp(&b.1).then_with(|| a.0.cmp(b.0)));
+        Ok(members
+            .into_iter()
+            .take(limit)
+            .map(|(member, _)| member.clone())
+            .collect())
+    }
+}
+
+/// Redis implementation of session store
+#[derive(Clone)]
+pub struct RedisSessionStore {
+    backend: Arc<dyn RedisBackend>,
+    config: RedisSessionConfig,
+}
+
+impl RedisSessionStore {
+    /// Connect to the Redis at `config.url`
+    pub async fn connect(config: RedisSessionConfig) -> Result<Self, PersistenceError> {
+        let client = RedisClient::connect(&config.url).await?;
+        Ok(Self::with_backend(Arc::new(client), config))
+    }
+
+    /// Use an existing backend (e.g. `InMemoryRedis` in tests)
+    pub fn with_backend(backend: Arc<dyn RedisBackend>, config: RedisSessionConfig) -> Self {
+        Self { backend, config }
+    }
+
+    /// How long a session lives after its last activity
+    pub fn session_ttl(&self) -> Duration {
+        self.config.session_ttl
+    }
+
+    fn key(&self, session_id: &str) -> String {
+        format!("{}{}", self.config.key_prefix, session_id)
+    }
+
+    fn index_key(&self) -> String {
+        format!("{}active", self.config.key_prefix)
+    }
+
+    /// Write the session with a TTL that runs out at `expires_at`
+    async fn write(&self, session: &SessionData) -> Result<(), PersistenceError> {
+        let ttl = match (session.expires_at - Utc::now()).to_std() {
+            Ok(ttl) if !ttl.is_zero() => ttl,
+            // Already expired: storing it would only resurrect it
+            _ => return self.delete(&session.session_id).await,
+        };
+
+        let value = serde_json::to_string(session)?;
+        self.backend
+            .set_with_ttl(&self.key(&session.session_id), &value, ttl)
+            .await?;
+        self.backend
+            .zadd(
+                &self.index_key(),
+                &session.session_id,
+                session.expires_at.timestamp_millis(),
+            )
+            .await
+    }
+}
+
+#[async_trait]
+impl SessionStore for RedisSessionStore {
+    async fn create(&self, session: &SessionData) -> Result<(), PersistenceError> {
+        self.write(session).await
+    }
+
+    async fn get(&self, session_id: &str) -> Result<Option<SessionData>, PersistenceError> {
+        match self.backend.get(&self.key(session_id)).await? {
+            Some(value) => Ok(Some(serde_json::from_str(&value)?)),
+            None => Ok(None),
+        }
+    }
+
+    async fn update(&self, session: &SessionData) -> Result<(), PersistenceError> {
+        self.write(session).await
+    }
+
+    async fn delete(&self, session_id: &str) -> Result<(), PersistenceError> {
+        self.backend.del(&self.key(session_id)).await?;
+        self.backend.zrem(&self.index_key(), session_id).await
+    }
+
+    async fn touch(&self, session_id: &str) -> Result<(), PersistenceError> {
+        let mut session = self
+            .get(session_id)
+            .await?
+            .ok_or_else(|| PersistenceError::SessionNotFound(session_id.to_string()))?;
+
+        let now = Utc::now();
+        session.updated_at = now;
+        session.expires_at = now
+            + chrono::Duration::from_std(self.config.session_ttl)
+                .map_err(|e| PersistenceError::InvalidData(e.to_string()))?;
+        self.write(&session).await
+    }
+
+    async fn list_active(&self, limit: i32) -> Result<Vec<SessionData>, PersistenceError> {
+        let index = self.index_key();
+        let now = Utc::now().timestamp_millis();
+        self.backend.zrem_below(&index, now).await?;
+
+        let ids = self
+            .backend
+            .zrange_from(&index, now, limit.max(0) as usize)
+            .await?;
+
+        let mut sessions = Vec::with_capacity(ids.len());
+        for id in ids {
+            match self.get(&id).await? {
+                Some(session) => sessions.push(session),
+                // Key expired before its index entry was pruned
+                None => self.backend.zrem(&index, &id).await?,
+            }
+        }
+        Ok(sessions)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn store(redis: &Arc<InMemoryRedis>) -> RedisSessionStore {
+        RedisSessionStore::with_backend(
+            redis.clone(),
+            RedisSessionConfig {
+                session_ttl: Duration::from_secs(60),
+                ..Default::default()
+            },
+        )
+    }
+
+    fn session(id: &str, ttl_secs: i64) -> SessionData {
+        let mut session = SessionData::new(id);
+        session.expires_at = session.created_at + chrono::Duration::seconds(ttl_secs);
+        session.conversation_stage = "discovery".to_string();
+        session.turn_count = 3;
+        session.memory_json = Some(r#"{"facts":["gold loan"]}"#.to_string());
+        session
+    }
+
+    #[tokio::test]
+    async fn test_store_and_load() {
+        let redis = Arc::new(InMemoryRedis::new());
+        let store = store(&redis);
+
+        store.create(&session("s1", 60)).await.unwrap();
+        let loaded = store.get("s1").await.unwrap().unwrap();
+        assert_eq!(loaded.conversation_stage, "discovery");
+        assert_eq!(loaded.turn_count, 3);
+        assert_eq!(
+            loaded.memory_json.as_deref(),
+            Some(r#"{"facts":["gold loan"]}"#)
+        );
+
+        store.delete("s1").await.unwrap();
+        assert!(store.get("s1").await.unwrap().is_none());
+        assert!(store.list_active(10).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_session_expires_with_ttl() {
+        let redis = Arc::new(InMemoryRedis::new());
+        let store = store(&redis);
+
+        store.create(&session("short", 30)).await.unwrap();
+        store.create(&session("long", 120)).await.unwrap();
+
+        redis.advance(Duration::from_secs(31));
+        assert!(store.get("short").await.unwrap().is_none());
+        assert!(store.get("long").await.unwrap().is_some());
+
+        // Index entries for expired keys are dropped on the next listing
+        let active = store.list_active(10).await.unwrap();
+        assert_eq!(active.len(), 1);
+        assert_eq!(active[0].session_id, "long");
+    }
+
+    #[tokio::test]
+    async fn test_touch_extends_ttl() {
+        let redis = Arc::new(InMemoryRedis::new());
+        let store = store(&redis);
+
+        store.create(&session("s1", 10)).await.unwrap();
+        store.touch("s1").await.unwrap();
+
+        // Touch resets expiry to the configured 60s session TTL
+        redis.advance(Duration::from_secs(30));
+        assert!(store.get("s1").await.unwrap().is_some());
+        redis.advance(Duration::from_secs(31));
+        assert!(store.get("s1").await.unwrap().is_none());
+
+        assert!(matches!(
+            store.touch("s1").await,
+            Err(PersistenceError::SessionNotFound(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_expired_session_is_not_written() {
+        let redis = Arc::new(InMemoryRedis::new());
+        let store = store(&redis);
+
+        store.create(&session("stale", -5)).await.unwrap();
+        assert!(redis.is_empty());
+        assert!(store.list_active(10).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_sessions_recovered_after_restart() {
+        let redis = Arc::new(InMemoryRedis::new());
+        {
+            let before = store(&redis);
+            before.create(&session("a", 60)).await.unwrap();
+            before.create(&session("b", 90)).await.unwrap();
+        }
+
+        // A new store over the same Redis sees the sessions, soonest expiry first
+        let after = store(&redis);
+        let recovered = after.list_active(10).await.unwrap();
+        let ids: Vec<_> = recovered.iter().map(|s| s.session_id.as_str()).collect();
+        assert_eq!(ids, vec!["a", "b"]);
+        assert_eq!(recovered[0].turn_count, 3);
+        assert_eq!(after.list_active(1).await.unwrap().len(), 1);
+    }
+}
@@ -0,0 +1,861 @@
+//! Scheduled SMS messages, such as appointment reminders
+//!
+//! A scheduled message is persisted with the time it should go out and the
+//! ID of what it refers to (an appointment). `ReminderDispatcher` polls for
+//! due messages and sends them through the `SmsService`. Cancelling by
+//! reference ID stops messages that have not gone out yet, e.g. when the
+//! appointment is cancelled or moved. Erasing a customer's data cancels
+//! their pending messages and clears the number and text from all of them.
+//!
+//! Each message is claimed before it is sent, and only one dispatcher wins
+//! the claim, so several server instances can poll the same store without
+//! sending a message twice. A message can carry an expiry, such as the
+//! start of the appointment, after which it is cancelled instead of sent.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use tokio::sync::Mutex;
+use uuid::Uuid;
+
+use crate::{PersistenceError, ScyllaClient, SmsService, SmsType, ERASED_MESSAGE_TEXT};
+
+/// Delivery state of a scheduled message
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ScheduledMessageStatus {
+    Pending,
+    /// Claimed by a dispatcher that is sending it
+    Sending,
+    Sent,
+    Cancelled,
+    Failed,
+}
+
+impl ScheduledMessageStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Pending => "pending",
+            Self::Sending => "sending",
+            Self::Sent => "sent",
+            Self::Cancelled => "cancelled",
+            Self::Failed => "failed",
+        }
+    }
+
+    pub fn from_str(s: &str) -> Self {
+        match s {
+            "sending" => Self::Sending,
+            "sent" => Self::Sent,
+            "cancelled" => Self::Cancelled,
+            "failed" => Self::Failed,
+            _ => Self::Pending,
+        }
+    }
+
+    /// Status a message must be in to move to this one
+    ///
+    /// Pending messages are claimed or cancelled; claimed ones are sent or
+    /// fail.
+    fn transitions_from(&self) -> Self {
+        match self {
+            Self::Sent | Self::Failed => Self::Sending,
+            Self::Pending | Self::Sending | Self::Cancelled => Self::Pending,
+        }
+    }
+}
+
+/// SMS to be sent at a later time
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScheduledMessage {
+    pub message_id: Uuid,
+    /// What the message is about, e.g. an appointment ID
+    pub reference_id: String,
+    pub phone_number: String,
+    pub session_id: Option<String>,
+    pub message_text: String,
+    pub message_type: SmsType,
+    pub send_at: DateTime<Utc>,
+    /// When the message stops being worth sending, e.g. the appointment start
+    #[serde(default)]
+    pub expires_at: Option<DateTime<Utc>>,
+    pub status: ScheduledMessageStatus,
+    pub created_at: DateTime<Utc>,
+    /// ID of the SMS once sent
+    pub sms_id: Option<Uuid>,
+}
+
+impl ScheduledMessage {
+    pub fn new(
+        reference_id: &str,
+        phone_number: &str,
+        message_text: &str,
+        message_type: SmsType,
+        send_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            message_id: Uuid::new_v4(),
+            reference_id: reference_id.to_string(),
+            phone_number: phone_number.to_string(),
+            session_id: None,
+            message_text: message_text.to_string(),
+            message_type,
+            send_at,
+            expires_at: None,
+            status: ScheduledMessageStatus::Pending,
+            created_at: Utc::now(),
+            sms_id: None,
+        }
+    }
+
+    /// Cancel the message instead of sending it from `at` on
+    pub fn with_expiry(mut self, at: DateTime<Utc>) -> Self {
+        self.expires_at = Some(at);
+        self
+    }
+
+    /// Whether the message is no longer worth sending at `now`
+    pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        self.expires_at.is_some_and(|at| at <= now)
+    }
+}
+
+/// Store of scheduled messages
+#[async_trait]
+pub trait ScheduledMessageStore: Send + Sync {
+    async fn schedule(&self, message: &ScheduledMessage) -> Result<(), PersistenceError>;
+
+    /// Pending messages whose send time is at or before `now`, oldest first
+    async fn due(
+        &self,
+        now: DateTime<Utc>,
+        limit: usize,
+    ) -> Result<Vec<ScheduledMessage>, PersistenceError>;
+
+    /// Move a message to `status`, returning whether it did
+    ///
+    /// A pending message can be claimed (`Sending`) or cancelled; a claimed
+    /// one is marked sent or failed. Any other change is refused, so a
+    /// message claimed by one dispatcher can't be claimed by another.
+    async fn mark(
+        &self,
+        message: &ScheduledMessage,
+        status: ScheduledMessageStatus,
+        sms_id: Option<Uuid>,
+    ) -> Result<bool, PersistenceError>;
+
+    /// Claim a pending message for sending; false if it is no longer pending
+    async fn claim(&self, message: &ScheduledMessage) -> Result<bool, PersistenceError> {
+        self.mark(message, ScheduledMessageStatus::Sending, None)
+            .await
+    }
+
+    /// Cancel pending messages for a reference, returning how many
+    async fn cancel_for_reference(&self, reference_id: &str) -> Result<usize, PersistenceError>;
+
+    async fn list_for_reference(
+        &self,
+        reference_id: &str,
+    ) -> Result<Vec<ScheduledMessage>, PersistenceError>;
+
+    /// Remove a customer's number and message text from their messages
+    ///
+    /// Pending messages are cancelled, so nothing more is sent. The rest
+    /// are kept under `pseudonym` for the delivery counts. Returns how many
+    /// were anonymized.
+    async fn anonymize_for_phone(
+        &self,
+        phone: &str,
+        pseudonym: &str,
+    ) -> Result<usize, PersistenceError>;
+}
+
+impl ScheduledMessage {
+    /// Copy with the number and text removed, keyed under `pseudonym`
+    pub fn anonymized(&self, pseudonym: &str) -> Self {
+        let status = match self.status {
+            ScheduledMessageStatus::Pending => ScheduledMessageStatus::Cancelled,
+            status => status,
+        };
+        Self {
+            phone_number: pseudonym.to_string(),
+            session_id: None,
+            message_text: ERASED_MESSAGE_TEXT.to_string(),
+            status,
+            ..self.clone()
+        }
+    }
+}
+
+/// In-process scheduled message store, for tests and development
+#[derive(Default)]
+pub struct InMemoryScheduledMessageStore {
+    messages: Mutex<Vec<ScheduledMessage>>,
+}
+
+impl InMemoryScheduledMessageStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl ScheduledMessageStore for InMemoryScheduledMessageStore {
+    async fn schedule(&self, message: &ScheduledMessage) -> Result<(), PersistenceError> {
+        self.messages.lock().await.push(message.clone());
+        Ok(())
+    }
+
+    async fn due(
+        &self,
+        now: DateTime<Utc>,
+        limit: usize,
+    ) -> Result<Vec<ScheduledMessage>, PersistenceError> {
+        let mut due: Vec<ScheduledMessage> = self
+            .messages
+            .lock()
+            .await
+            .iter()
+            .filter(|m| m.status == ScheduledMessageStatus::Pending && m.send_at <= now)
+            .cloned()
+            .collect();
+        due.sort_by_key(|m| m.send_at);
+        due.truncate(limit);
+        Ok(due)
+    }
+
+    async fn mark(
+        &self,
+        message: &ScheduledMessage,
+        status: ScheduledMessageStatus,
+        sms_id: Option<Uuid>,
+    ) -> Result<bool, PersistenceError> {
+        let mut messages = self.messages.lock().await;
+        let Some(stored) = messages
+            .iter_mut()
+            .find(|m| m.message_id == message.message_id)
+        else {
+            return Ok(false);
+        };
+        if stored.status != status.transitions_from() {
+            return Ok(false);
+        }
+        stored.status = status;
+        stored.sms_id = sms_id;
+        Ok(true)
+    }
+
+    async fn cancel_for_reference(&self, reference_id: &str) -> Result<usize, PersistenceError> {
+        let mut cancelled = 0;
+        for message in self.messages.lock().await.iter_mut() {
+            if message.reference_id == reference_id
+                && message.status == ScheduledMessageStatus::Pending
+            {
+                message.status = ScheduledMessageStatus::Cancelled;
+                cancelled += 1;
+            }
+        }
+        Ok(cancelled)
+    }
+
+    async fn list_for_reference(
+        &self,
+        reference_id: &str,
+    ) -> Result<Vec<ScheduledMessage>, PersistenceError> {
+        Ok(self
+            .messages
+            .lock()
+            .await
+            .iter()
+            .filter(|m| m.reference_id == reference_id)
+            .cloned()
+            .collect())
+    }
+
+    async fn anonymize_for_phone(
+        &self,
+        phone: &str,
+        pseudonym: &str,
+    ) -> Result<usize, PersistenceError> {
+        let mut messages = self.messages.lock().await;
+        let mut count = 0;
+        for stored in messages.iter_mut().filter(|m| m.phone_number == phone) {
+            *stored = stored.anonymized(pseudonym);
+            count += 1;
+        }
+        Ok(count)
+    }
+}
+
+/// Scheduled messages in ScyllaDB
+///
+/// Messages are partitioned by the day they are due. Polling reads every
+/// day's partition from the earliest one that still held a pending message
+/// through today; that day is kept in a cursor row, so messages due while
+/// no dispatcher ran are still found. Status changes are lightweight
+/// transactions conditioned on the current status. A lookup table by
+/// reference ID finds the messages to cancel.
+#[derive(Clone)]
+pub struct ScyllaScheduledMessageStore {
+    client: ScyllaClient,
+}
+
+/// Name of the cursor row polling starts from
+const DUE_CURSOR: &str = "due";
+
+impl ScyllaScheduledMessageStore {
+    pub fn new(client: ScyllaClient) -> Self {
+        Self { client }
+    }
+
+    fn send_day(at: &DateTime<Utc>) -> String {
+        Self::day_key(at.date_naive())
+    }
+
+    fn day_key(day: NaiveDate) -> String {
+        day.format("%Y-%m-%d").to_string()
+    }
+
+    /// First day that may hold pending messages; yesterday if never polled
+    async fn scan_from(&self, now: DateTime<Utc>) -> Result<NaiveDate, PersistenceError> {
+        let query = format!(
+            "SELECT send_day FROM {}.scheduled_messages_cursor WHERE name = ?",
+            self.client.keyspace()
+        );
+        let result = self.client.query_idempotent(query, (DUE_CURSOR,)).await?;
+        let day = result
+            .rows
+            .and_then(|rows| rows.into_iter().next())
+            .and_then(|row| row.into_typed::<(String,)>().ok())
+            .and_then(|(day,)| NaiveDate::parse_from_str(&day, "%Y-%m-%d").ok());
+        Ok(day.unwrap_or_else(|| (now - chrono::Duration::days(1)).date_naive()))
+    }
+
+    async fn set_scan_from(&self, day: NaiveDate) -> Result<(), PersistenceError> {
+        let query = format!(
+            "INSERT INTO {}.scheduled_messages_cursor (name, send_day) VALUES (?, ?)",
+            self.client.keyspace()
+        );
+        self.client
+            .query(query, (DUE_CURSOR, Self::day_key(day)))
+            .await?;
+        Ok(())
+    }
+
+    async fn get(
+        &self,
+        send_day: &str,
+        send_at: i64,
+        message_id: Uuid,
+    ) -> Result<Option<ScheduledMessage>, PersistenceError> {
+        let query = format!(
+            "SELECT message_id, reference_id, phone_number, session_id, message_text,
+                    message_type, send_at, expires_at, status, created_at, sms_id
+             FROM {}.scheduled_messages
+             WHERE send_day = ? AND send_at = ? AND message_id = ?",
+            self.client.keyspace()
+        );
+        let result = self
+            .client
+            .query_idempotent(query, (send_day, send_at, message_id))
+            .await?;
+        let Some(row) = result.rows.and_then(|rows| rows.into_iter().next()) else {
+            return Ok(None);
+        };
+        Ok(Some(Self::row_to_message(row)?))
+    }
+
+    fn row_to_message(
+        row: scylla::frame::response::result::Row,
+    ) -> Result<ScheduledMessage, PersistenceError> {
+        let (
+            message_id,
+            reference_id,
+            phone_number,
+            session_id,
+            message_text,
+            message_type,
+            send_at,
+            expires_at,
+            status,
+            created_at,
+            sms_id,
+        ): (
+            Uuid,
+            String,
+            String,
+            Option<String>,
+            String,
+            String,
+            i64,
+            Option<i64>,
+            String,
+            i64,
+            Option<Uuid>,
+        ) = row
+            .into_typed()
+            .map_err(|e| PersistenceError::InvalidData(e.to_string()))?;
+
+        Ok(ScheduledMessage {
+            message_id,
+            reference_id,
+            phone_number,
+            session_id,
+            message_text,
+            message_type: match message_type.as_str() {
+                "appointment_confirmation" => SmsType::AppointmentConfirmation,
+                "follow_up" => SmsType::FollowUp,
+                _ => SmsType::AppointmentReminder,
+            },
+            send_at: DateTime::from_timestamp_millis(send_at).unwrap_or_else(Utc::now),
+            expires_at: expires_at.and_then(DateTime::from_timestamp_millis),
+            status: ScheduledMessageStatus::from_str(&status),
+            created_at: DateTime::from_timestamp_millis(created_at).unwrap_or_else(Utc::now),
+            sms_id,
+        })
+    }
+}
+
+#[async_trait]
+impl ScheduledMessageStore for ScyllaScheduledMessageStore {
+    async fn schedule(&self, message: &ScheduledMessage) -> Result<(), PersistenceError> {
+        let send_day = Self::send_day(&message.send_at);
+        let query = format!(
+            "INSERT INTO {}.scheduled_messages (
+                send_day, send_at, message_id, reference_id, phone_number, session_id,
+                message_text, message_type, expires_at, status, created_at
+            ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+            self.client.keyspace()
+        );
+        self.client
+            .query(
+                query,
+                (
+                    &send_day,
+                    message.send_at.timestamp_millis(),
+                    message.message_id,
+                    &message.reference_id,
+                    &message.phone_number,
+                    &message.session_id,
+                    &message.message_text,
+                    message.message_type.as_str(),
+                    message.expires_at.map(|at| at.timestamp_millis()),
+                    message.status.as_str(),
+                    message.created_at.timestamp_millis(),
+                ),
+            )
+            .await?;
+
+        let query = format!(
+            "INSERT INTO {}.scheduled_messages_by_reference (
+                reference_id, message_id, send_day, send_at
+            ) VALUES (?, ?, ?, ?)",
+            self.client.keyspace()
+        );
+        self.client
+            .query(
+                query,
+                (
+                    &message.reference_id,
+                    message.message_id,
+                    &send_day,
+                    message.send_at.timestamp_millis(),
+                ),
+            )
+            .await?;
+
+        // A message already due before the cursor would never be polled
+        let day = message.send_at.date_naive();
+        if day < self.scan_from(Utc::now()).await? {
+            self.set_scan_from(day).await?;
+        }
+
+        tracing::info!(
+            message_id = %message.message_id,
+            reference_id = %message.reference_id,
+            send_at = %message.send_at,
+            "SMS scheduled"
+        );
+        Ok(())
+    }
+
+    async fn due(
+        &self,
+        now: DateTime<Utc>,
+        limit: usize,
+    ) -> Result<Vec<ScheduledMessage>, PersistenceError> {
+        let query = format!(
+            "SELECT message_id, reference_id, phone_number, session_id, message_text,
+                    message_type, send_at, expires_at, status, created_at, sms_id
+             FROM {}.scheduled_messages WHERE send_day = ? AND send_at <= ?",
+            self.client.keyspace()
+        );
+        let today = now.date_naive();
+        let scan_from = self.scan_from(now).await?;
+        let mut due = Vec::new();
+        // Earliest day still holding a pending message
+        let mut pending_from = None;
+        let mut day = scan_from;
+        while day <= today && due.len() < limit {
+            let result = self
+                .client
+                .query_idempotent(query.clone(), (Self::day_key(day), now.timestamp_millis()))
+                .await?;
+            for row in result.rows.unwrap_or_default() {
+                let message = Self::row_to_message(row)?;
+                if message.status == ScheduledMessageStatus::Pending {
+                    pending_from.get_or_insert(day);
+                    due.push(message);
+                }
+            }
+            let Some(next) = day.succ_opt() else {
+                break;
+            };
+            day = next;
+        }
+
+        // Days without pending messages are not read again
+        let next = pending_from.unwrap_or(day.min(today));
+        if next > scan_from {
+            self.set_scan_from(next).await?;
+        }
+        due.truncate(limit);
+        Ok(due)
+    }
+
+    async fn mark(
+        &self,
+        message: &ScheduledMessage,
+        status: ScheduledMessageStatus,
+        sms_id: Option<Uuid>,
+    ) -> Result<bool, PersistenceError> {
+        let query = format!(
+            "UPDATE {}.scheduled_messages SET status = ?, sms_id = ?
+             WHERE send_day = ? AND send_at = ? AND message_id = ? IF status = ?",
+            self.client.keyspace()
+        );
+        let result = self
+            .client
+            .query(
+                query,
+                (
+                    status.as_str(),
+                    sms_id,
+                    Self::send_day(&message.send_at),
+                    message.send_at.timestamp_millis(),
+                    message.message_id,
+                    status.transitions_from().as_str(),
+                ),
+            )
+            .await?;
+
+        // The first column of a conditional statement's result is [applied]
+        let applied = result
+            .rows
+            .and_then(|rows| rows.into_iter().next())
+            .and_then(|row| row.columns.into_iter().next().flatten())
+            .and_then(|value| value.as_boolean())
+            .unwrap_or(false);
+        Ok(applied)
+    }
+
+    async fn cancel_for_reference(&self, reference_id: &str) -> Result<usize, PersistenceError> {
+        let mut cancelled = 0;
+        for message in self.list_for_reference(reference_id).await? {
+            if message.status != ScheduledMessageStatus::Pending {
+                continue;
+            }
+            if self
+                .mark(&message, ScheduledMessageStatus::Cancelled, None)
+                .await?
+            {
+                cancelled += 1;
+            }
+        }
+        if cancelled > 0 {
+            tracing::info!(reference_id = %reference_id, cancelled, "Scheduled SMS cancelled");
+        }
+        Ok(cancelled)
+    }
+
+    async fn list_for_reference(
+        &self,
+        reference_id: &str,
+    ) -> Result<Vec<ScheduledMessage>, PersistenceError> {
+        let query = format!(
+            "SELECT message_id, send_day, send_at
+             FROM {}.scheduled_messages_by_reference WHERE reference_id = ?",
+            self.client.keyspace()
+        );
+        let result = self.client.query_idempotent(query, (reference_id,)).await?;
+
+        let mut messages = Vec::new();
+        for row in result.rows.unwrap_or_default() {
+            let (message_id, send_day, send_at): (Uuid, String, i64) = row
+                .into_typed()
+                .map_err(|e| PersistenceError::InvalidData(e.to_string()))?;
+            if let Some(message) = self.get(&send_day, send_at, message_id).await? {
+                messages.push(message);
+            }
+        }
+        Ok(messages)
+    }
+
+    async fn anonymize_for_phone(
+        &self,
+        phone: &str,
+        pseudonym: &str,
+    ) -> Result<usize, PersistenceError> {
+        // Messages are partitioned by day, not number; erasure is rare
+        // enough to scan for it
+        let query = format!(
+            "SELECT message_id, reference_id, phone_number, session_id, message_text,
+                    message_type, send_at, expires_at, status, created_at, sms_id
+             FROM {}.scheduled_messages WHERE phone_number = ? ALLOW FILTERING",
+            self.client.keyspace()
+        );
+        let result = self.client.query_idempotent(query, (phone,)).await?;
+
+        let update = format!(
+            "UPDATE {}.scheduled_messages
+             SET phone_number = ?, session_id = ?, message_text = ?, status = ?
+             WHERE send_day = ? AND send_at = ? AND message_id = ?",
+            self.client.keyspace()
+        );
+        // Without its lookup row a message can no longer be cancelled by
+        // the appointment, which no longer names the customer either
+        let delete_reference = format!(
+            "DELETE FROM {}.scheduled_messages_by_reference
+             WHERE reference_id = ? AND message_id = ?",
+            self.client.keyspace()
+        );
+        let mut count = 0;
+        for row in result.rows.unwrap_or_default() {
+            let message = Self::row_to_message(row)?.anonymized(pseudonym);
+            self.client
+                .query(
+                    update.clone(),
+                    (
+                        &message.phone_number,
+                        &message.session_id,
+                        &message.message_text,
+                        message.status.as_str(),
+                        Self::send_day(&message.send_at),
+                        message.send_at.timestamp_millis(),
+                        message.message_id,
+                    ),
+                )
+                .await?;
+            self.client
+                .query(
+                    delete_reference.clone(),
+                    (&message.reference_id, message.message_id),
+                )
+                .await?;
+            count += 1;
+        }
+
+        tracing::info!(count, pseudonym = %pseudonym, "Scheduled SMS anonymized");
+        Ok(count)
+    }
+}
+
+/// Sends scheduled messages once they are due
+#[derive(Clone)]
+pub struct ReminderDispatcher {
+    store: Arc<dyn ScheduledMessageStore>,
+    sms: Arc<dyn SmsService>,
+    /// Messages sent per poll
+    batch_size: usize,
+}
+
+impl ReminderDispatcher {
+    pub fn new(store: Arc<dyn ScheduledMessageStore>, sms: Arc<dyn SmsService>) -> Self {
+        Self {
+            store,
+            sms,
+            batch_size: 100,
+        }
+    }
+
+    /// Send every message due at `now`, returning how many were sent
+    ///
+    /// Messages another dispatcher claimed first are left to it, and expired
+    /// ones are cancelled. A message that fails to send is marked failed and
+    /// not retried.
+    pub async fn dispatch_due(&self, now: DateTime<Utc>) -> Result<usize, PersistenceError> {
+        let mut sent = 0;
+        for message in self.store.due(now, self.batch_size).await? {
+            if message.is_expired(now) {
+                self.store
+                    .mark(&message, ScheduledMessageStatus::Cancelled, None)
+                    .await?;
+                tracing::info!(
+                    message_id = %message.message_id,
+                    reference_id = %message.reference_id,
+                    "Scheduled SMS expired before it was sent"
+                );
+                continue;
+            }
+            if !self.store.claim(&message).await? {
+                continue;
+            }
+            match self
+                .sms
+                .send_sms(
+                    &message.phone_number,
+                    &message.message_text,
+                    message.message_type,
+                    message.session_id.as_deref(),
+                )
+                .await
+            {
+                Ok(result) => {
+                    self.store
+                        .mark(
+                            &message,
+                            ScheduledMessageStatus::Sent,
+                            Some(result.message_id),
+                        )
+                        .await?;
+                    sent += 1;
+                },
+                Err(e) => {
+                    tracing::warn!(
+                        message_id = %message.message_id,
+                        error = %e,
+                        "Failed to send scheduled SMS"
+                    );
+                    self.store
+                        .mark(&message, ScheduledMessageStatus::Failed, None)
+                        .await?;
+                },
+            }
+        }
+        Ok(sent)
+    }
+
+    /// Poll for due messages every `interval` in a background task
+    pub fn spawn(self, interval: Duration) -> tokio::task::JoinHandle<()> {
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            loop {
+                ticker.tick().await;
+                match self.dispatch_due(Utc::now()).await {
+                    Ok(0) => {},
+                    Ok(sent) => tracing::info!(sent, "Dispatched scheduled SMS"),
+                    Err(e) => tracing::warn!(error = %e, "Scheduled SMS dispatch failed"),
+                }
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::InMemorySmsService;
+
+    #[tokio::test]
+    async fn test_message_sent_once_when_due() {
+        let store = Arc::new(InMemoryScheduledMessageStore::new());
+        let sms = Arc::new(InMemorySmsService::new());
+        let dispatcher = ReminderDispatcher::new(store.clone(), sms.clone());
+        let send_at = Utc::now() + chrono::Duration::hours(2);
+        let message = ScheduledMessage::new(
+            "APT-1",
+            "9876543210",
+            "See you tomorrow",
+            SmsType::AppointmentReminder,
+            send_at,
+        );
+        store.schedule(&message).await.unwrap();
+
+        let early = send_at - chrono::Duration::minutes(1);
+        assert_eq!(dispatcher.dispatch_due(early).await.unwrap(), 0);
+        assert_eq!(dispatcher.dispatch_due(send_at).await.unwrap(), 1);
+        assert_eq!(dispatcher.dispatch_due(send_at).await.unwrap(), 0);
+
+        let sent = sms.sent().await;
+        assert_eq!(sent.len(), 1);
+        assert_eq!(sent[0].message_type, SmsType::AppointmentReminder);
+        let stored = store.list_for_reference("APT-1").await.unwrap();
+        assert_eq!(stored[0].status, ScheduledMessageStatus::Sent);
+        assert!(stored[0].sms_id.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_cancelled_message_not_sent() {
+        let store = Arc::new(InMemoryScheduledMessageStore::new());
+        let sms = Arc::new(InMemorySmsService::new());
+        let dispatcher = ReminderDispatcher::new(store.clone(), sms.clone());
+        let send_at = Utc::now();
+        let message = ScheduledMessage::new(
+            "APT-2",
+            "9876543210",
+            "See you",
+            SmsType::AppointmentReminder,
+            send_at,
+        );
+        store.schedule(&message).await.unwrap();
+
+        assert_eq!(store.cancel_for_reference("APT-2").await.unwrap(), 1);
+        assert_eq!(store.cancel_for_reference("APT-2").await.unwrap(), 0);
+        assert_eq!(dispatcher.dispatch_due(send_at).await.unwrap(), 0);
+        assert!(sms.sent().await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_claimed_message_not_sent_again() {
+        let store = Arc::new(InMemoryScheduledMessageStore::new());
+        let sms = Arc::new(InMemorySmsService::new());
+        let dispatcher = ReminderDispatcher::new(store.clone(), sms.clone());
+        let send_at = Utc::now();
+        let message = ScheduledMessage::new(
+            "APT-3",
+            "9876543210",
+            "See you",
+            SmsType::AppointmentReminder,
+            send_at,
+        );
+        store.schedule(&message).await.unwrap();
+
+        // Another dispatcher claimed it between polling and sending
+        let due = store.due(send_at, 10).await.unwrap();
+        assert!(store.claim(&due[0]).await.unwrap());
+        assert!(!store.claim(&due[0]).await.unwrap());
+        assert_eq!(dispatcher.dispatch_due(send_at).await.unwrap(), 0);
+        assert!(sms.sent().await.is_empty());
+
+        // Nor can it be cancelled once claimed
+        assert_eq!(store.cancel_for_reference("APT-3").await.unwrap(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_expired_message_cancelled() {
+        let store = Arc::new(InMemoryScheduledMessageStore::new());
+        let sms = Arc::new(InMemorySmsService::new());
+        let dispatcher = ReminderDispatcher::new(store.clone(), sms.clone());
+        let send_at = Utc::now();
+        let starts_at = send_at + chrono::Duration::hours(24);
+        let message = ScheduledMessage::new(
+            "APT-4",
+            "9876543210",
+            "See you tomorrow",
+            SmsType::AppointmentReminder,
+            send_at,
+        )
+        .with_expiry(starts_at);
+        store.schedule(&message).await.unwrap();
+
+        // The dispatcher was down until after the appointment
+        let late = starts_at + chrono::Duration::minutes(5);
+        assert_eq!(dispatcher.dispatch_due(late).await.unwrap(), 0);
+        assert!(sms.sent().await.is_empty());
+        let stored = store.list_for_reference("APT-4").await.unwrap();
+        assert_eq!(stored[0].status, ScheduledMessageStatus::Cancelled);
+    }
+}
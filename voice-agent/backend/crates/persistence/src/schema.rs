@@ -159,6 +159,49 @@ pub async fn create_tables(session: &Session, keyspace: &str) -> Result<(), Pers
             PersistenceError::SchemaError(format!("Failed to create appointments table: {}", e))
         })?;
 
+    // Lookups of appointments by ID and by date, without the customer's number
+    let appointments_by_id_table = format!(
+        r#"
+        CREATE TABLE IF NOT EXISTS {}.appointments_by_id (
+            appointment_id TIMEUUID PRIMARY KEY,
+            customer_phone TEXT
+        )
+    "#,
+        keyspace
+    );
+
+    session
+        .query_unpaged(appointments_by_id_table, &[])
+        .await
+        .map_err(|e| {
+            PersistenceError::SchemaError(format!(
+                "Failed to create appointments_by_id table: {}",
+                e
+            ))
+        })?;
+
+    let appointments_by_date_table = format!(
+        r#"
+        CREATE TABLE IF NOT EXISTS {}.appointments_by_date (
+            appointment_date DATE,
+            appointment_id TIMEUUID,
+            customer_phone TEXT,
+            PRIMARY KEY ((appointment_date), appointment_id)
+        )
+    "#,
+        keyspace
+    );
+
+    session
+        .query_unpaged(appointments_by_date_table, &[])
+        .await
+        .map_err(|e| {
+            PersistenceError::SchemaError(format!(
+                "Failed to create appointments_by_date table: {}",
+                e
+            ))
+        })?;
+
     // P0 FIX: Audit log table for RBI compliance
     // Required for regulatory auditing of all financial conversations
     // 7 year retention as per RBI guidelines (220752000 seconds)
@@ -193,6 +236,142 @@ pub async fn create_tables(session: &Session, keyspace: &str) -> Result<(), Pers
             PersistenceError::SchemaError(format!("Failed to create audit_log table: {}", e))
         })?;
 
+    // Audit log by day: cross-session compliance queries in time order
+    let audit_log_by_date_table = format!(
+        r#"
+        CREATE TABLE IF NOT EXISTS {}.audit_log_by_date (
+            partition_date TEXT,
+            timestamp BIGINT,
+            id UUID,
+            session_id TEXT,
+            event_type TEXT,
+            actor_type TEXT,
+            actor_id TEXT,
+            resource_type TEXT,
+            resource_id TEXT,
+            action TEXT,
+            outcome TEXT,
+            details TEXT,
+            previous_hash TEXT,
+            hash TEXT,
+            PRIMARY KEY ((partition_date), timestamp, id)
+        ) WITH CLUSTERING ORDER BY (timestamp ASC, id ASC)
+        AND default_time_to_live = 220752000
+    "#,
+        keyspace
+    );
+
+    session
+        .query_unpaged(audit_log_by_date_table, &[])
+        .await
+        .map_err(|e| {
+            PersistenceError::SchemaError(format!(
+                "Failed to create audit_log_by_date table: {}",
+                e
+            ))
+        })?;
+
+    // Head of each session's audit chain, advanced with lightweight
+    // transactions so concurrent writers cannot fork the chain
+    let audit_chain_heads_table = format!(
+        r#"
+        CREATE TABLE IF NOT EXISTS {}.audit_chain_heads (
+            session_id TEXT PRIMARY KEY,
+            hash TEXT,
+            first_date TEXT,
+            last_date TEXT
+        ) WITH default_time_to_live = 220752000
+    "#,
+        keyspace
+    );
+
+    session
+        .query_unpaged(audit_chain_heads_table, &[])
+        .await
+        .map_err(|e| {
+            PersistenceError::SchemaError(format!(
+                "Failed to create audit_chain_heads table: {}",
+                e
+            ))
+        })?;
+
+    // Scheduled SMS (appointment reminders), partitioned by the day they are due
+    let scheduled_messages_table = format!(
+        r#"
+        CREATE TABLE IF NOT EXISTS {}.scheduled_messages (
+            send_day TEXT,
+            send_at TIMESTAMP,
+            message_id UUID,
+            reference_id TEXT,
+            phone_number TEXT,
+            session_id TEXT,
+            message_text TEXT,
+            message_type TEXT,
+            expires_at TIMESTAMP,
+            status TEXT,
+            created_at TIMESTAMP,
+            sms_id UUID,
+            PRIMARY KEY ((send_day), send_at, message_id)
+        ) WITH CLUSTERING ORDER BY (send_at ASC, message_id ASC)
+    "#,
+        keyspace
+    );
+
+    session
+        .query_unpaged(scheduled_messages_table, &[])
+        .await
+        .map_err(|e| {
+            PersistenceError::SchemaError(format!(
+                "Failed to create scheduled_messages table: {}",
+                e
+            ))
+        })?;
+
+    // Lookup of scheduled SMS by what they refer to, for cancellation
+    let scheduled_by_reference_table = format!(
+        r#"
+        CREATE TABLE IF NOT EXISTS {}.scheduled_messages_by_reference (
+            reference_id TEXT,
+            message_id UUID,
+            send_day TEXT,
+            send_at TIMESTAMP,
+            PRIMARY KEY ((reference_id), message_id)
+        )
+    "#,
+        keyspace
+    );
+
+    session
+        .query_unpaged(scheduled_by_reference_table, &[])
+        .await
+        .map_err(|e| {
+            PersistenceError::SchemaError(format!(
+                "Failed to create scheduled_messages_by_reference table: {}",
+                e
+            ))
+        })?;
+
+    // Earliest day polling for due scheduled SMS starts from
+    let scheduled_cursor_table = format!(
+        r#"
+        CREATE TABLE IF NOT EXISTS {}.scheduled_messages_cursor (
+            name TEXT PRIMARY KEY,
+            send_day TEXT
+        )
+    "#,
+        keyspace
+    );
+
+    session
+        .query_unpaged(scheduled_cursor_table, &[])
+        .await
+        .map_err(|e| {
+            PersistenceError::SchemaError(format!(
+                "Failed to create scheduled_messages_cursor table: {}",
+                e
+            ))
+        })?;
+
     tracing::info!("All tables created successfully");
     Ok(())
 }
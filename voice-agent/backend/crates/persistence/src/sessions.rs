@@ -51,6 +51,24 @@ pub trait SessionStore: Send + Sync {
     async fn delete(&self, session_id: &str) -> Result<(), PersistenceError>;
     async fn touch(&self, session_id: &str) -> Result<(), PersistenceError>;
     async fn list_active(&self, limit: i32) -> Result<Vec<SessionData>, PersistenceError>;
+
+    /// Sessions recorded for a customer's phone number
+    ///
+    /// The default filters the active sessions, for stores that drop
+    /// sessions once they expire.
+    async fn list_for_customer(
+        &self,
+        phone: &str,
+        limit: i32,
+    ) -> Result<Vec<SessionData>, PersistenceError> {
+        Ok(self
+            .list_active(i32::MAX)
+            .await?
+            .into_iter()
+            .filter(|s| s.customer_phone.as_deref() == Some(phone))
+            .take(limit.max(0) as usize)
+            .collect())
+    }
 }
 
 /// ScyllaDB implementation of session store
@@ -79,8 +97,7 @@ impl SessionStore for ScyllaSessionStore {
         );
 
         self.client
-            .session()
-            .query_unpaged(
+            .query(
                 query,
                 (
                     &session.session_id,
@@ -113,11 +130,7 @@ impl SessionStore for ScyllaSessionStore {
             self.client.keyspace()
         );
 
-        let result = self
-            .client
-            .session()
-            .query_unpaged(query, (session_id,))
-            .await?;
+        let result = self.client.query_idempotent(query, (session_id,)).await?;
 
         if let Some(rows) = result.rows {
             if let Some(row) = rows.into_iter().next() {
@@ -191,8 +204,7 @@ impl SessionStore for ScyllaSessionStore {
         );
 
         self.client
-            .session()
-            .query_unpaged(
+            .query(
                 query,
                 (
                     Utc::now().timestamp_millis(),
@@ -219,10 +231,7 @@ impl SessionStore for ScyllaSessionStore {
             self.client.keyspace()
         );
 
-        self.client
-            .session()
-            .query_unpaged(query, (session_id,))
-            .await?;
+        self.client.query(query, (session_id,)).await?;
         tracing::debug!(session_id = %session_id, "Session deleted from ScyllaDB");
         Ok(())
     }
@@ -237,8 +246,7 @@ impl SessionStore for ScyllaSessionStore {
         let expires = now + Duration::hours(24);
 
         self.client
-            .session()
-            .query_unpaged(
+            .query(
                 query,
                 (
                     now.timestamp_millis(),
@@ -262,58 +270,40 @@ impl SessionStore for ScyllaSessionStore {
             self.client.keyspace()
         );
 
-        let result = self.client.session().query_unpaged(query, (limit,)).await?;
+        let result = self.client.query_idempotent(query, (limit,)).await?;
 
         let mut sessions = Vec::new();
         if let Some(rows) = result.rows {
             for row in rows {
-                let (
-                    session_id,
-                    created_at,
-                    updated_at,
-                    expires_at,
-                    customer_phone,
-                    customer_name,
-                    customer_segment,
-                    language,
-                    conversation_stage,
-                    turn_count,
-                    memory_json,
-                    metadata_json,
-                ): (
-                    String,
-                    i64,
-                    i64,
-                    i64,
-                    Option<String>,
-                    Option<String>,
-                    Option<String>,
-                    String,
-                    String,
-                    i32,
-                    Option<String>,
-                    Option<String>,
-                ) = row
-                    .into_typed()
-                    .map_err(|e| PersistenceError::InvalidData(e.to_string()))?;
+                sessions.push(row_to_session(row)?);
+            }
+        }
 
-                sessions.push(SessionData {
-                    session_id,
-                    created_at: DateTime::from_timestamp_millis(created_at)
-                        .unwrap_or_else(Utc::now),
-                    updated_at: DateTime::from_timestamp_millis(updated_at)
-                        .unwrap_or_else(Utc::now),
-                    expires_at: DateTime::from_timestamp_millis(expires_at)
-                        .unwrap_or_else(Utc::now),
-                    customer_phone,
-                    customer_name,
-                    customer_segment,
-                    language,
-                    conversation_stage,
-                    turn_count,
-                    memory_json,
-                    metadata_json,
-                });
+        Ok(sessions)
+    }
+
+    async fn list_for_customer(
+        &self,
+        phone: &str,
+        limit: i32,
+    ) -> Result<Vec<SessionData>, PersistenceError> {
+        // customer_phone isn't part of the key, so this scans; it only
+        // serves rare data access requests
+        let query = format!(
+            "SELECT session_id, created_at, updated_at, expires_at,
+                    customer_phone, customer_name, customer_segment,
+                    language, conversation_stage, turn_count,
+                    memory_json, metadata_json
+             FROM {}.sessions WHERE customer_phone = ? LIMIT ? ALLOW FILTERING",
+            self.client.keyspace()
+        );
+
+        let result = self.client.query_idempotent(query, (phone, limit)).await?;
+
+        let mut sessions = Vec::new();
+        if let Some(rows) = result.rows {
+            for row in rows {
+                sessions.push(row_to_session(row)?);
             }
         }
 
@@ -321,6 +311,55 @@ impl SessionStore for ScyllaSessionStore {
     }
 }
 
+fn row_to_session(
+    row: scylla::frame::response::result::Row,
+) -> Result<SessionData, PersistenceError> {
+    let (
+        session_id,
+        created_at,
+        updated_at,
+        expires_at,
+        customer_phone,
+        customer_name,
+        customer_segment,
+        language,
+        conversation_stage,
+        turn_count,
+        memory_json,
+        metadata_json,
+    ): (
+        String,
+        i64,
+        i64,
+        i64,
+        Option<String>,
+        Option<String>,
+        Option<String>,
+        String,
+        String,
+        i32,
+        Option<String>,
+        Option<String>,
+    ) = row
+        .into_typed()
+        .map_err(|e| PersistenceError::InvalidData(e.to_string()))?;
+
+    Ok(SessionData {
+        session_id,
+        created_at: DateTime::from_timestamp_millis(created_at).unwrap_or_else(Utc::now),
+        updated_at: DateTime::from_timestamp_millis(updated_at).unwrap_or_else(Utc::now),
+        expires_at: DateTime::from_timestamp_millis(expires_at).unwrap_or_else(Utc::now),
+        customer_phone,
+        customer_name,
+        customer_segment,
+        language,
+        conversation_stage,
+        turn_count,
+        memory_json,
+        metadata_json,
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -7,8 +7,12 @@ use crate::{PersistenceError, ScyllaClient};
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use tokio::sync::Mutex;
 use uuid::Uuid;
 
+/// Text left in place of an erased message
+pub const ERASED_MESSAGE_TEXT: &str = "[erased]";
+
 /// SMS message types
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
@@ -100,6 +104,118 @@ pub trait SmsService: Send + Sync {
         phone: &str,
         message_id: Uuid,
     ) -> Result<Option<SmsMessage>, PersistenceError>;
+
+    /// Remove a customer's number and message text from their messages
+    ///
+    /// The records stay, re-keyed under `pseudonym` with the text replaced
+    /// by `ERASED_MESSAGE_TEXT`, so delivery counts still add up. Returns
+    /// how many were anonymized.
+    async fn anonymize_for_phone(
+        &self,
+        phone: &str,
+        pseudonym: &str,
+    ) -> Result<usize, PersistenceError>;
+}
+
+impl SmsMessage {
+    /// Copy with the number and text removed, keyed under `pseudonym`
+    pub fn anonymized(&self, pseudonym: &str) -> Self {
+        Self {
+            phone_number: pseudonym.to_string(),
+            message_text: ERASED_MESSAGE_TEXT.to_string(),
+            metadata: None,
+            ..self.clone()
+        }
+    }
+}
+
+/// In-process SMS service, for tests and development
+#[derive(Default)]
+pub struct InMemorySmsService {
+    messages: Mutex<Vec<SmsMessage>>,
+}
+
+impl InMemorySmsService {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Every message sent so far, oldest first
+    pub async fn sent(&self) -> Vec<SmsMessage> {
+        self.messages.lock().await.clone()
+    }
+}
+
+#[async_trait]
+impl SmsService for InMemorySmsService {
+    async fn send_sms(
+        &self,
+        phone: &str,
+        message: &str,
+        msg_type: SmsType,
+        session_id: Option<&str>,
+    ) -> Result<SmsResult, PersistenceError> {
+        let now = Utc::now();
+        let sms = SmsMessage {
+            message_id: Uuid::new_v4(),
+            phone_number: phone.to_string(),
+            session_id: session_id.map(String::from),
+            message_text: message.to_string(),
+            message_type: msg_type,
+            status: SmsStatus::SimulatedSent,
+            created_at: now,
+            sent_at: Some(now),
+            metadata: None,
+        };
+        let result = SmsResult {
+            message_id: sms.message_id,
+            status: sms.status,
+            sent_at: now,
+            simulated: true,
+        };
+        self.messages.lock().await.push(sms);
+        Ok(result)
+    }
+
+    async fn get_messages_for_phone(
+        &self,
+        phone: &str,
+        limit: i32,
+    ) -> Result<Vec<SmsMessage>, PersistenceError> {
+        let messages = self.messages.lock().await;
+        Ok(messages
+            .iter()
+            .filter(|m| m.phone_number == phone)
+            .take(limit.max(0) as usize)
+            .cloned()
+            .collect())
+    }
+
+    async fn get_message(
+        &self,
+        phone: &str,
+        message_id: Uuid,
+    ) -> Result<Option<SmsMessage>, PersistenceError> {
+        let messages = self.messages.lock().await;
+        Ok(messages
+            .iter()
+            .find(|m| m.phone_number == phone && m.message_id == message_id)
+            .cloned())
+    }
+
+    async fn anonymize_for_phone(
+        &self,
+        phone: &str,
+        pseudonym: &str,
+    ) -> Result<usize, PersistenceError> {
+        let mut messages = self.messages.lock().await;
+        let mut count = 0;
+        for stored in messages.iter_mut().filter(|m| m.phone_number == phone) {
+            *stored = stored.anonymized(pseudonym);
+            count += 1;
+        }
+        Ok(count)
+    }
 }
 
 /// Simulated SMS service that persists to ScyllaDB
@@ -156,6 +272,37 @@ impl SimulatedSmsService {
         )
     }
 
+    /// Reminder sent ahead of an appointment
+    pub fn format_appointment_reminder(
+        customer_name: &str,
+        date: &str,
+        time: &str,
+        branch_name: &str,
+        brand: &SmsBrandContext,
+    ) -> String {
+        let product = if brand.product_name.is_empty() {
+            "your appointment".to_string()
+        } else {
+            format!("your {} appointment", brand.product_name)
+        };
+        let helpline = if brand.helpline.is_empty() {
+            "our helpline".to_string()
+        } else {
+            brand.helpline.clone()
+        };
+        let sender = if brand.company_name.is_empty() {
+            "".to_string()
+        } else {
+            format!(" - {}", brand.company_name)
+        };
+
+        format!(
+            "Dear {}, a reminder that {} is on {} at {} at our {} branch. \
+             To reschedule, call {}.{}",
+            customer_name, product, date, time, branch_name, helpline, sender
+        )
+    }
+
     /// P16 FIX: Generate follow-up message (domain-agnostic)
     /// Brand context should come from domain config.
     pub fn format_follow_up(customer_name: &str, brand: &SmsBrandContext) -> String {
@@ -206,8 +353,7 @@ impl SmsService for SimulatedSmsService {
         );
 
         self.client
-            .session()
-            .query_unpaged(
+            .query(
                 query,
                 (
                     phone,
@@ -256,11 +402,7 @@ impl SmsService for SimulatedSmsService {
             self.client.keyspace()
         );
 
-        let result = self
-            .client
-            .session()
-            .query_unpaged(query, (phone, limit))
-            .await?;
+        let result = self.client.query_idempotent(query, (phone, limit)).await?;
 
         let mut messages = Vec::new();
         if let Some(rows) = result.rows {
@@ -329,6 +471,55 @@ impl SmsService for SimulatedSmsService {
         let messages = self.get_messages_for_phone(phone, 100).await?;
         Ok(messages.into_iter().find(|m| m.message_id == message_id))
     }
+
+    async fn anonymize_for_phone(
+        &self,
+        phone: &str,
+        pseudonym: &str,
+    ) -> Result<usize, PersistenceError> {
+        // The phone is the partition key, so each message is copied under
+        // the pseudonym and the customer's partition dropped
+        let messages = self.get_messages_for_phone(phone, i32::MAX).await?;
+        let insert = format!(
+            "INSERT INTO {}.sms_messages (
+                phone_number, message_id, session_id, message_text,
+                message_type, status, created_at, sent_at
+            ) VALUES (?, ?, ?, ?, ?, ?, ?, ?)",
+            self.client.keyspace()
+        );
+        for message in &messages {
+            let anonymized = message.anonymized(pseudonym);
+            self.client
+                .query(
+                    insert.clone(),
+                    (
+                        &anonymized.phone_number,
+                        anonymized.message_id,
+                        &anonymized.session_id,
+                        &anonymized.message_text,
+                        anonymized.message_type.as_str(),
+                        anonymized.status.as_str(),
+                        anonymized.created_at.timestamp_millis(),
+                        anonymized.sent_at.map(|t| t.timestamp_millis()),
+                    ),
+                )
+                .await?;
+        }
+
+        let query = format!(
+            "DELETE FROM {}.sms_messages WHERE phone_number = ?",
+            self.client.keyspace()
+        );
+        self.client.query(query, (phone,)).await?;
+
+        tracing::info!(
+            count = messages.len(),
+            pseudonym = %pseudonym,
+            "Customer's SMS anonymized"
+        );
+
+        Ok(messages.len())
+    }
 }
 
 #[cfg(test)]
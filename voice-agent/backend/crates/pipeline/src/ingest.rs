@@ -0,0 +1,157 @@
+//! Offline File Ingestion
+//!
+//! Loads recorded calls (WAV) for batch transcription through the live
+//! VAD/STT/turn-detection path. See `VoicePipeline::process_file`.
+
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::PipelineError;
+
+/// Mono audio loaded from a file
+#[derive(Debug, Clone)]
+pub struct FileAudio {
+    /// Samples normalized to [-1.0, 1.0]
+    pub samples: Vec<f32>,
+    /// Sample rate of `samples`
+    pub sample_rate: u32,
+}
+
+impl FileAudio {
+    /// Duration in milliseconds
+    pub fn duration_ms(&self) -> u64 {
+        if self.sample_rate == 0 {
+            return 0;
+        }
+        self.samples.len() as u64 * 1000 / self.sample_rate as u64
+    }
+}
+
+/// One user turn detected in a file
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TranscriptSegment {
+    /// Offset where speech was detected (ms from file start)
+    pub start_ms: u64,
+    /// Offset where the turn was completed (ms from file start)
+    pub end_ms: u64,
+    /// Final transcript text
+    pub text: String,
+    /// STT confidence
+    pub confidence: f32,
+    /// Agent response, when the pipeline has an LLM
+    pub response: Option<String>,
+}
+
+/// Timestamped transcript of a file
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct FileTranscript {
+    /// Sample rate of the source file
+    pub source_sample_rate: u32,
+    /// Duration of the file in milliseconds
+    pub duration_ms: u64,
+    /// Turns in file order
+    pub segments: Vec<TranscriptSegment>,
+}
+
+impl FileTranscript {
+    /// Full transcript text, one turn per line
+    pub fn text(&self) -> String {
+        self.segments
+            .iter()
+            .map(|s| s.text.as_str())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// All transcribed words in order
+    pub fn words(&self) -> Vec<&str> {
+        self.segments
+            .iter()
+            .flat_map(|s| s.text.split_whitespace())
+            .collect()
+    }
+}
+
+/// Load a WAV file as mono f32 samples
+///
+/// Integer and float WAVs are supported; multi-channel audio is downmixed
+/// by averaging channels.
+pub fn load_wav(path: impl AsRef<Path>) -> Result<FileAudio, PipelineError> {
+    let path = path.as_ref();
+    let reader = hound::WavReader::open(path)
+        .map_err(|e| PipelineError::Audio(format!("Failed to open {}: {}", path.display(), e)))?;
+
+    let spec = reader.spec();
+    let samples: Vec<f32> = match spec.sample_format {
+        hound::SampleFormat::Float => reader
+            .into_samples::<f32>()
+            .collect::<Result<_, _>>()
+            .map_err(|e| PipelineError::Audio(format!("Invalid WAV data: {}", e)))?,
+        hound::SampleFormat::Int => {
+            let max_val = (1i64 << (spec.bits_per_sample - 1)) as f32;
+            reader
+                .into_samples::<i32>()
+                .map(|s| s.map(|s| s as f32 / max_val))
+                .collect::<Result<_, _>>()
+                .map_err(|e| PipelineError::Audio(format!("Invalid WAV data: {}", e)))?
+        },
+    };
+
+    let channels = spec.channels.max(1) as usize;
+    let samples = if channels > 1 {
+        samples
+            .chunks(channels)
+            .map(|frame| frame.iter().sum::<f32>() / frame.len() as f32)
+            .collect()
+    } else {
+        samples
+    };
+
+    Ok(FileAudio {
+        samples,
+        sample_rate: spec.sample_rate,
+    })
+}
+
+/// Resample mono audio between sample rates
+pub fn resample(samples: &[f32], from_hz: u32, to_hz: u32) -> Result<Vec<f32>, PipelineError> {
+    use rubato::{FftFixedIn, Resampler};
+
+    if from_hz == to_hz || samples.is_empty() {
+        return Ok(samples.to_vec());
+    }
+
+    // 10ms input chunks
+    let chunk_size = (from_hz / 100).max(1) as usize;
+    let mut resampler = FftFixedIn::<f32>::new(from_hz as usize, to_hz as usize, chunk_size, 2, 1)
+        .map_err(|e| PipelineError::Audio(format!("Failed to create resampler: {}", e)))?;
+
+    let expected_len = (samples.len() as u64 * to_hz as u64 / from_hz as u64) as usize;
+    let mut output = Vec::with_capacity(expected_len + chunk_size);
+    for chunk in samples.chunks(chunk_size) {
+        let mut input = chunk.to_vec();
+        input.resize(chunk_size, 0.0);
+        let resampled = resampler
+            .process(&[input], None)
+            .map_err(|e| PipelineError::Audio(format!("Resampling failed: {}", e)))?;
+        output.extend(resampled.into_iter().next().unwrap_or_default());
+    }
+    output.truncate(expected_len);
+
+    Ok(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resample_preserves_duration() {
+        let samples = vec![0.1f32; 8000];
+        let resampled = resample(&samples, 8000, 16000).unwrap();
+        assert_eq!(resampled.len(), 16000);
+
+        assert_eq!(resample(&samples, 16000, 16000).unwrap(), samples);
+    }
+}
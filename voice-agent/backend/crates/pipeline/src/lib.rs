@@ -10,8 +10,10 @@
 //! - Channel-based processor chains
 
 pub mod adapters;
+pub mod ingest;
 pub mod orchestrator;
 pub mod processors;
+pub mod reframe;
 pub mod stt;
 pub mod tts;
 pub mod turn_detection;
@@ -32,9 +34,14 @@ pub use stt::{
     create_indicconformer, create_stt_backend, IndicConformerBackend, IndicConformerConfig,
     SttBackend, StubSttBackend,
 };
+pub use stt::{LanguageDetection, LanguageIdConfig, StreamingLanguageId};
 
 // TTS exports
-pub use tts::{ChunkStrategy, StreamingTts, TtsConfig, TtsEngine, TtsEvent, WordChunker};
+pub use tts::{
+    spell_out, ChunkStrategy, PlaybackTracker, ProsodyParams, ProsodySupport, SpellOutConfig,
+    SpellOutMode, SpellOutRule, StreamingTts, TtsConfig, TtsEngine, TtsEvent, TtsFallback,
+    TtsRecovery, TtsStyle, WordChunker, FALLBACK_SAMPLE_RATE,
+};
 // P1-3 FIX: Export TTS backend types and factory
 pub use tts::{create_tts_backend, StubTtsBackend, TtsBackend};
 #[cfg(feature = "candle")]
@@ -44,6 +51,7 @@ pub use tts::{IndicF5Backend, IndicF5Config, IndicF5Model};
 pub use orchestrator::{
     BargeInAction,
     BargeInConfig,
+    BargeInProfile,
     PipelineConfig,
     PipelineEvent,
     PipelineState,
@@ -52,6 +60,12 @@ pub use orchestrator::{
     VoicePipeline,
 };
 
+// Offline file ingestion exports
+pub use ingest::{load_wav, FileAudio, FileTranscript, TranscriptSegment};
+
+// Reframes client audio chunks to the VAD/STT frame size
+pub use reframe::AudioReframer;
+
 // Processor exports
 pub use processors::{
     // P2-2 FIX: Export generic processors for extensibility
@@ -59,6 +73,7 @@ pub use processors::{
     InterruptHandler,
     InterruptHandlerConfig,
     InterruptMode,
+    InterruptionReason,
     MapProcessor,
     PassthroughProcessor,
     ProcessorChain,
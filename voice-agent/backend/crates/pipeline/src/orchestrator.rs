@@ -11,23 +11,29 @@
 
 use futures::StreamExt;
 use parking_lot::Mutex;
+use std::collections::{HashMap, VecDeque};
 use std::sync::Arc;
-use std::time::Instant;
+use std::time::{Duration, Instant};
 use tokio::sync::{broadcast, mpsc};
 
-use crate::stt::{IndicConformerConfig, IndicConformerStt, StreamingStt, SttBackend, SttConfig};
-use crate::tts::{StreamingTts, TtsConfig, TtsEvent};
+use crate::ingest::{load_wav, resample, FileTranscript, TranscriptSegment};
+use crate::reframe::AudioReframer;
+use crate::stt::{
+    IndicConformerConfig, IndicConformerStt, LanguageDetection, LanguageIdConfig,
+    StreamingLanguageId, StreamingStt, SttBackend, SttConfig,
+};
+use crate::tts::{StreamingTts, TtsConfig, TtsEvent, TtsFallback, TtsRecovery};
 use crate::turn_detection::{HybridTurnDetector, TurnDetectionConfig, TurnDetectionResult};
 use crate::vad::{SileroConfig, SileroVad, VadConfig, VadEngine, VadState, VoiceActivityDetector};
 use crate::PipelineError;
 use voice_agent_core::{
-    AudioFrame, AudioProcessor, ControlFrame, Frame, GenerateRequest, Language, LanguageModel,
+    AudioFrame, AudioProcessor, Channels, ControlFrame, Frame, GenerateRequest, Language, LanguageModel,
     ProcessorContext, TextProcessor, TranscriptResult,
 };
 
 // P1 FIX: Import processors for streaming LLM → TTS pipeline
 use crate::processors::{
-    InterruptHandler, InterruptHandlerConfig, ProcessorChain, SentenceDetector,
+    InterruptHandler, InterruptHandlerConfig, InterruptionReason, ProcessorChain, SentenceDetector,
     SentenceDetectorConfig, TtsProcessor, TtsProcessorConfig,
 };
 
@@ -42,6 +48,9 @@ pub enum PipelineEvent {
     PartialTranscript(TranscriptResult),
     /// Final transcript available
     FinalTranscript(TranscriptResult),
+    /// Spoken language identified from the early decode, at most once per
+    /// utterance and before its final transcript
+    LanguageDetected(LanguageDetection),
     /// P0 FIX: Agent text response (sent before TTS audio)
     Response {
         text: String,
@@ -50,13 +59,24 @@ pub enum PipelineEvent {
     /// TTS audio chunk ready
     TtsAudio {
         samples: Arc<[f32]>,
+        /// Text this audio starts; empty when it continues the previous chunk's
         text: String,
         is_final: bool,
     },
-    /// Barge-in detected
+    /// TTS playback interrupted, by the user or the system
     BargeIn {
-        /// Word index where user interrupted
+        /// Word index where playback stopped
         at_word: usize,
+        /// What cut playback short
+        reason: InterruptionReason,
+    },
+    /// TTS couldn't synthesize the response, even after retrying
+    ///
+    /// The caller heard the fallback audio (if any) instead of the rest of
+    /// the response, so it should be treated as undelivered.
+    TtsFailed {
+        /// Last synthesis error
+        error: String,
     },
     /// Error occurred
     Error(String),
@@ -71,6 +91,8 @@ pub struct PipelineConfig {
     pub turn_detection: TurnDetectionConfig,
     /// STT configuration
     pub stt: SttConfig,
+    /// Spoken-language identification from the STT's early decode
+    pub language_id: LanguageIdConfig,
     /// TTS configuration
     pub tts: TtsConfig,
     /// Barge-in settings
@@ -81,6 +103,9 @@ pub struct PipelineConfig {
     pub processors: ProcessorChainConfig,
     /// P0-3 FIX: LLM configuration for automatic response generation
     pub llm: LlmConfig,
+    /// Frame duration (ms) fed to VAD/STT; incoming chunks of any size are
+    /// reframed to it. `None` passes client chunks through unchanged.
+    pub frame_ms: Option<u32>,
 }
 
 /// P0-3 FIX: LLM configuration for the pipeline
@@ -144,16 +169,25 @@ impl Default for PipelineConfig {
             vad: VadConfig::default(),
             turn_detection: TurnDetectionConfig::default(),
             stt: SttConfig::default(),
+            language_id: LanguageIdConfig::default(),
             tts: TtsConfig::default(),
             barge_in: BargeInConfig::default(),
             latency_budget_ms: 500,
             processors: ProcessorChainConfig::default(),
             llm: LlmConfig::default(),
+            frame_ms: Some(voice_agent_config::constants::audio::FRAME_MS),
         }
     }
 }
 
 /// Barge-in configuration
+///
+/// `min_speech_ms`, `min_words` and the interrupt handler's
+/// `grace_period_ms` set how easily the caller can talk over the agent.
+/// `stages` overrides them per conversation stage, e.g. to make barge-in
+/// effectively impossible while a disclosure is read but easy during a long
+/// explanation; the agent's stage is set with
+/// `VoicePipeline::set_barge_in_stage`.
 #[derive(Debug, Clone)]
 pub struct BargeInConfig {
     /// Enable barge-in detection
@@ -164,6 +198,12 @@ pub struct BargeInConfig {
     pub min_energy_db: f32,
     /// Action on barge-in
     pub action: BargeInAction,
+    /// Longest a protected utterance can hold off barge-in (ms)
+    pub protected_max_ms: u32,
+    /// Words the caller must say before speech counts as barge-in (0 = any speech)
+    pub min_words: usize,
+    /// Sensitivity overrides keyed by stage ID (e.g. "closing")
+    pub stages: HashMap<String, BargeInProfile>,
 }
 
 impl Default for BargeInConfig {
@@ -173,10 +213,85 @@ impl Default for BargeInConfig {
             min_speech_ms: 150,
             min_energy_db: -40.0,
             action: BargeInAction::StopAndListen,
+            protected_max_ms: 8000,
+            min_words: 0,
+            stages: HashMap::new(),
         }
     }
 }
 
+impl PipelineConfig {
+    /// Barge-in sensitivity for a stage, falling back to the defaults
+    pub fn barge_in_profile(&self, stage: Option<&str>) -> BargeInProfile {
+        stage
+            .and_then(|stage| self.barge_in.stages.get(stage))
+            .copied()
+            .unwrap_or(BargeInProfile {
+                grace_period_ms: self.processors.interrupt_handler.grace_period_ms,
+                min_speech_ms: self.barge_in.min_speech_ms,
+                min_words: self.barge_in.min_words,
+            })
+    }
+
+    /// Take TTS settings from the `pipeline.tts` config section
+    pub fn apply_tts_settings(&mut self, settings: &voice_agent_config::pipeline::TtsConfig) {
+        self.tts.spell_out = settings.spell_out.clone();
+        self.processors.tts_processor.retries = settings.retries;
+        self.processors.tts_processor.fallback = TtsFallback::from_settings(&settings.fallback);
+    }
+
+    /// Take barge-in settings from the `pipeline.barge_in` config section
+    ///
+    /// The grace period goes to the interrupt handler; stage profiles fill
+    /// the fields they leave out from the top-level settings.
+    pub fn apply_barge_in_settings(
+        &mut self,
+        settings: &voice_agent_config::pipeline::BargeInConfig,
+    ) {
+        use voice_agent_config::pipeline::BargeInAction as Action;
+
+        self.barge_in.enabled = settings.enabled;
+        self.barge_in.min_speech_ms = settings.min_speech_ms;
+        self.barge_in.min_energy_db = settings.energy_threshold_db;
+        self.barge_in.action = match settings.action {
+            Action::StopAndListen | Action::StopAndAcknowledge => BargeInAction::StopAndListen,
+            Action::DuckAndContinue => BargeInAction::FadeOut,
+            Action::Ignore => BargeInAction::Ignore,
+        };
+        self.barge_in.protected_max_ms = settings.protected_max_ms;
+        self.barge_in.min_words = settings.min_words;
+        self.barge_in.stages = settings
+            .stages
+            .iter()
+            .map(|(stage, profile)| {
+                let profile = BargeInProfile {
+                    grace_period_ms: profile.grace_period_ms.unwrap_or(settings.grace_period_ms),
+                    min_speech_ms: profile.min_speech_ms.unwrap_or(settings.min_speech_ms),
+                    min_words: profile.min_words.unwrap_or(settings.min_words),
+                };
+                (stage.clone(), profile)
+            })
+            .collect();
+
+        let interrupt = &mut self.processors.interrupt_handler;
+        interrupt.grace_period_ms = settings.grace_period_ms;
+        interrupt.min_speech_duration_ms = settings.min_speech_ms;
+        interrupt.min_energy_db = settings.energy_threshold_db;
+        interrupt.protected_max_ms = settings.protected_max_ms;
+    }
+}
+
+/// How easily the caller can barge in during one conversation stage
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BargeInProfile {
+    /// Playback time after the agent starts speaking during which barge-in is ignored (ms)
+    pub grace_period_ms: u32,
+    /// Minimum speech duration to trigger barge-in (ms)
+    pub min_speech_ms: u32,
+    /// Words the caller must say before speech counts as barge-in (0 = any speech)
+    pub min_words: usize,
+}
+
 /// Barge-in action
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum BargeInAction {
@@ -188,6 +303,20 @@ pub enum BargeInAction {
     Ignore,
 }
 
+impl BargeInAction {
+    /// Interruption reported when user speech triggers this action
+    ///
+    /// None when the action leaves playback running.
+    pub fn interruption_reason(&self) -> Option<InterruptionReason> {
+        match self {
+            BargeInAction::StopAndListen | BargeInAction::FadeOut => {
+                Some(InterruptionReason::UserBargeIn)
+            },
+            BargeInAction::Ignore => None,
+        }
+    }
+}
+
 /// Pipeline state
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum PipelineState {
@@ -210,17 +339,31 @@ pub struct VoicePipeline {
     turn_detector: Arc<HybridTurnDetector>,
     /// STT backend (StreamingStt or IndicConformerStt)
     stt: Arc<Mutex<dyn SttBackend + Send>>,
+    /// Identifies the spoken language from STT partials
+    language_id: Mutex<StreamingLanguageId>,
     tts: Arc<StreamingTts>,
     state: Mutex<PipelineState>,
     /// Event broadcaster
     event_tx: broadcast::Sender<PipelineEvent>,
     /// Barge-in speech accumulator
     barge_in_speech_ms: Mutex<u32>,
+    /// Why TTS was last told to stop, once reported to subscribers
+    interruption: Mutex<Option<InterruptionReason>>,
+    /// Conversation stage picking the barge-in profile
+    barge_in_stage: Mutex<Option<String>>,
+    /// When the agent started speaking, for the barge-in grace period
+    speaking_since: Mutex<Option<Instant>>,
+    /// Silence heard since the caller last spoke over the agent (ms)
+    barge_in_silence_ms: Mutex<u32>,
     /// Last audio timestamp
     last_audio_time: Mutex<Instant>,
     /// P1 FIX: Processor chain for streaming LLM → TTS
     /// Contains: SentenceDetector → TtsProcessor → InterruptHandler
     processor_chain: Option<ProcessorChain>,
+    /// The chain's interrupt handler, kept to apply stage grace periods
+    interrupt_handler: Option<Arc<InterruptHandler>>,
+    /// Input of the chain run speaking the current streamed response
+    chain_input: Mutex<Option<mpsc::WeakSender<Frame>>>,
     /// P0-3 FIX: LLM for automatic response generation
     llm: Option<Arc<dyn LanguageModel>>,
     /// P0-3 FIX: Pending transcript waiting for LLM processing
@@ -229,6 +372,10 @@ pub struct VoicePipeline {
     text_processor: Option<Arc<dyn TextProcessor>>,
     /// P2 FIX: Noise suppressor for cleaning audio before VAD/STT
     noise_suppressor: Option<Arc<dyn AudioProcessor>>,
+    /// Reframes client chunks to the configured frame size
+    reframer: Option<Mutex<AudioReframer>>,
+    /// Recent idle frames replayed to STT when speech starts (VAD pre-roll)
+    pre_roll: Mutex<VecDeque<AudioFrame>>,
 }
 
 impl VoicePipeline {
@@ -244,6 +391,7 @@ impl VoicePipeline {
                 min_speech_frames: config.vad.min_speech_frames,
                 min_silence_frames: config.vad.min_silence_frames,
                 energy_floor_db: config.vad.energy_floor_db,
+                hangover_ms: config.vad.hangover_ms,
                 ..Default::default()
             };
             match SileroVad::new(silero_path, silero_config) {
@@ -276,27 +424,41 @@ impl VoicePipeline {
         let (event_tx, _) = broadcast::channel(1000);
 
         // P1 FIX: Build processor chain if enabled
-        let processor_chain = if config.processors.enabled {
-            Some(Self::build_processor_chain(&config.processors, tts.clone()))
+        let (processor_chain, interrupt_handler) = if config.processors.enabled {
+            let (chain, handler) =
+                Self::build_processor_chain(&config.processors, &config.barge_in, tts.clone());
+            (Some(chain), Some(handler))
         } else {
-            None
+            (None, None)
         };
 
+        let reframer = config.frame_ms.map(|ms| Mutex::new(AudioReframer::new(ms)));
+        let language_id = Mutex::new(StreamingLanguageId::new(config.language_id.clone()));
+
         Ok(Self {
             config,
             vad,
             turn_detector,
             stt,
+            language_id,
             tts,
             state: Mutex::new(PipelineState::Idle),
             event_tx,
             barge_in_speech_ms: Mutex::new(0),
+            interruption: Mutex::new(None),
+            barge_in_stage: Mutex::new(None),
+            speaking_since: Mutex::new(None),
+            barge_in_silence_ms: Mutex::new(0),
             last_audio_time: Mutex::new(Instant::now()),
             processor_chain,
+            interrupt_handler,
+            chain_input: Mutex::new(None),
             llm: None, // P0-3 FIX: LLM not set by default, use with_llm()
             pending_transcript: Mutex::new(None),
             text_processor: None, // P0 FIX: Not set by default, use with_text_processor()
             noise_suppressor: None, // P2 FIX: Not set by default, use with_noise_suppressor()
+            reframer,
+            pre_roll: Mutex::new(VecDeque::new()),
         })
     }
 
@@ -330,6 +492,7 @@ impl VoicePipeline {
                 min_speech_frames: config.vad.min_speech_frames,
                 min_silence_frames: config.vad.min_silence_frames,
                 energy_floor_db: config.vad.energy_floor_db,
+                hangover_ms: config.vad.hangover_ms,
                 ..Default::default()
             };
             match SileroVad::new(silero_path, silero_config) {
@@ -374,7 +537,7 @@ impl VoicePipeline {
         let tts_model_path = std::path::Path::new("models/tts/IndicF5");
         let tts_reference_path = std::path::Path::new("models/tts/IndicF5/samples/namaste.wav");
 
-        let tts_config = if tts_model_path.exists() {
+        let mut tts_config = if tts_model_path.exists() {
             if tts_reference_path.exists() {
                 tracing::info!("Configuring TTS with IndicF5 model and reference audio");
                 TtsConfig::indicf5_with_reference(tts_model_path, tts_reference_path)
@@ -386,6 +549,7 @@ impl VoicePipeline {
             tracing::warn!("IndicF5 TTS model not found at {}, using default TTS config", tts_model_path.display());
             config.tts.clone()
         };
+        tts_config.spell_out = config.tts.spell_out.clone();
 
         // P0 FIX: Use from_config to load real TTS model, fallback to simple (silence) on error
         let tts = match StreamingTts::from_config(tts_config.clone()) {
@@ -403,31 +567,45 @@ impl VoicePipeline {
         let (event_tx, _) = broadcast::channel(1000);
 
         // Build processor chain if enabled
-        let processor_chain = if config.processors.enabled {
-            Some(Self::build_processor_chain(&config.processors, tts.clone()))
+        let (processor_chain, interrupt_handler) = if config.processors.enabled {
+            let (chain, handler) =
+                Self::build_processor_chain(&config.processors, &config.barge_in, tts.clone());
+            (Some(chain), Some(handler))
         } else {
-            None
+            (None, None)
         };
 
         tracing::info!(
             "Created VoicePipeline with IndicConformer STT (ONNX enabled)"
         );
 
+        let reframer = config.frame_ms.map(|ms| Mutex::new(AudioReframer::new(ms)));
+        let language_id = Mutex::new(StreamingLanguageId::new(config.language_id.clone()));
+
         Ok(Self {
             config,
             vad,
             turn_detector,
             stt,
+            language_id,
             tts,
             state: Mutex::new(PipelineState::Idle),
             event_tx,
             barge_in_speech_ms: Mutex::new(0),
+            interruption: Mutex::new(None),
+            barge_in_stage: Mutex::new(None),
+            speaking_since: Mutex::new(None),
+            barge_in_silence_ms: Mutex::new(0),
             last_audio_time: Mutex::new(Instant::now()),
             processor_chain,
+            interrupt_handler,
+            chain_input: Mutex::new(None),
             llm: None,
             pending_transcript: Mutex::new(None),
             text_processor: None,
             noise_suppressor: None,
+            reframer,
+            pre_roll: Mutex::new(VecDeque::new()),
         })
     }
 
@@ -495,6 +673,19 @@ impl VoicePipeline {
         self.noise_suppressor.is_some()
     }
 
+    /// Replace the STT backend
+    ///
+    /// # Example
+    /// ```ignore
+    /// let stt = create_stt_backend(SttEngine::IndicConformer, model_dir, "hi")?;
+    /// let pipeline = VoicePipeline::simple(config)?
+    ///     .with_stt(stt);
+    /// ```
+    pub fn with_stt(mut self, stt: Arc<Mutex<dyn SttBackend + Send>>) -> Self {
+        self.stt = stt;
+        self
+    }
+
     /// P0-3 FIX: Handle a final transcript by calling LLM and streaming to TTS
     ///
     /// This is the core auto-response logic that connects STT → LLM → TTS.
@@ -576,13 +767,23 @@ impl VoicePipeline {
                 // Spawn task to forward TTS audio frames to event channel
                 tokio::spawn(async move {
                     let mut output_rx = output_rx;
+                    // Sentence whose audio comes next
+                    let mut sentence = String::new();
                     while let Some(frame) = output_rx.recv().await {
-                        if let Frame::AudioOutput(audio) = frame {
-                            let _ = pipeline_event_tx.send(PipelineEvent::TtsAudio {
-                                samples: audio.samples.into(),
-                                text: String::new(), // Word text not available in this path
-                                is_final: false,
-                            });
+                        match frame {
+                            Frame::Sentence { text, .. } => sentence = text,
+                            Frame::AudioOutput(audio) => {
+                                let _ = pipeline_event_tx.send(PipelineEvent::TtsAudio {
+                                    samples: audio.samples.into(),
+                                    text: std::mem::take(&mut sentence),
+                                    is_final: false,
+                                });
+                            },
+                            Frame::Error { stage, message, .. } if stage == "tts_processor" => {
+                                let _ = pipeline_event_tx
+                                    .send(PipelineEvent::TtsFailed { error: message });
+                            },
+                            _ => {},
                         }
                     }
                 })
@@ -682,10 +883,14 @@ impl VoicePipeline {
     /// 1. Buffers LLM text chunks until sentence boundary
     /// 2. Sends complete sentences to TTS for synthesis
     /// 3. Handles barge-in interrupts during audio playback
+    ///
+    /// The interrupt handler is returned too, to change its grace period
+    /// with the conversation stage.
     fn build_processor_chain(
         config: &ProcessorChainConfig,
+        barge_in: &BargeInConfig,
         tts: Arc<StreamingTts>,
-    ) -> ProcessorChain {
+    ) -> (ProcessorChain, Arc<InterruptHandler>) {
         let mut chain = ProcessorChain::new("llm-to-audio");
 
         // 1. Sentence detector: buffers LLM chunks, emits sentences
@@ -698,7 +903,10 @@ impl VoicePipeline {
         chain.add(TtsProcessor::with_tts(tts_config, tts));
 
         // 3. Interrupt handler: manages barge-in during audio output
-        chain.add(InterruptHandler::new(config.interrupt_handler.clone()));
+        let mut interrupt_config = config.interrupt_handler.clone();
+        interrupt_config.protected_max_ms = barge_in.protected_max_ms;
+        let interrupt_handler = Arc::new(InterruptHandler::new(interrupt_config));
+        chain.add_boxed(interrupt_handler.clone());
 
         tracing::info!(
             chain_name = chain.name(),
@@ -706,7 +914,7 @@ impl VoicePipeline {
             "Built LLM → Audio processor chain"
         );
 
-        chain
+        (chain, interrupt_handler)
     }
 
     /// Subscribe to pipeline events
@@ -714,8 +922,143 @@ impl VoicePipeline {
         self.event_tx.subscribe()
     }
 
-    /// Process an audio frame
-    pub async fn process_audio(&self, mut frame: AudioFrame) -> Result<(), PipelineError> {
+    /// Process incoming audio
+    ///
+    /// Chunks are reframed to `PipelineConfig::frame_ms` before VAD/STT;
+    /// residual samples are held until the next call.
+    pub async fn process_audio(&self, frame: AudioFrame) -> Result<(), PipelineError> {
+        let Some(reframer) = &self.reframer else {
+            return self.process_frame(frame).await;
+        };
+
+        let frames = reframer.lock().push(&frame);
+        for frame in frames {
+            self.process_frame(frame).await?;
+        }
+        Ok(())
+    }
+
+    /// Transcribe a recorded call (WAV) through the live VAD/STT/turn-detection path
+    ///
+    /// The file is resampled to the STT sample rate and fed in 20ms chunks
+    /// timestamped from the start of the file, so turns are detected with
+    /// live timing at full speed. With an LLM configured, each turn's agent
+    /// response is recorded alongside its transcript.
+    pub async fn process_file(
+        &self,
+        path: impl AsRef<std::path::Path>,
+    ) -> Result<FileTranscript, PipelineError> {
+        let audio = load_wav(path)?;
+        let sample_rate = self.config.stt.sample_rate;
+        let rate_hz = sample_rate.as_u32() as u64;
+        let samples = resample(&audio.samples, audio.sample_rate, sample_rate.as_u32())?;
+
+        let duration_ms = audio.duration_ms();
+        let mut transcript = FileTranscript {
+            source_sample_rate: audio.sample_rate,
+            duration_ms,
+            segments: Vec::new(),
+        };
+        let mut events = self.subscribe();
+        let mut turn_start_ms = None;
+
+        let base = Instant::now();
+        let chunk_size = sample_rate.frame_size_20ms();
+        let mut offset = 0usize;
+        for (sequence, chunk) in samples.chunks(chunk_size).enumerate() {
+            let start_ms = offset as u64 * 1000 / rate_hz;
+            offset += chunk.len();
+            let end_ms = offset as u64 * 1000 / rate_hz;
+
+            let frame = AudioFrame::with_timestamp(
+                chunk.to_vec(),
+                sample_rate,
+                Channels::Mono,
+                sequence as u64,
+                base + Duration::from_millis(start_ms),
+            );
+            self.process_audio(frame).await?;
+
+            if turn_start_ms.is_none() && self.state() == PipelineState::Listening {
+                turn_start_ms = Some(start_ms);
+            }
+            Self::drain_file_events(&mut events, &mut transcript, &mut turn_start_ms, end_ms);
+
+            if self.state() == PipelineState::Processing {
+                if self.has_llm() {
+                    self.process_pending().await?;
+                } else {
+                    // No agent to respond: return to listening for the next turn
+                    self.pending_transcript.lock().take();
+                    self.turn_detector.reset();
+                    *self.state.lock() = PipelineState::Idle;
+                }
+                Self::drain_file_events(&mut events, &mut transcript, &mut turn_start_ms, end_ms);
+            }
+        }
+
+        // The end of the file ends any turn still in progress
+        if let Some(residual) = self.reframer.as_ref().and_then(|r| r.lock().flush()) {
+            self.process_frame(residual).await?;
+            Self::drain_file_events(
+                &mut events,
+                &mut transcript,
+                &mut turn_start_ms,
+                duration_ms,
+            );
+        }
+        if self.state() == PipelineState::Listening {
+            let final_transcript = self.stt.lock().finalize_sync();
+            if !final_transcript.text.is_empty() {
+                transcript.segments.push(TranscriptSegment {
+                    start_ms: turn_start_ms.take().unwrap_or_default(),
+                    end_ms: duration_ms,
+                    text: final_transcript.text,
+                    confidence: final_transcript.confidence,
+                    response: None,
+                });
+            }
+            self.turn_detector.reset();
+            *self.state.lock() = PipelineState::Idle;
+        }
+
+        Ok(transcript)
+    }
+
+    /// Record final transcripts and agent responses emitted while replaying a file
+    fn drain_file_events(
+        events: &mut broadcast::Receiver<PipelineEvent>,
+        transcript: &mut FileTranscript,
+        turn_start_ms: &mut Option<u64>,
+        at_ms: u64,
+    ) {
+        loop {
+            match events.try_recv() {
+                Ok(PipelineEvent::FinalTranscript(result)) => {
+                    transcript.segments.push(TranscriptSegment {
+                        start_ms: turn_start_ms.take().unwrap_or(at_ms),
+                        end_ms: at_ms,
+                        text: result.text,
+                        confidence: result.confidence,
+                        response: None,
+                    });
+                },
+                Ok(PipelineEvent::Response {
+                    text,
+                    is_final: true,
+                }) => {
+                    if let Some(segment) = transcript.segments.last_mut() {
+                        segment.response = Some(text);
+                    }
+                },
+                Ok(_) | Err(broadcast::error::TryRecvError::Lagged(_)) => {},
+                Err(_) => break,
+            }
+        }
+    }
+
+    /// Process a single frame through noise suppression, VAD, STT and turn detection
+    async fn process_frame(&self, mut frame: AudioFrame) -> Result<(), PipelineError> {
         let now = Instant::now();
         *self.last_audio_time.lock() = now;
 
@@ -781,6 +1124,7 @@ impl VoicePipeline {
                 // Real speech typically has energy > -45 dB
                 const MIN_SPEECH_ENERGY_DB: f32 = -45.0;
                 let has_enough_energy = frame.energy_db > MIN_SPEECH_ENERGY_DB;
+                self.push_pre_roll(&frame);
 
                 if (vad_state == VadState::Speech || vad_state == VadState::SpeechStart) && has_enough_energy {
                     tracing::info!(
@@ -790,6 +1134,8 @@ impl VoicePipeline {
                     );
                     *self.state.lock() = PipelineState::Listening;
                     self.stt.lock().reset();
+                    self.language_id.lock().reset();
+                    self.feed_pre_roll()?;
                 } else if vad_state == VadState::Speech || vad_state == VadState::SpeechStart {
                     tracing::debug!(
                         vad_state = ?vad_state,
@@ -869,9 +1215,23 @@ impl VoicePipeline {
                         let _ = self
                             .event_tx
                             .send(PipelineEvent::PartialTranscript(partial.clone()));
+                        if let Some(detection) = self.language_id.lock().observe(&partial.text) {
+                            tracing::info!(
+                                language = ?detection.language,
+                                confidence = format!("{:.2}", detection.confidence),
+                                "Pipeline: Spoken language detected"
+                            );
+                            let _ = self
+                                .event_tx
+                                .send(PipelineEvent::LanguageDetected(detection));
+                        }
 
                         // Update turn detector with transcript
-                        let turn_result = self.turn_detector.process(vad_state, Some(&partial.text))?;
+                        let turn_result = self.turn_detector.process_at(
+                            vad_state,
+                            Some(&partial.text),
+                            frame.timestamp,
+                        )?;
 
                         tracing::debug!(
                             turn_state = ?turn_result.state,
@@ -905,7 +1265,9 @@ impl VoicePipeline {
                     },
                     Ok(None) => {
                         // No transcript yet, but still check turn detector with VAD
-                        let turn_result = self.turn_detector.process(vad_state, None)?;
+                        let turn_result =
+                            self.turn_detector
+                                .process_at(vad_state, None, frame.timestamp)?;
 
                         // DIAGNOSTIC: Log turn detection periodically when no transcript
                         if listening_frame % 15 == 0 {
@@ -984,6 +1346,45 @@ impl VoicePipeline {
         Ok(())
     }
 
+    /// Buffer an idle frame, keeping `VadConfig::pre_roll_ms` of audio before it
+    fn push_pre_roll(&self, frame: &AudioFrame) {
+        if self.config.vad.pre_roll_ms == 0 {
+            return;
+        }
+        let pre_roll = Duration::from_millis(self.config.vad.pre_roll_ms as u64);
+
+        let mut buffer = self.pre_roll.lock();
+        buffer.push_back(frame.clone());
+        // The newest frame is the onset candidate; older frames are pre-roll
+        let mut buffered: Duration =
+            buffer.iter().map(|f| f.duration).sum::<Duration>() - frame.duration;
+        while let Some(oldest) = buffer.front() {
+            if buffer.len() == 1 || buffered.saturating_sub(oldest.duration) < pre_roll {
+                break;
+            }
+            buffered -= oldest.duration;
+            buffer.pop_front();
+        }
+    }
+
+    /// Hand the buffered pre-roll and onset frame to STT when speech starts
+    fn feed_pre_roll(&self) -> Result<(), PipelineError> {
+        let frames: Vec<AudioFrame> = self.pre_roll.lock().drain(..).collect();
+        if frames.is_empty() {
+            return Ok(());
+        }
+
+        tracing::debug!(
+            frames = frames.len(),
+            "Pipeline: Feeding VAD pre-roll to STT"
+        );
+        let mut stt = self.stt.lock();
+        for frame in &frames {
+            stt.process(&frame.samples)?;
+        }
+        Ok(())
+    }
+
     /// Check for barge-in during TTS
     async fn check_barge_in(
         &self,
@@ -994,7 +1395,18 @@ impl VoicePipeline {
             return Ok(false);
         }
 
-        if self.config.barge_in.action == BargeInAction::Ignore {
+        let Some(reason) = self.config.barge_in.action.interruption_reason() else {
+            return Ok(false);
+        };
+
+        let profile = self.barge_in_profile();
+        let grace = Duration::from_millis(u64::from(profile.grace_period_ms));
+        if self
+            .speaking_since
+            .lock()
+            .is_some_and(|since| since.elapsed() < grace)
+        {
+            *self.barge_in_speech_ms.lock() = 0;
             return Ok(false);
         }
 
@@ -1003,53 +1415,195 @@ impl VoicePipeline {
         let sufficient_energy = frame.energy_db >= self.config.barge_in.min_energy_db;
 
         if is_speech && sufficient_energy {
-            let mut speech_ms = self.barge_in_speech_ms.lock();
-            *speech_ms += self.config.vad.frame_ms;
-
-            if *speech_ms >= self.config.barge_in.min_speech_ms {
-                // Barge-in triggered!
-                let word_index = self.tts.current_word_index();
+            *self.barge_in_silence_ms.lock() = 0;
+            let speech_ms = {
+                let mut speech_ms = self.barge_in_speech_ms.lock();
+                *speech_ms += self.config.vad.frame_ms;
+                *speech_ms
+            };
+            let enough_words = self.barge_in_words_reached(frame, profile.min_words);
 
-                // Stop TTS
+            if speech_ms >= profile.min_speech_ms && enough_words {
+                // Barge-in triggered! Stop TTS and emit event
                 self.tts.barge_in();
-
-                // Emit event
-                let _ = self.event_tx.send(PipelineEvent::BargeIn {
-                    at_word: word_index,
-                });
+                self.forward_barge_in();
+                self.emit_interruption(reason);
 
                 // Switch to listening
                 *self.state.lock() = PipelineState::Listening;
-                *speech_ms = 0;
+                *self.barge_in_speech_ms.lock() = 0;
 
-                // Reset turn detector
+                // Reset turn detector; STT keeps the words counted for
+                // the barge-in, they start the caller's turn
                 self.turn_detector.reset();
-                self.stt.lock().reset();
+                if profile.min_words == 0 {
+                    self.stt.lock().reset();
+                }
 
                 return Ok(true);
             }
         } else {
             *self.barge_in_speech_ms.lock() = 0;
+            // Words counted so far carry over a pause between them; only
+            // silence long enough to end speech starts the count again
+            let silence_ms = {
+                let mut silence_ms = self.barge_in_silence_ms.lock();
+                *silence_ms += self.config.vad.frame_ms;
+                *silence_ms
+            };
+            if profile.min_words > 0 && silence_ms >= self.barge_in_silence_reset_ms() {
+                self.stt.lock().reset();
+            }
         }
 
         Ok(false)
     }
 
+    /// Silence that resets the barge-in word count: as much as ends speech
+    fn barge_in_silence_reset_ms(&self) -> u32 {
+        self.config.vad.min_silence_frames as u32 * self.config.vad.frame_ms
+    }
+
+    /// Whether the caller has said `min_words` words over the agent
+    ///
+    /// Feeds the frame to STT when words are counted. Words can't be
+    /// counted without STT, so a failure holds the barge-in back.
+    fn barge_in_words_reached(&self, frame: &AudioFrame, min_words: usize) -> bool {
+        if min_words == 0 {
+            return true;
+        }
+        let mut stt = self.stt.lock();
+        if let Err(e) = stt.process(&frame.samples) {
+            tracing::debug!(error = %e, "STT failed while counting barge-in words");
+            return false;
+        }
+        let words = stt
+            .partial()
+            .map_or(0, |partial| partial.text.split_whitespace().count());
+        words >= min_words
+    }
+
+    /// Pick the barge-in profile for a conversation stage
+    ///
+    /// Call as the agent moves between stages; stages without a profile in
+    /// `BargeInConfig::stages` use the default sensitivity. The stage's
+    /// grace period also applies to the processor chain's interrupt handler.
+    pub fn set_barge_in_stage(&self, stage: &str) {
+        let mut current = self.barge_in_stage.lock();
+        if current.as_deref() != Some(stage) {
+            let profile = self.config.barge_in_profile(Some(stage));
+            tracing::debug!(stage, ?profile, "Barge-in stage changed");
+            if let Some(handler) = &self.interrupt_handler {
+                handler.set_grace_period_ms(profile.grace_period_ms);
+            }
+            *current = Some(stage.to_string());
+        }
+    }
+
+    /// Set the caller's language, for synthesis and spoken-language detection
+    ///
+    /// Call when the call starts and whenever the agent switches language;
+    /// detections of the current language's script are not reported.
+    pub fn set_language(&self, language: Language) {
+        self.tts.set_language(language);
+        self.language_id.lock().set_language(language);
+    }
+
+    /// Barge-in sensitivity in effect for the current stage
+    pub fn barge_in_profile(&self) -> BargeInProfile {
+        self.config
+            .barge_in_profile(self.barge_in_stage.lock().as_deref())
+    }
+
+    /// Stop playback for a reason other than user speech
+    ///
+    /// A server drain or forced turn-end stops TTS the way a barge-in does
+    /// but reports its own reason, so listeners can tell them apart. Returns
+    /// false when nothing was playing.
+    pub fn interrupt(&self, reason: InterruptionReason) -> bool {
+        if *self.state.lock() != PipelineState::Speaking {
+            return false;
+        }
+        self.tts.barge_in();
+        self.emit_interruption(reason);
+
+        *self.state.lock() = if reason.is_user_initiated() {
+            PipelineState::Listening
+        } else {
+            PipelineState::Idle
+        };
+        self.turn_detector.reset();
+        true
+    }
+
+    /// Hand a barge-in to the processor chain speaking a streamed response
+    ///
+    /// Its interrupt handler stops the response, or holds the barge-in until
+    /// a protected utterance ends.
+    fn forward_barge_in(&self) {
+        let Some(input) = self.chain_input.lock().as_ref().and_then(|tx| tx.upgrade()) else {
+            return;
+        };
+        let audio_position_ms = self
+            .speaking_since
+            .lock()
+            .map_or(0, |since| since.elapsed().as_millis() as u64);
+        let frame = Frame::BargeIn {
+            audio_position_ms,
+            transcript: None,
+        };
+        if input.try_send(frame).is_err() {
+            tracing::debug!("Processor chain busy or done, barge-in not forwarded");
+        }
+    }
+
+    /// Longest a protected utterance holds off barge-in
+    pub fn protected_max(&self) -> Duration {
+        Duration::from_millis(u64::from(self.config.barge_in.protected_max_ms))
+    }
+
+    /// Report playback stopped for `reason`
+    fn emit_interruption(&self, reason: InterruptionReason) {
+        let at_word = self.tts.current_word_index();
+        self.report_interruption(at_word, reason);
+    }
+
+    fn report_interruption(&self, at_word: usize, reason: InterruptionReason) {
+        *self.interruption.lock() = Some(reason);
+        tracing::debug!(
+            at_word,
+            reason = reason.as_str(),
+            "TTS playback interrupted"
+        );
+        let _ = self
+            .event_tx
+            .send(PipelineEvent::BargeIn { at_word, reason });
+    }
+
     /// Start speaking a response
+    ///
+    /// A chunk TTS fails on is synthesized again, with the rest of the text,
+    /// up to the TTS processor's `retries` times; if it still fails its
+    /// `fallback` plays before `PipelineEvent::TtsFailed` is reported.
     pub async fn speak(&self, text: &str) -> Result<(), PipelineError> {
         // Set state
         *self.state.lock() = PipelineState::Speaking;
         self.turn_detector.set_agent_speaking();
         *self.barge_in_speech_ms.lock() = 0;
+        *self.barge_in_silence_ms.lock() = 0;
+        *self.interruption.lock() = None;
+        *self.speaking_since.lock() = Some(Instant::now());
 
-        // Create channel for TTS events
-        let (tx, mut rx) = mpsc::channel::<TtsEvent>(100);
+        let tts_config = &self.config.processors.tts_processor;
+        let mut recovery = TtsRecovery::new(tts_config.retries, tts_config.fallback.clone());
 
-        // Start TTS
-        self.tts.start(text, tx);
+        // Start TTS; its events are pulled below
+        let (tx, _rx) = mpsc::channel::<TtsEvent>(1);
+        let mut remaining = text.to_string();
+        self.tts.start(&remaining, tx);
 
         // Process TTS events
-        while let Some(event) = rx.recv().await {
+        while let Some(event) = recovery.next_event(&self.tts, &mut remaining) {
             match event {
                 TtsEvent::Audio {
                     samples,
@@ -1068,19 +1622,38 @@ impl VoicePipeline {
                     self.turn_detector.reset();
                     break;
                 },
-                TtsEvent::BargedIn { word_index } => {
-                    let _ = self.event_tx.send(PipelineEvent::BargeIn {
-                        at_word: word_index,
-                    });
+                TtsEvent::BargedIn { word_index, .. } => {
+                    // Whatever stopped playback reported it already
+                    if self.interruption.lock().is_none() {
+                        self.report_interruption(word_index, InterruptionReason::UserBargeIn);
+                    }
                     break;
                 },
                 TtsEvent::Error(e) => {
-                    let _ = self.event_tx.send(PipelineEvent::Error(e));
+                    let samples = recovery.fallback_samples(self.tts.sample_rate());
+                    tracing::error!(
+                        attempts = recovery.attempts(),
+                        fallback = recovery.fallback().as_str(),
+                        error = %e,
+                        "TTS failed, playing fallback"
+                    );
+                    if !samples.is_empty() {
+                        let _ = self.event_tx.send(PipelineEvent::TtsAudio {
+                            samples: samples.into(),
+                            text: String::new(),
+                            is_final: true,
+                        });
+                    }
+                    self.emit_interruption(InterruptionReason::Error);
+                    let _ = self.event_tx.send(PipelineEvent::TtsFailed { error: e });
                     *self.state.lock() = PipelineState::Idle;
                     break;
                 },
                 _ => {},
             }
+
+            // Let barge-in detection run between chunks
+            tokio::task::yield_now().await;
         }
 
         Ok(())
@@ -1103,9 +1676,31 @@ impl VoicePipeline {
     /// # Returns
     /// Receiver for output audio frames
     pub async fn speak_streaming(
+        &self,
+        chunk_rx: mpsc::Receiver<String>,
+        language: Language,
+    ) -> Result<mpsc::Receiver<Frame>, PipelineError> {
+        self.speak_streaming_with(chunk_rx, language, false).await
+    }
+
+    /// Speak an utterance that barge-in must not cut off
+    ///
+    /// Like `speak_streaming`, but a barge-in while it plays is deferred until
+    /// it finishes (or `BargeInConfig::protected_max_ms` passes). Use for
+    /// compliance disclosures and required questions.
+    pub async fn speak_streaming_protected(
+        &self,
+        chunk_rx: mpsc::Receiver<String>,
+        language: Language,
+    ) -> Result<mpsc::Receiver<Frame>, PipelineError> {
+        self.speak_streaming_with(chunk_rx, language, true).await
+    }
+
+    async fn speak_streaming_with(
         &self,
         mut chunk_rx: mpsc::Receiver<String>,
         language: Language,
+        protected: bool,
     ) -> Result<mpsc::Receiver<Frame>, PipelineError> {
         // Check if processor chain is available
         let chain = self
@@ -1117,14 +1712,25 @@ impl VoicePipeline {
         *self.state.lock() = PipelineState::Speaking;
         self.turn_detector.set_agent_speaking();
         *self.barge_in_speech_ms.lock() = 0;
+        *self.barge_in_silence_ms.lock() = 0;
+        *self.interruption.lock() = None;
+        *self.speaking_since.lock() = Some(Instant::now());
 
         // Start the processor chain with session context
         let context = ProcessorContext::new("streaming-session").with_language(language);
 
         let (input_tx, output_rx) = chain.run(context);
+        // Weak, so the chain still ends once the response is fed in
+        *self.chain_input.lock() = Some(input_tx.downgrade());
 
         // Spawn task to feed LLM chunks into the processor chain
         tokio::spawn(async move {
+            if protected {
+                let _ = input_tx
+                    .send(Frame::Control(ControlFrame::ProtectUtterance(true)))
+                    .await;
+            }
+
             while let Some(chunk) = chunk_rx.recv().await {
                 // Create LLM chunk frame
                 let frame = Frame::LLMChunk {
@@ -1149,6 +1755,12 @@ impl VoicePipeline {
             // Send flush control frame
             let _ = input_tx.send(Frame::Control(ControlFrame::Flush)).await;
 
+            if protected {
+                let _ = input_tx
+                    .send(Frame::Control(ControlFrame::ProtectUtterance(false)))
+                    .await;
+            }
+
             tracing::debug!("LLM streaming complete, sent flush to processor chain");
         });
 
@@ -1187,8 +1799,15 @@ impl VoicePipeline {
         self.vad.reset();
         self.turn_detector.reset();
         self.stt.lock().reset();
+        self.language_id.lock().reset();
         self.tts.reset();
         *self.barge_in_speech_ms.lock() = 0;
+        *self.barge_in_silence_ms.lock() = 0;
+        *self.interruption.lock() = None;
+        self.pre_roll.lock().clear();
+        if let Some(reframer) = &self.reframer {
+            reframer.lock().reset();
+        }
     }
 
     /// Get current transcript
@@ -1230,4 +1849,573 @@ mod tests {
         pipeline.reset();
         assert_eq!(pipeline.state(), PipelineState::Idle);
     }
+
+    /// Next interruption reported on `events`
+    fn next_interruption(
+        events: &mut broadcast::Receiver<PipelineEvent>,
+    ) -> Option<InterruptionReason> {
+        while let Ok(event) = events.try_recv() {
+            if let PipelineEvent::BargeIn { reason, .. } = event {
+                return Some(reason);
+            }
+        }
+        None
+    }
+
+    #[tokio::test]
+    async fn test_barge_in_and_forced_turn_end_report_distinct_reasons() {
+        let pipeline = VoicePipeline::simple(PipelineConfig::default()).unwrap();
+        let mut events = pipeline.subscribe();
+
+        // The user talks over the agent
+        *pipeline.state.lock() = PipelineState::Speaking;
+        let loud = create_test_frame(vec![0.5; 320]);
+        let mut barged_in = false;
+        for _ in 0..50 {
+            if pipeline.check_barge_in(&loud, VadState::Speech).await.unwrap() {
+                barged_in = true;
+                break;
+            }
+        }
+        assert!(barged_in);
+        assert_eq!(
+            next_interruption(&mut events),
+            Some(InterruptionReason::UserBargeIn)
+        );
+        assert_eq!(next_interruption(&mut events), None);
+        assert_eq!(pipeline.state(), PipelineState::Listening);
+
+        // The system ends the agent's turn
+        *pipeline.state.lock() = PipelineState::Speaking;
+        assert!(pipeline.interrupt(InterruptionReason::ForcedTurnEnd));
+        assert_eq!(
+            next_interruption(&mut events),
+            Some(InterruptionReason::ForcedTurnEnd)
+        );
+        assert_eq!(pipeline.state(), PipelineState::Idle);
+
+        // Nothing playing, nothing reported
+        assert!(!pipeline.interrupt(InterruptionReason::ServerDrain));
+        assert_eq!(next_interruption(&mut events), None);
+    }
+
+    /// TTS backend failing the calls (numbered from 0) `fails` picks
+    struct FailingTts {
+        fails: fn(usize) -> bool,
+        calls: std::sync::atomic::AtomicUsize,
+    }
+
+    #[async_trait::async_trait]
+    impl crate::tts::TtsBackend for FailingTts {
+        async fn synthesize(&self, _text: &str) -> Result<Vec<f32>, PipelineError> {
+            let call = self.calls.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            if (self.fails)(call) {
+                return Err(PipelineError::Tts("engine crashed".to_string()));
+            }
+            Ok(vec![0.5; 160])
+        }
+
+        fn sample_rate(&self) -> u32 {
+            16000
+        }
+
+        fn supports_streaming(&self) -> bool {
+            false
+        }
+    }
+
+    /// Pipeline speaking through `backend`, falling back to an 800-sample clip
+    fn pipeline_with_tts(fails: fn(usize) -> bool) -> VoicePipeline {
+        let backend = FailingTts {
+            fails,
+            calls: Default::default(),
+        };
+        let mut config = PipelineConfig::default();
+        config.processors.tts_processor.fallback = TtsFallback::Clip(vec![0.25; 800].into());
+        let mut pipeline = VoicePipeline::simple(config).unwrap();
+        pipeline.tts = Arc::new(StreamingTts::with_backend(
+            Arc::new(backend),
+            TtsConfig::default(),
+        ));
+        pipeline
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_speak_retries_failed_chunk_with_the_rest() {
+        let pipeline = pipeline_with_tts(|call| call == 1);
+        let mut events = pipeline.subscribe();
+
+        pipeline
+            .speak("Your gold loan is approved today")
+            .await
+            .unwrap();
+
+        let mut spoken = Vec::new();
+        while let Ok(event) = events.try_recv() {
+            match event {
+                PipelineEvent::TtsAudio { text, .. } => spoken.push(text),
+                PipelineEvent::TtsFailed { .. } => panic!("retry should have recovered"),
+                _ => {},
+            }
+        }
+        let spoken = spoken.join(" ");
+        assert_eq!(
+            spoken.split_whitespace().collect::<Vec<_>>().join(" "),
+            "Your gold loan is approved today"
+        );
+        assert_eq!(pipeline.state(), PipelineState::Idle);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_speak_plays_fallback_once_then_reports_failure() {
+        let pipeline = pipeline_with_tts(|_| true);
+        let mut events = pipeline.subscribe();
+
+        pipeline.speak("Your gold loan is approved").await.unwrap();
+
+        let mut fallbacks = 0;
+        let mut failed = false;
+        while let Ok(event) = events.try_recv() {
+            match event {
+                PipelineEvent::TtsAudio { samples, .. } => {
+                    assert_eq!(samples.len(), 800);
+                    fallbacks += 1;
+                },
+                PipelineEvent::TtsFailed { error } => {
+                    assert!(error.contains("engine crashed"));
+                    failed = true;
+                },
+                _ => {},
+            }
+        }
+        assert_eq!(fallbacks, 1);
+        assert!(failed);
+        assert_eq!(pipeline.state(), PipelineState::Idle);
+    }
+
+    fn staged_barge_in_config() -> PipelineConfig {
+        let mut config = PipelineConfig::default();
+        config.processors.interrupt_handler.grace_period_ms = 0;
+        // Closing reads regulated terms; presentation is long explanations
+        config.barge_in.stages.insert(
+            "closing".to_string(),
+            BargeInProfile {
+                grace_period_ms: 60_000,
+                min_speech_ms: 150,
+                min_words: 0,
+            },
+        );
+        config.barge_in.stages.insert(
+            "presentation".to_string(),
+            BargeInProfile {
+                grace_period_ms: 0,
+                min_speech_ms: 40,
+                min_words: 0,
+            },
+        );
+        config
+    }
+
+    /// Frames of loud speech over the agent until barge-in, if within 50
+    async fn frames_to_barge_in(pipeline: &VoicePipeline) -> Option<usize> {
+        *pipeline.state.lock() = PipelineState::Speaking;
+        *pipeline.speaking_since.lock() = Some(Instant::now());
+        let loud = create_test_frame(vec![0.5; 320]);
+        for frame in 1..=50 {
+            if pipeline.check_barge_in(&loud, VadState::Speech).await.unwrap() {
+                return Some(frame);
+            }
+        }
+        None
+    }
+
+    #[tokio::test]
+    async fn test_disclosure_stage_defers_barge_in_info_stage_honors() {
+        let pipeline = VoicePipeline::simple(staged_barge_in_config()).unwrap();
+
+        pipeline.set_barge_in_stage("closing");
+        assert_eq!(frames_to_barge_in(&pipeline).await, None);
+        assert_eq!(pipeline.state(), PipelineState::Speaking);
+
+        pipeline.set_barge_in_stage("presentation");
+        let responsive = frames_to_barge_in(&pipeline).await;
+        assert!(responsive.is_some());
+        assert_eq!(pipeline.state(), PipelineState::Listening);
+
+        // A stage without a profile uses the default sensitivity
+        pipeline.set_barge_in_stage("discovery");
+        assert_eq!(pipeline.barge_in_profile().min_speech_ms, 150);
+        let default = frames_to_barge_in(&pipeline).await;
+        assert!(default.is_some());
+        assert!(responsive < default);
+    }
+
+    #[tokio::test]
+    async fn test_stage_grace_period_applies_to_interrupt_handler() {
+        let pipeline = VoicePipeline::simple(staged_barge_in_config()).unwrap();
+        let handler = pipeline.interrupt_handler.clone().unwrap();
+
+        pipeline.set_barge_in_stage("closing");
+        assert_eq!(handler.grace_period_ms(), 60_000);
+
+        pipeline.set_barge_in_stage("discovery");
+        assert_eq!(handler.grace_period_ms(), 0);
+    }
+
+    #[test]
+    fn test_barge_in_settings_fill_stage_profiles() {
+        let settings: voice_agent_config::pipeline::BargeInConfig =
+            serde_json::from_value(serde_json::json!({
+                "grace_period_ms": 300,
+                "min_speech_ms": 250,
+                "min_words": 2,
+                "stages": {
+                    "closing": { "grace_period_ms": 5000 },
+                    "presentation": { "min_words": 0 },
+                },
+            }))
+            .unwrap();
+        let mut config = PipelineConfig::default();
+        config.apply_barge_in_settings(&settings);
+
+        assert_eq!(config.processors.interrupt_handler.grace_period_ms, 300);
+        assert_eq!(
+            config.barge_in_profile(Some("closing")),
+            BargeInProfile {
+                grace_period_ms: 5000,
+                min_speech_ms: 250,
+                min_words: 2,
+            }
+        );
+        assert_eq!(
+            config.barge_in_profile(Some("presentation")),
+            BargeInProfile {
+                grace_period_ms: 300,
+                min_speech_ms: 250,
+                min_words: 0,
+            }
+        );
+        assert_eq!(config.barge_in_profile(None).grace_period_ms, 300);
+    }
+
+    /// STT stub hearing one more word with every chunk
+    #[derive(Default)]
+    struct WordPerChunkStt {
+        partial: Option<TranscriptResult>,
+    }
+
+    #[async_trait::async_trait]
+    impl SttBackend for WordPerChunkStt {
+        async fn process_chunk(
+            &mut self,
+            audio: &[f32],
+        ) -> Result<Option<TranscriptResult>, PipelineError> {
+            self.process(audio)
+        }
+
+        async fn finalize(&mut self) -> Result<TranscriptResult, PipelineError> {
+            Ok(self.partial.take().unwrap_or_default())
+        }
+
+        fn reset(&mut self) {
+            self.partial = None;
+        }
+
+        fn partial(&self) -> Option<&TranscriptResult> {
+            self.partial.as_ref()
+        }
+
+        fn process(&mut self, _audio: &[f32]) -> Result<Option<TranscriptResult>, PipelineError> {
+            let text = match self.partial.take() {
+                Some(partial) => format!("{} haan", partial.text),
+                None => "haan".to_string(),
+            };
+            self.partial = Some(TranscriptResult::new(text, false, 0.9));
+            Ok(self.partial.clone())
+        }
+    }
+
+    fn min_words_config() -> PipelineConfig {
+        let mut config = PipelineConfig::default();
+        config.processors.interrupt_handler.grace_period_ms = 0;
+        config.barge_in.min_speech_ms = 0;
+        config.barge_in.min_words = 3;
+        config
+    }
+
+    #[tokio::test]
+    async fn test_barge_in_waits_for_min_words() {
+        let stt = Arc::new(Mutex::new(WordPerChunkStt::default()));
+        let pipeline = VoicePipeline::simple(min_words_config())
+            .unwrap()
+            .with_stt(stt.clone());
+
+        assert_eq!(frames_to_barge_in(&pipeline).await, Some(3));
+        // The words that barged in start the caller's turn
+        assert_eq!(
+            stt.lock().partial().map(|p| p.text.clone()).as_deref(),
+            Some("haan haan haan")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_barge_in_word_count_resets_only_after_sustained_silence() {
+        let stt = Arc::new(Mutex::new(WordPerChunkStt::default()));
+        let pipeline = VoicePipeline::simple(min_words_config())
+            .unwrap()
+            .with_stt(stt.clone());
+        *pipeline.state.lock() = PipelineState::Speaking;
+
+        /// Whether `frames` frames in `vad_state` barge in
+        async fn hear(pipeline: &VoicePipeline, vad_state: VadState, frames: u32) -> bool {
+            let level = if vad_state == VadState::Speech {
+                0.5
+            } else {
+                0.0
+            };
+            let frame = create_test_frame(vec![level; 320]);
+            let mut barged_in = false;
+            for _ in 0..frames {
+                barged_in = pipeline.check_barge_in(&frame, vad_state).await.unwrap();
+            }
+            barged_in
+        }
+
+        // A short pause between words keeps the words heard so far
+        assert!(!hear(&pipeline, VadState::Speech, 2).await);
+        assert!(!hear(&pipeline, VadState::Silence, 5).await);
+        assert!(hear(&pipeline, VadState::Speech, 1).await);
+
+        // Silence long enough to end speech starts the count again
+        *pipeline.state.lock() = PipelineState::Speaking;
+        stt.lock().reset();
+        assert!(!hear(&pipeline, VadState::Speech, 2).await);
+        let reset_frames = pipeline.barge_in_silence_reset_ms() / pipeline.config.vad.frame_ms;
+        assert!(!hear(&pipeline, VadState::Silence, reset_frames).await);
+        assert!(!hear(&pipeline, VadState::Speech, 1).await);
+        assert_eq!(
+            stt.lock().partial().map(|p| p.text.clone()).as_deref(),
+            Some("haan")
+        );
+    }
+
+    /// STT stub returning one scripted utterance per turn that heard audio
+    struct ScriptedStt {
+        utterances: std::collections::VecDeque<&'static str>,
+        heard_samples: usize,
+    }
+
+    #[async_trait::async_trait]
+    impl SttBackend for ScriptedStt {
+        async fn process_chunk(
+            &mut self,
+            audio: &[f32],
+        ) -> Result<Option<TranscriptResult>, PipelineError> {
+            self.process(audio)
+        }
+
+        async fn finalize(&mut self) -> Result<TranscriptResult, PipelineError> {
+            Ok(self.finalize_sync())
+        }
+
+        fn reset(&mut self) {
+            self.heard_samples = 0;
+        }
+
+        fn partial(&self) -> Option<&TranscriptResult> {
+            None
+        }
+
+        fn process(&mut self, audio: &[f32]) -> Result<Option<TranscriptResult>, PipelineError> {
+            self.heard_samples += audio.len();
+            Ok(None)
+        }
+
+        fn finalize_sync(&mut self) -> TranscriptResult {
+            let text = if self.heard_samples > 0 {
+                self.utterances.pop_front().unwrap_or_default()
+            } else {
+                ""
+            };
+            self.heard_samples = 0;
+            TranscriptResult::new(text.to_string(), true, 0.9)
+        }
+    }
+
+    /// STT stub recording every sample it receives
+    struct RecordingStt(Arc<Mutex<Vec<f32>>>);
+
+    #[async_trait::async_trait]
+    impl SttBackend for RecordingStt {
+        async fn process_chunk(
+            &mut self,
+            audio: &[f32],
+        ) -> Result<Option<TranscriptResult>, PipelineError> {
+            self.process(audio)
+        }
+
+        async fn finalize(&mut self) -> Result<TranscriptResult, PipelineError> {
+            Ok(self.finalize_sync())
+        }
+
+        fn reset(&mut self) {
+            self.0.lock().clear();
+        }
+
+        fn partial(&self) -> Option<&TranscriptResult> {
+            None
+        }
+
+        fn process(&mut self, audio: &[f32]) -> Result<Option<TranscriptResult>, PipelineError> {
+            self.0.lock().extend_from_slice(audio);
+            Ok(None)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_pre_roll_includes_audio_before_onset() {
+        let mut config = PipelineConfig::default();
+        config.vad.pre_roll_ms = 50;
+        let heard = Arc::new(Mutex::new(Vec::new()));
+        let pipeline = VoicePipeline::simple(config)
+            .unwrap()
+            .with_stt(Arc::new(Mutex::new(RecordingStt(heard.clone()))));
+
+        // 100ms of faint audio below the VAD floor, then speech
+        for i in 0..10 {
+            let faint = vec![0.001 + i as f32 * 0.0001; 160];
+            pipeline.process_audio(create_test_frame(faint)).await.unwrap();
+        }
+        assert_eq!(pipeline.state(), PipelineState::Idle);
+        pipeline
+            .process_audio(create_test_frame(vec![0.5; 160]))
+            .await
+            .unwrap();
+        assert_eq!(pipeline.state(), PipelineState::Listening);
+
+        // The last 50ms before onset (frames 5..10) precede the onset frame
+        let heard = heard.lock().clone();
+        assert_eq!(heard.len(), 6 * 160);
+        assert_eq!(heard[0], 0.001 + 5.0 * 0.0001);
+        assert_eq!(heard[heard.len() - 1], 0.5);
+    }
+
+    #[tokio::test]
+    async fn test_process_file_transcribes_each_turn() {
+        let stt = ScriptedStt {
+            utterances: ["mujhe gold loan chahiye", "interest rate kya hai"].into(),
+            heard_samples: 0,
+        };
+        let pipeline = VoicePipeline::simple(PipelineConfig::default())
+            .unwrap()
+            .with_stt(Arc::new(Mutex::new(stt)));
+
+        // 8kHz fixture: two 600ms utterances, each followed by 1s of silence
+        let path = concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/fixtures/two_utterances_8k.wav"
+        );
+        let transcript = pipeline.process_file(path).await.unwrap();
+
+        assert_eq!(transcript.source_sample_rate, 8000);
+        assert_eq!(transcript.duration_ms, 3500);
+        assert_eq!(
+            transcript.words(),
+            vec!["mujhe", "gold", "loan", "chahiye", "interest", "rate", "kya", "hai"]
+        );
+
+        let [first, second] = transcript.segments.as_slice() else {
+            panic!("expected two turns, got {:?}", transcript.segments);
+        };
+        // Speech starts at 300ms and 1900ms; turns end after the silence threshold
+        assert!((250..=400).contains(&first.start_ms), "{:?}", first);
+        assert!(first.end_ms > 900 && first.end_ms < 1900, "{:?}", first);
+        assert!((1850..=2000).contains(&second.start_ms), "{:?}", second);
+        assert!(second.end_ms > 2500, "{:?}", second);
+        assert!(first.response.is_none());
+        assert_eq!(pipeline.state(), PipelineState::Idle);
+    }
+
+    /// STT stub whose early decode yields a fixed utterance after 200ms of audio
+    struct EarlyDecodeStt {
+        utterance: &'static str,
+        heard_samples: usize,
+    }
+
+    #[async_trait::async_trait]
+    impl SttBackend for EarlyDecodeStt {
+        async fn process_chunk(
+            &mut self,
+            audio: &[f32],
+        ) -> Result<Option<TranscriptResult>, PipelineError> {
+            self.process(audio)
+        }
+
+        async fn finalize(&mut self) -> Result<TranscriptResult, PipelineError> {
+            Ok(self.finalize_sync())
+        }
+
+        fn reset(&mut self) {
+            self.heard_samples = 0;
+        }
+
+        fn partial(&self) -> Option<&TranscriptResult> {
+            None
+        }
+
+        fn process(&mut self, audio: &[f32]) -> Result<Option<TranscriptResult>, PipelineError> {
+            self.heard_samples += audio.len();
+            Ok((self.heard_samples >= 3200)
+                .then(|| TranscriptResult::new(self.utterance.to_string(), false, 0.8)))
+        }
+
+        fn finalize_sync(&mut self) -> TranscriptResult {
+            self.heard_samples = 0;
+            TranscriptResult::new(self.utterance.to_string(), true, 0.9)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_language_detected_from_early_decode() {
+        let stt = EarlyDecodeStt {
+            utterance: "எனக்கு தங்க கடன் வேண்டும்",
+            heard_samples: 0,
+        };
+        let pipeline = VoicePipeline::simple(PipelineConfig::default())
+            .unwrap()
+            .with_stt(Arc::new(Mutex::new(stt)));
+        let mut events = pipeline.subscribe();
+
+        let path = concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/fixtures/two_utterances_8k.wav"
+        );
+        pipeline.process_file(path).await.unwrap();
+
+        let mut detections = Vec::new();
+        let mut finals = 0;
+        loop {
+            match events.try_recv() {
+                Ok(PipelineEvent::LanguageDetected(detection)) => detections.push(detection),
+                Ok(PipelineEvent::FinalTranscript(_)) => {
+                    finals += 1;
+                    assert_eq!(
+                        detections.len(),
+                        finals,
+                        "detection should precede the transcript"
+                    );
+                },
+                Ok(_) | Err(broadcast::error::TryRecvError::Lagged(_)) => {},
+                Err(_) => break,
+            }
+        }
+
+        // Once per utterance
+        assert_eq!(detections.len(), 2, "{:?}", detections);
+        for detection in detections {
+            assert_eq!(detection.language, Language::Tamil);
+            assert!(detection.confidence >= 0.6 && detection.confidence <= 1.0);
+            assert!(detection.evidence_chars >= 8);
+        }
+    }
 }
@@ -4,6 +4,13 @@
 //! - Immediate: Stop TTS immediately
 //! - SentenceBoundary: Finish current sentence before stopping
 //! - WordBoundary: Finish current word before stopping
+//!
+//! Utterances marked with `ControlFrame::ProtectUtterance` (compliance
+//! disclosures, required questions) are not cut off: a barge-in during one is
+//! held until the utterance ends or `protected_max_ms` passes.
+//!
+//! Every interruption records an `InterruptionReason`, so a user barge-in
+//! can be told apart from the system cutting playback short.
 
 use async_trait::async_trait;
 use parking_lot::Mutex;
@@ -25,6 +32,40 @@ pub enum InterruptMode {
     Disabled,
 }
 
+/// Why TTS playback was cut short
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum InterruptionReason {
+    /// The user spoke over the agent
+    UserBargeIn,
+    /// The server stopped playback to drain the session
+    ServerDrain,
+    /// Synthesis or playback failed
+    Error,
+    /// The system ended the agent's turn early
+    ForcedTurnEnd,
+}
+
+impl InterruptionReason {
+    /// Reason name for logs and metrics
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            InterruptionReason::UserBargeIn => "user_barge_in",
+            InterruptionReason::ServerDrain => "server_drain",
+            InterruptionReason::Error => "error",
+            InterruptionReason::ForcedTurnEnd => "forced_turn_end",
+        }
+    }
+
+    /// Whether the user cut the response off
+    ///
+    /// Only these should mark the response as interrupted in the agent's
+    /// memory; the user did hear the rest of it otherwise.
+    pub fn is_user_initiated(&self) -> bool {
+        matches!(self, InterruptionReason::UserBargeIn)
+    }
+}
+
 /// Interrupt handler configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct InterruptHandlerConfig {
@@ -38,6 +79,8 @@ pub struct InterruptHandlerConfig {
     pub grace_period_ms: u32,
     /// Fade out duration (ms) for smooth audio transition
     pub fade_out_ms: u32,
+    /// Longest a protected utterance can hold off a barge-in (ms)
+    pub protected_max_ms: u32,
 }
 
 impl Default for InterruptHandlerConfig {
@@ -48,6 +91,7 @@ impl Default for InterruptHandlerConfig {
             min_energy_db: -40.0,
             grace_period_ms: 200,
             fade_out_ms: 50,
+            protected_max_ms: 8000,
         }
     }
 }
@@ -80,12 +124,21 @@ pub struct InterruptHandler {
     tts_start_frame: Mutex<u64>,
     /// Current frame counter
     frame_counter: Mutex<u64>,
+    /// Frame index at which the current protected utterance started
+    protected_since: Mutex<Option<u64>>,
+    /// Barge-in held back by a protected utterance (audio position ms)
+    deferred_barge_in: Mutex<Option<u64>>,
+    /// Why the current utterance was interrupted
+    reason: Mutex<Option<InterruptionReason>>,
+    /// Grace period in effect, `config.grace_period_ms` unless changed
+    grace_period_ms: Mutex<u32>,
 }
 
 impl InterruptHandler {
     /// Create a new interrupt handler
     pub fn new(config: InterruptHandlerConfig) -> Self {
         Self {
+            grace_period_ms: Mutex::new(config.grace_period_ms),
             config,
             state: Mutex::new(HandlerState::Idle),
             current_sentence: Mutex::new(0),
@@ -93,6 +146,9 @@ impl InterruptHandler {
             speech_duration_ms: Mutex::new(0),
             tts_start_frame: Mutex::new(0),
             frame_counter: Mutex::new(0),
+            protected_since: Mutex::new(None),
+            deferred_barge_in: Mutex::new(None),
+            reason: Mutex::new(None),
         }
     }
 
@@ -116,7 +172,7 @@ impl InterruptHandler {
 
         // Approximate: 20ms per frame (50 fps)
         let elapsed_ms = elapsed_frames * 20;
-        if elapsed_ms < self.config.grace_period_ms as u64 {
+        if elapsed_ms < *self.grace_period_ms.lock() as u64 {
             // Still in grace period, ignore
             return vec![];
         }
@@ -126,6 +182,7 @@ impl InterruptHandler {
 
             InterruptMode::Immediate => {
                 *self.state.lock() = HandlerState::Interrupted;
+                *self.reason.lock() = Some(InterruptionReason::UserBargeIn);
                 vec![Frame::BargeIn {
                     audio_position_ms,
                     transcript: None,
@@ -134,6 +191,7 @@ impl InterruptHandler {
 
             InterruptMode::SentenceBoundary => {
                 *self.state.lock() = HandlerState::PendingInterrupt;
+                *self.reason.lock() = Some(InterruptionReason::UserBargeIn);
                 let current = *self.current_sentence.lock();
                 *self.target_sentence.lock() = Some(current);
                 // Don't emit barge-in yet, wait for sentence end
@@ -143,6 +201,7 @@ impl InterruptHandler {
             InterruptMode::WordBoundary => {
                 // For word boundary, we set pending and let TTS finish current word
                 *self.state.lock() = HandlerState::PendingInterrupt;
+                *self.reason.lock() = Some(InterruptionReason::UserBargeIn);
                 // TTS layer will handle word boundary
                 vec![Frame::BargeIn {
                     audio_position_ms,
@@ -152,6 +211,79 @@ impl InterruptHandler {
         }
     }
 
+    /// Cut playback short for a reason other than user speech
+    ///
+    /// Unlike a barge-in this applies at once, ignoring the grace period,
+    /// the interrupt mode and protected utterances. Returns false when
+    /// nothing was playing.
+    pub fn interrupt(&self, reason: InterruptionReason) -> bool {
+        let mut state = self.state.lock();
+        if !matches!(
+            *state,
+            HandlerState::Speaking | HandlerState::PendingInterrupt
+        ) {
+            return false;
+        }
+        *state = HandlerState::Interrupted;
+        *self.reason.lock() = Some(reason);
+        *self.deferred_barge_in.lock() = None;
+        tracing::debug!(reason = reason.as_str(), "Playback interrupted");
+        true
+    }
+
+    /// Why the current utterance was interrupted, if it was
+    pub fn interruption_reason(&self) -> Option<InterruptionReason> {
+        *self.reason.lock()
+    }
+
+    /// Hold a barge-in back if a protected utterance is playing
+    ///
+    /// Returns true if the barge-in was deferred.
+    fn defer_if_protected(&self, audio_position_ms: u64) -> bool {
+        if self.protected_since.lock().is_none() {
+            return false;
+        }
+        tracing::debug!(
+            audio_position_ms,
+            "Deferring barge-in until protected utterance ends"
+        );
+        *self.deferred_barge_in.lock() = Some(audio_position_ms);
+        true
+    }
+
+    /// Start a protected utterance
+    fn protect(&self) {
+        *self.protected_since.lock() = Some(*self.frame_counter.lock());
+    }
+
+    /// End the protected utterance, applying any barge-in it held back
+    fn unprotect(&self) -> Vec<Frame> {
+        *self.protected_since.lock() = None;
+        match self.deferred_barge_in.lock().take() {
+            Some(audio_position_ms) => self.handle_barge_in(audio_position_ms),
+            None => vec![],
+        }
+    }
+
+    /// End protection once it has run past `protected_max_ms`
+    fn release_if_capped(&self) -> Vec<Frame> {
+        let Some(since) = *self.protected_since.lock() else {
+            return vec![];
+        };
+
+        // Approximate: 20ms per frame (50 fps)
+        let elapsed_ms = self.frame_counter.lock().saturating_sub(since) * 20;
+        if elapsed_ms < self.config.protected_max_ms as u64 {
+            return vec![];
+        }
+
+        tracing::warn!(
+            elapsed_ms,
+            "Protected utterance exceeded its cap, allowing barge-in"
+        );
+        self.unprotect()
+    }
+
     /// Check if we should emit frames or block them
     fn should_pass(&self, frame: &Frame) -> bool {
         let state = *self.state.lock();
@@ -216,6 +348,26 @@ impl InterruptHandler {
         *self.current_sentence.lock() = 0;
         *self.target_sentence.lock() = None;
         *self.speech_duration_ms.lock() = 0;
+        *self.protected_since.lock() = None;
+        *self.deferred_barge_in.lock() = None;
+        *self.reason.lock() = None;
+    }
+
+    /// Whether a protected utterance is playing
+    pub fn is_protected(&self) -> bool {
+        self.protected_since.lock().is_some()
+    }
+
+    /// Change the grace period, e.g. as the conversation moves between stages
+    ///
+    /// Applies from the next barge-in, including during the current utterance.
+    pub fn set_grace_period_ms(&self, grace_period_ms: u32) {
+        *self.grace_period_ms.lock() = grace_period_ms;
+    }
+
+    /// Grace period in effect (ms)
+    pub fn grace_period_ms(&self) -> u32 {
+        *self.grace_period_ms.lock()
     }
 
     /// Get current mode
@@ -243,21 +395,29 @@ impl FrameProcessor for InterruptHandler {
         // Increment frame counter
         *self.frame_counter.lock() += 1;
 
+        // Barge-in released by a protected utterance running past its cap
+        let mut released = self.release_if_capped();
+
         match &frame {
             // Handle barge-in event
             Frame::BargeIn {
                 audio_position_ms, ..
             } => {
+                if self.config.mode != InterruptMode::Disabled
+                    && self.defer_if_protected(*audio_position_ms)
+                {
+                    return Ok(released);
+                }
+
                 let additional = self.handle_barge_in(*audio_position_ms);
                 if additional.is_empty() && self.config.mode == InterruptMode::Disabled {
                     // Pass through the original barge-in if disabled
-                    return Ok(vec![frame]);
-                }
-                if additional.is_empty() {
-                    // Consumed for pending interrupt
-                    return Ok(vec![]);
+                    released.push(frame);
+                    return Ok(released);
                 }
-                return Ok(additional);
+                // Empty when consumed for pending interrupt
+                released.extend(additional);
+                return Ok(released);
             },
 
             // Track sentence progress
@@ -284,6 +444,11 @@ impl FrameProcessor for InterruptHandler {
                 self.process_voice_activity(false, -60.0);
             },
 
+            // A failure mid-utterance cuts playback short
+            Frame::Error { .. } => {
+                self.interrupt(InterruptionReason::Error);
+            },
+
             // Reset on end of stream or control
             Frame::EndOfStream => {
                 self.reset();
@@ -293,15 +458,22 @@ impl FrameProcessor for InterruptHandler {
                 self.reset();
             },
 
+            Frame::Control(voice_agent_core::ControlFrame::ProtectUtterance(true)) => {
+                self.protect();
+            },
+
+            Frame::Control(voice_agent_core::ControlFrame::ProtectUtterance(false)) => {
+                released.extend(self.unprotect());
+            },
+
             _ => {},
         }
 
         // Filter based on current state
         if self.should_pass(&frame) {
-            Ok(vec![frame])
-        } else {
-            Ok(vec![])
+            released.push(frame);
         }
+        Ok(released)
     }
 
     fn name(&self) -> &'static str {
@@ -543,4 +715,141 @@ mod tests {
 
         assert!(frames.is_empty());
     }
+
+    fn barge_in() -> Frame {
+        Frame::BargeIn {
+            audio_position_ms: 1000,
+            transcript: None,
+        }
+    }
+
+    fn protect(on: bool) -> Frame {
+        Frame::Control(voice_agent_core::ControlFrame::ProtectUtterance(on))
+    }
+
+    fn audio() -> Frame {
+        Frame::AudioOutput(voice_agent_core::AudioFrame::new(
+            vec![0.0; 160],
+            voice_agent_core::SampleRate::Hz16000,
+            voice_agent_core::Channels::Mono,
+            0,
+        ))
+    }
+
+    #[tokio::test]
+    async fn test_barge_in_deferred_during_protected_utterance() {
+        let handler = InterruptHandler::new(InterruptHandlerConfig {
+            mode: InterruptMode::Immediate,
+            grace_period_ms: 0,
+            ..Default::default()
+        });
+
+        let mut ctx = ProcessorContext::default();
+
+        handler.process(protect(true), &mut ctx).await.unwrap();
+        handler.process(audio(), &mut ctx).await.unwrap();
+        assert!(handler.is_protected());
+
+        // Barge-in is held back and the disclosure keeps playing
+        let frames = handler.process(barge_in(), &mut ctx).await.unwrap();
+        assert!(frames.is_empty());
+        assert!(!handler.is_interrupted());
+
+        let frames = handler.process(audio(), &mut ctx).await.unwrap();
+        assert!(matches!(frames.as_slice(), [Frame::AudioOutput(_)]));
+
+        // Once the utterance ends the deferred barge-in takes effect
+        let frames = handler.process(protect(false), &mut ctx).await.unwrap();
+        assert!(frames.iter().any(|f| matches!(f, Frame::BargeIn { .. })));
+        assert!(handler.is_interrupted());
+        assert!(!handler.is_protected());
+    }
+
+    #[tokio::test]
+    async fn test_barge_in_honored_during_normal_utterance() {
+        let handler = InterruptHandler::new(InterruptHandlerConfig {
+            mode: InterruptMode::Immediate,
+            grace_period_ms: 0,
+            ..Default::default()
+        });
+
+        let mut ctx = ProcessorContext::default();
+
+        // A protected utterance that was not interrupted leaves nothing behind
+        handler.process(protect(true), &mut ctx).await.unwrap();
+        let frames = handler.process(protect(false), &mut ctx).await.unwrap();
+        assert_eq!(frames.len(), 1);
+
+        handler.process(audio(), &mut ctx).await.unwrap();
+        let frames = handler.process(barge_in(), &mut ctx).await.unwrap();
+        assert!(frames.iter().any(|f| matches!(f, Frame::BargeIn { .. })));
+        assert!(handler.is_interrupted());
+    }
+
+    #[tokio::test]
+    async fn test_protection_capped() {
+        let handler = InterruptHandler::new(InterruptHandlerConfig {
+            mode: InterruptMode::Immediate,
+            grace_period_ms: 0,
+            protected_max_ms: 100,
+            ..Default::default()
+        });
+
+        let mut ctx = ProcessorContext::default();
+
+        handler.process(protect(true), &mut ctx).await.unwrap();
+        handler.process(audio(), &mut ctx).await.unwrap();
+        let frames = handler.process(barge_in(), &mut ctx).await.unwrap();
+        assert!(frames.is_empty());
+
+        // The utterance never ends; the cap releases the barge-in
+        let mut released = false;
+        for _ in 0..10 {
+            let frames = handler.process(audio(), &mut ctx).await.unwrap();
+            if frames.iter().any(|f| matches!(f, Frame::BargeIn { .. })) {
+                released = true;
+                break;
+            }
+        }
+        assert!(released);
+        assert!(handler.is_interrupted());
+        assert!(!handler.is_protected());
+    }
+
+    #[tokio::test]
+    async fn test_interruption_reasons_distinguish_user_and_system() {
+        let mut ctx = ProcessorContext::default();
+        let config = InterruptHandlerConfig {
+            mode: InterruptMode::Immediate,
+            grace_period_ms: 0,
+            ..Default::default()
+        };
+
+        // The user talks over the agent
+        let handler = InterruptHandler::new(config.clone());
+        handler.process(audio(), &mut ctx).await.unwrap();
+        handler.process(barge_in(), &mut ctx).await.unwrap();
+        assert_eq!(
+            handler.interruption_reason(),
+            Some(InterruptionReason::UserBargeIn)
+        );
+        assert!(handler.interruption_reason().unwrap().is_user_initiated());
+
+        // The system ends the turn, even mid protected utterance
+        let handler = InterruptHandler::new(config);
+        handler.process(protect(true), &mut ctx).await.unwrap();
+        handler.process(audio(), &mut ctx).await.unwrap();
+        assert!(handler.interrupt(InterruptionReason::ForcedTurnEnd));
+        assert!(handler.is_interrupted());
+        assert_eq!(
+            handler.interruption_reason(),
+            Some(InterruptionReason::ForcedTurnEnd)
+        );
+        assert!(!handler.interruption_reason().unwrap().is_user_initiated());
+
+        // Nothing playing, nothing to interrupt
+        handler.reset();
+        assert!(!handler.interrupt(InterruptionReason::ServerDrain));
+        assert_eq!(handler.interruption_reason(), None);
+    }
 }
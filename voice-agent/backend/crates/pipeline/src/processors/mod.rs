@@ -14,6 +14,8 @@ mod tts_processor;
 pub use chain::{ProcessorChain, ProcessorChainBuilder};
 // P2-2 FIX: Export generic processors for external use
 pub use chain::{FilterProcessor, MapProcessor, PassthroughProcessor};
-pub use interrupt_handler::{InterruptHandler, InterruptHandlerConfig, InterruptMode};
+pub use interrupt_handler::{
+    InterruptHandler, InterruptHandlerConfig, InterruptMode, InterruptionReason,
+};
 pub use sentence_detector::{SentenceDetector, SentenceDetectorConfig};
 pub use tts_processor::{TtsProcessor, TtsProcessorConfig};
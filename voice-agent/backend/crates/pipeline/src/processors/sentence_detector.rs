@@ -3,10 +3,23 @@
 //! Buffers LLM chunks and emits complete sentences for TTS.
 //! Supports Indic script terminators (।, ॥, etc.) in addition to
 //! standard punctuation.
+//!
+//! A period does not end a sentence when it closes a known abbreviation
+//! ("Rs. 5 lakh", "Dr. Sharma", "डॉ. शर्मा") or sits between digits ("7.5%"),
+//! so TTS never cuts a chunk mid-phrase. The danda is always a terminator,
+//! which keeps mixed Hindi/English replies segmented whatever the session
+//! language is.
+
+use std::collections::HashMap;
 
 use async_trait::async_trait;
 use parking_lot::Mutex;
-use voice_agent_core::{Frame, FrameProcessor, Language, ProcessorContext, Result};
+use voice_agent_core::{
+    Frame, FrameProcessor, Language, ProcessorContext, Result, DEFAULT_ABBREVIATIONS,
+};
+
+/// Devanagari sentence terminators, accepted in every language
+const DANDA_TERMINATORS: &[char] = &['।', '॥'];
 
 /// Sentence detector configuration
 #[derive(Debug, Clone)]
@@ -19,6 +32,29 @@ pub struct SentenceDetectorConfig {
     pub emit_partial_on_flush: bool,
     /// Detect language from context for script-aware detection
     pub use_context_language: bool,
+    /// Terminator sets by language, overriding `Language::sentence_terminators`
+    pub terminators: HashMap<Language, Vec<char>>,
+    /// Words that don't end a sentence when followed by a period
+    pub abbreviations: Vec<String>,
+}
+
+impl SentenceDetectorConfig {
+    /// Use a custom terminator set for a language
+    pub fn with_terminators(mut self, language: Language, terminators: Vec<char>) -> Self {
+        self.terminators.insert(language, terminators);
+        self
+    }
+
+    /// Add abbreviations, e.g. from the domain vocabulary
+    pub fn with_abbreviations<I, S>(mut self, abbreviations: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.abbreviations
+            .extend(abbreviations.into_iter().map(|a| a.into().trim_end_matches('.').to_string()));
+        self
+    }
 }
 
 impl Default for SentenceDetectorConfig {
@@ -28,6 +64,8 @@ impl Default for SentenceDetectorConfig {
             max_buffer_chars: 500,
             emit_partial_on_flush: true,
             use_context_language: true,
+            terminators: HashMap::new(),
+            abbreviations: DEFAULT_ABBREVIATIONS.iter().map(|a| a.to_string()).collect(),
         }
     }
 }
@@ -68,13 +106,53 @@ impl SentenceDetector {
     }
 
     /// Get sentence terminators for current language
-    fn terminators(&self) -> &'static [char] {
-        self.language.lock().sentence_terminators()
+    fn terminators(&self) -> Vec<char> {
+        let language = *self.language.lock();
+        if let Some(custom) = self.config.terminators.get(&language) {
+            return custom.clone();
+        }
+
+        let mut terminators = language.sentence_terminators().to_vec();
+        for &danda in DANDA_TERMINATORS {
+            if !terminators.contains(&danda) {
+                terminators.push(danda);
+            }
+        }
+        terminators
     }
 
-    /// Check if character is a sentence terminator
-    fn is_terminator(&self, c: char) -> bool {
-        self.terminators().contains(&c)
+    /// Whether the period at `i` belongs to an abbreviation or a number
+    fn is_non_terminal_period(&self, chars: &[char], i: usize) -> bool {
+        if chars[i] != '.' {
+            return false;
+        }
+
+        // Decimal point: "7.5", or "7." at the end of the buffer while the
+        // fraction may still be streaming in
+        let after_digit = i > 0 && chars[i - 1].is_ascii_digit();
+        let before_digit = match chars.get(i + 1) {
+            Some(c) => c.is_ascii_digit(),
+            None => true,
+        };
+        if after_digit && before_digit {
+            return true;
+        }
+
+        // Word before the period, back to whitespace or an opening bracket/quote
+        let start = chars[..i]
+            .iter()
+            .rposition(|c| c.is_whitespace() || matches!(c, '(' | '[' | '"' | '\'' | '\u{201C}'))
+            .map(|p| p + 1)
+            .unwrap_or(0);
+        let word: String = chars[start..i].iter().collect();
+        if word.is_empty() {
+            return false;
+        }
+
+        self.config
+            .abbreviations
+            .iter()
+            .any(|a| a.eq_ignore_ascii_case(&word))
     }
 
     /// Find sentence boundaries in text
@@ -92,7 +170,7 @@ impl SentenceDetector {
             current.push(c);
 
             // Check if this is a terminator
-            if terminators.contains(&c) {
+            if terminators.contains(&c) && !self.is_non_terminal_period(&chars, i) {
                 // Look ahead for closing quotes or brackets
                 let mut end = i + 1;
                 while end < chars.len() {
@@ -483,4 +561,90 @@ mod tests {
 
         assert_eq!(indices, vec![0, 1, 2]);
     }
+
+    fn sentences(frames: &[Frame]) -> Vec<&str> {
+        frames
+            .iter()
+            .filter_map(|f| match f {
+                Frame::Sentence { text, .. } => Some(text.as_str()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_abbreviation_does_not_end_sentence() {
+        let detector = create_detector();
+        let mut ctx = ProcessorContext::default();
+
+        let frames = detector
+            .process(
+                Frame::LLMChunk {
+                    text: "You can get up to Rs. 5 lakh at 7.5% interest. Dr. Sharma will call you."
+                        .to_string(),
+                    is_final: true,
+                },
+                &mut ctx,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(
+            sentences(&frames),
+            vec![
+                "You can get up to Rs. 5 lakh at 7.5% interest.",
+                "Dr. Sharma will call you."
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_mixed_hindi_english_with_danda() {
+        let detector = create_detector();
+        detector.set_language(Language::Hindi);
+        let mut ctx = ProcessorContext::default();
+
+        let frames = detector
+            .process(
+                Frame::LLMChunk {
+                    text: "आपको रु. 5 लाख तक का loan मिल सकता है। Shall I book a visit?"
+                        .to_string(),
+                    is_final: true,
+                },
+                &mut ctx,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(
+            sentences(&frames),
+            vec!["आपको रु. 5 लाख तक का loan मिल सकता है।", "Shall I book a visit?"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_custom_terminators_and_abbreviations() {
+        let detector = SentenceDetector::new(
+            SentenceDetectorConfig::default()
+                .with_terminators(Language::English, vec!['.', '?'])
+                .with_abbreviations(["KYC."]),
+        );
+        let mut ctx = ProcessorContext::default();
+
+        let frames = detector
+            .process(
+                Frame::LLMChunk {
+                    text: "Bring your KYC. documents. Ready?".to_string(),
+                    is_final: false,
+                },
+                &mut ctx,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(
+            sentences(&frames),
+            vec!["Bring your KYC. documents.", "Ready?"]
+        );
+    }
 }
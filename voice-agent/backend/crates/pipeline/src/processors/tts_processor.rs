@@ -1,7 +1,15 @@
 //! TTS Processor
 //!
 //! Bridges Frame::Sentence to Frame::AudioOutput via StreamingTts.
-//! Wires the SentenceDetector output directly to TTS synthesis.
+//! Wires the SentenceDetector output directly to TTS synthesis. Each
+//! sentence is passed on ahead of its audio, so downstream knows what the
+//! audio says (e.g. to tell what a caller who barged in had heard).
+//!
+//! A sentence the engine fails on is retried `retries` times. If it still
+//! fails, a recoverable `Frame::Error` reports it so downstream knows the
+//! response wasn't delivered; the first such sentence of a response is
+//! preceded by the configured `TtsFallback` audio, so the caller hears
+//! something.
 
 use async_trait::async_trait;
 use parking_lot::Mutex;
@@ -10,7 +18,9 @@ use tokio::sync::mpsc;
 
 use voice_agent_core::{Frame, FrameProcessor, Language, ProcessorContext, Result};
 
-use crate::tts::{StreamingTts, TtsConfig, TtsEvent};
+use crate::tts::{
+    StreamingTts, TtsConfig, TtsEvent, TtsFallback, TtsRecovery, FALLBACK_SAMPLE_RATE,
+};
 
 /// TTS processor configuration
 #[derive(Debug, Clone)]
@@ -23,6 +33,10 @@ pub struct TtsProcessorConfig {
     pub max_queue_size: usize,
     /// Sample rate for output audio
     pub sample_rate: u32,
+    /// Times a failed sentence is re-synthesized before falling back
+    pub retries: usize,
+    /// Audio played, once per response, for a sentence that still fails after the retries
+    pub fallback: TtsFallback,
 }
 
 impl Default for TtsProcessorConfig {
@@ -32,6 +46,8 @@ impl Default for TtsProcessorConfig {
             parallel_synthesis: false,
             max_queue_size: 5,
             sample_rate: 22050,
+            retries: 1,
+            fallback: TtsFallback::default(),
         }
     }
 }
@@ -46,6 +62,10 @@ pub struct TtsProcessor {
     active: Mutex<bool>,
     /// Barge-in requested
     barge_in: Mutex<bool>,
+    /// Playing an utterance that barge-in must not cut off
+    protected: Mutex<bool>,
+    /// Retries and fallback for the current response
+    recovery: Mutex<TtsRecovery>,
 }
 
 impl TtsProcessor {
@@ -58,6 +78,8 @@ impl TtsProcessor {
             current_sentence: Mutex::new(0),
             active: Mutex::new(false),
             barge_in: Mutex::new(false),
+            protected: Mutex::new(false),
+            recovery: Mutex::new(TtsRecovery::new(config.retries, config.fallback.clone())),
         }
     }
 
@@ -69,11 +91,77 @@ impl TtsProcessor {
             current_sentence: Mutex::new(0),
             active: Mutex::new(false),
             barge_in: Mutex::new(false),
+            protected: Mutex::new(false),
+            recovery: Mutex::new(TtsRecovery::new(config.retries, config.fallback.clone())),
         }
     }
 
-    /// Synthesize a sentence and return audio frames
+    /// Synthesize a sentence, retrying and then falling back on failure
     async fn synthesize_sentence(
+        &self,
+        text: &str,
+        language: Language,
+        sentence_index: usize,
+    ) -> Result<Vec<Frame>> {
+        loop {
+            match self.synthesize_once(text, language, sentence_index).await {
+                Ok(frames) => {
+                    self.recovery.lock().succeeded();
+                    return Ok(frames);
+                },
+                Err(e) => {
+                    let retry = self.recovery.lock().failed();
+                    tracing::warn!(
+                        sentence = sentence_index,
+                        attempt = self.recovery.lock().attempts(),
+                        error = %e,
+                        "TTS synthesis failed"
+                    );
+                    *self.active.lock() = false;
+                    if !retry {
+                        return Ok(self.fallback_frames(sentence_index, &e.to_string()));
+                    }
+                },
+            }
+        }
+    }
+
+    /// Fallback audio for a sentence that couldn't be synthesized, then the error
+    ///
+    /// Only the response's first failed sentence gets the fallback audio.
+    fn fallback_frames(&self, sentence_index: usize, error: &str) -> Vec<Frame> {
+        let mut recovery = self.recovery.lock();
+        let attempts = recovery.attempts();
+        let samples = recovery.fallback_samples(FALLBACK_SAMPLE_RATE);
+        tracing::error!(
+            sentence = sentence_index,
+            attempts,
+            fallback = recovery.fallback().as_str(),
+            played = !samples.is_empty(),
+            "TTS failed, playing fallback"
+        );
+        recovery.succeeded();
+        drop(recovery);
+
+        let mut frames = Vec::with_capacity(2);
+        if !samples.is_empty() {
+            frames.push(Frame::AudioOutput(voice_agent_core::AudioFrame::new(
+                samples,
+                voice_agent_core::SampleRate::Hz16000,
+                voice_agent_core::Channels::Mono,
+                0,
+            )));
+        }
+        frames.push(Frame::Error {
+            stage: self.name().to_string(),
+            message: format!("TTS failed after {} attempts: {}", attempts, error),
+            recoverable: true,
+        });
+        frames
+    }
+
+    /// Synthesize a sentence and return audio frames
+    async fn synthesize_once(
         &self,
         text: &str,
         _language: Language, // May be used for language-specific TTS voices in future
@@ -141,7 +229,7 @@ impl TtsProcessor {
                     tracing::debug!(sentence = sentence_index, "TTS synthesis complete");
                     break;
                 },
-                Ok(Some(TtsEvent::BargedIn { word_index })) => {
+                Ok(Some(TtsEvent::BargedIn { word_index, .. })) => {
                     frames.push(Frame::BargeIn {
                         audio_position_ms: word_index as u64 * 100, // Approximate word to ms
                         transcript: None,
@@ -173,7 +261,7 @@ impl TtsProcessor {
                 match event {
                     TtsEvent::Started => {},
                     TtsEvent::Complete => break,
-                    TtsEvent::BargedIn { word_index } => {
+                    TtsEvent::BargedIn { word_index, .. } => {
                         frames.push(Frame::BargeIn {
                             audio_position_ms: word_index as u64 * 100,
                             transcript: None,
@@ -215,6 +303,8 @@ impl TtsProcessor {
         *self.current_sentence.lock() = 0;
         *self.active.lock() = false;
         *self.barge_in.lock() = false;
+        *self.protected.lock() = false;
+        self.recovery.lock().reset();
         self.tts.reset();
     }
 }
@@ -235,10 +325,16 @@ impl FrameProcessor for TtsProcessor {
                     "Processing sentence for TTS"
                 );
 
-                // Synthesize the sentence
+                // Synthesize the sentence, passing it on ahead of its audio
                 let audio_frames = self.synthesize_sentence(&text, language, index).await?;
-
-                Ok(audio_frames)
+                let mut frames = vec![Frame::Sentence {
+                    text,
+                    language,
+                    index,
+                }];
+                frames.extend(audio_frames);
+
+                Ok(frames)
             },
 
             Frame::Control(voice_agent_core::ControlFrame::Reset) => {
@@ -255,8 +351,16 @@ impl FrameProcessor for TtsProcessor {
             },
 
             Frame::BargeIn { .. } => {
-                // Propagate barge-in and stop synthesis
-                self.barge_in();
+                // Propagate barge-in and stop synthesis, unless the utterance is
+                // protected; the interrupt handler defers it until it ends
+                if !*self.protected.lock() {
+                    self.barge_in();
+                }
+                Ok(vec![frame])
+            },
+
+            Frame::Control(voice_agent_core::ControlFrame::ProtectUtterance(on)) => {
+                *self.protected.lock() = on;
                 Ok(vec![frame])
             },
 
@@ -303,11 +407,65 @@ impl FrameProcessor for TtsProcessor {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::tts::TtsBackend;
+    use crate::PipelineError;
+    use std::sync::atomic::{AtomicUsize, Ordering};
 
     fn create_processor() -> TtsProcessor {
         TtsProcessor::new(TtsProcessorConfig::default())
     }
 
+    /// Backend that fails its first `failures` calls
+    struct FlakyBackend {
+        failures: usize,
+        calls: AtomicUsize,
+    }
+
+    #[async_trait]
+    impl TtsBackend for FlakyBackend {
+        async fn synthesize(&self, _text: &str) -> std::result::Result<Vec<f32>, PipelineError> {
+            if self.calls.fetch_add(1, Ordering::SeqCst) < self.failures {
+                return Err(PipelineError::Tts("engine crashed".to_string()));
+            }
+            Ok(vec![0.5; 160])
+        }
+
+        fn sample_rate(&self) -> u32 {
+            16000
+        }
+
+        fn supports_streaming(&self) -> bool {
+            false
+        }
+    }
+
+    fn flaky_processor(
+        failures: usize,
+        fallback: TtsFallback,
+    ) -> (TtsProcessor, Arc<FlakyBackend>) {
+        let backend = Arc::new(FlakyBackend {
+            failures,
+            calls: AtomicUsize::new(0),
+        });
+        let tts = Arc::new(StreamingTts::with_backend(
+            backend.clone(),
+            TtsConfig::default(),
+        ));
+        let config = TtsProcessorConfig {
+            fallback,
+            ..Default::default()
+        };
+        (TtsProcessor::with_tts(config, tts), backend)
+    }
+
+    fn sentence(text: &str) -> Frame {
+        Frame::Sentence {
+            text: text.to_string(),
+            language: Language::English,
+            index: 0,
+        }
+    }
+
     #[tokio::test]
     async fn test_processor_creation() {
         let processor = create_processor();
@@ -403,4 +561,89 @@ mod tests {
         // Should produce barge-in frame
         assert!(frames.iter().any(|f| matches!(f, Frame::BargeIn { .. })));
     }
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_tts_failure_retried() {
+        let (processor, backend) = flaky_processor(1, TtsFallback::Silence);
+        let mut ctx = ProcessorContext::default();
+
+        let frames = processor
+            .process(sentence("Hello"), &mut ctx)
+            .await
+            .unwrap();
+
+        assert_eq!(backend.calls.load(Ordering::SeqCst), 2);
+        assert!(frames.iter().any(|f| matches!(f, Frame::AudioOutput(_))));
+        assert!(!frames.iter().any(Frame::is_error));
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_tts_failure_plays_fallback_clip() {
+        let clip: Arc<[f32]> = vec![0.25; 800].into();
+        let (processor, backend) = flaky_processor(usize::MAX, TtsFallback::Clip(clip));
+        let mut ctx = ProcessorContext::default();
+
+        let frames = processor
+            .process(sentence("Hello"), &mut ctx)
+            .await
+            .unwrap();
+
+        // One retry, then the clip and a recoverable error instead of silence
+        assert_eq!(backend.calls.load(Ordering::SeqCst), 2);
+        assert_eq!(frames.len(), 3);
+        assert!(matches!(&frames[0], Frame::Sentence { text, .. } if text == "Hello"));
+        match &frames[1] {
+            Frame::AudioOutput(audio) => assert_eq!(&audio.samples[..], &[0.25; 800][..]),
+            other => panic!("expected fallback audio, got {:?}", other),
+        }
+        match &frames[2] {
+            Frame::Error {
+                stage,
+                message,
+                recoverable,
+            } => {
+                assert_eq!(stage, "tts_processor");
+                assert!(message.contains("engine crashed"));
+                assert!(*recoverable);
+            },
+            other => panic!("expected error frame, got {:?}", other),
+        }
+        assert!(!processor.is_active());
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_tts_fallback_plays_once_per_response() {
+        let clip: Arc<[f32]> = vec![0.25; 800].into();
+        let (processor, _backend) = flaky_processor(usize::MAX, TtsFallback::Clip(clip));
+        let mut ctx = ProcessorContext::default();
+        let fallbacks = |frames: &[Frame]| {
+            frames
+                .iter()
+                .filter(|f| matches!(f, Frame::AudioOutput(_)))
+                .count()
+        };
+
+        let first = processor
+            .process(sentence("Hello"), &mut ctx)
+            .await
+            .unwrap();
+        let second = processor
+            .process(sentence("Again"), &mut ctx)
+            .await
+            .unwrap();
+        assert_eq!(fallbacks(&first), 1);
+        // Still reported, but the caller doesn't hear the fallback again
+        assert_eq!(fallbacks(&second), 0);
+        assert!(second.iter().any(Frame::is_error));
+
+        // The next response gets it again
+        processor
+            .process(Frame::EndOfStream, &mut ctx)
+            .await
+            .unwrap();
+        let next = processor
+            .process(sentence("Hello"), &mut ctx)
+            .await
+            .unwrap();
+        assert_eq!(fallbacks(&next), 1);
+    }
 }
@@ -0,0 +1,165 @@
+//! Audio Reframing
+//!
+//! Clients send audio in whatever chunk sizes their capture stack produces,
+//! while VAD/STT models expect a fixed frame duration (10/20/30ms). The
+//! reframer accumulates and splits incoming frames into uniform frames of the
+//! configured duration, carrying residual samples over to the next call.
+
+use std::time::Instant;
+
+use voice_agent_core::{AudioFrame, Channels, SampleRate};
+
+/// Accumulates incoming audio and emits fixed-size frames
+#[derive(Debug)]
+pub struct AudioReframer {
+    /// Output frame duration in milliseconds
+    frame_ms: u32,
+    /// Audio format of the buffered samples
+    format: Option<(SampleRate, Channels)>,
+    /// Residual samples not yet emitted
+    buffer: Vec<f32>,
+    /// Capture time of the first buffered sample
+    buffer_start: Instant,
+    /// Sequence number of the next emitted frame
+    next_sequence: u64,
+}
+
+impl AudioReframer {
+    /// Create a reframer emitting frames of `frame_ms` milliseconds
+    pub fn new(frame_ms: u32) -> Self {
+        Self {
+            frame_ms: frame_ms.max(1),
+            format: None,
+            buffer: Vec::new(),
+            buffer_start: Instant::now(),
+            next_sequence: 0,
+        }
+    }
+
+    /// Output frame duration in milliseconds
+    pub fn frame_ms(&self) -> u32 {
+        self.frame_ms
+    }
+
+    /// Samples per output frame (interleaved across channels)
+    pub fn frame_samples(sample_rate: SampleRate, channels: Channels, frame_ms: u32) -> usize {
+        (sample_rate.as_u32() as usize * frame_ms as usize / 1000) * channels.count()
+    }
+
+    /// Residual samples waiting for the next call
+    pub fn pending_samples(&self) -> usize {
+        self.buffer.len()
+    }
+
+    /// Add an incoming chunk and return every complete frame
+    ///
+    /// A format change flushes the residual of the previous format as a
+    /// short frame so no samples are dropped.
+    pub fn push(&mut self, frame: &AudioFrame) -> Vec<AudioFrame> {
+        let mut frames = Vec::new();
+
+        let format = (frame.sample_rate, frame.channels);
+        if self.format != Some(format) {
+            frames.extend(self.flush());
+            self.format = Some(format);
+        }
+
+        if self.buffer.is_empty() {
+            self.buffer_start = frame.timestamp;
+        }
+        self.buffer.extend_from_slice(&frame.samples);
+
+        let frame_samples = Self::frame_samples(format.0, format.1, self.frame_ms);
+        while frame_samples > 0 && self.buffer.len() >= frame_samples {
+            let samples: Vec<f32> = self.buffer.drain(..frame_samples).collect();
+            frames.push(self.emit(samples));
+        }
+
+        frames
+    }
+
+    /// Emit the residual samples as a final short frame
+    pub fn flush(&mut self) -> Option<AudioFrame> {
+        if self.buffer.is_empty() {
+            return None;
+        }
+        let samples = std::mem::take(&mut self.buffer);
+        Some(self.emit(samples))
+    }
+
+    /// Drop residual samples and restart sequence numbering
+    pub fn reset(&mut self) {
+        self.buffer.clear();
+        self.format = None;
+        self.next_sequence = 0;
+    }
+
+    fn emit(&mut self, samples: Vec<f32>) -> AudioFrame {
+        let (sample_rate, channels) = self.format.unwrap_or_default();
+        let frame = AudioFrame::with_timestamp(
+            samples,
+            sample_rate,
+            channels,
+            self.next_sequence,
+            self.buffer_start,
+        );
+        self.next_sequence += 1;
+        self.buffer_start += frame.duration;
+        frame
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chunk(start: usize, len: usize) -> AudioFrame {
+        let samples = (start..start + len).map(|i| i as f32 / 100_000.0).collect();
+        AudioFrame::new(samples, SampleRate::Hz16000, Channels::Mono, 0)
+    }
+
+    #[test]
+    fn test_irregular_chunks_reframed_without_loss() {
+        // 20ms at 16kHz = 320 samples
+        let mut reframer = AudioReframer::new(20);
+        let chunk_sizes = [100, 37, 512, 320, 1, 999, 250, 61];
+
+        let mut input = 0;
+        let mut output: Vec<f32> = Vec::new();
+        let mut sequences = Vec::new();
+        for size in chunk_sizes {
+            for frame in reframer.push(&chunk(input, size)) {
+                assert_eq!(frame.samples.len(), 320);
+                sequences.push(frame.sequence);
+                output.extend_from_slice(&frame.samples);
+            }
+            input += size;
+        }
+
+        let total: usize = chunk_sizes.iter().sum();
+        assert_eq!(output.len(), total / 320 * 320);
+        assert_eq!(reframer.pending_samples(), total % 320);
+        assert_eq!(sequences, (0..(total / 320) as u64).collect::<Vec<_>>());
+
+        let residual = reframer.flush().unwrap();
+        output.extend_from_slice(&residual.samples);
+        assert_eq!(reframer.pending_samples(), 0);
+
+        let expected: Vec<f32> = chunk(0, total).samples.to_vec();
+        assert_eq!(output, expected);
+    }
+
+    #[test]
+    fn test_format_change_flushes_residual() {
+        let mut reframer = AudioReframer::new(10);
+        assert!(reframer.push(&chunk(0, 100)).is_empty());
+
+        let stereo = AudioFrame::new(vec![0.0; 320], SampleRate::Hz16000, Channels::Stereo, 0);
+        let frames = reframer.push(&stereo);
+        assert_eq!(frames.len(), 2);
+        assert_eq!(frames[0].samples.len(), 100);
+        assert_eq!(frames[0].channels, Channels::Mono);
+        assert_eq!(frames[1].samples.len(), 320);
+        assert_eq!(frames[1].channels, Channels::Stereo);
+    }
+}
@@ -0,0 +1,247 @@
+//! Streaming spoken-language identification
+//!
+//! Identifies the caller's language from the STT's early decode, before a
+//! turn is finalized. Indic STT models emit each language in its own script,
+//! so the script of the decoded letters is a strong signal for which
+//! language is being spoken even while the words themselves are still
+//! unstable. Evidence accumulates over the partials of an utterance and a
+//! single `LanguageDetection` is emitted once it is conclusive.
+//!
+//! A script is reported as the language most spoken in it: Tamil script
+//! means Tamil, and Devanagari, which Marathi and Nepali share, is taken as
+//! Hindi. The script of the caller's current language is never reported,
+//! since it already matches what they speak, so a Marathi caller is not
+//! switched to Hindi by their own script. Latin letters are English
+//! evidence, which lets a Hindi caller who moves to English be detected;
+//! romanized Indic speech reads as English too, so detections are only
+//! reliable from STT models that decode each language in its own script.
+
+use serde::{Deserialize, Serialize};
+use voice_agent_core::{Language, Script};
+
+/// Scripts weighed as evidence, with the language each one is reported as
+///
+/// Latin letters are counted separately, as English.
+const SCRIPT_LANGUAGES: [(Script, Language); 12] = [
+    (Script::Devanagari, Language::Hindi),
+    (Script::Bengali, Language::Bengali),
+    (Script::Tamil, Language::Tamil),
+    (Script::Telugu, Language::Telugu),
+    (Script::Kannada, Language::Kannada),
+    (Script::Malayalam, Language::Malayalam),
+    (Script::Gujarati, Language::Gujarati),
+    (Script::Gurmukhi, Language::Punjabi),
+    (Script::Odia, Language::Odia),
+    (Script::Arabic, Language::Urdu),
+    (Script::OlChiki, Language::Santali),
+    (Script::MeeteiMayek, Language::Manipuri),
+];
+
+/// Spoken language identified during an utterance
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct LanguageDetection {
+    /// Most likely language
+    pub language: Language,
+    /// Share of the decoded letters supporting it (0.0 - 1.0)
+    pub confidence: f32,
+    /// Letters decoded when the decision was made
+    pub evidence_chars: usize,
+}
+
+/// Language identification configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LanguageIdConfig {
+    /// Enable language identification
+    pub enabled: bool,
+    /// Decoded letters (including vowel signs) needed before deciding
+    pub min_chars: usize,
+    /// Minimum confidence to report a detection
+    pub min_confidence: f32,
+}
+
+impl Default for LanguageIdConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            min_chars: 8,
+            min_confidence: 0.6,
+        }
+    }
+}
+
+/// Accumulates script evidence over an utterance's partial transcripts
+#[derive(Debug, Clone)]
+pub struct StreamingLanguageId {
+    config: LanguageIdConfig,
+    /// Letters per script in the latest partial
+    counts: [usize; SCRIPT_LANGUAGES.len()],
+    /// Latin letters in the latest partial, English evidence
+    latin: usize,
+    /// Language the caller is currently understood to speak
+    language: Language,
+    /// Whether this utterance's detection was already reported
+    reported: bool,
+}
+
+impl StreamingLanguageId {
+    /// Create a new identifier
+    pub fn new(config: LanguageIdConfig) -> Self {
+        Self {
+            config,
+            counts: [0; SCRIPT_LANGUAGES.len()],
+            latin: 0,
+            language: Language::English,
+            reported: false,
+        }
+    }
+
+    /// Set the caller's current language, whose script is never reported
+    pub fn set_language(&mut self, language: Language) {
+        self.language = language;
+    }
+
+    /// Feed the latest partial transcript of the current utterance
+    ///
+    /// Partials are cumulative, so each one replaces the evidence from the
+    /// previous. Returns a detection at most once per utterance, and only
+    /// for a script other than the current language's.
+    pub fn observe(&mut self, partial: &str) -> Option<LanguageDetection> {
+        if !self.config.enabled || self.reported {
+            return None;
+        }
+
+        self.counts = [0; SCRIPT_LANGUAGES.len()];
+        self.latin = 0;
+        for c in partial.chars() {
+            if c.is_ascii_alphabetic() {
+                self.latin += 1;
+            } else if c.is_ascii() {
+                continue;
+            } else if let Some(i) = SCRIPT_LANGUAGES
+                .iter()
+                .position(|(s, _)| s.contains_char(c))
+            {
+                self.counts[i] += 1;
+            }
+        }
+
+        let detection = self.current()?;
+        let script = detection.language.script();
+        if detection.evidence_chars < self.config.min_chars
+            || detection.confidence < self.config.min_confidence
+            || script == self.language.script()
+        {
+            return None;
+        }
+        self.reported = true;
+        Some(detection)
+    }
+
+    /// Best guess from the evidence so far, regardless of thresholds
+    pub fn current(&self) -> Option<LanguageDetection> {
+        let (best, &count) = self
+            .counts
+            .iter()
+            .enumerate()
+            .max_by_key(|(_, count)| **count)?;
+        let (language, count) = if self.latin > count {
+            (Language::English, self.latin)
+        } else {
+            (SCRIPT_LANGUAGES[best].1, count)
+        };
+        if count == 0 {
+            return None;
+        }
+        let total = self.counts.iter().sum::<usize>() + self.latin;
+        Some(LanguageDetection {
+            language,
+            confidence: count as f32 / total as f32,
+            evidence_chars: total,
+        })
+    }
+
+    /// Start a new utterance
+    pub fn reset(&mut self) {
+        self.counts = [0; SCRIPT_LANGUAGES.len()];
+        self.latin = 0;
+        self.reported = false;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detects_script_language_once_confident() {
+        let mut lid = StreamingLanguageId::new(LanguageIdConfig::default());
+
+        // Too little evidence yet
+        assert!(lid.observe("எனக்கு").is_none());
+        let detection = lid.observe("எனக்கு தங்க கடன் வேண்டும்").unwrap();
+        assert_eq!(detection.language, Language::Tamil);
+        assert!(detection.confidence > 0.99);
+
+        // Reported once per utterance
+        assert!(lid.observe("எனக்கு தங்க கடன் வேண்டும் இப்போது").is_none());
+        lid.reset();
+        let detection = lid.observe("నాకు గోల్డ్ లోన్ కావాలి").unwrap();
+        assert_eq!(detection.language, Language::Telugu);
+    }
+
+    #[test]
+    fn test_english_session_hearing_devanagari() {
+        let mut lid = StreamingLanguageId::new(LanguageIdConfig::default());
+
+        let detection = lid.observe("मुझे गोल्ड लोन चाहिए").unwrap();
+        assert_eq!(detection.language, Language::Hindi);
+
+        // Scripts several languages share are reported as the main one
+        lid.reset();
+        let detection = lid.observe("মাকে গোল্ড লোন চাই").unwrap();
+        assert_eq!(detection.language, Language::Bengali);
+        lid.reset();
+        let detection = lid.observe("مجھے گولڈ لون چاہیے").unwrap();
+        assert_eq!(detection.language, Language::Urdu);
+    }
+
+    #[test]
+    fn test_hindi_session_hearing_english() {
+        let mut lid = StreamingLanguageId::new(LanguageIdConfig::default());
+        lid.set_language(Language::Hindi);
+
+        let detection = lid.observe("I want a gold loan").unwrap();
+        assert_eq!(detection.language, Language::English);
+        assert!(detection.confidence > 0.99);
+
+        // Their own script is not reported
+        lid.reset();
+        assert!(lid.observe("मुझे गोल्ड लोन चाहिए").is_none());
+    }
+
+    #[test]
+    fn test_current_language_script_not_reported() {
+        let mut lid = StreamingLanguageId::new(LanguageIdConfig::default());
+        lid.set_language(Language::Marathi);
+
+        // A Marathi caller is not switched to Hindi by their own script
+        assert!(lid.observe("मला गोल्ड लोन हवे आहे").is_none());
+        lid.reset();
+        let detection = lid.observe("எனக்கு தங்க கடன் வேண்டும்").unwrap();
+        assert_eq!(detection.language, Language::Tamil);
+
+        lid.set_language(Language::Tamil);
+        lid.reset();
+        assert!(lid.observe("எனக்கு தங்க கடன் வேண்டும்").is_none());
+    }
+
+    #[test]
+    fn test_latin_is_not_reported_to_an_english_session() {
+        let mut lid = StreamingLanguageId::new(LanguageIdConfig::default());
+
+        assert!(lid.observe("mujhe gold loan chahiye").is_none());
+        // Mostly Latin with a few Devanagari letters stays English
+        assert!(lid.observe("mujhe gold loan chahiye अभी").is_none());
+        assert_eq!(lid.current().unwrap().language, Language::English);
+    }
+}
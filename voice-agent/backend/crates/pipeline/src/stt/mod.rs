@@ -13,11 +13,13 @@
 
 mod decoder;
 mod indicconformer;
+mod language_id;
 mod streaming;
 mod vocab;
 
 pub use decoder::{DecoderConfig, EnhancedDecoder};
 pub use indicconformer::{IndicConformerConfig, IndicConformerStt, MelFilterbank};
+pub use language_id::{LanguageDetection, LanguageIdConfig, StreamingLanguageId};
 pub use streaming::{StreamingStt, SttConfig, SttEngine};
 pub use vocab::{load_domain_vocab, load_vocabulary, Vocabulary};
 
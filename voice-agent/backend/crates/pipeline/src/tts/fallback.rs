@@ -0,0 +1,272 @@
+//! What the caller hears when synthesis fails
+//!
+//! A failed synthesis is tried again up to `retries` times. If it still
+//! fails, the `TtsFallback` audio plays in its place, so the caller hears
+//! something rather than dead air. `TtsRecovery` tracks this for one
+//! response: the fallback plays at most once however many of its sentences
+//! fail.
+
+use std::path::Path;
+use std::sync::Arc;
+use tokio::sync::mpsc;
+
+use super::{StreamingTts, TtsEvent};
+use crate::ingest::{load_wav, resample};
+use crate::PipelineError;
+
+/// Sample rate of `TtsFallback` audio
+pub const FALLBACK_SAMPLE_RATE: u32 = 16000;
+
+/// What the caller hears when speech can't be synthesized
+#[derive(Debug, Clone)]
+pub enum TtsFallback {
+    /// Nothing; the failure is only reported
+    Silence,
+    /// A sine tone
+    Beep {
+        /// Tone frequency (Hz)
+        frequency_hz: f32,
+        /// Tone length (ms)
+        duration_ms: u32,
+    },
+    /// A pre-recorded clip ("please hold"), 16 kHz mono samples
+    Clip(Arc<[f32]>),
+}
+
+impl TtsFallback {
+    /// Load a pre-recorded clip from a WAV file, resampled to 16 kHz mono
+    pub fn clip_from_wav(path: impl AsRef<Path>) -> Result<Self, PipelineError> {
+        let audio = load_wav(path)?;
+        let samples = resample(&audio.samples, audio.sample_rate, FALLBACK_SAMPLE_RATE)?;
+        Ok(Self::Clip(samples.into()))
+    }
+
+    /// Fallback from the `pipeline.tts.fallback` config section
+    ///
+    /// A clip that can't be loaded is logged and replaced by the default beep,
+    /// so a bad path doesn't leave callers with silence.
+    pub fn from_settings(settings: &voice_agent_config::pipeline::TtsFallbackConfig) -> Self {
+        use voice_agent_config::pipeline::TtsFallbackConfig;
+
+        match settings {
+            TtsFallbackConfig::Silence => TtsFallback::Silence,
+            TtsFallbackConfig::Beep {
+                frequency_hz,
+                duration_ms,
+            } => TtsFallback::Beep {
+                frequency_hz: *frequency_hz,
+                duration_ms: *duration_ms,
+            },
+            TtsFallbackConfig::Clip { path } => Self::clip_from_wav(path).unwrap_or_else(|e| {
+                tracing::error!(path = %path, error = %e, "Failed to load TTS fallback clip");
+                TtsFallback::default()
+            }),
+        }
+    }
+
+    /// Name for logs and metrics
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TtsFallback::Silence => "silence",
+            TtsFallback::Beep { .. } => "beep",
+            TtsFallback::Clip(_) => "clip",
+        }
+    }
+
+    /// Samples to play, at 16 kHz
+    pub fn samples(&self) -> Vec<f32> {
+        match self {
+            TtsFallback::Silence => Vec::new(),
+            TtsFallback::Beep {
+                frequency_hz,
+                duration_ms,
+            } => {
+                let len = (FALLBACK_SAMPLE_RATE * duration_ms / 1000) as usize;
+                let step = 2.0 * std::f32::consts::PI * frequency_hz / FALLBACK_SAMPLE_RATE as f32;
+                (0..len).map(|i| 0.3 * (step * i as f32).sin()).collect()
+            },
+            TtsFallback::Clip(samples) => samples.to_vec(),
+        }
+    }
+}
+
+impl Default for TtsFallback {
+    fn default() -> Self {
+        TtsFallback::Beep {
+            frequency_hz: 440.0,
+            duration_ms: 300,
+        }
+    }
+}
+
+/// Retries and fallback for one response
+#[derive(Debug, Clone)]
+pub struct TtsRecovery {
+    retries: usize,
+    fallback: TtsFallback,
+    /// Failures since the last successful synthesis
+    failures: usize,
+    /// The fallback already played for this response
+    fallback_played: bool,
+}
+
+impl TtsRecovery {
+    pub fn new(retries: usize, fallback: TtsFallback) -> Self {
+        Self {
+            retries,
+            fallback,
+            failures: 0,
+            fallback_played: false,
+        }
+    }
+
+    /// Synthesis failed; true if it should be tried again
+    pub fn failed(&mut self) -> bool {
+        self.failures += 1;
+        self.failures <= self.retries
+    }
+
+    /// Synthesis succeeded; the next failure gets its retries afresh
+    pub fn succeeded(&mut self) {
+        self.failures = 0;
+    }
+
+    /// Attempts made on what failed last
+    pub fn attempts(&self) -> usize {
+        self.failures
+    }
+
+    /// Fallback in use
+    pub fn fallback(&self) -> &TtsFallback {
+        &self.fallback
+    }
+
+    /// Fallback audio at `sample_rate` for a failure that retries didn't fix
+    ///
+    /// Empty once the fallback has played for this response.
+    pub fn fallback_samples(&mut self, sample_rate: u32) -> Vec<f32> {
+        if std::mem::replace(&mut self.fallback_played, true) {
+            return Vec::new();
+        }
+        let samples = self.fallback.samples();
+        resample(&samples, FALLBACK_SAMPLE_RATE, sample_rate).unwrap_or_else(|e| {
+            tracing::warn!(error = %e, "Failed to resample TTS fallback");
+            Vec::new()
+        })
+    }
+
+    /// Next event of `tts` speaking `text`, retrying chunks that fail
+    ///
+    /// A failed chunk is synthesized again, with the rest of `text` (which is
+    /// updated to what is left), while retries remain. A failure the retries
+    /// don't fix comes back as `TtsEvent::Error`; play `fallback_samples`
+    /// in its place.
+    pub fn next_event(&mut self, tts: &StreamingTts, text: &mut String) -> Option<TtsEvent> {
+        loop {
+            let error = match tts.process_next() {
+                Ok(Some(TtsEvent::Error(e))) => e,
+                Ok(Some(event)) => {
+                    if matches!(event, TtsEvent::Audio { .. }) {
+                        self.succeeded();
+                    }
+                    return Some(event);
+                },
+                Ok(None) => return None,
+                Err(e) => e.to_string(),
+            };
+            if !self.failed() {
+                return Some(TtsEvent::Error(error));
+            }
+            tracing::warn!(attempt = self.failures, error = %error, "TTS failed, retrying");
+            *text = unspoken_text(text, tts.current_word_index());
+            let (tx, _rx) = mpsc::channel(1);
+            tts.start(text, tx);
+        }
+    }
+
+    /// Start the next response
+    pub fn reset(&mut self) {
+        self.failures = 0;
+        self.fallback_played = false;
+    }
+}
+
+impl Default for TtsRecovery {
+    fn default() -> Self {
+        Self::new(1, TtsFallback::default())
+    }
+}
+
+/// The words of `text` after the first `spoken`, to synthesize again after a failure
+fn unspoken_text(text: &str, spoken: usize) -> String {
+    text.split_whitespace()
+        .skip(spoken)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_beep_fallback_length() {
+        let beep = TtsFallback::Beep {
+            frequency_hz: 440.0,
+            duration_ms: 250,
+        };
+        let samples = beep.samples();
+        assert_eq!(samples.len(), 4000);
+        assert!(samples.iter().any(|s| s.abs() > 0.1));
+        assert!(TtsFallback::Silence.samples().is_empty());
+    }
+
+    #[test]
+    fn test_fallback_plays_once_per_response() {
+        let clip: Arc<[f32]> = vec![0.25; 800].into();
+        let mut recovery = TtsRecovery::new(1, TtsFallback::Clip(clip));
+
+        assert!(recovery.failed());
+        assert!(!recovery.failed());
+        assert_eq!(recovery.attempts(), 2);
+        assert_eq!(recovery.fallback_samples(FALLBACK_SAMPLE_RATE).len(), 800);
+
+        // A later sentence of the same response fails too
+        recovery.succeeded();
+        assert!(recovery.failed());
+        assert!(!recovery.failed());
+        assert!(recovery.fallback_samples(FALLBACK_SAMPLE_RATE).is_empty());
+
+        recovery.reset();
+        assert_eq!(recovery.fallback_samples(FALLBACK_SAMPLE_RATE).len(), 800);
+    }
+
+    #[test]
+    fn test_clip_from_wav_resamples() {
+        let path = std::env::temp_dir().join("tts_fallback_clip_8k.wav");
+        let spec = hound::WavSpec {
+            channels: 1,
+            sample_rate: 8000,
+            bits_per_sample: 16,
+            sample_format: hound::SampleFormat::Int,
+        };
+        let mut writer = hound::WavWriter::create(&path, spec).unwrap();
+        for _ in 0..8000 {
+            writer.write_sample(8000i16).unwrap();
+        }
+        writer.finalize().unwrap();
+
+        let TtsFallback::Clip(samples) = TtsFallback::clip_from_wav(&path).unwrap() else {
+            panic!("expected a clip");
+        };
+        let _ = std::fs::remove_file(&path);
+        // One second of audio at 16 kHz
+        assert!((samples.len() as i64 - 16000).abs() < 160);
+    }
+
+    #[test]
+    fn test_unspoken_text() {
+        assert_eq!(unspoken_text("Your loan is approved", 2), "is approved");
+        assert_eq!(unspoken_text("Your loan", 5), "");
+    }
+}
@@ -5,6 +5,9 @@
 //! - Barge-in aware (can stop mid-word)
 //! - Multiple backend support (Piper, IndicF5, Parler)
 //! - Hindi/Hinglish G2P conversion
+//! - Per-language spell-out of codes the voice can't pronounce
+//! - Mapping played audio back to the words the caller heard
+//! - Retries and fallback audio when synthesis fails
 //! - Native Candle-based IndicF5 model (optional)
 //!
 //! ## P0-1 FIX: Engine Routing
@@ -15,8 +18,12 @@
 //! - `TtsEngine::ParlerTts` uses ONNX-based ParlerTts
 
 mod chunker;
+mod fallback;
 mod g2p;
+mod playback;
+mod spell_out;
 mod streaming;
+mod style;
 
 /// Candle-based TTS implementations (native Rust with SafeTensors)
 #[cfg(feature = "candle")]
@@ -30,8 +37,13 @@ pub mod candle {
 }
 
 pub use chunker::{ChunkStrategy, WordChunker};
+pub use fallback::{TtsFallback, TtsRecovery, FALLBACK_SAMPLE_RATE};
 pub use g2p::{create_hindi_g2p, G2pConfig, HindiG2p, Language, Phoneme};
+pub use playback::PlaybackTracker;
+pub use spell_out::spell_out;
 pub use streaming::{StreamingTts, TtsConfig, TtsEngine, TtsEvent};
+pub use style::{ProsodyParams, ProsodySupport, TtsStyle};
+pub use voice_agent_config::{SpellOutConfig, SpellOutMode, SpellOutRule};
 
 // P1-3 FIX: Re-export IndicF5 model types from candle module
 // TtsBackend, StubTtsBackend, IndicF5Backend, and create_tts_backend
@@ -53,6 +65,18 @@ pub trait TtsBackend: Send + Sync {
 
     /// Supports streaming word-by-word?
     fn supports_streaming(&self) -> bool;
+
+    /// Synthesize with prosody from a style hint
+    ///
+    /// Backends without style support ignore the hint and synthesize normally.
+    async fn synthesize_with_prosody(
+        &self,
+        text: &str,
+        prosody: &ProsodyParams,
+    ) -> Result<Vec<f32>, PipelineError> {
+        let _ = prosody;
+        self.synthesize(text).await
+    }
 }
 
 // ============================================================================
@@ -0,0 +1,168 @@
+//! What the caller heard of an utterance
+//!
+//! TTS synthesizes ahead of playback, so when the caller barges in the text
+//! synthesized so far overstates what they heard. `PlaybackTracker` records
+//! each piece of text with the audio synthesized for it and maps the audio
+//! played back to words: every piece whose audio played in full, then the
+//! share of the current piece's words its played audio covers. Audio is
+//! taken to play in real time from the first chunk sent to the caller; when
+//! playback runs dry waiting for synthesis, the clock picks up again with
+//! the next chunk.
+
+use std::time::{Duration, Instant};
+
+/// Maps audio played back to the words it carried
+#[derive(Debug, Default)]
+pub struct PlaybackTracker {
+    /// Text of each piece with the audio synthesized for it
+    pieces: Vec<(String, Duration)>,
+    /// When the first audio of the utterance was sent
+    started: Option<Instant>,
+    /// The utterance's last audio was sent; the next text starts a new one
+    finished: bool,
+}
+
+impl PlaybackTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Start the next piece of text, whose audio follows
+    pub fn text(&mut self, text: &str) {
+        if self.finished {
+            self.reset();
+        }
+        self.pieces.push((text.to_string(), Duration::ZERO));
+    }
+
+    /// Audio for the current piece was sent for playback
+    pub fn audio(&mut self, duration: Duration) {
+        let now = Instant::now();
+        let sent = self.sent();
+        match self.started {
+            // Everything sent has played; playback resumes with this audio
+            Some(at) if now.duration_since(at) > sent => {
+                self.started = Some(now.checked_sub(sent).unwrap_or(now));
+            },
+            Some(_) => {},
+            None => self.started = Some(now),
+        }
+        if let Some((_, total)) = self.pieces.last_mut() {
+            *total += duration;
+        }
+    }
+
+    /// Audio sent for the utterance so far
+    fn sent(&self) -> Duration {
+        self.pieces.iter().map(|(_, duration)| *duration).sum()
+    }
+
+    /// The current piece's audio doesn't carry its words, e.g. TTS failed on it
+    pub fn mark_unspoken(&mut self) {
+        if let Some((text, _)) = self.pieces.last_mut() {
+            text.clear();
+        }
+    }
+
+    /// The utterance's last audio was sent
+    pub fn finish(&mut self) {
+        self.finished = true;
+    }
+
+    /// Whether any audio of the utterance was sent
+    pub fn is_playing(&self) -> bool {
+        self.started.is_some()
+    }
+
+    /// Words heard so far
+    pub fn played_text(&self) -> String {
+        let played = self.started.map(|at| at.elapsed()).unwrap_or_default();
+        self.text_for(played)
+    }
+
+    /// Words carried by all the audio sent, once it has played
+    pub fn sent_text(&self) -> String {
+        self.text_for(self.sent())
+    }
+
+    /// Words carried by the first `played` of audio
+    pub fn text_for(&self, mut played: Duration) -> String {
+        let mut words: Vec<&str> = Vec::new();
+        for (text, duration) in &self.pieces {
+            // No audio for this text has been sent yet
+            if duration.is_zero() {
+                break;
+            }
+            if played >= *duration {
+                words.extend(text.split_whitespace());
+                played -= *duration;
+                continue;
+            }
+            let piece: Vec<&str> = text.split_whitespace().collect();
+            let heard = piece.len() as f64 * played.as_secs_f64() / duration.as_secs_f64();
+            words.extend(&piece[..heard.floor() as usize]);
+            break;
+        }
+        words.join(" ")
+    }
+
+    /// Forget the utterance
+    pub fn reset(&mut self) {
+        self.pieces.clear();
+        self.started = None;
+        self.finished = false;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tracker() -> PlaybackTracker {
+        let mut tracker = PlaybackTracker::new();
+        tracker.text("Your loan is approved.");
+        tracker.audio(Duration::from_millis(800));
+        tracker.text("Please visit the branch tomorrow.");
+        tracker.audio(Duration::from_millis(1000));
+        tracker
+    }
+
+    #[test]
+    fn test_played_audio_maps_to_words() {
+        let tracker = tracker();
+        assert_eq!(tracker.text_for(Duration::ZERO), "");
+        assert_eq!(tracker.text_for(Duration::from_millis(400)), "Your loan");
+        assert_eq!(
+            tracker.text_for(Duration::from_millis(1200)),
+            "Your loan is approved. Please visit"
+        );
+        assert_eq!(
+            tracker.text_for(Duration::from_secs(5)),
+            "Your loan is approved. Please visit the branch tomorrow."
+        );
+    }
+
+    #[test]
+    fn test_nothing_heard_before_audio_plays() {
+        let mut tracker = PlaybackTracker::new();
+        tracker.text("Hello there");
+        assert!(!tracker.is_playing());
+        assert_eq!(tracker.played_text(), "");
+    }
+
+    #[test]
+    fn test_unspoken_piece_not_heard() {
+        let mut tracker = tracker();
+        tracker.mark_unspoken();
+        assert_eq!(tracker.sent_text(), "Your loan is approved.");
+    }
+
+    #[test]
+    fn test_next_utterance_starts_fresh() {
+        let mut tracker = tracker();
+        tracker.finish();
+        tracker.text("Anything else?");
+        tracker.audio(Duration::from_millis(500));
+        assert_eq!(tracker.text_for(Duration::from_secs(5)), "Anything else?");
+    }
+}
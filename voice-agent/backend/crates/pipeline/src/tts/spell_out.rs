@@ -0,0 +1,215 @@
+//! Spell-out fallback for words the voice can't pronounce
+//!
+//! A Hindi voice has no pronunciation for a Latin-script reference number
+//! like "GL2024X" in the middle of a Devanagari sentence, and engines
+//! either skip such tokens or mangle them. Before synthesis, tokens that
+//! look like codes (uppercase letters mixed with digits) and, optionally,
+//! any word outside the language's script are rewritten so they are read
+//! one character at a time. With `Transliterate`, Latin
+//! letters are written as their spoken names in the language's script
+//! ("GL" → "जी एल" for Devanagari); scripts without a letter-name table
+//! fall back to plain letters. Rules are set per language in
+//! `pipeline.tts.spell_out`; English is off by default.
+
+use std::borrow::Cow;
+
+use voice_agent_config::{SpellOutConfig, SpellOutMode, SpellOutRule};
+use voice_agent_core::{Language, Script};
+
+/// Rewrite tokens the voice for `language` can't pronounce
+pub fn spell_out<'a>(config: &SpellOutConfig, text: &'a str, language: Language) -> Cow<'a, str> {
+    let rule = config.rule(language);
+    if rule.mode == SpellOutMode::Off {
+        return Cow::Borrowed(text);
+    }
+    let script = language.script();
+    let mut changed = false;
+    let words: Vec<Cow<str>> = text
+        .split(' ')
+        .map(|word| match spell_word(word, rule, script) {
+            Some(spelled) => {
+                changed = true;
+                Cow::Owned(spelled)
+            },
+            None => Cow::Borrowed(word),
+        })
+        .collect();
+    if !changed {
+        return Cow::Borrowed(text);
+    }
+    Cow::Owned(words.join(" "))
+}
+
+/// Spelled-out form of `word`, or None to leave it as is
+fn spell_word(word: &str, rule: &SpellOutRule, script: Script) -> Option<String> {
+    // Keep surrounding punctuation, e.g. the comma in "GL2024X,"
+    let core = word.trim_matches(|c: char| !c.is_alphanumeric());
+    if core.is_empty() {
+        return None;
+    }
+    let spell = (rule.codes && is_code(core))
+        || (rule.mismatched_script && !in_script(core, script) && !is_number(core));
+    if !spell {
+        return None;
+    }
+
+    let spelled = core
+        .chars()
+        .filter(|c| c.is_alphanumeric())
+        .map(|c| spell_char(c, rule.mode, script))
+        .collect::<Vec<_>>()
+        .join(" ");
+    let start = word.find(core).unwrap_or(0);
+    Some(format!(
+        "{}{}{}",
+        &word[..start],
+        spelled,
+        &word[start + core.len()..]
+    ))
+}
+
+/// Suffixes read as part of the number before them: ordinals and units
+const NUMBER_SUFFIXES: &[&str] = &[
+    "st", "nd", "rd", "th", "k", "l", "g", "gm", "gms", "kg", "mg", "ml", "km", "cm", "mm", "m",
+    "gb", "mb", "kb", "am", "pm", "cr",
+];
+
+/// Uppercase letters mixed with digits, like a reference number ("GL2024X")
+///
+/// Hyphenated words ("COVID-19"), ordinals ("21ST") and quantities with a
+/// unit ("5KG") are read fine and left alone, as are acronyms without
+/// digits ("KYC").
+fn is_code(token: &str) -> bool {
+    let has_letter = token.chars().any(|c| c.is_alphabetic());
+    let has_digit = token.chars().any(|c| c.is_ascii_digit());
+    if !has_letter || !has_digit || token.contains('-') {
+        return false;
+    }
+    if token
+        .chars()
+        .any(|c| c.is_alphabetic() && !c.is_uppercase())
+    {
+        return false;
+    }
+    let suffix = token.trim_start_matches(|c: char| c.is_ascii_digit() || c == '.');
+    let number_with_suffix =
+        suffix.len() < token.len() && NUMBER_SUFFIXES.contains(&suffix.to_lowercase().as_str());
+    !number_with_suffix
+}
+
+fn is_number(token: &str) -> bool {
+    token
+        .chars()
+        .all(|c| c.is_ascii_digit() || c == '.' || c == ',')
+}
+
+/// Whether every letter of `token` is in `script`
+fn in_script(token: &str, script: Script) -> bool {
+    token
+        .chars()
+        .filter(|c| c.is_alphabetic())
+        .all(|c| script.contains_char(c))
+}
+
+fn spell_char(c: char, mode: SpellOutMode, script: Script) -> String {
+    if mode == SpellOutMode::Transliterate && c.is_ascii_alphabetic() {
+        if let Some(name) = latin_letter_name(c, script) {
+            return name.to_string();
+        }
+    }
+    c.to_uppercase().collect()
+}
+
+/// Spoken name of a Latin letter written in `script`
+fn latin_letter_name(c: char, script: Script) -> Option<&'static str> {
+    // A to Z
+    const DEVANAGARI: &str = "ए बी सी डी ई एफ़ जी एच आई जे के एल एम एन ओ \
+                              पी क्यू आर एस टी यू वी डब्ल्यू एक्स वाई ज़ेड";
+    let index = (c.to_ascii_uppercase() as u8 - b'A') as usize;
+    match script {
+        Script::Devanagari => DEVANAGARI.split_whitespace().nth(index),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_latin_code_in_devanagari_sentence_is_spelled_out() {
+        let config = SpellOutConfig::default();
+        let text = "आपका रेफरेंस नंबर GL2024X है।";
+
+        let spoken = spell_out(&config, text, Language::Hindi);
+
+        assert_eq!(spoken, "आपका रेफरेंस नंबर G L 2 0 2 4 X है।");
+    }
+
+    #[test]
+    fn test_transliterated_code_uses_devanagari_letter_names() {
+        let config = SpellOutConfig::default().with_rule(
+            Language::Hindi,
+            SpellOutRule {
+                mode: SpellOutMode::Transliterate,
+                ..Default::default()
+            },
+        );
+
+        let spoken = spell_out(&config, "आपका नंबर GL24, नोट कर लीजिए", Language::Hindi);
+
+        assert_eq!(spoken, "आपका नंबर जी एल 2 4, नोट कर लीजिए");
+    }
+
+    #[test]
+    fn test_plain_words_and_numbers_are_left_alone() {
+        let config = SpellOutConfig::default();
+        let text = "आपका gold loan 50000 रुपये का है";
+
+        assert!(matches!(
+            spell_out(&config, text, Language::Hindi),
+            Cow::Borrowed(_)
+        ));
+        for text in [
+            "आपका KYC बाकी है",
+            "COVID-19 के बाद",
+            "21ST तारीख को 5KG",
+            "कोड gl24 है",
+        ] {
+            assert_eq!(spell_out(&config, text, Language::Hindi), text);
+        }
+    }
+
+    #[test]
+    fn test_english_off_by_default() {
+        let config = SpellOutConfig::default();
+        let text = "Your reference number is GL2024X";
+
+        assert!(matches!(
+            spell_out(&config, text, Language::English),
+            Cow::Borrowed(_)
+        ));
+        let english = SpellOutConfig::default().with_rule(Language::English, Default::default());
+        assert_eq!(
+            spell_out(&english, text, Language::English),
+            "Your reference number is G L 2 0 2 4 X"
+        );
+    }
+
+    #[test]
+    fn test_mismatched_script_words_per_language() {
+        let strict = SpellOutRule {
+            mismatched_script: true,
+            ..Default::default()
+        };
+        let config = SpellOutConfig::default().with_rule(Language::Tamil, strict);
+
+        // The Tamil voice can't read Latin at all; Hindi keeps Hinglish words
+        assert_eq!(spell_out(&config, "இது kyc", Language::Tamil), "இது K Y C");
+        assert_eq!(spell_out(&config, "यह kyc है", Language::Hindi), "यह kyc है");
+        assert_eq!(
+            spell_out(&SpellOutConfig::disabled(), "नंबर GL24", Language::Hindi),
+            "नंबर GL24"
+        );
+    }
+}
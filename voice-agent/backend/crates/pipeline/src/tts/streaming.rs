@@ -12,6 +12,7 @@ use parking_lot::Mutex;
 use std::path::Path;
 use std::sync::Arc;
 use tokio::sync::mpsc;
+use voice_agent_core::Language;
 
 #[cfg(feature = "onnx")]
 use ndarray::Array2;
@@ -21,7 +22,7 @@ use ort::session::{builder::GraphOptimizationLevel, Session};
 use ort::value::Tensor;
 
 use super::chunker::{ChunkStrategy, ChunkerConfig, TextChunk, WordChunker};
-use super::{create_tts_backend, TtsBackend};
+use super::{create_tts_backend, spell_out, ProsodyParams, SpellOutConfig, TtsBackend, TtsStyle};
 use crate::PipelineError;
 
 /// TTS engine selection
@@ -52,6 +53,12 @@ pub struct TtsConfig {
     pub chunk_strategy: ChunkStrategy,
     /// Enable prosody hints
     pub prosody_hints: bool,
+    /// Initial speaking style (the agent can change it per response)
+    pub style: TtsStyle,
+    /// Language of the text to speak (can be changed per session)
+    pub language: Language,
+    /// Per-language spell-out of codes and words the voice can't pronounce
+    pub spell_out: SpellOutConfig,
     /// P0-1 FIX: Path to the TTS model (required for IndicF5, Piper, etc.)
     pub model_path: Option<std::path::PathBuf>,
     /// P0-1 FIX: Path to reference audio for voice cloning (IndicF5)
@@ -68,6 +75,9 @@ impl Default for TtsConfig {
             pitch: 1.0,
             chunk_strategy: ChunkStrategy::Adaptive,
             prosody_hints: true,
+            style: TtsStyle::Neutral,
+            language: Language::default(),
+            spell_out: SpellOutConfig::default(),
             model_path: None,
             reference_audio_path: None,
         }
@@ -120,8 +130,10 @@ pub enum TtsEvent {
     Complete,
     /// Barge-in occurred, synthesis stopped
     BargedIn {
-        /// Word index where barge-in occurred
+        /// Word index where barge-in occurred (words emitted before it)
         word_index: usize,
+        /// Text emitted before the barge-in, i.e. what the user heard
+        spoken_text: String,
     },
     /// Error occurred
     Error(String),
@@ -142,6 +154,14 @@ pub struct StreamingTts {
     barge_in: Mutex<bool>,
     /// Current word index
     current_word: Mutex<usize>,
+    /// Text of the chunks emitted so far for the current utterance
+    spoken_text: Mutex<String>,
+    /// Speaking style for upcoming synthesis
+    style: Mutex<TtsStyle>,
+    /// Language of upcoming synthesis, for spell-out rules
+    language: Mutex<Language>,
+    /// Voice speaking rate and pitch (from config, overridden per persona)
+    voice: Mutex<(f32, f32)>,
 }
 
 impl StreamingTts {
@@ -165,11 +185,15 @@ impl StreamingTts {
         Ok(Self {
             session: Some(Mutex::new(session)),
             backend: None,
+            style: Mutex::new(config.style),
+            language: Mutex::new(config.language),
+            voice: Mutex::new((config.speaking_rate, config.pitch)),
             config,
             chunker: Mutex::new(WordChunker::new(chunker_config)),
             synthesizing: Mutex::new(false),
             barge_in: Mutex::new(false),
             current_word: Mutex::new(0),
+            spoken_text: Mutex::new(String::new()),
         })
     }
 
@@ -197,11 +221,15 @@ impl StreamingTts {
             #[cfg(feature = "onnx")]
             session: None,
             backend: Some(backend),
+            style: Mutex::new(config.style),
+            language: Mutex::new(config.language),
+            voice: Mutex::new((config.speaking_rate, config.pitch)),
             config,
             chunker: Mutex::new(WordChunker::new(chunker_config)),
             synthesizing: Mutex::new(false),
             barge_in: Mutex::new(false),
             current_word: Mutex::new(0),
+            spoken_text: Mutex::new(String::new()),
         }
     }
 
@@ -233,11 +261,15 @@ impl StreamingTts {
             #[cfg(feature = "onnx")]
             session: None, // No model - will use stub synthesis
             backend: None,
+            style: Mutex::new(config.style),
+            language: Mutex::new(config.language),
+            voice: Mutex::new((config.speaking_rate, config.pitch)),
             config,
             chunker: Mutex::new(WordChunker::new(chunker_config)),
             synthesizing: Mutex::new(false),
             barge_in: Mutex::new(false),
             current_word: Mutex::new(0),
+            spoken_text: Mutex::new(String::new()),
         }
     }
 
@@ -251,6 +283,7 @@ impl StreamingTts {
         *self.synthesizing.lock() = true;
         *self.barge_in.lock() = false;
         *self.current_word.lock() = 0;
+        self.spoken_text.lock().clear();
 
         let _ = tx.try_send(TtsEvent::Started);
     }
@@ -262,6 +295,7 @@ impl StreamingTts {
             let word_idx = *self.current_word.lock();
             return Ok(Some(TtsEvent::BargedIn {
                 word_index: word_idx,
+                spoken_text: self.spoken_text(),
             }));
         }
 
@@ -281,6 +315,13 @@ impl StreamingTts {
                 if let Some(&last_idx) = text_chunk.word_indices.last() {
                     *self.current_word.lock() = last_idx + 1;
                 }
+                {
+                    let mut spoken = self.spoken_text.lock();
+                    if !spoken.is_empty() {
+                        spoken.push(' ');
+                    }
+                    spoken.push_str(text_chunk.text.trim());
+                }
 
                 Ok(Some(TtsEvent::Audio {
                     samples: audio.into(),
@@ -305,12 +346,14 @@ impl StreamingTts {
         if let Some(ref backend) = self.backend {
             // Backend synthesis is async, but we're in a sync context
             // Use block_in_place to safely run async code from within tokio runtime
-            let text = chunk.text.clone();
+            let text = self.spoken_form(&chunk.text);
             let backend = backend.clone();
+            let prosody = self.prosody();
 
             // block_in_place allows blocking in async context by moving thread to blocking pool
             let audio = tokio::task::block_in_place(|| {
-                tokio::runtime::Handle::current().block_on(backend.synthesize(&text))
+                tokio::runtime::Handle::current()
+                    .block_on(backend.synthesize_with_prosody(&text, &prosody))
             })?;
 
             return Ok(audio);
@@ -326,15 +369,18 @@ impl StreamingTts {
             },
         };
 
-        let text_ids: Vec<i64> = chunk.text.chars().map(|c| c as i64).collect();
+        let text = self.spoken_form(&chunk.text);
+        let text_ids: Vec<i64> = text.chars().map(|c| c as i64).collect();
 
         let input = Array2::from_shape_vec((1, text_ids.len()), text_ids)
             .map_err(|e| PipelineError::Tts(e.to_string()))?;
 
-        let input_lengths = Array2::from_shape_vec((1, 1), vec![chunk.text.len() as i64])
+        let input_lengths = Array2::from_shape_vec((1, 1), vec![text.len() as i64])
             .map_err(|e| PipelineError::Tts(e.to_string()))?;
 
-        let scales = Array2::from_shape_vec((1, 3), vec![0.667, self.config.speaking_rate, 0.8])
+        // Piper's second scale is phoneme length, the inverse of speaking rate
+        let length_scale = 1.0 / self.prosody().rate;
+        let scales = Array2::from_shape_vec((1, 3), vec![0.667, length_scale, 0.8])
             .map_err(|e| PipelineError::Tts(e.to_string()))?;
 
         let mut session = session_mutex.lock();
@@ -360,12 +406,14 @@ impl StreamingTts {
     fn synthesize_chunk(&self, chunk: &TextChunk) -> Result<Vec<f32>, PipelineError> {
         // P0-1 FIX: Use backend if available
         if let Some(ref backend) = self.backend {
-            let text = chunk.text.clone();
+            let text = self.spoken_form(&chunk.text);
             let backend = backend.clone();
+            let prosody = self.prosody();
 
             // block_in_place allows blocking in async context by moving thread to blocking pool
             let audio = tokio::task::block_in_place(|| {
-                tokio::runtime::Handle::current().block_on(backend.synthesize(&text))
+                tokio::runtime::Handle::current()
+                    .block_on(backend.synthesize_with_prosody(&text, &prosody))
             })?;
 
             return Ok(audio);
@@ -391,6 +439,18 @@ impl StreamingTts {
         *self.current_word.lock()
     }
 
+    /// Text emitted so far for the current utterance
+    ///
+    /// After a barge-in this is the portion the user actually heard.
+    pub fn spoken_text(&self) -> String {
+        self.spoken_text.lock().clone()
+    }
+
+    /// Number of characters emitted so far for the current utterance
+    pub fn spoken_chars(&self) -> usize {
+        self.spoken_text.lock().chars().count()
+    }
+
     /// Add more text (for streaming input)
     pub fn add_text(&self, text: &str) {
         let mut chunker = self.chunker.lock();
@@ -410,12 +470,71 @@ impl StreamingTts {
         *self.synthesizing.lock() = false;
         *self.barge_in.lock() = false;
         *self.current_word.lock() = 0;
+        self.spoken_text.lock().clear();
     }
 
     /// Get sample rate
     pub fn sample_rate(&self) -> u32 {
         self.config.sample_rate
     }
+
+    /// Set the speaking style for upcoming synthesis
+    pub fn set_style(&self, style: TtsStyle) {
+        *self.style.lock() = style;
+    }
+
+    /// Current speaking style
+    pub fn style(&self) -> TtsStyle {
+        *self.style.lock()
+    }
+
+    /// Set the language of upcoming synthesis
+    pub fn set_language(&self, language: Language) {
+        *self.language.lock() = language;
+    }
+
+    /// Current synthesis language
+    pub fn language(&self) -> Language {
+        *self.language.lock()
+    }
+
+    /// Text as sent to the engine, with unpronounceable tokens spelled out
+    ///
+    /// Events and `spoken_text` keep the original text.
+    pub fn spoken_form(&self, text: &str) -> String {
+        spell_out(&self.config.spell_out, text, self.language()).into_owned()
+    }
+
+    /// Set the voice speaking rate and pitch (1.0 = normal)
+    pub fn set_voice(&self, speaking_rate: f32, pitch: f32) {
+        *self.voice.lock() = (speaking_rate, pitch);
+    }
+
+    /// Current voice speaking rate
+    pub fn speaking_rate(&self) -> f32 {
+        self.voice.lock().0
+    }
+
+    /// Current voice pitch
+    pub fn pitch(&self) -> f32 {
+        self.voice.lock().1
+    }
+
+    /// Prosody for the voice and current style, limited to what the engine can vary
+    pub fn prosody(&self) -> ProsodyParams {
+        let (speaking_rate, pitch) = *self.voice.lock();
+        let style = if self.config.prosody_hints {
+            self.style().prosody()
+        } else {
+            ProsodyParams::default()
+        };
+        ProsodyParams {
+            rate: speaking_rate * style.rate,
+            pitch: pitch * style.pitch,
+            energy: style.energy,
+        }
+        .limit_to(self.config.engine.prosody_support())
+    }
 }
 
 #[async_trait::async_trait]
@@ -519,6 +638,37 @@ mod tests {
         assert!(matches!(event, Some(TtsEvent::BargedIn { .. })));
     }
 
+    #[test]
+    fn test_barge_in_reports_spoken_portion() {
+        let tts = StreamingTts::simple(TtsConfig::default());
+        let (tx, _rx) = mpsc::channel(10);
+        let text = "Your gold loan is approved. The interest rate is eleven percent. \
+                    Please visit the branch with your documents tomorrow morning.";
+
+        tts.start(text, tx);
+        let Some(TtsEvent::Audio {
+            text: first_chunk, ..
+        }) = tts.process_next().unwrap()
+        else {
+            panic!("expected an audio chunk");
+        };
+
+        tts.barge_in();
+        let Some(TtsEvent::BargedIn {
+            word_index,
+            spoken_text,
+        }) = tts.process_next().unwrap()
+        else {
+            panic!("expected barge-in");
+        };
+
+        assert_eq!(spoken_text, first_chunk.trim());
+        assert!(text.starts_with(&spoken_text));
+        assert!(spoken_text.len() < text.len());
+        assert_eq!(word_index, spoken_text.split_whitespace().count());
+        assert_eq!(tts.spoken_chars(), spoken_text.chars().count());
+    }
+
     #[test]
     fn test_reset() {
         let tts = StreamingTts::simple(TtsConfig::default());
@@ -529,4 +679,50 @@ mod tests {
 
         assert!(!tts.is_synthesizing());
     }
+
+    #[test]
+    fn test_codes_spelled_out_for_engine_only() {
+        let tts = StreamingTts::simple(TtsConfig::default());
+        tts.set_language(Language::Hindi);
+        let text = "आपका नंबर GL24 है";
+        let (tx, _rx) = mpsc::channel(10);
+
+        tts.start(text, tx);
+        assert_eq!(tts.spoken_form(text), "आपका नंबर G L 2 4 है");
+        while let Some(event) = tts.process_next().unwrap() {
+            if matches!(event, TtsEvent::Complete) {
+                break;
+            }
+        }
+        assert_eq!(tts.spoken_text(), text);
+    }
+
+    #[test]
+    fn test_style_ignored_without_engine_support() {
+        let tts = StreamingTts::simple(TtsConfig::default());
+        assert_eq!(tts.style(), TtsStyle::Neutral);
+        assert!(tts.prosody().is_neutral());
+
+        // Piper only varies rate
+        tts.set_style(TtsStyle::Empathetic);
+        let prosody = tts.prosody();
+        assert!(prosody.rate < 1.0);
+        assert_eq!(prosody.pitch, 1.0);
+        assert_eq!(prosody.energy, 1.0);
+
+        // Styled synthesis still works on a backend that ignores prosody
+        let tts = StreamingTts::with_backend(
+            Arc::new(crate::tts::StubTtsBackend::new(16000)),
+            TtsConfig::default(),
+        );
+        tts.set_style(TtsStyle::Upbeat);
+        let audio = futures::executor::block_on(
+            tts.backend
+                .as_ref()
+                .unwrap()
+                .synthesize_with_prosody("Hello", &tts.prosody()),
+        )
+        .unwrap();
+        assert!(!audio.is_empty());
+    }
 }
@@ -0,0 +1,169 @@
+//! Speaking style hints
+//!
+//! The agent picks a `TtsStyle` per response (empathetic when the customer
+//! is upset, upbeat while closing). Each style maps to prosody multipliers,
+//! limited to what the selected engine can actually vary; backends that
+//! can't apply a parameter simply synthesize at their normal prosody.
+
+use serde::{Deserialize, Serialize};
+
+use super::TtsEngine;
+
+/// Speaking style / emotion hint for synthesis
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TtsStyle {
+    /// Plain delivery
+    #[default]
+    Neutral,
+    /// Slower, softer delivery for an upset customer
+    Empathetic,
+    /// Calm, steady delivery for concerns and objections
+    Reassuring,
+    /// Brighter, livelier delivery for closing
+    Upbeat,
+}
+
+impl TtsStyle {
+    /// Style ID as string
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TtsStyle::Neutral => "neutral",
+            TtsStyle::Empathetic => "empathetic",
+            TtsStyle::Reassuring => "reassuring",
+            TtsStyle::Upbeat => "upbeat",
+        }
+    }
+
+    /// Prosody multipliers for this style (1.0 = unchanged)
+    pub fn prosody(&self) -> ProsodyParams {
+        match self {
+            TtsStyle::Neutral => ProsodyParams::default(),
+            TtsStyle::Empathetic => ProsodyParams {
+                rate: 0.9,
+                pitch: 0.95,
+                energy: 0.85,
+            },
+            TtsStyle::Reassuring => ProsodyParams {
+                rate: 0.95,
+                pitch: 0.98,
+                energy: 0.95,
+            },
+            TtsStyle::Upbeat => ProsodyParams {
+                rate: 1.05,
+                pitch: 1.08,
+                energy: 1.15,
+            },
+        }
+    }
+}
+
+/// Prosody multipliers applied on top of the configured voice
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ProsodyParams {
+    /// Speaking rate
+    pub rate: f32,
+    /// Pitch
+    pub pitch: f32,
+    /// Energy (loudness/intensity)
+    pub energy: f32,
+}
+
+impl Default for ProsodyParams {
+    fn default() -> Self {
+        Self {
+            rate: 1.0,
+            pitch: 1.0,
+            energy: 1.0,
+        }
+    }
+}
+
+impl ProsodyParams {
+    /// Reset parameters the backend can't vary to 1.0
+    pub fn limit_to(self, support: ProsodySupport) -> Self {
+        Self {
+            rate: if support.rate { self.rate } else { 1.0 },
+            pitch: if support.pitch { self.pitch } else { 1.0 },
+            energy: if support.energy { self.energy } else { 1.0 },
+        }
+    }
+
+    /// Whether every parameter is unchanged
+    pub fn is_neutral(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+/// Prosody parameters a backend can vary
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ProsodySupport {
+    /// Speaking rate can be varied
+    pub rate: bool,
+    /// Pitch can be varied
+    pub pitch: bool,
+    /// Energy can be varied
+    pub energy: bool,
+}
+
+impl ProsodySupport {
+    /// No prosody control
+    pub const NONE: Self = Self {
+        rate: false,
+        pitch: false,
+        energy: false,
+    };
+
+    /// Full prosody control
+    pub const ALL: Self = Self {
+        rate: true,
+        pitch: true,
+        energy: true,
+    };
+}
+
+impl TtsEngine {
+    /// Prosody parameters the engine can vary
+    ///
+    /// Piper scales phoneme length, IndicF5 controls duration, and Parler is
+    /// prompted with a voice description covering all three.
+    pub fn prosody_support(&self) -> ProsodySupport {
+        match self {
+            TtsEngine::Piper | TtsEngine::IndicF5 => ProsodySupport {
+                rate: true,
+                ..ProsodySupport::NONE
+            },
+            TtsEngine::ParlerTts => ProsodySupport::ALL,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_style_prosody() {
+        assert!(TtsStyle::Neutral.prosody().is_neutral());
+
+        let empathetic = TtsStyle::Empathetic.prosody();
+        assert!(empathetic.rate < 1.0 && empathetic.energy < 1.0);
+
+        let upbeat = TtsStyle::Upbeat.prosody();
+        assert!(upbeat.rate > 1.0 && upbeat.pitch > 1.0);
+    }
+
+    #[test]
+    fn test_prosody_limited_to_engine() {
+        let upbeat = TtsStyle::Upbeat.prosody();
+
+        let piper = upbeat.limit_to(TtsEngine::Piper.prosody_support());
+        assert_eq!(piper.rate, upbeat.rate);
+        assert_eq!(piper.pitch, 1.0);
+        assert_eq!(piper.energy, 1.0);
+
+        let parler = upbeat.limit_to(TtsEngine::ParlerTts.prosody_support());
+        assert_eq!(parler, upbeat);
+        assert!(upbeat.limit_to(ProsodySupport::NONE).is_neutral());
+    }
+}
@@ -147,7 +147,19 @@ impl HybridTurnDetector {
         transcript: Option<&str>,
     ) -> Result<TurnDetectionResult, PipelineError> {
         // P1 FIX: Get timestamp before acquiring lock to avoid syscall while holding mutex
-        let now = Instant::now();
+        self.process_at(vad_state, transcript, Instant::now())
+    }
+
+    /// Process VAD result for audio captured at `now`
+    ///
+    /// Silence and speech durations are measured between these timestamps,
+    /// so replayed audio (e.g. from a file) is timed as if it were live.
+    pub fn process_at(
+        &self,
+        vad_state: VadState,
+        transcript: Option<&str>,
+        now: Instant,
+    ) -> Result<TurnDetectionResult, PipelineError> {
         let mut internal = self.internal.lock();
 
         // Update transcript if provided
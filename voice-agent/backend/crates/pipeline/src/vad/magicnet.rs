@@ -49,14 +49,18 @@ pub struct VadConfig {
     pub gru_hidden_size: usize,
     /// Energy floor in dB for quick silence detection
     pub energy_floor_db: f32,
+    /// Audio kept before speech onset and handed to STT with the utterance (ms)
+    pub pre_roll_ms: u32,
+    /// Extra silence after speech offset before declaring silence (ms)
+    pub hangover_ms: u32,
 }
 
 impl Default for VadConfig {
     fn default() -> Self {
         // P2-5 FIX: Use centralized audio constants
         use voice_agent_config::constants::audio::{
-            FRAME_MS, SAMPLE_RATE, VAD_ENERGY_FLOOR_DB, VAD_MIN_SILENCE_FRAMES,
-            VAD_MIN_SPEECH_FRAMES, VAD_THRESHOLD,
+            FRAME_MS, SAMPLE_RATE, VAD_ENERGY_FLOOR_DB, VAD_HANGOVER_MS, VAD_MIN_SILENCE_FRAMES,
+            VAD_MIN_SPEECH_FRAMES, VAD_PRE_ROLL_MS, VAD_THRESHOLD,
         };
 
         Self {
@@ -68,10 +72,19 @@ impl Default for VadConfig {
             sample_rate: SAMPLE_RATE,
             gru_hidden_size: 64,
             energy_floor_db: VAD_ENERGY_FLOOR_DB,
+            pre_roll_ms: VAD_PRE_ROLL_MS,
+            hangover_ms: VAD_HANGOVER_MS,
         }
     }
 }
 
+impl VadConfig {
+    /// Silence frames needed to declare speech end, including hangover
+    pub fn silence_frames_to_end(&self) -> usize {
+        self.min_silence_frames + self.hangover_ms.div_ceil(self.frame_ms.max(1)) as usize
+    }
+}
+
 /// VAD state machine states
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum VadState {
@@ -342,7 +355,7 @@ impl VoiceActivityDetector {
 
             (VadState::SpeechEnd, false) => {
                 state.silence_frames += 1;
-                if state.silence_frames >= self.config.min_silence_frames {
+                if state.silence_frames >= self.config.silence_frames_to_end() {
                     state.state = VadState::Silence;
                     state.speech_frames = 0;
                     state.silence_frames = 0;
@@ -646,6 +659,35 @@ mod tests {
         assert_eq!(config.frame_ms, 10);
     }
 
+    #[cfg(not(feature = "onnx"))]
+    #[test]
+    fn test_hangover_delays_silence() {
+        use voice_agent_core::{Channels, SampleRate};
+
+        let config = VadConfig {
+            min_speech_frames: 1,
+            min_silence_frames: 2,
+            hangover_ms: 30,
+            ..Default::default()
+        };
+        assert_eq!(config.silence_frames_to_end(), 5);
+        let vad = VoiceActivityDetector::simple(config).unwrap();
+
+        let mut speech = AudioFrame::new(vec![0.5; 160], SampleRate::Hz16000, Channels::Mono, 0);
+        vad.process_frame(&mut speech).unwrap();
+        vad.process_frame(&mut speech).unwrap();
+        assert_eq!(vad.state(), VadState::Speech);
+
+        let mut silence = AudioFrame::new(vec![0.0; 160], SampleRate::Hz16000, Channels::Mono, 0);
+        for _ in 0..4 {
+            let (state, _, _) = vad.process_frame(&mut silence).unwrap();
+            assert_eq!(state, VadState::SpeechEnd);
+        }
+        let (state, _, result) = vad.process_frame(&mut silence).unwrap();
+        assert_eq!(state, VadState::Silence);
+        assert_eq!(result, VadResult::SpeechEnd);
+    }
+
     #[cfg(feature = "onnx")]
     #[test]
     fn test_mel_filterbank() {
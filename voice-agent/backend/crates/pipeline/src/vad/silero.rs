@@ -34,6 +34,8 @@ pub struct SileroConfig {
     pub min_silence_frames: usize,
     /// Energy floor in dB for quick silence detection
     pub energy_floor_db: f32,
+    /// Extra silence after speech offset before declaring silence (ms)
+    pub hangover_ms: u32,
 }
 
 impl Default for SileroConfig {
@@ -45,10 +47,19 @@ impl Default for SileroConfig {
             min_speech_frames: 8,   // ~256ms
             min_silence_frames: 10, // ~320ms
             energy_floor_db: -50.0,
+            hangover_ms: 0,
         }
     }
 }
 
+impl SileroConfig {
+    /// Silence chunks needed to declare speech end, including hangover
+    pub fn silence_frames_to_end(&self) -> usize {
+        let chunk_ms = (self.chunk_size as u32 * 1000 / self.sample_rate.max(1)).max(1);
+        self.min_silence_frames + self.hangover_ms.div_ceil(chunk_ms) as usize
+    }
+}
+
 impl From<SileroConfig> for VadConfig {
     fn from(config: SileroConfig) -> Self {
         VadConfig {
@@ -60,6 +71,8 @@ impl From<SileroConfig> for VadConfig {
             sample_rate: config.sample_rate,
             gru_hidden_size: 64, // Silero uses 64-dim LSTM states
             energy_floor_db: config.energy_floor_db,
+            pre_roll_ms: 0, // Buffered by the pipeline, not the VAD
+            hangover_ms: config.hangover_ms,
         }
     }
 }
@@ -348,7 +361,7 @@ impl SileroVad {
 
             (VadState::SpeechEnd, false) => {
                 state.silence_frames += 1;
-                if state.silence_frames >= self.config.min_silence_frames {
+                if state.silence_frames >= self.config.silence_frames_to_end() {
                     state.state = VadState::Silence;
                     state.speech_frames = 0;
                     state.silence_frames = 0;
@@ -13,8 +13,9 @@ use voice_agent_core::{
 
 use crate::{
     // P2-1 FIX: Use QueryContext (renamed from ConversationContext)
-    agentic::QueryContext as RagContext, AgenticRetriever, DomainBoostConfig, DomainBooster,
-    HybridRetriever, QueryExpander, QueryExpansionConfig, SearchResult, VectorStore,
+    agentic::QueryContext as RagContext, AgenticRetriever, BoostResult, DomainBoostConfig,
+    DomainBooster, HybridRetriever, QueryExpander, QueryExpansionConfig, SearchResult,
+    VectorStoreBackend,
 };
 
 /// Enhanced retriever implementing the core Retriever trait
@@ -30,7 +31,7 @@ pub struct EnhancedRetriever {
     /// Optional agentic retriever for multi-step
     agentic: Option<Arc<AgenticRetriever>>,
     /// Vector store for search
-    vector_store: Arc<VectorStore>,
+    vector_store: Arc<dyn VectorStoreBackend>,
     /// Query expander
     expander: QueryExpander,
     /// Domain booster
@@ -85,7 +86,7 @@ impl EnhancedRetriever {
     /// behavior from config.
     pub fn new(
         hybrid: Arc<HybridRetriever>,
-        vector_store: Arc<VectorStore>,
+        vector_store: Arc<dyn VectorStoreBackend>,
         config: EnhancedRetrieverConfig,
     ) -> Self {
         Self {
@@ -105,7 +106,7 @@ impl EnhancedRetriever {
     /// expander and booster.
     pub fn with_domain_components(
         hybrid: Arc<HybridRetriever>,
-        vector_store: Arc<VectorStore>,
+        vector_store: Arc<dyn VectorStoreBackend>,
         config: EnhancedRetrieverConfig,
         expander: QueryExpander,
         booster: DomainBooster,
@@ -156,7 +157,13 @@ impl EnhancedRetriever {
         }
 
         let boost_result = self.booster.boost(query);
+        Self::apply_boost_result(results, &boost_result);
+    }
 
+    /// Multiply each result's score by the boosts of matched terms it contains
+    ///
+    /// Results carrying a `ScoreExplanation` record the boost and terms applied.
+    fn apply_boost_result(results: &mut [SearchResult], boost_result: &BoostResult) {
         for result in results.iter_mut() {
             let doc_lower = result.content.to_lowercase();
             let mut doc_boost = 1.0f32;
@@ -164,10 +171,17 @@ impl EnhancedRetriever {
             for matched in &boost_result.matched_terms {
                 if doc_lower.contains(&matched.term.to_lowercase()) {
                     doc_boost *= matched.boost;
+                    if let Some(ref mut explanation) = result.explanation {
+                        explanation.boosted_terms.push(matched.clone());
+                    }
                 }
             }
 
             result.score *= doc_boost;
+            if let Some(ref mut explanation) = result.explanation {
+                explanation.domain_boost *= doc_boost;
+                explanation.final_score = result.score;
+            }
         }
 
         // Re-sort after boosting
@@ -186,6 +200,13 @@ impl EnhancedRetriever {
             doc = doc.with_metadata("exit_layer", layer as i64);
         }
 
+        if let Some(explanation) = result.explanation {
+            match serde_json::to_value(&explanation) {
+                Ok(value) => doc = doc.with_metadata("explain", value),
+                Err(e) => tracing::warn!("Failed to serialize score explanation: {}", e),
+            }
+        }
+
         doc = doc.with_metadata("source", format!("{:?}", result.source));
         doc
     }
@@ -232,15 +253,20 @@ impl EnhancedRetriever {
 #[async_trait]
 impl Retriever for EnhancedRetriever {
     async fn retrieve(&self, query: &str, options: &RetrieveOptions) -> Result<Vec<Document>> {
-        // Check prefetch cache first
-        if self.config.prefetch_enabled {
+        // Check prefetch cache first (prefetched results carry no explanations)
+        if self.config.prefetch_enabled && !options.explain {
             if let Some(cached) = self.check_prefetch_cache(query) {
-                tracing::debug!("Using prefetch cache for query: {}", query);
-                return Ok(cached
+                let cached: Vec<Document> = options
+                    .apply_filters(cached)
                     .into_iter()
                     .filter(|d| d.score >= options.min_score)
                     .take(options.top_k)
-                    .collect());
+                    .collect();
+                // Fall through to a full search if filters removed every prefetched doc
+                if !cached.is_empty() {
+                    tracing::debug!("Using prefetch cache for query: {}", query);
+                    return Ok(cached);
+                }
             }
         }
 
@@ -248,30 +274,38 @@ impl Retriever for EnhancedRetriever {
         let processed_query = self.process_query(query);
 
         // Perform hybrid search
-        let mut results = self
-            .hybrid
-            .search(&processed_query, &self.vector_store, None)
-            .await
-            .map_err(|e| {
-                voice_agent_core::Error::Rag(format!(
-                    "hybrid search failed (query='{}'): {}",
-                    query.chars().take(50).collect::<String>(),
-                    e
-                ))
-            })?;
+        let search = if options.explain {
+            self.hybrid
+                .search_explained(&processed_query, &self.vector_store, None)
+                .await
+        } else {
+            self.hybrid
+                .search(&processed_query, &self.vector_store, None)
+                .await
+        };
+        let mut results = search.map_err(|e| {
+            voice_agent_core::Error::Rag(format!(
+                "hybrid search failed (query='{}'): {}",
+                query.chars().take(50).collect::<String>(),
+                e
+            ))
+        })?;
 
         // Apply domain boosting
         self.apply_boosting(&mut results, query);
 
-        // Convert and filter
+        // Convert, apply metadata filters (e.g. language), then truncate
         let documents: Vec<Document> = results
             .into_iter()
             .filter(|r| r.score >= options.min_score)
-            .take(options.top_k)
             .map(Self::to_document)
             .collect();
 
-        Ok(documents)
+        Ok(options
+            .apply_filters(documents)
+            .into_iter()
+            .take(options.top_k)
+            .collect())
     }
 
     async fn retrieve_agentic(
@@ -345,9 +379,7 @@ impl Retriever for EnhancedRetriever {
                 Ok(results) => {
                     let documents: Vec<Document> = results
                         .into_iter()
-                        .map(|r| {
-                            Document::new(r.id, r.content, r.score).with_metadata("prefetch", true)
-                        })
+                        .map(|r| Self::to_document(r).with_metadata("prefetch", true))
                         .collect();
 
                     *cache_clone.lock() = Some(PrefetchResult {
@@ -433,6 +465,7 @@ mod tests {
                 .collect(),
             source: SearchSource::Hybrid,
             exit_layer: Some(3),
+            explanation: None,
         };
 
         let doc = EnhancedRetriever::to_document(result);
@@ -441,6 +474,122 @@ mod tests {
         assert!(doc.metadata.contains_key("category"));
     }
 
+    #[test]
+    fn test_language_filter_on_ingested_docs() {
+        use crate::retriever::RetrieverConfig;
+        use crate::vector_store::Document as StoreDocument;
+        use crate::{RerankerConfig, SparseConfig, SparseIndex};
+        use voice_agent_core::RetrieveOptions;
+
+        let doc = |id: &str, content: &str, language: &str| StoreDocument {
+            id: id.to_string(),
+            content: content.to_string(),
+            title: None,
+            category: Some("faq".to_string()),
+            language: Some(language.to_string()),
+            metadata: HashMap::new(),
+        };
+
+        let index = SparseIndex::new(SparseConfig::default()).unwrap();
+        index
+            .index_documents(&[
+                doc("en-1", "gold loan interest rate is low", "en"),
+                doc("en-2", "gold loan processing fee details", "en"),
+                doc("hi-1", "gold loan ka interest rate kam hai", "HI"),
+            ])
+            .unwrap();
+
+        let hybrid = HybridRetriever::new(RetrieverConfig::default(), RerankerConfig::default())
+            .with_sparse_index(Arc::new(index));
+        let documents: Vec<Document> = hybrid
+            .search_sparse("gold loan interest")
+            .unwrap()
+            .into_iter()
+            .map(EnhancedRetriever::to_document)
+            .collect();
+        assert_eq!(documents.len(), 3);
+
+        // Strict filter returns only Hindi chunks
+        let strict = RetrieveOptions::default().with_language("hi");
+        let filtered = strict.apply_filters(documents.clone());
+        assert_eq!(filtered.len(), 1);
+        assert_eq!(filtered[0].id, "hi-1");
+
+        // Fallback tops up with other languages when too few match
+        let fallback = RetrieveOptions::default()
+            .with_language("hi")
+            .with_language_fallback(2);
+        let filtered = fallback.apply_filters(documents);
+        assert_eq!(filtered.len(), 2);
+        assert_eq!(filtered[0].id, "hi-1");
+        assert!(filtered[1].id.starts_with("en-"));
+    }
+
+    #[test]
+    fn test_boost_explanation() {
+        use crate::retriever::{ScoreExplanation, SearchSource};
+        use crate::{MatchedTerm, TermCategory};
+
+        let result = |id: &str, content: &str, score: f32| SearchResult {
+            id: id.to_string(),
+            content: content.to_string(),
+            score,
+            metadata: HashMap::new(),
+            source: SearchSource::Hybrid,
+            exit_layer: None,
+            explanation: Some(ScoreExplanation {
+                rrf_score: score,
+                final_score: score,
+                ..Default::default()
+            }),
+        };
+        let mut results = vec![
+            result("plain", "Branch opening hours", 0.5),
+            result("boosted", "Current interest rate for gold loan", 0.4),
+        ];
+
+        let boost = BoostResult {
+            matched_terms: vec![MatchedTerm {
+                term: "interest rate".to_string(),
+                position: 0,
+                boost: 1.5,
+                category: TermCategory::Rate,
+                category_weight: 1.0,
+            }],
+            total_boost: 1.5,
+            categories: vec![TermCategory::Rate],
+            intent: None,
+        };
+        EnhancedRetriever::apply_boost_result(&mut results, &boost);
+
+        // Boost re-orders the results and is reflected in the explanation
+        assert_eq!(results[0].id, "boosted");
+        let exp = results[0].explanation.as_ref().unwrap();
+        assert!((exp.domain_boost - 1.5).abs() < 1e-6);
+        assert_eq!(exp.boosted_terms.len(), 1);
+        assert!((exp.final_score - exp.rrf_score * exp.domain_boost).abs() < 1e-6);
+        assert_eq!(exp.final_score, results[0].score);
+
+        let plain = results[1].explanation.as_ref().unwrap();
+        assert_eq!(plain.domain_boost, 1.0);
+        assert!(plain.boosted_terms.is_empty());
+
+        // Explanation is surfaced in document metadata
+        let doc = EnhancedRetriever::to_document(results.remove(0));
+        let explain = doc.metadata.get("explain").unwrap();
+        for key in [
+            "dense_score",
+            "sparse_score",
+            "rrf_score",
+            "rerank_score",
+            "domain_boost",
+            "boosted_terms",
+            "final_score",
+        ] {
+            assert!(explain.get(key).is_some(), "missing {}", key);
+        }
+    }
+
     #[test]
     fn test_rag_context_conversion() {
         let mut ctx = CoreContext::default();
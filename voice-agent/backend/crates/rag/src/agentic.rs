@@ -11,11 +11,15 @@
 //! This module was added to implement the multi-step retrieval flow
 //! that was previously marked as "NOT IMPLEMENTED" in the RAG plan.
 
+use std::future::Future;
 use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use async_trait::async_trait;
 
 use crate::{
-    query_expansion::{QueryExpander, QueryExpansionConfig},
-    HybridRetriever, RagError, RerankerConfig, RetrieverConfig, SearchResult, VectorStore,
+    query_expansion::{QueryExpander, QueryExpansionConfig, TermSource},
+    HybridRetriever, RagError, RerankerConfig, RetrieverConfig, SearchResult, VectorStoreBackend,
 };
 
 use voice_agent_llm::{LlmBackend, Message, Role};
@@ -46,12 +50,27 @@ pub struct AgenticRagConfig {
     pub llm_query_rewriting: bool,
 
     /// Enable LLM-based sufficiency checking (disable for small models)
-    /// When false, heuristic scoring is used.
+    /// When false (or no LLM is set), `RuleBasedSufficiencyChecker` is used.
     pub llm_sufficiency_check: bool,
 
     /// Enable rule-based query expansion (always recommended)
     /// Uses domain synonyms, Hindi transliteration, and term expansion.
     pub use_rule_based_expansion: bool,
+
+    /// Attach score explanations to results (debugging only)
+    pub explain: bool,
+
+    /// Wall-clock budget for the whole agentic loop (None = unbounded)
+    ///
+    /// Once exceeded, the loop stops regardless of sufficiency and returns
+    /// the best results found so far.
+    pub max_duration: Option<Duration>,
+
+    /// LLM token budget for the whole agentic loop (None = unbounded)
+    ///
+    /// Counts prompt and generated tokens of query rewriting and LLM
+    /// sufficiency checks.
+    pub token_budget: Option<usize>,
 }
 
 impl Default for AgenticRagConfig {
@@ -70,6 +89,9 @@ impl Default for AgenticRagConfig {
             llm_sufficiency_check: true,
             // Rule-based expansion always enabled
             use_rule_based_expansion: true,
+            explain: false,
+            max_duration: None,
+            token_budget: None,
         }
     }
 }
@@ -79,7 +101,7 @@ impl AgenticRagConfig {
     ///
     /// Disables LLM-based operations for lower latency:
     /// - No LLM query rewriting (uses rule-based expansion only)
-    /// - No LLM sufficiency checking (uses heuristic scoring)
+    /// - No LLM sufficiency checking (uses rule-based scoring)
     /// - Single-shot retrieval (max_iterations = 0)
     pub fn for_small_model() -> Self {
         use voice_agent_config::constants::rag;
@@ -95,6 +117,9 @@ impl AgenticRagConfig {
             llm_sufficiency_check: false,
             // Keep rule-based expansion
             use_rule_based_expansion: true,
+            explain: false,
+            max_duration: None,
+            token_budget: None,
         }
     }
 
@@ -139,6 +164,46 @@ pub struct AgenticSearchResult {
     pub final_query: String,
     /// Sufficiency score of final results
     pub sufficiency_score: f32,
+    /// Sufficiency evaluation of final results, including why the loop stopped
+    pub evaluation: SufficiencyEvaluation,
+}
+
+/// Wall-clock and token spend of one agentic search against the configured budgets
+struct LoopBudget {
+    started: Instant,
+    max_duration: Option<Duration>,
+    token_budget: Option<usize>,
+    tokens_used: usize,
+}
+
+impl LoopBudget {
+    fn new(config: &AgenticRagConfig) -> Self {
+        Self {
+            started: Instant::now(),
+            max_duration: config.max_duration,
+            token_budget: config.token_budget,
+            tokens_used: 0,
+        }
+    }
+
+    fn charge(&mut self, tokens: usize) {
+        self.tokens_used += tokens;
+    }
+
+    /// Returns the budget that has run out, if any
+    fn exceeded(&self) -> Option<StopReason> {
+        if let Some(max_duration) = self.max_duration {
+            if self.started.elapsed() >= max_duration {
+                return Some(StopReason::TimeBudget);
+            }
+        }
+        if let Some(token_budget) = self.token_budget {
+            if self.tokens_used >= token_budget {
+                return Some(StopReason::TokenBudget);
+            }
+        }
+        None
+    }
 }
 
 /// Agentic retriever with multi-step refinement
@@ -146,12 +211,15 @@ pub struct AgenticSearchResult {
 /// Supports two modes based on configuration:
 /// 1. **Large Model Mode**: Full iterative refinement with LLM query rewriting
 /// 2. **Small Model Mode**: Single-shot retrieval with rule-based expansion only
+#[derive(Clone)]
 pub struct AgenticRetriever {
     config: AgenticRagConfig,
-    retriever: HybridRetriever,
+    /// Shared so the models loaded (and warmed up) once serve every session
+    retriever: Arc<HybridRetriever>,
     query_rewriter: Option<QueryRewriter>,
-    query_expander: QueryExpander,
-    sufficiency_checker: SufficiencyChecker,
+    query_expander: Arc<QueryExpander>,
+    /// Explicitly configured checker (LLM or custom); rule-based when None
+    sufficiency_checker: Option<Arc<dyn SufficiencyChecker>>,
 }
 
 impl AgenticRetriever {
@@ -164,18 +232,13 @@ impl AgenticRetriever {
             RetrieverConfig {
                 dense_top_k: config.initial_top_k,
                 final_top_k: config.final_top_k,
+                explain: config.explain,
                 ..RetrieverConfig::default()
             },
             RerankerConfig::default(),
         );
 
-        Self {
-            config,
-            retriever,
-            query_rewriter: None,
-            query_expander: QueryExpander::new(QueryExpansionConfig::default()),
-            sufficiency_checker: SufficiencyChecker::new(),
-        }
+        Self::with_retriever(config, retriever)
     }
 
     /// Create with custom retriever
@@ -185,17 +248,36 @@ impl AgenticRetriever {
     pub fn with_retriever(config: AgenticRagConfig, retriever: HybridRetriever) -> Self {
         Self {
             config,
-            retriever,
+            retriever: Arc::new(retriever),
             query_rewriter: None,
-            query_expander: QueryExpander::new(QueryExpansionConfig::default()),
-            sufficiency_checker: SufficiencyChecker::new(),
+            query_expander: Arc::new(QueryExpander::new(QueryExpansionConfig::default())),
+            sufficiency_checker: None,
         }
     }
 
+    /// Search with a retriever shared with other sessions
+    ///
+    /// Its own config (top-k, reranking) replaces the one derived from
+    /// this retriever's `AgenticRagConfig`.
+    pub fn with_shared_retriever(mut self, retriever: Arc<HybridRetriever>) -> Self {
+        self.retriever = retriever;
+        self
+    }
+
     /// Set LLM for query rewriting (only used if llm_query_rewriting is enabled)
     ///
+    /// Also used for sufficiency checking if llm_sufficiency_check is enabled.
     /// Uses generic domain context. For domain-specific prompts, use `with_llm_and_domain()`.
     pub fn with_llm(mut self, llm: Arc<dyn LlmBackend>) -> Self {
+        if self.config.llm_sufficiency_check {
+            self.sufficiency_checker = Some(Arc::new(LlmSufficiencyChecker::with_config(
+                Some(llm.clone()),
+                LlmSufficiencyConfig {
+                    min_coverage: self.config.sufficiency_threshold,
+                    ..LlmSufficiencyConfig::default()
+                },
+            )));
+        }
         // Only set query rewriter if LLM rewriting is enabled
         if self.config.llm_query_rewriting {
             self.query_rewriter = Some(QueryRewriter::new(llm));
@@ -212,6 +294,12 @@ impl AgenticRetriever {
         product_name: &str,
         company_name: &str,
     ) -> Self {
+        if self.config.llm_sufficiency_check {
+            self.sufficiency_checker = Some(Arc::new(LlmSufficiencyChecker::with_domain_context(
+                llm.clone(),
+                product_name,
+            )));
+        }
         // Only set query rewriter if LLM rewriting is enabled
         if self.config.llm_query_rewriting {
             self.query_rewriter = Some(QueryRewriter::with_domain_context(
@@ -225,10 +313,27 @@ impl AgenticRetriever {
 
     /// Set a custom query expander
     pub fn with_query_expander(mut self, expander: QueryExpander) -> Self {
-        self.query_expander = expander;
+        self.query_expander = Arc::new(expander);
+        self
+    }
+
+    /// Set a custom sufficiency checker
+    pub fn with_sufficiency_checker(mut self, checker: Arc<dyn SufficiencyChecker>) -> Self {
+        self.sufficiency_checker = Some(checker);
         self
     }
 
+    /// Configured sufficiency checker, or the rule-based one when no LLM is configured
+    fn sufficiency_checker(&self) -> Arc<dyn SufficiencyChecker> {
+        match &self.sufficiency_checker {
+            Some(checker) => checker.clone(),
+            None => Arc::new(
+                RuleBasedSufficiencyChecker::new(self.query_expander.clone())
+                    .with_sufficiency_threshold(self.config.sufficiency_threshold),
+            ),
+        }
+    }
+
     /// Multi-step retrieval with configurable complexity
     ///
     /// This implements the agentic RAG flow:
@@ -237,14 +342,14 @@ impl AgenticRetriever {
     /// 3. Check sufficiency of results
     /// 4. If insufficient and LLM rewriting enabled, rewrite query
     /// 5. Re-retrieve with rewritten query
-    /// 6. Repeat up to max_iterations
-    /// 7. Return final results
+    /// 6. Repeat up to max_iterations or until a budget is exhausted
+    /// 7. Return the best results seen
     ///
     /// For small models, steps 4-6 are skipped (single-shot retrieval).
     pub async fn search(
         &self,
         query: &str,
-        vector_store: &VectorStore,
+        vector_store: &dyn VectorStoreBackend,
         context: Option<&QueryContext>,
     ) -> Result<AgenticSearchResult, RagError> {
         // Step 1: Apply rule-based query expansion if enabled
@@ -268,22 +373,13 @@ impl AgenticRetriever {
 
         // Fast path: single-shot if agentic disabled
         if !self.config.enabled {
-            let results = self.retriever.search(&search_query, vector_store, None).await?;
-            return Ok(AgenticSearchResult {
-                sufficiency_score: self.sufficiency_checker.score(&results, query),
-                results,
-                iterations: 1,
-                query_rewritten: false,
-                final_query: search_query,
-            });
+            let results = self
+                .retriever
+                .search(&search_query, vector_store, None)
+                .await?;
+            return self.single_shot(query, search_query, results).await;
         }
 
-        // Step 2: Initial retrieval with expanded query
-        let mut results = self.retriever.search(&search_query, vector_store, None).await?;
-        let mut current_query = search_query;
-        let mut iterations = 1;
-        let mut query_rewritten = false;
-
         // Fast path for single-shot mode (small models)
         // Skip LLM iterations if llm_query_rewriting is disabled
         if !self.config.llm_query_rewriting || self.config.max_iterations == 0 {
@@ -292,91 +388,164 @@ impl AgenticRetriever {
                 max_iterations = self.config.max_iterations,
                 "Single-shot retrieval mode (LLM rewriting disabled)"
             );
-            let score = self.sufficiency_checker.score(&results, &current_query);
-            return Ok(AgenticSearchResult {
-                results,
-                iterations: 1,
-                query_rewritten: false,
-                final_query: current_query,
-                sufficiency_score: score,
-            });
+            let results = self
+                .retriever
+                .search(&search_query, vector_store, None)
+                .await?;
+            let query = search_query.clone();
+            return self.single_shot(&query, search_query, results).await;
         }
 
-        // Step 3-6: Iterative refinement (only for large models with LLM rewriting)
-        for iteration in 0..self.config.max_iterations {
-            // Check sufficiency
-            let score = self.sufficiency_checker.score(&results, &current_query);
+        // Step 2-6: Iterative refinement (only for large models with LLM rewriting)
+        self.refine(query, search_query, context, move |q: String| async move {
+            self.retriever.search(&q, vector_store, None).await
+        })
+        .await
+    }
+
+    /// Evaluate single-shot results without any refinement
+    async fn single_shot(
+        &self,
+        query: &str,
+        final_query: String,
+        results: Vec<SearchResult>,
+    ) -> Result<AgenticSearchResult, RagError> {
+        let mut evaluation = self.sufficiency_checker().evaluate(query, &results).await?;
+        evaluation.stop_reason = Some(StopReason::SingleShot);
+
+        Ok(AgenticSearchResult {
+            sufficiency_score: evaluation.coverage,
+            results,
+            iterations: 1,
+            query_rewritten: false,
+            final_query,
+            evaluation,
+        })
+    }
 
-            if score >= self.config.sufficiency_threshold {
+    /// Iterative retrieve/evaluate/rewrite loop
+    ///
+    /// Stops when results are sufficient, `max_iterations` rewrites were made,
+    /// rewriting can't improve the query, or a budget is exhausted. Always
+    /// returns the best-scoring results seen, so an early stop never discards
+    /// a good earlier iteration. Sufficiency is judged against the user's
+    /// query (or its rewrite), not the weighted expansion sent to search.
+    async fn refine<F, Fut>(
+        &self,
+        query: &str,
+        search_query: String,
+        context: Option<&QueryContext>,
+        retrieve: F,
+    ) -> Result<AgenticSearchResult, RagError>
+    where
+        F: Fn(String) -> Fut,
+        Fut: Future<Output = Result<Vec<SearchResult>, RagError>>,
+    {
+        let mut budget = LoopBudget::new(&self.config);
+        let checker = self.sufficiency_checker();
+        let default_ctx = QueryContext::default();
+        let ctx = context.unwrap_or(&default_ctx);
+
+        let mut current_query = search_query.clone();
+        let mut results = retrieve(current_query.clone()).await?;
+        let mut evaluation = checker.evaluate(query, &results).await?;
+        budget.charge(evaluation.tokens_used);
+
+        let mut best = (results.clone(), current_query.clone(), evaluation.clone());
+        let mut rewrites = 0;
+
+        let stop_reason = loop {
+            if evaluation.sufficient {
                 tracing::debug!(
-                    iteration = iteration + 1,
-                    score,
+                    iteration = rewrites + 1,
+                    score = evaluation.coverage,
                     "Sufficiency threshold met, stopping iteration"
                 );
-                return Ok(AgenticSearchResult {
-                    results,
-                    iterations,
-                    query_rewritten,
-                    final_query: current_query,
-                    sufficiency_score: score,
-                });
+                break StopReason::Sufficient;
+            }
+            if rewrites >= self.config.max_iterations {
+                break StopReason::MaxIterations;
+            }
+            if let Some(reason) = budget.exceeded() {
+                break reason;
             }
 
             // Rewrite query if we have a rewriter and LLM rewriting is enabled
-            if let Some(ref rewriter) = self.query_rewriter {
-                let default_ctx = QueryContext::default();
-                let ctx = context.unwrap_or(&default_ctx);
-
-                match rewriter.rewrite(&current_query, &results, ctx).await {
-                    Ok(new_query) => {
-                        if new_query != current_query && !new_query.is_empty() {
-                            tracing::debug!(
-                                iteration = iteration + 1,
-                                old_query = %current_query,
-                                new_query = %new_query,
-                                "Query rewritten by LLM"
-                            );
-
-                            current_query = new_query;
-                            query_rewritten = true;
-
-                            // Re-retrieve with new query
-                            results = self
-                                .retriever
-                                .search(&current_query, vector_store, None)
-                                .await?;
-                            iterations += 1;
-                        } else {
-                            tracing::debug!(
-                                iteration = iteration + 1,
-                                "Query rewriter returned same/empty query, stopping"
-                            );
-                            break;
-                        }
-                    },
-                    Err(e) => {
-                        tracing::warn!(
-                            iteration = iteration + 1,
-                            error = %e,
-                            "Query rewriting failed, using current results"
-                        );
-                        break;
-                    },
-                }
-            } else {
-                tracing::debug!("No query rewriter available, using single-shot results");
-                break; // No rewriter, can't improve
+            let rewriter = match &self.query_rewriter {
+                Some(rewriter) => rewriter,
+                None => {
+                    tracing::debug!("No query rewriter available, using single-shot results");
+                    break StopReason::NoImprovement; // No rewriter, can't improve
+                },
+            };
+
+            let new_query = match rewriter
+                .rewrite_with_usage(&current_query, &results, ctx)
+                .await
+            {
+                Ok((new_query, tokens)) => {
+                    budget.charge(tokens);
+                    new_query
+                },
+                Err(e) => {
+                    tracing::warn!(
+                        iteration = rewrites + 1,
+                        error = %e,
+                        "Query rewriting failed, using current results"
+                    );
+                    break StopReason::NoImprovement;
+                },
+            };
+
+            if new_query == current_query || new_query.is_empty() {
+                tracing::debug!(
+                    iteration = rewrites + 1,
+                    "Query rewriter returned same/empty query, stopping"
+                );
+                break StopReason::NoImprovement;
+            }
+            if let Some(reason) = budget.exceeded() {
+                break reason;
+            }
+
+            tracing::debug!(
+                iteration = rewrites + 1,
+                old_query = %current_query,
+                new_query = %new_query,
+                "Query rewritten by LLM"
+            );
+            current_query = new_query;
+            rewrites += 1;
+
+            // Re-retrieve with new query
+            results = retrieve(current_query.clone()).await?;
+            evaluation = checker.evaluate(&current_query, &results).await?;
+            budget.charge(evaluation.tokens_used);
+
+            if evaluation.sufficient || evaluation.coverage > best.2.coverage {
+                best = (results.clone(), current_query.clone(), evaluation.clone());
             }
+        };
+
+        if stop_reason.is_budget() {
+            tracing::debug!(
+                reason = ?stop_reason,
+                elapsed_ms = budget.started.elapsed().as_millis() as u64,
+                tokens_used = budget.tokens_used,
+                "Agentic retrieval budget exhausted, returning best results so far"
+            );
         }
 
-        // Return final results
-        let final_score = self.sufficiency_checker.score(&results, &current_query);
+        let (results, final_query, mut evaluation) = best;
+        evaluation.stop_reason = Some(stop_reason);
+
         Ok(AgenticSearchResult {
             results,
-            iterations,
-            query_rewritten,
-            final_query: current_query,
-            sufficiency_score: final_score,
+            iterations: rewrites + 1,
+            query_rewritten: final_query != search_query,
+            final_query,
+            sufficiency_score: evaluation.coverage,
+            evaluation,
         })
     }
 
@@ -416,15 +585,31 @@ impl AgenticRetriever {
     }
 }
 
-/// Checks if retrieved results are sufficient to answer the query
-pub struct SufficiencyChecker {
+/// Decides whether retrieved results are sufficient to answer a query
+///
+/// Used by `AgenticRetriever` to decide whether another retrieval iteration
+/// is worthwhile.
+#[async_trait]
+pub trait SufficiencyChecker: Send + Sync {
+    /// Evaluate how well `results` cover `query`
+    async fn evaluate(
+        &self,
+        query: &str,
+        results: &[SearchResult],
+    ) -> Result<SufficiencyEvaluation, RagError>;
+}
+
+/// Checks if retrieved results are sufficient using retrieval scores only
+pub struct HeuristicSufficiencyChecker {
     /// Minimum number of results for sufficiency
     min_results: usize,
     /// Minimum average score for sufficiency
     min_avg_score: f32,
+    /// Score at or above which results are considered sufficient
+    sufficiency_threshold: f32,
 }
 
-impl SufficiencyChecker {
+impl HeuristicSufficiencyChecker {
     /// Create a new sufficiency checker
     /// P6 FIX: Use centralized constants for consistency
     pub fn new() -> Self {
@@ -433,6 +618,7 @@ impl SufficiencyChecker {
         Self {
             min_results: 1,
             min_avg_score: rag::SUFFICIENCY_MIN_AVG_SCORE as f32,
+            sufficiency_threshold: rag::SUFFICIENCY_THRESHOLD as f32,
         }
     }
 
@@ -441,9 +627,16 @@ impl SufficiencyChecker {
         Self {
             min_results,
             min_avg_score,
+            ..Self::new()
         }
     }
 
+    /// Set the score at or above which results are considered sufficient
+    pub fn with_sufficiency_threshold(mut self, threshold: f32) -> Self {
+        self.sufficiency_threshold = threshold;
+        self
+    }
+
     /// Score the sufficiency of results for a query
     ///
     /// Returns a score between 0.0 and 1.0:
@@ -486,12 +679,201 @@ impl SufficiencyChecker {
     }
 }
 
-impl Default for SufficiencyChecker {
+impl Default for HeuristicSufficiencyChecker {
     fn default() -> Self {
         Self::new()
     }
 }
 
+#[async_trait]
+impl SufficiencyChecker for HeuristicSufficiencyChecker {
+    async fn evaluate(
+        &self,
+        query: &str,
+        results: &[SearchResult],
+    ) -> Result<SufficiencyEvaluation, RagError> {
+        let coverage = self.score(results, query);
+        Ok(SufficiencyEvaluation {
+            sufficient: coverage >= self.sufficiency_threshold,
+            coverage,
+            confidence: 0.6,
+            ..Default::default()
+        })
+    }
+}
+
+/// Weight of the retrieval score component in the rule-based score
+const RULE_SCORE_WEIGHT: f32 = 0.5;
+/// Weight of the result count component in the rule-based score
+const RULE_COUNT_WEIGHT: f32 = 0.2;
+/// Weight of the key-term coverage component in the rule-based score
+const RULE_TERM_WEIGHT: f32 = 0.3;
+
+/// LLM-free sufficiency checker for small-model deployments
+///
+/// Combines retrieval score coverage, result count and key-term coverage.
+/// Key terms are the query's non-stopword terms; a term is covered when it,
+/// or one of its expansions (synonyms, transliterations, glossary), appears
+/// in the retrieved text.
+pub struct RuleBasedSufficiencyChecker {
+    expander: Arc<QueryExpander>,
+    score_checker: HeuristicSufficiencyChecker,
+    /// Minimum number of results for sufficiency
+    min_results: usize,
+    /// Minimum fraction of key terms that must appear in retrieved text
+    min_term_coverage: f32,
+    /// Number of top results searched for key terms
+    term_search_depth: usize,
+    /// Combined score at or above which results are considered sufficient
+    sufficiency_threshold: f32,
+}
+
+impl RuleBasedSufficiencyChecker {
+    /// Create a checker using `expander` to find key terms and their expansions
+    pub fn new(expander: Arc<QueryExpander>) -> Self {
+        use voice_agent_config::constants::rag;
+
+        Self {
+            expander,
+            score_checker: HeuristicSufficiencyChecker::new(),
+            min_results: 2,
+            min_term_coverage: 1.0,
+            term_search_depth: 5,
+            sufficiency_threshold: rag::SUFFICIENCY_THRESHOLD as f32,
+        }
+    }
+
+    /// Set the minimum number of results for sufficiency
+    pub fn with_min_results(mut self, min_results: usize) -> Self {
+        self.min_results = min_results.max(1);
+        self
+    }
+
+    /// Set the minimum fraction of key terms that must be present (0.0-1.0)
+    pub fn with_min_term_coverage(mut self, coverage: f32) -> Self {
+        self.min_term_coverage = coverage.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Set the combined score at or above which results are considered sufficient
+    pub fn with_sufficiency_threshold(mut self, threshold: f32) -> Self {
+        self.sufficiency_threshold = threshold;
+        self
+    }
+
+    /// Fraction of key terms present in the top results, plus the missing terms
+    fn term_coverage(&self, query: &str, results: &[SearchResult]) -> (f32, Vec<String>) {
+        let text = results
+            .iter()
+            .take(self.term_search_depth)
+            .map(|r| r.content.to_lowercase())
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        let mut key_terms: Vec<String> = Vec::new();
+        for term in self.expander.expand(query).terms {
+            if term.source != TermSource::Original {
+                continue;
+            }
+            let word = term.term.trim_matches(|c: char| c.is_ascii_punctuation());
+            if word.chars().count() < 2 || self.expander.is_stopword(word) {
+                continue;
+            }
+            if !key_terms.iter().any(|t| t == word) {
+                key_terms.push(word.to_string());
+            }
+        }
+
+        if key_terms.is_empty() {
+            return (1.0, Vec::new());
+        }
+
+        let missing: Vec<String> = key_terms
+            .iter()
+            .filter(|term| {
+                !self
+                    .expander
+                    .expand(term)
+                    .terms
+                    .iter()
+                    .any(|alt| text.contains(alt.term.as_str()))
+            })
+            .cloned()
+            .collect();
+
+        let covered = key_terms.len() - missing.len();
+        (covered as f32 / key_terms.len() as f32, missing)
+    }
+}
+
+#[async_trait]
+impl SufficiencyChecker for RuleBasedSufficiencyChecker {
+    async fn evaluate(
+        &self,
+        query: &str,
+        results: &[SearchResult],
+    ) -> Result<SufficiencyEvaluation, RagError> {
+        if results.is_empty() {
+            return Ok(SufficiencyEvaluation {
+                missing: Some("No documents retrieved".to_string()),
+                confidence: 1.0,
+                method: "rule_based".to_string(),
+                reason: Some("no results".to_string()),
+                ..Default::default()
+            });
+        }
+
+        let score_coverage = self.score_checker.score(results, query);
+        let count_factor = (results.len() as f32 / self.min_results as f32).min(1.0);
+        let (term_coverage, missing_terms) = self.term_coverage(query, results);
+
+        let coverage = RULE_SCORE_WEIGHT * score_coverage
+            + RULE_COUNT_WEIGHT * count_factor
+            + RULE_TERM_WEIGHT * term_coverage;
+
+        let mut problems = Vec::new();
+        if results.len() < self.min_results {
+            problems.push(format!(
+                "only {} of {} required results",
+                results.len(),
+                self.min_results
+            ));
+        }
+        if term_coverage < self.min_term_coverage {
+            problems.push(format!("missing key terms: {}", missing_terms.join(", ")));
+        }
+        if coverage < self.sufficiency_threshold {
+            problems.push(format!(
+                "score {:.2} below threshold {:.2}",
+                coverage, self.sufficiency_threshold
+            ));
+        }
+
+        let sufficient = problems.is_empty();
+        let reason = if sufficient {
+            format!(
+                "score {:.2} with {} results and {:.0}% key-term coverage",
+                coverage,
+                results.len(),
+                term_coverage * 100.0
+            )
+        } else {
+            problems.join("; ")
+        };
+
+        Ok(SufficiencyEvaluation {
+            sufficient,
+            coverage,
+            missing: (!missing_terms.is_empty()).then(|| missing_terms.join(", ")),
+            refined_query: None,
+            confidence: 0.7,
+            method: "rule_based".to_string(),
+            reason: Some(reason),
+            ..Default::default()
+        })
+    }
+}
+
 // =============================================================================
 // P1 FIX: LLM-based Sufficiency Checking
 // =============================================================================
@@ -536,8 +918,38 @@ pub struct SufficiencyEvaluation {
     pub refined_query: Option<String>,
     /// Confidence in the evaluation
     pub confidence: f32,
-    /// Method used for evaluation (heuristic or llm)
+    /// Method used for evaluation (heuristic, rule_based or llm)
     pub method: String,
+    /// Human-readable explanation of the decision
+    pub reason: Option<String>,
+    /// LLM tokens (prompt + generated) spent on this evaluation
+    pub tokens_used: usize,
+    /// Why the agentic loop stopped (set on the final evaluation of a search)
+    pub stop_reason: Option<StopReason>,
+}
+
+/// Why the agentic retrieval loop stopped iterating
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StopReason {
+    /// Results met the sufficiency threshold
+    Sufficient,
+    /// Wall-clock budget (`max_duration`) exhausted
+    TimeBudget,
+    /// LLM token budget (`token_budget`) exhausted
+    TokenBudget,
+    /// `max_iterations` query rewrites were performed
+    MaxIterations,
+    /// Query rewriting unavailable, failed, or produced no new query
+    NoImprovement,
+    /// Agentic flow disabled or configured for single-shot retrieval
+    SingleShot,
+}
+
+impl StopReason {
+    /// Whether the loop was cut short by a budget rather than by its results
+    pub fn is_budget(&self) -> bool {
+        matches!(self, Self::TimeBudget | Self::TokenBudget)
+    }
 }
 
 impl Default for SufficiencyEvaluation {
@@ -549,6 +961,9 @@ impl Default for SufficiencyEvaluation {
             refined_query: None,
             confidence: 0.5,
             method: "heuristic".to_string(),
+            reason: None,
+            tokens_used: 0,
+            stop_reason: None,
         }
     }
 }
@@ -562,7 +977,7 @@ impl Default for SufficiencyEvaluation {
 pub struct LlmSufficiencyChecker {
     llm: Option<Arc<dyn LlmBackend>>,
     config: LlmSufficiencyConfig,
-    heuristic_checker: SufficiencyChecker,
+    heuristic_checker: HeuristicSufficiencyChecker,
     /// Product name for domain context in prompts (e.g., "gold loans", "car insurance")
     product_name: String,
 }
@@ -573,7 +988,7 @@ impl LlmSufficiencyChecker {
         Self {
             llm: None,
             config: LlmSufficiencyConfig::default(),
-            heuristic_checker: SufficiencyChecker::new(),
+            heuristic_checker: HeuristicSufficiencyChecker::new(),
             product_name: "loans".to_string(), // Generic default
         }
     }
@@ -583,7 +998,7 @@ impl LlmSufficiencyChecker {
         Self {
             llm: Some(llm),
             config: LlmSufficiencyConfig::default(),
-            heuristic_checker: SufficiencyChecker::new(),
+            heuristic_checker: HeuristicSufficiencyChecker::new(),
             product_name: "loans".to_string(), // Generic default
         }
     }
@@ -593,7 +1008,7 @@ impl LlmSufficiencyChecker {
         Self {
             llm,
             config,
-            heuristic_checker: SufficiencyChecker::new(),
+            heuristic_checker: HeuristicSufficiencyChecker::new(),
             product_name: "loans".to_string(), // Generic default
         }
     }
@@ -606,7 +1021,7 @@ impl LlmSufficiencyChecker {
         Self {
             llm: Some(llm),
             config: LlmSufficiencyConfig::default(),
-            heuristic_checker: SufficiencyChecker::new(),
+            heuristic_checker: HeuristicSufficiencyChecker::new(),
             product_name: product_name.to_string(),
         }
     }
@@ -628,6 +1043,7 @@ impl LlmSufficiencyChecker {
                 refined_query: Some(query.to_string()),
                 confidence: 1.0,
                 method: "empty_check".to_string(),
+                ..Default::default()
             });
         }
 
@@ -645,6 +1061,7 @@ impl LlmSufficiencyChecker {
                     refined_query: None,
                     confidence: 0.6, // Lower confidence for heuristic
                     method: "heuristic".to_string(),
+                    ..Default::default()
                 });
             },
         };
@@ -689,6 +1106,7 @@ JSON response:"#,
             documents = doc_context,
         );
 
+        let prompt_tokens = llm.estimate_tokens(&prompt);
         let messages = vec![Message {
             role: Role::User,
             content: prompt,
@@ -699,11 +1117,13 @@ JSON response:"#,
         // Call LLM for evaluation
         match llm.generate(&messages).await {
             Ok(response) => {
+                let tokens_used = prompt_tokens + response.tokens;
                 // Parse JSON response
                 match self.parse_evaluation_response(&response.text) {
                     Ok(mut eval) => {
                         eval.method = "llm".to_string();
                         eval.confidence = 0.85;
+                        eval.tokens_used = tokens_used;
 
                         // Blend with heuristic if configured
                         if self.config.use_heuristic_fallback {
@@ -722,6 +1142,9 @@ JSON response:"#,
                             refined_query: None,
                             confidence: 0.6,
                             method: "heuristic_fallback".to_string(),
+                            reason: None,
+                            tokens_used,
+                            stop_reason: None,
                         })
                     },
                 }
@@ -735,6 +1158,9 @@ JSON response:"#,
                     refined_query: None,
                     confidence: 0.6,
                     method: "heuristic_fallback".to_string(),
+                    reason: None,
+                    tokens_used: prompt_tokens,
+                    stop_reason: None,
                 })
             },
         }
@@ -776,6 +1202,7 @@ JSON response:"#,
             refined_query: parsed.refined_query,
             confidence: 0.85,
             method: "llm".to_string(),
+            ..Default::default()
         })
     }
 
@@ -801,9 +1228,21 @@ impl Default for LlmSufficiencyChecker {
     }
 }
 
+#[async_trait]
+impl SufficiencyChecker for LlmSufficiencyChecker {
+    async fn evaluate(
+        &self,
+        query: &str,
+        results: &[SearchResult],
+    ) -> Result<SufficiencyEvaluation, RagError> {
+        LlmSufficiencyChecker::evaluate(self, query, results).await
+    }
+}
+
 /// Rewrites queries for better retrieval using LLM
 ///
 /// P24 FIX: Made domain-agnostic with configurable product_name and company_name
+#[derive(Clone)]
 pub struct QueryRewriter {
     llm: Arc<dyn LlmBackend>,
     /// Product name for prompt context (e.g., "gold loan", "car insurance")
@@ -849,6 +1288,18 @@ impl QueryRewriter {
         results: &[SearchResult],
         context: &QueryContext,
     ) -> Result<String, RagError> {
+        self.rewrite_with_usage(query, results, context)
+            .await
+            .map(|(rewritten, _)| rewritten)
+    }
+
+    /// Rewrite a query, also returning the LLM tokens (prompt + generated) spent
+    pub async fn rewrite_with_usage(
+        &self,
+        query: &str,
+        results: &[SearchResult],
+        context: &QueryContext,
+    ) -> Result<(String, usize), RagError> {
         // Build context from results
         let results_text = results
             .iter()
@@ -896,6 +1347,7 @@ If the query is already good, output it unchanged."#,
             context = context_text,
         );
 
+        let prompt_tokens = self.llm.estimate_tokens(&prompt);
         let messages = vec![Message {
             role: Role::User,
             content: prompt,
@@ -910,14 +1362,15 @@ If the query is already good, output it unchanged."#,
             .await
             .map_err(|e| RagError::Search(format!("LLM query rewrite failed: {}", e)))?;
 
+        let tokens_used = prompt_tokens + response.tokens;
         let rewritten = response.text.trim().to_string();
 
         // Validate rewritten query
         if rewritten.is_empty() || rewritten.len() > 500 {
-            return Ok(query.to_string()); // Return original if invalid
+            return Ok((query.to_string(), tokens_used)); // Return original if invalid
         }
 
-        Ok(rewritten)
+        Ok((rewritten, tokens_used))
     }
 
     /// Truncate text to a maximum length at word boundary
@@ -953,13 +1406,13 @@ mod tests {
 
     #[test]
     fn test_sufficiency_checker_empty() {
-        let checker = SufficiencyChecker::new();
+        let checker = HeuristicSufficiencyChecker::new();
         assert_eq!(checker.score(&[], "test query"), 0.0);
     }
 
     #[test]
     fn test_sufficiency_checker_low_scores() {
-        let checker = SufficiencyChecker::new();
+        let checker = HeuristicSufficiencyChecker::new();
         let results = vec![
             create_test_result("1", 0.1),
             create_test_result("2", 0.15),
@@ -971,7 +1424,7 @@ mod tests {
 
     #[test]
     fn test_sufficiency_checker_high_scores() {
-        let checker = SufficiencyChecker::new();
+        let checker = HeuristicSufficiencyChecker::new();
         let results = vec![
             create_test_result("1", 0.9),
             create_test_result("2", 0.85),
@@ -1095,6 +1548,322 @@ mod tests {
         assert!(expanded.terms.len() >= 2); // At least original terms
     }
 
+    // =========================================================================
+    // Budget Tests
+    // =========================================================================
+
+    /// Rewriter backend that returns a fresh query on every call
+    struct CountingLlm {
+        prefix: &'static str,
+        calls: std::sync::atomic::AtomicUsize,
+    }
+
+    impl CountingLlm {
+        fn with_prefix(prefix: &'static str) -> Self {
+            Self {
+                prefix,
+                calls: Default::default(),
+            }
+        }
+    }
+
+    impl Default for CountingLlm {
+        fn default() -> Self {
+            Self::with_prefix("rewritten query")
+        }
+    }
+
+    #[async_trait]
+    impl LlmBackend for CountingLlm {
+        async fn generate(
+            &self,
+            _messages: &[Message],
+        ) -> Result<voice_agent_llm::GenerationResult, voice_agent_llm::LlmError> {
+            let n = self.calls.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            Ok(voice_agent_llm::GenerationResult {
+                text: format!("{} {}", self.prefix, n),
+                tokens: 10,
+                time_to_first_token_ms: 0,
+                total_time_ms: 0,
+                tokens_per_second: 0.0,
+                finish_reason: voice_agent_llm::FinishReason::Stop,
+                context: None,
+            })
+        }
+
+        async fn generate_stream(
+            &self,
+            messages: &[Message],
+            _tx: tokio::sync::mpsc::Sender<String>,
+        ) -> Result<voice_agent_llm::GenerationResult, voice_agent_llm::LlmError> {
+            self.generate(messages).await
+        }
+
+        async fn is_available(&self) -> bool {
+            true
+        }
+
+        fn model_name(&self) -> &str {
+            "counting-mock"
+        }
+    }
+
+    /// Checker that is slow and never satisfied; coverage mirrors the top score
+    struct SlowChecker {
+        delay: Duration,
+    }
+
+    #[async_trait]
+    impl SufficiencyChecker for SlowChecker {
+        async fn evaluate(
+            &self,
+            _query: &str,
+            results: &[SearchResult],
+        ) -> Result<SufficiencyEvaluation, RagError> {
+            tokio::time::sleep(self.delay).await;
+            Ok(SufficiencyEvaluation {
+                coverage: results.first().map(|r| r.score).unwrap_or(0.0),
+                ..Default::default()
+            })
+        }
+    }
+
+    fn budgeted_retriever(config: AgenticRagConfig, delay: Duration) -> AgenticRetriever {
+        AgenticRetriever::new(config)
+            .with_llm(Arc::new(CountingLlm::default()))
+            .with_sufficiency_checker(Arc::new(SlowChecker { delay }))
+    }
+
+    #[tokio::test]
+    async fn test_loop_stops_at_wall_clock_budget() {
+        let budget = Duration::from_millis(120);
+        let delay = Duration::from_millis(40);
+        let config = AgenticRagConfig {
+            max_iterations: 100,
+            max_duration: Some(budget),
+            ..Default::default()
+        };
+        let retriever = budgeted_retriever(config, delay);
+
+        let started = Instant::now();
+        let result = retriever
+            .refine(
+                "gold loan rate",
+                "gold loan rate".to_string(),
+                None,
+                |q: String| async move { Ok(vec![create_test_result(&q, 0.3)]) },
+            )
+            .await
+            .unwrap();
+        let elapsed = started.elapsed();
+
+        assert_eq!(result.evaluation.stop_reason, Some(StopReason::TimeBudget));
+        assert!(result.evaluation.stop_reason.unwrap().is_budget());
+        assert!(result.iterations < 100);
+        // At most one in-flight evaluation may overrun the budget
+        assert!(elapsed >= budget);
+        assert!(elapsed < budget + delay * 2, "took {:?}", elapsed);
+    }
+
+    #[tokio::test]
+    async fn test_loop_stops_at_token_budget_with_best_results() {
+        let config = AgenticRagConfig {
+            max_iterations: 10,
+            token_budget: Some(1),
+            ..Default::default()
+        };
+        let retriever = budgeted_retriever(config, Duration::ZERO);
+
+        let result = retriever
+            .refine(
+                "gold loan rate",
+                "gold loan rate".to_string(),
+                None,
+                |q: String| async move { Ok(vec![create_test_result(&q, 0.4)]) },
+            )
+            .await
+            .unwrap();
+
+        // First rewrite exhausts the budget before re-retrieval
+        assert_eq!(result.evaluation.stop_reason, Some(StopReason::TokenBudget));
+        assert_eq!(result.iterations, 1);
+        assert!(!result.query_rewritten);
+        assert_eq!(result.final_query, "gold loan rate");
+        assert_eq!(result.results[0].id, "gold loan rate");
+    }
+
+    #[tokio::test]
+    async fn test_loop_stops_at_max_iterations() {
+        let config = AgenticRagConfig {
+            max_iterations: 2,
+            ..Default::default()
+        };
+        let retriever = budgeted_retriever(config, Duration::ZERO);
+
+        let result = retriever
+            .refine(
+                "gold loan rate",
+                "gold loan rate".to_string(),
+                None,
+                |q: String| async move {
+                    // Rewritten queries score higher than the original
+                    let score = if q.starts_with("rewritten") { 0.5 } else { 0.2 };
+                    Ok(vec![create_test_result(&q, score)])
+                },
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(
+            result.evaluation.stop_reason,
+            Some(StopReason::MaxIterations)
+        );
+        assert_eq!(result.iterations, 3);
+        assert!(result.query_rewritten);
+        assert!((result.sufficiency_score - 0.5).abs() < f32::EPSILON);
+    }
+
+    #[tokio::test]
+    async fn test_loop_stops_when_sufficient() {
+        let retriever = AgenticRetriever::new(AgenticRagConfig::default())
+            .with_llm(Arc::new(CountingLlm::default()));
+
+        let result = retriever
+            .refine(
+                "gold loan rate",
+                "gold loan rate".to_string(),
+                None,
+                |q: String| async move {
+                    Ok(vec![
+                        create_test_result(&q, 0.9),
+                        create_test_result("2", 0.88),
+                        create_test_result("3", 0.85),
+                    ])
+                },
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(result.evaluation.stop_reason, Some(StopReason::Sufficient));
+        assert_eq!(result.iterations, 1);
+    }
+
+    // =========================================================================
+    // Rule-Based Sufficiency Tests
+    // =========================================================================
+
+    fn result_with_content(id: &str, score: f32, content: &str) -> SearchResult {
+        SearchResult {
+            content: content.to_string(),
+            ..create_test_result(id, score)
+        }
+    }
+
+    fn rule_based_checker() -> RuleBasedSufficiencyChecker {
+        let expander = QueryExpander::new(QueryExpansionConfig::default());
+        expander.add_stopwords(&["what", "is", "the"]);
+        expander.add_synonym("rate", &["interest"]);
+        RuleBasedSufficiencyChecker::new(Arc::new(expander))
+    }
+
+    #[tokio::test]
+    async fn test_rule_based_high_coverage_is_sufficient() {
+        let checker = rule_based_checker();
+        let results = vec![
+            result_with_content("1", 0.9, "Gold loan interest starts at 9.5% per annum"),
+            result_with_content("2", 0.85, "Eligibility for a gold loan"),
+            result_with_content("3", 0.88, "Gold loan tenure options"),
+        ];
+
+        let eval = checker
+            .evaluate("What is the gold loan rate?", &results)
+            .await
+            .unwrap();
+
+        // "rate" is covered by its synonym "interest"
+        assert!(eval.sufficient, "{:?}", eval.reason);
+        assert!(eval.coverage > 0.9);
+        assert_eq!(eval.method, "rule_based");
+        assert!(eval.missing.is_none());
+        assert!(eval.reason.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_rule_based_sparse_results_are_insufficient() {
+        let checker = rule_based_checker();
+        let results = vec![result_with_content(
+            "1",
+            0.9,
+            "Gold loan eligibility criteria",
+        )];
+
+        let eval = checker
+            .evaluate("gold loan foreclosure charges", &results)
+            .await
+            .unwrap();
+
+        assert!(!eval.sufficient);
+        assert_eq!(eval.missing.as_deref(), Some("foreclosure, charges"));
+        let reason = eval.reason.unwrap();
+        assert!(reason.contains("only 1 of 2"));
+        assert!(reason.contains("missing key terms"));
+    }
+
+    #[tokio::test]
+    async fn test_rule_based_empty_results() {
+        let eval = rule_based_checker()
+            .evaluate("gold loan", &[])
+            .await
+            .unwrap();
+        assert!(!eval.sufficient);
+        assert_eq!(eval.coverage, 0.0);
+    }
+
+    #[tokio::test]
+    async fn test_rule_based_checker_used_without_llm() {
+        let retriever = AgenticRetriever::new(AgenticRagConfig::for_small_model());
+        let eval = retriever
+            .sufficiency_checker()
+            .evaluate("gold loan", &[create_test_result("1", 0.9)])
+            .await
+            .unwrap();
+        assert_eq!(eval.method, "rule_based");
+    }
+
+    #[tokio::test]
+    async fn test_low_coverage_triggers_another_iteration() {
+        let config = AgenticRagConfig {
+            llm_sufficiency_check: false,
+            ..Default::default()
+        };
+        let llm = Arc::new(CountingLlm::with_prefix("gold loan foreclosure charges"));
+        let retriever = AgenticRetriever::new(config).with_llm(llm);
+
+        let result = retriever
+            .refine(
+                "gold loan foreclosure",
+                "gold loan foreclosure".to_string(),
+                None,
+                |q: String| async move {
+                    if q.contains("charges") {
+                        Ok(vec![
+                            result_with_content("a", 0.9, "Gold loan foreclosure charges are nil"),
+                            result_with_content("b", 0.88, "Foreclosure charges on a gold loan"),
+                        ])
+                    } else {
+                        Ok(vec![result_with_content("x", 0.2, "Gold loan eligibility")])
+                    }
+                },
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(result.iterations, 2);
+        assert!(result.query_rewritten);
+        assert_eq!(result.results[0].id, "a");
+        assert_eq!(result.evaluation.stop_reason, Some(StopReason::Sufficient));
+    }
+
     fn create_test_result(id: &str, score: f32) -> SearchResult {
         SearchResult {
             id: id.to_string(),
@@ -1103,6 +1872,7 @@ mod tests {
             metadata: std::collections::HashMap::new(),
             source: crate::retriever::SearchSource::Dense,
             exit_layer: None,
+            explanation: None,
         }
     }
 }
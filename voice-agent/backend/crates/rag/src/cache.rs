@@ -8,6 +8,9 @@ use std::collections::HashMap;
 use std::hash::{Hash, Hasher};
 use std::sync::atomic::{AtomicU64, Ordering};
 
+use crate::embeddings::TextEmbedder;
+use crate::RagError;
+
 /// Cache statistics
 #[derive(Debug, Default)]
 pub struct CacheStats {
@@ -312,10 +315,9 @@ impl<E> CachedEmbedder<E> {
     }
 }
 
-// Implement for ONNX Embedder
-impl CachedEmbedder<super::embeddings::Embedder> {
+impl<E: TextEmbedder> CachedEmbedder<E> {
     /// Embed with caching
-    pub fn embed(&self, text: &str) -> Result<Vec<f32>, super::RagError> {
+    pub fn embed(&self, text: &str) -> Result<Vec<f32>, RagError> {
         // Check cache first
         if let Some(embedding) = self.cache.get(text) {
             return Ok(embedding);
@@ -331,7 +333,10 @@ impl CachedEmbedder<super::embeddings::Embedder> {
     }
 
     /// Embed batch with caching
-    pub fn embed_batch(&self, texts: &[&str]) -> Result<Vec<Vec<f32>>, super::RagError> {
+    ///
+    /// Only cache misses reach the inner embedder, in one batch; results
+    /// keep the input order.
+    pub fn embed_batch(&self, texts: &[&str]) -> Result<Vec<Vec<f32>>, RagError> {
         let mut results = Vec::with_capacity(texts.len());
         let mut uncached_texts = Vec::new();
         let mut uncached_indices = Vec::new();
@@ -350,6 +355,13 @@ impl CachedEmbedder<super::embeddings::Embedder> {
         // Compute uncached embeddings
         if !uncached_texts.is_empty() {
             let uncached_embeddings = self.embedder.embed_batch(&uncached_texts)?;
+            if uncached_embeddings.len() != uncached_texts.len() {
+                return Err(RagError::Embedding(format!(
+                    "Embedder returned {} embeddings for {} texts",
+                    uncached_embeddings.len(),
+                    uncached_texts.len()
+                )));
+            }
 
             // Insert into cache and results
             for (idx, embedding) in uncached_indices.into_iter().zip(uncached_embeddings) {
@@ -358,8 +370,8 @@ impl CachedEmbedder<super::embeddings::Embedder> {
             }
         }
 
-        // Unwrap all results (all should be Some now)
-        Ok(results.into_iter().map(|r| r.unwrap()).collect())
+        // All slots are filled: hits above, misses from the batch
+        Ok(results.into_iter().flatten().collect())
     }
 
     /// Get embedding dimension
@@ -368,51 +380,17 @@ impl CachedEmbedder<super::embeddings::Embedder> {
     }
 }
 
-// Implement for Candle Embedder
-#[cfg(feature = "candle")]
-impl CachedEmbedder<super::candle_embeddings::CandleBertEmbedder> {
-    /// Embed with caching
-    pub fn embed(&self, text: &str) -> Result<Vec<f32>, super::RagError> {
-        if let Some(embedding) = self.cache.get(text) {
-            return Ok(embedding);
-        }
-
-        let embedding = self.embedder.embed(text)?;
-        self.cache.insert(text, embedding.clone());
-        Ok(embedding)
+impl<E: TextEmbedder> TextEmbedder for CachedEmbedder<E> {
+    fn embed_batch(&self, texts: &[&str]) -> Result<Vec<Vec<f32>>, RagError> {
+        CachedEmbedder::embed_batch(self, texts)
     }
 
-    /// Embed batch with caching
-    pub fn embed_batch(&self, texts: &[&str]) -> Result<Vec<Vec<f32>>, super::RagError> {
-        let mut results = Vec::with_capacity(texts.len());
-        let mut uncached_texts = Vec::new();
-        let mut uncached_indices = Vec::new();
-
-        for (i, text) in texts.iter().enumerate() {
-            if let Some(embedding) = self.cache.get(text) {
-                results.push(Some(embedding));
-            } else {
-                results.push(None);
-                uncached_texts.push(*text);
-                uncached_indices.push(i);
-            }
-        }
-
-        if !uncached_texts.is_empty() {
-            let uncached_embeddings = self.embedder.embed_batch(&uncached_texts)?;
-
-            for (idx, embedding) in uncached_indices.into_iter().zip(uncached_embeddings) {
-                self.cache.insert(texts[idx], embedding.clone());
-                results[idx] = Some(embedding);
-            }
-        }
-
-        Ok(results.into_iter().map(|r| r.unwrap()).collect())
+    fn embed(&self, text: &str) -> Result<Vec<f32>, RagError> {
+        CachedEmbedder::embed(self, text)
     }
 
-    /// Get embedding dimension
-    pub fn dim(&self) -> usize {
-        self.embedder.dim()
+    fn dim(&self) -> usize {
+        CachedEmbedder::dim(self)
     }
 }
 
@@ -497,6 +475,46 @@ mod tests {
         assert!(cache.get("d").is_some());
     }
 
+    /// Embedder recording which texts reach the model
+    struct CountingEmbedder {
+        calls: parking_lot::Mutex<Vec<Vec<String>>>,
+    }
+
+    impl TextEmbedder for CountingEmbedder {
+        fn embed_batch(&self, texts: &[&str]) -> Result<Vec<Vec<f32>>, RagError> {
+            self.calls
+                .lock()
+                .push(texts.iter().map(|t| t.to_string()).collect());
+            Ok(texts.iter().map(|t| vec![t.len() as f32]).collect())
+        }
+
+        fn dim(&self) -> usize {
+            1
+        }
+    }
+
+    #[test]
+    fn test_cached_batch_forwards_only_misses() {
+        let embedder = CachedEmbedder::new(
+            CountingEmbedder {
+                calls: Default::default(),
+            },
+            100,
+        );
+        embedder.embed("gold").unwrap();
+
+        let embeddings = embedder.embed_batch(&["loan", "gold", "interest"]).unwrap();
+
+        // Output aligned with input, hits included
+        assert_eq!(embeddings, vec![vec![4.0], vec![4.0], vec![8.0]]);
+        let calls = embedder.inner().calls.lock().clone();
+        assert_eq!(calls, vec![vec!["gold"], vec!["loan", "interest"]]);
+
+        // Everything cached now: nothing forwarded
+        embedder.embed_batch(&["interest", "loan"]).unwrap();
+        assert_eq!(embedder.inner().calls.lock().len(), 2);
+    }
+
     #[test]
     fn test_cache_clear() {
         let cache = EmbeddingCache::new(100);
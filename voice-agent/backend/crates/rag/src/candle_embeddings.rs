@@ -9,6 +9,7 @@
 //! - SafeTensors weight loading
 //! - Mean pooling for sentence embeddings
 //! - Support for multilingual models (e5-small, mBERT, etc.)
+//! - Automatic quantization selection from available memory
 
 #[cfg(feature = "candle")]
 use candle_core::{DType, Device, Result as CandleResult, Tensor, D};
@@ -21,11 +22,29 @@ use tokenizers::Tokenizer;
 
 #[cfg(feature = "candle")]
 use std::path::Path;
+#[cfg(feature = "candle")]
+use std::sync::atomic::{AtomicBool, Ordering};
+#[cfg(feature = "candle")]
+use std::sync::Arc;
+use std::time::Duration;
+#[cfg(feature = "candle")]
+use std::time::Instant;
+
+use voice_agent_llm::ConcurrencyLimitConfig;
 
+use crate::embeddings::TextEmbedder;
+#[cfg(feature = "candle")]
+use crate::limiter::EmbeddingLimiter;
 use crate::RagError;
 
+/// Text embedded by `warmup()`
+pub const WARMUP_TEXT: &str = "query: gold loan interest rate";
+
+/// Memory needed on top of the raw weights (activations, allocator slack)
+const AUTO_MEMORY_HEADROOM: f64 = 1.5;
+
 /// Quantization mode for inference
-#[derive(Debug, Clone, Copy, Default)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
 pub enum QuantizationMode {
     /// Full precision (FP32) - most accurate, slowest
     #[default]
@@ -34,14 +53,21 @@ pub enum QuantizationMode {
     F16,
     /// Brain float (BF16) - good for training, slightly less accurate than F16
     BF16,
+    /// Most precise mode that fits in available memory, resolved at load time
+    Auto,
 }
 
 impl QuantizationMode {
+    /// Modes `Auto` chooses from, most precise first
+    const AUTO_LADDER: [QuantizationMode; 2] = [QuantizationMode::F32, QuantizationMode::F16];
+
     /// Get the Candle DType for this quantization mode
+    ///
+    /// An unresolved `Auto` loads as F32.
     #[cfg(feature = "candle")]
     pub fn to_dtype(&self) -> DType {
         match self {
-            QuantizationMode::F32 => DType::F32,
+            QuantizationMode::F32 | QuantizationMode::Auto => DType::F32,
             QuantizationMode::F16 => DType::F16,
             QuantizationMode::BF16 => DType::BF16,
         }
@@ -50,7 +76,7 @@ impl QuantizationMode {
     /// Memory reduction factor compared to F32
     pub fn memory_factor(&self) -> f32 {
         match self {
-            QuantizationMode::F32 => 1.0,
+            QuantizationMode::F32 | QuantizationMode::Auto => 1.0,
             QuantizationMode::F16 | QuantizationMode::BF16 => 0.5,
         }
     }
@@ -58,13 +84,85 @@ impl QuantizationMode {
     /// Approximate speedup factor on CPU (varies by hardware)
     pub fn cpu_speedup(&self) -> f32 {
         match self {
-            QuantizationMode::F32 => 1.0,
+            QuantizationMode::F32 | QuantizationMode::Auto => 1.0,
             // FP16 on CPU can be slower due to lack of native support
             // But memory bandwidth reduction can help
             QuantizationMode::F16 => 1.2,
             QuantizationMode::BF16 => 1.1,
         }
     }
+
+    /// Modes to try loading, in order
+    ///
+    /// An explicit mode is tried alone; BF16 becomes F16 where the hardware
+    /// lacks BF16 support. `Auto` starts at the most precise mode whose
+    /// weights fit in `available_bytes` with headroom and keeps the lighter
+    /// modes as fallbacks should allocation still fail. With unknown memory
+    /// every mode is tried, most precise first.
+    ///
+    /// `model_bytes` is the size of the FP32 weights.
+    pub fn candidates(
+        &self,
+        model_bytes: u64,
+        available_bytes: Option<u64>,
+        bf16_supported: bool,
+    ) -> Vec<QuantizationMode> {
+        match self {
+            QuantizationMode::BF16 if !bf16_supported => {
+                tracing::warn!("BF16 not supported on this device, using F16");
+                vec![QuantizationMode::F16]
+            },
+            QuantizationMode::Auto => {
+                let fits = |mode: &QuantizationMode| match available_bytes {
+                    Some(available) => {
+                        let needed =
+                            model_bytes as f64 * mode.memory_factor() as f64 * AUTO_MEMORY_HEADROOM;
+                        needed <= available as f64
+                    },
+                    None => true,
+                };
+                let start = Self::AUTO_LADDER
+                    .iter()
+                    .position(fits)
+                    .unwrap_or(Self::AUTO_LADDER.len() - 1);
+                Self::AUTO_LADDER[start..].to_vec()
+            },
+            mode => vec![*mode],
+        }
+    }
+}
+
+/// Memory available to new allocations, from `/proc/meminfo` (Linux only)
+pub fn available_memory_bytes() -> Option<u64> {
+    let meminfo = std::fs::read_to_string("/proc/meminfo").ok()?;
+    meminfo
+        .lines()
+        .find_map(|line| line.strip_prefix("MemAvailable:"))
+        .and_then(|rest| rest.trim_end_matches("kB").trim().parse::<u64>().ok())
+        .map(|kb| kb * 1024)
+}
+
+/// Try each mode in turn until `load` succeeds
+///
+/// Returns the loaded value with the mode that worked, or the last error.
+pub fn load_with_fallback<T, F>(
+    candidates: &[QuantizationMode],
+    mut load: F,
+) -> Result<(T, QuantizationMode), RagError>
+where
+    F: FnMut(QuantizationMode) -> Result<T, RagError>,
+{
+    let mut last_error = RagError::Model("No quantization mode to try".to_string());
+    for &mode in candidates {
+        match load(mode) {
+            Ok(value) => return Ok((value, mode)),
+            Err(e) => {
+                tracing::warn!(mode = ?mode, error = %e, "Model load failed, trying lighter mode");
+                last_error = e;
+            },
+        }
+    }
+    Err(last_error)
 }
 
 /// Configuration for Candle BERT embedder
@@ -84,6 +182,8 @@ pub struct CandleEmbeddingConfig {
     pub device: DeviceConfig,
     /// Quantization mode for weights and activations
     pub quantization: QuantizationMode,
+    /// Cap on concurrent embedding calls
+    pub concurrency: ConcurrencyLimitConfig,
 }
 
 /// Pooling strategy for sentence embeddings
@@ -119,6 +219,7 @@ impl Default for CandleEmbeddingConfig {
             pooling: PoolingStrategy::Mean,
             device: DeviceConfig::Cpu,
             quantization: QuantizationMode::F32,
+            concurrency: ConcurrencyLimitConfig::default(),
         }
     }
 }
@@ -134,6 +235,7 @@ impl CandleEmbeddingConfig {
             pooling: PoolingStrategy::Mean,
             device: DeviceConfig::Cpu,
             quantization: QuantizationMode::F32,
+            concurrency: ConcurrencyLimitConfig::default(),
         }
     }
 
@@ -147,6 +249,7 @@ impl CandleEmbeddingConfig {
             pooling: PoolingStrategy::Mean,
             device: DeviceConfig::Cpu,
             quantization: QuantizationMode::F16,
+            concurrency: ConcurrencyLimitConfig::default(),
         }
     }
 
@@ -160,6 +263,7 @@ impl CandleEmbeddingConfig {
             pooling: PoolingStrategy::Mean,
             device: DeviceConfig::Cpu,
             quantization: QuantizationMode::F32,
+            concurrency: ConcurrencyLimitConfig::default(),
         }
     }
 
@@ -173,6 +277,7 @@ impl CandleEmbeddingConfig {
             pooling: PoolingStrategy::Mean,
             device: DeviceConfig::Cpu,
             quantization: QuantizationMode::F16,
+            concurrency: ConcurrencyLimitConfig::default(),
         }
     }
 
@@ -187,6 +292,26 @@ impl CandleEmbeddingConfig {
         self.quantization = QuantizationMode::BF16;
         self
     }
+
+    /// Pick the quantization mode from available memory at load time
+    pub fn with_auto_quantization(mut self) -> Self {
+        self.quantization = QuantizationMode::Auto;
+        self
+    }
+
+    /// Set the concurrent embedding call limit
+    pub fn with_concurrency(mut self, concurrency: ConcurrencyLimitConfig) -> Self {
+        self.concurrency = concurrency;
+        self
+    }
+}
+
+/// Embedding limits from the `rag.embedding_concurrency` config section
+impl From<&voice_agent_config::RagConfig> for CandleEmbeddingConfig {
+    fn from(config: &voice_agent_config::RagConfig) -> Self {
+        let concurrency = ConcurrencyLimitConfig::from(&config.embedding_concurrency);
+        Self::default().with_concurrency(concurrency)
+    }
 }
 
 /// Candle BERT Embedder
@@ -198,6 +323,10 @@ pub struct CandleBertEmbedder {
     tokenizer: Tokenizer,
     config: CandleEmbeddingConfig,
     device: Device,
+    /// Set once `warmup()` has run a forward pass
+    warm: AtomicBool,
+    /// Shared with every embedder on the same device
+    limiter: Arc<EmbeddingLimiter>,
 }
 
 #[cfg(feature = "candle")]
@@ -207,7 +336,7 @@ impl CandleBertEmbedder {
         model_path: P,
         config_path: P,
         tokenizer_path: P,
-        embed_config: CandleEmbeddingConfig,
+        mut embed_config: CandleEmbeddingConfig,
     ) -> Result<Self, RagError> {
         let device = match embed_config.device {
             DeviceConfig::Cpu => Device::Cpu,
@@ -217,23 +346,19 @@ impl CandleBertEmbedder {
                 .map_err(|e| RagError::Model(format!("Failed to create Metal device: {}", e)))?,
         };
 
-        // Get dtype from quantization config
-        let dtype = embed_config.quantization.to_dtype();
-
         // Load BERT config
         let config_data = std::fs::read_to_string(config_path.as_ref())
             .map_err(|e| RagError::Model(format!("Failed to read config: {}", e)))?;
         let bert_config: BertConfig = serde_json::from_str(&config_data)
             .map_err(|e| RagError::Model(format!("Failed to parse config: {}", e)))?;
 
-        // Load model weights with specified dtype
-        let vb = unsafe {
-            VarBuilder::from_mmaped_safetensors(&[model_path.as_ref()], dtype, &device)
-                .map_err(|e| RagError::Model(format!("Failed to load weights: {}", e)))?
-        };
-
-        let model = BertModel::load(vb, &bert_config)
-            .map_err(|e| RagError::Model(format!("Failed to load BERT model: {}", e)))?;
+        // Load model weights with the configured (or auto-selected) dtype
+        let model = Self::load_model(
+            model_path.as_ref(),
+            &bert_config,
+            &mut embed_config,
+            &device,
+        )?;
 
         // Load tokenizer
         let tokenizer = Tokenizer::from_file(tokenizer_path.as_ref())
@@ -248,13 +373,21 @@ impl CandleBertEmbedder {
         Ok(Self {
             model,
             tokenizer,
+            limiter: EmbeddingLimiter::shared(
+                &format!("{:?}", embed_config.device),
+                embed_config.concurrency.clone(),
+            ),
             config: embed_config,
             device,
+            warm: AtomicBool::new(false),
         })
     }
 
     /// Load from HuggingFace Hub
-    pub fn from_hub(repo_id: &str, embed_config: CandleEmbeddingConfig) -> Result<Self, RagError> {
+    pub fn from_hub(
+        repo_id: &str,
+        mut embed_config: CandleEmbeddingConfig,
+    ) -> Result<Self, RagError> {
         use hf_hub::{api::sync::Api, Repo, RepoType};
 
         let device = match embed_config.device {
@@ -265,9 +398,6 @@ impl CandleBertEmbedder {
                 .map_err(|e| RagError::Model(format!("Failed to create Metal device: {}", e)))?,
         };
 
-        // Get dtype from quantization config
-        let dtype = embed_config.quantization.to_dtype();
-
         let api = Api::new().map_err(|e| RagError::Model(e.to_string()))?;
         let repo = api.repo(Repo::new(repo_id.to_string(), RepoType::Model));
 
@@ -288,14 +418,8 @@ impl CandleBertEmbedder {
         let bert_config: BertConfig = serde_json::from_str(&config_data)
             .map_err(|e| RagError::Model(format!("Failed to parse config: {}", e)))?;
 
-        // Load model with specified dtype
-        let vb = unsafe {
-            VarBuilder::from_mmaped_safetensors(&[weights_path.as_path()], dtype, &device)
-                .map_err(|e| RagError::Model(format!("Failed to load weights: {}", e)))?
-        };
-
-        let model = BertModel::load(vb, &bert_config)
-            .map_err(|e| RagError::Model(format!("Failed to load BERT model: {}", e)))?;
+        // Load model with the configured (or auto-selected) dtype
+        let model = Self::load_model(&weights_path, &bert_config, &mut embed_config, &device)?;
 
         // Load tokenizer
         let tokenizer = Tokenizer::from_file(&tokenizer_path)
@@ -309,11 +433,65 @@ impl CandleBertEmbedder {
         Ok(Self {
             model,
             tokenizer,
+            limiter: EmbeddingLimiter::shared(
+                &format!("{:?}", embed_config.device),
+                embed_config.concurrency.clone(),
+            ),
             config: embed_config,
             device,
+            warm: AtomicBool::new(false),
         })
     }
 
+    /// Load weights, resolving `QuantizationMode::Auto` and BF16 support
+    ///
+    /// The mode that loaded is written back to `embed_config`.
+    fn load_model(
+        weights_path: &Path,
+        bert_config: &BertConfig,
+        embed_config: &mut CandleEmbeddingConfig,
+        device: &Device,
+    ) -> Result<BertModel, RagError> {
+        // Checkpoints are stored as FP32; GPU memory isn't probed
+        let model_bytes = std::fs::metadata(weights_path)
+            .map(|m| m.len())
+            .unwrap_or(0);
+        let available = match device {
+            Device::Cpu => available_memory_bytes(),
+            _ => None,
+        };
+        let candidates =
+            embed_config
+                .quantization
+                .candidates(model_bytes, available, device.supports_bf16());
+
+        let (model, mode) = load_with_fallback(&candidates, |mode| {
+            let vb = unsafe {
+                VarBuilder::from_mmaped_safetensors(&[weights_path], mode.to_dtype(), device)
+                    .map_err(|e| RagError::Model(format!("Failed to load weights: {}", e)))?
+            };
+            BertModel::load(vb, bert_config)
+                .map_err(|e| RagError::Model(format!("Failed to load BERT model: {}", e)))
+        })?;
+
+        if mode != embed_config.quantization {
+            tracing::info!(
+                requested = ?embed_config.quantization,
+                selected = ?mode,
+                available_bytes = ?available,
+                model_bytes = model_bytes,
+                "Selected embedding quantization mode"
+            );
+        }
+        embed_config.quantization = mode;
+        Ok(model)
+    }
+
+    /// Quantization mode the model was loaded with
+    pub fn quantization(&self) -> QuantizationMode {
+        self.config.quantization
+    }
+
     /// Embed a single text
     pub fn embed(&self, text: &str) -> Result<Vec<f32>, RagError> {
         let embeddings = self.embed_batch(&[text])?;
@@ -321,7 +499,11 @@ impl CandleBertEmbedder {
     }
 
     /// Embed multiple texts
+    ///
+    /// Fails with `RagError::Overloaded` when too many calls are already
+    /// running or waiting.
     pub fn embed_batch(&self, texts: &[&str]) -> Result<Vec<Vec<f32>>, RagError> {
+        let _permit = self.limiter.acquire()?;
         let mut all_embeddings = Vec::with_capacity(texts.len());
 
         for chunk in texts.chunks(self.config.batch_size) {
@@ -470,6 +652,27 @@ impl CandleBertEmbedder {
     pub fn device(&self) -> &Device {
         &self.device
     }
+
+    /// Run a dummy embedding so the first user query doesn't pay for it
+    ///
+    /// The first forward pass pages in the mmapped weights and grows the
+    /// allocator, which takes seconds on CPU. Returns the warmup duration.
+    pub fn warmup(&self) -> Result<Duration, RagError> {
+        let start = Instant::now();
+        self.embed(WARMUP_TEXT)?;
+        let elapsed = start.elapsed();
+        self.warm.store(true, Ordering::Release);
+        tracing::info!(
+            elapsed_ms = elapsed.as_millis() as u64,
+            "BERT embedder warmed up"
+        );
+        Ok(elapsed)
+    }
+
+    /// Whether `warmup()` has completed
+    pub fn is_warm(&self) -> bool {
+        self.warm.load(Ordering::Acquire)
+    }
 }
 
 // Non-Candle stubs
@@ -492,11 +695,33 @@ impl CandleBertEmbedder {
         Err(RagError::Model("Candle feature not enabled".to_string()))
     }
 
+    pub fn warmup(&self) -> Result<Duration, RagError> {
+        Err(RagError::Model("Candle feature not enabled".to_string()))
+    }
+
+    pub fn is_warm(&self) -> bool {
+        false
+    }
+
     pub fn dim(&self) -> usize {
         self.config.embedding_dim
     }
 }
 
+impl TextEmbedder for CandleBertEmbedder {
+    fn embed_batch(&self, texts: &[&str]) -> Result<Vec<Vec<f32>>, RagError> {
+        CandleBertEmbedder::embed_batch(self, texts)
+    }
+
+    fn embed(&self, text: &str) -> Result<Vec<f32>, RagError> {
+        CandleBertEmbedder::embed(self, text)
+    }
+
+    fn dim(&self) -> usize {
+        CandleBertEmbedder::dim(self)
+    }
+}
+
 /// Unified embedder that can use either ONNX or Candle backend
 pub enum UnifiedEmbedder {
     #[cfg(feature = "onnx")]
@@ -566,6 +791,17 @@ impl UnifiedEmbedder {
         }
     }
 
+    /// Run a dummy embedding to load the model before the first query
+    pub fn warmup(&self) -> Result<Duration, RagError> {
+        #[cfg(feature = "candle")]
+        if let Self::Candle(e) = self {
+            return e.warmup();
+        }
+        let start = std::time::Instant::now();
+        self.embed(WARMUP_TEXT)?;
+        Ok(start.elapsed())
+    }
+
     /// Get embedding dimension
     pub fn dim(&self) -> usize {
         match self {
@@ -578,6 +814,20 @@ impl UnifiedEmbedder {
     }
 }
 
+impl TextEmbedder for UnifiedEmbedder {
+    fn embed_batch(&self, texts: &[&str]) -> Result<Vec<Vec<f32>>, RagError> {
+        UnifiedEmbedder::embed_batch(self, texts)
+    }
+
+    fn embed(&self, text: &str) -> Result<Vec<f32>, RagError> {
+        UnifiedEmbedder::embed(self, text)
+    }
+
+    fn dim(&self) -> usize {
+        UnifiedEmbedder::dim(self)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -588,6 +838,15 @@ mod tests {
         assert_eq!(config.embedding_dim, 384);
         assert!(config.normalize);
         assert!(matches!(config.pooling, PoolingStrategy::Mean));
+        assert!(!config.concurrency.is_limited());
+    }
+
+    #[test]
+    fn test_config_concurrency_from_settings() {
+        let mut settings = voice_agent_config::RagConfig::default();
+        settings.embedding_concurrency.max_in_flight = 2;
+        let config = CandleEmbeddingConfig::from(&settings);
+        assert_eq!(config.concurrency, ConcurrencyLimitConfig::limited(2));
     }
 
     #[test]
@@ -596,11 +855,128 @@ mod tests {
         assert_eq!(config.embedding_dim, 384);
     }
 
+    const MB: u64 = 1024 * 1024;
+
+    #[test]
+    fn test_auto_picks_most_precise_mode_that_fits() {
+        let auto = QuantizationMode::Auto;
+
+        // Plenty of memory: F32, with F16 as the fallback
+        assert_eq!(
+            auto.candidates(470 * MB, Some(8192 * MB), false),
+            vec![QuantizationMode::F32, QuantizationMode::F16]
+        );
+        // F32 needs ~705MB with headroom, F16 ~353MB
+        assert_eq!(
+            auto.candidates(470 * MB, Some(500 * MB), false),
+            vec![QuantizationMode::F16]
+        );
+        // Nothing fits: still try the lightest mode
+        assert_eq!(
+            auto.candidates(470 * MB, Some(100 * MB), false),
+            vec![QuantizationMode::F16]
+        );
+        // Unknown memory: try everything, most precise first
+        assert_eq!(
+            auto.candidates(470 * MB, None, false),
+            vec![QuantizationMode::F32, QuantizationMode::F16]
+        );
+    }
+
+    #[test]
+    fn test_explicit_mode_and_bf16_support() {
+        assert_eq!(
+            QuantizationMode::F32.candidates(470 * MB, Some(100 * MB), false),
+            vec![QuantizationMode::F32]
+        );
+        assert_eq!(
+            QuantizationMode::BF16.candidates(470 * MB, None, true),
+            vec![QuantizationMode::BF16]
+        );
+        assert_eq!(
+            QuantizationMode::BF16.candidates(470 * MB, None, false),
+            vec![QuantizationMode::F16]
+        );
+    }
+
+    #[test]
+    fn test_allocation_failure_falls_back_to_lighter_mode() {
+        let candidates = QuantizationMode::Auto.candidates(470 * MB, None, false);
+        let mut attempts = Vec::new();
+
+        let (loaded, mode) = load_with_fallback(&candidates, |mode| {
+            attempts.push(mode);
+            match mode {
+                QuantizationMode::F32 => Err(RagError::Model("out of memory".to_string())),
+                _ => Ok("model"),
+            }
+        })
+        .unwrap();
+
+        assert_eq!(loaded, "model");
+        assert_eq!(mode, QuantizationMode::F16);
+        assert_eq!(attempts, candidates);
+
+        let all_fail = load_with_fallback(&candidates, |_| -> Result<(), RagError> {
+            Err(RagError::Model("out of memory".to_string()))
+        });
+        assert!(all_fail.is_err());
+    }
+
     #[cfg(not(feature = "candle"))]
     #[test]
     fn test_stub_returns_error() {
         let embedder = CandleBertEmbedder::new(CandleEmbeddingConfig::default());
         let result = embedder.embed("test");
         assert!(result.is_err());
+        assert!(embedder.warmup().is_err());
+        assert!(!embedder.is_warm());
+    }
+
+    #[test]
+    fn test_simple_embedder_warmup() {
+        let embedder = UnifiedEmbedder::simple(crate::embeddings::EmbeddingConfig::default());
+        assert!(embedder.warmup().is_ok());
+    }
+
+    /// Downloads multilingual-e5-small; run with `--features candle -- --ignored`
+    #[cfg(feature = "candle")]
+    #[test]
+    #[ignore]
+    fn test_warmup_makes_first_query_fast() {
+        let embedder =
+            CandleBertEmbedder::from_hub("intfloat/multilingual-e5-small", Default::default())
+                .unwrap();
+        assert!(!embedder.is_warm());
+
+        let cold = embedder.warmup().unwrap();
+        assert!(embedder.is_warm());
+
+        let start = Instant::now();
+        embedder.embed("query: what documents do I need?").unwrap();
+        let warm = start.elapsed();
+        assert!(warm < cold, "warm {:?} should beat cold {:?}", warm, cold);
+    }
+
+    /// Downloads multilingual-e5-small; run with `--features candle -- --ignored`
+    #[cfg(feature = "candle")]
+    #[test]
+    #[ignore]
+    fn test_padded_batch_matches_single() {
+        let embedder =
+            CandleBertEmbedder::from_hub("intfloat/multilingual-e5-small", Default::default())
+                .unwrap();
+        // Different lengths, so the shorter text is padded in the batch
+        let texts = [
+            "query: gold loan",
+            "query: what is the interest rate on a gold loan of five lakh rupees?",
+        ];
+
+        let batch = embedder.embed_batch(&texts).unwrap();
+
+        for (text, batched) in texts.iter().zip(&batch) {
+            let single = embedder.embed(text).unwrap();
+            assert!(single.iter().zip(batched).all(|(a, b)| (a - b).abs() < 1e-4));
+        }
     }
 }
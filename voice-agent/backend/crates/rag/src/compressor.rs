@@ -29,6 +29,8 @@
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 
+use voice_agent_core::{GenerateRequest, LanguageModel};
+
 use crate::RagError;
 
 /// A conversation turn (user or assistant message)
@@ -225,6 +227,86 @@ impl RuleBasedSummarizer {
     }
 }
 
+/// System prompt for LLM conversation compression
+pub const COMPRESSION_SYSTEM_PROMPT: &str =
+    "You are a context compression assistant. Extract and preserve only essential information.";
+
+/// Build the conversation compression prompt
+///
+/// Shared by `LlmSummarizer` and agent memory compaction so both produce
+/// summaries in the same key-value style.
+pub fn compression_prompt(conversation: &str, max_words: usize) -> String {
+    // P21 FIX: Domain-agnostic summarization prompt
+    // Enhanced summarization prompt inspired by LLMLingua research
+    format!(
+        r#"Compress this conversation into a concise summary.
+
+RULES:
+1. KEEP: Customer name, asset details, loan amount, interest rates, competitor names
+2. KEEP: Customer concerns, objections, and preferences
+3. REMOVE: Greetings, filler words, repeated information
+4. FORMAT: Use key-value pairs where possible (e.g., "Name: Rahul, Amount: 5 lakh")
+
+Conversation:
+{}
+
+Compressed Summary (max {} words):"#,
+        conversation, max_words
+    )
+}
+
+/// LLM summarizer using an injected language model
+///
+/// Falls back to `RuleBasedSummarizer` if the LLM fails or returns nothing.
+pub struct LlmSummarizer {
+    llm: Arc<dyn LanguageModel>,
+    /// Hard cap on generated tokens, whatever budget the caller asks for
+    max_output_tokens: usize,
+}
+
+impl LlmSummarizer {
+    /// Create with an LLM and the default output cap (256 tokens)
+    pub fn new(llm: Arc<dyn LanguageModel>) -> Self {
+        Self {
+            llm,
+            max_output_tokens: 256,
+        }
+    }
+
+    /// Set the maximum tokens the LLM may generate per summary
+    pub fn with_max_output_tokens(mut self, max_output_tokens: usize) -> Self {
+        self.max_output_tokens = max_output_tokens.max(1);
+        self
+    }
+}
+
+#[async_trait::async_trait]
+impl Summarizer for LlmSummarizer {
+    async fn summarize(&self, text: &str, max_tokens: usize) -> Result<String, RagError> {
+        let token_cap = max_tokens.clamp(1, self.max_output_tokens);
+        // ~0.75 words per token
+        let max_words = (token_cap * 3 / 4).max(1);
+
+        let request = GenerateRequest::new(COMPRESSION_SYSTEM_PROMPT)
+            .with_user_message(compression_prompt(text, max_words))
+            .with_max_tokens(token_cap as u32);
+
+        match self.llm.generate(request).await {
+            Ok(response) if !response.text.trim().is_empty() => {
+                Ok(response.text.trim().to_string())
+            },
+            Ok(_) => {
+                tracing::warn!("LLM returned empty summary, using rule-based summarizer");
+                RuleBasedSummarizer.summarize(text, max_tokens).await
+            },
+            Err(e) => {
+                tracing::warn!(error = %e, "LLM summarization failed, using rule-based summarizer");
+                RuleBasedSummarizer.summarize(text, max_tokens).await
+            },
+        }
+    }
+}
+
 /// Context compressor for conversation history
 pub struct ContextCompressor<S: Summarizer = RuleBasedSummarizer> {
     config: CompressorConfig,
@@ -465,6 +547,110 @@ mod tests {
         assert!(tokens < 50);
     }
 
+    /// LLM that returns a fixed summary (or fails) and records the requested token cap
+    struct MockLlm {
+        summary: Option<&'static str>,
+        last_max_tokens: parking_lot::Mutex<Option<u32>>,
+    }
+
+    impl MockLlm {
+        fn new(summary: Option<&'static str>) -> Self {
+            Self {
+                summary,
+                last_max_tokens: parking_lot::Mutex::new(None),
+            }
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl LanguageModel for MockLlm {
+        async fn generate(
+            &self,
+            request: GenerateRequest,
+        ) -> voice_agent_core::Result<voice_agent_core::GenerateResponse> {
+            *self.last_max_tokens.lock() = request.max_tokens;
+            match self.summary {
+                Some(summary) => Ok(voice_agent_core::GenerateResponse::text(summary)),
+                None => Err(voice_agent_core::Error::Llm(
+                    "backend unavailable".to_string(),
+                )),
+            }
+        }
+
+        fn generate_stream<'a>(
+            &'a self,
+            _request: GenerateRequest,
+        ) -> std::pin::Pin<
+            Box<
+                dyn futures::Stream<Item = voice_agent_core::Result<voice_agent_core::StreamChunk>>
+                    + Send
+                    + 'a,
+            >,
+        > {
+            Box::pin(futures::stream::empty())
+        }
+
+        async fn generate_with_tools(
+            &self,
+            request: GenerateRequest,
+            _tools: &[voice_agent_core::ToolDefinition],
+        ) -> voice_agent_core::Result<voice_agent_core::GenerateResponse> {
+            self.generate(request).await
+        }
+
+        async fn is_available(&self) -> bool {
+            true
+        }
+
+        fn model_name(&self) -> &str {
+            "mock-llm"
+        }
+    }
+
+    fn long_history() -> Vec<Turn> {
+        vec![
+            Turn::user("My name is Rahul and I want a 5 lakh loan against 50 grams of gold."),
+            Turn::assistant("Thank you Rahul. Our rates start at 10.5% per annum for gold loans."),
+            Turn::user("I currently pay 14% elsewhere and want to move my loan to you."),
+            Turn::assistant("A balance transfer at 10.5% would save you a significant amount."),
+            Turn::user("What documents do I need?"),
+        ]
+    }
+
+    #[tokio::test]
+    async fn test_llm_summarizer_in_compressor() {
+        let llm = Arc::new(MockLlm::new(Some("Name: Rahul, Amount: 5 lakh, Rate: 14%")));
+        let summarizer = Arc::new(LlmSummarizer::new(llm.clone()).with_max_output_tokens(64));
+        let compressor = ContextCompressor::with_summarizer(
+            CompressorConfig {
+                recency_window: 1,
+                ..Default::default()
+            },
+            summarizer,
+        );
+
+        let result = compressor.compress(&long_history(), 120).await.unwrap();
+
+        assert!(result
+            .text
+            .contains("Name: Rahul, Amount: 5 lakh, Rate: 14%"));
+        assert_eq!(result.summarized_turns, 4);
+        // Output cap bounds the request even if the budget is larger
+        assert!(llm.last_max_tokens.lock().unwrap() <= 64);
+    }
+
+    #[tokio::test]
+    async fn test_llm_summarizer_falls_back_on_error() {
+        let summarizer = LlmSummarizer::new(Arc::new(MockLlm::new(None)));
+        let text = "USER: My name is Rahul. I want a 5 lakh loan.";
+
+        let summary = summarizer.summarize(text, 100).await.unwrap();
+        let expected = RuleBasedSummarizer.summarize(text, 100).await.unwrap();
+
+        assert_eq!(summary, expected);
+        assert!(summary.starts_with("Previously discussed"));
+    }
+
     #[test]
     fn test_rule_based_summarizer_patterns() {
         let text = "My name is Rahul. I want a 5 lakh loan. I have gold from Muthoot.";
@@ -8,8 +8,15 @@
 //! - ObjectionHandling: High context for competitive comparisons
 //! - Closing: Moderate for final confirmations
 //! - Farewell: Minimal wrap-up context
+//!
+//! Each stage also caps how many retrieved results are used and the minimum
+//! score they need: early stages take a few high-precision results, complex
+//! stages take more. Results below the cutoff are dropped entirely.
 
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use crate::retriever::SearchResult;
 
 /// Conversation stage for context sizing
 ///
@@ -191,6 +198,44 @@ pub fn context_budget_for_stage(stage: Stage) -> ContextBudget {
     }
 }
 
+/// How many retrieved results a stage uses, and how good they must be
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct RetrievalLimits {
+    /// Maximum results added to the prompt
+    pub top_k: usize,
+    /// Results scoring below this are dropped
+    pub min_score: f32,
+}
+
+impl RetrievalLimits {
+    /// Create retrieval limits
+    pub fn new(top_k: usize, min_score: f32) -> Self {
+        Self { top_k, min_score }
+    }
+
+    /// Drop results below the score cutoff, then keep the best `top_k`
+    ///
+    /// Better no context than bad context, so this may return nothing.
+    pub fn apply(&self, mut results: Vec<SearchResult>) -> Vec<SearchResult> {
+        results.retain(|r| r.score >= self.min_score);
+        results.sort_by(|a, b| b.score.total_cmp(&a.score));
+        results.truncate(self.top_k);
+        results
+    }
+}
+
+/// Get retrieval limits for a conversation stage
+pub fn retrieval_limits_for_stage(stage: Stage) -> RetrievalLimits {
+    match stage {
+        // Little or no RAG needed; only confident matches
+        Stage::Greeting | Stage::Farewell => RetrievalLimits::new(1, 0.6),
+        Stage::Closing => RetrievalLimits::new(2, 0.5),
+        Stage::Discovery | Stage::Qualification => RetrievalLimits::new(3, 0.45),
+        // Rich context for product details and rebuttals
+        Stage::Presentation | Stage::ObjectionHandling => RetrievalLimits::new(5, 0.35),
+    }
+}
+
 /// Configuration for context management
 #[derive(Debug, Clone)]
 pub struct ContextConfig {
@@ -204,6 +249,8 @@ pub struct ContextConfig {
     pub min_rag_tokens: usize,
     /// Maximum RAG tokens (ceiling)
     pub max_rag_tokens: usize,
+    /// Per-stage retrieval limits overriding `retrieval_limits_for_stage`
+    pub retrieval_limits: HashMap<Stage, RetrievalLimits>,
 }
 
 impl Default for ContextConfig {
@@ -214,6 +261,7 @@ impl Default for ContextConfig {
             default_budget: ContextBudget::default(),
             min_rag_tokens: 100,
             max_rag_tokens: 2500,
+            retrieval_limits: HashMap::new(),
         }
     }
 }
@@ -289,6 +337,20 @@ impl ContextManager {
         budget
     }
 
+    /// Get retrieval limits for a stage, preferring configured overrides
+    pub fn retrieval_limits(&self, stage: Stage) -> RetrievalLimits {
+        self.config
+            .retrieval_limits
+            .get(&stage)
+            .copied()
+            .unwrap_or_else(|| retrieval_limits_for_stage(stage))
+    }
+
+    /// Filter retrieved results by the stage's score cutoff and top-k
+    pub fn limit_results(&self, stage: Stage, results: Vec<SearchResult>) -> Vec<SearchResult> {
+        self.retrieval_limits(stage).apply(results)
+    }
+
     /// Get budget from stage string (convenience method)
     pub fn get_budget_for(&self, stage_str: &str) -> ContextBudget {
         self.get_budget(Stage::from_str(stage_str))
@@ -414,6 +476,54 @@ mod tests {
         assert!(greeting.rag_tokens <= 300);
     }
 
+    fn result(id: &str, score: f32) -> SearchResult {
+        SearchResult {
+            id: id.to_string(),
+            content: format!("doc {}", id),
+            score,
+            metadata: HashMap::new(),
+            source: crate::retriever::SearchSource::Hybrid,
+            exit_layer: None,
+            explanation: None,
+        }
+    }
+
+    #[test]
+    fn test_stages_apply_different_top_k() {
+        let manager = ContextManager::default();
+        let results: Vec<_> = (0..8).map(|i| result(&i.to_string(), 0.9)).collect();
+
+        let greeting = manager.limit_results(Stage::Greeting, results.clone());
+        let objection = manager.limit_results(Stage::ObjectionHandling, results);
+
+        assert_eq!(greeting.len(), 1);
+        assert_eq!(objection.len(), 5);
+    }
+
+    #[test]
+    fn test_low_scoring_results_dropped() {
+        let mut config = ContextConfig::default();
+        config
+            .retrieval_limits
+            .insert(Stage::Discovery, RetrievalLimits::new(3, 0.5));
+        let manager = ContextManager::new(config);
+
+        let limited = manager.limit_results(
+            Stage::Discovery,
+            vec![
+                result("weak", 0.2),
+                result("strong", 0.8),
+                result("edge", 0.5),
+            ],
+        );
+        let ids: Vec<_> = limited.iter().map(|r| r.id.as_str()).collect();
+        assert_eq!(ids, vec!["strong", "edge"]);
+
+        // Nothing clears the cutoff: no context at all
+        let none = manager.limit_results(Stage::Discovery, vec![result("weak", 0.1)]);
+        assert!(none.is_empty());
+    }
+
     #[test]
     fn test_budget_scaling() {
         let budget = ContextBudget::new(2000, 1000, 800, 800);
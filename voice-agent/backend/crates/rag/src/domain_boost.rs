@@ -4,6 +4,7 @@
 //! No hardcoded domain-specific terminology.
 
 use parking_lot::RwLock;
+use serde::Serialize;
 use std::collections::HashMap;
 
 /// Domain booster configuration
@@ -18,6 +19,9 @@ pub struct DomainBoostConfig {
     pub brand_boost: f32,
     /// Enable category-based boosting
     pub category_boost_enabled: bool,
+    /// Per-category boost multipliers; unlisted categories use
+    /// `TermCategory::default_boost_multiplier`
+    pub category_weights: HashMap<TermCategory, f32>,
 }
 
 impl Default for DomainBoostConfig {
@@ -27,6 +31,7 @@ impl Default for DomainBoostConfig {
             exact_match_boost: 2.0,
             brand_boost: 1.3,
             category_boost_enabled: true,
+            category_weights: HashMap::new(),
         }
     }
 }
@@ -46,7 +51,8 @@ pub struct DomainTerm {
 
 /// Term category for domain boosting
 /// P16 FIX: Generic categories, not domain-specific
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "snake_case")]
 pub enum TermCategory {
     /// Product/service terms
     Product,
@@ -114,16 +120,18 @@ pub struct BoostResult {
 }
 
 /// A matched domain term
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct MatchedTerm {
     /// The term that matched
     pub term: String,
     /// Position in query
     pub position: usize,
-    /// Boost applied
+    /// Boost applied (includes `category_weight`)
     pub boost: f32,
     /// Category
     pub category: TermCategory,
+    /// Category multiplier folded into `boost`
+    pub category_weight: f32,
 }
 
 /// Detected query intent
@@ -155,8 +163,6 @@ pub struct DomainBooster {
     config: DomainBoostConfig,
     /// Domain terms dictionary
     terms: RwLock<HashMap<String, DomainTerm>>,
-    /// Category boost multipliers from config
-    category_boosts: HashMap<String, f32>,
     /// Intent patterns (config-driven in the future)
     intent_patterns: Vec<(Vec<String>, QueryIntent)>,
 }
@@ -167,7 +173,6 @@ impl DomainBooster {
         Self {
             config,
             terms: RwLock::new(HashMap::new()),
-            category_boosts: HashMap::new(),
             intent_patterns: Self::default_intent_patterns(),
         }
     }
@@ -181,14 +186,16 @@ impl DomainBooster {
             exact_match_boost: 2.0,
             brand_boost: 1.3,
             category_boost_enabled: true,
+            category_weights: config_boost
+                .category_boosts
+                .iter()
+                .map(|(k, v)| (TermCategory::from_str(k), *v as f32))
+                .collect(),
         };
 
         let mut booster = Self {
             config,
             terms: RwLock::new(HashMap::new()),
-            category_boosts: config_boost.category_boosts.iter()
-                .map(|(k, v)| (k.clone(), *v as f32))
-                .collect(),
             intent_patterns: Self::default_intent_patterns(),
         };
 
@@ -272,22 +279,14 @@ impl DomainBooster {
         ]
     }
 
-    /// Get category boost multiplier (from config or default)
-    fn category_boost_multiplier(&self, category: TermCategory) -> f32 {
-        let category_name = match category {
-            TermCategory::Product => "product",
-            TermCategory::Rate => "rate",
-            TermCategory::Process => "process",
-            TermCategory::Eligibility => "eligibility",
-            TermCategory::Brand => "brand",
-            TermCategory::Asset => "asset",
-            TermCategory::Customer => "customer",
-            TermCategory::Competitor => "competitor",
-            TermCategory::General => "general",
-        };
-
-        self.category_boosts
-            .get(category_name)
+    /// Category boost multiplier (from config or default; 1.0 when disabled)
+    pub fn category_weight(&self, category: TermCategory) -> f32 {
+        if !self.config.category_boost_enabled {
+            return 1.0;
+        }
+        self.config
+            .category_weights
+            .get(&category)
             .copied()
             .unwrap_or_else(|| category.default_boost_multiplier())
     }
@@ -304,11 +303,13 @@ impl DomainBooster {
         // Match single words
         for (pos, word) in words.iter().enumerate() {
             if let Some(domain_term) = terms.get(*word) {
+                let category_weight = self.category_weight(domain_term.category);
                 matched_terms.push(MatchedTerm {
                     term: domain_term.term.clone(),
                     position: pos,
-                    boost: domain_term.boost * self.config.base_boost,
+                    boost: domain_term.boost * self.config.base_boost * category_weight,
                     category: domain_term.category,
+                    category_weight,
                 });
                 if !categories.contains(&domain_term.category) {
                     categories.push(domain_term.category);
@@ -321,11 +322,13 @@ impl DomainBooster {
             if term.contains(' ') && query_lower.contains(term) {
                 // Avoid double counting if individual words already matched
                 if !matched_terms.iter().any(|m| m.term == domain_term.term) {
+                    let category_weight = self.category_weight(domain_term.category);
                     matched_terms.push(MatchedTerm {
                         term: domain_term.term.clone(),
                         position: query_lower.find(term).unwrap_or(0),
-                        boost: domain_term.boost * self.config.exact_match_boost,
+                        boost: domain_term.boost * self.config.exact_match_boost * category_weight,
                         category: domain_term.category,
+                        category_weight,
                     });
                     if !categories.contains(&domain_term.category) {
                         categories.push(domain_term.category);
@@ -337,19 +340,11 @@ impl DomainBooster {
         // Detect intent
         let intent = self.detect_intent(&query_lower);
 
-        // Calculate total boost
+        // Calculate total boost (category weights are already in each term's boost)
         let total_boost = if matched_terms.is_empty() {
             1.0
         } else {
-            let base: f32 =
-                matched_terms.iter().map(|m| m.boost).sum::<f32>() / matched_terms.len() as f32;
-            let category_bonus: f32 = if self.config.category_boost_enabled {
-                categories.iter().map(|c| self.category_boost_multiplier(*c)).sum::<f32>()
-                    / categories.len().max(1) as f32
-            } else {
-                1.0
-            };
-            base * category_bonus
+            matched_terms.iter().map(|m| m.boost).sum::<f32>() / matched_terms.len() as f32
         };
 
         BoostResult {
@@ -499,7 +494,70 @@ mod tests {
         let booster = DomainBooster::with_defaults();
 
         // Check default boost multiplier
-        let boost = booster.category_boost_multiplier(TermCategory::Product);
+        let boost = booster.category_weight(TermCategory::Product);
         assert!((boost - 1.5).abs() < 0.001);
+
+        let disabled = DomainBooster::new(DomainBoostConfig {
+            category_boost_enabled: false,
+            ..Default::default()
+        });
+        assert_eq!(disabled.category_weight(TermCategory::Product), 1.0);
+    }
+
+    fn weighted_booster() -> DomainBooster {
+        let config = DomainBoostConfig {
+            base_boost: 1.0,
+            category_weights: [(TermCategory::Product, 2.0), (TermCategory::General, 1.0)]
+                .into_iter()
+                .collect(),
+            ..Default::default()
+        };
+        let booster = DomainBooster::new(config);
+        for (term, category) in [
+            ("scheme", TermCategory::Product),
+            ("charges", TermCategory::General),
+        ] {
+            booster.add_term(DomainTerm {
+                term: term.to_string(),
+                category,
+                boost: 1.2,
+                related: vec![],
+            });
+        }
+        booster
+    }
+
+    #[test]
+    fn test_high_weight_category_outranks_low_weight() {
+        let booster = weighted_booster();
+        let mut results = vec![
+            ("flat charges on every loan", 0.5),
+            ("our premium scheme for loans", 0.5),
+        ];
+
+        booster.apply_boost(&mut results, "scheme charges");
+        results.sort_by(|a, b| b.1.total_cmp(&a.1));
+
+        assert_eq!(results[0].0, "our premium scheme for loans");
+        assert!(results[0].1 > results[1].1);
+    }
+
+    #[test]
+    fn test_boost_result_reflects_category_weights() {
+        let booster = weighted_booster();
+        let result = booster.boost("scheme charges");
+
+        let term = |name: &str| {
+            result
+                .matched_terms
+                .iter()
+                .find(|m| m.term == name)
+                .unwrap()
+        };
+        assert_eq!(term("scheme").category_weight, 2.0);
+        assert!((term("scheme").boost - 2.4).abs() < 1e-6);
+        assert_eq!(term("charges").category_weight, 1.0);
+        assert!((term("charges").boost - 1.2).abs() < 1e-6);
+        assert!((result.total_boost - 1.8).abs() < 1e-6);
     }
 }
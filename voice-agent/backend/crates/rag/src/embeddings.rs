@@ -13,6 +13,24 @@ use tokenizers::Tokenizer;
 
 use crate::RagError;
 
+/// Synchronous text embedding backend
+///
+/// `embed_batch` is the primary entry point: model backends run one padded
+/// forward pass per batch. Output order always matches input order.
+pub trait TextEmbedder {
+    /// Embed multiple texts
+    fn embed_batch(&self, texts: &[&str]) -> Result<Vec<Vec<f32>>, RagError>;
+
+    /// Embed a single text
+    fn embed(&self, text: &str) -> Result<Vec<f32>, RagError> {
+        let embeddings = self.embed_batch(&[text])?;
+        Ok(embeddings.into_iter().next().unwrap_or_default())
+    }
+
+    /// Embedding dimension
+    fn dim(&self) -> usize;
+}
+
 /// Embedding configuration
 #[derive(Debug, Clone)]
 pub struct EmbeddingConfig {
@@ -239,6 +257,20 @@ impl Embedder {
     }
 }
 
+impl TextEmbedder for Embedder {
+    fn embed_batch(&self, texts: &[&str]) -> Result<Vec<Vec<f32>>, RagError> {
+        Embedder::embed_batch(self, texts)
+    }
+
+    fn embed(&self, text: &str) -> Result<Vec<f32>, RagError> {
+        Embedder::embed(self, text)
+    }
+
+    fn dim(&self) -> usize {
+        Embedder::dim(self)
+    }
+}
+
 /// Simple embedder for testing (no model required)
 pub struct SimpleEmbedder {
     config: EmbeddingConfig,
@@ -271,6 +303,20 @@ impl SimpleEmbedder {
     }
 }
 
+impl TextEmbedder for SimpleEmbedder {
+    fn embed_batch(&self, texts: &[&str]) -> Result<Vec<Vec<f32>>, RagError> {
+        Ok(texts.iter().map(|t| SimpleEmbedder::embed(self, t)).collect())
+    }
+
+    fn embed(&self, text: &str) -> Result<Vec<f32>, RagError> {
+        Ok(SimpleEmbedder::embed(self, text))
+    }
+
+    fn dim(&self) -> usize {
+        self.config.embedding_dim
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -286,6 +332,19 @@ mod tests {
         assert!((norm - 1.0).abs() < 0.01);
     }
 
+    #[test]
+    fn test_batch_matches_single() {
+        let embedder = SimpleEmbedder::new(EmbeddingConfig::default());
+        let texts = ["gold loan", "interest rate kya hai", ""];
+
+        let batch = TextEmbedder::embed_batch(&embedder, &texts).unwrap();
+
+        assert_eq!(batch.len(), texts.len());
+        for (text, embedding) in texts.iter().zip(&batch) {
+            assert_eq!(embedding, &embedder.embed(text));
+        }
+    }
+
     #[test]
     fn test_config_default() {
         let config = EmbeddingConfig::default();
@@ -6,8 +6,8 @@
 use serde::{Deserialize, Serialize};
 use std::path::Path;
 
-use crate::vector_store::Document;
-use crate::{RagError, VectorStore};
+use crate::vector_store::{PointPayload, VectorPoint};
+use crate::{RagError, VectorStoreBackend};
 
 /// Knowledge document format for YAML/JSON files
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -27,6 +27,9 @@ pub struct KnowledgeDocument {
     /// Keywords for boosting
     #[serde(default)]
     pub keywords: Vec<String>,
+    /// Conversation stages the document is relevant to (empty = all)
+    #[serde(default)]
+    pub stages: Vec<String>,
 }
 
 fn default_language() -> String {
@@ -61,7 +64,7 @@ impl KnowledgeLoader {
     /// Number of documents loaded
     pub async fn load_directory<F, Fut>(
         knowledge_dir: &Path,
-        vector_store: &VectorStore,
+        vector_store: &dyn VectorStoreBackend,
         embedder: F,
     ) -> Result<usize, RagError>
     where
@@ -124,7 +127,7 @@ impl KnowledgeLoader {
     /// Load a single knowledge file
     async fn load_file<F, Fut>(
         path: &Path,
-        vector_store: &VectorStore,
+        vector_store: &dyn VectorStoreBackend,
         embedder: F,
     ) -> Result<usize, RagError>
     where
@@ -148,17 +151,22 @@ impl KnowledgeLoader {
             },
         };
 
-        let mut documents = Vec::new();
-        let mut embeddings = Vec::new();
+        let source = path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned());
+        let mut points = Vec::new();
 
         for doc in &knowledge.documents {
-            // Create document for vector store
-            let vs_doc = Document {
-                id: doc.id.clone(),
+            // Payload for the vector store
+            let payload = PointPayload {
+                document_id: doc.id.clone(),
                 content: doc.content.clone(),
                 title: Some(doc.title.clone()),
                 category: doc.category.clone(),
-                language: Some(doc.language.clone()),
+                // Normalized so retrieval language filters match regardless of case
+                language: Some(doc.language.to_lowercase()),
+                stages: doc.stages.iter().map(|s| s.to_lowercase()).collect(),
+                source: source.clone(),
                 metadata: doc
                     .keywords
                     .iter()
@@ -170,16 +178,15 @@ impl KnowledgeLoader {
             // Generate embedding
             let embedding = embedder(&doc.content).await?;
 
-            documents.push(vs_doc);
-            embeddings.push(embedding);
+            points.push(VectorPoint::new(doc.id.clone(), embedding, payload));
         }
 
         // Batch upsert to vector store
-        if !documents.is_empty() {
-            vector_store.upsert(&documents, &embeddings).await?;
+        if !points.is_empty() {
+            vector_store.upsert(&points).await?;
         }
 
-        Ok(documents.len())
+        Ok(points.len())
     }
 
     /// Create a sample knowledge file for reference
@@ -203,6 +210,7 @@ impl KnowledgeLoader {
                         "introduction".to_string(),
                         "overview".to_string(),
                     ],
+                    stages: Vec::new(),
                 },
                 KnowledgeDocument {
                     id: "service_benefits_001".to_string(),
@@ -217,6 +225,7 @@ impl KnowledgeLoader {
                         "quick".to_string(),
                         "competitive".to_string(),
                     ],
+                    stages: Vec::new(),
                 },
             ],
         };
@@ -247,6 +256,7 @@ mod tests {
             category: Some("test".to_string()),
             language: "en".to_string(),
             keywords: vec!["test".to_string()],
+            stages: Vec::new(),
         };
 
         let yaml = serde_yaml::to_string(&doc).unwrap();
@@ -1,7 +1,7 @@
 //! RAG (Retrieval-Augmented Generation) with hybrid search
 //!
 //! Features:
-//! - Dense vector search via Qdrant
+//! - Dense vector search via Qdrant, or an in-memory store for tests
 //! - Sparse BM25 search via Tantivy
 //! - Hybrid fusion with RRF
 //! - Early-exit cross-encoder reranking
@@ -21,6 +21,8 @@ pub mod candle_embeddings;
 pub mod cross_lingual;
 pub mod domain_boost;
 pub mod embeddings;
+pub mod limiter;
+pub mod memory_store;
 pub mod query_expansion;
 pub mod reranker;
 pub mod retriever;
@@ -40,12 +42,15 @@ pub use agentic::{
     AgenticRagConfig,
     AgenticRetriever,
     AgenticSearchResult,
+    HeuristicSufficiencyChecker,
     // P2-1 FIX: QueryContext is the new name, ConversationContext kept for backwards compat
     QueryContext,
     // P1 FIX: LLM-based sufficiency checking
     LlmSufficiencyChecker,
     LlmSufficiencyConfig,
     QueryRewriter,
+    RuleBasedSufficiencyChecker,
+    StopReason,
     SufficiencyChecker,
     SufficiencyEvaluation,
 };
@@ -56,7 +61,10 @@ pub use cache::{CacheStats, CachedEmbedder, EmbeddingCache};
 pub use candle_embeddings::{
     CandleBertEmbedder, CandleEmbeddingConfig, PoolingStrategy, QuantizationMode, UnifiedEmbedder,
 };
-pub use context::{context_budget_for_stage, ContextBudget, ContextConfig, ContextManager, Stage};
+pub use context::{
+    context_budget_for_stage, retrieval_limits_for_stage, ContextBudget, ContextConfig,
+    ContextManager, RetrievalLimits, Stage,
+};
 pub use cross_lingual::{
     CrossLingualNormalizer, DetectedScript, LanguageDetection, NormalizedQuery,
 };
@@ -64,18 +72,26 @@ pub use domain_boost::{
     BoostResult, DomainBoostConfig, DomainBooster, DomainTerm, MatchedTerm, QueryIntent,
     TermCategory,
 };
-pub use embeddings::{Embedder, EmbeddingConfig, SimpleEmbedder};
+pub use embeddings::{Embedder, EmbeddingConfig, SimpleEmbedder, TextEmbedder};
 pub use knowledge_loader::{KnowledgeDocument, KnowledgeFile, KnowledgeLoader};
+pub use limiter::{EmbeddingLimiter, EmbeddingPermit};
+pub use memory_store::InMemoryVectorStore;
 pub use query_expansion::{
     ExpandedQuery, ExpansionStats, QueryExpander, QueryExpansionConfig, TermSource, WeightedTerm,
 };
-pub use reranker::{EarlyExitReranker, ExitStrategy, RerankerConfig};
-pub use retriever::{HybridRetriever, RetrieverConfig, SearchResult};
+pub use reranker::{
+    EarlyExitReranker, ExitStrategy, RerankExitReason, RerankTrace, RerankerConfig,
+};
+pub use retriever::{HybridRetriever, RetrieverConfig, ScoreExplanation, SearchResult};
 pub use sparse_search::{SparseConfig, SparseIndex};
-pub use vector_store::{VectorDistance, VectorStore, VectorStoreConfig};
+pub use vector_store::{
+    FilterOp, MetadataFilter, PointPayload, VectorDistance, VectorPoint, VectorStore,
+    VectorStoreBackend, VectorStoreConfig,
+};
 // P2-2 FIX: Context compression exports
 pub use compressor::{
-    CompressedContext, CompressorConfig, ContextCompressor, RuleBasedSummarizer, Summarizer, Turn,
+    CompressedContext, CompressorConfig, ContextCompressor, LlmSummarizer, RuleBasedSummarizer,
+    Summarizer, Turn,
 };
 // Semantic chunking exports
 pub use chunker::{Chunk, ChunkConfig, ChunkStrategy, SemanticChunker};
@@ -108,6 +124,9 @@ pub enum RagError {
 
     #[error("Connection error: {0}")]
     Connection(String),
+
+    #[error("Overloaded: {0}")]
+    Overloaded(String),
 }
 
 impl From<RagError> for voice_agent_core::Error {
@@ -0,0 +1,220 @@
+//! Concurrency Limits for Embedding
+//!
+//! Embedding runs synchronously on the model's device, and a burst of
+//! sessions embedding at once can run a GPU out of memory. `EmbeddingLimiter`
+//! is the blocking counterpart of the LLM crate's `ConcurrencyLimiter` and
+//! takes the same `ConcurrencyLimitConfig`: calls beyond `max_in_flight`
+//! wait up to `max_wait`, and once `max_queued` are waiting new ones fail
+//! at once with `RagError::Overloaded`. Rejections are counted in
+//! `voice_agent_concurrency_rejected_total` with resource "embedding".
+//!
+//! Embedders share one limiter per device and limit (`EmbeddingLimiter::shared`),
+//! so the cap holds across every embedder on the node.
+
+use std::collections::HashMap;
+use std::sync::{Arc, OnceLock};
+use std::time::Instant;
+
+use metrics::counter;
+use parking_lot::{Condvar, Mutex};
+use voice_agent_llm::ConcurrencyLimitConfig;
+
+use crate::RagError;
+
+#[derive(Debug, Default)]
+struct LimiterState {
+    in_flight: usize,
+    queued: usize,
+}
+
+/// Caps concurrent embedding calls with a bounded wait queue
+#[derive(Debug)]
+pub struct EmbeddingLimiter {
+    config: ConcurrencyLimitConfig,
+    state: Mutex<LimiterState>,
+    released: Condvar,
+}
+
+/// A slot held by an embedding call, released on drop
+#[must_use]
+pub struct EmbeddingPermit<'a> {
+    limiter: Option<&'a EmbeddingLimiter>,
+}
+
+impl Drop for EmbeddingPermit<'_> {
+    fn drop(&mut self) {
+        if let Some(limiter) = self.limiter {
+            limiter.state.lock().in_flight -= 1;
+            limiter.released.notify_one();
+        }
+    }
+}
+
+impl EmbeddingLimiter {
+    pub fn new(config: ConcurrencyLimitConfig) -> Self {
+        Self {
+            config,
+            state: Mutex::new(LimiterState::default()),
+            released: Condvar::new(),
+        }
+    }
+
+    /// Limiter shared by every embedder on `key` (the device) with the same limit
+    pub fn shared(key: &str, config: ConcurrencyLimitConfig) -> Arc<EmbeddingLimiter> {
+        static SHARED: OnceLock<Mutex<HashMap<String, Arc<EmbeddingLimiter>>>> = OnceLock::new();
+        SHARED
+            .get_or_init(Default::default)
+            .lock()
+            .entry(format!("{}:{:?}", key, config))
+            .or_insert_with(|| Arc::new(EmbeddingLimiter::new(config)))
+            .clone()
+    }
+
+    /// Block until a slot is free, or fail with `RagError::Overloaded`
+    pub fn acquire(&self) -> Result<EmbeddingPermit<'_>, RagError> {
+        if !self.config.is_limited() {
+            return Ok(EmbeddingPermit { limiter: None });
+        }
+        let max = self.config.max_in_flight;
+        let mut state = self.state.lock();
+        if state.in_flight < max {
+            state.in_flight += 1;
+            return Ok(EmbeddingPermit {
+                limiter: Some(self),
+            });
+        }
+        if state.queued >= self.config.max_queued {
+            drop(state);
+            self.reject("queue_full");
+            return Err(RagError::Overloaded(format!(
+                "embedding queue is full ({} waiting)",
+                self.config.max_queued
+            )));
+        }
+
+        state.queued += 1;
+        let deadline = Instant::now() + self.config.max_wait;
+        while state.in_flight >= max {
+            if self.released.wait_until(&mut state, deadline).timed_out() {
+                break;
+            }
+        }
+        state.queued -= 1;
+        if state.in_flight >= max {
+            drop(state);
+            self.reject("timeout");
+            return Err(RagError::Overloaded(format!(
+                "no embedding slot free within {:?}",
+                self.config.max_wait
+            )));
+        }
+        state.in_flight += 1;
+        Ok(EmbeddingPermit {
+            limiter: Some(self),
+        })
+    }
+
+    /// Calls currently in flight
+    pub fn in_flight(&self) -> usize {
+        self.state.lock().in_flight
+    }
+
+    /// Calls currently waiting for a slot
+    pub fn queued(&self) -> usize {
+        self.state.lock().queued
+    }
+
+    fn reject(&self, reason: &'static str) {
+        counter!(
+            "voice_agent_concurrency_rejected_total",
+            "resource" => "embedding",
+            "reason" => reason
+        )
+        .increment(1);
+        tracing::warn!(reason, "Rejected embedding call over the concurrency limit");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::thread;
+    use std::time::Duration;
+
+    fn limiter(max_queued: usize, max_wait_ms: u64) -> Arc<EmbeddingLimiter> {
+        Arc::new(EmbeddingLimiter::new(ConcurrencyLimitConfig {
+            max_in_flight: 1,
+            max_queued,
+            max_wait: Duration::from_millis(max_wait_ms),
+        }))
+    }
+
+    fn wait_for_queue(limiter: &EmbeddingLimiter, queued: usize) {
+        let deadline = Instant::now() + Duration::from_secs(1);
+        while limiter.queued() < queued && Instant::now() < deadline {
+            thread::sleep(Duration::from_millis(1));
+        }
+        assert_eq!(limiter.queued(), queued);
+    }
+
+    #[test]
+    fn test_second_call_waits_for_the_first() {
+        let limiter = limiter(1, 1000);
+        let first = limiter.acquire().unwrap();
+
+        let waiter = {
+            let limiter = limiter.clone();
+            thread::spawn(move || limiter.acquire().map(|_| ()))
+        };
+        wait_for_queue(&limiter, 1);
+        assert!(!waiter.is_finished());
+
+        drop(first);
+        assert!(waiter.join().unwrap().is_ok());
+        assert_eq!(limiter.queued(), 0);
+        assert_eq!(limiter.in_flight(), 0);
+    }
+
+    #[test]
+    fn test_calls_beyond_queue_or_wait_are_rejected() {
+        let limiter = limiter(1, 50);
+        let _first = limiter.acquire().unwrap();
+
+        let started = Instant::now();
+        let queued = {
+            let limiter = limiter.clone();
+            thread::spawn(move || limiter.acquire().map(|_| ()))
+        };
+        wait_for_queue(&limiter, 1);
+
+        // The queue holds one caller, so a third is turned away at once
+        let third = limiter.acquire();
+        assert!(matches!(third, Err(RagError::Overloaded(ref m)) if m.contains("queue")));
+
+        // The queued caller gives up once the wait runs out
+        let queued = queued.join().unwrap();
+        assert!(matches!(queued, Err(RagError::Overloaded(ref m)) if m.contains("within")));
+        assert!(started.elapsed() >= Duration::from_millis(50));
+        assert_eq!(limiter.queued(), 0);
+        assert_eq!(limiter.in_flight(), 1);
+    }
+
+    #[test]
+    fn test_shared_limiter_per_device_and_config() {
+        let a = EmbeddingLimiter::shared("cpu-test", ConcurrencyLimitConfig::limited(2));
+        let b = EmbeddingLimiter::shared("cpu-test", ConcurrencyLimitConfig::limited(2));
+        let c = EmbeddingLimiter::shared("cpu-test", ConcurrencyLimitConfig::limited(4));
+        assert!(Arc::ptr_eq(&a, &b));
+        assert!(!Arc::ptr_eq(&a, &c));
+
+        let _permit = a.acquire().unwrap();
+        assert_eq!(b.in_flight(), 1);
+    }
+
+    #[test]
+    fn test_unlimited_never_blocks() {
+        let limiter = EmbeddingLimiter::new(ConcurrencyLimitConfig::unlimited());
+        let _permits: Vec<_> = (0..100).map(|_| limiter.acquire().unwrap()).collect();
+        assert_eq!(limiter.in_flight(), 0);
+    }
+}
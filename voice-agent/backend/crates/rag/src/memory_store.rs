@@ -0,0 +1,223 @@
+//! In-Memory Vector Store
+//!
+//! Brute-force `VectorStoreBackend` for tests and single-node deployments
+//! that don't warrant running Qdrant. Points and payloads live in a `Vec`;
+//! every search scores all points matching the filter.
+
+use async_trait::async_trait;
+use parking_lot::RwLock;
+
+use crate::vector_store::{
+    MetadataFilter, VectorDistance, VectorPoint, VectorSearchResult, VectorStoreBackend,
+};
+use crate::RagError;
+
+/// Vector store held in process memory
+///
+/// Scores follow Qdrant: cosine similarity and dot product rank highest
+/// first, Euclidean distance ranks lowest first.
+pub struct InMemoryVectorStore {
+    distance: VectorDistance,
+    points: RwLock<Vec<VectorPoint>>,
+}
+
+impl InMemoryVectorStore {
+    pub fn new(distance: VectorDistance) -> Self {
+        Self {
+            distance,
+            points: RwLock::new(Vec::new()),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.points.read().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.points.read().is_empty()
+    }
+
+    fn score(&self, a: &[f32], b: &[f32]) -> f32 {
+        let dot = || a.iter().zip(b).map(|(x, y)| x * y).sum::<f32>();
+        match self.distance {
+            VectorDistance::Cosine => {
+                let norm_a = a.iter().map(|x| x * x).sum::<f32>().sqrt();
+                let norm_b = b.iter().map(|x| x * x).sum::<f32>().sqrt();
+                if norm_a == 0.0 || norm_b == 0.0 {
+                    0.0
+                } else {
+                    dot() / (norm_a * norm_b)
+                }
+            },
+            VectorDistance::DotProduct => dot(),
+            VectorDistance::Euclidean => a
+                .iter()
+                .zip(b)
+                .map(|(x, y)| (x - y) * (x - y))
+                .sum::<f32>()
+                .sqrt(),
+        }
+    }
+}
+
+impl Default for InMemoryVectorStore {
+    fn default() -> Self {
+        Self::new(VectorDistance::Cosine)
+    }
+}
+
+#[async_trait]
+impl VectorStoreBackend for InMemoryVectorStore {
+    async fn upsert(&self, points: &[VectorPoint]) -> Result<(), RagError> {
+        let mut stored = self.points.write();
+        let dim = stored
+            .first()
+            .or_else(|| points.first())
+            .map(|p| p.vector.len());
+        if let Some(point) = points.iter().find(|p| Some(p.vector.len()) != dim) {
+            return Err(RagError::VectorStore(format!(
+                "Vector dimension mismatch for point {}: expected {}, got {}",
+                point.id,
+                dim.unwrap_or_default(),
+                point.vector.len()
+            )));
+        }
+
+        for point in points {
+            match stored.iter_mut().find(|p| p.id == point.id) {
+                Some(existing) => *existing = point.clone(),
+                None => stored.push(point.clone()),
+            }
+        }
+        Ok(())
+    }
+
+    async fn delete(&self, ids: &[String]) -> Result<(), RagError> {
+        self.points.write().retain(|p| !ids.contains(&p.id));
+        Ok(())
+    }
+
+    fn distance(&self) -> VectorDistance {
+        self.distance
+    }
+
+    async fn search(
+        &self,
+        query_embedding: &[f32],
+        top_k: usize,
+        filter: Option<MetadataFilter>,
+    ) -> Result<Vec<VectorSearchResult>, RagError> {
+        let mut results: Vec<VectorSearchResult> = self
+            .points
+            .read()
+            .iter()
+            .filter(|p| filter.as_ref().map_or(true, |f| f.matches(&p.payload)))
+            .map(|p| {
+                let score = self.score(query_embedding, &p.vector);
+                VectorSearchResult::from_payload(p.id.clone(), score, p.payload.clone())
+            })
+            .collect();
+
+        match self.distance {
+            VectorDistance::Euclidean => results.sort_by(|a, b| a.score.total_cmp(&b.score)),
+            _ => results.sort_by(|a, b| b.score.total_cmp(&a.score)),
+        }
+        results.truncate(top_k);
+        Ok(results)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vector_store::PointPayload;
+
+    fn point(id: &str, vector: Vec<f32>) -> VectorPoint {
+        VectorPoint::new(
+            id,
+            vector,
+            PointPayload {
+                document_id: id.to_string(),
+                content: format!("content of {}", id),
+                ..Default::default()
+            },
+        )
+    }
+
+    async fn store(distance: VectorDistance) -> InMemoryVectorStore {
+        let store = InMemoryVectorStore::new(distance);
+        store
+            .upsert(&[
+                point("orthogonal", vec![0.0, 1.0]),
+                point("same", vec![2.0, 0.0]),
+                point("close", vec![1.0, 0.5]),
+                point("opposite", vec![-1.0, 0.0]),
+            ])
+            .await
+            .unwrap();
+        store
+    }
+
+    async fn ids(store: &InMemoryVectorStore, query: &[f32]) -> Vec<String> {
+        store
+            .search(query, 10, None)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|r| r.id)
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_ranking_follows_cosine_order() {
+        let store = store(VectorDistance::Cosine).await;
+        assert_eq!(
+            ids(&store, &[1.0, 0.0]).await,
+            vec!["same", "close", "orthogonal", "opposite"]
+        );
+
+        let results = store.search(&[1.0, 0.0], 2, None).await.unwrap();
+        assert_eq!(results.len(), 2);
+        assert!((results[0].score - 1.0).abs() < 1e-6);
+        assert_eq!(results[0].content, "content of same");
+    }
+
+    #[tokio::test]
+    async fn test_dot_and_euclidean_ranking() {
+        let dot = store(VectorDistance::DotProduct).await;
+        assert_eq!(
+            ids(&dot, &[1.0, 0.0]).await,
+            vec!["same", "close", "orthogonal", "opposite"]
+        );
+
+        // Nearest first: |(1,0)-(1,0.5)| = 0.5 beats |(1,0)-(2,0)| = 1
+        let euclidean = store(VectorDistance::Euclidean).await;
+        assert_eq!(
+            ids(&euclidean, &[1.0, 0.0]).await,
+            vec!["close", "same", "orthogonal", "opposite"]
+        );
+
+        // Distances turn into similarities, so min_score keeps the nearest
+        let results = euclidean.search(&[1.0, 0.0], 2, None).await.unwrap();
+        let distance = euclidean.distance();
+        assert!((distance.similarity(results[0].score) - 1.0 / 1.5).abs() < 1e-6);
+        assert!(distance.similarity(results[0].score) > distance.similarity(results[1].score));
+    }
+
+    #[tokio::test]
+    async fn test_upsert_replaces_and_delete_removes() {
+        let store = store(VectorDistance::Cosine).await;
+        store
+            .upsert(&[point("opposite", vec![1.0, 0.1])])
+            .await
+            .unwrap();
+        assert_eq!(store.len(), 4);
+        assert_eq!(ids(&store, &[1.0, 0.0]).await[1], "opposite");
+
+        store.delete(&["opposite".to_string()]).await.unwrap();
+        assert_eq!(store.len(), 3);
+
+        let err = store.upsert(&[point("bad", vec![1.0, 0.0, 0.0])]).await;
+        assert!(err.is_err());
+    }
+}
@@ -9,6 +9,7 @@
 
 use parking_lot::RwLock;
 use std::collections::HashMap;
+use voice_agent_core::DomainContext;
 
 /// Query expansion configuration
 #[derive(Debug, Clone)]
@@ -72,6 +73,8 @@ pub enum TermSource {
     Transliteration,
     /// Domain-specific expansion
     Domain,
+    /// Abbreviation/expansion from the domain config glossary
+    ConfigGlossary,
 }
 
 /// Expansion statistics
@@ -85,6 +88,8 @@ pub struct ExpansionStats {
     pub transliteration_expansions: usize,
     /// Domain expansions added
     pub domain_expansions: usize,
+    /// Config glossary expansions added
+    pub glossary_expansions: usize,
 }
 
 /// Query expander for RAG
@@ -96,6 +101,8 @@ pub struct QueryExpander {
     transliterations: RwLock<HashMap<String, Vec<String>>>,
     /// Domain-specific term expansions
     domain_terms: RwLock<HashMap<String, Vec<String>>>,
+    /// Config glossary (abbreviation <-> full form, both directions, lowercase)
+    glossary: RwLock<HashMap<String, Vec<String>>>,
     /// Stopwords (common words to filter out)
     stopwords: RwLock<std::collections::HashSet<String>>,
 }
@@ -111,6 +118,7 @@ impl QueryExpander {
             synonyms: RwLock::new(HashMap::new()),
             transliterations: RwLock::new(HashMap::new()),
             domain_terms: RwLock::new(HashMap::new()),
+            glossary: RwLock::new(HashMap::new()),
             stopwords: RwLock::new(std::collections::HashSet::new()),
         }
     }
@@ -141,6 +149,7 @@ impl QueryExpander {
             synonyms: RwLock::new(synonyms),
             transliterations: RwLock::new(transliterations),
             domain_terms: RwLock::new(HashMap::new()),
+            glossary: RwLock::new(HashMap::new()),
             stopwords: RwLock::new(stopwords.into_iter().collect()),
         }
     }
@@ -172,10 +181,39 @@ impl QueryExpander {
             synonyms: RwLock::new(synonyms),
             transliterations: RwLock::new(transliterations),
             domain_terms: RwLock::new(domain_terms),
+            glossary: RwLock::new(HashMap::new()),
             stopwords: RwLock::new(stopwords.into_iter().collect()),
         }
     }
 
+    /// Load abbreviations from the domain config glossary
+    ///
+    /// Adding "BT = balance transfer" to the domain vocabulary makes
+    /// "BT kar sakte ho" also search for "balance transfer" (and vice versa).
+    pub fn with_glossary(self, context: &DomainContext) -> Self {
+        for abbreviation in &context.abbreviations {
+            self.add_abbreviation(&abbreviation.short, &abbreviation.full);
+        }
+        self
+    }
+
+    /// Add a glossary abbreviation, expanding in both directions
+    pub fn add_abbreviation(&self, short: &str, full: &str) {
+        let short = short.trim().to_lowercase();
+        let full = full.trim().to_lowercase();
+        if short.is_empty() || full.is_empty() || short == full {
+            return;
+        }
+
+        let mut glossary = self.glossary.write();
+        for (from, to) in [(&short, &full), (&full, &short)] {
+            let entry = glossary.entry(from.clone()).or_default();
+            if !entry.contains(to) {
+                entry.push(to.clone());
+            }
+        }
+    }
+
     /// Check if a word is a stopword
     pub fn is_stopword(&self, word: &str) -> bool {
         self.stopwords.read().contains(&word.to_lowercase())
@@ -272,9 +310,35 @@ impl QueryExpander {
             }
         }
 
+        // Config glossary expansion (abbreviations match whole words, full forms as phrases)
+        if self.config.enable_synonyms {
+            let glossary = self.glossary.read();
+            for (key, expansions) in glossary.iter() {
+                let matched = if key.contains(' ') {
+                    query_lower.contains(key.as_str())
+                } else {
+                    words.contains(&key.as_str())
+                };
+                if !matched {
+                    continue;
+                }
+                for exp in expansions.iter().take(self.config.max_expansions_per_term) {
+                    if !terms.iter().any(|t| t.term == *exp) {
+                        terms.push(WeightedTerm {
+                            term: exp.clone(),
+                            weight: 1.0,
+                            source: TermSource::ConfigGlossary,
+                        });
+                        stats.glossary_expansions += 1;
+                    }
+                }
+            }
+        }
+
         let was_expanded = stats.synonym_expansions > 0
             || stats.transliteration_expansions > 0
-            || stats.domain_expansions > 0;
+            || stats.domain_expansions > 0
+            || stats.glossary_expansions > 0;
 
         ExpandedQuery {
             original: query.to_string(),
@@ -502,4 +566,39 @@ mod tests {
         assert!(!expanded.was_expanded);
         assert_eq!(expanded.terms.len(), 2); // Just original terms
     }
+
+    #[test]
+    fn test_glossary_abbreviation_expansion() {
+        let context = DomainContext::from_config(
+            "test_domain",
+            vec![],
+            vec![],
+            vec![("BT".to_string(), "balance transfer".to_string())],
+            vec![],
+            vec![],
+        );
+        let expander = QueryExpander::new(QueryExpansionConfig::default()).with_glossary(&context);
+
+        let expanded = expander.expand("BT kar sakte ho");
+        assert!(expanded.was_expanded);
+        assert_eq!(expanded.stats.glossary_expansions, 1);
+
+        let term = expanded
+            .terms
+            .iter()
+            .find(|t| t.term == "balance transfer")
+            .expect("abbreviation should expand to full form");
+        assert_eq!(term.source, TermSource::ConfigGlossary);
+
+        // Full form expands back to the abbreviation
+        let reverse = expander.expand("balance transfer ka process");
+        assert!(reverse
+            .terms
+            .iter()
+            .any(|t| t.term == "bt" && t.source == TermSource::ConfigGlossary));
+
+        // Abbreviations only match whole words
+        let unrelated = expander.expand("subtotal");
+        assert_eq!(unrelated.stats.glossary_expansions, 0);
+    }
 }
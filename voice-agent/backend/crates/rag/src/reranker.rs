@@ -17,9 +17,16 @@
 //! 3. **Confidence Short-circuit**: Skip remaining docs if confidence is very high
 //!
 //! This provides 2-5x speedup in practice while maintaining accuracy.
+//!
+//! Every rerank records a `RerankTrace` (candidates scored, exit reason,
+//! score gap at exit), exported as metrics and attached to explain output so
+//! the exit thresholds can be tuned.
 
+use metrics::{counter, histogram};
 use parking_lot::Mutex;
+use serde::Serialize;
 use std::path::Path;
+use std::time::{Duration, Instant};
 
 #[cfg(feature = "onnx")]
 use ndarray::Array2;
@@ -28,6 +35,7 @@ use ort::{session::builder::GraphOptimizationLevel, session::Session, value::Ten
 #[cfg(feature = "onnx")]
 use tokenizers::Tokenizer;
 
+use crate::candle_embeddings::WARMUP_TEXT;
 use crate::RagError;
 
 /// Exit strategy for early exit
@@ -127,6 +135,60 @@ pub struct RerankResult {
     pub original_rank: usize,
 }
 
+/// Why a rerank stopped scoring candidates
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RerankExitReason {
+    /// Every candidate that passed the pre-filter was scored
+    Exhausted,
+    /// Enough candidates cleared `early_termination_threshold`
+    ConfidenceReached,
+    /// Candidates beyond `max_full_model_docs` were never scored
+    CandidateLimit,
+}
+
+impl RerankExitReason {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RerankExitReason::Exhausted => "exhausted",
+            RerankExitReason::ConfidenceReached => "confidence_reached",
+            RerankExitReason::CandidateLimit => "candidate_limit",
+        }
+    }
+}
+
+/// Telemetry for a single rerank call
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct RerankTrace {
+    /// Documents passed in
+    pub candidates: usize,
+    /// Documents dropped by the keyword pre-filter
+    pub prefiltered: usize,
+    /// Documents scored by the full model
+    pub scored: usize,
+    /// Why scoring stopped
+    pub exit_reason: RerankExitReason,
+    /// Margin by which the score that triggered a confidence exit cleared
+    /// `early_termination_threshold`
+    pub exit_score_gap: Option<f32>,
+}
+
+impl RerankTrace {
+    /// Export as Prometheus metrics
+    fn record(&self) {
+        counter!("voice_agent_rerank_exits_total", "reason" => self.exit_reason.as_str())
+            .increment(1);
+        histogram!("voice_agent_rerank_candidates_scored").record(self.scored as f64);
+        if self.candidates > 0 {
+            histogram!("voice_agent_rerank_scored_ratio")
+                .record(self.scored as f64 / self.candidates as f64);
+        }
+        if let Some(gap) = self.exit_score_gap {
+            histogram!("voice_agent_rerank_exit_score_gap").record(gap as f64);
+        }
+    }
+}
+
 /// Early-exit cross-encoder reranker
 ///
 /// # P0 FIX: Early-Exit Limitation with ONNX Runtime
@@ -223,6 +285,10 @@ pub struct RerankerStats {
     pub full_model_runs: usize,
     /// Early terminations (skipped remaining docs)
     pub early_terminations: usize,
+    /// Calls that stopped at `max_full_model_docs`
+    pub candidate_limit_exits: usize,
+    /// Pre-filter survivors never scored because of an early exit
+    pub candidates_skipped: usize,
     /// Total rerank calls
     pub total_calls: usize,
     /// Average docs per call sent to full model
@@ -240,6 +306,8 @@ impl Default for RerankerStats {
             prefilter_filtered: 0,
             full_model_runs: 0,
             early_terminations: 0,
+            candidate_limit_exits: 0,
+            candidates_skipped: 0,
             total_calls: 0,
             avg_full_model_docs: 0.0,
         }
@@ -310,11 +378,31 @@ impl EarlyExitReranker {
         query: &str,
         documents: &[(String, String)], // (id, text)
     ) -> Result<Vec<RerankResult>, RagError> {
-        if !self.config.cascaded_enabled {
-            return self.rerank_full(query, documents);
-        }
+        self.rerank_traced(query, documents)
+            .map(|(results, _)| results)
+    }
 
-        self.rerank_cascaded(query, documents)
+    /// Rerank documents, also returning how the call exited
+    pub fn rerank_traced(
+        &self,
+        query: &str,
+        documents: &[(String, String)], // (id, text)
+    ) -> Result<(Vec<RerankResult>, RerankTrace), RagError> {
+        let (results, trace) = if self.config.cascaded_enabled {
+            self.rerank_cascaded(query, documents)?
+        } else {
+            self.rerank_full(query, documents)?
+        };
+
+        tracing::debug!(
+            candidates = trace.candidates,
+            scored = trace.scored,
+            exit_reason = trace.exit_reason.as_str(),
+            exit_score_gap = ?trace.exit_score_gap,
+            "Rerank finished"
+        );
+        trace.record();
+        Ok((results, trace))
     }
 
     /// Full reranking without cascading (original behavior)
@@ -322,7 +410,7 @@ impl EarlyExitReranker {
         &self,
         query: &str,
         documents: &[(String, String)],
-    ) -> Result<Vec<RerankResult>, RagError> {
+    ) -> Result<(Vec<RerankResult>, RerankTrace), RagError> {
         let mut results: Vec<RerankResult> = documents
             .iter()
             .enumerate()
@@ -345,7 +433,14 @@ impl EarlyExitReranker {
         stats.total_docs += documents.len();
         stats.full_model_runs += documents.len();
 
-        Ok(results)
+        let trace = RerankTrace {
+            candidates: documents.len(),
+            prefiltered: 0,
+            scored: documents.len(),
+            exit_reason: RerankExitReason::Exhausted,
+            exit_score_gap: None,
+        };
+        Ok((results, trace))
     }
 
     /// Cascaded reranking with pre-filtering and early termination
@@ -353,7 +448,7 @@ impl EarlyExitReranker {
         &self,
         query: &str,
         documents: &[(String, String)],
-    ) -> Result<Vec<RerankResult>, RagError> {
+    ) -> Result<(Vec<RerankResult>, RerankTrace), RagError> {
         // Step 1: Fast pre-filter using keyword overlap
         let mut prefilter_scores: Vec<(usize, f32)> = documents
             .iter()
@@ -371,6 +466,7 @@ impl EarlyExitReranker {
             .count();
 
         // Take top candidates for full model (up to max_full_model_docs)
+        let passed_count = documents.len() - filtered_count;
         let candidates: Vec<(usize, f32)> = prefilter_scores
             .iter()
             .filter(|(_, score)| *score >= self.config.prefilter_threshold)
@@ -382,6 +478,7 @@ impl EarlyExitReranker {
         let mut results: Vec<RerankResult> = Vec::with_capacity(candidates.len());
         let mut high_confidence_count = 0;
         let mut early_terminated = false;
+        let mut exit_score_gap = None;
 
         for (original_idx, _prefilter_score) in &candidates {
             let (id, text) = &documents[*original_idx];
@@ -404,6 +501,7 @@ impl EarlyExitReranker {
             if high_confidence_count >= self.config.early_termination_min_results {
                 // We have enough high-confidence results, skip the rest
                 early_terminated = true;
+                exit_score_gap = Some(score - self.config.early_termination_threshold);
                 tracing::debug!(
                     "Early termination after {} docs ({} high confidence)",
                     results.len(),
@@ -413,6 +511,15 @@ impl EarlyExitReranker {
             }
         }
 
+        let scored = results.len();
+        let exit_reason = if early_terminated {
+            RerankExitReason::ConfidenceReached
+        } else if scored < passed_count {
+            RerankExitReason::CandidateLimit
+        } else {
+            RerankExitReason::Exhausted
+        };
+
         // Add filtered docs with their pre-filter scores (marked as not model-scored)
         // These go at the end since they weren't scored by the model
         for (original_idx, prefilter_score) in prefilter_scores
@@ -461,12 +568,23 @@ impl EarlyExitReranker {
         if early_terminated {
             stats.early_terminations += 1;
         }
+        if exit_reason == RerankExitReason::CandidateLimit {
+            stats.candidate_limit_exits += 1;
+        }
+        stats.candidates_skipped += passed_count - scored;
         // Update running average of docs sent to full model
         stats.avg_full_model_docs = (stats.avg_full_model_docs * (stats.total_calls - 1) as f32
             + full_model_count as f32)
             / stats.total_calls as f32;
 
-        Ok(results)
+        let trace = RerankTrace {
+            candidates: documents.len(),
+            prefiltered: filtered_count,
+            scored,
+            exit_reason,
+            exit_score_gap,
+        };
+        Ok((results, trace))
     }
 
     /// Score a query-document pair
@@ -572,6 +690,25 @@ impl EarlyExitReranker {
         }
     }
 
+    /// Score a dummy pair so the first real rerank doesn't pay for session
+    /// initialization and allocator growth
+    ///
+    /// Statistics are left untouched. Returns the warmup duration.
+    pub fn warmup(&self) -> Result<Duration, RagError> {
+        let stats = self.stats();
+        let start = Instant::now();
+        let result = self.score_pair(WARMUP_TEXT, WARMUP_TEXT);
+        *self.stats.lock() = stats;
+        result?;
+
+        let elapsed = start.elapsed();
+        tracing::info!(
+            elapsed_ms = elapsed.as_millis() as u64,
+            "Reranker warmed up"
+        );
+        Ok(elapsed)
+    }
+
     /// Get reranker statistics
     pub fn stats(&self) -> RerankerStats {
         self.stats.lock().clone()
@@ -1003,4 +1140,75 @@ mod tests {
         let stats = reranker.stats();
         assert_eq!(stats.full_model_runs, 2);
     }
+
+    fn tuning_documents() -> Vec<(String, String)> {
+        vec![
+            ("doc1".to_string(), "gold loan interest rate".to_string()),
+            ("doc2".to_string(), "gold loan interest offers".to_string()),
+            ("doc3".to_string(), "gold loan processing fee".to_string()),
+            ("doc4".to_string(), "loan interest calculation".to_string()),
+        ]
+    }
+
+    #[cfg(not(feature = "onnx"))]
+    #[test]
+    fn test_aggressive_exit_scores_fewer_candidates() {
+        let config = RerankerConfig {
+            prefilter_threshold: 0.0,
+            early_termination_threshold: 0.01,
+            early_termination_min_results: 1,
+            ..Default::default()
+        };
+        let reranker = EarlyExitReranker::simple(config);
+
+        let (_, trace) = reranker
+            .rerank_traced("gold loan interest", &tuning_documents())
+            .unwrap();
+
+        assert_eq!(trace.candidates, 4);
+        assert_eq!(trace.scored, 1);
+        assert_eq!(trace.exit_reason, RerankExitReason::ConfidenceReached);
+        assert!(trace.exit_score_gap.unwrap() >= 0.0);
+        assert_eq!(reranker.stats().candidates_skipped, 3);
+    }
+
+    #[cfg(not(feature = "onnx"))]
+    #[test]
+    fn test_conservative_exit_scores_all_candidates() {
+        let config = RerankerConfig {
+            prefilter_threshold: 0.0,
+            early_termination_threshold: 2.0,
+            ..Default::default()
+        };
+        let reranker = EarlyExitReranker::simple(config.clone());
+
+        let (results, trace) = reranker
+            .rerank_traced("gold loan interest", &tuning_documents())
+            .unwrap();
+
+        assert_eq!(results.len(), 4);
+        assert_eq!(trace.scored, 4);
+        assert_eq!(trace.exit_reason, RerankExitReason::Exhausted);
+        assert_eq!(trace.exit_score_gap, None);
+
+        // A lower candidate cap stops scoring early for a different reason
+        let capped = EarlyExitReranker::simple(RerankerConfig {
+            max_full_model_docs: 2,
+            ..config
+        });
+        let (_, trace) = capped
+            .rerank_traced("gold loan interest", &tuning_documents())
+            .unwrap();
+        assert_eq!(trace.scored, 2);
+        assert_eq!(trace.exit_reason, RerankExitReason::CandidateLimit);
+    }
+
+    #[cfg(not(feature = "onnx"))]
+    #[test]
+    fn test_warmup_leaves_stats_untouched() {
+        let reranker = EarlyExitReranker::simple(RerankerConfig::default());
+
+        assert!(reranker.warmup().is_ok());
+        assert_eq!(reranker.stats().total_docs, 0);
+    }
 }
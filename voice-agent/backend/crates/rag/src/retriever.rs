@@ -2,16 +2,21 @@
 //!
 //! Combines dense and sparse search with RRF fusion and reranking.
 
+use serde::Serialize;
+use std::collections::hash_map::Entry;
 use std::collections::HashMap;
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 // P1 FIX: Use centralized RAG constants
 use voice_agent_config::constants::rag;
 
+use crate::candle_embeddings::WARMUP_TEXT;
+use crate::domain_boost::MatchedTerm;
 use crate::embeddings::{EmbeddingConfig, SimpleEmbedder};
 use crate::query_expansion::QueryExpander;
-use crate::reranker::{EarlyExitReranker, RerankerConfig, SimpleScorer};
+use crate::reranker::{EarlyExitReranker, RerankTrace, RerankerConfig, SimpleScorer};
 use crate::sparse_search::SparseIndex;
-use crate::vector_store::{SearchFilter, VectorStore};
+use crate::vector_store::{MetadataFilter, SearchFilter, VectorStoreBackend};
 use crate::RagError;
 
 /// Retriever configuration
@@ -37,6 +42,8 @@ pub struct RetrieverConfig {
     pub prefetch_top_k: usize,
     /// P1 FIX: Enable query expansion for Hindi/Hinglish synonyms
     pub query_expansion_enabled: bool,
+    /// Attach a per-result score breakdown (debugging only, adds allocations)
+    pub explain: bool,
 }
 
 impl Default for RetrieverConfig {
@@ -54,6 +61,7 @@ impl Default for RetrieverConfig {
             prefetch_top_k: 3,
             // P1 FIX: Enable query expansion by default for Hindi/Hinglish
             query_expansion_enabled: true,
+            explain: false,
         }
     }
 }
@@ -73,6 +81,7 @@ impl From<&voice_agent_config::RagConfig> for RetrieverConfig {
             prefetch_top_k: config.prefetch_top_k,
             // P1 FIX: Default to enabled (config crate can add field later)
             query_expansion_enabled: true,
+            explain: false,
         }
     }
 }
@@ -94,6 +103,64 @@ pub struct SearchResult {
     pub source: SearchSource,
     /// Rerank exit layer (if early exit occurred)
     pub exit_layer: Option<usize>,
+    /// Score breakdown (only populated in explain mode)
+    pub explanation: Option<ScoreExplanation>,
+}
+
+/// Weight of the fused retrieval score when combining with the reranker score
+const RERANK_ORIGINAL_WEIGHT: f32 = 0.3;
+/// Weight of the reranker score when combining with the fused retrieval score
+const RERANK_SCORE_WEIGHT: f32 = 0.7;
+
+/// Per-result score breakdown for debugging relevance
+///
+/// Final ranking is `rrf_score` (or `0.3 * rrf_score + 0.7 * rerank_score`
+/// when reranking ran), multiplied by `domain_boost`.
+#[derive(Debug, Clone, Serialize)]
+pub struct ScoreExplanation {
+    /// Raw dense similarity score (if found by dense search)
+    pub dense_score: Option<f32>,
+    /// Position in dense results (0-based)
+    pub dense_rank: Option<usize>,
+    /// Raw BM25 score (if found by sparse search)
+    pub sparse_score: Option<f32>,
+    /// Position in sparse results (0-based)
+    pub sparse_rank: Option<usize>,
+    /// Weighted RRF contribution from the dense ranking
+    pub rrf_dense: f32,
+    /// Weighted RRF contribution from the sparse ranking
+    pub rrf_sparse: f32,
+    /// Fused RRF score
+    pub rrf_score: f32,
+    /// Reranker score (if reranking ran)
+    pub rerank_score: Option<f32>,
+    /// How the reranker exited for this query (if the model reranker ran)
+    pub rerank_exit: Option<RerankTrace>,
+    /// Domain boost multiplier (1.0 = no boost)
+    pub domain_boost: f32,
+    /// Domain terms that contributed to the boost
+    pub boosted_terms: Vec<MatchedTerm>,
+    /// Score used for the final ranking
+    pub final_score: f32,
+}
+
+impl Default for ScoreExplanation {
+    fn default() -> Self {
+        Self {
+            dense_score: None,
+            dense_rank: None,
+            sparse_score: None,
+            sparse_rank: None,
+            rrf_dense: 0.0,
+            rrf_sparse: 0.0,
+            rrf_score: 0.0,
+            rerank_score: None,
+            rerank_exit: None,
+            domain_boost: 1.0,
+            boosted_terms: Vec::new(),
+            final_score: 0.0,
+        }
+    }
 }
 
 /// Search source
@@ -177,13 +244,27 @@ impl HybridRetriever {
         self
     }
 
+    /// Warm up the embedder and reranker before the first query
+    ///
+    /// Returns the total warmup duration.
+    pub fn warmup(&self) -> Result<Duration, RagError> {
+        let start = Instant::now();
+        if let Some(ref embedder) = self.embedder {
+            embedder.embed(WARMUP_TEXT);
+        }
+        if let Some(ref reranker) = self.reranker {
+            reranker.warmup()?;
+        }
+        Ok(start.elapsed())
+    }
+
     /// Search with dense retrieval only
     ///
     /// P1 FIX: Embedding inference now runs in spawn_blocking to avoid blocking async runtime.
     pub async fn search_dense(
         &self,
         query: &str,
-        vector_store: &VectorStore,
+        vector_store: &dyn VectorStoreBackend,
         filter: Option<SearchFilter>,
     ) -> Result<Vec<SearchResult>, RagError> {
         let embedder = self
@@ -201,18 +282,24 @@ impl HybridRetriever {
                 .map_err(|e| RagError::Embedding(format!("Embedding task failed: {}", e)))?;
 
         let results = vector_store
-            .search(&query_embedding, self.config.dense_top_k, filter)
+            .search(
+                &query_embedding,
+                self.config.dense_top_k,
+                filter.map(MetadataFilter::from),
+            )
             .await?;
+        let distance = vector_store.distance();
 
         Ok(results
             .into_iter()
             .map(|r| SearchResult {
                 id: r.id,
                 content: r.content,
-                score: r.score,
+                score: distance.similarity(r.score),
                 metadata: r.metadata,
                 source: SearchSource::Dense,
                 exit_layer: None,
+                explanation: None,
             })
             .collect())
     }
@@ -235,6 +322,7 @@ impl HybridRetriever {
                 metadata: r.metadata,
                 source: SearchSource::Sparse,
                 exit_layer: None,
+                explanation: None,
             })
             .collect())
     }
@@ -246,8 +334,29 @@ impl HybridRetriever {
     pub async fn search(
         &self,
         query: &str,
-        vector_store: &VectorStore,
+        vector_store: &dyn VectorStoreBackend,
+        filter: Option<SearchFilter>,
+    ) -> Result<Vec<SearchResult>, RagError> {
+        self.search_impl(query, vector_store, filter, self.config.explain)
+            .await
+    }
+
+    /// Hybrid search with a `ScoreExplanation` attached to every result
+    pub async fn search_explained(
+        &self,
+        query: &str,
+        vector_store: &dyn VectorStoreBackend,
+        filter: Option<SearchFilter>,
+    ) -> Result<Vec<SearchResult>, RagError> {
+        self.search_impl(query, vector_store, filter, true).await
+    }
+
+    async fn search_impl(
+        &self,
+        query: &str,
+        vector_store: &dyn VectorStoreBackend,
         filter: Option<SearchFilter>,
+        explain: bool,
     ) -> Result<Vec<SearchResult>, RagError> {
         // P1 FIX: Expand query for better Hindi/Hinglish recall
         let expanded_query = self.expand_query(query);
@@ -279,6 +388,7 @@ impl HybridRetriever {
                             metadata: r.metadata,
                             source: SearchSource::Sparse,
                             exit_layer: None,
+                            explanation: None,
                         })
                         .collect(),
                 )
@@ -292,7 +402,7 @@ impl HybridRetriever {
         let sparse_results = sparse_result?;
 
         // Fuse results using RRF
-        let fused = self.rrf_fusion(&dense_results, &sparse_results);
+        let fused = self.rrf_fusion(&dense_results, &sparse_results, explain);
 
         // Apply reranking if enabled
         let final_results = if self.config.reranking_enabled {
@@ -312,7 +422,15 @@ impl HybridRetriever {
     }
 
     /// Reciprocal Rank Fusion
-    fn rrf_fusion(&self, dense: &[SearchResult], sparse: &[SearchResult]) -> Vec<SearchResult> {
+    ///
+    /// When `explain` is set, each result records its per-source ranks,
+    /// raw scores, and weighted RRF contributions.
+    fn rrf_fusion(
+        &self,
+        dense: &[SearchResult],
+        sparse: &[SearchResult],
+        explain: bool,
+    ) -> Vec<SearchResult> {
         let mut scores: HashMap<String, (f32, SearchResult)> = HashMap::new();
 
         // Add dense results with RRF scores
@@ -320,10 +438,17 @@ impl HybridRetriever {
             let rrf_score = 1.0 / (self.config.rrf_k + rank as f32 + 1.0);
             let weighted = rrf_score * self.config.dense_weight;
 
-            scores
+            let (score, fused) = scores
                 .entry(result.id.clone())
-                .and_modify(|(s, _)| *s += weighted)
-                .or_insert((weighted, result.clone()));
+                .or_insert_with(|| (0.0, result.clone()));
+            *score += weighted;
+
+            if explain {
+                let explanation = fused.explanation.get_or_insert_with(Default::default);
+                explanation.dense_score = Some(result.score);
+                explanation.dense_rank = Some(rank);
+                explanation.rrf_dense += weighted;
+            }
         }
 
         // Add sparse results with RRF scores
@@ -332,17 +457,26 @@ impl HybridRetriever {
             let rrf_score = 1.0 / (self.config.rrf_k + rank as f32 + 1.0);
             let weighted = rrf_score * sparse_weight;
 
-            scores
-                .entry(result.id.clone())
-                .and_modify(|(s, r)| {
-                    *s += weighted;
-                    r.source = SearchSource::Hybrid;
-                })
-                .or_insert((weighted, {
+            let (score, fused) = match scores.entry(result.id.clone()) {
+                Entry::Occupied(entry) => {
+                    let entry = entry.into_mut();
+                    entry.1.source = SearchSource::Hybrid;
+                    entry
+                },
+                Entry::Vacant(entry) => {
                     let mut r = result.clone();
                     r.source = SearchSource::Sparse;
-                    r
-                }));
+                    entry.insert((0.0, r))
+                },
+            };
+            *score += weighted;
+
+            if explain {
+                let explanation = fused.explanation.get_or_insert_with(Default::default);
+                explanation.sparse_score = Some(result.score);
+                explanation.sparse_rank = Some(rank);
+                explanation.rrf_sparse += weighted;
+            }
         }
 
         // Sort by fused score
@@ -350,6 +484,10 @@ impl HybridRetriever {
             .into_iter()
             .map(|(_, (score, mut result))| {
                 result.score = score;
+                if let Some(ref mut explanation) = result.explanation {
+                    explanation.rrf_score = score;
+                    explanation.final_score = score;
+                }
                 result
             })
             .collect();
@@ -376,7 +514,7 @@ impl HybridRetriever {
                 .collect();
 
             // Run reranking with early exit
-            let rerank_results = reranker.rerank(query, &docs)?;
+            let (rerank_results, trace) = reranker.rerank_traced(query, &docs)?;
 
             // Map back to SearchResults with updated scores and exit layers
             let id_to_result: HashMap<String, SearchResult> =
@@ -388,8 +526,13 @@ impl HybridRetriever {
                     id_to_result.get(&rr.id).map(|orig| {
                         let mut r = orig.clone();
                         // Combine original score with rerank score
-                        r.score = r.score * 0.3 + rr.score * 0.7;
+                        r.score = r.score * RERANK_ORIGINAL_WEIGHT + rr.score * RERANK_SCORE_WEIGHT;
                         r.exit_layer = rr.exit_layer;
+                        if let Some(ref mut explanation) = r.explanation {
+                            explanation.rerank_score = Some(rr.score);
+                            explanation.rerank_exit = Some(trace.clone());
+                            explanation.final_score = r.score;
+                        }
                         r
                     })
                 })
@@ -418,7 +561,11 @@ impl HybridRetriever {
             .into_iter()
             .map(|(mut r, score)| {
                 // Combine original and rerank scores
-                r.score = r.score * 0.3 + score * 0.7;
+                r.score = r.score * RERANK_ORIGINAL_WEIGHT + score * RERANK_SCORE_WEIGHT;
+                if let Some(ref mut explanation) = r.explanation {
+                    explanation.rerank_score = Some(score);
+                    explanation.final_score = r.score;
+                }
                 r
             })
             .collect())
@@ -431,7 +578,7 @@ impl HybridRetriever {
         &self,
         partial_transcript: &str,
         confidence: f32,
-        vector_store: &VectorStore,
+        vector_store: &dyn VectorStoreBackend,
     ) -> Result<Vec<SearchResult>, RagError> {
         // P2 FIX: Use configurable confidence threshold
         if confidence < self.config.prefetch_confidence_threshold {
@@ -463,16 +610,19 @@ impl HybridRetriever {
         let results = vector_store
             .search(&embedding, self.config.prefetch_top_k, None)
             .await?;
+        let distance = vector_store.distance();
 
         Ok(results
             .into_iter()
             .map(|r| SearchResult {
                 id: r.id,
                 content: r.content,
-                score: r.score * confidence, // Weight by transcript confidence
+                // Weight by transcript confidence
+                score: distance.similarity(r.score) * confidence,
                 metadata: r.metadata,
                 source: SearchSource::Dense,
                 exit_layer: None,
+                explanation: None,
             })
             .collect())
     }
@@ -522,6 +672,7 @@ mod tests {
                 metadata: HashMap::new(),
                 source: SearchSource::Dense,
                 exit_layer: None,
+                explanation: None,
             },
             SearchResult {
                 id: "2".to_string(),
@@ -530,6 +681,7 @@ mod tests {
                 metadata: HashMap::new(),
                 source: SearchSource::Dense,
                 exit_layer: None,
+                explanation: None,
             },
         ];
 
@@ -541,6 +693,7 @@ mod tests {
                 metadata: HashMap::new(),
                 source: SearchSource::Sparse,
                 exit_layer: None,
+                explanation: None,
             },
             SearchResult {
                 id: "3".to_string(),
@@ -549,10 +702,11 @@ mod tests {
                 metadata: HashMap::new(),
                 source: SearchSource::Sparse,
                 exit_layer: None,
+                explanation: None,
             },
         ];
 
-        let fused = retriever.rrf_fusion(&dense, &sparse);
+        let fused = retriever.rrf_fusion(&dense, &sparse, false);
 
         // doc2 should be ranked higher (appears in both)
         assert_eq!(fused.len(), 3);
@@ -560,6 +714,94 @@ mod tests {
         assert_eq!(doc2_result.source, SearchSource::Hybrid);
     }
 
+    fn result(id: &str, content: &str, score: f32, source: SearchSource) -> SearchResult {
+        SearchResult {
+            id: id.to_string(),
+            content: content.to_string(),
+            score,
+            metadata: HashMap::new(),
+            source,
+            exit_layer: None,
+            explanation: None,
+        }
+    }
+
+    #[test]
+    fn test_rrf_fusion_explain() {
+        let config = RetrieverConfig::default();
+        let retriever = HybridRetriever::new(config.clone(), RerankerConfig::default());
+
+        let dense = vec![
+            result("1", "gold loan rate", 0.9, SearchSource::Dense),
+            result("2", "gold loan documents", 0.8, SearchSource::Dense),
+        ];
+        let sparse = vec![result(
+            "2",
+            "gold loan documents",
+            7.5,
+            SearchSource::Sparse,
+        )];
+
+        // Explanations are not attached unless requested
+        let fused = retriever.rrf_fusion(&dense, &sparse, false);
+        assert!(fused.iter().all(|r| r.explanation.is_none()));
+
+        let fused = retriever.rrf_fusion(&dense, &sparse, true);
+        let doc2 = fused.iter().find(|r| r.id == "2").unwrap();
+        let exp = doc2.explanation.as_ref().unwrap();
+
+        assert_eq!(exp.dense_score, Some(0.8));
+        assert_eq!(exp.dense_rank, Some(1));
+        assert_eq!(exp.sparse_score, Some(7.5));
+        assert_eq!(exp.sparse_rank, Some(0));
+
+        let expected_dense = config.dense_weight / (config.rrf_k + 2.0);
+        let expected_sparse = (1.0 - config.dense_weight) / (config.rrf_k + 1.0);
+        assert!((exp.rrf_dense - expected_dense).abs() < 1e-6);
+        assert!((exp.rrf_sparse - expected_sparse).abs() < 1e-6);
+        assert!((exp.rrf_score - (exp.rrf_dense + exp.rrf_sparse)).abs() < 1e-6);
+        assert_eq!(exp.final_score, doc2.score);
+
+        let doc1 = fused.iter().find(|r| r.id == "1").unwrap();
+        let exp1 = doc1.explanation.as_ref().unwrap();
+        assert_eq!(exp1.sparse_score, None);
+        assert_eq!(exp1.rrf_sparse, 0.0);
+    }
+
+    #[test]
+    fn test_rerank_explain_combines_scores() {
+        let retriever = HybridRetriever::new(RetrieverConfig::default(), RerankerConfig::default());
+
+        let dense = vec![
+            result("1", "processing fee details", 0.9, SearchSource::Dense),
+            result("2", "gold loan interest rate", 0.8, SearchSource::Dense),
+        ];
+        let fused = retriever.rrf_fusion(&dense, &[], true);
+        let reranked = retriever.rerank("gold loan interest rate", fused).unwrap();
+
+        for r in &reranked {
+            let exp = r.explanation.as_ref().unwrap();
+            let rerank = exp.rerank_score.expect("rerank score recorded");
+            let expected = exp.rrf_score * RERANK_ORIGINAL_WEIGHT + rerank * RERANK_SCORE_WEIGHT;
+            assert!((exp.final_score - expected).abs() < 1e-6);
+            assert_eq!(exp.final_score, r.score);
+            assert_eq!(exp.domain_boost, 1.0);
+        }
+
+        // Final ranking follows the combined score
+        assert!(reranked
+            .windows(2)
+            .all(|w| w[0].explanation.as_ref().unwrap().final_score
+                >= w[1].explanation.as_ref().unwrap().final_score));
+        assert_eq!(reranked[0].id, "2");
+    }
+
+    #[test]
+    fn test_warmup_succeeds() {
+        let retriever = HybridRetriever::new(RetrieverConfig::default(), RerankerConfig::default());
+        assert!(retriever.warmup().is_ok());
+    }
+
     #[test]
     fn test_extract_keywords() {
         let keywords = HybridRetriever::extract_keywords("What is the gold loan interest rate?");
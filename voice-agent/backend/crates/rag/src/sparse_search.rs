@@ -67,6 +67,7 @@ pub struct SparseIndex {
     text_field: Field,
     title_field: Field,
     category_field: Field,
+    language_field: Field,
     config: SparseConfig,
 }
 
@@ -91,6 +92,7 @@ impl SparseIndex {
         let text_field = schema_builder.add_text_field("text", text_options.clone());
         let title_field = schema_builder.add_text_field("title", text_options);
         let category_field = schema_builder.add_text_field("category", STRING | STORED);
+        let language_field = schema_builder.add_text_field("language", STRING | STORED);
 
         let schema = schema_builder.build();
 
@@ -130,6 +132,7 @@ impl SparseIndex {
             text_field,
             title_field,
             category_field,
+            language_field,
             config,
         })
     }
@@ -170,20 +173,8 @@ impl SparseIndex {
             .ok_or_else(|| RagError::Index("Writer not available".to_string()))?;
 
         for doc in documents {
-            let mut tantivy_doc = TantivyDocument::default();
-
-            tantivy_doc.add_text(self.id_field, &doc.id);
-            tantivy_doc.add_text(self.text_field, &doc.content);
-
-            if let Some(ref title) = doc.title {
-                tantivy_doc.add_text(self.title_field, title);
-            }
-            if let Some(ref category) = doc.category {
-                tantivy_doc.add_text(self.category_field, category);
-            }
-
             writer
-                .add_document(tantivy_doc)
+                .add_document(self.to_tantivy_document(doc))
                 .map_err(|e| RagError::Index(e.to_string()))?;
         }
 
@@ -199,6 +190,81 @@ impl SparseIndex {
         Ok(())
     }
 
+    /// Incrementally add or replace documents without rebuilding the index
+    ///
+    /// Existing documents with the same ID are replaced, so this is safe to call
+    /// for live knowledge-base updates (e.g. new FAQ entries). Searches running
+    /// concurrently keep using their current searcher snapshot and see the new
+    /// documents once the commit completes and the reader is reloaded.
+    pub fn add_documents(
+        &self,
+        documents: &[super::vector_store::Document],
+    ) -> Result<usize, RagError> {
+        if documents.is_empty() {
+            return Ok(0);
+        }
+
+        {
+            let mut writer = self.writer.write();
+            let writer = writer
+                .as_mut()
+                .ok_or_else(|| RagError::Index("Writer not available".to_string()))?;
+
+            for doc in documents {
+                writer.delete_term(tantivy::Term::from_field_text(self.id_field, &doc.id));
+                writer
+                    .add_document(self.to_tantivy_document(doc))
+                    .map_err(|e| RagError::Index(e.to_string()))?;
+            }
+
+            writer
+                .commit()
+                .map_err(|e| RagError::Index(e.to_string()))?;
+        }
+
+        self.reload()?;
+
+        tracing::debug!(
+            count = documents.len(),
+            "Incrementally added documents to sparse index"
+        );
+        Ok(documents.len())
+    }
+
+    /// Delete a single document by ID and commit
+    pub fn delete_by_id(&self, id: &str) -> Result<(), RagError> {
+        self.delete(&[id.to_string()])
+    }
+
+    /// Refresh the searcher so it reflects the latest committed state
+    ///
+    /// Needed when another process or writer commits to an on-disk index.
+    pub fn reload(&self) -> Result<(), RagError> {
+        self.reader
+            .reload()
+            .map_err(|e| RagError::Index(e.to_string()))
+    }
+
+    /// Build a Tantivy document from a store document
+    fn to_tantivy_document(&self, doc: &super::vector_store::Document) -> TantivyDocument {
+        let mut tantivy_doc = TantivyDocument::default();
+
+        tantivy_doc.add_text(self.id_field, &doc.id);
+        tantivy_doc.add_text(self.text_field, &doc.content);
+
+        if let Some(ref title) = doc.title {
+            tantivy_doc.add_text(self.title_field, title);
+        }
+        if let Some(ref category) = doc.category {
+            tantivy_doc.add_text(self.category_field, category);
+        }
+        if let Some(ref language) = doc.language {
+            tantivy_doc.add_text(self.language_field, language.to_lowercase());
+        }
+
+        tantivy_doc
+    }
+
     /// Search using BM25
     pub fn search(&self, query: &str, top_k: Option<usize>) -> Result<Vec<SparseResult>, RagError> {
         let k = top_k.unwrap_or(self.config.top_k);
@@ -248,6 +314,9 @@ impl SparseIndex {
             if let Some(OwnedValue::Str(category)) = doc.get_first(self.category_field) {
                 metadata.insert("category".to_string(), category.to_string());
             }
+            if let Some(OwnedValue::Str(language)) = doc.get_first(self.language_field) {
+                metadata.insert("language".to_string(), language.to_string());
+            }
 
             results.push(SparseResult {
                 id,
@@ -262,25 +331,23 @@ impl SparseIndex {
 
     /// Delete documents by ID
     pub fn delete(&self, ids: &[String]) -> Result<(), RagError> {
-        let mut writer = self.writer.write();
-        let writer = writer
-            .as_mut()
-            .ok_or_else(|| RagError::Index("Writer not available".to_string()))?;
+        {
+            let mut writer = self.writer.write();
+            let writer = writer
+                .as_mut()
+                .ok_or_else(|| RagError::Index("Writer not available".to_string()))?;
+
+            for id in ids {
+                let term = tantivy::Term::from_field_text(self.id_field, id);
+                writer.delete_term(term);
+            }
 
-        for id in ids {
-            let term = tantivy::Term::from_field_text(self.id_field, id);
-            writer.delete_term(term);
+            writer
+                .commit()
+                .map_err(|e| RagError::Index(e.to_string()))?;
         }
 
-        writer
-            .commit()
-            .map_err(|e| RagError::Index(e.to_string()))?;
-
-        self.reader
-            .reload()
-            .map_err(|e| RagError::Index(e.to_string()))?;
-
-        Ok(())
+        self.reload()
     }
 
     /// Get document count
@@ -329,5 +396,90 @@ mod tests {
         let results = index.search("interest rate", None).unwrap();
         assert!(!results.is_empty());
         assert_eq!(results[0].id, "1");
+        assert_eq!(results[0].metadata.get("language"), Some(&"en".to_string()));
+    }
+
+    fn faq_doc(id: &str, content: &str) -> Document {
+        Document {
+            id: id.to_string(),
+            content: content.to_string(),
+            title: None,
+            category: Some("faq".to_string()),
+            language: Some("en".to_string()),
+            metadata: HashMap::new(),
+        }
+    }
+
+    #[test]
+    fn test_incremental_add_and_delete() {
+        let index = SparseIndex::new(SparseConfig::default()).unwrap();
+        index
+            .index_documents(&[faq_doc("1", "Gold loan interest rate is 10% per annum")])
+            .unwrap();
+
+        // Add a new FAQ entry to the existing index
+        let added = index
+            .add_documents(&[faq_doc(
+                "faq-new",
+                "Foreclosure charges are waived after six months",
+            )])
+            .unwrap();
+        assert_eq!(added, 1);
+        assert_eq!(index.doc_count(), 2);
+
+        let results = index.search("foreclosure charges", None).unwrap();
+        assert_eq!(results.first().map(|r| r.id.as_str()), Some("faq-new"));
+
+        // Delete it and confirm it no longer appears
+        index.delete_by_id("faq-new").unwrap();
+        assert_eq!(index.doc_count(), 1);
+        let results = index.search("foreclosure charges", None).unwrap();
+        assert!(results.iter().all(|r| r.id != "faq-new"));
+    }
+
+    #[test]
+    fn test_add_documents_replaces_existing_id() {
+        let index = SparseIndex::new(SparseConfig::default()).unwrap();
+        index
+            .add_documents(&[faq_doc("faq-1", "Processing fee is one percent")])
+            .unwrap();
+        index
+            .add_documents(&[faq_doc("faq-1", "Processing fee is waived this month")])
+            .unwrap();
+
+        assert_eq!(index.doc_count(), 1);
+        let results = index.search("waived", None).unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].id, "faq-1");
+    }
+
+    #[test]
+    fn test_concurrent_search_during_updates() {
+        use std::sync::Arc;
+
+        let index = Arc::new(SparseIndex::new(SparseConfig::default()).unwrap());
+        index
+            .index_documents(&[faq_doc("base", "Gold loan interest rate details")])
+            .unwrap();
+
+        let searcher = {
+            let index = Arc::clone(&index);
+            std::thread::spawn(move || {
+                for _ in 0..50 {
+                    let results = index.search("gold loan", None).unwrap();
+                    assert!(results.iter().any(|r| r.id == "base"));
+                }
+            })
+        };
+
+        for i in 0..10 {
+            let id = format!("faq-{}", i);
+            index
+                .add_documents(&[faq_doc(&id, "Gold loan repayment options")])
+                .unwrap();
+        }
+
+        searcher.join().unwrap();
+        assert_eq!(index.doc_count(), 11);
     }
 }
@@ -1,12 +1,18 @@
 //! Vector Store using Qdrant
 //!
 //! Dense vector storage and similarity search.
+//!
+//! Each point carries a `PointPayload` (document id, language, stage tags,
+//! source) so searches can be narrowed with a `MetadataFilter`, which is
+//! translated into a Qdrant filter. Callers hold a `VectorStoreBackend`, so
+//! `InMemoryVectorStore` can stand in for Qdrant.
 
+use async_trait::async_trait;
 use qdrant_client::{
     qdrant::{
-        value::Kind, Condition, CreateCollectionBuilder, DeletePointsBuilder, Distance,
-        FieldCondition, Filter, Match, PointId, PointStruct, PointsIdsList, SearchPointsBuilder,
-        UpsertPointsBuilder, VectorParamsBuilder,
+        r#match::MatchValue, value::Kind, Condition, CreateCollectionBuilder, DeletePointsBuilder,
+        Distance, FieldCondition, Filter, ListValue, Match, PointId, PointStruct, PointsIdsList,
+        RepeatedStrings, SearchPointsBuilder, UpsertPointsBuilder, Value, VectorParamsBuilder,
     },
     Qdrant,
 };
@@ -54,6 +60,19 @@ pub enum VectorDistance {
     DotProduct,
 }
 
+impl VectorDistance {
+    /// A search score as a similarity, higher meaning closer
+    ///
+    /// Euclidean scores are distances, lower meaning closer; they map to
+    /// `1 / (1 + distance)` so `min_score` thresholds hold for every metric.
+    pub fn similarity(self, score: f32) -> f32 {
+        match self {
+            VectorDistance::Euclidean => 1.0 / (1.0 + score.max(0.0)),
+            VectorDistance::Cosine | VectorDistance::DotProduct => score,
+        }
+    }
+}
+
 impl From<VectorDistance> for Distance {
     fn from(d: VectorDistance) -> Self {
         match d {
@@ -86,6 +105,195 @@ pub struct Document {
     pub metadata: HashMap<String, String>,
 }
 
+/// Payload keys with a fixed meaning, usable in a `MetadataFilter`
+pub mod payload_keys {
+    /// Source document ID (point IDs must be UUIDs or integers in Qdrant)
+    pub const DOCUMENT_ID: &str = "doc_id";
+    /// Document content, stored as "text" for backwards compatibility
+    pub const CONTENT: &str = "text";
+    pub const TITLE: &str = "title";
+    pub const CATEGORY: &str = "category";
+    pub const LANGUAGE: &str = "language";
+    /// Conversation stages the document is relevant to (keyword list)
+    pub const STAGES: &str = "stages";
+    /// File or system the document was loaded from
+    pub const SOURCE: &str = "source";
+}
+
+/// Structured payload stored with each point
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PointPayload {
+    /// Source document ID
+    pub document_id: String,
+    /// Document content
+    pub content: String,
+    /// Document title
+    pub title: Option<String>,
+    /// Category/type
+    pub category: Option<String>,
+    /// Language code
+    pub language: Option<String>,
+    /// Conversation stages the document is relevant to
+    #[serde(default)]
+    pub stages: Vec<String>,
+    /// File or system the document was loaded from
+    pub source: Option<String>,
+    /// Additional string metadata, stored as top-level payload fields
+    #[serde(default)]
+    pub metadata: HashMap<String, String>,
+}
+
+impl PointPayload {
+    /// Values of a payload field; lists yield one value per element
+    pub fn values(&self, key: &str) -> Vec<&str> {
+        match key {
+            payload_keys::DOCUMENT_ID => vec![self.document_id.as_str()],
+            payload_keys::CONTENT => vec![self.content.as_str()],
+            payload_keys::TITLE => self.title.as_deref().into_iter().collect(),
+            payload_keys::CATEGORY => self.category.as_deref().into_iter().collect(),
+            payload_keys::LANGUAGE => self.language.as_deref().into_iter().collect(),
+            payload_keys::SOURCE => self.source.as_deref().into_iter().collect(),
+            payload_keys::STAGES => self.stages.iter().map(|s| s.as_str()).collect(),
+            _ => self
+                .metadata
+                .get(key)
+                .map(|v| v.as_str())
+                .into_iter()
+                .collect(),
+        }
+    }
+
+    /// Flat string metadata, as exposed on search results
+    ///
+    /// Stages are joined with commas.
+    pub fn flat_metadata(&self) -> HashMap<String, String> {
+        let mut metadata = self.metadata.clone();
+        let fields = [
+            (payload_keys::TITLE, &self.title),
+            (payload_keys::CATEGORY, &self.category),
+            (payload_keys::LANGUAGE, &self.language),
+            (payload_keys::SOURCE, &self.source),
+        ];
+        for (key, value) in fields {
+            if let Some(value) = value {
+                metadata.insert(key.to_string(), value.clone());
+            }
+        }
+        if !self.stages.is_empty() {
+            metadata.insert(payload_keys::STAGES.to_string(), self.stages.join(","));
+        }
+        metadata
+    }
+
+    fn into_qdrant(self) -> HashMap<String, Value> {
+        let mut payload: HashMap<String, Value> = HashMap::new();
+        for (k, v) in self.metadata {
+            payload.insert(k, v.into());
+        }
+
+        payload.insert(
+            payload_keys::DOCUMENT_ID.to_string(),
+            self.document_id.into(),
+        );
+        // P2-2 FIX: Store as "text" in Qdrant for backwards compatibility
+        payload.insert(payload_keys::CONTENT.to_string(), self.content.into());
+        let fields = [
+            (payload_keys::TITLE, self.title),
+            (payload_keys::CATEGORY, self.category),
+            (payload_keys::LANGUAGE, self.language),
+            (payload_keys::SOURCE, self.source),
+        ];
+        for (key, value) in fields {
+            if let Some(value) = value {
+                payload.insert(key.to_string(), value.into());
+            }
+        }
+        if !self.stages.is_empty() {
+            let values = self.stages.into_iter().map(Value::from).collect();
+            payload.insert(
+                payload_keys::STAGES.to_string(),
+                Value {
+                    kind: Some(Kind::ListValue(ListValue { values })),
+                },
+            );
+        }
+
+        payload
+    }
+
+    fn from_qdrant(fields: HashMap<String, Value>) -> Self {
+        let mut payload = Self::default();
+        for (k, v) in fields {
+            match (k.as_str(), v.kind) {
+                (payload_keys::STAGES, Some(Kind::ListValue(list))) => {
+                    payload.stages = list
+                        .values
+                        .into_iter()
+                        .filter_map(|v| match v.kind {
+                            Some(Kind::StringValue(s)) => Some(s),
+                            _ => None,
+                        })
+                        .collect();
+                },
+                (key, Some(Kind::StringValue(s))) => match key {
+                    payload_keys::DOCUMENT_ID => payload.document_id = s,
+                    payload_keys::CONTENT => payload.content = s,
+                    payload_keys::TITLE => payload.title = Some(s),
+                    payload_keys::CATEGORY => payload.category = Some(s),
+                    payload_keys::LANGUAGE => payload.language = Some(s),
+                    payload_keys::SOURCE => payload.source = Some(s),
+                    _ => {
+                        payload.metadata.insert(key.to_string(), s);
+                    },
+                },
+                _ => {},
+            }
+        }
+        payload
+    }
+}
+
+impl From<&Document> for PointPayload {
+    fn from(doc: &Document) -> Self {
+        Self {
+            document_id: doc.id.clone(),
+            content: doc.content.clone(),
+            title: doc.title.clone(),
+            category: doc.category.clone(),
+            language: doc.language.clone(),
+            stages: Vec::new(),
+            source: None,
+            metadata: doc.metadata.clone(),
+        }
+    }
+}
+
+/// Vector with its payload, ready to upsert
+#[derive(Debug, Clone)]
+pub struct VectorPoint {
+    /// Point ID (a UUID or integer string for Qdrant)
+    pub id: String,
+    /// Embedding
+    pub vector: Vec<f32>,
+    /// Stored payload
+    pub payload: PointPayload,
+}
+
+impl VectorPoint {
+    pub fn new(id: impl Into<String>, vector: Vec<f32>, payload: PointPayload) -> Self {
+        Self {
+            id: id.into(),
+            vector,
+            payload,
+        }
+    }
+
+    /// Point for a document, keyed by the document ID
+    pub fn from_document(doc: &Document, vector: Vec<f32>) -> Self {
+        Self::new(doc.id.clone(), vector, PointPayload::from(doc))
+    }
+}
+
 /// Search result from vector store
 #[derive(Debug, Clone)]
 pub struct VectorSearchResult {
@@ -97,6 +305,50 @@ pub struct VectorSearchResult {
     pub content: String,
     /// Metadata
     pub metadata: HashMap<String, String>,
+    /// Full stored payload
+    pub payload: PointPayload,
+}
+
+impl VectorSearchResult {
+    /// Result for a point; the payload's document ID wins over the point ID
+    pub(crate) fn from_payload(point_id: String, score: f32, payload: PointPayload) -> Self {
+        let id = if payload.document_id.is_empty() {
+            point_id
+        } else {
+            payload.document_id.clone()
+        };
+        Self {
+            id,
+            score,
+            content: payload.content.clone(),
+            metadata: payload.flat_metadata(),
+            payload,
+        }
+    }
+}
+
+/// Dense index that stores points and answers filtered similarity searches
+///
+/// Implemented by the Qdrant-backed `VectorStore` and by
+/// `InMemoryVectorStore` for tests and single-node deployments.
+#[async_trait]
+pub trait VectorStoreBackend: Send + Sync {
+    /// Insert or replace points
+    async fn upsert(&self, points: &[VectorPoint]) -> Result<(), RagError>;
+
+    /// Delete points by ID
+    async fn delete(&self, ids: &[String]) -> Result<(), RagError>;
+
+    /// Metric the search scores are in
+    fn distance(&self) -> VectorDistance;
+
+    /// Top `top_k` points by similarity, restricted to those matching `filter`
+    async fn search(
+        &self,
+        query_embedding: &[f32],
+        top_k: usize,
+        filter: Option<MetadataFilter>,
+    ) -> Result<Vec<VectorSearchResult>, RagError>;
 }
 
 /// Vector store client
@@ -159,8 +411,29 @@ impl VectorStore {
         Ok(())
     }
 
+    /// Insert or replace points with their payloads
+    pub async fn upsert(&self, points: &[VectorPoint]) -> Result<(), RagError> {
+        let points: Vec<PointStruct> = points
+            .iter()
+            .map(|point| {
+                PointStruct::new(
+                    point.id.clone(),
+                    point.vector.clone(),
+                    point.payload.clone().into_qdrant(),
+                )
+            })
+            .collect();
+
+        self.client
+            .upsert_points(UpsertPointsBuilder::new(&self.config.collection, points))
+            .await
+            .map_err(|e| RagError::VectorStore(e.to_string()))?;
+
+        Ok(())
+    }
+
     /// Insert documents with embeddings
-    pub async fn upsert(
+    pub async fn upsert_documents(
         &self,
         documents: &[Document],
         embeddings: &[Vec<f32>],
@@ -171,38 +444,13 @@ impl VectorStore {
             ));
         }
 
-        let points: Vec<PointStruct> = documents
+        let points: Vec<VectorPoint> = documents
             .iter()
             .zip(embeddings.iter())
-            .map(|(doc, emb)| {
-                let mut payload: HashMap<String, qdrant_client::qdrant::Value> = HashMap::new();
-                // P2-2 FIX: Store as "text" in Qdrant for backwards compatibility
-                payload.insert("text".to_string(), doc.content.clone().into());
-
-                if let Some(ref title) = doc.title {
-                    payload.insert("title".to_string(), title.clone().into());
-                }
-                if let Some(ref category) = doc.category {
-                    payload.insert("category".to_string(), category.clone().into());
-                }
-                if let Some(ref language) = doc.language {
-                    payload.insert("language".to_string(), language.clone().into());
-                }
-
-                for (k, v) in &doc.metadata {
-                    payload.insert(k.clone(), v.clone().into());
-                }
-
-                PointStruct::new(doc.id.clone(), emb.clone(), payload)
-            })
+            .map(|(doc, emb)| VectorPoint::from_document(doc, emb.clone()))
             .collect();
 
-        self.client
-            .upsert_points(UpsertPointsBuilder::new(&self.config.collection, points))
-            .await
-            .map_err(|e| RagError::VectorStore(e.to_string()))?;
-
-        Ok(())
+        self.upsert(&points).await
     }
 
     /// Search by vector
@@ -210,9 +458,9 @@ impl VectorStore {
         &self,
         query_embedding: &[f32],
         top_k: usize,
-        filter: Option<SearchFilter>,
+        filter: Option<MetadataFilter>,
     ) -> Result<Vec<VectorSearchResult>, RagError> {
-        let qdrant_filter = filter.map(|f| f.into_qdrant());
+        let qdrant_filter = filter.filter(|f| !f.is_empty()).map(|f| f.into_qdrant());
 
         let mut search_builder = SearchPointsBuilder::new(
             &self.config.collection,
@@ -235,20 +483,6 @@ impl VectorStore {
             .result
             .into_iter()
             .map(|point| {
-                let mut metadata = HashMap::new();
-                let mut content = String::new();
-
-                for (k, v) in point.payload {
-                    // P2-2 FIX: "text" key in Qdrant maps to `content` field
-                    if k == "text" {
-                        if let Some(Kind::StringValue(s)) = v.kind {
-                            content = s;
-                        }
-                    } else if let Some(Kind::StringValue(s)) = v.kind {
-                        metadata.insert(k, s);
-                    }
-                }
-
                 let id = point
                     .id
                     .map(|pid| match pid.point_id_options {
@@ -260,12 +494,11 @@ impl VectorStore {
                     })
                     .unwrap_or_default();
 
-                VectorSearchResult {
+                VectorSearchResult::from_payload(
                     id,
-                    score: point.score,
-                    content,
-                    metadata,
-                }
+                    point.score,
+                    PointPayload::from_qdrant(point.payload),
+                )
             })
             .collect();
 
@@ -342,58 +575,193 @@ impl SearchFilter {
         self.language = Some(language.into());
         self
     }
+}
 
-    fn into_qdrant(self) -> Filter {
-        let mut conditions = Vec::new();
+impl Default for SearchFilter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
 
-        if let Some(category) = self.category {
-            conditions.push(Condition {
-                condition_one_of: Some(qdrant_client::qdrant::condition::ConditionOneOf::Field(
-                    FieldCondition {
-                        key: "category".to_string(),
-                        r#match: Some(Match {
-                            match_value: Some(qdrant_client::qdrant::r#match::MatchValue::Keyword(
-                                category,
-                            )),
-                        }),
-                        ..Default::default()
-                    },
-                )),
-            });
+impl From<SearchFilter> for MetadataFilter {
+    fn from(filter: SearchFilter) -> Self {
+        let mut result = MetadataFilter::new();
+        if let Some(category) = filter.category {
+            result = result.equals(payload_keys::CATEGORY, category);
+        }
+        if let Some(language) = filter.language {
+            result = result.equals(payload_keys::LANGUAGE, language);
+        }
+        let mut metadata: Vec<_> = filter.metadata.into_iter().collect();
+        metadata.sort();
+        for (key, value) in metadata {
+            result = result.equals(key, value);
         }
+        result
+    }
+}
+
+/// Condition on one payload field
+///
+/// List fields (stages) match when any element does, as in Qdrant.
+#[derive(Debug, Clone, PartialEq)]
+pub enum FilterOp {
+    /// Field equals the value
+    Eq(String),
+    /// Field equals any of the values
+    AnyOf(Vec<String>),
+    /// Field does not equal the value (points without the field match)
+    NotEq(String),
+    /// Field equals none of the values (points without the field match)
+    NoneOf(Vec<String>),
+}
+
+impl FilterOp {
+    fn matches(&self, values: &[&str]) -> bool {
+        let has = |v: &String| values.contains(&v.as_str());
+        match self {
+            FilterOp::Eq(v) => has(v),
+            FilterOp::AnyOf(vs) => vs.iter().any(has),
+            FilterOp::NotEq(v) => !has(v),
+            FilterOp::NoneOf(vs) => !vs.iter().any(has),
+        }
+    }
+
+    fn match_value(&self) -> MatchValue {
+        match self {
+            FilterOp::Eq(v) | FilterOp::NotEq(v) => MatchValue::Keyword(v.clone()),
+            FilterOp::AnyOf(vs) | FilterOp::NoneOf(vs) => MatchValue::Keywords(RepeatedStrings {
+                strings: vs.clone(),
+            }),
+        }
+    }
+
+    fn is_negated(&self) -> bool {
+        matches!(self, FilterOp::NotEq(_) | FilterOp::NoneOf(_))
+    }
+}
+
+/// Payload filter; a point matches when every condition holds
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MetadataFilter {
+    conditions: Vec<(String, FilterOp)>,
+}
+
+impl MetadataFilter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a condition on `key`
+    pub fn with(mut self, key: impl Into<String>, op: FilterOp) -> Self {
+        self.conditions.push((key.into(), op));
+        self
+    }
+
+    pub fn equals(self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.with(key, FilterOp::Eq(value.into()))
+    }
+
+    pub fn any_of<I, V>(self, key: impl Into<String>, values: I) -> Self
+    where
+        I: IntoIterator<Item = V>,
+        V: Into<String>,
+    {
+        self.with(
+            key,
+            FilterOp::AnyOf(values.into_iter().map(Into::into).collect()),
+        )
+    }
+
+    pub fn not_equals(self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.with(key, FilterOp::NotEq(value.into()))
+    }
+
+    /// Restrict to documents in `language`
+    pub fn language(self, language: impl Into<String>) -> Self {
+        self.equals(payload_keys::LANGUAGE, language)
+    }
+
+    /// Restrict to documents tagged with `stage`
+    pub fn stage(self, stage: impl Into<String>) -> Self {
+        self.equals(payload_keys::STAGES, stage)
+    }
+
+    pub fn conditions(&self) -> &[(String, FilterOp)] {
+        &self.conditions
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.conditions.is_empty()
+    }
+
+    /// Whether a payload satisfies every condition
+    pub fn matches(&self, payload: &PointPayload) -> bool {
+        self.conditions
+            .iter()
+            .all(|(key, op)| op.matches(&payload.values(key)))
+    }
 
-        if let Some(language) = self.language {
-            conditions.push(Condition {
+    /// Equivalent Qdrant filter (negated ops go to `must_not`)
+    pub fn into_qdrant(self) -> Filter {
+        let mut must = Vec::new();
+        let mut must_not = Vec::new();
+
+        for (key, op) in self.conditions {
+            let condition = Condition {
                 condition_one_of: Some(qdrant_client::qdrant::condition::ConditionOneOf::Field(
                     FieldCondition {
-                        key: "language".to_string(),
+                        key,
                         r#match: Some(Match {
-                            match_value: Some(qdrant_client::qdrant::r#match::MatchValue::Keyword(
-                                language,
-                            )),
+                            match_value: Some(op.match_value()),
                         }),
                         ..Default::default()
                     },
                 )),
-            });
+            };
+            if op.is_negated() {
+                must_not.push(condition);
+            } else {
+                must.push(condition);
+            }
         }
 
         Filter {
-            must: conditions,
+            must,
+            must_not,
             ..Default::default()
         }
     }
 }
 
-impl Default for SearchFilter {
-    fn default() -> Self {
-        Self::new()
+#[async_trait]
+impl VectorStoreBackend for VectorStore {
+    async fn upsert(&self, points: &[VectorPoint]) -> Result<(), RagError> {
+        VectorStore::upsert(self, points).await
+    }
+
+    async fn delete(&self, ids: &[String]) -> Result<(), RagError> {
+        VectorStore::delete(self, ids).await
+    }
+
+    fn distance(&self) -> VectorDistance {
+        self.config.distance
+    }
+
+    async fn search(
+        &self,
+        query_embedding: &[f32],
+        top_k: usize,
+        filter: Option<MetadataFilter>,
+    ) -> Result<Vec<VectorSearchResult>, RagError> {
+        VectorStore::search(self, query_embedding, top_k, filter).await
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::memory_store::InMemoryVectorStore;
 
     #[test]
     fn test_config_default() {
@@ -409,4 +777,81 @@ mod tests {
         assert_eq!(filter.category, Some("product".to_string()));
         assert_eq!(filter.language, Some("hi".to_string()));
     }
+
+    fn point(id: &str, vector: Vec<f32>, language: &str, stages: &[&str]) -> VectorPoint {
+        VectorPoint::new(
+            id,
+            vector,
+            PointPayload {
+                document_id: id.to_string(),
+                content: format!("content of {}", id),
+                language: Some(language.to_string()),
+                stages: stages.iter().map(|s| s.to_string()).collect(),
+                source: Some("faq.yaml".to_string()),
+                ..Default::default()
+            },
+        )
+    }
+
+    #[tokio::test]
+    async fn test_filtered_search_excludes_non_matching_points() {
+        let store = InMemoryVectorStore::default();
+        store
+            .upsert(&[
+                point("hi-rates", vec![1.0, 0.0], "hi", &["presentation"]),
+                point("en-rates", vec![1.0, 0.0], "en", &["presentation"]),
+                point("hi-docs", vec![0.9, 0.1], "hi", &["discovery", "closing"]),
+                point(
+                    "hi-both",
+                    vec![0.8, 0.2],
+                    "hi",
+                    &["presentation", "closing"],
+                ),
+            ])
+            .await
+            .unwrap();
+        assert_eq!(store.len(), 4);
+
+        let filter = MetadataFilter::new().language("hi").stage("presentation");
+        let results = store.search(&[1.0, 0.0], 10, Some(filter)).await.unwrap();
+        let ids: Vec<&str> = results.iter().map(|r| r.id.as_str()).collect();
+        assert_eq!(ids, vec!["hi-rates", "hi-both"]);
+        assert_eq!(results[0].payload.source.as_deref(), Some("faq.yaml"));
+        assert_eq!(results[1].metadata["stages"], "presentation,closing");
+
+        let filter = MetadataFilter::new().not_equals("stages", "presentation");
+        let results = store.search(&[1.0, 0.0], 10, Some(filter)).await.unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].id, "hi-docs");
+
+        let results = store.search(&[1.0, 0.0], 2, None).await.unwrap();
+        assert_eq!(results.len(), 2);
+    }
+
+    #[test]
+    fn test_metadata_filter_into_qdrant() {
+        let filter = MetadataFilter::new()
+            .language("hi")
+            .any_of("stages", ["presentation", "closing"])
+            .not_equals("source", "legacy.yaml");
+
+        let qdrant = filter.into_qdrant();
+        assert_eq!(qdrant.must.len(), 2);
+        assert_eq!(qdrant.must_not.len(), 1);
+
+        let from_legacy = MetadataFilter::from(SearchFilter::new().category("faq").language("hi"));
+        assert_eq!(
+            from_legacy,
+            MetadataFilter::new()
+                .equals("category", "faq")
+                .equals("language", "hi")
+        );
+    }
+
+    #[test]
+    fn test_payload_round_trips_through_qdrant_values() {
+        let payload = point("doc-1", vec![], "hi", &["discovery", "closing"]).payload;
+        let restored = PointPayload::from_qdrant(payload.clone().into_qdrant());
+        assert_eq!(restored, payload);
+    }
 }
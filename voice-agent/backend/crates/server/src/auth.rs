@@ -10,9 +10,10 @@ use axum::{
     response::{IntoResponse, Response},
 };
 use parking_lot::RwLock;
+use sha2::{Digest, Sha256};
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
-use voice_agent_config::Settings;
+use voice_agent_config::{ScopedApiKey, Settings};
 
 /// P1 FIX: Track if we've warned about auth being disabled (warn once only)
 static AUTH_DISABLED_WARNED: AtomicBool = AtomicBool::new(false);
@@ -25,8 +26,80 @@ enum AuthCheck {
     PublicPath,
     /// Config error
     ConfigError(&'static str),
-    /// Need to check API key with this expected key
-    CheckKey(String),
+    /// Need to check the provided key against these
+    CheckKey(ExpectedKeys),
+}
+
+/// Keys accepted for a request path
+struct ExpectedKeys {
+    /// Main API key (not accepted on scoped paths)
+    api_key: Option<String>,
+    /// Keys limited to scopes
+    scoped_keys: Vec<ScopedApiKey>,
+    /// Scope the path requires, if any
+    required_scope: Option<String>,
+}
+
+/// Tenant a request's API key is limited to
+///
+/// Added to request extensions for keys with a `tenant`; handlers that list
+/// or create tenant data must stay within it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TenantScope(pub String);
+
+/// Fingerprint of the API key a request was made with
+///
+/// Added to request extensions so handlers can record who acted in the
+/// audit trail without storing the key.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyFingerprint(pub String);
+
+impl KeyFingerprint {
+    /// First 8 bytes of the key's SHA-256, as hex
+    fn of(key: &str) -> Self {
+        let digest = Sha256::digest(key.as_bytes());
+        Self(digest[..8].iter().map(|b| format!("{:02x}", b)).collect())
+    }
+}
+
+/// Result of checking a provided key
+#[derive(Debug, PartialEq, Eq)]
+enum KeyCheck {
+    Allowed,
+    InvalidKey,
+    /// Valid key without the scope the path requires
+    MissingScope,
+}
+
+impl ExpectedKeys {
+    fn check(&self, provided: &str) -> KeyCheck {
+        let is_main_key = self
+            .api_key
+            .as_deref()
+            .is_some_and(|key| constant_time_compare(provided.as_bytes(), key.as_bytes()));
+        let scoped = self
+            .scoped_keys
+            .iter()
+            .find(|k| constant_time_compare(provided.as_bytes(), k.key.as_bytes()));
+
+        match (&self.required_scope, scoped) {
+            (Some(scope), Some(key)) if key.scopes.contains(scope) => KeyCheck::Allowed,
+            (Some(_), Some(_)) => KeyCheck::MissingScope,
+            (Some(_), None) if is_main_key => KeyCheck::MissingScope,
+            (None, Some(_)) => KeyCheck::Allowed,
+            (None, None) if is_main_key => KeyCheck::Allowed,
+            _ => KeyCheck::InvalidKey,
+        }
+    }
+
+    /// Tenant the provided scoped key is limited to, if any
+    fn tenant(&self, provided: &str) -> Option<TenantScope> {
+        self.scoped_keys
+            .iter()
+            .find(|k| constant_time_compare(provided.as_bytes(), k.key.as_bytes()))
+            .and_then(|k| k.tenant.clone())
+            .map(TenantScope)
+    }
 }
 
 /// Check auth config and return what action to take
@@ -53,11 +126,23 @@ fn check_auth_config(config: &Arc<RwLock<Settings>>, path: &str) -> AuthCheck {
         return AuthCheck::PublicPath;
     }
 
-    // Get the API key from config
-    match &auth_config.api_key {
-        Some(key) if !key.is_empty() => AuthCheck::CheckKey(key.clone()),
-        _ => AuthCheck::ConfigError("Auth is enabled but no API key is configured"),
+    // Get the API keys from config
+    let api_key = auth_config.api_key.clone().filter(|key| !key.is_empty());
+    let scoped_keys: Vec<ScopedApiKey> = auth_config
+        .scoped_keys
+        .iter()
+        .filter(|k| !k.key.is_empty())
+        .cloned()
+        .collect();
+    if api_key.is_none() && scoped_keys.is_empty() {
+        return AuthCheck::ConfigError("Auth is enabled but no API key is configured");
     }
+
+    AuthCheck::CheckKey(ExpectedKeys {
+        api_key,
+        scoped_keys,
+        required_scope: auth_config.required_scope(path).map(str::to_string),
+    })
     // config_guard is dropped here
 }
 
@@ -71,7 +156,7 @@ fn check_auth_config(config: &Arc<RwLock<Settings>>, path: &str) -> AuthCheck {
 /// # Configuration
 /// Set via environment: `VOICE_AGENT__SERVER__AUTH__API_KEY=your-secret-key`
 /// Enable via: `VOICE_AGENT__SERVER__AUTH__ENABLED=true`
-pub async fn auth_middleware(request: Request, next: Next) -> Response {
+pub async fn auth_middleware(mut request: Request, next: Next) -> Response {
     // Get config from request extensions
     let config = match request.extensions().get::<Arc<RwLock<Settings>>>() {
         Some(cfg) => cfg.clone(),
@@ -100,7 +185,7 @@ pub async fn auth_middleware(request: Request, next: Next) -> Response {
             )
                 .into_response()
         },
-        AuthCheck::CheckKey(expected_key) => {
+        AuthCheck::CheckKey(expected) => {
             // Extract Authorization header
             let auth_header = request
                 .headers()
@@ -112,16 +197,27 @@ pub async fn auth_middleware(request: Request, next: Next) -> Response {
                 Some(header) if header.starts_with("Bearer ") => {
                     let provided_key = &header[7..]; // Skip "Bearer "
 
-                    // Constant-time comparison to prevent timing attacks
-                    if constant_time_compare(provided_key.as_bytes(), expected_key.as_bytes()) {
-                        // Auth successful
-                        next.run(request).await
-                    } else {
-                        tracing::warn!(
-                            "Invalid API key provided from {:?}",
-                            request.headers().get("X-Forwarded-For")
-                        );
-                        (StatusCode::UNAUTHORIZED, "Invalid API key").into_response()
+                    match expected.check(provided_key) {
+                        KeyCheck::Allowed => {
+                            if let Some(tenant) = expected.tenant(provided_key) {
+                                request.extensions_mut().insert(tenant);
+                            }
+                            request
+                                .extensions_mut()
+                                .insert(KeyFingerprint::of(provided_key));
+                            next.run(request).await
+                        },
+                        KeyCheck::MissingScope => {
+                            tracing::warn!(path = %path, "API key lacks required scope");
+                            (StatusCode::FORBIDDEN, "API key lacks required scope").into_response()
+                        },
+                        KeyCheck::InvalidKey => {
+                            tracing::warn!(
+                                "Invalid API key provided from {:?}",
+                                request.headers().get("X-Forwarded-For")
+                            );
+                            (StatusCode::UNAUTHORIZED, "Invalid API key").into_response()
+                        },
                     }
                 },
                 Some(_) => (
@@ -159,4 +255,48 @@ mod tests {
         assert!(!constant_time_compare(b"secret", b"secreT"));
         assert!(!constant_time_compare(b"abc", b"xyz"));
     }
+
+    #[test]
+    fn test_scoped_key_check() {
+        let keys = |required_scope: Option<&str>| ExpectedKeys {
+            api_key: Some("main".to_string()),
+            scoped_keys: vec![
+                ScopedApiKey {
+                    key: "auditor".to_string(),
+                    scopes: vec!["audit:read".to_string()],
+                    tenant: None,
+                },
+                ScopedApiKey {
+                    key: "ops".to_string(),
+                    scopes: vec!["config:write".to_string()],
+                    tenant: Some("acme".to_string()),
+                },
+            ],
+            required_scope: required_scope.map(str::to_string),
+        };
+
+        let audit = keys(Some("audit:read"));
+        assert_eq!(audit.check("auditor"), KeyCheck::Allowed);
+        assert_eq!(audit.check("ops"), KeyCheck::MissingScope);
+        assert_eq!(audit.check("main"), KeyCheck::MissingScope);
+        assert_eq!(audit.check("wrong"), KeyCheck::InvalidKey);
+
+        let open = keys(None);
+        assert_eq!(open.check("main"), KeyCheck::Allowed);
+        assert_eq!(open.check("auditor"), KeyCheck::Allowed);
+        assert_eq!(open.check("wrong"), KeyCheck::InvalidKey);
+
+        assert_eq!(open.tenant("ops"), Some(TenantScope("acme".to_string())));
+        assert_eq!(open.tenant("auditor"), None);
+        assert_eq!(open.tenant("main"), None);
+    }
+
+    #[test]
+    fn test_key_fingerprint_hides_the_key() {
+        let fingerprint = KeyFingerprint::of("auditor");
+        assert_eq!(fingerprint.0.len(), 16);
+        assert!(!fingerprint.0.contains("auditor"));
+        assert_eq!(fingerprint, KeyFingerprint::of("auditor"));
+        assert_ne!(fingerprint, KeyFingerprint::of("ops"));
+    }
 }
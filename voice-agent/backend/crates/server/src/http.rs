@@ -3,26 +3,31 @@
 //! REST API for the voice agent.
 
 use axum::{
-    extract::{Json, Path, State},
-    http::{HeaderValue, Method, StatusCode},
-    response::IntoResponse,
+    extract::{Json, Path, Query, State},
+    http::{header, HeaderValue, Method, StatusCode},
+    response::{IntoResponse, Response},
     routing::{delete, get, post},
     Extension, Router,
 };
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use std::sync::Arc;
 use tower_http::compression::CompressionLayer;
 use tower_http::cors::{Any, CorsLayer};
 use tower_http::trace::TraceLayer;
 
-use crate::auth::auth_middleware;
+use crate::auth::{auth_middleware, KeyFingerprint, TenantScope};
 use crate::mcp_server::handle_mcp_request;
 use crate::metrics::metrics_handler;
 use crate::ptt;
+use crate::session::{Pagination, Session, SessionFilter};
 use crate::state::AppState;
 #[cfg(feature = "webrtc")]
 use crate::webrtc;
 use crate::websocket::{create_session, WebSocketHandler};
-use voice_agent_tools::ToolExecutor;
+use voice_agent_agent::EndReason;
+use voice_agent_persistence::{AuditCursor, AuditEventType, AuditOutcome, AuditQuery};
+use voice_agent_tools::{normalize_phone, ToolExecutor};
 
 /// Create the application router
 pub fn create_router(state: AppState) -> Router {
@@ -38,6 +43,7 @@ pub fn create_router(state: AppState) -> Router {
         .route("/api/sessions/:id", get(get_session))
         .route("/api/sessions/:id", delete(delete_session))
         .route("/api/sessions", get(list_sessions))
+        .route("/api/sessions/:id/debug/retrieval", get(retrieval_debug))
         // Chat endpoint (non-streaming)
         .route("/api/chat/:session_id", post(chat))
         // Tool endpoints
@@ -48,10 +54,19 @@ pub fn create_router(state: AppState) -> Router {
         // Health check
         .route("/health", get(health_check))
         .route("/ready", get(readiness_check))
+        .route("/readyz", get(readiness_check))
         // Prometheus metrics
         .route("/metrics", get(metrics_handler))
         // Admin endpoints
         .route("/admin/reload-config", post(reload_config))
+        .route("/admin/audit", get(query_audit_log))
+        .route("/admin/sessions", get(admin_list_sessions))
+        // Supervisor takeover and handback
+        .route("/admin/supervise/:id/takeover", post(takeover_session))
+        .route("/admin/supervise/:id/handback", post(handback_session))
+        // Customer data export (access requests)
+        .route("/customers/:id/export", get(export_customer_data))
+        .route("/admin/customers/:id/erase", post(erase_customer_data))
         // P12 FIX: Removed reload-domain-config (MasterDomainConfig loaded at startup)
         .route("/api/domain/info", get(domain_info))
         // WebSocket
@@ -151,8 +166,43 @@ async fn get_session(
     })))
 }
 
+/// Score breakdown for the session's most recent RAG search
+///
+/// Only populated when the agent runs with `agentic_rag.explain` enabled.
+async fn retrieval_debug(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> Result<Json<serde_json::Value>, StatusCode> {
+    let session = state.sessions.get(&id).ok_or(StatusCode::NOT_FOUND)?;
+
+    let results: Vec<serde_json::Value> = session
+        .agent
+        .last_retrieval_debug()
+        .into_iter()
+        .map(|r| {
+            serde_json::json!({
+                "id": r.id,
+                "score": r.score,
+                "source": format!("{:?}", r.source),
+                "explanation": r.explanation,
+            })
+        })
+        .collect();
+
+    Ok(Json(serde_json::json!({
+        "session_id": session.id,
+        "results": results,
+    })))
+}
+
 /// Delete session
+///
+/// The call is ended first, so its compliance gaps are audited and its
+/// summary reaches the CRM.
 async fn delete_session(State(state): State<AppState>, Path(id): Path<String>) -> StatusCode {
+    if let Some(session) = state.sessions.get(&id) {
+        session.agent.end_call(EndReason::UserEnded).await;
+    }
     state.sessions.remove(&id);
     StatusCode::NO_CONTENT
 }
@@ -403,6 +453,38 @@ async fn readiness_check(State(state): State<AppState>) -> (StatusCode, Json<ser
         }),
     );
 
+    // Check 3: ScyllaDB connectivity (reconnects if the cluster stopped responding)
+    if let Some(ref scylla) = state.scylla {
+        let healthy = scylla.health_check().await;
+        let pool = scylla.metrics();
+        crate::metrics::record_scylla_pool(&pool);
+        if !healthy {
+            ready = false;
+        }
+        checks.insert(
+            "scylla".to_string(),
+            serde_json::json!({
+                "status": if healthy { "ok" } else { "unreachable" },
+                "active_connections": pool.active_connections,
+                "reconnect_count": pool.reconnect_count,
+            }),
+        );
+    }
+
+    // Check 4: RAG models warmed up, so the first caller doesn't wait on model load
+    if state.vector_store.is_some() {
+        let warm = state.is_rag_warm();
+        if !warm {
+            ready = false;
+        }
+        checks.insert(
+            "rag_models".to_string(),
+            serde_json::json!({
+                "status": if warm { "ok" } else { "warming" },
+            }),
+        );
+    }
+
     let status = if ready { "ready" } else { "not_ready" };
     let status_code = if ready {
         StatusCode::OK
@@ -447,6 +529,428 @@ async fn reload_config(State(state): State<AppState>) -> impl IntoResponse {
     }
 }
 
+/// Filters for the audit log endpoint
+#[derive(Debug, Deserialize)]
+struct AuditQueryParams {
+    session_id: Option<String>,
+    event_type: Option<AuditEventType>,
+    outcome: Option<AuditOutcome>,
+    actor_type: Option<String>,
+    actor_id: Option<String>,
+    from: Option<DateTime<Utc>>,
+    to: Option<DateTime<Utc>>,
+    limit: Option<i32>,
+    cursor: Option<String>,
+}
+
+/// Audit log query endpoint for compliance investigations
+///
+/// GET /admin/audit?event_type=...&outcome=...&from=...&to=...&cursor=...
+///
+/// Returns matching entries oldest first. Requires an API key with the
+/// `audit:read` scope. Pass `next_cursor` back as `cursor` for the next page.
+async fn query_audit_log(
+    State(state): State<AppState>,
+    Query(params): Query<AuditQueryParams>,
+) -> impl IntoResponse {
+    let Some(ref logger) = state.audit_logger else {
+        return (
+            StatusCode::SERVICE_UNAVAILABLE,
+            Json(serde_json::json!({
+                "status": "error",
+                "message": "Audit logging is not configured"
+            })),
+        );
+    };
+
+    let after = match params.cursor.as_deref().map(AuditCursor::decode) {
+        Some(None) => {
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(serde_json::json!({
+                    "status": "error",
+                    "message": "Invalid cursor"
+                })),
+            );
+        },
+        Some(cursor) => cursor,
+        None => None,
+    };
+
+    let query = AuditQuery {
+        session_id: params.session_id,
+        event_type: params.event_type,
+        outcome: params.outcome,
+        actor_type: params.actor_type,
+        actor_id: params.actor_id,
+        from: params.from,
+        to: params.to,
+        limit: params.limit,
+        after,
+        ..Default::default()
+    };
+
+    match logger.query(query).await {
+        Ok(page) => (
+            StatusCode::OK,
+            Json(serde_json::json!({
+                "entries": page.entries,
+                "next_cursor": page.next_cursor.map(|c| c.encode()),
+            })),
+        ),
+        Err(e) => {
+            tracing::error!("Audit log query failed: {}", e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!({
+                    "status": "error",
+                    "message": e.to_string()
+                })),
+            )
+        },
+    }
+}
+
+/// Filters for the admin session listing
+#[derive(Debug, Deserialize)]
+struct SessionListParams {
+    language: Option<String>,
+    stage: Option<String>,
+    tenant_id: Option<String>,
+    lead_classification: Option<voice_agent_agent::LeadClassification>,
+    offset: Option<usize>,
+    limit: Option<usize>,
+}
+
+/// Active session listing for monitoring
+///
+/// GET /admin/sessions?language=hi&stage=discovery&tenant_id=...&offset=0&limit=50
+///
+/// Requires an API key with the `sessions:read` scope. Keys bound to a
+/// tenant only see that tenant's sessions.
+async fn admin_list_sessions(
+    State(state): State<AppState>,
+    scope: Option<Extension<TenantScope>>,
+    Query(params): Query<SessionListParams>,
+) -> impl IntoResponse {
+    let tenant_id = match (scope, params.tenant_id) {
+        (Some(Extension(TenantScope(own))), Some(requested)) if requested != own => {
+            return (
+                StatusCode::FORBIDDEN,
+                Json(serde_json::json!({
+                    "status": "error",
+                    "message": "API key is limited to another tenant"
+                })),
+            );
+        },
+        (Some(Extension(TenantScope(own))), _) => Some(own),
+        (None, requested) => requested,
+    };
+
+    let filter = SessionFilter {
+        language: params.language,
+        stage: params.stage,
+        tenant_id,
+        lead_classification: params.lead_classification,
+    };
+    let defaults = Pagination::default();
+    let page = Pagination {
+        offset: params.offset.unwrap_or(defaults.offset),
+        limit: params.limit.unwrap_or(defaults.limit),
+    };
+
+    let page = state.sessions.list_sessions(&filter, page);
+    (StatusCode::OK, Json(serde_json::json!(page)))
+}
+
+/// Session a supervisor may act on
+///
+/// Supervisor actions need an authenticated key (the `calls:supervise`
+/// scope is enforced by the auth middleware), so they are refused while
+/// authentication is disabled. Keys bound to a tenant only reach that
+/// tenant's sessions.
+fn supervised_session(
+    state: &AppState,
+    session_id: &str,
+    scope: Option<Extension<TenantScope>>,
+    caller: Option<Extension<KeyFingerprint>>,
+) -> Result<(Arc<Session>, String), (StatusCode, Json<serde_json::Value>)> {
+    let error = |status: StatusCode, message: &str| {
+        (
+            status,
+            Json(serde_json::json!({
+                "status": "error",
+                "message": message
+            })),
+        )
+    };
+
+    let Some(Extension(KeyFingerprint(fingerprint))) = caller else {
+        return Err(error(
+            StatusCode::UNAUTHORIZED,
+            "Supervisor actions need an authenticated API key",
+        ));
+    };
+    let session = state
+        .sessions
+        .get(session_id)
+        .ok_or_else(|| error(StatusCode::NOT_FOUND, "Session not found"))?;
+    if let Some(Extension(TenantScope(own))) = scope {
+        if session.tenant_id().as_deref() != Some(own.as_str()) {
+            return Err(error(StatusCode::NOT_FOUND, "Session not found"));
+        }
+    }
+    Ok((session, format!("key:{}", fingerprint)))
+}
+
+/// A supervisor takes a call over; the agent stops replying
+///
+/// POST /admin/supervise/{session_id}/takeover
+///
+/// Requires an API key with the `calls:supervise` scope.
+async fn takeover_session(
+    State(state): State<AppState>,
+    Path(session_id): Path<String>,
+    scope: Option<Extension<TenantScope>>,
+    caller: Option<Extension<KeyFingerprint>>,
+) -> impl IntoResponse {
+    let (session, supervisor) = match supervised_session(&state, &session_id, scope, caller) {
+        Ok(found) => found,
+        Err(error) => return error,
+    };
+
+    let taken_over = session.agent.take_over();
+    if !taken_over {
+        tracing::debug!(session_id = %session.id, "Already taken over");
+    }
+    tracing::info!(session_id = %session.id, %supervisor, "Supervisor takeover");
+    (
+        StatusCode::OK,
+        Json(serde_json::json!({
+            "session_id": session.id,
+            "taken_over": taken_over,
+        })),
+    )
+}
+
+/// Supervisor handback, with optional notes for the agent
+#[derive(Debug, Default, Deserialize)]
+struct HandbackRequest {
+    #[serde(default)]
+    notes: Option<String>,
+}
+
+/// The supervisor hands the call back to the agent
+///
+/// POST /admin/supervise/{session_id}/handback
+///
+/// Requires an API key with the `calls:supervise` scope. The customer hears
+/// the agent's acknowledgement through the session's own connection.
+async fn handback_session(
+    State(state): State<AppState>,
+    Path(session_id): Path<String>,
+    scope: Option<Extension<TenantScope>>,
+    caller: Option<Extension<KeyFingerprint>>,
+    Json(request): Json<HandbackRequest>,
+) -> impl IntoResponse {
+    let (session, supervisor) = match supervised_session(&state, &session_id, scope, caller) {
+        Ok(found) => found,
+        Err(error) => return error,
+    };
+
+    let Some(response) = session.agent.hand_back(request.notes.as_deref()).await else {
+        return (
+            StatusCode::CONFLICT,
+            Json(serde_json::json!({
+                "status": "error",
+                "message": "Call is not taken over"
+            })),
+        );
+    };
+    tracing::info!(session_id = %session.id, %supervisor, "Supervisor handback");
+    (
+        StatusCode::OK,
+        Json(serde_json::json!({
+            "session_id": session.id,
+            "response": response,
+        })),
+    )
+}
+
+/// Customer data export for access requests (DPDP)
+///
+/// GET /customers/{phone}/export
+///
+/// Returns every record held about the customer as one JSON bundle; `id` is
+/// the customer's phone number, in any common format. Requires an API key
+/// with the `customers:export` scope, so with authentication disabled every
+/// request is refused. Keys bound to a tenant are refused too, as
+/// appointments and SMS are not kept per tenant.
+/// Each export is recorded in the audit trail; without an audit log
+/// nothing is exported.
+async fn export_customer_data(
+    State(state): State<AppState>,
+    Path(customer_id): Path<String>,
+    scope: Option<Extension<TenantScope>>,
+    caller: Option<Extension<KeyFingerprint>>,
+) -> Response {
+    let error = |status: StatusCode, message: &str| {
+        (
+            status,
+            Json(serde_json::json!({
+                "status": "error",
+                "message": message
+            })),
+        )
+            .into_response()
+    };
+
+    let Some(Extension(KeyFingerprint(fingerprint))) = caller else {
+        return error(StatusCode::UNAUTHORIZED, "Customer exports need an API key");
+    };
+    if scope.is_some() {
+        return error(
+            StatusCode::FORBIDDEN,
+            "Customer exports need a key not bound to a tenant",
+        );
+    }
+    let Some(ref exporter) = state.data_export else {
+        return error(
+            StatusCode::SERVICE_UNAVAILABLE,
+            "Data export is not enabled",
+        );
+    };
+    let Some(ref logger) = state.audit_logger else {
+        return error(
+            StatusCode::SERVICE_UNAVAILABLE,
+            "Audit logging is not configured",
+        );
+    };
+
+    // Records are keyed by the normalized number
+    let customer_id = normalize_phone(&customer_id);
+    if customer_id.is_empty() {
+        return error(
+            StatusCode::BAD_REQUEST,
+            "Customer id must be a phone number",
+        );
+    }
+    let export = match exporter.export(&customer_id).await {
+        Ok(export) => export,
+        Err(e) => {
+            tracing::error!("Customer data export failed: {}", e);
+            return error(
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "Customer data could not be exported",
+            );
+        },
+    };
+
+    let requested_by = format!("key:{}", fingerprint);
+    let records = export.record_counts();
+    // Audited under a keyed hash, so the trail never holds the number
+    let customer = logger.customer_key(&customer_id);
+    if let Err(e) = logger
+        .log_data_export(&customer, &requested_by, records.clone())
+        .await
+    {
+        tracing::error!("Failed to audit customer data export: {}", e);
+        return error(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            "Export could not be recorded in the audit trail",
+        );
+    }
+    // The customer's number stays out of the logs
+    tracing::info!(%requested_by, %records, "Customer data exported");
+
+    (
+        StatusCode::OK,
+        [(header::CACHE_CONTROL, "no-store")],
+        Json(serde_json::json!(export)),
+    )
+        .into_response()
+}
+
+/// Customer data erasure for right-to-erasure requests (DPDP)
+///
+/// POST /admin/customers/{phone}/erase
+///
+/// Deletes or anonymizes every record held about the customer; `id` is the
+/// customer's phone number, in any common format. Requires an API key with
+/// the `customers:erase` scope, and like exports is refused for keys bound
+/// to a tenant. The erasure is recorded in the audit trail under a fresh
+/// erasure ID, which is returned with the record counts.
+async fn erase_customer_data(
+    State(state): State<AppState>,
+    Path(customer_id): Path<String>,
+    scope: Option<Extension<TenantScope>>,
+    caller: Option<Extension<KeyFingerprint>>,
+) -> Response {
+    let error = |status: StatusCode, message: &str| {
+        (
+            status,
+            Json(serde_json::json!({
+                "status": "error",
+                "message": message
+            })),
+        )
+            .into_response()
+    };
+
+    let Some(Extension(KeyFingerprint(fingerprint))) = caller else {
+        return error(
+            StatusCode::UNAUTHORIZED,
+            "Customer erasure needs an API key",
+        );
+    };
+    if scope.is_some() {
+        return error(
+            StatusCode::FORBIDDEN,
+            "Customer erasure needs a key not bound to a tenant",
+        );
+    }
+    let Some(ref eraser) = state.data_erasure else {
+        return error(
+            StatusCode::SERVICE_UNAVAILABLE,
+            "Data erasure is not enabled",
+        );
+    };
+    if normalize_phone(&customer_id).is_empty() {
+        return error(
+            StatusCode::BAD_REQUEST,
+            "Customer id must be a phone number",
+        );
+    }
+
+    let requested_by = format!("key:{}", fingerprint);
+    match eraser
+        .delete_customer_data(&customer_id, &requested_by)
+        .await
+    {
+        Ok(report) => {
+            // The customer's number stays out of the logs
+            tracing::info!(
+                %requested_by,
+                erasure_id = %report.erasure_id,
+                "Customer data erased on request"
+            );
+            (
+                StatusCode::OK,
+                [(header::CACHE_CONTROL, "no-store")],
+                Json(serde_json::json!(report)),
+            )
+                .into_response()
+        },
+        Err(e) => {
+            tracing::error!("Customer data erasure failed: {}", e);
+            error(
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "Customer data could not be erased",
+            )
+        },
+    }
+}
+
 /// P12 FIX: Domain config info endpoint
 ///
 /// GET /api/domain/info
@@ -528,4 +1032,138 @@ mod tests {
         let state = AppState::new(Settings::default());
         let _ = create_router(state);
     }
+
+    #[tokio::test]
+    async fn test_customer_export_needs_an_authenticated_key() {
+        let state = AppState::new(Settings::default());
+        let export = |caller| {
+            export_customer_data(
+                State(state.clone()),
+                Path("+91 98765-43210".to_string()),
+                None,
+                caller,
+            )
+        };
+
+        let refused = export(None).await;
+        assert_eq!(refused.status(), StatusCode::UNAUTHORIZED);
+
+        // Past authentication, the server has no export stores configured
+        let key = Extension(KeyFingerprint("0123456789abcdef".to_string()));
+        let unavailable = export(Some(key)).await;
+        assert_eq!(unavailable.status(), StatusCode::SERVICE_UNAVAILABLE);
+    }
+
+    #[tokio::test]
+    async fn test_customer_erasure_needs_an_authenticated_key() {
+        use voice_agent_persistence::{
+            CustomerDataEraser, InMemoryAppointmentStore, InMemoryAuditLog, InMemoryRedis,
+            InMemorySmsService, RedisSessionConfig, RedisSessionStore, SmsService, SmsType,
+        };
+
+        let sms = Arc::new(InMemorySmsService::new());
+        sms.send_sms(
+            "9876543210",
+            "Your visit is booked",
+            SmsType::AppointmentConfirmation,
+            None,
+        )
+        .await
+        .unwrap();
+        let state = AppState::new(Settings::default()).with_data_erasure(CustomerDataEraser::new(
+            Arc::new(RedisSessionStore::with_backend(
+                Arc::new(InMemoryRedis::new()),
+                RedisSessionConfig::default(),
+            )),
+            Arc::new(InMemoryAppointmentStore::new()),
+            sms.clone(),
+            Arc::new(InMemoryAuditLog::new()),
+        ));
+        let erase = |scope, caller| {
+            erase_customer_data(
+                State(state.clone()),
+                Path("+91 98765-43210".to_string()),
+                scope,
+                caller,
+            )
+        };
+        let key = || Some(Extension(KeyFingerprint("0123456789abcdef".to_string())));
+
+        assert_eq!(erase(None, None).await.status(), StatusCode::UNAUTHORIZED);
+        let tenant = Some(Extension(TenantScope("acme".to_string())));
+        assert_eq!(erase(tenant, key()).await.status(), StatusCode::FORBIDDEN);
+        assert_eq!(
+            sms.get_messages_for_phone("9876543210", 10)
+                .await
+                .unwrap()
+                .len(),
+            1
+        );
+
+        let erased = erase(None, key()).await;
+        assert_eq!(erased.status(), StatusCode::OK);
+        assert!(sms
+            .get_messages_for_phone("9876543210", 10)
+            .await
+            .unwrap()
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_supervisor_actions_need_an_authenticated_key() {
+        let state = AppState::new(Settings::default());
+        let session = state
+            .sessions
+            .create(
+                voice_agent_agent::AgentConfig::default(),
+                state.master_domain_config.clone(),
+            )
+            .unwrap();
+        let supervisor = || Some(Extension(KeyFingerprint("0123456789abcdef".to_string())));
+
+        // The customer's own (unauthenticated) client can't mute the agent
+        let refused = takeover_session(State(state.clone()), Path(session.id.clone()), None, None)
+            .await
+            .into_response();
+        assert_eq!(refused.status(), StatusCode::UNAUTHORIZED);
+        assert!(!session.agent.is_taken_over());
+
+        let taken = takeover_session(
+            State(state.clone()),
+            Path(session.id.clone()),
+            None,
+            supervisor(),
+        )
+        .await
+        .into_response();
+        assert_eq!(taken.status(), StatusCode::OK);
+        assert!(session.agent.is_taken_over());
+
+        let request = HandbackRequest {
+            notes: Some("Fee waived".to_string()),
+        };
+        let handed_back = handback_session(
+            State(state.clone()),
+            Path(session.id.clone()),
+            None,
+            supervisor(),
+            Json(request),
+        )
+        .await
+        .into_response();
+        assert_eq!(handed_back.status(), StatusCode::OK);
+        assert!(!session.agent.is_taken_over());
+
+        // A tenant-bound key can't reach another tenant's call
+        let other_tenant = Some(Extension(TenantScope("acme".to_string())));
+        let hidden = takeover_session(
+            State(state.clone()),
+            Path(session.id.clone()),
+            other_tenant,
+            supervisor(),
+        )
+        .await
+        .into_response();
+        assert_eq!(hidden.status(), StatusCode::NOT_FOUND);
+    }
 }
@@ -4,28 +4,39 @@
 
 pub mod auth;
 pub mod http;
+pub mod log_redaction;
 pub mod mcp_server;
 pub mod metrics;
+pub mod outbound;
+pub mod playout;
 pub mod ptt;
 pub mod rate_limit;
+pub mod resume;
 pub mod session;
 pub mod state;
+pub mod webhooks;
 #[cfg(feature = "webrtc")]
 pub mod webrtc;
 pub mod websocket;
 
-pub use auth::auth_middleware;
+pub use auth::{auth_middleware, TenantScope};
 pub use http::create_router;
+pub use log_redaction::{LogRedactor, RedactingFormat};
 pub use metrics::{
     init_metrics, record_error, record_llm_latency, record_request, record_stt_latency,
     record_total_latency, record_tts_latency,
 };
+pub use outbound::OutboundQueue;
+pub use playout::AudioPlayout;
 pub use rate_limit::{RateLimitError, RateLimiter};
+pub use resume::{ResumeError, ResumeTokens};
 pub use session::{
-    InMemorySessionStore, RecoverableSession, ScyllaSessionStore, Session, SessionManager,
-    SessionMetadata, SessionStore,
+    ConnectionLease, InMemorySessionStore, Pagination, RecoverableSession, RedisSessionStore,
+    ScyllaSessionStore, Session, SessionFilter, SessionManager, SessionMetadata, SessionPage,
+    SessionReporting, SessionStore,
 };
-pub use state::AppState;
+pub use state::{AppState, PersistenceServices};
+pub use webhooks::{DeadLetter, WebhookDispatcher, WebhookEvent, WebhookPayload};
 #[cfg(feature = "webrtc")]
 pub use webrtc::WebRtcSession;
 pub use websocket::WebSocketHandler;
@@ -53,6 +64,9 @@ pub enum ServerError {
     #[error("Invalid request: {0}")]
     InvalidRequest(String),
 
+    #[error("Conflict: {0}")]
+    Conflict(String),
+
     #[error("Internal error: {0}")]
     Internal(String),
 
@@ -70,6 +84,7 @@ impl From<ServerError> for axum::http::StatusCode {
             ServerError::Auth(_) => axum::http::StatusCode::UNAUTHORIZED,
             ServerError::RateLimit => axum::http::StatusCode::TOO_MANY_REQUESTS,
             ServerError::InvalidRequest(_) => axum::http::StatusCode::BAD_REQUEST,
+            ServerError::Conflict(_) => axum::http::StatusCode::CONFLICT,
             ServerError::Internal(_) => axum::http::StatusCode::INTERNAL_SERVER_ERROR,
             ServerError::Persistence(_) => axum::http::StatusCode::INTERNAL_SERVER_ERROR,
         }
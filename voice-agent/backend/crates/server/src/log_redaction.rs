@@ -0,0 +1,194 @@
+//! PII Masking for Logs
+//!
+//! Transcripts and responses are logged verbatim and carry phone, Aadhaar
+//! and PAN numbers spoken by customers. `RedactingFormat` wraps the fmt
+//! layer's event formatter: events with a user-content field (matched by
+//! name against `observability.log_content_fields`) are formatted into a
+//! buffer and masked before being written; every other event is written
+//! untouched, so the regular log path pays only a field-name check.
+//!
+//! This complements prompt-level redaction, it does not replace it.
+
+use std::collections::HashSet;
+use std::fmt;
+use std::sync::Arc;
+
+use tracing::{Event, Metadata, Subscriber};
+use tracing_subscriber::fmt::format::Writer;
+use tracing_subscriber::fmt::{FmtContext, FormatEvent, FormatFields};
+use tracing_subscriber::registry::LookupSpan;
+use tracing_subscriber::Layer;
+
+use voice_agent_config::settings::ObservabilityConfig;
+use voice_agent_core::RedactionStrategy;
+use voice_agent_text_processing::HybridPIIDetector;
+
+/// Masks PII in log events that carry user content
+pub struct LogRedactor {
+    detector: HybridPIIDetector,
+    content_fields: HashSet<String>,
+    strategy: RedactionStrategy,
+}
+
+impl LogRedactor {
+    /// Create a redactor scanning `content_fields` for `entities`
+    pub fn new(content_fields: &[String], entities: &[String]) -> Self {
+        Self {
+            detector: HybridPIIDetector::regex_only(entities),
+            content_fields: content_fields.iter().cloned().collect(),
+            strategy: RedactionStrategy::TypeMask,
+        }
+    }
+
+    /// Redactor for the configured fields, or None when log redaction is off
+    pub fn from_config(config: &ObservabilityConfig) -> Option<Arc<Self>> {
+        if !config.redact_logs || config.log_content_fields.is_empty() {
+            return None;
+        }
+        Some(Arc::new(Self::new(
+            &config.log_content_fields,
+            &config.log_redaction_entities,
+        )))
+    }
+
+    /// Whether the callsite has a field tagged as user content
+    pub fn tags(&self, metadata: &Metadata<'_>) -> bool {
+        metadata
+            .fields()
+            .iter()
+            .any(|field| self.content_fields.contains(field.name()))
+    }
+
+    /// Mask PII in a formatted log line
+    pub fn redact(&self, line: &str) -> String {
+        self.detector.redact_sync(line, &self.strategy)
+    }
+}
+
+/// Event formatter that masks PII in events tagged as user content
+pub struct RedactingFormat<E> {
+    inner: E,
+    redactor: Arc<LogRedactor>,
+}
+
+impl<E> RedactingFormat<E> {
+    /// Wrap an event formatter
+    pub fn new(inner: E, redactor: Arc<LogRedactor>) -> Self {
+        Self { inner, redactor }
+    }
+}
+
+impl<S, N, E> FormatEvent<S, N> for RedactingFormat<E>
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+    N: for<'a> FormatFields<'a> + 'static,
+    E: FormatEvent<S, N>,
+{
+    fn format_event(
+        &self,
+        ctx: &FmtContext<'_, S, N>,
+        mut writer: Writer<'_>,
+        event: &Event<'_>,
+    ) -> fmt::Result {
+        if !self.redactor.tags(event.metadata()) {
+            return self.inner.format_event(ctx, writer, event);
+        }
+
+        let mut line = String::new();
+        self.inner
+            .format_event(ctx, Writer::new(&mut line), event)?;
+        writer.write_str(&self.redactor.redact(&line))
+    }
+}
+
+/// Console/JSON fmt layer, masking user content when a redactor is given
+pub fn fmt_layer<S>(
+    log_json: bool,
+    redactor: Option<Arc<LogRedactor>>,
+) -> Box<dyn Layer<S> + Send + Sync + 'static>
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    use tracing_subscriber::fmt;
+
+    match (log_json, redactor) {
+        (true, Some(redactor)) => fmt::layer()
+            .json()
+            .event_format(RedactingFormat::new(fmt::format().json(), redactor))
+            .boxed(),
+        (true, None) => fmt::layer().json().boxed(),
+        (false, Some(redactor)) => fmt::layer()
+            .event_format(RedactingFormat::new(fmt::format(), redactor))
+            .boxed(),
+        (false, None) => fmt::layer().boxed(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use parking_lot::Mutex;
+    use tracing_subscriber::fmt::MakeWriter;
+    use tracing_subscriber::layer::SubscriberExt;
+
+    /// Writer capturing log output for assertions
+    #[derive(Clone, Default)]
+    struct Capture(Arc<Mutex<Vec<u8>>>);
+
+    impl std::io::Write for Capture {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    impl<'a> MakeWriter<'a> for Capture {
+        type Writer = Capture;
+
+        fn make_writer(&'a self) -> Self::Writer {
+            self.clone()
+        }
+    }
+
+    fn log_with_redaction(log: impl FnOnce()) -> String {
+        let capture = Capture::default();
+        let redactor = LogRedactor::from_config(&ObservabilityConfig::default()).unwrap();
+        let layer = tracing_subscriber::fmt::layer()
+            .with_writer(capture.clone())
+            .with_ansi(false)
+            .event_format(RedactingFormat::new(
+                tracing_subscriber::fmt::format().with_ansi(false),
+                redactor,
+            ));
+        let subscriber = tracing_subscriber::registry().with(layer);
+
+        tracing::subscriber::with_default(subscriber, log);
+        let output = capture.0.lock().clone();
+        String::from_utf8(output).unwrap()
+    }
+
+    #[test]
+    fn test_phone_number_masked_in_transcript() {
+        let transcript = "my number is 9876543210";
+        let output = log_with_redaction(|| {
+            tracing::info!(text = %transcript, "Final transcript");
+        });
+
+        assert!(output.contains("Final transcript"));
+        assert!(output.contains("[PHONE_NUMBER]"));
+        assert!(!output.contains("9876543210"));
+    }
+
+    #[test]
+    fn test_untagged_event_not_scanned() {
+        let output = log_with_redaction(|| {
+            tracing::info!(session_id = "9876543210", "Session created");
+        });
+
+        assert!(output.contains("9876543210"));
+    }
+}
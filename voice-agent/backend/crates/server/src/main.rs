@@ -6,10 +6,17 @@
 use std::net::SocketAddr;
 use std::path::Path;
 use std::sync::Arc;
-use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt, Layer};
-
-use voice_agent_config::{load_settings, MasterDomainConfig, Settings};
-use voice_agent_server::{create_router, init_metrics, session::ScyllaSessionStore, AppState};
+use std::time::Duration;
+use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
+
+use voice_agent_config::{
+    load_settings, MasterDomainConfig, SessionBackend, Settings, VectorBackend,
+};
+use voice_agent_server::log_redaction::{fmt_layer, LogRedactor};
+use voice_agent_server::session::{
+    RedisSessionStore, ScyllaSessionStore, SessionStore, DEFAULT_SESSION_TIMEOUT,
+};
+use voice_agent_server::{create_router, init_metrics, AppState, PersistenceServices};
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -51,6 +58,14 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         "Loaded hierarchical domain configuration"
     );
 
+    // The domain's PIN code regions replace the bundled table
+    let pincode_regions = &master_domain_config.branches.pincode_regions;
+    if !pincode_regions.is_empty() {
+        voice_agent_tools::initialize_pincode_regions(
+            pincode_regions.iter().map(Into::into).collect(),
+        );
+    }
+
     // P0 FIX: Initialize Prometheus metrics
     let _metrics_handle = init_metrics();
     tracing::info!("Initialized Prometheus metrics at /metrics");
@@ -65,7 +80,9 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                     keyspace = %config.persistence.keyspace,
                     "ScyllaDB persistence initialized"
                 );
-                let scylla_store = ScyllaSessionStore::new(persistence.sessions);
+                let scylla_client = persistence.client.clone();
+                let (session_store, customer_sessions) =
+                    init_session_store(&config, persistence.sessions).await;
                 // P2 FIX: Wire audit logging for RBI compliance
                 let audit_log: Arc<dyn voice_agent_persistence::AuditLog> =
                     Arc::new(persistence.audit);
@@ -76,15 +93,57 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 let gold_price_service: Arc<dyn voice_agent_persistence::AssetPriceService> =
                     Arc::new(persistence.asset_price);
                 tracing::info!("SMS and AssetPrice services wired into tools");
+                let scheduled_sms: Arc<dyn voice_agent_persistence::ScheduledMessageStore> =
+                    Arc::new(persistence.reminders.clone());
+                let reminders = init_reminders(&config, persistence.reminders, sms_service.clone());
+                let appointments: Arc<dyn voice_agent_persistence::AppointmentStore> =
+                    Arc::new(persistence.appointments);
+                // Leads and call summaries are kept in memory until a CRM is integrated
+                let crm: Arc<dyn voice_agent_tools::CrmIntegration> =
+                    Arc::new(voice_agent_tools::InMemoryCrm::new());
+                let data_export = config.server.data_export.enabled.then(|| {
+                    voice_agent_persistence::CustomerDataExporter::new(
+                        customer_sessions.clone(),
+                        appointments.clone(),
+                        sms_service.clone(),
+                        audit_log.clone(),
+                    )
+                    .with_max_records(config.server.data_export.max_records)
+                });
+                let data_erasure = config.server.data_erasure.enabled.then(|| {
+                    voice_agent_persistence::CustomerDataEraser::new(
+                        customer_sessions,
+                        appointments.clone(),
+                        sms_service.clone(),
+                        audit_log.clone(),
+                    )
+                    .with_scheduled_sms(scheduled_sms)
+                    .with_target(Arc::new(voice_agent_tools::CrmErasure::new(crm.clone())))
+                });
                 // P12 FIX: Use new method that only accepts MasterDomainConfig
-                AppState::with_full_persistence(
-                    config.clone(),
-                    Arc::new(scylla_store),
-                    master_domain_config.clone(),
+                let services = PersistenceServices {
                     sms_service,
                     gold_price_service,
+                    appointments,
+                    reminders,
+                    crm,
+                    audit_log,
+                };
+                let state = AppState::with_full_persistence(
+                    config.clone(),
+                    session_store,
+                    master_domain_config.clone(),
+                    services,
                 )
-                .with_audit_logger(audit_log)
+                .with_scylla_client(scylla_client);
+                let state = match data_export {
+                    Some(exporter) => state.with_data_export(exporter),
+                    None => state,
+                };
+                match data_erasure {
+                    Some(eraser) => state.with_data_erasure(eraser),
+                    None => state,
+                }
             },
             Err(e) => {
                 tracing::error!(
@@ -100,6 +159,25 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         // P12 FIX: Use new method that only accepts MasterDomainConfig
         AppState::with_master_domain_config(config.clone(), master_domain_config.clone())
     };
+    if config.server.data_export.enabled && state.data_export.is_none() {
+        tracing::warn!("Data export is enabled but needs persistence; exports will be refused");
+    }
+    if config.server.data_erasure.enabled && state.data_erasure.is_none() {
+        tracing::warn!("Data erasure is enabled but needs persistence; erasures will be refused");
+    }
+    let recording = &config.observability.recording;
+    if state.data_erasure.is_some()
+        && recording.enabled
+        && !matches!(
+            voice_agent_agent::RecordingSink::parse(&recording.sink),
+            voice_agent_agent::RecordingSink::File(_)
+        )
+    {
+        tracing::warn!(
+            sink = %recording.sink,
+            "Sessions are not recorded to a file, which erasures can't rewrite; erasures will fail"
+        );
+    }
 
     // P0 FIX: Optionally initialize VectorStore for RAG
     if config.rag.enabled {
@@ -107,11 +185,19 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         match init_vector_store(&config).await {
             Ok(vs) => {
                 tracing::info!(
+                    backend = ?config.rag.vector_backend,
                     endpoint = %config.rag.qdrant_endpoint,
                     collection = %config.rag.qdrant_collection,
                     "VectorStore initialized for RAG"
                 );
-                state = state.with_vector_store(Arc::new(vs));
+                let retriever = Arc::new(voice_agent_rag::HybridRetriever::new(
+                    voice_agent_rag::RetrieverConfig::from(&config.rag),
+                    voice_agent_rag::RerankerConfig::from(&config.rag),
+                ));
+                state = state
+                    .with_vector_store(vs)
+                    .with_retriever(retriever.clone());
+                spawn_rag_warmup(retriever, state.clone());
             },
             Err(e) => {
                 tracing::warn!(
@@ -194,6 +280,7 @@ async fn shutdown_signal() {
 #[cfg(feature = "telemetry")]
 fn init_tracing(config: &Settings) {
     use opentelemetry_otlp::WithExportConfig;
+    use tracing_subscriber::Layer;
 
     let env_filter = tracing_subscriber::EnvFilter::try_from_default_env().unwrap_or_else(|_| {
         let level = &config.observability.log_level;
@@ -201,11 +288,8 @@ fn init_tracing(config: &Settings) {
     });
 
     let subscriber = tracing_subscriber::registry().with(env_filter);
-    let fmt_layer = if config.observability.log_json {
-        tracing_subscriber::fmt::layer().json().boxed()
-    } else {
-        tracing_subscriber::fmt::layer().boxed()
-    };
+    let redactor = LogRedactor::from_config(&config.observability);
+    let fmt_layer = fmt_layer(config.observability.log_json, redactor.clone());
 
     if let Some(otlp_endpoint) = &config.observability.otlp_endpoint {
         if config.observability.tracing_enabled {
@@ -225,7 +309,12 @@ fn init_tracing(config: &Settings) {
                 .install_batch(opentelemetry_sdk::runtime::Tokio)
             {
                 Ok(tracer) => {
-                    let otel_layer = tracing_opentelemetry::layer().with_tracer(tracer);
+                    // Exported events can't be masked; keep user content out of traces
+                    let otel_layer = tracing_opentelemetry::layer()
+                        .with_tracer(tracer)
+                        .with_filter(tracing_subscriber::filter::filter_fn(move |meta| {
+                            !meta.is_event() || !redactor.as_ref().is_some_and(|r| r.tags(meta))
+                        }));
                     subscriber.with(fmt_layer).with(otel_layer).init();
                     tracing::info!(endpoint = %otlp_endpoint, "OpenTelemetry tracing enabled");
                     return;
@@ -246,11 +335,8 @@ fn init_tracing(config: &Settings) {
     });
 
     let subscriber = tracing_subscriber::registry().with(env_filter);
-    let fmt_layer = if config.observability.log_json {
-        tracing_subscriber::fmt::layer().json().boxed()
-    } else {
-        tracing_subscriber::fmt::layer().boxed()
-    };
+    let redactor = LogRedactor::from_config(&config.observability);
+    let fmt_layer = fmt_layer(config.observability.log_json, redactor);
     subscriber.with(fmt_layer).init();
 }
 
@@ -263,6 +349,8 @@ async fn init_persistence(
         hosts: config.persistence.scylla_hosts.clone(),
         keyspace: config.persistence.keyspace.clone(),
         replication_factor: config.persistence.replication_factor,
+        pool_size: config.persistence.pool_size,
+        ..Default::default()
     };
 
     // Extract tier definitions from domain config via ToolsDomainView
@@ -281,10 +369,78 @@ async fn init_persistence(
     voice_agent_persistence::init(scylla_config, base_price, tiers).await
 }
 
+/// Start the appointment reminder worker, if reminders are enabled
+fn init_reminders(
+    config: &Settings,
+    store: voice_agent_persistence::ScyllaScheduledMessageStore,
+    sms_service: Arc<dyn voice_agent_persistence::SmsService>,
+) -> Option<Arc<voice_agent_tools::AppointmentReminders>> {
+    let settings = &config.persistence.reminders;
+    if !settings.enabled {
+        return None;
+    }
+    let store: Arc<dyn voice_agent_persistence::ScheduledMessageStore> = Arc::new(store);
+    voice_agent_persistence::ReminderDispatcher::new(store.clone(), sms_service)
+        .spawn(Duration::from_secs(settings.poll_interval_secs.max(1)));
+    tracing::info!(
+        lead_time_mins = settings.lead_time_mins,
+        "Appointment reminder SMS enabled"
+    );
+    Some(Arc::new(voice_agent_tools::AppointmentReminders::new(
+        store,
+        Duration::from_secs(settings.lead_time_mins * 60),
+    )))
+}
+
+/// Pick the session store named by `persistence.session_backend`
+///
+/// Falls back to ScyllaDB if Redis is configured but unreachable. Also
+/// returns the underlying persistence store, which data exports read.
+async fn init_session_store(
+    config: &Settings,
+    scylla_sessions: voice_agent_persistence::ScyllaSessionStore,
+) -> (
+    Arc<dyn SessionStore>,
+    Arc<dyn voice_agent_persistence::SessionStore>,
+) {
+    if config.persistence.session_backend == SessionBackend::Redis {
+        let redis_config = voice_agent_persistence::RedisSessionConfig {
+            url: config.persistence.redis.url.clone(),
+            key_prefix: config.persistence.redis.key_prefix.clone(),
+            session_ttl: DEFAULT_SESSION_TIMEOUT,
+        };
+        match voice_agent_persistence::RedisSessionStore::connect(redis_config).await {
+            Ok(store) => {
+                tracing::info!("Using Redis session store");
+                return (
+                    Arc::new(RedisSessionStore::new(store.clone())),
+                    Arc::new(store),
+                );
+            },
+            Err(e) => {
+                tracing::error!(
+                    "Failed to connect to Redis: {}. Falling back to ScyllaDB sessions.",
+                    e
+                );
+            },
+        }
+    }
+    (
+        Arc::new(ScyllaSessionStore::new(scylla_sessions.clone())),
+        Arc::new(scylla_sessions),
+    )
+}
+
 /// P0 FIX: Initialize VectorStore for RAG retrieval
 async fn init_vector_store(
     config: &Settings,
-) -> Result<voice_agent_rag::VectorStore, voice_agent_rag::RagError> {
+) -> Result<Arc<dyn voice_agent_rag::VectorStoreBackend>, voice_agent_rag::RagError> {
+    if config.rag.vector_backend == VectorBackend::Memory {
+        return Ok(Arc::new(voice_agent_rag::InMemoryVectorStore::new(
+            voice_agent_rag::VectorDistance::Cosine,
+        )));
+    }
+
     let vs_config = voice_agent_rag::VectorStoreConfig {
         endpoint: config.rag.qdrant_endpoint.clone(),
         collection: config.rag.qdrant_collection.clone(),
@@ -294,7 +450,25 @@ async fn init_vector_store(
     };
     let store = voice_agent_rag::VectorStore::new(vs_config).await?;
     store.ensure_collection().await?;
-    Ok(store)
+    Ok(Arc::new(store))
+}
+
+/// Warm up the shared retriever's models in the background
+///
+/// `/readyz` reports not ready until this finishes, so traffic isn't routed
+/// to the instance while the first query would still pay for model load.
+fn spawn_rag_warmup(retriever: Arc<voice_agent_rag::HybridRetriever>, state: AppState) {
+    tokio::task::spawn_blocking(move || {
+        match retriever.warmup() {
+            Ok(elapsed) => tracing::info!(
+                elapsed_ms = elapsed.as_millis() as u64,
+                "RAG models warmed up"
+            ),
+            // Don't hold readiness forever; the first query loads the models instead
+            Err(e) => tracing::warn!("RAG warmup failed: {}. Serving with cold models.", e),
+        }
+        state.mark_rag_warm();
+    });
 }
 
 /// P12 FIX: Load hierarchical domain configuration from YAML files
@@ -29,6 +29,39 @@ pub fn init_metrics() -> PrometheusHandle {
     handle
 }
 
+/// Record ScyllaDB connection pool metrics
+pub fn record_scylla_pool(metrics: &voice_agent_persistence::PoolMetrics) {
+    gauge!("voice_agent_scylla_connections_active").set(metrics.active_connections as f64);
+    counter!("voice_agent_scylla_reconnects_total").absolute(metrics.reconnect_count);
+    counter!("voice_agent_scylla_failed_health_checks_total")
+        .absolute(metrics.failed_health_checks);
+}
+
+/// Record audio frames dropped for a WebSocket client that fell behind
+pub fn record_ws_audio_dropped(frames: u64) {
+    counter!("voice_agent_ws_audio_frames_dropped_total").increment(frames);
+}
+
+/// Record TTS audio running out mid-utterance while paced to a WebSocket client
+pub fn record_ws_audio_underrun() {
+    counter!("voice_agent_ws_audio_underruns_total").increment(1);
+}
+
+/// Record a webhook delivery outcome ("delivered", "retried" or "dead_lettered")
+pub fn record_webhook_delivery(outcome: &'static str) {
+    counter!("voice_agent_webhook_deliveries_total", "outcome" => outcome).increment(1);
+}
+
+/// Record TTS playback cut short, by reason (user barge-in vs system)
+pub fn record_tts_interruption(reason: voice_agent_pipeline::InterruptionReason) {
+    counter!("voice_agent_tts_interruptions_total", "reason" => reason.as_str()).increment(1);
+}
+
+/// Record a response TTS couldn't synthesize, even after retrying
+pub fn record_tts_failure() {
+    counter!("voice_agent_tts_failures_total").increment(1);
+}
+
 /// Get the global metrics handle
 pub fn get_metrics_handle() -> Option<&'static PrometheusHandle> {
     METRICS_HANDLE.get()
@@ -44,6 +77,7 @@ fn register_default_metrics() {
     counter!("voice_agent_requests_total", "endpoint" => "health").absolute(0);
     counter!("voice_agent_requests_total", "endpoint" => "chat").absolute(0);
     counter!("voice_agent_requests_total", "endpoint" => "ws").absolute(0);
+    counter!("voice_agent_ws_audio_frames_dropped_total").absolute(0);
 
     // Pipeline metrics
     histogram!("voice_agent_stt_duration_seconds").record(0.0);
@@ -93,6 +127,19 @@ pub fn record_total_latency(duration_secs: f64) {
     histogram!("voice_agent_total_latency_seconds").record(duration_secs);
 }
 
+/// Record a finished session's LLM token usage and estimated spend
+pub fn record_session_llm_cost(summary: &voice_agent_llm::CostSummary) {
+    histogram!("voice_agent_session_llm_cost_usd").record(summary.total_cost_usd());
+    for (source, usage) in [("llm", &summary.llm), ("slm", &summary.slm)] {
+        counter!("voice_agent_llm_tokens_total", "source" => source, "kind" => "prompt")
+            .increment(usage.prompt_tokens);
+        counter!("voice_agent_llm_tokens_total", "source" => source, "kind" => "completion")
+            .increment(usage.completion_tokens);
+        counter!("voice_agent_llm_cost_usd_micros_total", "source" => source)
+            .increment((usage.cost_usd * 1_000_000.0).round() as u64);
+    }
+}
+
 /// Record error by type
 pub fn record_error(error_type: &'static str) {
     counter!("voice_agent_errors_total", "type" => error_type).increment(1);
@@ -0,0 +1,273 @@
+//! Outbound message queue for WebSocket clients
+//!
+//! Every task that talks to a client pushes into one queue and a single
+//! writer drains it into the socket, so a slow client never blocks the
+//! pipeline. Control and transcript messages are always delivered; audio
+//! frames are bounded and the oldest are dropped once a client falls behind,
+//! since stale audio is worse than a gap.
+
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use axum::extract::ws::Message;
+use parking_lot::Mutex;
+use tokio::sync::Notify;
+
+/// Default number of audio frames buffered per client
+pub const DEFAULT_MAX_AUDIO_FRAMES: usize = 50;
+
+/// Kind of queued message
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Kind {
+    /// Never dropped
+    Control,
+    /// Dropped oldest-first past the audio limit
+    Audio,
+}
+
+#[derive(Debug, Default)]
+struct QueueState {
+    items: VecDeque<(Kind, Message)>,
+    audio_len: usize,
+    closed: bool,
+}
+
+/// Per-connection outbound queue
+#[derive(Debug)]
+pub struct OutboundQueue {
+    state: Mutex<QueueState>,
+    notify: Notify,
+    max_audio_frames: usize,
+    dropped_audio: AtomicU64,
+}
+
+impl OutboundQueue {
+    /// Create a queue holding at most `max_audio_frames` audio frames
+    pub fn new(max_audio_frames: usize) -> Self {
+        Self {
+            state: Mutex::new(QueueState::default()),
+            notify: Notify::new(),
+            max_audio_frames: max_audio_frames.max(1),
+            dropped_audio: AtomicU64::new(0),
+        }
+    }
+
+    /// Queue a control or transcript message
+    ///
+    /// Returns false once the queue is closed.
+    pub fn push_control(&self, message: Message) -> bool {
+        self.push(Kind::Control, message)
+    }
+
+    /// Queue an audio frame, dropping the oldest queued frame when full
+    ///
+    /// Returns false once the queue is closed.
+    pub fn push_audio(&self, message: Message) -> bool {
+        self.push(Kind::Audio, message)
+    }
+
+    fn push(&self, kind: Kind, message: Message) -> bool {
+        let mut dropped = 0;
+        {
+            let mut state = self.state.lock();
+            if state.closed {
+                return false;
+            }
+            if kind == Kind::Audio {
+                while state.audio_len >= self.max_audio_frames {
+                    let Some(pos) = state.items.iter().position(|(k, _)| *k == Kind::Audio) else {
+                        break;
+                    };
+                    state.items.remove(pos);
+                    state.audio_len -= 1;
+                    dropped += 1;
+                }
+                state.audio_len += 1;
+            }
+            state.items.push_back((kind, message));
+        }
+
+        if dropped > 0 {
+            self.dropped_audio.fetch_add(dropped, Ordering::Relaxed);
+            crate::metrics::record_ws_audio_dropped(dropped);
+            tracing::debug!(dropped, "Client is behind, dropped stale audio frames");
+        }
+        self.notify.notify_one();
+        true
+    }
+
+    /// Wait for the next message to send
+    ///
+    /// Returns `None` once the queue is closed and drained. Meant for a
+    /// single writer task.
+    pub async fn next(&self) -> Option<Message> {
+        loop {
+            {
+                let mut state = self.state.lock();
+                if let Some((kind, message)) = state.items.pop_front() {
+                    if kind == Kind::Audio {
+                        state.audio_len -= 1;
+                    }
+                    return Some(message);
+                }
+                if state.closed {
+                    return None;
+                }
+            }
+            self.notify.notified().await;
+        }
+    }
+
+    /// Stop accepting messages; queued ones are still handed to the writer
+    pub fn close(&self) {
+        self.state.lock().closed = true;
+        self.notify.notify_one();
+    }
+
+    /// Whether the queue has been closed
+    pub fn is_closed(&self) -> bool {
+        self.state.lock().closed
+    }
+
+    /// Messages waiting to be sent
+    pub fn len(&self) -> usize {
+        self.state.lock().items.len()
+    }
+
+    /// Whether nothing is waiting to be sent
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Audio frames waiting to be sent
+    pub fn audio_len(&self) -> usize {
+        self.state.lock().audio_len
+    }
+
+    /// Audio frames dropped because the client fell behind
+    pub fn dropped_audio(&self) -> u64 {
+        self.dropped_audio.load(Ordering::Relaxed)
+    }
+}
+
+impl Default for OutboundQueue {
+    fn default() -> Self {
+        Self::new(DEFAULT_MAX_AUDIO_FRAMES)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    fn text(s: &str) -> Message {
+        Message::Text(s.to_string())
+    }
+
+    fn drain(queue: &OutboundQueue) -> Vec<String> {
+        queue.close();
+        let mut out = Vec::new();
+        while let Some(Message::Text(t)) = futures::executor::block_on(queue.next()) {
+            out.push(t);
+        }
+        out
+    }
+
+    #[test]
+    fn test_slow_consumer_drops_old_audio_keeps_control() {
+        let queue = OutboundQueue::new(3);
+
+        // Nobody is draining: the client has stalled
+        for i in 0..10 {
+            assert!(queue.push_audio(text(&format!("audio-{}", i))));
+            if i % 2 == 0 {
+                assert!(queue.push_control(text(&format!("control-{}", i))));
+            }
+        }
+
+        assert_eq!(queue.audio_len(), 3);
+        assert_eq!(queue.dropped_audio(), 7);
+
+        let sent = drain(&queue);
+        let control: Vec<_> = sent.iter().filter(|m| m.starts_with("control")).collect();
+        let audio: Vec<_> = sent.iter().filter(|m| m.starts_with("audio")).collect();
+        assert_eq!(
+            control,
+            vec![
+                "control-0",
+                "control-2",
+                "control-4",
+                "control-6",
+                "control-8"
+            ]
+        );
+        // The latest frames survive, in order
+        assert_eq!(audio, vec!["audio-7", "audio-8", "audio-9"]);
+    }
+
+    #[test]
+    fn test_control_never_dropped() {
+        let queue = OutboundQueue::new(1);
+        for i in 0..1000 {
+            queue.push_control(text(&format!("control-{}", i)));
+        }
+        assert_eq!(queue.len(), 1000);
+        assert_eq!(queue.dropped_audio(), 0);
+        assert_eq!(drain(&queue).len(), 1000);
+    }
+
+    #[test]
+    fn test_closed_queue_rejects_and_drains() {
+        let queue = OutboundQueue::new(4);
+        queue.push_control(text("last"));
+        queue.close();
+        assert!(!queue.push_control(text("late")));
+        assert!(!queue.push_audio(text("late")));
+
+        assert!(matches!(
+            futures::executor::block_on(queue.next()),
+            Some(Message::Text(t)) if t == "last"
+        ));
+        assert!(futures::executor::block_on(queue.next()).is_none());
+    }
+
+    #[tokio::test]
+    async fn test_slow_writer_receives_control_in_order() {
+        let queue = Arc::new(OutboundQueue::new(2));
+
+        let writer = {
+            let queue = queue.clone();
+            tokio::spawn(async move {
+                let mut received = Vec::new();
+                while let Some(Message::Text(t)) = queue.next().await {
+                    // A client that reads slower than we produce
+                    tokio::time::sleep(Duration::from_millis(2)).await;
+                    received.push(t);
+                }
+                received
+            })
+        };
+
+        for i in 0..20 {
+            queue.push_audio(text(&format!("audio-{}", i)));
+            queue.push_control(text(&format!("transcript-{}", i)));
+        }
+        queue.close();
+
+        let received = writer.await.unwrap();
+        let transcripts: Vec<_> = received
+            .iter()
+            .filter(|m| m.starts_with("transcript"))
+            .cloned()
+            .collect();
+        let expected: Vec<_> = (0..20).map(|i| format!("transcript-{}", i)).collect();
+        assert_eq!(transcripts, expected);
+
+        let audio = received.len() - transcripts.len();
+        assert!(queue.dropped_audio() > 0);
+        assert_eq!(audio as u64 + queue.dropped_audio(), 20);
+        assert_eq!(received.last().map(String::as_str), Some("transcript-19"));
+    }
+}
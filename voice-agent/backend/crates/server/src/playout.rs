@@ -0,0 +1,310 @@
+//! Jitter buffering and pacing of TTS audio for WebSocket clients
+//!
+//! TTS produces audio in bursts: a sentence's worth of frames at once, then
+//! nothing while the next sentence synthesizes. Clients that play frames as
+//! they arrive stutter whenever the network adds jitter on top. With a
+//! jitter target set, frames of an utterance are held back until that much
+//! audio is buffered, then handed to the outbound queue at the rate they
+//! play. If the buffer runs dry mid-utterance, it fills up to the target
+//! again before resuming. Flushing at the end of an utterance plays out
+//! what is left without waiting for the target.
+//!
+//! A barge-in clears what is buffered, except while a protected utterance
+//! (a disclosure or required question) plays: that is held for at most the
+//! pipeline's `protected_max_ms`.
+
+use std::collections::VecDeque;
+use std::sync::Arc;
+use std::time::Duration;
+
+use axum::extract::ws::Message;
+use parking_lot::Mutex;
+use tokio::sync::mpsc;
+use tokio::time::Instant;
+use voice_agent_config::AudioPlayoutConfig;
+
+use crate::outbound::OutboundQueue;
+
+enum Command {
+    Frame {
+        message: Message,
+        duration: Duration,
+    },
+    Flush,
+    Clear,
+}
+
+/// Paces TTS audio frames into a connection's outbound queue
+#[derive(Debug, Clone)]
+pub struct AudioPlayout {
+    queue: Arc<OutboundQueue>,
+    /// None when no jitter target is set and frames go straight to the queue
+    tx: Option<mpsc::UnboundedSender<Command>>,
+    /// Until when the protected utterance playing may not be cleared
+    protected_until: Arc<Mutex<Option<Instant>>>,
+}
+
+impl AudioPlayout {
+    /// Create the playout for a connection
+    ///
+    /// Spawns the pacing task when a jitter target is set; it stops once
+    /// every handle is dropped and the buffer has played out.
+    pub fn new(config: &AudioPlayoutConfig, queue: Arc<OutboundQueue>) -> Self {
+        let target = Duration::from_millis(config.jitter_target_ms);
+        if target.is_zero() {
+            return Self {
+                queue,
+                tx: None,
+                protected_until: Arc::default(),
+            };
+        }
+        let (tx, rx) = mpsc::unbounded_channel();
+        tokio::spawn(pace(rx, queue.clone(), target));
+        Self {
+            queue,
+            tx: Some(tx),
+            protected_until: Arc::default(),
+        }
+    }
+
+    /// Queue an audio frame that plays for `duration`
+    ///
+    /// Returns false once the connection is closed.
+    pub fn push(&self, message: Message, duration: Duration) -> bool {
+        match self.tx {
+            Some(ref tx) => {
+                !self.queue.is_closed() && tx.send(Command::Frame { message, duration }).is_ok()
+            },
+            None => self.queue.push_audio(message),
+        }
+    }
+
+    /// End of an utterance: play out buffered audio without waiting for the target
+    pub fn flush(&self) {
+        if let Some(ref tx) = self.tx {
+            let _ = tx.send(Command::Flush);
+        }
+    }
+
+    /// Drop buffered audio that has not been sent, e.g. after a barge-in
+    ///
+    /// Does nothing while a protected utterance plays.
+    pub fn clear(&self) {
+        if self
+            .protected_until
+            .lock()
+            .is_some_and(|until| Instant::now() < until)
+        {
+            tracing::debug!("Protected utterance playing, audio not cleared");
+            return;
+        }
+        if let Some(ref tx) = self.tx {
+            let _ = tx.send(Command::Clear);
+        }
+    }
+
+    /// Start a protected utterance, which `clear` leaves alone for at most `max`
+    pub fn protect(&self, max: Duration) {
+        *self.protected_until.lock() = Some(Instant::now() + max);
+    }
+
+    /// End the protected utterance
+    pub fn unprotect(&self) {
+        *self.protected_until.lock() = None;
+    }
+}
+
+struct Buffered {
+    message: Message,
+    duration: Duration,
+}
+
+async fn pace(
+    mut rx: mpsc::UnboundedReceiver<Command>,
+    queue: Arc<OutboundQueue>,
+    target: Duration,
+) {
+    let mut buffer: VecDeque<Buffered> = VecDeque::new();
+    let mut buffered = Duration::ZERO;
+    // Play out what is buffered without waiting for the target
+    let mut draining = false;
+    let mut open = true;
+    // When the next frame is due; None while filling the buffer
+    let mut next_at: Option<Instant> = None;
+
+    loop {
+        if next_at.is_none() && !buffer.is_empty() && (buffered >= target || draining || !open) {
+            next_at = Some(Instant::now());
+        }
+        if next_at.is_none() && !open {
+            return;
+        }
+
+        tokio::select! {
+            command = rx.recv(), if open => match command {
+                Some(Command::Frame { message, duration }) => {
+                    buffered += duration;
+                    buffer.push_back(Buffered { message, duration });
+                },
+                Some(Command::Flush) => draining = !buffer.is_empty(),
+                Some(Command::Clear) => {
+                    buffer.clear();
+                    buffered = Duration::ZERO;
+                    draining = false;
+                    next_at = None;
+                },
+                None => open = false,
+            },
+            _ = tokio::time::sleep_until(next_at.unwrap_or_else(Instant::now)),
+                if next_at.is_some() =>
+            {
+                let Some(frame) = buffer.pop_front() else {
+                    // Played out everything before the next frame arrived
+                    if !draining && open {
+                        crate::metrics::record_ws_audio_underrun();
+                        tracing::debug!("TTS audio buffer ran dry, refilling");
+                    }
+                    next_at = None;
+                    draining = false;
+                    continue;
+                };
+                buffered = buffered.saturating_sub(frame.duration);
+                // Stale frames are dropped by the queue if the client is behind
+                if !queue.push_audio(frame.message) {
+                    tracing::debug!("Connection closed, stopping TTS audio playout");
+                    return;
+                }
+                next_at = next_at.map(|at| at + frame.duration);
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FRAME: Duration = Duration::from_millis(20);
+
+    fn playout(jitter_target_ms: u64) -> (AudioPlayout, Arc<OutboundQueue>) {
+        let queue = Arc::new(OutboundQueue::new(100));
+        let config = AudioPlayoutConfig { jitter_target_ms };
+        (AudioPlayout::new(&config, queue.clone()), queue)
+    }
+
+    /// Drain the queue, recording when each frame was sent
+    async fn sent_at(queue: &OutboundQueue, frames: usize) -> Vec<(String, Duration)> {
+        let start = Instant::now();
+        let mut sent = Vec::new();
+        while sent.len() < frames {
+            match queue.next().await {
+                Some(Message::Text(t)) => sent.push((t, start.elapsed())),
+                _ => break,
+            }
+        }
+        sent
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_first_frame_held_until_buffer_fills() {
+        let (playout, queue) = playout(100);
+
+        // A burst of 3 frames is under the 100ms target
+        for i in 0..3 {
+            assert!(playout.push(Message::Text(format!("audio-{}", i)), FRAME));
+        }
+        tokio::time::sleep(Duration::from_millis(500)).await;
+        assert!(queue.is_empty());
+
+        // Two more frames fill it
+        let arrived = Instant::now();
+        for i in 3..5 {
+            playout.push(Message::Text(format!("audio-{}", i)), FRAME);
+        }
+        let sent = sent_at(&queue, 1).await;
+        assert_eq!(sent[0].0, "audio-0");
+        assert!(arrived.elapsed() < FRAME);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_bursty_frames_sent_at_playback_rate() {
+        let (playout, queue) = playout(60);
+
+        // Frames arrive in bursts: 3 at once, a synthesis gap, then 5 at once
+        for i in 0..3 {
+            playout.push(Message::Text(format!("audio-{}", i)), FRAME);
+        }
+        let writer = {
+            let queue = queue.clone();
+            tokio::spawn(async move { sent_at(&queue, 8).await })
+        };
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        for i in 3..8 {
+            playout.push(Message::Text(format!("audio-{}", i)), FRAME);
+        }
+        playout.flush();
+
+        let sent = writer.await.unwrap();
+        let order: Vec<&str> = sent.iter().map(|(t, _)| t.as_str()).collect();
+        assert_eq!(
+            order,
+            (0..8).map(|i| format!("audio-{}", i)).collect::<Vec<_>>()
+        );
+        // Evenly spaced, one frame duration apart, across the burst boundary
+        let gaps: Vec<Duration> = sent.windows(2).map(|w| w[1].1 - w[0].1).collect();
+        assert!(gaps.iter().all(|gap| *gap == FRAME), "{:?}", gaps);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_flush_plays_out_short_utterance() {
+        let (playout, queue) = playout(200);
+        playout.push(Message::Text("audio-0".to_string()), FRAME);
+        playout.push(Message::Text("audio-1".to_string()), FRAME);
+        playout.flush();
+
+        let sent = sent_at(&queue, 2).await;
+        assert_eq!(sent.len(), 2);
+        assert_eq!(sent[1].1 - sent[0].1, FRAME);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_clear_drops_unsent_audio() {
+        let (playout, queue) = playout(100);
+        for i in 0..3 {
+            playout.push(Message::Text(format!("audio-{}", i)), FRAME);
+        }
+        playout.clear();
+        playout.flush();
+        tokio::time::sleep(Duration::from_millis(200)).await;
+        assert!(queue.is_empty());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_protected_utterance_survives_clear_until_cap() {
+        let (playout, queue) = playout(100);
+        playout.protect(Duration::from_secs(8));
+        for i in 0..3 {
+            playout.push(Message::Text(format!("audio-{}", i)), FRAME);
+        }
+        playout.clear();
+        playout.flush();
+        assert_eq!(sent_at(&queue, 3).await.len(), 3);
+
+        // Past the cap a barge-in clears it again
+        tokio::time::sleep(Duration::from_secs(8)).await;
+        for i in 3..6 {
+            playout.push(Message::Text(format!("audio-{}", i)), FRAME);
+        }
+        playout.clear();
+        playout.flush();
+        tokio::time::sleep(Duration::from_millis(200)).await;
+        assert!(queue.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_no_jitter_target_sends_immediately() {
+        let (playout, queue) = playout(0);
+        assert!(playout.push(Message::Text("audio-0".to_string()), FRAME));
+        assert_eq!(queue.audio_len(), 1);
+    }
+}
@@ -479,18 +479,22 @@ pub async fn handle_ptt(
 
     // 5. Call LLM via Agent pipeline (with RAG + tools)
     let llm_start = std::time::Instant::now();
-    let (llm_response, session_id) = match process_with_agent(
+    let (llm_response, session_id, response_language) = match process_with_agent(
         &state,
         text_for_llm,
         &request.language,
         request.session_id.as_deref(),
     ).await {
-        Ok((response, sid)) => (response, sid),
+        Ok(processed) => processed,
         Err(e) => {
             tracing::error!("Agent processing failed: {}", e);
             // Fallback to basic acknowledgment - generate new session_id
             let fallback_sid = uuid::Uuid::new_v4().to_string();
-            (format_fallback_response(text_for_llm, &request.language), fallback_sid)
+            (
+                format_fallback_response(text_for_llm, &request.language),
+                fallback_sid,
+                request.language.clone(),
+            )
         }
     };
     metrics.llm_ms = llm_start.elapsed().as_millis() as u64;
@@ -499,7 +503,7 @@ pub async fn handle_ptt(
 
     // 6. Generate TTS via IndicF5 service
     let tts_start = std::time::Instant::now();
-    let audio_response = match synthesize_with_tts(&llm_response, &request.language).await {
+    let audio_response = match synthesize_with_tts(&llm_response, &response_language).await {
         Ok((audio_b64, format)) => {
             tracing::info!("TTS generated {} bytes of {} audio", audio_b64.len(), format);
             Some(audio_b64)
@@ -657,13 +661,16 @@ fn extract_wav_pcm_f32(wav_bytes: &[u8]) -> Result<Vec<f32>, String> {
 }
 
 /// Process user input through the full Agent pipeline (LLM + RAG + tools)
-/// Returns (response_text, session_id) for conversation continuity
+/// Returns (response_text, session_id, response_language) for conversation continuity
+///
+/// The response language is the one the agent resolved for the user, which
+/// may be a fallback for the requested one.
 async fn process_with_agent(
     state: &AppState,
     user_text: &str,
     language: &str,
     existing_session_id: Option<&str>,
-) -> Result<(String, String), String> {
+) -> Result<(String, String, String), String> {
     use voice_agent_agent::AgentConfig;
 
     // Try to reuse existing session if provided
@@ -703,7 +710,9 @@ async fn process_with_agent(
     // Don't remove session - keep it for conversation continuity
     // Sessions will be cleaned up by timeout/explicit end
 
-    Ok((response, session_id))
+    let response_language = session.agent.user_language().code().to_string();
+
+    Ok((response, session_id, response_language))
 }
 
 /// Create a new agent session
@@ -711,9 +720,7 @@ fn create_new_session(
     state: &AppState,
     language: &str,
 ) -> Result<std::sync::Arc<crate::session::Session>, String> {
-    use voice_agent_agent::AgentConfig;
-
-    let mut config = AgentConfig::default();
+    let mut config = state.agent_config();
     config.language = language.to_string();
 
     // P21 FIX: Pass domain config to ensure agent uses loaded domain configuration
@@ -722,6 +729,7 @@ fn create_new_session(
         .create_with_full_integration(
             config,
             state.vector_store.clone(),
+            state.retriever.clone(),
             Some(state.tools.clone()),
             state.master_domain_config.clone(),
         )
@@ -1344,16 +1352,19 @@ pub async fn handle_ptt_stream(
 
         // 5. LLM processing
         let llm_start = std::time::Instant::now();
-        let llm_response = match process_with_agent(
+        let (llm_response, response_language) = match process_with_agent(
             &state,
             text_for_llm,
             &request.language,
             Some(&session_id),
         ).await {
-            Ok((response, _sid)) => response,
+            Ok((response, _sid, language)) => (response, language),
             Err(e) => {
                 tracing::error!("Agent processing failed: {}", e);
-                format_fallback_response(text_for_llm, &request.language)
+                (
+                    format_fallback_response(text_for_llm, &request.language),
+                    request.language.clone(),
+                )
             }
         };
         metrics.llm_ms = llm_start.elapsed().as_millis() as u64;
@@ -1365,7 +1376,7 @@ pub async fn handle_ptt_stream(
 
         // 6. TTS
         let tts_start = std::time::Instant::now();
-        if let Ok((audio_b64, format)) = synthesize_with_tts(&llm_response, &request.language).await {
+        if let Ok((audio_b64, format)) = synthesize_with_tts(&llm_response, &response_language).await {
             send_event(&tx, PttEvent::AudioReady {
                 audio: audio_b64,
                 format,
@@ -0,0 +1,191 @@
+//! Session resume tokens
+//!
+//! A client gets a signed token when its session starts and presents it when
+//! reconnecting to rejoin the same session instead of starting over. Tokens
+//! carry the session ID and an expiry, are signed with HMAC-SHA256 and can
+//! be redeemed once; redeeming issues a fresh token for the next reconnect.
+//!
+//! The record of redeemed tokens is kept per process, so with a shared
+//! secret a token redeemed on one instance is not known to another.
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD as B64, Engine as _};
+use hmac::{Hmac, Mac};
+use parking_lot::Mutex;
+use sha2::Sha256;
+use thiserror::Error;
+use voice_agent_config::ResumeConfig;
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// Why a resume token was refused
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum ResumeError {
+    #[error("Malformed resume token")]
+    Malformed,
+
+    #[error("Resume token signature is invalid")]
+    InvalidSignature,
+
+    #[error("Resume token has expired")]
+    Expired,
+
+    #[error("Resume token was already used")]
+    AlreadyUsed,
+}
+
+/// Issues and redeems resume tokens
+pub struct ResumeTokens {
+    key: Vec<u8>,
+    ttl: Duration,
+    /// Redeemed token IDs with their expiry (Unix epoch milliseconds)
+    redeemed: Mutex<HashMap<String, i64>>,
+}
+
+impl ResumeTokens {
+    /// Create with a signing secret and token lifetime
+    pub fn new(secret: impl AsRef<[u8]>, ttl: Duration) -> Self {
+        Self {
+            key: secret.as_ref().to_vec(),
+            ttl,
+            redeemed: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Create from configuration, generating a secret if none is set
+    pub fn from_config(config: &ResumeConfig) -> Self {
+        let ttl = Duration::from_secs(config.token_ttl_seconds);
+        match &config.secret {
+            Some(secret) if !secret.is_empty() => Self::new(secret, ttl),
+            _ => {
+                tracing::debug!("No resume token secret configured, generating one");
+                let secret = format!("{}{}", uuid::Uuid::new_v4(), uuid::Uuid::new_v4());
+                Self::new(secret, ttl)
+            },
+        }
+    }
+
+    /// Token lifetime
+    pub fn ttl(&self) -> Duration {
+        self.ttl
+    }
+
+    /// Issue a token for `session_id`
+    pub fn issue(&self, session_id: &str) -> String {
+        let expires_ms = now_ms() + self.ttl.as_millis() as i64;
+        let token_id = uuid::Uuid::new_v4().simple().to_string();
+        let payload = format!("{}.{}.{}", B64.encode(session_id), expires_ms, token_id);
+        let signature = B64.encode(self.sign(payload.as_bytes()));
+        format!("{}.{}", payload, signature)
+    }
+
+    /// Validate `token` and mark it used
+    ///
+    /// Returns the session ID the token was issued for.
+    pub fn redeem(&self, token: &str) -> Result<String, ResumeError> {
+        let (payload, signature) = token.rsplit_once('.').ok_or(ResumeError::Malformed)?;
+        let signature = B64.decode(signature).map_err(|_| ResumeError::Malformed)?;
+
+        let mut mac = self.mac();
+        mac.update(payload.as_bytes());
+        mac.verify_slice(&signature)
+            .map_err(|_| ResumeError::InvalidSignature)?;
+
+        let mut parts = payload.split('.');
+        let (Some(session_id), Some(expires_ms), Some(token_id), None) =
+            (parts.next(), parts.next(), parts.next(), parts.next())
+        else {
+            return Err(ResumeError::Malformed);
+        };
+        let session_id = B64
+            .decode(session_id)
+            .ok()
+            .and_then(|id| String::from_utf8(id).ok())
+            .ok_or(ResumeError::Malformed)?;
+        let expires_ms: i64 = expires_ms.parse().map_err(|_| ResumeError::Malformed)?;
+
+        let now = now_ms();
+        if expires_ms <= now {
+            return Err(ResumeError::Expired);
+        }
+
+        let mut redeemed = self.redeemed.lock();
+        redeemed.retain(|_, expiry| *expiry > now);
+        if redeemed.insert(token_id.to_string(), expires_ms).is_some() {
+            return Err(ResumeError::AlreadyUsed);
+        }
+
+        Ok(session_id)
+    }
+
+    fn mac(&self) -> HmacSha256 {
+        HmacSha256::new_from_slice(&self.key).expect("HMAC accepts keys of any length")
+    }
+
+    fn sign(&self, payload: &[u8]) -> Vec<u8> {
+        let mut mac = self.mac();
+        mac.update(payload);
+        mac.finalize().into_bytes().to_vec()
+    }
+}
+
+impl std::fmt::Debug for ResumeTokens {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ResumeTokens")
+            .field("ttl", &self.ttl)
+            .finish_non_exhaustive()
+    }
+}
+
+fn now_ms() -> i64 {
+    chrono::Utc::now().timestamp_millis()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tokens() -> ResumeTokens {
+        ResumeTokens::new("test-secret", Duration::from_secs(60))
+    }
+
+    #[test]
+    fn test_redeem_returns_session_once() {
+        let tokens = tokens();
+        let token = tokens.issue("session-1");
+
+        assert_eq!(tokens.redeem(&token), Ok("session-1".to_string()));
+        assert_eq!(tokens.redeem(&token), Err(ResumeError::AlreadyUsed));
+
+        // A freshly issued token works for the next reconnect
+        let next = tokens.issue("session-1");
+        assert_eq!(tokens.redeem(&next), Ok("session-1".to_string()));
+    }
+
+    #[test]
+    fn test_expired_token_rejected() {
+        let tokens = ResumeTokens::new("test-secret", Duration::ZERO);
+        let token = tokens.issue("session-1");
+        assert_eq!(tokens.redeem(&token), Err(ResumeError::Expired));
+    }
+
+    #[test]
+    fn test_tampered_or_foreign_token_rejected() {
+        let tokens = tokens();
+        let token = tokens.issue("session-1");
+
+        let (payload, signature) = token.rsplit_once('.').unwrap();
+        let forged_payload =
+            payload.replacen(&B64.encode("session-1"), &B64.encode("session-2"), 1);
+        let forged = format!("{}.{}", forged_payload, signature);
+        assert_eq!(tokens.redeem(&forged), Err(ResumeError::InvalidSignature));
+
+        let other = ResumeTokens::new("other-secret", Duration::from_secs(60));
+        assert_eq!(other.redeem(&token), Err(ResumeError::InvalidSignature));
+
+        assert_eq!(tokens.redeem("not-a-token"), Err(ResumeError::Malformed));
+        assert_eq!(tokens.redeem(""), Err(ResumeError::Malformed));
+    }
+}
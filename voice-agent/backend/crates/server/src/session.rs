@@ -9,18 +9,22 @@
 //!
 //! - `InMemorySessionStore` - Default, uses HashMap
 //! - `ScyllaSessionStore` - Production persistence using ScyllaDB
-//!
-//! P3-1 FIX: Removed deprecated RedisSessionStore stub.
-//! Use ScyllaSessionStore for distributed session persistence.
+//! - `RedisSessionStore` - Persistence in Redis, expiring with the session timeout
 
 use async_trait::async_trait;
 use parking_lot::RwLock;
 use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::sync::Arc;
 use std::time::{Duration, Instant};
-use tokio::sync::watch;
+use tokio::sync::{oneshot, watch};
 
-use voice_agent_agent::{AgentConfig, DomainAgent};
+use voice_agent_agent::{
+    AgentConfig, ConversationStage, DomainAgent, EscalationLimiter, GoalProgress,
+    LeadClassification,
+};
+use voice_agent_config::DuplicateConnectionPolicy;
+use voice_agent_persistence::{AuditLogger, SessionData};
 
 use crate::ServerError;
 
@@ -44,6 +48,100 @@ pub struct SessionMetadata {
     pub turn_count: usize,
     /// Instance ID that owns this session (for affinity)
     pub instance_id: Option<String>,
+    /// Language code the agent speaks
+    #[serde(default)]
+    pub language: String,
+    /// Tenant the session belongs to
+    #[serde(default)]
+    pub tenant_id: Option<String>,
+    /// Lead classification (only known for live sessions)
+    #[serde(default)]
+    pub lead_classification: Option<LeadClassification>,
+    /// Token usage and estimated LLM spend (only known for live sessions)
+    #[serde(default)]
+    pub llm_cost: Option<voice_agent_llm::CostSummary>,
+    /// A/B experiment ID -> assigned variant ID
+    #[serde(default)]
+    pub experiments: std::collections::HashMap<String, String>,
+    /// Progress toward conversation goals (only known for live sessions)
+    #[serde(default)]
+    pub goal_progress: Option<Vec<GoalProgress>>,
+}
+
+/// Filter for `SessionManager::list_sessions`; unset fields match everything
+#[derive(Debug, Clone, Default, serde::Deserialize)]
+pub struct SessionFilter {
+    pub language: Option<String>,
+    /// Stage name, matched ignoring case, spaces and underscores
+    pub stage: Option<String>,
+    pub tenant_id: Option<String>,
+    pub lead_classification: Option<LeadClassification>,
+}
+
+impl SessionFilter {
+    pub fn matches(&self, metadata: &SessionMetadata) -> bool {
+        self.language
+            .as_ref()
+            .map(|language| language.eq_ignore_ascii_case(&metadata.language))
+            .unwrap_or(true)
+            && self
+                .stage
+                .as_ref()
+                .map(|stage| normalize_stage(stage) == normalize_stage(&metadata.stage))
+                .unwrap_or(true)
+            && self
+                .tenant_id
+                .as_ref()
+                .map(|tenant| metadata.tenant_id.as_ref() == Some(tenant))
+                .unwrap_or(true)
+            && self
+                .lead_classification
+                .map(|class| metadata.lead_classification == Some(class))
+                .unwrap_or(true)
+    }
+}
+
+fn normalize_stage(stage: &str) -> String {
+    stage
+        .chars()
+        .filter(|c| *c != ' ' && *c != '_')
+        .map(|c| c.to_ascii_lowercase())
+        .collect()
+}
+
+/// Offset pagination for session listings
+#[derive(Debug, Clone, Copy, serde::Deserialize)]
+pub struct Pagination {
+    #[serde(default)]
+    pub offset: usize,
+    #[serde(default = "default_page_limit")]
+    pub limit: usize,
+}
+
+/// Most sessions returned in one page
+pub const MAX_PAGE_LIMIT: usize = 500;
+
+fn default_page_limit() -> usize {
+    50
+}
+
+impl Default for Pagination {
+    fn default() -> Self {
+        Self {
+            offset: 0,
+            limit: default_page_limit(),
+        }
+    }
+}
+
+/// One page of matching sessions, oldest first
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct SessionPage {
+    pub sessions: Vec<SessionMetadata>,
+    /// Matching sessions across all pages
+    pub total: usize,
+    /// Offset of the next page, if there is one
+    pub next_offset: Option<usize>,
 }
 
 /// P2 FIX: Session data for recovery (matches persistence layer)
@@ -57,6 +155,19 @@ pub struct RecoverableSession {
     pub language: String,
 }
 
+impl From<SessionData> for RecoverableSession {
+    fn from(data: SessionData) -> Self {
+        Self {
+            session_id: data.session_id,
+            created_at: data.created_at,
+            expires_at: data.expires_at,
+            conversation_stage: data.conversation_stage,
+            turn_count: data.turn_count,
+            language: data.language,
+        }
+    }
+}
+
 /// P1 FIX: Session store trait for pluggable backends
 #[async_trait]
 pub trait SessionStore: Send + Sync {
@@ -103,16 +214,9 @@ impl InMemorySessionStore {
 #[async_trait]
 impl SessionStore for InMemorySessionStore {
     async fn store_metadata(&self, session: &Session) -> Result<(), ServerError> {
-        let metadata = SessionMetadata {
-            id: session.id.clone(),
-            created_at_ms: session.created_at.elapsed().as_millis() as u64,
-            last_activity_ms: session.last_activity.read().elapsed().as_millis() as u64,
-            active: *session.active.read(),
-            stage: session.agent.stage().display_name().to_string(),
-            turn_count: session.agent.conversation().turn_count(),
-            instance_id: None,
-        };
-        self.metadata.write().insert(session.id.clone(), metadata);
+        self.metadata
+            .write()
+            .insert(session.id.clone(), session.metadata());
         Ok(())
     }
 
@@ -152,8 +256,74 @@ impl SessionStore for InMemorySessionStore {
     }
 }
 
-// P3-1 FIX: Removed deprecated RedisSessionStore stub.
-// Use ScyllaSessionStore for distributed session persistence.
+/// Persisted form of a session, owned by `instance_id`
+fn session_data(
+    session: &Session,
+    instance_id: &str,
+    expires_at: chrono::DateTime<chrono::Utc>,
+) -> SessionData {
+    let now = chrono::Utc::now();
+
+    // Get memory context from agent if available
+    let memory_json = serde_json::to_string(&session.agent.conversation().get_context()).ok();
+
+    SessionData {
+        session_id: session.id.clone(),
+        created_at: now,
+        updated_at: now,
+        expires_at,
+        customer_phone: None, // Will be set when customer provides phone
+        customer_name: None,
+        customer_segment: None,
+        language: session.agent.config().language.clone(),
+        conversation_stage: session.agent.stage().display_name().to_string(),
+        turn_count: session.agent.conversation().turn_count() as i32,
+        memory_json,
+        metadata_json: Some(
+            serde_json::json!({
+                "instance_id": instance_id,
+                "tenant_id": session.tenant_id(),
+                "experiments": session.agent.experiment_variants(),
+            })
+            .to_string(),
+        ),
+    }
+}
+
+fn session_metadata(data: SessionData) -> SessionMetadata {
+    // Extract instance_id, tenant_id and experiments from metadata_json if present
+    let extra = data
+        .metadata_json
+        .as_ref()
+        .and_then(|json| serde_json::from_str::<serde_json::Value>(json).ok());
+    let field = |name: &str| {
+        extra
+            .as_ref()
+            .and_then(|v| v.get(name))
+            .and_then(|i| i.as_str())
+            .map(String::from)
+    };
+
+    SessionMetadata {
+        id: data.session_id,
+        created_at_ms: data.created_at.timestamp_millis() as u64,
+        last_activity_ms: data.updated_at.timestamp_millis() as u64,
+        active: data.expires_at > chrono::Utc::now(),
+        stage: data.conversation_stage,
+        turn_count: data.turn_count as usize,
+        instance_id: field("instance_id"),
+        language: data.language,
+        tenant_id: field("tenant_id"),
+        lead_classification: None,
+        llm_cost: None,
+        experiments: extra
+            .as_ref()
+            .and_then(|v| v.get("experiments"))
+            .and_then(|e| serde_json::from_value(e.clone()).ok())
+            .unwrap_or_default(),
+        goal_progress: None,
+    }
+}
 
 /// P1 FIX: ScyllaDB session store for production persistence
 ///
@@ -190,36 +360,10 @@ impl ScyllaSessionStore {
 #[async_trait]
 impl SessionStore for ScyllaSessionStore {
     async fn store_metadata(&self, session: &Session) -> Result<(), ServerError> {
-        use chrono::Utc;
-        use voice_agent_persistence::sessions::{
-            SessionData, SessionStore as PersistenceSessionStore,
-        };
+        use voice_agent_persistence::sessions::SessionStore as PersistenceSessionStore;
 
-        let now = Utc::now();
-        let expires_at = now + chrono::Duration::hours(1);
-
-        // Get memory context from agent if available
-        let memory_json = serde_json::to_string(&session.agent.conversation().get_context()).ok();
-
-        let data = SessionData {
-            session_id: session.id.clone(),
-            created_at: now,
-            updated_at: now,
-            expires_at,
-            customer_phone: None, // Will be set when customer provides phone
-            customer_name: None,
-            customer_segment: None,
-            language: session.agent.config().language.clone(),
-            conversation_stage: session.agent.stage().display_name().to_string(),
-            turn_count: session.agent.conversation().turn_count() as i32,
-            memory_json,
-            metadata_json: Some(
-                serde_json::json!({
-                    "instance_id": self.instance_id
-                })
-                .to_string(),
-            ),
-        };
+        let expires_at = chrono::Utc::now() + chrono::Duration::hours(1);
+        let data = session_data(session, &self.instance_id, expires_at);
 
         self.store
             .create(&data)
@@ -239,28 +383,7 @@ impl SessionStore for ScyllaSessionStore {
         use voice_agent_persistence::sessions::SessionStore as PersistenceSessionStore;
 
         match self.store.get(id).await {
-            Ok(Some(data)) => {
-                // Extract instance_id from metadata_json if present
-                let instance_id = data
-                    .metadata_json
-                    .as_ref()
-                    .and_then(|json| serde_json::from_str::<serde_json::Value>(json).ok())
-                    .and_then(|v| {
-                        v.get("instance_id")
-                            .and_then(|i| i.as_str())
-                            .map(String::from)
-                    });
-
-                Ok(Some(SessionMetadata {
-                    id: data.session_id,
-                    created_at_ms: data.created_at.timestamp_millis() as u64,
-                    last_activity_ms: data.updated_at.timestamp_millis() as u64,
-                    active: data.expires_at > chrono::Utc::now(),
-                    stage: data.conversation_stage,
-                    turn_count: data.turn_count as usize,
-                    instance_id,
-                }))
-            },
+            Ok(Some(data)) => Ok(Some(session_metadata(data))),
             Ok(None) => Ok(None),
             Err(e) => Err(ServerError::Session(format!("ScyllaDB error: {}", e))),
         }
@@ -316,17 +439,124 @@ impl SessionStore for ScyllaSessionStore {
             .await
             .map_err(|e| ServerError::Session(format!("ScyllaDB list error: {}", e)))?;
 
-        Ok(sessions
-            .into_iter()
-            .map(|s| RecoverableSession {
-                session_id: s.session_id,
-                created_at: s.created_at,
-                expires_at: s.expires_at,
-                conversation_stage: s.conversation_stage,
-                turn_count: s.turn_count,
-                language: s.language,
-            })
-            .collect())
+        Ok(sessions.into_iter().map(RecoverableSession::from).collect())
+    }
+}
+
+/// Redis session store
+///
+/// Session keys expire after the store's session TTL, which should match the
+/// `SessionManager` timeout, so Redis drops sessions the server would have
+/// expired anyway.
+pub struct RedisSessionStore {
+    store: voice_agent_persistence::RedisSessionStore,
+    instance_id: String,
+}
+
+impl RedisSessionStore {
+    /// Create a new Redis session store
+    pub fn new(store: voice_agent_persistence::RedisSessionStore) -> Self {
+        Self {
+            store,
+            instance_id: uuid::Uuid::new_v4().to_string(),
+        }
+    }
+
+    /// Create with a specific instance ID (for session affinity)
+    pub fn with_instance_id(
+        store: voice_agent_persistence::RedisSessionStore,
+        instance_id: String,
+    ) -> Self {
+        Self { store, instance_id }
+    }
+
+    /// Get the instance ID
+    pub fn instance_id(&self) -> &str {
+        &self.instance_id
+    }
+}
+
+#[async_trait]
+impl SessionStore for RedisSessionStore {
+    async fn store_metadata(&self, session: &Session) -> Result<(), ServerError> {
+        use voice_agent_persistence::sessions::SessionStore as PersistenceSessionStore;
+
+        let ttl = chrono::Duration::from_std(self.store.session_ttl())
+            .map_err(|e| ServerError::Session(format!("Invalid session TTL: {}", e)))?;
+        let data = session_data(session, &self.instance_id, chrono::Utc::now() + ttl);
+
+        self.store
+            .create(&data)
+            .await
+            .map_err(|e| ServerError::Session(format!("Redis error: {}", e)))?;
+
+        tracing::debug!(
+            session_id = %session.id,
+            stage = %data.conversation_stage,
+            "Session persisted to Redis"
+        );
+
+        Ok(())
+    }
+
+    async fn get_metadata(&self, id: &str) -> Result<Option<SessionMetadata>, ServerError> {
+        use voice_agent_persistence::sessions::SessionStore as PersistenceSessionStore;
+
+        match self.store.get(id).await {
+            Ok(data) => Ok(data.map(session_metadata)),
+            Err(e) => Err(ServerError::Session(format!("Redis error: {}", e))),
+        }
+    }
+
+    async fn delete_metadata(&self, id: &str) -> Result<(), ServerError> {
+        use voice_agent_persistence::sessions::SessionStore as PersistenceSessionStore;
+
+        self.store
+            .delete(id)
+            .await
+            .map_err(|e| ServerError::Session(format!("Redis error: {}", e)))?;
+        tracing::debug!(session_id = %id, "Session deleted from Redis");
+        Ok(())
+    }
+
+    async fn list_ids(&self) -> Result<Vec<String>, ServerError> {
+        use voice_agent_persistence::sessions::SessionStore as PersistenceSessionStore;
+
+        let sessions = self
+            .store
+            .list_active(100)
+            .await
+            .map_err(|e| ServerError::Session(format!("Redis list error: {}", e)))?;
+
+        Ok(sessions.into_iter().map(|s| s.session_id).collect())
+    }
+
+    async fn touch(&self, id: &str) -> Result<(), ServerError> {
+        use voice_agent_persistence::sessions::SessionStore as PersistenceSessionStore;
+
+        self.store
+            .touch(id)
+            .await
+            .map_err(|e| ServerError::Session(format!("Redis error: {}", e)))
+    }
+
+    fn is_distributed(&self) -> bool {
+        true
+    }
+
+    async fn list_active_sessions(
+        &self,
+        limit: i32,
+    ) -> Result<Vec<RecoverableSession>, ServerError> {
+        use voice_agent_persistence::sessions::SessionStore as PersistenceSessionStore;
+
+        let sessions = self
+            .store
+            .list_active(limit)
+            .await
+            .map_err(|e| ServerError::Session(format!("Redis list error: {}", e)))?;
+
+        Ok(sessions.into_iter().map(RecoverableSession::from).collect())
     }
 }
 
@@ -338,10 +568,16 @@ pub struct Session {
     pub agent: Arc<DomainAgent>,
     /// Creation time
     pub created_at: Instant,
+    /// Wall-clock creation time, for reporting
+    pub started_at: chrono::DateTime<chrono::Utc>,
+    /// Tenant the session belongs to
+    tenant_id: RwLock<Option<String>>,
     /// Last activity
     pub last_activity: RwLock<Instant>,
     /// Is active
     pub active: RwLock<bool>,
+    /// Whether the call opening was spoken
+    opened: AtomicBool,
     #[cfg(feature = "webrtc")]
     webrtc: RwLock<Option<crate::webrtc::WebRtcSession>>,
 }
@@ -360,8 +596,11 @@ impl Session {
             agent: Arc::new(DomainAgent::new(&id, config, domain_config)),
             id,
             created_at: Instant::now(),
+            started_at: chrono::Utc::now(),
+            tenant_id: RwLock::new(None),
             last_activity: RwLock::new(Instant::now()),
             active: RwLock::new(true),
+            opened: AtomicBool::new(false),
             #[cfg(feature = "webrtc")]
             webrtc: RwLock::new(None),
         }
@@ -373,7 +612,7 @@ impl Session {
     pub fn with_vector_store(
         id: impl Into<String>,
         config: AgentConfig,
-        vector_store: Arc<voice_agent_rag::VectorStore>,
+        vector_store: Arc<dyn voice_agent_rag::VectorStoreBackend>,
         domain_config: Arc<voice_agent_config::MasterDomainConfig>,
     ) -> Self {
         let id = id.into();
@@ -382,8 +621,11 @@ impl Session {
             agent: Arc::new(agent),
             id,
             created_at: Instant::now(),
+            started_at: chrono::Utc::now(),
+            tenant_id: RwLock::new(None),
             last_activity: RwLock::new(Instant::now()),
             active: RwLock::new(true),
+            opened: AtomicBool::new(false),
             #[cfg(feature = "webrtc")]
             webrtc: RwLock::new(None),
         }
@@ -392,11 +634,17 @@ impl Session {
     /// Create a new session with full integration (RAG + persistence-wired tools)
     ///
     /// # P21 FIX: Accept domain config to pass to DomainAgent
+    ///
+    /// A shared `retriever` replaces the agent's own for searching the
+    /// vector store. The call is reported to `reporting`'s CRM and audit log
+    /// when given, and its escalations count against the shared limiter.
     pub fn with_full_integration(
         id: impl Into<String>,
         config: AgentConfig,
-        vector_store: Option<Arc<voice_agent_rag::VectorStore>>,
+        vector_store: Option<Arc<dyn voice_agent_rag::VectorStoreBackend>>,
+        retriever: Option<Arc<voice_agent_rag::HybridRetriever>>,
         tools: Arc<voice_agent_tools::ToolRegistry>,
+        reporting: SessionReporting,
         domain_config: Arc<voice_agent_config::MasterDomainConfig>,
     ) -> Self {
         let id = id.into();
@@ -404,12 +652,27 @@ impl Session {
         if let Some(vs) = vector_store {
             agent = agent.with_vector_store(vs);
         }
+        if let Some(retriever) = retriever {
+            agent = agent.with_retriever(retriever);
+        }
+        if let Some(crm) = reporting.crm {
+            agent = agent.with_crm(crm);
+        }
+        if let Some(audit_logger) = reporting.audit_logger {
+            agent = agent.with_audit_logger(audit_logger);
+        }
+        if let Some(limiter) = reporting.escalation_limiter {
+            agent = agent.with_escalation_limiter(limiter);
+        }
         Self {
             agent: Arc::new(agent),
             id,
             created_at: Instant::now(),
+            started_at: chrono::Utc::now(),
+            tenant_id: RwLock::new(None),
             last_activity: RwLock::new(Instant::now()),
             active: RwLock::new(true),
+            opened: AtomicBool::new(false),
             #[cfg(feature = "webrtc")]
             webrtc: RwLock::new(None),
         }
@@ -432,6 +695,19 @@ impl Session {
         self.webrtc.read().is_some()
     }
 
+    /// Open the call with the domain's opening script
+    ///
+    /// Runs the script up to the recording consent question (see
+    /// `DomainAgent::begin_opening`). Returns the text to speak the first
+    /// time a transport connects; a client reconnecting to the session gets
+    /// None.
+    pub async fn open_call(&self) -> Option<String> {
+        if self.opened.swap(true, Ordering::SeqCst) {
+            return None;
+        }
+        Some(self.agent.begin_opening().await)
+    }
+
     /// Update last activity
     pub fn touch(&self) {
         *self.last_activity.write() = Instant::now();
@@ -451,6 +727,117 @@ impl Session {
     pub fn is_active(&self) -> bool {
         *self.active.read()
     }
+
+    /// Assign the session to a tenant
+    pub fn set_tenant(&self, tenant_id: impl Into<String>) {
+        *self.tenant_id.write() = Some(tenant_id.into());
+    }
+
+    pub fn tenant_id(&self) -> Option<String> {
+        self.tenant_id.read().clone()
+    }
+
+    /// Current metadata, read from the live agent
+    pub fn metadata(&self) -> SessionMetadata {
+        let last_activity = chrono::Utc::now()
+            - chrono::Duration::from_std(self.last_activity.read().elapsed())
+                .unwrap_or_else(|_| chrono::Duration::zero());
+        SessionMetadata {
+            id: self.id.clone(),
+            created_at_ms: self.started_at.timestamp_millis() as u64,
+            last_activity_ms: last_activity.timestamp_millis() as u64,
+            active: self.is_active(),
+            stage: self.agent.stage().display_name().to_string(),
+            turn_count: self.agent.conversation().turn_count(),
+            instance_id: None,
+            language: self.agent.config().language.clone(),
+            tenant_id: self.tenant_id(),
+            lead_classification: Some(self.agent.lead_classification()),
+            llm_cost: Some(self.agent.cost_summary()),
+            experiments: self.agent.experiment_variants(),
+            goal_progress: Some(self.agent.goal_progress()),
+        }
+    }
+}
+
+/// Idle time after which a session expires, unless configured otherwise
+pub const DEFAULT_SESSION_TIMEOUT: Duration = Duration::from_secs(3600);
+
+/// How long a new connection waits for the one it supersedes to shut down
+pub const CONNECTION_HANDOFF_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Connection state for one session
+struct ConnectionSlot {
+    /// Held by the connection that owns the session; the next one waits for it
+    owner: Arc<tokio::sync::Mutex<()>>,
+    /// Newest connection
+    current: Option<LiveConnection>,
+}
+
+struct LiveConnection {
+    id: u64,
+    superseded: Arc<AtomicBool>,
+    /// Dropping or sending wakes the connection's `superseded` future
+    signal: oneshot::Sender<()>,
+}
+
+type ConnectionRegistry = Arc<parking_lot::Mutex<HashMap<String, ConnectionSlot>>>;
+
+/// A connection's claim on a session
+///
+/// Only the connection holding the lease may drive the session. Dropping the
+/// lease releases it to the next connection.
+pub struct ConnectionLease {
+    session_id: String,
+    id: u64,
+    registry: ConnectionRegistry,
+    closed: oneshot::Receiver<()>,
+    superseded: Arc<AtomicBool>,
+    _owner: Option<tokio::sync::OwnedMutexGuard<()>>,
+}
+
+impl ConnectionLease {
+    /// Resolves when a newer connection supersedes this one or the session is removed
+    pub fn closed(&mut self) -> &mut oneshot::Receiver<()> {
+        &mut self.closed
+    }
+
+    /// Whether a newer connection took over the session
+    pub fn was_superseded(&self) -> bool {
+        self.superseded.load(Ordering::SeqCst)
+    }
+}
+
+impl Drop for ConnectionLease {
+    fn drop(&mut self) {
+        let mut registry = self.registry.lock();
+        if let Some(slot) = registry.get_mut(&self.session_id) {
+            if slot.current.as_ref().is_some_and(|live| live.id == self.id) {
+                slot.current = None;
+            }
+        }
+    }
+}
+
+/// Where sessions with tools report their call
+#[derive(Clone, Default)]
+pub struct SessionReporting {
+    /// CRM the call summary is pushed to
+    pub crm: Option<Arc<dyn voice_agent_tools::CrmIntegration>>,
+    /// Audit log for the disclosure, consent and opening script
+    pub audit_logger: Option<Arc<AuditLogger>>,
+    /// Rate limit on human escalations shared by the sessions
+    pub escalation_limiter: Option<Arc<EscalationLimiter>>,
+}
+
+impl SessionReporting {
+    /// Reporting with a fresh escalation limiter for one manager's sessions
+    fn shared() -> Self {
+        Self {
+            escalation_limiter: Some(Arc::new(EscalationLimiter::new())),
+            ..Self::default()
+        }
+    }
 }
 
 /// Session manager
@@ -460,6 +847,11 @@ pub struct SessionManager {
     session_timeout: Duration,
     /// P2 FIX: Cleanup interval for passive session cleanup
     cleanup_interval: Duration,
+    /// Live connection per session
+    connections: ConnectionRegistry,
+    next_connection_id: AtomicU64,
+    /// Where sessions with tools report their call
+    reporting: RwLock<SessionReporting>,
 }
 
 impl SessionManager {
@@ -468,8 +860,11 @@ impl SessionManager {
         Self {
             sessions: RwLock::new(HashMap::new()),
             max_sessions,
-            session_timeout: Duration::from_secs(3600), // 1 hour
+            session_timeout: DEFAULT_SESSION_TIMEOUT,
             cleanup_interval: Duration::from_secs(300), // 5 minutes
+            connections: Arc::default(),
+            next_connection_id: AtomicU64::new(0),
+            reporting: RwLock::new(SessionReporting::shared()),
         }
     }
 
@@ -484,9 +879,23 @@ impl SessionManager {
             max_sessions,
             session_timeout,
             cleanup_interval,
+            connections: Arc::default(),
+            next_connection_id: AtomicU64::new(0),
+            reporting: RwLock::new(SessionReporting::shared()),
         }
     }
 
+    /// Push the call summary of new sessions to this CRM
+    pub fn with_crm(mut self, crm: Arc<dyn voice_agent_tools::CrmIntegration>) -> Self {
+        self.reporting.get_mut().crm = Some(crm);
+        self
+    }
+
+    /// Audit the disclosure, consent and opening script of new sessions here
+    pub fn set_audit_logger(&self, audit_logger: Arc<AuditLogger>) {
+        self.reporting.write().audit_logger = Some(audit_logger);
+    }
+
     /// P2 FIX: Start a background task that periodically cleans up expired sessions.
     ///
     /// Returns a shutdown sender that can be used to stop the cleanup task.
@@ -545,10 +954,10 @@ impl SessionManager {
     pub fn create_with_vector_store(
         &self,
         config: AgentConfig,
-        vector_store: Option<Arc<voice_agent_rag::VectorStore>>,
+        vector_store: Option<Arc<dyn voice_agent_rag::VectorStoreBackend>>,
         domain_config: Arc<voice_agent_config::MasterDomainConfig>,
     ) -> Result<Arc<Session>, ServerError> {
-        self.create_with_full_integration(config, vector_store, None, domain_config)
+        self.create_with_full_integration(config, vector_store, None, None, domain_config)
     }
 
     /// P0 FIX: Create a new session with full integration (RAG + persistence-wired tools)
@@ -564,7 +973,72 @@ impl SessionManager {
     pub fn create_with_full_integration(
         &self,
         config: AgentConfig,
-        vector_store: Option<Arc<voice_agent_rag::VectorStore>>,
+        vector_store: Option<Arc<dyn voice_agent_rag::VectorStoreBackend>>,
+        retriever: Option<Arc<voice_agent_rag::HybridRetriever>>,
+        tools: Option<Arc<voice_agent_tools::ToolRegistry>>,
+        domain_config: Arc<voice_agent_config::MasterDomainConfig>,
+    ) -> Result<Arc<Session>, ServerError> {
+        let id = uuid::Uuid::new_v4().to_string();
+        self.create_with_id(id, config, vector_store, retriever, tools, domain_config)
+    }
+
+    /// Recreate a session under its previous ID from stored metadata
+    ///
+    /// Used to resume a session whose agent is no longer in memory (e.g. after a
+    /// restart). The checkpointed stage, language and tenant are restored; a
+    /// session that is still live is returned as is.
+    pub fn restore_with_full_integration(
+        &self,
+        checkpoint: &SessionMetadata,
+        mut config: AgentConfig,
+        vector_store: Option<Arc<dyn voice_agent_rag::VectorStoreBackend>>,
+        retriever: Option<Arc<voice_agent_rag::HybridRetriever>>,
+        tools: Option<Arc<voice_agent_tools::ToolRegistry>>,
+        domain_config: Arc<voice_agent_config::MasterDomainConfig>,
+    ) -> Result<Arc<Session>, ServerError> {
+        if let Some(session) = self.get(&checkpoint.id) {
+            return Ok(session);
+        }
+
+        if !checkpoint.language.is_empty() {
+            config.language = checkpoint.language.clone();
+        }
+        let session = self.create_with_id(
+            checkpoint.id.clone(),
+            config,
+            vector_store,
+            retriever,
+            tools,
+            domain_config,
+        )?;
+
+        let stage = ConversationStage::from_str(&checkpoint.stage.to_lowercase().replace(' ', "_"));
+        match stage {
+            Some(stage) => session
+                .agent
+                .conversation()
+                .stage_manager()
+                .set_stage(stage),
+            None => tracing::warn!(
+                session_id = %session.id,
+                stage = %checkpoint.stage,
+                "Unknown stage in session checkpoint, starting from the beginning"
+            ),
+        }
+        if let Some(tenant_id) = &checkpoint.tenant_id {
+            session.set_tenant(tenant_id.clone());
+        }
+
+        tracing::info!(session_id = %session.id, stage = %checkpoint.stage, "Restored session");
+        Ok(session)
+    }
+
+    fn create_with_id(
+        &self,
+        id: String,
+        config: AgentConfig,
+        vector_store: Option<Arc<dyn voice_agent_rag::VectorStoreBackend>>,
+        retriever: Option<Arc<voice_agent_rag::HybridRetriever>>,
         tools: Option<Arc<voice_agent_tools::ToolRegistry>>,
         domain_config: Arc<voice_agent_config::MasterDomainConfig>,
     ) -> Result<Arc<Session>, ServerError> {
@@ -580,17 +1054,30 @@ impl SessionManager {
             }
         }
 
-        let id = uuid::Uuid::new_v4().to_string();
         let rag_enabled = vector_store.is_some();
         let tools_wired = tools.is_some();
 
         // P21 FIX: Pass domain_config to all Session constructors
         let session = match (vector_store, tools) {
-            (Some(vs), Some(t)) => {
-                Arc::new(Session::with_full_integration(&id, config, Some(vs), t, domain_config))
-            },
+            (Some(vs), Some(t)) => Arc::new(Session::with_full_integration(
+                &id,
+                config,
+                Some(vs),
+                retriever,
+                t,
+                self.reporting.read().clone(),
+                domain_config,
+            )),
             (Some(vs), None) => Arc::new(Session::with_vector_store(&id, config, vs, domain_config)),
-            (None, Some(t)) => Arc::new(Session::with_full_integration(&id, config, None, t, domain_config)),
+            (None, Some(t)) => Arc::new(Session::with_full_integration(
+                &id,
+                config,
+                None,
+                None,
+                t,
+                self.reporting.read().clone(),
+                domain_config,
+            )),
             (None, None) => Arc::new(Session::new(&id, config, domain_config)),
         };
         sessions.insert(id.clone(), session.clone());
@@ -616,10 +1103,82 @@ impl SessionManager {
         let mut sessions = self.sessions.write();
         if let Some(session) = sessions.remove(id) {
             session.close();
+            crate::metrics::record_session_llm_cost(&session.agent.cost_summary());
+            self.connections.lock().remove(id);
             tracing::info!("Removed session: {}", id);
         }
     }
 
+    /// Claim a session for a new connection
+    ///
+    /// If another connection holds the session, `Supersede` signals it to
+    /// close and waits (up to `CONNECTION_HANDOFF_TIMEOUT`) for its lease to
+    /// drop, so the two never drive the session at once. `Reject` refuses
+    /// the new connection instead. The session itself, with its conversation
+    /// state, is shared and carries over to the new connection.
+    pub async fn attach_connection(
+        &self,
+        session_id: &str,
+        policy: DuplicateConnectionPolicy,
+    ) -> Result<ConnectionLease, ServerError> {
+        let (signal, closed) = oneshot::channel();
+        let superseded = Arc::new(AtomicBool::new(false));
+        let id = self.next_connection_id.fetch_add(1, Ordering::Relaxed);
+
+        let (owner, previous) = {
+            let mut registry = self.connections.lock();
+            let slot = registry
+                .entry(session_id.to_string())
+                .or_insert_with(|| ConnectionSlot {
+                    owner: Arc::new(tokio::sync::Mutex::new(())),
+                    current: None,
+                });
+            if slot.current.is_some() && policy == DuplicateConnectionPolicy::Reject {
+                return Err(ServerError::Conflict(format!(
+                    "Session {} already has an open connection",
+                    session_id
+                )));
+            }
+            let live = LiveConnection {
+                id,
+                superseded: superseded.clone(),
+                signal,
+            };
+            (slot.owner.clone(), slot.current.replace(live))
+        };
+
+        let mut lease = ConnectionLease {
+            session_id: session_id.to_string(),
+            id,
+            registry: self.connections.clone(),
+            closed,
+            superseded,
+            _owner: None,
+        };
+
+        if let Some(previous) = previous {
+            tracing::info!(
+                session_id = %session_id,
+                previous_connection = previous.id,
+                connection = id,
+                "Superseding existing connection"
+            );
+            previous.superseded.store(true, Ordering::SeqCst);
+            let _ = previous.signal.send(());
+        }
+
+        let guard = tokio::time::timeout(CONNECTION_HANDOFF_TIMEOUT, owner.lock_owned())
+            .await
+            .map_err(|_| {
+                ServerError::Conflict(format!(
+                    "Previous connection for session {} did not close",
+                    session_id
+                ))
+            })?;
+        lease._owner = Some(guard);
+        Ok(lease)
+    }
+
     /// Get active session count
     pub fn count(&self) -> usize {
         self.sessions.read().len()
@@ -642,6 +1201,8 @@ impl SessionManager {
         for id in expired {
             if let Some(session) = sessions.remove(&id) {
                 session.close();
+                crate::metrics::record_session_llm_cost(&session.agent.cost_summary());
+                self.connections.lock().remove(&id);
                 tracing::info!("Expired session: {}", id);
             }
         }
@@ -651,6 +1212,36 @@ impl SessionManager {
     pub fn list(&self) -> Vec<String> {
         self.sessions.read().keys().cloned().collect()
     }
+
+    /// List metadata of sessions matching `filter`, oldest first
+    ///
+    /// Metadata is read from the live sessions, so stage and lead
+    /// classification reflect where each conversation is now.
+    pub fn list_sessions(&self, filter: &SessionFilter, page: Pagination) -> SessionPage {
+        let sessions: Vec<Arc<Session>> = self.sessions.read().values().cloned().collect();
+        let mut matching: Vec<SessionMetadata> = sessions
+            .iter()
+            .map(|session| session.metadata())
+            .filter(|metadata| filter.matches(metadata))
+            .collect();
+        matching.sort_by(|a, b| {
+            a.created_at_ms
+                .cmp(&b.created_at_ms)
+                .then_with(|| a.id.cmp(&b.id))
+        });
+
+        let total = matching.len();
+        let limit = page.limit.clamp(1, MAX_PAGE_LIMIT);
+        let sessions: Vec<SessionMetadata> =
+            matching.into_iter().skip(page.offset).take(limit).collect();
+        let end = page.offset + sessions.len();
+
+        SessionPage {
+            sessions,
+            total,
+            next_offset: (end < total).then_some(end),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -692,6 +1283,21 @@ mod tests {
         assert!(manager.get(&id).is_none());
     }
 
+    #[tokio::test]
+    async fn test_call_opens_once() {
+        let manager = SessionManager::new(10);
+        let session = manager
+            .create(AgentConfig::default(), test_domain_config())
+            .unwrap();
+
+        let opening = session.open_call().await.unwrap();
+        assert!(opening.contains(session.agent.conversation().consent_prompt()));
+        assert!(session.agent.conversation().awaiting_consent());
+
+        // A reconnecting client does not hear it again
+        assert!(session.open_call().await.is_none());
+    }
+
     #[tokio::test]
     async fn test_in_memory_session_store() {
         let store = InMemorySessionStore::new();
@@ -721,5 +1327,225 @@ mod tests {
         assert!(!store.is_distributed());
     }
 
-    // P3-1 FIX: Removed Redis session store tests (deprecated)
+    #[test]
+    fn test_list_sessions_filters_by_language_and_stage() {
+        use voice_agent_agent::ConversationStage;
+
+        let manager = SessionManager::new(10);
+        let create = |language: &str, discovery: bool, tenant: Option<&str>| {
+            let config = AgentConfig {
+                language: language.to_string(),
+                ..Default::default()
+            };
+            let session = manager.create(config, test_domain_config()).unwrap();
+            if discovery {
+                session
+                    .agent
+                    .conversation()
+                    .transition_stage(ConversationStage::Discovery)
+                    .unwrap();
+            }
+            if let Some(tenant) = tenant {
+                session.set_tenant(tenant);
+            }
+            session.id.clone()
+        };
+        let hi_discovery = create("hi", true, Some("acme"));
+        let _hi_greeting = create("hi", false, Some("acme"));
+        let _en_discovery = create("en", true, Some("acme"));
+        let hi_discovery_other = create("hi", true, Some("globex"));
+
+        let filter = SessionFilter {
+            language: Some("hi".to_string()),
+            stage: Some("discovery".to_string()),
+            ..Default::default()
+        };
+        let page = manager.list_sessions(&filter, Pagination::default());
+        let mut ids: Vec<_> = page.sessions.iter().map(|m| m.id.clone()).collect();
+        ids.sort();
+        let mut expected = vec![hi_discovery.clone(), hi_discovery_other];
+        expected.sort();
+        assert_eq!(ids, expected);
+        assert_eq!(page.total, 2);
+        assert!(page.sessions.iter().all(|m| m.stage == "Discovery"));
+
+        // Tenant scoping narrows the same filter
+        let scoped = SessionFilter {
+            tenant_id: Some("acme".to_string()),
+            ..filter.clone()
+        };
+        let page = manager.list_sessions(&scoped, Pagination::default());
+        assert_eq!(page.sessions.len(), 1);
+        assert_eq!(page.sessions[0].id, hi_discovery);
+
+        // Pages of one walk both matches
+        let one = |offset| Pagination { offset, limit: 1 };
+        let first = manager.list_sessions(&filter, one(0));
+        assert_eq!(first.sessions.len(), 1);
+        assert_eq!(first.next_offset, Some(1));
+        let second = manager.list_sessions(&filter, one(1));
+        assert_eq!(second.next_offset, None);
+        assert_ne!(first.sessions[0].id, second.sessions[0].id);
+    }
+
+    #[test]
+    fn test_metadata_follows_stage_changes() {
+        let manager = SessionManager::new(10);
+        let session = manager
+            .create(AgentConfig::default(), test_domain_config())
+            .unwrap();
+        assert_eq!(session.metadata().stage, "Greeting");
+
+        session
+            .agent
+            .conversation()
+            .transition_stage(voice_agent_agent::ConversationStage::Discovery)
+            .unwrap();
+        let metadata = session.metadata();
+        assert_eq!(metadata.stage, "Discovery");
+        assert_eq!(
+            metadata.lead_classification,
+            Some(LeadClassification::Unqualified)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_second_connection_supersedes_first() {
+        let manager = SessionManager::new(10);
+        let session = manager
+            .create(AgentConfig::default(), test_domain_config())
+            .unwrap();
+        let id = session.id.clone();
+
+        let mut first = manager
+            .attach_connection(&id, DuplicateConnectionPolicy::Supersede)
+            .await
+            .unwrap();
+        session
+            .agent
+            .conversation()
+            .transition_stage(voice_agent_agent::ConversationStage::Discovery)
+            .unwrap();
+
+        // The first connection runs until told it was superseded, then closes
+        let first_handler = tokio::spawn(async move {
+            let _ = first.closed().await;
+            let frame = first
+                .was_superseded()
+                .then(crate::websocket::superseded_close_frame);
+            drop(first);
+            frame
+        });
+
+        // Returns only once the first connection has released the session
+        let second = manager
+            .attach_connection(&id, DuplicateConnectionPolicy::Supersede)
+            .await
+            .unwrap();
+        let frame = first_handler
+            .await
+            .unwrap()
+            .expect("first connection superseded");
+        assert_eq!(frame.code, crate::websocket::CLOSE_CODE_SUPERSEDED);
+        assert_eq!(frame.reason, "superseded");
+        assert!(!second.was_superseded());
+
+        // The new connection continues the same conversation
+        let live = manager.get(&id).unwrap();
+        assert!(Arc::ptr_eq(&live, &session));
+        assert_eq!(live.agent.stage().display_name(), "Discovery");
+    }
+
+    #[tokio::test]
+    async fn test_reject_policy_refuses_second_connection() {
+        let manager = SessionManager::new(10);
+        let session = manager
+            .create(AgentConfig::default(), test_domain_config())
+            .unwrap();
+
+        let first = manager
+            .attach_connection(&session.id, DuplicateConnectionPolicy::Reject)
+            .await
+            .unwrap();
+        let second = manager
+            .attach_connection(&session.id, DuplicateConnectionPolicy::Reject)
+            .await;
+        assert!(matches!(second, Err(ServerError::Conflict(_))));
+        assert!(!first.was_superseded());
+
+        // Once the first connection goes away a new one is accepted
+        drop(first);
+        assert!(manager
+            .attach_connection(&session.id, DuplicateConnectionPolicy::Reject)
+            .await
+            .is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_removing_session_closes_connection() {
+        let manager = SessionManager::new(10);
+        let session = manager
+            .create(AgentConfig::default(), test_domain_config())
+            .unwrap();
+        let mut lease = manager
+            .attach_connection(&session.id, DuplicateConnectionPolicy::Supersede)
+            .await
+            .unwrap();
+
+        manager.remove(&session.id);
+        assert!(lease.closed().await.is_err());
+        assert!(!lease.was_superseded());
+    }
+
+    fn redis_store(redis: &Arc<voice_agent_persistence::InMemoryRedis>) -> RedisSessionStore {
+        RedisSessionStore::new(voice_agent_persistence::RedisSessionStore::with_backend(
+            redis.clone(),
+            voice_agent_persistence::RedisSessionConfig {
+                session_ttl: Duration::from_secs(60),
+                ..Default::default()
+            },
+        ))
+    }
+
+    #[tokio::test]
+    async fn test_redis_store_metadata() {
+        let redis = Arc::new(voice_agent_persistence::InMemoryRedis::new());
+        let store = redis_store(&redis);
+        assert!(store.is_distributed());
+
+        let manager = SessionManager::new(10);
+        let session = manager
+            .create(AgentConfig::default(), test_domain_config())
+            .unwrap();
+        store.store_metadata(&session).await.unwrap();
+
+        let metadata = store.get_metadata(&session.id).await.unwrap().unwrap();
+        assert!(metadata.active);
+        assert_eq!(metadata.instance_id.as_deref(), Some(store.instance_id()));
+        assert_eq!(store.list_ids().await.unwrap(), vec![session.id.clone()]);
+
+        store.delete_metadata(&session.id).await.unwrap();
+        assert!(store.get_metadata(&session.id).await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_redis_sessions_recovered_after_restart() {
+        let redis = Arc::new(voice_agent_persistence::InMemoryRedis::new());
+        let manager = SessionManager::new(10);
+        let session = manager
+            .create(AgentConfig::default(), test_domain_config())
+            .unwrap();
+        redis_store(&redis).store_metadata(&session).await.unwrap();
+
+        // A restarted server with a fresh store over the same Redis finds the session
+        let state = crate::AppState::with_session_store(
+            voice_agent_config::Settings::default(),
+            Arc::new(redis_store(&redis)),
+        );
+        assert_eq!(state.recover_sessions().await.unwrap(), 1);
+
+        // Once the session TTL passes, Redis has dropped it
+        redis.advance(Duration::from_secs(61));
+        assert_eq!(state.recover_sessions().await.unwrap(), 0);
+    }
 }
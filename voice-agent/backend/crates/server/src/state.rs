@@ -6,24 +6,44 @@
 //! through MasterDomainConfig and its views (AgentDomainView, LlmDomainView, ToolsDomainView).
 
 use parking_lot::RwLock;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 
 use voice_agent_config::{load_settings, MasterDomainConfig, Settings};
 use voice_agent_config::domain::{AgentDomainView, LlmDomainView, ToolsDomainView};
-use voice_agent_rag::VectorStore;
+use voice_agent_rag::{HybridRetriever, VectorStoreBackend};
 use voice_agent_tools::ToolRegistry;
 // P2 FIX: Text processing pipeline for grammar, PII, compliance
-use voice_agent_text_processing::{TextProcessingConfig, TextProcessingPipeline, TextSimplifier};
+use voice_agent_text_processing::{
+    PronunciationLexicon, TextProcessingConfig, TextProcessingPipeline, TextSimplifier,
+    TextSimplifierConfig,
+};
 // Deterministic phonetic error correction
 use voice_agent_text_processing::grammar::PhoneticCorrector;
 // Translation
 use voice_agent_text_processing::translation::{TranslationConfig, create_translator};
 use voice_agent_core::Translator;
 // P2 FIX: Audit logging for RBI compliance
-use voice_agent_persistence::{AuditLog, AuditLogger};
-
+use voice_agent_persistence::{
+    AuditLog, AuditLogger, CustomerDataEraser, CustomerDataExporter, ScyllaClient,
+};
+// Structured per-session event recording
+use voice_agent_agent::ConversationRecorder;
+
+use crate::resume::ResumeTokens;
+use crate::webhooks::WebhookDispatcher;
 use crate::session::{InMemorySessionStore, SessionManager, SessionStore};
 
+/// Persistence-backed services wired into the tools and sessions
+pub struct PersistenceServices {
+    pub sms_service: Arc<dyn voice_agent_persistence::SmsService>,
+    pub gold_price_service: Arc<dyn voice_agent_persistence::AssetPriceService>,
+    pub appointments: Arc<dyn voice_agent_persistence::AppointmentStore>,
+    pub reminders: Option<Arc<voice_agent_tools::AppointmentReminders>>,
+    pub crm: Arc<dyn voice_agent_tools::CrmIntegration>,
+    pub audit_log: Arc<dyn AuditLog>,
+}
+
 /// Application state
 #[derive(Clone)]
 pub struct AppState {
@@ -44,7 +64,12 @@ pub struct AppState {
     /// P2-3 FIX: Session store for persistence (ScyllaDB or in-memory)
     pub session_store: Arc<dyn SessionStore>,
     /// P0 FIX: Vector store for RAG retrieval (optional - initialized if Qdrant is available)
-    pub vector_store: Option<Arc<VectorStore>>,
+    pub vector_store: Option<Arc<dyn VectorStoreBackend>>,
+    /// Retriever every session searches the vector store with, so its models
+    /// are loaded and warmed up once
+    pub retriever: Option<Arc<HybridRetriever>>,
+    /// Set once the RAG embedding and reranker models have been warmed up
+    rag_warm: Arc<AtomicBool>,
     /// P2 FIX: Text processing pipeline for grammar, PII, compliance
     pub text_processing: Arc<TextProcessingPipeline>,
     /// P2 FIX: Text simplifier for TTS output (numbers, abbreviations)
@@ -55,6 +80,18 @@ pub struct AppState {
     pub translator: Arc<dyn Translator>,
     /// P2 FIX: Audit logger for RBI compliance (wrapped in Arc for Clone)
     pub audit_logger: Option<Arc<AuditLogger>>,
+    /// Exports a customer's records on an access request (None when disabled)
+    pub data_export: Option<Arc<CustomerDataExporter>>,
+    /// Erases a customer's records on a right-to-erasure request (None when disabled)
+    pub data_erasure: Option<Arc<CustomerDataEraser>>,
+    /// ScyllaDB client for readiness checks and pool metrics (None when in-memory)
+    pub scylla: Option<ScyllaClient>,
+    /// Signs and redeems session resume tokens
+    pub resume_tokens: Arc<ResumeTokens>,
+    /// Delivers call events to downstream webhooks (None when none are configured)
+    pub webhooks: Option<Arc<WebhookDispatcher>>,
+    /// Writes structured session events as JSONL (None when recording is disabled)
+    pub recorder: Option<Arc<ConversationRecorder>>,
     /// Environment name for config reload
     env: Option<String>,
 }
@@ -81,12 +118,32 @@ impl AppState {
 
         let text_config = TextProcessingConfig::default();
         let text_processing = Arc::new(TextProcessingPipeline::new(text_config, None));
-        let text_simplifier = Arc::new(TextSimplifier::default_config());
 
         // Config-driven phonetic corrector from domain.yaml
         let phonetic_config = &master_config.phonetic_corrections;
         let vocabulary = &master_config.vocabulary;
 
+        // Pronunciation lexicon from domain.yaml, merged with domain abbreviations
+        let mut lexicon = PronunciationLexicon::from_terms(
+            vocabulary.pronunciations.clone(),
+            vocabulary.language_pronunciations.clone(),
+        );
+        lexicon.merge_abbreviations(
+            vocabulary
+                .abbreviations
+                .clone()
+                .into_iter()
+                .chain(master_config.vocabulary_full.abbreviations.clone()),
+        );
+        tracing::info!(
+            pronunciations = lexicon.len(),
+            "Initialized TTS pronunciation lexicon"
+        );
+        let text_simplifier = Arc::new(TextSimplifier::new(TextSimplifierConfig {
+            pronunciation_lexicon: lexicon,
+            ..TextSimplifierConfig::default()
+        }));
+
         // Convert contextual rules from config format to tuple format
         let contextual_rules: Vec<(String, String, String)> = phonetic_config
             .contextual_rules
@@ -137,6 +194,9 @@ impl AppState {
         let (agent_view, llm_view, tools_view) = Self::create_views(&master_domain_config);
         // P15 FIX: Create tools before moving tools_view into struct
         let tools = Arc::new(voice_agent_tools::registry::create_registry_with_view(tools_view.clone()));
+        let resume_tokens = Arc::new(ResumeTokens::from_config(&config.server.resume));
+        let webhooks = WebhookDispatcher::from_config(&config.server.webhooks);
+        let recorder = ConversationRecorder::from_config(&config.observability.recording);
         Self {
             config: Arc::new(RwLock::new(config)),
             master_domain_config,
@@ -147,11 +207,19 @@ impl AppState {
             tools,
             session_store: Arc::new(InMemorySessionStore::new()),
             vector_store: None,
+            retriever: None,
+            rag_warm: Arc::new(AtomicBool::new(false)),
             text_processing,
             text_simplifier,
             phonetic_corrector,
             translator,
             audit_logger: None,
+            data_export: None,
+            data_erasure: None,
+            scylla: None,
+            resume_tokens,
+            webhooks,
+            recorder,
             env: None,
         }
     }
@@ -166,6 +234,9 @@ impl AppState {
         let (agent_view, llm_view, tools_view) = Self::create_views(&master_domain_config);
         // P15 FIX: Create tools before moving tools_view into struct
         let tools = Arc::new(voice_agent_tools::registry::create_registry_with_view(tools_view.clone()));
+        let resume_tokens = Arc::new(ResumeTokens::from_config(&config.server.resume));
+        let webhooks = WebhookDispatcher::from_config(&config.server.webhooks);
+        let recorder = ConversationRecorder::from_config(&config.observability.recording);
         Self {
             config: Arc::new(RwLock::new(config)),
             master_domain_config,
@@ -176,11 +247,19 @@ impl AppState {
             tools,
             session_store: Arc::new(InMemorySessionStore::new()),
             vector_store: None,
+            retriever: None,
+            rag_warm: Arc::new(AtomicBool::new(false)),
             text_processing,
             text_simplifier,
             phonetic_corrector,
             translator,
             audit_logger: None,
+            data_export: None,
+            data_erasure: None,
+            scylla: None,
+            resume_tokens,
+            webhooks,
+            recorder,
             env: None,
         }
     }
@@ -192,6 +271,9 @@ impl AppState {
         let (agent_view, llm_view, tools_view) = Self::create_views(&master_domain_config);
         // P15 FIX: Create tools before moving tools_view into struct
         let tools = Arc::new(voice_agent_tools::registry::create_registry_with_view(tools_view.clone()));
+        let resume_tokens = Arc::new(ResumeTokens::from_config(&config.server.resume));
+        let webhooks = WebhookDispatcher::from_config(&config.server.webhooks);
+        let recorder = ConversationRecorder::from_config(&config.observability.recording);
         Self {
             config: Arc::new(RwLock::new(config)),
             master_domain_config,
@@ -202,11 +284,19 @@ impl AppState {
             tools,
             session_store: Arc::new(InMemorySessionStore::new()),
             vector_store: None,
+            retriever: None,
+            rag_warm: Arc::new(AtomicBool::new(false)),
             text_processing,
             text_simplifier,
             phonetic_corrector,
             translator,
             audit_logger: None,
+            data_export: None,
+            data_erasure: None,
+            scylla: None,
+            resume_tokens,
+            webhooks,
+            recorder,
             env,
         }
     }
@@ -218,6 +308,9 @@ impl AppState {
         let (agent_view, llm_view, tools_view) = Self::create_views(&master_domain_config);
         // P15 FIX: Create tools before moving tools_view into struct
         let tools = Arc::new(voice_agent_tools::registry::create_registry_with_view(tools_view.clone()));
+        let resume_tokens = Arc::new(ResumeTokens::from_config(&config.server.resume));
+        let webhooks = WebhookDispatcher::from_config(&config.server.webhooks);
+        let recorder = ConversationRecorder::from_config(&config.observability.recording);
         Self {
             config: Arc::new(RwLock::new(config)),
             master_domain_config,
@@ -228,11 +321,19 @@ impl AppState {
             tools,
             session_store: store,
             vector_store: None,
+            retriever: None,
+            rag_warm: Arc::new(AtomicBool::new(false)),
             text_processing,
             text_simplifier,
             phonetic_corrector,
             translator,
             audit_logger: None,
+            data_export: None,
+            data_erasure: None,
+            scylla: None,
+            resume_tokens,
+            webhooks,
+            recorder,
             env: None,
         }
     }
@@ -245,51 +346,147 @@ impl AppState {
     ///
     /// All business config (rates, LTV, etc.) now comes from ToolsDomainView.
     /// P16 FIX: Accept AssetPriceService (generic) instead of GoldPriceService
+    ///
+    /// Appointments are booked, rescheduled and cancelled in `appointments`
+    /// unless `persistence.calendar` is disabled. Captured leads and call
+    /// summaries are written to `crm`; compliance events, including deferred
+    /// escalations, to `audit_log`.
     pub fn with_full_persistence(
         config: Settings,
         store: Arc<dyn SessionStore>,
         master_domain_config: Arc<MasterDomainConfig>,
-        sms_service: Arc<dyn voice_agent_persistence::SmsService>,
-        gold_price_service: Arc<dyn voice_agent_persistence::AssetPriceService>,
+        services: PersistenceServices,
     ) -> Self {
+        let PersistenceServices {
+            sms_service,
+            gold_price_service,
+            appointments,
+            reminders,
+            crm,
+            audit_log,
+        } = services;
+        let audit_logger = Arc::new(Self::audit_logger(&config, audit_log));
         // P16 FIX: Use config-driven phonetic corrector
         let (text_processing, text_simplifier, phonetic_corrector, translator) = Self::create_text_processing_with_domain(&master_domain_config);
         let (agent_view, llm_view, tools_view) = Self::create_views(&master_domain_config);
 
         // P15 FIX: Create tool registry with REQUIRED tools_view and persistence services
-        let integration_config = voice_agent_tools::FullIntegrationConfig::new(tools_view.clone())
-            .with_sms_service(sms_service)
-            .with_gold_price_service(gold_price_service);
+        let mut integration_config =
+            voice_agent_tools::FullIntegrationConfig::new(tools_view.clone())
+                .with_sms_service(sms_service)
+                .with_gold_price_service(gold_price_service)
+                .with_crm(crm.clone())
+                .with_audit_logger(audit_logger.clone());
+        if let Some(reminders) = reminders {
+            integration_config = integration_config.with_reminders(reminders);
+        }
+        let calendar = &config.persistence.calendar;
+        if calendar.enabled {
+            let calendar = voice_agent_tools::StoreCalendar::new(
+                appointments,
+                voice_agent_tools::appointment_slots(Some(tools_view.as_ref())),
+                calendar.slot_capacity,
+            );
+            integration_config = integration_config.with_calendar(Arc::new(calendar));
+        }
         let tools = voice_agent_tools::create_registry_with_persistence(integration_config);
 
+        let resume_tokens = Arc::new(ResumeTokens::from_config(&config.server.resume));
+        let webhooks = WebhookDispatcher::from_config(&config.server.webhooks);
+        let recorder = ConversationRecorder::from_config(&config.observability.recording);
+        let sessions = SessionManager::new(100).with_crm(crm);
+        sessions.set_audit_logger(audit_logger.clone());
         Self {
             config: Arc::new(RwLock::new(config)),
             master_domain_config,
             agent_view,
             llm_view,
             tools_view,
-            sessions: Arc::new(SessionManager::new(100)),
+            sessions: Arc::new(sessions),
             tools: Arc::new(tools),
             session_store: store,
             vector_store: None,
+            retriever: None,
+            rag_warm: Arc::new(AtomicBool::new(false)),
             text_processing,
             text_simplifier,
             phonetic_corrector,
             translator,
-            audit_logger: None,
+            audit_logger: Some(audit_logger),
+            data_export: None,
+            data_erasure: None,
+            scylla: None,
+            resume_tokens,
+            webhooks,
+            recorder,
             env: None,
         }
     }
 
     /// P0 FIX: Set vector store for RAG retrieval
-    pub fn with_vector_store(mut self, vector_store: Arc<VectorStore>) -> Self {
+    pub fn with_vector_store(mut self, vector_store: Arc<dyn VectorStoreBackend>) -> Self {
         self.vector_store = Some(vector_store);
         self
     }
 
+    /// Share one retriever across sessions
+    pub fn with_retriever(mut self, retriever: Arc<HybridRetriever>) -> Self {
+        self.retriever = Some(retriever);
+        self
+    }
+
+    /// Mark the RAG models as warmed up, letting readiness checks pass
+    pub fn mark_rag_warm(&self) {
+        self.rag_warm.store(true, Ordering::Release);
+    }
+
+    /// Whether the RAG models have been warmed up
+    pub fn is_rag_warm(&self) -> bool {
+        self.rag_warm.load(Ordering::Acquire)
+    }
+
+    /// Audit logger keying customer pseudonyms with the configured secret
+    fn audit_logger(config: &Settings, audit_log: Arc<dyn AuditLog>) -> AuditLogger {
+        let logger = AuditLogger::new(audit_log);
+        match &config.server.data_export.customer_key_secret {
+            Some(secret) if !secret.is_empty() => logger.with_customer_key_secret(secret),
+            _ => {
+                tracing::debug!("No audit customer key secret configured, generating one");
+                logger
+            },
+        }
+    }
+
     /// P2 FIX: Set audit logger for RBI compliance logging
     pub fn with_audit_logger(mut self, audit_log: Arc<dyn AuditLog>) -> Self {
-        self.audit_logger = Some(Arc::new(AuditLogger::new(audit_log)));
+        let audit_logger = Arc::new(Self::audit_logger(&self.config.read(), audit_log));
+        self.sessions.set_audit_logger(audit_logger.clone());
+        self.audit_logger = Some(audit_logger);
+        self
+    }
+
+    /// Serve customer data exports from these stores
+    pub fn with_data_export(mut self, exporter: CustomerDataExporter) -> Self {
+        self.data_export = Some(Arc::new(exporter));
+        self
+    }
+
+    /// Serve customer data erasure from these stores
+    ///
+    /// The customer's sessions are erased from the conversation recording
+    /// too, when sessions are recorded.
+    pub fn with_data_erasure(mut self, eraser: CustomerDataEraser) -> Self {
+        let eraser = match self.recorder {
+            Some(ref recorder) => eraser.with_target(recorder.clone()),
+            None => eraser,
+        };
+        self.data_erasure = Some(Arc::new(eraser));
+        self
+    }
+
+    /// Set ScyllaDB client so readiness checks cover the database
+    pub fn with_scylla_client(mut self, client: ScyllaClient) -> Self {
+        self.scylla = Some(client);
         self
     }
 
@@ -347,6 +544,19 @@ impl AppState {
         self.config.read()
     }
 
+    /// Agent config for a new session, from the current settings
+    pub fn agent_config(&self) -> voice_agent_agent::AgentConfig {
+        voice_agent_agent::AgentConfig::from(&self.config.read().agent)
+    }
+
+    /// Voice session config for a new session: the domain's idle prompts and
+    /// the current TTS settings
+    pub fn voice_session_config(&self) -> voice_agent_agent::VoiceSessionConfig {
+        voice_agent_agent::VoiceSessionConfig::default()
+            .with_domain_prompts(&self.master_domain_config.prompts)
+            .with_tts_settings(&self.config.read().pipeline.tts)
+    }
+
     /// P12 FIX: Get master domain configuration (source of truth for all domain config)
     pub fn get_master_domain_config(&self) -> &Arc<MasterDomainConfig> {
         &self.master_domain_config
@@ -0,0 +1,463 @@
+//! Outbound Webhooks
+//!
+//! Downstream systems (CRM, ticketing, analytics) subscribe to call events
+//! over HTTP. Each session's `AgentEvent` stream is mapped to a small set of
+//! typed `WebhookEvent`s, which are POSTed as JSON to every configured URL.
+//! Deliveries are signed with HMAC-SHA256 when a secret is configured,
+//! retried with exponential backoff, and dead-lettered (logged, kept in
+//! memory and optionally appended to a file) once the retries run out.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use hmac::{Hmac, Mac};
+use parking_lot::Mutex;
+use serde::Serialize;
+use sha2::Sha256;
+use tokio::sync::broadcast;
+use tokio::task::JoinHandle;
+
+use voice_agent_agent::{AgentEvent, ConversationEvent, EndReason};
+use voice_agent_config::WebhookConfig;
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// Header carrying the body's HMAC, as `sha256=<hex>`
+pub const SIGNATURE_HEADER: &str = "X-Webhook-Signature";
+
+/// Dead letters kept in memory for inspection; older ones are dropped
+const MAX_DEAD_LETTERS: usize = 1000;
+
+/// Call event delivered to webhook endpoints
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum WebhookEvent {
+    /// The lead capture tool saved the caller's details
+    LeadCaptured,
+    /// The appointment tool booked a branch visit
+    AppointmentBooked,
+    /// The call is being handed to a human
+    EscalationTriggered {
+        trigger: String,
+        recommendation: String,
+    },
+    /// The call ended
+    SessionEnded { reason: String },
+}
+
+impl WebhookEvent {
+    /// Webhook event for an agent event, if it is one downstream systems see
+    pub fn from_agent_event(event: &AgentEvent) -> Option<Self> {
+        match event {
+            AgentEvent::ToolResult {
+                name,
+                success: true,
+            } => match name.as_str() {
+                "capture_lead" => Some(WebhookEvent::LeadCaptured),
+                "schedule_appointment" => Some(WebhookEvent::AppointmentBooked),
+                "escalate_to_human" => Some(WebhookEvent::EscalationTriggered {
+                    trigger: "customer_request".to_string(),
+                    recommendation: "Transfer to a human agent".to_string(),
+                }),
+                _ => None,
+            },
+            AgentEvent::EscalationTriggered {
+                trigger,
+                recommendation,
+            } => Some(WebhookEvent::EscalationTriggered {
+                trigger: trigger.clone(),
+                recommendation: recommendation.clone(),
+            }),
+            AgentEvent::Conversation(ConversationEvent::Ended { reason }) => {
+                Some(WebhookEvent::SessionEnded {
+                    reason: end_reason_name(reason).to_string(),
+                })
+            },
+            _ => None,
+        }
+    }
+
+    /// Event type name, as it appears in the payload's `type` field
+    pub fn event_type(&self) -> &'static str {
+        match self {
+            WebhookEvent::LeadCaptured => "lead_captured",
+            WebhookEvent::AppointmentBooked => "appointment_booked",
+            WebhookEvent::EscalationTriggered { .. } => "escalation_triggered",
+            WebhookEvent::SessionEnded { .. } => "session_ended",
+        }
+    }
+}
+
+fn end_reason_name(reason: &EndReason) -> &'static str {
+    match reason {
+        EndReason::UserEnded => "user_ended",
+        EndReason::AgentEnded => "agent_ended",
+        EndReason::Timeout => "timeout",
+        EndReason::IdleTimeout => "idle_timeout",
+        EndReason::ConsentRefused => "consent_refused",
+        EndReason::MaxDuration => "max_duration",
+        EndReason::Error(_) => "error",
+    }
+}
+
+/// Webhook request body
+#[derive(Debug, Clone, Serialize)]
+pub struct WebhookPayload {
+    /// Unique per event; lets receivers drop retried duplicates
+    pub id: String,
+    pub session_id: String,
+    pub timestamp: chrono::DateTime<chrono::Utc>,
+    #[serde(flatten)]
+    pub event: WebhookEvent,
+}
+
+impl WebhookPayload {
+    pub fn new(session_id: impl Into<String>, event: WebhookEvent) -> Self {
+        Self {
+            id: uuid::Uuid::new_v4().to_string(),
+            session_id: session_id.into(),
+            timestamp: chrono::Utc::now(),
+            event,
+        }
+    }
+}
+
+/// Delivery that failed permanently
+#[derive(Debug, Clone, Serialize)]
+pub struct DeadLetter {
+    pub url: String,
+    pub event_id: String,
+    pub event_type: String,
+    pub body: String,
+    pub attempts: u32,
+    pub error: String,
+    pub failed_at: chrono::DateTime<chrono::Utc>,
+}
+
+/// POSTs webhook events to the configured endpoints
+pub struct WebhookDispatcher {
+    config: WebhookConfig,
+    client: reqwest::Client,
+    dead_letters: Mutex<Vec<DeadLetter>>,
+}
+
+impl WebhookDispatcher {
+    pub fn new(config: WebhookConfig) -> Self {
+        let client = reqwest::Client::builder()
+            .timeout(Duration::from_millis(config.timeout_ms))
+            .build()
+            .unwrap_or_default();
+        Self {
+            config,
+            client,
+            dead_letters: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Dispatcher for the configured endpoints, or None when there are none
+    pub fn from_config(config: &WebhookConfig) -> Option<Arc<Self>> {
+        if config.urls.is_empty() {
+            return None;
+        }
+        Some(Arc::new(Self::new(config.clone())))
+    }
+
+    /// Whether the event type is delivered
+    pub fn wants(&self, event: &WebhookEvent) -> bool {
+        self.config.events.is_empty() || self.config.events.iter().any(|e| e == event.event_type())
+    }
+
+    /// `sha256=<hex>` signature of the body, or None without a secret
+    pub fn signature(&self, body: &[u8]) -> Option<String> {
+        let secret = self.config.secret.as_ref()?;
+        let mut mac =
+            HmacSha256::new_from_slice(secret.as_bytes()).expect("HMAC accepts keys of any length");
+        mac.update(body);
+        let hex: String = mac
+            .finalize()
+            .into_bytes()
+            .iter()
+            .map(|b| format!("{:02x}", b))
+            .collect();
+        Some(format!("sha256={}", hex))
+    }
+
+    /// Deliver a payload to every endpoint
+    pub async fn dispatch(&self, payload: &WebhookPayload) {
+        if !self.wants(&payload.event) {
+            return;
+        }
+        let body = match serde_json::to_string(payload) {
+            Ok(body) => body,
+            Err(e) => {
+                tracing::error!(error = %e, "Failed to serialize webhook payload");
+                return;
+            },
+        };
+
+        let deliveries = self
+            .config
+            .urls
+            .iter()
+            .map(|url| self.deliver(url, payload, &body));
+        futures::future::join_all(deliveries).await;
+    }
+
+    /// Deliver to one endpoint, retrying with backoff, then dead-letter
+    async fn deliver(&self, url: &str, payload: &WebhookPayload, body: &str) {
+        let signature = self.signature(body.as_bytes());
+        let attempts = self.config.max_retries + 1;
+        let mut backoff = Duration::from_millis(self.config.initial_backoff_ms);
+        let mut error = String::new();
+
+        for attempt in 1..=attempts {
+            let mut request = self
+                .client
+                .post(url)
+                .header(reqwest::header::CONTENT_TYPE, "application/json")
+                .body(body.to_string());
+            if let Some(ref signature) = signature {
+                request = request.header(SIGNATURE_HEADER, signature);
+            }
+
+            match request.send().await {
+                Ok(response) if response.status().is_success() => {
+                    crate::metrics::record_webhook_delivery("delivered");
+                    tracing::debug!(
+                        url = %url,
+                        event = payload.event.event_type(),
+                        attempt,
+                        "Webhook delivered"
+                    );
+                    return;
+                },
+                Ok(response) => error = format!("HTTP {}", response.status()),
+                Err(e) => error = e.to_string(),
+            }
+
+            if attempt < attempts {
+                crate::metrics::record_webhook_delivery("retried");
+                tracing::warn!(
+                    url = %url,
+                    attempt,
+                    error = %error,
+                    "Webhook delivery failed, retrying"
+                );
+                tokio::time::sleep(backoff).await;
+                backoff *= 2;
+            }
+        }
+
+        self.dead_letter(DeadLetter {
+            url: url.to_string(),
+            event_id: payload.id.clone(),
+            event_type: payload.event.event_type().to_string(),
+            body: body.to_string(),
+            attempts,
+            error,
+            failed_at: chrono::Utc::now(),
+        })
+        .await;
+    }
+
+    async fn dead_letter(&self, letter: DeadLetter) {
+        crate::metrics::record_webhook_delivery("dead_lettered");
+        tracing::error!(
+            url = %letter.url,
+            event_id = %letter.event_id,
+            event = %letter.event_type,
+            attempts = letter.attempts,
+            error = %letter.error,
+            "Webhook delivery failed permanently"
+        );
+
+        if let Some(ref path) = self.config.dead_letter_path {
+            if let Err(e) = append_line(path, &letter).await {
+                tracing::warn!(path = %path, error = %e, "Failed to write webhook dead letter");
+            }
+        }
+
+        let mut dead_letters = self.dead_letters.lock();
+        if dead_letters.len() >= MAX_DEAD_LETTERS {
+            dead_letters.remove(0);
+        }
+        dead_letters.push(letter);
+    }
+
+    /// Deliveries that failed permanently, oldest first
+    pub fn dead_letters(&self) -> Vec<DeadLetter> {
+        self.dead_letters.lock().clone()
+    }
+
+    /// Deliver a session's events until it ends
+    ///
+    /// Each event is delivered on its own task, so a slow endpoint never
+    /// holds up the agent's event stream.
+    pub fn forward(
+        self: Arc<Self>,
+        session_id: String,
+        mut events: broadcast::Receiver<AgentEvent>,
+    ) -> JoinHandle<()> {
+        tokio::spawn(async move {
+            loop {
+                let event = match events.recv().await {
+                    Ok(event) => event,
+                    Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                        tracing::warn!(
+                            session_id = %session_id,
+                            skipped,
+                            "Webhook forwarder lagged"
+                        );
+                        continue;
+                    },
+                    Err(broadcast::error::RecvError::Closed) => break,
+                };
+                let Some(event) = WebhookEvent::from_agent_event(&event) else {
+                    continue;
+                };
+
+                let ended = matches!(event, WebhookEvent::SessionEnded { .. });
+                let payload = WebhookPayload::new(session_id.clone(), event);
+                let dispatcher = self.clone();
+                tokio::spawn(async move { dispatcher.dispatch(&payload).await });
+                if ended {
+                    break;
+                }
+            }
+        })
+    }
+}
+
+async fn append_line(path: &str, letter: &DeadLetter) -> std::io::Result<()> {
+    use tokio::io::AsyncWriteExt;
+
+    let mut line = serde_json::to_string(letter).map_err(std::io::Error::other)?;
+    line.push('\n');
+    let mut file = tokio::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .await?;
+    file.write_all(line.as_bytes()).await
+}
+
+impl std::fmt::Debug for WebhookDispatcher {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("WebhookDispatcher")
+            .field("urls", &self.config.urls)
+            .field("events", &self.config.events)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::body::Bytes;
+    use axum::http::{HeaderMap, StatusCode};
+    use axum::routing::post;
+    use axum::Router;
+
+    /// Requests received by the mock endpoint
+    type Received = Arc<Mutex<Vec<(HeaderMap, Bytes)>>>;
+
+    /// Start a mock endpoint answering every POST with `status`
+    async fn mock_endpoint(status: StatusCode) -> (String, Received) {
+        let received: Received = Arc::default();
+        let store = received.clone();
+        let app = Router::new().route(
+            "/hook",
+            post(move |headers: HeaderMap, body: Bytes| {
+                let store = store.clone();
+                async move {
+                    store.lock().push((headers, body));
+                    status
+                }
+            }),
+        );
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+        (format!("http://{}/hook", addr), received)
+    }
+
+    fn config(url: String) -> WebhookConfig {
+        WebhookConfig {
+            urls: vec![url],
+            secret: Some("test-secret".to_string()),
+            initial_backoff_ms: 10,
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_lead_capture_delivers_signed_payload() {
+        let (url, received) = mock_endpoint(StatusCode::OK).await;
+        let dispatcher = WebhookDispatcher::from_config(&config(url)).unwrap();
+        let (tx, rx) = broadcast::channel(16);
+        let forwarder = dispatcher.clone().forward("session-1".to_string(), rx);
+
+        tx.send(AgentEvent::ToolResult {
+            name: "capture_lead".to_string(),
+            success: true,
+        })
+        .unwrap();
+        tx.send(AgentEvent::Thinking).unwrap();
+        drop(tx);
+        forwarder.await.unwrap();
+
+        for _ in 0..100 {
+            if !received.lock().is_empty() {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+
+        let received = received.lock();
+        assert_eq!(received.len(), 1);
+        let (headers, body) = &received[0];
+        let payload: serde_json::Value = serde_json::from_slice(body).unwrap();
+        assert_eq!(payload["type"], "lead_captured");
+        assert_eq!(payload["session_id"], "session-1");
+        let signature = headers.get(SIGNATURE_HEADER).unwrap().to_str().unwrap();
+        assert_eq!(Some(signature.to_string()), dispatcher.signature(body));
+        assert!(dispatcher.dead_letters().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_failing_endpoint_retried_then_dead_lettered() {
+        let (url, received) = mock_endpoint(StatusCode::INTERNAL_SERVER_ERROR).await;
+        let dispatcher = WebhookDispatcher::new(WebhookConfig {
+            max_retries: 2,
+            ..config(url.clone())
+        });
+        let payload = WebhookPayload::new(
+            "session-2",
+            WebhookEvent::SessionEnded {
+                reason: "user_ended".to_string(),
+            },
+        );
+
+        dispatcher.dispatch(&payload).await;
+
+        assert_eq!(received.lock().len(), 3);
+        let dead_letters = dispatcher.dead_letters();
+        assert_eq!(dead_letters.len(), 1);
+        assert_eq!(dead_letters[0].url, url);
+        assert_eq!(dead_letters[0].event_id, payload.id);
+        assert_eq!(dead_letters[0].attempts, 3);
+        assert!(dead_letters[0].error.contains("500"));
+    }
+
+    #[test]
+    fn test_event_filter() {
+        let dispatcher = WebhookDispatcher::new(WebhookConfig {
+            events: vec!["session_ended".to_string()],
+            ..config("http://localhost/hook".to_string())
+        });
+        assert!(!dispatcher.wants(&WebhookEvent::LeadCaptured));
+        assert!(dispatcher.wants(&WebhookEvent::SessionEnded {
+            reason: "timeout".to_string()
+        }));
+    }
+}
@@ -26,8 +26,11 @@ use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 use tokio::sync::{mpsc, Mutex, RwLock};
 
+use voice_agent_agent::EndReason;
 use voice_agent_core::{AudioFrame, Channels, SampleRate};
-use voice_agent_pipeline::{create_noise_suppressor, PipelineConfig, PipelineEvent, VoicePipeline};
+use voice_agent_pipeline::{
+    create_noise_suppressor, PipelineConfig, PipelineEvent, PlaybackTracker, VoicePipeline,
+};
 use voice_agent_transport::{
     IceCandidate, IceServer, Transport, TransportEvent, WebRtcConfig, WebRtcTransport,
 };
@@ -178,11 +181,15 @@ pub async fn handle_offer(
     // P2 FIX: Wire noise suppression for cleaner audio input
     let noise_suppressor: Arc<dyn voice_agent_core::AudioProcessor> =
         Arc::from(create_noise_suppressor(16000)); // 16kHz input
-    let pipeline = match VoicePipeline::simple(PipelineConfig::default()) {
+    let mut pipeline_config = PipelineConfig::default();
+    pipeline_config.apply_tts_settings(&state.config.read().pipeline.tts);
+    pipeline_config.apply_barge_in_settings(&state.config.read().pipeline.barge_in);
+    let pipeline = match VoicePipeline::simple(pipeline_config) {
         Ok(p) => {
             let p = p
                 .with_text_processor(state.text_processing.clone())
                 .with_noise_suppressor(noise_suppressor);
+            p.set_language(session.agent.user_language());
             tracing::info!("Created voice pipeline with text processing and noise suppression for WebRTC session {}", session_id);
             Some(Arc::new(Mutex::new(p)))
         },
@@ -208,6 +215,23 @@ pub async fn handle_offer(
         (None, None)
     };
 
+    // Open the call with the opening script up to the consent question
+    if let Some(ref pipeline) = pipeline {
+        if let Some(opening) = session.open_call().await {
+            let pipeline = pipeline.clone();
+            let session_id = session_id.clone();
+            tokio::spawn(async move {
+                if let Err(e) = pipeline.lock().await.speak(&opening).await {
+                    tracing::warn!(
+                        session_id = %session_id,
+                        error = %e,
+                        "Failed to speak WebRTC call opening"
+                    );
+                }
+            });
+        }
+    }
+
     // Store WebRTC session
     let webrtc_session = WebRtcSession {
         transport,
@@ -501,16 +525,23 @@ async fn spawn_webrtc_audio_processor(
             session_id = %session_id_for_audio,
             "WebRTC audio receiver task ended"
         );
+
+        // The peer connection is gone, so the call is over; ending it
+        // delivers the session_ended webhook
+        session_for_audio.agent.end_call(EndReason::UserEnded).await;
     });
 
     // P1 FIX: Pipeline event task - handles transcripts and sends to agent
     let session_for_pipeline = session.clone();
     let session_id_for_pipeline = session_id.clone();
 
+    // Subscribed before returning, so nothing spoken after setup is missed
+    let mut pipeline_events = pipeline.lock().await.subscribe();
     let pipeline_task = tokio::spawn(async move {
-        let mut pipeline_events = pipeline.lock().await.subscribe();
         // P2 FIX: Track timestamp for TTS audio output
         let mut tts_timestamp_ms: u64 = 0;
+        // What the caller heard of the current utterance
+        let mut playback = PlaybackTracker::new();
 
         while let Ok(event) = pipeline_events.recv().await {
             match event {
@@ -522,8 +553,20 @@ async fn spawn_webrtc_audio_processor(
                     );
                     // Could send to WebRTC data channel if available
                 },
+                PipelineEvent::LanguageDetected(detection) => {
+                    let agent = &session_for_pipeline.agent;
+                    if agent.apply_language_detection(detection.language, detection.confidence) {
+                        pipeline.lock().await.set_language(agent.user_language());
+                        tracing::info!(
+                            session_id = %session_id_for_pipeline,
+                            language = ?agent.user_language(),
+                            "WebRTC caller language switched"
+                        );
+                    }
+                },
                 PipelineEvent::FinalTranscript(transcript) => {
                     let text = transcript.text.clone();
+                    playback.reset();
                     tracing::info!(
                         session_id = %session_id_for_pipeline,
                         text = %text,
@@ -539,6 +582,8 @@ async fn spawn_webrtc_audio_processor(
                                     response_len = response.len(),
                                     "Agent response generated for WebRTC"
                                 );
+                                let stage = session_for_pipeline.agent.stage();
+                                pipeline.lock().await.set_barge_in_stage(stage.as_str());
                                 // TTS audio will come through PipelineEvent::TtsAudio
                             },
                             Err(e) => {
@@ -567,9 +612,18 @@ async fn spawn_webrtc_audio_processor(
                 },
                 PipelineEvent::TtsAudio {
                     samples,
-                    text: _,
+                    text,
                     is_final,
                 } => {
+                    if !text.is_empty() {
+                        playback.text(&text);
+                    }
+                    playback.audio(std::time::Duration::from_secs_f64(
+                        samples.len() as f64 / 16000.0,
+                    ));
+                    if is_final {
+                        playback.finish();
+                    }
                     // P2 FIX: Send TTS audio back via WebRTC audio sink
                     if let Some(ref sink) = audio_sink {
                         // Upsample from 16kHz to 48kHz for WebRTC (Opus expects 48kHz)
@@ -603,16 +657,40 @@ async fn spawn_webrtc_audio_processor(
                         );
                     }
                 },
-                PipelineEvent::BargeIn { at_word } => {
+                PipelineEvent::BargeIn { at_word, reason } => {
+                    crate::metrics::record_tts_interruption(reason);
                     tracing::debug!(
                         session_id = %session_id_for_pipeline,
                         at_word = at_word,
-                        "WebRTC barge-in detected"
+                        reason = reason.as_str(),
+                        "WebRTC playback interrupted"
                     );
                     // P2 FIX: Flush any pending TTS audio on barge-in
                     if let Some(ref sink) = audio_sink {
                         let _ = sink.flush().await;
                     }
+                    // A system stop isn't the caller cutting the agent off
+                    if reason.is_user_initiated() && playback.is_playing() {
+                        session_for_pipeline
+                            .agent
+                            .record_interrupted_response(&playback.played_text());
+                    }
+                    playback.reset();
+                },
+                PipelineEvent::TtsFailed { error } => {
+                    crate::metrics::record_tts_failure();
+                    tracing::warn!(
+                        session_id = %session_id_for_pipeline,
+                        error = %error,
+                        "WebRTC TTS failed, response not delivered"
+                    );
+                    // The caller hears what was sent before the failure; the
+                    // agent gives the rest again
+                    playback.mark_unspoken();
+                    session_for_pipeline
+                        .agent
+                        .record_interrupted_response(&playback.sent_text());
+                    playback.reset();
                 },
                 PipelineEvent::Error(e) => {
                     tracing::error!(
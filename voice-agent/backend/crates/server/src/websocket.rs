@@ -4,7 +4,7 @@
 
 use axum::{
     extract::{
-        ws::{Message, WebSocket, WebSocketUpgrade},
+        ws::{CloseFrame, Message, WebSocket, WebSocketUpgrade},
         Path, State,
     },
     response::Response,
@@ -13,15 +13,27 @@ use base64::{engine::general_purpose::STANDARD as BASE64, Engine as _};
 use futures::{SinkExt, StreamExt};
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 use tokio::sync::mpsc;
 
-use voice_agent_core::{AudioFrame, Channels, Frame, LanguageModel, SampleRate};
-use voice_agent_llm::{LlmFactory, LlmProviderConfig};
-use voice_agent_pipeline::{create_noise_suppressor, PipelineConfig, PipelineEvent, VoicePipeline};
+use voice_agent_core::{AudioFrame, Channels, Frame, Language, LanguageModel, SampleRate};
+use voice_agent_llm::{ConcurrencyLimitConfig, LlmFactory, LlmProviderConfig};
+use voice_agent_pipeline::{
+    create_noise_suppressor, InterruptionReason, PipelineConfig, PipelineEvent, PlaybackTracker,
+    VoicePipeline,
+};
 
+use crate::outbound::OutboundQueue;
+use crate::playout::AudioPlayout;
 use crate::rate_limit::RateLimiter;
-use crate::session::Session;
+use crate::session::{ConnectionLease, Session};
 use crate::state::AppState;
+use crate::ServerError;
+use voice_agent_agent::{
+    AgentError, ConversationState, DomainAgent, EndReason, IdleAction, IdleMonitor,
+    VoiceSessionConfig,
+};
+use voice_agent_text_processing::TextSimplifier;
 
 /// WebSocket message types
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -53,9 +65,11 @@ pub enum WsMessage {
         state: String,
         stage: String,
     },
-    /// Error
+    /// Error; the socket stays open only if `recoverable`
     Error {
+        code: WsErrorCode,
         message: String,
+        recoverable: bool,
     },
     /// Ping/Pong
     Ping,
@@ -64,10 +78,153 @@ pub enum WsMessage {
     SessionInfo {
         session_id: String,
     },
+    /// Recommended next best action for the agent or supervisor
+    NextBestAction {
+        action: voice_agent_agent::NextBestAction,
+        rationale: String,
+    },
+    /// Slots collected so far, PII values masked
+    Slots {
+        goal_id: String,
+        filled: Vec<voice_agent_agent::FilledSlot>,
+        missing: Vec<String>,
+    },
+    /// A supervisor handed the call back to the agent, with their notes
+    ///
+    /// Sent to the client only; handbacks come through the supervisor
+    /// endpoint, so one sent by the client is ignored.
+    Handback {
+        text: String,
+        #[serde(default)]
+        notes: Option<String>,
+    },
     /// End session
     EndSession,
 }
 
+/// Stable error codes sent in `error` frames
+///
+/// Clients may branch on these; add new codes rather than renaming old ones.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WsErrorCode {
+    /// Too many messages or too much audio; retry after slowing down
+    RateLimited,
+    /// A client frame could not be understood
+    InvalidMessage,
+    /// The language model failed for this turn
+    LlmError,
+    /// A tool call failed for this turn
+    ToolError,
+    /// The agent did not respond in time
+    Timeout,
+    /// The agent could not handle the turn (intent, memory or stage failure)
+    AgentError,
+    /// The conversation ended, timed out or is paused
+    ConversationEnded,
+    /// The session does not exist or was closed
+    SessionUnavailable,
+    /// Another connection owns the session
+    SessionConflict,
+    /// The API key was missing or not allowed
+    Unauthorized,
+    /// The audio pipeline or transport failed
+    TransportError,
+    /// Unexpected server failure
+    Internal,
+}
+
+/// Error reported to a WebSocket client
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WsError {
+    pub code: WsErrorCode,
+    pub message: String,
+    /// Whether the session can continue after this error
+    pub recoverable: bool,
+}
+
+impl WsError {
+    pub fn new(code: WsErrorCode, message: impl Into<String>, recoverable: bool) -> Self {
+        Self {
+            code,
+            message: message.into(),
+            recoverable,
+        }
+    }
+
+    /// Frames to send: the error, then a close frame if the error is fatal
+    pub fn into_messages(self) -> Vec<Message> {
+        let close = (!self.recoverable).then(|| {
+            Message::Close(Some(CloseFrame {
+                code: CLOSE_CODE_INTERNAL_ERROR,
+                reason: serde_json::to_value(self.code)
+                    .ok()
+                    .and_then(|v| v.as_str().map(String::from))
+                    .unwrap_or_default()
+                    .into(),
+            }))
+        });
+        let frame = WsMessage::Error {
+            code: self.code,
+            message: self.message,
+            recoverable: self.recoverable,
+        };
+        std::iter::once(Message::Text(serde_json::to_string(&frame).unwrap()))
+            .chain(close)
+            .collect()
+    }
+}
+
+impl From<&AgentError> for WsError {
+    fn from(err: &AgentError) -> Self {
+        let (code, recoverable) = match err {
+            AgentError::Llm(_) => (WsErrorCode::LlmError, true),
+            AgentError::Tool(_) => (WsErrorCode::ToolError, true),
+            AgentError::Timeout => (WsErrorCode::Timeout, true),
+            AgentError::Intent(_) | AgentError::Memory(_) | AgentError::Stage(_) => {
+                (WsErrorCode::AgentError, true)
+            },
+            AgentError::Conversation(_) => (WsErrorCode::ConversationEnded, false),
+            AgentError::Pipeline(_) => (WsErrorCode::TransportError, false),
+            AgentError::Initialization(_) => (WsErrorCode::Internal, false),
+        };
+        Self::new(code, err.to_string(), recoverable)
+    }
+}
+
+impl From<&ServerError> for WsError {
+    fn from(err: &ServerError) -> Self {
+        let (code, recoverable) = match err {
+            ServerError::RateLimit => (WsErrorCode::RateLimited, true),
+            ServerError::InvalidRequest(_) => (WsErrorCode::InvalidMessage, true),
+            // The conversation does not depend on persistence succeeding
+            ServerError::Persistence(_) => (WsErrorCode::Internal, true),
+            ServerError::Session(_) => (WsErrorCode::SessionUnavailable, false),
+            ServerError::Conflict(_) => (WsErrorCode::SessionConflict, false),
+            ServerError::Auth(_) => (WsErrorCode::Unauthorized, false),
+            ServerError::WebSocket(_) | ServerError::WebRtc(_) => {
+                (WsErrorCode::TransportError, false)
+            },
+            ServerError::Internal(_) => (WsErrorCode::Internal, false),
+        };
+        Self::new(code, err.to_string(), recoverable)
+    }
+}
+
+/// Close code for a connection ended by a fatal error (RFC 6455 "internal error")
+pub const CLOSE_CODE_INTERNAL_ERROR: u16 = 1011;
+
+/// Close code sent to a connection replaced by a newer one for the same session
+pub const CLOSE_CODE_SUPERSEDED: u16 = 4000;
+
+/// Close frame telling a client its connection was superseded
+pub fn superseded_close_frame() -> CloseFrame<'static> {
+    CloseFrame {
+        code: CLOSE_CODE_SUPERSEDED,
+        reason: "superseded".into(),
+    }
+}
+
 /// WebSocket handler
 pub struct WebSocketHandler;
 
@@ -84,12 +241,26 @@ impl WebSocketHandler {
             .get(&session_id)
             .ok_or(axum::http::StatusCode::NOT_FOUND)?;
 
+        // Only one connection drives a session; a reconnect supersedes (or is
+        // refused by) a connection that is still open
+        let policy = state.config.read().server.duplicate_connection;
+        let lease = state
+            .sessions
+            .attach_connection(&session_id, policy)
+            .await
+            .map_err(|e| {
+                tracing::warn!(session_id = %session_id, error = %e, "WebSocket connection refused");
+                axum::http::StatusCode::from(e)
+            })?;
+
         // Create rate limiter for this connection
         // P1 FIX: Use RwLock for hot-reload support
         let rate_limit_config = state.config.read().server.rate_limit.clone();
         let rate_limiter = RateLimiter::new(rate_limit_config);
 
-        Ok(ws.on_upgrade(move |socket| Self::handle_socket(socket, session, state, rate_limiter)))
+        Ok(ws.on_upgrade(move |socket| {
+            Self::handle_socket(socket, session, state, rate_limiter, lease)
+        }))
     }
 
     /// Handle WebSocket connection
@@ -98,36 +269,62 @@ impl WebSocketHandler {
         session: Arc<Session>,
         state: AppState,
         rate_limiter: RateLimiter,
+        mut lease: ConnectionLease,
     ) {
         // P2 FIX: Get text processing components from state
         let text_processing = state.text_processing.clone();
         let text_simplifier = state.text_simplifier.clone();
-        let (sender, mut receiver) = socket.split();
+        let (mut sink, receiver) = socket.split();
+
+        // P1 FIX: Everything bound for the client goes through one queue drained by a
+        // single writer, so a slow client drops stale audio instead of stalling the pipeline
+        let audio_queue_frames = state.config.read().server.ws_audio_queue_frames;
+        let sender = Arc::new(OutboundQueue::new(audio_queue_frames));
+        let writer_task = {
+            let queue = sender.clone();
+            tokio::spawn(async move {
+                while let Some(message) = queue.next().await {
+                    if let Err(e) = sink.send(message).await {
+                        tracing::debug!("WebSocket send failed: {}", e);
+                        break;
+                    }
+                }
+                // Producers stop once the client is gone
+                queue.close();
+                if queue.dropped_audio() > 0 {
+                    tracing::info!(
+                        dropped = queue.dropped_audio(),
+                        "Dropped audio frames for slow WebSocket client"
+                    );
+                }
+            })
+        };
 
-        // Wrap sender in Arc<Mutex> for sharing across tasks
-        let sender = Arc::new(tokio::sync::Mutex::new(sender));
+        // TTS audio is buffered up to the jitter target and paced into the queue
+        let playout_config = state.config.read().server.audio_playout.clone();
+        let playout = AudioPlayout::new(&playout_config, sender.clone());
 
         // Wrap rate limiter in Arc<Mutex> for thread-safe access
         let rate_limiter = Arc::new(tokio::sync::Mutex::new(rate_limiter));
 
+        // A caller who goes quiet is asked whether they are still there, then
+        // the call is closed with `EndReason::IdleTimeout`
+        let voice_config = state.voice_session_config();
+        let idle = Arc::new(parking_lot::Mutex::new(voice_config.idle_monitor()));
+
         // Send session info
         {
             let info = WsMessage::SessionInfo {
                 session_id: session.id.clone(),
             };
-            let mut s = sender.lock().await;
-            let _ = s
-                .send(Message::Text(serde_json::to_string(&info).unwrap()))
-                .await;
+            sender.push_control(Message::Text(serde_json::to_string(&info).unwrap()));
 
             // Send initial status
             let status = WsMessage::Status {
                 state: "active".to_string(),
                 stage: session.agent.stage().display_name().to_string(),
             };
-            let _ = s
-                .send(Message::Text(serde_json::to_string(&status).unwrap()))
-                .await;
+            sender.push_control(Message::Text(serde_json::to_string(&status).unwrap()));
         }
 
         // Subscribe to agent events
@@ -144,7 +341,9 @@ impl WebSocketHandler {
 
         // P0 FIX: Create LLM backend (Ollama with qwen3) for response generation
         let llm: Option<Arc<dyn LanguageModel>> = {
-            let llm_config = LlmProviderConfig::ollama("qwen3:4b-instruct-2507-q4_K_M");
+            let settings = state.config.read().agent.llm.concurrency.clone();
+            let llm_config = LlmProviderConfig::ollama("qwen3:4b-instruct-2507-q4_K_M")
+                .with_concurrency(ConcurrencyLimitConfig::from(&settings));
             match LlmFactory::create(&llm_config) {
                 Ok(llm) => {
                     tracing::info!("LLM backend initialized: Ollama qwen3:4b");
@@ -158,19 +357,24 @@ impl WebSocketHandler {
         };
 
         // Create voice pipeline (use IndicConformer if onnx feature enabled, otherwise simple)
+        let mut pipeline_config = PipelineConfig::default();
+        pipeline_config.apply_tts_settings(&state.config.read().pipeline.tts);
+        pipeline_config.apply_barge_in_settings(&state.config.read().pipeline.barge_in);
+        let tts_sample_rate = pipeline_config.tts.sample_rate;
         #[cfg(feature = "onnx")]
         let pipeline_result = {
             let indicconformer_model_path = "models/stt/indicconformer";
-            VoicePipeline::with_indicconformer(indicconformer_model_path, PipelineConfig::default())
+            VoicePipeline::with_indicconformer(indicconformer_model_path, pipeline_config)
         };
         #[cfg(not(feature = "onnx"))]
-        let pipeline_result = VoicePipeline::simple(PipelineConfig::default());
+        let pipeline_result = VoicePipeline::simple(pipeline_config);
 
         let pipeline = match pipeline_result {
             Ok(p) => {
                 let mut p = p
                     .with_text_processor(text_processing.clone())
                     .with_noise_suppressor(noise_suppressor);
+                p.set_language(session.agent.user_language());
                 // Wire LLM for automatic response generation
                 if let Some(llm) = llm {
                     p = p.with_llm(llm);
@@ -252,16 +456,20 @@ impl WebSocketHandler {
         // Spawn pipeline event handler task
         let session_for_pipeline = session.clone();
         let sender_for_pipeline = sender.clone();
+        let playout_for_pipeline = playout.clone();
         let pipeline_for_tts = pipeline.clone(); // P0 FIX: Clone for TTS synthesis
                                                  // P2 FIX: Clone text processing for pipeline event handler
         let text_processing_for_pipeline = text_processing.clone();
         let text_simplifier_for_pipeline = text_simplifier.clone();
+        let idle_for_pipeline = idle.clone();
 
         #[allow(unused_mut)]
         let pipeline_event_task = if let Some(ref pipeline) = pipeline {
             let mut pipeline_events = pipeline.lock().await.subscribe();
             tracing::info!("Pipeline event handler task started, listening for events");
             Some(tokio::spawn(async move {
+                // What the caller heard of audio the pipeline spoke itself
+                let mut playback = PlaybackTracker::new();
                 loop {
                     let event = match pipeline_events.recv().await {
                         Ok(event) => event,
@@ -285,11 +493,20 @@ impl WebSocketHandler {
                                 is_final: false,
                             };
                             let json = serde_json::to_string(&msg).unwrap();
-                            let mut s = sender_for_pipeline.lock().await;
-                            let _ = s.send(Message::Text(json)).await;
+                            sender_for_pipeline.push_control(Message::Text(json));
+                        },
+                        PipelineEvent::LanguageDetected(detection) => {
+                            let agent = &session_for_pipeline.agent;
+                            let (language, confidence) = (detection.language, detection.confidence);
+                            if agent.apply_language_detection(language, confidence) {
+                                if let Some(ref pipeline) = pipeline_for_tts {
+                                    pipeline.lock().await.set_language(agent.user_language());
+                                }
+                            }
                         },
                         PipelineEvent::FinalTranscript(transcript) => {
                             let text = transcript.text.clone();
+                            playback.reset();
 
                             // Send final transcript to client
                             let msg = WsMessage::Transcript {
@@ -297,9 +514,7 @@ impl WebSocketHandler {
                                 is_final: true,
                             };
                             let json = serde_json::to_string(&msg).unwrap();
-                            let mut s = sender_for_pipeline.lock().await;
-                            let _ = s.send(Message::Text(json)).await;
-                            drop(s); // Release lock before async operations
+                            sender_for_pipeline.push_control(Message::Text(json));
 
                             // Process through agent
                             if !text.trim().is_empty() {
@@ -325,148 +540,28 @@ impl WebSocketHandler {
                                 };
 
                                 // P0-2 FIX: Use streaming agent response with streaming TTS
-                                // Note: process_stream blocks until complete (LLM stream lifetime limitation)
                                 // Spawn the entire flow to not block the pipeline event handler
-                                let session = session_for_pipeline.clone();
-                                let sender = sender_for_pipeline.clone();
-                                let text_simplifier = text_simplifier_for_pipeline.clone();
-                                let pipeline = pipeline_for_tts.clone();
-
+                                idle_for_pipeline.lock().pause();
+                                let respond = respond_streaming(
+                                    session_for_pipeline.clone(),
+                                    processed_input,
+                                    sender_for_pipeline.clone(),
+                                    playout_for_pipeline.clone(),
+                                    text_simplifier_for_pipeline.clone(),
+                                    pipeline_for_tts.clone(),
+                                );
+                                let idle = idle_for_pipeline.clone();
                                 tokio::spawn(async move {
-                                    let user_language = session.agent.user_language();
-
-                                    match session.agent.process_stream(&processed_input).await {
-                                        Ok(mut chunk_rx) => {
-                                            // P0-2 FIX: Use speak_streaming() for lower latency TTS
-                                            if let Some(ref pipeline) = pipeline {
-                                                let p = pipeline.lock().await;
-
-                                                // Create channel to forward to TTS
-                                                let (tts_tx, tts_rx) = mpsc::channel::<String>(32);
-
-                                                // Start streaming TTS first
-                                                match p.speak_streaming(tts_rx, user_language).await
-                                                {
-                                                    Ok(mut audio_rx) => {
-                                                        drop(p); // Release pipeline lock
-
-                                                        // Spawn task to handle audio output frames
-                                                        let sender_for_audio = sender.clone();
-                                                        tokio::spawn(async move {
-                                                            while let Some(frame) =
-                                                                audio_rx.recv().await
-                                                            {
-                                                                if let Frame::AudioOutput(
-                                                                    audio_frame,
-                                                                ) = frame
-                                                                {
-                                                                    // Convert f32 samples to i16 PCM bytes
-                                                                    let pcm_bytes: Vec<u8> =
-                                                                        audio_frame
-                                                                            .samples
-                                                                            .iter()
-                                                                            .flat_map(|&sample| {
-                                                                                let clamped =
-                                                                                    sample.clamp(
-                                                                                        -1.0, 1.0,
-                                                                                    );
-                                                                                let i16_sample =
-                                                                                    (clamped
-                                                                                        * 32767.0)
-                                                                                        as i16;
-                                                                                i16_sample
-                                                                                    .to_le_bytes()
-                                                                                    .to_vec()
-                                                                            })
-                                                                            .collect();
-
-                                                                    // Base64 encode and send
-                                                                    let audio_data =
-                                                                        BASE64.encode(&pcm_bytes);
-                                                                    let msg =
-                                                                        WsMessage::ResponseAudio {
-                                                                            data: audio_data,
-                                                                        };
-                                                                    let json =
-                                                                        serde_json::to_string(&msg)
-                                                                            .unwrap();
-                                                                    let mut s = sender_for_audio
-                                                                        .lock()
-                                                                        .await;
-                                                                    if let Err(e) = s
-                                                                        .send(Message::Text(json))
-                                                                        .await
-                                                                    {
-                                                                        tracing::debug!("Failed to send streaming TTS audio: {}", e);
-                                                                        break;
-                                                                    }
-                                                                }
-                                                            }
-                                                        });
-
-                                                        // Forward chunks to client and TTS
-                                                        while let Some(chunk) =
-                                                            chunk_rx.recv().await
-                                                        {
-                                                            // Send to client
-                                                            let resp = WsMessage::Response {
-                                                                text: chunk.clone(),
-                                                            };
-                                                            let json = serde_json::to_string(&resp)
-                                                                .unwrap();
-                                                            let mut s = sender.lock().await;
-                                                            let _ =
-                                                                s.send(Message::Text(json)).await;
-                                                            drop(s);
-
-                                                            // Simplify and send to TTS
-                                                            let simplified =
-                                                                text_simplifier.simplify(&chunk);
-                                                            let _ = tts_tx.send(simplified).await;
-                                                        }
-
-                                                        tracing::debug!(
-                                                            "Streaming response complete"
-                                                        );
-                                                    },
-                                                    Err(e) => {
-                                                        drop(p);
-                                                        tracing::warn!("speak_streaming failed: {}, using text-only", e);
-
-                                                        // Fallback: just stream text
-                                                        while let Some(chunk) =
-                                                            chunk_rx.recv().await
-                                                        {
-                                                            let resp =
-                                                                WsMessage::Response { text: chunk };
-                                                            let json = serde_json::to_string(&resp)
-                                                                .unwrap();
-                                                            let mut s = sender.lock().await;
-                                                            let _ =
-                                                                s.send(Message::Text(json)).await;
-                                                        }
-                                                    },
-                                                }
-                                            } else {
-                                                // No pipeline - just stream text responses
-                                                while let Some(chunk) = chunk_rx.recv().await {
-                                                    let resp = WsMessage::Response { text: chunk };
-                                                    let json =
-                                                        serde_json::to_string(&resp).unwrap();
-                                                    let mut s = sender.lock().await;
-                                                    let _ = s.send(Message::Text(json)).await;
-                                                }
-                                            }
-                                        },
-                                        Err(e) => {
-                                            tracing::error!("Agent streaming error: {}", e);
-                                        },
-                                    }
+                                    respond.await;
+                                    idle.lock().reset(Instant::now());
                                 });
                             }
                         },
                         PipelineEvent::VadStateChanged(state) => {
                             use voice_agent_pipeline::VadState;
+                            if state == VadState::SpeechStart {
+                                idle_for_pipeline.lock().reset(Instant::now());
+                            }
                             let (ws_state, stage) = match state {
                                 VadState::Speech => ("listening", "speech_active"),
                                 VadState::Silence => ("idle", "silence"),
@@ -478,11 +573,13 @@ impl WebSocketHandler {
                                 stage: stage.to_string(),
                             };
                             let json = serde_json::to_string(&msg).unwrap();
-                            let mut s = sender_for_pipeline.lock().await;
-                            let _ = s.send(Message::Text(json)).await;
+                            sender_for_pipeline.push_control(Message::Text(json));
                         },
                         PipelineEvent::Error(e) => {
                             tracing::error!("Pipeline error: {}", e);
+                            // The pipeline keeps running; text input still works
+                            let err = WsError::new(WsErrorCode::TransportError, e, true);
+                            send_error(&sender_for_pipeline, err);
                         },
                         PipelineEvent::Response { text, is_final } => {
                             // P0 FIX: Send text response to client (before TTS audio)
@@ -491,15 +588,14 @@ impl WebSocketHandler {
                                     text: text.clone(),
                                 };
                                 let json = serde_json::to_string(&msg).unwrap();
-                                let mut s = sender_for_pipeline.lock().await;
-                                let _ = s.send(Message::Text(json)).await;
+                                sender_for_pipeline.push_control(Message::Text(json));
                                 tracing::info!("Sent response to client: {} chars", text.len());
                             }
                         },
                         PipelineEvent::TtsAudio {
                             samples,
-                            text: _,
-                            is_final: _,
+                            text,
+                            is_final,
                         } => {
                             // P0 FIX: Send TTS audio to client
                             // Convert f32 samples to i16 PCM bytes
@@ -517,10 +613,47 @@ impl WebSocketHandler {
                             let audio_data = BASE64.encode(&pcm_bytes);
                             let msg = WsMessage::ResponseAudio { data: audio_data };
                             let json = serde_json::to_string(&msg).unwrap();
-                            let mut s = sender_for_pipeline.lock().await;
-                            if let Err(e) = s.send(Message::Text(json)).await {
-                                tracing::debug!("Failed to send TTS audio: {}", e);
+                            let duration = std::time::Duration::from_secs_f64(
+                                samples.len() as f64 / tts_sample_rate as f64,
+                            );
+                            if !text.is_empty() {
+                                playback.text(&text);
+                            }
+                            playback.audio(duration);
+                            // Stale frames are dropped if the client is behind
+                            if !playout_for_pipeline.push(Message::Text(json), duration) {
+                                tracing::debug!("Connection closed, dropping TTS audio");
+                            }
+                            if is_final {
+                                playout_for_pipeline.flush();
+                                playback.finish();
+                            }
+                        },
+                        PipelineEvent::TtsFailed { .. } => {
+                            playback.mark_unspoken();
+                            if playback.is_playing() {
+                                session_for_pipeline
+                                    .agent
+                                    .record_interrupted_response(&playback.sent_text());
+                            }
+                            playback.reset();
+                        },
+                        PipelineEvent::BargeIn { at_word, reason } => {
+                            crate::metrics::record_tts_interruption(reason);
+                            tracing::debug!(
+                                at_word,
+                                reason = reason.as_str(),
+                                "Playback interrupted"
+                            );
+                            // Buffered audio the client has not received is stale now
+                            playout_for_pipeline.clear();
+                            // A system stop isn't the caller cutting the agent off
+                            if reason.is_user_initiated() && playback.is_playing() {
+                                session_for_pipeline
+                                    .agent
+                                    .record_interrupted_response(&playback.played_text());
                             }
+                            playback.reset();
                         },
                         _ => {},
                     }
@@ -532,10 +665,30 @@ impl WebSocketHandler {
 
         // Spawn event forwarder task
         let sender_clone = sender.clone();
+        let session_for_events = session.clone();
+        let playout_for_events = playout.clone();
+        let pipeline_for_events = pipeline.clone();
+        let text_simplifier_for_events = text_simplifier.clone();
 
         let event_task = tokio::spawn(async move {
             while let Ok(event) = agent_events.recv().await {
                 let msg = match event {
+                    voice_agent_agent::AgentEvent::HandedBack {
+                        acknowledgement,
+                        notes,
+                    } => {
+                        handle_handback(
+                            acknowledgement,
+                            notes,
+                            &session_for_events,
+                            &sender_clone,
+                            &playout_for_events,
+                            &text_simplifier_for_events,
+                            pipeline_for_events.as_deref(),
+                        )
+                        .await;
+                        None
+                    },
                     voice_agent_agent::AgentEvent::Response(text) => {
                         Some(WsMessage::Response { text })
                     },
@@ -543,25 +696,78 @@ impl WebSocketHandler {
                         state: "thinking".to_string(),
                         stage: "processing".to_string(),
                     }),
-                    voice_agent_agent::AgentEvent::Error(e) => {
-                        Some(WsMessage::Error { message: e })
+                    voice_agent_agent::AgentEvent::Error(e) => Some(WsMessage::Error {
+                        code: WsErrorCode::AgentError,
+                        message: e,
+                        recoverable: true,
+                    }),
+                    voice_agent_agent::AgentEvent::NextBestAction(recommendation) => {
+                        Some(WsMessage::NextBestAction {
+                            action: recommendation.action,
+                            rationale: recommendation.rationale,
+                        })
+                    },
+                    voice_agent_agent::AgentEvent::SlotsUpdated(progress) => {
+                        Some(WsMessage::Slots {
+                            goal_id: progress.goal_id,
+                            filled: progress.filled,
+                            missing: progress.missing,
+                        })
                     },
+                    // The agent ended the call (e.g. consent refused during the
+                    // opening); the client hangs up after the closing line
+                    voice_agent_agent::AgentEvent::Conversation(
+                        voice_agent_agent::ConversationEvent::Ended { .. },
+                    ) => Some(WsMessage::EndSession),
                     _ => None,
                 };
 
                 if let Some(msg) = msg {
                     let json = serde_json::to_string(&msg).unwrap();
-                    let mut s = sender_clone.lock().await;
-                    let _ = s.send(Message::Text(json)).await;
+                    sender_clone.push_control(Message::Text(json));
                 }
             }
         });
 
+        // Open the call with the opening script up to the consent question;
+        // its text reaches the client through the agent event forwarder
+        if let Some(opening) = session.open_call().await {
+            if let Some(ref pipeline) = pipeline {
+                let text = text_simplifier.simplify_for(&opening, session.agent.user_language());
+                speak_line(pipeline, &session, playout.clone(), text, true).await;
+            }
+        }
+        idle.lock().reset(Instant::now());
+
         // Clone rate limiter for main loop
         let rate_limiter_main = rate_limiter.clone();
 
-        // Main message loop
-        while let Some(msg) = receiver.next().await {
+        // Main message loop, until the client leaves or a newer connection takes over
+        let mut receiver = receiver.take_until(lease.closed());
+        let mut idle_check = tokio::time::interval(Duration::from_secs(1));
+        loop {
+            let msg = tokio::select! {
+                msg = receiver.next() => match msg {
+                    Some(msg) => msg,
+                    None => break,
+                },
+                _ = idle_check.tick() => {
+                    let action = idle.lock().poll(Instant::now());
+                    if action != IdleAction::None {
+                        handle_idle(
+                            action,
+                            &session,
+                            &voice_config,
+                            &sender,
+                            &playout,
+                            &text_simplifier,
+                            pipeline.as_deref(),
+                        )
+                        .await;
+                    }
+                    continue;
+                },
+            };
             match msg {
                 Ok(Message::Text(text)) => {
                     // Check rate limit for messages
@@ -569,13 +775,12 @@ impl WebSocketHandler {
                         let mut limiter = rate_limiter_main.lock().await;
                         if let Err(e) = limiter.check_message() {
                             tracing::warn!("Rate limit exceeded: {}", e);
-                            let err = WsMessage::Error {
-                                message: format!("Rate limit exceeded: {}", e),
-                            };
-                            let mut s = sender.lock().await;
-                            let _ = s
-                                .send(Message::Text(serde_json::to_string(&err).unwrap()))
-                                .await;
+                            let err = WsError::new(
+                                WsErrorCode::RateLimited,
+                                format!("Rate limit exceeded: {}", e),
+                                true,
+                            );
+                            send_error(&sender, err);
                             continue;
                         }
                     }
@@ -606,43 +811,43 @@ impl WebSocketHandler {
                                 };
 
                                 // Process text input
-                                match session.agent.process(&processed_input).await {
+                                idle.lock().pause();
+                                let result = session.agent.process(&processed_input).await;
+                                idle.lock().reset(Instant::now());
+                                match result {
+                                    // Nothing to say while a supervisor holds the call
+                                    Ok(response) if response.is_empty() => {},
                                     Ok(response) => {
                                         let resp = WsMessage::Response { text: response };
                                         let json = serde_json::to_string(&resp).unwrap();
-                                        let mut s = sender.lock().await;
-                                        let _ = s.send(Message::Text(json)).await;
+                                        sender.push_control(Message::Text(json));
 
                                         // Send status update
                                         let status = WsMessage::Status {
                                             state: "active".to_string(),
                                             stage: session.agent.stage().display_name().to_string(),
                                         };
-                                        let _ = s
-                                            .send(Message::Text(
-                                                serde_json::to_string(&status).unwrap(),
-                                            ))
-                                            .await;
+                                        sender.push_control(Message::Text(
+                                            serde_json::to_string(&status).unwrap(),
+                                        ));
                                     },
                                     Err(e) => {
-                                        let err = WsMessage::Error {
-                                            message: e.to_string(),
-                                        };
-                                        let mut s = sender.lock().await;
-                                        let _ = s
-                                            .send(Message::Text(
-                                                serde_json::to_string(&err).unwrap(),
-                                            ))
-                                            .await;
+                                        tracing::warn!(
+                                            session_id = %session.id,
+                                            error = %e,
+                                            "Agent failed to process input"
+                                        );
+                                        if !send_error(&sender, WsError::from(&e)) {
+                                            break;
+                                        }
                                     },
                                 }
                             },
                             WsMessage::Ping => {
                                 let pong = WsMessage::Pong;
-                                let mut s = sender.lock().await;
-                                let _ = s
-                                    .send(Message::Text(serde_json::to_string(&pong).unwrap()))
-                                    .await;
+                                sender.push_control(Message::Text(
+                                    serde_json::to_string(&pong).unwrap(),
+                                ));
                             },
                             WsMessage::Audio { data } => {
                                 // Decode base64 audio data and send to processor
@@ -655,15 +860,12 @@ impl WebSocketHandler {
                                                 "Audio rate limit exceeded: {} bytes",
                                                 audio_bytes.len()
                                             );
-                                            let err = WsMessage::Error {
-                                                message: format!("Rate limit exceeded: {}", e),
-                                            };
-                                            let mut s = sender.lock().await;
-                                            let _ = s
-                                                .send(Message::Text(
-                                                    serde_json::to_string(&err).unwrap(),
-                                                ))
-                                                .await;
+                                            let err = WsError::new(
+                                                WsErrorCode::RateLimited,
+                                                format!("Rate limit exceeded: {}", e),
+                                                true,
+                                            );
+                                            send_error(&sender, err);
                                             continue;
                                         }
                                         drop(limiter); // Release lock before sending
@@ -675,11 +877,21 @@ impl WebSocketHandler {
                                 }
                             },
                             WsMessage::EndSession => {
+                                if let Some(ref pipeline) = pipeline {
+                                    pipeline
+                                        .lock()
+                                        .await
+                                        .interrupt(InterruptionReason::ForcedTurnEnd);
+                                }
                                 session.close();
                                 break;
                             },
                             _ => {},
                         }
+                    } else {
+                        let err =
+                            WsError::new(WsErrorCode::InvalidMessage, "Unrecognized message", true);
+                        send_error(&sender, err);
                     }
                 },
                 Ok(Message::Binary(data)) => {
@@ -690,13 +902,12 @@ impl WebSocketHandler {
                         let mut limiter = rate_limiter_main.lock().await;
                         if let Err(e) = limiter.check_audio(data.len()) {
                             tracing::warn!("Audio rate limit exceeded: {} bytes", data.len());
-                            let err = WsMessage::Error {
-                                message: format!("Rate limit exceeded: {}", e),
-                            };
-                            let mut s = sender.lock().await;
-                            let _ = s
-                                .send(Message::Text(serde_json::to_string(&err).unwrap()))
-                                .await;
+                            let err = WsError::new(
+                                WsErrorCode::RateLimited,
+                                format!("Rate limit exceeded: {}", e),
+                                true,
+                            );
+                            send_error(&sender, err);
                             continue;
                         }
                     }
@@ -707,8 +918,7 @@ impl WebSocketHandler {
                     }
                 },
                 Ok(Message::Ping(data)) => {
-                    let mut s = sender.lock().await;
-                    let _ = s.send(Message::Pong(data)).await;
+                    sender.push_control(Message::Pong(data));
                 },
                 Ok(Message::Close(_)) => break,
                 Err(e) => {
@@ -719,75 +929,846 @@ impl WebSocketHandler {
             }
         }
 
-        // Cleanup
+        drop(receiver);
+
+        // Cleanup; wait for the tasks to stop so nothing from this connection
+        // touches the session after the lease is released
         audio_task.abort();
         event_task.abort();
+        let _ = audio_task.await;
+        let _ = event_task.await;
         if let Some(task) = pipeline_event_task {
             task.abort();
+            let _ = task.await;
+        }
+
+        if lease.was_superseded() {
+            tracing::info!(session_id = %session.id, "WebSocket superseded by a newer connection");
+            sender.push_control(Message::Close(Some(superseded_close_frame())));
+        }
+
+        // Flush what is already queued, then let the writer finish
+        sender.close();
+        let _ = writer_task.await;
+
+        // Checkpoint the session so a client resuming after a restart picks up here
+        if let Err(e) = state.persist_session(&session).await {
+            tracing::warn!(session_id = %session.id, error = %e, "Failed to checkpoint session");
+        }
+
+        // The caller hung up, which ends the call and delivers the
+        // session_ended webhook; a superseded connection leaves it running
+        if !lease.was_superseded() {
+            session.agent.end_call(EndReason::UserEnded).await;
         }
 
         tracing::info!("WebSocket closed for session: {}", session.id);
     }
 }
 
+/// Answer a spoken turn, streaming the agent's response to the client
+///
+/// Each chunk is sent as a `response` frame as soon as the agent produces
+/// it and, with a pipeline, spoken through streaming TTS. An agent error is
+/// reported to the client like one from a text turn, closing the socket if
+/// it is fatal. Returns once the agent has finished.
+async fn respond_streaming(
+    session: Arc<Session>,
+    input: String,
+    sender: Arc<OutboundQueue>,
+    playout: AudioPlayout,
+    text_simplifier: Arc<TextSimplifier>,
+    pipeline: Option<Arc<tokio::sync::Mutex<VoicePipeline>>>,
+) {
+    let user_language = session.agent.user_language();
+
+    // Run the agent beside TTS so each sentence is spoken as soon as it is
+    // ready, not after the whole response
+    let (chunk_tx, mut chunk_rx) = mpsc::channel::<String>(32);
+    let agent_task = {
+        let session = session.clone();
+        let sender = sender.clone();
+        tokio::spawn(async move {
+            if let Err(e) = session.agent.process_stream_into(&input, chunk_tx).await {
+                tracing::warn!(
+                    session_id = %session.id,
+                    error = %e,
+                    "Agent failed to stream a response"
+                );
+                send_error(&sender, WsError::from(&e));
+            }
+        })
+    };
+
+    // The agent has settled its stage and whether the response is
+    // protected by its first chunk
+    let Some(first_chunk) = chunk_rx.recv().await else {
+        let _ = agent_task.await;
+        return;
+    };
+    let protected = session.agent.last_response_protected();
+
+    // P0-2 FIX: Use speak_streaming() for lower latency TTS
+    let (tts_tx, tts_rx) = mpsc::channel::<String>(32);
+    // Without TTS only the text is streamed
+    let mut speaking = false;
+    if let Some(pipeline) = pipeline {
+        // Barge-in sensitivity follows the agent's stage
+        let stage = session.agent.stage();
+        pipeline.lock().await.set_barge_in_stage(stage.as_str());
+        speaking = speak_response(
+            &pipeline,
+            session.agent.clone(),
+            playout,
+            tts_rx,
+            user_language,
+            protected,
+        )
+        .await;
+    }
+
+    // Forward chunks to client and TTS
+    let mut next_chunk = Some(first_chunk);
+    while let Some(chunk) = match next_chunk.take() {
+        Some(chunk) => Some(chunk),
+        None => chunk_rx.recv().await,
+    } {
+        let resp = WsMessage::Response {
+            text: chunk.clone(),
+        };
+        let json = serde_json::to_string(&resp).unwrap();
+        sender.push_control(Message::Text(json));
+
+        if speaking {
+            // Simplify and send to TTS
+            let simplified = text_simplifier.simplify_for(&chunk, user_language);
+            let _ = tts_tx.send(simplified).await;
+        }
+    }
+    let _ = agent_task.await;
+    tracing::debug!("Streaming response complete");
+}
+
+/// Speak one line, such as the opening or an idle prompt
+async fn speak_line(
+    pipeline: &tokio::sync::Mutex<VoicePipeline>,
+    session: &Session,
+    playout: AudioPlayout,
+    text: String,
+    protected: bool,
+) {
+    let (tts_tx, tts_rx) = mpsc::channel::<String>(1);
+    let _ = tts_tx.send(text).await;
+    drop(tts_tx);
+    let language = session.agent.user_language();
+    speak_response(
+        pipeline,
+        session.agent.clone(),
+        playout,
+        tts_rx,
+        language,
+        protected,
+    )
+    .await;
+}
+
+/// Act on a caller's silence reported by the idle monitor
+///
+/// A prompt is sent and spoken like a response. An idle call gets a closing
+/// line and ends with `EndReason::IdleTimeout`; the client hangs up after it,
+/// as when the agent ends the call.
+async fn handle_idle(
+    action: IdleAction,
+    session: &Session,
+    voice_config: &VoiceSessionConfig,
+    sender: &OutboundQueue,
+    playout: &AudioPlayout,
+    text_simplifier: &TextSimplifier,
+    pipeline: Option<&tokio::sync::Mutex<VoicePipeline>>,
+) {
+    // Nothing to re-engage once the call is over
+    if session.agent.conversation().state() == ConversationState::Ended {
+        return;
+    }
+    let language = session.agent.user_language();
+    let text = match action {
+        IdleAction::None => return,
+        IdleAction::Prompt => {
+            tracing::info!(session_id = %session.id, "Caller silent, re-engaging");
+            voice_config.idle_prompt(language.code())
+        },
+        IdleAction::End => {
+            tracing::info!(session_id = %session.id, "Caller silent, ending call");
+            voice_config.idle_farewell(language.code())
+        },
+    };
+
+    let resp = WsMessage::Response {
+        text: text.to_string(),
+    };
+    sender.push_control(Message::Text(serde_json::to_string(&resp).unwrap()));
+    if let Some(pipeline) = pipeline {
+        // The closing line plays out even if the caller talks over it
+        let closing = action == IdleAction::End;
+        let text = text_simplifier.simplify_for(text, language);
+        speak_line(pipeline, session, playout.clone(), text, closing).await;
+    }
+    if action == IdleAction::End {
+        session.agent.end_call(EndReason::IdleTimeout).await;
+    }
+}
+
+/// Speak the agent's acknowledgement when a supervisor hands the call back
+///
+/// The line is sent like a response and spoken to the customer; a
+/// `handback` frame carries it with the supervisor's notes.
+async fn handle_handback(
+    acknowledgement: String,
+    notes: Option<String>,
+    session: &Session,
+    sender: &OutboundQueue,
+    playout: &AudioPlayout,
+    text_simplifier: &TextSimplifier,
+    pipeline: Option<&tokio::sync::Mutex<VoicePipeline>>,
+) {
+    let resp = WsMessage::Response {
+        text: acknowledgement.clone(),
+    };
+    sender.push_control(Message::Text(serde_json::to_string(&resp).unwrap()));
+    let handback = WsMessage::Handback {
+        text: acknowledgement.clone(),
+        notes,
+    };
+    sender.push_control(Message::Text(serde_json::to_string(&handback).unwrap()));
+    if let Some(pipeline) = pipeline {
+        let language = session.agent.user_language();
+        let text = text_simplifier.simplify_for(&acknowledgement, language);
+        speak_line(pipeline, session, playout.clone(), text, false).await;
+    }
+}
+
+/// Speak a response streamed sentence by sentence through `tts_rx`
+///
+/// Audio is paced to the client through `playout`. A protected response is
+/// spoken with `speak_streaming_protected`, and a barge-in neither stops it
+/// nor clears its buffered audio until it ends or the pipeline's
+/// `protected_max_ms` passes. Returns false if streaming TTS could not start.
+async fn speak_response(
+    pipeline: &tokio::sync::Mutex<VoicePipeline>,
+    agent: Arc<DomainAgent>,
+    playout: AudioPlayout,
+    tts_rx: mpsc::Receiver<String>,
+    language: Language,
+    protected: bool,
+) -> bool {
+    let p = pipeline.lock().await;
+    let started = if protected {
+        playout.protect(p.protected_max());
+        p.speak_streaming_protected(tts_rx, language).await
+    } else {
+        playout.unprotect();
+        p.speak_streaming(tts_rx, language).await
+    };
+    drop(p); // Release pipeline lock
+    let mut audio_rx = match started {
+        Ok(audio_rx) => audio_rx,
+        Err(e) => {
+            tracing::warn!("speak_streaming failed: {}, using text-only", e);
+            return false;
+        },
+    };
+
+    tokio::spawn(async move {
+        let mut undelivered = false;
+        let mut playback = PlaybackTracker::new();
+        let mut heard = None;
+        while let Some(frame) = audio_rx.recv().await {
+            let tts_failed = matches!(
+                &frame,
+                Frame::Error { stage, .. } if stage == "tts_processor"
+            );
+            if tts_failed {
+                crate::metrics::record_tts_failure();
+                tracing::warn!("TTS failed, response not delivered");
+                undelivered = true;
+                // The sentence's audio is the fallback clip
+                playback.mark_unspoken();
+            }
+            // Barge-in the chain let through; a protected utterance only
+            // gets here once it ended or ran past its cap
+            if matches!(frame, Frame::BargeIn { .. }) {
+                playout.clear();
+                heard = Some(playback.played_text());
+                break;
+            }
+            if let Frame::Sentence { ref text, .. } = frame {
+                playback.text(text);
+            }
+            if let Frame::AudioOutput(audio_frame) = frame {
+                playback.audio(audio_frame.duration);
+                // Convert f32 samples to i16 PCM bytes
+                let pcm_bytes: Vec<u8> = audio_frame
+                    .samples
+                    .iter()
+                    .flat_map(|&sample| {
+                        let clamped = sample.clamp(-1.0, 1.0);
+                        let i16_sample = (clamped * 32767.0) as i16;
+                        i16_sample.to_le_bytes().to_vec()
+                    })
+                    .collect();
+
+                // Base64 encode and send
+                let audio_data = BASE64.encode(&pcm_bytes);
+                let msg = WsMessage::ResponseAudio { data: audio_data };
+                let json = serde_json::to_string(&msg).unwrap();
+                // Stale frames are dropped if the client is behind
+                if !playout.push(Message::Text(json), audio_frame.duration) {
+                    tracing::debug!("Connection closed, stopping streaming TTS audio");
+                    break;
+                }
+            }
+        }
+        playout.flush();
+        // Audio ends after the agent's turn; record what the caller heard
+        if let Some(heard) = heard {
+            agent.record_interrupted_response(&heard);
+        } else if undelivered {
+            agent.record_interrupted_response(&playback.sent_text());
+        }
+    });
+    true
+}
+
+/// Send `error` to the client, closing the socket if it is fatal
+///
+/// Returns whether the connection can continue.
+fn send_error(sender: &OutboundQueue, error: WsError) -> bool {
+    let recoverable = error.recoverable;
+    for message in error.into_messages() {
+        if !sender.push_control(message) {
+            return false;
+        }
+    }
+    recoverable
+}
+
+/// Query parameters for session creation
+#[derive(Debug, Default, serde::Deserialize)]
+pub struct CreateSessionParams {
+    /// Tenant to tag the session with (must match a tenant-bound API key)
+    pub tenant_id: Option<String>,
+    /// Token from an earlier response; rejoins that session instead of starting a new one
+    pub resume_token: Option<String>,
+}
+
 /// Create new session endpoint
+///
+/// With a valid `resume_token` the client rejoins its previous session;
+/// otherwise (or if the token is expired, used or unknown) a new session
+/// starts. Either way the response carries a fresh resume token.
 pub async fn create_session(
     State(state): State<AppState>,
+    scope: Option<axum::Extension<crate::auth::TenantScope>>,
+    axum::extract::Query(params): axum::extract::Query<CreateSessionParams>,
 ) -> Result<axum::Json<serde_json::Value>, axum::http::StatusCode> {
-    let config = voice_agent_agent::AgentConfig::default();
+    let tenant_id = match (scope, params.tenant_id) {
+        (Some(axum::Extension(scope)), Some(requested)) if requested != scope.0 => {
+            return Err(axum::http::StatusCode::FORBIDDEN);
+        },
+        (Some(axum::Extension(scope)), _) => Some(scope.0),
+        (None, requested) => requested,
+    };
+
+    let resume_enabled = state.config.read().server.resume.enabled;
+    let resumed = match params.resume_token.as_deref() {
+        Some(token) if resume_enabled => resume_session(&state, token, tenant_id.as_deref()).await,
+        _ => None,
+    };
+
+    let (session, resumed) = match resumed {
+        Some(session) => (session, true),
+        None => match start_session(&state, tenant_id).await {
+            Ok(session) => (session, false),
+            Err(_) => return Err(axum::http::StatusCode::SERVICE_UNAVAILABLE),
+        },
+    };
+
+    // Build ICE servers from config for frontend
+    let config = state.config.read();
+    let mut ice_servers: Vec<serde_json::Value> = config
+        .server
+        .stun_servers
+        .iter()
+        .map(|url| serde_json::json!({ "urls": url }))
+        .collect();
+
+    // Add TURN servers with credentials
+    for turn in &config.server.turn_servers {
+        ice_servers.push(serde_json::json!({
+            "urls": turn.url,
+            "username": turn.username,
+            "credential": turn.credential
+        }));
+    }
+    drop(config);
+
+    let resume_token = resume_enabled.then(|| state.resume_tokens.issue(&session.id));
+
+    Ok(axum::Json(serde_json::json!({
+        "session_id": session.id,
+        "websocket_url": format!("/ws/{}", session.id),
+        "resumed": resumed,
+        "resume_token": resume_token,
+        "rag_enabled": state.vector_store.is_some(),
+        "tools_wired": true,
+        "ice_servers": ice_servers
+    })))
+}
+
+/// Start a new session and persist its metadata
+async fn start_session(
+    state: &AppState,
+    tenant_id: Option<String>,
+) -> Result<Arc<Session>, ServerError> {
+    let config = state.agent_config();
 
     // P0 FIX: Pass vector store AND tools to enable full integration in agent
     // This ensures the agent uses the persistence-wired tool registry from AppState
     // instead of creating its own default registry without persistence.
     // P21 FIX: Pass domain config to ensure agent uses loaded domain configuration
-    match state.sessions.create_with_full_integration(
+    let session = state.sessions.create_with_full_integration(
         config,
         state.vector_store.clone(),
+        state.retriever.clone(),
         Some(state.tools.clone()),
         state.master_domain_config.clone(),
-    ) {
-        Ok(session) => {
-            // P2-3 FIX: Persist session metadata to configured store
-            if let Err(e) = state.persist_session(&session).await {
-                tracing::warn!(session_id = %session.id, error = %e, "Failed to persist session metadata");
-                // Continue anyway - session is functional even if persistence fails
-            } else {
-                tracing::debug!(
-                    session_id = %session.id,
-                    distributed = state.is_distributed_sessions(),
-                    rag_enabled = state.vector_store.is_some(),
-                    tools_wired = true,
-                    "Session persisted with full integration"
-                );
-            }
+    )?;
 
-            // Build ICE servers from config for frontend
-            let config = state.config.read();
-            let mut ice_servers: Vec<serde_json::Value> = config
-                .server
-                .stun_servers
-                .iter()
-                .map(|url| serde_json::json!({ "urls": url }))
-                .collect();
-
-            // Add TURN servers with credentials
-            for turn in &config.server.turn_servers {
-                ice_servers.push(serde_json::json!({
-                    "urls": turn.url,
-                    "username": turn.username,
-                    "credential": turn.credential
-                }));
+    if let Some(tenant_id) = tenant_id {
+        session.set_tenant(tenant_id);
+    }
+    if let Some(ref webhooks) = state.webhooks {
+        webhooks
+            .clone()
+            .forward(session.id.clone(), session.agent.subscribe());
+    }
+    if let Some(ref recorder) = state.recorder {
+        recorder.clone().attach(&session.agent);
+    }
+
+    // P2-3 FIX: Persist session metadata to configured store
+    if let Err(e) = state.persist_session(&session).await {
+        tracing::warn!(session_id = %session.id, error = %e, "Failed to persist session metadata");
+        // Continue anyway - session is functional even if persistence fails
+    } else {
+        tracing::debug!(
+            session_id = %session.id,
+            distributed = state.is_distributed_sessions(),
+            rag_enabled = state.vector_store.is_some(),
+            tools_wired = true,
+            "Session persisted with full integration"
+        );
+    }
+
+    Ok(session)
+}
+
+/// Rejoin the session a resume token was issued for
+///
+/// A session still in memory is returned with its full conversation; one
+/// that is not (e.g. after a restart) is restored from the session store's
+/// checkpoint. Returns `None` if the token is refused, the session has ended
+/// or belongs to another tenant.
+async fn resume_session(
+    state: &AppState,
+    token: &str,
+    tenant_id: Option<&str>,
+) -> Option<Arc<Session>> {
+    let session_id = match state.resume_tokens.redeem(token) {
+        Ok(session_id) => session_id,
+        Err(e) => {
+            tracing::info!(error = %e, "Resume token refused, starting a new session");
+            return None;
+        },
+    };
+    let same_tenant =
+        |session_tenant: Option<&str>| tenant_id.is_none() || session_tenant == tenant_id;
+
+    let session = match state.sessions.get(&session_id) {
+        Some(session) => {
+            if !session.is_active() || !same_tenant(session.tenant_id().as_deref()) {
+                tracing::info!(session_id = %session_id, "Session cannot be resumed");
+                return None;
+            }
+            session
+        },
+        None => {
+            let checkpoint = match state.session_store.get_metadata(&session_id).await {
+                Ok(Some(checkpoint)) if checkpoint.active => checkpoint,
+                Ok(_) => {
+                    tracing::info!(session_id = %session_id, "No checkpoint to resume from");
+                    return None;
+                },
+                Err(e) => {
+                    tracing::warn!(session_id = %session_id, error = %e, "Failed to load session checkpoint");
+                    return None;
+                },
+            };
+            if !same_tenant(checkpoint.tenant_id.as_deref()) {
+                tracing::info!(session_id = %session_id, "Session cannot be resumed");
+                return None;
+            }
+            match state.sessions.restore_with_full_integration(
+                &checkpoint,
+                state.agent_config(),
+                state.vector_store.clone(),
+                state.retriever.clone(),
+                Some(state.tools.clone()),
+                state.master_domain_config.clone(),
+            ) {
+                Ok(session) => {
+                    if let Some(ref webhooks) = state.webhooks {
+                        webhooks
+                            .clone()
+                            .forward(session.id.clone(), session.agent.subscribe());
+                    }
+                    if let Some(ref recorder) = state.recorder {
+                        recorder.clone().attach(&session.agent);
+                    }
+                    session
+                },
+                Err(e) => {
+                    tracing::warn!(session_id = %session_id, error = %e, "Failed to restore session");
+                    return None;
+                },
             }
-            drop(config);
-
-            Ok(axum::Json(serde_json::json!({
-                "session_id": session.id,
-                "websocket_url": format!("/ws/{}", session.id),
-                "rag_enabled": state.vector_store.is_some(),
-                "tools_wired": true,
-                "ice_servers": ice_servers
-            })))
         },
-        Err(_) => Err(axum::http::StatusCode::SERVICE_UNAVAILABLE),
+    };
+
+    session.touch();
+    tracing::info!(session_id = %session.id, "Session resumed");
+    Some(session)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::resume::ResumeTokens;
+    use std::time::Duration;
+
+    fn error_frame(message: &Message) -> serde_json::Value {
+        match message {
+            Message::Text(text) => serde_json::from_str(text).unwrap(),
+            other => panic!("expected text frame, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_recoverable_agent_error_keeps_socket_open() {
+        let error = WsError::from(&AgentError::Llm("backend unavailable".to_string()));
+        let messages = error.into_messages();
+
+        // Only the error frame; no close follows
+        assert_eq!(messages.len(), 1);
+        let frame = error_frame(&messages[0]);
+        assert_eq!(frame["type"], "error");
+        assert_eq!(frame["code"], "llm_error");
+        assert_eq!(frame["recoverable"], true);
+        assert_eq!(frame["message"], "LLM error: backend unavailable");
+    }
+
+    #[test]
+    fn test_fatal_agent_error_sends_error_then_close() {
+        let error = WsError::from(&AgentError::Conversation(
+            "Conversation has ended".to_string(),
+        ));
+        let messages = error.into_messages();
+
+        assert_eq!(messages.len(), 2);
+        let frame = error_frame(&messages[0]);
+        assert_eq!(frame["code"], "conversation_ended");
+        assert_eq!(frame["recoverable"], false);
+        match &messages[1] {
+            Message::Close(Some(close)) => {
+                assert_eq!(close.code, CLOSE_CODE_INTERNAL_ERROR);
+                assert_eq!(close.reason, "conversation_ended");
+            },
+            other => panic!("expected close frame, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_server_error_codes() {
+        let rate_limited = WsError::from(&ServerError::RateLimit);
+        assert_eq!(rate_limited.code, WsErrorCode::RateLimited);
+        assert!(rate_limited.recoverable);
+
+        let gone = WsError::from(&ServerError::Session("not found".to_string()));
+        assert_eq!(gone.code, WsErrorCode::SessionUnavailable);
+        assert!(!gone.recoverable);
+
+        // Codes are part of the protocol; their wire names must not change
+        assert_eq!(
+            serde_json::to_value(WsErrorCode::SessionConflict).unwrap(),
+            "session_conflict"
+        );
+    }
+
+    #[test]
+    fn test_slots_frame() {
+        let frame = WsMessage::Slots {
+            goal_id: "lead_capture".to_string(),
+            filled: vec![voice_agent_agent::FilledSlot {
+                name: "phone_number".to_string(),
+                value: "98******10".to_string(),
+                confidence: 0.9,
+                confirmed: true,
+            }],
+            missing: vec!["customer_name".to_string()],
+        };
+
+        let json = serde_json::to_value(&frame).unwrap();
+        assert_eq!(json["type"], "slots");
+        assert_eq!(json["filled"][0]["value"], "98******10");
+        assert_eq!(json["missing"][0], "customer_name");
+    }
+
+    /// Frames queued for the client so far
+    async fn drain(sender: &OutboundQueue) -> Vec<Message> {
+        let mut messages = Vec::new();
+        while !sender.is_empty() {
+            messages.extend(sender.next().await);
+        }
+        messages
+    }
+
+    #[tokio::test]
+    async fn test_streamed_turn_reports_agent_errors() {
+        let state = AppState::new(voice_agent_config::Settings::default());
+        let session = state
+            .sessions
+            .create(
+                voice_agent_agent::AgentConfig::default(),
+                state.master_domain_config.clone(),
+            )
+            .unwrap();
+        let sender = Arc::new(OutboundQueue::default());
+        let playout = AudioPlayout::new(&Default::default(), sender.clone());
+        let turn = |input: &str| {
+            respond_streaming(
+                session.clone(),
+                input.to_string(),
+                sender.clone(),
+                playout.clone(),
+                state.text_simplifier.clone(),
+                None,
+            )
+        };
+
+        // A turn streams its response as text frames
+        turn("I want a gold loan").await;
+        let frames: Vec<serde_json::Value> = drain(&sender).await.iter().map(error_frame).collect();
+        assert!(!frames.is_empty());
+        assert!(frames.iter().all(|f| f["type"] == "response"));
+
+        // Once the conversation is over the client is told, then disconnected
+        session
+            .agent
+            .end_call(voice_agent_agent::EndReason::UserEnded)
+            .await;
+        turn("Are you still there?").await;
+        let messages = drain(&sender).await;
+        assert_eq!(messages.len(), 2);
+        let frame = error_frame(&messages[0]);
+        assert_eq!(frame["type"], "error");
+        assert_eq!(frame["code"], "conversation_ended");
+        assert!(matches!(messages[1], Message::Close(Some(_))));
+    }
+
+    #[tokio::test]
+    async fn test_idle_caller_is_prompted_then_call_ends() {
+        let mut domain = voice_agent_config::MasterDomainConfig::default();
+        domain
+            .prompts
+            .idle_prompts
+            .insert("en".to_string(), "Still with me?".to_string());
+        let settings = voice_agent_config::Settings::default();
+        let state = AppState::with_master_domain_config(settings, Arc::new(domain));
+        let session = state
+            .sessions
+            .create(
+                voice_agent_agent::AgentConfig::default(),
+                state.master_domain_config.clone(),
+            )
+            .unwrap();
+        let sender = Arc::new(OutboundQueue::default());
+        let playout = AudioPlayout::new(&Default::default(), sender.clone());
+        let voice_config = state.voice_session_config();
+        let idle = |action| {
+            handle_idle(
+                action,
+                &session,
+                &voice_config,
+                &sender,
+                &playout,
+                &state.text_simplifier,
+                None,
+            )
+        };
+
+        // The domain's re-engagement prompt is sent like a response
+        idle(IdleAction::Prompt).await;
+        let frames: Vec<serde_json::Value> = drain(&sender).await.iter().map(error_frame).collect();
+        assert_eq!(frames.len(), 1);
+        assert_eq!(frames[0]["type"], "response");
+        assert_eq!(frames[0]["text"], "Still with me?");
+
+        idle(IdleAction::End).await;
+        let frames: Vec<serde_json::Value> = drain(&sender).await.iter().map(error_frame).collect();
+        assert_eq!(frames[0]["text"], voice_config.idle_farewell("en"));
+        assert_eq!(
+            session.agent.conversation().state(),
+            ConversationState::Ended
+        );
+
+        // Nothing more is said once the call is over
+        idle(IdleAction::Prompt).await;
+        assert!(drain(&sender).await.is_empty());
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_handback_line_is_spoken() {
+        let state = AppState::new(voice_agent_config::Settings::default());
+        let session = state
+            .sessions
+            .create(
+                voice_agent_agent::AgentConfig::default(),
+                state.master_domain_config.clone(),
+            )
+            .unwrap();
+        let sender = Arc::new(OutboundQueue::default());
+        let playout = AudioPlayout::new(&Default::default(), sender.clone());
+        let pipeline = VoicePipeline::simple(PipelineConfig::default()).unwrap();
+        let pipeline = tokio::sync::Mutex::new(pipeline);
+
+        let line = "Thanks for holding. I'm back with you now.";
+        handle_handback(
+            line.to_string(),
+            Some("Fee waived".to_string()),
+            &session,
+            &sender,
+            &playout,
+            &state.text_simplifier,
+            Some(&pipeline),
+        )
+        .await;
+
+        // Audio is paced to the client; wait for the first chunk
+        let mut frames: Vec<serde_json::Value> = Vec::new();
+        for _ in 0..100 {
+            frames.extend(drain(&sender).await.iter().map(error_frame));
+            if frames.iter().any(|f| f["type"] == "response_audio") {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(20)).await;
+        }
+
+        assert_eq!(frames[0]["type"], "response");
+        assert_eq!(frames[0]["text"], line);
+        assert_eq!(frames[1]["type"], "handback");
+        assert_eq!(frames[1]["notes"], "Fee waived");
+        assert!(
+            frames.iter().any(|f| f["type"] == "response_audio"),
+            "handback line was not synthesized: {:?}",
+            frames
+        );
+    }
+
+    async fn connect(state: &AppState, resume_token: Option<&str>) -> serde_json::Value {
+        let params = CreateSessionParams {
+            tenant_id: None,
+            resume_token: resume_token.map(String::from),
+        };
+        let axum::Json(body) =
+            create_session(State(state.clone()), None, axum::extract::Query(params))
+                .await
+                .unwrap();
+        body
+    }
+
+    #[tokio::test]
+    async fn test_resume_token_rejoins_session() {
+        let state = AppState::new(voice_agent_config::Settings::default());
+
+        let first = connect(&state, None).await;
+        assert_eq!(first["resumed"], false);
+        let session_id = first["session_id"].as_str().unwrap().to_string();
+        let token = first["resume_token"].as_str().unwrap().to_string();
+
+        // The connection drops; the client comes back with its token
+        let resumed = connect(&state, Some(&token)).await;
+        assert_eq!(resumed["resumed"], true);
+        assert_eq!(resumed["session_id"], session_id.as_str());
+        assert_eq!(state.sessions.count(), 1);
+
+        // Each reconnect gets a new token; the old one is spent
+        let next_token = resumed["resume_token"].as_str().unwrap();
+        assert_ne!(next_token, token);
+        let replayed = connect(&state, Some(&token)).await;
+        assert_eq!(replayed["resumed"], false);
+        assert_ne!(replayed["session_id"], session_id.as_str());
+    }
+
+    #[tokio::test]
+    async fn test_expired_resume_token_starts_new_session() {
+        let mut state = AppState::new(voice_agent_config::Settings::default());
+        state.resume_tokens = Arc::new(ResumeTokens::new("secret", Duration::ZERO));
+
+        let first = connect(&state, None).await;
+        let token = first["resume_token"].as_str().unwrap();
+
+        let second = connect(&state, Some(token)).await;
+        assert_eq!(second["resumed"], false);
+        assert_ne!(second["session_id"], first["session_id"]);
+        assert_eq!(state.sessions.count(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_resume_restores_checkpoint_after_restart() {
+        use voice_agent_agent::ConversationStage;
+
+        let store: Arc<dyn crate::session::SessionStore> =
+            Arc::new(crate::session::InMemorySessionStore::new());
+        let booted = |tokens: &Arc<ResumeTokens>| {
+            let mut state = AppState::with_session_store(
+                voice_agent_config::Settings::default(),
+                store.clone(),
+            );
+            state.resume_tokens = tokens.clone();
+            state
+        };
+        // Instances share the signing secret
+        let tokens = Arc::new(ResumeTokens::new("shared", Duration::from_secs(60)));
+
+        let before = booted(&tokens);
+        let first = connect(&before, None).await;
+        let session_id = first["session_id"].as_str().unwrap();
+        let session = before.sessions.get(session_id).unwrap();
+        session
+            .agent
+            .conversation()
+            .stage_manager()
+            .set_stage(ConversationStage::Presentation);
+        before.persist_session(&session).await.unwrap();
+
+        // After a restart the agent is gone but the checkpoint is not
+        let after = booted(&tokens);
+        assert!(after.sessions.get(session_id).is_none());
+        let resumed = connect(&after, first["resume_token"].as_str()).await;
+        assert_eq!(resumed["resumed"], true);
+        assert_eq!(resumed["session_id"], session_id);
+
+        let restored = after.sessions.get(session_id).unwrap();
+        assert_eq!(restored.agent.stage(), ConversationStage::Presentation);
     }
 }
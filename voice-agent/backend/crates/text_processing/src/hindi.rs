@@ -2,6 +2,7 @@
 //!
 //! P2.2 FIX: Shared utilities for Hindi text processing.
 //! Consolidates duplicate Hindi number conversion code from entities and intent modules.
+//! Also holds negation cues for code-mixed Hindi/English speech.
 
 /// Convert Hindi number word (Devanagari script) to numeric value
 ///
@@ -46,6 +47,43 @@ pub fn word_to_number(word: &str) -> Option<f64> {
     }
 }
 
+/// Which neighbouring words a negation cue applies to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NegationScope {
+    /// Negates the words after it ("don't want a transfer")
+    Following,
+    /// Negates the words before it ("transfer nahi chahiye")
+    Preceding,
+}
+
+/// Classify a word as a negation cue
+///
+/// Hindi negation sits after the object, before the verb, so it scopes
+/// backwards; English negation scopes forwards. English cues are included
+/// because callers speak code-mixed Hinglish. Ambiguous particles like
+/// "na" (also a question tag) are deliberately left out.
+///
+/// # Examples
+/// ```
+/// use voice_agent_text_processing::hindi::{negation_scope, NegationScope};
+/// assert_eq!(negation_scope("nahi"), Some(NegationScope::Preceding));
+/// assert_eq!(negation_scope("नहीं"), Some(NegationScope::Preceding));
+/// assert_eq!(negation_scope("Don't"), Some(NegationScope::Following));
+/// assert_eq!(negation_scope("transfer"), None);
+/// ```
+pub fn negation_scope(word: &str) -> Option<NegationScope> {
+    match word.to_lowercase().replace('’', "'").as_str() {
+        // Hindi (Devanagari and romanized)
+        "नहीं" | "नही" | "मत" | "nahi" | "nahin" | "nahee" | "nai" | "mat" => {
+            Some(NegationScope::Preceding)
+        },
+        // English
+        "no" | "not" | "never" | "don't" | "dont" | "doesn't" | "didn't" | "won't" | "can't"
+        | "cannot" => Some(NegationScope::Following),
+        _ => None,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -73,6 +111,15 @@ mod tests {
         assert_eq!(word_to_number("सौ"), Some(100.0));
     }
 
+    #[test]
+    fn test_negation_scope() {
+        assert_eq!(negation_scope("mat"), Some(NegationScope::Preceding));
+        assert_eq!(negation_scope("NAHIN"), Some(NegationScope::Preceding));
+        assert_eq!(negation_scope("don’t"), Some(NegationScope::Following));
+        assert_eq!(negation_scope("na"), None);
+        assert_eq!(negation_scope("now"), None);
+    }
+
     #[test]
     fn test_unknown() {
         assert_eq!(word_to_number("unknown"), None);
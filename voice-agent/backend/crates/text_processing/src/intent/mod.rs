@@ -9,6 +9,9 @@
 //! - Slot extraction with multi-script support (11 Indic scripts)
 //! - Currency parsing with lakh/crore multipliers
 //! - Hindi number word recognition
+//! - Bare numbers without a unit ("I need five") flagged as ambiguous
+//! - Negation-aware scoring ("I don't want a balance transfer" is not `balance_transfer`)
+//! - Optional logistic calibration of match scores into confidences
 //!
 //! # Example
 //!
@@ -27,21 +30,71 @@ use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use unicode_segmentation::UnicodeSegmentation;
 
+use crate::hindi::{self, NegationScope};
+
+/// Intent returned when nothing matches (or nothing matches confidently)
+pub const UNKNOWN_INTENT: &str = "unknown";
+/// Intent returned when the user negates an intent's phrase
+pub const NEGATIVE_INTENT: &str = "negative";
+/// Slot holding the intent the user negated
+pub const NEGATED_INTENT_SLOT: &str = "negated_intent";
+
+/// Number of words a negation cue reaches
+const NEGATION_WINDOW: usize = 4;
+/// Conjunctions that end a negation's reach ("not X but Y")
+const CLAUSE_BREAKS: &[&str] = &["but", "lekin", "magar", "लेकिन", "मगर"];
+
+/// Gradient descent steps when fitting a calibration
+const CALIBRATION_FIT_ITERATIONS: usize = 5000;
+/// Gradient descent learning rate when fitting a calibration
+const CALIBRATION_FIT_LEARNING_RATE: f32 = 1.0;
+/// L2 penalty keeping the fitted slope finite on separable samples
+const CALIBRATION_FIT_L2: f32 = 0.001;
+
+/// Default intents loaded by `IntentDetector::new()` on top of the core set
+const DEFAULT_INTENTS_TOML: &str = include_str!("../../config/default_intents.toml");
+
 /// Intent definition
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Intent {
     /// Intent name
     pub name: String,
     /// Description
+    #[serde(default)]
     pub description: String,
     /// Required slots
+    #[serde(default)]
     pub required_slots: Vec<String>,
     /// Optional slots
+    #[serde(default)]
     pub optional_slots: Vec<String>,
     /// Example utterances
+    #[serde(default)]
     pub examples: Vec<String>,
 }
 
+/// Intent set as stored in a TOML config file (`[[intents]]` tables)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct IntentSet {
+    /// Intent definitions
+    #[serde(default)]
+    pub intents: Vec<Intent>,
+}
+
+impl IntentSet {
+    /// Parse an intent set from TOML
+    pub fn from_toml(content: &str) -> Result<Self, String> {
+        toml::from_str(content).map_err(|e| format!("Failed to parse intents: {}", e))
+    }
+
+    /// Load an intent set from a TOML file
+    pub fn load(path: &str) -> Result<Self, String> {
+        let content = std::fs::read_to_string(path)
+            .map_err(|e| format!("Failed to read intents file: {}", e))?;
+        Self::from_toml(&content)
+    }
+}
+
 /// Slot/Entity definition
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Slot {
@@ -79,6 +132,166 @@ pub struct DetectedIntent {
     pub slots: HashMap<String, Slot>,
     /// Alternative intents
     pub alternatives: Vec<(String, f32)>,
+    /// Number spoken without a unit or scale that no slot pattern claimed
+    pub ambiguous_number: Option<AmbiguousNumber>,
+}
+
+/// A bare number that could be rupees, thousands, lakhs or grams
+#[derive(Debug, Clone, PartialEq)]
+pub struct AmbiguousNumber {
+    /// Parsed value
+    pub value: f64,
+    /// Text as spoken ("five", "50")
+    pub text: String,
+}
+
+/// Slots a bare number could belong to; if one was extracted there is no ambiguity
+const QUANTITY_SLOTS: &[&str] = &["loan_amount", "gold_weight"];
+
+/// Whether a word after a number gives it a unit or scale
+fn is_unit_word(word: &str) -> bool {
+    match word {
+        // Scale and currency
+        "lakh" | "lakhs" | "lac" | "lacs" | "crore" | "crores" | "cr" | "thousand" | "hazar"
+        | "k" | "rupees" | "rupee" | "rs" | "inr" | "लाख" | "करोड़" | "हज़ार" | "हजार" => true,
+        // Weight and purity
+        "grams" | "gram" | "gm" | "gms" | "g" | "tola" | "tole" | "masha" | "ratti" | "oz"
+        | "ounce" | "ounces" | "karat" | "carat" | "kt" | "ग्राम" | "तोला" | "माशा" | "रत्ती" => {
+            true
+        },
+        // Rates, durations and times
+        "percent" | "months" | "month" | "years" | "year" | "saal" | "mahine" | "days" | "day"
+        | "baje" | "am" | "pm" => true,
+        _ => word.starts_with('%'),
+    }
+}
+
+/// Whether a word before a number makes it a rupee amount
+fn is_currency_prefix(word: &str) -> bool {
+    matches!(word, "rs" | "rs." | "inr" | "₹")
+}
+
+/// Whether a word shows the utterance is about a loan amount or gold weight
+///
+/// Without one, a bare number is more likely a count, a time or a date
+/// ("I have five children", "call me at five").
+fn is_amount_context(word: &str) -> bool {
+    matches!(
+        word,
+        "need"
+            | "want"
+            | "loan"
+            | "amount"
+            | "borrow"
+            | "gold"
+            | "weight"
+            | "worth"
+            | "chahiye"
+            | "chahie"
+            | "chaiye"
+            | "lena"
+            | "sona"
+            | "paisa"
+            | "paise"
+            | "चाहिए"
+            | "लोन"
+            | "सोना"
+            | "राशि"
+    )
+}
+
+/// English and romanized Hindi number words for small bare amounts
+///
+/// "one"/"ek" and "do" are left out: they are far more often an article,
+/// a pronoun or the English verb.
+fn number_word(word: &str) -> Option<f64> {
+    match word {
+        "two" => Some(2.0),
+        "three" | "teen" => Some(3.0),
+        "four" => Some(4.0),
+        "five" | "paanch" | "panch" => Some(5.0),
+        "six" => Some(6.0),
+        "seven" => Some(7.0),
+        "eight" => Some(8.0),
+        "nine" => Some(9.0),
+        "ten" | "das" => Some(10.0),
+        "fifteen" => Some(15.0),
+        "twenty" | "bees" => Some(20.0),
+        "thirty" => Some(30.0),
+        "forty" => Some(40.0),
+        "fifty" | "pachas" => Some(50.0),
+        "hundred" | "sau" => Some(100.0),
+        _ => hindi::word_to_number(word),
+    }
+}
+
+/// Words of (lowercased) text, each flagged if a negation cue reaches it
+struct NegationScopedText<'a> {
+    words: Vec<&'a str>,
+    negated: Vec<bool>,
+}
+
+impl<'a> NegationScopedText<'a> {
+    fn new(text: &'a str) -> Self {
+        let mut words = Vec::new();
+        let mut negated = Vec::new();
+
+        for clause in text.split([',', '.', '?', '!', ';', '।']) {
+            let clause_words: Vec<&str> = clause.unicode_words().collect();
+            let mut clause_negated = vec![false; clause_words.len()];
+            let mut clause_start = 0;
+
+            for (i, word) in clause_words.iter().enumerate() {
+                if CLAUSE_BREAKS.contains(word) {
+                    clause_start = i + 1;
+                    continue;
+                }
+                match hindi::negation_scope(word) {
+                    Some(NegationScope::Following) => {
+                        let end = clause_words[i + 1..]
+                            .iter()
+                            .position(|w| CLAUSE_BREAKS.contains(w))
+                            .map_or(clause_words.len(), |p| i + 1 + p)
+                            .min(i + 1 + NEGATION_WINDOW);
+                        clause_negated[i + 1..end].fill(true);
+                    },
+                    Some(NegationScope::Preceding) => {
+                        let start = clause_start.max(i.saturating_sub(NEGATION_WINDOW));
+                        clause_negated[start..i].fill(true);
+                    },
+                    None => {},
+                }
+            }
+
+            words.extend(clause_words);
+            negated.extend(clause_negated);
+        }
+
+        Self { words, negated }
+    }
+
+    /// Whether the word occurs, but only where a negation reaches it
+    fn only_negated(&self, word: &str) -> bool {
+        let mut found = false;
+        for (w, negated) in self.words.iter().zip(&self.negated) {
+            if *w == word {
+                if !negated {
+                    return false;
+                }
+                found = true;
+            }
+        }
+        found
+    }
+}
+
+/// Intent match score, split by whether the matched words were negated
+#[derive(Debug, Clone, Copy, Default)]
+struct IntentMatch {
+    /// Score from words the user affirmed
+    affirmative: f32,
+    /// Score of matches that a negation cue reached
+    negated: f32,
 }
 
 /// Compiled slot pattern with its regex
@@ -90,42 +303,126 @@ struct CompiledSlotPattern {
     multiplier: Option<f64>,
 }
 
+/// Logistic calibration of raw intent match scores
+///
+/// Raw scores are example-overlap heuristics, not probabilities. Calibration
+/// maps them through `1 / (1 + e^(-slope * (raw - midpoint)))` so that
+/// thresholds on `DetectedIntent::confidence` behave consistently.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ConfidenceCalibration {
+    /// Steepness of the logistic curve
+    pub slope: f32,
+    /// Raw score that maps to a confidence of 0.5
+    pub midpoint: f32,
+}
+
+impl Default for ConfidenceCalibration {
+    fn default() -> Self {
+        Self {
+            slope: 10.0,
+            midpoint: 0.5,
+        }
+    }
+}
+
+impl ConfidenceCalibration {
+    /// Map a raw match score to a calibrated confidence
+    pub fn calibrate(&self, raw: f32) -> f32 {
+        1.0 / (1.0 + (-self.slope * (raw - self.midpoint)).exp())
+    }
+
+    /// Fit by logistic regression over `(raw score, prediction was correct)` samples
+    ///
+    /// Falls back to the default if the samples are all correct or all wrong.
+    pub fn fit(samples: &[(f32, bool)]) -> Self {
+        let correct = samples.iter().filter(|(_, c)| *c).count();
+        if correct == 0 || correct == samples.len() {
+            return Self::default();
+        }
+
+        let n = samples.len() as f32;
+        let (mut weight, mut bias) = (1.0f32, 0.0f32);
+        for _ in 0..CALIBRATION_FIT_ITERATIONS {
+            let (mut grad_weight, mut grad_bias) = (0.0f32, 0.0f32);
+            for &(raw, is_correct) in samples {
+                let predicted = 1.0 / (1.0 + (-(weight * raw + bias)).exp());
+                let error = predicted - if is_correct { 1.0 } else { 0.0 };
+                grad_weight += error * raw;
+                grad_bias += error;
+            }
+            weight -=
+                CALIBRATION_FIT_LEARNING_RATE * (grad_weight / n + CALIBRATION_FIT_L2 * weight);
+            bias -= CALIBRATION_FIT_LEARNING_RATE * grad_bias / n;
+        }
+
+        // Higher raw scores must never mean lower confidence
+        let slope = weight.max(f32::EPSILON);
+        Self {
+            slope,
+            midpoint: -bias / slope,
+        }
+    }
+}
+
 /// Intent detector
 pub struct IntentDetector {
     intents: RwLock<Vec<Intent>>,
     /// P0 FIX: Compiled regex patterns for slot extraction
     compiled_patterns: HashMap<String, Vec<CompiledSlotPattern>>,
+    /// Calibration applied to detected confidences (raw scores if None)
+    calibration: RwLock<Option<ConfidenceCalibration>>,
 }
 
 impl IntentDetector {
-    /// Create a new intent detector with minimal generic intents
+    /// Create a new intent detector with the core intents plus the default set
     ///
-    /// For domain-specific intents, use `with_intents()` to load from config.
+    /// The default set comes from `config/default_intents.toml`. For
+    /// domain-specific intents, use `with_domain_intents()` to load from config.
     pub fn new() -> Self {
-        let mut detector = Self {
-            intents: RwLock::new(Vec::new()),
-            compiled_patterns: HashMap::new(),
+        let defaults = match IntentSet::from_toml(DEFAULT_INTENTS_TOML) {
+            Ok(set) => set.intents,
+            Err(e) => {
+                tracing::warn!(
+                    "Default intents unavailable, using core intents only: {}",
+                    e
+                );
+                Vec::new()
+            },
         };
 
-        detector.register_core_intents();
-        detector.compile_slot_patterns();
-
-        detector
+        Self::with_domain_intents(defaults)
     }
 
     /// Create intent detector with custom intents (for config-driven domains)
     ///
-    /// This is the preferred way to create domain-specific intent detectors.
-    /// Load intents from your domain's intents.yaml and pass them here.
+    /// The given intents fully replace everything, including the core
+    /// conversational intents. Use `with_domain_intents()` to keep them.
     pub fn with_intents(intents: Vec<Intent>) -> Self {
         let mut detector = Self {
             intents: RwLock::new(intents),
             compiled_patterns: HashMap::new(),
+            calibration: RwLock::new(None),
         };
         detector.compile_slot_patterns();
         detector
     }
 
+    /// Create intent detector with domain intents on top of the core intents
+    ///
+    /// This is the preferred way to create domain-specific intent detectors.
+    /// Load intents from your domain's config and pass them here; a domain
+    /// intent with a core intent's name overrides it.
+    pub fn with_domain_intents(intents: Vec<Intent>) -> Self {
+        let mut merged = intents;
+        for core in Self::core_intents() {
+            if !merged.iter().any(|i| i.name == core.name) {
+                merged.push(core);
+            }
+        }
+
+        Self::with_intents(merged)
+    }
+
     /// P16 FIX: Create intent detector with competitor patterns from config
     ///
     /// This is the preferred constructor for domain-agnostic operation.
@@ -251,113 +548,46 @@ impl IntentDetector {
         intents.extend(new_intents);
     }
 
-    /// Replace all intents with new ones
+    /// Replace all intents with new ones, including the core intents
     pub fn set_intents(&self, new_intents: Vec<Intent>) {
         *self.intents.write() = new_intents;
     }
 
-    /// Register core intents that are domain-agnostic
+    /// Set (or clear) the confidence calibration
+    pub fn set_calibration(&self, calibration: Option<ConfidenceCalibration>) {
+        *self.calibration.write() = calibration;
+    }
+
+    /// Current confidence calibration, if any
+    pub fn calibration(&self) -> Option<ConfidenceCalibration> {
+        *self.calibration.read()
+    }
+
+    /// Fit and apply a calibration from labeled utterances
+    ///
+    /// Each pair is `(utterance, expected intent)`; label out-of-domain
+    /// utterances with `UNKNOWN_INTENT` so the fit learns what a wrong match
+    /// looks like.
+    pub fn fit_calibration(&self, labeled: &[(&str, &str)]) -> ConfidenceCalibration {
+        let samples: Vec<(f32, bool)> = labeled
+            .iter()
+            .map(|(text, expected)| {
+                let detected = self.detect_raw(text);
+                (detected.confidence, detected.intent == *expected)
+            })
+            .collect();
+
+        let calibration = ConfidenceCalibration::fit(&samples);
+        self.set_calibration(Some(calibration));
+        calibration
+    }
+
+    /// Built-in core intents that are domain-agnostic
     ///
     /// These handle basic conversational patterns common to all domains.
-    fn register_core_intents(&self) {
-        let intents = vec![
-            // Service inquiry (generic)
-            Intent {
-                name: "service_inquiry".to_string(),
-                description: "User wants to know about the service".to_string(),
-                required_slots: vec![],
-                optional_slots: vec!["requested_amount".to_string()],
-                examples: vec![
-                    "I want to apply".to_string(),
-                    "Tell me about your services".to_string(),
-                    "How does this work".to_string(),
-                ],
-            },
-            // Interest/rate inquiry
-            Intent {
-                name: "interest_rate".to_string(),
-                description: "User asking about interest rates".to_string(),
-                required_slots: vec![],
-                optional_slots: vec!["requested_amount".to_string()],
-                examples: vec![
-                    "What is the interest rate".to_string(),
-                    "Interest rate kitna hai".to_string(),
-                    "Rate of interest".to_string(),
-                ],
-            },
-            // Eligibility check
-            Intent {
-                name: "eligibility_check".to_string(),
-                description: "User wants to check eligibility".to_string(),
-                required_slots: vec![],
-                optional_slots: vec!["asset_quantity".to_string()],
-                examples: vec![
-                    "Am I eligible".to_string(),
-                    "Can I get approved".to_string(),
-                    "Kitna milega".to_string(),
-                ],
-            },
-            // Balance transfer
-            Intent {
-                name: "balance_transfer".to_string(),
-                description: "User wants to transfer from another provider".to_string(),
-                required_slots: vec![],
-                optional_slots: vec!["current_provider".to_string()],
-                examples: vec![
-                    "I want to transfer".to_string(),
-                    "Balance transfer".to_string(),
-                    "Switch provider".to_string(),
-                ],
-            },
-            // Objection/concern
-            Intent {
-                name: "objection".to_string(),
-                description: "User has concerns or objections".to_string(),
-                required_slots: vec![],
-                optional_slots: vec![],
-                examples: vec![
-                    "I'm not sure".to_string(),
-                    "Is it safe".to_string(),
-                    "What are the risks".to_string(),
-                ],
-            },
-            // Schedule visit
-            Intent {
-                name: "schedule_visit".to_string(),
-                description: "User wants to schedule appointment".to_string(),
-                required_slots: vec![],
-                optional_slots: vec!["location".to_string(), "preferred_date".to_string()],
-                examples: vec![
-                    "I want to visit".to_string(),
-                    "Schedule appointment".to_string(),
-                    "Book a time".to_string(),
-                ],
-            },
-            // Documentation inquiry
-            Intent {
-                name: "documentation".to_string(),
-                description: "User asking about required documents".to_string(),
-                required_slots: vec![],
-                optional_slots: vec![],
-                examples: vec![
-                    "What documents needed".to_string(),
-                    "Documents required".to_string(),
-                    "What should I bring".to_string(),
-                ],
-            },
-            // Price inquiry
-            Intent {
-                name: "price_inquiry".to_string(),
-                description: "User asking about rates/prices".to_string(),
-                required_slots: vec![],
-                optional_slots: vec![],
-                examples: vec![
-                    "What is the current rate".to_string(),
-                    "Today's price".to_string(),
-                    "Current rate".to_string(),
-                ],
-            },
-            // Core conversational intents
+    /// Everything domain-specific comes from config.
+    pub fn core_intents() -> Vec<Intent> {
+        vec![
             Intent {
                 name: "greeting".to_string(),
                 description: "User greeting".to_string(),
@@ -384,7 +614,7 @@ impl IntentDetector {
                 examples: vec!["Yes".to_string(), "Sure".to_string(), "Okay".to_string()],
             },
             Intent {
-                name: "negative".to_string(),
+                name: NEGATIVE_INTENT.to_string(),
                 description: "User declining".to_string(),
                 required_slots: vec![],
                 optional_slots: vec![],
@@ -401,20 +631,7 @@ impl IntentDetector {
                     "Real person".to_string(),
                 ],
             },
-            Intent {
-                name: "send_sms".to_string(),
-                description: "User wants information via SMS".to_string(),
-                required_slots: vec![],
-                optional_slots: vec!["phone_number".to_string()],
-                examples: vec![
-                    "Send me details".to_string(),
-                    "Text me".to_string(),
-                    "Send SMS".to_string(),
-                ],
-            },
-        ];
-
-        *self.intents.write() = intents;
+        ]
     }
 
     /// P0 FIX: Compile slot patterns into regex at startup
@@ -676,6 +893,24 @@ impl IntentDetector {
                 slot_type: SlotType::Number,
                 multiplier: Some(11.66), // 1 tola = 11.66 grams
             },
+            CompiledSlotPattern {
+                name: "masha".to_string(),
+                regex: Regex::new(r"(?i)(\d+(?:\.\d+)?)\s*(?:maa?sha\b|माशा)").unwrap(),
+                slot_type: SlotType::Number,
+                multiplier: Some(0.972), // 1 masha = 0.972 grams
+            },
+            CompiledSlotPattern {
+                name: "ratti".to_string(),
+                regex: Regex::new(r"(?i)(\d+(?:\.\d+)?)\s*(?:rat?ti\b|रत्ती)").unwrap(),
+                slot_type: SlotType::Number,
+                multiplier: Some(0.1215), // 1 ratti = 0.1215 grams
+            },
+            CompiledSlotPattern {
+                name: "troy_ounce".to_string(),
+                regex: Regex::new(r"(?i)(\d+(?:\.\d+)?)\s*(?:(?:troy\s*)?ounces?|oz\b)").unwrap(),
+                slot_type: SlotType::Number,
+                multiplier: Some(31.103), // 1 troy ounce = 31.103 grams
+            },
         ];
         self.compiled_patterns
             .insert("gold_weight".to_string(), weight_patterns);
@@ -742,43 +977,161 @@ impl IntentDetector {
     }
 
     /// Detect intent from text
+    ///
+    /// If the strongest match is a phrase the user negated ("I don't want a
+    /// balance transfer"), returns `NEGATIVE_INTENT` with the negated intent
+    /// in the `NEGATED_INTENT_SLOT` slot. Confidences are calibrated if a
+    /// calibration is set.
     pub fn detect(&self, text: &str) -> DetectedIntent {
+        let mut detected = self.detect_raw(text);
+
+        if let Some(calibration) = self.calibration() {
+            detected.confidence = calibration.calibrate(detected.confidence);
+            for (_, score) in &mut detected.alternatives {
+                *score = calibration.calibrate(*score);
+            }
+        }
+
+        detected
+    }
+
+    /// Detect intent with uncalibrated match scores
+    fn detect_raw(&self, text: &str) -> DetectedIntent {
         let intents = self.intents.read();
         let text_lower = text.to_lowercase();
+        let scoped = NegationScopedText::new(&text_lower);
 
-        let mut scores: Vec<(String, f32)> = intents
+        let matches: Vec<(String, IntentMatch)> = intents
             .iter()
             .map(|intent| {
-                let score = self.calculate_intent_score(&text_lower, intent);
+                let score = self.calculate_intent_score(&text_lower, &scoped, intent);
                 (intent.name.clone(), score)
             })
             .collect();
 
+        let strongest_negated = matches
+            .iter()
+            .filter(|(_, m)| m.negated > 0.0)
+            .max_by(|a, b| a.1.negated.total_cmp(&b.1.negated))
+            .map(|(name, m)| (name.clone(), m.negated));
+
+        let mut scores: Vec<(String, f32)> = matches
+            .into_iter()
+            .map(|(name, m)| (name, m.affirmative))
+            .collect();
+
         // Sort by score descending
         scores.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap());
 
         let (best_intent, best_score) = scores
             .first()
             .cloned()
-            .unwrap_or(("unknown".to_string(), 0.0));
+            .unwrap_or((UNKNOWN_INTENT.to_string(), 0.0));
 
         // Extract slots
-        let slots = self.extract_slots(text);
+        let mut slots = self.extract_slots(text);
+
+        // The user is declining a topic rather than asking for anything
+        if let Some((negated_intent, score)) = strongest_negated.filter(|(_, s)| *s > best_score) {
+            slots.insert(
+                NEGATED_INTENT_SLOT.to_string(),
+                Slot {
+                    name: NEGATED_INTENT_SLOT.to_string(),
+                    slot_type: SlotType::Text,
+                    value: Some(negated_intent),
+                    confidence: score,
+                },
+            );
+            return DetectedIntent {
+                intent: NEGATIVE_INTENT.to_string(),
+                confidence: score,
+                slots,
+                alternatives: scores.into_iter().take(3).collect(),
+                ambiguous_number: None,
+            };
+        }
 
+        let ambiguous_number = Self::ambiguous_number(&text_lower, &slots);
         DetectedIntent {
             intent: best_intent,
             confidence: best_score,
             slots,
             alternatives: scores.into_iter().skip(1).take(3).collect(),
+            ambiguous_number,
+        }
+    }
+
+    /// Find a number spoken without a unit or scale ("I need five")
+    ///
+    /// Only small numbers (up to three digits, or number words) count; larger
+    /// values are already taken as rupees by the `plain_number` pattern. The
+    /// utterance must also be about an amount or weight ("need", "loan",
+    /// "chahiye"). Returns None when an amount or weight slot was extracted.
+    pub fn ambiguous_number(text: &str, slots: &HashMap<String, Slot>) -> Option<AmbiguousNumber> {
+        if QUANTITY_SLOTS
+            .iter()
+            .any(|name| slots.get(*name).is_some_and(|s| s.value.is_some()))
+        {
+            return None;
         }
+
+        let words: Vec<String> = text
+            .split_whitespace()
+            .map(|w| {
+                w.trim_matches(|c: char| c.is_ascii_punctuation() && c != '%' && c != '.')
+                    .trim_end_matches('.')
+                    .to_lowercase()
+            })
+            .filter(|w| !w.is_empty())
+            .collect();
+        if !words.iter().any(|w| is_amount_context(w)) {
+            return None;
+        }
+
+        for (i, word) in words.iter().enumerate() {
+            let starts_with_digit = word.chars().next().is_some_and(|c| c.is_ascii_digit());
+            let value = if starts_with_digit {
+                match word.parse::<f64>() {
+                    Ok(v) if v < 1000.0 => v,
+                    _ => continue,
+                }
+            } else {
+                match number_word(word) {
+                    Some(v) => v,
+                    None => continue,
+                }
+            };
+
+            let has_unit = words.get(i + 1).is_some_and(|next| is_unit_word(next));
+            let has_prefix = i > 0 && is_currency_prefix(&words[i - 1]);
+            if has_unit || has_prefix || value <= 0.0 {
+                continue;
+            }
+
+            return Some(AmbiguousNumber {
+                value,
+                text: word.clone(),
+            });
+        }
+
+        None
     }
 
     /// Calculate intent match score
     ///
     /// P2 FIX: Uses unicode_segmentation for proper Hindi/Devanagari word boundaries
     /// instead of split_whitespace() which doesn't handle Indian scripts correctly.
-    fn calculate_intent_score(&self, text: &str, intent: &Intent) -> f32 {
-        let mut score: f32 = 0.0;
+    ///
+    /// Example words that a negation cue reaches only count towards the
+    /// negated score, unless the example is itself negative ("Not now").
+    fn calculate_intent_score(
+        &self,
+        text: &str,
+        scoped: &NegationScopedText,
+        intent: &Intent,
+    ) -> IntentMatch {
+        let mut score = IntentMatch::default();
+        let text_words: std::collections::HashSet<&str> = scoped.words.iter().copied().collect();
 
         // Check examples
         for example in &intent.examples {
@@ -786,23 +1139,37 @@ impl IntentDetector {
 
             // Exact match
             if text == example_lower {
-                return 1.0;
-            }
-
-            // Contains check
-            if text.contains(&example_lower) {
-                score = score.max(0.9);
+                return IntentMatch {
+                    affirmative: 1.0,
+                    negated: 0.0,
+                };
             }
 
             // Word overlap - P2 FIX: Use Unicode word boundaries for Hindi/Devanagari support
             let example_words: std::collections::HashSet<&str> =
                 example_lower.unicode_words().collect();
-            let text_words: std::collections::HashSet<&str> = text.unicode_words().collect();
+            let example_is_negative = example_words
+                .iter()
+                .any(|w| hindi::negation_scope(w).is_some());
+            let is_negated = |word: &str| !example_is_negative && scoped.only_negated(word);
+
+            // Contains check
+            if text.contains(&example_lower) {
+                if example_words.iter().any(|w| is_negated(w)) {
+                    score.negated = score.negated.max(0.9);
+                } else {
+                    score.affirmative = score.affirmative.max(0.9);
+                }
+            }
 
-            let overlap = example_words.intersection(&text_words).count();
-            if overlap > 0 {
-                let overlap_score = overlap as f32 / example_words.len().max(1) as f32;
-                score = score.max(overlap_score * 0.8);
+            let overlap: Vec<&str> = example_words.intersection(&text_words).copied().collect();
+            let affirmed = overlap.iter().filter(|w| !is_negated(w)).count();
+            let example_len = example_words.len().max(1) as f32;
+            if affirmed > 0 {
+                score.affirmative = score.affirmative.max(affirmed as f32 / example_len * 0.8);
+            }
+            if affirmed < overlap.len() {
+                score.negated = score.negated.max(overlap.len() as f32 / example_len * 0.8);
             }
         }
 
@@ -1155,6 +1522,9 @@ impl IntentDetector {
                         "punjabi_lakh" | "punjabi_crore" => 0.95,
                         // General patterns
                         "thousand" | "grams" | "karat" => 0.90,
+                        // Traditional/imperial weight units: rarer, more ASR confusion
+                        "tola" | "troy_ounce" => 0.85,
+                        "masha" | "ratti" => 0.80,
                         "plain_number" => 0.70,
                         _ => 0.85,
                     };
@@ -1222,6 +1592,116 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_negated_intent_does_not_match() {
+        let detector = IntentDetector::new();
+
+        let result = detector.detect("I don't want a balance transfer");
+        assert_ne!(result.intent, "balance_transfer");
+        assert_eq!(result.intent, NEGATIVE_INTENT);
+        assert_eq!(
+            result.slots[NEGATED_INTENT_SLOT].value.as_deref(),
+            Some("balance_transfer")
+        );
+
+        let affirmed = detector.detect("I want a balance transfer");
+        assert_eq!(affirmed.intent, "balance_transfer");
+    }
+
+    #[test]
+    fn test_negated_intent_hindi() {
+        let detector = IntentDetector::new();
+
+        for text in [
+            "mujhe balance transfer nahi chahiye",
+            "balance transfer नहीं करना",
+            "balance transfer mat karo",
+        ] {
+            let result = detector.detect(text);
+            assert_eq!(result.intent, NEGATIVE_INTENT, "{}", text);
+            assert_eq!(
+                result.slots[NEGATED_INTENT_SLOT].value.as_deref(),
+                Some("balance_transfer"),
+                "{}",
+                text
+            );
+        }
+    }
+
+    #[test]
+    fn test_negation_scope_ends_at_clause() {
+        let detector = IntentDetector::new();
+
+        // Negation of the first clause doesn't reach the second
+        let result = detector.detect("never mind that, balance transfer please");
+        assert_eq!(result.intent, "balance_transfer");
+
+        // Negative examples keep matching their own intent
+        let result = detector.detect("not now");
+        assert_eq!(result.intent, "negative");
+        assert!(!result.slots.contains_key(NEGATED_INTENT_SLOT));
+    }
+
+    #[test]
+    fn test_calibration_out_of_domain_low() {
+        let detector = IntentDetector::new();
+        detector.set_calibration(Some(ConfidenceCalibration::default()));
+
+        let in_domain = detector.detect("What is the interest rate");
+        assert_eq!(in_domain.intent, "interest_rate");
+        assert!(in_domain.confidence > 0.9);
+
+        for text in ["play some music", "what's the weather like tomorrow"] {
+            let result = detector.detect(text);
+            assert!(result.confidence < 0.3, "{}: {}", text, result.confidence);
+        }
+    }
+
+    #[test]
+    fn test_calibration_fit() {
+        let samples = [
+            (1.0, true),
+            (0.9, true),
+            (0.8, true),
+            (0.72, true),
+            (0.64, true),
+            (0.4, false),
+            (0.3, false),
+            (0.25, false),
+            (0.2, false),
+            (0.1, false),
+        ];
+
+        let calibration = ConfidenceCalibration::fit(&samples);
+
+        assert!(calibration.calibrate(0.9) > 0.9);
+        assert!(calibration.calibrate(0.2) < 0.1);
+        assert!(calibration.midpoint > 0.4 && calibration.midpoint < 0.64);
+
+        // Degenerate samples keep the default mapping
+        assert_eq!(
+            ConfidenceCalibration::fit(&[(0.5, true)]),
+            ConfidenceCalibration::default()
+        );
+    }
+
+    #[test]
+    fn test_fit_calibration_from_utterances() {
+        let detector = IntentDetector::new();
+
+        let calibration = detector.fit_calibration(&[
+            ("What is the interest rate", "interest_rate"),
+            ("Hello", "greeting"),
+            ("I want a balance transfer", "balance_transfer"),
+            ("play some music", UNKNOWN_INTENT),
+            ("book a cab to the airport", UNKNOWN_INTENT),
+        ]);
+
+        assert_eq!(detector.calibration(), Some(calibration));
+        assert!(detector.detect("play some music").confidence < 0.5);
+        assert!(detector.detect("What is the interest rate").confidence > 0.5);
+    }
+
     #[test]
     fn test_greeting() {
         let detector = IntentDetector::new();
@@ -1230,6 +1710,62 @@ mod tests {
         assert_eq!(result.intent, "greeting");
     }
 
+    #[test]
+    fn test_custom_intent_set_keeps_core_intents() {
+        let set = IntentSet::from_toml(
+            r#"
+            [[intents]]
+            name = "book_table"
+            description = "User wants to reserve a table"
+            optional_slots = ["party_size"]
+            examples = ["Book a table", "Reserve a table for dinner"]
+            "#,
+        )
+        .unwrap();
+        let detector = IntentDetector::with_domain_intents(set.intents);
+        let intents = detector.list_intents();
+
+        assert!(!intents.contains(&"balance_transfer".to_string()));
+        assert!(!intents.contains(&"eligibility_check".to_string()));
+        for core in [
+            "greeting",
+            "farewell",
+            "affirmative",
+            "negative",
+            "escalate",
+        ] {
+            assert!(intents.contains(&core.to_string()), "missing {}", core);
+        }
+
+        assert_eq!(detector.detect("Book a table").intent, "book_table");
+        assert_eq!(detector.detect("Hello").intent, "greeting");
+    }
+
+    #[test]
+    fn test_with_intents_replaces_core_intents() {
+        let detector = IntentDetector::with_intents(vec![Intent {
+            name: "book_table".to_string(),
+            description: String::new(),
+            required_slots: vec![],
+            optional_slots: vec![],
+            examples: vec!["Book a table".to_string()],
+        }]);
+
+        assert_eq!(detector.list_intents(), vec!["book_table".to_string()]);
+
+        detector.set_intents(IntentDetector::core_intents());
+        assert!(detector.get_intent("book_table").is_none());
+        assert!(detector.get_intent("greeting").is_some());
+    }
+
+    #[test]
+    fn test_default_intents_loaded_from_config() {
+        let intents = IntentDetector::new().list_intents();
+
+        assert!(intents.contains(&"balance_transfer".to_string()));
+        assert!(intents.contains(&"greeting".to_string()));
+    }
+
     #[test]
     fn test_loan_amount_extraction_lakh() {
         let detector = IntentDetector::new();
@@ -1338,6 +1874,56 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_traditional_weight_units_to_grams() {
+        let detector = IntentDetector::new();
+
+        // (utterance, grams truncated to whole grams)
+        let cases = [
+            ("I have 20 masha gold", "19"),    // 20 * 0.972
+            ("about 100 ratti of gold", "12"), // 100 * 0.1215
+            ("I have 2 troy ounces", "62"),    // 2 * 31.103
+            ("my coin is 1 oz", "31"),         // 1 * 31.103
+        ];
+        for (utterance, grams) in cases {
+            let slots = detector.extract_slots(utterance);
+            assert_eq!(
+                slots.get("gold_weight").and_then(|s| s.value.clone()),
+                Some(grams.to_string()),
+                "{}",
+                utterance
+            );
+        }
+    }
+
+    #[test]
+    fn test_bare_number_flagged_ambiguous() {
+        let detector = IntentDetector::new();
+
+        let bare = detector.detect("I need five");
+        assert_eq!(
+            bare.ambiguous_number,
+            Some(AmbiguousNumber {
+                value: 5.0,
+                text: "five".to_string()
+            })
+        );
+        let bare = detector.detect("mujhe 50 chahiye");
+        assert_eq!(bare.ambiguous_number.map(|n| n.value), Some(50.0));
+
+        for utterance in [
+            "I need five lakh",
+            "I have 20 grams",
+            "rate is 12 percent",
+            "I need a gold loan",
+            "I have five children",
+            "please call me at five",
+        ] {
+            let detected = detector.detect(utterance);
+            assert!(detected.ambiguous_number.is_none(), "{}", utterance);
+        }
+    }
+
     // P0 FIX: Hindi/Devanagari slot extraction tests
 
     #[test]
@@ -24,6 +24,7 @@ pub mod compliance;
 pub mod entities;
 pub mod grammar;
 pub mod hindi; // P2.2 FIX: Shared Hindi language utilities
+pub mod injection; // Prompt injection detection on caller input
 pub mod intent; // P1-2 FIX: Intent detection moved from agent crate
 pub mod pii;
 pub mod sentiment; // P2-1 FIX: Sentiment analysis for customer emotion detection
@@ -41,10 +42,20 @@ pub use pipeline::{ProcessedText, TextProcessingConfig, TextProcessingPipeline};
 pub use compliance::{ComplianceConfig, ComplianceProvider, RuleBasedComplianceChecker};
 pub use grammar::{GrammarConfig, GrammarProvider, LLMGrammarCorrector, NoopCorrector};
 pub use pii::{HybridPIIDetector, IndianPIIPatterns, PIIConfig, PIIProvider};
-pub use simplifier::{AbbreviationExpander, NumberToWords, TextSimplifier, TextSimplifierConfig};
+pub use simplifier::{
+    AbbreviationExpander, NumberToWords, PronunciationLexicon, TextSimplifier,
+    TextSimplifierConfig,
+};
 pub use translation::{ScriptDetector, TranslationConfig, TranslationProvider};
 // P1-2 FIX: Intent detection exports
-pub use intent::{DetectedIntent, Intent, IntentDetector, Slot, SlotType};
+pub use intent::{
+    AmbiguousNumber, ConfidenceCalibration, DetectedIntent, Intent, IntentDetector, IntentSet,
+    Slot, SlotType, NEGATED_INTENT_SLOT, NEGATIVE_INTENT, UNKNOWN_INTENT,
+};
+// Prompt injection guard exports
+pub use injection::{
+    InjectionAction, InjectionCategory, InjectionConfig, InjectionGuard, InjectionResult,
+};
 // P2-1 FIX: Sentiment analysis exports
 pub use sentiment::{Sentiment, SentimentAnalyzer, SentimentConfig, SentimentResult};
 // P2-5 FIX: Loan entity extraction exports
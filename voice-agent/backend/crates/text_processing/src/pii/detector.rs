@@ -59,7 +59,7 @@ impl HybridPIIDetector {
     ///
     /// Uses heuristic-based NER with pattern matching and dictionary lookup.
     /// More accurate than ML models for Indian names in banking context.
-    async fn detect_ner(&self, text: &str) -> Vec<PIIEntity> {
+    fn detect_ner(&self, text: &str) -> Vec<PIIEntity> {
         let mut entities = Vec::new();
 
         // Detect names if enabled
@@ -140,25 +140,27 @@ impl HybridPIIDetector {
     ) -> String {
         strategy.apply(text, entity.pii_type)
     }
-}
 
-#[async_trait]
-impl PIIRedactor for HybridPIIDetector {
-    async fn detect(&self, text: &str) -> Result<Vec<PIIEntity>> {
+    /// Detect PII without going through the async trait
+    ///
+    /// Detection is CPU-only, so callers that cannot await (log formatting)
+    /// use this directly.
+    pub fn detect_sync(&self, text: &str) -> Vec<PIIEntity> {
         let mut entities = self.detect_regex(text);
 
         if self.use_ner {
-            entities.extend(self.detect_ner(text).await);
+            entities.extend(self.detect_ner(text));
         }
 
-        Ok(self.merge_detections(entities))
+        self.merge_detections(entities)
     }
 
-    async fn redact(&self, text: &str, strategy: &RedactionStrategy) -> Result<String> {
-        let entities = self.detect(text).await?;
+    /// Redact PII without going through the async trait
+    pub fn redact_sync(&self, text: &str, strategy: &RedactionStrategy) -> String {
+        let entities = self.detect_sync(text);
 
         if entities.is_empty() {
-            return Ok(text.to_string());
+            return text.to_string();
         }
 
         let mut result = text.to_string();
@@ -169,7 +171,18 @@ impl PIIRedactor for HybridPIIDetector {
             result.replace_range(entity.start..entity.end, &replacement);
         }
 
-        Ok(result)
+        result
+    }
+}
+
+#[async_trait]
+impl PIIRedactor for HybridPIIDetector {
+    async fn detect(&self, text: &str) -> Result<Vec<PIIEntity>> {
+        Ok(self.detect_sync(text))
+    }
+
+    async fn redact(&self, text: &str, strategy: &RedactionStrategy) -> Result<String> {
+        Ok(self.redact_sync(text, strategy))
     }
 
     fn supported_types(&self) -> &[PIIType] {
@@ -0,0 +1,247 @@
+//! Pronunciation Lexicon for TTS
+//!
+//! Maps brand names, loanwords and acronyms to the form TTS should speak,
+//! optionally per language (e.g. "EMI" → "ee-em-eye", "LTV" → "L T V").
+//! Applied before generic abbreviation expansion so configured forms win.
+//!
+//! Acronyms (all-caps terms such as "IT") match only in capitals, so the
+//! ordinary word "it" is left alone; other terms match in any case.
+
+use parking_lot::RwLock;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use voice_agent_core::Language;
+
+/// Configurable term → spoken form mapping
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct PronunciationLexicon {
+    /// Spoken forms used for every language
+    #[serde(default)]
+    terms: HashMap<String, String>,
+    /// Per-language overrides keyed by language code ("hi", "ta", ...)
+    #[serde(default)]
+    languages: HashMap<String, HashMap<String, String>>,
+    /// Compiled term pattern per language code, built on first use
+    #[serde(skip)]
+    patterns: RwLock<HashMap<String, Option<Regex>>>,
+}
+
+impl Clone for PronunciationLexicon {
+    fn clone(&self) -> Self {
+        Self::from_terms(self.terms.clone(), self.languages.clone())
+    }
+}
+
+impl PronunciationLexicon {
+    /// Create an empty lexicon
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create from spoken forms for every language and per-language overrides
+    pub fn from_terms(
+        terms: HashMap<String, String>,
+        languages: HashMap<String, HashMap<String, String>>,
+    ) -> Self {
+        Self {
+            terms,
+            languages,
+            patterns: RwLock::default(),
+        }
+    }
+
+    /// Add a spoken form used for every language
+    pub fn add(&mut self, term: &str, spoken: &str) {
+        self.terms.insert(term.to_string(), spoken.to_string());
+        self.patterns.get_mut().clear();
+    }
+
+    /// Add a spoken form for one language
+    pub fn add_for_language(&mut self, language: Language, term: &str, spoken: &str) {
+        self.languages
+            .entry(language.code().to_string())
+            .or_default()
+            .insert(term.to_string(), spoken.to_string());
+        self.patterns.get_mut().clear();
+    }
+
+    /// Merge domain abbreviations (short → spoken form)
+    ///
+    /// Entries already in the lexicon take precedence.
+    pub fn merge_abbreviations<I>(&mut self, abbreviations: I)
+    where
+        I: IntoIterator<Item = (String, String)>,
+    {
+        for (abbrev, expansion) in abbreviations {
+            if self.lookup_any(&abbrev).is_none() {
+                self.terms.insert(abbrev, expansion);
+            }
+        }
+        self.patterns.get_mut().clear();
+    }
+
+    /// Number of language-independent entries
+    pub fn len(&self) -> usize {
+        self.terms.len()
+    }
+
+    /// Whether the lexicon has no entries at all
+    pub fn is_empty(&self) -> bool {
+        self.terms.is_empty() && self.languages.values().all(|m| m.is_empty())
+    }
+
+    /// Spoken form of `term` in `language`
+    ///
+    /// Acronyms match only in capitals, other terms in any case.
+    pub fn lookup(&self, term: &str, language: Language) -> Option<&str> {
+        self.languages
+            .get(language.code())
+            .and_then(|m| find_term(m, term))
+            .or_else(|| find_term(&self.terms, term))
+    }
+
+    fn lookup_any(&self, term: &str) -> Option<&str> {
+        find_term(&self.terms, term)
+            .or_else(|| self.languages.values().find_map(|m| find_term(m, term)))
+    }
+
+    /// Replace every listed term in `text` with its spoken form
+    ///
+    /// Terms match on word boundaries; longer terms are tried first so
+    /// multi-word brand names win over their parts.
+    pub fn apply(&self, text: &str, language: Language) -> String {
+        let code = language.code();
+        let cached = self.patterns.read().get(code).cloned();
+        let pattern = match cached {
+            Some(pattern) => pattern,
+            None => {
+                let pattern = self.compile(code);
+                self.patterns
+                    .write()
+                    .insert(code.to_string(), pattern.clone());
+                pattern
+            },
+        };
+        let Some(pattern) = pattern else {
+            return text.to_string();
+        };
+
+        pattern
+            .replace_all(text, |caps: &regex::Captures| {
+                let matched = &caps[0];
+                self.lookup(matched, language)
+                    .unwrap_or(matched)
+                    .to_string()
+            })
+            .to_string()
+    }
+
+    /// Pattern matching every term for the language `code`, if there are any
+    fn compile(&self, code: &str) -> Option<Regex> {
+        let mut keys: Vec<&str> = self.terms.keys().map(|k| k.as_str()).collect();
+        if let Some(overrides) = self.languages.get(code) {
+            keys.extend(overrides.keys().map(|k| k.as_str()));
+        }
+        keys.retain(|k| !k.trim().is_empty());
+        if keys.is_empty() {
+            return None;
+        }
+        keys.sort_by(|a, b| b.len().cmp(&a.len()).then(a.cmp(b)));
+        keys.dedup();
+
+        let alternation = keys
+            .iter()
+            .map(|k| {
+                if is_acronym(k) {
+                    regex::escape(k)
+                } else {
+                    format!("(?i:{})", regex::escape(k))
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("|");
+        Regex::new(&format!(r"\b(?:{})\b", alternation)).ok()
+    }
+}
+
+/// Whether `term` is an acronym: letters all in capitals, at least two of them
+fn is_acronym(term: &str) -> bool {
+    let letters: Vec<char> = term.chars().filter(|c| c.is_alphabetic()).collect();
+    letters.len() >= 2 && letters.iter().all(|c| c.is_uppercase())
+}
+
+fn find_term<'a>(map: &'a HashMap<String, String>, term: &str) -> Option<&'a str> {
+    map.get(term)
+        .or_else(|| {
+            let term = term.to_lowercase();
+            map.iter()
+                .find(|(k, _)| !is_acronym(k) && k.to_lowercase() == term)
+                .map(|(_, v)| v)
+        })
+        .map(|s| s.as_str())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lexicon() -> PronunciationLexicon {
+        let mut lexicon = PronunciationLexicon::new();
+        lexicon.add("EMI", "ee-em-eye");
+        lexicon.add("LTV", "L T V");
+        lexicon.add_for_language(Language::Hindi, "EMI", "ई एम आई");
+        lexicon
+    }
+
+    #[test]
+    fn test_configured_acronym_expanded() {
+        let lexicon = lexicon();
+        assert_eq!(
+            lexicon.apply("Your EMI and LTV", Language::English),
+            "Your ee-em-eye and L T V"
+        );
+        assert_eq!(lexicon.apply("EMI", Language::Hindi), "ई एम आई");
+        // Cached patterns are per language
+        assert_eq!(lexicon.apply("EMI", Language::English), "ee-em-eye");
+    }
+
+    #[test]
+    fn test_acronyms_match_case_sensitively() {
+        let mut lexicon = lexicon();
+        lexicon.add("IT", "I T");
+        lexicon.add("Kotak", "Ko-tuck");
+        assert_eq!(
+            lexicon.apply("Is it for IT staff?", Language::English),
+            "Is it for I T staff?"
+        );
+        assert_eq!(lexicon.apply("emi", Language::English), "emi");
+        assert_eq!(
+            lexicon.apply("KOTAK kotak", Language::English),
+            "Ko-tuck Ko-tuck"
+        );
+    }
+
+    #[test]
+    fn test_unlisted_word_unchanged() {
+        let lexicon = lexicon();
+        assert_eq!(
+            lexicon.apply("Premium gold loan", Language::English),
+            "Premium gold loan"
+        );
+    }
+
+    #[test]
+    fn test_merge_keeps_lexicon_entries() {
+        let mut lexicon = lexicon();
+        lexicon.merge_abbreviations([
+            ("EMI".to_string(), "Equated Monthly Instalment".to_string()),
+            ("KMBL".to_string(), "Kotak Mahindra Bank".to_string()),
+        ]);
+        assert_eq!(lexicon.lookup("EMI", Language::English), Some("ee-em-eye"));
+        assert_eq!(
+            lexicon.lookup("KMBL", Language::English),
+            Some("Kotak Mahindra Bank")
+        );
+    }
+}
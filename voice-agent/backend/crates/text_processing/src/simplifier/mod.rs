@@ -3,6 +3,7 @@
 //! Converts text into TTS-friendly format:
 //! - Numbers to words (Indian numbering: lakh, crore)
 //! - Currency formatting (₹50000 → "fifty thousand rupees")
+//! - Pronunciation lexicon for brand/domain terms (configurable per language)
 //! - Abbreviation expansion (EMI → "E M I")
 //! - Complex sentence breaking for natural speech
 //!
@@ -15,9 +16,11 @@
 //! ```
 
 mod abbreviations;
+mod lexicon;
 mod numbers;
 
 pub use abbreviations::AbbreviationExpander;
+pub use lexicon::PronunciationLexicon;
 pub use numbers::{IndianNumberSystem, NumberToWords};
 
 use serde::{Deserialize, Serialize};
@@ -44,6 +47,9 @@ pub struct TextSimplifierConfig {
     /// Language for number words
     #[serde(default)]
     pub language: Language,
+    /// Spoken forms for brand names, loanwords and acronyms
+    #[serde(default)]
+    pub pronunciation_lexicon: PronunciationLexicon,
 }
 
 fn default_true() -> bool {
@@ -63,6 +69,7 @@ impl Default for TextSimplifierConfig {
             max_sentence_length: 150,
             pause_after_numbers: false,
             language: Language::English,
+            pronunciation_lexicon: PronunciationLexicon::default(),
         }
     }
 }
@@ -89,9 +96,22 @@ impl TextSimplifier {
         Self::new(TextSimplifierConfig::default())
     }
 
-    /// Simplify text for TTS
+    /// Simplify text for TTS in the configured language
     pub fn simplify(&self, text: &str) -> String {
-        let mut result = text.to_string();
+        self.simplify_for(text, self.config.language)
+    }
+
+    /// Simplify text for TTS in `language`
+    ///
+    /// Pronunciations and number words follow `language`, e.g. the session's
+    /// language on a server shared by callers speaking different languages.
+    pub fn simplify_for(&self, text: &str, language: Language) -> String {
+        // Step 0: Configured pronunciations take precedence over generic expansion
+        let mut result = if self.config.pronunciation_lexicon.is_empty() {
+            text.to_string()
+        } else {
+            self.config.pronunciation_lexicon.apply(text, language)
+        };
 
         // Step 1: Expand abbreviations first (before number processing)
         if self.config.expand_abbreviations {
@@ -100,7 +120,11 @@ impl TextSimplifier {
 
         // Step 2: Convert numbers to words
         if self.config.convert_numbers {
-            result = self.number_converter.convert(&result);
+            result = if language == self.config.language {
+                self.number_converter.convert(&result)
+            } else {
+                NumberToWords::new(language).convert(&result)
+            };
         }
 
         // Step 3: Break long sentences
@@ -182,6 +206,11 @@ impl TextSimplifier {
         text.split_whitespace().collect::<Vec<_>>().join(" ")
     }
 
+    /// Pronunciation lexicon applied before synthesis
+    pub fn pronunciation_lexicon(&self) -> &PronunciationLexicon {
+        &self.config.pronunciation_lexicon
+    }
+
     /// Set language for number conversion
     pub fn set_language(&mut self, language: Language) {
         self.config.language = language;
@@ -209,6 +238,35 @@ mod tests {
         assert!(result.contains("eight point five percent"));
     }
 
+    #[test]
+    fn test_simplify_with_pronunciation_lexicon() {
+        let mut config = TextSimplifierConfig::default();
+        config.pronunciation_lexicon.add("EMI", "ee-em-eye");
+        let simplifier = TextSimplifier::new(config);
+
+        assert_eq!(
+            simplifier.simplify("Your EMI is due"),
+            "Your ee-em-eye is due"
+        );
+        assert_eq!(
+            simplifier.simplify("Visit the branch today"),
+            "Visit the branch today"
+        );
+    }
+
+    #[test]
+    fn test_simplify_for_session_language() {
+        let mut config = TextSimplifierConfig::default();
+        config.pronunciation_lexicon.add("EMI", "ee-em-eye");
+        config
+            .pronunciation_lexicon
+            .add_for_language(Language::Hindi, "EMI", "ई एम आई");
+        let simplifier = TextSimplifier::new(config);
+
+        assert_eq!(simplifier.simplify_for("EMI", Language::Hindi), "ई एम आई");
+        assert_eq!(simplifier.simplify("EMI"), "ee-em-eye");
+    }
+
     #[test]
     fn test_simplify_phone() {
         let simplifier = TextSimplifier::default_config();
@@ -122,13 +122,58 @@ static AMOUNT_PATTERNS: Lazy<Vec<(Regex, AmountMultiplier)>> = Lazy::new(|| vec!
     (Regex::new(r"\b(\d{5,8})\b").unwrap(), AmountMultiplier::Unit),
 ]);
 
-// Weight patterns (grams, tola, contextual)
+/// Weight unit for parsing
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum WeightUnit {
+    Gram,
+    Tola,      // 11.66 g
+    Masha,     // 0.972 g
+    Ratti,     // 0.1215 g
+    TroyOunce, // 31.103 g
+    Unspecified,
+}
+
+impl WeightUnit {
+    /// Grams per unit
+    fn grams(&self) -> f64 {
+        match self {
+            WeightUnit::Gram | WeightUnit::Unspecified => 1.0,
+            WeightUnit::Tola => 11.66,
+            WeightUnit::Masha => 0.972,
+            WeightUnit::Ratti => 0.1215,
+            WeightUnit::TroyOunce => 31.103,
+        }
+    }
+
+    /// Extraction confidence for an explicit unit mention
+    ///
+    /// Traditional units are rarer and more often misheard by ASR.
+    fn confidence(&self) -> Option<f32> {
+        match self {
+            WeightUnit::Gram | WeightUnit::Tola => Some(0.9),
+            WeightUnit::TroyOunce => Some(0.85),
+            WeightUnit::Masha | WeightUnit::Ratti => Some(0.8),
+            WeightUnit::Unspecified => None,
+        }
+    }
+}
+
+// Weight patterns (grams, traditional units, contextual)
 // P18 FIX: Asset-specific terms (gold/sona) removed - use config-driven asset_terms for confidence
-static WEIGHT_PATTERNS: Lazy<Vec<Regex>> = Lazy::new(|| vec![
-    Regex::new(r"(?i)(\d+(?:\.\d+)?)\s*(?:grams?|gm|g|ग्राम)").unwrap(),
-    Regex::new(r"(?i)(\d+(?:\.\d+)?)\s*(?:tola|तोला)").unwrap(),
+static WEIGHT_PATTERNS: Lazy<Vec<(Regex, WeightUnit)>> = Lazy::new(|| vec![
+    (Regex::new(r"(?i)(\d+(?:\.\d+)?)\s*(?:grams?|gm|g|ग्राम)").unwrap(), WeightUnit::Gram),
+    (Regex::new(r"(?i)(\d+(?:\.\d+)?)\s*(?:tola|तोला)").unwrap(), WeightUnit::Tola),
+    (Regex::new(r"(?i)(\d+(?:\.\d+)?)\s*(?:maa?sha\b|माशा)").unwrap(), WeightUnit::Masha),
+    (Regex::new(r"(?i)(\d+(?:\.\d+)?)\s*(?:rat?ti\b|रत्ती)").unwrap(), WeightUnit::Ratti),
+    (
+        Regex::new(r"(?i)(\d+(?:\.\d+)?)\s*(?:(?:troy\s*)?ounces?|oz\b)").unwrap(),
+        WeightUnit::TroyOunce,
+    ),
     // Contextual pattern without asset-specific term - matches "have 50 grams", "hai 50 g"
-    Regex::new(r"(?i)(?:have|hai|है)\s*(\d+(?:\.\d+)?)\s*(?:grams?|g)?").unwrap(),
+    (
+        Regex::new(r"(?i)(?:have|hai|है)\s*(\d+(?:\.\d+)?)\s*(?:grams?|g)?").unwrap(),
+        WeightUnit::Unspecified,
+    ),
 ]);
 
 // Phone patterns (Indian mobile numbers)
@@ -151,13 +196,56 @@ static TIME_PATTERNS: Lazy<Vec<Regex>> = Lazy::new(|| vec![
 ]);
 
 // Name patterns (English and Hindi)
-static NAME_PATTERNS: Lazy<Vec<Regex>> = Lazy::new(|| vec![
-    Regex::new(r"(?i)(?:my\s+name\s+is|i\s+am|i'm|this\s+is|call\s+me)\s+([A-Z][a-zA-Z]+(?:\s+[A-Z][a-zA-Z]+)*)").unwrap(),
-    Regex::new(r"(?i)(?:mera\s+)?(?:naam|name)\s+([A-Z][a-zA-Z]+(?:\s+[A-Z][a-zA-Z]*)?)\s+(?:hai|h)\b").unwrap(),
-    Regex::new(r"(?i)(?:mera\s+)?(?:naam|name)\s+([A-Z][a-zA-Z]+)(?:\s+[A-Z][a-zA-Z]+)?(?:\s|$|[.,])").unwrap(),
-    Regex::new(r"(?i)(?:myself|name[:\s]+)\s*([A-Z][a-zA-Z]+(?:\s+[A-Z][a-zA-Z]+)*)").unwrap(),
+// Matched against the lowercased utterance since STT output is rarely capitalized.
+// Strong cues introduce a name outright; weak cues ("i am", "main") also introduce
+// states and intents ("i am interested"), so their candidate must be a known name.
+static NAME_PATTERNS: Lazy<Vec<(Regex, NameCue)>> = Lazy::new(|| vec![
+    (Regex::new(r"\b(?:my\s+name\s+is|my\s+name's|myself)\s+([a-z]+(?:\s+[a-z]+){0,2})").unwrap(), NameCue::Strong),
+    (Regex::new(r"\b(?:mera|meraa|mere|apna)\s+(?:naam|name)\s+(?:hai\s+|is\s+)?([a-z]+(?:\s+[a-z]+){0,2})").unwrap(), NameCue::Strong),
+    (Regex::new(r"\bname\s*:\s*([a-z]+(?:\s+[a-z]+){0,2})").unwrap(), NameCue::Strong),
+    (Regex::new(r"\b(?:naam|name)\s+(?:hai\s+|is\s+)?([a-z]+(?:\s+[a-z]+){0,2})").unwrap(), NameCue::Weak),
+    (Regex::new(r"\b(?:i\s+am|i'm|this\s+is|call\s+me|main|mai|mein)\s+([a-z]+(?:\s+[a-z]+){0,2})").unwrap(), NameCue::Weak),
 ]);
 
+/// How strongly a context cue implies that a name follows
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum NameCue {
+    Strong,
+    Weak,
+}
+
+// Common Indian given names, used to validate weak-cue candidates and to
+// restore the spelling of phonetically mangled STT output
+static COMMON_INDIAN_NAMES: &[&str] = &[
+    "Aarav", "Abhishek", "Aditi", "Aditya", "Ajay", "Akash", "Alok", "Amit", "Amitabh", "Anand",
+    "Anil", "Anita", "Anjali", "Ankit", "Anupam", "Arjun", "Arun", "Asha", "Ashok", "Deepak",
+    "Deepika", "Dinesh", "Divya", "Ganesh", "Gaurav", "Geeta", "Harish", "Imran", "Jyoti", "Kavita",
+    "Kiran", "Krishna", "Kunal", "Lakshmi", "Madhu", "Mahesh", "Manish", "Manoj", "Meena", "Mohan",
+    "Mohammed", "Mukesh", "Naveen", "Neha", "Nikhil", "Nisha", "Pankaj", "Pooja", "Prakash", "Priya",
+    "Rahul", "Rajesh", "Rakesh", "Ramesh", "Ravi", "Rekha", "Rohit", "Sachin", "Sandeep", "Sanjay",
+    "Santosh", "Sarita", "Shankar", "Shweta", "Sneha", "Sunil", "Sunita", "Suresh", "Swati", "Usha",
+    "Varun", "Vijay", "Vikas", "Vikram", "Vinod", "Vishal", "Yogesh",
+];
+
+// Words that follow a name in an utterance and end the name ("rahul hai", "priya bol rahi hoon")
+static NAME_TERMINATORS: &[&str] = &[
+    "hai", "h", "he", "hoon", "hun", "hu", "ji", "and", "aur", "from", "here", "speaking", "bol",
+    "baat", "kar", "raha", "rahi", "se", "is", "of", "the", "a", "an", "to", "for",
+];
+
+// Words that commonly follow a name cue but are never names
+static NAME_STOPWORDS: &[&str] = &[
+    "interested", "looking", "calling", "going", "fine", "good", "okay", "ok", "not", "sure",
+    "here", "very", "so", "just", "also", "already", "ready", "new", "old", "your", "my", "ek",
+    "bhi", "to", "aapka", "aapki", "aapse", "kya", "yeh", "woh", "abhi",
+];
+
+// Spelling variants folded together when comparing names phonetically
+static NAME_PHONETIC_FOLDS: &[(&str, &str)] = &[
+    ("ee", "i"), ("oo", "u"), ("ph", "f"), ("bh", "b"), ("dh", "d"), ("th", "t"),
+    ("kh", "k"), ("gh", "g"), ("sh", "s"), ("ch", "c"), ("w", "v"), ("z", "j"), ("q", "k"),
+];
+
 // PAN patterns
 static PAN_PATTERNS: Lazy<Vec<Regex>> = Lazy::new(|| vec![
     Regex::new(r"(?i)(?:pan|pan\s+(?:card|number|no\.?)|my\s+pan)\s*(?:is|:)?\s*([A-Z]{5}[0-9]{4}[A-Z])").unwrap(),
@@ -251,6 +339,35 @@ static LENDER_PATTERNS: Lazy<HashMap<&'static str, Vec<&'static str>>> = Lazy::n
     HashMap::new()
 });
 
+/// Phonetic key for Indian names so STT spelling variants compare equal
+///
+/// Folds aspirated consonants ("bh" -> "b"), long vowels ("ee" -> "i",
+/// "oo" -> "u"), common letter swaps ("w" -> "v", "z" -> "j") and doubled
+/// letters, so "raahul", "rahool" and "Rahul" share a key.
+fn name_phonetic_key(word: &str) -> String {
+    let mut key = word.to_lowercase();
+    for (from, to) in NAME_PHONETIC_FOLDS {
+        key = key.replace(from, to);
+    }
+
+    let mut folded = String::with_capacity(key.len());
+    for c in key.chars().filter(|c| c.is_ascii_alphabetic()) {
+        if !folded.ends_with(c) {
+            folded.push(c);
+        }
+    }
+    folded
+}
+
+/// Capitalize the first letter of a word
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
 // =============================================================================
 // SLOT EXTRACTOR
 // =============================================================================
@@ -630,28 +747,27 @@ impl SlotExtractor {
     pub fn extract_weight(&self, utterance: &str) -> Option<(f64, f32)> {
         let lower = utterance.to_lowercase();
 
-        for pattern in WEIGHT_PATTERNS.iter() {
+        for (pattern, unit) in WEIGHT_PATTERNS.iter() {
             if let Some(caps) = pattern.captures(&lower) {
                 if let Some(num_match) = caps.get(1) {
                     if let Ok(num) = num_match.as_str().parse::<f64>() {
-                        // Check if it's tola (convert to grams)
-                        let weight = if lower.contains("tola") || lower.contains("तोला") {
-                            num * 11.66 // 1 tola ≈ 11.66 grams
-                        } else {
-                            num
-                        };
+                        // Convert to grams
+                        let weight = num * unit.grams();
 
                         // P18 FIX: Config-driven confidence boosting
                         // Check if any asset term is present in the utterance
                         let has_asset_context = self.asset_terms.iter().any(|term| lower.contains(term.as_str()));
 
-                        // Confidence based on context (unit mentions or asset terms)
-                        let confidence = if has_asset_context
-                            || lower.contains("gram") || lower.contains("tola")
-                        {
-                            0.9
-                        } else {
-                            0.7
+                        // Confidence based on the unit, else on context (unit or asset terms)
+                        let confidence = match unit.confidence() {
+                            Some(confidence) => confidence,
+                            None if has_asset_context
+                                || lower.contains("gram")
+                                || lower.contains("tola") =>
+                            {
+                                0.9
+                            },
+                            None => 0.7,
                         };
 
                         return Some((weight, confidence));
@@ -985,25 +1101,20 @@ impl SlotExtractor {
     }
 
     /// Extract customer name from utterance
+    ///
+    /// Works on lowercase and phonetically mangled STT output: a context cue
+    /// ("my name is", "mera naam") locates the name, and candidates that
+    /// sound like a common Indian given name are returned in its spelling.
     pub fn extract_name(&self, utterance: &str) -> Option<(String, f32)> {
-        for pattern in NAME_PATTERNS.iter() {
-            if let Some(caps) = pattern.captures(utterance) {
-                if let Some(m) = caps.get(1) {
-                    let name = m.as_str().trim().to_string();
-                    // Basic validation: name should be 2-50 chars and not be common words
-                    if name.len() >= 2 && name.len() <= 50 {
-                        let lower = name.to_lowercase();
-                        // P18 FIX: Filter out common false positives (domain-agnostic)
-                        // Note: Brand/competitor names should be filtered at runtime
-                        // using domain config, not hardcoded here
-                        let exclude_words = [
-                            "loan", "bank", "amount", "finance", "company",
-                            "rate", "interest", "help", "need", "want", "please",
-                        ];
-                        if !exclude_words.iter().any(|w| lower == *w) {
-                            return Some((name, 0.85));
-                        }
-                    }
+        let lower = utterance.to_lowercase();
+
+        for (pattern, cue) in NAME_PATTERNS.iter() {
+            for caps in pattern.captures_iter(&lower) {
+                let Some(m) = caps.get(1) else {
+                    continue;
+                };
+                if let Some(name) = Self::validate_name(m.as_str(), *cue) {
+                    return Some(name);
                 }
             }
         }
@@ -1011,6 +1122,53 @@ impl SlotExtractor {
         None
     }
 
+    /// Turn the words after a name cue into a name, if they look like one
+    fn validate_name(candidate: &str, cue: NameCue) -> Option<(String, f32)> {
+        // P18 FIX: Filter out common false positives (domain-agnostic)
+        // Note: Brand/competitor names should be filtered at runtime
+        // using domain config, not hardcoded here
+        let exclude_words = [
+            "loan", "bank", "amount", "finance", "company", "rate", "interest", "help", "need",
+            "want", "please",
+        ];
+        let is_name_word = |word: &str| {
+            word.len() >= 2
+                && word.len() <= 20
+                && !exclude_words.contains(&word)
+                && !NAME_STOPWORDS.contains(&word)
+                && !NAME_TERMINATORS.contains(&word)
+        };
+
+        let words: Vec<&str> = candidate
+            .split_whitespace()
+            .take_while(|word| is_name_word(word))
+            .collect();
+        let (first, rest) = words.split_first()?;
+
+        let known = Self::match_common_name(first);
+        let confidence = match (cue, known.is_some()) {
+            (NameCue::Strong, true) => 0.9,
+            (NameCue::Strong, false) => 0.75,
+            (NameCue::Weak, true) => 0.8,
+            (NameCue::Weak, false) => return None,
+        };
+
+        let first = known.map(str::to_string).unwrap_or_else(|| capitalize(first));
+        let mut parts = vec![first];
+        parts.extend(rest.iter().map(|word| capitalize(word)));
+
+        Some((parts.join(" "), confidence))
+    }
+
+    /// Find the common given name that sounds like `word`
+    fn match_common_name(word: &str) -> Option<&'static str> {
+        let key = name_phonetic_key(word);
+        COMMON_INDIAN_NAMES
+            .iter()
+            .find(|name| name_phonetic_key(name) == key)
+            .copied()
+    }
+
     /// Extract PAN number from utterance
     pub fn extract_pan(&self, utterance: &str) -> Option<(String, f32)> {
         let upper = utterance.to_uppercase();
@@ -1160,6 +1318,32 @@ mod tests {
         assert!((weight - 58.3).abs() < 0.1); // 5 * 11.66
     }
 
+    #[test]
+    fn test_traditional_weight_units() {
+        let extractor = SlotExtractor::new();
+
+        let (weight, confidence) = extractor.extract_weight("20 masha sona hai").unwrap();
+        assert!((weight - 19.44).abs() < 0.001); // 20 * 0.972
+        assert_eq!(confidence, 0.8);
+
+        let (weight, confidence) = extractor.extract_weight("mere paas 50 ratti hai").unwrap();
+        assert!((weight - 6.075).abs() < 0.001); // 50 * 0.1215
+        assert_eq!(confidence, 0.8);
+
+        let (weight, confidence) = extractor.extract_weight("I have 2 troy ounces").unwrap();
+        assert!((weight - 62.206).abs() < 0.001); // 2 * 31.103
+        assert_eq!(confidence, 0.85);
+
+        let (weight, _) = extractor.extract_weight("one coin of 1 oz").unwrap();
+        assert!((weight - 31.103).abs() < 0.001);
+
+        let (weight, _) = extractor.extract_weight("10 माशा").unwrap();
+        assert!((weight - 9.72).abs() < 0.001);
+
+        let (_, confidence) = extractor.extract_weight("5 tola gold").unwrap();
+        assert_eq!(confidence, 0.9);
+    }
+
     #[test]
     fn test_phone_extraction() {
         let extractor = SlotExtractor::new();
@@ -1272,6 +1456,64 @@ mod tests {
         assert!((weight - 50.0).abs() < 0.1);
     }
 
+    #[test]
+    fn test_name_extraction_lowercase_hinglish() {
+        let extractor = SlotExtractor::new();
+
+        let (name, confidence) = extractor.extract_name("mera naam rahul hai").unwrap();
+        assert_eq!(name, "Rahul");
+        assert!(confidence >= 0.85);
+
+        let (name, _) = extractor.extract_name("mera naam priya sharma hai").unwrap();
+        assert_eq!(name, "Priya Sharma");
+
+        let (name, _) = extractor.extract_name("main vikram bol raha hoon").unwrap();
+        assert_eq!(name, "Vikram");
+
+        // Unknown names are still taken from a strong cue, less confidently
+        let (name, confidence) = extractor.extract_name("my name is tanmay").unwrap();
+        assert_eq!(name, "Tanmay");
+        assert!(confidence < 0.85);
+    }
+
+    #[test]
+    fn test_name_extraction_phonetic_variants() {
+        let extractor = SlotExtractor::new();
+
+        let (name, _) = extractor.extract_name("haan mera naam raahul hai").unwrap();
+        assert_eq!(name, "Rahul");
+
+        let (name, _) = extractor.extract_name("this is preeya calling").unwrap();
+        assert_eq!(name, "Priya");
+    }
+
+    #[test]
+    fn test_name_extraction_rejects_false_positives() {
+        let extractor = SlotExtractor::new();
+
+        assert!(extractor.extract_name("i am interested in loan").is_none());
+        assert!(extractor.extract_name("i am looking for help").is_none());
+        assert!(extractor.extract_name("mera naam loan hai").is_none());
+        assert!(extractor.extract_name("please call me back").is_none());
+        assert!(extractor.extract_name("aapka naam kya hai").is_none());
+    }
+
+    #[test]
+    fn test_weak_cue_drops_names_not_in_the_list() {
+        let extractor = SlotExtractor::new();
+
+        // Weak cues only accept known Indian given names
+        assert!(extractor.extract_name("i am john").is_none());
+        assert!(extractor.extract_name("this is michael").is_none());
+        assert!(extractor.extract_name("call me david").is_none());
+        assert!(extractor.extract_name("main tanmay bol raha").is_none());
+
+        // The same name after a strong cue is kept, less confidently
+        let (name, confidence) = extractor.extract_name("my name is john").unwrap();
+        assert_eq!(name, "John");
+        assert!(confidence < 0.8);
+    }
+
     #[test]
     fn test_intent_extraction() {
         let extractor = SlotExtractor::new();
@@ -3,6 +3,17 @@
 use std::collections::HashMap;
 use voice_agent_core::{Language, Script};
 
+/// Common romanized Hindi words that aren't also English words
+const ROMANIZED_HINDI_WORDS: &[&str] = &[
+    "aap", "aapka", "aapki", "aapke", "aapko", "accha", "acha", "abhi", "aur", "bahut", "bhi",
+    "chahiye", "chahte", "dijiye", "gaya", "gaye", "gayi", "haan", "hai", "hain", "hamara",
+    "hamare", "ho", "hoga", "hoon", "hum", "hun", "ji", "ka", "kab", "kahan", "kaise", "kar",
+    "karna", "karte", "ke", "ki", "kitna", "kitne", "ko", "koi", "kuch", "kya", "kyun", "lekin",
+    "liye", "mein", "mera", "meri", "mere", "milega", "milegi", "mujhe", "nahi", "nahin", "raha",
+    "rahe", "rahi", "sakta", "sakte", "sakti", "se", "theek", "thik", "tha", "thi", "toh", "wala",
+    "wale", "yeh", "woh",
+];
+
 /// Script-based language detector
 #[derive(Debug, Clone)]
 pub struct ScriptDetector {
@@ -151,6 +162,38 @@ impl ScriptDetector {
 
         (language, confidence)
     }
+
+    /// Share of Latin-script text's words that are common Hindi words
+    ///
+    /// Script alone calls romanized Hindi (Hinglish) English; this scores
+    /// how Hindi it reads. Zero for non-Latin text and for text with fewer
+    /// than two Hindi words.
+    pub fn romanized_hindi_share(&self, text: &str) -> f32 {
+        let words: Vec<String> = text
+            .split(|c: char| !c.is_alphanumeric())
+            .filter(|w| !w.is_empty())
+            .map(str::to_lowercase)
+            .collect();
+        if words.iter().any(|w| !w.is_ascii()) {
+            return 0.0;
+        }
+        let hindi = words
+            .iter()
+            .filter(|w| ROMANIZED_HINDI_WORDS.contains(&w.as_str()))
+            .count();
+        if hindi < 2 {
+            return 0.0;
+        }
+        hindi as f32 / words.len() as f32
+    }
+
+    /// Whether Latin-script text reads as romanized Hindi: at least half
+    /// its words are common Hindi words
+    ///
+    /// English with a Hindi tag ("interest rate kya hai?") stays English.
+    pub fn is_romanized_hindi(&self, text: &str) -> bool {
+        self.romanized_hindi_share(text) >= 0.5
+    }
 }
 
 impl Default for ScriptDetector {
@@ -215,4 +258,14 @@ mod tests {
         let detector = ScriptDetector::new();
         assert_eq!(detector.detect("నమస్కారం"), Language::Telugu);
     }
+
+    #[test]
+    fn test_romanized_hindi() {
+        let detector = ScriptDetector::new();
+        assert!(detector.is_romanized_hindi("Mujhe gold loan chahiye, kitna milega?"));
+        assert!(detector.is_romanized_hindi("Aapka loan approve ho gaya hai"));
+        assert!(!detector.is_romanized_hindi("I need a gold loan, how much can I get?"));
+        assert!(!detector.is_romanized_hindi("I want a gold loan, interest rate kya hai?"));
+        assert!(!detector.is_romanized_hindi("मुझे लोन चाहिए"));
+    }
 }
@@ -0,0 +1,329 @@
+//! Calendar backed by the appointment store
+//!
+//! `StoreCalendar` books, moves and cancels branch visits in the persisted
+//! `AppointmentStore`, so they outlive the call and are covered by customer
+//! data export and erasure. Each branch takes `slot_capacity` visitors per
+//! time slot: a slot is offered while fewer active appointments hold it,
+//! and booking or moving into a full slot is refused.
+
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use voice_agent_persistence::{
+    Appointment as StoredAppointment, AppointmentStatus as StoredStatus, AppointmentStore,
+    PersistenceError,
+};
+
+use crate::integrations::{
+    normalize_phone, Appointment, AppointmentPurpose, AppointmentStatus, CalendarIntegration,
+    IntegrationError, TimeSlot,
+};
+
+use super::hours::parse_clock;
+use super::locations::get_branches;
+
+/// Calendar keeping appointments in an `AppointmentStore`
+pub struct StoreCalendar {
+    store: Arc<dyn AppointmentStore>,
+    /// Bookable time slots, e.g. "11:00 AM"
+    slots: Vec<String>,
+    /// Appointments a branch takes per slot
+    slot_capacity: u32,
+}
+
+impl StoreCalendar {
+    pub fn new(store: Arc<dyn AppointmentStore>, slots: Vec<String>, slot_capacity: u32) -> Self {
+        Self {
+            store,
+            slots,
+            slot_capacity,
+        }
+    }
+
+    /// Stored appointment by calendar ID
+    async fn find(&self, id: &str) -> Result<StoredAppointment, IntegrationError> {
+        let not_found = || IntegrationError::NotFound(id.to_string());
+        let appointment_id = Uuid::parse_str(id).map_err(|_| not_found())?;
+        self.store
+            .find(appointment_id)
+            .await
+            .map_err(store_error)?
+            .ok_or_else(not_found)
+    }
+
+    /// Free places in a slot at a branch, not counting the appointment `except`
+    async fn remaining(
+        &self,
+        branch_id: &str,
+        date: NaiveDate,
+        time: &str,
+        except: Option<Uuid>,
+    ) -> Result<u32, IntegrationError> {
+        let taken = self
+            .store
+            .list_for_date(date)
+            .await
+            .map_err(store_error)?
+            .iter()
+            .filter(|a| {
+                a.is_active()
+                    && Some(a.appointment_id) != except
+                    && a.branch_id.eq_ignore_ascii_case(branch_id)
+                    && same_slot(&a.appointment_time, time)
+            })
+            .count() as u32;
+        Ok(self.slot_capacity.saturating_sub(taken))
+    }
+
+    /// Refuse a slot with no free place
+    async fn check_room(
+        &self,
+        branch_id: &str,
+        date: NaiveDate,
+        time: &str,
+        except: Option<Uuid>,
+    ) -> Result<(), IntegrationError> {
+        if self.remaining(branch_id, date, time, except).await? == 0 {
+            return Err(IntegrationError::InvalidRequest(format!(
+                "The {} slot on {} is fully booked",
+                time, date
+            )));
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl CalendarIntegration for StoreCalendar {
+    async fn get_available_slots(
+        &self,
+        branch_id: &str,
+        date: &str,
+    ) -> Result<Vec<TimeSlot>, IntegrationError> {
+        let date = parse_day(date)?;
+        let mut slots = Vec::with_capacity(self.slots.len());
+        for time in &self.slots {
+            let remaining = self.remaining(branch_id, date, time, None).await?;
+            slots.push(TimeSlot {
+                time: time.clone(),
+                available: remaining > 0,
+                remaining_capacity: remaining,
+            });
+        }
+        Ok(slots)
+    }
+
+    async fn schedule_appointment(
+        &self,
+        appointment: Appointment,
+    ) -> Result<String, IntegrationError> {
+        let date = parse_day(&appointment.date)?;
+        self.check_room(&appointment.branch_id, date, &appointment.time_slot, None)
+            .await?;
+
+        let branch = get_branches()
+            .into_iter()
+            .find(|b| b.branch_id.eq_ignore_ascii_case(&appointment.branch_id));
+        let (branch_name, branch_address) = match branch {
+            Some(b) => (b.name, b.address),
+            None => (appointment.branch_id.clone(), String::new()),
+        };
+        let mut stored = StoredAppointment::new(
+            &normalize_phone(&appointment.customer_phone),
+            &appointment.branch_id,
+            &branch_name,
+            &branch_address,
+            date,
+            &appointment.time_slot,
+        );
+        stored.customer_name = Some(appointment.customer_name);
+        stored.notes = Some(match appointment.notes {
+            Some(notes) => format!("{}: {}", appointment.purpose.as_str(), notes),
+            None => appointment.purpose.as_str().to_string(),
+        });
+
+        self.store.create(&stored).await.map_err(store_error)?;
+        Ok(stored.appointment_id.to_string())
+    }
+
+    async fn cancel_appointment(&self, id: &str) -> Result<(), IntegrationError> {
+        let appointment = self.find(id).await?;
+        self.store
+            .cancel(&appointment.customer_phone, appointment.appointment_id)
+            .await
+            .map_err(store_error)
+    }
+
+    async fn reschedule_appointment(
+        &self,
+        id: &str,
+        new_date: &str,
+        new_time: &str,
+    ) -> Result<(), IntegrationError> {
+        let appointment = self.find(id).await?;
+        let date = parse_day(new_date)?;
+        self.check_room(
+            &appointment.branch_id,
+            date,
+            new_time,
+            Some(appointment.appointment_id),
+        )
+        .await?;
+        self.store
+            .reschedule(
+                &appointment.customer_phone,
+                appointment.appointment_id,
+                date,
+                new_time,
+            )
+            .await
+            .map_err(store_error)
+    }
+
+    async fn get_appointment(&self, id: &str) -> Result<Appointment, IntegrationError> {
+        let appointment = self.find(id).await?;
+        Ok(Appointment {
+            id: Some(id.to_string()),
+            customer_name: appointment.customer_name.unwrap_or_default(),
+            customer_phone: appointment.customer_phone,
+            branch_id: appointment.branch_id,
+            date: appointment.appointment_date.format("%Y-%m-%d").to_string(),
+            time_slot: appointment.appointment_time,
+            purpose: AppointmentPurpose::default(),
+            notes: appointment.notes,
+            status: match appointment.status {
+                StoredStatus::Scheduled => AppointmentStatus::Scheduled,
+                StoredStatus::Confirmed => AppointmentStatus::Confirmed,
+                StoredStatus::Rescheduled => AppointmentStatus::Rescheduled,
+                StoredStatus::Cancelled => AppointmentStatus::Cancelled,
+                StoredStatus::Completed => AppointmentStatus::Completed,
+                StoredStatus::NoShow => AppointmentStatus::NoShow,
+            },
+            confirmation_sent: appointment.confirmation_sms_id.is_some(),
+        })
+    }
+
+    /// Confirmations are not sent from here
+    ///
+    /// The store only keeps appointments; the booking's reminder SMS is
+    /// scheduled separately, and the team calls to confirm.
+    async fn send_confirmation(&self, id: &str) -> Result<(), IntegrationError> {
+        Err(IntegrationError::Internal(format!(
+            "No confirmation SMS is sent for appointment {}",
+            id
+        )))
+    }
+}
+
+fn store_error(e: PersistenceError) -> IntegrationError {
+    IntegrationError::Internal(e.to_string())
+}
+
+fn parse_day(date: &str) -> Result<NaiveDate, IntegrationError> {
+    NaiveDate::parse_from_str(date, "%Y-%m-%d")
+        .map_err(|_| IntegrationError::InvalidRequest(format!("Invalid date: {}", date)))
+}
+
+/// Whether two slot labels are the same clock time, e.g. "2:00 PM" and "02:00 PM"
+fn same_slot(a: &str, b: &str) -> bool {
+    match (parse_clock(a), parse_clock(b)) {
+        (Some(a), Some(b)) => a == b,
+        _ => a == b,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use voice_agent_persistence::InMemoryAppointmentStore;
+
+    fn calendar(store: Arc<InMemoryAppointmentStore>) -> StoreCalendar {
+        StoreCalendar::new(
+            store,
+            vec!["11:00 AM".to_string(), "2:00 PM".to_string()],
+            1,
+        )
+    }
+
+    fn appointment(phone: &str, time: &str) -> Appointment {
+        Appointment {
+            id: None,
+            customer_name: "Rahul".to_string(),
+            customer_phone: phone.to_string(),
+            branch_id: "KMBL001".to_string(),
+            date: "2030-01-07".to_string(),
+            time_slot: time.to_string(),
+            purpose: AppointmentPurpose::new("Consultation"),
+            notes: None,
+            status: AppointmentStatus::Scheduled,
+            confirmation_sent: false,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_bookings_persisted_and_slots_filled() {
+        let store = Arc::new(InMemoryAppointmentStore::new());
+        let calendar = calendar(store.clone());
+
+        let id = calendar
+            .schedule_appointment(appointment("+91 98765 43210", "11:00 AM"))
+            .await
+            .unwrap();
+        let stored = store.list_for_customer("9876543210", 10).await.unwrap();
+        assert_eq!(stored.len(), 1);
+        assert_eq!(stored[0].appointment_id.to_string(), id);
+
+        let slots = calendar
+            .get_available_slots("KMBL001", "2030-01-07")
+            .await
+            .unwrap();
+        assert!(!slots[0].available);
+        assert!(slots[1].available);
+        // The full slot can't be booked again
+        let full = calendar
+            .schedule_appointment(appointment("9123456780", "11:00 AM"))
+            .await;
+        assert!(matches!(full, Err(IntegrationError::InvalidRequest(_))));
+        assert!(calendar.send_confirmation(&id).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_reschedule_and_cancel_free_the_slot() {
+        let store = Arc::new(InMemoryAppointmentStore::new());
+        let calendar = calendar(store.clone());
+        let first = calendar
+            .schedule_appointment(appointment("9876543210", "11:00 AM"))
+            .await
+            .unwrap();
+        let second = calendar
+            .schedule_appointment(appointment("9123456780", "2:00 PM"))
+            .await
+            .unwrap();
+
+        // Both slots are taken, so neither appointment can move
+        let moved = calendar
+            .reschedule_appointment(&first, "2030-01-07", "2:00 PM")
+            .await;
+        assert!(matches!(moved, Err(IntegrationError::InvalidRequest(_))));
+        // Keeping its own slot doesn't count against it
+        calendar
+            .reschedule_appointment(&first, "2030-01-07", "11:00 AM")
+            .await
+            .unwrap();
+
+        calendar.cancel_appointment(&second).await.unwrap();
+        calendar
+            .reschedule_appointment(&first, "2030-01-07", "2:00 PM")
+            .await
+            .unwrap();
+        let moved = calendar.get_appointment(&first).await.unwrap();
+        assert_eq!(moved.time_slot, "2:00 PM");
+        assert!(matches!(moved.status, AppointmentStatus::Rescheduled));
+        assert!(matches!(
+            calendar.get_appointment("unknown").await,
+            Err(IntegrationError::NotFound(_))
+        ));
+    }
+}
@@ -200,7 +200,7 @@ impl ToolFactory for DomainToolFactory {
                     Ok(Arc::new(AppointmentSchedulerTool::with_view(self.view.clone())))
                 }
             }
-            "escalate_to_human" => Ok(Arc::new(EscalateToHumanTool::new())),
+            "escalate_to_human" => Ok(Arc::new(EscalateToHumanTool::with_view(self.view.clone()))),
             // P16 FIX: SMS and Document tools now use view for config-driven content
             "send_sms" => Ok(Arc::new(SendSmsTool::with_view(self.view.clone()))),
             "get_document_checklist" => Ok(Arc::new(DocumentChecklistTool::with_view(self.view.clone()))),
@@ -0,0 +1,285 @@
+//! Operating Hours
+//!
+//! Weekly opening hours and holidays for service locations.
+//! Times are local to the location; Indian locations use IST.
+
+use chrono::{DateTime, Datelike, FixedOffset, NaiveDate, NaiveTime, TimeZone, Timelike, Weekday};
+use serde::{Deserialize, Serialize};
+
+/// India Standard Time (UTC+05:30), the default time zone for locations
+pub fn india_timezone() -> FixedOffset {
+    FixedOffset::east_opt(5 * 3600 + 30 * 60).expect("IST offset is valid")
+}
+
+/// Opening and closing time on a set of weekdays
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DayHours {
+    pub days: Vec<Weekday>,
+    pub open: NaiveTime,
+    pub close: NaiveTime,
+}
+
+/// Weekly schedule plus holidays for a location
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct OperatingHours {
+    /// Weekdays not listed here are closed
+    #[serde(default)]
+    pub weekly: Vec<DayHours>,
+    /// Dates the location is closed regardless of the weekly schedule
+    #[serde(default)]
+    pub holidays: Vec<NaiveDate>,
+}
+
+/// Why a location is closed at a given time
+#[derive(Debug, Clone, PartialEq)]
+pub enum Closure {
+    Holiday(NaiveDate),
+    ClosedOnDay(Weekday),
+    BeforeOpening(NaiveTime),
+    AfterClosing(NaiveTime),
+}
+
+impl Closure {
+    /// Customer-facing explanation, e.g. "This branch opens at 10am."
+    pub fn message(&self) -> String {
+        match self {
+            Self::Holiday(date) => {
+                format!(
+                    "This branch is closed on {} for a holiday.",
+                    date.format("%d %B")
+                )
+            },
+            Self::ClosedOnDay(day) => {
+                format!("This branch is closed on {}s.", weekday_name(*day))
+            },
+            Self::BeforeOpening(open) => format!("This branch opens at {}.", format_clock(*open)),
+            Self::AfterClosing(close) => format!("This branch closes at {}.", format_clock(*close)),
+        }
+    }
+}
+
+impl OperatingHours {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Open from `open` until `close` on the given weekdays
+    pub fn with_days(mut self, days: &[Weekday], open: NaiveTime, close: NaiveTime) -> Self {
+        self.weekly.push(DayHours {
+            days: days.to_vec(),
+            open,
+            close,
+        });
+        self
+    }
+
+    pub fn with_holiday(mut self, date: NaiveDate) -> Self {
+        self.holidays.push(date);
+        self
+    }
+
+    /// Parse a display string such as "10:00 AM - 5:00 PM (Mon-Sat)"
+    ///
+    /// Without a day list the hours apply to every day of the week.
+    pub fn parse(timing: &str) -> Option<Self> {
+        let (times, days) = match timing.split_once('(') {
+            Some((times, days)) => (times, Some(days.trim_end_matches(')'))),
+            None => (timing, None),
+        };
+
+        let (open, close) = times.split_once('-')?;
+        let open = parse_clock(open)?;
+        let close = parse_clock(close)?;
+        let days = match days {
+            Some(days) => parse_days(days)?,
+            None => all_days(),
+        };
+
+        Some(Self::new().with_days(&days, open, close))
+    }
+
+    /// Hours that apply on the given weekday, if open that day
+    pub fn hours_on(&self, day: Weekday) -> Option<&DayHours> {
+        self.weekly.iter().find(|h| h.days.contains(&day))
+    }
+
+    /// Check a local date and time, explaining why the location is closed
+    pub fn check(&self, date: NaiveDate, time: NaiveTime) -> Result<(), Closure> {
+        if self.holidays.contains(&date) {
+            return Err(Closure::Holiday(date));
+        }
+
+        let day = date.weekday();
+        let hours = self.hours_on(day).ok_or(Closure::ClosedOnDay(day))?;
+        if time < hours.open {
+            Err(Closure::BeforeOpening(hours.open))
+        } else if time >= hours.close {
+            Err(Closure::AfterClosing(hours.close))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Whether the location is open at the given instant, in its time zone `tz`
+    pub fn is_open_at<Tz: TimeZone>(&self, at: &DateTime<Tz>, tz: &FixedOffset) -> bool {
+        let local = at.with_timezone(tz);
+        self.check(local.date_naive(), local.time()).is_ok()
+    }
+}
+
+/// Parse "10:00 AM", "10 AM" or "17:30"
+pub fn parse_clock(s: &str) -> Option<NaiveTime> {
+    let s = s.trim().to_uppercase().replace(' ', "");
+    // chrono needs minutes, so "10AM" becomes "10:00AM"
+    let s = if s.contains(':') {
+        s
+    } else {
+        s.replacen("AM", ":00AM", 1).replacen("PM", ":00PM", 1)
+    };
+    ["%I:%M%p", "%H:%M"]
+        .iter()
+        .find_map(|fmt| NaiveTime::parse_from_str(&s, fmt).ok())
+}
+
+/// Spoken-style time: 10:00 -> "10am", 17:30 -> "5:30pm"
+pub fn format_clock(time: NaiveTime) -> String {
+    let (pm, hour) = time.hour12();
+    let suffix = if pm { "pm" } else { "am" };
+    if time.minute() == 0 {
+        format!("{}{}", hour, suffix)
+    } else {
+        format!("{}:{:02}{}", hour, time.minute(), suffix)
+    }
+}
+
+/// Parse "Mon-Sat" or "Mon, Wed, Fri"
+fn parse_days(s: &str) -> Option<Vec<Weekday>> {
+    let mut days = Vec::new();
+    for part in s.split(',') {
+        match part.split_once('-') {
+            Some((start, end)) => {
+                let mut day: Weekday = start.trim().parse().ok()?;
+                let end: Weekday = end.trim().parse().ok()?;
+                days.push(day);
+                while day != end {
+                    day = day.succ();
+                    days.push(day);
+                }
+            },
+            None => days.push(part.trim().parse().ok()?),
+        }
+    }
+    Some(days)
+}
+
+fn all_days() -> Vec<Weekday> {
+    vec![
+        Weekday::Mon,
+        Weekday::Tue,
+        Weekday::Wed,
+        Weekday::Thu,
+        Weekday::Fri,
+        Weekday::Sat,
+        Weekday::Sun,
+    ]
+}
+
+fn weekday_name(day: Weekday) -> &'static str {
+    match day {
+        Weekday::Mon => "Monday",
+        Weekday::Tue => "Tuesday",
+        Weekday::Wed => "Wednesday",
+        Weekday::Thu => "Thursday",
+        Weekday::Fri => "Friday",
+        Weekday::Sat => "Saturday",
+        Weekday::Sun => "Sunday",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+
+    fn time(h: u32, m: u32) -> NaiveTime {
+        NaiveTime::from_hms_opt(h, m, 0).unwrap()
+    }
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    fn utc(y: i32, mo: u32, d: u32, h: u32, mi: u32) -> DateTime<Utc> {
+        date(y, mo, d).and_hms_opt(h, mi, 0).unwrap().and_utc()
+    }
+
+    #[test]
+    fn test_parse_timing() {
+        let hours = OperatingHours::parse("10:00 AM - 5:00 PM (Mon-Sat)").unwrap();
+        let mon_sat = hours.hours_on(Weekday::Sat).unwrap();
+        assert_eq!(mon_sat.open, time(10, 0));
+        assert_eq!(mon_sat.close, time(17, 0));
+        assert!(hours.hours_on(Weekday::Sun).is_none());
+
+        let every_day = OperatingHours::parse("9:30 AM - 1 PM").unwrap();
+        assert!(every_day.hours_on(Weekday::Sun).is_some());
+        assert!(OperatingHours::parse("By appointment").is_none());
+    }
+
+    #[test]
+    fn test_open_in_hours() {
+        let hours = OperatingHours::parse("10:00 AM - 5:00 PM (Mon-Sat)").unwrap();
+        let ist = india_timezone();
+
+        // Friday 2026-10-16 05:00 UTC is 10:30 IST
+        assert!(hours.is_open_at(&utc(2026, 10, 16, 5, 0), &ist));
+        // 11:29 UTC is 16:59 IST
+        assert!(hours.is_open_at(&utc(2026, 10, 16, 11, 29), &ist));
+    }
+
+    #[test]
+    fn test_closed_out_of_hours() {
+        let hours = OperatingHours::parse("10:00 AM - 5:00 PM (Mon-Sat)")
+            .unwrap()
+            .with_holiday(date(2026, 10, 20));
+        let ist = india_timezone();
+
+        // 04:00 UTC is 09:30 IST, open in UTC terms but not in India
+        assert!(!hours.is_open_at(&utc(2026, 10, 16, 4, 0), &ist));
+        // 11:30 UTC is 17:00 IST, closing time
+        assert!(!hours.is_open_at(&utc(2026, 10, 16, 11, 30), &ist));
+        // Sunday
+        assert!(!hours.is_open_at(&utc(2026, 10, 18, 6, 0), &ist));
+        // Holiday on a Tuesday
+        assert!(!hours.is_open_at(&utc(2026, 10, 20, 6, 0), &ist));
+
+        assert_eq!(
+            hours.check(date(2026, 10, 16), time(9, 0)),
+            Err(Closure::BeforeOpening(time(10, 0)))
+        );
+        assert_eq!(
+            hours.check(date(2026, 10, 18), time(11, 0)),
+            Err(Closure::ClosedOnDay(Weekday::Sun))
+        );
+        assert_eq!(
+            hours.check(date(2026, 10, 20), time(11, 0)),
+            Err(Closure::Holiday(date(2026, 10, 20)))
+        );
+    }
+
+    #[test]
+    fn test_closure_messages() {
+        assert_eq!(
+            Closure::BeforeOpening(time(10, 0)).message(),
+            "This branch opens at 10am."
+        );
+        assert_eq!(
+            Closure::AfterClosing(time(17, 30)).message(),
+            "This branch closes at 5:30pm."
+        );
+        assert_eq!(
+            Closure::ClosedOnDay(Weekday::Sun).message(),
+            "This branch is closed on Sundays."
+        );
+    }
+}
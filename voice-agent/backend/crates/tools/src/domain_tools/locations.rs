@@ -3,11 +3,15 @@
 //! Handles loading and managing service location data for domain tools.
 //! This is domain-agnostic - the actual locations come from domain config.
 
+use chrono::{DateTime, FixedOffset, TimeZone};
 use once_cell::sync::Lazy;
 use parking_lot::RwLock;
 use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
 use std::path::{Path, PathBuf};
 
+use super::hours::OperatingHours;
+
 /// Location/branch data structure for service locations
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BranchData {
@@ -25,8 +29,163 @@ pub struct BranchData {
     pub timing: String,
     #[serde(default)]
     pub facilities: Vec<String>,
+    /// Geographic location, used to sort results by distance
+    #[serde(default)]
+    pub coordinates: Option<Coordinates>,
+    /// Whether staff from this location can visit the customer
+    #[serde(default)]
+    pub doorstep_available: bool,
+    /// Structured weekly hours and holidays (falls back to parsing `timing`)
+    #[serde(default)]
+    pub operating_hours: Option<OperatingHours>,
+}
+
+impl BranchData {
+    /// Distance in km from the given point, if this location has coordinates
+    pub fn distance_from(&self, origin: &Coordinates) -> Option<f64> {
+        self.coordinates.map(|c| c.distance_km(origin))
+    }
+
+    /// Operating hours from config, or parsed from the display timing
+    pub fn hours(&self) -> Option<OperatingHours> {
+        self.operating_hours
+            .clone()
+            .or_else(|| OperatingHours::parse(&self.timing))
+    }
+
+    /// Whether the location is open at the given instant in time zone `tz`
+    ///
+    /// Locations whose hours are unknown are treated as open.
+    pub fn is_open_at<Tz: TimeZone>(&self, at: &DateTime<Tz>, tz: &FixedOffset) -> bool {
+        self.hours().map(|h| h.is_open_at(at, tz)).unwrap_or(true)
+    }
+}
+
+/// Mean Earth radius used by the haversine formula
+const EARTH_RADIUS_KM: f64 = 6371.0;
+
+/// Latitude/longitude in decimal degrees
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Coordinates {
+    pub latitude: f64,
+    pub longitude: f64,
+}
+
+impl Coordinates {
+    pub fn new(latitude: f64, longitude: f64) -> Self {
+        Self {
+            latitude,
+            longitude,
+        }
+    }
+
+    /// Great-circle (haversine) distance in km
+    pub fn distance_km(&self, other: &Coordinates) -> f64 {
+        let d_lat = (other.latitude - self.latitude).to_radians();
+        let d_lon = (other.longitude - self.longitude).to_radians();
+        let a = (d_lat / 2.0).sin().powi(2)
+            + self.latitude.to_radians().cos()
+                * other.latitude.to_radians().cos()
+                * (d_lon / 2.0).sin().powi(2);
+        2.0 * EARTH_RADIUS_KM * a.sqrt().asin()
+    }
+}
+
+/// City and state that a PIN code prefix belongs to
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PincodeRegion {
+    /// PIN code prefix (the first 3 digits identify the sorting district)
+    pub prefix: String,
+    pub city: String,
+    #[serde(default)]
+    pub state: String,
+    /// Approximate centre of the region, used as the user's location
+    #[serde(default)]
+    pub centroid: Option<Coordinates>,
+}
+
+impl PincodeRegion {
+    pub fn new(prefix: &str, city: &str, state: &str) -> Self {
+        Self {
+            prefix: prefix.to_string(),
+            city: city.to_string(),
+            state: state.to_string(),
+            centroid: None,
+        }
+    }
+
+    pub fn with_centroid(mut self, latitude: f64, longitude: f64) -> Self {
+        self.centroid = Some(Coordinates::new(latitude, longitude));
+        self
+    }
+}
+
+impl From<&voice_agent_config::PincodeRegionEntry> for PincodeRegion {
+    fn from(entry: &voice_agent_config::PincodeRegionEntry) -> Self {
+        Self {
+            prefix: entry.prefix.clone(),
+            city: entry.city.clone(),
+            state: entry.state.clone(),
+            centroid: entry
+                .latitude
+                .zip(entry.longitude)
+                .map(|(latitude, longitude)| Coordinates::new(latitude, longitude)),
+        }
+    }
 }
 
+/// Bundled PIN code prefixes for major cities: (prefix, city, state, latitude, longitude)
+///
+/// Longer prefixes win, so "2013" (Noida) overrides "201" (Ghaziabad).
+const DEFAULT_PINCODE_REGIONS: &[(&str, &str, &str, f64, f64)] = &[
+    ("110", "Delhi", "Delhi", 28.6139, 77.2090),
+    ("122", "Gurgaon", "Haryana", 28.4595, 77.0266),
+    ("141", "Ludhiana", "Punjab", 30.9010, 75.8573),
+    ("143", "Amritsar", "Punjab", 31.6340, 74.8723),
+    ("160", "Chandigarh", "Chandigarh", 30.7333, 76.7794),
+    ("201", "Ghaziabad", "Uttar Pradesh", 28.6692, 77.4538),
+    ("2013", "Noida", "Uttar Pradesh", 28.5355, 77.3910),
+    ("208", "Kanpur", "Uttar Pradesh", 26.4499, 80.3319),
+    ("221", "Varanasi", "Uttar Pradesh", 25.3176, 82.9739),
+    ("226", "Lucknow", "Uttar Pradesh", 26.8467, 80.9462),
+    ("302", "Jaipur", "Rajasthan", 26.9124, 75.7873),
+    ("380", "Ahmedabad", "Gujarat", 23.0225, 72.5714),
+    ("390", "Vadodara", "Gujarat", 22.3072, 73.1812),
+    ("395", "Surat", "Gujarat", 21.1702, 72.8311),
+    ("400", "Mumbai", "Maharashtra", 19.0760, 72.8777),
+    ("4006", "Thane", "Maharashtra", 19.2183, 72.9781),
+    ("411", "Pune", "Maharashtra", 18.5204, 73.8567),
+    ("440", "Nagpur", "Maharashtra", 21.1458, 79.0882),
+    ("452", "Indore", "Madhya Pradesh", 22.7196, 75.8577),
+    ("462", "Bhopal", "Madhya Pradesh", 23.2599, 77.4126),
+    ("500", "Hyderabad", "Telangana", 17.3850, 78.4867),
+    ("520", "Vijayawada", "Andhra Pradesh", 16.5062, 80.6480),
+    ("530", "Visakhapatnam", "Andhra Pradesh", 17.6868, 83.2185),
+    ("560", "Bangalore", "Karnataka", 12.9716, 77.5946),
+    ("570", "Mysore", "Karnataka", 12.2958, 76.6394),
+    ("600", "Chennai", "Tamil Nadu", 13.0827, 80.2707),
+    ("625", "Madurai", "Tamil Nadu", 9.9252, 78.1198),
+    ("641", "Coimbatore", "Tamil Nadu", 11.0168, 76.9558),
+    ("682", "Kochi", "Kerala", 9.9312, 76.2673),
+    ("695", "Thiruvananthapuram", "Kerala", 8.5241, 76.9366),
+    ("700", "Kolkata", "West Bengal", 22.5726, 88.3639),
+    ("751", "Bhubaneswar", "Odisha", 20.2961, 85.8245),
+    ("781", "Guwahati", "Assam", 26.1445, 91.7362),
+    ("800", "Patna", "Bihar", 25.5941, 85.1376),
+];
+
+/// PIN code table used by `resolve_pincode` (bundled defaults until replaced from config)
+static PINCODE_REGIONS: Lazy<RwLock<Vec<PincodeRegion>>> = Lazy::new(|| {
+    RwLock::new(
+        DEFAULT_PINCODE_REGIONS
+            .iter()
+            .map(|(prefix, city, state, lat, lon)| {
+                PincodeRegion::new(prefix, city, state).with_centroid(*lat, *lon)
+            })
+            .collect(),
+    )
+});
+
 /// Branch data file structure
 #[derive(Debug, Deserialize)]
 struct BranchDataFile {
@@ -111,6 +270,54 @@ pub fn initialize_locations(locations: Vec<BranchData>) {
     tracing::info!("Initialized {} service locations from config", count);
 }
 
+/// Replace the PIN code table (e.g. with a domain's own region list)
+pub fn initialize_pincode_regions(regions: Vec<PincodeRegion>) {
+    let count = regions.len();
+    *PINCODE_REGIONS.write() = regions;
+    tracing::info!("Initialized {} PIN code regions", count);
+}
+
+/// Resolve a 6-digit PIN code to the city it belongs to
+///
+/// A branch with that exact PIN code wins, so the city matches the branch
+/// data; otherwise the longest matching prefix in the PIN code table is used.
+/// Returns None for malformed or unknown PIN codes.
+pub fn resolve_pincode(pincode: &str) -> Option<PincodeRegion> {
+    resolve_pincode_in(pincode, &get_branches(), &PINCODE_REGIONS.read())
+}
+
+fn resolve_pincode_in(
+    pincode: &str,
+    branches: &[BranchData],
+    regions: &[PincodeRegion],
+) -> Option<PincodeRegion> {
+    let pincode = pincode.trim();
+    let well_formed = pincode.len() == 6
+        && pincode.chars().all(|c| c.is_ascii_digit())
+        && !pincode.starts_with('0');
+    if !well_formed {
+        return None;
+    }
+
+    let region = regions
+        .iter()
+        .filter(|r| pincode.starts_with(r.prefix.as_str()))
+        .max_by_key(|r| r.prefix.len());
+
+    if let Some(branch) = branches.iter().find(|b| b.pincode == pincode) {
+        return Some(PincodeRegion {
+            prefix: pincode.to_string(),
+            city: branch.city.clone(),
+            state: region.map(|r| r.state.clone()).unwrap_or_default(),
+            centroid: branch
+                .coordinates
+                .or_else(|| region.and_then(|r| r.centroid)),
+        });
+    }
+
+    region.cloned()
+}
+
 /// Find service locations by criteria
 ///
 /// Filters locations by city, pincode, and/or area.
@@ -158,3 +365,174 @@ pub fn find_locations(
         .collect()
 }
 
+/// Sort locations nearest-first from the given point
+///
+/// Locations without coordinates keep their relative order after the rest.
+pub fn sort_by_distance(locations: &mut [BranchData], origin: &Coordinates) {
+    locations.sort_by(|a, b| {
+        let (da, db) = (a.distance_from(origin), b.distance_from(origin));
+        match (da, db) {
+            (Some(da), Some(db)) => da.total_cmp(&db),
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => Ordering::Equal,
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn branch(id: &str, city: &str, pincode: &str) -> BranchData {
+        BranchData {
+            branch_id: id.to_string(),
+            name: format!("{} Branch", city),
+            city: city.to_string(),
+            area: "Central".to_string(),
+            address: format!("Main Road, {} - {}", city, pincode),
+            pincode: pincode.to_string(),
+            phone: "022-12345678".to_string(),
+            service_available: true,
+            timing: "10:00 AM - 5:00 PM".to_string(),
+            facilities: vec![],
+            coordinates: None,
+            doorstep_available: false,
+            operating_hours: None,
+        }
+    }
+
+    fn located(id: &str, latitude: f64, longitude: f64) -> BranchData {
+        BranchData {
+            coordinates: Some(Coordinates::new(latitude, longitude)),
+            ..branch(id, "Mumbai", "400001")
+        }
+    }
+
+    fn regions() -> Vec<PincodeRegion> {
+        DEFAULT_PINCODE_REGIONS
+            .iter()
+            .map(|(prefix, city, state, lat, lon)| {
+                PincodeRegion::new(prefix, city, state).with_centroid(*lat, *lon)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_resolve_known_pincode() {
+        let region = resolve_pincode_in("560034", &[], &regions()).unwrap();
+        assert_eq!(region.city, "Bangalore");
+        assert_eq!(region.state, "Karnataka");
+
+        // Longest prefix wins
+        let region = resolve_pincode_in("201301", &[], &regions()).unwrap();
+        assert_eq!(region.city, "Noida");
+    }
+
+    #[test]
+    fn test_resolve_prefers_branch_city() {
+        let branches = vec![branch("B1", "Navi Mumbai", "400703")];
+
+        let region = resolve_pincode_in("400703", &branches, &regions()).unwrap();
+        assert_eq!(region.city, "Navi Mumbai");
+        assert_eq!(region.state, "Maharashtra");
+    }
+
+    #[test]
+    fn test_resolve_unknown_or_malformed_pincode() {
+        assert!(resolve_pincode_in("999999", &[], &regions()).is_none());
+        assert!(resolve_pincode_in("056001", &[], &regions()).is_none());
+        assert!(resolve_pincode_in("5600", &[], &regions()).is_none());
+        assert!(resolve_pincode_in("56003a", &[], &regions()).is_none());
+    }
+
+    #[test]
+    fn test_haversine_distance() {
+        let mumbai = Coordinates::new(19.0760, 72.8777);
+        let pune = Coordinates::new(18.5204, 73.8567);
+
+        let distance = mumbai.distance_km(&pune);
+        assert!((distance - 120.0).abs() < 2.0, "got {}", distance);
+        assert_eq!(mumbai.distance_km(&mumbai), 0.0);
+    }
+
+    #[test]
+    fn test_sort_by_distance_ascending() {
+        // Reference point: Mumbai city centroid
+        let origin = Coordinates::new(19.0760, 72.8777);
+        let mut branches = vec![
+            located("THANE", 19.2183, 72.9781),
+            branch("UNKNOWN", "Mumbai", "400002"),
+            located("FORT", 18.9345, 72.8356),
+            located("BANDRA", 19.0596, 72.8295),
+        ];
+
+        sort_by_distance(&mut branches, &origin);
+
+        let ids: Vec<&str> = branches.iter().map(|b| b.branch_id.as_str()).collect();
+        assert_eq!(ids, vec!["BANDRA", "FORT", "THANE", "UNKNOWN"]);
+
+        let distances: Vec<f64> = branches
+            .iter()
+            .filter_map(|b| b.distance_from(&origin))
+            .collect();
+        assert!(distances.windows(2).all(|w| w[0] <= w[1]));
+    }
+
+    #[test]
+    fn test_pincode_centroid() {
+        let region = resolve_pincode_in("411014", &[], &regions()).unwrap();
+        assert_eq!(region.centroid, Some(Coordinates::new(18.5204, 73.8567)));
+
+        // An exact branch match uses the branch's own coordinates
+        let branches = vec![located("B1", 19.0596, 72.8295)];
+        let region = resolve_pincode_in("400001", &branches, &regions()).unwrap();
+        assert_eq!(region.centroid, Some(Coordinates::new(19.0596, 72.8295)));
+    }
+
+    #[test]
+    fn test_pincode_region_from_config() {
+        let entry = voice_agent_config::PincodeRegionEntry {
+            prefix: "411".to_string(),
+            city: "Pune".to_string(),
+            state: "Maharashtra".to_string(),
+            latitude: Some(18.5204),
+            longitude: Some(73.8567),
+        };
+        let region = PincodeRegion::from(&entry);
+        assert_eq!(
+            region,
+            PincodeRegion::new("411", "Pune", "Maharashtra").with_centroid(18.5204, 73.8567)
+        );
+
+        // Without both coordinates there is no centroid
+        let entry = voice_agent_config::PincodeRegionEntry {
+            longitude: None,
+            ..entry
+        };
+        assert!(PincodeRegion::from(&entry).centroid.is_none());
+    }
+
+    #[test]
+    fn test_branch_is_open_at() {
+        use super::super::hours::india_timezone;
+        use chrono::{NaiveDate, Utc};
+
+        let at = |h, m| -> DateTime<Utc> {
+            NaiveDate::from_ymd_opt(2026, 10, 16)
+                .unwrap()
+                .and_hms_opt(h, m, 0)
+                .unwrap()
+                .and_utc()
+        };
+        let mut b = branch("B1", "Mumbai", "400001");
+
+        // Parsed from "10:00 AM - 5:00 PM"; 06:00 UTC is 11:30 IST
+        assert!(b.is_open_at(&at(6, 0), &india_timezone()));
+        assert!(!b.is_open_at(&at(13, 0), &india_timezone()));
+
+        // Unknown hours are not treated as closed
+        b.timing = "Call for timings".to_string();
+        assert!(b.is_open_at(&at(13, 0), &india_timezone()));
+    }
+}
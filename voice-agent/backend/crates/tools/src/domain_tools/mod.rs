@@ -6,23 +6,39 @@
 //! This module is organized into:
 //! - `utils`: Financial calculations (EMI, interest)
 //! - `locations`: Location/branch data management
+//! - `hours`: Operating hours and holidays for locations
+//! - `reminders`: Appointment reminder SMS
+//! - `calendar`: Calendar backed by the appointment store
 //! - `tools`: MCP tool implementations
 
+mod calendar;
+mod hours;
 mod locations;
+mod reminders;
 mod tools;
 mod utils;
 
 // Re-export utilities
 pub use utils::{calculate_emi, calculate_total_interest};
 
+// Re-export operating hours
+pub use hours::{india_timezone, Closure, DayHours, OperatingHours};
+
+// Re-export appointment reminders
+pub use reminders::{AppointmentReminders, ReminderDetails};
+
+// Re-export the persisted appointment calendar
+pub use calendar::StoreCalendar;
+
 // Re-export location management
 pub use locations::{
     get_branches, find_locations, load_branches_from_file, reload_branches, BranchData,
+    initialize_pincode_regions, resolve_pincode, sort_by_distance, Coordinates, PincodeRegion,
 };
 
 // Re-export all tools
 pub use tools::{
-    AppointmentSchedulerTool, BranchLocatorTool, CompetitorComparisonTool, DocumentChecklistTool,
-    EligibilityCheckTool, EscalateToHumanTool, GetGoldPriceTool, LeadCaptureTool,
-    SavingsCalculatorTool, SendSmsTool,
+    appointment_slots, AppointmentChangeTool, AppointmentSchedulerTool, BranchLocatorTool,
+    CompetitorComparisonTool, DeferredEscalation, DocumentChecklistTool, EligibilityCheckTool,
+    EscalateToHumanTool, GetGoldPriceTool, LeadCaptureTool, SavingsCalculatorTool, SendSmsTool,
 };
@@ -0,0 +1,126 @@
+//! Appointment reminder SMS
+//!
+//! When an appointment is booked, a reminder is scheduled to go out a lead
+//! time before it (branch local time). The reminder is persisted in a
+//! `ScheduledMessageStore` and sent by the `ReminderDispatcher` worker.
+//! Reminders are keyed by appointment ID, so cancelling or moving the
+//! appointment cancels them.
+
+use chrono::{DateTime, FixedOffset, NaiveDate, Utc};
+use std::sync::Arc;
+use std::time::Duration;
+
+use voice_agent_persistence::{
+    PersistenceError, ScheduledMessage, ScheduledMessageStore, SimulatedSmsService,
+    SmsBrandContext, SmsType,
+};
+
+use super::hours::{india_timezone, parse_clock};
+
+/// Appointment being reminded about
+#[derive(Debug, Clone)]
+pub struct ReminderDetails<'a> {
+    pub appointment_id: &'a str,
+    pub customer_name: &'a str,
+    pub phone: &'a str,
+    pub branch_name: &'a str,
+    pub date: NaiveDate,
+    /// Time slot, e.g. "11:00 AM"
+    pub time: &'a str,
+}
+
+/// Schedules and cancels appointment reminder SMS
+pub struct AppointmentReminders {
+    store: Arc<dyn ScheduledMessageStore>,
+    lead_time: chrono::Duration,
+    brand: SmsBrandContext,
+    /// Time zone the appointment slots are in
+    tz: FixedOffset,
+}
+
+impl AppointmentReminders {
+    pub fn new(store: Arc<dyn ScheduledMessageStore>, lead_time: Duration) -> Self {
+        Self {
+            store,
+            lead_time: chrono::Duration::from_std(lead_time)
+                .unwrap_or_else(|_| chrono::Duration::days(1)),
+            brand: SmsBrandContext::default(),
+            tz: india_timezone(),
+        }
+    }
+
+    /// Brand names used in the reminder text
+    pub fn with_brand(mut self, brand: SmsBrandContext) -> Self {
+        self.brand = brand;
+        self
+    }
+
+    /// When an appointment starts; None if the time slot can't be parsed
+    fn starts_at(&self, date: NaiveDate, time: &str) -> Option<DateTime<Utc>> {
+        let starts_at = date
+            .and_time(parse_clock(time)?)
+            .and_local_timezone(self.tz)
+            .single()?;
+        Some(starts_at.with_timezone(&Utc))
+    }
+
+    /// When the reminder for an appointment goes out
+    ///
+    /// None if the time slot can't be parsed.
+    pub fn send_at(&self, date: NaiveDate, time: &str) -> Option<DateTime<Utc>> {
+        Some(self.starts_at(date, time)? - self.lead_time)
+    }
+
+    /// Schedule the reminder for a booked appointment
+    ///
+    /// Returns when it will be sent, or None if that time has already
+    /// passed at `now` (the appointment is sooner than the lead time).
+    /// A reminder still unsent when the appointment starts is dropped.
+    pub async fn schedule(
+        &self,
+        appointment: &ReminderDetails<'_>,
+        now: DateTime<Utc>,
+    ) -> Result<Option<DateTime<Utc>>, PersistenceError> {
+        let Some(starts_at) = self.starts_at(appointment.date, appointment.time) else {
+            tracing::warn!(time = %appointment.time, "Unparseable time slot, no reminder");
+            return Ok(None);
+        };
+        let send_at = starts_at - self.lead_time;
+        if send_at <= now {
+            return Ok(None);
+        }
+
+        let text = SimulatedSmsService::format_appointment_reminder(
+            appointment.customer_name,
+            &appointment.date.format("%Y-%m-%d").to_string(),
+            appointment.time,
+            appointment.branch_name,
+            &self.brand,
+        );
+        let message = ScheduledMessage::new(
+            appointment.appointment_id,
+            appointment.phone,
+            &text,
+            SmsType::AppointmentReminder,
+            send_at,
+        )
+        .with_expiry(starts_at);
+        self.store.schedule(&message).await?;
+        Ok(Some(send_at))
+    }
+
+    /// Cancel reminders not yet sent for an appointment
+    pub async fn cancel(&self, appointment_id: &str) -> Result<usize, PersistenceError> {
+        self.store.cancel_for_reference(appointment_id).await
+    }
+
+    /// Replace an appointment's reminder after it moves
+    pub async fn reschedule(
+        &self,
+        appointment: &ReminderDetails<'_>,
+        now: DateTime<Utc>,
+    ) -> Result<Option<DateTime<Utc>>, PersistenceError> {
+        self.cancel(appointment.appointment_id).await?;
+        self.schedule(appointment, now).await
+    }
+}
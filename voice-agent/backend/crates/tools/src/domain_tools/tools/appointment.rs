@@ -2,28 +2,43 @@
 //!
 //! Schedule branch visit appointments.
 //! P16 FIX: Purposes and time slots are now config-driven via ToolsDomainView.
+//! With reminders set, a reminder SMS is scheduled for each booking.
+//!
+//! `AppointmentChangeTool` reschedules and cancels appointments booked
+//! through the calendar, by appointment ID, so a customer can hold several
+//! appointments and move any one of them. Only the number an appointment
+//! was booked with can change it. A new slot is re-checked against the
+//! branch's hours and the calendar's availability before it is taken.
 
 use async_trait::async_trait;
-use chrono::{NaiveDate, Utc};
+use chrono::{Datelike, NaiveDate, Utc};
 use serde_json::{json, Value};
 use std::sync::Arc;
 
 use voice_agent_config::ToolsDomainView;
 
 use crate::integrations::{
-    Appointment, AppointmentPurpose, AppointmentStatus, CalendarIntegration,
+    normalize_phone, Appointment, AppointmentPurpose, AppointmentStatus, CalendarIntegration,
+    IntegrationError, TimeSlot,
 };
 use crate::mcp::{InputSchema, PropertySchema, Tool, ToolError, ToolOutput, ToolSchema};
 
+use super::super::hours::{format_clock, parse_clock};
+use super::super::locations::{get_branches, BranchData};
+use super::super::reminders::{AppointmentReminders, ReminderDetails};
+
 /// Appointment scheduler tool
 ///
 /// P16 FIX: Now uses ToolsDomainView for:
 /// - Time slots from config
 /// - Purposes from config (no hardcoded domain-specific terms)
+#[derive(Clone)]
 pub struct AppointmentSchedulerTool {
     calendar: Option<Arc<dyn CalendarIntegration>>,
     /// P16 FIX: Domain view for config-driven values
     view: Option<Arc<ToolsDomainView>>,
+    /// Reminder SMS scheduled for each booking
+    reminders: Option<Arc<AppointmentReminders>>,
 }
 
 impl AppointmentSchedulerTool {
@@ -31,6 +46,7 @@ impl AppointmentSchedulerTool {
         Self {
             calendar: None,
             view: None,
+            reminders: None,
         }
     }
 
@@ -39,6 +55,7 @@ impl AppointmentSchedulerTool {
         Self {
             calendar: None,
             view: Some(view),
+            reminders: None,
         }
     }
 
@@ -46,6 +63,7 @@ impl AppointmentSchedulerTool {
         Self {
             calendar: Some(calendar),
             view: None,
+            reminders: None,
         }
     }
 
@@ -57,35 +75,55 @@ impl AppointmentSchedulerTool {
         Self {
             calendar: Some(calendar),
             view: Some(view),
+            reminders: None,
+        }
+    }
+
+    /// Schedule a reminder SMS for each booking
+    pub fn with_reminders(mut self, reminders: Arc<AppointmentReminders>) -> Self {
+        self.reminders = Some(reminders);
+        self
+    }
+
+    /// Schedule the appointment's reminder, returning when it goes out
+    ///
+    /// Replaces any reminder from before the appointment moved. A failure
+    /// to schedule doesn't fail the booking.
+    async fn schedule_reminder(
+        &self,
+        appointment_id: &str,
+        name: &str,
+        phone: &str,
+        branch_id: &str,
+        date: NaiveDate,
+        time: &str,
+    ) -> Option<String> {
+        let reminders = self.reminders.as_ref()?;
+        let branch_name = get_branches()
+            .into_iter()
+            .find(|b| b.branch_id.eq_ignore_ascii_case(branch_id))
+            .map(|b| b.name)
+            .unwrap_or_else(|| branch_id.to_string());
+        let details = ReminderDetails {
+            appointment_id,
+            customer_name: name,
+            phone,
+            branch_name: &branch_name,
+            date,
+            time,
+        };
+        match reminders.reschedule(&details, Utc::now()).await {
+            Ok(send_at) => send_at.map(|at| at.to_rfc3339()),
+            Err(e) => {
+                tracing::warn!(appointment_id, error = %e, "Failed to schedule reminder SMS");
+                None
+            },
         }
     }
 
     /// Get time slots from config or defaults
     fn time_slots(&self) -> Vec<String> {
-        if let Some(ref view) = self.view {
-            if let Some(tool) = view.get_tool("schedule_appointment") {
-                // Find the preferred_time parameter and get its enum values
-                for param in &tool.parameters {
-                    if param.name == "preferred_time" {
-                        if let Some(ref values) = param.enum_values {
-                            if !values.is_empty() {
-                                return values.clone();
-                            }
-                        }
-                    }
-                }
-            }
-        }
-        // Default time slots (generic)
-        vec![
-            "10:00 AM".to_string(),
-            "11:00 AM".to_string(),
-            "12:00 PM".to_string(),
-            "2:00 PM".to_string(),
-            "3:00 PM".to_string(),
-            "4:00 PM".to_string(),
-            "5:00 PM".to_string(),
-        ]
+        appointment_slots(self.view.as_deref())
     }
 
     /// Get appointment purposes from config or defaults
@@ -125,6 +163,91 @@ impl AppointmentSchedulerTool {
         "Schedule a branch visit appointment"
     }
 
+    /// Reject a slot outside the branch's operating hours
+    ///
+    /// Returns the tool output to send instead of booking, listing the
+    /// configured slots that fall within that day's hours. Unknown branches,
+    /// hours or slot formats are not rejected.
+    fn outside_hours_response(
+        &self,
+        branches: &[BranchData],
+        branch_id: &str,
+        date: NaiveDate,
+        time: &str,
+    ) -> Option<Value> {
+        let branch = branches
+            .iter()
+            .find(|b| b.branch_id.eq_ignore_ascii_case(branch_id))?;
+        let hours = branch.hours()?;
+        let slot = parse_clock(time)?;
+        let closure = hours.check(date, slot).err()?;
+
+        let available_slots: Vec<String> = self
+            .time_slots()
+            .into_iter()
+            .filter(|s| parse_clock(s).is_some_and(|t| hours.check(date, t).is_ok()))
+            .collect();
+        let day_hours = hours.hours_on(date.weekday());
+
+        Some(json!({
+            "success": false,
+            "reason": "outside_operating_hours",
+            "branch_id": branch.branch_id,
+            "date": date.format("%Y-%m-%d").to_string(),
+            "time": time,
+            "opens_at": day_hours.map(|h| format_clock(h.open)),
+            "closes_at": day_hours.map(|h| format_clock(h.close)),
+            "available_slots": available_slots,
+            "message": closure.message()
+        }))
+    }
+
+    /// Reject a slot the calendar has no room in
+    ///
+    /// Returns the tool output to send instead, listing the slots still
+    /// open that day. A slot the calendar doesn't list counts as full; if
+    /// the calendar can't be reached the slot is not rejected.
+    async fn slot_unavailable_response(
+        &self,
+        calendar: &dyn CalendarIntegration,
+        branch_id: &str,
+        date: NaiveDate,
+        time: &str,
+    ) -> Option<Value> {
+        let date_str = date.format("%Y-%m-%d").to_string();
+        let slots = match calendar.get_available_slots(branch_id, &date_str).await {
+            Ok(slots) => slots,
+            Err(e) => {
+                tracing::warn!(branch_id, error = %e, "Couldn't check slot availability");
+                return None;
+            },
+        };
+        let is_open = |slot: &TimeSlot| slot.available && slot.remaining_capacity > 0;
+        // Compare as clock times so "2:00 PM" matches "02:00 PM"
+        let is_requested = |slot: &TimeSlot| match parse_clock(time) {
+            Some(requested) => parse_clock(&slot.time) == Some(requested),
+            None => slot.time == time,
+        };
+        if slots.iter().any(|slot| is_open(slot) && is_requested(slot)) {
+            return None;
+        }
+
+        let available_slots: Vec<&str> = slots
+            .iter()
+            .filter(|slot| is_open(slot))
+            .map(|slot| slot.time.as_str())
+            .collect();
+        Some(json!({
+            "success": false,
+            "reason": "slot_unavailable",
+            "branch_id": branch_id,
+            "date": date_str,
+            "time": time,
+            "available_slots": available_slots,
+            "message": format!("The {} slot on {} is fully booked.", time, date_str)
+        }))
+    }
+
     /// Get product name from config or default
     fn product_name(&self) -> String {
         self.view.as_ref()
@@ -133,6 +256,61 @@ impl AppointmentSchedulerTool {
     }
 }
 
+/// Appointment time slots from the booking tool's config, or the defaults
+pub fn appointment_slots(view: Option<&ToolsDomainView>) -> Vec<String> {
+    if let Some(view) = view {
+        if let Some(tool) = view.get_tool("schedule_appointment") {
+            // Find the preferred_time parameter and get its enum values
+            for param in &tool.parameters {
+                if param.name == "preferred_time" {
+                    if let Some(ref values) = param.enum_values {
+                        if !values.is_empty() {
+                            return values.clone();
+                        }
+                    }
+                }
+            }
+        }
+    }
+    // Default time slots (generic)
+    vec![
+        "10:00 AM".to_string(),
+        "11:00 AM".to_string(),
+        "12:00 PM".to_string(),
+        "2:00 PM".to_string(),
+        "3:00 PM".to_string(),
+        "4:00 PM".to_string(),
+        "5:00 PM".to_string(),
+    ]
+}
+
+fn required_str<'a>(input: &'a Value, field: &str) -> Result<&'a str, ToolError> {
+    input
+        .get(field)
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| ToolError::invalid_params(format!("{} is required", field)))
+}
+
+/// Parse an appointment date, rejecting dates in the past
+fn parse_date(date_str: &str) -> Result<NaiveDate, ToolError> {
+    let parsed_date = NaiveDate::parse_from_str(date_str, "%Y-%m-%d")
+        .or_else(|_| NaiveDate::parse_from_str(date_str, "%d-%m-%Y"))
+        .or_else(|_| NaiveDate::parse_from_str(date_str, "%d/%m/%Y"))
+        .map_err(|_| {
+            ToolError::invalid_params(
+                "preferred_date must be in format YYYY-MM-DD, DD-MM-YYYY, or DD/MM/YYYY",
+            )
+        })?;
+
+    let today = Utc::now().date_naive();
+    if parsed_date < today {
+        return Err(ToolError::invalid_params(
+            "preferred_date cannot be in the past",
+        ));
+    }
+    Ok(parsed_date)
+}
+
 #[async_trait]
 impl Tool for AppointmentSchedulerTool {
     fn name(&self) -> &str {
@@ -206,22 +384,7 @@ impl Tool for AppointmentSchedulerTool {
             .and_then(|v| v.as_str())
             .ok_or_else(|| ToolError::invalid_params("preferred_date is required"))?;
 
-        let parsed_date = NaiveDate::parse_from_str(date_str, "%Y-%m-%d")
-            .or_else(|_| NaiveDate::parse_from_str(date_str, "%d-%m-%Y"))
-            .or_else(|_| NaiveDate::parse_from_str(date_str, "%d/%m/%Y"))
-            .map_err(|_| {
-                ToolError::invalid_params(
-                    "preferred_date must be in format YYYY-MM-DD, DD-MM-YYYY, or DD/MM/YYYY",
-                )
-            })?;
-
-        let today = Utc::now().date_naive();
-        if parsed_date < today {
-            return Err(ToolError::invalid_params(
-                "preferred_date cannot be in the past",
-            ));
-        }
-
+        let parsed_date = parse_date(date_str)?;
         let date = parsed_date.format("%Y-%m-%d").to_string();
 
         let time = input
@@ -229,6 +392,13 @@ impl Tool for AppointmentSchedulerTool {
             .and_then(|v| v.as_str())
             .ok_or_else(|| ToolError::invalid_params("preferred_time is required"))?;
 
+        // Don't book a slot when the branch is closed
+        if let Some(result) =
+            self.outside_hours_response(&get_branches(), branch, parsed_date, time)
+        {
+            return Ok(ToolOutput::json(result));
+        }
+
         let default_purpose = self.default_purpose();
         let purpose_str = input
             .get("purpose")
@@ -258,6 +428,9 @@ impl Tool for AppointmentSchedulerTool {
                 Ok(appointment_id) => {
                     let confirmation_sent =
                         calendar.send_confirmation(&appointment_id).await.is_ok();
+                    let reminder_at = self
+                        .schedule_reminder(&appointment_id, name, phone, branch, parsed_date, time)
+                        .await;
 
                     let result = json!({
                         "success": true,
@@ -269,6 +442,7 @@ impl Tool for AppointmentSchedulerTool {
                         "time": time,
                         "purpose": purpose_str,
                         "confirmation_sent": confirmation_sent,
+                        "reminder_at": reminder_at,
                         "calendar_integrated": true,
                         "status": "pending_confirmation",
                         "confirmation_method": "agent_will_call_to_confirm",
@@ -297,6 +471,9 @@ impl Tool for AppointmentSchedulerTool {
             "APT{}",
             uuid::Uuid::new_v4().to_string()[..8].to_uppercase()
         );
+        let reminder_at = self
+            .schedule_reminder(&appointment_id, name, phone, branch, parsed_date, time)
+            .await;
 
         let result = json!({
             "success": true,
@@ -308,6 +485,7 @@ impl Tool for AppointmentSchedulerTool {
             "time": time,
             "purpose": purpose_str,
             "confirmation_sent": false,
+            "reminder_at": reminder_at,
             "calendar_integrated": false,
             "status": "pending_confirmation",
             "confirmation_method": "agent_will_call_to_confirm",
@@ -331,3 +509,621 @@ impl Default for AppointmentSchedulerTool {
         Self::new()
     }
 }
+
+/// Appointment reschedule and cancel tool
+///
+/// Changes appointments booked by an `AppointmentSchedulerTool`, through its
+/// calendar and reminders. The caller must give the number the appointment
+/// was booked with; an appointment booked on another number is reported as
+/// not found, so one caller can't move or cancel another's visit.
+#[derive(Clone)]
+pub struct AppointmentChangeTool {
+    scheduler: AppointmentSchedulerTool,
+}
+
+impl AppointmentChangeTool {
+    pub fn new(scheduler: AppointmentSchedulerTool) -> Self {
+        Self { scheduler }
+    }
+
+    /// The appointment, if it was booked on the caller's number
+    async fn owned_appointment(
+        calendar: &dyn CalendarIntegration,
+        appointment_id: &str,
+        caller: &str,
+    ) -> Result<Option<Appointment>, ToolError> {
+        let appointment = match calendar.get_appointment(appointment_id).await {
+            Ok(appointment) => appointment,
+            Err(IntegrationError::NotFound(_)) => return Ok(None),
+            Err(e) => return Err(e.into()),
+        };
+        let owned = normalize_phone(&appointment.customer_phone) == normalize_phone(caller);
+        Ok(owned.then_some(appointment))
+    }
+
+    /// Output when no appointment with the ID is booked on the caller's number
+    fn not_found_response(action: &str, appointment_id: &str) -> ToolOutput {
+        ToolOutput::json(json!({
+            "success": false,
+            "reason": "appointment_not_found",
+            "action": action,
+            "appointment_id": appointment_id,
+            "message": format!(
+                "No appointment {} is booked on this number.",
+                appointment_id
+            )
+        }))
+    }
+
+    /// Output when the action needs a calendar and none is configured
+    fn no_calendar_response(action: &str, appointment_id: &str) -> ToolOutput {
+        ToolOutput::json(json!({
+            "success": false,
+            "reason": "calendar_unavailable",
+            "action": action,
+            "appointment_id": appointment_id,
+            "next_action": "Agent will call customer to change the appointment",
+            "message": "Our team will call you to change this appointment."
+        }))
+    }
+
+    /// Move a booked appointment to a new date and/or time
+    async fn reschedule(&self, input: &Value) -> Result<ToolOutput, ToolError> {
+        let appointment_id = required_str(input, "appointment_id")?;
+        let Some(ref calendar) = self.scheduler.calendar else {
+            return Ok(Self::no_calendar_response("reschedule", appointment_id));
+        };
+
+        let new_date = input.get("preferred_date").and_then(|v| v.as_str());
+        let new_time = input.get("preferred_time").and_then(|v| v.as_str());
+        if new_date.is_none() && new_time.is_none() {
+            return Err(ToolError::invalid_params(
+                "preferred_date or preferred_time is required to reschedule",
+            ));
+        }
+
+        let caller = required_str(input, "phone_number")?;
+        let Some(current) =
+            Self::owned_appointment(calendar.as_ref(), appointment_id, caller).await?
+        else {
+            return Ok(Self::not_found_response("reschedule", appointment_id));
+        };
+        let parsed_date = parse_date(new_date.unwrap_or(current.date.as_str()))?;
+        let date = parsed_date.format("%Y-%m-%d").to_string();
+        let time = new_time.unwrap_or(current.time_slot.as_str());
+
+        if let Some(result) = self.scheduler.outside_hours_response(
+            &get_branches(),
+            &current.branch_id,
+            parsed_date,
+            time,
+        ) {
+            return Ok(ToolOutput::json(result));
+        }
+        if let Some(result) = self
+            .scheduler
+            .slot_unavailable_response(calendar.as_ref(), &current.branch_id, parsed_date, time)
+            .await
+        {
+            return Ok(ToolOutput::json(result));
+        }
+
+        calendar
+            .reschedule_appointment(appointment_id, &date, time)
+            .await?;
+        let reminder_at = self
+            .scheduler
+            .schedule_reminder(
+                appointment_id,
+                &current.customer_name,
+                &current.customer_phone,
+                &current.branch_id,
+                parsed_date,
+                time,
+            )
+            .await;
+
+        Ok(ToolOutput::json(json!({
+            "success": true,
+            "appointment_id": appointment_id,
+            "branch_id": current.branch_id,
+            "previous_date": current.date,
+            "previous_time": current.time_slot,
+            "date": date,
+            "time": time,
+            "reminder_at": reminder_at,
+            "status": AppointmentStatus::Rescheduled,
+            "message": format!(
+                "{} appointment moved to {} at {}.",
+                self.scheduler.product_name(),
+                date,
+                time
+            )
+        })))
+    }
+
+    /// Cancel a booked appointment and its reminder
+    async fn cancel(&self, input: &Value) -> Result<ToolOutput, ToolError> {
+        let appointment_id = required_str(input, "appointment_id")?;
+        let Some(ref calendar) = self.scheduler.calendar else {
+            return Ok(Self::no_calendar_response("cancel", appointment_id));
+        };
+        let caller = required_str(input, "phone_number")?;
+        if Self::owned_appointment(calendar.as_ref(), appointment_id, caller)
+            .await?
+            .is_none()
+        {
+            return Ok(Self::not_found_response("cancel", appointment_id));
+        }
+
+        calendar.cancel_appointment(appointment_id).await?;
+        if let Some(ref reminders) = self.scheduler.reminders {
+            if let Err(e) = reminders.cancel(appointment_id).await {
+                tracing::warn!(appointment_id, error = %e, "Failed to cancel reminder SMS");
+            }
+        }
+
+        Ok(ToolOutput::json(json!({
+            "success": true,
+            "appointment_id": appointment_id,
+            "status": AppointmentStatus::Cancelled,
+            "message": format!(
+                "{} appointment {} cancelled.",
+                self.scheduler.product_name(),
+                appointment_id
+            )
+        })))
+    }
+}
+
+#[async_trait]
+impl Tool for AppointmentChangeTool {
+    fn name(&self) -> &str {
+        "change_appointment"
+    }
+
+    fn description(&self) -> &str {
+        "Reschedule or cancel a booked branch visit appointment"
+    }
+
+    fn schema(&self) -> ToolSchema {
+        ToolSchema {
+            name: self.name().to_string(),
+            description: self.description().to_string(),
+            input_schema: InputSchema::object()
+                .property(
+                    "action",
+                    PropertySchema::enum_type(
+                        "reschedule or cancel",
+                        vec!["reschedule".into(), "cancel".into()],
+                    ),
+                    true,
+                )
+                .property(
+                    "appointment_id",
+                    PropertySchema::string("Appointment to change"),
+                    true,
+                )
+                .property(
+                    "phone_number",
+                    PropertySchema::string("Number the appointment was booked with"),
+                    true,
+                )
+                .property(
+                    "preferred_date",
+                    PropertySchema::string("New date (YYYY-MM-DD), to reschedule"),
+                    false,
+                )
+                .property(
+                    "preferred_time",
+                    PropertySchema::enum_type(
+                        "New time slot, to reschedule",
+                        self.scheduler.time_slots(),
+                    ),
+                    false,
+                ),
+        }
+    }
+
+    async fn execute(&self, input: Value) -> Result<ToolOutput, ToolError> {
+        match required_str(&input, "action")? {
+            "reschedule" => self.reschedule(&input).await,
+            "cancel" => self.cancel(&input).await,
+            other => Err(ToolError::invalid_params(format!(
+                "action must be reschedule or cancel, not '{}'",
+                other
+            ))),
+        }
+    }
+
+    fn timeout_secs(&self) -> u64 {
+        60
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::integrations::IntegrationError;
+    use chrono::DateTime;
+    use std::collections::HashMap;
+    use voice_agent_persistence::{
+        InMemoryScheduledMessageStore, InMemorySmsService, ReminderDispatcher, SmsType,
+    };
+
+    fn branch() -> BranchData {
+        BranchData {
+            branch_id: "KMBL001".to_string(),
+            name: "Andheri West".to_string(),
+            city: "Mumbai".to_string(),
+            area: "Andheri West".to_string(),
+            address: "S.V. Road, Andheri West, Mumbai - 400058".to_string(),
+            pincode: "400058".to_string(),
+            phone: "022-66006060".to_string(),
+            service_available: true,
+            timing: "10:00 AM - 5:00 PM (Mon-Sat)".to_string(),
+            facilities: vec![],
+            coordinates: None,
+            doorstep_available: false,
+            operating_hours: None,
+        }
+    }
+
+    /// Calendar keeping appointments in memory, with the 12:00 PM slot full
+    #[derive(Default)]
+    struct InMemoryCalendar {
+        appointments: parking_lot::Mutex<HashMap<String, Appointment>>,
+    }
+
+    impl InMemoryCalendar {
+        fn appointment(&self, id: &str) -> Appointment {
+            self.appointments.lock()[id].clone()
+        }
+    }
+
+    #[async_trait]
+    impl CalendarIntegration for InMemoryCalendar {
+        async fn get_available_slots(
+            &self,
+            _branch_id: &str,
+            _date: &str,
+        ) -> Result<Vec<TimeSlot>, IntegrationError> {
+            Ok(["10:00 AM", "11:00 AM", "12:00 PM", "2:00 PM"]
+                .into_iter()
+                .map(|time| TimeSlot {
+                    time: time.to_string(),
+                    available: time != "12:00 PM",
+                    remaining_capacity: if time == "12:00 PM" { 0 } else { 2 },
+                })
+                .collect())
+        }
+
+        async fn schedule_appointment(
+            &self,
+            mut appointment: Appointment,
+        ) -> Result<String, IntegrationError> {
+            let id = format!("APT-{}", self.appointments.lock().len() + 1);
+            appointment.id = Some(id.clone());
+            self.appointments.lock().insert(id.clone(), appointment);
+            Ok(id)
+        }
+
+        async fn cancel_appointment(&self, id: &str) -> Result<(), IntegrationError> {
+            let mut appointments = self.appointments.lock();
+            let appointment = appointments
+                .get_mut(id)
+                .ok_or_else(|| IntegrationError::NotFound(id.to_string()))?;
+            appointment.status = AppointmentStatus::Cancelled;
+            Ok(())
+        }
+
+        async fn reschedule_appointment(
+            &self,
+            id: &str,
+            new_date: &str,
+            new_time: &str,
+        ) -> Result<(), IntegrationError> {
+            let mut appointments = self.appointments.lock();
+            let appointment = appointments
+                .get_mut(id)
+                .ok_or_else(|| IntegrationError::NotFound(id.to_string()))?;
+            appointment.date = new_date.to_string();
+            appointment.time_slot = new_time.to_string();
+            appointment.status = AppointmentStatus::Rescheduled;
+            Ok(())
+        }
+
+        async fn get_appointment(&self, id: &str) -> Result<Appointment, IntegrationError> {
+            self.appointments
+                .lock()
+                .get(id)
+                .cloned()
+                .ok_or_else(|| IntegrationError::NotFound(id.to_string()))
+        }
+
+        async fn send_confirmation(&self, _id: &str) -> Result<(), IntegrationError> {
+            Ok(())
+        }
+    }
+
+    /// Tool booking with a 24h reminder, plus the worker sending reminders
+    fn tool_with_reminders(
+        tool: AppointmentSchedulerTool,
+    ) -> (
+        AppointmentSchedulerTool,
+        Arc<AppointmentReminders>,
+        ReminderDispatcher,
+        Arc<InMemorySmsService>,
+    ) {
+        let store = Arc::new(InMemoryScheduledMessageStore::new());
+        let reminders = Arc::new(AppointmentReminders::new(
+            store.clone(),
+            std::time::Duration::from_secs(24 * 3600),
+        ));
+        let sms = Arc::new(InMemorySmsService::new());
+        let dispatcher = ReminderDispatcher::new(store, sms.clone());
+        let tool = tool.with_reminders(reminders.clone());
+        (tool, reminders, dispatcher, sms)
+    }
+
+    async fn run(tool: &dyn Tool, input: Value) -> Value {
+        let output = tool.execute(input).await.unwrap();
+        match &output.content[0] {
+            crate::mcp::ContentBlock::Text { text } => serde_json::from_str(text).unwrap(),
+            _ => panic!("expected text output"),
+        }
+    }
+
+    async fn book(tool: &AppointmentSchedulerTool) -> Value {
+        let date = Utc::now().date_naive() + chrono::Duration::days(3);
+        run(
+            tool,
+            json!({
+                "customer_name": "Rahul",
+                "phone_number": "9876543210",
+                "branch_id": "TEST01",
+                "preferred_date": date.format("%Y-%m-%d").to_string(),
+                "preferred_time": "11:00 AM"
+            }),
+        )
+        .await
+    }
+
+    /// Next Friday at least a week out
+    fn friday() -> String {
+        let mut date = Utc::now().date_naive() + chrono::Duration::days(7);
+        while date.weekday() != chrono::Weekday::Fri {
+            date = date.succ_opt().unwrap();
+        }
+        date.format("%Y-%m-%d").to_string()
+    }
+
+    #[tokio::test]
+    async fn test_reschedule_to_available_slot() {
+        let calendar = Arc::new(InMemoryCalendar::default());
+        let (tool, _, dispatcher, sms) =
+            tool_with_reminders(AppointmentSchedulerTool::with_calendar(calendar.clone()));
+        let booked = book(&tool).await;
+        let id = booked["appointment_id"].as_str().unwrap();
+        let old_reminder: DateTime<Utc> = booked["reminder_at"].as_str().unwrap().parse().unwrap();
+
+        // "Can we move it to Friday afternoon?"
+        let moved = run(
+            &AppointmentChangeTool::new(tool),
+            json!({
+                "action": "reschedule",
+                "appointment_id": id,
+                "phone_number": "+91 98765 43210",
+                "preferred_date": friday(),
+                "preferred_time": "2:00 PM"
+            }),
+        )
+        .await;
+
+        assert_eq!(moved["success"], true);
+        assert_eq!(moved["status"], "rescheduled");
+        assert_eq!(moved["previous_time"], "11:00 AM");
+        let appointment = calendar.appointment(id);
+        assert_eq!(appointment.date, friday());
+        assert_eq!(appointment.time_slot, "2:00 PM");
+
+        // The reminder moved with it
+        assert_eq!(dispatcher.dispatch_due(old_reminder).await.unwrap(), 0);
+        let new_reminder: DateTime<Utc> = moved["reminder_at"].as_str().unwrap().parse().unwrap();
+        assert_eq!(dispatcher.dispatch_due(new_reminder).await.unwrap(), 1);
+        assert!(sms.sent().await[0].message_text.contains("2:00 PM"));
+    }
+
+    #[tokio::test]
+    async fn test_reschedule_into_full_slot_rejected() {
+        let calendar = Arc::new(InMemoryCalendar::default());
+        let tool = AppointmentSchedulerTool::with_calendar(calendar.clone());
+        let booked = book(&tool).await;
+        let id = booked["appointment_id"].as_str().unwrap();
+
+        let result = run(
+            &AppointmentChangeTool::new(tool),
+            json!({
+                "action": "reschedule",
+                "appointment_id": id,
+                "phone_number": "9876543210",
+                "preferred_date": friday(),
+                "preferred_time": "12:00 PM"
+            }),
+        )
+        .await;
+
+        assert_eq!(result["success"], false);
+        assert_eq!(result["reason"], "slot_unavailable");
+        let open = result["available_slots"].as_array().unwrap();
+        assert!(open.iter().all(|slot| slot != "12:00 PM"));
+        assert!(open.iter().any(|slot| slot == "2:00 PM"));
+        let appointment = calendar.appointment(id);
+        assert_eq!(appointment.date, booked["date"]);
+        assert_eq!(appointment.time_slot, "11:00 AM");
+    }
+
+    #[tokio::test]
+    async fn test_cancel_appointment() {
+        let calendar = Arc::new(InMemoryCalendar::default());
+        let (tool, _, dispatcher, sms) =
+            tool_with_reminders(AppointmentSchedulerTool::with_calendar(calendar.clone()));
+        // A customer holding two appointments cancels one of them
+        let first = book(&tool).await;
+        let second = book(&tool).await;
+        let id = second["appointment_id"].as_str().unwrap();
+        let reminder_at: DateTime<Utc> = second["reminder_at"].as_str().unwrap().parse().unwrap();
+
+        let change = AppointmentChangeTool::new(tool);
+        let cancel = json!({
+            "action": "cancel",
+            "appointment_id": id,
+            "phone_number": "9876543210"
+        });
+        let result = run(&change, cancel.clone()).await;
+
+        assert_eq!(result["success"], true);
+        assert_eq!(result["status"], "cancelled");
+        assert!(matches!(
+            calendar.appointment(id).status,
+            AppointmentStatus::Cancelled
+        ));
+        let kept = calendar.appointment(first["appointment_id"].as_str().unwrap());
+        assert!(matches!(kept.status, AppointmentStatus::Scheduled));
+        // Only the remaining appointment is reminded about
+        assert_eq!(dispatcher.dispatch_due(reminder_at).await.unwrap(), 1);
+        assert_eq!(sms.sent().await.len(), 1);
+
+        // Without a calendar there's nothing to cancel against
+        let change = AppointmentChangeTool::new(AppointmentSchedulerTool::new());
+        let result = run(&change, cancel).await;
+        assert_eq!(result["reason"], "calendar_unavailable");
+    }
+
+    #[tokio::test]
+    async fn test_other_callers_appointment_not_changed() {
+        let calendar = Arc::new(InMemoryCalendar::default());
+        let tool = AppointmentSchedulerTool::with_calendar(calendar.clone());
+        let booked = book(&tool).await;
+        let id = booked["appointment_id"].as_str().unwrap();
+        let change = AppointmentChangeTool::new(tool);
+
+        for input in [
+            json!({ "action": "cancel", "appointment_id": id, "phone_number": "9123456780" }),
+            json!({
+                "action": "reschedule",
+                "appointment_id": id,
+                "phone_number": "9123456780",
+                "preferred_date": friday()
+            }),
+            json!({ "action": "cancel", "appointment_id": "APT-99", "phone_number": "9876543210" }),
+        ] {
+            let result = run(&change, input).await;
+            assert_eq!(result["success"], false);
+            assert_eq!(result["reason"], "appointment_not_found");
+        }
+        let appointment = calendar.appointment(id);
+        assert!(matches!(appointment.status, AppointmentStatus::Scheduled));
+        assert_eq!(appointment.date, booked["date"]);
+    }
+
+    #[test]
+    fn test_booking_requires_every_field() {
+        let schema = AppointmentSchedulerTool::new().schema();
+        let required = schema.input_schema.required;
+        for field in [
+            "customer_name",
+            "phone_number",
+            "branch_id",
+            "preferred_date",
+            "preferred_time",
+        ] {
+            assert!(required.contains(&field.to_string()));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_booking_sends_reminder_at_lead_time() {
+        let (tool, _, dispatcher, sms) = tool_with_reminders(AppointmentSchedulerTool::new());
+        let result = book(&tool).await;
+        let reminder_at: DateTime<Utc> = result["reminder_at"].as_str().unwrap().parse().unwrap();
+
+        // 11:00 IST is 05:30 UTC, the day before the appointment
+        assert_eq!(reminder_at.format("%H:%M").to_string(), "05:30");
+        let before = reminder_at - chrono::Duration::minutes(1);
+        assert_eq!(dispatcher.dispatch_due(before).await.unwrap(), 0);
+        assert_eq!(dispatcher.dispatch_due(reminder_at).await.unwrap(), 1);
+
+        let sent = sms.sent().await;
+        assert_eq!(sent.len(), 1);
+        assert_eq!(sent[0].phone_number, "9876543210");
+        assert!(sent[0].message_text.contains("Rahul"));
+        assert!(sent[0].message_text.contains("11:00 AM"));
+        assert_eq!(sent[0].message_type, SmsType::AppointmentReminder);
+    }
+
+    #[tokio::test]
+    async fn test_cancelled_appointment_gets_no_reminder() {
+        let (tool, reminders, dispatcher, sms) =
+            tool_with_reminders(AppointmentSchedulerTool::new());
+        let result = book(&tool).await;
+        let reminder_at: DateTime<Utc> = result["reminder_at"].as_str().unwrap().parse().unwrap();
+
+        let cancelled = reminders
+            .cancel(result["appointment_id"].as_str().unwrap())
+            .await
+            .unwrap();
+        assert_eq!(cancelled, 1);
+        assert_eq!(dispatcher.dispatch_due(reminder_at).await.unwrap(), 0);
+        assert!(sms.sent().await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_no_reminder_without_reminders_configured() {
+        let result = book(&AppointmentSchedulerTool::new()).await;
+        assert!(result["reminder_at"].is_null());
+    }
+
+    fn monday() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2030, 1, 7).unwrap()
+    }
+
+    #[test]
+    fn test_slot_in_hours_accepted() {
+        let tool = AppointmentSchedulerTool::new();
+        let branches = vec![branch()];
+
+        assert!(tool
+            .outside_hours_response(&branches, "KMBL001", monday(), "11:00 AM")
+            .is_none());
+        // Unknown branches are not blocked
+        assert!(tool
+            .outside_hours_response(&branches, "OTHER", monday(), "8:00 AM")
+            .is_none());
+    }
+
+    #[test]
+    fn test_slot_outside_hours_rejected() {
+        let tool = AppointmentSchedulerTool::new();
+        let branches = vec![branch()];
+
+        let result = tool
+            .outside_hours_response(&branches, "kmbl001", monday(), "9:00 AM")
+            .unwrap();
+        assert_eq!(result["success"], false);
+        assert_eq!(result["message"], "This branch opens at 10am.");
+        assert_eq!(result["opens_at"], "10am");
+        // The 5:00 PM default slot is at closing time
+        let slots = result["available_slots"].as_array().unwrap();
+        assert_eq!(slots.first().unwrap(), "10:00 AM");
+        assert_eq!(slots.last().unwrap(), "4:00 PM");
+
+        // Sunday
+        let sunday = monday().pred_opt().unwrap();
+        let result = tool
+            .outside_hours_response(&branches, "KMBL001", sunday, "11:00 AM")
+            .unwrap();
+        assert_eq!(result["message"], "This branch is closed on Sundays.");
+        assert!(result["available_slots"].as_array().unwrap().is_empty());
+    }
+}
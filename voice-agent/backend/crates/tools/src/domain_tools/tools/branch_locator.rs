@@ -3,15 +3,22 @@
 //! Find nearby service locations/branches.
 
 use async_trait::async_trait;
+use chrono::Utc;
 use serde_json::{json, Value};
 
 use crate::mcp::{InputSchema, PropertySchema, Tool, ToolError, ToolOutput, ToolSchema};
 
-use super::super::locations::{get_branches, BranchData};
+use super::super::hours::india_timezone;
+use super::super::locations::{
+    get_branches, resolve_pincode, sort_by_distance, BranchData, Coordinates,
+};
 
 /// Location finder tool
 ///
 /// Finds service locations based on city, area, or pincode.
+/// A PIN code is resolved to its city, so the user need not name both.
+/// When the user's position is known (PIN code centroid or a known area),
+/// results are sorted nearest-first with the distance included.
 /// This is domain-agnostic - actual locations come from domain config.
 pub struct BranchLocatorTool;
 
@@ -36,7 +43,11 @@ impl Tool for BranchLocatorTool {
             name: self.name().to_string(),
             description: self.description().to_string(),
             input_schema: InputSchema::object()
-                .property("city", PropertySchema::string("City name"), true)
+                .property(
+                    "city",
+                    PropertySchema::string("City name (optional when a PIN code is given)"),
+                    false,
+                )
                 .property("area", PropertySchema::string("Area or locality"), false)
                 .property("pincode", PropertySchema::string("6-digit PIN code"), false)
                 .property(
@@ -48,11 +59,7 @@ impl Tool for BranchLocatorTool {
     }
 
     async fn execute(&self, input: Value) -> Result<ToolOutput, ToolError> {
-        let city = input
-            .get("city")
-            .and_then(|v| v.as_str())
-            .ok_or_else(|| ToolError::invalid_params("city is required"))?;
-
+        let stated_city = input.get("city").and_then(|v| v.as_str());
         let area = input.get("area").and_then(|v| v.as_str());
         let pincode = input.get("pincode").and_then(|v| v.as_str());
         let max_results = input
@@ -60,11 +67,43 @@ impl Tool for BranchLocatorTool {
             .and_then(|v| v.as_i64())
             .unwrap_or(5) as usize;
 
-        let locations = filter_locations_json(city, area, pincode, max_results);
+        if stated_city.is_none() && pincode.is_none() {
+            return Err(ToolError::invalid_params("city or pincode is required"));
+        }
+
+        // The PIN code is more specific than a stated (or defaulted) city
+        let region = pincode.and_then(resolve_pincode);
+        let city = match (&region, stated_city) {
+            (Some(region), _) => region.city.clone(),
+            (None, Some(city)) => city.to_string(),
+            (None, None) => {
+                return Ok(ToolOutput::json(json!({
+                    "pincode": pincode,
+                    "pincode_resolved": false,
+                    "locations_found": 0,
+                    "locations": [],
+                    "message": format!(
+                        "We couldn't find PIN code {}. Could you tell us your city?",
+                        pincode.unwrap_or_default()
+                    )
+                })));
+            },
+        };
+
+        let branches = get_branches();
+        let origin = region
+            .as_ref()
+            .and_then(|r| r.centroid)
+            .or_else(|| area.and_then(|a| area_coordinates(&branches, &city, a)));
+
+        let locations = filter_locations_json(branches, &city, area, pincode, origin, max_results);
 
         let result = json!({
             "city": city,
             "area": area,
+            "pincode": pincode,
+            "pincode_resolved": region.is_some(),
+            "sorted_by": if origin.is_some() { "distance" } else { "city" },
             "locations_found": locations.len(),
             "locations": locations,
             "message": if locations.is_empty() {
@@ -84,15 +123,30 @@ impl Default for BranchLocatorTool {
     }
 }
 
+/// Coordinates of a known location in the stated area, used as the user's position
+fn area_coordinates(locations: &[BranchData], city: &str, area: &str) -> Option<Coordinates> {
+    let city_lower = city.to_lowercase();
+    let area_lower = area.to_lowercase();
+    locations
+        .iter()
+        .filter(|b| b.city.to_lowercase().contains(&city_lower))
+        .find(|b| b.area.to_lowercase().contains(&area_lower))
+        .and_then(|b| b.coordinates)
+}
+
 /// Filter locations and return as JSON values for tool output
+///
+/// With a user position the city's locations are sorted nearest-first;
+/// otherwise PIN code/area matches are preferred and results grouped by city.
 fn filter_locations_json(
+    locations: Vec<BranchData>,
     city: &str,
     area: Option<&str>,
     pincode: Option<&str>,
+    origin: Option<Coordinates>,
     max: usize,
 ) -> Vec<Value> {
     let city_lower = city.to_lowercase();
-    let locations = get_branches();
 
     let mut filtered: Vec<BranchData> = locations
         .into_iter()
@@ -102,6 +156,15 @@ fn filter_locations_json(
         })
         .collect();
 
+    if let Some(origin) = &origin {
+        sort_by_distance(&mut filtered, origin);
+        filtered.truncate(max);
+        return filtered
+            .iter()
+            .map(|b| location_json(b, b.distance_from(origin)))
+            .collect();
+    }
+
     if let Some(pin) = pincode {
         let pin_matches: Vec<BranchData> = filtered
             .iter()
@@ -125,22 +188,157 @@ fn filter_locations_json(
         }
     }
 
+    filtered.sort_by(|a, b| a.city.cmp(&b.city));
     filtered.truncate(max);
-    filtered
-        .into_iter()
-        .map(|b| {
-            json!({
-                "location_id": b.branch_id,
-                "name": b.name,
-                "city": b.city,
-                "area": b.area,
-                "address": b.address,
-                "pincode": b.pincode,
-                "phone": b.phone,
-                "service_available": b.service_available,
-                "timing": b.timing,
-                "facilities": b.facilities
-            })
-        })
-        .collect()
+    filtered.iter().map(|b| location_json(b, None)).collect()
+}
+
+fn location_json(b: &BranchData, distance_km: Option<f64>) -> Value {
+    json!({
+        "location_id": b.branch_id,
+        "name": b.name,
+        "city": b.city,
+        "area": b.area,
+        "address": b.address,
+        "pincode": b.pincode,
+        "phone": b.phone,
+        "service_available": b.service_available,
+        "doorstep_available": b.doorstep_available,
+        "timing": b.timing,
+        "open_now": b.is_open_at(&Utc::now(), &india_timezone()),
+        "facilities": b.facilities,
+        "distance_km": distance_km.map(|d| (d * 10.0).round() / 10.0)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mcp::ContentBlock;
+
+    fn output_json(output: &ToolOutput) -> Value {
+        match &output.content[0] {
+            ContentBlock::Text { text } => serde_json::from_str(text).unwrap(),
+            other => panic!("unexpected content: {:?}", other),
+        }
+    }
+
+    fn branch(id: &str, city: &str, pincode: &str) -> BranchData {
+        BranchData {
+            branch_id: id.to_string(),
+            name: format!("{} Branch", city),
+            city: city.to_string(),
+            area: "Central".to_string(),
+            address: format!("Main Road, {} - {}", city, pincode),
+            pincode: pincode.to_string(),
+            phone: "080-12345678".to_string(),
+            service_available: true,
+            timing: "10:00 AM - 5:00 PM".to_string(),
+            facilities: vec![],
+            coordinates: None,
+            doorstep_available: false,
+            operating_hours: None,
+        }
+    }
+
+    fn located(id: &str, area: &str, latitude: f64, longitude: f64) -> BranchData {
+        BranchData {
+            area: area.to_string(),
+            coordinates: Some(Coordinates::new(latitude, longitude)),
+            ..branch(id, "Bangalore", "560001")
+        }
+    }
+
+    #[tokio::test]
+    async fn test_pincode_resolves_city() {
+        let tool = BranchLocatorTool::new();
+
+        let output = tool.execute(json!({ "pincode": "560034" })).await.unwrap();
+        let result = output_json(&output);
+
+        assert_eq!(result["city"], "Bangalore");
+        assert_eq!(result["pincode_resolved"], true);
+    }
+
+    #[test]
+    fn test_resolved_city_feeds_locator() {
+        let branches = vec![
+            branch("B1", "Bangalore", "560001"),
+            branch("B2", "Bangalore", "560034"),
+            branch("M1", "Mumbai", "400001"),
+        ];
+        let city = resolve_pincode("560095").unwrap().city;
+
+        let locations = filter_locations_json(branches, &city, None, Some("560095"), None, 5);
+
+        assert_eq!(locations.len(), 2);
+        assert!(locations.iter().all(|l| l["city"] == "Bangalore"));
+    }
+
+    #[tokio::test]
+    async fn test_unknown_pincode_falls_back() {
+        let tool = BranchLocatorTool::new();
+
+        // Without a city, ask for one instead of failing
+        let output = tool.execute(json!({ "pincode": "999999" })).await.unwrap();
+        let result = output_json(&output);
+        assert_eq!(result["pincode_resolved"], false);
+        assert_eq!(result["locations_found"], 0);
+        assert!(result["message"].as_str().unwrap().contains("city"));
+
+        // With a city, search the city
+        let output = tool
+            .execute(json!({ "pincode": "999999", "city": "Pune" }))
+            .await
+            .unwrap();
+        let result = output_json(&output);
+        assert_eq!(result["city"], "Pune");
+        assert_eq!(result["pincode_resolved"], false);
+    }
+
+    #[test]
+    fn test_results_sorted_by_distance() {
+        let mut koramangala = located("KOR", "Koramangala", 12.9352, 77.6245);
+        koramangala.doorstep_available = true;
+        let branches = vec![
+            located("MG", "MG Road", 12.9756, 77.6050),
+            koramangala,
+            located("JAY", "Jayanagar", 12.9299, 77.5826),
+        ];
+        // Reference point: HSR Layout
+        let origin = Coordinates::new(12.9116, 77.6474);
+
+        let locations = filter_locations_json(branches, "Bangalore", None, None, Some(origin), 5);
+
+        let ids: Vec<&str> = locations
+            .iter()
+            .map(|l| l["location_id"].as_str().unwrap())
+            .collect();
+        assert_eq!(ids, vec!["KOR", "JAY", "MG"]);
+        let distances: Vec<f64> = locations
+            .iter()
+            .map(|l| l["distance_km"].as_f64().unwrap())
+            .collect();
+        assert!(distances.windows(2).all(|w| w[0] <= w[1]));
+        assert!((distances[0] - 3.6).abs() < 0.3, "got {}", distances[0]);
+        assert_eq!(locations[0]["doorstep_available"], true);
+        assert_eq!(locations[1]["doorstep_available"], false);
+    }
+
+    #[test]
+    fn test_area_sets_origin_and_fallback_without_location() {
+        let branches = vec![
+            located("MG", "MG Road", 12.9756, 77.6050),
+            located("JAY", "Jayanagar", 12.9299, 77.5826),
+        ];
+
+        let origin = area_coordinates(&branches, "Bangalore", "jayanagar");
+        assert_eq!(origin, Some(Coordinates::new(12.9299, 77.5826)));
+        assert!(area_coordinates(&branches, "Bangalore", "Whitefield").is_none());
+
+        // No user position: no distances, city grouping
+        let locations = filter_locations_json(branches, "Bangalore", None, None, None, 5);
+        assert_eq!(locations.len(), 2);
+        assert!(locations.iter().all(|l| l["distance_km"].is_null()));
+    }
 }
@@ -1,22 +1,87 @@
 //! Human Escalation Tool
 //!
 //! Escalate the conversation to a human agent.
+//!
+//! When live agent hours are configured, an escalation outside them is not
+//! transferred: the customer is offered a callback (`callback_request`) or an
+//! SMS follow-up instead, and the deferred escalation is written to the audit
+//! log so a callback can be arranged once agents are back. The most recent
+//! deferrals are also kept in memory, up to `MAX_DEFERRED_ESCALATIONS`.
 
 use async_trait::async_trait;
-use chrono::Utc;
+use chrono::{DateTime, FixedOffset, Utc};
+use parking_lot::Mutex;
+use serde::Serialize;
 use serde_json::{json, Value};
+use std::collections::{HashMap, VecDeque};
 use std::sync::Arc;
 
+use voice_agent_config::domain::LiveAgentHours;
+use voice_agent_config::ToolsDomainView;
+use voice_agent_persistence::AuditLogger;
+
+use super::super::hours::{format_clock, india_timezone, Closure, OperatingHours};
 use crate::mcp::{InputSchema, PropertySchema, Tool, ToolError, ToolOutput, ToolSchema};
 
+/// Deferred escalations kept in memory; older ones are only in the audit log
+pub const MAX_DEFERRED_ESCALATIONS: usize = 256;
+
+/// Live agent hours in their local time zone
+#[derive(Debug, Clone)]
+struct Shift {
+    hours: OperatingHours,
+    tz: FixedOffset,
+}
+
+impl Shift {
+    fn from_config(config: &LiveAgentHours) -> Option<Self> {
+        let Some(mut hours) = OperatingHours::parse(&config.timing) else {
+            tracing::warn!(timing = %config.timing, "Unparseable live agent hours, ignoring");
+            return None;
+        };
+        hours.holidays.extend(config.holidays.iter().copied());
+        let tz =
+            FixedOffset::east_opt(config.utc_offset_minutes * 60).unwrap_or_else(india_timezone);
+        Some(Self { hours, tz })
+    }
+
+    fn check(&self, at: &DateTime<Utc>) -> Result<(), Closure> {
+        let local = at.with_timezone(&self.tz);
+        self.hours.check(local.date_naive(), local.time())
+    }
+}
+
+/// Escalation deferred to a callback because no agents were on shift
+#[derive(Debug, Clone, Serialize)]
+pub struct DeferredEscalation {
+    pub escalation_id: String,
+    pub session_id: String,
+    pub reason: String,
+    pub region: Option<String>,
+    pub requested_at: DateTime<Utc>,
+}
+
 /// Human escalation tool
 pub struct EscalateToHumanTool {
     on_escalate: Option<Arc<dyn Fn(String, String, String) + Send + Sync>>,
+    /// Live agent hours; None means agents are always available
+    shift: Option<Shift>,
+    /// Hours for specific regions or branch cities (lowercase keys)
+    regional_shifts: HashMap<String, Shift>,
+    deferred: Mutex<VecDeque<DeferredEscalation>>,
+    /// Records deferred escalations for follow-up
+    audit_logger: Option<Arc<AuditLogger>>,
 }
 
 impl EscalateToHumanTool {
     pub fn new() -> Self {
-        Self { on_escalate: None }
+        Self {
+            on_escalate: None,
+            shift: None,
+            regional_shifts: HashMap::new(),
+            deferred: Mutex::new(VecDeque::new()),
+            audit_logger: None,
+        }
     }
 
     pub fn with_callback<F>(callback: F) -> Self
@@ -25,7 +90,89 @@ impl EscalateToHumanTool {
     {
         Self {
             on_escalate: Some(Arc::new(callback)),
+            ..Self::new()
+        }
+    }
+
+    /// Tool using the domain's live agent hours, if configured
+    pub fn with_view(view: Arc<ToolsDomainView>) -> Self {
+        let mut tool = Self::new();
+        if let Some(config) = view.live_agent_hours() {
+            tool.shift = Shift::from_config(config);
+            tool.regional_shifts = config
+                .regions
+                .iter()
+                .filter_map(|(region, hours)| {
+                    Shift::from_config(hours).map(|shift| (region.to_lowercase(), shift))
+                })
+                .collect();
         }
+        tool
+    }
+
+    /// Only transfer live during `hours`, local to `tz`
+    pub fn with_live_agent_hours(mut self, hours: OperatingHours, tz: FixedOffset) -> Self {
+        self.shift = Some(Shift { hours, tz });
+        self
+    }
+
+    /// Live agent hours for a region or branch city
+    pub fn with_region_hours(
+        mut self,
+        region: impl Into<String>,
+        hours: OperatingHours,
+        tz: FixedOffset,
+    ) -> Self {
+        self.regional_shifts
+            .insert(region.into().to_lowercase(), Shift { hours, tz });
+        self
+    }
+
+    /// Audit deferred escalations so callbacks survive a restart
+    pub fn with_audit_logger(mut self, audit_logger: Arc<AuditLogger>) -> Self {
+        self.audit_logger = Some(audit_logger);
+        self
+    }
+
+    /// Recent escalations deferred outside live agent hours, oldest first
+    pub fn deferred_escalations(&self) -> Vec<DeferredEscalation> {
+        self.deferred.lock().iter().cloned().collect()
+    }
+
+    /// Whether live agents are on shift for the region at `at`
+    fn live_agents_available(
+        &self,
+        region: Option<&str>,
+        at: &DateTime<Utc>,
+    ) -> Result<(), Closure> {
+        let shift = region
+            .and_then(|r| self.regional_shifts.get(&r.to_lowercase()))
+            .or(self.shift.as_ref());
+        match shift {
+            Some(shift) => shift.check(at),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Why no one can take the call now, e.g. "Our specialists start at 10am."
+fn unavailable_message(closure: &Closure) -> String {
+    match closure {
+        Closure::BeforeOpening(open) => {
+            format!(
+                "Our specialists start taking calls at {}.",
+                format_clock(*open)
+            )
+        },
+        Closure::AfterClosing(close) => {
+            format!(
+                "Our specialists are available until {}.",
+                format_clock(*close)
+            )
+        },
+        Closure::ClosedOnDay(_) | Closure::Holiday(_) => {
+            "Our specialists are not available today.".to_string()
+        },
     }
 }
 
@@ -73,6 +220,11 @@ impl Tool for EscalateToHumanTool {
                     PropertySchema::string("Brief summary of conversation so far"),
                     false,
                 )
+                .property(
+                    "region",
+                    PropertySchema::string("Customer's region or branch city, for agent hours"),
+                    false,
+                )
                 .property(
                     "priority",
                     PropertySchema::enum_type(
@@ -86,6 +238,17 @@ impl Tool for EscalateToHumanTool {
     }
 
     async fn execute(&self, input: Value) -> Result<ToolOutput, ToolError> {
+        self.escalate(input, Utc::now()).await
+    }
+
+    fn timeout_secs(&self) -> u64 {
+        10
+    }
+}
+
+impl EscalateToHumanTool {
+    /// Escalate as of `now`: a live transfer in hours, a callback offer outside them
+    async fn escalate(&self, input: Value, now: DateTime<Utc>) -> Result<ToolOutput, ToolError> {
         let reason = input
             .get("reason")
             .and_then(|v| v.as_str())
@@ -116,6 +279,17 @@ impl Tool for EscalateToHumanTool {
             uuid::Uuid::new_v4().to_string()[..8].to_uppercase()
         );
 
+        let region = input
+            .get("region")
+            .or_else(|| input.get("city"))
+            .and_then(|v| v.as_str());
+
+        if let Err(closure) = self.live_agents_available(region, &now) {
+            return Ok(self
+                .defer(&escalation_id, session_id, reason, region, &closure, now)
+                .await);
+        }
+
         let estimated_wait = match priority {
             "urgent" => "1-2 minutes",
             "high" => "2-5 minutes",
@@ -147,6 +321,7 @@ impl Tool for EscalateToHumanTool {
             "priority": priority,
             "summary": summary,
             "status": "queued",
+            "live_transfer": true,
             "estimated_wait": estimated_wait,
             "queue_position": 1,
             "created_at": Utc::now().to_rfc3339(),
@@ -160,8 +335,70 @@ impl Tool for EscalateToHumanTool {
         Ok(ToolOutput::json(result))
     }
 
-    fn timeout_secs(&self) -> u64 {
-        10
+    /// Record an escalation outside live agent hours and offer a callback or SMS
+    async fn defer(
+        &self,
+        escalation_id: &str,
+        session_id: &str,
+        reason: &str,
+        region: Option<&str>,
+        closure: &Closure,
+        now: DateTime<Utc>,
+    ) -> ToolOutput {
+        tracing::info!(
+            escalation_id = %escalation_id,
+            session_id = %session_id,
+            reason = %reason,
+            closure = ?closure,
+            "Escalation deferred outside live agent hours"
+        );
+        {
+            let mut deferred = self.deferred.lock();
+            if deferred.len() >= MAX_DEFERRED_ESCALATIONS {
+                if let Some(dropped) = deferred.pop_front() {
+                    tracing::warn!(
+                        escalation_id = %dropped.escalation_id,
+                        "Deferred escalation queue full, dropping the oldest from memory"
+                    );
+                }
+            }
+            deferred.push_back(DeferredEscalation {
+                escalation_id: escalation_id.to_string(),
+                session_id: session_id.to_string(),
+                reason: reason.to_string(),
+                region: region.map(str::to_string),
+                requested_at: now,
+            });
+        }
+        if let Some(ref audit_logger) = self.audit_logger {
+            if let Err(e) = audit_logger
+                .log_escalation_deferred(session_id, reason, escalation_id, region)
+                .await
+            {
+                tracing::warn!(
+                    escalation_id = %escalation_id,
+                    error = %e,
+                    "Failed to audit deferred escalation"
+                );
+            }
+        }
+
+        ToolOutput::json(json!({
+            "success": true,
+            "escalation_id": escalation_id,
+            "session_id": session_id,
+            "reason": reason,
+            "status": "deferred",
+            "live_transfer": false,
+            "next_step_intent": "callback_request",
+            "alternatives": ["callback_request", "send_sms"],
+            "created_at": now.to_rfc3339(),
+            "message": format!(
+                "{} I can arrange a callback as soon as they are back, or send you the details by SMS. Which would you prefer?",
+                unavailable_message(closure)
+            ),
+            "instructions": "Offer a callback or an SMS follow-up. Do not tell the customer to hold."
+        }))
     }
 }
 
@@ -170,3 +407,127 @@ impl Default for EscalateToHumanTool {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{NaiveDate, Weekday};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    fn utc(d: u32, h: u32, m: u32) -> DateTime<Utc> {
+        NaiveDate::from_ymd_opt(2026, 10, d)
+            .unwrap()
+            .and_hms_opt(h, m, 0)
+            .unwrap()
+            .and_utc()
+    }
+
+    fn input() -> Value {
+        json!({ "reason": "customer_request", "session_id": "s1" })
+    }
+
+    fn output_json(output: ToolOutput) -> Value {
+        match &output.content[0] {
+            crate::mcp::ContentBlock::Text { text } => serde_json::from_str(text).unwrap(),
+            _ => panic!("expected text output"),
+        }
+    }
+
+    /// Tool with IST 10am-5pm Mon-Sat hours, counting live transfers
+    fn office_hours_tool() -> (EscalateToHumanTool, Arc<AtomicUsize>) {
+        let transfers = Arc::new(AtomicUsize::new(0));
+        let counter = transfers.clone();
+        let tool = EscalateToHumanTool::with_callback(move |_, _, _| {
+            counter.fetch_add(1, Ordering::SeqCst);
+        })
+        .with_live_agent_hours(
+            OperatingHours::parse("10:00 AM - 5:00 PM (Mon-Sat)").unwrap(),
+            india_timezone(),
+        );
+        (tool, transfers)
+    }
+
+    #[tokio::test]
+    async fn test_after_hours_escalation_offers_callback() {
+        let (tool, transfers) = office_hours_tool();
+
+        // Friday 14:00 UTC is 19:30 IST
+        let output = output_json(tool.escalate(input(), utc(16, 14, 0)).await.unwrap());
+
+        assert_eq!(output["status"], "deferred");
+        assert_eq!(output["live_transfer"], false);
+        assert_eq!(output["next_step_intent"], "callback_request");
+        assert!(output["message"].as_str().unwrap().contains("callback"));
+        assert_eq!(transfers.load(Ordering::SeqCst), 0);
+
+        let deferred = tool.deferred_escalations();
+        assert_eq!(deferred.len(), 1);
+        assert_eq!(deferred[0].escalation_id, output["escalation_id"]);
+    }
+
+    #[tokio::test]
+    async fn test_in_hours_escalation_transfers_live() {
+        let (tool, transfers) = office_hours_tool();
+
+        // Friday 05:00 UTC is 10:30 IST
+        let output = output_json(tool.escalate(input(), utc(16, 5, 0)).await.unwrap());
+
+        assert_eq!(output["status"], "queued");
+        assert_eq!(output["live_transfer"], true);
+        assert_eq!(transfers.load(Ordering::SeqCst), 1);
+        assert!(tool.deferred_escalations().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_region_hours_use_region_timezone() {
+        let gulf = FixedOffset::east_opt(4 * 3600).unwrap();
+        let (tool, _) = office_hours_tool();
+        let tool = tool.with_region_hours(
+            "Dubai",
+            OperatingHours::new().with_days(
+                &[Weekday::Fri],
+                chrono::NaiveTime::from_hms_opt(9, 0, 0).unwrap(),
+                chrono::NaiveTime::from_hms_opt(18, 0, 0).unwrap(),
+            ),
+            gulf,
+        );
+
+        // Friday 13:45 UTC: 19:15 IST (closed), 17:45 in Dubai (open)
+        let mut dubai = input();
+        dubai["region"] = json!("dubai");
+        let output = output_json(tool.escalate(dubai, utc(16, 13, 45)).await.unwrap());
+        assert_eq!(output["status"], "queued");
+
+        let output = output_json(tool.escalate(input(), utc(16, 13, 45)).await.unwrap());
+        assert_eq!(output["status"], "deferred");
+    }
+
+    #[tokio::test]
+    async fn test_deferred_escalation_is_audited() {
+        let log = Arc::new(voice_agent_persistence::InMemoryAuditLog::new());
+        let (tool, _) = office_hours_tool();
+        let tool = tool.with_audit_logger(Arc::new(AuditLogger::new(log.clone())));
+
+        let output = output_json(tool.escalate(input(), utc(16, 14, 0)).await.unwrap());
+
+        let entries = log.entries_for("s1");
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].action, "defer_human_escalation");
+        assert_eq!(entries[0].resource_id, output["escalation_id"]);
+    }
+
+    #[tokio::test]
+    async fn test_deferred_escalations_are_bounded() {
+        let (tool, _) = office_hours_tool();
+
+        let mut first = None;
+        for _ in 0..=MAX_DEFERRED_ESCALATIONS {
+            let output = output_json(tool.escalate(input(), utc(16, 14, 0)).await.unwrap());
+            first.get_or_insert(output["escalation_id"].clone());
+        }
+
+        let deferred = tool.deferred_escalations();
+        assert_eq!(deferred.len(), MAX_DEFERRED_ESCALATIONS);
+        assert_ne!(deferred[0].escalation_id, first.unwrap());
+    }
+}
@@ -1,13 +1,19 @@
 //! Lead Capture Tool
 //!
-//! Capture customer lead information for follow-up.
+//! Capture customer lead information for follow-up. A returning caller is
+//! matched against existing CRM leads by normalized phone number or PAN;
+//! a match is updated with the new interaction instead of creating a
+//! duplicate lead.
 
 use async_trait::async_trait;
 use chrono::Utc;
 use serde_json::{json, Value};
 use std::sync::Arc;
 
-use crate::integrations::{CrmIntegration, CrmLead, InterestLevel, LeadSource, LeadStatus};
+use crate::integrations::{
+    normalize_pan, normalize_phone, save_lead, CrmIntegration, CrmLead, InterestLevel, LeadSource,
+    LeadStatus,
+};
 use crate::mcp::{InputSchema, PropertySchema, Tool, ToolError, ToolOutput, ToolSchema};
 
 /// Lead capture tool
@@ -50,6 +56,11 @@ impl Tool for LeadCaptureTool {
                     PropertySchema::string("10-digit mobile number"),
                     true,
                 )
+                .property(
+                    "pan",
+                    PropertySchema::string("Customer's PAN, if given"),
+                    false,
+                )
                 .property("city", PropertySchema::string("Customer's city"), false)
                 .property(
                     "preferred_location",
@@ -86,11 +97,19 @@ impl Tool for LeadCaptureTool {
         let phone = input
             .get("phone_number")
             .and_then(|v| v.as_str())
+            .map(normalize_phone)
             .ok_or_else(|| ToolError::invalid_params("phone_number is required"))?;
 
-        if phone.len() != 10 || !phone.chars().all(|c| c.is_ascii_digit()) {
+        if phone.len() != 10 {
             return Err(ToolError::invalid_params("phone_number must be 10 digits"));
         }
+        let phone = phone.as_str();
+
+        let pan = input
+            .get("pan")
+            .and_then(|v| v.as_str())
+            .map(normalize_pan)
+            .filter(|p| !p.is_empty());
 
         let city = input.get("city").and_then(|v| v.as_str()).map(String::from);
         let estimated_value = input.get("estimated_value").and_then(|v| v.as_f64());
@@ -114,6 +133,7 @@ impl Tool for LeadCaptureTool {
                 id: None,
                 name: name.to_string(),
                 phone: phone.to_string(),
+                pan,
                 email: None,
                 city,
                 source: LeadSource::VoiceAgent,
@@ -125,11 +145,12 @@ impl Tool for LeadCaptureTool {
                 status: LeadStatus::New,
             };
 
-            match crm.create_lead(lead).await {
-                Ok(lead_id) => {
+            match save_lead(crm.as_ref(), lead).await {
+                Ok((lead_id, existing_lead)) => {
                     let result = json!({
                         "success": true,
                         "lead_id": lead_id,
+                        "existing_lead": existing_lead,
                         "customer_name": name,
                         "phone_number": phone,
                         "city": input.get("city").and_then(|v| v.as_str()),
@@ -177,3 +198,107 @@ impl Default for LeadCaptureTool {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::integrations::InMemoryCrm;
+
+    fn lead_id(output: &ToolOutput) -> (String, bool) {
+        let text = match &output.content[0] {
+            crate::mcp::ContentBlock::Text { text } => text,
+            other => panic!("unexpected content: {:?}", other),
+        };
+        let value: Value = serde_json::from_str(text).unwrap();
+        (
+            value["lead_id"].as_str().unwrap().to_string(),
+            value["existing_lead"].as_bool().unwrap(),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_returning_phone_updates_existing_lead() {
+        let crm = Arc::new(InMemoryCrm::new());
+        let tool = LeadCaptureTool::with_crm(crm.clone());
+
+        let first = tool
+            .execute(json!({
+                "customer_name": "Rahul",
+                "phone_number": "9876543210",
+                "interest_level": "Low",
+            }))
+            .await
+            .unwrap();
+        let second = tool
+            .execute(json!({
+                "customer_name": "Rahul Sharma",
+                "phone_number": "+91 98765 43210",
+                "interest_level": "High",
+                "notes": "Wants to switch lender",
+            }))
+            .await
+            .unwrap();
+
+        let (first_id, first_existing) = lead_id(&first);
+        let (second_id, second_existing) = lead_id(&second);
+        assert!(!first_existing);
+        assert!(second_existing);
+        assert_eq!(first_id, second_id);
+
+        let leads = crm.leads();
+        assert_eq!(leads.len(), 1);
+        let lead = &leads[0];
+        assert_eq!(lead.name, "Rahul Sharma");
+        assert_eq!(lead.interest_level, InterestLevel::High);
+        assert_eq!(lead.status, LeadStatus::Contacted);
+        assert_eq!(lead.source, LeadSource::VoiceAgent);
+        assert!(lead
+            .notes
+            .as_deref()
+            .unwrap()
+            .contains("voice agent call: Wants to switch lender"));
+    }
+
+    #[tokio::test]
+    async fn test_new_phone_creates_fresh_lead() {
+        let crm = Arc::new(InMemoryCrm::new());
+        let tool = LeadCaptureTool::with_crm(crm.clone());
+
+        for phone in ["9876543210", "9123456780"] {
+            tool.execute(json!({ "customer_name": "Rahul", "phone_number": phone }))
+                .await
+                .unwrap();
+        }
+
+        let leads = crm.leads();
+        assert_eq!(leads.len(), 2);
+        assert!(leads.iter().all(|l| l.status == LeadStatus::New));
+    }
+
+    #[tokio::test]
+    async fn test_matching_pan_updates_lead_with_new_phone() {
+        let crm = Arc::new(InMemoryCrm::new());
+        let tool = LeadCaptureTool::with_crm(crm.clone());
+
+        tool.execute(json!({
+            "customer_name": "Rahul",
+            "phone_number": "9876543210",
+            "pan": "ABCDE1234F",
+        }))
+        .await
+        .unwrap();
+        let output = tool
+            .execute(json!({
+                "customer_name": "Rahul",
+                "phone_number": "9123456780",
+                "pan": "abcde 1234f",
+            }))
+            .await
+            .unwrap();
+
+        assert!(lead_id(&output).1);
+        let leads = crm.leads();
+        assert_eq!(leads.len(), 1);
+        assert_eq!(leads[0].phone, "9123456780");
+    }
+}
@@ -17,12 +17,12 @@ mod savings;
 mod sms;
 
 // Re-export all tools
-pub use appointment::AppointmentSchedulerTool;
+pub use appointment::{appointment_slots, AppointmentChangeTool, AppointmentSchedulerTool};
 pub use branch_locator::BranchLocatorTool;
 pub use competitor::CompetitorComparisonTool;
 pub use document_checklist::DocumentChecklistTool;
 pub use eligibility::EligibilityCheckTool;
-pub use escalate::EscalateToHumanTool;
+pub use escalate::{DeferredEscalation, EscalateToHumanTool};
 pub use lead_capture::LeadCaptureTool;
 pub use price::GetPriceTool;
 /// Legacy alias for backwards compatibility
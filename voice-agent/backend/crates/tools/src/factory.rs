@@ -261,15 +261,30 @@ impl DomainToolFactory {
                 }
             }
 
+            "change_appointment" | "reschedule_appointment" | "cancel_appointment" => {
+                let scheduler = match self.integrations.calendar {
+                    Some(ref calendar) => {
+                        domain_tools::AppointmentSchedulerTool::with_calendar_and_view(
+                            calendar.clone(),
+                            self.view.clone(),
+                        )
+                    },
+                    None => domain_tools::AppointmentSchedulerTool::with_view(self.view.clone()),
+                };
+                Ok(Arc::new(domain_tools::AppointmentChangeTool::new(
+                    scheduler,
+                )))
+            },
+
             // Document tools
             "get_document_checklist" | "document_checklist" => Ok(Arc::new(
                 domain_tools::DocumentChecklistTool::with_view(self.view.clone()),
             )),
 
             // Escalation tools
-            "escalate_to_human" | "escalate" | "human_agent" => {
-                Ok(Arc::new(domain_tools::EscalateToHumanTool::new()))
-            }
+            "escalate_to_human" | "escalate" | "human_agent" => Ok(Arc::new(
+                domain_tools::EscalateToHumanTool::with_view(self.view.clone()),
+            )),
 
             // Unknown tool - check if it's in config but not implemented
             _ => {
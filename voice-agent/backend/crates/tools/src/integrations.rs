@@ -3,9 +3,16 @@
 //! P0 FIX: Traits and stubs for CRM and Calendar integrations.
 //! These will be implemented when actual systems are available.
 
+use std::sync::Arc;
+
 use async_trait::async_trait;
+use chrono::Utc;
+use parking_lot::Mutex;
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
+use voice_agent_persistence::{ErasureTarget, PersistenceError};
+
+pub use voice_agent_core::normalize_phone;
 
 /// Integration errors
 #[derive(Error, Debug)]
@@ -56,6 +63,8 @@ pub struct CrmLead {
     pub name: String,
     /// Phone number
     pub phone: String,
+    /// PAN (optional, used to match returning customers)
+    pub pan: Option<String>,
     /// Email (optional)
     pub email: Option<String>,
     /// City
@@ -76,8 +85,16 @@ pub struct CrmLead {
     pub status: LeadStatus,
 }
 
+/// PAN in upper case without whitespace
+pub fn normalize_pan(pan: &str) -> String {
+    pan.chars()
+        .filter(|c| !c.is_whitespace())
+        .collect::<String>()
+        .to_uppercase()
+}
+
 /// Lead source
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
 #[serde(rename_all = "snake_case")]
 pub enum LeadSource {
     #[default]
@@ -89,7 +106,7 @@ pub enum LeadSource {
 }
 
 /// Interest level
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
 #[serde(rename_all = "snake_case")]
 pub enum InterestLevel {
     High,
@@ -98,8 +115,27 @@ pub enum InterestLevel {
     Low,
 }
 
+impl InterestLevel {
+    fn rank(self) -> u8 {
+        match self {
+            InterestLevel::Low => 0,
+            InterestLevel::Medium => 1,
+            InterestLevel::High => 2,
+        }
+    }
+
+    /// The higher of two interest levels
+    pub fn max(self, other: Self) -> Self {
+        if other.rank() > self.rank() {
+            other
+        } else {
+            self
+        }
+    }
+}
+
 /// Lead status
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
 #[serde(rename_all = "snake_case")]
 pub enum LeadStatus {
     #[default]
@@ -115,7 +151,9 @@ pub enum LeadStatus {
 /// CRM integration trait
 ///
 /// Implement this trait to integrate with your CRM system
-/// (e.g., Salesforce, HubSpot, Zoho CRM).
+/// (e.g., Salesforce, HubSpot, Zoho CRM). Leads are written through
+/// [`save_lead`], so phone numbers and PANs reach the CRM normalized and a
+/// returning caller's lead is merged rather than duplicated.
 #[async_trait]
 pub trait CrmIntegration: Send + Sync {
     /// Create a new lead
@@ -130,6 +168,13 @@ pub trait CrmIntegration: Send + Sync {
     /// Search leads by phone number
     async fn find_by_phone(&self, phone: &str) -> Result<Vec<CrmLead>, IntegrationError>;
 
+    /// Search leads by PAN
+    ///
+    /// CRMs that don't index PAN can keep the default, which finds nothing.
+    async fn find_by_pan(&self, _pan: &str) -> Result<Vec<CrmLead>, IntegrationError> {
+        Ok(Vec::new())
+    }
+
     /// Assign lead to sales rep
     async fn assign_lead(&self, lead_id: &str, rep_id: &str) -> Result<(), IntegrationError>;
 
@@ -142,6 +187,142 @@ pub trait CrmIntegration: Send + Sync {
         lead_id: &str,
         status: LeadStatus,
     ) -> Result<(), IntegrationError>;
+
+    /// Delete every lead for a phone number, for a right-to-erasure request
+    ///
+    /// Returns how many leads were deleted.
+    async fn erase_by_phone(&self, phone: &str) -> Result<usize, IntegrationError>;
+}
+
+/// Existing CRM lead for the same normalized phone number or PAN
+pub async fn find_existing_lead(
+    crm: &dyn CrmIntegration,
+    phone: &str,
+    pan: Option<&str>,
+) -> Option<CrmLead> {
+    let phone = normalize_phone(phone);
+    let pan = pan.map(normalize_pan).filter(|p| !p.is_empty());
+    let phone = phone.as_str();
+    let pan = pan.as_deref();
+
+    let mut candidates = crm.find_by_phone(phone).await.unwrap_or_else(|e| {
+        tracing::warn!("CRM phone lookup failed: {}", e);
+        Vec::new()
+    });
+    if let Some(pan) = pan {
+        candidates.extend(crm.find_by_pan(pan).await.unwrap_or_else(|e| {
+            tracing::warn!("CRM PAN lookup failed: {}", e);
+            Vec::new()
+        }));
+    }
+
+    candidates.into_iter().find(|lead| {
+        lead.id.is_some()
+            && (normalize_phone(&lead.phone) == phone
+                || pan.is_some_and(|pan| {
+                    lead.pan.as_deref().map(normalize_pan).as_deref() == Some(pan)
+                }))
+    })
+}
+
+/// Fold a repeat interaction into the existing lead
+///
+/// Details from the new interaction win, it is appended to the notes and
+/// the interest level only goes up. The original source and assignee are
+/// kept; a lead that was new is now contacted, a lost lead is reopened, and
+/// a qualified interaction qualifies a lead that wasn't further along.
+pub fn merge_lead(existing: CrmLead, update: CrmLead) -> CrmLead {
+    let interaction = format!(
+        "{} voice agent call: {}",
+        Utc::now().format("%Y-%m-%d"),
+        update.notes.as_deref().unwrap_or("lead captured again")
+    );
+    let notes = match existing.notes.filter(|n| !n.is_empty()) {
+        Some(previous) => format!("{}\n{}", previous, interaction),
+        None => interaction,
+    };
+    let status = match (existing.status, update.status) {
+        (LeadStatus::New | LeadStatus::Contacted | LeadStatus::Lost, LeadStatus::Qualified) => {
+            LeadStatus::Qualified
+        },
+        (LeadStatus::New | LeadStatus::Lost, _) => LeadStatus::Contacted,
+        (status, _) => status,
+    };
+
+    CrmLead {
+        id: existing.id,
+        name: if update.name.is_empty() {
+            existing.name
+        } else {
+            update.name
+        },
+        phone: update.phone,
+        pan: update.pan.or(existing.pan),
+        email: update.email.or(existing.email),
+        city: update.city.or(existing.city),
+        source: existing.source,
+        interest_level: existing.interest_level.max(update.interest_level),
+        estimated_asset_value: update
+            .estimated_asset_value
+            .or(existing.estimated_asset_value),
+        current_provider: update.current_provider.or(existing.current_provider),
+        notes: Some(notes),
+        assigned_to: existing.assigned_to,
+        status,
+    }
+}
+
+/// Write a lead to the CRM, merging it into the caller's existing lead
+///
+/// The phone number and PAN are normalized first, so the same caller is
+/// matched however the number was spoken or typed. Returns the lead ID and
+/// whether an existing lead was updated.
+pub async fn save_lead(
+    crm: &dyn CrmIntegration,
+    mut lead: CrmLead,
+) -> Result<(String, bool), IntegrationError> {
+    lead.phone = normalize_phone(&lead.phone);
+    lead.pan = lead
+        .pan
+        .as_deref()
+        .map(normalize_pan)
+        .filter(|p| !p.is_empty());
+
+    match find_existing_lead(crm, &lead.phone, lead.pan.as_deref()).await {
+        Some(existing) => {
+            let lead_id = existing.id.clone().unwrap_or_default();
+            tracing::info!(lead_id = %lead_id, "Updating existing CRM lead");
+            crm.update_lead(&lead_id, merge_lead(existing, lead))
+                .await
+                .map(|_| (lead_id, true))
+        },
+        None => crm.create_lead(lead).await.map(|lead_id| (lead_id, false)),
+    }
+}
+
+/// Erases a customer's CRM leads along with their persisted data
+pub struct CrmErasure {
+    crm: Arc<dyn CrmIntegration>,
+}
+
+impl CrmErasure {
+    pub fn new(crm: Arc<dyn CrmIntegration>) -> Self {
+        Self { crm }
+    }
+}
+
+#[async_trait]
+impl ErasureTarget for CrmErasure {
+    fn name(&self) -> &'static str {
+        "crm_leads_deleted"
+    }
+
+    async fn erase(&self, phone: &str, _session_ids: &[String]) -> Result<usize, PersistenceError> {
+        self.crm
+            .erase_by_phone(&normalize_phone(phone))
+            .await
+            .map_err(|e| PersistenceError::Query(format!("CRM erasure failed: {}", e)))
+    }
 }
 
 /// Stub CRM implementation for development/testing
@@ -185,6 +366,7 @@ impl CrmIntegration for StubCrmIntegration {
             id: Some(id.to_string()),
             name: "Mock Customer".to_string(),
             phone: "9999999999".to_string(),
+            pan: None,
             email: None,
             city: Some("Mumbai".to_string()),
             source: LeadSource::VoiceAgent,
@@ -220,6 +402,128 @@ impl CrmIntegration for StubCrmIntegration {
         tracing::info!(lead_id = %lead_id, status = ?status, "Stub CRM: Updated status");
         Ok(())
     }
+
+    async fn erase_by_phone(&self, _phone: &str) -> Result<usize, IntegrationError> {
+        tracing::info!("Stub CRM: Erased leads");
+        Ok(0)
+    }
+}
+
+/// CRM keeping leads in memory
+///
+/// Used until a real CRM is integrated, and in tests.
+#[derive(Default)]
+pub struct InMemoryCrm {
+    leads: Mutex<Vec<CrmLead>>,
+}
+
+impl InMemoryCrm {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Snapshot of every lead
+    pub fn leads(&self) -> Vec<CrmLead> {
+        self.leads.lock().clone()
+    }
+}
+
+#[async_trait]
+impl CrmIntegration for InMemoryCrm {
+    async fn create_lead(&self, mut lead: CrmLead) -> Result<String, IntegrationError> {
+        let mut leads = self.leads.lock();
+        let id = format!("LEAD-{}", leads.len() + 1);
+        lead.id = Some(id.clone());
+        leads.push(lead);
+        Ok(id)
+    }
+
+    async fn update_lead(&self, id: &str, mut lead: CrmLead) -> Result<(), IntegrationError> {
+        let mut leads = self.leads.lock();
+        let existing = leads
+            .iter_mut()
+            .find(|l| l.id.as_deref() == Some(id))
+            .ok_or_else(|| IntegrationError::NotFound(id.to_string()))?;
+        lead.id = Some(id.to_string());
+        *existing = lead;
+        Ok(())
+    }
+
+    async fn get_lead(&self, id: &str) -> Result<CrmLead, IntegrationError> {
+        self.leads
+            .lock()
+            .iter()
+            .find(|l| l.id.as_deref() == Some(id))
+            .cloned()
+            .ok_or_else(|| IntegrationError::NotFound(id.to_string()))
+    }
+
+    async fn find_by_phone(&self, phone: &str) -> Result<Vec<CrmLead>, IntegrationError> {
+        let phone = normalize_phone(phone);
+        Ok(self
+            .leads
+            .lock()
+            .iter()
+            .filter(|l| normalize_phone(&l.phone) == phone)
+            .cloned()
+            .collect())
+    }
+
+    async fn find_by_pan(&self, pan: &str) -> Result<Vec<CrmLead>, IntegrationError> {
+        let pan = normalize_pan(pan);
+        Ok(self
+            .leads
+            .lock()
+            .iter()
+            .filter(|l| l.pan.as_deref().map(normalize_pan).as_deref() == Some(pan.as_str()))
+            .cloned()
+            .collect())
+    }
+
+    async fn assign_lead(&self, lead_id: &str, rep_id: &str) -> Result<(), IntegrationError> {
+        let mut leads = self.leads.lock();
+        let lead = leads
+            .iter_mut()
+            .find(|l| l.id.as_deref() == Some(lead_id))
+            .ok_or_else(|| IntegrationError::NotFound(lead_id.to_string()))?;
+        lead.assigned_to = Some(rep_id.to_string());
+        Ok(())
+    }
+
+    async fn add_note(&self, lead_id: &str, note: &str) -> Result<(), IntegrationError> {
+        let mut leads = self.leads.lock();
+        let lead = leads
+            .iter_mut()
+            .find(|l| l.id.as_deref() == Some(lead_id))
+            .ok_or_else(|| IntegrationError::NotFound(lead_id.to_string()))?;
+        lead.notes = Some(match lead.notes.take().filter(|n| !n.is_empty()) {
+            Some(previous) => format!("{}\n{}", previous, note),
+            None => note.to_string(),
+        });
+        Ok(())
+    }
+
+    async fn update_status(
+        &self,
+        lead_id: &str,
+        status: LeadStatus,
+    ) -> Result<(), IntegrationError> {
+        let mut leads = self.leads.lock();
+        let lead = leads
+            .iter_mut()
+            .find(|l| l.id.as_deref() == Some(lead_id))
+            .ok_or_else(|| IntegrationError::NotFound(lead_id.to_string()))?;
+        lead.status = status;
+        Ok(())
+    }
+
+    async fn erase_by_phone(&self, phone: &str) -> Result<usize, IntegrationError> {
+        let phone = normalize_phone(phone);
+        let mut leads = self.leads.lock();
+        let before = leads.len();
+        leads.retain(|l| normalize_phone(&l.phone) != phone);
+        Ok(before - leads.len())
+    }
 }
 
 // ============================================================================
@@ -294,6 +598,8 @@ pub enum AppointmentStatus {
     #[default]
     Scheduled,
     Confirmed,
+    /// Moved to a new date or time
+    Rescheduled,
     InProgress,
     Completed,
     Cancelled,
@@ -481,6 +787,7 @@ mod tests {
             id: None,
             name: "Test Customer".to_string(),
             phone: "9876543210".to_string(),
+            pan: None,
             email: None,
             city: Some("Mumbai".to_string()),
             source: LeadSource::VoiceAgent,
@@ -496,6 +803,81 @@ mod tests {
         assert!(id.starts_with("LEAD-"));
     }
 
+    #[test]
+    fn test_phone_and_pan_normalization() {
+        assert_eq!(normalize_phone("+91 98765-43210"), "9876543210");
+        assert_eq!(normalize_phone("098765 43210"), "9876543210");
+        assert_eq!(normalize_phone("9876543210"), "9876543210");
+        assert_eq!(normalize_pan(" abcde 1234f "), "ABCDE1234F");
+    }
+
+    #[tokio::test]
+    async fn test_save_lead_merges_by_normalized_phone() {
+        let crm = InMemoryCrm::new();
+        let lead = |phone: &str, status: LeadStatus, notes: &str| CrmLead {
+            id: None,
+            name: String::new(),
+            phone: phone.to_string(),
+            pan: None,
+            email: None,
+            city: None,
+            source: LeadSource::VoiceAgent,
+            interest_level: InterestLevel::Medium,
+            estimated_asset_value: None,
+            current_provider: None,
+            notes: Some(notes.to_string()),
+            assigned_to: None,
+            status,
+        };
+
+        let first = lead("+91 98765-43210", LeadStatus::New, "First call");
+        let (first_id, existing) = save_lead(&crm, first).await.unwrap();
+        assert!(!existing);
+        let second = lead("9876543210", LeadStatus::Qualified, "Second call");
+        let (second_id, existing) = save_lead(&crm, second).await.unwrap();
+        assert!(existing);
+        assert_eq!(first_id, second_id);
+
+        let leads = crm.leads();
+        assert_eq!(leads.len(), 1);
+        assert_eq!(leads[0].phone, "9876543210");
+        assert_eq!(leads[0].status, LeadStatus::Qualified);
+        let notes = leads[0].notes.as_deref().unwrap();
+        assert!(notes.starts_with("First call\n"));
+        assert!(notes.contains("voice agent call: Second call"));
+
+        let erasure = CrmErasure::new(Arc::new(crm));
+        assert_eq!(erasure.erase("098765 43210", &[]).await.unwrap(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_find_existing_lead_normalizes_lookup() {
+        let crm = InMemoryCrm::new();
+        crm.create_lead(CrmLead {
+            id: None,
+            name: "Rahul".to_string(),
+            phone: "9876543210".to_string(),
+            pan: Some("ABCDE1234F".to_string()),
+            email: None,
+            city: None,
+            source: LeadSource::VoiceAgent,
+            interest_level: InterestLevel::Medium,
+            estimated_asset_value: None,
+            current_provider: None,
+            notes: None,
+            assigned_to: None,
+            status: LeadStatus::New,
+        })
+        .await
+        .unwrap();
+
+        let by_phone = find_existing_lead(&crm, "+91 98765 43210", None).await;
+        assert!(by_phone.is_some());
+        let by_pan = find_existing_lead(&crm, "9123456780", Some(" abcde 1234f")).await;
+        assert!(by_pan.is_some());
+        assert!(find_existing_lead(&crm, "9123456780", None).await.is_none());
+    }
+
     #[tokio::test]
     async fn test_stub_calendar_get_slots() {
         let calendar = StubCalendarIntegration::new();
@@ -25,17 +25,23 @@ pub mod registry;
 pub use domain_tools::{
     // Location data management
     get_branches, find_locations, load_branches_from_file, reload_branches, BranchData,
+    initialize_pincode_regions, resolve_pincode, sort_by_distance, Coordinates, PincodeRegion,
+    // Operating hours
+    india_timezone, Closure, DayHours, OperatingHours,
+    // Appointment reminders and calendar
+    AppointmentReminders, ReminderDetails, StoreCalendar,
     // Utility functions
     calculate_emi, calculate_total_interest,
     // Tool implementations
-    AppointmentSchedulerTool, BranchLocatorTool, CompetitorComparisonTool, DocumentChecklistTool,
-    EligibilityCheckTool, EscalateToHumanTool, GetGoldPriceTool, LeadCaptureTool,
-    SavingsCalculatorTool, SendSmsTool,
+    appointment_slots, AppointmentChangeTool, AppointmentSchedulerTool, BranchLocatorTool,
+    CompetitorComparisonTool, DeferredEscalation, DocumentChecklistTool, EligibilityCheckTool,
+    EscalateToHumanTool, GetGoldPriceTool, LeadCaptureTool, SavingsCalculatorTool, SendSmsTool,
 };
 pub use integrations::{
-    Appointment, AppointmentPurpose, AppointmentStatus, CalendarIntegration, CrmIntegration,
-    CrmLead, IntegrationError, InterestLevel, LeadSource, LeadStatus, StubCalendarIntegration,
-    StubCrmIntegration, TimeSlot,
+    Appointment, AppointmentPurpose, AppointmentStatus, CalendarIntegration, CrmErasure,
+    CrmIntegration, CrmLead, InMemoryCrm, IntegrationError, InterestLevel, LeadSource, LeadStatus,
+    StubCalendarIntegration, StubCrmIntegration, TimeSlot, find_existing_lead, merge_lead,
+    normalize_pan, normalize_phone, save_lead,
 };
 pub use mcp::{
     methods,
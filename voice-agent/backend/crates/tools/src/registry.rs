@@ -285,9 +285,13 @@ pub fn create_registry_with_view(
     // Tools that don't need domain config (CRM/calendar integrations only)
     registry.register(crate::domain_tools::LeadCaptureTool::new());
     // P16 FIX: Appointment tool uses view for config-driven purposes/times
-    registry.register(crate::domain_tools::AppointmentSchedulerTool::with_view(view.clone()));
+    let appointments = crate::domain_tools::AppointmentSchedulerTool::with_view(view.clone());
+    registry.register(crate::domain_tools::AppointmentChangeTool::new(
+        appointments.clone(),
+    ));
+    registry.register(appointments);
     registry.register(crate::domain_tools::BranchLocatorTool::new());
-    registry.register(crate::domain_tools::EscalateToHumanTool::new());
+    registry.register(crate::domain_tools::EscalateToHumanTool::with_view(view.clone()));
     // P16 FIX: SMS and Document tools now use view for config-driven content
     registry.register(crate::domain_tools::SendSmsTool::with_view(view.clone()));
     registry.register(crate::domain_tools::DocumentChecklistTool::with_view(view.clone()));
@@ -487,16 +491,20 @@ pub fn create_registry_with_integrations(config: IntegrationConfig) -> ToolRegis
     }
 
     // P16 FIX: AppointmentSchedulerTool with optional calendar integration and view
-    if let Some(calendar) = config.calendar {
-        registry.register(crate::domain_tools::AppointmentSchedulerTool::with_calendar_and_view(
+    let appointments = if let Some(calendar) = config.calendar {
+        crate::domain_tools::AppointmentSchedulerTool::with_calendar_and_view(
             calendar,
             config.view.clone(),
-        ));
+        )
     } else {
-        registry.register(crate::domain_tools::AppointmentSchedulerTool::with_view(config.view.clone()));
-    }
+        crate::domain_tools::AppointmentSchedulerTool::with_view(config.view.clone())
+    };
+    registry.register(crate::domain_tools::AppointmentChangeTool::new(
+        appointments.clone(),
+    ));
+    registry.register(appointments);
 
-    registry.register(crate::domain_tools::EscalateToHumanTool::new());
+    registry.register(crate::domain_tools::EscalateToHumanTool::with_view(config.view.clone()));
     // P16 FIX: SMS and Document tools now use view for config-driven content
     registry.register(crate::domain_tools::SendSmsTool::with_view(config.view.clone()));
     registry.register(crate::domain_tools::DocumentChecklistTool::with_view(config.view.clone()));
@@ -527,6 +535,10 @@ pub struct FullIntegrationConfig {
     pub sms_service: Option<Arc<dyn voice_agent_persistence::SmsService>>,
     /// P16 FIX: Asset price service (generic, gold_price_service for backwards compatibility)
     pub gold_price_service: Option<Arc<dyn voice_agent_persistence::AssetPriceService>>,
+    /// Reminder SMS scheduled for booked appointments
+    pub reminders: Option<Arc<crate::domain_tools::AppointmentReminders>>,
+    /// Audit log for escalations deferred outside live agent hours
+    pub audit_logger: Option<Arc<voice_agent_persistence::AuditLogger>>,
 }
 
 impl FullIntegrationConfig {
@@ -538,6 +550,8 @@ impl FullIntegrationConfig {
             calendar: None,
             sms_service: None,
             gold_price_service: None,
+            reminders: None,
+            audit_logger: None,
         }
     }
 
@@ -556,6 +570,8 @@ impl FullIntegrationConfig {
             // P16 FIX: Use generic asset_price field (AssetPriceService)
             gold_price_service: Some(Arc::new(persistence.asset_price.clone())
                 as Arc<dyn voice_agent_persistence::AssetPriceService>),
+            reminders: None,
+            audit_logger: None,
         }
     }
 
@@ -589,6 +605,24 @@ impl FullIntegrationConfig {
         self
     }
 
+    /// Schedule reminder SMS for booked appointments
+    pub fn with_reminders(
+        mut self,
+        reminders: Arc<crate::domain_tools::AppointmentReminders>,
+    ) -> Self {
+        self.reminders = Some(reminders);
+        self
+    }
+
+    /// Audit escalations deferred outside live agent hours
+    pub fn with_audit_logger(
+        mut self,
+        audit_logger: Arc<voice_agent_persistence::AuditLogger>,
+    ) -> Self {
+        self.audit_logger = Some(audit_logger);
+        self
+    }
+
     /// P16 FIX: Set asset price service (preferred method name)
     pub fn with_asset_price_service(
         mut self,
@@ -622,14 +656,21 @@ pub fn create_registry_with_persistence(config: FullIntegrationConfig) -> ToolRe
     }
 
     // P16 FIX: AppointmentSchedulerTool with optional calendar integration and view
-    if let Some(calendar) = config.calendar {
-        registry.register(crate::domain_tools::AppointmentSchedulerTool::with_calendar_and_view(
+    let mut appointments = if let Some(calendar) = config.calendar {
+        crate::domain_tools::AppointmentSchedulerTool::with_calendar_and_view(
             calendar,
             config.view.clone(),
-        ));
+        )
     } else {
-        registry.register(crate::domain_tools::AppointmentSchedulerTool::with_view(config.view.clone()));
+        crate::domain_tools::AppointmentSchedulerTool::with_view(config.view.clone())
+    };
+    if let Some(reminders) = config.reminders {
+        appointments = appointments.with_reminders(reminders);
     }
+    registry.register(crate::domain_tools::AppointmentChangeTool::new(
+        appointments.clone(),
+    ));
+    registry.register(appointments);
 
     // GetGoldPriceTool with REQUIRED view and optional price service
     if let Some(service) = config.gold_price_service {
@@ -641,8 +682,12 @@ pub fn create_registry_with_persistence(config: FullIntegrationConfig) -> ToolRe
         registry.register(crate::domain_tools::GetGoldPriceTool::new(config.view.clone()));
     }
 
-    // EscalateToHumanTool (no domain config needed)
-    registry.register(crate::domain_tools::EscalateToHumanTool::new());
+    // EscalateToHumanTool checks the domain's live agent hours
+    let mut escalate = crate::domain_tools::EscalateToHumanTool::with_view(config.view.clone());
+    if let Some(audit_logger) = config.audit_logger {
+        escalate = escalate.with_audit_logger(audit_logger);
+    }
+    registry.register(escalate);
 
     // P16 FIX: SendSmsTool with view and optional persistence service
     if let Some(sms_service) = config.sms_service {
@@ -741,12 +786,13 @@ mod tests {
         let registry = create_registry_with_integrations(config);
 
         // P20 FIX: Tool names now come from config (domain-agnostic)
-        // Should have all 10 tools
-        assert_eq!(registry.len(), 10);
+        // Should have all 11 tools
+        assert_eq!(registry.len(), 11);
         assert!(registry.has("check_eligibility"));
         assert!(registry.has("calculate_savings"));
         assert!(registry.has("capture_lead"));
         assert!(registry.has("schedule_appointment"));
+        assert!(registry.has("change_appointment"));
         assert!(registry.has("find_locations")); // Config-driven name (was find_branches)
         assert!(registry.has("get_price")); // Config-driven name (was get_gold_price)
         assert!(registry.has("escalate_to_human"));
@@ -762,10 +808,11 @@ mod tests {
         let registry = create_registry_with_integrations(config);
 
         // P20 FIX: Tool names now come from config (domain-agnostic)
-        // Should still have all 10 tools (just without integrations)
-        assert_eq!(registry.len(), 10);
+        // Should still have all 11 tools (just without integrations)
+        assert_eq!(registry.len(), 11);
         assert!(registry.has("capture_lead"));
         assert!(registry.has("schedule_appointment"));
+        assert!(registry.has("change_appointment"));
         assert!(registry.has("get_price")); // Config-driven name (was get_gold_price)
         assert!(registry.has("escalate_to_human"));
         assert!(registry.has("send_sms"));
@@ -779,12 +826,13 @@ mod tests {
         let registry = create_registry_with_view(view);
 
         // P20 FIX: Tool names now come from config (domain-agnostic)
-        // Registry should have all 10 tools
-        assert_eq!(registry.len(), 10);
+        // Registry should have all 11 tools
+        assert_eq!(registry.len(), 11);
         assert!(registry.has("check_eligibility"));
         assert!(registry.has("calculate_savings"));
         assert!(registry.has("capture_lead"));
         assert!(registry.has("schedule_appointment"));
+        assert!(registry.has("change_appointment"));
         assert!(registry.has("find_locations")); // Config-driven name (was find_branches)
         assert!(registry.has("get_price")); // Config-driven name (was get_gold_price)
         assert!(registry.has("escalate_to_human"));
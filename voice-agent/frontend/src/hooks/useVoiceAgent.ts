@@ -178,7 +178,7 @@ export function useVoiceAgent(): UseVoiceAgentReturn {
             // Note: Browser TTS timing is logged via utterance.onend callback
           }
         } else if (message.type === 'error') {
-          console.error('[ERROR] Server error:', message.message)
+          console.error(`[ERROR] Server error (${message.code}):`, message.message)
         }
       }
 
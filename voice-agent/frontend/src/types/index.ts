@@ -59,9 +59,25 @@ export interface WsResponseAudioMessage {
   data: string; // base64 PCM audio (fallback if WebRTC fails)
 }
 
+export type WsErrorCode =
+  | 'rate_limited'
+  | 'invalid_message'
+  | 'llm_error'
+  | 'tool_error'
+  | 'timeout'
+  | 'agent_error'
+  | 'conversation_ended'
+  | 'session_unavailable'
+  | 'session_conflict'
+  | 'unauthorized'
+  | 'transport_error'
+  | 'internal';
+
 export interface WsErrorMessage {
   type: 'error';
+  code: WsErrorCode;
   message: string;
+  recoverable: boolean; // false: the server closes the socket after this frame
 }
 
 export interface WsPongMessage {